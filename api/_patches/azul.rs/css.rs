@@ -76,6 +76,9 @@
             CssPropertyType::BoxShadowTop => CssProperty::BoxShadowTop(StyleBoxShadowValue::$content_type),
             CssPropertyType::BoxShadowBottom => CssProperty::BoxShadowBottom(StyleBoxShadowValue::$content_type),
             CssPropertyType::ScrollbarStyle => CssProperty::ScrollbarStyle(ScrollbarStyleValue::$content_type),
+            CssPropertyType::ScrollBehavior => CssProperty::ScrollBehavior(StyleScrollBehaviorValue::$content_type),
+            CssPropertyType::ScrollSnapType => CssProperty::ScrollSnapType(StyleScrollSnapTypeValue::$content_type),
+            CssPropertyType::ScrollSnapAlign => CssProperty::ScrollSnapAlign(StyleScrollSnapAlignValue::$content_type),
             CssPropertyType::Opacity => CssProperty::Opacity(StyleOpacityValue::$content_type),
             CssPropertyType::Transform => CssProperty::Transform(StyleTransformVecValue::$content_type),
             CssPropertyType::PerspectiveOrigin => CssProperty::PerspectiveOrigin(StylePerspectiveOriginValue::$content_type),
@@ -158,6 +161,9 @@
                 CssProperty::BoxShadowTop(_) => CssPropertyType::BoxShadowTop,
                 CssProperty::BoxShadowBottom(_) => CssPropertyType::BoxShadowBottom,
                 CssProperty::ScrollbarStyle(_) => CssPropertyType::ScrollbarStyle,
+                CssProperty::ScrollBehavior(_) => CssPropertyType::ScrollBehavior,
+                CssProperty::ScrollSnapType(_) => CssPropertyType::ScrollSnapType,
+                CssProperty::ScrollSnapAlign(_) => CssPropertyType::ScrollSnapAlign,
                 CssProperty::Opacity(_) => CssPropertyType::Opacity,
                 CssProperty::Transform(_) => CssPropertyType::Transform,
                 CssProperty::PerspectiveOrigin(_) => CssPropertyType::PerspectiveOrigin,
@@ -241,6 +247,9 @@
         pub const fn box_shadow_right(input: StyleBoxShadow) -> Self { CssProperty::BoxShadowRight(StyleBoxShadowValue::Exact(input)) }
         pub const fn box_shadow_top(input: StyleBoxShadow) -> Self { CssProperty::BoxShadowTop(StyleBoxShadowValue::Exact(input)) }
         pub const fn box_shadow_bottom(input: StyleBoxShadow) -> Self { CssProperty::BoxShadowBottom(StyleBoxShadowValue::Exact(input)) }
+        pub const fn scroll_behavior(input: StyleScrollBehavior) -> Self { CssProperty::ScrollBehavior(StyleScrollBehaviorValue::Exact(input)) }
+        pub const fn scroll_snap_type(input: StyleScrollSnapType) -> Self { CssProperty::ScrollSnapType(StyleScrollSnapTypeValue::Exact(input)) }
+        pub const fn scroll_snap_align(input: StyleScrollSnapAlign) -> Self { CssProperty::ScrollSnapAlign(StyleScrollSnapAlignValue::Exact(input)) }
         pub const fn opacity(input: StyleOpacity) -> Self { CssProperty::Opacity(StyleOpacityValue::Exact(input)) }
         pub const fn transform(input: StyleTransformVec) -> Self { CssProperty::Transform(StyleTransformVecValue::Exact(input)) }
         pub const fn transform_origin(input: StyleTransformOrigin) -> Self { CssProperty::TransformOrigin(StyleTransformOriginValue::Exact(input)) }
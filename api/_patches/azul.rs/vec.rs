@@ -351,6 +351,8 @@
     impl_vec_clone!(AzMonitor, AzMonitorVec, AzMonitorVecDestructor);
     impl_vec!(AzStyleFontFamily, AzStyleFontFamilyVec, AzStyleFontFamilyVecDestructor, az_style_font_family_vec_destructor, AzStyleFontFamilyVec_delete);
     impl_vec_clone!(AzStyleFontFamily, AzStyleFontFamilyVec, AzStyleFontFamilyVecDestructor);
+    impl_vec!(AzStyleCursor, AzStyleCursorVec, AzStyleCursorVecDestructor, az_style_cursor_vec_destructor, AzStyleCursorVec_delete);
+    impl_vec_clone!(AzStyleCursor, AzStyleCursorVec, AzStyleCursorVecDestructor);
     impl_vec!(AzNodeTypeIdInfoMap, AzNodeTypeIdInfoMapVec, AzNodeTypeIdInfoMapVecDestructor, az_node_type_id_info_map_vec_destructor, AzNodeTypeIdInfoMapVec_delete);
     impl_vec_clone!(AzNodeTypeIdInfoMap, AzNodeTypeIdInfoMapVec, AzNodeTypeIdInfoMapVecDestructor);
     impl_vec!(AzInputOutputTypeIdInfoMap, AzInputOutputTypeIdInfoMapVec, AzInputOutputTypeIdInfoMapVecDestructor, az_input_output_type_id_info_map_vec_destructor, AzInputOutputTypeIdInfoMapVec_delete);
@@ -380,6 +382,14 @@
     impl_vec_clone!(AzAccessibilityState,  AzAccessibilityStateVec,  AzAccessibilityStateVecDestructor);
     impl_vec!(AzMenuItem,  AzMenuItemVec,  AzMenuItemVecDestructor, az_menu_item_vec_destructor, AzMenuItemVec_delete);
     impl_vec_clone!(AzMenuItem,  AzMenuItemVec,  AzMenuItemVecDestructor);
+    impl_vec!(AzNotificationAction, AzNotificationActionVec, AzNotificationActionVecDestructor, az_notification_action_vec_destructor, AzNotificationActionVec_delete);
+    impl_vec_clone!(AzNotificationAction, AzNotificationActionVec, AzNotificationActionVecDestructor);
+    impl_vec!(AzTouchPoint, AzTouchPointVec, AzTouchPointVecDestructor, az_touch_point_vec_destructor, AzTouchPointVec_delete);
+    impl_vec_clone!(AzTouchPoint, AzTouchPointVec, AzTouchPointVecDestructor);
+    impl_vec!(AzVirtualKeyCodeCombo, AzVirtualKeyCodeComboVec, AzVirtualKeyCodeComboVecDestructor, az_virtual_key_code_combo_vec_destructor, AzVirtualKeyCodeComboVec_delete);
+    impl_vec_clone!(AzVirtualKeyCodeCombo, AzVirtualKeyCodeComboVec, AzVirtualKeyCodeComboVecDestructor);
+    impl_vec!(AzShortcutBinding, AzShortcutBindingVec, AzShortcutBindingVecDestructor, az_shortcut_binding_vec_destructor, AzShortcutBindingVec_delete);
+    impl_vec_clone!(AzShortcutBinding, AzShortcutBindingVec, AzShortcutBindingVecDestructor);
     impl_vec!(AzSvgSimpleNode,  AzSvgSimpleNodeVec,  AzSvgSimpleNodeVecDestructor, az_svg_simple_node_vec_destructor, AzSvgSimpleNodeVec_delete);
     impl_vec_clone!(AzSvgSimpleNode,  AzSvgSimpleNodeVec,  AzSvgSimpleNodeVecDestructor);
     impl_vec!(AzSvgColoredVertex, AzSvgColoredVertexVec, AzSvgColoredVertexVecDestructor, az_svg_colored_vertex_vec_destructor, AzSvgColoredVertexVec_delete);
@@ -194,6 +194,10 @@ typedef void (*AzThreadReceiverDestructorFnType)(AzThreadReceiver* restrict A);
 
 typedef void (*AzThreadSenderDestructorFnType)(AzThreadSender* restrict A);
 
+struct AzStyleCursorVec;
+typedef struct AzStyleCursorVec AzStyleCursorVec;
+typedef void (*AzStyleCursorVecDestructorType)(AzStyleCursorVec* restrict A);
+
 struct AzStyleFontFamilyVec;
 typedef struct AzStyleFontFamilyVec AzStyleFontFamilyVec;
 typedef void (*AzStyleFontFamilyVecDestructorType)(AzStyleFontFamilyVec* restrict A);
@@ -250,6 +254,22 @@ struct AzAccessibilityStateVec;
 typedef struct AzAccessibilityStateVec AzAccessibilityStateVec;
 typedef void (*AzAccessibilityStateVecDestructorType)(AzAccessibilityStateVec* restrict A);
 
+struct AzVirtualKeyCodeComboVec;
+typedef struct AzVirtualKeyCodeComboVec AzVirtualKeyCodeComboVec;
+typedef void (*AzVirtualKeyCodeComboVecDestructorType)(AzVirtualKeyCodeComboVec* restrict A);
+
+struct AzShortcutBindingVec;
+typedef struct AzShortcutBindingVec AzShortcutBindingVec;
+typedef void (*AzShortcutBindingVecDestructorType)(AzShortcutBindingVec* restrict A);
+
+struct AzTouchPointVec;
+typedef struct AzTouchPointVec AzTouchPointVec;
+typedef void (*AzTouchPointVecDestructorType)(AzTouchPointVec* restrict A);
+
+struct AzNotificationActionVec;
+typedef struct AzNotificationActionVec AzNotificationActionVec;
+typedef void (*AzNotificationActionVecDestructorType)(AzNotificationActionVec* restrict A);
+
 struct AzMenuItemVec;
 typedef struct AzMenuItemVec AzMenuItemVec;
 typedef void (*AzMenuItemVecDestructorType)(AzMenuItemVec* restrict A);
@@ -484,6 +504,11 @@ enum AzLayoutSolver {
 };
 typedef enum AzLayoutSolver AzLayoutSolver;
 
+struct AzWindowId {
+    size_t id;
+};
+typedef struct AzWindowId AzWindowId;
+
 enum AzVsync {
    AzVsync_Enabled,
    AzVsync_Disabled,
@@ -886,10 +911,24 @@ enum AzWindowTheme {
 };
 typedef enum AzWindowTheme AzWindowTheme;
 
-struct AzTouchState {
-    uint8_t unused;
+enum AzTouchPointKind {
+   AzTouchPointKind_Finger,
+   AzTouchPointKind_Pen,
+   AzTouchPointKind_Eraser,
 };
-typedef struct AzTouchState AzTouchState;
+typedef enum AzTouchPointKind AzTouchPointKind;
+
+enum AzNotificationUrgency {
+   AzNotificationUrgency_Low,
+   AzNotificationUrgency_Normal,
+   AzNotificationUrgency_Critical,
+};
+typedef enum AzNotificationUrgency AzNotificationUrgency;
+
+struct AzShortcutId {
+    size_t id;
+};
+typedef struct AzShortcutId AzShortcutId;
 
 struct AzMarshaledLayoutCallbackInner {
     AzMarshaledLayoutCallbackType cb;
@@ -937,6 +976,14 @@ struct AzPositionInfoInner {
 };
 typedef struct AzPositionInfoInner AzPositionInfoInner;
 
+enum AzScrollIntoViewAlign {
+   AzScrollIntoViewAlign_Start,
+   AzScrollIntoViewAlign_Center,
+   AzScrollIntoViewAlign_End,
+   AzScrollIntoViewAlign_Nearest,
+};
+typedef enum AzScrollIntoViewAlign AzScrollIntoViewAlign;
+
 enum AzAnimationRepeat {
    AzAnimationRepeat_NoRepeat,
    AzAnimationRepeat_Loop,
@@ -1012,6 +1059,23 @@ enum AzOn {
    AzOn_HoveredFileCancelled,
    AzOn_FocusReceived,
    AzOn_FocusLost,
+   AzOn_DragStart,
+   AzOn_DragEnter,
+   AzOn_DragOver,
+   AzOn_DragLeave,
+   AzOn_Drop,
+   AzOn_TextCompositionStart,
+   AzOn_TextCompositionUpdate,
+   AzOn_TextCompositionEnd,
+   AzOn_TouchStart,
+   AzOn_TouchMove,
+   AzOn_TouchEnd,
+   AzOn_TouchCancel,
+   AzOn_Pinch,
+   AzOn_Rotate,
+   AzOn_TwoFingerPan,
+   AzOn_LongPress,
+   AzOn_Scrolled,
 };
 typedef enum AzOn AzOn;
 
@@ -1040,6 +1104,18 @@ enum AzHoverEventFilter {
    AzHoverEventFilter_TouchMove,
    AzHoverEventFilter_TouchEnd,
    AzHoverEventFilter_TouchCancel,
+   AzHoverEventFilter_Pinch,
+   AzHoverEventFilter_Rotate,
+   AzHoverEventFilter_TwoFingerPan,
+   AzHoverEventFilter_LongPress,
+   AzHoverEventFilter_DragStart,
+   AzHoverEventFilter_DragEnter,
+   AzHoverEventFilter_DragOver,
+   AzHoverEventFilter_DragLeave,
+   AzHoverEventFilter_Drop,
+   AzHoverEventFilter_TextCompositionStart,
+   AzHoverEventFilter_TextCompositionUpdate,
+   AzHoverEventFilter_TextCompositionEnd,
 };
 typedef enum AzHoverEventFilter AzHoverEventFilter;
 
@@ -1063,6 +1139,9 @@ enum AzFocusEventFilter {
    AzFocusEventFilter_VirtualKeyUp,
    AzFocusEventFilter_FocusReceived,
    AzFocusEventFilter_FocusLost,
+   AzFocusEventFilter_TextCompositionStart,
+   AzFocusEventFilter_TextCompositionUpdate,
+   AzFocusEventFilter_TextCompositionEnd,
 };
 typedef enum AzFocusEventFilter AzFocusEventFilter;
 
@@ -1093,10 +1172,26 @@ enum AzWindowEventFilter {
    AzWindowEventFilter_TouchMove,
    AzWindowEventFilter_TouchEnd,
    AzWindowEventFilter_TouchCancel,
+   AzWindowEventFilter_Pinch,
+   AzWindowEventFilter_Rotate,
+   AzWindowEventFilter_TwoFingerPan,
+   AzWindowEventFilter_LongPress,
    AzWindowEventFilter_FocusReceived,
    AzWindowEventFilter_FocusLost,
    AzWindowEventFilter_CloseRequested,
    AzWindowEventFilter_ThemeChanged,
+   AzWindowEventFilter_WindowFocusReceived,
+   AzWindowEventFilter_WindowFocusLost,
+   AzWindowEventFilter_ClipboardChanged,
+   AzWindowEventFilter_DragStart,
+   AzWindowEventFilter_DragEnter,
+   AzWindowEventFilter_DragOver,
+   AzWindowEventFilter_DragLeave,
+   AzWindowEventFilter_Drop,
+   AzWindowEventFilter_DragEnd,
+   AzWindowEventFilter_TextCompositionStart,
+   AzWindowEventFilter_TextCompositionUpdate,
+   AzWindowEventFilter_TextCompositionEnd,
 };
 typedef enum AzWindowEventFilter AzWindowEventFilter;
 
@@ -1106,6 +1201,7 @@ enum AzComponentEventFilter {
    AzComponentEventFilter_NodeResized,
    AzComponentEventFilter_DefaultAction,
    AzComponentEventFilter_Selected,
+   AzComponentEventFilter_Scrolled,
 };
 typedef enum AzComponentEventFilter AzComponentEventFilter;
 
@@ -1333,6 +1429,9 @@ enum AzCssPropertyType {
    AzCssPropertyType_BoxShadowTop,
    AzCssPropertyType_BoxShadowBottom,
    AzCssPropertyType_ScrollbarStyle,
+   AzCssPropertyType_ScrollBehavior,
+   AzCssPropertyType_ScrollSnapType,
+   AzCssPropertyType_ScrollSnapAlign,
    AzCssPropertyType_Opacity,
    AzCssPropertyType_Transform,
    AzCssPropertyType_TransformOrigin,
@@ -1533,39 +1632,33 @@ enum AzBorderStyle {
 };
 typedef enum AzBorderStyle AzBorderStyle;
 
-enum AzStyleCursor {
-   AzStyleCursor_Alias,
-   AzStyleCursor_AllScroll,
-   AzStyleCursor_Cell,
-   AzStyleCursor_ColResize,
-   AzStyleCursor_ContextMenu,
-   AzStyleCursor_Copy,
-   AzStyleCursor_Crosshair,
-   AzStyleCursor_Default,
-   AzStyleCursor_EResize,
-   AzStyleCursor_EwResize,
-   AzStyleCursor_Grab,
-   AzStyleCursor_Grabbing,
-   AzStyleCursor_Help,
-   AzStyleCursor_Move,
-   AzStyleCursor_NResize,
-   AzStyleCursor_NsResize,
-   AzStyleCursor_NeswResize,
-   AzStyleCursor_NwseResize,
-   AzStyleCursor_Pointer,
-   AzStyleCursor_Progress,
-   AzStyleCursor_RowResize,
-   AzStyleCursor_SResize,
-   AzStyleCursor_SeResize,
-   AzStyleCursor_Text,
-   AzStyleCursor_Unset,
-   AzStyleCursor_VerticalText,
-   AzStyleCursor_WResize,
-   AzStyleCursor_Wait,
-   AzStyleCursor_ZoomIn,
-   AzStyleCursor_ZoomOut,
-};
-typedef enum AzStyleCursor AzStyleCursor;
+enum AzStyleScrollBehavior {
+   AzStyleScrollBehavior_Auto,
+   AzStyleScrollBehavior_Smooth,
+};
+typedef enum AzStyleScrollBehavior AzStyleScrollBehavior;
+
+enum AzScrollSnapAxis {
+   AzScrollSnapAxis_None,
+   AzScrollSnapAxis_X,
+   AzScrollSnapAxis_Y,
+   AzScrollSnapAxis_Both,
+};
+typedef enum AzScrollSnapAxis AzScrollSnapAxis;
+
+enum AzScrollSnapStrictness {
+   AzScrollSnapStrictness_Proximity,
+   AzScrollSnapStrictness_Mandatory,
+};
+typedef enum AzScrollSnapStrictness AzScrollSnapStrictness;
+
+enum AzStyleScrollSnapAlign {
+   AzStyleScrollSnapAlign_None,
+   AzStyleScrollSnapAlign_Start,
+   AzStyleScrollSnapAlign_Center,
+   AzStyleScrollSnapAlign_End,
+};
+typedef enum AzStyleScrollSnapAlign AzStyleScrollSnapAlign;
 
 enum AzStyleBackfaceVisibility {
    AzStyleBackfaceVisibility_Hidden,
@@ -2301,6 +2394,26 @@ struct AzThreadSenderDestructorFn {
 };
 typedef struct AzThreadSenderDestructorFn AzThreadSenderDestructorFn;
 
+enum AzStyleCursorVecDestructorTag {
+   AzStyleCursorVecDestructorTag_DefaultRust,
+   AzStyleCursorVecDestructorTag_NoDestructor,
+   AzStyleCursorVecDestructorTag_External,
+};
+typedef enum AzStyleCursorVecDestructorTag AzStyleCursorVecDestructorTag;
+
+struct AzStyleCursorVecDestructorVariant_DefaultRust { AzStyleCursorVecDestructorTag tag; };
+typedef struct AzStyleCursorVecDestructorVariant_DefaultRust AzStyleCursorVecDestructorVariant_DefaultRust;
+struct AzStyleCursorVecDestructorVariant_NoDestructor { AzStyleCursorVecDestructorTag tag; };
+typedef struct AzStyleCursorVecDestructorVariant_NoDestructor AzStyleCursorVecDestructorVariant_NoDestructor;
+struct AzStyleCursorVecDestructorVariant_External { AzStyleCursorVecDestructorTag tag; AzStyleCursorVecDestructorType payload; };
+typedef struct AzStyleCursorVecDestructorVariant_External AzStyleCursorVecDestructorVariant_External;
+union AzStyleCursorVecDestructor {
+    AzStyleCursorVecDestructorVariant_DefaultRust DefaultRust;
+    AzStyleCursorVecDestructorVariant_NoDestructor NoDestructor;
+    AzStyleCursorVecDestructorVariant_External External;
+};
+typedef union AzStyleCursorVecDestructor AzStyleCursorVecDestructor;
+
 enum AzStyleFontFamilyVecDestructorTag {
    AzStyleFontFamilyVecDestructorTag_DefaultRust,
    AzStyleFontFamilyVecDestructorTag_NoDestructor,
@@ -2581,6 +2694,86 @@ union AzAccessibilityStateVecDestructor {
 };
 typedef union AzAccessibilityStateVecDestructor AzAccessibilityStateVecDestructor;
 
+enum AzVirtualKeyCodeComboVecDestructorTag {
+   AzVirtualKeyCodeComboVecDestructorTag_DefaultRust,
+   AzVirtualKeyCodeComboVecDestructorTag_NoDestructor,
+   AzVirtualKeyCodeComboVecDestructorTag_External,
+};
+typedef enum AzVirtualKeyCodeComboVecDestructorTag AzVirtualKeyCodeComboVecDestructorTag;
+
+struct AzVirtualKeyCodeComboVecDestructorVariant_DefaultRust { AzVirtualKeyCodeComboVecDestructorTag tag; };
+typedef struct AzVirtualKeyCodeComboVecDestructorVariant_DefaultRust AzVirtualKeyCodeComboVecDestructorVariant_DefaultRust;
+struct AzVirtualKeyCodeComboVecDestructorVariant_NoDestructor { AzVirtualKeyCodeComboVecDestructorTag tag; };
+typedef struct AzVirtualKeyCodeComboVecDestructorVariant_NoDestructor AzVirtualKeyCodeComboVecDestructorVariant_NoDestructor;
+struct AzVirtualKeyCodeComboVecDestructorVariant_External { AzVirtualKeyCodeComboVecDestructorTag tag; AzVirtualKeyCodeComboVecDestructorType payload; };
+typedef struct AzVirtualKeyCodeComboVecDestructorVariant_External AzVirtualKeyCodeComboVecDestructorVariant_External;
+union AzVirtualKeyCodeComboVecDestructor {
+    AzVirtualKeyCodeComboVecDestructorVariant_DefaultRust DefaultRust;
+    AzVirtualKeyCodeComboVecDestructorVariant_NoDestructor NoDestructor;
+    AzVirtualKeyCodeComboVecDestructorVariant_External External;
+};
+typedef union AzVirtualKeyCodeComboVecDestructor AzVirtualKeyCodeComboVecDestructor;
+
+enum AzShortcutBindingVecDestructorTag {
+   AzShortcutBindingVecDestructorTag_DefaultRust,
+   AzShortcutBindingVecDestructorTag_NoDestructor,
+   AzShortcutBindingVecDestructorTag_External,
+};
+typedef enum AzShortcutBindingVecDestructorTag AzShortcutBindingVecDestructorTag;
+
+struct AzShortcutBindingVecDestructorVariant_DefaultRust { AzShortcutBindingVecDestructorTag tag; };
+typedef struct AzShortcutBindingVecDestructorVariant_DefaultRust AzShortcutBindingVecDestructorVariant_DefaultRust;
+struct AzShortcutBindingVecDestructorVariant_NoDestructor { AzShortcutBindingVecDestructorTag tag; };
+typedef struct AzShortcutBindingVecDestructorVariant_NoDestructor AzShortcutBindingVecDestructorVariant_NoDestructor;
+struct AzShortcutBindingVecDestructorVariant_External { AzShortcutBindingVecDestructorTag tag; AzShortcutBindingVecDestructorType payload; };
+typedef struct AzShortcutBindingVecDestructorVariant_External AzShortcutBindingVecDestructorVariant_External;
+union AzShortcutBindingVecDestructor {
+    AzShortcutBindingVecDestructorVariant_DefaultRust DefaultRust;
+    AzShortcutBindingVecDestructorVariant_NoDestructor NoDestructor;
+    AzShortcutBindingVecDestructorVariant_External External;
+};
+typedef union AzShortcutBindingVecDestructor AzShortcutBindingVecDestructor;
+
+enum AzTouchPointVecDestructorTag {
+   AzTouchPointVecDestructorTag_DefaultRust,
+   AzTouchPointVecDestructorTag_NoDestructor,
+   AzTouchPointVecDestructorTag_External,
+};
+typedef enum AzTouchPointVecDestructorTag AzTouchPointVecDestructorTag;
+
+struct AzTouchPointVecDestructorVariant_DefaultRust { AzTouchPointVecDestructorTag tag; };
+typedef struct AzTouchPointVecDestructorVariant_DefaultRust AzTouchPointVecDestructorVariant_DefaultRust;
+struct AzTouchPointVecDestructorVariant_NoDestructor { AzTouchPointVecDestructorTag tag; };
+typedef struct AzTouchPointVecDestructorVariant_NoDestructor AzTouchPointVecDestructorVariant_NoDestructor;
+struct AzTouchPointVecDestructorVariant_External { AzTouchPointVecDestructorTag tag; AzTouchPointVecDestructorType payload; };
+typedef struct AzTouchPointVecDestructorVariant_External AzTouchPointVecDestructorVariant_External;
+union AzTouchPointVecDestructor {
+    AzTouchPointVecDestructorVariant_DefaultRust DefaultRust;
+    AzTouchPointVecDestructorVariant_NoDestructor NoDestructor;
+    AzTouchPointVecDestructorVariant_External External;
+};
+typedef union AzTouchPointVecDestructor AzTouchPointVecDestructor;
+
+enum AzNotificationActionVecDestructorTag {
+   AzNotificationActionVecDestructorTag_DefaultRust,
+   AzNotificationActionVecDestructorTag_NoDestructor,
+   AzNotificationActionVecDestructorTag_External,
+};
+typedef enum AzNotificationActionVecDestructorTag AzNotificationActionVecDestructorTag;
+
+struct AzNotificationActionVecDestructorVariant_DefaultRust { AzNotificationActionVecDestructorTag tag; };
+typedef struct AzNotificationActionVecDestructorVariant_DefaultRust AzNotificationActionVecDestructorVariant_DefaultRust;
+struct AzNotificationActionVecDestructorVariant_NoDestructor { AzNotificationActionVecDestructorTag tag; };
+typedef struct AzNotificationActionVecDestructorVariant_NoDestructor AzNotificationActionVecDestructorVariant_NoDestructor;
+struct AzNotificationActionVecDestructorVariant_External { AzNotificationActionVecDestructorTag tag; AzNotificationActionVecDestructorType payload; };
+typedef struct AzNotificationActionVecDestructorVariant_External AzNotificationActionVecDestructorVariant_External;
+union AzNotificationActionVecDestructor {
+    AzNotificationActionVecDestructorVariant_DefaultRust DefaultRust;
+    AzNotificationActionVecDestructorVariant_NoDestructor NoDestructor;
+    AzNotificationActionVecDestructorVariant_External External;
+};
+typedef union AzNotificationActionVecDestructor AzNotificationActionVecDestructor;
+
 enum AzMenuItemVecDestructorTag {
    AzMenuItemVecDestructorTag_DefaultRust,
    AzMenuItemVecDestructorTag_NoDestructor,
@@ -3894,7 +4087,10 @@ struct AzWindowFlags {
     bool  has_focus;
     bool  has_extended_window_frame;
     bool  has_blur_behind_window;
+    bool  is_click_through;
     bool  smooth_scroll_enabled;
+    bool  overscroll_bounce_enabled;
+    bool  scrollbar_auto_hide_enabled;
     bool  autotab_enabled;
 };
 typedef struct AzWindowFlags AzWindowFlags;
@@ -3951,6 +4147,27 @@ union AzImePosition {
 };
 typedef union AzImePosition AzImePosition;
 
+struct AzTouchPoint {
+    uint64_t id;
+    AzTouchPointKind kind;
+    AzLogicalPosition position;
+    float pressure;
+    float tilt_x;
+    float tilt_y;
+};
+typedef struct AzTouchPoint AzTouchPoint;
+
+struct AzGestureState {
+    AzLogicalPosition center;
+    float scale;
+    float rotation;
+    AzLogicalPosition translation;
+    bool  is_pinch;
+    bool  is_rotate;
+    bool  is_pan;
+};
+typedef struct AzGestureState AzGestureState;
+
 struct AzVideoMode {
     AzLayoutSize size;
     uint16_t bit_depth;
@@ -3958,6 +4175,53 @@ struct AzVideoMode {
 };
 typedef struct AzVideoMode AzVideoMode;
 
+struct AzCustomCursor {
+    AzImageRef image;
+    uint32_t hotspot_x;
+    uint32_t hotspot_y;
+};
+typedef struct AzCustomCursor AzCustomCursor;
+
+enum AzShortcutScopeTag {
+   AzShortcutScopeTag_Window,
+   AzShortcutScopeTag_App,
+   AzShortcutScopeTag_Global,
+};
+typedef enum AzShortcutScopeTag AzShortcutScopeTag;
+
+struct AzShortcutScopeVariant_Window { AzShortcutScopeTag tag; AzWindowId payload; };
+typedef struct AzShortcutScopeVariant_Window AzShortcutScopeVariant_Window;
+struct AzShortcutScopeVariant_App { AzShortcutScopeTag tag; };
+typedef struct AzShortcutScopeVariant_App AzShortcutScopeVariant_App;
+struct AzShortcutScopeVariant_Global { AzShortcutScopeTag tag; };
+typedef struct AzShortcutScopeVariant_Global AzShortcutScopeVariant_Global;
+union AzShortcutScope {
+    AzShortcutScopeVariant_Window Window;
+    AzShortcutScopeVariant_App App;
+    AzShortcutScopeVariant_Global Global;
+};
+typedef union AzShortcutScope AzShortcutScope;
+
+enum AzShortcutErrorTag {
+   AzShortcutErrorTag_NoKeys,
+   AzShortcutErrorTag_GlobalSequence,
+   AzShortcutErrorTag_Conflict,
+};
+typedef enum AzShortcutErrorTag AzShortcutErrorTag;
+
+struct AzShortcutErrorVariant_NoKeys { AzShortcutErrorTag tag; };
+typedef struct AzShortcutErrorVariant_NoKeys AzShortcutErrorVariant_NoKeys;
+struct AzShortcutErrorVariant_GlobalSequence { AzShortcutErrorTag tag; };
+typedef struct AzShortcutErrorVariant_GlobalSequence AzShortcutErrorVariant_GlobalSequence;
+struct AzShortcutErrorVariant_Conflict { AzShortcutErrorTag tag; AzShortcutId payload; };
+typedef struct AzShortcutErrorVariant_Conflict AzShortcutErrorVariant_Conflict;
+union AzShortcutError {
+    AzShortcutErrorVariant_NoKeys NoKeys;
+    AzShortcutErrorVariant_GlobalSequence GlobalSequence;
+    AzShortcutErrorVariant_Conflict Conflict;
+};
+typedef union AzShortcutError AzShortcutError;
+
 struct AzDomNodeId {
     AzDomId dom;
     AzNodeId node;
@@ -3988,6 +4252,13 @@ union AzPositionInfo {
 };
 typedef union AzPositionInfo AzPositionInfo;
 
+struct AzScrollPosition {
+    AzLogicalRect parent_rect;
+    AzLogicalRect children_rect;
+    AzLogicalPosition scroll_offset;
+};
+typedef struct AzScrollPosition AzScrollPosition;
+
 struct AzHidpiAdjustedBounds {
     AzLogicalSize logical_size;
     float hidpi_factor;
@@ -4489,6 +4760,12 @@ struct AzLayoutBorderTopWidth {
 };
 typedef struct AzLayoutBorderTopWidth AzLayoutBorderTopWidth;
 
+struct AzStyleScrollSnapType {
+    AzScrollSnapAxis axis;
+    AzScrollSnapStrictness strictness;
+};
+typedef struct AzStyleScrollSnapType AzStyleScrollSnapType;
+
 struct AzStyleFontSize {
     AzPixelValue inner;
 };
@@ -5474,6 +5751,90 @@ union AzLayoutOverflowValue {
 };
 typedef union AzLayoutOverflowValue AzLayoutOverflowValue;
 
+enum AzStyleScrollBehaviorValueTag {
+   AzStyleScrollBehaviorValueTag_Auto,
+   AzStyleScrollBehaviorValueTag_None,
+   AzStyleScrollBehaviorValueTag_Inherit,
+   AzStyleScrollBehaviorValueTag_Initial,
+   AzStyleScrollBehaviorValueTag_Exact,
+};
+typedef enum AzStyleScrollBehaviorValueTag AzStyleScrollBehaviorValueTag;
+
+struct AzStyleScrollBehaviorValueVariant_Auto { AzStyleScrollBehaviorValueTag tag; };
+typedef struct AzStyleScrollBehaviorValueVariant_Auto AzStyleScrollBehaviorValueVariant_Auto;
+struct AzStyleScrollBehaviorValueVariant_None { AzStyleScrollBehaviorValueTag tag; };
+typedef struct AzStyleScrollBehaviorValueVariant_None AzStyleScrollBehaviorValueVariant_None;
+struct AzStyleScrollBehaviorValueVariant_Inherit { AzStyleScrollBehaviorValueTag tag; };
+typedef struct AzStyleScrollBehaviorValueVariant_Inherit AzStyleScrollBehaviorValueVariant_Inherit;
+struct AzStyleScrollBehaviorValueVariant_Initial { AzStyleScrollBehaviorValueTag tag; };
+typedef struct AzStyleScrollBehaviorValueVariant_Initial AzStyleScrollBehaviorValueVariant_Initial;
+struct AzStyleScrollBehaviorValueVariant_Exact { AzStyleScrollBehaviorValueTag tag; AzStyleScrollBehavior payload; };
+typedef struct AzStyleScrollBehaviorValueVariant_Exact AzStyleScrollBehaviorValueVariant_Exact;
+union AzStyleScrollBehaviorValue {
+    AzStyleScrollBehaviorValueVariant_Auto Auto;
+    AzStyleScrollBehaviorValueVariant_None None;
+    AzStyleScrollBehaviorValueVariant_Inherit Inherit;
+    AzStyleScrollBehaviorValueVariant_Initial Initial;
+    AzStyleScrollBehaviorValueVariant_Exact Exact;
+};
+typedef union AzStyleScrollBehaviorValue AzStyleScrollBehaviorValue;
+
+enum AzStyleScrollSnapTypeValueTag {
+   AzStyleScrollSnapTypeValueTag_Auto,
+   AzStyleScrollSnapTypeValueTag_None,
+   AzStyleScrollSnapTypeValueTag_Inherit,
+   AzStyleScrollSnapTypeValueTag_Initial,
+   AzStyleScrollSnapTypeValueTag_Exact,
+};
+typedef enum AzStyleScrollSnapTypeValueTag AzStyleScrollSnapTypeValueTag;
+
+struct AzStyleScrollSnapTypeValueVariant_Auto { AzStyleScrollSnapTypeValueTag tag; };
+typedef struct AzStyleScrollSnapTypeValueVariant_Auto AzStyleScrollSnapTypeValueVariant_Auto;
+struct AzStyleScrollSnapTypeValueVariant_None { AzStyleScrollSnapTypeValueTag tag; };
+typedef struct AzStyleScrollSnapTypeValueVariant_None AzStyleScrollSnapTypeValueVariant_None;
+struct AzStyleScrollSnapTypeValueVariant_Inherit { AzStyleScrollSnapTypeValueTag tag; };
+typedef struct AzStyleScrollSnapTypeValueVariant_Inherit AzStyleScrollSnapTypeValueVariant_Inherit;
+struct AzStyleScrollSnapTypeValueVariant_Initial { AzStyleScrollSnapTypeValueTag tag; };
+typedef struct AzStyleScrollSnapTypeValueVariant_Initial AzStyleScrollSnapTypeValueVariant_Initial;
+struct AzStyleScrollSnapTypeValueVariant_Exact { AzStyleScrollSnapTypeValueTag tag; AzStyleScrollSnapType payload; };
+typedef struct AzStyleScrollSnapTypeValueVariant_Exact AzStyleScrollSnapTypeValueVariant_Exact;
+union AzStyleScrollSnapTypeValue {
+    AzStyleScrollSnapTypeValueVariant_Auto Auto;
+    AzStyleScrollSnapTypeValueVariant_None None;
+    AzStyleScrollSnapTypeValueVariant_Inherit Inherit;
+    AzStyleScrollSnapTypeValueVariant_Initial Initial;
+    AzStyleScrollSnapTypeValueVariant_Exact Exact;
+};
+typedef union AzStyleScrollSnapTypeValue AzStyleScrollSnapTypeValue;
+
+enum AzStyleScrollSnapAlignValueTag {
+   AzStyleScrollSnapAlignValueTag_Auto,
+   AzStyleScrollSnapAlignValueTag_None,
+   AzStyleScrollSnapAlignValueTag_Inherit,
+   AzStyleScrollSnapAlignValueTag_Initial,
+   AzStyleScrollSnapAlignValueTag_Exact,
+};
+typedef enum AzStyleScrollSnapAlignValueTag AzStyleScrollSnapAlignValueTag;
+
+struct AzStyleScrollSnapAlignValueVariant_Auto { AzStyleScrollSnapAlignValueTag tag; };
+typedef struct AzStyleScrollSnapAlignValueVariant_Auto AzStyleScrollSnapAlignValueVariant_Auto;
+struct AzStyleScrollSnapAlignValueVariant_None { AzStyleScrollSnapAlignValueTag tag; };
+typedef struct AzStyleScrollSnapAlignValueVariant_None AzStyleScrollSnapAlignValueVariant_None;
+struct AzStyleScrollSnapAlignValueVariant_Inherit { AzStyleScrollSnapAlignValueTag tag; };
+typedef struct AzStyleScrollSnapAlignValueVariant_Inherit AzStyleScrollSnapAlignValueVariant_Inherit;
+struct AzStyleScrollSnapAlignValueVariant_Initial { AzStyleScrollSnapAlignValueTag tag; };
+typedef struct AzStyleScrollSnapAlignValueVariant_Initial AzStyleScrollSnapAlignValueVariant_Initial;
+struct AzStyleScrollSnapAlignValueVariant_Exact { AzStyleScrollSnapAlignValueTag tag; AzStyleScrollSnapAlign payload; };
+typedef struct AzStyleScrollSnapAlignValueVariant_Exact AzStyleScrollSnapAlignValueVariant_Exact;
+union AzStyleScrollSnapAlignValue {
+    AzStyleScrollSnapAlignValueVariant_Auto Auto;
+    AzStyleScrollSnapAlignValueVariant_None None;
+    AzStyleScrollSnapAlignValueVariant_Inherit Inherit;
+    AzStyleScrollSnapAlignValueVariant_Initial Initial;
+    AzStyleScrollSnapAlignValueVariant_Exact Exact;
+};
+typedef union AzStyleScrollSnapAlignValue AzStyleScrollSnapAlignValue;
+
 enum AzStyleBorderBottomColorValueTag {
    AzStyleBorderBottomColorValueTag_Auto,
    AzStyleBorderBottomColorValueTag_None,
@@ -5922,34 +6283,6 @@ union AzLayoutBorderTopWidthValue {
 };
 typedef union AzLayoutBorderTopWidthValue AzLayoutBorderTopWidthValue;
 
-enum AzStyleCursorValueTag {
-   AzStyleCursorValueTag_Auto,
-   AzStyleCursorValueTag_None,
-   AzStyleCursorValueTag_Inherit,
-   AzStyleCursorValueTag_Initial,
-   AzStyleCursorValueTag_Exact,
-};
-typedef enum AzStyleCursorValueTag AzStyleCursorValueTag;
-
-struct AzStyleCursorValueVariant_Auto { AzStyleCursorValueTag tag; };
-typedef struct AzStyleCursorValueVariant_Auto AzStyleCursorValueVariant_Auto;
-struct AzStyleCursorValueVariant_None { AzStyleCursorValueTag tag; };
-typedef struct AzStyleCursorValueVariant_None AzStyleCursorValueVariant_None;
-struct AzStyleCursorValueVariant_Inherit { AzStyleCursorValueTag tag; };
-typedef struct AzStyleCursorValueVariant_Inherit AzStyleCursorValueVariant_Inherit;
-struct AzStyleCursorValueVariant_Initial { AzStyleCursorValueTag tag; };
-typedef struct AzStyleCursorValueVariant_Initial AzStyleCursorValueVariant_Initial;
-struct AzStyleCursorValueVariant_Exact { AzStyleCursorValueTag tag; AzStyleCursor payload; };
-typedef struct AzStyleCursorValueVariant_Exact AzStyleCursorValueVariant_Exact;
-union AzStyleCursorValue {
-    AzStyleCursorValueVariant_Auto Auto;
-    AzStyleCursorValueVariant_None None;
-    AzStyleCursorValueVariant_Inherit Inherit;
-    AzStyleCursorValueVariant_Initial Initial;
-    AzStyleCursorValueVariant_Exact Exact;
-};
-typedef union AzStyleCursorValue AzStyleCursorValue;
-
 enum AzStyleFontSizeValueTag {
    AzStyleFontSizeValueTag_Auto,
    AzStyleFontSizeValueTag_None,
@@ -6671,6 +7004,14 @@ struct AzAccessibilityStateVec {
 };
 typedef struct AzAccessibilityStateVec AzAccessibilityStateVec;
 
+struct AzTouchPointVec {
+    AzTouchPoint* ptr;
+    size_t len;
+    size_t cap;
+    AzTouchPointVecDestructor destructor;
+};
+typedef struct AzTouchPointVec AzTouchPointVec;
+
 struct AzMenuItem;
 typedef struct AzMenuItem AzMenuItem;
 struct AzMenuItemVec {
@@ -6681,6 +7022,16 @@ struct AzMenuItemVec {
 };
 typedef struct AzMenuItemVec AzMenuItemVec;
 
+struct AzStyleCursor;
+typedef struct AzStyleCursor AzStyleCursor;
+struct AzStyleCursorVec {
+    AzStyleCursor* ptr;
+    size_t len;
+    size_t cap;
+    AzStyleCursorVecDestructor destructor;
+};
+typedef struct AzStyleCursorVec AzStyleCursorVec;
+
 struct AzXmlNode;
 typedef struct AzXmlNode AzXmlNode;
 struct AzXmlNodeVec {
@@ -7381,6 +7732,38 @@ union AzOptionImageRef {
 };
 typedef union AzOptionImageRef AzOptionImageRef;
 
+enum AzOptionWindowIdTag {
+   AzOptionWindowIdTag_None,
+   AzOptionWindowIdTag_Some,
+};
+typedef enum AzOptionWindowIdTag AzOptionWindowIdTag;
+
+struct AzOptionWindowIdVariant_None { AzOptionWindowIdTag tag; };
+typedef struct AzOptionWindowIdVariant_None AzOptionWindowIdVariant_None;
+struct AzOptionWindowIdVariant_Some { AzOptionWindowIdTag tag; AzWindowId payload; };
+typedef struct AzOptionWindowIdVariant_Some AzOptionWindowIdVariant_Some;
+union AzOptionWindowId {
+    AzOptionWindowIdVariant_None None;
+    AzOptionWindowIdVariant_Some Some;
+};
+typedef union AzOptionWindowId AzOptionWindowId;
+
+enum AzOptionCustomCursorTag {
+   AzOptionCustomCursorTag_None,
+   AzOptionCustomCursorTag_Some,
+};
+typedef enum AzOptionCustomCursorTag AzOptionCustomCursorTag;
+
+struct AzOptionCustomCursorVariant_None { AzOptionCustomCursorTag tag; };
+typedef struct AzOptionCustomCursorVariant_None AzOptionCustomCursorVariant_None;
+struct AzOptionCustomCursorVariant_Some { AzOptionCustomCursorTag tag; AzCustomCursor payload; };
+typedef struct AzOptionCustomCursorVariant_Some AzOptionCustomCursorVariant_Some;
+union AzOptionCustomCursor {
+    AzOptionCustomCursorVariant_None None;
+    AzOptionCustomCursorVariant_Some Some;
+};
+typedef union AzOptionCustomCursor AzOptionCustomCursor;
+
 enum AzOptionFontRefTag {
    AzOptionFontRefTag_None,
    AzOptionFontRefTag_Some,
@@ -7541,6 +7924,22 @@ union AzOptionRefAny {
 };
 typedef union AzOptionRefAny AzOptionRefAny;
 
+enum AzOptionScrollPositionTag {
+   AzOptionScrollPositionTag_None,
+   AzOptionScrollPositionTag_Some,
+};
+typedef enum AzOptionScrollPositionTag AzOptionScrollPositionTag;
+
+struct AzOptionScrollPositionVariant_None { AzOptionScrollPositionTag tag; };
+typedef struct AzOptionScrollPositionVariant_None AzOptionScrollPositionVariant_None;
+struct AzOptionScrollPositionVariant_Some { AzOptionScrollPositionTag tag; AzScrollPosition payload; };
+typedef struct AzOptionScrollPositionVariant_Some AzOptionScrollPositionVariant_Some;
+union AzOptionScrollPosition {
+    AzOptionScrollPositionVariant_None None;
+    AzOptionScrollPositionVariant_Some Some;
+};
+typedef union AzOptionScrollPosition AzOptionScrollPosition;
+
 enum AzOptionLayoutPointTag {
    AzOptionLayoutPointTag_None,
    AzOptionLayoutPointTag_Some,
@@ -7653,6 +8052,22 @@ union AzOptionSvgDashPattern {
 };
 typedef union AzOptionSvgDashPattern AzOptionSvgDashPattern;
 
+enum AzOptionGestureStateTag {
+   AzOptionGestureStateTag_None,
+   AzOptionGestureStateTag_Some,
+};
+typedef enum AzOptionGestureStateTag AzOptionGestureStateTag;
+
+struct AzOptionGestureStateVariant_None { AzOptionGestureStateTag tag; };
+typedef struct AzOptionGestureStateVariant_None AzOptionGestureStateVariant_None;
+struct AzOptionGestureStateVariant_Some { AzOptionGestureStateTag tag; AzGestureState payload; };
+typedef struct AzOptionGestureStateVariant_Some AzOptionGestureStateVariant_Some;
+union AzOptionGestureState {
+    AzOptionGestureStateVariant_None None;
+    AzOptionGestureStateVariant_Some Some;
+};
+typedef union AzOptionGestureState AzOptionGestureState;
+
 enum AzOptionLogicalPositionTag {
    AzOptionLogicalPositionTag_None,
    AzOptionLogicalPositionTag_Some,
@@ -7845,6 +8260,22 @@ union AzResultU8VecEncodeImageError {
 };
 typedef union AzResultU8VecEncodeImageError AzResultU8VecEncodeImageError;
 
+enum AzResultShortcutIdShortcutErrorTag {
+   AzResultShortcutIdShortcutErrorTag_Ok,
+   AzResultShortcutIdShortcutErrorTag_Err,
+};
+typedef enum AzResultShortcutIdShortcutErrorTag AzResultShortcutIdShortcutErrorTag;
+
+struct AzResultShortcutIdShortcutErrorVariant_Ok { AzResultShortcutIdShortcutErrorTag tag; AzShortcutId payload; };
+typedef struct AzResultShortcutIdShortcutErrorVariant_Ok AzResultShortcutIdShortcutErrorVariant_Ok;
+struct AzResultShortcutIdShortcutErrorVariant_Err { AzResultShortcutIdShortcutErrorTag tag; AzShortcutError payload; };
+typedef struct AzResultShortcutIdShortcutErrorVariant_Err AzResultShortcutIdShortcutErrorVariant_Err;
+union AzResultShortcutIdShortcutError {
+    AzResultShortcutIdShortcutErrorVariant_Ok Ok;
+    AzResultShortcutIdShortcutErrorVariant_Err Err;
+};
+typedef union AzResultShortcutIdShortcutError AzResultShortcutIdShortcutError;
+
 struct AzNonXmlCharError {
     uint32_t ch;
     AzSvgParseErrorPosition pos;
@@ -7883,10 +8314,17 @@ struct AzAppConfig {
     bool  enable_visual_panic_hook;
     bool  enable_logging_on_panic;
     bool  enable_tab_navigation;
+    bool  enable_inspector;
     AzSystemCallbacks system_callbacks;
 };
 typedef struct AzAppConfig AzAppConfig;
 
+struct AzWindowResultCallback {
+    AzRefAny data;
+    AzWriteBackCallback callback;
+};
+typedef struct AzWindowResultCallback AzWindowResultCallback;
+
 struct AzSmallWindowIconBytes {
     AzIconKey key;
     AzU8Vec rgba_bytes;
@@ -7949,6 +8387,15 @@ struct AzMouseState {
 };
 typedef struct AzMouseState AzMouseState;
 
+struct AzTouchState {
+    AzTouchPointVec touch_points;
+    AzTouchPointVec ended_touch_points;
+    AzTouchPointVec cancelled_touch_points;
+    AzOptionGestureState gesture;
+    AzOptionLogicalPosition long_press;
+};
+typedef struct AzTouchState AzTouchState;
+
 struct AzMarshaledLayoutCallback {
     AzRefAny marshal_data;
     AzMarshaledLayoutCallbackInner cb;
@@ -8636,6 +9083,14 @@ struct AzOutputConnectionVec {
 };
 typedef struct AzOutputConnectionVec AzOutputConnectionVec;
 
+struct AzVirtualKeyCodeComboVec {
+    AzVirtualKeyCodeCombo* ptr;
+    size_t len;
+    size_t cap;
+    AzVirtualKeyCodeComboVecDestructor destructor;
+};
+typedef struct AzVirtualKeyCodeComboVec AzVirtualKeyCodeComboVec;
+
 struct AzTessellatedSvgNodeVec {
     AzTessellatedSvgNode* ptr;
     size_t len;
@@ -8740,6 +9195,22 @@ union AzOptionVirtualKeyCodeCombo {
 };
 typedef union AzOptionVirtualKeyCodeCombo AzOptionVirtualKeyCodeCombo;
 
+enum AzOptionWindowResultCallbackTag {
+   AzOptionWindowResultCallbackTag_None,
+   AzOptionWindowResultCallbackTag_Some,
+};
+typedef enum AzOptionWindowResultCallbackTag AzOptionWindowResultCallbackTag;
+
+struct AzOptionWindowResultCallbackVariant_None { AzOptionWindowResultCallbackTag tag; };
+typedef struct AzOptionWindowResultCallbackVariant_None AzOptionWindowResultCallbackVariant_None;
+struct AzOptionWindowResultCallbackVariant_Some { AzOptionWindowResultCallbackTag tag; AzWindowResultCallback payload; };
+typedef struct AzOptionWindowResultCallbackVariant_Some AzOptionWindowResultCallbackVariant_Some;
+union AzOptionWindowResultCallback {
+    AzOptionWindowResultCallbackVariant_None None;
+    AzOptionWindowResultCallbackVariant_Some Some;
+};
+typedef union AzOptionWindowResultCallback AzOptionWindowResultCallback;
+
 enum AzOptionMouseStateTag {
    AzOptionMouseStateTag_None,
    AzOptionMouseStateTag_Some,
@@ -8921,6 +9392,13 @@ struct AzInvalidStringError {
 };
 typedef struct AzInvalidStringError AzInvalidStringError;
 
+struct AzTrayIcon {
+    AzWindowIcon icon;
+    AzString tooltip;
+    AzMenu menu;
+};
+typedef struct AzTrayIcon AzTrayIcon;
+
 struct AzWindowsWindowOptions {
     bool  allow_drag_drop;
     bool  no_redirection_bitmap;
@@ -8995,6 +9473,26 @@ struct AzMonitor {
 };
 typedef struct AzMonitor AzMonitor;
 
+struct AzNotificationAction {
+    AzString label;
+    AzMenuCallback callback;
+};
+typedef struct AzNotificationAction AzNotificationAction;
+
+struct AzShortcut {
+    AzVirtualKeyCodeComboVec keys;
+    AzShortcutScope scope;
+    AzString description;
+    AzMenuCallback callback;
+};
+typedef struct AzShortcut AzShortcut;
+
+struct AzShortcutBinding {
+    AzShortcutId id;
+    AzShortcut shortcut;
+};
+typedef struct AzShortcutBinding AzShortcutBinding;
+
 enum AzLayoutCallbackTag {
    AzLayoutCallbackTag_Raw,
    AzLayoutCallbackTag_Marshaled,
@@ -9192,6 +9690,14 @@ struct AzScrollbarStyle {
 };
 typedef struct AzScrollbarStyle AzScrollbarStyle;
 
+struct AzStyleCursorImage {
+    AzString image;
+    uint32_t hotspot_x;
+    uint32_t hotspot_y;
+    AzStyleCursorVec fallback;
+};
+typedef struct AzStyleCursorImage AzStyleCursorImage;
+
 enum AzStyleFontFamilyTag {
    AzStyleFontFamilyTag_System,
    AzStyleFontFamilyTag_File,
@@ -9576,6 +10082,22 @@ struct AzFmtArg {
 };
 typedef struct AzFmtArg AzFmtArg;
 
+struct AzShortcutBindingVec {
+    AzShortcutBinding* ptr;
+    size_t len;
+    size_t cap;
+    AzShortcutBindingVecDestructor destructor;
+};
+typedef struct AzShortcutBindingVec AzShortcutBindingVec;
+
+struct AzNotificationActionVec {
+    AzNotificationAction* ptr;
+    size_t len;
+    size_t cap;
+    AzNotificationActionVecDestructor destructor;
+};
+typedef struct AzNotificationActionVec AzNotificationActionVec;
+
 struct AzStyleFontFamilyVec {
     AzStyleFontFamily* ptr;
     size_t len;
@@ -9736,6 +10258,22 @@ union AzOptionWaylandTheme {
 };
 typedef union AzOptionWaylandTheme AzOptionWaylandTheme;
 
+enum AzOptionTrayIconTag {
+   AzOptionTrayIconTag_None,
+   AzOptionTrayIconTag_Some,
+};
+typedef enum AzOptionTrayIconTag AzOptionTrayIconTag;
+
+struct AzOptionTrayIconVariant_None { AzOptionTrayIconTag tag; };
+typedef struct AzOptionTrayIconVariant_None AzOptionTrayIconVariant_None;
+struct AzOptionTrayIconVariant_Some { AzOptionTrayIconTag tag; AzTrayIcon payload; };
+typedef struct AzOptionTrayIconVariant_Some AzOptionTrayIconVariant_Some;
+union AzOptionTrayIcon {
+    AzOptionTrayIconVariant_None None;
+    AzOptionTrayIconVariant_Some Some;
+};
+typedef union AzOptionTrayIcon AzOptionTrayIcon;
+
 enum AzResultRawImageDecodeImageErrorTag {
    AzResultRawImageDecodeImageErrorTag_Ok,
    AzResultRawImageDecodeImageErrorTag_Err,
@@ -9825,9 +10363,20 @@ struct AzLinuxWindowOptions {
     AzOptionWaylandTheme wayland_theme;
     bool  request_user_attention;
     AzOptionWindowIcon window_icon;
+    AzOptionTrayIcon tray_icon;
 };
 typedef struct AzLinuxWindowOptions AzLinuxWindowOptions;
 
+struct AzNotification {
+    AzString title;
+    AzString body;
+    AzOptionImageRef icon;
+    AzNotificationUrgency urgency;
+    AzNotificationActionVec actions;
+    AzOptionMenuCallback on_click;
+};
+typedef struct AzNotification AzNotification;
+
 struct AzInlineLine {
     AzInlineWordVec words;
     AzLogicalRect bounds;
@@ -9859,6 +10408,138 @@ struct AzCssPath {
 };
 typedef struct AzCssPath AzCssPath;
 
+enum AzStyleCursorTag {
+   AzStyleCursorTag_Alias,
+   AzStyleCursorTag_AllScroll,
+   AzStyleCursorTag_Cell,
+   AzStyleCursorTag_ColResize,
+   AzStyleCursorTag_ContextMenu,
+   AzStyleCursorTag_Copy,
+   AzStyleCursorTag_Crosshair,
+   AzStyleCursorTag_Default,
+   AzStyleCursorTag_EResize,
+   AzStyleCursorTag_EwResize,
+   AzStyleCursorTag_Grab,
+   AzStyleCursorTag_Grabbing,
+   AzStyleCursorTag_Help,
+   AzStyleCursorTag_Move,
+   AzStyleCursorTag_NResize,
+   AzStyleCursorTag_NsResize,
+   AzStyleCursorTag_NeswResize,
+   AzStyleCursorTag_NwseResize,
+   AzStyleCursorTag_Pointer,
+   AzStyleCursorTag_Progress,
+   AzStyleCursorTag_RowResize,
+   AzStyleCursorTag_SResize,
+   AzStyleCursorTag_SeResize,
+   AzStyleCursorTag_Text,
+   AzStyleCursorTag_Unset,
+   AzStyleCursorTag_VerticalText,
+   AzStyleCursorTag_WResize,
+   AzStyleCursorTag_Wait,
+   AzStyleCursorTag_ZoomIn,
+   AzStyleCursorTag_ZoomOut,
+   AzStyleCursorTag_Image,
+};
+typedef enum AzStyleCursorTag AzStyleCursorTag;
+
+struct AzStyleCursorVariant_Alias { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_Alias AzStyleCursorVariant_Alias;
+struct AzStyleCursorVariant_AllScroll { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_AllScroll AzStyleCursorVariant_AllScroll;
+struct AzStyleCursorVariant_Cell { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_Cell AzStyleCursorVariant_Cell;
+struct AzStyleCursorVariant_ColResize { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_ColResize AzStyleCursorVariant_ColResize;
+struct AzStyleCursorVariant_ContextMenu { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_ContextMenu AzStyleCursorVariant_ContextMenu;
+struct AzStyleCursorVariant_Copy { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_Copy AzStyleCursorVariant_Copy;
+struct AzStyleCursorVariant_Crosshair { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_Crosshair AzStyleCursorVariant_Crosshair;
+struct AzStyleCursorVariant_Default { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_Default AzStyleCursorVariant_Default;
+struct AzStyleCursorVariant_EResize { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_EResize AzStyleCursorVariant_EResize;
+struct AzStyleCursorVariant_EwResize { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_EwResize AzStyleCursorVariant_EwResize;
+struct AzStyleCursorVariant_Grab { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_Grab AzStyleCursorVariant_Grab;
+struct AzStyleCursorVariant_Grabbing { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_Grabbing AzStyleCursorVariant_Grabbing;
+struct AzStyleCursorVariant_Help { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_Help AzStyleCursorVariant_Help;
+struct AzStyleCursorVariant_Move { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_Move AzStyleCursorVariant_Move;
+struct AzStyleCursorVariant_NResize { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_NResize AzStyleCursorVariant_NResize;
+struct AzStyleCursorVariant_NsResize { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_NsResize AzStyleCursorVariant_NsResize;
+struct AzStyleCursorVariant_NeswResize { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_NeswResize AzStyleCursorVariant_NeswResize;
+struct AzStyleCursorVariant_NwseResize { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_NwseResize AzStyleCursorVariant_NwseResize;
+struct AzStyleCursorVariant_Pointer { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_Pointer AzStyleCursorVariant_Pointer;
+struct AzStyleCursorVariant_Progress { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_Progress AzStyleCursorVariant_Progress;
+struct AzStyleCursorVariant_RowResize { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_RowResize AzStyleCursorVariant_RowResize;
+struct AzStyleCursorVariant_SResize { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_SResize AzStyleCursorVariant_SResize;
+struct AzStyleCursorVariant_SeResize { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_SeResize AzStyleCursorVariant_SeResize;
+struct AzStyleCursorVariant_Text { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_Text AzStyleCursorVariant_Text;
+struct AzStyleCursorVariant_Unset { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_Unset AzStyleCursorVariant_Unset;
+struct AzStyleCursorVariant_VerticalText { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_VerticalText AzStyleCursorVariant_VerticalText;
+struct AzStyleCursorVariant_WResize { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_WResize AzStyleCursorVariant_WResize;
+struct AzStyleCursorVariant_Wait { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_Wait AzStyleCursorVariant_Wait;
+struct AzStyleCursorVariant_ZoomIn { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_ZoomIn AzStyleCursorVariant_ZoomIn;
+struct AzStyleCursorVariant_ZoomOut { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_ZoomOut AzStyleCursorVariant_ZoomOut;
+struct AzStyleCursorVariant_Image { AzStyleCursorTag tag; AzStyleCursorImage payload; };
+typedef struct AzStyleCursorVariant_Image AzStyleCursorVariant_Image;
+union AzStyleCursor {
+    AzStyleCursorVariant_Alias Alias;
+    AzStyleCursorVariant_AllScroll AllScroll;
+    AzStyleCursorVariant_Cell Cell;
+    AzStyleCursorVariant_ColResize ColResize;
+    AzStyleCursorVariant_ContextMenu ContextMenu;
+    AzStyleCursorVariant_Copy Copy;
+    AzStyleCursorVariant_Crosshair Crosshair;
+    AzStyleCursorVariant_Default Default;
+    AzStyleCursorVariant_EResize EResize;
+    AzStyleCursorVariant_EwResize EwResize;
+    AzStyleCursorVariant_Grab Grab;
+    AzStyleCursorVariant_Grabbing Grabbing;
+    AzStyleCursorVariant_Help Help;
+    AzStyleCursorVariant_Move Move;
+    AzStyleCursorVariant_NResize NResize;
+    AzStyleCursorVariant_NsResize NsResize;
+    AzStyleCursorVariant_NeswResize NeswResize;
+    AzStyleCursorVariant_NwseResize NwseResize;
+    AzStyleCursorVariant_Pointer Pointer;
+    AzStyleCursorVariant_Progress Progress;
+    AzStyleCursorVariant_RowResize RowResize;
+    AzStyleCursorVariant_SResize SResize;
+    AzStyleCursorVariant_SeResize SeResize;
+    AzStyleCursorVariant_Text Text;
+    AzStyleCursorVariant_Unset Unset;
+    AzStyleCursorVariant_VerticalText VerticalText;
+    AzStyleCursorVariant_WResize WResize;
+    AzStyleCursorVariant_Wait Wait;
+    AzStyleCursorVariant_ZoomIn ZoomIn;
+    AzStyleCursorVariant_ZoomOut ZoomOut;
+    AzStyleCursorVariant_Image Image;
+};
+typedef union AzStyleCursor AzStyleCursor;
+
 enum AzStyleBackgroundContentVecValueTag {
    AzStyleBackgroundContentVecValueTag_Auto,
    AzStyleBackgroundContentVecValueTag_None,
@@ -9887,6 +10568,34 @@ union AzStyleBackgroundContentVecValue {
 };
 typedef union AzStyleBackgroundContentVecValue AzStyleBackgroundContentVecValue;
 
+enum AzStyleCursorValueTag {
+   AzStyleCursorValueTag_Auto,
+   AzStyleCursorValueTag_None,
+   AzStyleCursorValueTag_Inherit,
+   AzStyleCursorValueTag_Initial,
+   AzStyleCursorValueTag_Exact,
+};
+typedef enum AzStyleCursorValueTag AzStyleCursorValueTag;
+
+struct AzStyleCursorValueVariant_Auto { AzStyleCursorValueTag tag; };
+typedef struct AzStyleCursorValueVariant_Auto AzStyleCursorValueVariant_Auto;
+struct AzStyleCursorValueVariant_None { AzStyleCursorValueTag tag; };
+typedef struct AzStyleCursorValueVariant_None AzStyleCursorValueVariant_None;
+struct AzStyleCursorValueVariant_Inherit { AzStyleCursorValueTag tag; };
+typedef struct AzStyleCursorValueVariant_Inherit AzStyleCursorValueVariant_Inherit;
+struct AzStyleCursorValueVariant_Initial { AzStyleCursorValueTag tag; };
+typedef struct AzStyleCursorValueVariant_Initial AzStyleCursorValueVariant_Initial;
+struct AzStyleCursorValueVariant_Exact { AzStyleCursorValueTag tag; AzStyleCursor payload; };
+typedef struct AzStyleCursorValueVariant_Exact AzStyleCursorValueVariant_Exact;
+union AzStyleCursorValue {
+    AzStyleCursorValueVariant_Auto Auto;
+    AzStyleCursorValueVariant_None None;
+    AzStyleCursorValueVariant_Inherit Inherit;
+    AzStyleCursorValueVariant_Initial Initial;
+    AzStyleCursorValueVariant_Exact Exact;
+};
+typedef union AzStyleCursorValue AzStyleCursorValue;
+
 enum AzStyleFontFamilyVecValueTag {
    AzStyleFontFamilyVecValueTag_Auto,
    AzStyleFontFamilyVecValueTag_None,
@@ -9981,6 +10690,9 @@ enum AzCssPropertyTag {
    AzCssPropertyTag_BoxShadowTop,
    AzCssPropertyTag_BoxShadowBottom,
    AzCssPropertyTag_ScrollbarStyle,
+   AzCssPropertyTag_ScrollBehavior,
+   AzCssPropertyTag_ScrollSnapType,
+   AzCssPropertyTag_ScrollSnapAlign,
    AzCssPropertyTag_Opacity,
    AzCssPropertyTag_Transform,
    AzCssPropertyTag_TransformOrigin,
@@ -10123,6 +10835,12 @@ struct AzCssPropertyVariant_BoxShadowBottom { AzCssPropertyTag tag; AzStyleBoxSh
 typedef struct AzCssPropertyVariant_BoxShadowBottom AzCssPropertyVariant_BoxShadowBottom;
 struct AzCssPropertyVariant_ScrollbarStyle { AzCssPropertyTag tag; AzScrollbarStyleValue payload; };
 typedef struct AzCssPropertyVariant_ScrollbarStyle AzCssPropertyVariant_ScrollbarStyle;
+struct AzCssPropertyVariant_ScrollBehavior { AzCssPropertyTag tag; AzStyleScrollBehaviorValue payload; };
+typedef struct AzCssPropertyVariant_ScrollBehavior AzCssPropertyVariant_ScrollBehavior;
+struct AzCssPropertyVariant_ScrollSnapType { AzCssPropertyTag tag; AzStyleScrollSnapTypeValue payload; };
+typedef struct AzCssPropertyVariant_ScrollSnapType AzCssPropertyVariant_ScrollSnapType;
+struct AzCssPropertyVariant_ScrollSnapAlign { AzCssPropertyTag tag; AzStyleScrollSnapAlignValue payload; };
+typedef struct AzCssPropertyVariant_ScrollSnapAlign AzCssPropertyVariant_ScrollSnapAlign;
 struct AzCssPropertyVariant_Opacity { AzCssPropertyTag tag; AzStyleOpacityValue payload; };
 typedef struct AzCssPropertyVariant_Opacity AzCssPropertyVariant_Opacity;
 struct AzCssPropertyVariant_Transform { AzCssPropertyTag tag; AzStyleTransformVecValue payload; };
@@ -10207,6 +10925,9 @@ union AzCssProperty {
     AzCssPropertyVariant_BoxShadowTop BoxShadowTop;
     AzCssPropertyVariant_BoxShadowBottom BoxShadowBottom;
     AzCssPropertyVariant_ScrollbarStyle ScrollbarStyle;
+    AzCssPropertyVariant_ScrollBehavior ScrollBehavior;
+    AzCssPropertyVariant_ScrollSnapType ScrollSnapType;
+    AzCssPropertyVariant_ScrollSnapAlign ScrollSnapAlign;
     AzCssPropertyVariant_Opacity Opacity;
     AzCssPropertyVariant_Transform Transform;
     AzCssPropertyVariant_TransformOrigin TransformOrigin;
@@ -10459,6 +11180,7 @@ struct AzWindowState {
     AzPlatformSpecificOptions platform_specific_options;
     AzRendererOptions renderer_options;
     AzColorU background_color;
+    AzOptionCustomCursor custom_cursor;
     AzLayoutCallback layout_callback;
     AzOptionCallback close_callback;
 };
@@ -10480,9 +11202,14 @@ struct AzCallbackInfo {
     void* restrict threads_removed;
     AzRawWindowHandle* current_window_handle;
     void* restrict new_windows;
+    void* restrict notifications;
+    void* restrict shortcuts_changed;
+    void* restrict windows_closed;
+    void* restrict window_result;
     AzSystemCallbacks* system_callbacks;
     bool * restrict stop_propagation;
     void* restrict focus_target;
+    void* restrict drag_data;
     void* restrict words_changed_in_callbacks;
     void* restrict images_changed_in_callbacks;
     void* restrict image_masks_changed_in_callbacks;
@@ -10706,6 +11433,11 @@ struct AzWindowCreateOptions {
     AzOptionWindowTheme theme;
     AzOptionCallback create_callback;
     bool  hot_reload;
+    AzWindowId id;
+    AzOptionWindowId parent;
+    bool  modal;
+    AzOptionWindowResultCallback result_callback;
+    AzOptionString persistence_key;
 };
 typedef struct AzWindowCreateOptions AzWindowCreateOptions;
 
@@ -11128,6 +11860,9 @@ typedef struct AzCss AzCss;
 #define AzSvgFitTo_Width(v) { .Width = { .tag = AzSvgFitToTag_Width, .payload = v } }
 #define AzSvgFitTo_Height(v) { .Height = { .tag = AzSvgFitToTag_Height, .payload = v } }
 #define AzSvgFitTo_Zoom(v) { .Zoom = { .tag = AzSvgFitToTag_Zoom, .payload = v } }
+#define AzStyleCursorVecDestructor_DefaultRust { .DefaultRust = { .tag = AzStyleCursorVecDestructorTag_DefaultRust } }
+#define AzStyleCursorVecDestructor_NoDestructor { .NoDestructor = { .tag = AzStyleCursorVecDestructorTag_NoDestructor } }
+#define AzStyleCursorVecDestructor_External(v) { .External = { .tag = AzStyleCursorVecDestructorTag_External, .payload = v } }
 #define AzStyleFontFamilyVecDestructor_DefaultRust { .DefaultRust = { .tag = AzStyleFontFamilyVecDestructorTag_DefaultRust } }
 #define AzStyleFontFamilyVecDestructor_NoDestructor { .NoDestructor = { .tag = AzStyleFontFamilyVecDestructorTag_NoDestructor } }
 #define AzStyleFontFamilyVecDestructor_External(v) { .External = { .tag = AzStyleFontFamilyVecDestructorTag_External, .payload = v } }
@@ -11170,6 +11905,18 @@ typedef struct AzCss AzCss;
 #define AzAccessibilityStateVecDestructor_DefaultRust { .DefaultRust = { .tag = AzAccessibilityStateVecDestructorTag_DefaultRust } }
 #define AzAccessibilityStateVecDestructor_NoDestructor { .NoDestructor = { .tag = AzAccessibilityStateVecDestructorTag_NoDestructor } }
 #define AzAccessibilityStateVecDestructor_External(v) { .External = { .tag = AzAccessibilityStateVecDestructorTag_External, .payload = v } }
+#define AzVirtualKeyCodeComboVecDestructor_DefaultRust { .DefaultRust = { .tag = AzVirtualKeyCodeComboVecDestructorTag_DefaultRust } }
+#define AzVirtualKeyCodeComboVecDestructor_NoDestructor { .NoDestructor = { .tag = AzVirtualKeyCodeComboVecDestructorTag_NoDestructor } }
+#define AzVirtualKeyCodeComboVecDestructor_External(v) { .External = { .tag = AzVirtualKeyCodeComboVecDestructorTag_External, .payload = v } }
+#define AzShortcutBindingVecDestructor_DefaultRust { .DefaultRust = { .tag = AzShortcutBindingVecDestructorTag_DefaultRust } }
+#define AzShortcutBindingVecDestructor_NoDestructor { .NoDestructor = { .tag = AzShortcutBindingVecDestructorTag_NoDestructor } }
+#define AzShortcutBindingVecDestructor_External(v) { .External = { .tag = AzShortcutBindingVecDestructorTag_External, .payload = v } }
+#define AzTouchPointVecDestructor_DefaultRust { .DefaultRust = { .tag = AzTouchPointVecDestructorTag_DefaultRust } }
+#define AzTouchPointVecDestructor_NoDestructor { .NoDestructor = { .tag = AzTouchPointVecDestructorTag_NoDestructor } }
+#define AzTouchPointVecDestructor_External(v) { .External = { .tag = AzTouchPointVecDestructorTag_External, .payload = v } }
+#define AzNotificationActionVecDestructor_DefaultRust { .DefaultRust = { .tag = AzNotificationActionVecDestructorTag_DefaultRust } }
+#define AzNotificationActionVecDestructor_NoDestructor { .NoDestructor = { .tag = AzNotificationActionVecDestructorTag_NoDestructor } }
+#define AzNotificationActionVecDestructor_External(v) { .External = { .tag = AzNotificationActionVecDestructorTag_External, .payload = v } }
 #define AzMenuItemVecDestructor_DefaultRust { .DefaultRust = { .tag = AzMenuItemVecDestructorTag_DefaultRust } }
 #define AzMenuItemVecDestructor_NoDestructor { .NoDestructor = { .tag = AzMenuItemVecDestructorTag_NoDestructor } }
 #define AzMenuItemVecDestructor_External(v) { .External = { .tag = AzMenuItemVecDestructorTag_External, .payload = v } }
@@ -11367,6 +12114,12 @@ typedef struct AzCss AzCss;
 #define AzWindowPosition_Initialized(v) { .Initialized = { .tag = AzWindowPositionTag_Initialized, .payload = v } }
 #define AzImePosition_Uninitialized { .Uninitialized = { .tag = AzImePositionTag_Uninitialized } }
 #define AzImePosition_Initialized(v) { .Initialized = { .tag = AzImePositionTag_Initialized, .payload = v } }
+#define AzShortcutScope_Window(v) { .Window = { .tag = AzShortcutScopeTag_Window, .payload = v } }
+#define AzShortcutScope_App { .App = { .tag = AzShortcutScopeTag_App } }
+#define AzShortcutScope_Global { .Global = { .tag = AzShortcutScopeTag_Global } }
+#define AzShortcutError_NoKeys { .NoKeys = { .tag = AzShortcutErrorTag_NoKeys } }
+#define AzShortcutError_GlobalSequence { .GlobalSequence = { .tag = AzShortcutErrorTag_GlobalSequence } }
+#define AzShortcutError_Conflict(v) { .Conflict = { .tag = AzShortcutErrorTag_Conflict, .payload = v } }
 #define AzPositionInfo_Static(v) { .Static = { .tag = AzPositionInfoTag_Static, .payload = v } }
 #define AzPositionInfo_Fixed(v) { .Fixed = { .tag = AzPositionInfoTag_Fixed, .payload = v } }
 #define AzPositionInfo_Absolute(v) { .Absolute = { .tag = AzPositionInfoTag_Absolute, .payload = v } }
@@ -11554,6 +12307,21 @@ typedef struct AzCss AzCss;
 #define AzLayoutOverflowValue_Inherit { .Inherit = { .tag = AzLayoutOverflowValueTag_Inherit } }
 #define AzLayoutOverflowValue_Initial { .Initial = { .tag = AzLayoutOverflowValueTag_Initial } }
 #define AzLayoutOverflowValue_Exact(v) { .Exact = { .tag = AzLayoutOverflowValueTag_Exact, .payload = v } }
+#define AzStyleScrollBehaviorValue_Auto { .Auto = { .tag = AzStyleScrollBehaviorValueTag_Auto } }
+#define AzStyleScrollBehaviorValue_None { .None = { .tag = AzStyleScrollBehaviorValueTag_None } }
+#define AzStyleScrollBehaviorValue_Inherit { .Inherit = { .tag = AzStyleScrollBehaviorValueTag_Inherit } }
+#define AzStyleScrollBehaviorValue_Initial { .Initial = { .tag = AzStyleScrollBehaviorValueTag_Initial } }
+#define AzStyleScrollBehaviorValue_Exact(v) { .Exact = { .tag = AzStyleScrollBehaviorValueTag_Exact, .payload = v } }
+#define AzStyleScrollSnapTypeValue_Auto { .Auto = { .tag = AzStyleScrollSnapTypeValueTag_Auto } }
+#define AzStyleScrollSnapTypeValue_None { .None = { .tag = AzStyleScrollSnapTypeValueTag_None } }
+#define AzStyleScrollSnapTypeValue_Inherit { .Inherit = { .tag = AzStyleScrollSnapTypeValueTag_Inherit } }
+#define AzStyleScrollSnapTypeValue_Initial { .Initial = { .tag = AzStyleScrollSnapTypeValueTag_Initial } }
+#define AzStyleScrollSnapTypeValue_Exact(v) { .Exact = { .tag = AzStyleScrollSnapTypeValueTag_Exact, .payload = v } }
+#define AzStyleScrollSnapAlignValue_Auto { .Auto = { .tag = AzStyleScrollSnapAlignValueTag_Auto } }
+#define AzStyleScrollSnapAlignValue_None { .None = { .tag = AzStyleScrollSnapAlignValueTag_None } }
+#define AzStyleScrollSnapAlignValue_Inherit { .Inherit = { .tag = AzStyleScrollSnapAlignValueTag_Inherit } }
+#define AzStyleScrollSnapAlignValue_Initial { .Initial = { .tag = AzStyleScrollSnapAlignValueTag_Initial } }
+#define AzStyleScrollSnapAlignValue_Exact(v) { .Exact = { .tag = AzStyleScrollSnapAlignValueTag_Exact, .payload = v } }
 #define AzStyleBorderBottomColorValue_Auto { .Auto = { .tag = AzStyleBorderBottomColorValueTag_Auto } }
 #define AzStyleBorderBottomColorValue_None { .None = { .tag = AzStyleBorderBottomColorValueTag_None } }
 #define AzStyleBorderBottomColorValue_Inherit { .Inherit = { .tag = AzStyleBorderBottomColorValueTag_Inherit } }
@@ -11634,11 +12402,6 @@ typedef struct AzCss AzCss;
 #define AzLayoutBorderTopWidthValue_Inherit { .Inherit = { .tag = AzLayoutBorderTopWidthValueTag_Inherit } }
 #define AzLayoutBorderTopWidthValue_Initial { .Initial = { .tag = AzLayoutBorderTopWidthValueTag_Initial } }
 #define AzLayoutBorderTopWidthValue_Exact(v) { .Exact = { .tag = AzLayoutBorderTopWidthValueTag_Exact, .payload = v } }
-#define AzStyleCursorValue_Auto { .Auto = { .tag = AzStyleCursorValueTag_Auto } }
-#define AzStyleCursorValue_None { .None = { .tag = AzStyleCursorValueTag_None } }
-#define AzStyleCursorValue_Inherit { .Inherit = { .tag = AzStyleCursorValueTag_Inherit } }
-#define AzStyleCursorValue_Initial { .Initial = { .tag = AzStyleCursorValueTag_Initial } }
-#define AzStyleCursorValue_Exact(v) { .Exact = { .tag = AzStyleCursorValueTag_Exact, .payload = v } }
 #define AzStyleFontSizeValue_Auto { .Auto = { .tag = AzStyleFontSizeValueTag_Auto } }
 #define AzStyleFontSizeValue_None { .None = { .tag = AzStyleFontSizeValueTag_None } }
 #define AzStyleFontSizeValue_Inherit { .Inherit = { .tag = AzStyleFontSizeValueTag_Inherit } }
@@ -11768,6 +12531,10 @@ typedef struct AzCss AzCss;
 #define AzOptionThreadId_Some(v) { .Some = { .tag = AzOptionThreadIdTag_Some, .payload = v } }
 #define AzOptionImageRef_None { .None = { .tag = AzOptionImageRefTag_None } }
 #define AzOptionImageRef_Some(v) { .Some = { .tag = AzOptionImageRefTag_Some, .payload = v } }
+#define AzOptionWindowId_None { .None = { .tag = AzOptionWindowIdTag_None } }
+#define AzOptionWindowId_Some(v) { .Some = { .tag = AzOptionWindowIdTag_Some, .payload = v } }
+#define AzOptionCustomCursor_None { .None = { .tag = AzOptionCustomCursorTag_None } }
+#define AzOptionCustomCursor_Some(v) { .Some = { .tag = AzOptionCustomCursorTag_Some, .payload = v } }
 #define AzOptionFontRef_None { .None = { .tag = AzOptionFontRefTag_None } }
 #define AzOptionFontRef_Some(v) { .Some = { .tag = AzOptionFontRefTag_Some, .payload = v } }
 #define AzOptionSystemClipboard_None { .None = { .tag = AzOptionSystemClipboardTag_None } }
@@ -11788,6 +12555,8 @@ typedef struct AzCss AzCss;
 #define AzOptionLayoutRect_Some(v) { .Some = { .tag = AzOptionLayoutRectTag_Some, .payload = v } }
 #define AzOptionRefAny_None { .None = { .tag = AzOptionRefAnyTag_None } }
 #define AzOptionRefAny_Some(v) { .Some = { .tag = AzOptionRefAnyTag_Some, .payload = v } }
+#define AzOptionScrollPosition_None { .None = { .tag = AzOptionScrollPositionTag_None } }
+#define AzOptionScrollPosition_Some(v) { .Some = { .tag = AzOptionScrollPositionTag_Some, .payload = v } }
 #define AzOptionLayoutPoint_None { .None = { .tag = AzOptionLayoutPointTag_None } }
 #define AzOptionLayoutPoint_Some(v) { .Some = { .tag = AzOptionLayoutPointTag_Some, .payload = v } }
 #define AzOptionLayoutSize_None { .None = { .tag = AzOptionLayoutSizeTag_None } }
@@ -11802,6 +12571,8 @@ typedef struct AzCss AzCss;
 #define AzOptionColorU_Some(v) { .Some = { .tag = AzOptionColorUTag_Some, .payload = v } }
 #define AzOptionSvgDashPattern_None { .None = { .tag = AzOptionSvgDashPatternTag_None } }
 #define AzOptionSvgDashPattern_Some(v) { .Some = { .tag = AzOptionSvgDashPatternTag_Some, .payload = v } }
+#define AzOptionGestureState_None { .None = { .tag = AzOptionGestureStateTag_None } }
+#define AzOptionGestureState_Some(v) { .Some = { .tag = AzOptionGestureStateTag_Some, .payload = v } }
 #define AzOptionLogicalPosition_None { .None = { .tag = AzOptionLogicalPositionTag_None } }
 #define AzOptionLogicalPosition_Some(v) { .Some = { .tag = AzOptionLogicalPositionTag_Some, .payload = v } }
 #define AzOptionPhysicalPositionI32_None { .None = { .tag = AzOptionPhysicalPositionI32Tag_None } }
@@ -11826,6 +12597,8 @@ typedef struct AzCss AzCss;
 #define AzOptionU8VecRef_Some(v) { .Some = { .tag = AzOptionU8VecRefTag_Some, .payload = v } }
 #define AzResultU8VecEncodeImageError_Ok(v) { .Ok = { .tag = AzResultU8VecEncodeImageErrorTag_Ok, .payload = v } }
 #define AzResultU8VecEncodeImageError_Err(v) { .Err = { .tag = AzResultU8VecEncodeImageErrorTag_Err, .payload = v } }
+#define AzResultShortcutIdShortcutError_Ok(v) { .Ok = { .tag = AzResultShortcutIdShortcutErrorTag_Ok, .payload = v } }
+#define AzResultShortcutIdShortcutError_Err(v) { .Err = { .tag = AzResultShortcutIdShortcutErrorTag_Err, .payload = v } }
 #define AzWindowIcon_Small(v) { .Small = { .tag = AzWindowIconTag_Small, .payload = v } }
 #define AzWindowIcon_Large(v) { .Large = { .tag = AzWindowIconTag_Large, .payload = v } }
 #define AzAnimationEasing_Ease { .Ease = { .tag = AzAnimationEasingTag_Ease } }
@@ -11913,6 +12686,8 @@ typedef struct AzCss AzCss;
 #define AzOptionResolvedTextLayoutOptions_Some(v) { .Some = { .tag = AzOptionResolvedTextLayoutOptionsTag_Some, .payload = v } }
 #define AzOptionVirtualKeyCodeCombo_None { .None = { .tag = AzOptionVirtualKeyCodeComboTag_None } }
 #define AzOptionVirtualKeyCodeCombo_Some(v) { .Some = { .tag = AzOptionVirtualKeyCodeComboTag_Some, .payload = v } }
+#define AzOptionWindowResultCallback_None { .None = { .tag = AzOptionWindowResultCallbackTag_None } }
+#define AzOptionWindowResultCallback_Some(v) { .Some = { .tag = AzOptionWindowResultCallbackTag_Some, .payload = v } }
 #define AzOptionMouseState_None { .None = { .tag = AzOptionMouseStateTag_None } }
 #define AzOptionMouseState_Some(v) { .Some = { .tag = AzOptionMouseStateTag_Some, .payload = v } }
 #define AzOptionKeyboardState_None { .None = { .tag = AzOptionKeyboardStateTag_None } }
@@ -12005,6 +12780,8 @@ typedef struct AzCss AzCss;
 #define AzOptionRawImage_Some(v) { .Some = { .tag = AzOptionRawImageTag_Some, .payload = v } }
 #define AzOptionWaylandTheme_None { .None = { .tag = AzOptionWaylandThemeTag_None } }
 #define AzOptionWaylandTheme_Some(v) { .Some = { .tag = AzOptionWaylandThemeTag_Some, .payload = v } }
+#define AzOptionTrayIcon_None { .None = { .tag = AzOptionTrayIconTag_None } }
+#define AzOptionTrayIcon_Some(v) { .Some = { .tag = AzOptionTrayIconTag_Some, .payload = v } }
 #define AzResultRawImageDecodeImageError_Ok(v) { .Ok = { .tag = AzResultRawImageDecodeImageErrorTag_Ok, .payload = v } }
 #define AzResultRawImageDecodeImageError_Err(v) { .Err = { .tag = AzResultRawImageDecodeImageErrorTag_Err, .payload = v } }
 #define AzXmlStreamError_UnexpectedEndOfStream { .UnexpectedEndOfStream = { .tag = AzXmlStreamErrorTag_UnexpectedEndOfStream } }
@@ -12023,11 +12800,47 @@ typedef struct AzCss AzCss;
 #define AzMenuItem_String(v) { .String = { .tag = AzMenuItemTag_String, .payload = v } }
 #define AzMenuItem_Separator { .Separator = { .tag = AzMenuItemTag_Separator } }
 #define AzMenuItem_BreakLine { .BreakLine = { .tag = AzMenuItemTag_BreakLine } }
+#define AzStyleCursor_Alias { .Alias = { .tag = AzStyleCursorTag_Alias } }
+#define AzStyleCursor_AllScroll { .AllScroll = { .tag = AzStyleCursorTag_AllScroll } }
+#define AzStyleCursor_Cell { .Cell = { .tag = AzStyleCursorTag_Cell } }
+#define AzStyleCursor_ColResize { .ColResize = { .tag = AzStyleCursorTag_ColResize } }
+#define AzStyleCursor_ContextMenu { .ContextMenu = { .tag = AzStyleCursorTag_ContextMenu } }
+#define AzStyleCursor_Copy { .Copy = { .tag = AzStyleCursorTag_Copy } }
+#define AzStyleCursor_Crosshair { .Crosshair = { .tag = AzStyleCursorTag_Crosshair } }
+#define AzStyleCursor_Default { .Default = { .tag = AzStyleCursorTag_Default } }
+#define AzStyleCursor_EResize { .EResize = { .tag = AzStyleCursorTag_EResize } }
+#define AzStyleCursor_EwResize { .EwResize = { .tag = AzStyleCursorTag_EwResize } }
+#define AzStyleCursor_Grab { .Grab = { .tag = AzStyleCursorTag_Grab } }
+#define AzStyleCursor_Grabbing { .Grabbing = { .tag = AzStyleCursorTag_Grabbing } }
+#define AzStyleCursor_Help { .Help = { .tag = AzStyleCursorTag_Help } }
+#define AzStyleCursor_Move { .Move = { .tag = AzStyleCursorTag_Move } }
+#define AzStyleCursor_NResize { .NResize = { .tag = AzStyleCursorTag_NResize } }
+#define AzStyleCursor_NsResize { .NsResize = { .tag = AzStyleCursorTag_NsResize } }
+#define AzStyleCursor_NeswResize { .NeswResize = { .tag = AzStyleCursorTag_NeswResize } }
+#define AzStyleCursor_NwseResize { .NwseResize = { .tag = AzStyleCursorTag_NwseResize } }
+#define AzStyleCursor_Pointer { .Pointer = { .tag = AzStyleCursorTag_Pointer } }
+#define AzStyleCursor_Progress { .Progress = { .tag = AzStyleCursorTag_Progress } }
+#define AzStyleCursor_RowResize { .RowResize = { .tag = AzStyleCursorTag_RowResize } }
+#define AzStyleCursor_SResize { .SResize = { .tag = AzStyleCursorTag_SResize } }
+#define AzStyleCursor_SeResize { .SeResize = { .tag = AzStyleCursorTag_SeResize } }
+#define AzStyleCursor_Text { .Text = { .tag = AzStyleCursorTag_Text } }
+#define AzStyleCursor_Unset { .Unset = { .tag = AzStyleCursorTag_Unset } }
+#define AzStyleCursor_VerticalText { .VerticalText = { .tag = AzStyleCursorTag_VerticalText } }
+#define AzStyleCursor_WResize { .WResize = { .tag = AzStyleCursorTag_WResize } }
+#define AzStyleCursor_Wait { .Wait = { .tag = AzStyleCursorTag_Wait } }
+#define AzStyleCursor_ZoomIn { .ZoomIn = { .tag = AzStyleCursorTag_ZoomIn } }
+#define AzStyleCursor_ZoomOut { .ZoomOut = { .tag = AzStyleCursorTag_ZoomOut } }
+#define AzStyleCursor_Image(v) { .Image = { .tag = AzStyleCursorTag_Image, .payload = v } }
 #define AzStyleBackgroundContentVecValue_Auto { .Auto = { .tag = AzStyleBackgroundContentVecValueTag_Auto } }
 #define AzStyleBackgroundContentVecValue_None { .None = { .tag = AzStyleBackgroundContentVecValueTag_None } }
 #define AzStyleBackgroundContentVecValue_Inherit { .Inherit = { .tag = AzStyleBackgroundContentVecValueTag_Inherit } }
 #define AzStyleBackgroundContentVecValue_Initial { .Initial = { .tag = AzStyleBackgroundContentVecValueTag_Initial } }
 #define AzStyleBackgroundContentVecValue_Exact(v) { .Exact = { .tag = AzStyleBackgroundContentVecValueTag_Exact, .payload = v } }
+#define AzStyleCursorValue_Auto { .Auto = { .tag = AzStyleCursorValueTag_Auto } }
+#define AzStyleCursorValue_None { .None = { .tag = AzStyleCursorValueTag_None } }
+#define AzStyleCursorValue_Inherit { .Inherit = { .tag = AzStyleCursorValueTag_Inherit } }
+#define AzStyleCursorValue_Initial { .Initial = { .tag = AzStyleCursorValueTag_Initial } }
+#define AzStyleCursorValue_Exact(v) { .Exact = { .tag = AzStyleCursorValueTag_Exact, .payload = v } }
 #define AzStyleFontFamilyVecValue_Auto { .Auto = { .tag = AzStyleFontFamilyVecValueTag_Auto } }
 #define AzStyleFontFamilyVecValue_None { .None = { .tag = AzStyleFontFamilyVecValueTag_None } }
 #define AzStyleFontFamilyVecValue_Inherit { .Inherit = { .tag = AzStyleFontFamilyVecValueTag_Inherit } }
@@ -12098,6 +12911,9 @@ typedef struct AzCss AzCss;
 #define AzCssProperty_BoxShadowTop(v) { .BoxShadowTop = { .tag = AzCssPropertyTag_BoxShadowTop, .payload = v } }
 #define AzCssProperty_BoxShadowBottom(v) { .BoxShadowBottom = { .tag = AzCssPropertyTag_BoxShadowBottom, .payload = v } }
 #define AzCssProperty_ScrollbarStyle(v) { .ScrollbarStyle = { .tag = AzCssPropertyTag_ScrollbarStyle, .payload = v } }
+#define AzCssProperty_ScrollBehavior(v) { .ScrollBehavior = { .tag = AzCssPropertyTag_ScrollBehavior, .payload = v } }
+#define AzCssProperty_ScrollSnapType(v) { .ScrollSnapType = { .tag = AzCssPropertyTag_ScrollSnapType, .payload = v } }
+#define AzCssProperty_ScrollSnapAlign(v) { .ScrollSnapAlign = { .tag = AzCssPropertyTag_ScrollSnapAlign, .payload = v } }
 #define AzCssProperty_Opacity(v) { .Opacity = { .tag = AzCssPropertyTag_Opacity, .payload = v } }
 #define AzCssProperty_Transform(v) { .Transform = { .tag = AzCssPropertyTag_Transform, .payload = v } }
 #define AzCssProperty_TransformOrigin(v) { .TransformOrigin = { .tag = AzCssPropertyTag_TransformOrigin, .payload = v } }
@@ -12232,6 +13048,22 @@ AzAccessibilityState AzAccessibilityStateVecArray[] = {};
 #define AzAccessibilityStateVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzAccessibilityState), .cap = sizeof(v) / sizeof(AzAccessibilityState), .destructor = { .NoDestructor = { .tag = AzAccessibilityStateVecDestructorTag_NoDestructor, }, }, }
 #define AzAccessibilityStateVec_empty { .ptr = &AzAccessibilityStateVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzAccessibilityStateVecDestructorTag_NoDestructor, }, }, }
 
+AzVirtualKeyCodeCombo AzVirtualKeyCodeComboVecArray[] = {};
+#define AzVirtualKeyCodeComboVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzVirtualKeyCodeCombo), .cap = sizeof(v) / sizeof(AzVirtualKeyCodeCombo), .destructor = { .NoDestructor = { .tag = AzVirtualKeyCodeComboVecDestructorTag_NoDestructor, }, }, }
+#define AzVirtualKeyCodeComboVec_empty { .ptr = &AzVirtualKeyCodeComboVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzVirtualKeyCodeComboVecDestructorTag_NoDestructor, }, }, }
+
+AzShortcutBinding AzShortcutBindingVecArray[] = {};
+#define AzShortcutBindingVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzShortcutBinding), .cap = sizeof(v) / sizeof(AzShortcutBinding), .destructor = { .NoDestructor = { .tag = AzShortcutBindingVecDestructorTag_NoDestructor, }, }, }
+#define AzShortcutBindingVec_empty { .ptr = &AzShortcutBindingVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzShortcutBindingVecDestructorTag_NoDestructor, }, }, }
+
+AzTouchPoint AzTouchPointVecArray[] = {};
+#define AzTouchPointVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzTouchPoint), .cap = sizeof(v) / sizeof(AzTouchPoint), .destructor = { .NoDestructor = { .tag = AzTouchPointVecDestructorTag_NoDestructor, }, }, }
+#define AzTouchPointVec_empty { .ptr = &AzTouchPointVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzTouchPointVecDestructorTag_NoDestructor, }, }, }
+
+AzNotificationAction AzNotificationActionVecArray[] = {};
+#define AzNotificationActionVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzNotificationAction), .cap = sizeof(v) / sizeof(AzNotificationAction), .destructor = { .NoDestructor = { .tag = AzNotificationActionVecDestructorTag_NoDestructor, }, }, }
+#define AzNotificationActionVec_empty { .ptr = &AzNotificationActionVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzNotificationActionVecDestructorTag_NoDestructor, }, }, }
+
 AzMenuItem AzMenuItemVecArray[] = {};
 #define AzMenuItemVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzMenuItem), .cap = sizeof(v) / sizeof(AzMenuItem), .destructor = { .NoDestructor = { .tag = AzMenuItemVecDestructorTag_NoDestructor, }, }, }
 #define AzMenuItemVec_empty { .ptr = &AzMenuItemVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzMenuItemVecDestructorTag_NoDestructor, }, }, }
@@ -12244,6 +13076,10 @@ AzTessellatedColoredSvgNode AzTessellatedColoredSvgNodeVecArray[] = {};
 #define AzTessellatedColoredSvgNodeVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzTessellatedColoredSvgNode), .cap = sizeof(v) / sizeof(AzTessellatedColoredSvgNode), .destructor = { .NoDestructor = { .tag = AzTessellatedColoredSvgNodeVecDestructorTag_NoDestructor, }, }, }
 #define AzTessellatedColoredSvgNodeVec_empty { .ptr = &AzTessellatedColoredSvgNodeVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzTessellatedColoredSvgNodeVecDestructorTag_NoDestructor, }, }, }
 
+AzStyleCursor AzStyleCursorVecArray[] = {};
+#define AzStyleCursorVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzStyleCursor), .cap = sizeof(v) / sizeof(AzStyleCursor), .destructor = { .NoDestructor = { .tag = AzStyleCursorVecDestructorTag_NoDestructor, }, }, }
+#define AzStyleCursorVec_empty { .ptr = &AzStyleCursorVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzStyleCursorVecDestructorTag_NoDestructor, }, }, }
+
 AzStyleFontFamily AzStyleFontFamilyVecArray[] = {};
 #define AzStyleFontFamilyVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzStyleFontFamily), .cap = sizeof(v) / sizeof(AzStyleFontFamily), .destructor = { .NoDestructor = { .tag = AzStyleFontFamilyVecDestructorTag_NoDestructor, }, }, }
 #define AzStyleFontFamilyVec_empty { .ptr = &AzStyleFontFamilyVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzStyleFontFamilyVecDestructorTag_NoDestructor, }, }, }
@@ -12454,6 +13290,9 @@ extern DLLIMPORT AzApp AzApp_new(AzRefAny  data, AzAppConfig  config);
 extern DLLIMPORT void AzApp_addWindow(AzApp* restrict app, AzWindowCreateOptions  window);
 extern DLLIMPORT void AzApp_addImage(AzApp* restrict app, AzString  id, AzImageRef  image);
 extern DLLIMPORT AzMonitorVec AzApp_getMonitors(const AzApp* app);
+extern DLLIMPORT AzResultShortcutIdShortcutError AzApp_registerShortcut(AzApp* restrict app, AzShortcut  shortcut);
+extern DLLIMPORT bool  AzApp_unregisterShortcut(AzApp* restrict app, AzShortcutId  shortcut_id);
+extern DLLIMPORT AzShortcutBindingVec AzApp_getShortcuts(const AzApp* app);
 extern DLLIMPORT void AzApp_run(const AzApp* app, AzWindowCreateOptions  window);
 extern DLLIMPORT void AzApp_delete(AzApp* restrict instance);
 extern DLLIMPORT AzApp AzApp_deepCopy(AzApp* const instance);
@@ -12461,12 +13300,16 @@ extern DLLIMPORT AzAppConfig AzAppConfig_new(AzLayoutSolver  layout_solver);
 extern DLLIMPORT AzSystemCallbacks AzSystemCallbacks_libraryInternal();
 extern DLLIMPORT AzWindowCreateOptions AzWindowCreateOptions_new(AzLayoutCallbackType  layout_callback);
 extern DLLIMPORT void AzWindowCreateOptions_delete(AzWindowCreateOptions* restrict instance);
+extern DLLIMPORT AzWindowResultCallback AzWindowResultCallback_new(AzRefAny  data, AzWriteBackCallbackType  callback);
+extern DLLIMPORT void AzWindowResultCallback_delete(AzWindowResultCallback* restrict instance);
 extern DLLIMPORT AzLogicalPosition AzLogicalPosition_new(float x, float y);
 extern DLLIMPORT AzLogicalPosition AzLogicalPosition_zero();
 extern DLLIMPORT AzPhysicalSizeU32 AzLogicalSize_toPhysical(const AzLogicalSize* logicalsize, float hidpi_factor);
 extern DLLIMPORT void AzSmallWindowIconBytes_delete(AzSmallWindowIconBytes* restrict instance);
 extern DLLIMPORT void AzLargeWindowIconBytes_delete(AzLargeWindowIconBytes* restrict instance);
 extern DLLIMPORT void AzWindowIcon_delete(AzWindowIcon* restrict instance);
+extern DLLIMPORT AzTrayIcon AzTrayIcon_new(AzWindowIcon  icon, AzString  tooltip, AzMenu  menu);
+extern DLLIMPORT void AzTrayIcon_delete(AzTrayIcon* restrict instance);
 extern DLLIMPORT void AzTaskBarIcon_delete(AzTaskBarIcon* restrict instance);
 extern DLLIMPORT float AzWindowSize_getHidpiFactor(const AzWindowSize* windowsize);
 extern DLLIMPORT bool  AzKeyboardState_shiftDown(const AzKeyboardState* keyboardstate);
@@ -12481,10 +13324,24 @@ extern DLLIMPORT void AzWindowsWindowOptions_delete(AzWindowsWindowOptions* rest
 extern DLLIMPORT void AzWaylandTheme_delete(AzWaylandTheme* restrict instance);
 extern DLLIMPORT void AzStringPair_delete(AzStringPair* restrict instance);
 extern DLLIMPORT void AzLinuxWindowOptions_delete(AzLinuxWindowOptions* restrict instance);
+extern DLLIMPORT void AzTouchState_delete(AzTouchState* restrict instance);
 extern DLLIMPORT void AzMonitor_delete(AzMonitor* restrict instance);
 extern DLLIMPORT AzWindowState AzWindowState_new(AzLayoutCallbackType  layout_callback);
 extern DLLIMPORT AzWindowState AzWindowState_default();
 extern DLLIMPORT void AzWindowState_delete(AzWindowState* restrict instance);
+extern DLLIMPORT AzCustomCursor AzCustomCursor_new(AzImageRef  image, uint32_t hotspot_x, uint32_t hotspot_y);
+extern DLLIMPORT void AzCustomCursor_delete(AzCustomCursor* restrict instance);
+extern DLLIMPORT AzNotification AzNotification_new(AzString  title, AzString  body);
+extern DLLIMPORT AzNotification AzNotification_withIcon(const AzNotification notification, AzImageRef  icon);
+extern DLLIMPORT AzNotification AzNotification_withUrgency(const AzNotification notification, AzNotificationUrgency  urgency);
+extern DLLIMPORT AzNotification AzNotification_withAction(const AzNotification notification, AzString  label, AzRefAny  data, AzCallbackType  callback);
+extern DLLIMPORT AzNotification AzNotification_withClickCallback(const AzNotification notification, AzRefAny  data, AzCallbackType  callback);
+extern DLLIMPORT void AzNotification_delete(AzNotification* restrict instance);
+extern DLLIMPORT void AzNotificationAction_delete(AzNotificationAction* restrict instance);
+extern DLLIMPORT AzShortcut AzShortcut_new(AzVirtualKeyCodeComboVec  keys, AzShortcutScope  scope, AzString  description, AzRefAny  data, AzCallbackType  callback);
+extern DLLIMPORT AzString AzShortcut_getKeyString(const AzShortcut* shortcut);
+extern DLLIMPORT void AzShortcut_delete(AzShortcut* restrict instance);
+extern DLLIMPORT void AzShortcutBinding_delete(AzShortcutBinding* restrict instance);
 extern DLLIMPORT void AzLayoutCallback_delete(AzLayoutCallback* restrict instance);
 extern DLLIMPORT void AzMarshaledLayoutCallback_delete(AzMarshaledLayoutCallback* restrict instance);
 extern DLLIMPORT AzDomNodeId AzCallbackInfo_getHitNode(const AzCallbackInfo* callbackinfo);
@@ -12493,6 +13350,8 @@ extern DLLIMPORT AzOptionLogicalPosition AzCallbackInfo_getCursorRelativeToViewp
 extern DLLIMPORT AzOptionLogicalPosition AzCallbackInfo_getCursorRelativeToNode(const AzCallbackInfo* callbackinfo);
 extern DLLIMPORT AzWindowState AzCallbackInfo_getCurrentWindowState(const AzCallbackInfo* callbackinfo);
 extern DLLIMPORT AzKeyboardState AzCallbackInfo_getCurrentKeyboardState(const AzCallbackInfo* callbackinfo);
+extern DLLIMPORT AzTouchState AzCallbackInfo_getTouchState(const AzCallbackInfo* callbackinfo);
+extern DLLIMPORT AzOptionGestureState AzCallbackInfo_getGesture(const AzCallbackInfo* callbackinfo);
 extern DLLIMPORT AzMouseState AzCallbackInfo_getCurrentMouseState(const AzCallbackInfo* callbackinfo);
 extern DLLIMPORT AzOptionWindowState AzCallbackInfo_getPreviousWindowState(const AzCallbackInfo* callbackinfo);
 extern DLLIMPORT AzOptionKeyboardState AzCallbackInfo_getPreviousKeyboardState(const AzCallbackInfo* callbackinfo);
@@ -12500,6 +13359,7 @@ extern DLLIMPORT AzOptionMouseState AzCallbackInfo_getPreviousMouseState(const A
 extern DLLIMPORT AzRawWindowHandle AzCallbackInfo_getCurrentWindowHandle(const AzCallbackInfo* callbackinfo);
 extern DLLIMPORT AzOptionGl AzCallbackInfo_getGlContext(const AzCallbackInfo* callbackinfo);
 extern DLLIMPORT AzOptionLogicalPosition AzCallbackInfo_getScrollPosition(const AzCallbackInfo* callbackinfo, AzDomNodeId  node_id);
+extern DLLIMPORT AzOptionScrollPosition AzCallbackInfo_getScrollFrame(const AzCallbackInfo* callbackinfo, AzDomNodeId  node_id);
 extern DLLIMPORT AzOptionRefAny AzCallbackInfo_getDataset(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node_id);
 extern DLLIMPORT AzOptionDomNodeId AzCallbackInfo_getNodeIdOfRootDataset(AzCallbackInfo* restrict callbackinfo, AzRefAny  dataset);
 extern DLLIMPORT AzOptionString AzCallbackInfo_getStringContents(const AzCallbackInfo* callbackinfo, AzDomNodeId  node_id);
@@ -12517,9 +13377,13 @@ extern DLLIMPORT AzOptionPositionInfo AzCallbackInfo_getNodePosition(AzCallbackI
 extern DLLIMPORT AzOptionLogicalSize AzCallbackInfo_getNodeSize(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node_id);
 extern DLLIMPORT AzOptionCssProperty AzCallbackInfo_getComputedCssProperty(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzCssPropertyType  property_type);
 extern DLLIMPORT void AzCallbackInfo_setWindowState(AzCallbackInfo* restrict callbackinfo, AzWindowState  new_state);
+extern DLLIMPORT void AzCallbackInfo_setCustomCursor(AzCallbackInfo* restrict callbackinfo, AzImageRef  image, uint32_t hotspot_x, uint32_t hotspot_y);
+extern DLLIMPORT void AzCallbackInfo_resetCustomCursor(AzCallbackInfo* restrict callbackinfo);
 extern DLLIMPORT void AzCallbackInfo_setFocus(AzCallbackInfo* restrict callbackinfo, AzFocusTarget  target);
 extern DLLIMPORT void AzCallbackInfo_setCssProperty(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzCssProperty  new_property);
 extern DLLIMPORT void AzCallbackInfo_setScrollPosition(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzLogicalPosition  scroll_position);
+extern DLLIMPORT void AzCallbackInfo_scrollTo(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzLogicalPosition  scroll_position);
+extern DLLIMPORT void AzCallbackInfo_scrollIntoView(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzScrollIntoViewAlign  align, bool  smooth);
 extern DLLIMPORT void AzCallbackInfo_setStringContents(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzString  string);
 extern DLLIMPORT void AzCallbackInfo_addImage(AzCallbackInfo* restrict callbackinfo, AzString  id, AzImageRef  image);
 extern DLLIMPORT bool  AzCallbackInfo_hasImage(const AzCallbackInfo* callbackinfo, AzString  id);
@@ -12528,7 +13392,12 @@ extern DLLIMPORT void AzCallbackInfo_updateImage(AzCallbackInfo* restrict callba
 extern DLLIMPORT void AzCallbackInfo_deleteImage(AzCallbackInfo* restrict callbackinfo, AzString  id);
 extern DLLIMPORT void AzCallbackInfo_updateImageMask(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzImageMask  new_mask);
 extern DLLIMPORT void AzCallbackInfo_stopPropagation(AzCallbackInfo* restrict callbackinfo);
-extern DLLIMPORT void AzCallbackInfo_createWindow(AzCallbackInfo* restrict callbackinfo, AzWindowCreateOptions  new_window);
+extern DLLIMPORT AzWindowId AzCallbackInfo_createWindow(AzCallbackInfo* restrict callbackinfo, AzWindowCreateOptions  new_window);
+extern DLLIMPORT void AzCallbackInfo_closeWindow(AzCallbackInfo* restrict callbackinfo, AzWindowId  window_id);
+extern DLLIMPORT void AzCallbackInfo_setWindowResult(AzCallbackInfo* restrict callbackinfo, AzRefAny  result);
+extern DLLIMPORT void AzCallbackInfo_showNotification(AzCallbackInfo* restrict callbackinfo, AzNotification  notification);
+extern DLLIMPORT AzShortcutId AzCallbackInfo_registerShortcut(AzCallbackInfo* restrict callbackinfo, AzShortcut  shortcut);
+extern DLLIMPORT void AzCallbackInfo_unregisterShortcut(AzCallbackInfo* restrict callbackinfo, AzShortcutId  shortcut_id);
 extern DLLIMPORT AzTimerId AzCallbackInfo_startTimer(AzCallbackInfo* restrict callbackinfo, AzTimer  timer);
 extern DLLIMPORT AzOptionTimerId AzCallbackInfo_startAnimation(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node, AzAnimation  animation);
 extern DLLIMPORT bool  AzCallbackInfo_stopTimer(AzCallbackInfo* restrict callbackinfo, AzTimerId  timer_id);
@@ -12720,12 +13589,15 @@ extern DLLIMPORT void AzConicGradient_delete(AzConicGradient* restrict instance)
 extern DLLIMPORT void AzStyleBackgroundContent_delete(AzStyleBackgroundContent* restrict instance);
 extern DLLIMPORT void AzScrollbarInfo_delete(AzScrollbarInfo* restrict instance);
 extern DLLIMPORT void AzScrollbarStyle_delete(AzScrollbarStyle* restrict instance);
+extern DLLIMPORT void AzStyleCursor_delete(AzStyleCursor* restrict instance);
+extern DLLIMPORT void AzStyleCursorImage_delete(AzStyleCursorImage* restrict instance);
 extern DLLIMPORT void AzStyleFontFamily_delete(AzStyleFontFamily* restrict instance);
 extern DLLIMPORT void AzScrollbarStyleValue_delete(AzScrollbarStyleValue* restrict instance);
 extern DLLIMPORT void AzStyleBackgroundContentVecValue_delete(AzStyleBackgroundContentVecValue* restrict instance);
 extern DLLIMPORT void AzStyleBackgroundPositionVecValue_delete(AzStyleBackgroundPositionVecValue* restrict instance);
 extern DLLIMPORT void AzStyleBackgroundRepeatVecValue_delete(AzStyleBackgroundRepeatVecValue* restrict instance);
 extern DLLIMPORT void AzStyleBackgroundSizeVecValue_delete(AzStyleBackgroundSizeVecValue* restrict instance);
+extern DLLIMPORT void AzStyleCursorValue_delete(AzStyleCursorValue* restrict instance);
 extern DLLIMPORT void AzStyleFontFamilyVecValue_delete(AzStyleFontFamilyVecValue* restrict instance);
 extern DLLIMPORT void AzStyleTransformVecValue_delete(AzStyleTransformVecValue* restrict instance);
 extern DLLIMPORT void AzStyleFilterVecValue_delete(AzStyleFilterVecValue* restrict instance);
@@ -13386,11 +14258,16 @@ extern DLLIMPORT void AzOutputNodeAndIndexVec_delete(AzOutputNodeAndIndexVec* re
 extern DLLIMPORT void AzOutputConnectionVec_delete(AzOutputConnectionVec* restrict instance);
 extern DLLIMPORT void AzInputNodeAndIndexVec_delete(AzInputNodeAndIndexVec* restrict instance);
 extern DLLIMPORT void AzAccessibilityStateVec_delete(AzAccessibilityStateVec* restrict instance);
+extern DLLIMPORT void AzVirtualKeyCodeComboVec_delete(AzVirtualKeyCodeComboVec* restrict instance);
+extern DLLIMPORT void AzShortcutBindingVec_delete(AzShortcutBindingVec* restrict instance);
+extern DLLIMPORT void AzTouchPointVec_delete(AzTouchPointVec* restrict instance);
+extern DLLIMPORT void AzNotificationActionVec_delete(AzNotificationActionVec* restrict instance);
 extern DLLIMPORT void AzMenuItemVec_delete(AzMenuItemVec* restrict instance);
 extern DLLIMPORT AzTessellatedSvgNodeVecRef AzTessellatedSvgNodeVec_asRefVec(const AzTessellatedSvgNodeVec* tessellatedsvgnodevec);
 extern DLLIMPORT void AzTessellatedSvgNodeVec_delete(AzTessellatedSvgNodeVec* restrict instance);
 extern DLLIMPORT AzTessellatedColoredSvgNodeVecRef AzTessellatedColoredSvgNodeVec_asRefVec(const AzTessellatedColoredSvgNodeVec* tessellatedcoloredsvgnodevec);
 extern DLLIMPORT void AzTessellatedColoredSvgNodeVec_delete(AzTessellatedColoredSvgNodeVec* restrict instance);
+extern DLLIMPORT void AzStyleCursorVec_delete(AzStyleCursorVec* restrict instance);
 extern DLLIMPORT void AzStyleFontFamilyVec_delete(AzStyleFontFamilyVec* restrict instance);
 extern DLLIMPORT void AzXmlNodeVec_delete(AzXmlNodeVec* restrict instance);
 extern DLLIMPORT void AzFmtArgVec_delete(AzFmtArgVec* restrict instance);
@@ -13473,6 +14350,8 @@ extern DLLIMPORT void AzOptionMenuCallback_delete(AzOptionMenuCallback* restrict
 extern DLLIMPORT void AzOptionVirtualKeyCodeCombo_delete(AzOptionVirtualKeyCodeCombo* restrict instance);
 extern DLLIMPORT void AzOptionCssProperty_delete(AzOptionCssProperty* restrict instance);
 extern DLLIMPORT void AzOptionImageRef_delete(AzOptionImageRef* restrict instance);
+extern DLLIMPORT void AzOptionWindowResultCallback_delete(AzOptionWindowResultCallback* restrict instance);
+extern DLLIMPORT void AzOptionCustomCursor_delete(AzOptionCustomCursor* restrict instance);
 extern DLLIMPORT void AzOptionFontRef_delete(AzOptionFontRef* restrict instance);
 extern DLLIMPORT void AzOptionSystemClipboard_delete(AzOptionSystemClipboard* restrict instance);
 extern DLLIMPORT void AzOptionFileTypeList_delete(AzOptionFileTypeList* restrict instance);
@@ -13488,6 +14367,7 @@ extern DLLIMPORT void AzOptionInlineText_delete(AzOptionInlineText* restrict ins
 extern DLLIMPORT void AzOptionRawImage_delete(AzOptionRawImage* restrict instance);
 extern DLLIMPORT void AzOptionWaylandTheme_delete(AzOptionWaylandTheme* restrict instance);
 extern DLLIMPORT void AzOptionTaskBarIcon_delete(AzOptionTaskBarIcon* restrict instance);
+extern DLLIMPORT void AzOptionTrayIcon_delete(AzOptionTrayIcon* restrict instance);
 extern DLLIMPORT void AzOptionWindowIcon_delete(AzOptionWindowIcon* restrict instance);
 extern DLLIMPORT void AzOptionString_delete(AzOptionString* restrict instance);
 extern DLLIMPORT void AzOptionDom_delete(AzOptionDom* restrict instance);
@@ -15162,6 +16042,34 @@ bool AzImePosition_matchMutInitialized(AzImePosition* restrict value, AzLogicalP
     return valid;
 }
 
+bool AzShortcutScope_matchRefWindow(const AzShortcutScope* value, const AzWindowId** restrict out) {
+    const AzShortcutScopeVariant_Window* casted = (const AzShortcutScopeVariant_Window*)value;
+    bool valid = casted->tag == AzShortcutScopeTag_Window;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzShortcutScope_matchMutWindow(AzShortcutScope* restrict value, AzWindowId* restrict * restrict out) {
+    AzShortcutScopeVariant_Window* restrict casted = (AzShortcutScopeVariant_Window* restrict)value;
+    bool valid = casted->tag == AzShortcutScopeTag_Window;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzShortcutError_matchRefConflict(const AzShortcutError* value, const AzShortcutId** restrict out) {
+    const AzShortcutErrorVariant_Conflict* casted = (const AzShortcutErrorVariant_Conflict*)value;
+    bool valid = casted->tag == AzShortcutErrorTag_Conflict;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzShortcutError_matchMutConflict(AzShortcutError* restrict value, AzShortcutId* restrict * restrict out) {
+    AzShortcutErrorVariant_Conflict* restrict casted = (AzShortcutErrorVariant_Conflict* restrict)value;
+    bool valid = casted->tag == AzShortcutErrorTag_Conflict;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzLayoutCallback_matchRefRaw(const AzLayoutCallback* value, const AzLayoutCallbackInner** restrict out) {
     const AzLayoutCallbackVariant_Raw* casted = (const AzLayoutCallbackVariant_Raw*)value;
     bool valid = casted->tag == AzLayoutCallbackTag_Raw;
@@ -16016,6 +16924,20 @@ bool AzStyleBackgroundSize_matchMutExactSize(AzStyleBackgroundSize* restrict val
     return valid;
 }
 
+bool AzStyleCursor_matchRefImage(const AzStyleCursor* value, const AzStyleCursorImage** restrict out) {
+    const AzStyleCursorVariant_Image* casted = (const AzStyleCursorVariant_Image*)value;
+    bool valid = casted->tag == AzStyleCursorTag_Image;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleCursor_matchMutImage(AzStyleCursor* restrict value, AzStyleCursorImage* restrict * restrict out) {
+    AzStyleCursorVariant_Image* restrict casted = (AzStyleCursorVariant_Image* restrict)value;
+    bool valid = casted->tag == AzStyleCursorTag_Image;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzStyleFontFamily_matchRefSystem(const AzStyleFontFamily* value, const AzString** restrict out) {
     const AzStyleFontFamilyVariant_System* casted = (const AzStyleFontFamilyVariant_System*)value;
     bool valid = casted->tag == AzStyleFontFamilyTag_System;
@@ -16800,6 +17722,48 @@ bool AzScrollbarStyleValue_matchMutExact(AzScrollbarStyleValue* restrict value,
     return valid;
 }
 
+bool AzStyleScrollBehaviorValue_matchRefExact(const AzStyleScrollBehaviorValue* value, const AzStyleScrollBehavior** restrict out) {
+    const AzStyleScrollBehaviorValueVariant_Exact* casted = (const AzStyleScrollBehaviorValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleScrollBehaviorValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleScrollBehaviorValue_matchMutExact(AzStyleScrollBehaviorValue* restrict value, AzStyleScrollBehavior* restrict * restrict out) {
+    AzStyleScrollBehaviorValueVariant_Exact* restrict casted = (AzStyleScrollBehaviorValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleScrollBehaviorValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleScrollSnapTypeValue_matchRefExact(const AzStyleScrollSnapTypeValue* value, const AzStyleScrollSnapType** restrict out) {
+    const AzStyleScrollSnapTypeValueVariant_Exact* casted = (const AzStyleScrollSnapTypeValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleScrollSnapTypeValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleScrollSnapTypeValue_matchMutExact(AzStyleScrollSnapTypeValue* restrict value, AzStyleScrollSnapType* restrict * restrict out) {
+    AzStyleScrollSnapTypeValueVariant_Exact* restrict casted = (AzStyleScrollSnapTypeValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleScrollSnapTypeValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleScrollSnapAlignValue_matchRefExact(const AzStyleScrollSnapAlignValue* value, const AzStyleScrollSnapAlign** restrict out) {
+    const AzStyleScrollSnapAlignValueVariant_Exact* casted = (const AzStyleScrollSnapAlignValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleScrollSnapAlignValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleScrollSnapAlignValue_matchMutExact(AzStyleScrollSnapAlignValue* restrict value, AzStyleScrollSnapAlign* restrict * restrict out) {
+    AzStyleScrollSnapAlignValueVariant_Exact* restrict casted = (AzStyleScrollSnapAlignValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleScrollSnapAlignValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzStyleBackgroundContentVecValue_matchRefExact(const AzStyleBackgroundContentVecValue* value, const AzStyleBackgroundContentVec** restrict out) {
     const AzStyleBackgroundContentVecValueVariant_Exact* casted = (const AzStyleBackgroundContentVecValueVariant_Exact*)value;
     bool valid = casted->tag == AzStyleBackgroundContentVecValueTag_Exact;
@@ -18214,6 +19178,48 @@ bool AzCssProperty_matchMutScrollbarStyle(AzCssProperty* restrict value, AzScrol
     return valid;
 }
 
+bool AzCssProperty_matchRefScrollBehavior(const AzCssProperty* value, const AzStyleScrollBehaviorValue** restrict out) {
+    const AzCssPropertyVariant_ScrollBehavior* casted = (const AzCssPropertyVariant_ScrollBehavior*)value;
+    bool valid = casted->tag == AzCssPropertyTag_ScrollBehavior;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutScrollBehavior(AzCssProperty* restrict value, AzStyleScrollBehaviorValue* restrict * restrict out) {
+    AzCssPropertyVariant_ScrollBehavior* restrict casted = (AzCssPropertyVariant_ScrollBehavior* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_ScrollBehavior;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefScrollSnapType(const AzCssProperty* value, const AzStyleScrollSnapTypeValue** restrict out) {
+    const AzCssPropertyVariant_ScrollSnapType* casted = (const AzCssPropertyVariant_ScrollSnapType*)value;
+    bool valid = casted->tag == AzCssPropertyTag_ScrollSnapType;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutScrollSnapType(AzCssProperty* restrict value, AzStyleScrollSnapTypeValue* restrict * restrict out) {
+    AzCssPropertyVariant_ScrollSnapType* restrict casted = (AzCssPropertyVariant_ScrollSnapType* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_ScrollSnapType;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefScrollSnapAlign(const AzCssProperty* value, const AzStyleScrollSnapAlignValue** restrict out) {
+    const AzCssPropertyVariant_ScrollSnapAlign* casted = (const AzCssPropertyVariant_ScrollSnapAlign*)value;
+    bool valid = casted->tag == AzCssPropertyTag_ScrollSnapAlign;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutScrollSnapAlign(AzCssProperty* restrict value, AzStyleScrollSnapAlignValue* restrict * restrict out) {
+    AzCssPropertyVariant_ScrollSnapAlign* restrict casted = (AzCssPropertyVariant_ScrollSnapAlign* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_ScrollSnapAlign;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzCssProperty_matchRefOpacity(const AzCssProperty* value, const AzStyleOpacityValue** restrict out) {
     const AzCssPropertyVariant_Opacity* casted = (const AzCssPropertyVariant_Opacity*)value;
     bool valid = casted->tag == AzCssPropertyTag_Opacity;
@@ -19068,6 +20074,20 @@ bool AzFmtValue_matchMutStrVec(AzFmtValue* restrict value, AzStringVec* restrict
     return valid;
 }
 
+bool AzStyleCursorVecDestructor_matchRefExternal(const AzStyleCursorVecDestructor* value, const AzStyleCursorVecDestructorType** restrict out) {
+    const AzStyleCursorVecDestructorVariant_External* casted = (const AzStyleCursorVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzStyleCursorVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleCursorVecDestructor_matchMutExternal(AzStyleCursorVecDestructor* restrict value, AzStyleCursorVecDestructorType* restrict * restrict out) {
+    AzStyleCursorVecDestructorVariant_External* restrict casted = (AzStyleCursorVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzStyleCursorVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzStyleFontFamilyVecDestructor_matchRefExternal(const AzStyleFontFamilyVecDestructor* value, const AzStyleFontFamilyVecDestructorType** restrict out) {
     const AzStyleFontFamilyVecDestructorVariant_External* casted = (const AzStyleFontFamilyVecDestructorVariant_External*)value;
     bool valid = casted->tag == AzStyleFontFamilyVecDestructorTag_External;
@@ -19264,6 +20284,62 @@ bool AzAccessibilityStateVecDestructor_matchMutExternal(AzAccessibilityStateVecD
     return valid;
 }
 
+bool AzVirtualKeyCodeComboVecDestructor_matchRefExternal(const AzVirtualKeyCodeComboVecDestructor* value, const AzVirtualKeyCodeComboVecDestructorType** restrict out) {
+    const AzVirtualKeyCodeComboVecDestructorVariant_External* casted = (const AzVirtualKeyCodeComboVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzVirtualKeyCodeComboVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzVirtualKeyCodeComboVecDestructor_matchMutExternal(AzVirtualKeyCodeComboVecDestructor* restrict value, AzVirtualKeyCodeComboVecDestructorType* restrict * restrict out) {
+    AzVirtualKeyCodeComboVecDestructorVariant_External* restrict casted = (AzVirtualKeyCodeComboVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzVirtualKeyCodeComboVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzShortcutBindingVecDestructor_matchRefExternal(const AzShortcutBindingVecDestructor* value, const AzShortcutBindingVecDestructorType** restrict out) {
+    const AzShortcutBindingVecDestructorVariant_External* casted = (const AzShortcutBindingVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzShortcutBindingVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzShortcutBindingVecDestructor_matchMutExternal(AzShortcutBindingVecDestructor* restrict value, AzShortcutBindingVecDestructorType* restrict * restrict out) {
+    AzShortcutBindingVecDestructorVariant_External* restrict casted = (AzShortcutBindingVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzShortcutBindingVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzTouchPointVecDestructor_matchRefExternal(const AzTouchPointVecDestructor* value, const AzTouchPointVecDestructorType** restrict out) {
+    const AzTouchPointVecDestructorVariant_External* casted = (const AzTouchPointVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzTouchPointVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzTouchPointVecDestructor_matchMutExternal(AzTouchPointVecDestructor* restrict value, AzTouchPointVecDestructorType* restrict * restrict out) {
+    AzTouchPointVecDestructorVariant_External* restrict casted = (AzTouchPointVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzTouchPointVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzNotificationActionVecDestructor_matchRefExternal(const AzNotificationActionVecDestructor* value, const AzNotificationActionVecDestructorType** restrict out) {
+    const AzNotificationActionVecDestructorVariant_External* casted = (const AzNotificationActionVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzNotificationActionVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzNotificationActionVecDestructor_matchMutExternal(AzNotificationActionVecDestructor* restrict value, AzNotificationActionVecDestructorType* restrict * restrict out) {
+    AzNotificationActionVecDestructorVariant_External* restrict casted = (AzNotificationActionVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzNotificationActionVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzMenuItemVecDestructor_matchRefExternal(const AzMenuItemVecDestructor* value, const AzMenuItemVecDestructorType** restrict out) {
     const AzMenuItemVecDestructorVariant_External* casted = (const AzMenuItemVecDestructorVariant_External*)value;
     bool valid = casted->tag == AzMenuItemVecDestructorTag_External;
@@ -20538,6 +21614,48 @@ bool AzOptionImageRef_matchMutSome(AzOptionImageRef* restrict value, AzImageRef*
     return valid;
 }
 
+bool AzOptionWindowId_matchRefSome(const AzOptionWindowId* value, const AzWindowId** restrict out) {
+    const AzOptionWindowIdVariant_Some* casted = (const AzOptionWindowIdVariant_Some*)value;
+    bool valid = casted->tag == AzOptionWindowIdTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionWindowId_matchMutSome(AzOptionWindowId* restrict value, AzWindowId* restrict * restrict out) {
+    AzOptionWindowIdVariant_Some* restrict casted = (AzOptionWindowIdVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionWindowIdTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionWindowResultCallback_matchRefSome(const AzOptionWindowResultCallback* value, const AzWindowResultCallback** restrict out) {
+    const AzOptionWindowResultCallbackVariant_Some* casted = (const AzOptionWindowResultCallbackVariant_Some*)value;
+    bool valid = casted->tag == AzOptionWindowResultCallbackTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionWindowResultCallback_matchMutSome(AzOptionWindowResultCallback* restrict value, AzWindowResultCallback* restrict * restrict out) {
+    AzOptionWindowResultCallbackVariant_Some* restrict casted = (AzOptionWindowResultCallbackVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionWindowResultCallbackTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionCustomCursor_matchRefSome(const AzOptionCustomCursor* value, const AzCustomCursor** restrict out) {
+    const AzOptionCustomCursorVariant_Some* casted = (const AzOptionCustomCursorVariant_Some*)value;
+    bool valid = casted->tag == AzOptionCustomCursorTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionCustomCursor_matchMutSome(AzOptionCustomCursor* restrict value, AzCustomCursor* restrict * restrict out) {
+    AzOptionCustomCursorVariant_Some* restrict casted = (AzOptionCustomCursorVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionCustomCursorTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzOptionFontRef_matchRefSome(const AzOptionFontRef* value, const AzFontRef** restrict out) {
     const AzOptionFontRefVariant_Some* casted = (const AzOptionFontRefVariant_Some*)value;
     bool valid = casted->tag == AzOptionFontRefTag_Some;
@@ -20776,6 +21894,20 @@ bool AzOptionRefAny_matchMutSome(AzOptionRefAny* restrict value, AzRefAny* restr
     return valid;
 }
 
+bool AzOptionScrollPosition_matchRefSome(const AzOptionScrollPosition* value, const AzScrollPosition** restrict out) {
+    const AzOptionScrollPositionVariant_Some* casted = (const AzOptionScrollPositionVariant_Some*)value;
+    bool valid = casted->tag == AzOptionScrollPositionTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionScrollPosition_matchMutSome(AzOptionScrollPosition* restrict value, AzScrollPosition* restrict * restrict out) {
+    AzOptionScrollPositionVariant_Some* restrict casted = (AzOptionScrollPositionVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionScrollPositionTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzOptionInlineText_matchRefSome(const AzOptionInlineText* value, const AzInlineText** restrict out) {
     const AzOptionInlineTextVariant_Some* casted = (const AzOptionInlineTextVariant_Some*)value;
     bool valid = casted->tag == AzOptionInlineTextTag_Some;
@@ -20944,6 +22076,20 @@ bool AzOptionHwndHandle_matchMutSome(AzOptionHwndHandle* restrict value, Az*mut
     return valid;
 }
 
+bool AzOptionGestureState_matchRefSome(const AzOptionGestureState* value, const AzGestureState** restrict out) {
+    const AzOptionGestureStateVariant_Some* casted = (const AzOptionGestureStateVariant_Some*)value;
+    bool valid = casted->tag == AzOptionGestureStateTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionGestureState_matchMutSome(AzOptionGestureState* restrict value, AzGestureState* restrict * restrict out) {
+    AzOptionGestureStateVariant_Some* restrict casted = (AzOptionGestureStateVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionGestureStateTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzOptionLogicalPosition_matchRefSome(const AzOptionLogicalPosition* value, const AzLogicalPosition** restrict out) {
     const AzOptionLogicalPositionVariant_Some* casted = (const AzOptionLogicalPositionVariant_Some*)value;
     bool valid = casted->tag == AzOptionLogicalPositionTag_Some;
@@ -20972,6 +22118,20 @@ bool AzOptionPhysicalPositionI32_matchMutSome(AzOptionPhysicalPositionI32* restr
     return valid;
 }
 
+bool AzOptionTrayIcon_matchRefSome(const AzOptionTrayIcon* value, const AzTrayIcon** restrict out) {
+    const AzOptionTrayIconVariant_Some* casted = (const AzOptionTrayIconVariant_Some*)value;
+    bool valid = casted->tag == AzOptionTrayIconTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTrayIcon_matchMutSome(AzOptionTrayIcon* restrict value, AzTrayIcon* restrict * restrict out) {
+    AzOptionTrayIconVariant_Some* restrict casted = (AzOptionTrayIconVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionTrayIconTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzOptionWindowIcon_matchRefSome(const AzOptionWindowIcon* value, const AzWindowIcon** restrict out) {
     const AzOptionWindowIconVariant_Some* casted = (const AzOptionWindowIconVariant_Some*)value;
     bool valid = casted->tag == AzOptionWindowIconTag_Some;
@@ -21350,6 +22510,34 @@ bool AzResultSvgXmlNodeSvgParseError_matchMutErr(AzResultSvgXmlNodeSvgParseError
     return valid;
 }
 
+bool AzResultShortcutIdShortcutError_matchRefOk(const AzResultShortcutIdShortcutError* value, const AzShortcutId** restrict out) {
+    const AzResultShortcutIdShortcutErrorVariant_Ok* casted = (const AzResultShortcutIdShortcutErrorVariant_Ok*)value;
+    bool valid = casted->tag == AzResultShortcutIdShortcutErrorTag_Ok;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzResultShortcutIdShortcutError_matchMutOk(AzResultShortcutIdShortcutError* restrict value, AzShortcutId* restrict * restrict out) {
+    AzResultShortcutIdShortcutErrorVariant_Ok* restrict casted = (AzResultShortcutIdShortcutErrorVariant_Ok* restrict)value;
+    bool valid = casted->tag == AzResultShortcutIdShortcutErrorTag_Ok;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzResultShortcutIdShortcutError_matchRefErr(const AzResultShortcutIdShortcutError* value, const AzShortcutError** restrict out) {
+    const AzResultShortcutIdShortcutErrorVariant_Err* casted = (const AzResultShortcutIdShortcutErrorVariant_Err*)value;
+    bool valid = casted->tag == AzResultShortcutIdShortcutErrorTag_Err;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzResultShortcutIdShortcutError_matchMutErr(AzResultShortcutIdShortcutError* restrict value, AzShortcutError* restrict * restrict out) {
+    AzResultShortcutIdShortcutErrorVariant_Err* restrict casted = (AzResultShortcutIdShortcutErrorVariant_Err* restrict)value;
+    bool valid = casted->tag == AzResultShortcutIdShortcutErrorTag_Err;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzResultSvgSvgParseError_matchRefOk(const AzResultSvgSvgParseError* value, const AzSvg** restrict out) {
     const AzResultSvgSvgParseErrorVariant_Ok* casted = (const AzResultSvgSvgParseErrorVariant_Ok*)value;
     bool valid = casted->tag == AzResultSvgSvgParseErrorTag_Ok;
@@ -21863,6 +23051,7 @@ bool AzXmlStreamError_matchMutInvalidString(AzXmlStreamError* restrict value, Az
     .enable_visual_panic_hook = true, \
     .enable_logging_on_panic = true, \
     .enable_tab_navigation = true, \
+    .enable_inspector = false, \
     .system_callbacks = AzSystemCallbacks_libraryInternal(), \
 }
 
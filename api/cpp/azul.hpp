@@ -129,6 +129,9 @@ namespace dll {
     
     using ThreadSenderDestructorFnType = void(*)(ThreadSender* restrict);
     
+    struct StyleCursorVec;
+    using StyleCursorVecDestructorType = void(*)(StyleCursorVec* restrict);
+    
     struct StyleFontFamilyVec;
     using StyleFontFamilyVecDestructorType = void(*)(StyleFontFamilyVec* restrict);
     
@@ -171,6 +174,18 @@ namespace dll {
     struct AccessibilityStateVec;
     using AccessibilityStateVecDestructorType = void(*)(AccessibilityStateVec* restrict);
     
+    struct VirtualKeyCodeComboVec;
+    using VirtualKeyCodeComboVecDestructorType = void(*)(VirtualKeyCodeComboVec* restrict);
+    
+    struct ShortcutBindingVec;
+    using ShortcutBindingVecDestructorType = void(*)(ShortcutBindingVec* restrict);
+    
+    struct TouchPointVec;
+    using TouchPointVecDestructorType = void(*)(TouchPointVec* restrict);
+    
+    struct NotificationActionVec;
+    using NotificationActionVecDestructorType = void(*)(NotificationActionVec* restrict);
+    
     struct MenuItemVec;
     using MenuItemVecDestructorType = void(*)(MenuItemVec* restrict);
     
@@ -352,6 +367,12 @@ namespace dll {
        Default,
     };
     
+    struct WindowId {
+        size_t id;
+        WindowId& operator=(const WindowId&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        WindowId() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class Vsync {
        Enabled,
        Disabled,
@@ -762,10 +783,22 @@ namespace dll {
        LightMode,
     };
     
-    struct TouchState {
-        uint8_t unused;
-        TouchState& operator=(const TouchState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
-        TouchState() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    enum class TouchPointKind {
+       Finger,
+       Pen,
+       Eraser,
+    };
+    
+    enum class NotificationUrgency {
+       Low,
+       Normal,
+       Critical,
+    };
+    
+    struct ShortcutId {
+        size_t id;
+        ShortcutId& operator=(const ShortcutId&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        ShortcutId() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
     struct MarshaledLayoutCallbackInner {
@@ -819,6 +852,13 @@ namespace dll {
         PositionInfoInner() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    enum class ScrollIntoViewAlign {
+       Start,
+       Center,
+       End,
+       Nearest,
+    };
+    
     enum class AnimationRepeat {
        NoRepeat,
        Loop,
@@ -899,6 +939,23 @@ namespace dll {
        HoveredFileCancelled,
        FocusReceived,
        FocusLost,
+       DragStart,
+       DragEnter,
+       DragOver,
+       DragLeave,
+       Drop,
+       TextCompositionStart,
+       TextCompositionUpdate,
+       TextCompositionEnd,
+       TouchStart,
+       TouchMove,
+       TouchEnd,
+       TouchCancel,
+       Pinch,
+       Rotate,
+       TwoFingerPan,
+       LongPress,
+       Scrolled,
     };
     
     enum class HoverEventFilter {
@@ -926,6 +983,18 @@ namespace dll {
        TouchMove,
        TouchEnd,
        TouchCancel,
+       Pinch,
+       Rotate,
+       TwoFingerPan,
+       LongPress,
+       DragStart,
+       DragEnter,
+       DragOver,
+       DragLeave,
+       Drop,
+       TextCompositionStart,
+       TextCompositionUpdate,
+       TextCompositionEnd,
     };
     
     enum class FocusEventFilter {
@@ -948,6 +1017,9 @@ namespace dll {
        VirtualKeyUp,
        FocusReceived,
        FocusLost,
+       TextCompositionStart,
+       TextCompositionUpdate,
+       TextCompositionEnd,
     };
     
     enum class WindowEventFilter {
@@ -977,10 +1049,26 @@ namespace dll {
        TouchMove,
        TouchEnd,
        TouchCancel,
+       Pinch,
+       Rotate,
+       TwoFingerPan,
+       LongPress,
        FocusReceived,
        FocusLost,
        CloseRequested,
        ThemeChanged,
+       WindowFocusReceived,
+       WindowFocusLost,
+       ClipboardChanged,
+       DragStart,
+       DragEnter,
+       DragOver,
+       DragLeave,
+       Drop,
+       DragEnd,
+       TextCompositionStart,
+       TextCompositionUpdate,
+       TextCompositionEnd,
     };
     
     enum class ComponentEventFilter {
@@ -989,6 +1077,7 @@ namespace dll {
        NodeResized,
        DefaultAction,
        Selected,
+       Scrolled,
     };
     
     enum class ApplicationEventFilter {
@@ -1205,6 +1294,9 @@ namespace dll {
        BoxShadowTop,
        BoxShadowBottom,
        ScrollbarStyle,
+       ScrollBehavior,
+       ScrollSnapType,
+       ScrollSnapAlign,
        Opacity,
        Transform,
        TransformOrigin,
@@ -1386,37 +1478,28 @@ namespace dll {
        Outset,
     };
     
-    enum class StyleCursor {
-       Alias,
-       AllScroll,
-       Cell,
-       ColResize,
-       ContextMenu,
-       Copy,
-       Crosshair,
-       Default,
-       EResize,
-       EwResize,
-       Grab,
-       Grabbing,
-       Help,
-       Move,
-       NResize,
-       NsResize,
-       NeswResize,
-       NwseResize,
-       Pointer,
-       Progress,
-       RowResize,
-       SResize,
-       SeResize,
-       Text,
-       Unset,
-       VerticalText,
-       WResize,
-       Wait,
-       ZoomIn,
-       ZoomOut,
+    enum class StyleScrollBehavior {
+       Auto,
+       Smooth,
+    };
+    
+    enum class ScrollSnapAxis {
+       None,
+       X,
+       Y,
+       Both,
+    };
+    
+    enum class ScrollSnapStrictness {
+       Proximity,
+       Mandatory,
+    };
+    
+    enum class StyleScrollSnapAlign {
+       None,
+       Start,
+       Center,
+       End,
     };
     
     enum class StyleBackfaceVisibility {
@@ -2278,6 +2361,22 @@ namespace dll {
         ThreadSenderDestructorFn() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    enum class StyleCursorVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct StyleCursorVecDestructorVariant_DefaultRust { StyleCursorVecDestructorTag tag; };
+    struct StyleCursorVecDestructorVariant_NoDestructor { StyleCursorVecDestructorTag tag; };
+    struct StyleCursorVecDestructorVariant_External { StyleCursorVecDestructorTag tag; StyleCursorVecDestructorType payload; };
+    union StyleCursorVecDestructor {
+        StyleCursorVecDestructorVariant_DefaultRust DefaultRust;
+        StyleCursorVecDestructorVariant_NoDestructor NoDestructor;
+        StyleCursorVecDestructorVariant_External External;
+    };
+    
+    
     enum class StyleFontFamilyVecDestructorTag {
        DefaultRust,
        NoDestructor,
@@ -2502,6 +2601,70 @@ namespace dll {
     };
     
     
+    enum class VirtualKeyCodeComboVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct VirtualKeyCodeComboVecDestructorVariant_DefaultRust { VirtualKeyCodeComboVecDestructorTag tag; };
+    struct VirtualKeyCodeComboVecDestructorVariant_NoDestructor { VirtualKeyCodeComboVecDestructorTag tag; };
+    struct VirtualKeyCodeComboVecDestructorVariant_External { VirtualKeyCodeComboVecDestructorTag tag; VirtualKeyCodeComboVecDestructorType payload; };
+    union VirtualKeyCodeComboVecDestructor {
+        VirtualKeyCodeComboVecDestructorVariant_DefaultRust DefaultRust;
+        VirtualKeyCodeComboVecDestructorVariant_NoDestructor NoDestructor;
+        VirtualKeyCodeComboVecDestructorVariant_External External;
+    };
+    
+    
+    enum class ShortcutBindingVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct ShortcutBindingVecDestructorVariant_DefaultRust { ShortcutBindingVecDestructorTag tag; };
+    struct ShortcutBindingVecDestructorVariant_NoDestructor { ShortcutBindingVecDestructorTag tag; };
+    struct ShortcutBindingVecDestructorVariant_External { ShortcutBindingVecDestructorTag tag; ShortcutBindingVecDestructorType payload; };
+    union ShortcutBindingVecDestructor {
+        ShortcutBindingVecDestructorVariant_DefaultRust DefaultRust;
+        ShortcutBindingVecDestructorVariant_NoDestructor NoDestructor;
+        ShortcutBindingVecDestructorVariant_External External;
+    };
+    
+    
+    enum class TouchPointVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct TouchPointVecDestructorVariant_DefaultRust { TouchPointVecDestructorTag tag; };
+    struct TouchPointVecDestructorVariant_NoDestructor { TouchPointVecDestructorTag tag; };
+    struct TouchPointVecDestructorVariant_External { TouchPointVecDestructorTag tag; TouchPointVecDestructorType payload; };
+    union TouchPointVecDestructor {
+        TouchPointVecDestructorVariant_DefaultRust DefaultRust;
+        TouchPointVecDestructorVariant_NoDestructor NoDestructor;
+        TouchPointVecDestructorVariant_External External;
+    };
+    
+    
+    enum class NotificationActionVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct NotificationActionVecDestructorVariant_DefaultRust { NotificationActionVecDestructorTag tag; };
+    struct NotificationActionVecDestructorVariant_NoDestructor { NotificationActionVecDestructorTag tag; };
+    struct NotificationActionVecDestructorVariant_External { NotificationActionVecDestructorTag tag; NotificationActionVecDestructorType payload; };
+    union NotificationActionVecDestructor {
+        NotificationActionVecDestructorVariant_DefaultRust DefaultRust;
+        NotificationActionVecDestructorVariant_NoDestructor NoDestructor;
+        NotificationActionVecDestructorVariant_External External;
+    };
+    
+    
     enum class MenuItemVecDestructorTag {
        DefaultRust,
        NoDestructor,
@@ -3567,7 +3730,10 @@ namespace dll {
         bool  has_focus;
         bool  has_extended_window_frame;
         bool  has_blur_behind_window;
+        bool  is_click_through;
         bool  smooth_scroll_enabled;
+        bool  overscroll_bounce_enabled;
+        bool  scrollbar_auto_hide_enabled;
         bool  autotab_enabled;
         WindowFlags& operator=(const WindowFlags&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         WindowFlags() = delete; /* disable default constructor, use C++20 designated initializer instead */
@@ -3615,6 +3781,29 @@ namespace dll {
     };
     
     
+    struct TouchPoint {
+        uint64_t id;
+        TouchPointKind kind;
+        LogicalPosition position;
+        float pressure;
+        float tilt_x;
+        float tilt_y;
+        TouchPoint& operator=(const TouchPoint&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TouchPoint() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct GestureState {
+        LogicalPosition center;
+        float scale;
+        float rotation;
+        LogicalPosition translation;
+        bool  is_pinch;
+        bool  is_rotate;
+        bool  is_pan;
+        GestureState& operator=(const GestureState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        GestureState() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct VideoMode {
         LayoutSize size;
         uint16_t bit_depth;
@@ -3623,6 +3812,47 @@ namespace dll {
         VideoMode() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct CustomCursor {
+        ImageRef image;
+        uint32_t hotspot_x;
+        uint32_t hotspot_y;
+        CustomCursor& operator=(const CustomCursor&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        CustomCursor(const CustomCursor&) = delete; /* disable copy constructor, use explicit .clone() */
+        CustomCursor() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    enum class ShortcutScopeTag {
+       Window,
+       App,
+       Global,
+    };
+    
+    struct ShortcutScopeVariant_Window { ShortcutScopeTag tag; WindowId payload; };
+    struct ShortcutScopeVariant_App { ShortcutScopeTag tag; };
+    struct ShortcutScopeVariant_Global { ShortcutScopeTag tag; };
+    union ShortcutScope {
+        ShortcutScopeVariant_Window Window;
+        ShortcutScopeVariant_App App;
+        ShortcutScopeVariant_Global Global;
+    };
+    
+    
+    enum class ShortcutErrorTag {
+       NoKeys,
+       GlobalSequence,
+       Conflict,
+    };
+    
+    struct ShortcutErrorVariant_NoKeys { ShortcutErrorTag tag; };
+    struct ShortcutErrorVariant_GlobalSequence { ShortcutErrorTag tag; };
+    struct ShortcutErrorVariant_Conflict { ShortcutErrorTag tag; ShortcutId payload; };
+    union ShortcutError {
+        ShortcutErrorVariant_NoKeys NoKeys;
+        ShortcutErrorVariant_GlobalSequence GlobalSequence;
+        ShortcutErrorVariant_Conflict Conflict;
+    };
+    
+    
     struct DomNodeId {
         DomId dom;
         NodeId node;
@@ -3649,6 +3879,15 @@ namespace dll {
     };
     
     
+    struct ScrollPosition {
+        LogicalRect parent_rect;
+        LogicalRect children_rect;
+        LogicalPosition scroll_offset;
+        ScrollPosition& operator=(const ScrollPosition&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        ScrollPosition(const ScrollPosition&) = delete; /* disable copy constructor, use explicit .clone() */
+        ScrollPosition() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct HidpiAdjustedBounds {
         LogicalSize logical_size;
         float hidpi_factor;
@@ -4177,6 +4416,13 @@ namespace dll {
         LayoutBorderTopWidth() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct StyleScrollSnapType {
+        ScrollSnapAxis axis;
+        ScrollSnapStrictness strictness;
+        StyleScrollSnapType& operator=(const StyleScrollSnapType&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleScrollSnapType() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct StyleFontSize {
         PixelValue inner;
         StyleFontSize& operator=(const StyleFontSize&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
@@ -4993,6 +5239,72 @@ namespace dll {
     };
     
     
+    enum class StyleScrollBehaviorValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleScrollBehaviorValueVariant_Auto { StyleScrollBehaviorValueTag tag; };
+    struct StyleScrollBehaviorValueVariant_None { StyleScrollBehaviorValueTag tag; };
+    struct StyleScrollBehaviorValueVariant_Inherit { StyleScrollBehaviorValueTag tag; };
+    struct StyleScrollBehaviorValueVariant_Initial { StyleScrollBehaviorValueTag tag; };
+    struct StyleScrollBehaviorValueVariant_Exact { StyleScrollBehaviorValueTag tag; StyleScrollBehavior payload; };
+    union StyleScrollBehaviorValue {
+        StyleScrollBehaviorValueVariant_Auto Auto;
+        StyleScrollBehaviorValueVariant_None None;
+        StyleScrollBehaviorValueVariant_Inherit Inherit;
+        StyleScrollBehaviorValueVariant_Initial Initial;
+        StyleScrollBehaviorValueVariant_Exact Exact;
+    };
+    
+    
+    enum class StyleScrollSnapTypeValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleScrollSnapTypeValueVariant_Auto { StyleScrollSnapTypeValueTag tag; };
+    struct StyleScrollSnapTypeValueVariant_None { StyleScrollSnapTypeValueTag tag; };
+    struct StyleScrollSnapTypeValueVariant_Inherit { StyleScrollSnapTypeValueTag tag; };
+    struct StyleScrollSnapTypeValueVariant_Initial { StyleScrollSnapTypeValueTag tag; };
+    struct StyleScrollSnapTypeValueVariant_Exact { StyleScrollSnapTypeValueTag tag; StyleScrollSnapType payload; };
+    union StyleScrollSnapTypeValue {
+        StyleScrollSnapTypeValueVariant_Auto Auto;
+        StyleScrollSnapTypeValueVariant_None None;
+        StyleScrollSnapTypeValueVariant_Inherit Inherit;
+        StyleScrollSnapTypeValueVariant_Initial Initial;
+        StyleScrollSnapTypeValueVariant_Exact Exact;
+    };
+    
+    
+    enum class StyleScrollSnapAlignValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleScrollSnapAlignValueVariant_Auto { StyleScrollSnapAlignValueTag tag; };
+    struct StyleScrollSnapAlignValueVariant_None { StyleScrollSnapAlignValueTag tag; };
+    struct StyleScrollSnapAlignValueVariant_Inherit { StyleScrollSnapAlignValueTag tag; };
+    struct StyleScrollSnapAlignValueVariant_Initial { StyleScrollSnapAlignValueTag tag; };
+    struct StyleScrollSnapAlignValueVariant_Exact { StyleScrollSnapAlignValueTag tag; StyleScrollSnapAlign payload; };
+    union StyleScrollSnapAlignValue {
+        StyleScrollSnapAlignValueVariant_Auto Auto;
+        StyleScrollSnapAlignValueVariant_None None;
+        StyleScrollSnapAlignValueVariant_Inherit Inherit;
+        StyleScrollSnapAlignValueVariant_Initial Initial;
+        StyleScrollSnapAlignValueVariant_Exact Exact;
+    };
+    
+    
     enum class StyleBorderBottomColorValueTag {
        Auto,
        None,
@@ -5345,28 +5657,6 @@ namespace dll {
     };
     
     
-    enum class StyleCursorValueTag {
-       Auto,
-       None,
-       Inherit,
-       Initial,
-       Exact,
-    };
-    
-    struct StyleCursorValueVariant_Auto { StyleCursorValueTag tag; };
-    struct StyleCursorValueVariant_None { StyleCursorValueTag tag; };
-    struct StyleCursorValueVariant_Inherit { StyleCursorValueTag tag; };
-    struct StyleCursorValueVariant_Initial { StyleCursorValueTag tag; };
-    struct StyleCursorValueVariant_Exact { StyleCursorValueTag tag; StyleCursor payload; };
-    union StyleCursorValue {
-        StyleCursorValueVariant_Auto Auto;
-        StyleCursorValueVariant_None None;
-        StyleCursorValueVariant_Inherit Inherit;
-        StyleCursorValueVariant_Initial Initial;
-        StyleCursorValueVariant_Exact Exact;
-    };
-    
-    
     enum class StyleFontSizeValueTag {
        Auto,
        None,
@@ -6087,6 +6377,16 @@ namespace dll {
         AccessibilityStateVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct TouchPointVec {
+        TouchPoint* ptr;
+        size_t len;
+        size_t cap;
+        TouchPointVecDestructor destructor;
+        TouchPointVec& operator=(const TouchPointVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TouchPointVec(const TouchPointVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        TouchPointVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct AzMenuItem;
     struct MenuItemVec {
         MenuItem* ptr;
@@ -6098,6 +6398,17 @@ namespace dll {
         MenuItemVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct AzStyleCursor;
+    struct StyleCursorVec {
+        StyleCursor* ptr;
+        size_t len;
+        size_t cap;
+        StyleCursorVecDestructor destructor;
+        StyleCursorVec& operator=(const StyleCursorVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleCursorVec(const StyleCursorVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        StyleCursorVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct AzXmlNode;
     struct XmlNodeVec {
         XmlNode* ptr;
@@ -6753,6 +7064,32 @@ namespace dll {
     };
     
     
+    enum class OptionWindowIdTag {
+       None,
+       Some,
+    };
+    
+    struct OptionWindowIdVariant_None { OptionWindowIdTag tag; };
+    struct OptionWindowIdVariant_Some { OptionWindowIdTag tag; WindowId payload; };
+    union OptionWindowId {
+        OptionWindowIdVariant_None None;
+        OptionWindowIdVariant_Some Some;
+    };
+    
+    
+    enum class OptionCustomCursorTag {
+       None,
+       Some,
+    };
+    
+    struct OptionCustomCursorVariant_None { OptionCustomCursorTag tag; };
+    struct OptionCustomCursorVariant_Some { OptionCustomCursorTag tag; CustomCursor payload; };
+    union OptionCustomCursor {
+        OptionCustomCursorVariant_None None;
+        OptionCustomCursorVariant_Some Some;
+    };
+    
+    
     enum class OptionFontRefTag {
        None,
        Some,
@@ -6883,6 +7220,19 @@ namespace dll {
     };
     
     
+    enum class OptionScrollPositionTag {
+       None,
+       Some,
+    };
+    
+    struct OptionScrollPositionVariant_None { OptionScrollPositionTag tag; };
+    struct OptionScrollPositionVariant_Some { OptionScrollPositionTag tag; ScrollPosition payload; };
+    union OptionScrollPosition {
+        OptionScrollPositionVariant_None None;
+        OptionScrollPositionVariant_Some Some;
+    };
+    
+    
     enum class OptionLayoutPointTag {
        None,
        Some,
@@ -6974,6 +7324,19 @@ namespace dll {
     };
     
     
+    enum class OptionGestureStateTag {
+       None,
+       Some,
+    };
+    
+    struct OptionGestureStateVariant_None { OptionGestureStateTag tag; };
+    struct OptionGestureStateVariant_Some { OptionGestureStateTag tag; GestureState payload; };
+    union OptionGestureState {
+        OptionGestureStateVariant_None None;
+        OptionGestureStateVariant_Some Some;
+    };
+    
+    
     enum class OptionLogicalPositionTag {
        None,
        Some,
@@ -7130,6 +7493,19 @@ namespace dll {
     };
     
     
+    enum class ResultShortcutIdShortcutErrorTag {
+       Ok,
+       Err,
+    };
+    
+    struct ResultShortcutIdShortcutErrorVariant_Ok { ResultShortcutIdShortcutErrorTag tag; ShortcutId payload; };
+    struct ResultShortcutIdShortcutErrorVariant_Err { ResultShortcutIdShortcutErrorTag tag; ShortcutError payload; };
+    union ResultShortcutIdShortcutError {
+        ResultShortcutIdShortcutErrorVariant_Ok Ok;
+        ResultShortcutIdShortcutErrorVariant_Err Err;
+    };
+    
+    
     struct NonXmlCharError {
         uint32_t ch;
         SvgParseErrorPosition pos;
@@ -7174,12 +7550,21 @@ namespace dll {
         bool  enable_visual_panic_hook;
         bool  enable_logging_on_panic;
         bool  enable_tab_navigation;
+        bool  enable_inspector;
         SystemCallbacks system_callbacks;
         AppConfig& operator=(const AppConfig&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         AppConfig(const AppConfig&) = delete; /* disable copy constructor, use explicit .clone() */
         AppConfig() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct WindowResultCallback {
+        RefAny data;
+        WriteBackCallback callback;
+        WindowResultCallback& operator=(const WindowResultCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        WindowResultCallback(const WindowResultCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        WindowResultCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct SmallWindowIconBytes {
         IconKey key;
         U8Vec rgba_bytes;
@@ -7250,6 +7635,17 @@ namespace dll {
         MouseState() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct TouchState {
+        TouchPointVec touch_points;
+        TouchPointVec ended_touch_points;
+        TouchPointVec cancelled_touch_points;
+        OptionGestureState gesture;
+        OptionLogicalPosition long_press;
+        TouchState& operator=(const TouchState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TouchState(const TouchState&) = delete; /* disable copy constructor, use explicit .clone() */
+        TouchState() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct MarshaledLayoutCallback {
         RefAny marshal_data;
         MarshaledLayoutCallbackInner cb;
@@ -7911,6 +8307,16 @@ namespace dll {
         OutputConnectionVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct VirtualKeyCodeComboVec {
+        VirtualKeyCodeCombo* ptr;
+        size_t len;
+        size_t cap;
+        VirtualKeyCodeComboVecDestructor destructor;
+        VirtualKeyCodeComboVec& operator=(const VirtualKeyCodeComboVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        VirtualKeyCodeComboVec(const VirtualKeyCodeComboVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        VirtualKeyCodeComboVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct TessellatedSvgNodeVec {
         TessellatedSvgNode* ptr;
         size_t len;
@@ -8020,6 +8426,19 @@ namespace dll {
     };
     
     
+    enum class OptionWindowResultCallbackTag {
+       None,
+       Some,
+    };
+    
+    struct OptionWindowResultCallbackVariant_None { OptionWindowResultCallbackTag tag; };
+    struct OptionWindowResultCallbackVariant_Some { OptionWindowResultCallbackTag tag; WindowResultCallback payload; };
+    union OptionWindowResultCallback {
+        OptionWindowResultCallbackVariant_None None;
+        OptionWindowResultCallbackVariant_Some Some;
+    };
+    
+    
     enum class OptionMouseStateTag {
        None,
        Some,
@@ -8186,6 +8605,15 @@ namespace dll {
         InvalidStringError() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct TrayIcon {
+        WindowIcon icon;
+        String tooltip;
+        Menu menu;
+        TrayIcon& operator=(const TrayIcon&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TrayIcon(const TrayIcon&) = delete; /* disable copy constructor, use explicit .clone() */
+        TrayIcon() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct WindowsWindowOptions {
         bool  allow_drag_drop;
         bool  no_redirection_bitmap;
@@ -8268,6 +8696,32 @@ namespace dll {
         Monitor() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct NotificationAction {
+        String label;
+        MenuCallback callback;
+        NotificationAction& operator=(const NotificationAction&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        NotificationAction(const NotificationAction&) = delete; /* disable copy constructor, use explicit .clone() */
+        NotificationAction() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct Shortcut {
+        VirtualKeyCodeComboVec keys;
+        ShortcutScope scope;
+        String description;
+        MenuCallback callback;
+        Shortcut& operator=(const Shortcut&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        Shortcut(const Shortcut&) = delete; /* disable copy constructor, use explicit .clone() */
+        Shortcut() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct ShortcutBinding {
+        ShortcutId id;
+        Shortcut shortcut;
+        ShortcutBinding& operator=(const ShortcutBinding&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        ShortcutBinding(const ShortcutBinding&) = delete; /* disable copy constructor, use explicit .clone() */
+        ShortcutBinding() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class LayoutCallbackTag {
        Raw,
        Marshaled,
@@ -8443,6 +8897,16 @@ namespace dll {
         ScrollbarStyle() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct StyleCursorImage {
+        String image;
+        uint32_t hotspot_x;
+        uint32_t hotspot_y;
+        StyleCursorVec fallback;
+        StyleCursorImage& operator=(const StyleCursorImage&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleCursorImage(const StyleCursorImage&) = delete; /* disable copy constructor, use explicit .clone() */
+        StyleCursorImage() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class StyleFontFamilyTag {
        System,
        File,
@@ -8822,6 +9286,26 @@ namespace dll {
         FmtArg() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct ShortcutBindingVec {
+        ShortcutBinding* ptr;
+        size_t len;
+        size_t cap;
+        ShortcutBindingVecDestructor destructor;
+        ShortcutBindingVec& operator=(const ShortcutBindingVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        ShortcutBindingVec(const ShortcutBindingVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        ShortcutBindingVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct NotificationActionVec {
+        NotificationAction* ptr;
+        size_t len;
+        size_t cap;
+        NotificationActionVecDestructor destructor;
+        NotificationActionVec& operator=(const NotificationActionVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        NotificationActionVec(const NotificationActionVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        NotificationActionVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct StyleFontFamilyVec {
         StyleFontFamily* ptr;
         size_t len;
@@ -8994,6 +9478,19 @@ namespace dll {
     };
     
     
+    enum class OptionTrayIconTag {
+       None,
+       Some,
+    };
+    
+    struct OptionTrayIconVariant_None { OptionTrayIconTag tag; };
+    struct OptionTrayIconVariant_Some { OptionTrayIconTag tag; TrayIcon payload; };
+    union OptionTrayIcon {
+        OptionTrayIconVariant_None None;
+        OptionTrayIconVariant_Some Some;
+    };
+    
+    
     enum class ResultRawImageDecodeImageErrorTag {
        Ok,
        Err,
@@ -9066,11 +9563,24 @@ namespace dll {
         OptionWaylandTheme wayland_theme;
         bool  request_user_attention;
         OptionWindowIcon window_icon;
+        OptionTrayIcon tray_icon;
         LinuxWindowOptions& operator=(const LinuxWindowOptions&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         LinuxWindowOptions(const LinuxWindowOptions&) = delete; /* disable copy constructor, use explicit .clone() */
         LinuxWindowOptions() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct Notification {
+        String title;
+        String body;
+        OptionImageRef icon;
+        NotificationUrgency urgency;
+        NotificationActionVec actions;
+        OptionMenuCallback on_click;
+        Notification& operator=(const Notification&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        Notification(const Notification&) = delete; /* disable copy constructor, use explicit .clone() */
+        Notification() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct InlineLine {
         InlineWordVec words;
         LogicalRect bounds;
@@ -9102,6 +9612,106 @@ namespace dll {
         CssPath() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    enum class StyleCursorTag {
+       Alias,
+       AllScroll,
+       Cell,
+       ColResize,
+       ContextMenu,
+       Copy,
+       Crosshair,
+       Default,
+       EResize,
+       EwResize,
+       Grab,
+       Grabbing,
+       Help,
+       Move,
+       NResize,
+       NsResize,
+       NeswResize,
+       NwseResize,
+       Pointer,
+       Progress,
+       RowResize,
+       SResize,
+       SeResize,
+       Text,
+       Unset,
+       VerticalText,
+       WResize,
+       Wait,
+       ZoomIn,
+       ZoomOut,
+       Image,
+    };
+    
+    struct StyleCursorVariant_Alias { StyleCursorTag tag; };
+    struct StyleCursorVariant_AllScroll { StyleCursorTag tag; };
+    struct StyleCursorVariant_Cell { StyleCursorTag tag; };
+    struct StyleCursorVariant_ColResize { StyleCursorTag tag; };
+    struct StyleCursorVariant_ContextMenu { StyleCursorTag tag; };
+    struct StyleCursorVariant_Copy { StyleCursorTag tag; };
+    struct StyleCursorVariant_Crosshair { StyleCursorTag tag; };
+    struct StyleCursorVariant_Default { StyleCursorTag tag; };
+    struct StyleCursorVariant_EResize { StyleCursorTag tag; };
+    struct StyleCursorVariant_EwResize { StyleCursorTag tag; };
+    struct StyleCursorVariant_Grab { StyleCursorTag tag; };
+    struct StyleCursorVariant_Grabbing { StyleCursorTag tag; };
+    struct StyleCursorVariant_Help { StyleCursorTag tag; };
+    struct StyleCursorVariant_Move { StyleCursorTag tag; };
+    struct StyleCursorVariant_NResize { StyleCursorTag tag; };
+    struct StyleCursorVariant_NsResize { StyleCursorTag tag; };
+    struct StyleCursorVariant_NeswResize { StyleCursorTag tag; };
+    struct StyleCursorVariant_NwseResize { StyleCursorTag tag; };
+    struct StyleCursorVariant_Pointer { StyleCursorTag tag; };
+    struct StyleCursorVariant_Progress { StyleCursorTag tag; };
+    struct StyleCursorVariant_RowResize { StyleCursorTag tag; };
+    struct StyleCursorVariant_SResize { StyleCursorTag tag; };
+    struct StyleCursorVariant_SeResize { StyleCursorTag tag; };
+    struct StyleCursorVariant_Text { StyleCursorTag tag; };
+    struct StyleCursorVariant_Unset { StyleCursorTag tag; };
+    struct StyleCursorVariant_VerticalText { StyleCursorTag tag; };
+    struct StyleCursorVariant_WResize { StyleCursorTag tag; };
+    struct StyleCursorVariant_Wait { StyleCursorTag tag; };
+    struct StyleCursorVariant_ZoomIn { StyleCursorTag tag; };
+    struct StyleCursorVariant_ZoomOut { StyleCursorTag tag; };
+    struct StyleCursorVariant_Image { StyleCursorTag tag; StyleCursorImage payload; };
+    union StyleCursor {
+        StyleCursorVariant_Alias Alias;
+        StyleCursorVariant_AllScroll AllScroll;
+        StyleCursorVariant_Cell Cell;
+        StyleCursorVariant_ColResize ColResize;
+        StyleCursorVariant_ContextMenu ContextMenu;
+        StyleCursorVariant_Copy Copy;
+        StyleCursorVariant_Crosshair Crosshair;
+        StyleCursorVariant_Default Default;
+        StyleCursorVariant_EResize EResize;
+        StyleCursorVariant_EwResize EwResize;
+        StyleCursorVariant_Grab Grab;
+        StyleCursorVariant_Grabbing Grabbing;
+        StyleCursorVariant_Help Help;
+        StyleCursorVariant_Move Move;
+        StyleCursorVariant_NResize NResize;
+        StyleCursorVariant_NsResize NsResize;
+        StyleCursorVariant_NeswResize NeswResize;
+        StyleCursorVariant_NwseResize NwseResize;
+        StyleCursorVariant_Pointer Pointer;
+        StyleCursorVariant_Progress Progress;
+        StyleCursorVariant_RowResize RowResize;
+        StyleCursorVariant_SResize SResize;
+        StyleCursorVariant_SeResize SeResize;
+        StyleCursorVariant_Text Text;
+        StyleCursorVariant_Unset Unset;
+        StyleCursorVariant_VerticalText VerticalText;
+        StyleCursorVariant_WResize WResize;
+        StyleCursorVariant_Wait Wait;
+        StyleCursorVariant_ZoomIn ZoomIn;
+        StyleCursorVariant_ZoomOut ZoomOut;
+        StyleCursorVariant_Image Image;
+    };
+    
+    
     enum class StyleBackgroundContentVecValueTag {
        Auto,
        None,
@@ -9124,6 +9734,28 @@ namespace dll {
     };
     
     
+    enum class StyleCursorValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleCursorValueVariant_Auto { StyleCursorValueTag tag; };
+    struct StyleCursorValueVariant_None { StyleCursorValueTag tag; };
+    struct StyleCursorValueVariant_Inherit { StyleCursorValueTag tag; };
+    struct StyleCursorValueVariant_Initial { StyleCursorValueTag tag; };
+    struct StyleCursorValueVariant_Exact { StyleCursorValueTag tag; StyleCursor payload; };
+    union StyleCursorValue {
+        StyleCursorValueVariant_Auto Auto;
+        StyleCursorValueVariant_None None;
+        StyleCursorValueVariant_Inherit Inherit;
+        StyleCursorValueVariant_Initial Initial;
+        StyleCursorValueVariant_Exact Exact;
+    };
+    
+    
     enum class StyleFontFamilyVecValueTag {
        Auto,
        None,
@@ -9212,6 +9844,9 @@ namespace dll {
        BoxShadowTop,
        BoxShadowBottom,
        ScrollbarStyle,
+       ScrollBehavior,
+       ScrollSnapType,
+       ScrollSnapAlign,
        Opacity,
        Transform,
        TransformOrigin,
@@ -9288,6 +9923,9 @@ namespace dll {
     struct CssPropertyVariant_BoxShadowTop { CssPropertyTag tag; StyleBoxShadowValue payload; };
     struct CssPropertyVariant_BoxShadowBottom { CssPropertyTag tag; StyleBoxShadowValue payload; };
     struct CssPropertyVariant_ScrollbarStyle { CssPropertyTag tag; ScrollbarStyleValue payload; };
+    struct CssPropertyVariant_ScrollBehavior { CssPropertyTag tag; StyleScrollBehaviorValue payload; };
+    struct CssPropertyVariant_ScrollSnapType { CssPropertyTag tag; StyleScrollSnapTypeValue payload; };
+    struct CssPropertyVariant_ScrollSnapAlign { CssPropertyTag tag; StyleScrollSnapAlignValue payload; };
     struct CssPropertyVariant_Opacity { CssPropertyTag tag; StyleOpacityValue payload; };
     struct CssPropertyVariant_Transform { CssPropertyTag tag; StyleTransformVecValue payload; };
     struct CssPropertyVariant_TransformOrigin { CssPropertyTag tag; StyleTransformOriginValue payload; };
@@ -9363,6 +10001,9 @@ namespace dll {
         CssPropertyVariant_BoxShadowTop BoxShadowTop;
         CssPropertyVariant_BoxShadowBottom BoxShadowBottom;
         CssPropertyVariant_ScrollbarStyle ScrollbarStyle;
+        CssPropertyVariant_ScrollBehavior ScrollBehavior;
+        CssPropertyVariant_ScrollSnapType ScrollSnapType;
+        CssPropertyVariant_ScrollSnapAlign ScrollSnapAlign;
         CssPropertyVariant_Opacity Opacity;
         CssPropertyVariant_Transform Transform;
         CssPropertyVariant_TransformOrigin TransformOrigin;
@@ -9647,6 +10288,7 @@ namespace dll {
         PlatformSpecificOptions platform_specific_options;
         RendererOptions renderer_options;
         ColorU background_color;
+        OptionCustomCursor custom_cursor;
         LayoutCallback layout_callback;
         OptionCallback close_callback;
         WindowState& operator=(const WindowState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
@@ -9670,9 +10312,14 @@ namespace dll {
         void* restrict threads_removed;
         RawWindowHandle* current_window_handle;
         void* restrict new_windows;
+        void* restrict notifications;
+        void* restrict shortcuts_changed;
+        void* restrict windows_closed;
+        void* restrict window_result;
         SystemCallbacks* system_callbacks;
         bool * restrict stop_propagation;
         void* restrict focus_target;
+        void* restrict drag_data;
         void* restrict words_changed_in_callbacks;
         void* restrict images_changed_in_callbacks;
         void* restrict image_masks_changed_in_callbacks;
@@ -9885,6 +10532,11 @@ namespace dll {
         OptionWindowTheme theme;
         OptionCallback create_callback;
         bool  hot_reload;
+        WindowId id;
+        OptionWindowId parent;
+        bool  modal;
+        OptionWindowResultCallback result_callback;
+        OptionString persistence_key;
         WindowCreateOptions& operator=(const WindowCreateOptions&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         WindowCreateOptions(const WindowCreateOptions&) = delete; /* disable copy constructor, use explicit .clone() */
         WindowCreateOptions() = delete; /* disable default constructor, use C++20 designated initializer instead */
@@ -10302,6 +10954,9 @@ namespace dll {
         void App_addWindow(App* restrict app, AzWindowCreateOptions  window);
         void App_addImage(App* restrict app, AzString  id, AzImageRef  image);
         MonitorVec App_getMonitors(const App* app);
+        ResultShortcutIdShortcutError App_registerShortcut(App* restrict app, AzShortcut  shortcut);
+        bool  App_unregisterShortcut(App* restrict app, AzShortcutId  shortcut_id);
+        ShortcutBindingVec App_getShortcuts(const App* app);
         void App_run(const App* app, AzWindowCreateOptions  window);
         void App_delete(App* restrict instance);
         App App_deepCopy(App* const instance);
@@ -10309,12 +10964,16 @@ namespace dll {
         SystemCallbacks SystemCallbacks_libraryInternal();
         WindowCreateOptions WindowCreateOptions_new(AzLayoutCallbackType  layout_callback);
         void WindowCreateOptions_delete(WindowCreateOptions* restrict instance);
+        WindowResultCallback WindowResultCallback_new(AzRefAny  data, AzWriteBackCallbackType  callback);
+        void WindowResultCallback_delete(WindowResultCallback* restrict instance);
         LogicalPosition LogicalPosition_new(float x, float y);
         LogicalPosition LogicalPosition_zero();
         PhysicalSizeU32 LogicalSize_toPhysical(const LogicalSize* logicalsize, float hidpi_factor);
         void SmallWindowIconBytes_delete(SmallWindowIconBytes* restrict instance);
         void LargeWindowIconBytes_delete(LargeWindowIconBytes* restrict instance);
         void WindowIcon_delete(WindowIcon* restrict instance);
+        TrayIcon TrayIcon_new(AzWindowIcon  icon, AzString  tooltip, AzMenu  menu);
+        void TrayIcon_delete(TrayIcon* restrict instance);
         void TaskBarIcon_delete(TaskBarIcon* restrict instance);
         float WindowSize_getHidpiFactor(const WindowSize* windowsize);
         bool  KeyboardState_shiftDown(const KeyboardState* keyboardstate);
@@ -10329,10 +10988,24 @@ namespace dll {
         void WaylandTheme_delete(WaylandTheme* restrict instance);
         void StringPair_delete(StringPair* restrict instance);
         void LinuxWindowOptions_delete(LinuxWindowOptions* restrict instance);
+        void TouchState_delete(TouchState* restrict instance);
         void Monitor_delete(Monitor* restrict instance);
         WindowState WindowState_new(AzLayoutCallbackType  layout_callback);
         WindowState WindowState_default();
         void WindowState_delete(WindowState* restrict instance);
+        CustomCursor CustomCursor_new(AzImageRef  image, uint32_t hotspot_x, uint32_t hotspot_y);
+        void CustomCursor_delete(CustomCursor* restrict instance);
+        Notification Notification_new(AzString  title, AzString  body);
+        Notification Notification_withIcon(const Notification notification, AzImageRef  icon);
+        Notification Notification_withUrgency(const Notification notification, AzNotificationUrgency  urgency);
+        Notification Notification_withAction(const Notification notification, AzString  label, AzRefAny  data, AzCallbackType  callback);
+        Notification Notification_withClickCallback(const Notification notification, AzRefAny  data, AzCallbackType  callback);
+        void Notification_delete(Notification* restrict instance);
+        void NotificationAction_delete(NotificationAction* restrict instance);
+        Shortcut Shortcut_new(AzVirtualKeyCodeComboVec  keys, AzShortcutScope  scope, AzString  description, AzRefAny  data, AzCallbackType  callback);
+        String Shortcut_getKeyString(const Shortcut* shortcut);
+        void Shortcut_delete(Shortcut* restrict instance);
+        void ShortcutBinding_delete(ShortcutBinding* restrict instance);
         void LayoutCallback_delete(LayoutCallback* restrict instance);
         void MarshaledLayoutCallback_delete(MarshaledLayoutCallback* restrict instance);
         DomNodeId CallbackInfo_getHitNode(const CallbackInfo* callbackinfo);
@@ -10341,6 +11014,8 @@ namespace dll {
         OptionLogicalPosition CallbackInfo_getCursorRelativeToNode(const CallbackInfo* callbackinfo);
         WindowState CallbackInfo_getCurrentWindowState(const CallbackInfo* callbackinfo);
         KeyboardState CallbackInfo_getCurrentKeyboardState(const CallbackInfo* callbackinfo);
+        TouchState CallbackInfo_getTouchState(const CallbackInfo* callbackinfo);
+        OptionGestureState CallbackInfo_getGesture(const CallbackInfo* callbackinfo);
         MouseState CallbackInfo_getCurrentMouseState(const CallbackInfo* callbackinfo);
         OptionWindowState CallbackInfo_getPreviousWindowState(const CallbackInfo* callbackinfo);
         OptionKeyboardState CallbackInfo_getPreviousKeyboardState(const CallbackInfo* callbackinfo);
@@ -10348,6 +11023,7 @@ namespace dll {
         RawWindowHandle CallbackInfo_getCurrentWindowHandle(const CallbackInfo* callbackinfo);
         OptionGl CallbackInfo_getGlContext(const CallbackInfo* callbackinfo);
         OptionLogicalPosition CallbackInfo_getScrollPosition(const CallbackInfo* callbackinfo, AzDomNodeId  node_id);
+        OptionScrollPosition CallbackInfo_getScrollFrame(const CallbackInfo* callbackinfo, AzDomNodeId  node_id);
         OptionRefAny CallbackInfo_getDataset(CallbackInfo* restrict callbackinfo, AzDomNodeId  node_id);
         OptionDomNodeId CallbackInfo_getNodeIdOfRootDataset(CallbackInfo* restrict callbackinfo, AzRefAny  dataset);
         OptionString CallbackInfo_getStringContents(const CallbackInfo* callbackinfo, AzDomNodeId  node_id);
@@ -10365,9 +11041,13 @@ namespace dll {
         OptionLogicalSize CallbackInfo_getNodeSize(CallbackInfo* restrict callbackinfo, AzDomNodeId  node_id);
         OptionCssProperty CallbackInfo_getComputedCssProperty(CallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzCssPropertyType  property_type);
         void CallbackInfo_setWindowState(CallbackInfo* restrict callbackinfo, AzWindowState  new_state);
+        void CallbackInfo_setCustomCursor(CallbackInfo* restrict callbackinfo, AzImageRef  image, uint32_t hotspot_x, uint32_t hotspot_y);
+        void CallbackInfo_resetCustomCursor(CallbackInfo* restrict callbackinfo);
         void CallbackInfo_setFocus(CallbackInfo* restrict callbackinfo, AzFocusTarget  target);
         void CallbackInfo_setCssProperty(CallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzCssProperty  new_property);
         void CallbackInfo_setScrollPosition(CallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzLogicalPosition  scroll_position);
+        void CallbackInfo_scrollTo(CallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzLogicalPosition  scroll_position);
+        void CallbackInfo_scrollIntoView(CallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzScrollIntoViewAlign  align, bool  smooth);
         void CallbackInfo_setStringContents(CallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzString  string);
         void CallbackInfo_addImage(CallbackInfo* restrict callbackinfo, AzString  id, AzImageRef  image);
         bool  CallbackInfo_hasImage(const CallbackInfo* callbackinfo, AzString  id);
@@ -10376,7 +11056,12 @@ namespace dll {
         void CallbackInfo_deleteImage(CallbackInfo* restrict callbackinfo, AzString  id);
         void CallbackInfo_updateImageMask(CallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzImageMask  new_mask);
         void CallbackInfo_stopPropagation(CallbackInfo* restrict callbackinfo);
-        void CallbackInfo_createWindow(CallbackInfo* restrict callbackinfo, AzWindowCreateOptions  new_window);
+        WindowId CallbackInfo_createWindow(CallbackInfo* restrict callbackinfo, AzWindowCreateOptions  new_window);
+        void CallbackInfo_closeWindow(CallbackInfo* restrict callbackinfo, AzWindowId  window_id);
+        void CallbackInfo_setWindowResult(CallbackInfo* restrict callbackinfo, AzRefAny  result);
+        void CallbackInfo_showNotification(CallbackInfo* restrict callbackinfo, AzNotification  notification);
+        ShortcutId CallbackInfo_registerShortcut(CallbackInfo* restrict callbackinfo, AzShortcut  shortcut);
+        void CallbackInfo_unregisterShortcut(CallbackInfo* restrict callbackinfo, AzShortcutId  shortcut_id);
         TimerId CallbackInfo_startTimer(CallbackInfo* restrict callbackinfo, AzTimer  timer);
         OptionTimerId CallbackInfo_startAnimation(CallbackInfo* restrict callbackinfo, AzDomNodeId  node, AzAnimation  animation);
         bool  CallbackInfo_stopTimer(CallbackInfo* restrict callbackinfo, AzTimerId  timer_id);
@@ -10568,12 +11253,15 @@ namespace dll {
         void StyleBackgroundContent_delete(StyleBackgroundContent* restrict instance);
         void ScrollbarInfo_delete(ScrollbarInfo* restrict instance);
         void ScrollbarStyle_delete(ScrollbarStyle* restrict instance);
+        void StyleCursor_delete(StyleCursor* restrict instance);
+        void StyleCursorImage_delete(StyleCursorImage* restrict instance);
         void StyleFontFamily_delete(StyleFontFamily* restrict instance);
         void ScrollbarStyleValue_delete(ScrollbarStyleValue* restrict instance);
         void StyleBackgroundContentVecValue_delete(StyleBackgroundContentVecValue* restrict instance);
         void StyleBackgroundPositionVecValue_delete(StyleBackgroundPositionVecValue* restrict instance);
         void StyleBackgroundRepeatVecValue_delete(StyleBackgroundRepeatVecValue* restrict instance);
         void StyleBackgroundSizeVecValue_delete(StyleBackgroundSizeVecValue* restrict instance);
+        void StyleCursorValue_delete(StyleCursorValue* restrict instance);
         void StyleFontFamilyVecValue_delete(StyleFontFamilyVecValue* restrict instance);
         void StyleTransformVecValue_delete(StyleTransformVecValue* restrict instance);
         void StyleFilterVecValue_delete(StyleFilterVecValue* restrict instance);
@@ -11234,11 +11922,16 @@ namespace dll {
         void OutputConnectionVec_delete(OutputConnectionVec* restrict instance);
         void InputNodeAndIndexVec_delete(InputNodeAndIndexVec* restrict instance);
         void AccessibilityStateVec_delete(AccessibilityStateVec* restrict instance);
+        void VirtualKeyCodeComboVec_delete(VirtualKeyCodeComboVec* restrict instance);
+        void ShortcutBindingVec_delete(ShortcutBindingVec* restrict instance);
+        void TouchPointVec_delete(TouchPointVec* restrict instance);
+        void NotificationActionVec_delete(NotificationActionVec* restrict instance);
         void MenuItemVec_delete(MenuItemVec* restrict instance);
         TessellatedSvgNodeVecRef TessellatedSvgNodeVec_asRefVec(const TessellatedSvgNodeVec* tessellatedsvgnodevec);
         void TessellatedSvgNodeVec_delete(TessellatedSvgNodeVec* restrict instance);
         TessellatedColoredSvgNodeVecRef TessellatedColoredSvgNodeVec_asRefVec(const TessellatedColoredSvgNodeVec* tessellatedcoloredsvgnodevec);
         void TessellatedColoredSvgNodeVec_delete(TessellatedColoredSvgNodeVec* restrict instance);
+        void StyleCursorVec_delete(StyleCursorVec* restrict instance);
         void StyleFontFamilyVec_delete(StyleFontFamilyVec* restrict instance);
         void XmlNodeVec_delete(XmlNodeVec* restrict instance);
         void FmtArgVec_delete(FmtArgVec* restrict instance);
@@ -11321,6 +12014,8 @@ namespace dll {
         void OptionVirtualKeyCodeCombo_delete(OptionVirtualKeyCodeCombo* restrict instance);
         void OptionCssProperty_delete(OptionCssProperty* restrict instance);
         void OptionImageRef_delete(OptionImageRef* restrict instance);
+        void OptionWindowResultCallback_delete(OptionWindowResultCallback* restrict instance);
+        void OptionCustomCursor_delete(OptionCustomCursor* restrict instance);
         void OptionFontRef_delete(OptionFontRef* restrict instance);
         void OptionSystemClipboard_delete(OptionSystemClipboard* restrict instance);
         void OptionFileTypeList_delete(OptionFileTypeList* restrict instance);
@@ -11336,6 +12031,7 @@ namespace dll {
         void OptionRawImage_delete(OptionRawImage* restrict instance);
         void OptionWaylandTheme_delete(OptionWaylandTheme* restrict instance);
         void OptionTaskBarIcon_delete(OptionTaskBarIcon* restrict instance);
+        void OptionTrayIcon_delete(OptionTrayIcon* restrict instance);
         void OptionWindowIcon_delete(OptionWindowIcon* restrict instance);
         void OptionString_delete(OptionString* restrict instance);
         void OptionDom_delete(OptionDom* restrict instance);
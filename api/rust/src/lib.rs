@@ -718,6 +718,16 @@ mod dll {
             Default,
         }
 
+        /// Unique ID of a window, used to close a window or to open a child window
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzWindowId {
+            pub id: usize,
+        }
+
         /// Whether the renderer has VSync enabled
         #[repr(C)]
         #[derive(Debug)]
@@ -1276,14 +1286,38 @@ mod dll {
             LightMode,
         }
 
-        /// Current state of touch devices / touch inputs
+        /// Kind of device that touches the screen / graphics tablet
         #[repr(C)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
         #[derive(Copy)]
-        pub struct AzTouchState {
-            pub unused: u8,
+        pub enum AzTouchPointKind {
+            Finger,
+            Pen,
+            Eraser,
+        }
+
+        /// Urgency of a desktop notification, critical notifications usually don't time out
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzNotificationUrgency {
+            Low,
+            Normal,
+            Critical,
+        }
+
+        /// ID of a registered keyboard shortcut, used to unregister the shortcut again
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzShortcutId {
+            pub id: usize,
         }
 
         /// C-ABI stable wrapper over a `MarshaledLayoutCallbackInner`
@@ -1376,6 +1410,19 @@ mod dll {
             pub static_y_offset: f32,
         }
 
+        /// How a node is aligned in its scroll containers by `CallbackInfo::scroll_into_view`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzScrollIntoViewAlign {
+            Start,
+            Center,
+            End,
+            Nearest,
+        }
+
         /// How should an animation repeat (loop, ping-pong, etc.)
         #[repr(C)]
         #[derive(Debug)]
@@ -1493,6 +1540,23 @@ mod dll {
             HoveredFileCancelled,
             FocusReceived,
             FocusLost,
+            DragStart,
+            DragEnter,
+            DragOver,
+            DragLeave,
+            Drop,
+            TextCompositionStart,
+            TextCompositionUpdate,
+            TextCompositionEnd,
+            TouchStart,
+            TouchMove,
+            TouchEnd,
+            TouchCancel,
+            Pinch,
+            Rotate,
+            TwoFingerPan,
+            LongPress,
+            Scrolled,
         }
 
         /// Re-export of rust-allocated (stack based) `HoverEventFilter` struct
@@ -1526,6 +1590,18 @@ mod dll {
             TouchMove,
             TouchEnd,
             TouchCancel,
+            Pinch,
+            Rotate,
+            TwoFingerPan,
+            LongPress,
+            DragStart,
+            DragEnter,
+            DragOver,
+            DragLeave,
+            Drop,
+            TextCompositionStart,
+            TextCompositionUpdate,
+            TextCompositionEnd,
         }
 
         /// Re-export of rust-allocated (stack based) `FocusEventFilter` struct
@@ -1554,6 +1630,9 @@ mod dll {
             VirtualKeyUp,
             FocusReceived,
             FocusLost,
+            TextCompositionStart,
+            TextCompositionUpdate,
+            TextCompositionEnd,
         }
 
         /// Re-export of rust-allocated (stack based) `WindowEventFilter` struct
@@ -1589,10 +1668,26 @@ mod dll {
             TouchMove,
             TouchEnd,
             TouchCancel,
+            Pinch,
+            Rotate,
+            TwoFingerPan,
+            LongPress,
             FocusReceived,
             FocusLost,
             CloseRequested,
             ThemeChanged,
+            WindowFocusReceived,
+            WindowFocusLost,
+            ClipboardChanged,
+            DragStart,
+            DragEnter,
+            DragOver,
+            DragLeave,
+            Drop,
+            DragEnd,
+            TextCompositionStart,
+            TextCompositionUpdate,
+            TextCompositionEnd,
         }
 
         /// Re-export of rust-allocated (stack based) `ComponentEventFilter` struct
@@ -1607,6 +1702,7 @@ mod dll {
             NodeResized,
             DefaultAction,
             Selected,
+            Scrolled,
         }
 
         /// Re-export of rust-allocated (stack based) `ApplicationEventFilter` struct
@@ -1868,6 +1964,9 @@ mod dll {
             BoxShadowTop,
             BoxShadowBottom,
             ScrollbarStyle,
+            ScrollBehavior,
+            ScrollSnapType,
+            ScrollSnapAlign,
             Opacity,
             Transform,
             TransformOrigin,
@@ -2186,43 +2285,52 @@ mod dll {
             Outset,
         }
 
-        /// Re-export of rust-allocated (stack based) `StyleCursor` struct
+        /// Represents a `scroll-behavior` attribute: whether programmatic scrolling (such as `CallbackInfo::scroll_to`) is animated
         #[repr(C)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
         #[derive(Copy)]
-        pub enum AzStyleCursor {
-            Alias,
-            AllScroll,
-            Cell,
-            ColResize,
-            ContextMenu,
-            Copy,
-            Crosshair,
-            Default,
-            EResize,
-            EwResize,
-            Grab,
-            Grabbing,
-            Help,
-            Move,
-            NResize,
-            NsResize,
-            NeswResize,
-            NwseResize,
-            Pointer,
-            Progress,
-            RowResize,
-            SResize,
-            SeResize,
-            Text,
-            Unset,
-            VerticalText,
-            WResize,
-            Wait,
-            ZoomIn,
-            ZoomOut,
+        pub enum AzStyleScrollBehavior {
+            Auto,
+            Smooth,
+        }
+
+        /// Axis on which a scroll container snaps to its children
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzScrollSnapAxis {
+            None,
+            X,
+            Y,
+            Both,
+        }
+
+        /// `mandatory` always snaps to the nearest snap position, `proximity` only snaps if the scroll position comes close to one
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzScrollSnapStrictness {
+            Proximity,
+            Mandatory,
+        }
+
+        /// Represents a `scroll-snap-align` attribute: which edge of the node is aligned with the scroll container when snapping
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzStyleScrollSnapAlign {
+            None,
+            Start,
+            Center,
+            End,
         }
 
         /// Re-export of rust-allocated (stack based) `StyleBackfaceVisibility` struct
@@ -3485,6 +3593,19 @@ mod dll {
             pub cb: AzThreadSenderDestructorFnType,
         }
 
+        /// Re-export of rust-allocated (stack based) `StyleCursorVecDestructor` struct
+        #[repr(C, u8)]
+        #[derive(Clone)]
+        #[derive(Copy)]
+        pub enum AzStyleCursorVecDestructor {
+            DefaultRust,
+            NoDestructor,
+            External(AzStyleCursorVecDestructorType),
+        }
+
+        /// `AzStyleCursorVecDestructorType` struct
+        pub type AzStyleCursorVecDestructorType = extern "C" fn(&mut AzStyleCursorVec);
+
         /// Re-export of rust-allocated (stack based) `StyleFontFamilyVecDestructor` struct
         #[repr(C, u8)]
         #[derive(Clone)]
@@ -3667,6 +3788,58 @@ mod dll {
         /// `AzAccessibilityStateVecDestructorType` struct
         pub type AzAccessibilityStateVecDestructorType = extern "C" fn(&mut AzAccessibilityStateVec);
 
+        /// Re-export of rust-allocated (stack based) `VirtualKeyCodeComboVecDestructor` struct
+        #[repr(C, u8)]
+        #[derive(Clone)]
+        #[derive(Copy)]
+        pub enum AzVirtualKeyCodeComboVecDestructor {
+            DefaultRust,
+            NoDestructor,
+            External(AzVirtualKeyCodeComboVecDestructorType),
+        }
+
+        /// `AzVirtualKeyCodeComboVecDestructorType` struct
+        pub type AzVirtualKeyCodeComboVecDestructorType = extern "C" fn(&mut AzVirtualKeyCodeComboVec);
+
+        /// Re-export of rust-allocated (stack based) `ShortcutBindingVecDestructor` struct
+        #[repr(C, u8)]
+        #[derive(Clone)]
+        #[derive(Copy)]
+        pub enum AzShortcutBindingVecDestructor {
+            DefaultRust,
+            NoDestructor,
+            External(AzShortcutBindingVecDestructorType),
+        }
+
+        /// `AzShortcutBindingVecDestructorType` struct
+        pub type AzShortcutBindingVecDestructorType = extern "C" fn(&mut AzShortcutBindingVec);
+
+        /// Re-export of rust-allocated (stack based) `TouchPointVecDestructor` struct
+        #[repr(C, u8)]
+        #[derive(Clone)]
+        #[derive(Copy)]
+        pub enum AzTouchPointVecDestructor {
+            DefaultRust,
+            NoDestructor,
+            External(AzTouchPointVecDestructorType),
+        }
+
+        /// `AzTouchPointVecDestructorType` struct
+        pub type AzTouchPointVecDestructorType = extern "C" fn(&mut AzTouchPointVec);
+
+        /// Re-export of rust-allocated (stack based) `NotificationActionVecDestructor` struct
+        #[repr(C, u8)]
+        #[derive(Clone)]
+        #[derive(Copy)]
+        pub enum AzNotificationActionVecDestructor {
+            DefaultRust,
+            NoDestructor,
+            External(AzNotificationActionVecDestructorType),
+        }
+
+        /// `AzNotificationActionVecDestructorType` struct
+        pub type AzNotificationActionVecDestructorType = extern "C" fn(&mut AzNotificationActionVec);
+
         /// Re-export of rust-allocated (stack based) `MenuItemVecDestructor` struct
         #[repr(C, u8)]
         #[derive(Clone)]
@@ -4559,7 +4732,10 @@ mod dll {
             pub has_focus: bool,
             pub has_extended_window_frame: bool,
             pub has_blur_behind_window: bool,
+            pub is_click_through: bool,
             pub smooth_scroll_enabled: bool,
+            pub overscroll_bounce_enabled: bool,
+            pub scrollbar_auto_hide_enabled: bool,
             pub autotab_enabled: bool,
         }
 
@@ -4600,6 +4776,37 @@ mod dll {
             Initialized(AzLogicalPosition),
         }
 
+        /// Finger or pen that touches the window
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzTouchPoint {
+            pub id: u64,
+            pub kind: AzTouchPointKind,
+            pub position: AzLogicalPosition,
+            pub pressure: f32,
+            pub tilt_x: f32,
+            pub tilt_y: f32,
+        }
+
+        /// Two-finger gesture, all values are relative to the positions of the fingers at the start of the gesture
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzGestureState {
+            pub center: AzLogicalPosition,
+            pub scale: f32,
+            pub rotation: f32,
+            pub translation: AzLogicalPosition,
+            pub is_pinch: bool,
+            pub is_rotate: bool,
+            pub is_pan: bool,
+        }
+
         /// Describes a rendering configuration for a monitor
         #[repr(C)]
         #[derive(Debug)]
@@ -4613,6 +4820,41 @@ mod dll {
             pub refresh_rate: u16,
         }
 
+        /// Image-based mouse cursor, created either from a CSS `cursor: url(...)` property or set at runtime via `CallbackInfo::set_custom_cursor()`. The hotspot is the "click point" of the cursor in pixels, relative to the top left corner of the image.
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzCustomCursor {
+            pub image: AzImageRef,
+            pub hotspot_x: u32,
+            pub hotspot_y: u32,
+        }
+
+        /// Where a keyboard shortcut is active
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzShortcutScope {
+            Window(AzWindowId),
+            App,
+            Global,
+        }
+
+        /// Error when registering a keyboard shortcut
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzShortcutError {
+            NoKeys,
+            GlobalSequence,
+            Conflict(AzShortcutId),
+        }
+
         /// Combination of node ID + DOM ID, both together can identify a node
         #[repr(C)]
         #[derive(Debug)]
@@ -4636,6 +4878,17 @@ mod dll {
             Relative(AzPositionInfoInner),
         }
 
+        /// Information about a scroll frame, given to the user by the framework
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzScrollPosition {
+            pub parent_rect: AzLogicalRect,
+            pub children_rect: AzLogicalRect,
+            pub scroll_offset: AzLogicalPosition,
+        }
+
         /// Re-export of rust-allocated (stack based) `HidpiAdjustedBounds` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -5348,6 +5601,17 @@ mod dll {
             pub inner: AzPixelValue,
         }
 
+        /// Represents a `scroll-snap-type` attribute, for example `y mandatory`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzStyleScrollSnapType {
+            pub axis: AzScrollSnapAxis,
+            pub strictness: AzScrollSnapStrictness,
+        }
+
         /// Re-export of rust-allocated (stack based) `StyleFontSize` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -5984,6 +6248,48 @@ mod dll {
             Exact(AzLayoutOverflow),
         }
 
+        /// Re-export of rust-allocated (stack based) `StyleScrollBehaviorValue` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzStyleScrollBehaviorValue {
+            Auto,
+            None,
+            Inherit,
+            Initial,
+            Exact(AzStyleScrollBehavior),
+        }
+
+        /// Re-export of rust-allocated (stack based) `StyleScrollSnapTypeValue` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzStyleScrollSnapTypeValue {
+            Auto,
+            None,
+            Inherit,
+            Initial,
+            Exact(AzStyleScrollSnapType),
+        }
+
+        /// Re-export of rust-allocated (stack based) `StyleScrollSnapAlignValue` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzStyleScrollSnapAlignValue {
+            Auto,
+            None,
+            Inherit,
+            Initial,
+            Exact(AzStyleScrollSnapAlign),
+        }
+
         /// Re-export of rust-allocated (stack based) `StyleBorderBottomColorValue` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -6208,20 +6514,6 @@ mod dll {
             Exact(AzLayoutBorderTopWidth),
         }
 
-        /// Re-export of rust-allocated (stack based) `StyleCursorValue` struct
-        #[repr(C, u8)]
-        #[derive(Debug)]
-        #[derive(Clone)]
-        #[derive(PartialEq, PartialOrd)]
-        #[derive(Copy)]
-        pub enum AzStyleCursorValue {
-            Auto,
-            None,
-            Inherit,
-            Initial,
-            Exact(AzStyleCursor),
-        }
-
         /// Re-export of rust-allocated (stack based) `StyleFontSizeValue` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -6918,6 +7210,15 @@ mod dll {
             pub destructor: AzAccessibilityStateVecDestructor,
         }
 
+        /// Wrapper over a Rust-allocated `Vec<TouchPoint>`
+        #[repr(C)]
+        pub struct AzTouchPointVec {
+            pub(crate) ptr: *const AzTouchPoint,
+            pub len: usize,
+            pub cap: usize,
+            pub destructor: AzTouchPointVecDestructor,
+        }
+
         /// Wrapper over a Rust-allocated `Vec<MenuItem>`
         #[repr(C)]
         pub struct AzMenuItemVec {
@@ -6927,6 +7228,15 @@ mod dll {
             pub destructor: AzMenuItemVecDestructor,
         }
 
+        /// Wrapper over a Rust-allocated `Vec<StyleCursor>`
+        #[repr(C)]
+        pub struct AzStyleCursorVec {
+            pub(crate) ptr: *const AzStyleCursor,
+            pub len: usize,
+            pub cap: usize,
+            pub destructor: AzStyleCursorVecDestructor,
+        }
+
         /// Wrapper over a Rust-allocated `Vec<XmlNode>`
         #[repr(C)]
         pub struct AzXmlNodeVec {
@@ -7462,6 +7772,27 @@ mod dll {
             Some(AzImageRef),
         }
 
+        /// Re-export of rust-allocated (stack based) `OptionWindowId` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzOptionWindowId {
+            None,
+            Some(AzWindowId),
+        }
+
+        /// Re-export of rust-allocated (stack based) `OptionCustomCursor` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzOptionCustomCursor {
+            None,
+            Some(AzCustomCursor),
+        }
+
         /// Re-export of rust-allocated (stack based) `OptionFontRef` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -7567,6 +7898,16 @@ mod dll {
             Some(AzRefAny),
         }
 
+        /// Re-export of rust-allocated (stack based) `OptionScrollPosition` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzOptionScrollPosition {
+            None,
+            Some(AzScrollPosition),
+        }
+
         /// Re-export of rust-allocated (stack based) `OptionLayoutPoint` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -7644,6 +7985,17 @@ mod dll {
             Some(AzSvgDashPattern),
         }
 
+        /// Re-export of rust-allocated (stack based) `OptionGestureState` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzOptionGestureState {
+            None,
+            Some(AzGestureState),
+        }
+
         /// Re-export of rust-allocated (stack based) `OptionLogicalPosition` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -7774,6 +8126,17 @@ mod dll {
             Err(AzEncodeImageError),
         }
 
+        /// Re-export of rust-allocated (stack based) `ResultShortcutIdShortcutError` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzResultShortcutIdShortcutError {
+            Ok(AzShortcutId),
+            Err(AzShortcutError),
+        }
+
         /// Re-export of rust-allocated (stack based) `NonXmlCharError` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -7841,9 +8204,20 @@ mod dll {
             pub enable_visual_panic_hook: bool,
             pub enable_logging_on_panic: bool,
             pub enable_tab_navigation: bool,
+            pub enable_inspector: bool,
             pub system_callbacks: AzSystemCallbacks,
         }
 
+        /// Callback that is invoked on the parent window with the result of a child window after the child window has been closed
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzWindowResultCallback {
+            pub data: AzRefAny,
+            pub callback: AzWriteBackCallback,
+        }
+
         /// Small (16x16x4) window icon, usually shown in the window titlebar
         #[repr(C)]
         #[derive(Debug)]
@@ -7926,6 +8300,19 @@ mod dll {
             pub scroll_y: AzOptionF32,
         }
 
+        /// Fingers and pens touching the window and the recognized gestures
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzTouchState {
+            pub touch_points: AzTouchPointVec,
+            pub ended_touch_points: AzTouchPointVec,
+            pub cancelled_touch_points: AzTouchPointVec,
+            pub gesture: AzOptionGestureState,
+            pub long_press: AzOptionLogicalPosition,
+        }
+
         /// C-ABI stable wrapper over a `MarshaledLayoutCallback`
         #[repr(C)]
         #[derive(Debug)]
@@ -8509,6 +8896,15 @@ mod dll {
             pub destructor: AzOutputConnectionVecDestructor,
         }
 
+        /// Wrapper over a Rust-allocated `Vec<VirtualKeyCodeCombo>`
+        #[repr(C)]
+        pub struct AzVirtualKeyCodeComboVec {
+            pub(crate) ptr: *const AzVirtualKeyCodeCombo,
+            pub len: usize,
+            pub cap: usize,
+            pub destructor: AzVirtualKeyCodeComboVecDestructor,
+        }
+
         /// Wrapper over a Rust-allocated `Vec<TessellatedSvgNode>`
         #[repr(C)]
         pub struct AzTessellatedSvgNodeVec {
@@ -8602,6 +8998,16 @@ mod dll {
             Some(AzVirtualKeyCodeCombo),
         }
 
+        /// Re-export of rust-allocated (stack based) `OptionWindowResultCallback` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzOptionWindowResultCallback {
+            None,
+            Some(AzWindowResultCallback),
+        }
+
         /// Re-export of rust-allocated (stack based) `OptionMouseState` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -8754,6 +9160,17 @@ mod dll {
             pub pos: AzSvgParseErrorPosition,
         }
 
+        /// Icon in the system tray / notification area of the desktop, with a context menu
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzTrayIcon {
+            pub icon: AzWindowIcon,
+            pub tooltip: AzString,
+            pub menu: AzMenu,
+        }
+
         /// Window configuration specific to Win32
         #[repr(C)]
         #[derive(Debug)]
@@ -8845,6 +9262,38 @@ mod dll {
             pub is_primary_monitor: bool,
         }
 
+        /// Button of a desktop notification
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzNotificationAction {
+            pub label: AzString,
+            pub callback: AzMenuCallback,
+        }
+
+        /// Keyboard shortcut: a sequence of key combinations (i.e. "Ctrl + K, Ctrl + S") that invokes a callback, registered with `App::register_shortcut` or `CallbackInfo::register_shortcut`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzShortcut {
+            pub keys: AzVirtualKeyCodeComboVec,
+            pub scope: AzShortcutScope,
+            pub description: AzString,
+            pub callback: AzMenuCallback,
+        }
+
+        /// Registered keyboard shortcut, see `App::get_shortcuts`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzShortcutBinding {
+            pub id: AzShortcutId,
+            pub shortcut: AzShortcut,
+        }
+
         /// Re-export of rust-allocated (stack based) `LayoutCallback` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -8984,6 +9433,18 @@ mod dll {
             pub vertical: AzScrollbarInfo,
         }
 
+        /// Custom image cursor, parsed from `cursor: url("image-id") x y, fallback`. The image ID refers to an image in the `ImageCache`, the hotspot is given in pixels relative to the top left corner of the image.
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzStyleCursorImage {
+            pub image: AzString,
+            pub hotspot_x: u32,
+            pub hotspot_y: u32,
+            pub fallback: AzStyleCursorVec,
+        }
+
         /// Re-export of rust-allocated (stack based) `StyleFontFamily` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -9336,6 +9797,24 @@ mod dll {
             pub value: AzFmtValue,
         }
 
+        /// Wrapper over a Rust-allocated `Vec<ShortcutBinding>`
+        #[repr(C)]
+        pub struct AzShortcutBindingVec {
+            pub(crate) ptr: *const AzShortcutBinding,
+            pub len: usize,
+            pub cap: usize,
+            pub destructor: AzShortcutBindingVecDestructor,
+        }
+
+        /// Wrapper over a Rust-allocated `Vec<NotificationAction>`
+        #[repr(C)]
+        pub struct AzNotificationActionVec {
+            pub(crate) ptr: *const AzNotificationAction,
+            pub len: usize,
+            pub cap: usize,
+            pub destructor: AzNotificationActionVecDestructor,
+        }
+
         /// Wrapper over a Rust-allocated `Vec<StyleFontFamily>`
         #[repr(C)]
         pub struct AzStyleFontFamilyVec {
@@ -9484,6 +9963,16 @@ mod dll {
             Some(AzWaylandTheme),
         }
 
+        /// Re-export of rust-allocated (stack based) `OptionTrayIcon` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzOptionTrayIcon {
+            None,
+            Some(AzTrayIcon),
+        }
+
         /// Re-export of rust-allocated (stack based) `ResultRawImageDecodeImageError` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -9533,6 +10022,21 @@ mod dll {
             pub wayland_theme: AzOptionWaylandTheme,
             pub request_user_attention: bool,
             pub window_icon: AzOptionWindowIcon,
+            pub tray_icon: AzOptionTrayIcon,
+        }
+
+        /// Desktop notification, shown with `CallbackInfo::show_notification()`. The callbacks are invoked on the window that showed the notification, as long as the notification is visible and the window is open.
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzNotification {
+            pub title: AzString,
+            pub body: AzString,
+            pub icon: AzOptionImageRef,
+            pub urgency: AzNotificationUrgency,
+            pub actions: AzNotificationActionVec,
+            pub on_click: AzOptionMenuCallback,
         }
 
         /// Re-export of rust-allocated (stack based) `InlineLine` struct
@@ -9565,6 +10069,45 @@ mod dll {
             pub selectors: AzCssPathSelectorVec,
         }
 
+        /// Re-export of rust-allocated (stack based) `StyleCursor` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzStyleCursor {
+            Alias,
+            AllScroll,
+            Cell,
+            ColResize,
+            ContextMenu,
+            Copy,
+            Crosshair,
+            Default,
+            EResize,
+            EwResize,
+            Grab,
+            Grabbing,
+            Help,
+            Move,
+            NResize,
+            NsResize,
+            NeswResize,
+            NwseResize,
+            Pointer,
+            Progress,
+            RowResize,
+            SResize,
+            SeResize,
+            Text,
+            Unset,
+            VerticalText,
+            WResize,
+            Wait,
+            ZoomIn,
+            ZoomOut,
+            Image(AzStyleCursorImage),
+        }
+
         /// Re-export of rust-allocated (stack based) `StyleBackgroundContentVecValue` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -9578,6 +10121,19 @@ mod dll {
             Exact(AzStyleBackgroundContentVec),
         }
 
+        /// Re-export of rust-allocated (stack based) `StyleCursorValue` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzStyleCursorValue {
+            Auto,
+            None,
+            Inherit,
+            Initial,
+            Exact(AzStyleCursor),
+        }
+
         /// Re-export of rust-allocated (stack based) `StyleFontFamilyVecValue` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -9662,6 +10218,9 @@ mod dll {
             BoxShadowTop(AzStyleBoxShadowValue),
             BoxShadowBottom(AzStyleBoxShadowValue),
             ScrollbarStyle(AzScrollbarStyleValue),
+            ScrollBehavior(AzStyleScrollBehaviorValue),
+            ScrollSnapType(AzStyleScrollSnapTypeValue),
+            ScrollSnapAlign(AzStyleScrollSnapAlignValue),
             Opacity(AzStyleOpacityValue),
             Transform(AzStyleTransformVecValue),
             TransformOrigin(AzStyleTransformOriginValue),
@@ -9959,6 +10518,7 @@ mod dll {
             pub platform_specific_options: AzPlatformSpecificOptions,
             pub renderer_options: AzRendererOptions,
             pub background_color: AzColorU,
+            pub custom_cursor: AzOptionCustomCursor,
             pub layout_callback: AzLayoutCallback,
             pub close_callback: AzOptionCallback,
         }
@@ -9984,9 +10544,14 @@ mod dll {
             pub threads_removed: *mut c_void,
             pub current_window_handle: *const AzRawWindowHandle,
             pub new_windows: *mut c_void,
+            pub notifications: *mut c_void,
+            pub shortcuts_changed: *mut c_void,
+            pub windows_closed: *mut c_void,
+            pub window_result: *mut c_void,
             pub system_callbacks: *const AzSystemCallbacks,
             pub stop_propagation: *mut bool,
             pub focus_target: *mut c_void,
+            pub drag_data: *mut c_void,
             pub words_changed_in_callbacks: *mut c_void,
             pub images_changed_in_callbacks: *mut c_void,
             pub image_masks_changed_in_callbacks: *mut c_void,
@@ -10171,6 +10736,11 @@ mod dll {
             pub theme: AzOptionWindowTheme,
             pub create_callback: AzOptionCallback,
             pub hot_reload: bool,
+            pub id: AzWindowId,
+            pub parent: AzOptionWindowId,
+            pub modal: bool,
+            pub result_callback: AzOptionWindowResultCallback,
+            pub persistence_key: AzOptionString,
         }
 
         /// Defines the keyboard input focus target
@@ -10550,15 +11120,20 @@ mod dll {
         pub(crate) fn AzApp_addWindow(app: &mut AzApp, window: AzWindowCreateOptions) { unsafe { transmute(azul::AzApp_addWindow(transmute(app), transmute(window))) } }
         pub(crate) fn AzApp_addImage(app: &mut AzApp, id: AzString, image: AzImageRef) { unsafe { transmute(azul::AzApp_addImage(transmute(app), transmute(id), transmute(image))) } }
         pub(crate) fn AzApp_getMonitors(app: &AzApp) -> AzMonitorVec { unsafe { transmute(azul::AzApp_getMonitors(transmute(app))) } }
+        pub(crate) fn AzApp_registerShortcut(app: &mut AzApp, shortcut: AzShortcut) -> AzResultShortcutIdShortcutError { unsafe { transmute(azul::AzApp_registerShortcut(transmute(app), transmute(shortcut))) } }
+        pub(crate) fn AzApp_unregisterShortcut(app: &mut AzApp, shortcut_id: AzShortcutId) -> bool { unsafe { transmute(azul::AzApp_unregisterShortcut(transmute(app), transmute(shortcut_id))) } }
+        pub(crate) fn AzApp_getShortcuts(app: &AzApp) -> AzShortcutBindingVec { unsafe { transmute(azul::AzApp_getShortcuts(transmute(app))) } }
         pub(crate) fn AzApp_run(app: &AzApp, window: AzWindowCreateOptions) { unsafe { transmute(azul::AzApp_run(transmute(app), transmute(window))) } }
         pub(crate) fn AzApp_delete(object: &mut AzApp) { unsafe { transmute(azul::AzApp_delete(transmute(object))) } }
         pub(crate) fn AzApp_deepCopy(object: &AzApp) -> AzApp { unsafe { transmute(azul::AzApp_deepCopy(transmute(object))) } }
         pub(crate) fn AzAppConfig_new(layout_solver: AzLayoutSolver) -> AzAppConfig { unsafe { transmute(azul::AzAppConfig_new(transmute(layout_solver))) } }
         pub(crate) fn AzSystemCallbacks_libraryInternal() -> AzSystemCallbacks { unsafe { transmute(azul::AzSystemCallbacks_libraryInternal()) } }
         pub(crate) fn AzWindowCreateOptions_new(layout_callback: AzLayoutCallbackType) -> AzWindowCreateOptions { unsafe { transmute(azul::AzWindowCreateOptions_new(transmute(layout_callback))) } }
+        pub(crate) fn AzWindowResultCallback_new(data: AzRefAny, callback: AzWriteBackCallbackType) -> AzWindowResultCallback { unsafe { transmute(azul::AzWindowResultCallback_new(transmute(data), transmute(callback))) } }
         pub(crate) fn AzLogicalPosition_new(x: f32, y: f32) -> AzLogicalPosition { unsafe { transmute(azul::AzLogicalPosition_new(transmute(x), transmute(y))) } }
         pub(crate) fn AzLogicalPosition_zero() -> AzLogicalPosition { unsafe { transmute(azul::AzLogicalPosition_zero()) } }
         pub(crate) fn AzLogicalSize_toPhysical(logicalsize: &AzLogicalSize, hidpi_factor: f32) -> AzPhysicalSizeU32 { unsafe { transmute(azul::AzLogicalSize_toPhysical(transmute(logicalsize), transmute(hidpi_factor))) } }
+        pub(crate) fn AzTrayIcon_new(icon: AzWindowIcon, tooltip: AzString, menu: AzMenu) -> AzTrayIcon { unsafe { transmute(azul::AzTrayIcon_new(transmute(icon), transmute(tooltip), transmute(menu))) } }
         pub(crate) fn AzWindowSize_getHidpiFactor(windowsize: &AzWindowSize) -> f32 { unsafe { transmute(azul::AzWindowSize_getHidpiFactor(transmute(windowsize))) } }
         pub(crate) fn AzKeyboardState_shiftDown(keyboardstate: &AzKeyboardState) -> bool { unsafe { transmute(azul::AzKeyboardState_shiftDown(transmute(keyboardstate))) } }
         pub(crate) fn AzKeyboardState_ctrlDown(keyboardstate: &AzKeyboardState) -> bool { unsafe { transmute(azul::AzKeyboardState_ctrlDown(transmute(keyboardstate))) } }
@@ -10568,12 +11143,22 @@ mod dll {
         pub(crate) fn AzCursorPosition_getPosition(cursorposition: &AzCursorPosition) -> AzOptionLogicalPosition { unsafe { transmute(azul::AzCursorPosition_getPosition(transmute(cursorposition))) } }
         pub(crate) fn AzWindowState_new(layout_callback: AzLayoutCallbackType) -> AzWindowState { unsafe { transmute(azul::AzWindowState_new(transmute(layout_callback))) } }
         pub(crate) fn AzWindowState_default() -> AzWindowState { unsafe { transmute(azul::AzWindowState_default()) } }
+        pub(crate) fn AzCustomCursor_new(image: AzImageRef, hotspot_x: u32, hotspot_y: u32) -> AzCustomCursor { unsafe { transmute(azul::AzCustomCursor_new(transmute(image), transmute(hotspot_x), transmute(hotspot_y))) } }
+        pub(crate) fn AzNotification_new(title: AzString, body: AzString) -> AzNotification { unsafe { transmute(azul::AzNotification_new(transmute(title), transmute(body))) } }
+        pub(crate) fn AzNotification_withIcon(notification: AzNotification, icon: AzImageRef) -> AzNotification { unsafe { transmute(azul::AzNotification_withIcon(transmute(notification), transmute(icon))) } }
+        pub(crate) fn AzNotification_withUrgency(notification: AzNotification, urgency: AzNotificationUrgency) -> AzNotification { unsafe { transmute(azul::AzNotification_withUrgency(transmute(notification), transmute(urgency))) } }
+        pub(crate) fn AzNotification_withAction(notification: AzNotification, label: AzString, data: AzRefAny, callback: AzCallbackType) -> AzNotification { unsafe { transmute(azul::AzNotification_withAction(transmute(notification), transmute(label), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzNotification_withClickCallback(notification: AzNotification, data: AzRefAny, callback: AzCallbackType) -> AzNotification { unsafe { transmute(azul::AzNotification_withClickCallback(transmute(notification), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzShortcut_new(keys: AzVirtualKeyCodeComboVec, scope: AzShortcutScope, description: AzString, data: AzRefAny, callback: AzCallbackType) -> AzShortcut { unsafe { transmute(azul::AzShortcut_new(transmute(keys), transmute(scope), transmute(description), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzShortcut_getKeyString(shortcut: &AzShortcut) -> AzString { unsafe { transmute(azul::AzShortcut_getKeyString(transmute(shortcut))) } }
         pub(crate) fn AzCallbackInfo_getHitNode(callbackinfo: &AzCallbackInfo) -> AzDomNodeId { unsafe { transmute(azul::AzCallbackInfo_getHitNode(transmute(callbackinfo))) } }
         pub(crate) fn AzCallbackInfo_getSystemTimeFn(callbackinfo: &AzCallbackInfo) -> AzGetSystemTimeFn { unsafe { transmute(azul::AzCallbackInfo_getSystemTimeFn(transmute(callbackinfo))) } }
         pub(crate) fn AzCallbackInfo_getCursorRelativeToViewport(callbackinfo: &AzCallbackInfo) -> AzOptionLogicalPosition { unsafe { transmute(azul::AzCallbackInfo_getCursorRelativeToViewport(transmute(callbackinfo))) } }
         pub(crate) fn AzCallbackInfo_getCursorRelativeToNode(callbackinfo: &AzCallbackInfo) -> AzOptionLogicalPosition { unsafe { transmute(azul::AzCallbackInfo_getCursorRelativeToNode(transmute(callbackinfo))) } }
         pub(crate) fn AzCallbackInfo_getCurrentWindowState(callbackinfo: &AzCallbackInfo) -> AzWindowState { unsafe { transmute(azul::AzCallbackInfo_getCurrentWindowState(transmute(callbackinfo))) } }
         pub(crate) fn AzCallbackInfo_getCurrentKeyboardState(callbackinfo: &AzCallbackInfo) -> AzKeyboardState { unsafe { transmute(azul::AzCallbackInfo_getCurrentKeyboardState(transmute(callbackinfo))) } }
+        pub(crate) fn AzCallbackInfo_getTouchState(callbackinfo: &AzCallbackInfo) -> AzTouchState { unsafe { transmute(azul::AzCallbackInfo_getTouchState(transmute(callbackinfo))) } }
+        pub(crate) fn AzCallbackInfo_getGesture(callbackinfo: &AzCallbackInfo) -> AzOptionGestureState { unsafe { transmute(azul::AzCallbackInfo_getGesture(transmute(callbackinfo))) } }
         pub(crate) fn AzCallbackInfo_getCurrentMouseState(callbackinfo: &AzCallbackInfo) -> AzMouseState { unsafe { transmute(azul::AzCallbackInfo_getCurrentMouseState(transmute(callbackinfo))) } }
         pub(crate) fn AzCallbackInfo_getPreviousWindowState(callbackinfo: &AzCallbackInfo) -> AzOptionWindowState { unsafe { transmute(azul::AzCallbackInfo_getPreviousWindowState(transmute(callbackinfo))) } }
         pub(crate) fn AzCallbackInfo_getPreviousKeyboardState(callbackinfo: &AzCallbackInfo) -> AzOptionKeyboardState { unsafe { transmute(azul::AzCallbackInfo_getPreviousKeyboardState(transmute(callbackinfo))) } }
@@ -10581,6 +11166,7 @@ mod dll {
         pub(crate) fn AzCallbackInfo_getCurrentWindowHandle(callbackinfo: &AzCallbackInfo) -> AzRawWindowHandle { unsafe { transmute(azul::AzCallbackInfo_getCurrentWindowHandle(transmute(callbackinfo))) } }
         pub(crate) fn AzCallbackInfo_getGlContext(callbackinfo: &AzCallbackInfo) -> AzOptionGl { unsafe { transmute(azul::AzCallbackInfo_getGlContext(transmute(callbackinfo))) } }
         pub(crate) fn AzCallbackInfo_getScrollPosition(callbackinfo: &AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionLogicalPosition { unsafe { transmute(azul::AzCallbackInfo_getScrollPosition(transmute(callbackinfo), transmute(node_id))) } }
+        pub(crate) fn AzCallbackInfo_getScrollFrame(callbackinfo: &AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionScrollPosition { unsafe { transmute(azul::AzCallbackInfo_getScrollFrame(transmute(callbackinfo), transmute(node_id))) } }
         pub(crate) fn AzCallbackInfo_getDataset(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionRefAny { unsafe { transmute(azul::AzCallbackInfo_getDataset(transmute(callbackinfo), transmute(node_id))) } }
         pub(crate) fn AzCallbackInfo_getNodeIdOfRootDataset(callbackinfo: &mut AzCallbackInfo, dataset: AzRefAny) -> AzOptionDomNodeId { unsafe { transmute(azul::AzCallbackInfo_getNodeIdOfRootDataset(transmute(callbackinfo), transmute(dataset))) } }
         pub(crate) fn AzCallbackInfo_getStringContents(callbackinfo: &AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionString { unsafe { transmute(azul::AzCallbackInfo_getStringContents(transmute(callbackinfo), transmute(node_id))) } }
@@ -10598,9 +11184,13 @@ mod dll {
         pub(crate) fn AzCallbackInfo_getNodeSize(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionLogicalSize { unsafe { transmute(azul::AzCallbackInfo_getNodeSize(transmute(callbackinfo), transmute(node_id))) } }
         pub(crate) fn AzCallbackInfo_getComputedCssProperty(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, property_type: AzCssPropertyType) -> AzOptionCssProperty { unsafe { transmute(azul::AzCallbackInfo_getComputedCssProperty(transmute(callbackinfo), transmute(node_id), transmute(property_type))) } }
         pub(crate) fn AzCallbackInfo_setWindowState(callbackinfo: &mut AzCallbackInfo, new_state: AzWindowState) { unsafe { transmute(azul::AzCallbackInfo_setWindowState(transmute(callbackinfo), transmute(new_state))) } }
+        pub(crate) fn AzCallbackInfo_setCustomCursor(callbackinfo: &mut AzCallbackInfo, image: AzImageRef, hotspot_x: u32, hotspot_y: u32) { unsafe { transmute(azul::AzCallbackInfo_setCustomCursor(transmute(callbackinfo), transmute(image), transmute(hotspot_x), transmute(hotspot_y))) } }
+        pub(crate) fn AzCallbackInfo_resetCustomCursor(callbackinfo: &mut AzCallbackInfo) { unsafe { transmute(azul::AzCallbackInfo_resetCustomCursor(transmute(callbackinfo))) } }
         pub(crate) fn AzCallbackInfo_setFocus(callbackinfo: &mut AzCallbackInfo, target: AzFocusTarget) { unsafe { transmute(azul::AzCallbackInfo_setFocus(transmute(callbackinfo), transmute(target))) } }
         pub(crate) fn AzCallbackInfo_setCssProperty(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, new_property: AzCssProperty) { unsafe { transmute(azul::AzCallbackInfo_setCssProperty(transmute(callbackinfo), transmute(node_id), transmute(new_property))) } }
         pub(crate) fn AzCallbackInfo_setScrollPosition(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, scroll_position: AzLogicalPosition) { unsafe { transmute(azul::AzCallbackInfo_setScrollPosition(transmute(callbackinfo), transmute(node_id), transmute(scroll_position))) } }
+        pub(crate) fn AzCallbackInfo_scrollTo(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, scroll_position: AzLogicalPosition) { unsafe { transmute(azul::AzCallbackInfo_scrollTo(transmute(callbackinfo), transmute(node_id), transmute(scroll_position))) } }
+        pub(crate) fn AzCallbackInfo_scrollIntoView(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, align: AzScrollIntoViewAlign, smooth: bool) { unsafe { transmute(azul::AzCallbackInfo_scrollIntoView(transmute(callbackinfo), transmute(node_id), transmute(align), transmute(smooth))) } }
         pub(crate) fn AzCallbackInfo_setStringContents(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, string: AzString) { unsafe { transmute(azul::AzCallbackInfo_setStringContents(transmute(callbackinfo), transmute(node_id), transmute(string))) } }
         pub(crate) fn AzCallbackInfo_addImage(callbackinfo: &mut AzCallbackInfo, id: AzString, image: AzImageRef) { unsafe { transmute(azul::AzCallbackInfo_addImage(transmute(callbackinfo), transmute(id), transmute(image))) } }
         pub(crate) fn AzCallbackInfo_hasImage(callbackinfo: &AzCallbackInfo, id: AzString) -> bool { unsafe { transmute(azul::AzCallbackInfo_hasImage(transmute(callbackinfo), transmute(id))) } }
@@ -10609,7 +11199,12 @@ mod dll {
         pub(crate) fn AzCallbackInfo_deleteImage(callbackinfo: &mut AzCallbackInfo, id: AzString) { unsafe { transmute(azul::AzCallbackInfo_deleteImage(transmute(callbackinfo), transmute(id))) } }
         pub(crate) fn AzCallbackInfo_updateImageMask(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, new_mask: AzImageMask) { unsafe { transmute(azul::AzCallbackInfo_updateImageMask(transmute(callbackinfo), transmute(node_id), transmute(new_mask))) } }
         pub(crate) fn AzCallbackInfo_stopPropagation(callbackinfo: &mut AzCallbackInfo) { unsafe { transmute(azul::AzCallbackInfo_stopPropagation(transmute(callbackinfo))) } }
-        pub(crate) fn AzCallbackInfo_createWindow(callbackinfo: &mut AzCallbackInfo, new_window: AzWindowCreateOptions) { unsafe { transmute(azul::AzCallbackInfo_createWindow(transmute(callbackinfo), transmute(new_window))) } }
+        pub(crate) fn AzCallbackInfo_createWindow(callbackinfo: &mut AzCallbackInfo, new_window: AzWindowCreateOptions) -> AzWindowId { unsafe { transmute(azul::AzCallbackInfo_createWindow(transmute(callbackinfo), transmute(new_window))) } }
+        pub(crate) fn AzCallbackInfo_closeWindow(callbackinfo: &mut AzCallbackInfo, window_id: AzWindowId) { unsafe { transmute(azul::AzCallbackInfo_closeWindow(transmute(callbackinfo), transmute(window_id))) } }
+        pub(crate) fn AzCallbackInfo_setWindowResult(callbackinfo: &mut AzCallbackInfo, result: AzRefAny) { unsafe { transmute(azul::AzCallbackInfo_setWindowResult(transmute(callbackinfo), transmute(result))) } }
+        pub(crate) fn AzCallbackInfo_showNotification(callbackinfo: &mut AzCallbackInfo, notification: AzNotification) { unsafe { transmute(azul::AzCallbackInfo_showNotification(transmute(callbackinfo), transmute(notification))) } }
+        pub(crate) fn AzCallbackInfo_registerShortcut(callbackinfo: &mut AzCallbackInfo, shortcut: AzShortcut) -> AzShortcutId { unsafe { transmute(azul::AzCallbackInfo_registerShortcut(transmute(callbackinfo), transmute(shortcut))) } }
+        pub(crate) fn AzCallbackInfo_unregisterShortcut(callbackinfo: &mut AzCallbackInfo, shortcut_id: AzShortcutId) { unsafe { transmute(azul::AzCallbackInfo_unregisterShortcut(transmute(callbackinfo), transmute(shortcut_id))) } }
         pub(crate) fn AzCallbackInfo_startTimer(callbackinfo: &mut AzCallbackInfo, timer: AzTimer) -> AzTimerId { unsafe { transmute(azul::AzCallbackInfo_startTimer(transmute(callbackinfo), transmute(timer))) } }
         pub(crate) fn AzCallbackInfo_startAnimation(callbackinfo: &mut AzCallbackInfo, node: AzDomNodeId, animation: AzAnimation) -> AzOptionTimerId { unsafe { transmute(azul::AzCallbackInfo_startAnimation(transmute(callbackinfo), transmute(node), transmute(animation))) } }
         pub(crate) fn AzCallbackInfo_stopTimer(callbackinfo: &mut AzCallbackInfo, timer_id: AzTimerId) -> bool { unsafe { transmute(azul::AzCallbackInfo_stopTimer(transmute(callbackinfo), transmute(timer_id))) } }
@@ -11324,11 +11919,16 @@ mod dll {
         pub(crate) fn AzOutputConnectionVec_delete(object: &mut AzOutputConnectionVec) { unsafe { transmute(azul::AzOutputConnectionVec_delete(transmute(object))) } }
         pub(crate) fn AzInputNodeAndIndexVec_delete(object: &mut AzInputNodeAndIndexVec) { unsafe { transmute(azul::AzInputNodeAndIndexVec_delete(transmute(object))) } }
         pub(crate) fn AzAccessibilityStateVec_delete(object: &mut AzAccessibilityStateVec) { unsafe { transmute(azul::AzAccessibilityStateVec_delete(transmute(object))) } }
+        pub(crate) fn AzVirtualKeyCodeComboVec_delete(object: &mut AzVirtualKeyCodeComboVec) { unsafe { transmute(azul::AzVirtualKeyCodeComboVec_delete(transmute(object))) } }
+        pub(crate) fn AzShortcutBindingVec_delete(object: &mut AzShortcutBindingVec) { unsafe { transmute(azul::AzShortcutBindingVec_delete(transmute(object))) } }
+        pub(crate) fn AzTouchPointVec_delete(object: &mut AzTouchPointVec) { unsafe { transmute(azul::AzTouchPointVec_delete(transmute(object))) } }
+        pub(crate) fn AzNotificationActionVec_delete(object: &mut AzNotificationActionVec) { unsafe { transmute(azul::AzNotificationActionVec_delete(transmute(object))) } }
         pub(crate) fn AzMenuItemVec_delete(object: &mut AzMenuItemVec) { unsafe { transmute(azul::AzMenuItemVec_delete(transmute(object))) } }
         pub(crate) fn AzTessellatedSvgNodeVec_asRefVec(tessellatedsvgnodevec: &AzTessellatedSvgNodeVec) -> AzTessellatedSvgNodeVecRef { unsafe { transmute(azul::AzTessellatedSvgNodeVec_asRefVec(transmute(tessellatedsvgnodevec))) } }
         pub(crate) fn AzTessellatedSvgNodeVec_delete(object: &mut AzTessellatedSvgNodeVec) { unsafe { transmute(azul::AzTessellatedSvgNodeVec_delete(transmute(object))) } }
         pub(crate) fn AzTessellatedColoredSvgNodeVec_asRefVec(tessellatedcoloredsvgnodevec: &AzTessellatedColoredSvgNodeVec) -> AzTessellatedColoredSvgNodeVecRef { unsafe { transmute(azul::AzTessellatedColoredSvgNodeVec_asRefVec(transmute(tessellatedcoloredsvgnodevec))) } }
         pub(crate) fn AzTessellatedColoredSvgNodeVec_delete(object: &mut AzTessellatedColoredSvgNodeVec) { unsafe { transmute(azul::AzTessellatedColoredSvgNodeVec_delete(transmute(object))) } }
+        pub(crate) fn AzStyleCursorVec_delete(object: &mut AzStyleCursorVec) { unsafe { transmute(azul::AzStyleCursorVec_delete(transmute(object))) } }
         pub(crate) fn AzStyleFontFamilyVec_delete(object: &mut AzStyleFontFamilyVec) { unsafe { transmute(azul::AzStyleFontFamilyVec_delete(transmute(object))) } }
         pub(crate) fn AzXmlNodeVec_delete(object: &mut AzXmlNodeVec) { unsafe { transmute(azul::AzXmlNodeVec_delete(transmute(object))) } }
         pub(crate) fn AzFmtArgVec_delete(object: &mut AzFmtArgVec) { unsafe { transmute(azul::AzFmtArgVec_delete(transmute(object))) } }
@@ -11397,15 +11997,20 @@ mod dll {
             pub(crate) fn AzApp_addWindow(_:  &mut AzApp, _:  AzWindowCreateOptions);
             pub(crate) fn AzApp_addImage(_:  &mut AzApp, _:  AzString, _:  AzImageRef);
             pub(crate) fn AzApp_getMonitors(_:  &AzApp) -> AzMonitorVec;
+            pub(crate) fn AzApp_registerShortcut(_:  &mut AzApp, _:  AzShortcut) -> AzResultShortcutIdShortcutError;
+            pub(crate) fn AzApp_unregisterShortcut(_:  &mut AzApp, _:  AzShortcutId) -> bool;
+            pub(crate) fn AzApp_getShortcuts(_:  &AzApp) -> AzShortcutBindingVec;
             pub(crate) fn AzApp_run(_:  &AzApp, _:  AzWindowCreateOptions);
             pub(crate) fn AzApp_delete(_:  &mut AzApp);
             pub(crate) fn AzApp_deepCopy(_:  &AzApp) -> AzApp;
             pub(crate) fn AzAppConfig_new(_:  AzLayoutSolver) -> AzAppConfig;
             pub(crate) fn AzSystemCallbacks_libraryInternal() -> AzSystemCallbacks;
             pub(crate) fn AzWindowCreateOptions_new(_:  AzLayoutCallbackType) -> AzWindowCreateOptions;
+            pub(crate) fn AzWindowResultCallback_new(_:  AzRefAny, _:  AzWriteBackCallbackType) -> AzWindowResultCallback;
             pub(crate) fn AzLogicalPosition_new(_:  f32, _:  f32) -> AzLogicalPosition;
             pub(crate) fn AzLogicalPosition_zero() -> AzLogicalPosition;
             pub(crate) fn AzLogicalSize_toPhysical(_:  &AzLogicalSize, _:  f32) -> AzPhysicalSizeU32;
+            pub(crate) fn AzTrayIcon_new(_:  AzWindowIcon, _:  AzString, _:  AzMenu) -> AzTrayIcon;
             pub(crate) fn AzWindowSize_getHidpiFactor(_:  &AzWindowSize) -> f32;
             pub(crate) fn AzKeyboardState_shiftDown(_:  &AzKeyboardState) -> bool;
             pub(crate) fn AzKeyboardState_ctrlDown(_:  &AzKeyboardState) -> bool;
@@ -11415,12 +12020,22 @@ mod dll {
             pub(crate) fn AzCursorPosition_getPosition(_:  &AzCursorPosition) -> AzOptionLogicalPosition;
             pub(crate) fn AzWindowState_new(_:  AzLayoutCallbackType) -> AzWindowState;
             pub(crate) fn AzWindowState_default() -> AzWindowState;
+            pub(crate) fn AzCustomCursor_new(_:  AzImageRef, _:  u32, _:  u32) -> AzCustomCursor;
+            pub(crate) fn AzNotification_new(_:  AzString, _:  AzString) -> AzNotification;
+            pub(crate) fn AzNotification_withIcon(_:  AzNotification, _:  AzImageRef) -> AzNotification;
+            pub(crate) fn AzNotification_withUrgency(_:  AzNotification, _:  AzNotificationUrgency) -> AzNotification;
+            pub(crate) fn AzNotification_withAction(_:  AzNotification, _:  AzString, _:  AzRefAny, _:  AzCallbackType) -> AzNotification;
+            pub(crate) fn AzNotification_withClickCallback(_:  AzNotification, _:  AzRefAny, _:  AzCallbackType) -> AzNotification;
+            pub(crate) fn AzShortcut_new(_:  AzVirtualKeyCodeComboVec, _:  AzShortcutScope, _:  AzString, _:  AzRefAny, _:  AzCallbackType) -> AzShortcut;
+            pub(crate) fn AzShortcut_getKeyString(_:  &AzShortcut) -> AzString;
             pub(crate) fn AzCallbackInfo_getHitNode(_:  &AzCallbackInfo) -> AzDomNodeId;
             pub(crate) fn AzCallbackInfo_getSystemTimeFn(_:  &AzCallbackInfo) -> AzGetSystemTimeFn;
             pub(crate) fn AzCallbackInfo_getCursorRelativeToViewport(_:  &AzCallbackInfo) -> AzOptionLogicalPosition;
             pub(crate) fn AzCallbackInfo_getCursorRelativeToNode(_:  &AzCallbackInfo) -> AzOptionLogicalPosition;
             pub(crate) fn AzCallbackInfo_getCurrentWindowState(_:  &AzCallbackInfo) -> AzWindowState;
             pub(crate) fn AzCallbackInfo_getCurrentKeyboardState(_:  &AzCallbackInfo) -> AzKeyboardState;
+            pub(crate) fn AzCallbackInfo_getTouchState(_:  &AzCallbackInfo) -> AzTouchState;
+            pub(crate) fn AzCallbackInfo_getGesture(_:  &AzCallbackInfo) -> AzOptionGestureState;
             pub(crate) fn AzCallbackInfo_getCurrentMouseState(_:  &AzCallbackInfo) -> AzMouseState;
             pub(crate) fn AzCallbackInfo_getPreviousWindowState(_:  &AzCallbackInfo) -> AzOptionWindowState;
             pub(crate) fn AzCallbackInfo_getPreviousKeyboardState(_:  &AzCallbackInfo) -> AzOptionKeyboardState;
@@ -11428,6 +12043,7 @@ mod dll {
             pub(crate) fn AzCallbackInfo_getCurrentWindowHandle(_:  &AzCallbackInfo) -> AzRawWindowHandle;
             pub(crate) fn AzCallbackInfo_getGlContext(_:  &AzCallbackInfo) -> AzOptionGl;
             pub(crate) fn AzCallbackInfo_getScrollPosition(_:  &AzCallbackInfo, _:  AzDomNodeId) -> AzOptionLogicalPosition;
+            pub(crate) fn AzCallbackInfo_getScrollFrame(_:  &AzCallbackInfo, _:  AzDomNodeId) -> AzOptionScrollPosition;
             pub(crate) fn AzCallbackInfo_getDataset(_:  &mut AzCallbackInfo, _:  AzDomNodeId) -> AzOptionRefAny;
             pub(crate) fn AzCallbackInfo_getNodeIdOfRootDataset(_:  &mut AzCallbackInfo, _:  AzRefAny) -> AzOptionDomNodeId;
             pub(crate) fn AzCallbackInfo_getStringContents(_:  &AzCallbackInfo, _:  AzDomNodeId) -> AzOptionString;
@@ -11445,9 +12061,13 @@ mod dll {
             pub(crate) fn AzCallbackInfo_getNodeSize(_:  &mut AzCallbackInfo, _:  AzDomNodeId) -> AzOptionLogicalSize;
             pub(crate) fn AzCallbackInfo_getComputedCssProperty(_:  &mut AzCallbackInfo, _:  AzDomNodeId, _:  AzCssPropertyType) -> AzOptionCssProperty;
             pub(crate) fn AzCallbackInfo_setWindowState(_:  &mut AzCallbackInfo, _:  AzWindowState);
+            pub(crate) fn AzCallbackInfo_setCustomCursor(_:  &mut AzCallbackInfo, _:  AzImageRef, _:  u32, _:  u32);
+            pub(crate) fn AzCallbackInfo_resetCustomCursor(_:  &mut AzCallbackInfo);
             pub(crate) fn AzCallbackInfo_setFocus(_:  &mut AzCallbackInfo, _:  AzFocusTarget);
             pub(crate) fn AzCallbackInfo_setCssProperty(_:  &mut AzCallbackInfo, _:  AzDomNodeId, _:  AzCssProperty);
             pub(crate) fn AzCallbackInfo_setScrollPosition(_:  &mut AzCallbackInfo, _:  AzDomNodeId, _:  AzLogicalPosition);
+            pub(crate) fn AzCallbackInfo_scrollTo(_:  &mut AzCallbackInfo, _:  AzDomNodeId, _:  AzLogicalPosition);
+            pub(crate) fn AzCallbackInfo_scrollIntoView(_:  &mut AzCallbackInfo, _:  AzDomNodeId, _:  AzScrollIntoViewAlign, _:  bool);
             pub(crate) fn AzCallbackInfo_setStringContents(_:  &mut AzCallbackInfo, _:  AzDomNodeId, _:  AzString);
             pub(crate) fn AzCallbackInfo_addImage(_:  &mut AzCallbackInfo, _:  AzString, _:  AzImageRef);
             pub(crate) fn AzCallbackInfo_hasImage(_:  &AzCallbackInfo, _:  AzString) -> bool;
@@ -11456,7 +12076,12 @@ mod dll {
             pub(crate) fn AzCallbackInfo_deleteImage(_:  &mut AzCallbackInfo, _:  AzString);
             pub(crate) fn AzCallbackInfo_updateImageMask(_:  &mut AzCallbackInfo, _:  AzDomNodeId, _:  AzImageMask);
             pub(crate) fn AzCallbackInfo_stopPropagation(_:  &mut AzCallbackInfo);
-            pub(crate) fn AzCallbackInfo_createWindow(_:  &mut AzCallbackInfo, _:  AzWindowCreateOptions);
+            pub(crate) fn AzCallbackInfo_createWindow(_:  &mut AzCallbackInfo, _:  AzWindowCreateOptions) -> AzWindowId;
+            pub(crate) fn AzCallbackInfo_closeWindow(_:  &mut AzCallbackInfo, _:  AzWindowId);
+            pub(crate) fn AzCallbackInfo_setWindowResult(_:  &mut AzCallbackInfo, _:  AzRefAny);
+            pub(crate) fn AzCallbackInfo_showNotification(_:  &mut AzCallbackInfo, _:  AzNotification);
+            pub(crate) fn AzCallbackInfo_registerShortcut(_:  &mut AzCallbackInfo, _:  AzShortcut) -> AzShortcutId;
+            pub(crate) fn AzCallbackInfo_unregisterShortcut(_:  &mut AzCallbackInfo, _:  AzShortcutId);
             pub(crate) fn AzCallbackInfo_startTimer(_:  &mut AzCallbackInfo, _:  AzTimer) -> AzTimerId;
             pub(crate) fn AzCallbackInfo_startAnimation(_:  &mut AzCallbackInfo, _:  AzDomNodeId, _:  AzAnimation) -> AzOptionTimerId;
             pub(crate) fn AzCallbackInfo_stopTimer(_:  &mut AzCallbackInfo, _:  AzTimerId) -> bool;
@@ -12171,11 +12796,16 @@ mod dll {
             pub(crate) fn AzOutputConnectionVec_delete(_:  &mut AzOutputConnectionVec);
             pub(crate) fn AzInputNodeAndIndexVec_delete(_:  &mut AzInputNodeAndIndexVec);
             pub(crate) fn AzAccessibilityStateVec_delete(_:  &mut AzAccessibilityStateVec);
+            pub(crate) fn AzVirtualKeyCodeComboVec_delete(_:  &mut AzVirtualKeyCodeComboVec);
+            pub(crate) fn AzShortcutBindingVec_delete(_:  &mut AzShortcutBindingVec);
+            pub(crate) fn AzTouchPointVec_delete(_:  &mut AzTouchPointVec);
+            pub(crate) fn AzNotificationActionVec_delete(_:  &mut AzNotificationActionVec);
             pub(crate) fn AzMenuItemVec_delete(_:  &mut AzMenuItemVec);
             pub(crate) fn AzTessellatedSvgNodeVec_asRefVec(_:  &AzTessellatedSvgNodeVec) -> AzTessellatedSvgNodeVecRef;
             pub(crate) fn AzTessellatedSvgNodeVec_delete(_:  &mut AzTessellatedSvgNodeVec);
             pub(crate) fn AzTessellatedColoredSvgNodeVec_asRefVec(_:  &AzTessellatedColoredSvgNodeVec) -> AzTessellatedColoredSvgNodeVecRef;
             pub(crate) fn AzTessellatedColoredSvgNodeVec_delete(_:  &mut AzTessellatedColoredSvgNodeVec);
+            pub(crate) fn AzStyleCursorVec_delete(_:  &mut AzStyleCursorVec);
             pub(crate) fn AzStyleFontFamilyVec_delete(_:  &mut AzStyleFontFamilyVec);
             pub(crate) fn AzXmlNodeVec_delete(_:  &mut AzXmlNodeVec);
             pub(crate) fn AzFmtArgVec_delete(_:  &mut AzFmtArgVec);
@@ -12243,7 +12873,7 @@ pub mod app {
     use crate::dll::*;
     use core::ffi::c_void;
     use crate::callbacks::RefAny;
-    use crate::window::WindowCreateOptions;
+    use crate::window::{Shortcut, ShortcutId, WindowCreateOptions};
     use crate::str::String;
     use crate::image::ImageRef;
     /// Main application class
@@ -12259,6 +12889,12 @@ pub mod app {
         pub fn add_image<_1: Into<String>, _2: Into<ImageRef>>(&mut self, id: _1, image: _2)  { unsafe { crate::dll::AzApp_addImage(self, id.into(), image.into()) } }
         /// Returns a list of monitors - useful for setting the monitor that a window should spawn on.
         pub fn get_monitors(&self)  -> crate::vec::MonitorVec { unsafe { crate::dll::AzApp_getMonitors(self) } }
+        /// Registers a keyboard shortcut, fails if the keys conflict with an already registered shortcut in the same scope
+        pub fn register_shortcut<_1: Into<Shortcut>>(&mut self, shortcut: _1)  -> crate::error::ResultShortcutIdShortcutError { unsafe { crate::dll::AzApp_registerShortcut(self, shortcut.into()) } }
+        /// Removes a keyboard shortcut, returns whether it was registered
+        pub fn unregister_shortcut<_1: Into<ShortcutId>>(&mut self, shortcut_id: _1)  -> bool { unsafe { crate::dll::AzApp_unregisterShortcut(self, shortcut_id.into()) } }
+        /// Returns the registered keyboard shortcuts (i.e. for a keyboard shortcut help screen)
+        pub fn get_shortcuts(&self)  -> crate::vec::ShortcutBindingVec { unsafe { crate::dll::AzApp_getShortcuts(self) } }
         /// Runs the application. Due to platform restrictions (specifically `WinMain` on Windows), this function never returns.
         pub fn run<_1: Into<WindowCreateOptions>>(&self, window: _1)  { unsafe { crate::dll::AzApp_run(self, window.into()) } }
     }
@@ -12379,7 +13015,11 @@ pub mod window {
             b_x + b_width <= a_x + a_width &&
             b_y + b_height <= a_y + a_height
         }
-    }    use crate::callbacks::LayoutCallbackType;
+    }    use crate::callbacks::{CallbackType, LayoutCallbackType, RefAny, WriteBackCallbackType};
+    use crate::str::String;
+    use crate::menu::Menu;
+    use crate::image::ImageRef;
+    use crate::vec::VirtualKeyCodeComboVec;
     /// Options on how to initially create the window
     
     #[doc(inline)] pub use crate::dll::AzWindowCreateOptions as WindowCreateOptions;
@@ -12389,6 +13029,18 @@ pub mod window {
         pub fn new(layout_callback: LayoutCallbackType) -> Self { unsafe { crate::dll::AzWindowCreateOptions_new(layout_callback) } }
     }
 
+    /// Unique ID of a window, used to close a window or to open a child window
+    
+    #[doc(inline)] pub use crate::dll::AzWindowId as WindowId;
+    /// Callback that is invoked on the parent window with the result of a child window after the child window has been closed
+    
+    #[doc(inline)] pub use crate::dll::AzWindowResultCallback as WindowResultCallback;
+    impl WindowResultCallback {
+
+        /// Creates a new `WindowResultCallback` instance.
+        pub fn new<_1: Into<RefAny>>(data: _1, callback: WriteBackCallbackType) -> Self { unsafe { crate::dll::AzWindowResultCallback_new(data.into(), callback) } }
+    }
+
     /// Force a specific renderer: note that azul will **crash** on startup if the `RendererOptions` are not satisfied.
     
     #[doc(inline)] pub use crate::dll::AzRendererOptions as RendererOptions;
@@ -12481,6 +13133,15 @@ pub mod window {
     /// Window "favicon", usually shown in the top left of the window on Windows
     
     #[doc(inline)] pub use crate::dll::AzWindowIcon as WindowIcon;
+    /// Icon in the system tray / notification area of the desktop, with a context menu
+    
+    #[doc(inline)] pub use crate::dll::AzTrayIcon as TrayIcon;
+    impl TrayIcon {
+
+        /// Creates a new tray icon
+        pub fn new<_1: Into<WindowIcon>, _2: Into<String>, _3: Into<Menu>>(icon: _1, tooltip: _2, menu: _3) -> Self { unsafe { crate::dll::AzTrayIcon_new(icon.into(), tooltip.into(), menu.into()) } }
+    }
+
     /// Application taskbar icon, 256x256x4 bytes in size
     
     #[doc(inline)] pub use crate::dll::AzTaskBarIcon as TaskBarIcon;
@@ -12576,7 +13237,16 @@ pub mod window {
     /// Position of the virtual keyboard necessary to insert CJK characters
     
     #[doc(inline)] pub use crate::dll::AzImePosition as ImePosition;
-    /// Current state of touch devices / touch inputs
+    /// Kind of device that touches the screen / graphics tablet
+    
+    #[doc(inline)] pub use crate::dll::AzTouchPointKind as TouchPointKind;
+    /// Finger or pen that touches the window
+    
+    #[doc(inline)] pub use crate::dll::AzTouchPoint as TouchPoint;
+    /// Two-finger gesture, all values are relative to the positions of the fingers at the start of the gesture
+    
+    #[doc(inline)] pub use crate::dll::AzGestureState as GestureState;
+    /// Fingers and pens touching the window and the recognized gestures
     
     #[doc(inline)] pub use crate::dll::AzTouchState as TouchState;
     /// Information about a single (or many) monitors, useful for dock widgets
@@ -12596,6 +13266,61 @@ pub mod window {
         pub fn default() -> Self { unsafe { crate::dll::AzWindowState_default() } }
     }
 
+    /// Image-based mouse cursor, created either from a CSS `cursor: url(...)` property or set at runtime via `CallbackInfo::set_custom_cursor()`. The hotspot is the "click point" of the cursor in pixels, relative to the top left corner of the image.
+    
+    #[doc(inline)] pub use crate::dll::AzCustomCursor as CustomCursor;
+    impl CustomCursor {
+
+        /// Creates a new `CustomCursor` instance.
+        pub fn new<_1: Into<ImageRef>>(image: _1, hotspot_x: u32, hotspot_y: u32) -> Self { unsafe { crate::dll::AzCustomCursor_new(image.into(), hotspot_x, hotspot_y) } }
+    }
+
+    /// Desktop notification, shown with `CallbackInfo::show_notification()`. The callbacks are invoked on the window that showed the notification, as long as the notification is visible and the window is open.
+    
+    #[doc(inline)] pub use crate::dll::AzNotification as Notification;
+    impl Notification {
+
+        /// Creates a new notification with the given title and body text
+        pub fn new<_1: Into<String>, _2: Into<String>>(title: _1, body: _2) -> Self { unsafe { crate::dll::AzNotification_new(title.into(), body.into()) } }
+        /// Calls the `Notification::with_icon` function.
+        pub fn with_icon<_1: Into<ImageRef>>(self, icon: _1)  -> crate::window::Notification { unsafe { crate::dll::AzNotification_withIcon(self, icon.into()) } }
+        /// Calls the `Notification::with_urgency` function.
+        pub fn with_urgency<_1: Into<NotificationUrgency>>(self, urgency: _1)  -> crate::window::Notification { unsafe { crate::dll::AzNotification_withUrgency(self, urgency.into()) } }
+        /// Adds a button to the notification
+        pub fn with_action<_1: Into<String>, _2: Into<RefAny>>(self, label: _1, data: _2, callback: CallbackType)  -> crate::window::Notification { unsafe { crate::dll::AzNotification_withAction(self, label.into(), data.into(), callback) } }
+        /// Sets the callback that is invoked when the notification is clicked
+        pub fn with_click_callback<_1: Into<RefAny>>(self, data: _1, callback: CallbackType)  -> crate::window::Notification { unsafe { crate::dll::AzNotification_withClickCallback(self, data.into(), callback) } }
+    }
+
+    /// Button of a desktop notification
+    
+    #[doc(inline)] pub use crate::dll::AzNotificationAction as NotificationAction;
+    /// Urgency of a desktop notification, critical notifications usually don't time out
+    
+    #[doc(inline)] pub use crate::dll::AzNotificationUrgency as NotificationUrgency;
+    /// ID of a registered keyboard shortcut, used to unregister the shortcut again
+    
+    #[doc(inline)] pub use crate::dll::AzShortcutId as ShortcutId;
+    /// Where a keyboard shortcut is active
+    
+    #[doc(inline)] pub use crate::dll::AzShortcutScope as ShortcutScope;
+    /// Keyboard shortcut: a sequence of key combinations (i.e. "Ctrl + K, Ctrl + S") that invokes a callback, registered with `App::register_shortcut` or `CallbackInfo::register_shortcut`
+    
+    #[doc(inline)] pub use crate::dll::AzShortcut as Shortcut;
+    impl Shortcut {
+
+        /// Creates a new `Shortcut` instance.
+        pub fn new<_1: Into<VirtualKeyCodeComboVec>, _2: Into<ShortcutScope>, _3: Into<String>, _4: Into<RefAny>>(keys: _1, scope: _2, description: _3, data: _4, callback: CallbackType) -> Self { unsafe { crate::dll::AzShortcut_new(keys.into(), scope.into(), description.into(), data.into(), callback) } }
+        /// Returns the keys as a human-readable string, i.e. "Ctrl+K Ctrl+S"
+        pub fn get_key_string(&self)  -> crate::str::String { unsafe { crate::dll::AzShortcut_getKeyString(self) } }
+    }
+
+    /// Registered keyboard shortcut, see `App::get_shortcuts`
+    
+    #[doc(inline)] pub use crate::dll::AzShortcutBinding as ShortcutBinding;
+    /// Error when registering a keyboard shortcut
+    
+    #[doc(inline)] pub use crate::dll::AzShortcutError as ShortcutError;
 }
 
 pub mod callbacks {
@@ -12741,7 +13466,7 @@ pub mod callbacks {
         }
     }    use crate::str::String;
     use crate::css::{CssProperty, CssPropertyType};
-    use crate::window::{LogicalPosition, WindowCreateOptions, WindowState};
+    use crate::window::{LogicalPosition, Notification, Shortcut, ShortcutId, WindowCreateOptions, WindowId, WindowState};
     use crate::image::{ImageMask, ImageRef};
     use crate::task::{ThreadId, ThreadSendMsg, Timer, TimerId};
     /// `LayoutCallback` struct
@@ -12785,6 +13510,10 @@ pub mod callbacks {
         pub fn get_current_window_state(&self)  -> crate::window::WindowState { unsafe { crate::dll::AzCallbackInfo_getCurrentWindowState(self) } }
         /// Returns a copy of the internal `KeyboardState`. Same as `self.get_window_state().keyboard_state`
         pub fn get_current_keyboard_state(&self)  -> crate::window::KeyboardState { unsafe { crate::dll::AzCallbackInfo_getCurrentKeyboardState(self) } }
+        /// Returns the fingers and pens that touch the window (`On::TouchStart`, `On::TouchMove`) and the ones that were just lifted (`On::TouchEnd`)
+        pub fn get_touch_state(&self)  -> crate::window::TouchState { unsafe { crate::dll::AzCallbackInfo_getTouchState(self) } }
+        /// Returns the current two-finger gesture (`On::Pinch`, `On::Rotate`, `On::TwoFingerPan`), `None` if less or more than two fingers touch the window
+        pub fn get_gesture(&self)  -> crate::option::OptionGestureState { unsafe { crate::dll::AzCallbackInfo_getGesture(self) } }
         /// Returns a copy of the internal `MouseState`. Same as `self.get_window_state().mouse_state`
         pub fn get_current_mouse_state(&self)  -> crate::window::MouseState { unsafe { crate::dll::AzCallbackInfo_getCurrentMouseState(self) } }
         /// Returns a copy of the current windows `WindowState`.
@@ -12799,6 +13528,8 @@ pub mod callbacks {
         pub fn get_gl_context(&self)  -> crate::option::OptionGl { unsafe { crate::dll::AzCallbackInfo_getGlContext(self) } }
         /// Returns the x / y offset that this node has been scrolled to by the user or `None` if the node has not been scrolled.
         pub fn get_scroll_position<_1: Into<DomNodeId>>(&self, node_id: _1)  -> crate::option::OptionLogicalPosition { unsafe { crate::dll::AzCallbackInfo_getScrollPosition(self, node_id.into()) } }
+        /// Returns the size of the scroll container, the size of its content and the scroll offset of the node, i.e. to check in an `On::Scrolled` callback whether the end of a lazily loaded list was reached
+        pub fn get_scroll_frame<_1: Into<DomNodeId>>(&self, node_id: _1)  -> crate::option::OptionScrollPosition { unsafe { crate::dll::AzCallbackInfo_getScrollFrame(self, node_id.into()) } }
         /// Returns the `dataset` property of the given Node or `None` if the node doesn't have a `dataset` property.
         pub fn get_dataset<_1: Into<DomNodeId>>(&mut self, node_id: _1)  -> crate::option::OptionRefAny { unsafe { crate::dll::AzCallbackInfo_getDataset(self, node_id.into()) } }
         /// Given a dataset, returns the node ID of the "root" `RefAny`, i.e. the `RefAny` with the lowest `instance` count that is set as a `dataset` on any node.
@@ -12833,12 +13564,20 @@ pub mod callbacks {
         pub fn get_computed_css_property<_1: Into<DomNodeId>, _2: Into<CssPropertyType>>(&mut self, node_id: _1, property_type: _2)  -> crate::option::OptionCssProperty { unsafe { crate::dll::AzCallbackInfo_getComputedCssProperty(self, node_id.into(), property_type.into()) } }
         /// Sets the new `WindowState` for the next frame. The window is updated after all callbacks are run.
         pub fn set_window_state<_1: Into<WindowState>>(&mut self, new_state: _1)  { unsafe { crate::dll::AzCallbackInfo_setWindowState(self, new_state.into()) } }
+        /// Sets an image cursor for the entire window, overriding the CSS `cursor` property of all nodes until `reset_custom_cursor()` is called
+        pub fn set_custom_cursor<_1: Into<ImageRef>>(&mut self, image: _1, hotspot_x: u32, hotspot_y: u32)  { unsafe { crate::dll::AzCallbackInfo_setCustomCursor(self, image.into(), hotspot_x, hotspot_y) } }
+        /// Removes the image cursor set by `set_custom_cursor()`
+        pub fn reset_custom_cursor(&mut self)  { unsafe { crate::dll::AzCallbackInfo_resetCustomCursor(self) } }
         /// Sets the new `FocusTarget` for the next frame. Note that this will emit a `On::FocusLost` and `On::FocusReceived` event, if the focused node has changed.
         pub fn set_focus<_1: Into<FocusTarget>>(&mut self, target: _1)  { unsafe { crate::dll::AzCallbackInfo_setFocus(self, target.into()) } }
         /// Sets a `CssProperty` on a given node to its new value. If this property change affects the layout, this will automatically trigger a relayout and redraw of the screen.
         pub fn set_css_property<_1: Into<DomNodeId>, _2: Into<CssProperty>>(&mut self, node_id: _1, new_property: _2)  { unsafe { crate::dll::AzCallbackInfo_setCssProperty(self, node_id.into(), new_property.into()) } }
         /// Sets the scroll position of the node
         pub fn set_scroll_position<_1: Into<DomNodeId>, _2: Into<LogicalPosition>>(&mut self, node_id: _1, scroll_position: _2)  { unsafe { crate::dll::AzCallbackInfo_setScrollPosition(self, node_id.into(), scroll_position.into()) } }
+        /// Scrolls the node to the given position: animated if the node has `scroll-behavior: smooth`, otherwise immediately
+        pub fn scroll_to<_1: Into<DomNodeId>, _2: Into<LogicalPosition>>(&mut self, node_id: _1, scroll_position: _2)  { unsafe { crate::dll::AzCallbackInfo_scrollTo(self, node_id.into(), scroll_position.into()) } }
+        /// Scrolls all scroll containers that the node is in (from the innermost to the outermost one) so that the node is visible and aligned according to `align`
+        pub fn scroll_into_view<_1: Into<DomNodeId>, _2: Into<ScrollIntoViewAlign>>(&mut self, node_id: _1, align: _2, smooth: bool)  { unsafe { crate::dll::AzCallbackInfo_scrollIntoView(self, node_id.into(), align.into(), smooth) } }
         /// If the node is a `Text` node, overwrites the `Text` content with the new string, without requiring the entire UI to be rebuilt.
         pub fn set_string_contents<_1: Into<DomNodeId>, _2: Into<String>>(&mut self, node_id: _1, string: _2)  { unsafe { crate::dll::AzCallbackInfo_setStringContents(self, node_id.into(), string.into()) } }
         /// Adds a new image identified by an ID to the image cache
@@ -12855,8 +13594,18 @@ pub mod callbacks {
         pub fn update_image_mask<_1: Into<DomNodeId>, _2: Into<ImageMask>>(&mut self, node_id: _1, new_mask: _2)  { unsafe { crate::dll::AzCallbackInfo_updateImageMask(self, node_id.into(), new_mask.into()) } }
         /// Stops the propagation of the current callback event type to the parent. Events are bubbled from the inside out (children first, then parents), this event stops the propagation of the event to the parent.
         pub fn stop_propagation(&mut self)  { unsafe { crate::dll::AzCallbackInfo_stopPropagation(self) } }
-        /// Spawns a new window with the given `WindowCreateOptions`.
-        pub fn create_window<_1: Into<WindowCreateOptions>>(&mut self, new_window: _1)  { unsafe { crate::dll::AzCallbackInfo_createWindow(self, new_window.into()) } }
+        /// Spawns a new window with the given `WindowCreateOptions` once the callback returns, returns the ID of the new window.
+        pub fn create_window<_1: Into<WindowCreateOptions>>(&mut self, new_window: _1)  -> crate::window::WindowId { unsafe { crate::dll::AzCallbackInfo_createWindow(self, new_window.into()) } }
+        /// Closes the window with the given ID (and its child windows) once the callback returns. To close the current window, set `WindowFlags::is_about_to_close` instead.
+        pub fn close_window<_1: Into<WindowId>>(&mut self, window_id: _1)  { unsafe { crate::dll::AzCallbackInfo_closeWindow(self, window_id.into()) } }
+        /// Sets the result of the current window: once the window is closed, the result is passed to the `WindowCreateOptions::result_callback` on the parent window
+        pub fn set_window_result<_1: Into<RefAny>>(&mut self, result: _1)  { unsafe { crate::dll::AzCallbackInfo_setWindowResult(self, result.into()) } }
+        /// Shows a desktop notification once the callback returns
+        pub fn show_notification<_1: Into<Notification>>(&mut self, notification: _1)  { unsafe { crate::dll::AzCallbackInfo_showNotification(self, notification.into()) } }
+        /// Registers an application-wide keyboard shortcut once the callback returns and returns its ID. If the shortcut conflicts with an already registered shortcut, it is not registered.
+        pub fn register_shortcut<_1: Into<Shortcut>>(&mut self, shortcut: _1)  -> crate::window::ShortcutId { unsafe { crate::dll::AzCallbackInfo_registerShortcut(self, shortcut.into()) } }
+        /// Removes the keyboard shortcut once the callback returns
+        pub fn unregister_shortcut<_1: Into<ShortcutId>>(&mut self, shortcut_id: _1)  { unsafe { crate::dll::AzCallbackInfo_unregisterShortcut(self, shortcut_id.into()) } }
         /// Adds a new `Timer` to the runtime. See the documentation for `Timer` for more information.
         pub fn start_timer<_1: Into<Timer>>(&mut self, timer: _1)  -> crate::task::TimerId { unsafe { crate::dll::AzCallbackInfo_startTimer(self, timer.into()) } }
         /// Starts an animation timer on a give NodeId - same as a `Timer`, but uses a pre-configured interpolation function to drive the animation timer
@@ -12902,6 +13651,12 @@ pub mod callbacks {
     /// `PositionInfoInner` struct
     
     #[doc(inline)] pub use crate::dll::AzPositionInfoInner as PositionInfoInner;
+    /// Information about a scroll frame, given to the user by the framework
+    
+    #[doc(inline)] pub use crate::dll::AzScrollPosition as ScrollPosition;
+    /// How a node is aligned in its scroll containers by `CallbackInfo::scroll_into_view`
+    
+    #[doc(inline)] pub use crate::dll::AzScrollIntoViewAlign as ScrollIntoViewAlign;
     /// `HidpiAdjustedBounds` struct
     
     #[doc(inline)] pub use crate::dll::AzHidpiAdjustedBounds as HidpiAdjustedBounds;
@@ -13635,6 +14390,9 @@ pub mod css {
             CssPropertyType::BoxShadowTop => CssProperty::BoxShadowTop(StyleBoxShadowValue::$content_type),
             CssPropertyType::BoxShadowBottom => CssProperty::BoxShadowBottom(StyleBoxShadowValue::$content_type),
             CssPropertyType::ScrollbarStyle => CssProperty::ScrollbarStyle(ScrollbarStyleValue::$content_type),
+            CssPropertyType::ScrollBehavior => CssProperty::ScrollBehavior(StyleScrollBehaviorValue::$content_type),
+            CssPropertyType::ScrollSnapType => CssProperty::ScrollSnapType(StyleScrollSnapTypeValue::$content_type),
+            CssPropertyType::ScrollSnapAlign => CssProperty::ScrollSnapAlign(StyleScrollSnapAlignValue::$content_type),
             CssPropertyType::Opacity => CssProperty::Opacity(StyleOpacityValue::$content_type),
             CssPropertyType::Transform => CssProperty::Transform(StyleTransformVecValue::$content_type),
             CssPropertyType::PerspectiveOrigin => CssProperty::PerspectiveOrigin(StylePerspectiveOriginValue::$content_type),
@@ -13717,6 +14475,9 @@ pub mod css {
                 CssProperty::BoxShadowTop(_) => CssPropertyType::BoxShadowTop,
                 CssProperty::BoxShadowBottom(_) => CssPropertyType::BoxShadowBottom,
                 CssProperty::ScrollbarStyle(_) => CssPropertyType::ScrollbarStyle,
+                CssProperty::ScrollBehavior(_) => CssPropertyType::ScrollBehavior,
+                CssProperty::ScrollSnapType(_) => CssPropertyType::ScrollSnapType,
+                CssProperty::ScrollSnapAlign(_) => CssPropertyType::ScrollSnapAlign,
                 CssProperty::Opacity(_) => CssPropertyType::Opacity,
                 CssProperty::Transform(_) => CssPropertyType::Transform,
                 CssProperty::PerspectiveOrigin(_) => CssPropertyType::PerspectiveOrigin,
@@ -13800,6 +14561,9 @@ pub mod css {
         pub const fn box_shadow_right(input: StyleBoxShadow) -> Self { CssProperty::BoxShadowRight(StyleBoxShadowValue::Exact(input)) }
         pub const fn box_shadow_top(input: StyleBoxShadow) -> Self { CssProperty::BoxShadowTop(StyleBoxShadowValue::Exact(input)) }
         pub const fn box_shadow_bottom(input: StyleBoxShadow) -> Self { CssProperty::BoxShadowBottom(StyleBoxShadowValue::Exact(input)) }
+        pub const fn scroll_behavior(input: StyleScrollBehavior) -> Self { CssProperty::ScrollBehavior(StyleScrollBehaviorValue::Exact(input)) }
+        pub const fn scroll_snap_type(input: StyleScrollSnapType) -> Self { CssProperty::ScrollSnapType(StyleScrollSnapTypeValue::Exact(input)) }
+        pub const fn scroll_snap_align(input: StyleScrollSnapAlign) -> Self { CssProperty::ScrollSnapAlign(StyleScrollSnapAlignValue::Exact(input)) }
         pub const fn opacity(input: StyleOpacity) -> Self { CssProperty::Opacity(StyleOpacityValue::Exact(input)) }
         pub const fn transform(input: StyleTransformVec) -> Self { CssProperty::Transform(StyleTransformVecValue::Exact(input)) }
         pub const fn transform_origin(input: StyleTransformOrigin) -> Self { CssProperty::TransformOrigin(StyleTransformOriginValue::Exact(input)) }
@@ -14525,9 +15289,27 @@ pub mod css {
     /// `ScrollbarStyle` struct
     
     #[doc(inline)] pub use crate::dll::AzScrollbarStyle as ScrollbarStyle;
+    /// Represents a `scroll-behavior` attribute: whether programmatic scrolling (such as `CallbackInfo::scroll_to`) is animated
+    
+    #[doc(inline)] pub use crate::dll::AzStyleScrollBehavior as StyleScrollBehavior;
+    /// Represents a `scroll-snap-type` attribute, for example `y mandatory`
+    
+    #[doc(inline)] pub use crate::dll::AzStyleScrollSnapType as StyleScrollSnapType;
+    /// Axis on which a scroll container snaps to its children
+    
+    #[doc(inline)] pub use crate::dll::AzScrollSnapAxis as ScrollSnapAxis;
+    /// `mandatory` always snaps to the nearest snap position, `proximity` only snaps if the scroll position comes close to one
+    
+    #[doc(inline)] pub use crate::dll::AzScrollSnapStrictness as ScrollSnapStrictness;
+    /// Represents a `scroll-snap-align` attribute: which edge of the node is aligned with the scroll container when snapping
+    
+    #[doc(inline)] pub use crate::dll::AzStyleScrollSnapAlign as StyleScrollSnapAlign;
     /// `StyleCursor` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleCursor as StyleCursor;
+    /// Custom image cursor, parsed from `cursor: url("image-id") x y, fallback`. The image ID refers to an image in the `ImageCache`, the hotspot is given in pixels relative to the top left corner of the image.
+    
+    #[doc(inline)] pub use crate::dll::AzStyleCursorImage as StyleCursorImage;
     /// `StyleFontFamily` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleFontFamily as StyleFontFamily;
@@ -14687,6 +15469,15 @@ pub mod css {
     /// `ScrollbarStyleValue` struct
     
     #[doc(inline)] pub use crate::dll::AzScrollbarStyleValue as ScrollbarStyleValue;
+    /// `StyleScrollBehaviorValue` struct
+    
+    #[doc(inline)] pub use crate::dll::AzStyleScrollBehaviorValue as StyleScrollBehaviorValue;
+    /// `StyleScrollSnapTypeValue` struct
+    
+    #[doc(inline)] pub use crate::dll::AzStyleScrollSnapTypeValue as StyleScrollSnapTypeValue;
+    /// `StyleScrollSnapAlignValue` struct
+    
+    #[doc(inline)] pub use crate::dll::AzStyleScrollSnapAlignValue as StyleScrollSnapAlignValue;
     /// `StyleBackgroundContentVecValue` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleBackgroundContentVecValue as StyleBackgroundContentVecValue;
@@ -19181,6 +19972,8 @@ pub mod vec {
     impl_vec_clone!(AzMonitor, AzMonitorVec, AzMonitorVecDestructor);
     impl_vec!(AzStyleFontFamily, AzStyleFontFamilyVec, AzStyleFontFamilyVecDestructor, az_style_font_family_vec_destructor, AzStyleFontFamilyVec_delete);
     impl_vec_clone!(AzStyleFontFamily, AzStyleFontFamilyVec, AzStyleFontFamilyVecDestructor);
+    impl_vec!(AzStyleCursor, AzStyleCursorVec, AzStyleCursorVecDestructor, az_style_cursor_vec_destructor, AzStyleCursorVec_delete);
+    impl_vec_clone!(AzStyleCursor, AzStyleCursorVec, AzStyleCursorVecDestructor);
     impl_vec!(AzNodeTypeIdInfoMap, AzNodeTypeIdInfoMapVec, AzNodeTypeIdInfoMapVecDestructor, az_node_type_id_info_map_vec_destructor, AzNodeTypeIdInfoMapVec_delete);
     impl_vec_clone!(AzNodeTypeIdInfoMap, AzNodeTypeIdInfoMapVec, AzNodeTypeIdInfoMapVecDestructor);
     impl_vec!(AzInputOutputTypeIdInfoMap, AzInputOutputTypeIdInfoMapVec, AzInputOutputTypeIdInfoMapVecDestructor, az_input_output_type_id_info_map_vec_destructor, AzInputOutputTypeIdInfoMapVec_delete);
@@ -19210,6 +20003,14 @@ pub mod vec {
     impl_vec_clone!(AzAccessibilityState,  AzAccessibilityStateVec,  AzAccessibilityStateVecDestructor);
     impl_vec!(AzMenuItem,  AzMenuItemVec,  AzMenuItemVecDestructor, az_menu_item_vec_destructor, AzMenuItemVec_delete);
     impl_vec_clone!(AzMenuItem,  AzMenuItemVec,  AzMenuItemVecDestructor);
+    impl_vec!(AzNotificationAction, AzNotificationActionVec, AzNotificationActionVecDestructor, az_notification_action_vec_destructor, AzNotificationActionVec_delete);
+    impl_vec_clone!(AzNotificationAction, AzNotificationActionVec, AzNotificationActionVecDestructor);
+    impl_vec!(AzTouchPoint, AzTouchPointVec, AzTouchPointVecDestructor, az_touch_point_vec_destructor, AzTouchPointVec_delete);
+    impl_vec_clone!(AzTouchPoint, AzTouchPointVec, AzTouchPointVecDestructor);
+    impl_vec!(AzVirtualKeyCodeCombo, AzVirtualKeyCodeComboVec, AzVirtualKeyCodeComboVecDestructor, az_virtual_key_code_combo_vec_destructor, AzVirtualKeyCodeComboVec_delete);
+    impl_vec_clone!(AzVirtualKeyCodeCombo, AzVirtualKeyCodeComboVec, AzVirtualKeyCodeComboVecDestructor);
+    impl_vec!(AzShortcutBinding, AzShortcutBindingVec, AzShortcutBindingVecDestructor, az_shortcut_binding_vec_destructor, AzShortcutBindingVec_delete);
+    impl_vec_clone!(AzShortcutBinding, AzShortcutBindingVec, AzShortcutBindingVecDestructor);
     impl_vec!(AzSvgSimpleNode,  AzSvgSimpleNodeVec,  AzSvgSimpleNodeVecDestructor, az_svg_simple_node_vec_destructor, AzSvgSimpleNodeVec_delete);
     impl_vec_clone!(AzSvgSimpleNode,  AzSvgSimpleNodeVec,  AzSvgSimpleNodeVecDestructor);
     impl_vec!(AzSvgColoredVertex, AzSvgColoredVertexVec, AzSvgColoredVertexVecDestructor, az_svg_colored_vertex_vec_destructor, AzSvgColoredVertexVec_delete);
@@ -19281,6 +20082,18 @@ pub mod vec {
     /// Wrapper over a Rust-allocated `Vec<AccessibilityState>`
     
     #[doc(inline)] pub use crate::dll::AzAccessibilityStateVec as AccessibilityStateVec;
+    /// Wrapper over a Rust-allocated `Vec<VirtualKeyCodeCombo>`
+    
+    #[doc(inline)] pub use crate::dll::AzVirtualKeyCodeComboVec as VirtualKeyCodeComboVec;
+    /// Wrapper over a Rust-allocated `Vec<ShortcutBinding>`
+    
+    #[doc(inline)] pub use crate::dll::AzShortcutBindingVec as ShortcutBindingVec;
+    /// Wrapper over a Rust-allocated `Vec<TouchPoint>`
+    
+    #[doc(inline)] pub use crate::dll::AzTouchPointVec as TouchPointVec;
+    /// Wrapper over a Rust-allocated `Vec<NotificationAction>`
+    
+    #[doc(inline)] pub use crate::dll::AzNotificationActionVec as NotificationActionVec;
     /// Wrapper over a Rust-allocated `Vec<MenuItem>`
     
     #[doc(inline)] pub use crate::dll::AzMenuItemVec as MenuItemVec;
@@ -19302,6 +20115,9 @@ pub mod vec {
         pub fn as_ref_vec(&self)  -> crate::svg::TessellatedColoredSvgNodeVecRef { unsafe { crate::dll::AzTessellatedColoredSvgNodeVec_asRefVec(self) } }
     }
 
+    /// Wrapper over a Rust-allocated `Vec<StyleCursor>`
+    
+    #[doc(inline)] pub use crate::dll::AzStyleCursorVec as StyleCursorVec;
     /// Wrapper over a Rust-allocated `Vec<StyleFontFamily>`
     
     #[doc(inline)] pub use crate::dll::AzStyleFontFamilyVec as StyleFontFamilyVec;
@@ -19463,6 +20279,12 @@ pub mod vec {
     /// Wrapper over a Rust-allocated `NodeDataVec`
     
     #[doc(inline)] pub use crate::dll::AzNodeDataVec as NodeDataVec;
+    /// `StyleCursorVecDestructor` struct
+    
+    #[doc(inline)] pub use crate::dll::AzStyleCursorVecDestructor as StyleCursorVecDestructor;
+    /// `StyleCursorVecDestructorType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzStyleCursorVecDestructorType as StyleCursorVecDestructorType;
     /// `StyleFontFamilyVecDestructor` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleFontFamilyVecDestructor as StyleFontFamilyVecDestructor;
@@ -19547,6 +20369,30 @@ pub mod vec {
     /// `AccessibilityStateVecDestructorType` struct
     
     #[doc(inline)] pub use crate::dll::AzAccessibilityStateVecDestructorType as AccessibilityStateVecDestructorType;
+    /// `VirtualKeyCodeComboVecDestructor` struct
+    
+    #[doc(inline)] pub use crate::dll::AzVirtualKeyCodeComboVecDestructor as VirtualKeyCodeComboVecDestructor;
+    /// `VirtualKeyCodeComboVecDestructorType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzVirtualKeyCodeComboVecDestructorType as VirtualKeyCodeComboVecDestructorType;
+    /// `ShortcutBindingVecDestructor` struct
+    
+    #[doc(inline)] pub use crate::dll::AzShortcutBindingVecDestructor as ShortcutBindingVecDestructor;
+    /// `ShortcutBindingVecDestructorType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzShortcutBindingVecDestructorType as ShortcutBindingVecDestructorType;
+    /// `TouchPointVecDestructor` struct
+    
+    #[doc(inline)] pub use crate::dll::AzTouchPointVecDestructor as TouchPointVecDestructor;
+    /// `TouchPointVecDestructorType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzTouchPointVecDestructorType as TouchPointVecDestructorType;
+    /// `NotificationActionVecDestructor` struct
+    
+    #[doc(inline)] pub use crate::dll::AzNotificationActionVecDestructor as NotificationActionVecDestructor;
+    /// `NotificationActionVecDestructorType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzNotificationActionVecDestructorType as NotificationActionVecDestructorType;
     /// `MenuItemVecDestructor` struct
     
     #[doc(inline)] pub use crate::dll::AzMenuItemVecDestructor as MenuItemVecDestructor;
@@ -20174,6 +21020,15 @@ pub mod option {
     /// `OptionImageRef` struct
     
     #[doc(inline)] pub use crate::dll::AzOptionImageRef as OptionImageRef;
+    /// `OptionWindowId` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionWindowId as OptionWindowId;
+    /// `OptionWindowResultCallback` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionWindowResultCallback as OptionWindowResultCallback;
+    /// `OptionCustomCursor` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionCustomCursor as OptionCustomCursor;
     /// `OptionFontRef` struct
     
     #[doc(inline)] pub use crate::dll::AzOptionFontRef as OptionFontRef;
@@ -20225,6 +21080,9 @@ pub mod option {
     /// `OptionRefAny` struct
     
     #[doc(inline)] pub use crate::dll::AzOptionRefAny as OptionRefAny;
+    /// `OptionScrollPosition` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionScrollPosition as OptionScrollPosition;
     /// `OptionInlineText` struct
     
     #[doc(inline)] pub use crate::dll::AzOptionInlineText as OptionInlineText;
@@ -20261,12 +21119,18 @@ pub mod option {
     /// `OptionHwndHandle` struct
     
     #[doc(inline)] pub use crate::dll::AzOptionHwndHandle as OptionHwndHandle;
+    /// `OptionGestureState` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionGestureState as OptionGestureState;
     /// `OptionLogicalPosition` struct
     
     #[doc(inline)] pub use crate::dll::AzOptionLogicalPosition as OptionLogicalPosition;
     /// `OptionPhysicalPositionI32` struct
     
     #[doc(inline)] pub use crate::dll::AzOptionPhysicalPositionI32 as OptionPhysicalPositionI32;
+    /// `OptionTrayIcon` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionTrayIcon as OptionTrayIcon;
     /// `OptionWindowIcon` struct
     
     #[doc(inline)] pub use crate::dll::AzOptionWindowIcon as OptionWindowIcon;
@@ -20343,6 +21207,9 @@ pub mod error {
     /// `ResultSvgXmlNodeSvgParseError` struct
     
     #[doc(inline)] pub use crate::dll::AzResultSvgXmlNodeSvgParseError as ResultSvgXmlNodeSvgParseError;
+    /// `ResultShortcutIdShortcutError` struct
+    
+    #[doc(inline)] pub use crate::dll::AzResultShortcutIdShortcutError as ResultShortcutIdShortcutError;
     /// `ResultSvgSvgParseError` struct
     
     #[doc(inline)] pub use crate::dll::AzResultSvgSvgParseError as ResultSvgSvgParseError;
@@ -58,6 +58,9 @@ pub struct AppConfig {
     /// (STUB) Whether keyboard navigation should be enabled (default: true).
     /// Currently not implemented.
     pub enable_tab_navigation: bool,
+    /// Whether pressing F12 opens the DOM / layout inspector
+    /// for the focused window (default: false).
+    pub enable_inspector: bool,
     /// External callbacks to create a thread or get the curent time
     pub system_callbacks: ExternalSystemCallbacks,
 }
@@ -70,6 +73,7 @@ impl AppConfig {
             enable_visual_panic_hook: true,
             enable_logging_on_panic: true,
             enable_tab_navigation: true,
+            enable_inspector: false,
             system_callbacks: ExternalSystemCallbacks::rust_internal(),
         }
     }
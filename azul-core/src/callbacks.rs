@@ -7,6 +7,7 @@ use crate::{
         RendererResources, ShapedWords, WordPositions, Words,
     },
     id_tree::{NodeDataContainer, NodeId},
+    scroll::ScrollIntoViewAlign,
     styled_dom::{CssPropertyCache, StyledDom, StyledNode},
     styled_dom::{DomId, NodeHierarchyItemId, NodeHierarchyItemVec, StyledNodeVec},
     task::{
@@ -20,9 +21,11 @@ use crate::{
     },
     window::{AzStringPair, OptionLogicalPosition},
     window::{
-        FullWindowState, KeyboardState, LogicalPosition, LogicalRect, LogicalSize, MouseState,
-        OptionChar, PhysicalSize, RawWindowHandle, UpdateFocusWarning, WindowCreateOptions,
-        WindowFlags, WindowSize, WindowState, WindowTheme,
+        CustomCursor, DragData, FullWindowState, GestureState, ImePosition, KeyboardState,
+        LogicalPosition, LogicalRect, LogicalSize, MouseState, Notification, OptionChar,
+        PhysicalSize, RawWindowHandle, Shortcut, ShortcutChange, ShortcutId, TextComposition,
+        TouchState, UpdateFocusWarning, WindowCreateOptions, WindowFlags, WindowId, WindowSize,
+        WindowState, WindowTheme,
     },
     FastBTreeSet, FastHashMap,
 };
@@ -536,14 +539,24 @@ pub type PipelineSourceId = u32;
 
 /// Information about a scroll frame, given to the user by the framework
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
 pub struct ScrollPosition {
     /// How big is the parent container
     /// (so that things like "scroll to left edge" can be implemented)?
     pub parent_rect: LogicalRect,
     /// How big is the scroll rect (i.e. the union of all children)?
     pub children_rect: LogicalRect,
+    /// How far the node is currently scrolled
+    pub scroll_offset: LogicalPosition,
 }
 
+impl_option!(
+    ScrollPosition,
+    OptionScrollPosition,
+    copy = false,
+    [Debug, Clone, PartialEq, PartialOrd]
+);
+
 #[derive(Copy, Clone, Eq, Hash, PartialEq, PartialOrd, Ord)]
 pub struct DocumentId {
     pub namespace_id: IdNamespace,
@@ -1147,6 +1160,14 @@ pub struct CallbackInfo {
     current_window_handle: *const RawWindowHandle,
     /// Used to spawn new windows from callbacks. You can use `get_current_window_handle()` to spawn child windows.
     new_windows: *mut Vec<WindowCreateOptions>,
+    /// Desktop notifications requested by the callback, shown by the platform shell
+    notifications: *mut Vec<Notification>,
+    /// Keyboard shortcuts registered / unregistered by the callback
+    shortcuts_changed: *mut Vec<ShortcutChange>,
+    /// Windows closed by the callback
+    windows_closed: *mut Vec<WindowId>,
+    /// Result of the current window for its parent window
+    window_result: *mut Option<RefAny>,
     /// Callbacks for creating threads and getting the system time (since this crate uses no_std)
     system_callbacks: *const ExternalSystemCallbacks,
     /// Sets whether the event should be propagated to the parent hit node or not
@@ -1154,6 +1175,8 @@ pub struct CallbackInfo {
     /// The callback can change the focus_target - note that the focus_target is set before the
     /// next frames' layout() function is invoked, but the current frames callbacks are not affected.
     focus_target: *mut Option<FocusTarget>,
+    /// The callback can set or cancel the data of the current drag-and-drop operation
+    drag_data: *mut Option<Option<DragData>>,
     /// Mutable reference to a list of words / text items that were changed in the callback
     words_changed_in_callbacks: *mut BTreeMap<DomId, BTreeMap<NodeId, AzString>>,
     /// Mutable reference to a list of images that were changed in the callback
@@ -1202,9 +1225,14 @@ impl CallbackInfo {
         threads_removed: &'a mut FastBTreeSet<ThreadId>,
         current_window_handle: &'a RawWindowHandle,
         new_windows: &'a mut Vec<WindowCreateOptions>,
+        notifications: &'a mut Vec<Notification>,
+        shortcuts_changed: &'a mut Vec<ShortcutChange>,
+        windows_closed: &'a mut Vec<WindowId>,
+        window_result: &'a mut Option<RefAny>,
         system_callbacks: &'a ExternalSystemCallbacks,
         stop_propagation: &'a mut bool,
         focus_target: &'a mut Option<FocusTarget>,
+        drag_data: &'a mut Option<Option<DragData>>,
         words_changed_in_callbacks: &'a mut BTreeMap<DomId, BTreeMap<NodeId, AzString>>,
         images_changed_in_callbacks: &'a mut BTreeMap<
             DomId,
@@ -1239,10 +1267,15 @@ impl CallbackInfo {
             timers_removed: timers_removed as *mut FastBTreeSet<TimerId>,
             threads_removed: threads_removed as *mut FastBTreeSet<ThreadId>,
             new_windows: new_windows as *mut Vec<WindowCreateOptions>,
+            notifications: notifications as *mut Vec<Notification>,
+            shortcuts_changed: shortcuts_changed as *mut Vec<ShortcutChange>,
+            windows_closed: windows_closed as *mut Vec<WindowId>,
+            window_result: window_result as *mut Option<RefAny>,
             current_window_handle: current_window_handle as *const RawWindowHandle,
             system_callbacks: system_callbacks as *const ExternalSystemCallbacks,
             stop_propagation: stop_propagation as *mut bool,
             focus_target: focus_target as *mut Option<FocusTarget>,
+            drag_data: drag_data as *mut Option<Option<DragData>>,
             words_changed_in_callbacks: words_changed_in_callbacks
                 as *mut BTreeMap<DomId, BTreeMap<NodeId, AzString>>,
             images_changed_in_callbacks: images_changed_in_callbacks
@@ -1263,7 +1296,7 @@ impl CallbackInfo {
         }
     }
 
-    fn internal_get_layout_results<'a>(&'a self) -> &'a [LayoutResult] {
+    pub(crate) fn internal_get_layout_results<'a>(&'a self) -> &'a [LayoutResult] {
         unsafe { core::slice::from_raw_parts(self.layout_results, self.layout_results_count) }
     }
     fn internal_get_renderer_resources<'a>(&'a self) -> &'a RendererResources {
@@ -1272,7 +1305,7 @@ impl CallbackInfo {
     fn internal_get_previous_window_state<'a>(&'a self) -> &'a Option<FullWindowState> {
         unsafe { &*self.previous_window_state }
     }
-    fn internal_get_current_window_state<'a>(&'a self) -> &'a FullWindowState {
+    pub(crate) fn internal_get_current_window_state<'a>(&'a self) -> &'a FullWindowState {
         unsafe { &*self.current_window_state }
     }
     fn internal_get_modifiable_window_state<'a>(&'a mut self) -> &'a mut WindowState {
@@ -1305,6 +1338,18 @@ impl CallbackInfo {
     fn internal_get_new_windows<'a>(&'a mut self) -> &'a mut Vec<WindowCreateOptions> {
         unsafe { &mut *self.new_windows }
     }
+    fn internal_get_notifications<'a>(&'a mut self) -> &'a mut Vec<Notification> {
+        unsafe { &mut *self.notifications }
+    }
+    fn internal_get_shortcuts_changed<'a>(&'a mut self) -> &'a mut Vec<ShortcutChange> {
+        unsafe { &mut *self.shortcuts_changed }
+    }
+    fn internal_get_windows_closed<'a>(&'a mut self) -> &'a mut Vec<WindowId> {
+        unsafe { &mut *self.windows_closed }
+    }
+    fn internal_get_window_result<'a>(&'a mut self) -> &'a mut Option<RefAny> {
+        unsafe { &mut *self.window_result }
+    }
     fn internal_get_current_window_handle<'a>(&'a self) -> &'a RawWindowHandle {
         unsafe { &*self.current_window_handle }
     }
@@ -1317,6 +1362,9 @@ impl CallbackInfo {
     fn internal_get_focus_target<'a>(&'a mut self) -> &'a mut Option<FocusTarget> {
         unsafe { &mut *self.focus_target }
     }
+    fn internal_get_drag_data<'a>(&'a mut self) -> &'a mut Option<Option<DragData>> {
+        unsafe { &mut *self.drag_data }
+    }
     fn internal_get_current_scroll_states<'a>(
         &'a self,
     ) -> &'a BTreeMap<DomId, BTreeMap<NodeHierarchyItemId, ScrollPosition>> {
@@ -1429,12 +1477,17 @@ impl CallbackInfo {
         self.internal_get_current_scroll_states()
             .get(&node_id.dom)?
             .get(&node_id.node)
-            .map(|sp| {
-                LogicalPosition::new(
-                    sp.children_rect.origin.x - sp.parent_rect.origin.x,
-                    sp.children_rect.origin.y - sp.parent_rect.origin.y,
-                )
-            })
+            .map(|sp| sp.scroll_offset)
+    }
+
+    /// Returns the size of the scroll container, the size of its content and the
+    /// scroll offset of the node, i.e. to check in an `On::Scrolled` callback
+    /// whether the end of a lazily loaded list was reached
+    pub fn get_scroll_frame(&self, node_id: DomNodeId) -> Option<ScrollPosition> {
+        self.internal_get_current_scroll_states()
+            .get(&node_id.dom)?
+            .get(&node_id.node)
+            .cloned()
     }
 
     pub fn set_scroll_position(&mut self, node_id: DomNodeId, scroll_position: LogicalPosition) {
@@ -1444,6 +1497,31 @@ impl CallbackInfo {
             .insert(node_id.node, scroll_position);
     }
 
+    /// Scrolls the node to the given position: animated if the node has
+    /// `scroll-behavior: smooth`, otherwise immediately
+    pub fn scroll_to(&mut self, node_id: DomNodeId, scroll_position: LogicalPosition) {
+        if !crate::scroll::smooth_scroll_to(self, node_id, scroll_position) {
+            self.set_scroll_position(node_id, scroll_position);
+        }
+    }
+
+    /// Scrolls all scroll containers that the node is in (from the innermost to the
+    /// outermost one) so that the node is visible and aligned according to `align`
+    pub fn scroll_into_view(
+        &mut self,
+        node_id: DomNodeId,
+        align: ScrollIntoViewAlign,
+        smooth: bool,
+    ) {
+        for (scroll_node_id, scroll_position) in
+            crate::scroll::get_scroll_into_view_positions(self, node_id, align)
+        {
+            if !smooth || !crate::scroll::animate_scroll_to(self, scroll_node_id, scroll_position) {
+                self.set_scroll_position(scroll_node_id, scroll_position);
+            }
+        }
+    }
+
     pub fn get_parent(&self, node_id: DomNodeId) -> Option<DomNodeId> {
         let nid = node_id.node.into_crate_internal()?;
         self.internal_get_layout_results()
@@ -1588,6 +1666,80 @@ impl CallbackInfo {
         *self.internal_get_focus_target() = Some(target);
     }
 
+    /// Returns the data of the drag that is currently hovering over the window
+    /// or (in an `On::Drop` callback) the data that was just dropped
+    pub fn get_drag_data(&self) -> Option<DragData> {
+        let drag_state = &self.internal_get_current_window_state().drag_state;
+        drag_state
+            .current
+            .as_ref()
+            .or(drag_state.dropped.as_ref())
+            .cloned()
+    }
+
+    /// Sets the payload, MIME data and drag image of the current drag,
+    /// usually called from an `On::DragStart` callback
+    pub fn set_drag_data(&mut self, drag_data: DragData) {
+        *self.internal_get_drag_data() = Some(Some(drag_data));
+    }
+
+    /// Cancels the current drag, no `On::Drop` event will be fired
+    pub fn cancel_drag(&mut self) {
+        *self.internal_get_drag_data() = Some(None);
+    }
+
+    /// Returns the current preedit string of the input method (IME),
+    /// `None` if the input method is not composing text
+    pub fn get_text_composition(&self) -> Option<TextComposition> {
+        self.internal_get_current_window_state()
+            .ime_state
+            .composition
+            .clone()
+    }
+
+    /// Returns the text that was committed by the input method (IME) in a
+    /// `TextInput` callback, `None` if the text input came from a regular key press
+    pub fn get_committed_text(&self) -> Option<AzString> {
+        self.internal_get_current_window_state()
+            .ime_state
+            .committed_text
+            .clone()
+    }
+
+    /// Returns the fingers and pens that touch the window (`On::TouchStart`,
+    /// `On::TouchMove`) and the ones that were just lifted (`On::TouchEnd`)
+    pub fn get_touch_state(&self) -> TouchState {
+        self.internal_get_current_window_state().touch_state.clone()
+    }
+
+    /// Returns the current two-finger gesture (`On::Pinch`, `On::Rotate`,
+    /// `On::TwoFingerPan`), `None` if less or more than two fingers touch the window
+    pub fn get_gesture(&self) -> Option<GestureState> {
+        self.internal_get_current_window_state()
+            .touch_state
+            .gesture
+            .into_option()
+    }
+
+    /// Places the candidate window of the input method (IME) below the given
+    /// text cursor rectangle (relative to the window)
+    pub fn set_ime_caret_rect(&mut self, caret_rect: LogicalRect) {
+        self.internal_get_modifiable_window_state().ime_position =
+            ImePosition::from_caret_rect(caret_rect);
+    }
+
+    /// Sets an image cursor for the entire window, overriding the CSS `cursor`
+    /// property of all nodes until `reset_custom_cursor()` is called
+    pub fn set_custom_cursor(&mut self, image: ImageRef, hotspot_x: u32, hotspot_y: u32) {
+        self.internal_get_modifiable_window_state().custom_cursor =
+            Some(CustomCursor::new(image, hotspot_x, hotspot_y)).into();
+    }
+
+    /// Removes the image cursor set by `set_custom_cursor()`
+    pub fn reset_custom_cursor(&mut self) {
+        self.internal_get_modifiable_window_state().custom_cursor = None.into();
+    }
+
     pub fn get_string_contents(&self, node_id: DomNodeId) -> Option<AzString> {
         self.internal_get_layout_results()
             .get(node_id.dom.inner)?
@@ -1695,8 +1847,49 @@ impl CallbackInfo {
         *self.internal_get_stop_propagation() = true;
     }
 
-    pub fn create_window(&mut self, window: WindowCreateOptions) {
+    /// Opens a new window once the callback returns, returns the ID of the new window.
+    ///
+    /// Use `WindowCreateOptions::parent` and `modal` to open a dialog (a modal child
+    /// window) that can return a result with `set_window_result`.
+    pub fn create_window(&mut self, mut window: WindowCreateOptions) -> WindowId {
+        // the options may have been cloned, so the window gets a new ID
+        window.id = WindowId::new();
+        let window_id = window.id;
         self.internal_get_new_windows().push(window);
+        window_id
+    }
+
+    /// Closes the window with the given ID (and its child windows) once the callback returns.
+    /// To close the current window, set `WindowFlags::is_about_to_close` instead.
+    pub fn close_window(&mut self, window_id: WindowId) {
+        self.internal_get_windows_closed().push(window_id);
+    }
+
+    /// Sets the result of the current window: once the window is closed, the result is
+    /// passed to the `WindowCreateOptions::result_callback` on the parent window
+    pub fn set_window_result(&mut self, result: RefAny) {
+        *self.internal_get_window_result() = Some(result);
+    }
+
+    /// Shows a desktop notification once the callback returns
+    pub fn show_notification(&mut self, notification: Notification) {
+        self.internal_get_notifications().push(notification);
+    }
+
+    /// Registers an application-wide keyboard shortcut once the callback returns and
+    /// returns its ID. If the shortcut conflicts with an already registered shortcut
+    /// (see `ShortcutRegistry::find_conflict`), it is not registered.
+    pub fn register_shortcut(&mut self, shortcut: Shortcut) -> ShortcutId {
+        let shortcut_id = ShortcutId::new();
+        self.internal_get_shortcuts_changed()
+            .push(ShortcutChange::Register(shortcut_id, shortcut));
+        shortcut_id
+    }
+
+    /// Removes the keyboard shortcut once the callback returns
+    pub fn unregister_shortcut(&mut self, shortcut_id: ShortcutId) {
+        self.internal_get_shortcuts_changed()
+            .push(ShortcutChange::Unregister(shortcut_id));
     }
 
     /// Starts a thread, returns Some(thread_id) if the `thread_initialize_data` is the only copy
@@ -1903,9 +2096,14 @@ impl Clone for CallbackInfo {
             threads_removed: self.threads_removed,
             current_window_handle: self.current_window_handle,
             new_windows: self.new_windows,
+            notifications: self.notifications,
+            shortcuts_changed: self.shortcuts_changed,
+            windows_closed: self.windows_closed,
+            window_result: self.window_result,
             system_callbacks: self.system_callbacks,
             stop_propagation: self.stop_propagation,
             focus_target: self.focus_target,
+            drag_data: self.drag_data,
             words_changed_in_callbacks: self.words_changed_in_callbacks,
             images_changed_in_callbacks: self.images_changed_in_callbacks,
             image_masks_changed_in_callbacks: self.image_masks_changed_in_callbacks,
@@ -639,6 +639,18 @@ pub(crate) fn format_static_css_prop(prop: &CssProperty, tabs: usize) -> String
             "CssProperty::ScrollbarStyle({})",
             print_css_property_value(p, tabs, "ScrollbarStyle")
         ),
+        CssProperty::ScrollBehavior(p) => format!(
+            "CssProperty::ScrollBehavior({})",
+            print_css_property_value(p, tabs, "StyleScrollBehavior")
+        ),
+        CssProperty::ScrollSnapType(p) => format!(
+            "CssProperty::ScrollSnapType({})",
+            print_css_property_value(p, tabs, "StyleScrollSnapType")
+        ),
+        CssProperty::ScrollSnapAlign(p) => format!(
+            "CssProperty::ScrollSnapAlign({})",
+            print_css_property_value(p, tabs, "StyleScrollSnapAlign")
+        ),
         CssProperty::Opacity(p) => format!(
             "CssProperty::Opacity({})",
             print_css_property_value(p, tabs, "StyleOpacity")
@@ -896,39 +908,24 @@ impl_enum_fmt!(
     Luminosity
 );
 
-impl_enum_fmt!(
-    StyleCursor,
-    Alias,
-    AllScroll,
-    Cell,
-    ColResize,
-    ContextMenu,
-    Copy,
-    Crosshair,
-    Default,
-    EResize,
-    EwResize,
-    Grab,
-    Grabbing,
-    Help,
-    Move,
-    NResize,
-    NsResize,
-    NeswResize,
-    NwseResize,
-    Pointer,
-    Progress,
-    RowResize,
-    SResize,
-    SeResize,
-    Text,
-    Unset,
-    VerticalText,
-    WResize,
-    Wait,
-    ZoomIn,
-    ZoomOut
-);
+impl FormatAsRustCode for StyleCursor {
+    fn format_as_rust_code(&self, tabs: usize) -> String {
+        match self {
+            StyleCursor::Image(i) => format!(
+                "StyleCursor::Image(StyleCursorImage {{ image: {:?}, hotspot_x: {}, hotspot_y: {}, fallback: StyleCursorVec::from_const_slice(&[{}]) }})",
+                i.image,
+                i.hotspot_x,
+                i.hotspot_y,
+                i.fallback
+                    .iter()
+                    .map(|c| c.format_as_rust_code(tabs))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            keyword => format!("StyleCursor::{:?}", keyword),
+        }
+    }
+}
 
 impl_enum_fmt!(
     BorderStyle,
@@ -1073,6 +1070,14 @@ impl_enum_fmt!(ExtendMode, Clamp, Repeat);
 
 impl_enum_fmt!(StyleBackfaceVisibility, Visible, Hidden);
 
+impl_enum_fmt!(StyleScrollBehavior, Auto, Smooth);
+
+impl_enum_fmt!(ScrollSnapAxis, None, X, Y, Both);
+
+impl_enum_fmt!(ScrollSnapStrictness, Proximity, Mandatory);
+
+impl_enum_fmt!(StyleScrollSnapAlign, None, Start, Center, End);
+
 impl FormatAsRustCode for StyleBackgroundContentVec {
     fn format_as_rust_code(&self, tabs: usize) -> String {
         format!(
@@ -1443,6 +1448,16 @@ impl FormatAsRustCode for StyleTransformOrigin {
     }
 }
 
+impl FormatAsRustCode for StyleScrollSnapType {
+    fn format_as_rust_code(&self, tabs: usize) -> String {
+        format!(
+            "StyleScrollSnapType {{ axis: {}, strictness: {} }}",
+            self.axis.format_as_rust_code(tabs),
+            self.strictness.format_as_rust_code(tabs)
+        )
+    }
+}
+
 impl FormatAsRustCode for StylePerspectiveOrigin {
     fn format_as_rust_code(&self, _tabs: usize) -> String {
         format!(
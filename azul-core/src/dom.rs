@@ -7,7 +7,7 @@ use crate::{
         CssPropertyCache, CssPropertyCachePtr, StyleFontFamilyHash, StyledNode, StyledNodeState,
     },
     styled_dom::{NodeHierarchyItemId, StyledDom},
-    window::{Menu, OptionVirtualKeyCodeCombo},
+    window::{Menu, OptionVirtualKeyCodeCombo, WindowRegion},
 };
 use alloc::boxed::Box;
 use alloc::collections::btree_map::BTreeMap;
@@ -208,6 +208,45 @@ pub enum On {
     FocusReceived,
     /// Equivalent to `onblur`
     FocusLost,
+    /// A drag was started on this (`draggable`) element
+    DragStart,
+    /// A drag has entered the element
+    DragEnter,
+    /// A drag is moving over the element
+    DragOver,
+    /// A drag has left the element without being dropped
+    DragLeave,
+    /// A drag was dropped on the element
+    Drop,
+    /// The input method (IME) started composing text in the focused element
+    TextCompositionStart,
+    /// The preedit string of the input method changed. Check
+    /// `CallbackInfo::get_text_composition()` to get the current preedit string
+    TextCompositionUpdate,
+    /// The input method finished (or cancelled) the composition, the
+    /// committed text is delivered as a separate `TextInput` event
+    TextCompositionEnd,
+    /// A finger or pen started touching the element. Check
+    /// `CallbackInfo::get_touch_state()` to get the touch points
+    TouchStart,
+    /// A finger or pen moved over the element (or its pressure / tilt changed)
+    TouchMove,
+    /// A finger or pen was lifted from the element
+    TouchEnd,
+    /// A touch was cancelled, i.e. because the window manager took over the touch
+    TouchCancel,
+    /// Two fingers moved apart / together on the element. Check
+    /// `CallbackInfo::get_gesture()` to get the scale
+    Pinch,
+    /// Two fingers rotated on the element, see `CallbackInfo::get_gesture()`
+    Rotate,
+    /// Two fingers moved in the same direction on the element, see `CallbackInfo::get_gesture()`
+    TwoFingerPan,
+    /// A finger was held down on the element without moving
+    LongPress,
+    /// The scroll offset of the (scrollable) element changed, either by the user or
+    /// programmatically. Check `CallbackInfo::get_scroll_position()` for the new position
+    Scrolled,
 }
 
 /// Sets the target for what events can reach the callbacks specifically.
@@ -244,7 +283,8 @@ pub enum EventFilter {
     /// (i.e. global gestures that aren't attached to any component, but rather
     /// the "window" itself).
     Window(WindowEventFilter),
-    /// API stub: Something happened with the node itself (node resized, created or removed)
+    /// Something happened with the node itself (node resized, created, removed or scrolled).
+    /// Only `ComponentEventFilter::Scrolled` is currently dispatched
     Component(ComponentEventFilter),
     /// Something happened with the application (started, shutdown, device plugged in)
     Application(ApplicationEventFilter),
@@ -327,6 +367,23 @@ impl From<On> for EventFilter {
             HoveredFileCancelled => EventFilter::Hover(HoverEventFilter::HoveredFileCancelled),
             FocusReceived => EventFilter::Focus(FocusEventFilter::FocusReceived), // focus!
             FocusLost => EventFilter::Focus(FocusEventFilter::FocusLost),         // focus!
+            DragStart => EventFilter::Hover(HoverEventFilter::DragStart),
+            DragEnter => EventFilter::Hover(HoverEventFilter::DragEnter),
+            DragOver => EventFilter::Hover(HoverEventFilter::DragOver),
+            DragLeave => EventFilter::Hover(HoverEventFilter::DragLeave),
+            Drop => EventFilter::Hover(HoverEventFilter::Drop),
+            TextCompositionStart => EventFilter::Focus(FocusEventFilter::TextCompositionStart), // focus!
+            TextCompositionUpdate => EventFilter::Focus(FocusEventFilter::TextCompositionUpdate), // focus!
+            TextCompositionEnd => EventFilter::Focus(FocusEventFilter::TextCompositionEnd), // focus!
+            TouchStart => EventFilter::Hover(HoverEventFilter::TouchStart),
+            TouchMove => EventFilter::Hover(HoverEventFilter::TouchMove),
+            TouchEnd => EventFilter::Hover(HoverEventFilter::TouchEnd),
+            TouchCancel => EventFilter::Hover(HoverEventFilter::TouchCancel),
+            Pinch => EventFilter::Hover(HoverEventFilter::Pinch),
+            Rotate => EventFilter::Hover(HoverEventFilter::Rotate),
+            TwoFingerPan => EventFilter::Hover(HoverEventFilter::TwoFingerPan),
+            LongPress => EventFilter::Hover(HoverEventFilter::LongPress),
+            Scrolled => EventFilter::Component(ComponentEventFilter::Scrolled),
         }
     }
 }
@@ -359,6 +416,18 @@ pub enum HoverEventFilter {
     TouchMove,
     TouchEnd,
     TouchCancel,
+    Pinch,
+    Rotate,
+    TwoFingerPan,
+    LongPress,
+    DragStart,
+    DragEnter,
+    DragOver,
+    DragLeave,
+    Drop,
+    TextCompositionStart,
+    TextCompositionUpdate,
+    TextCompositionEnd,
 }
 
 impl HoverEventFilter {
@@ -388,6 +457,20 @@ impl HoverEventFilter {
             HoverEventFilter::TouchMove => None,
             HoverEventFilter::TouchEnd => None,
             HoverEventFilter::TouchCancel => None,
+            HoverEventFilter::Pinch => None,
+            HoverEventFilter::Rotate => None,
+            HoverEventFilter::TwoFingerPan => None,
+            HoverEventFilter::LongPress => None,
+            HoverEventFilter::DragStart => None,
+            HoverEventFilter::DragEnter => None,
+            HoverEventFilter::DragOver => None,
+            HoverEventFilter::DragLeave => None,
+            HoverEventFilter::Drop => None,
+            HoverEventFilter::TextCompositionStart => Some(FocusEventFilter::TextCompositionStart),
+            HoverEventFilter::TextCompositionUpdate => {
+                Some(FocusEventFilter::TextCompositionUpdate)
+            }
+            HoverEventFilter::TextCompositionEnd => Some(FocusEventFilter::TextCompositionEnd),
         }
     }
 }
@@ -437,6 +520,9 @@ pub enum FocusEventFilter {
     VirtualKeyUp,
     FocusReceived,
     FocusLost,
+    TextCompositionStart,
+    TextCompositionUpdate,
+    TextCompositionEnd,
 }
 
 /// Event filter that fires when any action fires on the entire window
@@ -470,12 +556,26 @@ pub enum WindowEventFilter {
     TouchMove,
     TouchEnd,
     TouchCancel,
+    Pinch,
+    Rotate,
+    TwoFingerPan,
+    LongPress,
     FocusReceived,
     FocusLost,
     CloseRequested,
     ThemeChanged,
     WindowFocusReceived,
     WindowFocusLost,
+    ClipboardChanged,
+    DragStart,
+    DragEnter,
+    DragOver,
+    DragLeave,
+    Drop,
+    DragEnd,
+    TextCompositionStart,
+    TextCompositionUpdate,
+    TextCompositionEnd,
 }
 
 impl WindowEventFilter {
@@ -509,12 +609,30 @@ impl WindowEventFilter {
             WindowEventFilter::TouchMove => Some(HoverEventFilter::TouchMove),
             WindowEventFilter::TouchEnd => Some(HoverEventFilter::TouchEnd),
             WindowEventFilter::TouchCancel => Some(HoverEventFilter::TouchCancel),
+            WindowEventFilter::Pinch => Some(HoverEventFilter::Pinch),
+            WindowEventFilter::Rotate => Some(HoverEventFilter::Rotate),
+            WindowEventFilter::TwoFingerPan => Some(HoverEventFilter::TwoFingerPan),
+            WindowEventFilter::LongPress => Some(HoverEventFilter::LongPress),
             WindowEventFilter::FocusReceived => None,
             WindowEventFilter::FocusLost => None,
             WindowEventFilter::CloseRequested => None,
             WindowEventFilter::ThemeChanged => None,
             WindowEventFilter::WindowFocusReceived => None, // specific to window!
             WindowEventFilter::WindowFocusLost => None,     // specific to window!
+            WindowEventFilter::ClipboardChanged => None,
+            WindowEventFilter::DragStart => Some(HoverEventFilter::DragStart),
+            // DragEnter and DragLeave on the **window** - the elements get their
+            // DragEnter / DragLeave events when the hovered nodes change
+            WindowEventFilter::DragEnter => None,
+            WindowEventFilter::DragLeave => None,
+            WindowEventFilter::DragOver => Some(HoverEventFilter::DragOver),
+            WindowEventFilter::Drop => Some(HoverEventFilter::Drop),
+            WindowEventFilter::DragEnd => None, // specific to window!
+            WindowEventFilter::TextCompositionStart => Some(HoverEventFilter::TextCompositionStart),
+            WindowEventFilter::TextCompositionUpdate => {
+                Some(HoverEventFilter::TextCompositionUpdate)
+            }
+            WindowEventFilter::TextCompositionEnd => Some(HoverEventFilter::TextCompositionEnd),
         }
     }
 }
@@ -527,6 +645,8 @@ pub enum ComponentEventFilter {
     NodeResized,
     DefaultAction,
     Selected,
+    /// The scroll offset of the node changed
+    Scrolled,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -781,6 +901,8 @@ impl Hash for NodeData {
             if let Some(c) = ext.context_menu.as_ref() {
                 c.hash(state);
             }
+            ext.is_draggable.hash(state);
+            ext.window_region.hash(state);
         }
     }
 }
@@ -798,6 +920,10 @@ pub struct NodeDataExt {
     pub(crate) menu_bar: Option<Box<Menu>>,
     /// Context menu that should be opened when the item is left-clicked
     pub(crate) context_menu: Option<Box<Menu>>,
+    /// Whether the node can be dragged with the mouse (see `On::DragStart`)
+    pub(crate) is_draggable: bool,
+    /// Whether the node moves or resizes the window when clicked (custom title bars)
+    pub(crate) window_region: Option<WindowRegion>,
     // ... insert further API extensions here...
 }
 
@@ -1102,7 +1228,20 @@ fn node_data_to_string(node_data: &NodeData) -> String {
         tabindex_string = format!(" tabindex=\"{}\" ", tab_index.get_index());
     };
 
-    format!("{}{}{}", id_string, class_string, tabindex_string)
+    let mut draggable_string = String::new();
+    if node_data.is_draggable() {
+        draggable_string = String::from(" draggable=\"true\" ");
+    }
+
+    let mut window_region_string = String::new();
+    if let Some(region) = node_data.get_window_region() {
+        window_region_string = format!(" window-region=\"{}\" ", region.as_keyword());
+    }
+
+    format!(
+        "{}{}{}{}{}",
+        id_string, class_string, tabindex_string, draggable_string, window_region_string
+    )
 }
 
 impl NodeData {
@@ -1185,6 +1324,14 @@ impl NodeData {
             .unwrap_or(false)
     }
 
+    pub fn is_draggable(&self) -> bool {
+        self.extra.as_ref().map(|m| m.is_draggable).unwrap_or(false)
+    }
+
+    pub fn get_window_region(&self) -> Option<WindowRegion> {
+        self.extra.as_ref().and_then(|m| m.window_region)
+    }
+
     pub fn is_text_node(&self) -> bool {
         match self.node_type {
             NodeType::Text(_) => true,
@@ -1289,12 +1436,34 @@ impl NodeData {
             .get_or_insert_with(|| Box::new(NodeDataExt::default()))
             .context_menu = Some(Box::new(context_menu));
     }
+    #[inline]
+    pub fn set_draggable(&mut self, draggable: bool) {
+        self.extra
+            .get_or_insert_with(|| Box::new(NodeDataExt::default()))
+            .is_draggable = draggable;
+    }
+    #[inline]
+    pub fn set_window_region(&mut self, region: WindowRegion) {
+        self.extra
+            .get_or_insert_with(|| Box::new(NodeDataExt::default()))
+            .window_region = Some(region);
+    }
 
     #[inline]
     pub fn with_context_menu(mut self, context_menu: Menu) -> Self {
         self.set_context_menu(context_menu);
         self
     }
+    #[inline]
+    pub fn with_draggable(mut self, draggable: bool) -> Self {
+        self.set_draggable(draggable);
+        self
+    }
+    #[inline]
+    pub fn with_window_region(mut self, region: WindowRegion) -> Self {
+        self.set_window_region(region);
+        self
+    }
 
     #[inline]
     pub fn add_callback(&mut self, event: EventFilter, data: RefAny, callback: CallbackType) {
@@ -1455,6 +1624,23 @@ impl NodeData {
         format!("<{} data-az-node-id=\"{}\" {} style=\"overflow:visible; box-sizing:border-box; margin: 0px; padding: 0px; display: flex; flex-direction: column;{}\">", html_type, node_id.index(), attributes_string, style)
     }
 
+    /// Same as `debug_print_start`, but appends the `layout_style` (the solved position
+    /// and size of the node) to the inline style. If no `css_cache` is given, the computed
+    /// style is left out, so that the node can be styled by the original stylesheet instead.
+    pub fn debug_print_start_with_layout(
+        &self,
+        css_cache: Option<(&CssPropertyCache, &StyledNodeState)>,
+        node_id: &NodeId,
+        layout_style: &str,
+    ) -> String {
+        let html_type = self.node_type.get_path();
+        let attributes_string = node_data_to_string(&self);
+        let style = css_cache
+            .map(|(css_cache, node_state)| css_cache.get_computed_css_style_string(&self, node_id, node_state))
+            .unwrap_or_default();
+        format!("<{} data-az-node-id=\"{}\" {} style=\"overflow:visible; box-sizing:border-box; margin: 0px;{} {}\">", html_type, node_id.index(), attributes_string, style, layout_style)
+    }
+
     pub fn debug_print_end(&self) -> String {
         let html_type = self.node_type.get_path();
         format!("</{}>", html_type)
@@ -1633,6 +1819,18 @@ impl Dom {
         self
     }
 
+    #[inline]
+    pub fn with_draggable(mut self, draggable: bool) -> Self {
+        self.root.set_draggable(draggable);
+        self
+    }
+
+    #[inline]
+    pub fn with_window_region(mut self, region: WindowRegion) -> Self {
+        self.root.set_window_region(region);
+        self
+    }
+
     fn fixup_children_estimated(&mut self) -> usize {
         if self.children.is_empty() {
             self.estimated_total_children = 0;
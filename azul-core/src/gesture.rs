@@ -0,0 +1,352 @@
+//! Recognition of touch gestures
+//!
+//! The platform shells feed the touch events of a window into a `GestureRecognizer`,
+//! which updates the `TouchState` of the window: the touch points, the two-finger
+//! gesture (pinch, rotate, pan) and the long-press. The `TouchStart`, `Pinch`,
+//! `LongPress`, ... events are then generated by comparing the previous and the
+//! current `TouchState` (see `window_state::get_window_events`).
+
+use crate::window::{
+    GestureState, LogicalPosition, OptionGestureState, OptionLogicalPosition, TouchPoint,
+    TouchPointKind, TouchPointVec, TouchState,
+};
+use alloc::vec::Vec;
+
+/// Time in milliseconds that a finger has to be held down without moving to be a long-press
+pub const LONG_PRESS_DURATION_MS: u64 = 500;
+/// Distance in logical pixels that a finger can move without cancelling the long-press
+pub const LONG_PRESS_MAX_DISTANCE: f32 = 10.0;
+/// Change of the distance between the fingers (relative) before a pinch is recognized
+pub const PINCH_MIN_SCALE: f32 = 0.05;
+/// Rotation in degrees before a rotation is recognized
+pub const ROTATE_MIN_DEGREES: f32 = 5.0;
+/// Movement of the center between the fingers in logical pixels before a pan is recognized
+pub const PAN_MIN_DISTANCE: f32 = 10.0;
+
+/// Touch input of a window
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum TouchEvent {
+    /// A finger / pen started touching the window
+    Down(TouchPoint),
+    /// A finger / pen moved or its pressure / tilt changed
+    Move(TouchPoint),
+    /// The finger / pen with the given ID was lifted
+    Up(u64),
+    /// The touch with the given ID was cancelled
+    Cancel(u64),
+}
+
+// Fingers and their positions at the start of a two-finger gesture
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct GestureStart {
+    ids: [u64; 2],
+    positions: [LogicalPosition; 2],
+}
+
+// Finger that may become a long-press, with the time it was pressed
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct LongPressCandidate {
+    id: u64,
+    position: LogicalPosition,
+    time_ms: u64,
+}
+
+/// Tracks the touch points of a window and recognizes gestures
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GestureRecognizer {
+    gesture_start: Option<GestureStart>,
+    long_press_candidate: Option<LongPressCandidate>,
+}
+
+impl GestureRecognizer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Applies the touch event to the touch state, `time_ms` is a monotonic
+    /// timestamp in milliseconds (used for the long-press)
+    pub fn handle_event(&mut self, state: &mut TouchState, event: TouchEvent, time_ms: u64) {
+        // the ended / cancelled touch points only belong to one event
+        state.ended_touch_points = TouchPointVec::from_const_slice(&[]);
+        state.cancelled_touch_points = TouchPointVec::from_const_slice(&[]);
+
+        let mut touch_points = state.touch_points.clone().into_library_owned_vec();
+
+        match event {
+            TouchEvent::Down(point) => {
+                touch_points.retain(|p| p.id != point.id);
+                touch_points.push(point);
+
+                self.long_press_candidate = match touch_points.as_slice() {
+                    [p] if p.kind == TouchPointKind::Finger => Some(LongPressCandidate {
+                        id: p.id,
+                        position: p.position,
+                        time_ms,
+                    }),
+                    _ => None,
+                };
+
+                self.gesture_start = match touch_points.as_slice() {
+                    [a, b]
+                        if a.kind == TouchPointKind::Finger && b.kind == TouchPointKind::Finger =>
+                    {
+                        Some(GestureStart {
+                            ids: [a.id, b.id],
+                            positions: [a.position, b.position],
+                        })
+                    }
+                    _ => None,
+                };
+                state.gesture = self
+                    .gesture_start
+                    .map(|s| GestureState {
+                        center: get_center(s.positions),
+                        scale: 1.0,
+                        ..Default::default()
+                    })
+                    .into();
+            }
+            TouchEvent::Move(point) => {
+                if let Some(p) = touch_points.iter_mut().find(|p| p.id == point.id) {
+                    *p = point;
+                }
+
+                if let Some(candidate) = self.long_press_candidate {
+                    if candidate.id == point.id
+                        && get_distance(candidate.position, point.position)
+                            > LONG_PRESS_MAX_DISTANCE
+                    {
+                        self.long_press_candidate = None;
+                    }
+                }
+
+                if let (Some(start), Some(gesture)) =
+                    (self.gesture_start, state.gesture.into_option())
+                {
+                    state.gesture = Some(update_gesture(start, gesture, &touch_points)).into();
+                }
+            }
+            TouchEvent::Up(id) | TouchEvent::Cancel(id) => {
+                let removed = touch_points
+                    .iter()
+                    .filter(|p| p.id == id)
+                    .copied()
+                    .collect::<Vec<_>>();
+                touch_points.retain(|p| p.id != id);
+
+                if let TouchEvent::Up(_) = event {
+                    state.ended_touch_points = removed.into();
+                } else {
+                    state.cancelled_touch_points = removed.into();
+                }
+
+                if self.long_press_candidate.map(|c| c.id) == Some(id) {
+                    self.long_press_candidate = None;
+                }
+                if touch_points.is_empty() {
+                    state.long_press = OptionLogicalPosition::None;
+                }
+                if self
+                    .gesture_start
+                    .map(|s| s.ids.contains(&id))
+                    .unwrap_or(false)
+                {
+                    self.gesture_start = None;
+                    state.gesture = OptionGestureState::None;
+                }
+            }
+        }
+
+        state.touch_points = touch_points.into();
+        self.check_long_press(state, time_ms);
+    }
+
+    /// Recognizes a long-press if the finger was held down long enough,
+    /// returns whether the touch state changed
+    pub fn check_long_press(&mut self, state: &mut TouchState, time_ms: u64) -> bool {
+        match self.long_press_candidate {
+            Some(c) if time_ms.saturating_sub(c.time_ms) >= LONG_PRESS_DURATION_MS => {
+                self.long_press_candidate = None;
+                state.long_press = Some(c.position).into();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the time in milliseconds until the long-press is recognized if
+    /// a finger is currently held down, so that the event loop can wake up
+    pub fn get_long_press_timeout(&self, time_ms: u64) -> Option<u64> {
+        let c = self.long_press_candidate?;
+        Some((c.time_ms + LONG_PRESS_DURATION_MS).saturating_sub(time_ms))
+    }
+}
+
+fn update_gesture(
+    start: GestureStart,
+    mut gesture: GestureState,
+    touch_points: &[TouchPoint],
+) -> GestureState {
+    let find = |id: u64| touch_points.iter().find(|p| p.id == id).map(|p| p.position);
+    let positions = match (find(start.ids[0]), find(start.ids[1])) {
+        (Some(a), Some(b)) => [a, b],
+        _ => return gesture,
+    };
+
+    let start_center = get_center(start.positions);
+    let start_distance = get_distance(start.positions[0], start.positions[1]);
+
+    gesture.center = get_center(positions);
+    gesture.translation = LogicalPosition::new(
+        gesture.center.x - start_center.x,
+        gesture.center.y - start_center.y,
+    );
+    gesture.scale = if start_distance > 0.0 {
+        get_distance(positions[0], positions[1]) / start_distance
+    } else {
+        1.0
+    };
+    gesture.rotation = normalize_degrees(get_angle(positions) - get_angle(start.positions));
+
+    // once recognized, the gestures stay active until a finger is lifted
+    gesture.is_pinch |= libm::fabsf(gesture.scale - 1.0) >= PINCH_MIN_SCALE;
+    gesture.is_rotate |= libm::fabsf(gesture.rotation) >= ROTATE_MIN_DEGREES;
+    gesture.is_pan |=
+        libm::hypotf(gesture.translation.x, gesture.translation.y) >= PAN_MIN_DISTANCE;
+    gesture
+}
+
+fn get_center(positions: [LogicalPosition; 2]) -> LogicalPosition {
+    LogicalPosition::new(
+        (positions[0].x + positions[1].x) / 2.0,
+        (positions[0].y + positions[1].y) / 2.0,
+    )
+}
+
+fn get_distance(a: LogicalPosition, b: LogicalPosition) -> f32 {
+    libm::hypotf(b.x - a.x, b.y - a.y)
+}
+
+// Angle of the line between the fingers in degrees (clockwise, since y points down)
+fn get_angle(positions: [LogicalPosition; 2]) -> f32 {
+    libm::atan2f(
+        positions[1].y - positions[0].y,
+        positions[1].x - positions[0].x,
+    )
+    .to_degrees()
+}
+
+// Maps the angle to -180.0 ..= 180.0
+fn normalize_degrees(degrees: f32) -> f32 {
+    let mut degrees = degrees % 360.0;
+    if degrees > 180.0 {
+        degrees -= 360.0;
+    } else if degrees < -180.0 {
+        degrees += 360.0;
+    }
+    degrees
+}
+
+#[test]
+fn test_gesture_recognizer() {
+    fn finger(id: u64, x: f32, y: f32) -> TouchPoint {
+        TouchPoint {
+            id,
+            kind: TouchPointKind::Finger,
+            position: LogicalPosition::new(x, y),
+            pressure: 1.0,
+            ..Default::default()
+        }
+    }
+
+    // recorded event stream: (time in ms, event)
+    let pinch_and_rotate = [
+        (0, TouchEvent::Down(finger(1, 100.0, 100.0))),
+        (16, TouchEvent::Down(finger(2, 200.0, 100.0))),
+        // fingers moved apart: distance 100 => 150
+        (32, TouchEvent::Move(finger(2, 225.0, 100.0))),
+        (48, TouchEvent::Move(finger(1, 75.0, 100.0))),
+        // second finger rotated clockwise by 90 degrees around the first one
+        (64, TouchEvent::Move(finger(2, 75.0, 250.0))),
+        (80, TouchEvent::Up(2)),
+        (96, TouchEvent::Up(1)),
+    ];
+
+    let mut recognizer = GestureRecognizer::new();
+    let mut state = TouchState::default();
+    let mut gestures = Vec::new();
+    for (time, event) in pinch_and_rotate.iter() {
+        recognizer.handle_event(&mut state, *event, *time);
+        gestures.push(state.gesture.into_option());
+    }
+
+    assert_eq!(gestures[0], None);
+    assert_eq!(gestures[1].map(|g| g.scale), Some(1.0));
+    assert_eq!(
+        gestures[1].map(|g| g.center),
+        Some(LogicalPosition::new(150.0, 100.0))
+    );
+    let pinch = gestures[3].unwrap();
+    assert_eq!(pinch.scale, 1.5);
+    assert!(pinch.is_pinch && !pinch.is_rotate && !pinch.is_pan);
+    let rotate = gestures[4].unwrap();
+    assert!(libm::fabsf(rotate.rotation - 90.0) < 0.01);
+    assert!(rotate.is_pinch && rotate.is_rotate);
+    assert_eq!(gestures[5], None);
+    assert_eq!(state.ended_touch_points.as_ref(), &[finger(1, 75.0, 100.0)]);
+    assert!(state.touch_points.is_empty());
+
+    // two-finger pan: both fingers move down in small steps (as reported by the
+    // touchscreen), one finger after the other
+    let mut state = TouchState::default();
+    let mut two_finger_pan = vec![
+        (0, TouchEvent::Down(finger(3, 100.0, 100.0))),
+        (0, TouchEvent::Down(finger(4, 200.0, 100.0))),
+    ];
+    for step in 1..=8 {
+        let y = 100.0 + step as f32 * 4.0;
+        two_finger_pan.push((step * 16, TouchEvent::Move(finger(3, 100.0, y))));
+        two_finger_pan.push((step * 16 + 8, TouchEvent::Move(finger(4, 200.0, y))));
+    }
+    for (time, event) in two_finger_pan {
+        recognizer.handle_event(&mut state, event, time);
+    }
+    let pan = state.gesture.into_option().unwrap();
+    assert_eq!(pan.translation, LogicalPosition::new(0.0, 32.0));
+    assert!(pan.is_pan && !pan.is_pinch && !pan.is_rotate);
+    recognizer.handle_event(&mut state, TouchEvent::Cancel(3), 200);
+    assert_eq!(state.gesture, OptionGestureState::None);
+    assert_eq!(state.cancelled_touch_points.len(), 1);
+
+    // long-press: recognized after the timeout, unless the finger moves
+    let mut state = TouchState::default();
+    recognizer.handle_event(&mut state, TouchEvent::Up(4), 0);
+    recognizer.handle_event(&mut state, TouchEvent::Down(finger(5, 50.0, 50.0)), 1000);
+    recognizer.handle_event(&mut state, TouchEvent::Move(finger(5, 53.0, 54.0)), 1200);
+    assert_eq!(recognizer.get_long_press_timeout(1200), Some(300));
+    assert!(!recognizer.check_long_press(&mut state, 1499));
+    assert!(recognizer.check_long_press(&mut state, 1500));
+    assert_eq!(
+        state.long_press.into_option(),
+        Some(LogicalPosition::new(50.0, 50.0))
+    );
+    recognizer.handle_event(&mut state, TouchEvent::Up(5), 1600);
+    assert_eq!(state.long_press, OptionLogicalPosition::None);
+
+    recognizer.handle_event(&mut state, TouchEvent::Down(finger(6, 50.0, 50.0)), 2000);
+    recognizer.handle_event(&mut state, TouchEvent::Move(finger(6, 70.0, 50.0)), 2100);
+    assert_eq!(recognizer.get_long_press_timeout(2100), None);
+    assert!(!recognizer.check_long_press(&mut state, 3000));
+
+    // pens don't start a long-press
+    let pen = TouchPoint {
+        kind: TouchPointKind::Pen,
+        pressure: 0.4,
+        tilt_x: 30.0,
+        ..finger(7, 10.0, 10.0)
+    };
+    let mut state = TouchState::default();
+    recognizer.handle_event(&mut state, TouchEvent::Down(pen), 0);
+    assert_eq!(recognizer.get_long_press_timeout(0), None);
+    assert_eq!(state.get_touch_point(7).map(|p| p.pressure), Some(0.4));
+}
@@ -34,10 +34,16 @@ pub mod display_list;
 pub mod dom;
 // Algorithms to create git-like diffs between two doms in linear time
 // pub mod diff;
+/// Recognition of touch gestures (pinch, rotate, two-finger pan, long-press)
+pub mod gesture;
 /// Contains OpenGL helper functions (to compile / link shaders), `VirtualGlDriver` for unit testing
 pub mod gl;
 /// Internal, arena-based storage for Dom nodes
 pub mod id_tree;
+/// Smooth / kinetic scrolling, overscroll bounce and scroll snapping
+pub mod scroll;
+/// Interactive overlay scrollbars: geometry, thumb dragging and track paging
+pub mod scrollbar;
 /// CSS cascading module
 pub mod style;
 /// `StyledDom` = CSSOM
@@ -0,0 +1,1068 @@
+//! Smooth and kinetic scrolling, overscroll bounce and scroll snapping
+//!
+//! The default `On::Scroll` handler of a `StyledDom` (see `styled_dom::default_on_scroll`)
+//! forwards the scroll input of the scroll containers to a `ScrollAnimator`. Mouse wheel
+//! input is animated with an ease-out curve, touchpad input follows the fingers and
+//! continues with a decelerating "fling" once the platform reports the end of the
+//! scroll gesture. The animations are driven by a timer which sets the scroll position
+//! of the animated nodes every frame (`CallbackInfo::set_scroll_position`).
+
+use crate::callbacks::{
+    CallbackInfo, DomNodeId, RefAny, TimerCallbackInfo, TimerCallbackReturn, Update,
+};
+use crate::id_tree::NodeId;
+use crate::styled_dom::DefaultScrollCallbackData;
+use crate::task::{
+    Duration, Instant, SystemTickDiff, SystemTimeDiff, TerminateTimer, Timer, TimerId,
+};
+use crate::ui_solver::{LayoutResult, OverflowingScrollNode};
+use crate::window::{LogicalPosition, LogicalSize};
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use azul_css::{ScrollSnapStrictness, StyleScrollSnapAlign, StyleScrollSnapType};
+use core::cmp::Ordering;
+
+/// Logical pixels scrolled per "click" of the mouse wheel (`MouseState::scroll_y` = 1.0)
+pub const WHEEL_SCROLL_STEP: f32 = 40.0;
+/// Duration of a smooth scroll animation (mouse wheel, `scroll-behavior: smooth`)
+pub const SMOOTH_SCROLL_DURATION_MS: f32 = 150.0;
+/// Duration of the animation that moves a scroll container to a snap point
+pub const SNAP_DURATION_MS: f32 = 250.0;
+/// Time constant of the exponential deceleration of a fling
+pub const KINETIC_TIME_CONSTANT_MS: f32 = 325.0;
+/// Velocity (in logical pixels per millisecond) below which a fling stops
+pub const KINETIC_MIN_VELOCITY: f32 = 0.02;
+/// Only the scroll deltas of the last milliseconds are used to calculate the fling velocity
+pub const FLING_SAMPLE_DURATION_MS: f32 = 100.0;
+/// Distance in logical pixels that a scroll container can be scrolled past its edges
+pub const MAX_OVERSCROLL: f32 = 100.0;
+/// Duration of the animation that moves an overscrolled container back to its edge
+pub const BOUNCE_DURATION_MS: f32 = 300.0;
+/// Duration of the overshoot when a fling hits the edge of a scroll container
+pub const OVERSHOOT_DURATION_MS: f32 = 100.0;
+/// Interval of the timer that drives the scroll animations
+pub const SCROLL_ANIMATION_INTERVAL_MS: u64 = 16;
+/// `scroll-snap-type: proximity` only snaps if the snap point is closer
+/// than this fraction of the size of the scroll container
+pub const SNAP_PROXIMITY: f32 = 0.3;
+
+/// Animation of the scroll position of one scroll container
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ScrollAnimation {
+    /// Eases out from `from` to `to` (mouse wheel, `scroll-behavior: smooth`, snapping)
+    Smooth {
+        from: LogicalPosition,
+        to: LogicalPosition,
+        duration_ms: f32,
+    },
+    /// Continues with `velocity` (in logical pixels per millisecond) and decelerates
+    Kinetic {
+        from: LogicalPosition,
+        velocity: LogicalPosition,
+    },
+    /// Springs back from the overscrolled position `from` to the edge `to`
+    Bounce {
+        from: LogicalPosition,
+        to: LogicalPosition,
+    },
+}
+
+impl ScrollAnimation {
+    /// Returns the scroll position `elapsed_ms` after the start of the
+    /// animation and whether the animation has finished
+    pub fn get_position(&self, elapsed_ms: f32) -> (LogicalPosition, bool) {
+        match self {
+            ScrollAnimation::Smooth {
+                from,
+                to,
+                duration_ms,
+            } => {
+                let t = if *duration_ms > 0.0 {
+                    (elapsed_ms / duration_ms).min(1.0)
+                } else {
+                    1.0
+                };
+                (interpolate(*from, *to, ease_out(t)), t >= 1.0)
+            }
+            ScrollAnimation::Kinetic { from, velocity } => {
+                let decay = libm::expf(-elapsed_ms / KINETIC_TIME_CONSTANT_MS);
+                let distance = KINETIC_TIME_CONSTANT_MS * (1.0 - decay);
+                let position = LogicalPosition::new(
+                    from.x + velocity.x * distance,
+                    from.y + velocity.y * distance,
+                );
+                let speed = libm::hypotf(velocity.x, velocity.y) * decay;
+                (position, speed < KINETIC_MIN_VELOCITY)
+            }
+            ScrollAnimation::Bounce { from, to } => {
+                let t = (elapsed_ms / BOUNCE_DURATION_MS).min(1.0);
+                (interpolate(*from, *to, ease_out(t)), t >= 1.0)
+            }
+        }
+    }
+
+    /// Returns the position where the animation comes to rest
+    pub fn get_target(&self) -> LogicalPosition {
+        match self {
+            ScrollAnimation::Smooth { to, .. } | ScrollAnimation::Bounce { to, .. } => *to,
+            ScrollAnimation::Kinetic { from, velocity } => LogicalPosition::new(
+                from.x + velocity.x * KINETIC_TIME_CONSTANT_MS,
+                from.y + velocity.y * KINETIC_TIME_CONSTANT_MS,
+            ),
+        }
+    }
+}
+
+/// A running `ScrollAnimation` of a scroll container
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActiveScrollAnimation {
+    pub animation: ScrollAnimation,
+    /// When the current `animation` was started
+    pub start: Instant,
+    /// Maximum scroll position of the scroll container
+    pub max: LogicalPosition,
+    /// Whether the animation can move past the edges of the container and bounce back
+    pub overscroll_bounce: bool,
+}
+
+impl ActiveScrollAnimation {
+    /// Advances the animation to `now`, returns the new scroll
+    /// position and whether the animation has finished
+    pub fn tick(&mut self, now: &Instant) -> (LogicalPosition, bool) {
+        let elapsed_ms = get_elapsed_ms(now, &self.start);
+        let (position, finished) = self.animation.get_position(elapsed_ms);
+        let clamped = clamp_position(position, self.max, 0.0);
+
+        if !self.overscroll_bounce {
+            // a fling stops at the edge of the scroll container
+            let hit_edge = match self.animation {
+                ScrollAnimation::Kinetic { .. } => clamped != position,
+                _ => false,
+            };
+            return (clamped, finished || hit_edge);
+        }
+
+        let overscrolled = clamp_position(position, self.max, MAX_OVERSCROLL);
+        if overscrolled == clamped {
+            return (clamped, finished);
+        }
+
+        match self.animation {
+            // a fling that crosses the edge overshoots with its current velocity,
+            // then bounces back once the overshoot animation has finished
+            ScrollAnimation::Kinetic { velocity, .. } if !finished => {
+                let decay = libm::expf(-elapsed_ms / KINETIC_TIME_CONSTANT_MS);
+                let overshoot = |v: f32, p: f32, c: f32| {
+                    if p == c {
+                        0.0
+                    } else {
+                        (v * decay * OVERSHOOT_DURATION_MS / 3.0)
+                            .max(-MAX_OVERSCROLL)
+                            .min(MAX_OVERSCROLL)
+                    }
+                };
+                let to = LogicalPosition::new(
+                    clamped.x + overshoot(velocity.x, position.x, clamped.x),
+                    clamped.y + overshoot(velocity.y, position.y, clamped.y),
+                );
+                self.animation = ScrollAnimation::Smooth {
+                    from: overscrolled,
+                    to: clamp_position(to, self.max, MAX_OVERSCROLL),
+                    duration_ms: OVERSHOOT_DURATION_MS,
+                };
+                self.start = now.clone();
+                (overscrolled, false)
+            }
+            _ if finished => {
+                self.animation = ScrollAnimation::Bounce {
+                    from: overscrolled,
+                    to: clamped,
+                };
+                self.start = now.clone();
+                (overscrolled, false)
+            }
+            _ => (overscrolled, false),
+        }
+    }
+}
+
+/// Scroll positions of a scroll container with a `scroll-snap-type`, at which
+/// its direct children with a `scroll-snap-align` are aligned to the container
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScrollSnapPoints {
+    pub snap_type: StyleScrollSnapType,
+    /// Size of the scroll container
+    pub viewport: LogicalSize,
+    /// Horizontal snap positions
+    pub x: Vec<f32>,
+    /// Vertical snap positions
+    pub y: Vec<f32>,
+}
+
+impl ScrollSnapPoints {
+    /// Collects the snap positions of the scroll container `node_id`, returns
+    /// `None` if the container doesn't have a `scroll-snap-type`
+    pub fn new(
+        layout_result: &LayoutResult,
+        node_id: NodeId,
+        scroll_node: &OverflowingScrollNode,
+    ) -> Option<Self> {
+        let styled_dom = &layout_result.styled_dom;
+        let css_property_cache = styled_dom.get_css_property_cache();
+        let node_data = styled_dom.node_data.as_container();
+        let styled_nodes = styled_dom.styled_nodes.as_container();
+        let node_hierarchy = styled_dom.node_hierarchy.as_container();
+        let rects = layout_result.rects.as_ref();
+
+        let snap_type = css_property_cache
+            .get_scroll_snap_type(&node_data[node_id], &node_id, &styled_nodes[node_id].state)
+            .and_then(|p| p.get_property().copied())?;
+
+        if !snap_type.axis.snaps_x() && !snap_type.axis.snaps_y() {
+            return None;
+        }
+
+        let max = scroll_node.get_max_scroll_position();
+        let parent = scroll_node.parent_rect;
+        let mut x = Vec::new();
+        let mut y = Vec::new();
+
+        for child_id in node_id.az_children(&node_hierarchy) {
+            let align = css_property_cache
+                .get_scroll_snap_align(
+                    &node_data[child_id],
+                    &child_id,
+                    &styled_nodes[child_id].state,
+                )
+                .and_then(|p| p.get_property().copied())
+                .unwrap_or_default();
+
+            // how far the child is from being aligned at the start of the container
+            let origin = rects[child_id].position.get_static_offset();
+            let size = rects[child_id].size;
+            let (offset_x, offset_y) = match align {
+                StyleScrollSnapAlign::None => continue,
+                StyleScrollSnapAlign::Start => (0.0, 0.0),
+                StyleScrollSnapAlign::Center => (
+                    (size.width - parent.size.width) / 2.0,
+                    (size.height - parent.size.height) / 2.0,
+                ),
+                StyleScrollSnapAlign::End => (
+                    size.width - parent.size.width,
+                    size.height - parent.size.height,
+                ),
+            };
+
+            x.push((origin.x - parent.origin.x + offset_x).max(0.0).min(max.x));
+            y.push((origin.y - parent.origin.y + offset_y).max(0.0).min(max.y));
+        }
+
+        Some(Self {
+            snap_type,
+            viewport: parent.size,
+            x,
+            y,
+        })
+    }
+
+    /// Returns the position that scrolling from `current` to `target` snaps to
+    pub fn get_snap_position(
+        &self,
+        current: LogicalPosition,
+        target: LogicalPosition,
+    ) -> LogicalPosition {
+        let strictness = self.snap_type.strictness;
+        LogicalPosition::new(
+            if self.snap_type.axis.snaps_x() {
+                snap_axis(
+                    current.x,
+                    target.x,
+                    &self.x,
+                    strictness,
+                    self.viewport.width,
+                )
+            } else {
+                target.x
+            },
+            if self.snap_type.axis.snaps_y() {
+                snap_axis(
+                    current.y,
+                    target.y,
+                    &self.y,
+                    strictness,
+                    self.viewport.height,
+                )
+            } else {
+                target.y
+            },
+        )
+    }
+}
+
+/// Running scroll animations of a `StyledDom`, shared between the default
+/// `On::Scroll` handler and the timer that drives the animations
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ScrollAnimator {
+    pub animations: BTreeMap<DomNodeId, ActiveScrollAnimation>,
+    /// Recent touchpad scroll deltas, used for the velocity of a fling
+    pub samples: Vec<(Instant, LogicalPosition)>,
+    /// Scroll container that the `samples` belong to
+    pub sample_node: Option<DomNodeId>,
+    /// Timer that drives the animations, `None` if no timer is running
+    pub timer: Option<TimerId>,
+}
+
+impl ScrollAnimator {
+    /// Scrolls the scroll container by `delta` logical pixels, returns false if
+    /// the node isn't a scroll container.
+    ///
+    /// Deltas smaller than one wheel step are treated as touchpad input, which is
+    /// applied immediately (snapping and flinging happen in `fling`), larger deltas
+    /// are animated if `smooth` is set.
+    pub fn scroll_by(
+        &mut self,
+        info: &mut CallbackInfo,
+        node_id: DomNodeId,
+        delta: LogicalPosition,
+        smooth: bool,
+        overscroll_bounce: bool,
+    ) -> bool {
+        let (max, snap_points) = match get_scroll_container(info, node_id) {
+            Some(s) => s,
+            None => return false,
+        };
+
+        let now = info.get_current_time();
+        let current = info.get_scroll_position(node_id).unwrap_or_default();
+        let precise =
+            libm::fabsf(delta.x) < WHEEL_SCROLL_STEP && libm::fabsf(delta.y) < WHEEL_SCROLL_STEP;
+
+        if precise {
+            if self.sample_node != Some(node_id) {
+                self.samples.clear();
+                self.sample_node = Some(node_id);
+            }
+            self.samples
+                .retain(|(t, _)| get_elapsed_ms(&now, t) <= FLING_SAMPLE_DURATION_MS);
+            self.samples.push((now, delta));
+            self.animations.remove(&node_id);
+
+            let new_position = if overscroll_bounce {
+                LogicalPosition::new(
+                    rubber_band(current.x, delta.x, max.x),
+                    rubber_band(current.y, delta.y, max.y),
+                )
+            } else {
+                clamp_position(current + delta, max, 0.0)
+            };
+            info.set_scroll_position(node_id, new_position);
+            return true;
+        }
+
+        // continue from the end of a running animation, so that fast wheel turns add up
+        let base = self
+            .animations
+            .get(&node_id)
+            .map(|a| a.animation.get_target())
+            .unwrap_or(current);
+        let mut target = clamp_position(base + delta, max, 0.0);
+        if let Some(snap_points) = snap_points.as_ref() {
+            target = snap_points.get_snap_position(base, target);
+        }
+
+        if smooth {
+            let animation = ScrollAnimation::Smooth {
+                from: current,
+                to: target,
+                duration_ms: SMOOTH_SCROLL_DURATION_MS,
+            };
+            self.start_animation(node_id, animation, max, false, now);
+        } else {
+            self.animations.remove(&node_id);
+            info.set_scroll_position(node_id, target);
+        }
+
+        true
+    }
+
+    /// Ends a touchpad scroll gesture: continues scrolling with the velocity of the
+    /// last scroll deltas, then snaps to a snap point or bounces back from the edge.
+    /// Returns false if no animation was started.
+    pub fn fling(
+        &mut self,
+        info: &mut CallbackInfo,
+        node_id: DomNodeId,
+        overscroll_bounce: bool,
+    ) -> bool {
+        let (max, snap_points) = match get_scroll_container(info, node_id) {
+            Some(s) => s,
+            None => return false,
+        };
+
+        // the end of the gesture is reported once per axis
+        if self.sample_node != Some(node_id) && self.animations.contains_key(&node_id) {
+            return false;
+        }
+
+        let now = info.get_current_time();
+        let current = info.get_scroll_position(node_id).unwrap_or_default();
+        let velocity = if self.sample_node == Some(node_id) {
+            get_fling_velocity(&self.samples, &now)
+        } else {
+            LogicalPosition::zero()
+        };
+        self.samples.clear();
+        self.sample_node = None;
+
+        let is_fling = libm::hypotf(velocity.x, velocity.y) >= KINETIC_MIN_VELOCITY;
+        let clamped = clamp_position(current, max, 0.0);
+
+        let animation = if let Some(snap_points) = snap_points.as_ref() {
+            let end = if is_fling {
+                ScrollAnimation::Kinetic {
+                    from: clamped,
+                    velocity,
+                }
+                .get_target()
+            } else {
+                clamped
+            };
+            ScrollAnimation::Smooth {
+                from: current,
+                to: snap_points.get_snap_position(clamped, clamp_position(end, max, 0.0)),
+                duration_ms: SNAP_DURATION_MS,
+            }
+        } else if clamped != current {
+            // gesture ended while the container was overscrolled
+            ScrollAnimation::Bounce {
+                from: current,
+                to: clamped,
+            }
+        } else if is_fling {
+            ScrollAnimation::Kinetic {
+                from: current,
+                velocity,
+            }
+        } else {
+            return false;
+        };
+
+        self.start_animation(node_id, animation, max, overscroll_bounce, now);
+        true
+    }
+
+    /// Animates the scroll container to `position`, returns false if the node
+    /// isn't a scroll container
+    pub fn scroll_to(
+        &mut self,
+        info: &mut CallbackInfo,
+        node_id: DomNodeId,
+        position: LogicalPosition,
+    ) -> bool {
+        let (max, _) = match get_scroll_container(info, node_id) {
+            Some(s) => s,
+            None => return false,
+        };
+
+        let now = info.get_current_time();
+        let animation = ScrollAnimation::Smooth {
+            from: info.get_scroll_position(node_id).unwrap_or_default(),
+            to: clamp_position(position, max, 0.0),
+            duration_ms: SMOOTH_SCROLL_DURATION_MS,
+        };
+        self.start_animation(node_id, animation, max, false, now);
+        true
+    }
+
+    /// Starts the timer that drives the animations if animations are running
+    /// and no timer is running yet - `data` has to be the `RefAny` of the
+    /// `DefaultScrollCallbackData` that contains this `ScrollAnimator`
+    pub fn start_timer(&mut self, data: RefAny, info: &mut CallbackInfo) {
+        if self.animations.is_empty() || self.timer.is_some() {
+            return;
+        }
+
+        let interval = match info.get_current_time() {
+            Instant::System(_) => {
+                Duration::System(SystemTimeDiff::from_millis(SCROLL_ANIMATION_INTERVAL_MS))
+            }
+            Instant::Tick(_) => Duration::Tick(SystemTickDiff {
+                tick_diff: SCROLL_ANIMATION_INTERVAL_MS,
+            }),
+        };
+
+        let timer = Timer::new(data, drive_scroll_animations, info.get_system_time_fn())
+            .with_interval(interval);
+        self.timer = Some(info.start_timer(timer));
+    }
+
+    /// Advances all animations to `now` and updates the scroll positions of
+    /// the animated nodes, returns whether any animation is still running
+    pub fn tick(&mut self, info: &mut CallbackInfo, now: &Instant) -> bool {
+        let mut finished = Vec::new();
+
+        for (node_id, animation) in self.animations.iter_mut() {
+            let (position, is_finished) = animation.tick(now);
+            info.set_scroll_position(*node_id, position);
+            if is_finished {
+                finished.push(*node_id);
+            }
+        }
+
+        for node_id in finished {
+            self.animations.remove(&node_id);
+        }
+
+        !self.animations.is_empty()
+    }
+
+    fn start_animation(
+        &mut self,
+        node_id: DomNodeId,
+        animation: ScrollAnimation,
+        max: LogicalPosition,
+        overscroll_bounce: bool,
+        now: Instant,
+    ) {
+        self.animations.insert(
+            node_id,
+            ActiveScrollAnimation {
+                animation,
+                start: now,
+                max,
+                overscroll_bounce,
+            },
+        );
+    }
+}
+
+/// How a node is aligned in its scroll containers by `CallbackInfo::scroll_into_view`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum ScrollIntoViewAlign {
+    /// Aligns the start of the node with the start of the scroll container
+    Start,
+    /// Centers the node in the scroll container
+    Center,
+    /// Aligns the end of the node with the end of the scroll container
+    End,
+    /// Scrolls as little as possible so that the node is visible
+    /// (doesn't scroll at all if the node is already visible)
+    Nearest,
+}
+
+impl Default for ScrollIntoViewAlign {
+    fn default() -> Self {
+        ScrollIntoViewAlign::Nearest
+    }
+}
+
+/// Returns the new scroll positions of the scroll containers that have to be scrolled
+/// so that the node is visible, from the innermost to the outermost scroll container
+pub(crate) fn get_scroll_into_view_positions(
+    info: &CallbackInfo,
+    node_id: DomNodeId,
+    align: ScrollIntoViewAlign,
+) -> Vec<(DomNodeId, LogicalPosition)> {
+    let mut positions = Vec::new();
+
+    let nid = match node_id.node.into_crate_internal() {
+        Some(s) => s,
+        None => return positions,
+    };
+    let layout_result = match info.internal_get_layout_results().get(node_id.dom.inner) {
+        Some(s) => s,
+        None => return positions,
+    };
+    let rects = layout_result.rects.as_ref();
+    let rect = match rects.get(nid) {
+        Some(s) => s,
+        None => return positions,
+    };
+
+    // origin of the node, including the new scroll offsets of the inner scroll containers
+    let mut origin = rect.position.get_static_offset();
+    let size = rect.size;
+
+    let mut parent = info.get_parent(node_id);
+    while let Some(parent_id) = parent {
+        if let Some(scroll_node) = layout_result
+            .scrollable_nodes
+            .overflowing_nodes
+            .get(&parent_id.node)
+        {
+            let current = info.get_scroll_position(parent_id).unwrap_or_default();
+            let max = scroll_node.get_max_scroll_position();
+            let viewport = scroll_node.parent_rect;
+            let new_position = LogicalPosition::new(
+                align_axis(
+                    current.x,
+                    origin.x - viewport.origin.x,
+                    size.width,
+                    viewport.size.width,
+                    max.x,
+                    align,
+                ),
+                align_axis(
+                    current.y,
+                    origin.y - viewport.origin.y,
+                    size.height,
+                    viewport.size.height,
+                    max.y,
+                    align,
+                ),
+            );
+            origin.x -= new_position.x;
+            origin.y -= new_position.y;
+            positions.push((parent_id, new_position));
+        }
+        parent = info.get_parent(parent_id);
+    }
+
+    positions
+}
+
+/// Animates the scroll container to `position` if it has `scroll-behavior: smooth`,
+/// returns false if the node isn't scrolled smoothly (see `CallbackInfo::scroll_to`)
+pub(crate) fn smooth_scroll_to(
+    info: &mut CallbackInfo,
+    node_id: DomNodeId,
+    position: LogicalPosition,
+) -> bool {
+    use azul_css::StyleScrollBehavior;
+
+    let behavior = {
+        let nid = match node_id.node.into_crate_internal() {
+            Some(s) => s,
+            None => return false,
+        };
+        let styled_dom = match info.internal_get_layout_results().get(node_id.dom.inner) {
+            Some(s) => &s.styled_dom,
+            None => return false,
+        };
+        styled_dom
+            .get_css_property_cache()
+            .get_scroll_behavior(
+                &styled_dom.node_data.as_container()[nid],
+                &nid,
+                &styled_dom.styled_nodes.as_container()[nid].state,
+            )
+            .and_then(|p| p.get_property().copied())
+            .unwrap_or_default()
+    };
+
+    behavior == StyleScrollBehavior::Smooth && animate_scroll_to(info, node_id, position)
+}
+
+/// Animates the scroll container to `position`, returns false if the node has no
+/// default On::Scroll handler (i.e. it isn't a scroll container)
+pub(crate) fn animate_scroll_to(
+    info: &mut CallbackInfo,
+    node_id: DomNodeId,
+    position: LogicalPosition,
+) -> bool {
+    use crate::dom::{EventFilter, HoverEventFilter};
+
+    let mut data = {
+        let nid = match node_id.node.into_crate_internal() {
+            Some(s) => s,
+            None => return false,
+        };
+        let styled_dom = match info.internal_get_layout_results().get(node_id.dom.inner) {
+            Some(s) => &s.styled_dom,
+            None => return false,
+        };
+        let node_data_container = styled_dom.node_data.as_container();
+        let node_data = match node_data_container.get(nid) {
+            Some(s) => s,
+            None => return false,
+        };
+
+        // the animation is shared with the default On::Scroll handler of the node
+        match node_data
+            .callbacks
+            .iter()
+            .find(|cb| cb.event == EventFilter::Hover(HoverEventFilter::Scroll))
+        {
+            Some(cb) => cb.data.clone(),
+            None => return false,
+        }
+    };
+
+    let timer_data = data.clone();
+    let mut data = match data.downcast_mut::<DefaultScrollCallbackData>() {
+        Some(s) => s,
+        None => return false,
+    };
+
+    if !data.animator.scroll_to(info, node_id, position) {
+        return false;
+    }
+    data.animator.start_timer(timer_data, info);
+    true
+}
+
+/// Returns whether the node is the innermost scroll container under the cursor,
+/// so that nested scroll containers don't all scroll at the same time
+pub fn is_innermost_scroll_container(info: &CallbackInfo, node_id: DomNodeId) -> bool {
+    let nid = match node_id.node.into_crate_internal() {
+        Some(s) => s,
+        None => return false,
+    };
+
+    info.internal_get_current_window_state()
+        .last_hit_test
+        .hovered_nodes
+        .get(&node_id.dom)
+        .and_then(|ht| ht.scroll_hit_test_nodes.keys().max())
+        == Some(&nid)
+}
+
+/// Returns the velocity (in logical pixels per millisecond) of the scroll deltas
+/// that were recorded in the last `FLING_SAMPLE_DURATION_MS`
+pub fn get_fling_velocity(
+    samples: &[(Instant, LogicalPosition)],
+    now: &Instant,
+) -> LogicalPosition {
+    let recent = samples
+        .iter()
+        .filter(|(t, _)| get_elapsed_ms(now, t) <= FLING_SAMPLE_DURATION_MS)
+        .collect::<Vec<_>>();
+
+    let (first, last) = match (recent.first(), recent.last()) {
+        (Some(first), Some(last)) if recent.len() > 1 => (first, last),
+        _ => return LogicalPosition::zero(),
+    };
+
+    let duration_ms = get_elapsed_ms(&last.0, &first.0);
+    if duration_ms <= 0.0 {
+        return LogicalPosition::zero();
+    }
+
+    // the first delta was scrolled before the start of the measured time span
+    let mut distance = LogicalPosition::zero();
+    for (_, delta) in recent.iter().skip(1) {
+        distance += *delta;
+    }
+
+    LogicalPosition::new(distance.x / duration_ms, distance.y / duration_ms)
+}
+
+/// Timer callback that drives the animations of a `DefaultScrollCallbackData`
+extern "C" fn drive_scroll_animations(
+    data: &mut RefAny,
+    info: &mut TimerCallbackInfo,
+) -> TimerCallbackReturn {
+    let mut data = match data.downcast_mut::<DefaultScrollCallbackData>() {
+        Some(s) => s,
+        None => {
+            return TimerCallbackReturn {
+                should_update: Update::DoNothing,
+                should_terminate: TerminateTimer::Terminate,
+            };
+        }
+    };
+
+    let now = info.frame_start.clone();
+    let is_running = data.animator.tick(&mut info.callback_info, &now);
+    if !is_running {
+        data.animator.timer = None;
+    }
+
+    TimerCallbackReturn {
+        should_update: Update::DoNothing,
+        should_terminate: if is_running {
+            TerminateTimer::Continue
+        } else {
+            TerminateTimer::Terminate
+        },
+    }
+}
+
+// Maximum scroll position and snap points of a scroll container
+fn get_scroll_container(
+    info: &CallbackInfo,
+    node_id: DomNodeId,
+) -> Option<(LogicalPosition, Option<ScrollSnapPoints>)> {
+    let nid = node_id.node.into_crate_internal()?;
+    let layout_result = info.internal_get_layout_results().get(node_id.dom.inner)?;
+    let scroll_node = layout_result
+        .scrollable_nodes
+        .overflowing_nodes
+        .get(&node_id.node)?;
+    Some((
+        scroll_node.get_max_scroll_position(),
+        ScrollSnapPoints::new(layout_result, nid, scroll_node),
+    ))
+}
+
+// Returns the snap point closest to `target`. Mandatory snapping moves to the next
+// snap point in the scroll direction if the closest one is the current position,
+// so that small wheel deltas don't get snapped back
+fn snap_axis(
+    current: f32,
+    target: f32,
+    points: &[f32],
+    strictness: ScrollSnapStrictness,
+    viewport: f32,
+) -> f32 {
+    let by_distance_to = |p: f32| {
+        move |a: &f32, b: &f32| {
+            libm::fabsf(a - p)
+                .partial_cmp(&libm::fabsf(b - p))
+                .unwrap_or(Ordering::Equal)
+        }
+    };
+
+    let snapped = match points.iter().copied().min_by(by_distance_to(target)) {
+        Some(s) => s,
+        None => return target,
+    };
+
+    match strictness {
+        ScrollSnapStrictness::Proximity => {
+            if libm::fabsf(snapped - target) <= viewport * SNAP_PROXIMITY {
+                snapped
+            } else {
+                target
+            }
+        }
+        ScrollSnapStrictness::Mandatory => {
+            if libm::fabsf(snapped - current) > 0.5 || libm::fabsf(target - current) < 0.5 {
+                return snapped;
+            }
+            let next = if target > current {
+                points
+                    .iter()
+                    .copied()
+                    .filter(|p| *p > current + 0.5)
+                    .min_by(by_distance_to(current))
+            } else {
+                points
+                    .iter()
+                    .copied()
+                    .filter(|p| *p < current - 0.5)
+                    .min_by(by_distance_to(current))
+            };
+            next.unwrap_or(snapped)
+        }
+    }
+}
+
+// Returns the scroll offset that aligns the node (`start` and `size` relative to
+// the content of the scroll container) in a scroll container of size `viewport`
+fn align_axis(
+    current: f32,
+    start: f32,
+    size: f32,
+    viewport: f32,
+    max: f32,
+    align: ScrollIntoViewAlign,
+) -> f32 {
+    let position = match align {
+        ScrollIntoViewAlign::Start => start,
+        ScrollIntoViewAlign::Center => start + (size - viewport) / 2.0,
+        ScrollIntoViewAlign::End => start + size - viewport,
+        ScrollIntoViewAlign::Nearest => {
+            if start < current || size > viewport {
+                start
+            } else if start + size > current + viewport {
+                start + size - viewport
+            } else {
+                current
+            }
+        }
+    };
+    position.max(0.0).min(max.max(0.0))
+}
+
+// Moves by `delta` with a resistance of 50% while the position is past the edges
+fn rubber_band(current: f32, delta: f32, max: f32) -> f32 {
+    let is_overscrolled = current < 0.0 || current > max;
+    let moves_outwards = (current <= 0.0 && delta < 0.0) || (current >= max && delta > 0.0);
+    let delta = if is_overscrolled || moves_outwards {
+        delta * 0.5
+    } else {
+        delta
+    };
+    (current + delta)
+        .max(-MAX_OVERSCROLL)
+        .min(max + MAX_OVERSCROLL)
+}
+
+fn clamp_position(
+    position: LogicalPosition,
+    max: LogicalPosition,
+    overscroll: f32,
+) -> LogicalPosition {
+    LogicalPosition::new(
+        position.x.max(-overscroll).min(max.x + overscroll),
+        position.y.max(-overscroll).min(max.y + overscroll),
+    )
+}
+
+fn interpolate(from: LogicalPosition, to: LogicalPosition, t: f32) -> LogicalPosition {
+    LogicalPosition::new(from.x + (to.x - from.x) * t, from.y + (to.y - from.y) * t)
+}
+
+// cubic ease-out: starts fast, slows down towards the end
+fn ease_out(t: f32) -> f32 {
+    let t = 1.0 - t;
+    1.0 - t * t * t
+}
+
+fn get_elapsed_ms(now: &Instant, earlier: &Instant) -> f32 {
+    if now <= earlier {
+        return 0.0;
+    }
+    match now.duration_since(earlier) {
+        Duration::System(d) => d.secs as f32 * 1000.0 + d.nanos as f32 / 1_000_000.0,
+        // ticks are treated as milliseconds (see `Timer::tick_millis`)
+        Duration::Tick(d) => d.tick_diff as f32,
+    }
+}
+
+#[test]
+fn test_scroll_animations() {
+    use crate::task::SystemTick;
+    use azul_css::ScrollSnapAxis;
+
+    fn tick(t: u64) -> Instant {
+        Instant::Tick(SystemTick::new(t))
+    }
+
+    // smooth scrolling eases out and ends exactly at the target
+    let smooth = ScrollAnimation::Smooth {
+        from: LogicalPosition::new(0.0, 0.0),
+        to: LogicalPosition::new(0.0, 100.0),
+        duration_ms: 100.0,
+    };
+    let (half, finished) = smooth.get_position(50.0);
+    assert!(half.y > 50.0 && !finished);
+    assert_eq!(
+        smooth.get_position(100.0),
+        (LogicalPosition::new(0.0, 100.0), true)
+    );
+
+    // a fling decelerates and comes to rest at `velocity * time constant`
+    let kinetic = ScrollAnimation::Kinetic {
+        from: LogicalPosition::zero(),
+        velocity: LogicalPosition::new(0.0, 1.0),
+    };
+    assert!(!kinetic.get_position(100.0).1);
+    let (rest, finished) = kinetic.get_position(2000.0);
+    assert!(finished && rest.y < kinetic.get_target().y && rest.y > 300.0);
+
+    // without overscroll, a fling stops at the edge
+    let mut active = ActiveScrollAnimation {
+        animation: kinetic,
+        start: tick(0),
+        max: LogicalPosition::new(0.0, 200.0),
+        overscroll_bounce: false,
+    };
+    assert_eq!(
+        active.tick(&tick(1000)),
+        (LogicalPosition::new(0.0, 200.0), true)
+    );
+
+    // with overscroll, it overshoots the edge and bounces back
+    active.animation = kinetic;
+    active.overscroll_bounce = true;
+    let (overshoot, finished) = active.tick(&tick(1000));
+    assert!(overshoot.y > 200.0 && !finished);
+    let (overshoot, finished) = active.tick(&tick(1000 + OVERSHOOT_DURATION_MS as u64));
+    assert!(overshoot.y > 200.0 && !finished);
+    match active.animation {
+        ScrollAnimation::Bounce { to, .. } => assert_eq!(to, LogicalPosition::new(0.0, 200.0)),
+        other => panic!("expected bounce, got {:?}", other),
+    }
+    let end = 1000 + (OVERSHOOT_DURATION_MS + BOUNCE_DURATION_MS) as u64;
+    assert_eq!(
+        active.tick(&tick(end)),
+        (LogicalPosition::new(0.0, 200.0), true)
+    );
+
+    // fling velocity from the deltas of the last 100ms
+    let samples = [
+        (tick(0), LogicalPosition::new(0.0, 50.0)),
+        (tick(200), LogicalPosition::new(0.0, 10.0)),
+        (tick(220), LogicalPosition::new(0.0, 10.0)),
+        (tick(240), LogicalPosition::new(0.0, 10.0)),
+    ];
+    assert_eq!(
+        get_fling_velocity(&samples, &tick(250)),
+        LogicalPosition::new(0.0, 0.5)
+    );
+    assert_eq!(
+        get_fling_velocity(&samples[..1], &tick(10)),
+        LogicalPosition::zero()
+    );
+
+    // scroll snapping
+    let snap_points = ScrollSnapPoints {
+        snap_type: StyleScrollSnapType {
+            axis: ScrollSnapAxis::Y,
+            strictness: ScrollSnapStrictness::Mandatory,
+        },
+        viewport: LogicalSize::new(100.0, 100.0),
+        x: vec![0.0, 100.0, 200.0],
+        y: vec![0.0, 100.0, 200.0],
+    };
+    let snap = |snap_points: &ScrollSnapPoints, current: f32, target: f32| {
+        snap_points.get_snap_position(
+            LogicalPosition::new(0.0, current),
+            LogicalPosition::new(30.0, target),
+        )
+    };
+    assert_eq!(
+        snap(&snap_points, 0.0, 140.0),
+        LogicalPosition::new(30.0, 100.0)
+    );
+    // a small wheel delta still moves to the next snap point
+    assert_eq!(
+        snap(&snap_points, 100.0, 120.0),
+        LogicalPosition::new(30.0, 200.0)
+    );
+    assert_eq!(
+        snap(&snap_points, 100.0, 80.0),
+        LogicalPosition::new(30.0, 0.0)
+    );
+
+    let proximity = ScrollSnapPoints {
+        snap_type: StyleScrollSnapType {
+            axis: ScrollSnapAxis::Both,
+            strictness: ScrollSnapStrictness::Proximity,
+        },
+        ..snap_points
+    };
+    assert_eq!(
+        snap(&proximity, 0.0, 120.0),
+        LogicalPosition::new(0.0, 100.0)
+    );
+    assert_eq!(
+        snap(&proximity, 0.0, 150.0),
+        LogicalPosition::new(0.0, 150.0)
+    );
+
+    assert_eq!(rubber_band(0.0, -20.0, 100.0), -10.0);
+    assert_eq!(rubber_band(50.0, 20.0, 100.0), 70.0);
+}
+
+#[test]
+fn test_scroll_into_view_align() {
+    use self::ScrollIntoViewAlign::*;
+
+    // node at 300..350 in a 100px viewport, content can be scrolled by 900px
+    assert_eq!(align_axis(0.0, 300.0, 50.0, 100.0, 900.0, Start), 300.0);
+    assert_eq!(align_axis(0.0, 300.0, 50.0, 100.0, 900.0, Center), 275.0);
+    assert_eq!(align_axis(0.0, 300.0, 50.0, 100.0, 900.0, End), 250.0);
+    // nearest: scrolls down until the end of the node is visible...
+    assert_eq!(align_axis(0.0, 300.0, 50.0, 100.0, 900.0, Nearest), 250.0);
+    // ... up until the start of the node is visible ...
+    assert_eq!(align_axis(400.0, 300.0, 50.0, 100.0, 900.0, Nearest), 300.0);
+    // ... and doesn't scroll if the node is already visible
+    assert_eq!(align_axis(280.0, 300.0, 50.0, 100.0, 900.0, Nearest), 280.0);
+    // the position is clamped to the scrollable area
+    assert_eq!(align_axis(0.0, 10.0, 50.0, 100.0, 900.0, Center), 0.0);
+    assert_eq!(align_axis(0.0, 980.0, 20.0, 100.0, 900.0, Start), 900.0);
+}
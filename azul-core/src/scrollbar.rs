@@ -0,0 +1,834 @@
+//! Interactive overlay scrollbars of the scroll containers
+//!
+//! Every `OverflowingScrollNode` of the root DOM whose `overflow-x` / `overflow-y`
+//! needs a scrollbar gets a track and a thumb, which are drawn on top of the display
+//! list with the colors of its `-azul-scrollbar-style`. The platform shells feed the
+//! mouse input of the window into the `ScrollbarStates` of the window before invoking
+//! the callbacks: dragging the thumb scrolls the container proportionally, pressing
+//! the track scrolls by one page towards the cursor. While a scrollbar is pressed,
+//! the mouse input is not delivered to the nodes below the scrollbar.
+//!
+//! Every track and thumb is drawn with its own `TransformKey`: scrolling only moves
+//! them by updating the transforms, the display list is only rebuilt when the
+//! scrollbars change their size, style or visibility.
+
+use crate::app_resources::TransformKey;
+use crate::id_tree::NodeId;
+use crate::styled_dom::{DomId, NodeHierarchyItemId, StyledNodeState};
+use crate::ui_solver::{ComputedTransform3D, LayoutResult, OverflowingScrollNode};
+use crate::window::{
+    FullWindowState, LogicalPosition, LogicalRect, LogicalSize, ScrollStates, VirtualKeyCode,
+};
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::vec::Vec;
+use azul_css::{ColorU, ScrollbarInfo, StyleBackgroundContent};
+
+/// Minimum length of the thumb in logical pixels, so that the thumb of
+/// very long scroll containers can still be grabbed
+pub const MIN_THUMB_SIZE: f32 = 20.0;
+/// Fraction of the scroll container that is scrolled by one page
+/// (pressing the track, PageUp / PageDown), the rest stays visible for orientation
+pub const PAGE_SCROLL_FACTOR: f32 = 0.875;
+
+/// Orientation of a scrollbar
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ScrollbarAxis {
+    Horizontal,
+    Vertical,
+}
+
+/// Part of a scrollbar that is hovered or pressed
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ScrollbarPart {
+    Track,
+    Thumb,
+}
+
+/// Position of one scrollbar of a scroll container, in window coordinates
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ScrollbarGeometry {
+    /// Scroll container in the root DOM
+    pub node_id: NodeHierarchyItemId,
+    pub axis: ScrollbarAxis,
+    /// Rectangle of the whole scrollbar along the edge of the scroll container
+    pub track: LogicalRect,
+    /// Rectangle of the thumb inside of the track
+    pub thumb: LogicalRect,
+    /// Distance that the thumb can move inside of the track
+    pub thumb_travel: f32,
+    /// Current scroll offset of the container on this axis
+    pub scroll_offset: f32,
+    /// Maximum scroll offset of the container on this axis
+    pub max_scroll: f32,
+    /// Size of the scroll container on this axis
+    pub viewport_size: f32,
+}
+
+impl ScrollbarGeometry {
+    /// Calculates the scrollbar of a scroll container that is visible at `visible_rect`.
+    ///
+    /// `padding_start` / `padding_end` are the `padding-left` / `padding-right` of the
+    /// `ScrollbarInfo`, i.e. the space on both sides of the thumb across the track.
+    /// `reserved_end` is the space at the end of the track that is reserved for the
+    /// scrollbar of the other axis.
+    pub fn new(
+        node_id: NodeHierarchyItemId,
+        axis: ScrollbarAxis,
+        visible_rect: LogicalRect,
+        thickness: f32,
+        padding_start: f32,
+        padding_end: f32,
+        reserved_end: f32,
+        viewport_size: f32,
+        max_scroll: f32,
+        scroll_offset: f32,
+    ) -> Self {
+        let track = match axis {
+            ScrollbarAxis::Vertical => LogicalRect::new(
+                LogicalPosition::new(visible_rect.max_x() - thickness, visible_rect.min_y()),
+                LogicalSize::new(
+                    thickness,
+                    (visible_rect.size.height - reserved_end).max(0.0),
+                ),
+            ),
+            ScrollbarAxis::Horizontal => LogicalRect::new(
+                LogicalPosition::new(visible_rect.min_x(), visible_rect.max_y() - thickness),
+                LogicalSize::new((visible_rect.size.width - reserved_end).max(0.0), thickness),
+            ),
+        };
+
+        let track_length = get_axis(track.size, axis);
+        let content_size = viewport_size + max_scroll;
+        let thumb_length = if content_size > 0.0 {
+            (track_length * viewport_size / content_size)
+                .max(MIN_THUMB_SIZE)
+                .min(track_length)
+        } else {
+            track_length
+        };
+        let thumb_travel = (track_length - thumb_length).max(0.0);
+        let thumb_position = if max_scroll > 0.0 {
+            thumb_travel * (scroll_offset / max_scroll).max(0.0).min(1.0)
+        } else {
+            0.0
+        };
+        let thumb_thickness = (thickness - padding_start - padding_end).max(0.0);
+
+        let thumb = match axis {
+            ScrollbarAxis::Vertical => LogicalRect::new(
+                LogicalPosition::new(
+                    track.origin.x + padding_start,
+                    track.origin.y + thumb_position,
+                ),
+                LogicalSize::new(thumb_thickness, thumb_length),
+            ),
+            ScrollbarAxis::Horizontal => LogicalRect::new(
+                LogicalPosition::new(
+                    track.origin.x + thumb_position,
+                    track.origin.y + padding_start,
+                ),
+                LogicalSize::new(thumb_length, thumb_thickness),
+            ),
+        };
+
+        Self {
+            node_id,
+            axis,
+            track,
+            thumb,
+            thumb_travel,
+            scroll_offset,
+            max_scroll,
+            viewport_size,
+        }
+    }
+
+    /// Returns which part of the scrollbar is at the `cursor` position
+    /// (the thumb extends over the padding of the track)
+    pub fn hit_test(&self, cursor: LogicalPosition) -> Option<ScrollbarPart> {
+        self.track.hit_test(&cursor)?;
+        let (cursor, thumb_start, thumb_end) = match self.axis {
+            ScrollbarAxis::Vertical => (cursor.y, self.thumb.min_y(), self.thumb.max_y()),
+            ScrollbarAxis::Horizontal => (cursor.x, self.thumb.min_x(), self.thumb.max_x()),
+        };
+        if cursor >= thumb_start && cursor < thumb_end {
+            Some(ScrollbarPart::Thumb)
+        } else {
+            Some(ScrollbarPart::Track)
+        }
+    }
+
+    /// Returns the scroll offset after the thumb was dragged
+    /// by `delta` pixels, starting at the scroll offset `start`
+    pub fn get_dragged_offset(&self, start: f32, delta: f32) -> f32 {
+        if self.thumb_travel <= 0.0 {
+            return start;
+        }
+        (start + delta * self.max_scroll / self.thumb_travel)
+            .max(0.0)
+            .min(self.max_scroll)
+    }
+
+    /// Returns the scroll offset after pressing the track at `cursor`,
+    /// which scrolls by one page towards the cursor
+    pub fn get_paged_offset(&self, cursor: LogicalPosition) -> f32 {
+        let (cursor, thumb_start) = match self.axis {
+            ScrollbarAxis::Vertical => (cursor.y, self.thumb.min_y()),
+            ScrollbarAxis::Horizontal => (cursor.x, self.thumb.min_x()),
+        };
+        let page = self.viewport_size * PAGE_SCROLL_FACTOR;
+        let offset = if cursor < thumb_start {
+            self.scroll_offset - page
+        } else {
+            self.scroll_offset + page
+        };
+        offset.max(0.0).min(self.max_scroll)
+    }
+}
+
+/// Scrollbar that is currently pressed
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ScrollbarDrag {
+    pub node_id: NodeHierarchyItemId,
+    pub axis: ScrollbarAxis,
+    /// Pressing the track only pages once, but holds the mouse
+    /// until the button is released
+    pub part: ScrollbarPart,
+    /// Cursor position when the scrollbar was pressed
+    pub start_cursor: LogicalPosition,
+    /// Scroll offset of the container when the scrollbar was pressed
+    pub start_offset: LogicalPosition,
+}
+
+/// Result of `ScrollbarStates::process_input`
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ScrollbarInputResult {
+    /// Whether the display list has to be rebuilt, because a scrollbar was
+    /// (un-)hovered, pressed or (dis-)appeared
+    pub needs_redraw: bool,
+    /// Whether the mouse input was consumed by a scrollbar, i.e. the callbacks
+    /// of the nodes below the scrollbar must not be invoked
+    pub mouse_captured: bool,
+    /// Scroll containers that were scrolled by the scrollbars
+    /// (these get an `On::Scrolled` event)
+    pub scrolled_nodes: BTreeMap<DomId, BTreeSet<NodeHierarchyItemId>>,
+}
+
+/// Track or thumb of a scrollbar
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScrollbarRect {
+    pub node_id: NodeHierarchyItemId,
+    pub axis: ScrollbarAxis,
+    pub part: ScrollbarPart,
+    /// Position of the rect in window coordinates
+    pub rect: LogicalRect,
+    pub background: StyleBackgroundContent,
+    /// Transform of the rect in the display list, which moves it while scrolling
+    pub transform_key: TransformKey,
+}
+
+/// Hover and drag state of the scrollbars of a window
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ScrollbarStates {
+    /// Scrollbar part under the cursor
+    pub hovered: Option<(NodeHierarchyItemId, ScrollbarAxis, ScrollbarPart)>,
+    /// Scrollbar that is currently pressed
+    pub pressed: Option<ScrollbarDrag>,
+    /// Scroll containers under the cursor: auto-hiding scrollbars
+    /// are only shown while the cursor is over their container
+    pub hovered_containers: BTreeSet<NodeId>,
+    /// Tracks and thumbs as they were drawn into the current display list
+    pub drawn: Vec<ScrollbarRect>,
+}
+
+impl ScrollbarStates {
+    /// Updates the hover / drag state from the mouse state of the window and scrolls
+    /// the containers whose scrollbars are dragged or whose track was pressed
+    pub fn process_input(
+        &mut self,
+        current_window_state: &FullWindowState,
+        previous_window_state: &Option<FullWindowState>,
+        layout_results: &[LayoutResult],
+        scroll_states: &mut ScrollStates,
+    ) -> ScrollbarInputResult {
+        let mut result = ScrollbarInputResult::default();
+        let previous_states = self.clone();
+
+        let layout_result = match layout_results.get(DomId::ROOT_ID.inner) {
+            Some(s) => s,
+            None => {
+                self.hovered = None;
+                self.pressed = None;
+                self.hovered_containers.clear();
+                result.needs_redraw = previous_states != *self;
+                return result;
+            }
+        };
+
+        let mouse_state = &current_window_state.mouse_state;
+        let cursor = mouse_state.cursor_position.get_position();
+        let left_was_down = previous_window_state
+            .as_ref()
+            .map(|s| s.mouse_state.left_down)
+            .unwrap_or(false);
+
+        self.hovered_containers = current_window_state
+            .last_hit_test
+            .hovered_nodes
+            .get(&DomId::ROOT_ID)
+            .map(|ht| ht.scroll_hit_test_nodes.keys().copied().collect())
+            .unwrap_or_default();
+
+        let scrollbars = get_scrollbars(layout_result, scroll_states);
+
+        // nested scroll containers are drawn on top of their parents
+        self.hovered = cursor.and_then(|cursor| {
+            scrollbars.iter().rev().find_map(|scrollbar| {
+                let part = scrollbar.hit_test(cursor)?;
+                Some((scrollbar.node_id, scrollbar.axis, part))
+            })
+        });
+
+        let mut nodes_scrolled = BTreeMap::new();
+
+        if !mouse_state.left_down {
+            self.pressed = None;
+        } else if !left_was_down {
+            // left mouse button was just pressed
+            if let (Some(cursor), Some((node_id, axis, part))) = (cursor, self.hovered) {
+                let scrollbar = scrollbars
+                    .iter()
+                    .find(|s| s.node_id == node_id && s.axis == axis);
+                let scroll_node = layout_result
+                    .scrollable_nodes
+                    .overflowing_nodes
+                    .get(&node_id);
+                if let (Some(scrollbar), Some(scroll_node)) = (scrollbar, scroll_node) {
+                    let start_offset =
+                        get_scroll_offset(scroll_states, scroll_node).unwrap_or_default();
+                    if part == ScrollbarPart::Track {
+                        let offset = scrollbar.get_paged_offset(cursor);
+                        nodes_scrolled.insert(node_id, with_axis(start_offset, axis, offset));
+                    }
+                    self.pressed = Some(ScrollbarDrag {
+                        node_id,
+                        axis,
+                        part,
+                        start_cursor: cursor,
+                        start_offset,
+                    });
+                }
+            }
+        } else if let (Some(cursor), Some(drag)) = (cursor, self.pressed) {
+            let scrollbar = scrollbars
+                .iter()
+                .find(|s| s.node_id == drag.node_id && s.axis == drag.axis);
+            if let (ScrollbarPart::Thumb, Some(scrollbar)) = (drag.part, scrollbar) {
+                let (start, delta) = match drag.axis {
+                    ScrollbarAxis::Vertical => {
+                        (drag.start_offset.y, cursor.y - drag.start_cursor.y)
+                    }
+                    ScrollbarAxis::Horizontal => {
+                        (drag.start_offset.x, cursor.x - drag.start_cursor.x)
+                    }
+                };
+                let offset = scrollbar.get_dragged_offset(start, delta);
+                nodes_scrolled.insert(
+                    drag.node_id,
+                    with_axis(drag.start_offset, drag.axis, offset),
+                );
+            }
+        }
+
+        if !nodes_scrolled.is_empty() {
+            let mut map = BTreeMap::new();
+            map.insert(DomId::ROOT_ID, nodes_scrolled);
+            result.scrolled_nodes =
+                scroll_states.apply_nodes_scrolled_in_callbacks(&map, layout_results, 0.0);
+        }
+
+        // the release of the mouse button also belongs to the scrollbar
+        result.mouse_captured = self.pressed.is_some() || previous_states.pressed.is_some();
+
+        let auto_hide = current_window_state.flags.scrollbar_auto_hide_enabled;
+        result.needs_redraw = self.hovered != previous_states.hovered
+            || self.pressed.map(|p| (p.node_id, p.axis))
+                != previous_states.pressed.map(|p| (p.node_id, p.axis))
+            || (auto_hide && self.hovered_containers != previous_states.hovered_containers);
+
+        result
+    }
+
+    /// Returns the tracks and thumbs of all visible scrollbars at their current
+    /// position, in drawing order. Rects that are already drawn keep their `TransformKey`.
+    pub fn get_scrollbar_rects(
+        &self,
+        layout_results: &[LayoutResult],
+        scroll_states: &ScrollStates,
+        auto_hide: bool,
+    ) -> Vec<ScrollbarRect> {
+        let mut rects = Vec::new();
+
+        let layout_result = match layout_results.get(DomId::ROOT_ID.inner) {
+            Some(s) => s,
+            None => return rects,
+        };
+
+        for scrollbar in get_scrollbars(layout_result, scroll_states) {
+            let is_pressed = self
+                .pressed
+                .map(|p| p.node_id == scrollbar.node_id && p.axis == scrollbar.axis)
+                .unwrap_or(false);
+            let is_hovered = self
+                .hovered
+                .map(|(node_id, axis, part)| {
+                    node_id == scrollbar.node_id
+                        && axis == scrollbar.axis
+                        && part == ScrollbarPart::Thumb
+                })
+                .unwrap_or(false);
+
+            let nid = match scrollbar.node_id.into_crate_internal() {
+                Some(s) => s,
+                None => continue,
+            };
+
+            let is_visible = !auto_hide || is_pressed || self.hovered_containers.contains(&nid);
+            if !is_visible {
+                continue;
+            }
+
+            let normal = get_scrollbar_info(layout_result, nid, scrollbar.axis, false, false);
+            let thumb = if is_hovered || is_pressed {
+                let styled =
+                    get_scrollbar_info(layout_result, nid, scrollbar.axis, is_hovered, is_pressed);
+                if styled.thumb != normal.thumb {
+                    styled.thumb
+                } else {
+                    // no :hover / :active style, highlight the default thumb
+                    darken(&normal.thumb, if is_pressed { 0.6 } else { 0.8 })
+                }
+            } else {
+                normal.thumb.clone()
+            };
+
+            for (part, rect, background) in [
+                (ScrollbarPart::Track, scrollbar.track, normal.track),
+                (ScrollbarPart::Thumb, scrollbar.thumb, thumb),
+            ] {
+                let transform_key = self
+                    .get_drawn(scrollbar.node_id, scrollbar.axis, part)
+                    .map(|d| d.transform_key)
+                    .unwrap_or_else(TransformKey::unique);
+                rects.push(ScrollbarRect {
+                    node_id: scrollbar.node_id,
+                    axis: scrollbar.axis,
+                    part,
+                    rect,
+                    background,
+                    transform_key,
+                });
+            }
+        }
+
+        rects
+    }
+
+    /// Returns whether the display list has to be rebuilt, because scrollbars
+    /// (dis-)appeared or changed their size or style since they were drawn.
+    /// Scrollbars that only moved are updated with `get_scrollbar_transforms()`.
+    pub fn geometry_changed(
+        &self,
+        layout_results: &[LayoutResult],
+        scroll_states: &ScrollStates,
+        auto_hide: bool,
+    ) -> bool {
+        let current = self.get_scrollbar_rects(layout_results, scroll_states, auto_hide);
+        current.len() != self.drawn.len()
+            || current.iter().zip(self.drawn.iter()).any(|(c, d)| {
+                c.transform_key != d.transform_key
+                    || c.rect.size != d.rect.size
+                    || c.background != d.background
+            })
+    }
+
+    /// Returns the translations that move the drawn tracks and thumbs
+    /// to their current position
+    pub fn get_scrollbar_transforms(
+        &self,
+        layout_results: &[LayoutResult],
+        scroll_states: &ScrollStates,
+        auto_hide: bool,
+    ) -> Vec<(TransformKey, ComputedTransform3D)> {
+        let current = self.get_scrollbar_rects(layout_results, scroll_states, auto_hide);
+        self.drawn
+            .iter()
+            .map(|d| {
+                let transform = current
+                    .iter()
+                    .find(|c| c.transform_key == d.transform_key)
+                    .map(|c| {
+                        ComputedTransform3D::new_translation(
+                            c.rect.origin.x - d.rect.origin.x,
+                            c.rect.origin.y - d.rect.origin.y,
+                            0.0,
+                        )
+                    })
+                    .unwrap_or(ComputedTransform3D::IDENTITY);
+                (d.transform_key, transform)
+            })
+            .collect()
+    }
+
+    fn get_drawn(
+        &self,
+        node_id: NodeHierarchyItemId,
+        axis: ScrollbarAxis,
+        part: ScrollbarPart,
+    ) -> Option<&ScrollbarRect> {
+        self.drawn
+            .iter()
+            .find(|d| d.node_id == node_id && d.axis == axis && d.part == part)
+    }
+}
+
+/// Returns the scrollbars of all scroll containers of the DOM that need one
+pub fn get_scrollbars(
+    layout_result: &LayoutResult,
+    scroll_states: &ScrollStates,
+) -> Vec<ScrollbarGeometry> {
+    let mut scrollbars = Vec::new();
+    let rects = layout_result.rects.as_ref();
+
+    for (node_id, scroll_node) in layout_result.scrollable_nodes.overflowing_nodes.iter() {
+        let nid = match node_id.into_crate_internal() {
+            Some(s) => s,
+            None => continue,
+        };
+        let rect = match rects.get(nid) {
+            Some(s) => s,
+            None => continue,
+        };
+        let visible_rect = match get_visible_rect(layout_result, nid, scroll_node, scroll_states) {
+            Some(s) => s,
+            None => continue,
+        };
+
+        let max = scroll_node.get_max_scroll_position();
+        let offset = get_scroll_offset(scroll_states, scroll_node).unwrap_or_default();
+        let viewport = scroll_node.parent_rect.size;
+
+        let show_x = rect.overflow_x.needs_scrollbar(max.x > 0.0);
+        let show_y = rect.overflow_y.needs_scrollbar(max.y > 0.0);
+        let x_info =
+            get_scrollbar_info(layout_result, nid, ScrollbarAxis::Horizontal, false, false);
+        let y_info = get_scrollbar_info(layout_result, nid, ScrollbarAxis::Vertical, false, false);
+        let x_thickness = x_info.width.inner.to_pixels(viewport.height);
+        let y_thickness = y_info.width.inner.to_pixels(viewport.width);
+
+        if show_y {
+            scrollbars.push(ScrollbarGeometry::new(
+                *node_id,
+                ScrollbarAxis::Vertical,
+                visible_rect,
+                y_thickness,
+                y_info.padding_left.inner.to_pixels(y_thickness),
+                y_info.padding_right.inner.to_pixels(y_thickness),
+                if show_x { x_thickness } else { 0.0 },
+                viewport.height,
+                max.y,
+                offset.y,
+            ));
+        }
+
+        if show_x {
+            scrollbars.push(ScrollbarGeometry::new(
+                *node_id,
+                ScrollbarAxis::Horizontal,
+                visible_rect,
+                x_thickness,
+                x_info.padding_left.inner.to_pixels(x_thickness),
+                x_info.padding_right.inner.to_pixels(x_thickness),
+                if show_y { y_thickness } else { 0.0 },
+                viewport.width,
+                max.x,
+                offset.x,
+            ));
+        }
+    }
+
+    scrollbars
+}
+
+/// Returns the scroll position after pressing PageUp, PageDown, Home or End
+/// in a scroll container, `None` if the key doesn't scroll
+pub fn get_keyboard_scroll_position(
+    key: VirtualKeyCode,
+    current: LogicalPosition,
+    max: LogicalPosition,
+    viewport: LogicalSize,
+) -> Option<LogicalPosition> {
+    let page = viewport.height * PAGE_SCROLL_FACTOR;
+    let y = match key {
+        VirtualKeyCode::PageUp => current.y - page,
+        VirtualKeyCode::PageDown => current.y + page,
+        VirtualKeyCode::Home => 0.0,
+        VirtualKeyCode::End => max.y,
+        _ => return None,
+    };
+    Some(LogicalPosition::new(current.x, y.max(0.0).min(max.y)))
+}
+
+/// Returns the node itself or its nearest ancestor that is a scroll container
+pub(crate) fn get_nearest_scroll_container(
+    layout_result: &LayoutResult,
+    node_id: NodeId,
+) -> Option<NodeId> {
+    let node_hierarchy = layout_result.styled_dom.node_hierarchy.as_container();
+    let mut current = Some(node_id);
+    while let Some(nid) = current {
+        if layout_result
+            .scrollable_nodes
+            .overflowing_nodes
+            .contains_key(&NodeHierarchyItemId::from_crate_internal(Some(nid)))
+        {
+            return Some(nid);
+        }
+        current = node_hierarchy.get(nid)?.parent_id();
+    }
+    None
+}
+
+// Resolves the -azul-scrollbar-style of the scroll container for the given state
+fn get_scrollbar_info(
+    layout_result: &LayoutResult,
+    node_id: NodeId,
+    axis: ScrollbarAxis,
+    hover: bool,
+    active: bool,
+) -> ScrollbarInfo {
+    let styled_dom = &layout_result.styled_dom;
+    let state = StyledNodeState {
+        normal: !hover && !active,
+        hover,
+        active,
+        focused: false,
+    };
+    let style = styled_dom
+        .get_css_property_cache()
+        .get_scrollbar_style(
+            &styled_dom.node_data.as_container()[node_id],
+            &node_id,
+            &state,
+        )
+        .and_then(|p| p.get_property().cloned())
+        .unwrap_or_default();
+    match axis {
+        ScrollbarAxis::Horizontal => style.horizontal,
+        ScrollbarAxis::Vertical => style.vertical,
+    }
+}
+
+// Returns the rectangle of the scroll container after scrolling its parent scroll
+// containers, clipped to their visible rectangles - None if it is scrolled out of view
+fn get_visible_rect(
+    layout_result: &LayoutResult,
+    node_id: NodeId,
+    scroll_node: &OverflowingScrollNode,
+    scroll_states: &ScrollStates,
+) -> Option<LogicalRect> {
+    let node_hierarchy = layout_result.styled_dom.node_hierarchy.as_container();
+
+    // scroll containers that the node is in, from the innermost to the outermost one
+    let mut parents = Vec::new();
+    let mut parent = node_hierarchy.get(node_id)?.parent_id();
+    while let Some(parent_id) = parent {
+        if let Some(parent_scroll_node) = layout_result
+            .scrollable_nodes
+            .overflowing_nodes
+            .get(&NodeHierarchyItemId::from_crate_internal(Some(parent_id)))
+        {
+            let offset = get_scroll_offset(scroll_states, parent_scroll_node).unwrap_or_default();
+            parents.push((parent_scroll_node.parent_rect, offset));
+        }
+        parent = node_hierarchy.get(parent_id)?.parent_id();
+    }
+
+    let mut total_offset = LogicalPosition::zero();
+    for (_, offset) in parents.iter() {
+        total_offset += *offset;
+    }
+
+    let mut visible_rect = translate(scroll_node.parent_rect, total_offset);
+    let mut outer_offset = total_offset;
+    for (parent_rect, offset) in parents.iter() {
+        outer_offset.x -= offset.x;
+        outer_offset.y -= offset.y;
+        visible_rect = intersect(visible_rect, translate(*parent_rect, outer_offset))?;
+    }
+
+    Some(visible_rect)
+}
+
+fn get_scroll_offset(
+    scroll_states: &ScrollStates,
+    scroll_node: &OverflowingScrollNode,
+) -> Option<LogicalPosition> {
+    scroll_states.get_scroll_position(&scroll_node.parent_external_scroll_id)
+}
+
+fn translate(rect: LogicalRect, scroll_offset: LogicalPosition) -> LogicalRect {
+    LogicalRect::new(
+        LogicalPosition::new(
+            rect.origin.x - scroll_offset.x,
+            rect.origin.y - scroll_offset.y,
+        ),
+        rect.size,
+    )
+}
+
+fn intersect(a: LogicalRect, b: LogicalRect) -> Option<LogicalRect> {
+    let min_x = a.min_x().max(b.min_x());
+    let min_y = a.min_y().max(b.min_y());
+    let max_x = a.max_x().min(b.max_x());
+    let max_y = a.max_y().min(b.max_y());
+    if max_x <= min_x || max_y <= min_y {
+        return None;
+    }
+    Some(LogicalRect::new(
+        LogicalPosition::new(min_x, min_y),
+        LogicalSize::new(max_x - min_x, max_y - min_y),
+    ))
+}
+
+fn get_axis(size: LogicalSize, axis: ScrollbarAxis) -> f32 {
+    match axis {
+        ScrollbarAxis::Horizontal => size.width,
+        ScrollbarAxis::Vertical => size.height,
+    }
+}
+
+fn with_axis(position: LogicalPosition, axis: ScrollbarAxis, value: f32) -> LogicalPosition {
+    match axis {
+        ScrollbarAxis::Horizontal => LogicalPosition::new(value, position.y),
+        ScrollbarAxis::Vertical => LogicalPosition::new(position.x, value),
+    }
+}
+
+fn darken(background: &StyleBackgroundContent, factor: f32) -> StyleBackgroundContent {
+    match background {
+        StyleBackgroundContent::Color(c) => StyleBackgroundContent::Color(ColorU {
+            r: (c.r as f32 * factor) as u8,
+            g: (c.g as f32 * factor) as u8,
+            b: (c.b as f32 * factor) as u8,
+            a: c.a,
+        }),
+        other => other.clone(),
+    }
+}
+
+#[test]
+fn test_scrollbar_geometry() {
+    let node_id = NodeHierarchyItemId::from_crate_internal(Some(NodeId::new(1)));
+    let visible_rect = LogicalRect::new(
+        LogicalPosition::new(10.0, 20.0),
+        LogicalSize::new(200.0, 400.0),
+    );
+
+    // 400px container with 1600px of content, scrolled to the middle
+    let scrollbar = ScrollbarGeometry::new(
+        node_id,
+        ScrollbarAxis::Vertical,
+        visible_rect,
+        17.0,
+        2.0,
+        2.0,
+        0.0,
+        400.0,
+        1200.0,
+        600.0,
+    );
+    assert_eq!(
+        scrollbar.track,
+        LogicalRect::new(
+            LogicalPosition::new(193.0, 20.0),
+            LogicalSize::new(17.0, 400.0)
+        )
+    );
+    assert_eq!(
+        scrollbar.thumb,
+        LogicalRect::new(
+            LogicalPosition::new(195.0, 170.0),
+            LogicalSize::new(13.0, 100.0)
+        )
+    );
+    assert_eq!(scrollbar.thumb_travel, 300.0);
+
+    assert_eq!(
+        scrollbar.hit_test(LogicalPosition::new(200.0, 200.0)),
+        Some(ScrollbarPart::Thumb)
+    );
+    assert_eq!(
+        scrollbar.hit_test(LogicalPosition::new(200.0, 30.0)),
+        Some(ScrollbarPart::Track)
+    );
+    assert_eq!(scrollbar.hit_test(LogicalPosition::new(100.0, 200.0)), None);
+
+    // dragging the thumb by its travel scrolls the whole content
+    assert_eq!(scrollbar.get_dragged_offset(600.0, 150.0), 1200.0);
+    assert_eq!(scrollbar.get_dragged_offset(600.0, -30.0), 480.0);
+
+    // pressing the track pages towards the cursor
+    assert_eq!(
+        scrollbar.get_paged_offset(LogicalPosition::new(200.0, 30.0)),
+        250.0
+    );
+    assert_eq!(
+        scrollbar.get_paged_offset(LogicalPosition::new(200.0, 400.0)),
+        950.0
+    );
+
+    // very long content: the thumb doesn't get smaller than MIN_THUMB_SIZE
+    let scrollbar = ScrollbarGeometry::new(
+        node_id,
+        ScrollbarAxis::Horizontal,
+        visible_rect,
+        17.0,
+        2.0,
+        2.0,
+        17.0,
+        200.0,
+        100_000.0,
+        0.0,
+    );
+    assert_eq!(scrollbar.track.size, LogicalSize::new(183.0, 17.0));
+    assert_eq!(scrollbar.thumb.size, LogicalSize::new(MIN_THUMB_SIZE, 13.0));
+
+    assert_eq!(
+        get_keyboard_scroll_position(
+            VirtualKeyCode::PageDown,
+            LogicalPosition::new(0.0, 100.0),
+            LogicalPosition::new(0.0, 1200.0),
+            LogicalSize::new(200.0, 400.0),
+        ),
+        Some(LogicalPosition::new(0.0, 450.0))
+    );
+    assert_eq!(
+        get_keyboard_scroll_position(
+            VirtualKeyCode::End,
+            LogicalPosition::new(0.0, 100.0),
+            LogicalPosition::new(0.0, 1200.0),
+            LogicalSize::new(200.0, 400.0),
+        ),
+        Some(LogicalPosition::new(0.0, 1200.0))
+    );
+    assert_eq!(
+        get_keyboard_scroll_position(
+            VirtualKeyCode::A,
+            LogicalPosition::new(0.0, 100.0),
+            LogicalPosition::new(0.0, 1200.0),
+            LogicalSize::new(200.0, 400.0),
+        ),
+        None
+    );
+}
@@ -1,16 +1,18 @@
 use crate::{
     app_resources::{Au, ImageCache, ImageRef, ImmediateFontId, RendererResources},
-    callbacks::{CallbackInfo, RefAny, Update},
+    callbacks::{CallbackInfo, DomNodeId, RefAny, Update},
     dom::{
         CompactDom, Dom, NodeData, NodeDataInlineCssProperty, NodeDataVec, OptionTabIndex,
         TabIndex, TagId,
     },
     id_tree::{Node, NodeDataContainer, NodeDataContainerRef, NodeDataContainerRefMut, NodeId},
+    scroll::ScrollAnimator,
     style::{
         construct_html_cascade_tree, matches_html_element, rule_ends_with, CascadeInfo,
         CascadeInfoVec,
     },
-    window::Menu,
+    ui_solver::PositionedRectangle,
+    window::{LogicalPosition, Menu},
     FastBTreeSet, FastHashMap,
 };
 use alloc::boxed::Box;
@@ -27,18 +29,19 @@ use azul_css::{
     LayoutMarginRightValue, LayoutMarginTopValue, LayoutMaxHeightValue, LayoutMaxWidthValue,
     LayoutMinHeightValue, LayoutMinWidthValue, LayoutOverflowValue, LayoutPaddingBottomValue,
     LayoutPaddingLeftValue, LayoutPaddingRightValue, LayoutPaddingTopValue, LayoutPositionValue,
-    LayoutRightValue, LayoutTopValue, LayoutWidthValue, StyleBackfaceVisibilityValue,
-    StyleBackgroundContentVecValue, StyleBackgroundPositionVecValue, StyleBackgroundRepeatVecValue,
-    StyleBackgroundSizeVecValue, StyleBorderBottomColorValue, StyleBorderBottomLeftRadiusValue,
-    StyleBorderBottomRightRadiusValue, StyleBorderBottomStyleValue, StyleBorderLeftColorValue,
-    StyleBorderLeftStyleValue, StyleBorderRightColorValue, StyleBorderRightStyleValue,
-    StyleBorderTopColorValue, StyleBorderTopLeftRadiusValue, StyleBorderTopRightRadiusValue,
-    StyleBorderTopStyleValue, StyleBoxShadowValue, StyleCursorValue, StyleFilterVecValue,
-    StyleFontFamily, StyleFontFamilyVec, StyleFontFamilyVecValue, StyleFontSize,
-    StyleFontSizeValue, StyleLetterSpacingValue, StyleLineHeightValue, StyleMixBlendModeValue,
-    StyleOpacityValue, StylePerspectiveOriginValue, StyleTabWidthValue, StyleTextAlignValue,
-    StyleTextColor, StyleTextColorValue, StyleTransformOriginValue, StyleTransformVecValue,
-    StyleWordSpacingValue,
+    LayoutRightValue, LayoutTopValue, LayoutWidthValue, ScrollbarStyleValue,
+    StyleBackfaceVisibilityValue, StyleBackgroundContentVecValue, StyleBackgroundPositionVecValue,
+    StyleBackgroundRepeatVecValue, StyleBackgroundSizeVecValue, StyleBorderBottomColorValue,
+    StyleBorderBottomLeftRadiusValue, StyleBorderBottomRightRadiusValue,
+    StyleBorderBottomStyleValue, StyleBorderLeftColorValue, StyleBorderLeftStyleValue,
+    StyleBorderRightColorValue, StyleBorderRightStyleValue, StyleBorderTopColorValue,
+    StyleBorderTopLeftRadiusValue, StyleBorderTopRightRadiusValue, StyleBorderTopStyleValue,
+    StyleBoxShadowValue, StyleCursorValue, StyleFilterVecValue, StyleFontFamily,
+    StyleFontFamilyVec, StyleFontFamilyVecValue, StyleFontSize, StyleFontSizeValue,
+    StyleLetterSpacingValue, StyleLineHeightValue, StyleMixBlendModeValue, StyleOpacityValue,
+    StylePerspectiveOriginValue, StyleScrollBehaviorValue, StyleScrollSnapAlignValue,
+    StyleScrollSnapTypeValue, StyleTabWidthValue, StyleTextAlignValue, StyleTextColor,
+    StyleTextColorValue, StyleTransformOriginValue, StyleTransformVecValue, StyleWordSpacingValue,
 };
 use azul_css_parser::CssApiWrapper;
 use core::{
@@ -211,6 +214,12 @@ pub struct CssPropertyCache {
     pub css_hover_props: BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
     pub css_active_props: BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
     pub css_focus_props: BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
+
+    // which CSS rule the css_*_props were set by (only used for debugging, i.e. the inspector)
+    pub css_normal_sources: BTreeMap<NodeId, BTreeMap<CssPropertyType, CssPath>>,
+    pub css_hover_sources: BTreeMap<NodeId, BTreeMap<CssPropertyType, CssPath>>,
+    pub css_active_sources: BTreeMap<NodeId, BTreeMap<CssPropertyType, CssPath>>,
+    pub css_focus_sources: BTreeMap<NodeId, BTreeMap<CssPropertyType, CssPath>>,
 }
 
 impl CssPropertyCache {
@@ -252,13 +261,33 @@ impl CssPropertyCache {
                     .iter()
                     .filter_map(move |declaration| {
                         match declaration {
-                            CssDeclaration::Static(s) => Some(s),
+                            CssDeclaration::Static(s) => Some((&matched_rule.path, s)),
                             CssDeclaration::Dynamic(_d) => None, // TODO: No variable support yet!
                         }
                     })
                 })
-                .map(|prop| prop.clone())
-                .collect::<Vec<CssProperty>>()
+                .map(|(path, prop)| (path, prop.clone()))
+                .collect::<Vec<(&CssPath, CssProperty)>>()
+            }};}
+
+            // Splits the matched rules into the property map and the source map
+            // (later rules override earlier rules, so that the sources stay in sync),
+            // only the path of the winning rule is cloned
+            macro_rules! collect_props_and_sources {($rules:expr, $props:expr, $sources:expr) => {{
+                let mut props = BTreeMap::new();
+                let mut sources = BTreeMap::new();
+                for (node_id, matched) in $rules.internal.into_iter() {
+                    let mut node_props = BTreeMap::new();
+                    let mut node_sources = BTreeMap::new();
+                    for (path, prop) in matched {
+                        node_sources.insert(prop.get_type(), path);
+                        node_props.insert(prop.get_type(), prop);
+                    }
+                    props.insert(node_id, node_props);
+                    sources.insert(node_id, node_sources.into_iter().map(|(k, v)| (k, v.clone())).collect());
+                }
+                $props = props;
+                $sources = sources;
             }};}
 
             // NOTE: This is wrong, but fast
@@ -270,7 +299,7 @@ impl CssPropertyCache {
             // but that can be fixed later
 
             // go through each HTML node (in parallel) and see which CSS rules match
-            let css_normal_rules: NodeDataContainer<(NodeId, Vec<CssProperty>)> = node_data
+            let css_normal_rules: NodeDataContainer<(NodeId, Vec<(&CssPath, CssProperty)>)> = node_data
                 .transform_nodeid_multithreaded_optional(|node_id| {
                     let r = filter_rules!(None, node_id);
                     if r.is_empty() {
@@ -280,7 +309,7 @@ impl CssPropertyCache {
                     }
                 });
 
-            let css_hover_rules: NodeDataContainer<(NodeId, Vec<CssProperty>)> = node_data
+            let css_hover_rules: NodeDataContainer<(NodeId, Vec<(&CssPath, CssProperty)>)> = node_data
                 .transform_nodeid_multithreaded_optional(|node_id| {
                     let r = filter_rules!(Some(Hover), node_id);
                     if r.is_empty() {
@@ -290,7 +319,7 @@ impl CssPropertyCache {
                     }
                 });
 
-            let css_active_rules: NodeDataContainer<(NodeId, Vec<CssProperty>)> = node_data
+            let css_active_rules: NodeDataContainer<(NodeId, Vec<(&CssPath, CssProperty)>)> = node_data
                 .transform_nodeid_multithreaded_optional(|node_id| {
                     let r = filter_rules!(Some(Active), node_id);
                     if r.is_empty() {
@@ -300,7 +329,7 @@ impl CssPropertyCache {
                     }
                 });
 
-            let css_focus_rules: NodeDataContainer<(NodeId, Vec<CssProperty>)> = node_data
+            let css_focus_rules: NodeDataContainer<(NodeId, Vec<(&CssPath, CssProperty)>)> = node_data
                 .transform_nodeid_multithreaded_optional(|node_id| {
                     let r = filter_rules!(Some(Focus), node_id);
                     if r.is_empty() {
@@ -310,57 +339,10 @@ impl CssPropertyCache {
                     }
                 });
 
-            self.css_normal_props = css_normal_rules
-                .internal
-                .into_iter()
-                .map(|(n, map)| {
-                    (
-                        n,
-                        map.into_iter()
-                            .map(|prop| (prop.get_type(), prop))
-                            .collect(),
-                    )
-                })
-                .collect();
-
-            self.css_hover_props = css_hover_rules
-                .internal
-                .into_iter()
-                .map(|(n, map)| {
-                    (
-                        n,
-                        map.into_iter()
-                            .map(|prop| (prop.get_type(), prop))
-                            .collect(),
-                    )
-                })
-                .collect();
-
-            self.css_active_props = css_active_rules
-                .internal
-                .into_iter()
-                .map(|(n, map)| {
-                    (
-                        n,
-                        map.into_iter()
-                            .map(|prop| (prop.get_type(), prop))
-                            .collect(),
-                    )
-                })
-                .collect();
-
-            self.css_focus_props = css_focus_rules
-                .internal
-                .into_iter()
-                .map(|(n, map)| {
-                    (
-                        n,
-                        map.into_iter()
-                            .map(|prop| (prop.get_type(), prop))
-                            .collect(),
-                    )
-                })
-                .collect();
+            collect_props_and_sources!(css_normal_rules, self.css_normal_props, self.css_normal_sources);
+            collect_props_and_sources!(css_hover_rules, self.css_hover_props, self.css_hover_sources);
+            collect_props_and_sources!(css_active_rules, self.css_active_props, self.css_active_sources);
+            collect_props_and_sources!(css_focus_rules, self.css_focus_props, self.css_focus_sources);
         }
 
         // Inheritance: Inherit all values of the parent to the children, but
@@ -511,6 +493,18 @@ impl CssPropertyCache {
                         break;
                     }
 
+                    // draggable nodes have to be hit-tested to start a drag
+                    if node_data.is_draggable() {
+                        node_should_have_tag = true;
+                        break;
+                    }
+
+                    // custom title bars: the shell starts a window move / resize
+                    if node_data.get_window_region().is_some() {
+                        node_should_have_tag = true;
+                        break;
+                    }
+
                     if tab_index.is_some() {
                         node_should_have_tag = true;
                         break;
@@ -739,6 +733,21 @@ impl CssPropertyCache {
         if let Some(p) = self.get_backface_visibility(&node_data, node_id, node_state) {
             s.push_str(&format!("backface-visibility: {};", p.get_css_value_fmt()));
         }
+        if let Some(p) = self.get_scroll_behavior(&node_data, node_id, node_state) {
+            s.push_str(&format!("scroll-behavior: {};", p.get_css_value_fmt()));
+        }
+        if let Some(p) = self.get_scroll_snap_type(&node_data, node_id, node_state) {
+            s.push_str(&format!("scroll-snap-type: {};", p.get_css_value_fmt()));
+        }
+        if let Some(p) = self.get_scroll_snap_align(&node_data, node_id, node_state) {
+            s.push_str(&format!("scroll-snap-align: {};", p.get_css_value_fmt()));
+        }
+        if let Some(p) = self.get_scrollbar_style(&node_data, node_id, node_state) {
+            s.push_str(&format!(
+                "-azul-scrollbar-style: {};",
+                p.get_css_value_fmt()
+            ));
+        }
         if let Some(p) = self.get_display(&node_data, node_id, node_state) {
             s.push_str(&format!("display: {};", p.get_css_value_fmt()));
         }
@@ -903,6 +912,11 @@ impl CssPropertyCache {
             css_hover_props: BTreeMap::new(),
             css_active_props: BTreeMap::new(),
             css_focus_props: BTreeMap::new(),
+
+            css_normal_sources: BTreeMap::new(),
+            css_hover_sources: BTreeMap::new(),
+            css_active_sources: BTreeMap::new(),
+            css_focus_sources: BTreeMap::new(),
         }
     }
 
@@ -927,6 +941,10 @@ impl CssPropertyCache {
         append_css_property_vec!(css_hover_props);
         append_css_property_vec!(css_active_props);
         append_css_property_vec!(css_focus_props);
+        append_css_property_vec!(css_normal_sources);
+        append_css_property_vec!(css_hover_sources);
+        append_css_property_vec!(css_active_sources);
+        append_css_property_vec!(css_focus_sources);
 
         self.node_count += other.node_count;
     }
@@ -1226,6 +1244,61 @@ impl CssPropertyCache {
         None
     }
 
+    /// Returns where the value returned by `get_property` was set: by a CSS rule or inline
+    /// on the node (properties overridden in callbacks count as inline properties).
+    ///
+    /// Returns `None` if the property is inherited from the parent or not set at all.
+    pub fn get_property_source(
+        &self,
+        node_data: &NodeData,
+        node_id: &NodeId,
+        node_state: &StyledNodeState,
+        css_property_type: &CssPropertyType,
+    ) -> Option<CssPropertySource> {
+        if self
+            .user_overridden_properties
+            .get(node_id)
+            .and_then(|n| n.get(css_property_type))
+            .is_some()
+        {
+            return Some(CssPropertySource::Inline);
+        }
+
+        macro_rules! check_state {($state:ident, $props:ident, $sources:ident, $inline:ident, $cascaded:ident) => {
+            if node_state.$state {
+                if self.$props.get(node_id).and_then(|map| map.get(css_property_type)).is_some() {
+                    return self.$sources
+                        .get(node_id)
+                        .and_then(|map| map.get(css_property_type))
+                        .map(|path| CssPropertySource::Css(path.clone()));
+                }
+
+                let has_inline_prop = node_data.inline_css_props.as_ref().iter().any(|css_prop| {
+                    match css_prop {
+                        NodeDataInlineCssProperty::$inline(p) => p.get_type() == *css_property_type,
+                        _ => false,
+                    }
+                });
+
+                if has_inline_prop {
+                    return Some(CssPropertySource::Inline);
+                }
+
+                if self.$cascaded.get(node_id).and_then(|map| map.get(css_property_type)).is_some() {
+                    return None;
+                }
+            }
+        };}
+
+        // same order as get_property: :focus > :active > :hover > :normal
+        check_state!(focused, css_focus_props, css_focus_sources, Focus, cascaded_focus_props);
+        check_state!(active, css_active_props, css_active_sources, Active, cascaded_active_props);
+        check_state!(hover, css_hover_props, css_hover_sources, Hover, cascaded_hover_props);
+        check_state!(normal, css_normal_props, css_normal_sources, Normal, cascaded_normal_props);
+
+        None
+    }
+
     pub fn get_background_content<'a>(
         &'a self,
         node_data: &'a NodeData,
@@ -1657,6 +1730,62 @@ impl CssPropertyCache {
         )
         .and_then(|p| p.as_backface_visibility())
     }
+    pub fn get_scroll_behavior<'a>(
+        &'a self,
+        node_data: &'a NodeData,
+        node_id: &NodeId,
+        node_state: &StyledNodeState,
+    ) -> Option<&'a StyleScrollBehaviorValue> {
+        self.get_property(
+            node_data,
+            node_id,
+            node_state,
+            &CssPropertyType::ScrollBehavior,
+        )
+        .and_then(|p| p.as_scroll_behavior())
+    }
+    pub fn get_scroll_snap_type<'a>(
+        &'a self,
+        node_data: &'a NodeData,
+        node_id: &NodeId,
+        node_state: &StyledNodeState,
+    ) -> Option<&'a StyleScrollSnapTypeValue> {
+        self.get_property(
+            node_data,
+            node_id,
+            node_state,
+            &CssPropertyType::ScrollSnapType,
+        )
+        .and_then(|p| p.as_scroll_snap_type())
+    }
+    pub fn get_scroll_snap_align<'a>(
+        &'a self,
+        node_data: &'a NodeData,
+        node_id: &NodeId,
+        node_state: &StyledNodeState,
+    ) -> Option<&'a StyleScrollSnapAlignValue> {
+        self.get_property(
+            node_data,
+            node_id,
+            node_state,
+            &CssPropertyType::ScrollSnapAlign,
+        )
+        .and_then(|p| p.as_scroll_snap_align())
+    }
+    pub fn get_scrollbar_style<'a>(
+        &'a self,
+        node_data: &'a NodeData,
+        node_id: &NodeId,
+        node_state: &StyledNodeState,
+    ) -> Option<&'a ScrollbarStyleValue> {
+        self.get_property(
+            node_data,
+            node_id,
+            node_state,
+            &CssPropertyType::ScrollbarStyle,
+        )
+        .and_then(|p| p.as_scrollbar_style())
+    }
     pub fn get_display<'a>(
         &'a self,
         node_data: &'a NodeData,
@@ -2842,10 +2971,14 @@ impl StyledDom {
     #[inline]
     pub fn insert_default_system_callbacks(&mut self, config: DefaultCallbacksCfg) {
         use crate::callbacks::Callback;
-        use crate::dom::{CallbackData, EventFilter, FocusEventFilter, HoverEventFilter};
+        use crate::dom::{
+            CallbackData, EventFilter, FocusEventFilter, HoverEventFilter, WindowEventFilter,
+        };
 
         let scroll_refany = RefAny::new(DefaultScrollCallbackData {
             smooth_scroll: config.smooth_scroll,
+            overscroll_bounce: config.overscroll_bounce,
+            animator: ScrollAnimator::default(),
         });
 
         for n in self.node_data.iter_mut() {
@@ -2865,6 +2998,26 @@ impl StyledDom {
             }
         }
 
+        // PageUp / PageDown / Home / End share the scroll animations with On::Scroll
+        if let Some(root) = self.node_data.as_container_mut().get_mut(NodeId::ZERO) {
+            let key_event = EventFilter::Window(WindowEventFilter::VirtualKeyDown);
+            if !root.callbacks.iter().any(|cb| {
+                cb.event == key_event && cb.data.get_type_id() == scroll_refany.get_type_id()
+            }) {
+                root.callbacks.push(CallbackData {
+                    event: key_event,
+                    data: scroll_refany.clone(),
+                    callback: Callback {
+                        cb: default_on_scroll_key,
+                    },
+                });
+                let root_id = NodeHierarchyItemId::from_crate_internal(Some(NodeId::ZERO));
+                if !self.nodes_with_window_callbacks.as_ref().contains(&root_id) {
+                    self.nodes_with_window_callbacks.push(root_id);
+                }
+            }
+        }
+
         if !config.enable_autotab {
             return;
         }
@@ -3545,6 +3698,32 @@ impl StyledDom {
     /// </div>
     /// ```
     pub fn get_html_string(&self, custom_head: &str, custom_body: &str, test_mode: bool) -> String {
+        self.get_html_string_internal(custom_head, custom_body, test_mode, None)
+    }
+
+    /// Same as `get_html_string`, but positions every node absolutely at its solved
+    /// `PositionedRectangle`, so that the layout of azul can be compared with a browser.
+    ///
+    /// If `computed_style` is false, the computed CSS properties are left out of
+    /// the inline style, so that the nodes can be styled by the original stylesheet
+    /// (which should then be passed in via the `custom_head`).
+    pub fn get_html_string_with_layout(
+        &self,
+        rects: &NodeDataContainerRef<PositionedRectangle>,
+        custom_head: &str,
+        custom_body: &str,
+        computed_style: bool,
+    ) -> String {
+        self.get_html_string_internal(custom_head, custom_body, false, Some((rects, computed_style)))
+    }
+
+    fn get_html_string_internal(
+        &self,
+        custom_head: &str,
+        custom_body: &str,
+        test_mode: bool,
+        layout: Option<(&NodeDataContainerRef<PositionedRectangle>, bool)>,
+    ) -> String {
         let css_property_cache = self.get_css_property_cache();
 
         let mut output = String::new();
@@ -3603,7 +3782,40 @@ impl StyledDom {
 
             output.push_str("\r\n");
             output.push_str(&tabs);
-            output.push_str(&node_data.debug_print_start(css_property_cache, &node_id, node_state));
+            match layout {
+                Some((rects, computed_style)) => {
+                    let rect = &rects[node_id];
+                    // the solved size and offset are the content box, the
+                    // exported nodes use box-sizing: border-box
+                    let (border_box_size, _) = rect.get_background_bounds();
+                    let content_origin = rect.position.get_static_offset();
+                    let origin = (
+                        content_origin.x - rect.padding.left - rect.border_widths.left,
+                        content_origin.y - rect.padding.top - rect.border_widths.top,
+                    );
+                    // absolute positions are relative to the padding box of the parent
+                    let parent_origin = self.node_hierarchy.as_container()[node_id]
+                        .parent_id()
+                        .map(|parent| {
+                            let parent_rect = &rects[parent];
+                            let o = parent_rect.position.get_static_offset();
+                            (o.x - parent_rect.padding.left, o.y - parent_rect.padding.top)
+                        })
+                        .unwrap_or((0.0, 0.0));
+                    let layout_style = format!(
+                        "position: absolute; left: {}px; top: {}px; width: {}px; height: {}px;",
+                        origin.0 - parent_origin.0,
+                        origin.1 - parent_origin.1,
+                        border_box_size.width,
+                        border_box_size.height,
+                    );
+                    let css_cache = if computed_style { Some((css_property_cache, node_state)) } else { None };
+                    output.push_str(&node_data.debug_print_start_with_layout(css_cache, &node_id, &layout_style));
+                },
+                None => {
+                    output.push_str(&node_data.debug_print_start(css_property_cache, &node_id, node_state));
+                },
+            }
 
             if let Some(content) = node_data.get_node_type().format().as_ref() {
                 output.push_str(content);
@@ -3655,6 +3867,41 @@ impl StyledDom {
         }
     }
 
+    /// Returns all nodes that a CSS rule with the given path gets applied to.
+    ///
+    /// Matches the path the same way as `CssPropertyCache::restyle`: a path ending in
+    /// `:hover`, `:active` or `:focus` matches the node independent of its current state,
+    /// paths ending in any other pseudo-selector never match.
+    pub fn get_nodes_matching_css_path(&self, path: &CssPath) -> Vec<NodeId> {
+        use azul_css::CssPathPseudoSelector::*;
+
+        let expected_path_ending = match [None, Some(Hover), Some(Active), Some(Focus)]
+            .iter()
+            .find(|p| rule_ends_with(path, **p))
+        {
+            Some(s) => *s,
+            None => return Vec::new(),
+        };
+
+        let node_hierarchy = self.node_hierarchy.as_container();
+        let node_data = self.node_data.as_container();
+        let html_tree = self.cascade_info.as_container();
+
+        (0..self.node_data.len())
+            .map(NodeId::new)
+            .filter(|node_id| {
+                matches_html_element(
+                    path,
+                    *node_id,
+                    &node_hierarchy,
+                    &node_data,
+                    &html_tree,
+                    expected_path_ending,
+                )
+            })
+            .collect()
+    }
+
     // Same as get_subtree, but only returns parents
     pub fn get_subtree_parents(&self, parent: NodeId) -> Vec<NodeId> {
         let mut total_last_child = None;
@@ -3769,12 +4016,17 @@ impl StyledDom {
 #[derive(Debug, Clone, PartialEq)]
 pub struct DefaultCallbacksCfg {
     pub smooth_scroll: bool,
+    pub overscroll_bounce: bool,
     pub enable_autotab: bool,
 }
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+/// Data of the default On::Scroll handler, shared by all nodes of a `StyledDom`
+#[derive(Debug, Clone, PartialEq)]
 pub struct DefaultScrollCallbackData {
     pub smooth_scroll: bool,
+    pub overscroll_bounce: bool,
+    /// Running scroll animations of the scroll containers
+    pub animator: ScrollAnimator,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -3792,33 +4044,158 @@ extern "C" fn default_on_tabindex(data: &mut RefAny, info: &mut CallbackInfo) ->
 
 /// Default On::Scroll event handler
 extern "C" fn default_on_scroll(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
-    let mut data = match data.downcast_mut::<DefaultScrollCallbackData>() {
-        Some(s) => s,
-        None => return Update::DoNothing,
-    };
+    use crate::scroll::{is_innermost_scroll_container, WHEEL_SCROLL_STEP};
+
+    let hit_node_id = info.get_hit_node();
+
+    // only scroll the innermost scroll container under the cursor
+    if info.get_scroll_position(hit_node_id).is_none()
+        || !is_innermost_scroll_container(info, hit_node_id)
+    {
+        return Update::DoNothing;
+    }
 
     let mouse_state = info.get_current_mouse_state();
 
     let (scroll_x, scroll_y) = match (
-        mouse_state.scroll_y.into_option(),
         mouse_state.scroll_x.into_option(),
+        mouse_state.scroll_y.into_option(),
     ) {
         (None, None) => return Update::DoNothing,
         (x, y) => (x.unwrap_or(0.0), y.unwrap_or(0.0)),
     };
 
-    let hit_node_id = info.get_hit_node();
+    let timer_data = data.clone();
+    let mut data = match data.downcast_mut::<DefaultScrollCallbackData>() {
+        Some(s) => s,
+        None => return Update::DoNothing,
+    };
+    let smooth_scroll = data.smooth_scroll;
+    let overscroll_bounce = data.overscroll_bounce;
 
-    let new_scroll_position = match info.get_scroll_position(hit_node_id) {
-        Some(mut s) => {
-            s.x += scroll_x;
-            s.y += scroll_y;
-            s
+    if scroll_x == 0.0 && scroll_y == 0.0 {
+        // a zero scroll delta marks the end of a touchpad scroll gesture
+        data.animator.fling(info, hit_node_id, overscroll_bounce);
+    } else {
+        // positive scroll values scroll up / left
+        let delta =
+            LogicalPosition::new(-scroll_x * WHEEL_SCROLL_STEP, -scroll_y * WHEEL_SCROLL_STEP);
+        data.animator
+            .scroll_by(info, hit_node_id, delta, smooth_scroll, overscroll_bounce);
+    }
+
+    data.animator.start_timer(timer_data, info);
+
+    Update::DoNothing
+}
+
+/// Whether the node edits text or reacts to key presses itself, in which case
+/// PageUp / PageDown / Home / End don't scroll while the node is focused
+fn handles_scroll_keys(node_data: &NodeData) -> bool {
+    use crate::callbacks::Callback;
+    use crate::dom::{EventFilter, FocusEventFilter, HoverEventFilter};
+
+    // added to every focusable node by insert_default_system_callbacks
+    let tab_callback = Callback {
+        cb: default_on_tabindex,
+    };
+
+    node_data.callbacks.iter().any(|cb| match cb.event {
+        EventFilter::Focus(FocusEventFilter::TextInput)
+        | EventFilter::Hover(HoverEventFilter::TextInput) => true,
+        EventFilter::Focus(FocusEventFilter::VirtualKeyDown)
+        | EventFilter::Hover(HoverEventFilter::VirtualKeyDown) => cb.callback != tab_callback,
+        _ => false,
+    })
+}
+
+/// Default handler for PageUp / PageDown / Home / End: scrolls the scroll container
+/// of the focused node or (if no node is focused) the scroll container under the cursor
+extern "C" fn default_on_scroll_key(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+    use crate::scrollbar::{get_keyboard_scroll_position, get_nearest_scroll_container};
+
+    let key = match info
+        .get_current_keyboard_state()
+        .current_virtual_keycode
+        .into_option()
+    {
+        Some(s) => s,
+        None => return Update::DoNothing,
+    };
+
+    let dom_id = info.get_hit_node().dom;
+    let window_state = info.internal_get_current_window_state();
+    let start_node_id = match window_state.focused_node {
+        Some(focused) if focused.dom == dom_id => {
+            let node_id = focused.node.into_crate_internal();
+            let handles_keys = node_id
+                .and_then(|n| {
+                    let layout_result = info.internal_get_layout_results().get(dom_id.inner)?;
+                    let node_data = layout_result.styled_dom.node_data.as_container();
+                    Some(handles_scroll_keys(node_data.get(n)?))
+                })
+                .unwrap_or(false);
+            // i.e. Home / End move the cursor of a text input instead of scrolling
+            if handles_keys {
+                return Update::DoNothing;
+            }
+            node_id
+        }
+        _ => window_state
+            .last_hit_test
+            .hovered_nodes
+            .get(&dom_id)
+            .and_then(|ht| ht.scroll_hit_test_nodes.keys().max().copied()),
+    };
+    let start_node_id = match start_node_id {
+        Some(s) => s,
+        None => return Update::DoNothing,
+    };
+
+    let (scroll_node_id, position) = {
+        let layout_result = match info.internal_get_layout_results().get(dom_id.inner) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+        let node_id = match get_nearest_scroll_container(layout_result, start_node_id) {
+            Some(s) => NodeHierarchyItemId::from_crate_internal(Some(s)),
+            None => return Update::DoNothing,
+        };
+        let scroll_node = match layout_result
+            .scrollable_nodes
+            .overflowing_nodes
+            .get(&node_id)
+        {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+        let scroll_node_id = DomNodeId {
+            dom: dom_id,
+            node: node_id,
+        };
+        let current = info.get_scroll_position(scroll_node_id).unwrap_or_default();
+        match get_keyboard_scroll_position(
+            key,
+            current,
+            scroll_node.get_max_scroll_position(),
+            scroll_node.parent_rect.size,
+        ) {
+            Some(s) => (scroll_node_id, s),
+            None => return Update::DoNothing,
         }
+    };
+
+    let timer_data = data.clone();
+    let mut data = match data.downcast_mut::<DefaultScrollCallbackData>() {
+        Some(s) => s,
         None => return Update::DoNothing,
     };
 
-    info.set_scroll_position(hit_node_id, new_scroll_position);
+    if data.smooth_scroll && data.animator.scroll_to(info, scroll_node_id, position) {
+        data.animator.start_timer(timer_data, info);
+    } else {
+        info.set_scroll_position(scroll_node_id, position);
+    }
 
     Update::DoNothing
 }
@@ -3909,3 +4286,80 @@ fn recursive_get_last_child(
         }
     }
 }
+
+#[test]
+fn test_html_export_uses_border_box() {
+    use crate::ui_solver::{PositionInfo, PositionInfoInner, ResolvedOffsets};
+    use crate::window::LogicalSize;
+
+    let mut dom = Dom::body().with_child(Dom::div());
+    let styled_dom = StyledDom::new(&mut dom, CssApiWrapper::empty());
+
+    let positioned = |x: f32, y: f32, w: f32, h: f32, padding: f32, border: f32| PositionedRectangle {
+        size: LogicalSize::new(w, h),
+        position: PositionInfo::Static(PositionInfoInner {
+            x_offset: x,
+            y_offset: y,
+            static_x_offset: x,
+            static_y_offset: y,
+        }),
+        padding: ResolvedOffsets { top: padding, left: padding, right: padding, bottom: padding },
+        border_widths: ResolvedOffsets { top: border, left: border, right: border, bottom: border },
+        ..PositionedRectangle::default()
+    };
+
+    // body: 80x80 content box with 10px padding at (0, 0)
+    // div: 20x20 content box with 5px padding and a 2px border, placed at the content origin of the body
+    let rects = NodeDataContainer::new(vec![
+        positioned(10.0, 10.0, 80.0, 80.0, 10.0, 0.0),
+        positioned(17.0, 17.0, 20.0, 20.0, 5.0, 2.0),
+    ]);
+
+    let html = styled_dom.get_html_string_with_layout(&rects.as_ref(), "", "", false);
+    assert!(html.contains("position: absolute; left: 0px; top: 0px; width: 100px; height: 100px;"));
+    assert!(html.contains("position: absolute; left: 10px; top: 10px; width: 34px; height: 34px;"));
+}
+
+#[test]
+fn test_css_property_sources_track_the_winning_rule() {
+    let width = CssPropertyType::Width;
+    let div = NodeId::new(1);
+    let css = "div { width: 10px; } body > div { width: 20px; }";
+    let mut dom = Dom::body().with_child(Dom::div());
+    let styled_dom = StyledDom::new(&mut dom, CssApiWrapper::from_string(css.to_string().into()));
+
+    let cache = styled_dom.get_css_property_cache();
+    assert!(cache.css_normal_props[&div].contains_key(&width));
+    let source = &cache.css_normal_sources[&div][&width];
+    assert_eq!(format!("{}", source), "body>div");
+}
+
+#[test]
+fn test_scroll_keys_are_left_to_text_inputs() {
+    use crate::callbacks::CallbackType;
+    use crate::dom::{EventFilter, FocusEventFilter};
+
+    extern "C" fn on_key(_: &mut RefAny, _: &mut CallbackInfo) -> Update {
+        Update::DoNothing
+    }
+
+    let with_callback = |event: EventFilter, cb: CallbackType| {
+        let mut node = NodeData::div();
+        node.add_callback(event, RefAny::new(DefaultTabIndexCallbackData {}), cb);
+        node
+    };
+
+    assert!(!handles_scroll_keys(&NodeData::div()));
+    assert!(!handles_scroll_keys(&with_callback(
+        EventFilter::Focus(FocusEventFilter::VirtualKeyDown),
+        default_on_tabindex
+    )));
+    assert!(handles_scroll_keys(&with_callback(
+        EventFilter::Focus(FocusEventFilter::VirtualKeyDown),
+        on_key
+    )));
+    assert!(handles_scroll_keys(&with_callback(
+        EventFilter::Focus(FocusEventFilter::TextInput),
+        on_key
+    )));
+}
@@ -688,6 +688,18 @@ impl Timer {
             .add_optional_duration(self.interval.as_ref())
     }
 
+    /// Returns the milliseconds until the timer needs to run again (0 if the timer is due)
+    pub fn millis_until_next_run(&self, instant_now: &Instant) -> u64 {
+        let next_run = self.instant_of_next_run();
+        if next_run <= *instant_now {
+            return 0;
+        }
+        match next_run.duration_since(instant_now) {
+            Duration::System(s) => s.millis(),
+            Duration::Tick(s) => s.tick_diff,
+        }
+    }
+
     /// Delays the timer to not start immediately but rather
     /// start after a certain time frame has elapsed.
     #[inline]
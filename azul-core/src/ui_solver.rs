@@ -229,6 +229,21 @@ pub struct OverflowingScrollNode {
     pub scroll_tag_id: ScrollTagId,
 }
 
+impl OverflowingScrollNode {
+    /// Returns how far the node can be scrolled until the end of
+    /// the children is aligned with the end of the node
+    pub fn get_max_scroll_position(&self) -> LogicalPosition {
+        let parent_end_x = self.parent_rect.origin.x + self.parent_rect.size.width;
+        let parent_end_y = self.parent_rect.origin.y + self.parent_rect.size.height;
+        let child_end_x = self.child_rect.origin.x + self.child_rect.size.width;
+        let child_end_y = self.child_rect.origin.y + self.child_rect.size.height;
+        LogicalPosition::new(
+            (child_end_x - parent_end_x).max(0.0),
+            (child_end_y - parent_end_y).max(0.0),
+        )
+    }
+}
+
 impl Default for OverflowingScrollNode {
     fn default() -> Self {
         use crate::dom::TagId;
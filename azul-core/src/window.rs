@@ -2,16 +2,18 @@ use crate::gl::OptionGlContextPtr;
 use crate::{
     app_resources::{
         DpiScaleFactor, Epoch, GlTextureCache, IdNamespace, ImageCache, ImageMask, ImageRef,
-        RendererResources, ResourceUpdate,
+        OptionImageRef, RendererResources, ResourceUpdate,
     },
     callbacks::{Callback, HitTestItem, UpdateImageType},
     callbacks::{
         CallbackType, DocumentId, DomNodeId, LayoutCallback, LayoutCallbackType, OptionCallback,
-        PipelineId, RefAny, ScrollPosition, Update,
+        OptionDomNodeId, OptionRefAny, PipelineId, RefAny, ScrollPosition, Update,
+        WriteBackCallback,
     },
     display_list::RenderCallbacks,
     dom::NodeHierarchy,
     id_tree::NodeId,
+    scrollbar::{ScrollbarInputResult, ScrollbarStates},
     styled_dom::{DomId, NodeHierarchyItemId},
     task::{ExternalSystemCallbacks, Instant, Thread, ThreadId, Timer, TimerId},
     ui_solver::{
@@ -26,7 +28,7 @@ use alloc::collections::btree_set::BTreeSet;
 use alloc::vec::Vec;
 use azul_css::{
     AzString, ColorU, CssPath, CssProperty, FloatValue, LayoutPoint, LayoutRect, LayoutSize,
-    OptionAzString, OptionF32, OptionI32, U8Vec,
+    OptionAzString, OptionF32, OptionI32, StyleCursor, StyleCursorImage, U8Vec,
 };
 use core::{
     cmp::Ordering,
@@ -57,6 +59,12 @@ impl WindowId {
     }
 }
 
+impl_option!(
+    WindowId,
+    OptionWindowId,
+    [Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd]
+);
+
 static LAST_ICON_KEY: AtomicUsize = AtomicUsize::new(0);
 
 /// Key that is used for checking whether a window icon has changed -
@@ -288,6 +296,76 @@ impl Default for MouseCursorType {
     }
 }
 
+impl MouseCursorType {
+    /// Translates a CSS `cursor` keyword, image cursors map to their first keyword fallback
+    pub fn from_style_cursor(cursor: &StyleCursor) -> Self {
+        match cursor.get_keyword_cursor() {
+            StyleCursor::Alias => MouseCursorType::Alias,
+            StyleCursor::AllScroll => MouseCursorType::AllScroll,
+            StyleCursor::Cell => MouseCursorType::Cell,
+            StyleCursor::ColResize => MouseCursorType::ColResize,
+            StyleCursor::ContextMenu => MouseCursorType::ContextMenu,
+            StyleCursor::Copy => MouseCursorType::Copy,
+            StyleCursor::Crosshair => MouseCursorType::Crosshair,
+            StyleCursor::Default => MouseCursorType::Default,
+            StyleCursor::EResize => MouseCursorType::EResize,
+            StyleCursor::EwResize => MouseCursorType::EwResize,
+            StyleCursor::Grab => MouseCursorType::Grab,
+            StyleCursor::Grabbing => MouseCursorType::Grabbing,
+            StyleCursor::Help => MouseCursorType::Help,
+            StyleCursor::Move => MouseCursorType::Move,
+            StyleCursor::NResize => MouseCursorType::NResize,
+            StyleCursor::NsResize => MouseCursorType::NsResize,
+            StyleCursor::NeswResize => MouseCursorType::NeswResize,
+            StyleCursor::NwseResize => MouseCursorType::NwseResize,
+            StyleCursor::Pointer => MouseCursorType::Hand,
+            StyleCursor::Progress => MouseCursorType::Progress,
+            StyleCursor::RowResize => MouseCursorType::RowResize,
+            StyleCursor::SResize => MouseCursorType::SResize,
+            StyleCursor::SeResize => MouseCursorType::SeResize,
+            StyleCursor::Text => MouseCursorType::Text,
+            StyleCursor::Unset => MouseCursorType::Default,
+            StyleCursor::VerticalText => MouseCursorType::VerticalText,
+            StyleCursor::WResize => MouseCursorType::WResize,
+            StyleCursor::Wait => MouseCursorType::Wait,
+            StyleCursor::ZoomIn => MouseCursorType::ZoomIn,
+            StyleCursor::ZoomOut => MouseCursorType::ZoomOut,
+            // get_keyword_cursor() never returns an image
+            StyleCursor::Image(_) => MouseCursorType::Default,
+        }
+    }
+}
+
+/// Image-based mouse cursor, created either from a CSS `cursor: url(...)`
+/// property or set at runtime via `CallbackInfo::set_custom_cursor()`
+///
+/// The hotspot is the "click point" of the cursor in pixels,
+/// relative to the top left corner of the image.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct CustomCursor {
+    pub image: ImageRef,
+    pub hotspot_x: u32,
+    pub hotspot_y: u32,
+}
+
+impl_option!(
+    CustomCursor,
+    OptionCustomCursor,
+    copy = false,
+    [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]
+);
+
+impl CustomCursor {
+    pub fn new(image: ImageRef, hotspot_x: u32, hotspot_y: u32) -> Self {
+        Self {
+            image,
+            hotspot_x,
+            hotspot_y,
+        }
+    }
+}
+
 /// Hardware-dependent keyboard scan code.
 pub type ScanCode = u32;
 
@@ -594,11 +672,27 @@ impl ScrollStates {
         &mut self,
         node: &OverflowingScrollNode,
         scroll_position: LogicalPosition,
+    ) {
+        self.set_scroll_position_with_overscroll(node, scroll_position, 0.0);
+    }
+
+    /// Same as `set_scroll_position`, but allows the node to be scrolled up to
+    /// `overscroll` pixels past its edges (for the overscroll bounce animation)
+    pub fn set_scroll_position_with_overscroll(
+        &mut self,
+        node: &OverflowingScrollNode,
+        scroll_position: LogicalPosition,
+        overscroll: f32,
     ) {
         self.0
             .entry(node.parent_external_scroll_id)
             .or_insert_with(|| ScrollState::default())
-            .set(scroll_position.x, scroll_position.y, &node.child_rect);
+            .set(
+                scroll_position.x,
+                scroll_position.y,
+                &node.get_max_scroll_position(),
+                overscroll,
+            );
     }
 
     /// Updating (add to) the existing scroll amount does not update the `entry.used_this_frame`,
@@ -612,7 +706,76 @@ impl ScrollStates {
         self.0
             .entry(node.parent_external_scroll_id)
             .or_insert_with(|| ScrollState::default())
-            .add(scroll_by_x, scroll_by_y, &node.child_rect);
+            .add(scroll_by_x, scroll_by_y, &node.get_max_scroll_position());
+    }
+
+    /// Applies the scroll positions that were set in the callbacks, returns the
+    /// nodes whose scroll offset actually changed (these get an `On::Scrolled` event)
+    pub fn apply_nodes_scrolled_in_callbacks(
+        &mut self,
+        nodes_scrolled: &BTreeMap<DomId, BTreeMap<NodeHierarchyItemId, LogicalPosition>>,
+        layout_results: &[LayoutResult],
+        overscroll: f32,
+    ) -> BTreeMap<DomId, BTreeSet<NodeHierarchyItemId>> {
+        let mut changed_nodes = BTreeMap::new();
+
+        for (dom_id, scrolled_nodes) in nodes_scrolled.iter() {
+            let scrollable_nodes = match layout_results.get(dom_id.inner) {
+                Some(s) => &s.scrollable_nodes,
+                None => continue,
+            };
+            for (scroll_node_id, scroll_position) in scrolled_nodes.iter() {
+                let scroll_node = match scrollable_nodes.overflowing_nodes.get(scroll_node_id) {
+                    Some(s) => s,
+                    None => continue,
+                };
+                let old_position = self.get_scroll_position(&scroll_node.parent_external_scroll_id);
+                self.set_scroll_position_with_overscroll(scroll_node, *scroll_position, overscroll);
+                let new_position = self.get_scroll_position(&scroll_node.parent_external_scroll_id);
+                if old_position.unwrap_or_default() != new_position.unwrap_or_default() {
+                    changed_nodes
+                        .entry(*dom_id)
+                        .or_insert_with(|| BTreeSet::new())
+                        .insert(*scroll_node_id);
+                }
+            }
+        }
+
+        changed_nodes
+    }
+
+    /// Returns the scroll positions of all overflowing nodes in the `layout_results`
+    pub fn get_scroll_positions(
+        &self,
+        layout_results: &[LayoutResult],
+    ) -> BTreeMap<DomId, BTreeMap<NodeHierarchyItemId, ScrollPosition>> {
+        layout_results
+            .iter()
+            .enumerate()
+            .filter_map(|(dom_id, layout_result)| {
+                let scroll_positions = layout_result
+                    .scrollable_nodes
+                    .overflowing_nodes
+                    .iter()
+                    .filter_map(|(node_id, overflowing_node)| {
+                        let scroll_position = ScrollPosition {
+                            parent_rect: overflowing_node.parent_rect,
+                            children_rect: overflowing_node.child_rect,
+                            scroll_offset: self
+                                .get_scroll_position(&overflowing_node.parent_external_scroll_id)
+                                .unwrap_or_default(),
+                        };
+                        Some((*node_id, scroll_position))
+                    })
+                    .collect::<BTreeMap<_, _>>();
+
+                if scroll_positions.is_empty() {
+                    None
+                } else {
+                    Some((DomId { inner: dom_id }, scroll_positions))
+                }
+            })
+            .collect()
     }
 }
 
@@ -629,19 +792,20 @@ impl ScrollState {
     }
 
     /// Add a scroll X / Y onto the existing scroll state
-    pub fn add(&mut self, x: f32, y: f32, child_rect: &LogicalRect) {
-        self.scroll_position.x = (self.scroll_position.x + x)
-            .max(0.0)
-            .min(child_rect.size.width);
-        self.scroll_position.y = (self.scroll_position.y + y)
-            .max(0.0)
-            .min(child_rect.size.height);
+    pub fn add(&mut self, x: f32, y: f32, max_scroll_position: &LogicalPosition) {
+        self.set(
+            self.scroll_position.x + x,
+            self.scroll_position.y + y,
+            max_scroll_position,
+            0.0,
+        );
     }
 
-    /// Set the scroll state to a new position
-    pub fn set(&mut self, x: f32, y: f32, child_rect: &LogicalRect) {
-        self.scroll_position.x = x.max(0.0).min(child_rect.size.width);
-        self.scroll_position.y = y.max(0.0).min(child_rect.size.height);
+    /// Set the scroll state to a new position, at most `overscroll`
+    /// pixels outside of `0..max_scroll_position`
+    pub fn set(&mut self, x: f32, y: f32, max_scroll_position: &LogicalPosition, overscroll: f32) {
+        self.scroll_position.x = x.max(-overscroll).min(max_scroll_position.x + overscroll);
+        self.scroll_position.y = y.max(-overscroll).min(max_scroll_position.y + overscroll);
     }
 }
 
@@ -667,6 +831,7 @@ pub fn update_full_window_state(
     full_window_state.keyboard_state = window_state.keyboard_state.clone();
     full_window_state.mouse_state = window_state.mouse_state;
     full_window_state.ime_position = window_state.ime_position.into();
+    full_window_state.custom_cursor = window_state.custom_cursor.clone();
     full_window_state.platform_specific_options = window_state.platform_specific_options.clone();
 }
 
@@ -697,6 +862,13 @@ pub struct WindowInternal {
     pub timers: BTreeMap<TimerId, Timer>,
     /// List of threads running in the background
     pub threads: BTreeMap<ThreadId, Thread>,
+    /// ID of the window (see `WindowCreateOptions::id`)
+    pub id: WindowId,
+    /// Node in the root DOM that is hovered in the inspector window, drawn
+    /// with its margin / padding / content boxes on top of the display list
+    pub inspector_highlight: Option<NodeId>,
+    /// Hovered and dragged scrollbars, drawn on top of the display list
+    pub scrollbar_states: ScrollbarStates,
 }
 
 impl WindowInternal {
@@ -705,6 +877,33 @@ impl WindowInternal {
             inner: FloatValue::new(self.current_window_state.size.get_hidpi_factor()),
         }
     }
+
+    /// Starts / drops drags of `draggable` nodes, see `FullWindowState::update_drag_state()`
+    pub fn update_drag_state(&mut self) {
+        self.current_window_state
+            .update_drag_state(&self.previous_window_state, &self.layout_results);
+    }
+
+    /// Updates the hovered / dragged scrollbars and scrolls the containers whose
+    /// scrollbar is dragged, see `ScrollbarStates::process_input()`
+    pub fn process_scrollbar_input(&mut self) -> ScrollbarInputResult {
+        self.scrollbar_states.process_input(
+            &self.current_window_state,
+            &self.previous_window_state,
+            &self.layout_results,
+            &mut self.scroll_states,
+        )
+    }
+
+    /// Returns whether the scrollbars changed since the last display list was built,
+    /// see `ScrollbarStates::geometry_changed()`
+    pub fn scrollbar_geometry_changed(&self) -> bool {
+        self.scrollbar_states.geometry_changed(
+            &self.layout_results,
+            &self.scroll_states,
+            self.current_window_state.flags.scrollbar_auto_hide_enabled,
+        )
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -720,6 +919,118 @@ impl FullHitTest {
             focused_node: focused_node.and_then(|f| Some((f.dom, f.node.into_crate_internal()?))),
         }
     }
+
+    /// Returns the window region of the topmost hovered node that has one (see
+    /// `NodeData::set_window_region()`) or `None` if the cursor is over regular content
+    pub fn get_window_region(&self, layout_results: &[LayoutResult]) -> Option<WindowRegion> {
+        self.find_window_region(|dom_id, node_id| {
+            layout_results
+                .get(dom_id.inner)?
+                .styled_dom
+                .node_data
+                .as_container()
+                .get(node_id)?
+                .get_window_region()
+        })
+    }
+
+    fn find_window_region<F: Fn(DomId, NodeId) -> Option<WindowRegion>>(
+        &self,
+        get_node_region: F,
+    ) -> Option<WindowRegion> {
+        // iframes are drawn on top of their parent DOM, nodes
+        // with a higher ID on top of nodes with a lower ID
+        let region = self
+            .hovered_nodes
+            .iter()
+            .rev()
+            .find_map(|(dom_id, hit_test)| {
+                hit_test
+                    .regular_hit_test_nodes
+                    .keys()
+                    .rev()
+                    .find_map(|node_id| get_node_region(*dom_id, *node_id))
+            })?;
+
+        match region {
+            WindowRegion::Client => None,
+            other => Some(other),
+        }
+    }
+}
+
+/// Part of the window frame that a node of a custom title bar acts as: pressing
+/// the left mouse button on it lets the window manager move or resize the window.
+///
+/// Child nodes inherit the region of their parent, so buttons inside of
+/// a title bar have to be marked as `WindowRegion::Client`.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[repr(C)]
+pub enum WindowRegion {
+    /// Regular window content, mouse events are delivered to the callbacks
+    Client,
+    /// Moves the window (title bar)
+    Drag,
+    ResizeN,
+    ResizeNE,
+    ResizeE,
+    ResizeSE,
+    ResizeS,
+    ResizeSW,
+    ResizeW,
+    ResizeNW,
+}
+
+impl WindowRegion {
+    /// Parses the value of the `window-region` XML attribute (`drag`, `resize-se`, `none`, ...)
+    pub fn from_keyword(keyword: &str) -> Option<Self> {
+        use self::WindowRegion::*;
+        match keyword {
+            "none" => Some(Client),
+            "drag" => Some(Drag),
+            "resize-n" => Some(ResizeN),
+            "resize-ne" => Some(ResizeNE),
+            "resize-e" => Some(ResizeE),
+            "resize-se" => Some(ResizeSE),
+            "resize-s" => Some(ResizeS),
+            "resize-sw" => Some(ResizeSW),
+            "resize-w" => Some(ResizeW),
+            "resize-nw" => Some(ResizeNW),
+            _ => None,
+        }
+    }
+
+    pub fn as_keyword(&self) -> &'static str {
+        use self::WindowRegion::*;
+        match self {
+            Client => "none",
+            Drag => "drag",
+            ResizeN => "resize-n",
+            ResizeNE => "resize-ne",
+            ResizeE => "resize-e",
+            ResizeSE => "resize-se",
+            ResizeS => "resize-s",
+            ResizeSW => "resize-sw",
+            ResizeW => "resize-w",
+            ResizeNW => "resize-nw",
+        }
+    }
+
+    /// Cursor that is displayed over the region if the node has no `cursor:` property
+    pub fn get_cursor(&self) -> Option<MouseCursorType> {
+        use self::WindowRegion::*;
+        match self {
+            Client | Drag => None,
+            ResizeN => Some(MouseCursorType::NResize),
+            ResizeNE => Some(MouseCursorType::NeResize),
+            ResizeE => Some(MouseCursorType::EResize),
+            ResizeSE => Some(MouseCursorType::SeResize),
+            ResizeS => Some(MouseCursorType::SResize),
+            ResizeSW => Some(MouseCursorType::SwResize),
+            ResizeW => Some(MouseCursorType::WResize),
+            ResizeNW => Some(MouseCursorType::NwResize),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Default, PartialEq)]
@@ -730,14 +1041,16 @@ pub struct CursorTypeHitTest {
     pub cursor_node: Option<(DomId, NodeId)>,
     /// Mouse cursor type to set (if cursor_node is None, this is set to `MouseCursorType::Default`)
     pub cursor_icon: MouseCursorType,
+    /// Set if the cursor: property of the `cursor_node` is a `url()` image cursor,
+    /// `cursor_icon` is then the keyword fallback in case the image can't be loaded
+    pub cursor_image: Option<StyleCursorImage>,
 }
 
 impl CursorTypeHitTest {
     pub fn new(hit_test: &FullHitTest, layout_results: &[LayoutResult]) -> Self {
-        use azul_css::StyleCursor;
-
         let mut cursor_node = None;
         let mut cursor_icon = MouseCursorType::Default;
+        let mut cursor_image = None;
 
         for (dom_id, hit_nodes) in hit_test.hovered_nodes.iter() {
             for (node_id, _) in hit_nodes.regular_hit_test_nodes.iter() {
@@ -749,49 +1062,65 @@ impl CursorTypeHitTest {
                     node_id,
                     &styled_dom.styled_nodes.as_container()[*node_id].state,
                 ) {
+                    let cursor = cursor_prop.get_property().cloned().unwrap_or_default();
                     cursor_node = Some((*dom_id, *node_id));
-                    cursor_icon = match cursor_prop.get_property().copied().unwrap_or_default() {
-                        StyleCursor::Alias => MouseCursorType::Alias,
-                        StyleCursor::AllScroll => MouseCursorType::AllScroll,
-                        StyleCursor::Cell => MouseCursorType::Cell,
-                        StyleCursor::ColResize => MouseCursorType::ColResize,
-                        StyleCursor::ContextMenu => MouseCursorType::ContextMenu,
-                        StyleCursor::Copy => MouseCursorType::Copy,
-                        StyleCursor::Crosshair => MouseCursorType::Crosshair,
-                        StyleCursor::Default => MouseCursorType::Default,
-                        StyleCursor::EResize => MouseCursorType::EResize,
-                        StyleCursor::EwResize => MouseCursorType::EwResize,
-                        StyleCursor::Grab => MouseCursorType::Grab,
-                        StyleCursor::Grabbing => MouseCursorType::Grabbing,
-                        StyleCursor::Help => MouseCursorType::Help,
-                        StyleCursor::Move => MouseCursorType::Move,
-                        StyleCursor::NResize => MouseCursorType::NResize,
-                        StyleCursor::NsResize => MouseCursorType::NsResize,
-                        StyleCursor::NeswResize => MouseCursorType::NeswResize,
-                        StyleCursor::NwseResize => MouseCursorType::NwseResize,
-                        StyleCursor::Pointer => MouseCursorType::Hand,
-                        StyleCursor::Progress => MouseCursorType::Progress,
-                        StyleCursor::RowResize => MouseCursorType::RowResize,
-                        StyleCursor::SResize => MouseCursorType::SResize,
-                        StyleCursor::SeResize => MouseCursorType::SeResize,
-                        StyleCursor::Text => MouseCursorType::Text,
-                        StyleCursor::Unset => MouseCursorType::Default,
-                        StyleCursor::VerticalText => MouseCursorType::VerticalText,
-                        StyleCursor::WResize => MouseCursorType::WResize,
-                        StyleCursor::Wait => MouseCursorType::Wait,
-                        StyleCursor::ZoomIn => MouseCursorType::ZoomIn,
-                        StyleCursor::ZoomOut => MouseCursorType::ZoomOut,
-                    }
+                    cursor_icon = MouseCursorType::from_style_cursor(&cursor);
+                    cursor_image = match cursor {
+                        StyleCursor::Image(i) => Some(i),
+                        _ => None,
+                    };
                 }
             }
         }
 
+        // resize handles of custom title bars
+        if cursor_node.is_none() {
+            if let Some(cursor) = hit_test
+                .get_window_region(layout_results)
+                .and_then(|r| r.get_cursor())
+            {
+                cursor_icon = cursor;
+            }
+        }
+
         Self {
             cursor_node,
             cursor_icon,
+            cursor_image,
+        }
+    }
+
+    /// Returns the image cursor to display: a cursor set via
+    /// `CallbackInfo::set_custom_cursor()` overrides the CSS `cursor: url()`,
+    /// otherwise the first `url()` of the fallback chain that is
+    /// present in the `ImageCache` is used
+    ///
+    /// Returns `None` if the `cursor_icon` should be displayed instead.
+    pub fn get_custom_cursor(
+        &self,
+        window_state: &FullWindowState,
+        image_cache: &ImageCache,
+    ) -> Option<CustomCursor> {
+        if let Some(c) = window_state.custom_cursor.as_ref() {
+            return Some(c.clone());
         }
+
+        let first = self.cursor_image.as_ref()?;
+        core::iter::once(first)
+            .chain(first.fallback.iter().filter_map(|c| match c {
+                StyleCursor::Image(i) => Some(i),
+                _ => None,
+            }))
+            .find_map(|i| {
+                Some(CustomCursor {
+                    image: image_cache.get_css_image_id(&i.image)?.clone(),
+                    hotspot_x: i.hotspot_x,
+                    hotspot_y: i.hotspot_y,
+                })
+            })
     }
 }
+
 pub struct WindowInternalInit {
     pub window_create_options: WindowCreateOptions,
     pub document_id: DocumentId,
@@ -817,13 +1146,14 @@ impl WindowInternal {
     {
         use crate::callbacks::LayoutCallbackInfo;
         use crate::display_list::SolvedLayout;
+        use crate::styled_dom::DefaultCallbacksCfg;
         use crate::window_state::{NodesToCheck, StyleAndLayoutChanges};
 
         let mut inital_renderer_resources = RendererResources::default();
 
         let epoch = Epoch::new();
 
-        let styled_dom = {
+        let mut styled_dom = {
             let layout_callback = &mut init.window_create_options.state.layout_callback;
             let mut layout_info = LayoutCallbackInfo::new(
                 init.window_create_options.state.size,
@@ -842,12 +1172,22 @@ impl WindowInternal {
             }
         };
 
+        let flags = &init.window_create_options.state.flags;
+        styled_dom.insert_default_system_callbacks(DefaultCallbacksCfg {
+            smooth_scroll: flags.smooth_scroll_enabled,
+            overscroll_bounce: flags.overscroll_bounce_enabled,
+            enable_autotab: flags.autotab_enabled,
+        });
+
         let mut current_window_state = FullWindowState::from_window_state(
             /*window_state: */ &init.window_create_options.state,
             /*dropped_file: */ None,
             /*hovered_file: */ None,
             /*focused_node: */ None,
             /*last_hit_test: */ FullHitTest::empty(/*current_focus*/ None),
+            /*clipboard_sequence: */ 0,
+            /*drag_state: */ DragState::default(),
+            /*ime_state: */ ImeState::default(),
         );
 
         let SolvedLayout { mut layout_results } = SolvedLayout::new(
@@ -918,7 +1258,10 @@ impl WindowInternal {
             gl_texture_cache,
             timers: BTreeMap::new(),
             threads: BTreeMap::new(),
+            id: init.window_create_options.id,
             scroll_states,
+            inspector_highlight: None,
+            scrollbar_states: ScrollbarStates::default(),
         }
     }
 
@@ -967,6 +1310,7 @@ impl WindowInternal {
 
         styled_dom.insert_default_system_callbacks(DefaultCallbacksCfg {
             smooth_scroll: self.current_window_state.flags.smooth_scroll_enabled,
+            overscroll_bounce: self.current_window_state.flags.overscroll_bounce_enabled,
             enable_autotab: self.current_window_state.flags.autotab_enabled,
         });
 
@@ -1048,30 +1392,8 @@ impl WindowInternal {
     pub fn get_current_scroll_states(
         &self,
     ) -> BTreeMap<DomId, BTreeMap<NodeHierarchyItemId, ScrollPosition>> {
-        self.layout_results
-            .iter()
-            .enumerate()
-            .filter_map(|(dom_id, layout_result)| {
-                let scroll_positions = layout_result
-                    .scrollable_nodes
-                    .overflowing_nodes
-                    .iter()
-                    .filter_map(|(node_id, overflowing_node)| {
-                        let scroll_position = ScrollPosition {
-                            parent_rect: overflowing_node.parent_rect,
-                            children_rect: overflowing_node.child_rect,
-                        };
-                        Some((*node_id, scroll_position))
-                    })
-                    .collect::<BTreeMap<_, _>>();
-
-                if scroll_positions.is_empty() {
-                    None
-                } else {
-                    Some((DomId { inner: dom_id }, scroll_positions))
-                }
-            })
-            .collect()
+        self.scroll_states
+            .get_scroll_positions(&self.layout_results)
     }
 
     /// Returns the overflowing size of the root body node. If WindowCreateOptions.size_to_content
@@ -1128,7 +1450,7 @@ impl WindowInternal {
         };
         let current = &self.current_window_state;
 
-        previous.size.dimensions != current.size.dimensions && previous.position != current.position
+        previous.size.dimensions != current.size.dimensions || previous.position != current.position
     }
 
     pub fn get_layout_size(&self) -> LayoutSize {
@@ -1205,11 +1527,16 @@ impl WindowInternal {
             image_masks_changed: None,
             nodes_scrolled_in_callbacks: None,
             update_focused_node: None,
+            update_drag_data: None,
             timers: None,
             threads: None,
             timers_removed: None,
             threads_removed: None,
             windows_created: Vec::new(),
+            notifications: Vec::new(),
+            shortcuts_changed: Vec::new(),
+            windows_closed: Vec::new(),
+            window_result: None,
             cursor_changed: false,
         };
 
@@ -1227,6 +1554,7 @@ impl WindowInternal {
 
         let mut should_terminate = TerminateTimer::Continue;
         let mut new_focus_target = None;
+        let mut ret_drag_data = None;
 
         let current_scroll_states = self.get_current_scroll_states();
 
@@ -1259,9 +1587,14 @@ impl WindowInternal {
                 &mut ret_threads_removed,
                 current_window_handle,
                 &mut ret.windows_created,
+                &mut ret.notifications,
+                &mut ret.shortcuts_changed,
+                &mut ret.windows_closed,
+                &mut ret.window_result,
                 system_callbacks,
                 &mut stop_propagation,
                 &mut new_focus_target,
+                &mut ret_drag_data,
                 &mut ret_words_changed,
                 &mut ret_images_changed,
                 &mut ret_image_masks_changed,
@@ -1322,6 +1655,39 @@ impl WindowInternal {
             }
         }
 
+        ret.update_drag_data = ret_drag_data;
+
+        // Scroll nodes that were scrolled by the timer (i.e. scroll animations)
+        let scrolled_nodes = match ret.nodes_scrolled_in_callbacks.as_ref() {
+            Some(nodes_scrolled) => {
+                let overscroll = if self.current_window_state.flags.overscroll_bounce_enabled {
+                    crate::scroll::MAX_OVERSCROLL
+                } else {
+                    0.0
+                };
+                self.scroll_states.apply_nodes_scrolled_in_callbacks(
+                    nodes_scrolled,
+                    &self.layout_results,
+                    overscroll,
+                )
+            }
+            None => BTreeMap::new(),
+        };
+
+        // Invoke the On::Scrolled callbacks of the nodes that were scrolled
+        if !scrolled_nodes.is_empty() {
+            ret.should_scroll_render = true;
+            let scroll_callbacks_result = self.invoke_scrolled_callbacks(
+                &scrolled_nodes,
+                current_window_handle,
+                gl_context,
+                image_cache,
+                system_fonts,
+                system_callbacks,
+            );
+            ret.merge(scroll_callbacks_result);
+        }
+
         if should_terminate == TerminateTimer::Terminate {
             ret.timers_removed
                 .get_or_insert_with(|| BTreeSet::new())
@@ -1331,6 +1697,38 @@ impl WindowInternal {
         return ret;
     }
 
+    /// Invokes the `On::Scrolled` callbacks of the nodes that were scrolled outside
+    /// of a regular callback (by a timer or by dragging a scrollbar)
+    pub fn invoke_scrolled_callbacks(
+        &mut self,
+        scrolled_nodes: &BTreeMap<DomId, BTreeSet<NodeHierarchyItemId>>,
+        current_window_handle: &RawWindowHandle,
+        gl_context: &OptionGlContextPtr,
+        image_cache: &mut ImageCache,
+        system_fonts: &mut FcFontCache,
+        system_callbacks: &ExternalSystemCallbacks,
+    ) -> CallCallbacksResult {
+        use crate::window_state::CallbacksOfHitTest;
+
+        let current_scroll_states = self.get_current_scroll_states();
+        let mut scroll_callbacks =
+            CallbacksOfHitTest::new_scrolled(scrolled_nodes, &self.layout_results);
+        scroll_callbacks.call_inner(
+            &self.previous_window_state,
+            &self.current_window_state,
+            current_window_handle,
+            &current_scroll_states,
+            gl_context,
+            &mut self.layout_results,
+            &mut self.scroll_states,
+            image_cache,
+            system_fonts,
+            system_callbacks,
+            &self.renderer_resources,
+            /* dispatch_scroll_events: */ false,
+        )
+    }
+
     pub fn run_all_threads(
         &mut self,
         data: &mut RefAny,
@@ -1356,11 +1754,16 @@ impl WindowInternal {
             image_masks_changed: None,
             nodes_scrolled_in_callbacks: None,
             update_focused_node: None,
+            update_drag_data: None,
             timers: None,
             threads: None,
             timers_removed: None,
             threads_removed: None,
             windows_created: Vec::new(),
+            notifications: Vec::new(),
+            shortcuts_changed: Vec::new(),
+            windows_closed: Vec::new(),
+            window_result: None,
             cursor_changed: false,
         };
 
@@ -1376,6 +1779,7 @@ impl WindowInternal {
         let mut ret_css_properties_changed = BTreeMap::new();
         let mut ret_nodes_scrolled_in_callbacks = BTreeMap::new();
         let mut new_focus_target = None;
+        let mut ret_drag_data = None;
         let mut stop_propagation = false;
         let current_scroll_states = self.get_current_scroll_states();
 
@@ -1400,7 +1804,15 @@ impl WindowInternal {
             let _ = thread.sender_send(ThreadSendMsg::Tick);
             let update = thread.receiver_try_recv();
             let msg = match update {
-                OptionThreadReceiveMsg::None => continue,
+                OptionThreadReceiveMsg::None => {
+                    // the thread may have finished right after sending its last message
+                    if thread.is_finished() {
+                        ret.threads_removed
+                            .get_or_insert_with(|| BTreeSet::default())
+                            .insert(*thread_id);
+                    }
+                    continue;
+                }
                 OptionThreadReceiveMsg::Some(s) => s,
             };
 
@@ -1427,9 +1839,14 @@ impl WindowInternal {
                 &mut ret_threads_removed,
                 current_window_handle,
                 &mut ret.windows_created,
+                &mut ret.notifications,
+                &mut ret.shortcuts_changed,
+                &mut ret.windows_closed,
+                &mut ret.window_result,
                 system_callbacks,
                 &mut stop_propagation,
                 &mut new_focus_target,
+                &mut ret_drag_data,
                 &mut ret_words_changed,
                 &mut ret_images_changed,
                 &mut ret_image_masks_changed,
@@ -1462,7 +1879,10 @@ impl WindowInternal {
             ret.modified_window_state = Some(ret_modified_window_state);
         }
         if !ret_threads_removed.is_empty() {
-            ret.threads_removed = Some(ret_threads_removed);
+            // keep the threads that finished (inserted above)
+            ret.threads_removed
+                .get_or_insert_with(|| BTreeSet::default())
+                .extend(ret_threads_removed.into_iter());
         }
         if !ret_timers_removed.is_empty() {
             ret.timers_removed = Some(ret_timers_removed);
@@ -1491,6 +1911,8 @@ impl WindowInternal {
             }
         }
 
+        ret.update_drag_data = ret_drag_data;
+
         return ret;
     }
 
@@ -1523,11 +1945,16 @@ impl WindowInternal {
             image_masks_changed: None,
             nodes_scrolled_in_callbacks: None,
             update_focused_node: None,
+            update_drag_data: None,
             timers: None,
             threads: None,
             timers_removed: None,
             threads_removed: None,
             windows_created: Vec::new(),
+            notifications: Vec::new(),
+            shortcuts_changed: Vec::new(),
+            windows_closed: Vec::new(),
+            window_result: None,
             cursor_changed: false,
         };
 
@@ -1543,6 +1970,7 @@ impl WindowInternal {
         let mut ret_css_properties_changed = BTreeMap::new();
         let mut ret_nodes_scrolled_in_callbacks = BTreeMap::new();
         let mut new_focus_target = None;
+        let mut ret_drag_data = None;
         let mut stop_propagation = false;
         let current_scroll_states = self.get_current_scroll_states();
 
@@ -1564,9 +1992,14 @@ impl WindowInternal {
             &mut ret_threads_removed,
             current_window_handle,
             &mut ret.windows_created,
+            &mut ret.notifications,
+            &mut ret.shortcuts_changed,
+            &mut ret.windows_closed,
+            &mut ret.window_result,
             system_callbacks,
             &mut stop_propagation,
             &mut new_focus_target,
+            &mut ret_drag_data,
             &mut ret_words_changed,
             &mut ret_images_changed,
             &mut ret_image_masks_changed,
@@ -1619,6 +2052,8 @@ impl WindowInternal {
             }
         }
 
+        ret.update_drag_data = ret_drag_data;
+
         return ret;
     }
 
@@ -1644,11 +2079,16 @@ impl WindowInternal {
             image_masks_changed: None,
             nodes_scrolled_in_callbacks: None,
             update_focused_node: None,
+            update_drag_data: None,
             timers: None,
             threads: None,
             timers_removed: None,
             threads_removed: None,
             windows_created: Vec::new(),
+            notifications: Vec::new(),
+            shortcuts_changed: Vec::new(),
+            windows_closed: Vec::new(),
+            window_result: None,
             cursor_changed: false,
         };
 
@@ -1664,6 +2104,7 @@ impl WindowInternal {
         let mut ret_css_properties_changed = BTreeMap::new();
         let mut ret_nodes_scrolled_in_callbacks = BTreeMap::new();
         let mut new_focus_target = None;
+        let mut ret_drag_data = None;
         let mut stop_propagation = false;
         let current_scroll_states = self.get_current_scroll_states();
 
@@ -1685,9 +2126,14 @@ impl WindowInternal {
             &mut ret_threads_removed,
             current_window_handle,
             &mut ret.windows_created,
+            &mut ret.notifications,
+            &mut ret.shortcuts_changed,
+            &mut ret.windows_closed,
+            &mut ret.window_result,
             system_callbacks,
             &mut stop_propagation,
             &mut new_focus_target,
+            &mut ret_drag_data,
             &mut ret_words_changed,
             &mut ret_images_changed,
             &mut ret_image_masks_changed,
@@ -1741,44 +2187,273 @@ impl WindowInternal {
             }
         }
 
+        ret.update_drag_data = ret_drag_data;
+
         return ret;
     }
-}
 
-#[derive(Debug, Default, Copy, Clone, PartialEq, PartialOrd, Hash, Ord, Eq)]
-#[repr(C)]
-pub struct TouchState {
-    /// TODO: not yet implemented
-    pub unimplemented: u8,
-}
+    /// Invokes the `WindowCreateOptions::result_callback` of a child window that has been
+    /// closed, with the result that the child window set in `CallbackInfo::set_window_result`
+    pub fn invoke_window_result_callback(
+        &mut self,
+        result_callback: &mut WindowResultCallback,
+        result: &mut RefAny,
+        current_window_handle: &RawWindowHandle,
+        gl_context: &OptionGlContextPtr,
+        image_cache: &mut ImageCache,
+        system_fonts: &mut FcFontCache,
+        system_callbacks: &ExternalSystemCallbacks,
+    ) -> CallCallbacksResult {
+        use crate::callbacks::CallbackInfo;
 
-/// State, size, etc of the window, for comparing to the last frame
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Hash, Ord, Eq)]
-#[repr(C)]
-pub enum WindowTheme {
-    DarkMode,
-    LightMode,
-}
+        let hit_dom_node = DomNodeId {
+            dom: DomId::ROOT_ID,
+            node: NodeHierarchyItemId::from_crate_internal(None),
+        };
 
-impl Default for WindowTheme {
-    fn default() -> WindowTheme {
-        WindowTheme::LightMode // sorry!
-    }
-}
+        let mut ret = CallCallbacksResult {
+            should_scroll_render: false,
+            callbacks_update_screen: Update::DoNothing,
+            modified_window_state: None,
+            css_properties_changed: None,
+            words_changed: None,
+            images_changed: None,
+            image_masks_changed: None,
+            nodes_scrolled_in_callbacks: None,
+            update_focused_node: None,
+            update_drag_data: None,
+            timers: None,
+            threads: None,
+            timers_removed: None,
+            threads_removed: None,
+            windows_created: Vec::new(),
+            notifications: Vec::new(),
+            shortcuts_changed: Vec::new(),
+            windows_closed: Vec::new(),
+            window_result: None,
+            cursor_changed: false,
+        };
 
-impl_option!(
-    WindowTheme,
-    OptionWindowTheme,
-    [Debug, Copy, Clone, PartialEq, PartialOrd, Ord, Eq, Hash]
-);
+        let mut ret_modified_window_state: WindowState = self.current_window_state.clone().into();
+        let ret_window_state = ret_modified_window_state.clone();
+        let mut ret_timers = FastHashMap::new();
+        let mut ret_timers_removed = FastBTreeSet::new();
+        let mut ret_threads = FastHashMap::new();
+        let mut ret_threads_removed = FastBTreeSet::new();
+        let mut ret_words_changed = BTreeMap::new();
+        let mut ret_images_changed = BTreeMap::new();
+        let mut ret_image_masks_changed = BTreeMap::new();
+        let mut ret_css_properties_changed = BTreeMap::new();
+        let mut ret_nodes_scrolled_in_callbacks = BTreeMap::new();
+        let mut new_focus_target = None;
+        let mut ret_drag_data = None;
+        let mut stop_propagation = false;
+        let current_scroll_states = self.get_current_scroll_states();
 
-#[derive(Debug, PartialEq, PartialOrd, Clone)]
-#[repr(C)]
-pub struct Monitor {
-    pub id: usize,
-    pub name: OptionAzString,
-    pub size: LayoutSize,
-    pub position: LayoutPoint,
+        let cursor_relative_to_item = OptionLogicalPosition::None;
+        let cursor_in_viewport = OptionLogicalPosition::None;
+
+        let mut callback_info = CallbackInfo::new(
+            &self.layout_results,
+            &self.renderer_resources,
+            &self.previous_window_state,
+            &self.current_window_state,
+            &mut ret_modified_window_state,
+            gl_context,
+            image_cache,
+            system_fonts,
+            &mut ret_timers,
+            &mut ret_threads,
+            &mut ret_timers_removed,
+            &mut ret_threads_removed,
+            current_window_handle,
+            &mut ret.windows_created,
+            &mut ret.notifications,
+            &mut ret.shortcuts_changed,
+            &mut ret.windows_closed,
+            &mut ret.window_result,
+            system_callbacks,
+            &mut stop_propagation,
+            &mut new_focus_target,
+            &mut ret_drag_data,
+            &mut ret_words_changed,
+            &mut ret_images_changed,
+            &mut ret_image_masks_changed,
+            &mut ret_css_properties_changed,
+            &current_scroll_states,
+            &mut ret_nodes_scrolled_in_callbacks,
+            hit_dom_node,
+            cursor_relative_to_item,
+            cursor_in_viewport,
+        );
+
+        ret.callbacks_update_screen =
+            (result_callback.callback.cb)(&mut result_callback.data, result, &mut callback_info);
+
+        if !ret_timers.is_empty() {
+            ret.timers = Some(ret_timers);
+        }
+        if !ret_threads.is_empty() {
+            ret.threads = Some(ret_threads);
+        }
+        if ret_modified_window_state != ret_window_state {
+            ret.modified_window_state = Some(ret_modified_window_state);
+        }
+        if !ret_threads_removed.is_empty() {
+            ret.threads_removed = Some(ret_threads_removed);
+        }
+        if !ret_timers_removed.is_empty() {
+            ret.timers_removed = Some(ret_timers_removed);
+        }
+        if !ret_words_changed.is_empty() {
+            ret.words_changed = Some(ret_words_changed);
+        }
+        if !ret_images_changed.is_empty() {
+            ret.images_changed = Some(ret_images_changed);
+        }
+        if !ret_image_masks_changed.is_empty() {
+            ret.image_masks_changed = Some(ret_image_masks_changed);
+        }
+        if !ret_css_properties_changed.is_empty() {
+            ret.css_properties_changed = Some(ret_css_properties_changed);
+        }
+        if !ret_nodes_scrolled_in_callbacks.is_empty() {
+            ret.nodes_scrolled_in_callbacks = Some(ret_nodes_scrolled_in_callbacks);
+        }
+
+        if let Some(ft) = new_focus_target {
+            if let Ok(new_focus_node) =
+                ft.resolve(&self.layout_results, self.current_window_state.focused_node)
+            {
+                ret.update_focused_node = Some(new_focus_node);
+            }
+        }
+
+        ret.update_drag_data = ret_drag_data;
+
+        return ret;
+    }
+}
+
+/// Kind of device that touches the screen / graphics tablet
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum TouchPointKind {
+    Finger,
+    Pen,
+    /// Back end of a pen
+    Eraser,
+}
+
+impl Default for TouchPointKind {
+    fn default() -> Self {
+        TouchPointKind::Finger
+    }
+}
+
+/// Finger or pen that touches the window
+#[derive(Debug, Default, Copy, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct TouchPoint {
+    /// ID of the touch point, stays the same until the finger / pen is lifted
+    pub id: u64,
+    pub kind: TouchPointKind,
+    /// Position relative to the top left of the window
+    pub position: LogicalPosition,
+    /// Pressure from 0.0 to 1.0, 1.0 if the device doesn't report the pressure
+    pub pressure: f32,
+    /// Tilt of the pen in degrees (-90.0 to 90.0) towards the right edge
+    /// of the screen, 0.0 if the device doesn't report the tilt
+    pub tilt_x: f32,
+    /// Tilt of the pen in degrees (-90.0 to 90.0) towards the bottom edge of the screen
+    pub tilt_y: f32,
+}
+
+impl_vec!(TouchPoint, TouchPointVec, TouchPointVecDestructor);
+impl_vec_clone!(TouchPoint, TouchPointVec, TouchPointVecDestructor);
+impl_vec_debug!(TouchPoint, TouchPointVec);
+impl_vec_partialeq!(TouchPoint, TouchPointVec);
+impl_vec_partialord!(TouchPoint, TouchPointVec);
+
+/// Two-finger gesture (see `gesture::GestureRecognizer`), all values are
+/// relative to the positions of the fingers at the start of the gesture
+#[derive(Debug, Default, Copy, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct GestureState {
+    /// Center between the two fingers
+    pub center: LogicalPosition,
+    /// Distance between the fingers divided by the distance at the start (pinch)
+    pub scale: f32,
+    /// Rotation of the fingers around the center in degrees, clockwise (rotate)
+    pub rotation: f32,
+    /// Movement of the center (two-finger pan)
+    pub translation: LogicalPosition,
+    /// Whether the fingers moved far enough apart / together to be a pinch
+    pub is_pinch: bool,
+    /// Whether the fingers rotated far enough to be a rotation
+    pub is_rotate: bool,
+    /// Whether the fingers moved far enough in the same direction to be a pan
+    pub is_pan: bool,
+}
+
+impl_option!(
+    GestureState,
+    OptionGestureState,
+    [Debug, Copy, Clone, PartialEq, PartialOrd]
+);
+
+/// Fingers and pens touching the window and the recognized gestures
+#[derive(Debug, Default, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct TouchState {
+    /// Fingers / pens that currently touch the window
+    pub touch_points: TouchPointVec,
+    /// Touch points that were lifted in the current event (`TouchEnd`)
+    pub ended_touch_points: TouchPointVec,
+    /// Touch points that were cancelled in the current event (`TouchCancel`),
+    /// i.e. because the window manager took over the touch
+    pub cancelled_touch_points: TouchPointVec,
+    /// Two-finger gesture, set while exactly two fingers touch the window
+    pub gesture: OptionGestureState,
+    /// Position of the finger that was held down without moving (`LongPress`),
+    /// set until the finger is lifted
+    pub long_press: OptionLogicalPosition,
+}
+
+impl TouchState {
+    /// Returns the touch point with the given ID
+    pub fn get_touch_point(&self, id: u64) -> Option<&TouchPoint> {
+        self.touch_points.iter().find(|p| p.id == id)
+    }
+}
+
+/// State, size, etc of the window, for comparing to the last frame
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Hash, Ord, Eq)]
+#[repr(C)]
+pub enum WindowTheme {
+    DarkMode,
+    LightMode,
+}
+
+impl Default for WindowTheme {
+    fn default() -> WindowTheme {
+        WindowTheme::LightMode // sorry!
+    }
+}
+
+impl_option!(
+    WindowTheme,
+    OptionWindowTheme,
+    [Debug, Copy, Clone, PartialEq, PartialOrd, Ord, Eq, Hash]
+);
+
+#[derive(Debug, PartialEq, PartialOrd, Clone)]
+#[repr(C)]
+pub struct Monitor {
+    pub id: usize,
+    pub name: OptionAzString,
+    pub size: LayoutSize,
+    pub position: LayoutPoint,
     pub scale_factor: f64,
     pub video_modes: VideoModeVec,
     pub is_primary_monitor: bool,
@@ -1863,6 +2538,8 @@ pub struct WindowState {
     pub renderer_options: RendererOptions,
     /// Color of the window background (can be transparent if necessary)
     pub background_color: ColorU,
+    /// Image cursor that overrides the CSS `cursor` property of all nodes, if set
+    pub custom_cursor: OptionCustomCursor,
     /// The `layout()` function for this window, stored as a callback function pointer,
     /// There are multiple reasons for doing this (instead of requiring `T: Layout` everywhere):
     ///
@@ -1910,6 +2587,16 @@ impl Default for ImePosition {
     }
 }
 
+impl ImePosition {
+    /// Places the candidate window of the input method below the given text cursor
+    pub fn from_caret_rect(caret_rect: LogicalRect) -> Self {
+        ImePosition::Initialized(LogicalPosition::new(
+            caret_rect.origin.x,
+            caret_rect.origin.y + caret_rect.size.height,
+        ))
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct FullWindowState {
     /// Theme of this window (dark or light) - can be set / overridden by the user
@@ -1945,6 +2632,8 @@ pub struct FullWindowState {
     pub renderer_options: RendererOptions,
     /// Background color of the window
     pub background_color: ColorU,
+    /// Image cursor that overrides the CSS `cursor` property of all nodes, if set
+    pub custom_cursor: OptionCustomCursor,
     /// The `layout()` function for this window, stored as a callback function pointer,
     /// There are multiple reasons for doing this (instead of requiring `T: Layout` everywhere):
     ///
@@ -1973,6 +2662,13 @@ pub struct FullWindowState {
     /// events are stored in a queue and only storing the hovered
     /// nodes is not sufficient to correctly determine events
     pub last_hit_test: FullHitTest,
+    /// Incremented by the platform shell every time the contents of the
+    /// system clipboard change, emits a `WindowEventFilter::ClipboardChanged` event
+    pub clipboard_sequence: u64,
+    /// Current drag-and-drop operation, see `FullWindowState::update_drag_state()`
+    pub drag_state: DragState,
+    /// Current input method (IME) composition, updated by the platform shell
+    pub ime_state: ImeState,
 }
 
 impl Default for FullWindowState {
@@ -1990,6 +2686,7 @@ impl Default for FullWindowState {
             ime_position: ImePosition::Uninitialized,
             platform_specific_options: PlatformSpecificOptions::default(),
             background_color: ColorU::WHITE,
+            custom_cursor: OptionCustomCursor::None,
             layout_callback: LayoutCallback::default(),
             close_callback: OptionCallback::None,
             renderer_options: RendererOptions::default(),
@@ -1999,6 +2696,9 @@ impl Default for FullWindowState {
             dropped_file: None,
             focused_node: None,
             last_hit_test: FullHitTest::empty(None),
+            clipboard_sequence: 0,
+            drag_state: DragState::default(),
+            ime_state: ImeState::default(),
         }
     }
 }
@@ -2042,6 +2742,9 @@ impl FullWindowState {
         hovered_file: Option<AzString>,
         focused_node: Option<DomNodeId>,
         last_hit_test: FullHitTest,
+        clipboard_sequence: u64,
+        drag_state: DragState,
+        ime_state: ImeState,
     ) -> Self {
         Self {
             monitor: window_state.monitor.clone(),
@@ -2053,10 +2756,11 @@ impl FullWindowState {
             debug_state: window_state.debug_state,
             keyboard_state: window_state.keyboard_state.clone(),
             mouse_state: window_state.mouse_state,
-            touch_state: window_state.touch_state,
+            touch_state: window_state.touch_state.clone(),
             ime_position: window_state.ime_position.into(),
             platform_specific_options: window_state.platform_specific_options.clone(),
             background_color: window_state.background_color,
+            custom_cursor: window_state.custom_cursor.clone(),
             layout_callback: window_state.layout_callback.clone(),
             close_callback: window_state.close_callback,
             renderer_options: window_state.renderer_options,
@@ -2064,14 +2768,453 @@ impl FullWindowState {
             hovered_file,
             focused_node,
             last_hit_test,
+            clipboard_sequence,
+            drag_state,
+            ime_state,
         }
     }
 
     pub fn process_system_scroll(&mut self, scroll_states: &ScrollStates) -> Option<ScrollResult> {
-        let (x, y) = self.mouse_state.get_scroll_amount()?;
-        // TODO
+        // the scroll amount is applied by the default On::Scroll handlers (see
+        // styled_dom::default_on_scroll), which also need the zero delta at the
+        // end of a touchpad scroll gesture, so any scroll input is consumed here
+        if self.mouse_state.scroll_x.is_none() && self.mouse_state.scroll_y.is_none() {
+            return None;
+        }
         Some(ScrollResult {})
     }
+
+    /// Updates the `drag_state` after the mouse state and the `last_hit_test` changed:
+    /// starts a drag once the cursor moved `DRAG_THRESHOLD` pixels while the left mouse
+    /// button is held down on a `draggable` node and drops it when the button is released
+    pub fn update_drag_state(
+        &mut self,
+        previous_window_state: &Option<FullWindowState>,
+        layout_results: &[LayoutResult],
+    ) {
+        self.update_drag_state_with(previous_window_state, |dom_id, node_id| {
+            layout_results
+                .get(dom_id.inner)
+                .and_then(|l| {
+                    let node_data = l.styled_dom.node_data.as_container();
+                    node_data.get(node_id).map(|n| n.is_draggable())
+                })
+                .unwrap_or(false)
+        })
+    }
+
+    pub(crate) fn update_drag_state_with<F: Fn(DomId, NodeId) -> bool>(
+        &mut self,
+        previous_window_state: &Option<FullWindowState>,
+        is_draggable: F,
+    ) {
+        let previous_left_down = previous_window_state
+            .as_ref()
+            .map(|s| s.mouse_state.left_down)
+            .unwrap_or(false);
+
+        // only set for the event in which the drop happened (the shell
+        // sets it directly when a drag from another application is dropped)
+        let is_new_drop = previous_window_state
+            .as_ref()
+            .map(|s| s.drag_state.dropped.is_none())
+            .unwrap_or(false);
+        if !is_new_drop {
+            self.drag_state.dropped = None;
+        }
+
+        let cursor_position = self.mouse_state.cursor_position.get_position();
+
+        if !self.mouse_state.left_down {
+            self.drag_state.pending = None;
+            let is_internal_drag = self
+                .drag_state
+                .current
+                .as_ref()
+                .map(|d| !d.is_external())
+                .unwrap_or(false);
+            if is_internal_drag {
+                let current = self.drag_state.current.take();
+                // released outside of the window: the drag is cancelled
+                if cursor_position.is_some() {
+                    self.drag_state.dropped = current;
+                }
+            }
+            return;
+        }
+
+        if self.drag_state.current.is_some() {
+            return;
+        }
+
+        let cursor_position = match cursor_position {
+            Some(s) => s,
+            None => return,
+        };
+
+        if !previous_left_down {
+            self.drag_state.pending = self.get_draggable_hit_node(is_draggable).map(
+                |(source_node, cursor_relative_to_node)| PendingDrag {
+                    source_node,
+                    start_position: cursor_position,
+                    cursor_relative_to_node,
+                },
+            );
+            return;
+        }
+
+        let pending = match self.drag_state.pending {
+            Some(s) => s,
+            None => return,
+        };
+
+        let dx = cursor_position.x - pending.start_position.x;
+        let dy = cursor_position.y - pending.start_position.y;
+        if dx * dx + dy * dy < DRAG_THRESHOLD * DRAG_THRESHOLD {
+            return;
+        }
+
+        self.drag_state.pending = None;
+        self.drag_state.current = Some(DragData {
+            source_node: Some(pending.source_node).into(),
+            drag_image_offset: pending.cursor_relative_to_node,
+            ..DragData::default()
+        });
+    }
+
+    /// Returns the topmost hovered `draggable` node and the cursor position relative to it
+    fn get_draggable_hit_node<F: Fn(DomId, NodeId) -> bool>(
+        &self,
+        is_draggable: F,
+    ) -> Option<(DomNodeId, LogicalPosition)> {
+        // iframes are drawn on top of their parent DOM, nodes
+        // with a higher ID on top of nodes with a lower ID
+        for (dom_id, hit_test) in self.last_hit_test.hovered_nodes.iter().rev() {
+            let draggable_node = hit_test
+                .regular_hit_test_nodes
+                .iter()
+                .rev()
+                .find(|(node_id, _)| is_draggable(*dom_id, **node_id));
+            if let Some((node_id, hit_test_item)) = draggable_node {
+                let dom_node_id = DomNodeId {
+                    dom: *dom_id,
+                    node: NodeHierarchyItemId::from_crate_internal(Some(*node_id)),
+                };
+                return Some((dom_node_id, hit_test_item.point_relative_to_item));
+            }
+        }
+        None
+    }
+
+    /// Returns whether the drag image moved or changed, in which
+    /// case the display list has to be rebuilt
+    pub fn drag_image_changed(&self, previous_window_state: &Option<FullWindowState>) -> bool {
+        let current_image = self
+            .drag_state
+            .current
+            .as_ref()
+            .and_then(|d| d.drag_image.as_ref());
+        let previous = previous_window_state.as_ref();
+        let previous_image = previous
+            .and_then(|p| p.drag_state.current.as_ref())
+            .and_then(|d| d.drag_image.as_ref());
+
+        if current_image != previous_image {
+            return true;
+        }
+
+        current_image.is_some()
+            && previous.map(|p| p.mouse_state.cursor_position)
+                != Some(self.mouse_state.cursor_position)
+    }
+}
+
+/// Distance (in logical pixels) that the cursor has to move while the left mouse
+/// button is held down on a `draggable` node before a drag is started
+pub const DRAG_THRESHOLD: f32 = 4.0;
+
+/// MIME type of a list of file URIs (`file:///home/user/image.png`), one per line
+pub const DRAG_URI_LIST_MIME_TYPE: &str = "text/uri-list";
+/// MIME type of UTF-8 text
+pub const DRAG_TEXT_MIME_TYPE: &str = "text/plain;charset=utf-8";
+
+/// Dragged data in one format
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct DragItem {
+    /// MIME type of the data, for example `text/plain;charset=utf-8`
+    pub mime_type: AzString,
+    /// Data in the format of the `mime_type`. Note that drags from other applications
+    /// only list the available formats and have empty data until they are dropped
+    pub data: U8Vec,
+}
+
+impl_vec!(DragItem, DragItemVec, DragItemVecDestructor);
+impl_vec_debug!(DragItem, DragItemVec);
+impl_vec_clone!(DragItem, DragItemVec, DragItemVecDestructor);
+impl_vec_partialeq!(DragItem, DragItemVec);
+
+/// Data of a drag-and-drop operation, set with `CallbackInfo::set_drag_data()`
+/// in an `On::DragStart` callback and read with `CallbackInfo::get_drag_data()`
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct DragData {
+    /// Application-defined payload, only available if the drag was started in this application
+    pub payload: OptionRefAny,
+    /// The dragged data in one or more formats, used to exchange data with other applications
+    pub items: DragItemVec,
+    /// Image that is drawn below the cursor while dragging
+    pub drag_image: OptionImageRef,
+    /// Position of the cursor relative to the top left corner of the `drag_image`
+    pub drag_image_offset: LogicalPosition,
+    /// Node that the drag was started on, `None` if the drag comes from another application
+    pub source_node: OptionDomNodeId,
+}
+
+impl Default for DragData {
+    fn default() -> Self {
+        Self {
+            payload: OptionRefAny::None,
+            items: DragItemVec::from_const_slice(&[]),
+            drag_image: OptionImageRef::None,
+            drag_image_offset: LogicalPosition::zero(),
+            source_node: OptionDomNodeId::None,
+        }
+    }
+}
+
+impl DragData {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_payload(mut self, payload: RefAny) -> Self {
+        self.payload = Some(payload).into();
+        self
+    }
+
+    /// Adds the data in the given format, replaces existing data of the same format
+    pub fn with_item(mut self, mime_type: AzString, data: U8Vec) -> Self {
+        self.set_item(mime_type, data);
+        self
+    }
+
+    /// Adds the text as `text/plain;charset=utf-8`
+    pub fn with_text(self, text: AzString) -> Self {
+        let data = text.as_str().as_bytes().to_vec();
+        self.with_item(DRAG_TEXT_MIME_TYPE.into(), data.into())
+    }
+
+    /// Adds the file paths as a `text/uri-list`
+    pub fn with_files(self, paths: &[AzString]) -> Self {
+        let uri_list = paths
+            .iter()
+            .map(|p| format!("file://{}\r\n", uri_encode_path(p.as_str())))
+            .collect::<Vec<_>>()
+            .join("");
+        self.with_item(DRAG_URI_LIST_MIME_TYPE.into(), uri_list.into_bytes().into())
+    }
+
+    pub fn with_drag_image(mut self, image: ImageRef, offset: LogicalPosition) -> Self {
+        self.drag_image = Some(image).into();
+        self.drag_image_offset = offset;
+        self
+    }
+
+    pub fn set_item(&mut self, mime_type: AzString, data: U8Vec) {
+        let mut items = self.items.as_slice().to_vec();
+        items.retain(|i| i.mime_type.as_str() != mime_type.as_str());
+        items.push(DragItem { mime_type, data });
+        self.items = items.into();
+    }
+
+    /// Returns whether the drag comes from another application
+    pub fn is_external(&self) -> bool {
+        self.source_node.is_none()
+    }
+
+    /// Returns the formats that the dragged data is available in
+    pub fn get_mime_types(&self) -> Vec<AzString> {
+        self.items.iter().map(|i| i.mime_type.clone()).collect()
+    }
+
+    pub fn get_item(&self, mime_type: &str) -> Option<&[u8]> {
+        self.items
+            .iter()
+            .find(|i| i.mime_type.as_str() == mime_type)
+            .map(|i| i.data.as_slice())
+    }
+
+    /// Returns the dragged text, if the data is available as (UTF-8) text
+    pub fn get_text(&self) -> Option<AzString> {
+        let data = self
+            .get_item(DRAG_TEXT_MIME_TYPE)
+            .or_else(|| self.get_item("text/plain"))?;
+        core::str::from_utf8(data)
+            .ok()
+            .map(|s| AzString::from(s.to_string()))
+    }
+
+    /// Returns the local file paths of the dragged `text/uri-list`
+    pub fn get_files(&self) -> Vec<AzString> {
+        let data = match self.get_item(DRAG_URI_LIST_MIME_TYPE) {
+            Some(s) => s,
+            None => return Vec::new(),
+        };
+        core::str::from_utf8(data)
+            .unwrap_or("")
+            .lines()
+            .map(|line| line.trim())
+            // lines starting with '#' are comments (RFC 2483)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|uri| {
+                let path = uri.strip_prefix("file://")?;
+                // skip the (optional) hostname
+                let path = &path[path.find('/')?..];
+                uri_decode_path(path).map(AzString::from)
+            })
+            .collect()
+    }
+}
+
+impl_option!(
+    DragData,
+    OptionDragData,
+    copy = false,
+    [Debug, Clone, PartialEq]
+);
+
+fn uri_encode_path(path: &str) -> alloc::string::String {
+    let mut encoded = alloc::string::String::with_capacity(path.len());
+    for byte in path.bytes() {
+        match byte {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'/' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+fn uri_decode_path(path: &str) -> Option<alloc::string::String> {
+    let bytes = path.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = core::str::from_utf8(bytes.get((i + 1)..(i + 3))?).ok()?;
+            decoded.push(u8::from_str_radix(hex, 16).ok()?);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+    alloc::string::String::from_utf8(decoded).ok()
+}
+
+/// Mouse button was pressed on a `draggable` node, but the cursor
+/// hasn't moved far enough yet to start a drag
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PendingDrag {
+    pub source_node: DomNodeId,
+    /// Position of the cursor (relative to the window) when the mouse button was pressed
+    pub start_position: LogicalPosition,
+    /// Position of the cursor relative to the top left corner of the source node
+    pub cursor_relative_to_node: LogicalPosition,
+}
+
+/// Drag-and-drop state of a window
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct DragState {
+    pub pending: Option<PendingDrag>,
+    /// Drag that is currently hovering over the window (started in this
+    /// window or coming from another application)
+    pub current: Option<DragData>,
+    /// Data that was dropped on the window, only set for the event in which the
+    /// drop happened (emits a `WindowEventFilter::Drop` event)
+    pub dropped: Option<DragData>,
+}
+
+impl DragState {
+    /// Applies the `CallCallbacksResult::update_drag_data` of the callbacks
+    pub fn update_drag_data(&mut self, drag_data: Option<DragData>) {
+        match drag_data {
+            Some(mut new) => {
+                if let Some(current) = self.current.as_ref() {
+                    // the source can't be changed from a callback
+                    new.source_node = current.source_node;
+                    self.current = Some(new);
+                }
+            }
+            None => {
+                self.pending = None;
+                self.current = None;
+            }
+        }
+    }
+}
+
+/// Text that is being composed with an input method (IME), but not committed yet
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct TextComposition {
+    /// Preedit string, usually drawn underlined at the text cursor
+    pub text: AzString,
+    /// Position of the cursor in the preedit string, in characters
+    pub cursor: usize,
+}
+
+impl_option!(
+    TextComposition,
+    OptionTextComposition,
+    copy = false,
+    [Debug, Clone, PartialEq]
+);
+
+/// Input method (IME) state of a window
+///
+/// The platform shell translates the events of the input method into calls to
+/// `set_composition()`, `commit()` and `cancel_composition()`, the differences
+/// to the previous window state are emitted as `TextCompositionStart` /
+/// `TextCompositionUpdate` / `TextCompositionEnd` and `TextInput` events.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ImeState {
+    /// Current composition, `None` if no composition is active
+    pub composition: Option<TextComposition>,
+    /// Text committed by the input method in the current event
+    pub committed_text: Option<AzString>,
+}
+
+impl ImeState {
+    /// Starts or updates the composition
+    pub fn set_composition(&mut self, text: AzString, cursor: usize) {
+        self.committed_text = None;
+        self.composition = Some(TextComposition { text, cursor });
+    }
+
+    /// Ends the composition (if any) and commits the text
+    pub fn commit(&mut self, text: AzString) {
+        self.composition = None;
+        self.committed_text = Some(text);
+    }
+
+    /// Ends the composition without committing any text
+    pub fn cancel_composition(&mut self) {
+        self.composition = None;
+        self.committed_text = None;
+    }
+
+    /// Has to be called before the previous window state is saved for the next
+    /// event, so that committing the same text twice fires two `TextInput` events
+    pub fn clear_committed_text(&mut self) {
+        self.committed_text = None;
+    }
+
+    pub fn is_composing(&self) -> bool {
+        self.composition.is_some()
+    }
 }
 
 impl From<FullWindowState> for WindowState {
@@ -2090,6 +3233,7 @@ impl From<FullWindowState> for WindowState {
             ime_position: full_window_state.ime_position.into(),
             platform_specific_options: full_window_state.platform_specific_options,
             background_color: full_window_state.background_color,
+            custom_cursor: full_window_state.custom_cursor,
             layout_callback: full_window_state.layout_callback,
             close_callback: full_window_state.close_callback,
             renderer_options: full_window_state.renderer_options,
@@ -2122,6 +3266,8 @@ pub struct CallCallbacksResult {
         Option<BTreeMap<DomId, BTreeMap<NodeHierarchyItemId, LogicalPosition>>>,
     /// Whether the focused node was changed from the callbacks
     pub update_focused_node: Option<Option<DomNodeId>>,
+    /// Whether the data of the current drag was set (`Some(Some)`) or the drag was cancelled (`Some(None)`)
+    pub update_drag_data: Option<Option<DragData>>,
     /// Timers that were added in the callbacks
     pub timers: Option<FastHashMap<TimerId, Timer>>,
     /// Tasks that were added in the callbacks
@@ -2132,6 +3278,14 @@ pub struct CallCallbacksResult {
     pub threads_removed: Option<FastBTreeSet<ThreadId>>,
     /// Windows that were created in the callbacks
     pub windows_created: Vec<WindowCreateOptions>,
+    /// Desktop notifications that were requested in the callbacks
+    pub notifications: Vec<Notification>,
+    /// Keyboard shortcuts that were registered / unregistered in the callbacks
+    pub shortcuts_changed: Vec<ShortcutChange>,
+    /// Windows that were closed in the callbacks (see `CallbackInfo::close_window`)
+    pub windows_closed: Vec<WindowId>,
+    /// Result of the window for its parent window (see `CallbackInfo::set_window_result`)
+    pub window_result: Option<RefAny>,
     /// Whether the cursor changed in the callbacks
     pub cursor_changed: bool,
 }
@@ -2143,6 +3297,74 @@ impl CallCallbacksResult {
     pub fn focus_changed(&self) -> bool {
         self.update_focused_node.is_some()
     }
+
+    /// Merges the result of callbacks that were invoked after `self` into `self`
+    /// (window state, focus and drag data of `other` take precedence if they were set)
+    pub fn merge(&mut self, other: CallCallbacksResult) {
+        fn merge_nested<K: Ord, K2: Ord, V>(
+            a: &mut Option<BTreeMap<K, BTreeMap<K2, V>>>,
+            b: Option<BTreeMap<K, BTreeMap<K2, V>>>,
+        ) {
+            for (k, v) in b.into_iter().flatten() {
+                a.get_or_insert_with(|| BTreeMap::new())
+                    .entry(k)
+                    .or_insert_with(|| BTreeMap::new())
+                    .extend(v);
+            }
+        }
+
+        self.should_scroll_render |= other.should_scroll_render;
+        self.callbacks_update_screen
+            .max_self(other.callbacks_update_screen);
+        if other.modified_window_state.is_some() {
+            self.modified_window_state = other.modified_window_state;
+        }
+        merge_nested(&mut self.words_changed, other.words_changed);
+        merge_nested(&mut self.images_changed, other.images_changed);
+        merge_nested(&mut self.image_masks_changed, other.image_masks_changed);
+        merge_nested(
+            &mut self.css_properties_changed,
+            other.css_properties_changed,
+        );
+        merge_nested(
+            &mut self.nodes_scrolled_in_callbacks,
+            other.nodes_scrolled_in_callbacks,
+        );
+        if other.update_focused_node.is_some() {
+            self.update_focused_node = other.update_focused_node;
+        }
+        if other.update_drag_data.is_some() {
+            self.update_drag_data = other.update_drag_data;
+        }
+        if let Some(timers) = other.timers {
+            self.timers
+                .get_or_insert_with(|| FastHashMap::new())
+                .extend(timers);
+        }
+        if let Some(threads) = other.threads {
+            self.threads
+                .get_or_insert_with(|| FastHashMap::new())
+                .extend(threads);
+        }
+        if let Some(timers_removed) = other.timers_removed {
+            self.timers_removed
+                .get_or_insert_with(|| FastBTreeSet::new())
+                .extend(timers_removed);
+        }
+        if let Some(threads_removed) = other.threads_removed {
+            self.threads_removed
+                .get_or_insert_with(|| FastBTreeSet::new())
+                .extend(threads_removed);
+        }
+        self.windows_created.extend(other.windows_created);
+        self.notifications.extend(other.notifications);
+        self.shortcuts_changed.extend(other.shortcuts_changed);
+        self.windows_closed.extend(other.windows_closed);
+        if other.window_result.is_some() {
+            self.window_result = other.window_result;
+        }
+        self.cursor_changed |= other.cursor_changed;
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Ord, PartialOrd, Hash)]
@@ -2167,8 +3389,17 @@ pub struct WindowFlags {
     pub has_extended_window_frame: bool,
     /// Whether or not the compositor should blur the application background
     pub has_blur_behind_window: bool,
+    /// Whether mouse input passes through the window to the windows
+    /// below it ("click-through" overlays), default: false
+    pub is_click_through: bool,
     /// Is smooth scrolling enabled for this window?
     pub smooth_scroll_enabled: bool,
+    /// Whether scroll containers can be scrolled past their edges with the
+    /// touchpad and bounce back, default: false
+    pub overscroll_bounce_enabled: bool,
+    /// Whether the scrollbars are only shown while the cursor is over
+    /// their scroll container or while they are dragged, default: false
+    pub scrollbar_auto_hide_enabled: bool,
     /// Is automatic TAB switching supported?
     pub autotab_enabled: bool,
 }
@@ -2194,7 +3425,10 @@ impl Default for WindowFlags {
             has_focus: true,
             has_extended_window_frame: false,
             has_blur_behind_window: false,
+            is_click_through: false,
             smooth_scroll_enabled: true,
+            overscroll_bounce_enabled: false,
+            scrollbar_auto_hide_enabled: false,
             autotab_enabled: true,
         }
     }
@@ -2349,6 +3583,10 @@ pub struct LinuxWindowOptions {
     pub wayland_theme: OptionWaylandTheme,
     pub request_user_attention: UserAttentionType,
     pub window_icon: OptionWindowIcon,
+    /// READWRITE: Icon in the system tray (StatusNotifierItem, XEmbed tray as a fallback on X11).
+    ///
+    /// Can be changed in callbacks / at runtime.
+    pub tray_icon: OptionTrayIcon,
 }
 
 type X11Visual = *const c_void;
@@ -2614,6 +3852,22 @@ pub struct WindowCreateOptions {
     /// If set to true, will hot-reload the UI every 200ms, useful in combination with `StyledDom::from_file()`
     /// to hot-reload the UI from a file while developing.
     pub hot_reload: bool,
+    /// ID of the window, `CallbackInfo::create_window` assigns a new ID and returns it
+    pub id: WindowId,
+    /// Owner of the window: a child window stays on top of its parent, is centered
+    /// over it (unless a position is set) and is closed together with the parent
+    pub parent: OptionWindowId,
+    /// If set, the parent window does not receive any input while this window is open
+    /// (only has an effect if `parent` is set)
+    pub modal: bool,
+    /// Invoked on the parent window when this window is closed
+    /// with a result (see `CallbackInfo::set_window_result`)
+    pub result_callback: OptionWindowResultCallback,
+    /// If set, the position, size and frame of the window are saved to a config file
+    /// when the window is closed and restored when a window with the same key is created
+    /// (see `PersistedWindowState`). The key has to be unique within the application.
+    /// On Wayland, only the size and frame are restored.
+    pub persistence_key: OptionAzString,
 }
 
 impl Default for WindowCreateOptions {
@@ -2625,6 +3879,11 @@ impl Default for WindowCreateOptions {
             theme: OptionWindowTheme::None,
             create_callback: OptionCallback::None,
             hot_reload: false,
+            id: WindowId::new(),
+            parent: OptionWindowId::None,
+            modal: false,
+            result_callback: OptionWindowResultCallback::None,
+            persistence_key: OptionAzString::None,
         }
     }
 }
@@ -2636,8 +3895,184 @@ impl WindowCreateOptions {
             ..WindowCreateOptions::default()
         }
     }
+
+    /// Creates the options for a modal dialog of the given parent window
+    pub fn new_modal(callback: LayoutCallbackType, parent: WindowId) -> Self {
+        Self {
+            state: WindowState::new(callback),
+            parent: OptionWindowId::Some(parent),
+            modal: true,
+            ..WindowCreateOptions::default()
+        }
+    }
+}
+
+/// Geometry of a window that is saved when a window with a
+/// `WindowCreateOptions::persistence_key` is closed
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PersistedWindowState {
+    /// Position of the top left corner of the window in physical pixels
+    pub position: PhysicalPositionI32,
+    /// Logical size of the window
+    pub size: LogicalSize,
+    pub frame: WindowFrame,
+    /// ID of the monitor that the window was on
+    pub monitor_id: usize,
+}
+
+impl PersistedWindowState {
+    /// Returns `None` if the position of the window is not known
+    pub fn from_window_state(state: &FullWindowState) -> Option<Self> {
+        let position = match state.position {
+            WindowPosition::Initialized(p) => p,
+            WindowPosition::Uninitialized => return None,
+        };
+
+        Some(Self {
+            position,
+            size: state.size.dimensions,
+            frame: state.flags.frame,
+            monitor_id: state.monitor.id,
+        })
+    }
+
+    /// Serializes the state to the contents of the config file (`key=value` lines)
+    pub fn serialize(&self) -> String {
+        let frame = match self.frame {
+            WindowFrame::Normal => "normal",
+            WindowFrame::Minimized => "minimized",
+            WindowFrame::Maximized => "maximized",
+            WindowFrame::Fullscreen => "fullscreen",
+        };
+
+        format!(
+            "x={}\ny={}\nwidth={}\nheight={}\nframe={}\nmonitor={}\n",
+            self.position.x,
+            self.position.y,
+            self.size.width,
+            self.size.height,
+            frame,
+            self.monitor_id
+        )
+    }
+
+    /// Parses the contents of the config file, returns `None` if a key is missing or invalid
+    pub fn deserialize(s: &str) -> Option<Self> {
+        let get = |key: &str| {
+            s.lines()
+                .filter_map(|line| line.split_once('='))
+                .find(|(k, _)| k.trim() == key)
+                .map(|(_, v)| v.trim())
+        };
+
+        let frame = match get("frame")? {
+            "normal" => WindowFrame::Normal,
+            "minimized" => WindowFrame::Minimized,
+            "maximized" => WindowFrame::Maximized,
+            "fullscreen" => WindowFrame::Fullscreen,
+            _ => return None,
+        };
+
+        let size = LogicalSize::new(get("width")?.parse().ok()?, get("height")?.parse().ok()?);
+        if !(size.width.is_finite() && size.height.is_finite()) {
+            return None;
+        }
+
+        Some(Self {
+            position: PhysicalPositionI32::new(get("x")?.parse().ok()?, get("y")?.parse().ok()?),
+            size,
+            frame,
+            monitor_id: get("monitor")?.parse().ok()?,
+        })
+    }
+
+    /// Writes the saved geometry into the initial state of a new window.
+    ///
+    /// The window is placed on the saved monitor (or on the primary monitor if
+    /// it is not connected anymore), shrunk to the size of the monitor and moved
+    /// so that it is fully visible. `monitors` may be empty if they are not known.
+    pub fn restore(&self, state: &mut WindowState, monitors: &[Monitor]) {
+        let mut position = self.position;
+        let mut size = LogicalSize::new(self.size.width.max(1.0), self.size.height.max(1.0));
+
+        if let Some(monitor) = self.get_monitor(monitors) {
+            let scale_factor = if monitor.scale_factor > 0.0 {
+                monitor.scale_factor as f32
+            } else {
+                1.0
+            };
+            size.width = size.width.min(monitor.size.width as f32 / scale_factor);
+            size.height = size.height.min(monitor.size.height as f32 / scale_factor);
+
+            let physical_size = size.to_physical(scale_factor);
+            let left = monitor.position.x as i32;
+            let top = monitor.position.y as i32;
+            let right = left + monitor.size.width as i32 - physical_size.width as i32;
+            let bottom = top + monitor.size.height as i32 - physical_size.height as i32;
+            position.x = position.x.min(right).max(left);
+            position.y = position.y.min(bottom).max(top);
+            state.monitor = monitor.clone();
+        }
+
+        state.position = WindowPosition::Initialized(position);
+        state.size.dimensions = size;
+        state.flags.frame = self.get_restored_frame();
+    }
+
+    /// Writes the saved size and frame into the initial state of a new window,
+    /// for platforms where applications can't position their windows (Wayland)
+    pub fn restore_size_and_frame(&self, state: &mut WindowState) {
+        state.size.dimensions =
+            LogicalSize::new(self.size.width.max(1.0), self.size.height.max(1.0));
+        state.flags.frame = self.get_restored_frame();
+    }
+
+    /// Windows that were minimized when they were closed are restored as normal windows
+    pub fn get_restored_frame(&self) -> WindowFrame {
+        match self.frame {
+            WindowFrame::Minimized => WindowFrame::Normal,
+            other => other,
+        }
+    }
+
+    // Saved monitor if it is still connected, otherwise the monitor
+    // that contains the saved position or the primary monitor
+    fn get_monitor<'a>(&self, monitors: &'a [Monitor]) -> Option<&'a Monitor> {
+        let contains_position = |m: &&Monitor| {
+            let (x, y) = (self.position.x as isize, self.position.y as isize);
+            x >= m.position.x
+                && y >= m.position.y
+                && x < m.position.x + m.size.width
+                && y < m.position.y + m.size.height
+        };
+
+        monitors
+            .iter()
+            .find(|m| m.id == self.monitor_id)
+            .or_else(|| monitors.iter().find(contains_position))
+            .or_else(|| monitors.iter().find(|m| m.is_primary_monitor))
+            .or_else(|| monitors.first())
+    }
+}
+
+/// Receives the result of a child window (see `CallbackInfo::set_window_result`),
+/// invoked on the parent window after the child window has been closed.
+///
+/// The first argument of the callback is `data`, the second one the result.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[repr(C)]
+pub struct WindowResultCallback {
+    pub data: RefAny,
+    pub callback: WriteBackCallback,
 }
 
+impl_option!(
+    WindowResultCallback,
+    OptionWindowResultCallback,
+    copy = false,
+    [Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash]
+);
+
 #[repr(C)]
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Ord, Eq, Hash)]
 pub enum RendererType {
@@ -3339,6 +4774,22 @@ impl WindowIcon {
             WindowIcon::Large(LargeWindowIconBytes { key, .. }) => *key,
         }
     }
+
+    /// Returns the width / height of the (square) icon in pixels
+    pub fn get_size(&self) -> usize {
+        match &self {
+            WindowIcon::Small(_) => 16,
+            WindowIcon::Large(_) => 32,
+        }
+    }
+
+    /// Returns the RGBA bytes of the icon
+    pub fn get_rgba_bytes(&self) -> &[u8] {
+        match &self {
+            WindowIcon::Small(SmallWindowIconBytes { rgba_bytes, .. }) => rgba_bytes.as_ref(),
+            WindowIcon::Large(LargeWindowIconBytes { rgba_bytes, .. }) => rgba_bytes.as_ref(),
+        }
+    }
 }
 // -- Only compare the IconKey (for WindowIcon and TaskBarIcon)
 
@@ -3415,6 +4866,534 @@ impl Hash for TaskBarIcon {
     }
 }
 
+/// Icon in the system tray / notification area of the desktop, with a context menu
+#[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
+#[repr(C)]
+pub struct TrayIcon {
+    /// Icon bytes, usually the same as the window icon
+    pub icon: WindowIcon,
+    /// Text that is shown when hovering over the icon
+    pub tooltip: AzString,
+    /// Menu that opens when the icon is clicked with the `menu.context_mouse_btn`
+    pub menu: Menu,
+}
+
+impl_option!(
+    TrayIcon,
+    OptionTrayIcon,
+    copy = false,
+    [Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord]
+);
+
+impl TrayIcon {
+    pub fn new(icon: WindowIcon, tooltip: AzString, menu: Menu) -> Self {
+        Self {
+            icon,
+            tooltip,
+            menu,
+        }
+    }
+
+    pub fn get_hash(&self) -> u64 {
+        use highway::{HighwayHash, HighwayHasher, Key};
+        let mut hasher = HighwayHasher::new(Key([0; 4]));
+        self.hash(&mut hasher);
+        hasher.finalize64()
+    }
+}
+
+/// Urgency of a desktop notification, critical notifications usually don't time out
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
+#[repr(C)]
+pub enum NotificationUrgency {
+    Low,
+    Normal,
+    Critical,
+}
+
+impl Default for NotificationUrgency {
+    fn default() -> Self {
+        NotificationUrgency::Normal
+    }
+}
+
+/// Button of a desktop notification
+#[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
+#[repr(C)]
+pub struct NotificationAction {
+    pub label: AzString,
+    pub callback: MenuCallback,
+}
+
+impl_vec!(
+    NotificationAction,
+    NotificationActionVec,
+    NotificationActionVecDestructor
+);
+impl_vec_clone!(
+    NotificationAction,
+    NotificationActionVec,
+    NotificationActionVecDestructor
+);
+impl_vec_debug!(NotificationAction, NotificationActionVec);
+impl_vec_partialeq!(NotificationAction, NotificationActionVec);
+
+/// Desktop notification, shown with `CallbackInfo::show_notification()`
+///
+/// The callbacks are invoked on the window that showed the notification,
+/// as long as the notification is visible and the window is open.
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct Notification {
+    pub title: AzString,
+    pub body: AzString,
+    pub icon: OptionImageRef,
+    pub urgency: NotificationUrgency,
+    /// Buttons of the notification (not supported by every notification server)
+    pub actions: NotificationActionVec,
+    /// Invoked when the notification itself is clicked
+    pub on_click: OptionMenuCallback,
+}
+
+impl Notification {
+    pub fn new(title: AzString, body: AzString) -> Self {
+        Self {
+            title,
+            body,
+            icon: None.into(),
+            urgency: NotificationUrgency::Normal,
+            actions: NotificationActionVec::from_const_slice(&[]),
+            on_click: None.into(),
+        }
+    }
+
+    pub fn with_icon(mut self, icon: ImageRef) -> Self {
+        self.icon = Some(icon).into();
+        self
+    }
+
+    pub fn with_urgency(mut self, urgency: NotificationUrgency) -> Self {
+        self.urgency = urgency;
+        self
+    }
+
+    pub fn with_action(mut self, label: AzString, data: RefAny, callback: CallbackType) -> Self {
+        let mut actions = self.actions.into_library_owned_vec();
+        actions.push(NotificationAction {
+            label,
+            callback: MenuCallback {
+                data,
+                callback: Callback { cb: callback },
+            },
+        });
+        self.actions = actions.into();
+        self
+    }
+
+    pub fn with_click_callback(mut self, data: RefAny, callback: CallbackType) -> Self {
+        self.on_click = Some(MenuCallback {
+            data,
+            callback: Callback { cb: callback },
+        })
+        .into();
+        self
+    }
+}
+
+impl_vec!(
+    VirtualKeyCodeCombo,
+    VirtualKeyCodeComboVec,
+    VirtualKeyCodeComboVecDestructor
+);
+impl_vec_clone!(
+    VirtualKeyCodeCombo,
+    VirtualKeyCodeComboVec,
+    VirtualKeyCodeComboVecDestructor
+);
+impl_vec_debug!(VirtualKeyCodeCombo, VirtualKeyCodeComboVec);
+impl_vec_partialeq!(VirtualKeyCodeCombo, VirtualKeyCodeComboVec);
+
+static LAST_SHORTCUT_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// ID of a registered keyboard shortcut, used to unregister the shortcut again
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+#[repr(C)]
+pub struct ShortcutId {
+    id: usize,
+}
+
+impl ShortcutId {
+    pub fn new() -> Self {
+        ShortcutId {
+            id: LAST_SHORTCUT_ID.fetch_add(1, AtomicOrdering::SeqCst),
+        }
+    }
+}
+
+/// Where a keyboard shortcut is active
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+#[repr(C, u8)]
+pub enum ShortcutScope {
+    /// Only while the window with the given ID (see `WindowCreateOptions::id`) has the focus
+    Window(WindowId),
+    /// While any window of the application has the focus
+    App,
+    /// System-wide, even if the application has no focus. Global shortcuts can only
+    /// consist of a single key combination and may already be taken by another application.
+    Global,
+}
+
+impl ShortcutScope {
+    /// Whether the shortcut is active in the given window
+    pub fn is_active_in(&self, window_id: WindowId) -> bool {
+        match self {
+            ShortcutScope::Window(w) => *w == window_id,
+            ShortcutScope::App | ShortcutScope::Global => true,
+        }
+    }
+
+    // Whether there is a window in which both scopes are active
+    fn overlaps(&self, other: &ShortcutScope) -> bool {
+        match (self, other) {
+            (ShortcutScope::Window(a), ShortcutScope::Window(b)) => a == b,
+            _ => true,
+        }
+    }
+}
+
+/// Keyboard shortcut: a sequence of key combinations (i.e. "Ctrl + K, Ctrl + S")
+/// that invokes a callback, registered with `App::register_shortcut` or
+/// `CallbackInfo::register_shortcut`
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct Shortcut {
+    /// Key combinations that have to be pressed one after another
+    pub keys: VirtualKeyCodeComboVec,
+    pub scope: ShortcutScope,
+    /// Description of the action for keyboard shortcut help screens
+    pub description: AzString,
+    pub callback: MenuCallback,
+}
+
+impl Shortcut {
+    pub fn new(
+        keys: VirtualKeyCodeComboVec,
+        scope: ShortcutScope,
+        description: AzString,
+        data: RefAny,
+        callback: CallbackType,
+    ) -> Self {
+        Self {
+            keys,
+            scope,
+            description,
+            callback: MenuCallback {
+                data,
+                callback: Callback { cb: callback },
+            },
+        }
+    }
+
+    /// Returns the keys as a human-readable string, i.e. "Ctrl+K Ctrl+S"
+    pub fn get_key_string(&self) -> AzString {
+        self.get_chords()
+            .iter()
+            .map(|chord| {
+                chord
+                    .iter()
+                    .map(|k| match k {
+                        AcceleratorKey::Ctrl => "Ctrl".to_string(),
+                        AcceleratorKey::Alt => "Alt".to_string(),
+                        AcceleratorKey::Shift => "Shift".to_string(),
+                        AcceleratorKey::Key(k) => {
+                            let name = format!("{:?}", k);
+                            match name.strip_prefix("Key") {
+                                Some(digit) if !digit.is_empty() => digit.to_string(),
+                                _ => name,
+                            }
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join("+")
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+            .into()
+    }
+
+    // Key combinations with the left / right modifiers merged, so that
+    // "LControl + S" and "RControl + S" are the same combination
+    fn get_chords(&self) -> Vec<Vec<AcceleratorKey>> {
+        self.keys
+            .iter()
+            .map(|combo| {
+                let mut keys = combo.get_accelerator_keys();
+                keys.sort();
+                keys.dedup();
+                keys
+            })
+            .collect()
+    }
+}
+
+/// Registered keyboard shortcut, see `ShortcutRegistry::get_bindings`
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct ShortcutBinding {
+    pub id: ShortcutId,
+    pub shortcut: Shortcut,
+}
+
+impl_vec!(
+    ShortcutBinding,
+    ShortcutBindingVec,
+    ShortcutBindingVecDestructor
+);
+impl_vec_clone!(
+    ShortcutBinding,
+    ShortcutBindingVec,
+    ShortcutBindingVecDestructor
+);
+impl_vec_debug!(ShortcutBinding, ShortcutBindingVec);
+impl_vec_partialeq!(ShortcutBinding, ShortcutBindingVec);
+
+/// Error when registering a keyboard shortcut
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+#[repr(C, u8)]
+pub enum ShortcutError {
+    /// The shortcut has no keys or a key combination consists only of modifiers
+    NoKeys,
+    /// Global shortcuts can't be a sequence of more than one key combination
+    GlobalSequence,
+    /// The keys of the shortcut are the same as (or start with / are the start of)
+    /// the keys of the already registered shortcut in an overlapping scope
+    Conflict(ShortcutId),
+}
+
+impl_result!(
+    ShortcutId,
+    ShortcutError,
+    ResultShortcutIdShortcutError,
+    [Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd]
+);
+
+/// Registration or removal of a keyboard shortcut in a callback,
+/// applied by the platform shell once the callback returns
+#[derive(Debug, Clone, PartialEq)]
+pub enum ShortcutChange {
+    Register(ShortcutId, Shortcut),
+    Unregister(ShortcutId),
+}
+
+/// Application-wide keyboard shortcuts
+///
+/// Key presses are fed into `handle_key_down`, which keeps track of the key
+/// combinations of a sequence that have already been pressed. A key combination
+/// that doesn't continue the sequence starts a new one.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ShortcutRegistry {
+    bindings: Vec<ShortcutBinding>,
+    /// Key combinations of the sequence that were pressed so far
+    pending_chords: Vec<Vec<AcceleratorKey>>,
+    /// Window in which the pending sequence was started
+    pending_window: Option<WindowId>,
+}
+
+impl ShortcutRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the shortcut, fails if it conflicts with an already registered shortcut
+    pub fn register(&mut self, shortcut: Shortcut) -> Result<ShortcutId, ShortcutError> {
+        let id = ShortcutId::new();
+        self.register_with_id(id, shortcut)?;
+        Ok(id)
+    }
+
+    /// Same as `register`, but with an ID that was already allocated
+    /// (see `CallbackInfo::register_shortcut`)
+    pub fn register_with_id(
+        &mut self,
+        id: ShortcutId,
+        shortcut: Shortcut,
+    ) -> Result<(), ShortcutError> {
+        let chords = shortcut.get_chords();
+        if chords.is_empty()
+            || chords
+                .iter()
+                .any(|c| !c.iter().any(|k| matches!(k, AcceleratorKey::Key(_))))
+        {
+            return Err(ShortcutError::NoKeys);
+        }
+        if shortcut.scope == ShortcutScope::Global && chords.len() > 1 {
+            return Err(ShortcutError::GlobalSequence);
+        }
+
+        // re-registering an ID replaces its shortcut, so the old shortcut
+        // can't conflict - but it is kept if the new one can't be registered
+        let old = self
+            .bindings
+            .iter()
+            .position(|b| b.id == id)
+            .map(|i| (i, self.bindings.remove(i)));
+        if let Some(conflict) = self.find_conflict(&shortcut) {
+            if let Some((i, old)) = old {
+                self.bindings.insert(i, old);
+            }
+            return Err(ShortcutError::Conflict(conflict));
+        }
+
+        self.bindings.push(ShortcutBinding { id, shortcut });
+        Ok(())
+    }
+
+    /// Returns the registered shortcut that can't be distinguished from the given
+    /// shortcut: both are active in the same window and the keys of one of them
+    /// are the same as the first keys of the other one
+    pub fn find_conflict(&self, shortcut: &Shortcut) -> Option<ShortcutId> {
+        let chords = shortcut.get_chords();
+        self.bindings
+            .iter()
+            .find(|b| {
+                let other = b.shortcut.get_chords();
+                let len = chords.len().min(other.len());
+                b.shortcut.scope.overlaps(&shortcut.scope) && chords[..len] == other[..len]
+            })
+            .map(|b| b.id)
+    }
+
+    /// Removes the shortcut, returns whether it was registered
+    pub fn unregister(&mut self, id: ShortcutId) -> bool {
+        let len = self.bindings.len();
+        self.bindings.retain(|b| b.id != id);
+        self.bindings.len() != len
+    }
+
+    /// Applies the shortcuts registered / unregistered in a callback,
+    /// returns the registrations that failed
+    pub fn apply_changes(
+        &mut self,
+        changes: &[ShortcutChange],
+    ) -> Vec<(ShortcutId, ShortcutError)> {
+        let mut errors = Vec::new();
+        for change in changes {
+            match change {
+                ShortcutChange::Register(id, shortcut) => {
+                    if let Err(e) = self.register_with_id(*id, shortcut.clone()) {
+                        errors.push((*id, e));
+                    }
+                }
+                ShortcutChange::Unregister(id) => {
+                    self.unregister(*id);
+                }
+            }
+        }
+        errors
+    }
+
+    /// Returns all registered shortcuts (i.e. for a keyboard shortcut help screen)
+    pub fn get_bindings(&self) -> ShortcutBindingVec {
+        self.bindings.clone().into()
+    }
+
+    /// Returns the shortcuts that are active in the given window
+    pub fn get_bindings_for_window(&self, window_id: WindowId) -> ShortcutBindingVec {
+        self.bindings
+            .iter()
+            .filter(|b| b.shortcut.scope.is_active_in(window_id))
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the shortcut with the given ID
+    pub fn get_shortcut(&self, id: ShortcutId) -> Option<&Shortcut> {
+        self.bindings
+            .iter()
+            .find(|b| b.id == id)
+            .map(|b| &b.shortcut)
+    }
+
+    /// Returns the global shortcuts, which have to be grabbed by the platform shell
+    pub fn get_global_bindings(&self) -> impl Iterator<Item = &ShortcutBinding> {
+        self.bindings
+            .iter()
+            .filter(|b| b.shortcut.scope == ShortcutScope::Global)
+    }
+
+    /// Whether a key sequence was started, but not finished yet
+    pub fn has_pending_sequence(&self) -> bool {
+        !self.pending_chords.is_empty()
+    }
+
+    /// Feeds a key press in the given window into the registry, returns the callback
+    /// of the shortcut if the key press completes it. Presses of modifier keys are ignored.
+    pub fn handle_key_down(
+        &mut self,
+        window_id: WindowId,
+        keyboard_state: &KeyboardState,
+    ) -> Option<&MenuCallback> {
+        use self::VirtualKeyCode::*;
+
+        match keyboard_state.current_virtual_keycode.into_option() {
+            None | Some(LControl) | Some(RControl) | Some(LAlt) | Some(RAlt) | Some(LShift)
+            | Some(RShift) => return None,
+            Some(_) => {}
+        }
+
+        if self.pending_window != Some(window_id) {
+            self.pending_chords.clear();
+        }
+
+        let mut matched = self.find_next_chord(window_id, keyboard_state);
+        if matched.is_none() && !self.pending_chords.is_empty() {
+            // the key doesn't continue the sequence, but may start a new one
+            self.pending_chords.clear();
+            matched = self.find_next_chord(window_id, keyboard_state);
+        }
+
+        let (index, chord) = match matched {
+            Some(s) => s,
+            None => {
+                self.pending_window = None;
+                return None;
+            }
+        };
+
+        self.pending_chords.push(chord);
+        if self.bindings[index].shortcut.keys.len() > self.pending_chords.len() {
+            self.pending_window = Some(window_id);
+            return None;
+        }
+
+        self.pending_chords.clear();
+        self.pending_window = None;
+        Some(&self.bindings[index].shortcut.callback)
+    }
+
+    // Returns the first shortcut that starts with the pending key combinations and
+    // whose next key combination is pressed (conflicting shortcuts can't be registered,
+    // so if a shortcut is completed by the key press, it is the only matching one)
+    fn find_next_chord(
+        &self,
+        window_id: WindowId,
+        keyboard_state: &KeyboardState,
+    ) -> Option<(usize, Vec<AcceleratorKey>)> {
+        let pressed = self.pending_chords.len();
+        self.bindings.iter().enumerate().find_map(|(i, b)| {
+            if !b.shortcut.scope.is_active_in(window_id) {
+                return None;
+            }
+            let chords = b.shortcut.get_chords();
+            let next = b.shortcut.keys.get(pressed)?;
+            if chords[..pressed] != self.pending_chords[..] || !next.matches(keyboard_state) {
+                return None;
+            }
+            Some((i, chords[pressed].clone()))
+        })
+    }
+}
+
 /// Menu struct (context menu, dropdown menu, context menu)
 ///
 /// Modeled after the Windows API
@@ -3441,6 +5420,35 @@ impl Menu {
             context_mouse_btn: ContextMenuMouseButton::Right,
         }
     }
+
+    /// Returns the callback of the first enabled menu item (including
+    /// sub-menus) whose accelerator matches the current keyboard state
+    pub fn get_accelerator_callback(
+        &self,
+        keyboard_state: &KeyboardState,
+    ) -> Option<&MenuCallback> {
+        fn find<'a>(
+            items: &'a [MenuItem],
+            keyboard_state: &KeyboardState,
+        ) -> Option<&'a MenuCallback> {
+            items.iter().find_map(|item| {
+                let item = match item {
+                    MenuItem::String(s) if s.state == MenuItemState::Normal => s,
+                    _ => return None,
+                };
+                let matches = item
+                    .accelerator
+                    .as_ref()
+                    .map(|a| a.matches(keyboard_state))
+                    .unwrap_or(false);
+                match item.callback.as_ref() {
+                    Some(cb) if matches => Some(cb),
+                    _ => find(item.children.as_ref(), keyboard_state),
+                }
+            })
+        }
+        find(self.items.as_ref(), keyboard_state)
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
@@ -3596,6 +5604,40 @@ impl_option!(
     [Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord]
 );
 
+impl VirtualKeyCodeCombo {
+    /// Converts the combo into accelerator keys, the left / right
+    /// variants of the modifier keys are mapped to `Ctrl`, `Alt` and `Shift`
+    pub fn get_accelerator_keys(&self) -> Vec<AcceleratorKey> {
+        use self::VirtualKeyCode::*;
+        self.keys
+            .iter()
+            .map(|k| match k {
+                LControl | RControl => AcceleratorKey::Ctrl,
+                LAlt | RAlt => AcceleratorKey::Alt,
+                LShift | RShift => AcceleratorKey::Shift,
+                other => AcceleratorKey::Key(*other),
+            })
+            .collect()
+    }
+
+    /// Returns whether all keys of the combo are pressed and no other
+    /// modifier is held down (so that "Ctrl + S" doesn't fire on "Ctrl + Shift + S")
+    pub fn matches(&self, keyboard_state: &KeyboardState) -> bool {
+        let keys = self.get_accelerator_keys();
+        if !keys.iter().any(|k| matches!(k, AcceleratorKey::Key(_))) {
+            return false;
+        }
+        let extra_modifier = [
+            AcceleratorKey::Ctrl,
+            AcceleratorKey::Alt,
+            AcceleratorKey::Shift,
+        ]
+        .iter()
+        .any(|m| !keys.contains(m) && m.matches(keyboard_state));
+        !extra_modifier && keys.iter().all(|k| k.matches(keyboard_state))
+    }
+}
+
 /// Menu callback: What data / function pointer should
 /// be called when the menu item is clicked?
 #[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
@@ -3639,3 +5681,302 @@ pub enum MenuItemState {
     /// Menu item is disabled, but NOT greyed out
     Disabled,
 }
+
+#[test]
+fn test_window_region_hit_test() {
+    fn hit_test(nodes: &[(usize, usize)]) -> FullHitTest {
+        let mut hit_test = FullHitTest::empty(None);
+        for (dom, node) in nodes.iter() {
+            let item = HitTestItem {
+                point_in_viewport: LogicalPosition::zero(),
+                point_relative_to_item: LogicalPosition::zero(),
+                is_focusable: false,
+                is_iframe_hit: None,
+            };
+            hit_test
+                .hovered_nodes
+                .entry(DomId { inner: *dom })
+                .or_insert_with(HitTest::empty)
+                .regular_hit_test_nodes
+                .insert(NodeId::new(*node), item);
+        }
+        hit_test
+    }
+
+    // DOM 0: 1 = title bar, 2 = close button inside of the title bar,
+    // 3 = resize handle, 4 = label without a region; DOM 1: iframe content
+    let regions = |dom_id: DomId, node_id: NodeId| match (dom_id.inner, node_id.index()) {
+        (0, 1) => Some(WindowRegion::Drag),
+        (0, 2) => Some(WindowRegion::Client),
+        (0, 3) => Some(WindowRegion::ResizeSE),
+        _ => None,
+    };
+
+    let title_bar = hit_test(&[(0, 0), (0, 1), (0, 4)]);
+    assert_eq!(
+        title_bar.find_window_region(regions),
+        Some(WindowRegion::Drag)
+    );
+    let close_button = hit_test(&[(0, 0), (0, 1), (0, 2)]);
+    assert_eq!(close_button.find_window_region(regions), None);
+    let resize_handle = hit_test(&[(0, 0), (0, 3)]);
+    assert_eq!(
+        resize_handle.find_window_region(regions),
+        Some(WindowRegion::ResizeSE)
+    );
+    assert_eq!(
+        hit_test(&[(0, 0), (1, 1)]).find_window_region(regions),
+        None
+    );
+    assert_eq!(
+        WindowRegion::ResizeSE.get_cursor(),
+        Some(MouseCursorType::SeResize)
+    );
+    assert_eq!(
+        WindowRegion::from_keyword(WindowRegion::ResizeNW.as_keyword()),
+        Some(WindowRegion::ResizeNW)
+    );
+}
+
+#[test]
+fn test_persisted_window_state() {
+    let saved = PersistedWindowState {
+        position: PhysicalPositionI32::new(2200, -40),
+        size: LogicalSize::new(1600.0, 900.0),
+        frame: WindowFrame::Maximized,
+        monitor_id: 2,
+    };
+    assert_eq!(
+        PersistedWindowState::deserialize(&saved.serialize()),
+        Some(saved)
+    );
+    assert_eq!(PersistedWindowState::deserialize("x=10\ny=10\n"), None);
+
+    let monitor = |id, x, width, height, scale_factor, is_primary_monitor| Monitor {
+        id,
+        size: LayoutSize::new(width, height),
+        position: LayoutPoint::new(x, 0),
+        scale_factor,
+        is_primary_monitor,
+        ..Monitor::default()
+    };
+
+    // the monitor is still connected: the window is moved down to be fully visible
+    let mut state = WindowState::default();
+    let monitors = [
+        monitor(1, 0, 1920, 1080, 1.0, true),
+        monitor(2, 1920, 3840, 2160, 2.0, false),
+    ];
+    saved.restore(&mut state, &monitors);
+    assert_eq!(
+        state.position,
+        WindowPosition::Initialized(PhysicalPositionI32::new(2200, 0))
+    );
+    assert_eq!(state.size.dimensions, LogicalSize::new(1600.0, 900.0));
+    assert_eq!(state.flags.frame, WindowFrame::Maximized);
+    assert_eq!(state.monitor.id, 2);
+
+    // the second monitor was disconnected: the window is moved to the primary monitor
+    let mut state = WindowState::default();
+    saved.restore(&mut state, &monitors[..1]);
+    assert_eq!(
+        state.position,
+        WindowPosition::Initialized(PhysicalPositionI32::new(320, 0))
+    );
+    assert_eq!(state.size.dimensions, LogicalSize::new(1600.0, 900.0));
+    assert_eq!(state.monitor.id, 1);
+
+    // the window is shrunk to the size of a smaller monitor
+    let mut state = WindowState::default();
+    let small = [monitor(1, 0, 1280, 720, 1.0, true)];
+    saved.restore(&mut state, &small);
+    assert_eq!(state.size.dimensions, LogicalSize::new(1280.0, 720.0));
+
+    // the position is not restored if the platform can't position windows,
+    // minimized windows are restored as normal windows
+    let minimized = PersistedWindowState {
+        frame: WindowFrame::Minimized,
+        ..saved
+    };
+    let mut state = WindowState::default();
+    minimized.restore_size_and_frame(&mut state);
+    assert_eq!(state.position, WindowPosition::Uninitialized);
+    assert_eq!(state.size.dimensions, LogicalSize::new(1600.0, 900.0));
+    assert_eq!(state.flags.frame, WindowFrame::Normal);
+}
+
+#[test]
+fn test_shortcut_registry() {
+    use self::VirtualKeyCode::*;
+    use crate::callbacks::CallbackInfo;
+
+    extern "C" fn save(_: &mut RefAny, _: &mut CallbackInfo) -> Update {
+        Update::DoNothing
+    }
+    extern "C" fn save_all(_: &mut RefAny, _: &mut CallbackInfo) -> Update {
+        Update::DoNothing
+    }
+
+    fn shortcut(keys: &[&[VirtualKeyCode]], scope: ShortcutScope, cb: CallbackType) -> Shortcut {
+        let keys = keys
+            .iter()
+            .map(|k| VirtualKeyCodeCombo {
+                keys: k.to_vec().into(),
+            })
+            .collect::<Vec<_>>();
+        Shortcut::new(keys.into(), scope, "".into(), RefAny::new(0_usize), cb)
+    }
+
+    fn press(keys: &[VirtualKeyCode]) -> KeyboardState {
+        KeyboardState {
+            current_virtual_keycode: keys.last().copied().into(),
+            pressed_virtual_keycodes: keys.to_vec().into(),
+            ..Default::default()
+        }
+    }
+
+    let window = WindowId::new();
+    let other_window = WindowId::new();
+    let mut registry = ShortcutRegistry::new();
+
+    let save_id = registry
+        .register(shortcut(&[&[LControl, S]], ShortcutScope::App, save))
+        .unwrap();
+    let save_all_id = registry
+        .register(shortcut(
+            &[&[LControl, K], &[LControl, S]],
+            ShortcutScope::Window(window),
+            save_all,
+        ))
+        .unwrap();
+
+    // same keys, the right control key is the same modifier
+    assert_eq!(
+        registry.register(shortcut(&[&[RControl, S]], ShortcutScope::App, save)),
+        Err(ShortcutError::Conflict(save_id))
+    );
+    // "Ctrl + S" would fire before the sequence is finished
+    assert_eq!(
+        registry.register(shortcut(
+            &[&[LControl, S], &[A]],
+            ShortcutScope::Window(other_window),
+            save
+        )),
+        Err(ShortcutError::Conflict(save_id))
+    );
+    assert_eq!(
+        registry.register(shortcut(&[&[LControl]], ShortcutScope::App, save)),
+        Err(ShortcutError::NoKeys)
+    );
+    assert_eq!(
+        registry.register(shortcut(&[&[A], &[B]], ShortcutScope::Global, save)),
+        Err(ShortcutError::GlobalSequence)
+    );
+
+    let fired = |registry: &mut ShortcutRegistry, window_id, keys: &[VirtualKeyCode]| {
+        registry
+            .handle_key_down(window_id, &press(keys))
+            .map(|c| c.callback.cb as usize)
+    };
+
+    assert_eq!(
+        fired(&mut registry, window, &[LControl, S]),
+        Some(save as usize)
+    );
+    // modifier keys don't interrupt the sequence
+    assert_eq!(fired(&mut registry, window, &[LControl]), None);
+    assert_eq!(fired(&mut registry, window, &[LControl, K]), None);
+    assert!(registry.has_pending_sequence());
+    assert_eq!(
+        fired(&mut registry, window, &[LControl, S]),
+        Some(save_all as usize)
+    );
+    assert!(!registry.has_pending_sequence());
+
+    // a key that doesn't continue the sequence starts a new one
+    assert_eq!(fired(&mut registry, window, &[LControl, K]), None);
+    assert_eq!(fired(&mut registry, window, &[A]), None);
+    assert_eq!(
+        fired(&mut registry, window, &[LControl, S]),
+        Some(save as usize)
+    );
+
+    // window shortcuts are only active in their window
+    assert_eq!(fired(&mut registry, other_window, &[LControl, K]), None);
+    assert!(!registry.has_pending_sequence());
+
+    assert_eq!(registry.get_bindings().len(), 2);
+    assert_eq!(registry.get_bindings_for_window(other_window).len(), 1);
+    assert_eq!(
+        registry.get_bindings().as_ref()[1]
+            .shortcut
+            .get_key_string(),
+        AzString::from("Ctrl+K Ctrl+S")
+    );
+
+    assert!(registry.unregister(save_id));
+    assert_eq!(
+        registry.register(shortcut(&[&[RControl, K]], ShortcutScope::App, save)),
+        Err(ShortcutError::Conflict(save_all_id))
+    );
+    assert!(registry
+        .register(shortcut(
+            &[&[RControl, S]],
+            ShortcutScope::Window(other_window),
+            save
+        ))
+        .is_ok());
+}
+
+#[test]
+fn test_shortcut_registry_reregister() {
+    use self::VirtualKeyCode::*;
+    use crate::callbacks::CallbackInfo;
+
+    extern "C" fn save(_: &mut RefAny, _: &mut CallbackInfo) -> Update {
+        Update::DoNothing
+    }
+
+    fn shortcut(keys: &[VirtualKeyCode]) -> Shortcut {
+        let keys = vec![VirtualKeyCodeCombo {
+            keys: keys.to_vec().into(),
+        }];
+        Shortcut::new(
+            keys.into(),
+            ShortcutScope::App,
+            "".into(),
+            RefAny::new(0_usize),
+            save,
+        )
+    }
+
+    let mut registry = ShortcutRegistry::new();
+    let save_id = registry.register(shortcut(&[LControl, S])).unwrap();
+    let open_id = registry.register(shortcut(&[LControl, O])).unwrap();
+
+    // the same ID with the same (or overlapping) keys doesn't conflict with itself
+    assert_eq!(
+        registry.register_with_id(save_id, shortcut(&[RControl, S])),
+        Ok(())
+    );
+    assert_eq!(
+        registry.register_with_id(save_id, shortcut(&[LControl, LShift, S])),
+        Ok(())
+    );
+    assert_eq!(registry.get_bindings().len(), 2);
+    assert_eq!(
+        registry.get_shortcut(save_id).unwrap().get_key_string(),
+        AzString::from("Ctrl+Shift+S")
+    );
+
+    // the old shortcut is kept if the new one conflicts with another shortcut
+    assert_eq!(
+        registry.register_with_id(save_id, shortcut(&[LControl, O])),
+        Err(ShortcutError::Conflict(open_id))
+    );
+    assert_eq!(registry.get_bindings().len(), 2);
+    assert_eq!(
+        registry.get_shortcut(save_id).unwrap().get_key_string(),
+        AzString::from("Ctrl+Shift+S")
+    );
+}
@@ -67,7 +67,10 @@ use crate::gl::OptionGlContextPtr;
 use crate::{
     app_resources::{ImageCache, RendererResources},
     callbacks::{DocumentId, DomNodeId, HitTestItem, ScrollPosition, Update},
-    dom::{EventFilter, FocusEventFilter, HoverEventFilter, NotEventFilter, WindowEventFilter},
+    dom::{
+        ComponentEventFilter, EventFilter, FocusEventFilter, HoverEventFilter, NotEventFilter,
+        WindowEventFilter,
+    },
     id_tree::NodeId,
     styled_dom::{ChangedCssProperty, DomId, NodeHierarchyItemId},
     task::ExternalSystemCallbacks,
@@ -94,6 +97,13 @@ pub struct Events {
     pub event_was_mouse_down: bool,
     pub event_was_mouse_leave: bool,
     pub event_was_mouse_release: bool,
+    /// Node that a drag was started on in this event (receives the `DragStart` event)
+    pub drag_source_node: Option<DomNodeId>,
+    /// Whether a drag is hovering over the window (nodes that the cursor enters get a `DragEnter` event)
+    pub drag_is_active: bool,
+    /// Whether a drag was hovering over the window before the event and was not dropped
+    /// (nodes that the cursor leaves get a `DragLeave` event)
+    pub drag_was_active: bool,
 }
 
 impl Events {
@@ -122,6 +132,22 @@ impl Events {
             .map(|f| f.mouse_state.mouse_down())
             .unwrap_or(false);
 
+        let drag_is_active = current_window_state.drag_state.current.is_some();
+        let drag_was_active = previous_window_state
+            .as_ref()
+            .map(|f| f.drag_state.current.is_some())
+            .unwrap_or(false)
+            && current_window_state.drag_state.dropped.is_none();
+        let drag_source_node = if current_window_events.contains(&WindowEventFilter::DragStart) {
+            current_window_state
+                .drag_state
+                .current
+                .as_ref()
+                .and_then(|d| d.source_node.into_option())
+        } else {
+            None
+        };
+
         let old_focus_node = previous_window_state
             .as_ref()
             .and_then(|f| f.focused_node.clone());
@@ -171,6 +197,9 @@ impl Events {
             previous_window_state_mouse_is_down,
             old_focus_node,
             old_hit_node_ids,
+            drag_source_node,
+            drag_is_active,
+            drag_was_active,
         }
     }
 
@@ -733,6 +762,9 @@ impl CallbacksOfHitTest {
         let mouseleave_filter = EventFilter::Hover(HoverEventFilter::MouseEnter);
         let focus_received_filter = EventFilter::Focus(FocusEventFilter::FocusReceived);
         let focus_lost_filter = EventFilter::Focus(FocusEventFilter::FocusLost);
+        let dragstart_filter = EventFilter::Hover(HoverEventFilter::DragStart);
+        let dragenter_filter = EventFilter::Hover(HoverEventFilter::DragEnter);
+        let dragleave_filter = EventFilter::Hover(HoverEventFilter::DragLeave);
 
         for (dom_id, layout_result) in layout_results.iter().enumerate() {
             let dom_id = DomId { inner: dom_id };
@@ -818,6 +850,85 @@ impl CallbacksOfHitTest {
                     }),
             );
 
+            // insert Hover::DragEnter events
+            if events.drag_is_active {
+                window_callbacks_this_dom.extend(
+                    nodes_to_check
+                        .onmouseenter_nodes
+                        .get(&dom_id)
+                        .unwrap_or(&default_map)
+                        .iter()
+                        .filter_map(|(node_id, ht)| {
+                            if layout_result.styled_dom.node_data.as_container()[*node_id]
+                                .get_callbacks()
+                                .iter()
+                                .any(|e| e.event == dragenter_filter)
+                            {
+                                Some(CallbackToCall {
+                                    event_filter: dragenter_filter.clone(),
+                                    hit_test_item: Some(*ht),
+                                    node_id: *node_id,
+                                })
+                            } else {
+                                None
+                            }
+                        }),
+                );
+            }
+
+            // insert Hover::DragLeave events
+            if events.drag_was_active {
+                window_callbacks_this_dom.extend(
+                    nodes_to_check
+                        .onmouseleave_nodes
+                        .get(&dom_id)
+                        .unwrap_or(&default_map)
+                        .iter()
+                        .filter_map(|(node_id, ht)| {
+                            if layout_result.styled_dom.node_data.as_container()[*node_id]
+                                .get_callbacks()
+                                .iter()
+                                .any(|e| e.event == dragleave_filter)
+                            {
+                                Some(CallbackToCall {
+                                    event_filter: dragleave_filter.clone(),
+                                    hit_test_item: Some(*ht),
+                                    node_id: *node_id,
+                                })
+                            } else {
+                                None
+                            }
+                        }),
+                );
+            }
+
+            // insert Hover::DragStart event (only on the node the drag was started on)
+            if let Some(DomNodeId {
+                dom,
+                node: az_node_id,
+            }) = events.drag_source_node
+            {
+                if dom == dom_id {
+                    if let Some(nid) = az_node_id.into_crate_internal() {
+                        if layout_result.styled_dom.node_data.as_container()[nid]
+                            .get_callbacks()
+                            .iter()
+                            .any(|e| e.event == dragstart_filter)
+                        {
+                            window_callbacks_this_dom.push(CallbackToCall {
+                                event_filter: dragstart_filter.clone(),
+                                hit_test_item: events
+                                    .old_hit_node_ids
+                                    .get(&dom_id)
+                                    .and_then(|map| map.get(&nid))
+                                    .cloned(),
+                                node_id: nid,
+                            })
+                        }
+                    }
+                }
+            }
+
             // insert other Hover:: events
             for (nid, ht) in nodes_to_check
                 .new_hit_node_ids
@@ -834,6 +945,7 @@ impl CallbacksOfHitTest {
                                 if e.event == EventFilter::Hover(*hev)
                                     && e.event != mouseenter_filter
                                     && e.event != mouseleave_filter
+                                    && e.event != dragstart_filter
                                 {
                                     Some(CallbackToCall {
                                         event_filter: EventFilter::Hover(hev.clone()),
@@ -998,6 +1110,48 @@ impl CallbacksOfHitTest {
         }
     }
 
+    /// Returns the `On::Scrolled` callbacks of the nodes whose scroll offset changed
+    pub fn new_scrolled(
+        scrolled_nodes: &BTreeMap<DomId, BTreeSet<NodeHierarchyItemId>>,
+        layout_results: &[LayoutResult],
+    ) -> Self {
+        let event_filter = EventFilter::Component(ComponentEventFilter::Scrolled);
+        let mut nodes_with_callbacks = BTreeMap::new();
+
+        for (dom_id, node_ids) in scrolled_nodes.iter() {
+            let layout_result = match layout_results.get(dom_id.inner) {
+                Some(s) => s,
+                None => continue,
+            };
+            let node_data = layout_result.styled_dom.node_data.as_container();
+            for node_id in node_ids.iter().filter_map(|n| n.into_crate_internal()) {
+                let has_callback = node_data
+                    .get(node_id)
+                    .map(|nd| {
+                        nd.callbacks
+                            .as_ref()
+                            .iter()
+                            .any(|cb| cb.event == event_filter)
+                    })
+                    .unwrap_or(false);
+                if has_callback {
+                    nodes_with_callbacks
+                        .entry(*dom_id)
+                        .or_insert_with(|| Vec::new())
+                        .push(CallbackToCall {
+                            event_filter,
+                            hit_test_item: None,
+                            node_id,
+                        });
+                }
+            }
+        }
+
+        CallbacksOfHitTest {
+            nodes_with_callbacks,
+        }
+    }
+
     /// The actual function that calls the callbacks in their proper hierarchy and order
     pub fn call(
         &mut self,
@@ -1012,6 +1166,40 @@ impl CallbacksOfHitTest {
         system_fonts: &mut FcFontCache,
         system_callbacks: &ExternalSystemCallbacks,
         renderer_resources: &RendererResources,
+    ) -> CallCallbacksResult {
+        self.call_inner(
+            previous_window_state,
+            full_window_state,
+            raw_window_handle,
+            scroll_states,
+            gl_context,
+            layout_results,
+            modifiable_scroll_states,
+            image_cache,
+            system_fonts,
+            system_callbacks,
+            renderer_resources,
+            /* dispatch_scroll_events: */ true,
+        )
+    }
+
+    /// Same as `call`, but only invokes the `On::Scrolled` callbacks of the nodes scrolled
+    /// in the callbacks if `dispatch_scroll_events` is set (so that scrolling a node in an
+    /// `On::Scrolled` callback can't recurse endlessly)
+    pub(crate) fn call_inner(
+        &mut self,
+        previous_window_state: &Option<FullWindowState>,
+        full_window_state: &FullWindowState,
+        raw_window_handle: &RawWindowHandle,
+        scroll_states: &BTreeMap<DomId, BTreeMap<NodeHierarchyItemId, ScrollPosition>>,
+        gl_context: &OptionGlContextPtr,
+        layout_results: &mut Vec<LayoutResult>,
+        modifiable_scroll_states: &mut ScrollStates,
+        image_cache: &mut ImageCache,
+        system_fonts: &mut FcFontCache,
+        system_callbacks: &ExternalSystemCallbacks,
+        renderer_resources: &RendererResources,
+        dispatch_scroll_events: bool,
     ) -> CallCallbacksResult {
         use crate::callbacks::CallbackInfo;
         use crate::styled_dom::ParentWithNodeDepth;
@@ -1027,14 +1215,20 @@ impl CallbacksOfHitTest {
             image_masks_changed: None,
             nodes_scrolled_in_callbacks: None,
             update_focused_node: None,
+            update_drag_data: None,
             timers: None,
             threads: None,
             timers_removed: None,
             threads_removed: None,
             windows_created: Vec::new(),
+            notifications: Vec::new(),
+            shortcuts_changed: Vec::new(),
+            windows_closed: Vec::new(),
+            window_result: None,
             cursor_changed: false,
         };
         let mut new_focus_target = None;
+        let mut ret_drag_data = None;
 
         let current_cursor = full_window_state.mouse_state.mouse_cursor_type.clone();
 
@@ -1108,9 +1302,14 @@ impl CallbacksOfHitTest {
                                 /*threads_removed:*/ &mut ret_threads_removed,
                                 /*current_window_handle:*/ raw_window_handle,
                                 /*new_windows:*/ &mut ret.windows_created,
+                                /*notifications:*/ &mut ret.notifications,
+                                /*shortcuts_changed:*/ &mut ret.shortcuts_changed,
+                                /*windows_closed:*/ &mut ret.windows_closed,
+                                /*window_result:*/ &mut ret.window_result,
                                 /*system_callbacks*/ system_callbacks,
                                 /*stop_propagation:*/ &mut stop_propagation,
                                 /*focus_target:*/ &mut new_focus,
+                                /*drag_data:*/ &mut ret_drag_data,
                                 /*words_changed_in_callbacks:*/ &mut ret_words_changed,
                                 /*images_changed_in_callbacks:*/ &mut ret_images_changed,
                                 /*image_masks_changed_in_callbacks:*/
@@ -1208,9 +1407,14 @@ impl CallbacksOfHitTest {
                             /*threads_removed:*/ &mut ret_threads_removed,
                             /*current_window_handle:*/ raw_window_handle,
                             /*new_windows:*/ &mut ret.windows_created,
+                            /*notifications:*/ &mut ret.notifications,
+                            /*shortcuts_changed:*/ &mut ret.shortcuts_changed,
+                            /*windows_closed:*/ &mut ret.windows_closed,
+                            /*window_result:*/ &mut ret.window_result,
                             /*system_callbacks*/ system_callbacks,
                             /*stop_propagation:*/ &mut stop_propagation,
                             /*focus_target:*/ &mut new_focus,
+                            /*drag_data:*/ &mut ret_drag_data,
                             /*words_changed_in_callbacks:*/ &mut ret_words_changed,
                             /*images_changed_in_callbacks:*/ &mut ret_images_changed,
                             /*image_masks_changed_in_callbacks:*/
@@ -1273,17 +1477,18 @@ impl CallbacksOfHitTest {
         }
 
         // Scroll nodes from programmatic callbacks
-        for (dom_id, callback_scrolled_nodes) in ret_nodes_scrolled_in_callbacks.iter() {
-            let scrollable_nodes = &layout_results[dom_id.inner].scrollable_nodes;
-            for (scroll_node_id, scroll_position) in callback_scrolled_nodes.iter() {
-                let scroll_node = match scrollable_nodes.overflowing_nodes.get(&scroll_node_id) {
-                    Some(s) => s,
-                    None => continue,
-                };
-
-                modifiable_scroll_states.set_scroll_position(&scroll_node, *scroll_position);
-                ret.should_scroll_render = true;
-            }
+        let overscroll = if full_window_state.flags.overscroll_bounce_enabled {
+            crate::scroll::MAX_OVERSCROLL
+        } else {
+            0.0
+        };
+        let scrolled_nodes = modifiable_scroll_states.apply_nodes_scrolled_in_callbacks(
+            &ret_nodes_scrolled_in_callbacks,
+            layout_results,
+            overscroll,
+        );
+        if !scrolled_nodes.is_empty() {
+            ret.should_scroll_render = true;
         }
 
         // Resolve the new focus target
@@ -1294,6 +1499,8 @@ impl CallbacksOfHitTest {
             }
         }
 
+        ret.update_drag_data = ret_drag_data;
+
         if current_cursor != ret_modified_window_state.mouse_state.mouse_cursor_type {
             ret.cursor_changed = true;
         }
@@ -1329,6 +1536,29 @@ impl CallbacksOfHitTest {
             ret.nodes_scrolled_in_callbacks = Some(ret_nodes_scrolled_in_callbacks);
         }
 
+        // Invoke the On::Scrolled callbacks of the nodes that were scrolled
+        if dispatch_scroll_events && !scrolled_nodes.is_empty() {
+            let current_scroll_states =
+                modifiable_scroll_states.get_scroll_positions(layout_results);
+            let mut scroll_callbacks =
+                CallbacksOfHitTest::new_scrolled(&scrolled_nodes, layout_results);
+            let scroll_callbacks_result = scroll_callbacks.call_inner(
+                previous_window_state,
+                full_window_state,
+                raw_window_handle,
+                &current_scroll_states,
+                gl_context,
+                layout_results,
+                modifiable_scroll_states,
+                image_cache,
+                system_fonts,
+                system_callbacks,
+                renderer_resources,
+                /* dispatch_scroll_events: */ false,
+            );
+            ret.merge(scroll_callbacks_result);
+        }
+
         ret
     }
 }
@@ -1487,7 +1717,34 @@ fn get_window_events(
         events.push(WindowEventFilter::VirtualKeyDown);
     }
 
-    if !cur_char_equal && current_window_state.keyboard_state.current_char.is_some() {
+    // input method (IME) events
+
+    let previous_composition = previous_window_state.ime_state.composition.as_ref();
+    let current_composition = current_window_state.ime_state.composition.as_ref();
+
+    match (previous_composition, current_composition) {
+        (None, Some(_)) => {
+            events.push(WindowEventFilter::TextCompositionStart);
+            events.push(WindowEventFilter::TextCompositionUpdate);
+        }
+        (Some(previous), Some(current)) => {
+            if previous != current {
+                events.push(WindowEventFilter::TextCompositionUpdate);
+            }
+        }
+        (Some(_), None) => {
+            events.push(WindowEventFilter::TextCompositionEnd);
+        }
+        (None, None) => {}
+    }
+
+    let committed_text_equal = current_window_state.ime_state.committed_text
+        == previous_window_state.ime_state.committed_text;
+    let has_committed_text = current_window_state.ime_state.committed_text.is_some();
+
+    if (!cur_char_equal && current_window_state.keyboard_state.current_char.is_some())
+        || (!committed_text_equal && has_committed_text)
+    {
         events.push(WindowEventFilter::TextInput);
     }
 
@@ -1504,6 +1761,59 @@ fn get_window_events(
         events.push(WindowEventFilter::VirtualKeyUp);
     }
 
+    // touch and gesture events
+
+    let previous_touch = &previous_window_state.touch_state;
+    let current_touch = &current_window_state.touch_state;
+
+    let touch_started = current_touch
+        .touch_points
+        .iter()
+        .any(|p| previous_touch.get_touch_point(p.id).is_none());
+    if touch_started {
+        events.push(WindowEventFilter::TouchStart);
+    }
+
+    let touch_moved = current_touch.touch_points.iter().any(|p| {
+        previous_touch
+            .get_touch_point(p.id)
+            .map(|previous| previous != p)
+            .unwrap_or(false)
+    });
+    if touch_moved {
+        events.push(WindowEventFilter::TouchMove);
+    }
+
+    if !current_touch.ended_touch_points.is_empty()
+        && current_touch.ended_touch_points != previous_touch.ended_touch_points
+    {
+        events.push(WindowEventFilter::TouchEnd);
+    }
+
+    if !current_touch.cancelled_touch_points.is_empty()
+        && current_touch.cancelled_touch_points != previous_touch.cancelled_touch_points
+    {
+        events.push(WindowEventFilter::TouchCancel);
+    }
+
+    // gestures fire whenever their value changes after they were recognized
+    if let Some(current) = current_touch.gesture.into_option() {
+        let previous = previous_touch.gesture.into_option();
+        if current.is_pinch && previous.map(|p| p.scale) != Some(current.scale) {
+            events.push(WindowEventFilter::Pinch);
+        }
+        if current.is_rotate && previous.map(|p| p.rotation) != Some(current.rotation) {
+            events.push(WindowEventFilter::Rotate);
+        }
+        if current.is_pan && previous.map(|p| p.translation) != Some(current.translation) {
+            events.push(WindowEventFilter::TwoFingerPan);
+        }
+    }
+
+    if previous_touch.long_press.is_none() && current_touch.long_press.is_some() {
+        events.push(WindowEventFilter::LongPress);
+    }
+
     // misc events
 
     let hovered_file_equals =
@@ -1527,6 +1837,44 @@ fn get_window_events(
         events.push(WindowEventFilter::ThemeChanged);
     }
 
+    if current_window_state.clipboard_sequence != previous_window_state.clipboard_sequence {
+        events.push(WindowEventFilter::ClipboardChanged);
+    }
+
+    // drag and drop events
+
+    let previous_drag = previous_window_state.drag_state.current.as_ref();
+    let current_drag = current_window_state.drag_state.current.as_ref();
+
+    match (previous_drag, current_drag) {
+        (None, Some(current)) => {
+            if current.is_external() {
+                events.push(WindowEventFilter::DragEnter);
+            } else {
+                events.push(WindowEventFilter::DragStart);
+            }
+            events.push(WindowEventFilter::DragOver);
+        }
+        (Some(_), Some(_)) => {
+            if current_window_state.mouse_state.cursor_position
+                != previous_window_state.mouse_state.cursor_position
+            {
+                events.push(WindowEventFilter::DragOver);
+            }
+        }
+        (Some(previous), None) => {
+            if current_window_state.drag_state.dropped.is_some() {
+                events.push(WindowEventFilter::Drop);
+            } else if previous.is_external() {
+                events.push(WindowEventFilter::DragLeave);
+            }
+            if !previous.is_external() {
+                events.push(WindowEventFilter::DragEnd);
+            }
+        }
+        (None, None) => {}
+    }
+
     events
 }
 
@@ -1543,3 +1891,314 @@ fn get_focus_events(input: &[HoverEventFilter]) -> Vec<FocusEventFilter> {
         .filter_map(|hover_event| hover_event.to_focus_event_filter())
         .collect()
 }
+
+// Drives the window state like a platform shell would for the given input method events
+#[cfg(test)]
+fn fake_ime_events(steps: &[&dyn Fn(&mut crate::window::ImeState)]) -> Vec<Vec<WindowEventFilter>> {
+    let mut state = FullWindowState::default();
+    steps
+        .iter()
+        .map(|step| {
+            state.ime_state.clear_committed_text();
+            let previous = Some(state.clone());
+            step(&mut state.ime_state);
+            get_window_events(&state, &previous)
+        })
+        .collect()
+}
+
+#[test]
+fn test_ime_composition_events() {
+    use self::WindowEventFilter::*;
+
+    let events = fake_ime_events(&[
+        &|ime| ime.set_composition("n".into(), 1),
+        &|ime| ime.set_composition("ni".into(), 2),
+        &|ime| ime.set_composition("ni".into(), 2),
+        &|ime| ime.commit("你".into()),
+        &|ime| ime.commit("你".into()),
+    ]);
+
+    assert_eq!(
+        events,
+        vec![
+            vec![TextCompositionStart, TextCompositionUpdate],
+            vec![TextCompositionUpdate],
+            vec![],
+            vec![TextCompositionEnd, TextInput],
+            vec![TextInput],
+        ]
+    );
+}
+
+#[test]
+fn test_ime_composition_cancelled() {
+    use self::WindowEventFilter::*;
+
+    let events = fake_ime_events(&[
+        &|ime| ime.set_composition("a".into(), 1),
+        &|ime| ime.cancel_composition(),
+        &|ime| ime.cancel_composition(),
+    ]);
+
+    assert_eq!(
+        events,
+        vec![
+            vec![TextCompositionStart, TextCompositionUpdate],
+            vec![TextCompositionEnd],
+            vec![],
+        ]
+    );
+}
+
+#[test]
+fn test_touch_and_gesture_events() {
+    use self::WindowEventFilter::*;
+    use crate::gesture::{GestureRecognizer, TouchEvent};
+    use crate::window::{LogicalPosition, TouchPoint};
+
+    let finger = |id: u64, x: f32, y: f32| TouchPoint {
+        id,
+        position: LogicalPosition::new(x, y),
+        pressure: 1.0,
+        ..Default::default()
+    };
+
+    let recorded = [
+        (0, TouchEvent::Down(finger(1, 100.0, 100.0))),
+        (0, TouchEvent::Move(finger(1, 100.0, 100.0))),
+        (16, TouchEvent::Down(finger(2, 200.0, 100.0))),
+        (32, TouchEvent::Move(finger(2, 300.0, 100.0))),
+        (48, TouchEvent::Move(finger(2, 310.0, 100.0))),
+        (64, TouchEvent::Up(2)),
+        (80, TouchEvent::Cancel(1)),
+        (600, TouchEvent::Down(finger(3, 50.0, 50.0))),
+        (1200, TouchEvent::Move(finger(3, 52.0, 50.0))),
+    ];
+
+    let mut recognizer = GestureRecognizer::new();
+    let mut state = FullWindowState::default();
+    let events = recorded
+        .iter()
+        .map(|(time, event)| {
+            let previous = Some(state.clone());
+            recognizer.handle_event(&mut state.touch_state, *event, *time);
+            get_window_events(&state, &previous)
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        events,
+        vec![
+            vec![TouchStart],
+            vec![],
+            vec![TouchStart],
+            // fingers moved apart by 100px: pinch, and the center moved by 50px: pan
+            vec![TouchMove, Pinch, TwoFingerPan],
+            vec![TouchMove, Pinch, TwoFingerPan],
+            vec![TouchEnd],
+            vec![TouchCancel],
+            vec![TouchStart],
+            vec![TouchMove, LongPress],
+        ]
+    );
+}
+
+// Drives the drag state like a platform shell would for the given (left button down,
+// cursor position) mouse input, returns the drag and drop events of every step
+#[cfg(test)]
+fn fake_drag_events(
+    state: &mut FullWindowState,
+    steps: &[(bool, crate::window::CursorPosition)],
+) -> Vec<Vec<WindowEventFilter>> {
+    // DOM 0: node 1 is draggable, node 2 (a label inside of it) is not,
+    // DOM 1 is an iframe with the draggable node 3
+    let is_draggable = |dom_id: DomId, node_id: NodeId| match (dom_id.inner, node_id.index()) {
+        (0, 1) | (1, 3) => true,
+        _ => false,
+    };
+
+    steps
+        .iter()
+        .map(|(left_down, cursor_position)| {
+            let previous = Some(state.clone());
+            state.mouse_state.left_down = *left_down;
+            state.mouse_state.cursor_position = *cursor_position;
+            state.update_drag_state_with(&previous, is_draggable);
+            get_drag_events(state, &previous)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+fn get_drag_events(
+    current_window_state: &FullWindowState,
+    previous_window_state: &Option<FullWindowState>,
+) -> Vec<WindowEventFilter> {
+    use self::WindowEventFilter::*;
+    get_window_events(current_window_state, previous_window_state)
+        .into_iter()
+        .filter(|e| [DragStart, DragEnter, DragOver, DragLeave, Drop, DragEnd].contains(e))
+        .collect()
+}
+
+#[cfg(test)]
+fn hover_nodes(nodes: &[(usize, usize)]) -> FullHitTest {
+    use crate::ui_solver::HitTest;
+    use crate::window::LogicalPosition;
+
+    let mut hit_test = FullHitTest::empty(None);
+    for (dom, node) in nodes.iter() {
+        let item = HitTestItem {
+            point_in_viewport: LogicalPosition::new(10.0, 10.0),
+            point_relative_to_item: LogicalPosition::new(*node as f32, 5.0),
+            is_focusable: false,
+            is_iframe_hit: None,
+        };
+        hit_test
+            .hovered_nodes
+            .entry(DomId { inner: *dom })
+            .or_insert_with(HitTest::empty)
+            .regular_hit_test_nodes
+            .insert(NodeId::new(*node), item);
+    }
+    hit_test
+}
+
+#[test]
+fn test_drag_start_and_drop() {
+    use self::WindowEventFilter::*;
+    use crate::window::{CursorPosition::InWindow, LogicalPosition};
+
+    let at = |x: f32| InWindow(LogicalPosition::new(x, 10.0));
+    let mut state = FullWindowState::default();
+    state.mouse_state.cursor_position = at(10.0);
+    // the label inside of the draggable node is hit, the drag starts on its parent
+    state.last_hit_test = hover_nodes(&[(0, 0), (0, 1), (0, 2)]);
+
+    let events = fake_drag_events(&mut state, &[(true, at(10.0)), (true, at(12.0))]);
+    assert_eq!(events, vec![vec![], vec![]]);
+    let source_node = DomNodeId {
+        dom: DomId { inner: 0 },
+        node: NodeHierarchyItemId::from_crate_internal(Some(NodeId::new(1))),
+    };
+    assert_eq!(
+        state.drag_state.pending.map(|p| p.source_node),
+        Some(source_node)
+    );
+    assert!(state.drag_state.current.is_none());
+
+    // the cursor moved further than the DRAG_THRESHOLD
+    let events = fake_drag_events(&mut state, &[(true, at(20.0)), (true, at(30.0))]);
+    assert_eq!(events, vec![vec![DragStart, DragOver], vec![DragOver]]);
+    let current = state.drag_state.current.clone().unwrap();
+    assert_eq!(current.source_node.into_option(), Some(source_node));
+    assert_eq!(current.drag_image_offset, LogicalPosition::new(1.0, 5.0));
+
+    // the drop is only visible for the event in which it happened
+    let events = fake_drag_events(&mut state, &[(false, at(30.0))]);
+    assert_eq!(events, vec![vec![Drop, DragEnd]]);
+    assert_eq!(state.drag_state.dropped, Some(current));
+    assert!(state.drag_state.current.is_none());
+
+    let events = fake_drag_events(&mut state, &[(false, at(30.0))]);
+    assert_eq!(events, vec![vec![]]);
+    assert!(state.drag_state.dropped.is_none());
+
+    // the drop target is the hovered node: Drop is a hover event, while
+    // DragEnter / DragLeave are fired when the hovered nodes change
+    assert_eq!(Drop.to_hover_event_filter(), Some(HoverEventFilter::Drop));
+    assert_eq!(
+        DragOver.to_hover_event_filter(),
+        Some(HoverEventFilter::DragOver)
+    );
+    assert_eq!(DragEnter.to_hover_event_filter(), None);
+    assert_eq!(DragEnd.to_hover_event_filter(), None);
+}
+
+#[test]
+fn test_drag_source_resolution() {
+    use crate::window::{CursorPosition::InWindow, LogicalPosition};
+
+    let at = |x: f32| InWindow(LogicalPosition::new(x, 10.0));
+
+    // iframes are drawn on top of their parent DOM
+    let mut state = FullWindowState::default();
+    state.last_hit_test = hover_nodes(&[(0, 1), (1, 3)]);
+    fake_drag_events(&mut state, &[(true, at(10.0))]);
+    assert_eq!(
+        state.drag_state.pending.map(|p| p.source_node.dom),
+        Some(DomId { inner: 1 })
+    );
+
+    // pressing the mouse button on a node that isn't draggable does nothing
+    let mut state = FullWindowState::default();
+    state.last_hit_test = hover_nodes(&[(0, 0), (0, 2)]);
+    let events = fake_drag_events(&mut state, &[(true, at(10.0)), (true, at(50.0))]);
+    assert_eq!(events, vec![vec![], vec![]]);
+    assert_eq!(state.drag_state, crate::window::DragState::default());
+
+    // the button has to be pressed on the node, not moved onto it
+    let mut state = FullWindowState::default();
+    state.last_hit_test = hover_nodes(&[(0, 0), (0, 1)]);
+    state.mouse_state.left_down = true;
+    let events = fake_drag_events(&mut state, &[(true, at(10.0)), (true, at(50.0))]);
+    assert_eq!(events, vec![vec![], vec![]]);
+    assert!(state.drag_state.current.is_none());
+}
+
+#[test]
+fn test_drag_cancel() {
+    use self::WindowEventFilter::*;
+    use crate::window::{
+        CursorPosition::{InWindow, OutOfWindow},
+        DragData, LogicalPosition,
+    };
+
+    let at = |x: f32| InWindow(LogicalPosition::new(x, 10.0));
+    let start_drag = |state: &mut FullWindowState| {
+        state.last_hit_test = hover_nodes(&[(0, 1)]);
+        let events = fake_drag_events(state, &[(true, at(10.0)), (true, at(30.0))]);
+        assert_eq!(events, vec![vec![], vec![DragStart, DragOver]]);
+    };
+
+    // released outside of the window
+    let mut state = FullWindowState::default();
+    start_drag(&mut state);
+    let outside = OutOfWindow(LogicalPosition::new(-5.0, 10.0));
+    let events = fake_drag_events(&mut state, &[(true, outside), (false, outside)]);
+    assert_eq!(events, vec![vec![DragOver], vec![DragEnd]]);
+    assert_eq!(state.drag_state.dropped, None);
+
+    // cancelled from a callback (CallbackInfo::cancel_drag), the
+    // mouse button is still down, but no new drag is started
+    let mut state = FullWindowState::default();
+    start_drag(&mut state);
+    let previous = Some(state.clone());
+    state.drag_state.update_drag_data(None);
+    assert_eq!(get_drag_events(&state, &previous), vec![DragEnd]);
+    let events = fake_drag_events(&mut state, &[(true, at(50.0)), (false, at(50.0))]);
+    assert_eq!(events, vec![vec![], vec![]]);
+    assert_eq!(state.drag_state, crate::window::DragState::default());
+
+    // a drag from another application leaves the window or is dropped
+    let external = DragData::new().with_text("text".into());
+    let mut state = FullWindowState::default();
+    let mut previous = Some(state.clone());
+    state.drag_state.current = Some(external.clone());
+    assert_eq!(
+        get_drag_events(&state, &previous),
+        vec![DragEnter, DragOver]
+    );
+    previous = Some(state.clone());
+    state.drag_state.current = None;
+    assert_eq!(get_drag_events(&state, &previous), vec![DragLeave]);
+
+    state.drag_state.current = Some(external.clone());
+    previous = Some(state.clone());
+    state.drag_state.current = None;
+    state.drag_state.dropped = Some(external);
+    state.update_drag_state_with(&previous, |_, _| false);
+    assert_eq!(get_drag_events(&state, &previous), vec![Drop]);
+    assert!(state.drag_state.dropped.is_some());
+}
@@ -3,7 +3,7 @@
 use crate::css::VecContents;
 use crate::dom::Dom;
 use crate::styled_dom::StyledDom;
-use crate::window::{AzStringPair, StringPairVec};
+use crate::window::{AzStringPair, StringPairVec, WindowRegion};
 use alloc::collections::BTreeMap;
 use azul_css::{
     AzString, Css, CssPath, CssPathPseudoSelector, CssPathSelector, CssRuleBlock, NodeTypeTag,
@@ -34,11 +34,13 @@ pub type ComponentName = String;
 pub type CompiledComponent = String;
 pub type FilteredComponentArguments = ComponentArguments;
 
-pub const DEFAULT_ARGS: [&str; 7] = [
+pub const DEFAULT_ARGS: [&str; 9] = [
     "id",
     "class",
     "tabindex",
     "focusable",
+    "draggable",
+    "window-region",
     "accepts_text",
     "name",
     "args",
@@ -1309,7 +1311,8 @@ fn main() {
         Dom, IdOrClass, TabIndex,
         IdOrClass::{{Id, Class}},
         NodeDataInlineCssProperty,
-    }};\r\n\r\n{}\r\n\r\n{}
+    }};
+    use azul::window::WindowRegion;\r\n\r\n{}\r\n\r\n{}
 
     pub fn render() -> Dom {{\r\n{}\r\n    }}\r\n}}", extra_block_string, css_blocks, app_source),
         main_func,
@@ -1532,6 +1535,22 @@ pub fn set_attributes(
             _ => node_data.set_tab_index(TabIndex::NoKeyboardFocus),
         }
     }
+
+    if let Some(draggable) = xml_attributes
+        .get_key("draggable")
+        .map(|f| format_args_dynamic(f.as_str(), &filtered_xml_attributes.args))
+        .and_then(|f| parse_bool(&f))
+    {
+        node_data.set_draggable(draggable);
+    }
+
+    if let Some(region) = xml_attributes
+        .get_key("window-region")
+        .map(|f| format_args_dynamic(f.as_str(), &filtered_xml_attributes.args))
+        .and_then(|f| WindowRegion::from_keyword(f.trim()))
+    {
+        node_data.set_window_region(region);
+    }
 }
 
 pub fn set_stringified_attributes(
@@ -1613,6 +1632,25 @@ pub fn set_stringified_attributes(
             )),
         }
     }
+
+    if let Some(draggable) = xml_attributes
+        .get_key("draggable")
+        .map(|f| format_args_dynamic(f, &filtered_xml_attributes))
+        .and_then(|f| parse_bool(&f))
+    {
+        dom_string.push_str(&format!("\r\n{}.with_draggable({})", t, draggable));
+    }
+
+    if let Some(region) = xml_attributes
+        .get_key("window-region")
+        .map(|f| format_args_dynamic(f, &filtered_xml_attributes))
+        .and_then(|f| WindowRegion::from_keyword(f.trim()))
+    {
+        dom_string.push_str(&format!(
+            "\r\n{}.with_window_region(WindowRegion::{:?})",
+            t, region
+        ));
+    }
 }
 
 /// Item of a split string - either a variable name or a string
@@ -18,16 +18,18 @@ use azul_css::{
     LayoutPaddingLeft, LayoutPaddingRight, LayoutPaddingTop, LayoutPosition, LayoutRight,
     LayoutTop, LayoutWidth, LinearColorStop, LinearGradient, NormalizedLinearColorStop,
     NormalizedRadialColorStop, OptionPercentageValue, PercentageValue, PixelValue,
-    PixelValueNoPercent, RadialColorStop, RadialGradient, RadialGradientSize, ScrollbarStyle,
-    Shape, SizeMetric, StyleBackfaceVisibility, StyleBackgroundContent, StyleBackgroundContentVec,
+    PixelValueNoPercent, PrintAsCssValue, RadialColorStop, RadialGradient, RadialGradientSize,
+    ScrollSnapAxis, ScrollSnapStrictness, ScrollbarStyle, Shape, SizeMetric,
+    StyleBackfaceVisibility, StyleBackgroundContent, StyleBackgroundContentVec,
     StyleBackgroundPosition, StyleBackgroundPositionVec, StyleBackgroundRepeat,
     StyleBackgroundRepeatVec, StyleBackgroundSize, StyleBackgroundSizeVec, StyleBorderBottomColor,
     StyleBorderBottomLeftRadius, StyleBorderBottomRightRadius, StyleBorderBottomStyle,
     StyleBorderLeftColor, StyleBorderLeftStyle, StyleBorderRightColor, StyleBorderRightStyle,
     StyleBorderSide, StyleBorderTopColor, StyleBorderTopLeftRadius, StyleBorderTopRightRadius,
-    StyleBorderTopStyle, StyleBoxShadow, StyleCursor, StyleFilter, StyleFilterVec, StyleFontFamily,
-    StyleFontFamilyVec, StyleFontSize, StyleLetterSpacing, StyleLineHeight, StyleMixBlendMode,
-    StyleOpacity, StylePerspectiveOrigin, StyleTabWidth, StyleTextAlign, StyleTextColor,
+    StyleBorderTopStyle, StyleBoxShadow, StyleCursor, StyleCursorImage, StyleFilter,
+    StyleFilterVec, StyleFontFamily, StyleFontFamilyVec, StyleFontSize, StyleLetterSpacing,
+    StyleLineHeight, StyleMixBlendMode, StyleOpacity, StylePerspectiveOrigin, StyleScrollBehavior,
+    StyleScrollSnapAlign, StyleScrollSnapType, StyleTabWidth, StyleTextAlign, StyleTextColor,
     StyleTransform, StyleTransformOrigin, StyleTransformVec, StyleWordSpacing,
 };
 
@@ -361,6 +363,9 @@ pub fn parse_css_property<'a>(
             .into(),
 
             ScrollbarStyle => parse_scrollbar_style(value)?.into(), // TODO: stub - always returns default style
+            ScrollBehavior => parse_style_scroll_behavior(value)?.into(),
+            ScrollSnapType => parse_style_scroll_snap_type(value)?.into(),
+            ScrollSnapAlign => parse_style_scroll_snap_align(value)?.into(),
 
             Opacity => parse_style_opacity(value)?.into(),
             Transform => parse_style_transform_vec(value)?.into(),
@@ -4031,40 +4036,121 @@ multi_type_parser!(
     ["outset", Outset]
 );
 
-multi_type_parser!(
-    parse_style_cursor,
-    StyleCursor,
-    ["alias", Alias],
-    ["all-scroll", AllScroll],
-    ["cell", Cell],
-    ["col-resize", ColResize],
-    ["context-menu", ContextMenu],
-    ["copy", Copy],
-    ["crosshair", Crosshair],
-    ["default", Default],
-    ["e-resize", EResize],
-    ["ew-resize", EwResize],
-    ["grab", Grab],
-    ["grabbing", Grabbing],
-    ["help", Help],
-    ["move", Move],
-    ["n-resize", NResize],
-    ["ns-resize", NsResize],
-    ["nesw-resize", NeswResize],
-    ["nwse-resize", NwseResize],
-    ["pointer", Pointer],
-    ["progress", Progress],
-    ["row-resize", RowResize],
-    ["s-resize", SResize],
-    ["se-resize", SeResize],
-    ["text", Text],
-    ["unset", Unset],
-    ["vertical-text", VerticalText],
-    ["w-resize", WResize],
-    ["wait", Wait],
-    ["zoom-in", ZoomIn],
-    ["zoom-out", ZoomOut]
-);
+/// Parses a `StyleCursor` attribute from a `&str`, including image cursors
+///
+/// # Example
+///
+/// ```rust
+/// # extern crate azul_css;
+/// # extern crate azul_css_parser;
+/// # use azul_css_parser::parse_style_cursor;
+/// # use azul_css::{StyleCursor, StyleCursorImage};
+/// assert_eq!(parse_style_cursor("pointer"), Ok(StyleCursor::Pointer));
+/// assert_eq!(
+///     parse_style_cursor("url(\"brush\") 4 8, crosshair"),
+///     Ok(StyleCursor::Image(StyleCursorImage {
+///         image: String::from("brush").into(),
+///         hotspot_x: 4,
+///         hotspot_y: 8,
+///         fallback: vec![StyleCursor::Crosshair].into(),
+///     }))
+/// );
+/// ```
+pub fn parse_style_cursor<'a>(input: &'a str) -> Result<StyleCursor, InvalidValueErr<'a>> {
+    let input = input.trim();
+
+    let mut cursors = split_string_respect_comma(input)
+        .into_iter()
+        .map(|c| parse_style_cursor_single(c.trim()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if cursors.is_empty() {
+        return Err(InvalidValueErr(input));
+    }
+
+    match cursors.remove(0) {
+        StyleCursor::Image(mut image) => {
+            image.fallback = cursors.into();
+            Ok(StyleCursor::Image(image))
+        }
+        // only image cursors can have fallbacks
+        keyword if cursors.is_empty() => Ok(keyword),
+        _ => Err(InvalidValueErr(input)),
+    }
+}
+
+/// Parses either a `url("id") [x y]` image cursor or a cursor keyword
+fn parse_style_cursor_single<'a>(input: &'a str) -> Result<StyleCursor, InvalidValueErr<'a>> {
+    if !(input.starts_with("url(") || input.starts_with("image(")) {
+        return parse_style_cursor_keyword(input);
+    }
+
+    let closing_brace = input.rfind(')').ok_or(InvalidValueErr(input))?;
+    let (_, brace_contents) = parse_parentheses(&input[..=closing_brace], &["url", "image"])
+        .map_err(|_| InvalidValueErr(input))?;
+    // url() also allows unquoted image IDs
+    let image =
+        parse_image(brace_contents).unwrap_or_else(|_| String::from(brace_contents.trim()).into());
+
+    let mut hotspot = input[(closing_brace + 1)..].split_whitespace();
+    let (hotspot_x, hotspot_y) = match (hotspot.next(), hotspot.next(), hotspot.next()) {
+        (None, None, None) => (0, 0),
+        (Some(x), Some(y), None) => (
+            x.parse::<u32>().map_err(|_| InvalidValueErr(input))?,
+            y.parse::<u32>().map_err(|_| InvalidValueErr(input))?,
+        ),
+        _ => return Err(InvalidValueErr(input)),
+    };
+
+    Ok(StyleCursor::Image(StyleCursorImage {
+        image,
+        hotspot_x,
+        hotspot_y,
+        fallback: Vec::new().into(),
+    }))
+}
+
+fn parse_style_cursor_keyword<'a>(input: &'a str) -> Result<StyleCursor, InvalidValueErr<'a>> {
+    match input {
+        "alias" => Ok(StyleCursor::Alias),
+        "all-scroll" => Ok(StyleCursor::AllScroll),
+        "cell" => Ok(StyleCursor::Cell),
+        "col-resize" => Ok(StyleCursor::ColResize),
+        "context-menu" => Ok(StyleCursor::ContextMenu),
+        "copy" => Ok(StyleCursor::Copy),
+        "crosshair" => Ok(StyleCursor::Crosshair),
+        "default" => Ok(StyleCursor::Default),
+        "e-resize" => Ok(StyleCursor::EResize),
+        "ew-resize" => Ok(StyleCursor::EwResize),
+        "grab" => Ok(StyleCursor::Grab),
+        "grabbing" => Ok(StyleCursor::Grabbing),
+        "help" => Ok(StyleCursor::Help),
+        "move" => Ok(StyleCursor::Move),
+        "n-resize" => Ok(StyleCursor::NResize),
+        "ns-resize" => Ok(StyleCursor::NsResize),
+        "nesw-resize" => Ok(StyleCursor::NeswResize),
+        "nwse-resize" => Ok(StyleCursor::NwseResize),
+        "pointer" => Ok(StyleCursor::Pointer),
+        "progress" => Ok(StyleCursor::Progress),
+        "row-resize" => Ok(StyleCursor::RowResize),
+        "s-resize" => Ok(StyleCursor::SResize),
+        "se-resize" => Ok(StyleCursor::SeResize),
+        "text" => Ok(StyleCursor::Text),
+        "unset" => Ok(StyleCursor::Unset),
+        "vertical-text" => Ok(StyleCursor::VerticalText),
+        "w-resize" => Ok(StyleCursor::WResize),
+        "wait" => Ok(StyleCursor::Wait),
+        "zoom-in" => Ok(StyleCursor::ZoomIn),
+        "zoom-out" => Ok(StyleCursor::ZoomOut),
+        _ => Err(InvalidValueErr(input)),
+    }
+}
+
+impl FormatAsCssValue for StyleCursor {
+    fn format_as_css_value(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.print_as_css_value())
+    }
+}
 
 multi_type_parser!(
     parse_style_backface_visibility,
@@ -4073,6 +4159,59 @@ multi_type_parser!(
     ["visible", Visible]
 );
 
+multi_type_parser!(
+    parse_style_scroll_behavior,
+    StyleScrollBehavior,
+    ["auto", Auto],
+    ["smooth", Smooth]
+);
+
+multi_type_parser!(
+    parse_style_scroll_snap_align,
+    StyleScrollSnapAlign,
+    ["none", None],
+    ["start", Start],
+    ["center", Center],
+    ["end", End]
+);
+
+/// Parses a `scroll-snap-type` attribute, such as `none`, `x` or `y mandatory`
+pub fn parse_style_scroll_snap_type<'a>(
+    input: &'a str,
+) -> Result<StyleScrollSnapType, InvalidValueErr<'a>> {
+    let input = input.trim();
+    let mut iter = input.split_whitespace();
+
+    // block and inline are the axes of horizontal text
+    let axis = match iter.next() {
+        Some("none") => ScrollSnapAxis::None,
+        Some("x") | Some("inline") => ScrollSnapAxis::X,
+        Some("y") | Some("block") => ScrollSnapAxis::Y,
+        Some("both") => ScrollSnapAxis::Both,
+        _ => return Err(InvalidValueErr(input)),
+    };
+
+    let strictness = match (axis, iter.next()) {
+        (_, None) => ScrollSnapStrictness::Proximity,
+        (ScrollSnapAxis::None, Some(_)) => return Err(InvalidValueErr(input)),
+        (_, Some("proximity")) => ScrollSnapStrictness::Proximity,
+        (_, Some("mandatory")) => ScrollSnapStrictness::Mandatory,
+        (_, Some(_)) => return Err(InvalidValueErr(input)),
+    };
+
+    if iter.next().is_some() {
+        return Err(InvalidValueErr(input));
+    }
+
+    Ok(StyleScrollSnapType { axis, strictness })
+}
+
+impl FormatAsCssValue for StyleScrollSnapType {
+    fn format_as_css_value(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.print_as_css_value())
+    }
+}
+
 pub fn parse_style_background_size<'a>(
     input: &'a str,
 ) -> Result<StyleBackgroundSize, InvalidValueErr<'a>> {
@@ -5615,6 +5754,31 @@ mod css_tests {
         );
     }
 
+    #[test]
+    fn test_parse_cursor_image() {
+        use crate::alloc::string::ToString;
+        assert_eq!(
+            parse_style_cursor("url('rotate') 16 16, image(\"brush\"), crosshair"),
+            Ok(StyleCursor::Image(StyleCursorImage {
+                image: "rotate".to_string().into(),
+                hotspot_x: 16,
+                hotspot_y: 16,
+                fallback: vec![
+                    StyleCursor::Image(StyleCursorImage {
+                        image: "brush".to_string().into(),
+                        hotspot_x: 0,
+                        hotspot_y: 0,
+                        fallback: Vec::new().into(),
+                    }),
+                    StyleCursor::Crosshair,
+                ]
+                .into(),
+            }))
+        );
+        assert!(parse_style_cursor("pointer, crosshair").is_err());
+        assert!(parse_style_cursor("url(brush) 4").is_err());
+    }
+
     #[test]
     fn test_parse_padding_1() {
         assert_eq!(
@@ -5694,4 +5858,28 @@ mod css_tests {
     fn test_parse_angle_value_3() {
         assert_eq!(parse_angle_value("20.4grad"), Ok(AngleValue::grad(20.4)));
     }
+
+    #[test]
+    fn test_parse_scroll_snap_type() {
+        assert_eq!(
+            parse_style_scroll_snap_type("y mandatory"),
+            Ok(StyleScrollSnapType {
+                axis: ScrollSnapAxis::Y,
+                strictness: ScrollSnapStrictness::Mandatory,
+            })
+        );
+        assert_eq!(
+            parse_style_scroll_snap_type("inline"),
+            Ok(StyleScrollSnapType {
+                axis: ScrollSnapAxis::X,
+                strictness: ScrollSnapStrictness::Proximity,
+            })
+        );
+        assert_eq!(
+            parse_style_scroll_snap_type("none"),
+            Ok(StyleScrollSnapType::default())
+        );
+        assert!(parse_style_scroll_snap_type("none mandatory").is_err());
+        assert!(parse_style_scroll_snap_type("y sometimes").is_err());
+    }
 }
@@ -110,6 +110,9 @@ const CSS_PROPERTY_KEY_MAP: [(CssPropertyType, &'static str); 74] = [
     (CssPropertyType::BoxShadowLeft, "-azul-box-shadow-left"),
     (CssPropertyType::BoxShadowBottom, "-azul-box-shadow-bottom"),
     (CssPropertyType::ScrollbarStyle, "-azul-scrollbar-style"),
+    (CssPropertyType::ScrollBehavior, "scroll-behavior"),
+    (CssPropertyType::ScrollSnapType, "scroll-snap-type"),
+    (CssPropertyType::ScrollSnapAlign, "scroll-snap-align"),
     (CssPropertyType::Opacity, "opacity"),
     (CssPropertyType::Transform, "transform"),
     (CssPropertyType::PerspectiveOrigin, "perspective-origin"),
@@ -1061,6 +1064,9 @@ pub enum CssPropertyType {
     BoxShadowTop,
     BoxShadowBottom,
     ScrollbarStyle,
+    ScrollBehavior,
+    ScrollSnapType,
+    ScrollSnapAlign,
     Opacity,
     Transform,
     TransformOrigin,
@@ -1157,6 +1163,9 @@ impl CssPropertyType {
             CssPropertyType::BoxShadowTop => "-azul-box-shadow-top",
             CssPropertyType::BoxShadowBottom => "-azul-box-shadow-bottom",
             CssPropertyType::ScrollbarStyle => "-azul-scrollbar-style",
+            CssPropertyType::ScrollBehavior => "scroll-behavior",
+            CssPropertyType::ScrollSnapType => "scroll-snap-type",
+            CssPropertyType::ScrollSnapAlign => "scroll-snap-align",
             CssPropertyType::Opacity => "opacity",
             CssPropertyType::Transform => "transform",
             CssPropertyType::TransformOrigin => "transform-origin",
@@ -1212,6 +1221,9 @@ impl CssPropertyType {
             | BoxShadowTop
             | BoxShadowBottom
             | ScrollbarStyle
+            | ScrollBehavior
+            | ScrollSnapType
+            | ScrollSnapAlign
             | Opacity
             | Transform
             | TransformOrigin
@@ -1316,6 +1328,9 @@ pub enum CssProperty {
     BoxShadowTop(StyleBoxShadowValue),
     BoxShadowBottom(StyleBoxShadowValue),
     ScrollbarStyle(ScrollbarStyleValue),
+    ScrollBehavior(StyleScrollBehaviorValue),
+    ScrollSnapType(StyleScrollSnapTypeValue),
+    ScrollSnapAlign(StyleScrollSnapAlignValue),
     Opacity(StyleOpacityValue),
     Transform(StyleTransformVecValue),
     TransformOrigin(StyleTransformOriginValue),
@@ -1500,6 +1515,15 @@ macro_rules! css_property_from_type {
             CssPropertyType::ScrollbarStyle => {
                 CssProperty::ScrollbarStyle(ScrollbarStyleValue::$content_type)
             }
+            CssPropertyType::ScrollBehavior => {
+                CssProperty::ScrollBehavior(StyleScrollBehaviorValue::$content_type)
+            }
+            CssPropertyType::ScrollSnapType => {
+                CssProperty::ScrollSnapType(StyleScrollSnapTypeValue::$content_type)
+            }
+            CssPropertyType::ScrollSnapAlign => {
+                CssProperty::ScrollSnapAlign(StyleScrollSnapAlignValue::$content_type)
+            }
             CssPropertyType::Opacity => CssProperty::Opacity(StyleOpacityValue::$content_type),
             CssPropertyType::Transform => {
                 CssProperty::Transform(StyleTransformVecValue::$content_type)
@@ -1596,6 +1620,9 @@ impl CssProperty {
             BoxShadowTop(c) => c.is_initial(),
             BoxShadowBottom(c) => c.is_initial(),
             ScrollbarStyle(c) => c.is_initial(),
+            ScrollBehavior(c) => c.is_initial(),
+            ScrollSnapType(c) => c.is_initial(),
+            ScrollSnapAlign(c) => c.is_initial(),
             Opacity(c) => c.is_initial(),
             Transform(c) => c.is_initial(),
             TransformOrigin(c) => c.is_initial(),
@@ -1828,6 +1855,15 @@ impl CssProperty {
     pub const fn const_backface_visiblity(input: StyleBackfaceVisibility) -> Self {
         CssProperty::BackfaceVisibility(StyleBackfaceVisibilityValue::Exact(input))
     }
+    pub const fn const_scroll_behavior(input: StyleScrollBehavior) -> Self {
+        CssProperty::ScrollBehavior(StyleScrollBehaviorValue::Exact(input))
+    }
+    pub const fn const_scroll_snap_type(input: StyleScrollSnapType) -> Self {
+        CssProperty::ScrollSnapType(StyleScrollSnapTypeValue::Exact(input))
+    }
+    pub const fn const_scroll_snap_align(input: StyleScrollSnapAlign) -> Self {
+        CssProperty::ScrollSnapAlign(StyleScrollSnapAlignValue::Exact(input))
+    }
 }
 #[derive(Debug, Copy, Clone, PartialEq)]
 #[repr(C, u8)]
@@ -2355,6 +2391,9 @@ impl CssProperty {
             CssProperty::BoxShadowTop(v) => v.get_css_value_fmt(),
             CssProperty::BoxShadowBottom(v) => v.get_css_value_fmt(),
             CssProperty::ScrollbarStyle(v) => v.get_css_value_fmt(),
+            CssProperty::ScrollBehavior(v) => v.get_css_value_fmt(),
+            CssProperty::ScrollSnapType(v) => v.get_css_value_fmt(),
+            CssProperty::ScrollSnapAlign(v) => v.get_css_value_fmt(),
             CssProperty::Opacity(v) => v.get_css_value_fmt(),
             CssProperty::Transform(v) => v.get_css_value_fmt(),
             CssProperty::TransformOrigin(v) => v.get_css_value_fmt(),
@@ -2788,6 +2827,15 @@ macro_rules! css_property_from_type {
             CssPropertyType::ScrollbarStyle => {
                 CssProperty::ScrollbarStyle(CssPropertyValue::$content_type)
             }
+            CssPropertyType::ScrollBehavior => {
+                CssProperty::ScrollBehavior(CssPropertyValue::$content_type)
+            }
+            CssPropertyType::ScrollSnapType => {
+                CssProperty::ScrollSnapType(CssPropertyValue::$content_type)
+            }
+            CssPropertyType::ScrollSnapAlign => {
+                CssProperty::ScrollSnapAlign(CssPropertyValue::$content_type)
+            }
             CssPropertyType::Opacity => CssProperty::Opacity(CssPropertyValue::$content_type),
             CssPropertyType::Transform => CssProperty::Transform(CssPropertyValue::$content_type),
             CssPropertyType::PerspectiveOrigin => {
@@ -2880,6 +2928,9 @@ impl CssProperty {
             CssProperty::BoxShadowTop(_) => CssPropertyType::BoxShadowTop,
             CssProperty::BoxShadowBottom(_) => CssPropertyType::BoxShadowBottom,
             CssProperty::ScrollbarStyle(_) => CssPropertyType::ScrollbarStyle,
+            CssProperty::ScrollBehavior(_) => CssPropertyType::ScrollBehavior,
+            CssProperty::ScrollSnapType(_) => CssPropertyType::ScrollSnapType,
+            CssProperty::ScrollSnapAlign(_) => CssPropertyType::ScrollSnapAlign,
             CssProperty::Opacity(_) => CssPropertyType::Opacity,
             CssProperty::Transform(_) => CssPropertyType::Transform,
             CssProperty::PerspectiveOrigin(_) => CssPropertyType::PerspectiveOrigin,
@@ -3114,6 +3165,15 @@ impl CssProperty {
     pub const fn backface_visiblity(input: StyleBackfaceVisibility) -> Self {
         CssProperty::BackfaceVisibility(CssPropertyValue::Exact(input))
     }
+    pub const fn scroll_behavior(input: StyleScrollBehavior) -> Self {
+        CssProperty::ScrollBehavior(CssPropertyValue::Exact(input))
+    }
+    pub const fn scroll_snap_type(input: StyleScrollSnapType) -> Self {
+        CssProperty::ScrollSnapType(CssPropertyValue::Exact(input))
+    }
+    pub const fn scroll_snap_align(input: StyleScrollSnapAlign) -> Self {
+        CssProperty::ScrollSnapAlign(CssPropertyValue::Exact(input))
+    }
 
     // functions that downcast to the concrete CSS type (style)
 
@@ -3323,6 +3383,30 @@ impl CssProperty {
             _ => None,
         }
     }
+    pub const fn as_scroll_behavior(&self) -> Option<&StyleScrollBehaviorValue> {
+        match self {
+            CssProperty::ScrollBehavior(f) => Some(f),
+            _ => None,
+        }
+    }
+    pub const fn as_scroll_snap_type(&self) -> Option<&StyleScrollSnapTypeValue> {
+        match self {
+            CssProperty::ScrollSnapType(f) => Some(f),
+            _ => None,
+        }
+    }
+    pub const fn as_scroll_snap_align(&self) -> Option<&StyleScrollSnapAlignValue> {
+        match self {
+            CssProperty::ScrollSnapAlign(f) => Some(f),
+            _ => None,
+        }
+    }
+    pub const fn as_scrollbar_style(&self) -> Option<&ScrollbarStyleValue> {
+        match self {
+            CssProperty::ScrollbarStyle(f) => Some(f),
+            _ => None,
+        }
+    }
     pub const fn as_mix_blend_mode(&self) -> Option<&StyleMixBlendModeValue> {
         match self {
             CssProperty::MixBlendMode(f) => Some(f),
@@ -3637,6 +3721,9 @@ impl_from_css_prop!(LayoutBorderRightWidth, CssProperty::BorderRightWidth);
 impl_from_css_prop!(LayoutBorderLeftWidth, CssProperty::BorderLeftWidth);
 impl_from_css_prop!(LayoutBorderBottomWidth, CssProperty::BorderBottomWidth);
 impl_from_css_prop!(ScrollbarStyle, CssProperty::ScrollbarStyle);
+impl_from_css_prop!(StyleScrollBehavior, CssProperty::ScrollBehavior);
+impl_from_css_prop!(StyleScrollSnapType, CssProperty::ScrollSnapType);
+impl_from_css_prop!(StyleScrollSnapAlign, CssProperty::ScrollSnapAlign);
 impl_from_css_prop!(StyleOpacity, CssProperty::Opacity);
 impl_from_css_prop!(StyleTransformVec, CssProperty::Transform);
 impl_from_css_prop!(StyleTransformOrigin, CssProperty::TransformOrigin);
@@ -5005,8 +5092,8 @@ impl Default for Shape {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[repr(C)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C, u8)]
 pub enum StyleCursor {
     /// `alias`
     Alias,
@@ -5068,6 +5155,8 @@ pub enum StyleCursor {
     ZoomIn,
     /// `zoom-out`
     ZoomOut,
+    /// `url("image-id") x y, fallback` - custom image cursor
+    Image(StyleCursorImage),
 }
 
 impl Default for StyleCursor {
@@ -5076,6 +5165,52 @@ impl Default for StyleCursor {
     }
 }
 
+impl StyleCursor {
+    /// Returns the first non-image cursor, following the fallback
+    /// chain of image cursors (`Default` if there is no keyword fallback)
+    pub fn get_keyword_cursor(&self) -> StyleCursor {
+        match self {
+            StyleCursor::Image(i) => i
+                .fallback
+                .iter()
+                .find(|c| !c.is_image())
+                .cloned()
+                .unwrap_or_default(),
+            other => other.clone(),
+        }
+    }
+
+    pub fn is_image(&self) -> bool {
+        match self {
+            StyleCursor::Image(_) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Image cursor, i.e. `cursor: url("brush") 4 4, crosshair`
+///
+/// The image ID refers to an image in the `ImageCache`, the hotspot
+/// is given in pixels relative to the top left corner of the image.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct StyleCursorImage {
+    pub image: AzString,
+    pub hotspot_x: u32,
+    pub hotspot_y: u32,
+    /// Remaining cursors of the comma-separated list, in order
+    pub fallback: StyleCursorVec,
+}
+
+impl_vec!(StyleCursor, StyleCursorVec, StyleCursorVecDestructor);
+impl_vec_debug!(StyleCursor, StyleCursorVec);
+impl_vec_partialord!(StyleCursor, StyleCursorVec);
+impl_vec_ord!(StyleCursor, StyleCursorVec);
+impl_vec_clone!(StyleCursor, StyleCursorVec, StyleCursorVecDestructor);
+impl_vec_partialeq!(StyleCursor, StyleCursorVec);
+impl_vec_eq!(StyleCursor, StyleCursorVec);
+impl_vec_hash!(StyleCursor, StyleCursorVec);
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
 pub enum DirectionCorner {
@@ -5976,6 +6111,9 @@ pub type StyleBackfaceVisibilityValue = CssPropertyValue<StyleBackfaceVisibility
 pub type StyleMixBlendModeValue = CssPropertyValue<StyleMixBlendMode>;
 pub type StyleFilterVecValue = CssPropertyValue<StyleFilterVec>;
 pub type ScrollbarStyleValue = CssPropertyValue<ScrollbarStyle>;
+pub type StyleScrollBehaviorValue = CssPropertyValue<StyleScrollBehavior>;
+pub type StyleScrollSnapTypeValue = CssPropertyValue<StyleScrollSnapType>;
+pub type StyleScrollSnapAlignValue = CssPropertyValue<StyleScrollSnapAlign>;
 pub type LayoutDisplayValue = CssPropertyValue<LayoutDisplay>;
 impl_option!(
     LayoutDisplayValue,
@@ -6256,6 +6394,93 @@ pub struct ScrollbarStyle {
     pub vertical: ScrollbarInfo,
 }
 
+/// Represents a `scroll-behavior` attribute: whether programmatic
+/// scrolling (such as `CallbackInfo::scroll_to`) is animated
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum StyleScrollBehavior {
+    Auto,
+    Smooth,
+}
+
+impl Default for StyleScrollBehavior {
+    fn default() -> Self {
+        StyleScrollBehavior::Auto
+    }
+}
+
+/// Represents a `scroll-snap-type` attribute, for example `y mandatory`
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct StyleScrollSnapType {
+    pub axis: ScrollSnapAxis,
+    pub strictness: ScrollSnapStrictness,
+}
+
+/// Axis on which a scroll container snaps to its children
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum ScrollSnapAxis {
+    None,
+    X,
+    Y,
+    Both,
+}
+
+impl Default for ScrollSnapAxis {
+    fn default() -> Self {
+        ScrollSnapAxis::None
+    }
+}
+
+impl ScrollSnapAxis {
+    pub fn snaps_x(&self) -> bool {
+        match self {
+            ScrollSnapAxis::X | ScrollSnapAxis::Both => true,
+            _ => false,
+        }
+    }
+
+    pub fn snaps_y(&self) -> bool {
+        match self {
+            ScrollSnapAxis::Y | ScrollSnapAxis::Both => true,
+            _ => false,
+        }
+    }
+}
+
+/// `mandatory` always snaps to the nearest snap position,
+/// `proximity` only snaps if the scroll position comes close to one
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum ScrollSnapStrictness {
+    Proximity,
+    Mandatory,
+}
+
+impl Default for ScrollSnapStrictness {
+    fn default() -> Self {
+        ScrollSnapStrictness::Proximity
+    }
+}
+
+/// Represents a `scroll-snap-align` attribute: which edge of the node
+/// is aligned with the scroll container when snapping
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum StyleScrollSnapAlign {
+    None,
+    Start,
+    Center,
+    End,
+}
+
+impl Default for StyleScrollSnapAlign {
+    fn default() -> Self {
+        StyleScrollSnapAlign::None
+    }
+}
+
 /// Represents a `font-size` attribute
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
@@ -143,6 +143,19 @@ impl PrintAsCssValue for StyleCursor {
             StyleCursor::Wait => "wait",
             StyleCursor::ZoomIn => "zoom-in",
             StyleCursor::ZoomOut => "zoom-out",
+            StyleCursor::Image(i) => {
+                let mut s = format!(
+                    "url(\"{}\") {} {}",
+                    i.image.as_str(),
+                    i.hotspot_x,
+                    i.hotspot_y
+                );
+                for f in i.fallback.iter() {
+                    s.push_str(", ");
+                    s.push_str(&f.print_as_css_value());
+                }
+                return s;
+            }
         })
     }
 }
@@ -586,6 +599,41 @@ impl PrintAsCssValue for StyleBackfaceVisibility {
     }
 }
 
+impl PrintAsCssValue for StyleScrollBehavior {
+    fn print_as_css_value(&self) -> String {
+        String::from(match self {
+            StyleScrollBehavior::Auto => "auto",
+            StyleScrollBehavior::Smooth => "smooth",
+        })
+    }
+}
+
+impl PrintAsCssValue for StyleScrollSnapType {
+    fn print_as_css_value(&self) -> String {
+        let axis = match self.axis {
+            ScrollSnapAxis::None => return String::from("none"),
+            ScrollSnapAxis::X => "x",
+            ScrollSnapAxis::Y => "y",
+            ScrollSnapAxis::Both => "both",
+        };
+        match self.strictness {
+            ScrollSnapStrictness::Proximity => format!("{} proximity", axis),
+            ScrollSnapStrictness::Mandatory => format!("{} mandatory", axis),
+        }
+    }
+}
+
+impl PrintAsCssValue for StyleScrollSnapAlign {
+    fn print_as_css_value(&self) -> String {
+        String::from(match self {
+            StyleScrollSnapAlign::None => "none",
+            StyleScrollSnapAlign::Start => "start",
+            StyleScrollSnapAlign::Center => "center",
+            StyleScrollSnapAlign::End => "end",
+        })
+    }
+}
+
 // extra ---
 
 impl PrintAsCssValue for StyleTransform {
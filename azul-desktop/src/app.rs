@@ -1,13 +1,17 @@
 use alloc::sync::Arc;
 use azul_core::{
-    app_resources::{AppConfig, ImageCache, ImageRef},
+    app_resources::{AppConfig, ImageCache, ImageRef, RawImage},
     callbacks::{RefAny, Update},
     display_list::RenderCallbacks,
     task::{Timer, TimerId},
-    window::{MonitorVec, WindowCreateOptions},
+    window::{
+        MonitorVec, ResultShortcutIdShortcutError, Shortcut, ShortcutBindingVec, ShortcutError,
+        ShortcutId, ShortcutRegistry, WindowCreateOptions,
+    },
 };
-use azul_css::AzString;
-use clipboard2::{Clipboard as _, ClipboardError, SystemClipboard};
+use azul_css::{AzString, U8Vec};
+#[cfg(not(target_os = "linux"))]
+use clipboard2::{Clipboard as _, SystemClipboard};
 use rust_fontconfig::FcFontCache;
 use std::fmt;
 use std::sync::Mutex;
@@ -60,6 +64,25 @@ impl AzAppPtr {
             .unwrap_or(MonitorVec::from_const_slice(&[]))
     }
 
+    pub fn register_shortcut(&mut self, shortcut: Shortcut) -> ResultShortcutIdShortcutError {
+        let mut l = self.ptr.lock().unwrap_or_else(|e| e.into_inner());
+        l.register_shortcut(shortcut).into()
+    }
+
+    pub fn unregister_shortcut(&mut self, shortcut_id: ShortcutId) -> bool {
+        self.ptr
+            .lock()
+            .map(|mut l| l.unregister_shortcut(shortcut_id))
+            .unwrap_or(false)
+    }
+
+    pub fn get_shortcuts(&self) -> ShortcutBindingVec {
+        self.ptr
+            .lock()
+            .map(|l| l.get_shortcuts())
+            .unwrap_or(ShortcutBindingVec::from_const_slice(&[]))
+    }
+
     pub fn run(&self, root_window: WindowCreateOptions) {
         if let Ok(mut l) = self.ptr.try_lock() {
             let mut app = App::new(RefAny::new(Dummy { _dummy: 0 }), l.config.clone());
@@ -90,6 +113,9 @@ pub struct App {
     /// Font configuration cache - already start building the font cache
     /// while the app is starting
     pub fc_cache: LazyFcCache,
+    /// Keyboard shortcuts of the application, can be changed from
+    /// callbacks with `CallbackInfo::register_shortcut()`
+    pub shortcuts: ShortcutRegistry,
 }
 
 impl App {
@@ -132,6 +158,7 @@ impl App {
             config: app_config,
             image_cache: ImageCache::new(),
             fc_cache,
+            shortcuts: ShortcutRegistry::new(),
         }
     }
 
@@ -147,6 +174,22 @@ impl App {
         self.windows.push(create_options);
     }
 
+    /// Registers a keyboard shortcut, fails if the keys conflict with
+    /// an already registered shortcut in the same scope
+    pub fn register_shortcut(&mut self, shortcut: Shortcut) -> Result<ShortcutId, ShortcutError> {
+        self.shortcuts.register(shortcut)
+    }
+
+    /// Removes a keyboard shortcut, returns whether it was registered
+    pub fn unregister_shortcut(&mut self, shortcut_id: ShortcutId) -> bool {
+        self.shortcuts.unregister(shortcut_id)
+    }
+
+    /// Returns the registered keyboard shortcuts (i.e. for a keyboard shortcut help screen)
+    pub fn get_shortcuts(&self) -> ShortcutBindingVec {
+        self.shortcuts.get_bindings()
+    }
+
     /// Returns a list of monitors available on the system
     pub fn get_monitors(&self) -> MonitorVec {
         #[cfg(target_os = "windows")] {
@@ -172,7 +215,11 @@ impl App {
         let err = crate::shell::win32::run(self, root_window);
 
         #[cfg(target_os = "linux")]
-        let err = crate::shell::x11::run(self, root_window);
+        let err = if crate::shell::wayland::is_wayland_session() {
+            crate::shell::wayland::run(self, root_window)
+        } else {
+            crate::shell::x11::run(self, root_window)
+        };
 
         #[cfg(target_os = "macos")]
         let err = crate::shell::appkit::run(self, root_window);
@@ -229,12 +276,156 @@ impl LazyFcCache {
     }
 }
 
+/// Which clipboard to read from / write to
+///
+/// `Primary` is the X11 "middle mouse button" selection,
+/// on all other platforms it is the same as `Clipboard`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub enum ClipboardSelection {
+    Clipboard,
+    Primary,
+}
+
+/// MIME type of UTF-8 text on the clipboard
+pub const CLIPBOARD_TEXT_MIME_TYPE: &str = "text/plain;charset=utf-8";
+/// MIME type of images on the clipboard (images are always copied as PNG)
+pub const CLIPBOARD_IMAGE_MIME_TYPE: &str = "image/png";
+
+// formats to try when reading text, in order of preference
+const CLIPBOARD_TEXT_TARGETS: &[&str] = &[
+    "UTF8_STRING",
+    CLIPBOARD_TEXT_MIME_TYPE,
+    "text/plain",
+    "STRING",
+];
+
+// formats to try when reading images, in order of preference
+const CLIPBOARD_IMAGE_TARGETS: &[&str] = &[
+    CLIPBOARD_IMAGE_MIME_TYPE,
+    "image/bmp",
+    "image/jpeg",
+    "image/gif",
+    "image/tiff",
+];
+
+/// One representation of the copied data, i.e. `"text/html"` + the HTML source
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClipboardItem {
+    pub mime_type: AzString,
+    pub data: U8Vec,
+}
+
+/// Contents of one copy operation: the same data in multiple formats, so that
+/// the pasting application can pick the format it understands best
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ClipboardContents {
+    pub items: Vec<ClipboardItem>,
+}
+
+impl ClipboardContents {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a representation of the data, replaces the existing item of the same MIME type
+    pub fn with_item(mut self, mime_type: &str, data: Vec<u8>) -> Self {
+        self.items.retain(|i| i.mime_type.as_str() != mime_type);
+        self.items.push(ClipboardItem {
+            mime_type: mime_type.to_string().into(),
+            data: data.into(),
+        });
+        self
+    }
+
+    pub fn with_text(self, text: &str) -> Self {
+        self.with_item(CLIPBOARD_TEXT_MIME_TYPE, text.as_bytes().to_vec())
+    }
+
+    pub fn with_html(self, html: &str) -> Self {
+        self.with_item("text/html", html.as_bytes().to_vec())
+    }
+
+    /// Adds the image as `image/png`, returns `None` if the image can't be encoded
+    #[cfg(feature = "image_loading")]
+    pub fn with_image(self, image: &RawImage) -> Option<Self> {
+        let png = azulc_lib::image::encode::encode_png(image)
+            .into_result()
+            .ok()?;
+        Some(self.with_item(CLIPBOARD_IMAGE_MIME_TYPE, png.into_library_owned_vec()))
+    }
+
+    pub fn get(&self, mime_type: &str) -> Option<&[u8]> {
+        self.items
+            .iter()
+            .find(|i| i.mime_type.as_str() == mime_type)
+            .map(|i| i.data.as_slice())
+    }
+
+    // (MIME type, data) pairs, as passed to the native clipboard
+    fn into_raw_items(self) -> Vec<(String, Vec<u8>)> {
+        self.items
+            .into_iter()
+            .map(|i| {
+                (
+                    i.mime_type.into_library_owned_string(),
+                    i.data.into_library_owned_vec(),
+                )
+            })
+            .collect()
+    }
+}
+
+// Returns the formats to request from the native clipboard for the MIME type,
+// in order of preference: text can be read from any of the text targets
+fn clipboard_read_formats(mime_type: &str) -> Vec<&str> {
+    if CLIPBOARD_TEXT_TARGETS.contains(&mime_type) || mime_type == "text/plain" {
+        CLIPBOARD_TEXT_TARGETS.to_vec()
+    } else {
+        vec![mime_type]
+    }
+}
+
+// Returns the index of the first item that is available in one of the formats,
+// formats are tried in order of preference
+fn select_clipboard_item(items: &[(String, Vec<u8>)], formats: &[&str]) -> Option<usize> {
+    formats
+        .iter()
+        .find_map(|f| items.iter().position(|(mime_type, _)| mime_type == f))
+}
+
+// Removes the X11 protocol targets (which are not formats of the data) and
+// adds the text MIME type if the data is available as any kind of text
+fn filter_clipboard_mime_types(mut targets: Vec<String>) -> Vec<String> {
+    const IGNORED_TARGETS: &[&str] = &["TARGETS", "MULTIPLE", "TIMESTAMP", "SAVE_TARGETS"];
+
+    targets.retain(|t| !IGNORED_TARGETS.contains(&t.as_str()));
+
+    let has_text = targets
+        .iter()
+        .any(|t| CLIPBOARD_TEXT_TARGETS.contains(&t.as_str()));
+    if has_text && !targets.iter().any(|t| t == CLIPBOARD_TEXT_MIME_TYPE) {
+        targets.push(CLIPBOARD_TEXT_MIME_TYPE.to_string());
+    }
+
+    targets
+}
+
+#[cfg(target_os = "linux")]
+type NativeClipboard = crate::shell::x11::clipboard::X11Clipboard;
+#[cfg(not(target_os = "linux"))]
+type NativeClipboard = SystemClipboard;
+
 /// Clipboard is an empty class with only static methods,
 /// which is why it doesn't have any #[derive] markers.
+///
+/// On Linux, the clipboard supports arbitrary MIME types, images and the
+/// `PRIMARY` selection (via X11 / XWayland), on all other platforms only
+/// text can be copied (other formats are ignored).
 #[repr(C)]
 #[derive(Clone)]
 pub struct Clipboard {
-    pub _native: Box<Arc<Mutex<SystemClipboard>>>,
+    pub _native: Box<Arc<Mutex<NativeClipboard>>>,
     pub run_destructor: bool,
 }
 
@@ -248,7 +439,11 @@ impl_option!(Clipboard, OptionClipboard, copy = false, [Clone, Debug]);
 
 impl Clipboard {
     pub fn new() -> Option<Self> {
+        #[cfg(target_os = "linux")]
+        let clipboard = NativeClipboard::new()?;
+        #[cfg(not(target_os = "linux"))]
         let clipboard = SystemClipboard::new().ok()?;
+
         Some(Self {
             _native: Box::new(Arc::new(Mutex::new(clipboard))),
             run_destructor: true,
@@ -257,21 +452,137 @@ impl Clipboard {
 
     /// Returns the contents of the system clipboard
     pub fn get_clipboard_string(&self) -> Option<AzString> {
-        self._native
-            .lock()
-            .ok()?
-            .get_string_contents()
-            .map(|o| o.into())
-            .ok()
+        self.get_selection_string(ClipboardSelection::Clipboard)
     }
 
     /// Sets the contents of the system clipboard
     pub fn set_clipboard_string(&mut self, contents: AzString) -> Option<()> {
-        Arc::get_mut(&mut *self._native)?
-            .get_mut()
-            .ok()?
-            .set_string_contents(contents.into_library_owned_string())
-            .ok()?;
+        self.set_selection_string(ClipboardSelection::Clipboard, contents)
+    }
+
+    pub fn get_selection_string(&self, selection: ClipboardSelection) -> Option<AzString> {
+        let (_, data) = self.get_raw(selection, CLIPBOARD_TEXT_TARGETS)?;
+        Some(String::from_utf8_lossy(&data).into_owned().into())
+    }
+
+    pub fn set_selection_string(
+        &mut self,
+        selection: ClipboardSelection,
+        contents: AzString,
+    ) -> Option<()> {
+        self.set_selection_contents(
+            selection,
+            ClipboardContents::new().with_text(contents.as_str()),
+        )
+    }
+
+    /// Returns the MIME types the current clipboard contents are available in
+    pub fn get_mime_types(&self) -> Vec<AzString> {
+        self.get_selection_mime_types(ClipboardSelection::Clipboard)
+    }
+
+    pub fn get_selection_mime_types(&self, selection: ClipboardSelection) -> Vec<AzString> {
+        #[cfg(target_os = "linux")]
+        {
+            let targets = match self._native.lock() {
+                Ok(o) => o.get_targets(selection).unwrap_or_default(),
+                Err(_) => return Vec::new(),
+            };
+
+            filter_clipboard_mime_types(targets)
+                .into_iter()
+                .map(|t| t.into())
+                .collect()
+        }
+
+        #[cfg(not(target_os = "linux"))]
+        {
+            match self.get_selection_string(selection) {
+                Some(_) => vec![CLIPBOARD_TEXT_MIME_TYPE.into()],
+                None => Vec::new(),
+            }
+        }
+    }
+
+    /// Returns the clipboard contents in the given MIME type or `None` if
+    /// the clipboard is empty or the contents are not available in that format
+    pub fn get_contents(&self, mime_type: &str) -> Option<U8Vec> {
+        self.get_selection_contents(ClipboardSelection::Clipboard, mime_type)
+    }
+
+    pub fn get_selection_contents(
+        &self,
+        selection: ClipboardSelection,
+        mime_type: &str,
+    ) -> Option<U8Vec> {
+        let (_, data) = self.get_raw(selection, &clipboard_read_formats(mime_type))?;
+        Some(data.into())
+    }
+
+    /// Replaces the clipboard contents with all representations in `contents`
+    pub fn set_contents(&mut self, contents: ClipboardContents) -> Option<()> {
+        self.set_selection_contents(ClipboardSelection::Clipboard, contents)
+    }
+
+    pub fn set_selection_contents(
+        &mut self,
+        selection: ClipboardSelection,
+        contents: ClipboardContents,
+    ) -> Option<()> {
+        self.set_raw(selection, contents.into_raw_items())
+    }
+
+    /// Returns the image on the clipboard, decoded from any supported format
+    #[cfg(feature = "image_loading")]
+    pub fn get_image(&self) -> Option<RawImage> {
+        let (_, data) = self.get_raw(ClipboardSelection::Clipboard, CLIPBOARD_IMAGE_TARGETS)?;
+        azulc_lib::image::decode::decode_raw_image_from_any_bytes(&data)
+            .into_result()
+            .ok()
+    }
+
+    /// Copies the image to the clipboard (as `image/png`)
+    #[cfg(feature = "image_loading")]
+    pub fn set_image(&mut self, image: &RawImage) -> Option<()> {
+        let contents = ClipboardContents::new().with_image(image)?;
+        self.set_contents(contents)
+    }
+
+    // Returns the first of the formats the clipboard contents are available in
+    #[cfg(target_os = "linux")]
+    fn get_raw(
+        &self,
+        selection: ClipboardSelection,
+        formats: &[&str],
+    ) -> Option<(String, Vec<u8>)> {
+        self._native.lock().ok()?.get_contents(selection, formats)
+    }
+
+    #[cfg(target_os = "linux")]
+    fn set_raw(
+        &mut self,
+        selection: ClipboardSelection,
+        items: Vec<(String, Vec<u8>)>,
+    ) -> Option<()> {
+        self._native.lock().ok()?.set_contents(selection, items)
+    }
+
+    // clipboard2 only supports text, the PRIMARY selection doesn't exist
+    #[cfg(not(target_os = "linux"))]
+    fn get_raw(&self, _: ClipboardSelection, formats: &[&str]) -> Option<(String, Vec<u8>)> {
+        let format = formats
+            .iter()
+            .find(|f| CLIPBOARD_TEXT_TARGETS.contains(f))?;
+        let text = self._native.lock().ok()?.get_string_contents().ok()?;
+        Some((format.to_string(), text.into_bytes()))
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn set_raw(&mut self, _: ClipboardSelection, mut items: Vec<(String, Vec<u8>)>) -> Option<()> {
+        let index = select_clipboard_item(&items, CLIPBOARD_TEXT_TARGETS)?;
+        let (_, text) = items.swap_remove(index);
+        let text = String::from_utf8(text).ok()?;
+        self._native.lock().ok()?.set_string_contents(text).ok()?;
         Some(())
     }
 }
@@ -282,6 +593,35 @@ impl Drop for Clipboard {
     }
 }
 
+/// Error of the platform clipboard, the same type on every platform
+/// (on Linux the clipboard is implemented in the X11 shell, not by clipboard2)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClipboardError {
+    /// No clipboard is available, i.e. because no X11 display could be opened
+    Unavailable,
+    /// The clipboard contents are not valid UTF-8 text
+    InvalidUtf8,
+    /// Error reported by the platform backend
+    Backend(AzString),
+}
+
+impl fmt::Display for ClipboardError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ClipboardError::Unavailable => write!(f, "no clipboard available"),
+            ClipboardError::InvalidUtf8 => write!(f, "clipboard contents are not valid UTF-8"),
+            ClipboardError::Backend(e) => write!(f, "clipboard error: {}", e.as_str()),
+        }
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+impl From<clipboard2::ClipboardError> for ClipboardError {
+    fn from(e: clipboard2::ClipboardError) -> Self {
+        ClipboardError::Backend(format!("{:?}", e).into())
+    }
+}
+
 pub mod extra {
 
     use azul_core::dom::{Dom, NodeType};
@@ -320,3 +660,52 @@ pub mod extra {
         azulc_lib::xml::domxml_from_str(s, &mut XmlComponentMap::default()).parsed_dom
     }
 }
+
+#[test]
+fn test_clipboard_contents_round_trip() {
+    let contents = ClipboardContents::new()
+        .with_text("old")
+        .with_html("<b>bold</b>")
+        .with_text("new");
+
+    // the second text item replaces the first one
+    assert_eq!(contents.items.len(), 2);
+    assert_eq!(contents.get(CLIPBOARD_TEXT_MIME_TYPE), Some(&b"new"[..]));
+    assert_eq!(contents.get("text/html"), Some(&b"<b>bold</b>"[..]));
+    assert_eq!(contents.get("image/png"), None);
+
+    let items = contents.into_raw_items();
+    let text = select_clipboard_item(&items, &clipboard_read_formats("text/plain")).unwrap();
+    assert_eq!(items[text], (CLIPBOARD_TEXT_MIME_TYPE.to_string(), b"new".to_vec()));
+    let html = select_clipboard_item(&items, &clipboard_read_formats("text/html")).unwrap();
+    assert_eq!(items[html].1, b"<b>bold</b>".to_vec());
+    assert_eq!(select_clipboard_item(&items, &clipboard_read_formats("image/png")), None);
+}
+
+#[test]
+fn test_clipboard_format_selection() {
+    // text is read from any text target, other types only from themselves
+    assert_eq!(clipboard_read_formats("text/plain"), CLIPBOARD_TEXT_TARGETS.to_vec());
+    assert_eq!(clipboard_read_formats("STRING"), CLIPBOARD_TEXT_TARGETS.to_vec());
+    assert_eq!(clipboard_read_formats("image/png"), vec!["image/png"]);
+
+    // the formats are tried in order of preference, not in order of the items
+    let items = vec![
+        ("STRING".to_string(), b"latin1".to_vec()),
+        ("UTF8_STRING".to_string(), b"utf8".to_vec()),
+    ];
+    assert_eq!(select_clipboard_item(&items, CLIPBOARD_TEXT_TARGETS), Some(1));
+    assert_eq!(select_clipboard_item(&items, CLIPBOARD_IMAGE_TARGETS), None);
+
+    let targets = vec!["TARGETS", "TIMESTAMP", "UTF8_STRING", "text/html", "MULTIPLE"]
+        .into_iter()
+        .map(|t| t.to_string())
+        .collect();
+    assert_eq!(
+        filter_clipboard_mime_types(targets),
+        vec!["UTF8_STRING", "text/html", CLIPBOARD_TEXT_MIME_TYPE]
+    );
+
+    let targets = vec!["image/png".to_string()];
+    assert_eq!(filter_clipboard_mime_types(targets), vec!["image/png"]);
+}
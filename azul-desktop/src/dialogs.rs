@@ -1,8 +1,13 @@
 #![allow(missing_copy_implementations)]
 
 use core::ffi::c_void;
-use azul_css::{AzString, StringVec, ColorU};
-use azul_core::window::AzStringPair;
+use azul_css::{AzString, StringVec, ColorU, OptionAzString};
+use azul_core::window::{AzStringPair, RawWindowHandle};
+use azul_core::callbacks::{CallbackInfo, RefAny};
+use azul_core::task::{
+    ThreadId, ThreadSender, ThreadReceiver, ThreadSendMsg,
+    ThreadReceiveMsg, ThreadWriteBackMsg, WriteBackCallbackType,
+};
 use tinyfiledialogs::{MessageBoxIcon, DefaultColorValue};
 
 /// Ok or cancel result, returned from the `msg_box_ok_cancel` function
@@ -208,6 +213,29 @@ pub struct FileTypeList {
 }
 
 impl_option!(FileTypeList, OptionFileTypeList, copy = false, [Debug, Clone, PartialEq, PartialOrd]);
+impl_vec!(FileTypeList, FileTypeListVec, FileTypeListVecDestructor);
+impl_vec_clone!(FileTypeList, FileTypeListVec, FileTypeListVecDestructor);
+impl_vec_debug!(FileTypeList, FileTypeListVec);
+impl_vec_partialeq!(FileTypeList, FileTypeListVec);
+impl_vec_partialord!(FileTypeList, FileTypeListVec);
+
+impl FileTypeList {
+    /// Returns the glob patterns of the document types, i.e. `"png"` => `"*.png"`
+    /// (types that already contain a `*` are returned unchanged)
+    pub fn get_patterns(&self) -> Vec<String> {
+        self.document_types
+            .iter()
+            .map(|t| {
+                let t = t.as_str();
+                if t.contains('*') {
+                    t.to_string()
+                } else {
+                    format!("*.{}", t.trim_start_matches('.'))
+                }
+            })
+            .collect()
+    }
+}
 
 /// Open a single file, returns `None` if the user canceled the dialog.
 ///
@@ -257,6 +285,219 @@ pub fn save_file_dialog(title: &str, default_path: Option<&str>)
     ::tinyfiledialogs::save_file_dialog(title, path).map(|s| s.into())
 }
 
+/// Options for the asynchronous file dialogs
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct FileDialogOptions {
+    pub title: AzString,
+    /// File type filters, the user can switch between them in the dialog
+    /// (the first filter is selected by default)
+    pub filters: FileTypeListVec,
+    /// Folder that the dialog starts in
+    pub current_folder: OptionAzString,
+    /// Suggested file name, only used in save dialogs
+    pub default_file_name: OptionAzString,
+}
+
+impl FileDialogOptions {
+    pub fn new(title: AzString) -> Self {
+        Self {
+            title,
+            filters: FileTypeListVec::from_const_slice(&[]),
+            current_folder: None.into(),
+            default_file_name: None.into(),
+        }
+    }
+}
+
+/// Result of an asynchronous dialog, passed as the second `RefAny`
+/// to the `WriteBackCallback` once the dialog is closed
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C, u8)]
+pub enum DialogResult {
+    /// The user closed the dialog without selecting anything
+    Canceled,
+    /// Selected file or directory paths
+    Files(StringVec),
+    OkCancel(OkCancel),
+    YesNo(YesNo),
+    Color(ColorU),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum FileDialogMode {
+    OpenFile,
+    OpenMultipleFiles,
+    OpenDirectory,
+    SaveFile,
+}
+
+#[derive(Debug, Clone)]
+enum DialogRequest {
+    File { mode: FileDialogMode, options: FileDialogOptions },
+    OkCancel { title: String, message: String, icon: MsgBoxIcon, default: OkCancel },
+    YesNo { title: String, message: String, icon: MsgBoxIcon, default: YesNo },
+    Color { title: String, default_value: Option<ColorU> },
+}
+
+// Initialization data of the dialog thread
+#[derive(Debug)]
+struct DialogThreadData {
+    request: DialogRequest,
+    callback: WriteBackCallbackType,
+    parent_window: RawWindowHandle,
+}
+
+/// Opens a single file without blocking the UI thread: once the dialog is closed,
+/// `callback` is invoked with `data` and the `DialogResult` (`Files` or `Canceled`).
+///
+/// Returns the ID of the thread that runs the dialog, removing the thread
+/// (`CallbackInfo::stop_thread`) closes the dialog if the platform supports it.
+pub fn open_file_dialog_async(info: &mut CallbackInfo, options: FileDialogOptions, data: RefAny, callback: WriteBackCallbackType) -> Option<ThreadId> {
+    start_dialog_thread(info, DialogRequest::File { mode: FileDialogMode::OpenFile, options }, data, callback)
+}
+
+/// Asynchronous version of `open_multiple_files_dialog`, see `open_file_dialog_async`
+pub fn open_multiple_files_dialog_async(info: &mut CallbackInfo, options: FileDialogOptions, data: RefAny, callback: WriteBackCallbackType) -> Option<ThreadId> {
+    start_dialog_thread(info, DialogRequest::File { mode: FileDialogMode::OpenMultipleFiles, options }, data, callback)
+}
+
+/// Asynchronous version of `open_directory_dialog`, see `open_file_dialog_async`
+pub fn open_directory_dialog_async(info: &mut CallbackInfo, options: FileDialogOptions, data: RefAny, callback: WriteBackCallbackType) -> Option<ThreadId> {
+    start_dialog_thread(info, DialogRequest::File { mode: FileDialogMode::OpenDirectory, options }, data, callback)
+}
+
+/// Asynchronous version of `save_file_dialog`, see `open_file_dialog_async`
+pub fn save_file_dialog_async(info: &mut CallbackInfo, options: FileDialogOptions, data: RefAny, callback: WriteBackCallbackType) -> Option<ThreadId> {
+    start_dialog_thread(info, DialogRequest::File { mode: FileDialogMode::SaveFile, options }, data, callback)
+}
+
+/// Asynchronous version of `msg_box_ok_cancel`, the result is `DialogResult::OkCancel`
+pub fn msg_box_ok_cancel_async(info: &mut CallbackInfo, title: &str, message: &str, icon: MsgBoxIcon, default: OkCancel, data: RefAny, callback: WriteBackCallbackType) -> Option<ThreadId> {
+    let request = DialogRequest::OkCancel { title: title.to_string(), message: message.to_string(), icon, default };
+    start_dialog_thread(info, request, data, callback)
+}
+
+/// Asynchronous version of `msg_box_yes_no`, the result is `DialogResult::YesNo`
+pub fn msg_box_yes_no_async(info: &mut CallbackInfo, title: &str, message: &str, icon: MsgBoxIcon, default: YesNo, data: RefAny, callback: WriteBackCallbackType) -> Option<ThreadId> {
+    let request = DialogRequest::YesNo { title: title.to_string(), message: message.to_string(), icon, default };
+    start_dialog_thread(info, request, data, callback)
+}
+
+/// Asynchronous version of `color_picker_dialog`, the result is `DialogResult::Color` or `Canceled`
+pub fn color_picker_dialog_async(info: &mut CallbackInfo, title: &str, default_value: Option<ColorU>, data: RefAny, callback: WriteBackCallbackType) -> Option<ThreadId> {
+    let request = DialogRequest::Color { title: title.to_string(), default_value };
+    start_dialog_thread(info, request, data, callback)
+}
+
+fn start_dialog_thread(info: &mut CallbackInfo, request: DialogRequest, data: RefAny, callback: WriteBackCallbackType) -> Option<ThreadId> {
+    let parent_window = info.get_current_window_handle();
+    let thread_data = RefAny::new(DialogThreadData { request, callback, parent_window });
+    info.start_thread(thread_data, data, dialog_thread)
+}
+
+extern "C" fn dialog_thread(mut thread_data: RefAny, mut sender: ThreadSender, mut receiver: ThreadReceiver) {
+    let (request, callback, parent_window) = match thread_data.downcast_ref::<DialogThreadData>() {
+        Some(s) => (s.request.clone(), s.callback, s.parent_window),
+        None => return,
+    };
+
+    let mut is_terminated = || loop {
+        match receiver.recv().into_option() {
+            Some(ThreadSendMsg::TerminateThread) => return true,
+            Some(_) => continue,
+            None => return false,
+        }
+    };
+
+    let result = match request {
+        DialogRequest::File { mode, options } => {
+            let files = match native_file_dialog(mode, &options, &parent_window, &mut is_terminated) {
+                Ok(o) => o,
+                Err(_) => tinyfiledialogs_file_dialog(mode, &options),
+            };
+            match files {
+                Some(f) => DialogResult::Files(f.into_iter().map(AzString::from).collect::<Vec<_>>().into()),
+                None => DialogResult::Canceled,
+            }
+        },
+        DialogRequest::OkCancel { title, message, icon, default } => {
+            DialogResult::OkCancel(msg_box_ok_cancel(&title, &message, icon.into(), default))
+        },
+        DialogRequest::YesNo { title, message, icon, default } => {
+            DialogResult::YesNo(msg_box_yes_no(&title, &message, icon.into(), default))
+        },
+        DialogRequest::Color { title, default_value } => {
+            match color_picker_dialog(&title, default_value) {
+                Some(c) => DialogResult::Color(c),
+                None => DialogResult::Canceled,
+            }
+        },
+    };
+
+    // the thread was stopped while the dialog was open
+    if is_terminated() {
+        return;
+    }
+
+    sender.send(ThreadReceiveMsg::WriteBack(ThreadWriteBackMsg::new(callback, RefAny::new(result))));
+}
+
+// Shows the file dialog of the XDG desktop portal, returns an error
+// if the portal is not available (so that tinyfiledialogs is used instead)
+#[cfg(target_os = "linux")]
+fn native_file_dialog(
+    mode: FileDialogMode,
+    options: &FileDialogOptions,
+    parent_window: &RawWindowHandle,
+    is_terminated: &mut dyn FnMut() -> bool,
+) -> Result<Option<Vec<String>>, String> {
+    let parent_window = match parent_window {
+        RawWindowHandle::Xlib(h) => format!("x11:{:x}", h.window),
+        _ => String::new(),
+    };
+    crate::shell::x11::portal::show_file_chooser(mode, options, &parent_window, is_terminated)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn native_file_dialog(
+    _mode: FileDialogMode,
+    _options: &FileDialogOptions,
+    _parent_window: &RawWindowHandle,
+    _is_terminated: &mut dyn FnMut() -> bool,
+) -> Result<Option<Vec<String>>, String> {
+    Err(String::from("no native asynchronous file dialog on this platform"))
+}
+
+fn tinyfiledialogs_file_dialog(mode: FileDialogMode, options: &FileDialogOptions) -> Option<Vec<String>> {
+    use std::path::{Path, MAIN_SEPARATOR};
+
+    // tinyfiledialogs only supports a single filter, so all filters are merged
+    let patterns = options.filters.iter().flat_map(|f| f.get_patterns()).collect::<Vec<_>>();
+    let patterns = patterns.iter().map(|p| p.as_str()).collect::<Vec<_>>();
+    let descriptor = options.filters.iter().map(|f| f.document_descriptor.as_str()).collect::<Vec<_>>().join(", ");
+    let filter = if patterns.is_empty() { None } else { Some((patterns.as_slice(), descriptor.as_str())) };
+
+    // the default path is the folder (with a trailing separator) or the default file
+    let folder = options.current_folder.as_ref().map(|f| f.as_str().to_string()).unwrap_or_default();
+    let path = match options.default_file_name.as_ref() {
+        Some(name) if mode == FileDialogMode::SaveFile => Path::new(&folder).join(name.as_str()).to_string_lossy().into_owned(),
+        _ if !folder.is_empty() && !folder.ends_with(MAIN_SEPARATOR) => format!("{}{}", folder, MAIN_SEPARATOR),
+        _ => folder,
+    };
+
+    let title = options.title.as_str();
+    match mode {
+        FileDialogMode::OpenFile => ::tinyfiledialogs::open_file_dialog(title, &path, filter).map(|f| vec![f]),
+        FileDialogMode::OpenMultipleFiles => ::tinyfiledialogs::open_file_dialog_multi(title, &path, filter),
+        FileDialogMode::OpenDirectory => ::tinyfiledialogs::select_folder_dialog(title, &path).map(|f| vec![f]),
+        FileDialogMode::SaveFile => match filter {
+            Some((patterns, descriptor)) => ::tinyfiledialogs::save_file_dialog_with_filter(title, &path, patterns, descriptor),
+            None => ::tinyfiledialogs::save_file_dialog(title, &path),
+        },
+    }
+}
+
 // TODO (at least on Windows):
 // - Find and replace dialog
 // - Font picker dialog
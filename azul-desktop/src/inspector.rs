@@ -0,0 +1,472 @@
+//! Built-in DOM / layout inspector window
+//!
+//! Opened by pressing F12 in a window if `AppConfig::enable_inspector` is set.
+//! The inspector shows a snapshot of the root DOM of the inspected window
+//! (updated every time the inspected window regenerates its DOM), the computed
+//! CSS properties including where they were set, the solved layout rectangle
+//! and the callbacks of the selected node. Hovering a node in the tree highlights
+//! the node in the inspected window (see `WindowInternal::inspector_highlight`).
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use azul_core::{
+    callbacks::{
+        Callback, CallbackInfo, CallbackType, LayoutCallback, LayoutCallbackInfo,
+        MarshaledLayoutCallback, MarshaledLayoutCallbackInner, RefAny, Update,
+    },
+    dom::{
+        CallbackData, Dom, EventFilter, HoverEventFilter, IdOrClass,
+        NodeDataInlineCssProperty, NodeType,
+    },
+    id_tree::NodeId,
+    styled_dom::{CssPropertySource, StyledDom},
+    ui_solver::{LayoutResult, ResolvedOffsets},
+    window::{LogicalSize, WindowCreateOptions},
+};
+use azul_css::CssPropertyType;
+use azul_css_parser::CssApiWrapper;
+use alloc::collections::BTreeSet;
+
+const INSPECTOR_CSS: &str = "
+    body { flex-direction: row; font-family: monospace; font-size: 12px; background: #ffffff; color: #222222; }
+    .tree { width: 40%; overflow-y: auto; border-right: 1px solid #cccccc; padding: 4px 0px; }
+    .row { padding: 1px 4px; }
+    .row:hover { background: #e8f0fe; }
+    .row.selected { background: #cfe1fc; }
+    .details { flex-grow: 1; overflow-y: auto; padding: 6px; }
+    .heading { font-size: 13px; color: #881280; padding-bottom: 6px; }
+    .section { padding: 6px 0px 2px 0px; color: #555555; border-bottom: 1px solid #dddddd; margin-bottom: 4px; }
+    .property { padding: 1px 0px; }
+    .source { color: #888888; padding-left: 8px; }
+    .margin-box { background: #f9cc9d; border: 1px dashed #555555; padding: 4px 12px; }
+    .border-box { background: #fddd9b; border: 1px solid #000000; padding: 4px 12px; }
+    .padding-box { background: #c3d08b; border: 1px dashed #555555; padding: 4px 12px; }
+    .content-box { background: #8cb6c0; border: 1px solid #555555; padding: 4px 12px; }
+    .box-label { font-size: 10px; padding-bottom: 2px; }
+";
+
+/// Data of the inspector window, shared between the inspector window (as the
+/// marshal data of its layout callback) and the window being inspected
+#[derive(Debug)]
+pub(crate) struct InspectorData {
+    /// Platform-specific ID of the inspected window (the HWND on Windows, the X11
+    /// window on X11, the address of the wl_surface on Wayland)
+    pub inspected_window: usize,
+    /// Snapshot of the root DOM of the inspected window
+    pub nodes: Vec<InspectorNode>,
+    /// Node selected in the tree, its details are shown on the right side
+    pub selected: Option<NodeId>,
+    /// Node hovered in the tree, highlighted in the inspected window
+    pub hovered: Option<NodeId>,
+}
+
+#[derive(Debug)]
+pub(crate) struct InspectorNode {
+    pub depth: usize,
+    /// i.e. `div#main.container` or `"Hello"` for text nodes
+    pub label: String,
+    pub properties: Vec<InspectorProperty>,
+    pub rect: Option<InspectorRect>,
+    /// Event filter + address of the callback function
+    pub callbacks: Vec<String>,
+}
+
+#[derive(Debug)]
+pub(crate) struct InspectorProperty {
+    pub key: &'static str,
+    pub value: String,
+    /// `None` if the property is inherited from the parent node
+    pub source: Option<CssPropertySource>,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct InspectorRect {
+    pub x: f32,
+    pub y: f32,
+    pub size: LogicalSize,
+    pub margin: ResolvedOffsets,
+    pub border: ResolvedOffsets,
+    pub padding: ResolvedOffsets,
+}
+
+/// Data of a single row in the tree view
+struct InspectorRow {
+    inspector: RefAny,
+    node_id: NodeId,
+}
+
+/// Creates the inspector window for the window with the given ID
+pub(crate) fn open_inspector(inspected_window: usize, layout_results: &[LayoutResult]) -> WindowCreateOptions {
+    let data = InspectorData {
+        inspected_window,
+        nodes: snapshot_nodes(layout_results),
+        selected: None,
+        hovered: None,
+    };
+
+    let mut options = WindowCreateOptions::default();
+    options.state.title = "Inspector".into();
+    options.state.size.dimensions = LogicalSize::new(900.0, 600.0);
+    options.state.layout_callback = LayoutCallback::Marshaled(MarshaledLayoutCallback {
+        marshal_data: RefAny::new(data),
+        cb: MarshaledLayoutCallbackInner { cb: inspector_layout },
+    });
+    options
+}
+
+/// If the layout callback belongs to an inspector window, returns the ID of the inspected window
+pub(crate) fn get_inspected_window(layout_callback: &mut LayoutCallback) -> Option<usize> {
+    let data = get_inspector_data(layout_callback)?;
+    let inspected_window = data.downcast_ref::<InspectorData>()?.inspected_window;
+    Some(inspected_window)
+}
+
+/// Updates the snapshot of the inspected window, called after the inspected window regenerated its DOM
+pub(crate) fn update_inspector(layout_callback: &mut LayoutCallback, layout_results: &[LayoutResult]) {
+    let data = match get_inspector_data(layout_callback) {
+        Some(s) => s,
+        None => return,
+    };
+    let mut data = match data.downcast_mut::<InspectorData>() {
+        Some(s) => s,
+        None => return,
+    };
+    data.nodes = snapshot_nodes(layout_results);
+    if data.selected.map(|s| s.index() >= data.nodes.len()).unwrap_or(false) {
+        data.selected = None;
+    }
+    if data.hovered.map(|s| s.index() >= data.nodes.len()).unwrap_or(false) {
+        data.hovered = None;
+    }
+}
+
+/// Returns the node that should be highlighted in the inspected window
+pub(crate) fn get_inspector_highlight(layout_callback: &mut LayoutCallback) -> Option<NodeId> {
+    let data = get_inspector_data(layout_callback)?;
+    let hovered = data.downcast_ref::<InspectorData>()?.hovered;
+    hovered
+}
+
+fn get_inspector_data(layout_callback: &mut LayoutCallback) -> Option<&mut RefAny> {
+    match layout_callback {
+        LayoutCallback::Marshaled(m) if m.cb.cb as usize == inspector_layout as usize => {
+            Some(&mut m.marshal_data)
+        }
+        _ => None,
+    }
+}
+
+fn snapshot_nodes(layout_results: &[LayoutResult]) -> Vec<InspectorNode> {
+    let layout_result = match layout_results.first() {
+        Some(s) => s,
+        None => return Vec::new(),
+    };
+
+    let styled_dom = &layout_result.styled_dom;
+    let css_property_cache = styled_dom.get_css_property_cache();
+    let node_hierarchy = styled_dom.node_hierarchy.as_container();
+    let node_data_container = styled_dom.node_data.as_container();
+    let styled_nodes = styled_dom.styled_nodes.as_container();
+    let rects = layout_result.rects.as_ref();
+
+    let mut depths = vec![0; styled_dom.node_data.len()];
+
+    (0..styled_dom.node_data.len())
+        .map(NodeId::new)
+        .map(|node_id| {
+            let depth = match node_hierarchy[node_id].parent_id() {
+                Some(parent) => depths[parent.index()] + 1,
+                None => 0,
+            };
+            depths[node_id.index()] = depth;
+
+            let node_data = &node_data_container[node_id];
+            let node_state = &styled_nodes[node_id].state;
+
+            let label = match node_data.get_node_type() {
+                NodeType::Text(t) => format!("\"{}\"", t.as_str().trim()),
+                other => {
+                    let mut label = other.get_path().to_string();
+                    for id_or_class in node_data.get_ids_and_classes().as_ref().iter() {
+                        if let Some(id) = id_or_class.as_id() {
+                            label.push('#');
+                            label.push_str(id);
+                        }
+                        if let Some(class) = id_or_class.as_class() {
+                            label.push('.');
+                            label.push_str(class);
+                        }
+                    }
+                    label
+                }
+            };
+
+            // all property types that are set on the node in any way
+            let mut property_types = BTreeSet::new();
+            for map in [
+                &css_property_cache.user_overridden_properties,
+                &css_property_cache.css_normal_props,
+                &css_property_cache.css_hover_props,
+                &css_property_cache.css_active_props,
+                &css_property_cache.css_focus_props,
+                &css_property_cache.cascaded_normal_props,
+                &css_property_cache.cascaded_hover_props,
+                &css_property_cache.cascaded_active_props,
+                &css_property_cache.cascaded_focus_props,
+            ]
+            .iter()
+            {
+                if let Some(props) = map.get(&node_id) {
+                    property_types.extend(props.keys().copied());
+                }
+            }
+            for prop in node_data.get_inline_css_props().as_ref().iter() {
+                use self::NodeDataInlineCssProperty::*;
+                match prop {
+                    Normal(p) | Active(p) | Focus(p) | Hover(p) => {
+                        property_types.insert(p.get_type());
+                    }
+                }
+            }
+
+            let properties = property_types
+                .into_iter()
+                .filter_map(|property_type: CssPropertyType| {
+                    let property = css_property_cache.get_property(
+                        node_data,
+                        &node_id,
+                        node_state,
+                        &property_type,
+                    )?;
+                    Some(InspectorProperty {
+                        key: property_type.to_str(),
+                        value: property.value(),
+                        source: css_property_cache.get_property_source(
+                            node_data,
+                            &node_id,
+                            node_state,
+                            &property_type,
+                        ),
+                    })
+                })
+                .collect();
+
+            let rect = rects.get(node_id).map(|r| {
+                let position = r.position.get_static_offset();
+                InspectorRect {
+                    x: position.x,
+                    y: position.y,
+                    size: r.size,
+                    margin: r.margin,
+                    border: r.border_widths,
+                    padding: r.padding,
+                }
+            });
+
+            let callbacks = node_data
+                .get_callbacks()
+                .as_ref()
+                .iter()
+                .map(|c| format!("{:?} -> 0x{:x}", c.event, c.callback.cb as usize))
+                .collect();
+
+            InspectorNode {
+                depth,
+                label,
+                properties,
+                rect,
+                callbacks,
+            }
+        })
+        .collect()
+}
+
+extern "C" fn inspector_layout(
+    marshal_data: &mut RefAny,
+    _: &mut RefAny,
+    _: &mut LayoutCallbackInfo,
+) -> StyledDom {
+    let inspector = marshal_data.clone();
+
+    let data = match marshal_data.downcast_ref::<InspectorData>() {
+        Some(s) => s,
+        None => return StyledDom::default(),
+    };
+
+    let tree = data
+        .nodes
+        .iter()
+        .enumerate()
+        .map(|(node_id, node)| {
+            let node_id = NodeId::new(node_id);
+            let classes: &[&str] = if data.selected == Some(node_id) { &["row", "selected"] } else { &["row"] };
+            let row_data = RefAny::new(InspectorRow {
+                inspector: inspector.clone(),
+                node_id,
+            });
+            div(classes)
+                .with_inline_style(&format!("padding-left: {}px;", 4 + node.depth * 12))
+                .with_callbacks(vec![
+                    callback(HoverEventFilter::MouseEnter, row_data.clone(), on_row_mouse_enter),
+                    callback(HoverEventFilter::MouseUp, row_data, on_row_mouse_up),
+                ].into())
+                .with_children(vec![Dom::text(node.label.as_str())].into())
+        })
+        .collect::<Dom>()
+        .with_ids_and_classes(vec![IdOrClass::Class("tree".into())].into())
+        .with_callbacks(vec![
+            callback(HoverEventFilter::MouseLeave, inspector.clone(), on_tree_mouse_leave),
+        ].into());
+
+    let details = match data.selected.and_then(|s| data.nodes.get(s.index())) {
+        Some(node) => render_details(node),
+        None => vec![text(&[], "Select a node in the tree")],
+    };
+
+    Dom::body()
+        .with_children(vec![tree, div(&["details"]).with_children(details.into())].into())
+        .style(CssApiWrapper::from_string(INSPECTOR_CSS.into()))
+}
+
+fn render_details(node: &InspectorNode) -> Vec<Dom> {
+    let mut children = vec![
+        text(&["heading"], node.label.as_str()),
+        text(&["section"], "Layout"),
+    ];
+
+    match node.rect.as_ref() {
+        Some(rect) => {
+            children.push(text(&["property"], &format!(
+                "{}x{} at ({}, {})",
+                rect.size.width, rect.size.height, rect.x, rect.y
+            )));
+            children.push(render_box_model(rect));
+        }
+        None => children.push(text(&["property"], "not layouted")),
+    }
+
+    children.push(text(&["section"], "Computed style"));
+    for property in node.properties.iter() {
+        let source = match property.source.as_ref() {
+            Some(CssPropertySource::Css(path)) => format!("/* {} */", path),
+            Some(CssPropertySource::Inline) => String::from("/* inline */"),
+            None => String::from("/* inherited */"),
+        };
+        children.push(
+            div(&["property"])
+                .with_inline_style("flex-direction: row;")
+                .with_children(vec![
+                    Dom::text(format!("{}: {};", property.key, property.value)),
+                    text(&["source"], &source),
+                ].into()),
+        );
+    }
+
+    children.push(text(&["section"], "Callbacks"));
+    if node.callbacks.is_empty() {
+        children.push(text(&["property"], "none"));
+    }
+    for callback in node.callbacks.iter() {
+        children.push(text(&["property"], callback));
+    }
+
+    children
+}
+
+/// Nested margin / border / padding / content boxes, similar to the browser dev tools
+fn render_box_model(rect: &InspectorRect) -> Dom {
+
+    fn boxed(class: &str, label: String, child: Dom) -> Dom {
+        div(&[class]).with_children(vec![text(&["box-label"], &label), child].into())
+    }
+
+    fn offsets(o: &ResolvedOffsets) -> String {
+        format!("{} {} {} {}", o.top, o.right, o.bottom, o.left)
+    }
+
+    // the layout size is the content box, see get_background_bounds
+    let padding_width = rect.size.width + rect.padding.total_horizontal();
+    let padding_height = rect.size.height + rect.padding.total_vertical();
+    let border_width = padding_width + rect.border.total_horizontal();
+    let border_height = padding_height + rect.border.total_vertical();
+
+    let content = text(&["content-box"], &format!("{} x {}", rect.size.width, rect.size.height));
+    let padding = boxed(
+        "padding-box",
+        format!("padding {} ({} x {})", offsets(&rect.padding), padding_width, padding_height),
+        content,
+    );
+    let border = boxed(
+        "border-box",
+        format!("border {} ({} x {})", offsets(&rect.border), border_width, border_height),
+        padding,
+    );
+    boxed("margin-box", format!("margin {}", offsets(&rect.margin)), border)
+}
+
+fn div(classes: &[&str]) -> Dom {
+    Dom::div().with_ids_and_classes(classes.iter().map(|c| IdOrClass::Class((*c).into())).collect::<Vec<_>>().into())
+}
+
+fn text(classes: &[&str], text: &str) -> Dom {
+    Dom::text(text).with_ids_and_classes(classes.iter().map(|c| IdOrClass::Class((*c).into())).collect::<Vec<_>>().into())
+}
+
+fn callback(event: HoverEventFilter, data: RefAny, cb: CallbackType) -> CallbackData {
+    CallbackData {
+        event: EventFilter::Hover(event),
+        callback: Callback { cb },
+        data,
+    }
+}
+
+extern "C" fn on_row_mouse_enter(data: &mut RefAny, _: &mut CallbackInfo) -> Update {
+    let mut row = match data.downcast_mut::<InspectorRow>() {
+        Some(s) => s,
+        None => return Update::DoNothing,
+    };
+    let node_id = row.node_id;
+    if let Some(mut inspector) = row.inspector.downcast_mut::<InspectorData>() {
+        inspector.hovered = Some(node_id);
+    }
+    // the shell picks up the changed hovered node after the callback returns
+    Update::DoNothing
+}
+
+extern "C" fn on_row_mouse_up(data: &mut RefAny, _: &mut CallbackInfo) -> Update {
+    let mut row = match data.downcast_mut::<InspectorRow>() {
+        Some(s) => s,
+        None => return Update::DoNothing,
+    };
+    let node_id = row.node_id;
+    match row.inspector.downcast_mut::<InspectorData>() {
+        Some(mut inspector) => {
+            inspector.selected = Some(node_id);
+            Update::RefreshDom
+        }
+        None => Update::DoNothing,
+    }
+}
+
+extern "C" fn on_tree_mouse_leave(data: &mut RefAny, _: &mut CallbackInfo) -> Update {
+    if let Some(mut inspector) = data.downcast_mut::<InspectorData>() {
+        inspector.hovered = None;
+    }
+    Update::DoNothing
+}
+
+#[test]
+fn test_inspector_window_identification() {
+    let mut inspector = open_inspector(42, &[]);
+    let layout_callback = &mut inspector.state.layout_callback;
+    assert_eq!(get_inspected_window(layout_callback), Some(42));
+    assert_eq!(get_inspector_highlight(layout_callback), None);
+
+    // updating the snapshot keeps the inspected window
+    update_inspector(layout_callback, &[]);
+    assert_eq!(get_inspected_window(layout_callback), Some(42));
+
+    // regular windows are not inspectors
+    let mut window = WindowCreateOptions::default();
+    assert_eq!(get_inspected_window(&mut window.state.layout_callback), None);
+}
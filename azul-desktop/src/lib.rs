@@ -45,6 +45,7 @@ extern crate azulc_lib;
 extern crate raw_window_handle;
 extern crate webrender;
 extern crate tinyfiledialogs;
+#[cfg(not(target_os = "linux"))]
 extern crate clipboard2;
 
 /// Manages application state (`App` / `AppState` / `AppResources`), wrapping resources and app state
@@ -86,6 +87,11 @@ mod compositor;
 #[cfg(feature = "logging")]
 mod logging;
 mod wr_translate;
+/// Saved window geometry (`WindowCreateOptions::persistence_key`)
+mod persistence;
+/// Built-in DOM / layout inspector window (F12)
+#[cfg(feature = "css_parser")]
+mod inspector;
 
 /// `GetTextLayout` trait definition
 pub mod traits {
@@ -113,8 +119,7 @@ pub mod xml {
 
 /// Re-exports of errors
 pub mod errors {
-    // TODO: re-export the sub-types of ClipboardError!
-    pub use clipboard2::ClipboardError;
+    pub use crate::app::ClipboardError;
     pub use azulc_lib::font_loading::FontReloadError;
 }
 
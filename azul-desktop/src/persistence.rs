@@ -0,0 +1,118 @@
+//! Config files of windows with a `WindowCreateOptions::persistence_key`
+//!
+//! The geometry of every window is stored in its own file at
+//! `$XDG_CONFIG_HOME/<application>/windows/<persistence_key>.conf`
+//! (`~/.config` if `XDG_CONFIG_HOME` is not set, `%APPDATA%` on Windows),
+//! characters of the key that may not be valid in file names are escaped as `%XX`.
+//! The application name is the file name of the executable. Errors are ignored: if the file can't be read,
+//! the window is created with the state of the `WindowCreateOptions`.
+
+use azul_core::window::PersistedWindowState;
+use std::path::PathBuf;
+
+/// Loads the saved state of the window with the given key
+pub(crate) fn load_window_state(key: &str) -> Option<PersistedWindowState> {
+    let contents = std::fs::read_to_string(get_window_state_path(key)?).ok()?;
+    PersistedWindowState::deserialize(&contents)
+}
+
+/// Saves the state of the window with the given key, returns whether the file was written
+pub(crate) fn save_window_state(key: &str, state: &PersistedWindowState) -> bool {
+    let path = match get_window_state_path(key) {
+        Some(s) => s,
+        None => return false,
+    };
+
+    if let Some(parent) = path.parent() {
+        if std::fs::create_dir_all(parent).is_err() {
+            return false;
+        }
+    }
+
+    std::fs::write(path, state.serialize()).is_ok()
+}
+
+fn get_window_state_path(key: &str) -> Option<PathBuf> {
+    let app_name = std::env::current_exe()
+        .ok()?
+        .file_stem()?
+        .to_string_lossy()
+        .into_owned();
+
+    let mut path = get_config_dir()?;
+    path.push(sanitize_file_name(&app_name)?);
+    path.push("windows");
+    path.push(format!("{}.conf", escape_file_name(key)?));
+    Some(path)
+}
+
+#[cfg(target_os = "windows")]
+fn get_config_dir() -> Option<PathBuf> {
+    std::env::var_os("APPDATA")
+        .map(PathBuf::from)
+        .filter(|p| p.is_absolute())
+}
+
+#[cfg(not(target_os = "windows"))]
+fn get_config_dir() -> Option<PathBuf> {
+    // relative paths in XDG_CONFIG_HOME are invalid and have to be ignored
+    let xdg_config_home = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .filter(|p| p.is_absolute());
+
+    xdg_config_home.or_else(|| {
+        let mut home = PathBuf::from(std::env::var_os("HOME")?);
+        home.push(".config");
+        Some(home)
+    })
+}
+
+// Replaces all characters that are not allowed in file names ("My App" => "My_App")
+fn sanitize_file_name(name: &str) -> Option<String> {
+    let sanitized = name
+        .chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' | '.' => c,
+            _ => '_',
+        })
+        .collect::<String>();
+
+    if sanitized.is_empty() || sanitized.chars().all(|c| c == '.') {
+        None
+    } else {
+        Some(sanitized)
+    }
+}
+
+// Escapes all characters except lowercase ASCII letters, digits, '-' and '_' as
+// "%XX" per UTF-8 byte ("main/settings" => "main%2Fsettings"), so that different
+// keys never map to the same file, even on case-insensitive file systems
+fn escape_file_name(name: &str) -> Option<String> {
+    if name.is_empty() {
+        return None;
+    }
+
+    let mut escaped = String::with_capacity(name.len());
+    for b in name.bytes() {
+        match b {
+            b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' => escaped.push(b as char),
+            _ => escaped.push_str(&format!("%{:02X}", b)),
+        }
+    }
+
+    Some(escaped)
+}
+
+#[test]
+fn test_escape_file_name_is_unique() {
+    assert_eq!(
+        escape_file_name("main-window_2").as_deref(),
+        Some("main-window_2")
+    );
+    assert_eq!(escape_file_name("a/b").as_deref(), Some("a%2Fb"));
+    assert_eq!(escape_file_name("a_b").as_deref(), Some("a_b"));
+    assert_eq!(escape_file_name("A").as_deref(), Some("%41"));
+    assert_eq!(escape_file_name("..").as_deref(), Some("%2E%2E"));
+    assert_eq!(escape_file_name("%2F").as_deref(), Some("%252F"));
+    assert_eq!(escape_file_name(""), None);
+}
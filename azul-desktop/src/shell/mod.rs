@@ -2,5 +2,7 @@
 pub mod win32;
 #[cfg(target_os = "linux")]
 pub mod x11;
+#[cfg(target_os = "linux")]
+pub mod wayland;
 #[cfg(target_os = "macos")]
 pub mod appkit;
\ No newline at end of file
@@ -0,0 +1,494 @@
+//! Client-side window decorations for Wayland
+//!
+//! Wayland compositors are not required to draw a title bar (GNOME / mutter
+//! never does), so the title bar is rendered by azul itself: the layout callback
+//! of a decorated window is wrapped in `decorated_layout`, which prepends a title
+//! bar (styled by the `WaylandTheme` of the window) to the user DOM. Clicks on the
+//! title bar, the buttons and the resize border are handled natively by the shell,
+//! see `get_decoration_hit`.
+
+use alloc::string::String;
+use azul_core::{
+    callbacks::{
+        LayoutCallback, LayoutCallbackInfo, MarshaledLayoutCallback, MarshaledLayoutCallbackInner,
+        RefAny,
+    },
+    dom::{Dom, IdOrClass},
+    id_tree::NodeId,
+    styled_dom::StyledDom,
+    window::{LogicalPosition, LogicalSize, OptionWaylandTheme, WaylandTheme},
+};
+use azul_css::{CssProperty, FloatValue, LayoutFlexGrow};
+use azul_css_parser::CssApiWrapper;
+
+/// Height of the title bar in logical pixels
+pub(super) const TITLE_BAR_HEIGHT: f32 = 32.0;
+/// Width of the minimize / maximize / close buttons in logical pixels
+pub(super) const TITLE_BAR_BUTTON_WIDTH: f32 = 40.0;
+/// Width of the invisible border at the window edges that starts an interactive resize
+pub(super) const RESIZE_BORDER_WIDTH: f32 = 6.0;
+
+/// Marshal data of the `decorated_layout` callback
+#[derive(Debug)]
+pub(super) struct Decorations {
+    pub title: String,
+    /// Whether the window is activated (xdg_toplevel state), selects
+    /// between the active and inactive colors of the theme
+    pub is_active: bool,
+    pub is_maximized: bool,
+    pub is_resizable: bool,
+    pub theme: WaylandTheme,
+    /// Original layout callback of the window
+    pub inner: LayoutCallback,
+}
+
+/// Part of the decorations that is under the cursor
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(super) enum DecorationHit {
+    TitleBar,
+    Minimize,
+    Maximize,
+    Close,
+    Resize(ResizeEdge),
+}
+
+/// Edge of the window, values are the ones of `xdg_toplevel.resize_edge`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(u32)]
+pub(super) enum ResizeEdge {
+    Top = 1,
+    Bottom = 2,
+    Left = 4,
+    TopLeft = 5,
+    BottomLeft = 6,
+    Right = 8,
+    TopRight = 9,
+    BottomRight = 10,
+}
+
+impl ResizeEdge {
+    /// Name of the cursor in the XCursor theme
+    pub(super) fn get_cursor_name(&self) -> &'static str {
+        use self::ResizeEdge::*;
+        match self {
+            Top => "top_side",
+            Bottom => "bottom_side",
+            Left => "left_side",
+            TopLeft => "top_left_corner",
+            BottomLeft => "bottom_left_corner",
+            Right => "right_side",
+            TopRight => "top_right_corner",
+            BottomRight => "bottom_right_corner",
+        }
+    }
+}
+
+/// Default theme if the window does not set `LinuxWindowOptions::wayland_theme`
+pub(super) fn default_theme() -> WaylandTheme {
+    const TITLE_BAR_ACTIVE: [u8; 4] = [235, 235, 235, 255];
+    const TITLE_BAR_INACTIVE: [u8; 4] = [250, 250, 250, 255];
+    const SEPARATOR: [u8; 4] = [205, 205, 205, 255];
+    const TEXT_ACTIVE: [u8; 4] = [46, 52, 54, 255];
+    const TEXT_INACTIVE: [u8; 4] = [146, 149, 149, 255];
+    const BUTTON_HOVERED: [u8; 4] = [214, 214, 214, 255];
+    const CLOSE_HOVERED: [u8; 4] = [224, 27, 36, 255];
+    const WHITE: [u8; 4] = [255, 255, 255, 255];
+    const DISABLED: [u8; 4] = [190, 190, 190, 255];
+
+    WaylandTheme {
+        title_bar_active_background_color: TITLE_BAR_ACTIVE,
+        title_bar_active_separator_color: SEPARATOR,
+        title_bar_active_text_color: TEXT_ACTIVE,
+        title_bar_inactive_background_color: TITLE_BAR_INACTIVE,
+        title_bar_inactive_separator_color: SEPARATOR,
+        title_bar_inactive_text_color: TEXT_INACTIVE,
+        maximize_idle_foreground_inactive_color: TEXT_INACTIVE,
+        minimize_idle_foreground_inactive_color: TEXT_INACTIVE,
+        close_idle_foreground_inactive_color: TEXT_INACTIVE,
+        maximize_hovered_foreground_inactive_color: TEXT_ACTIVE,
+        minimize_hovered_foreground_inactive_color: TEXT_ACTIVE,
+        close_hovered_foreground_inactive_color: WHITE,
+        maximize_disabled_foreground_inactive_color: DISABLED,
+        minimize_disabled_foreground_inactive_color: DISABLED,
+        close_disabled_foreground_inactive_color: DISABLED,
+        maximize_idle_background_inactive_color: TITLE_BAR_INACTIVE,
+        minimize_idle_background_inactive_color: TITLE_BAR_INACTIVE,
+        close_idle_background_inactive_color: TITLE_BAR_INACTIVE,
+        maximize_hovered_background_inactive_color: BUTTON_HOVERED,
+        minimize_hovered_background_inactive_color: BUTTON_HOVERED,
+        close_hovered_background_inactive_color: CLOSE_HOVERED,
+        maximize_disabled_background_inactive_color: TITLE_BAR_INACTIVE,
+        minimize_disabled_background_inactive_color: TITLE_BAR_INACTIVE,
+        close_disabled_background_inactive_color: TITLE_BAR_INACTIVE,
+        maximize_idle_foreground_active_color: TEXT_ACTIVE,
+        minimize_idle_foreground_active_color: TEXT_ACTIVE,
+        close_idle_foreground_active_color: TEXT_ACTIVE,
+        maximize_hovered_foreground_active_color: TEXT_ACTIVE,
+        minimize_hovered_foreground_active_color: TEXT_ACTIVE,
+        close_hovered_foreground_active_color: WHITE,
+        maximize_disabled_foreground_active_color: DISABLED,
+        minimize_disabled_foreground_active_color: DISABLED,
+        close_disabled_foreground_active_color: DISABLED,
+        maximize_idle_background_active_color: TITLE_BAR_ACTIVE,
+        minimize_idle_background_active_color: TITLE_BAR_ACTIVE,
+        close_idle_background_active_color: TITLE_BAR_ACTIVE,
+        maximize_hovered_background_active_color: BUTTON_HOVERED,
+        minimize_hovered_background_active_color: BUTTON_HOVERED,
+        close_hovered_background_active_color: CLOSE_HOVERED,
+        maximize_disabled_background_active_color: TITLE_BAR_ACTIVE,
+        minimize_disabled_background_active_color: TITLE_BAR_ACTIVE,
+        close_disabled_background_active_color: TITLE_BAR_ACTIVE,
+        title_bar_font: "sans-serif".into(),
+        title_bar_font_size: 13.0,
+    }
+}
+
+/// Wraps the layout callback of the window in `decorated_layout`
+/// (does nothing if the callback is already wrapped)
+pub(super) fn decorate(
+    layout_callback: &mut LayoutCallback,
+    title: &str,
+    is_resizable: bool,
+    theme: &OptionWaylandTheme,
+) {
+    if is_decorated(layout_callback) {
+        return;
+    }
+
+    let decorations = Decorations {
+        title: title.into(),
+        is_active: true,
+        is_maximized: false,
+        is_resizable,
+        theme: theme.as_ref().cloned().unwrap_or_else(default_theme),
+        inner: layout_callback.clone(),
+    };
+
+    *layout_callback = LayoutCallback::Marshaled(MarshaledLayoutCallback {
+        marshal_data: RefAny::new(decorations),
+        cb: MarshaledLayoutCallbackInner {
+            cb: decorated_layout,
+        },
+    });
+}
+
+/// Restores the original layout callback of the window
+pub(super) fn undecorate(layout_callback: &mut LayoutCallback) {
+    let inner = match get_decorations(layout_callback)
+        .and_then(|data| data.downcast_ref::<Decorations>().map(|d| d.inner.clone()))
+    {
+        Some(s) => s,
+        None => return,
+    };
+    *layout_callback = inner;
+}
+
+/// Synchronizes the decorations with the window state, returns whether the
+/// title bar changed (and the DOM of the window has to be regenerated)
+pub(super) fn update_decorations(
+    layout_callback: &mut LayoutCallback,
+    title: &str,
+    is_active: bool,
+    is_maximized: bool,
+    is_resizable: bool,
+    theme: &OptionWaylandTheme,
+) -> bool {
+    let data = match get_decorations(layout_callback) {
+        Some(s) => s,
+        None => return false,
+    };
+    let mut decorations = match data.downcast_mut::<Decorations>() {
+        Some(s) => s,
+        None => return false,
+    };
+
+    let theme = theme
+        .as_ref()
+        .cloned()
+        .unwrap_or_else(|| decorations.theme.clone());
+    let changed = decorations.title != title
+        || decorations.is_active != is_active
+        || decorations.is_maximized != is_maximized
+        || decorations.is_resizable != is_resizable
+        || decorations.theme != theme;
+
+    decorations.title = title.into();
+    decorations.is_active = is_active;
+    decorations.is_maximized = is_maximized;
+    decorations.is_resizable = is_resizable;
+    decorations.theme = theme;
+
+    changed
+}
+
+/// Returns whether the layout callback is wrapped in `decorated_layout`
+pub(super) fn is_decorated(layout_callback: &mut LayoutCallback) -> bool {
+    get_decorations(layout_callback).is_some()
+}
+
+fn get_decorations(layout_callback: &mut LayoutCallback) -> Option<&mut RefAny> {
+    match layout_callback {
+        LayoutCallback::Marshaled(m) if m.cb.cb as usize == decorated_layout as usize => {
+            Some(&mut m.marshal_data)
+        }
+        _ => None,
+    }
+}
+
+/// Returns which part of the decorations is at the given position
+/// (relative to the top left corner of the window surface)
+pub(super) fn get_decoration_hit(
+    position: LogicalPosition,
+    window_size: LogicalSize,
+    is_maximized: bool,
+    is_resizable: bool,
+) -> Option<DecorationHit> {
+    let LogicalPosition { x, y } = position;
+    let LogicalSize { width, height } = window_size;
+
+    if is_resizable && !is_maximized {
+        let top = y < RESIZE_BORDER_WIDTH;
+        let bottom = y >= height - RESIZE_BORDER_WIDTH;
+        let left = x < RESIZE_BORDER_WIDTH;
+        let right = x >= width - RESIZE_BORDER_WIDTH;
+
+        let edge = match (top, bottom, left, right) {
+            (true, _, true, _) => Some(ResizeEdge::TopLeft),
+            (true, _, _, true) => Some(ResizeEdge::TopRight),
+            (_, true, true, _) => Some(ResizeEdge::BottomLeft),
+            (_, true, _, true) => Some(ResizeEdge::BottomRight),
+            (true, _, _, _) => Some(ResizeEdge::Top),
+            (_, true, _, _) => Some(ResizeEdge::Bottom),
+            (_, _, true, _) => Some(ResizeEdge::Left),
+            (_, _, _, true) => Some(ResizeEdge::Right),
+            _ => None,
+        };
+
+        if let Some(edge) = edge {
+            return Some(DecorationHit::Resize(edge));
+        }
+    }
+
+    if y < 0.0 || y >= TITLE_BAR_HEIGHT || x < 0.0 || x >= width {
+        return None;
+    }
+
+    let button = ((width - x) / TITLE_BAR_BUTTON_WIDTH) as usize;
+    Some(match button {
+        0 => DecorationHit::Close,
+        1 => DecorationHit::Maximize,
+        2 => DecorationHit::Minimize,
+        _ => DecorationHit::TitleBar,
+    })
+}
+
+extern "C" fn decorated_layout(
+    marshal_data: &mut RefAny,
+    app_data: &mut RefAny,
+    info: &mut LayoutCallbackInfo,
+) -> StyledDom {
+    let mut decorations = match marshal_data.downcast_mut::<Decorations>() {
+        Some(s) => s,
+        None => return StyledDom::default(),
+    };
+
+    let user_dom = match &mut decorations.inner {
+        LayoutCallback::Raw(r) => (r.cb)(app_data, info),
+        LayoutCallback::Marshaled(m) => (m.cb.cb)(&mut m.marshal_data, app_data, info),
+    };
+
+    let mut styled_dom = render_title_bar(&*decorations);
+    let user_root = NodeId::new(styled_dom.node_count());
+    styled_dom.append_child(user_dom);
+
+    // let the user DOM fill the remaining space below the title bar
+    let _ = styled_dom.restyle_user_property(
+        &user_root,
+        &[CssProperty::flex_grow(LayoutFlexGrow {
+            inner: FloatValue::const_new(1),
+        })],
+    );
+
+    styled_dom
+}
+
+fn render_title_bar(decorations: &Decorations) -> StyledDom {
+    let title = Dom::div()
+        .with_ids_and_classes(class("__azul-native-titlebar-title"))
+        .with_children(vec![Dom::text(decorations.title.as_str())].into());
+
+    let buttons = [
+        ("__azul-native-titlebar-minimize", "\u{2013}"),
+        ("__azul-native-titlebar-maximize", "\u{25a1}"),
+        ("__azul-native-titlebar-close", "\u{00d7}"),
+    ];
+
+    let mut children = vec![title];
+    children.extend(buttons.iter().map(|(class_name, icon)| {
+        Dom::div()
+            .with_ids_and_classes(class(class_name))
+            .with_children(vec![Dom::text(*icon)].into())
+    }));
+
+    let title_bar = Dom::div()
+        .with_ids_and_classes(class("__azul-native-titlebar"))
+        .with_children(children.into());
+
+    Dom::body()
+        .with_children(vec![title_bar].into())
+        .style(CssApiWrapper::from_string(
+            get_title_bar_css(decorations).into(),
+        ))
+}
+
+fn class(name: &str) -> azul_core::dom::IdOrClassVec {
+    vec![IdOrClass::Class(name.into())].into()
+}
+
+fn get_title_bar_css(decorations: &Decorations) -> String {
+    let t = &decorations.theme;
+    let a = decorations.is_active;
+
+    let pick = |active: [u8; 4], inactive: [u8; 4]| hex(if a { active } else { inactive });
+
+    let (maximize_fg, maximize_bg, maximize_hover_fg, maximize_hover_bg) =
+        if decorations.is_resizable {
+            (
+                pick(
+                    t.maximize_idle_foreground_active_color,
+                    t.maximize_idle_foreground_inactive_color,
+                ),
+                pick(
+                    t.maximize_idle_background_active_color,
+                    t.maximize_idle_background_inactive_color,
+                ),
+                pick(
+                    t.maximize_hovered_foreground_active_color,
+                    t.maximize_hovered_foreground_inactive_color,
+                ),
+                pick(
+                    t.maximize_hovered_background_active_color,
+                    t.maximize_hovered_background_inactive_color,
+                ),
+            )
+        } else {
+            let fg = pick(
+                t.maximize_disabled_foreground_active_color,
+                t.maximize_disabled_foreground_inactive_color,
+            );
+            let bg = pick(
+                t.maximize_disabled_background_active_color,
+                t.maximize_disabled_background_inactive_color,
+            );
+            (fg.clone(), bg.clone(), fg, bg)
+        };
+
+    format!(
+        "
+        body {{ flex-direction: column; }}
+        .__azul-native-titlebar {{
+            height: {height}px;
+            flex-direction: row;
+            background: {bg};
+            border-bottom: 1px solid {separator};
+            color: {text};
+            font-family: \"{font}\";
+            font-size: {font_size}px;
+        }}
+        .__azul-native-titlebar-title {{ flex-grow: 1; justify-content: center; padding-left: 12px; }}
+        .__azul-native-titlebar-minimize {{ width: {button}px; justify-content: center; align-items: center; color: {minimize_fg}; background: {minimize_bg}; }}
+        .__azul-native-titlebar-minimize:hover {{ color: {minimize_hover_fg}; background: {minimize_hover_bg}; }}
+        .__azul-native-titlebar-maximize {{ width: {button}px; justify-content: center; align-items: center; color: {maximize_fg}; background: {maximize_bg}; }}
+        .__azul-native-titlebar-maximize:hover {{ color: {maximize_hover_fg}; background: {maximize_hover_bg}; }}
+        .__azul-native-titlebar-close {{ width: {button}px; justify-content: center; align-items: center; color: {close_fg}; background: {close_bg}; }}
+        .__azul-native-titlebar-close:hover {{ color: {close_hover_fg}; background: {close_hover_bg}; }}
+        ",
+        height = TITLE_BAR_HEIGHT,
+        button = TITLE_BAR_BUTTON_WIDTH,
+        bg = pick(
+            t.title_bar_active_background_color,
+            t.title_bar_inactive_background_color
+        ),
+        separator = pick(
+            t.title_bar_active_separator_color,
+            t.title_bar_inactive_separator_color
+        ),
+        text = pick(
+            t.title_bar_active_text_color,
+            t.title_bar_inactive_text_color
+        ),
+        font = t.title_bar_font.as_str(),
+        font_size = t.title_bar_font_size,
+        minimize_fg = pick(
+            t.minimize_idle_foreground_active_color,
+            t.minimize_idle_foreground_inactive_color
+        ),
+        minimize_bg = pick(
+            t.minimize_idle_background_active_color,
+            t.minimize_idle_background_inactive_color
+        ),
+        minimize_hover_fg = pick(
+            t.minimize_hovered_foreground_active_color,
+            t.minimize_hovered_foreground_inactive_color
+        ),
+        minimize_hover_bg = pick(
+            t.minimize_hovered_background_active_color,
+            t.minimize_hovered_background_inactive_color
+        ),
+        maximize_fg = maximize_fg,
+        maximize_bg = maximize_bg,
+        maximize_hover_fg = maximize_hover_fg,
+        maximize_hover_bg = maximize_hover_bg,
+        close_fg = pick(
+            t.close_idle_foreground_active_color,
+            t.close_idle_foreground_inactive_color
+        ),
+        close_bg = pick(
+            t.close_idle_background_active_color,
+            t.close_idle_background_inactive_color
+        ),
+        close_hover_fg = pick(
+            t.close_hovered_foreground_active_color,
+            t.close_hovered_foreground_inactive_color
+        ),
+        close_hover_bg = pick(
+            t.close_hovered_background_active_color,
+            t.close_hovered_background_inactive_color
+        ),
+    )
+}
+
+fn hex(c: [u8; 4]) -> String {
+    format!("#{:02x}{:02x}{:02x}{:02x}", c[0], c[1], c[2], c[3])
+}
+
+#[test]
+fn test_decoration_hit() {
+    let size = LogicalSize::new(800.0, 600.0);
+    let hit =
+        |x, y, maximized| get_decoration_hit(LogicalPosition::new(x, y), size, maximized, true);
+
+    assert_eq!(
+        hit(2.0, 2.0, false),
+        Some(DecorationHit::Resize(ResizeEdge::TopLeft))
+    );
+    assert_eq!(
+        hit(400.0, 598.0, false),
+        Some(DecorationHit::Resize(ResizeEdge::Bottom))
+    );
+    assert_eq!(
+        hit(799.0, 300.0, false),
+        Some(DecorationHit::Resize(ResizeEdge::Right))
+    );
+    assert_eq!(
+        hit(790.0, 16.0, false),
+        Some(DecorationHit::Resize(ResizeEdge::Right))
+    );
+    assert_eq!(hit(780.0, 16.0, false), Some(DecorationHit::Close));
+    assert_eq!(hit(790.0, 2.0, true), Some(DecorationHit::Close));
+    assert_eq!(hit(740.0, 16.0, false), Some(DecorationHit::Maximize));
+    assert_eq!(hit(700.0, 16.0, false), Some(DecorationHit::Minimize));
+    assert_eq!(hit(400.0, 16.0, false), Some(DecorationHit::TitleBar));
+    assert_eq!(hit(400.0, 300.0, false), None);
+    assert_eq!(hit(2.0, 300.0, true), None);
+}
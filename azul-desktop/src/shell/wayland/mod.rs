@@ -0,0 +1,4774 @@
+//! Native Wayland backend (xdg-shell + EGL)
+//!
+//! Selected at runtime instead of the X11 backend if `WAYLAND_DISPLAY` is set.
+//! All libraries (libwayland-client, libwayland-egl, libwayland-cursor,
+//! libxkbcommon, libEGL) are loaded dynamically, the protocol objects that are
+//! not part of libwayland-client (xdg-shell, viewporter, fractional-scale-v1)
+//! are defined in this file.
+//!
+//! Every protocol object gets the (boxed) `WaylandApp` as its listener data,
+//! events are processed immediately inside of the listeners, while windows are
+//! only created / destroyed in the main loop (outside of the dispatch).
+
+#[cfg(feature = "css_parser")]
+mod decorations;
+
+use super::x11::{cursor, notify, tray};
+use super::x11::{
+    display_egl_status, event, ApplicationData, EGLConfig, EGLContext, EGLDisplay, EGLSurface, Egl,
+    GlFunctions, Library, LinuxStartupError, LinuxWindowCreateError, Notifier, ProcessEventResult,
+    SharedApplicationData, EGL_BACK_BUFFER, EGL_BLUE_SIZE, EGL_COLOR_BUFFER_TYPE, EGL_CONFORMANT,
+    EGL_CONTEXT_MAJOR_VERSION, EGL_CONTEXT_MINOR_VERSION, EGL_CONTEXT_OPENGL_CORE_PROFILE_BIT,
+    EGL_CONTEXT_OPENGL_PROFILE_MASK, EGL_DEPTH_SIZE, EGL_GL_COLORSPACE, EGL_GL_COLORSPACE_LINEAR,
+    EGL_GREEN_SIZE, EGL_NONE, EGL_NO_CONTEXT, EGL_NO_DISPLAY, EGL_NO_SURFACE, EGL_OPENGL_API,
+    EGL_OPENGL_BIT, EGL_RED_SIZE, EGL_RENDERABLE_TYPE, EGL_RENDER_BUFFER, EGL_RGB_BUFFER,
+    EGL_STENCIL_SIZE, EGL_SURFACE_TYPE, EGL_TRUE, EGL_WINDOW_BIT,
+};
+use crate::{
+    app::{App, LazyFcCache},
+    gl::{c_char, c_int, c_uint, c_ulong},
+    wr_translate::{
+        generate_frame, rebuild_display_list, wr_synchronize_updated_images, AsyncHitTester,
+    },
+};
+use alloc::{collections::BTreeMap, rc::Rc, string::String};
+use azul_core::{
+    app_resources::{AppConfig, ImageCache},
+    callbacks::{LayoutCallback, RefAny},
+    gl::OptionGlContextPtr,
+    scroll::WHEEL_SCROLL_STEP,
+    styled_dom::DomId,
+    ui_solver::LayoutResult,
+    window::{
+        CallCallbacksResult, CursorPosition, CustomCursor, FullWindowState, LogicalPosition,
+        LogicalSize, MenuCallback, ScanCode, ShortcutChange, VirtualKeyCode, WindowCreateOptions,
+        WindowFrame, WindowId, WindowInternal, WindowRegion, WindowResultCallback,
+    },
+    window_state::NodesToCheck,
+};
+use azul_css::AzString;
+use core::{cell::RefCell, ffi::c_void, mem, ptr};
+use gl_context_loader::gl;
+use std::{ffi::CString, time::Instant};
+use webrender::{
+    api::units::{DeviceIntRect as WrDeviceIntRect, DeviceIntSize as WrDeviceIntSize},
+    render_api::RenderApi as WrRenderApi,
+    Renderer as WrRenderer, RendererOptions as WrRendererOptions,
+    ShaderPrecacheFlags as WrShaderPrecacheFlags, Transaction as WrTransaction,
+};
+
+extern "C" {
+    // libc
+    fn poll(fds: *mut pollfd, nfds: c_ulong, timeout: c_int) -> c_int;
+    fn mmap(
+        addr: *mut c_void,
+        len: usize,
+        prot: c_int,
+        flags: c_int,
+        fd: c_int,
+        offset: i64,
+    ) -> *mut c_void;
+    fn munmap(addr: *mut c_void, len: usize) -> c_int;
+    fn close(fd: c_int) -> c_int;
+    fn pipe(fds: *mut c_int) -> c_int;
+    fn read(fd: c_int, buf: *mut c_void, count: usize) -> isize;
+    fn memfd_create(name: *const c_char, flags: u32) -> c_int;
+    fn ftruncate(fd: c_int, length: i64) -> c_int;
+}
+
+#[repr(C)]
+struct pollfd {
+    fd: c_int,
+    events: i16,
+    revents: i16,
+}
+
+const POLLIN: i16 = 0x1;
+const PROT_READ: c_int = 0x1;
+const PROT_WRITE: c_int = 0x2;
+const MAP_SHARED: c_int = 0x1;
+const MAP_PRIVATE: c_int = 0x2;
+const MFD_CLOEXEC: u32 = 0x1;
+const MAP_FAILED: *mut c_void = !0 as *mut c_void;
+
+// how often the messages of running threads are polled (same as on Windows)
+const THREAD_POLL_INTERVAL_MS: c_int = 16;
+
+/// Returns whether the application runs in a Wayland session
+pub fn is_wayland_session() -> bool {
+    std::env::var_os("WAYLAND_DISPLAY")
+        .map(|s| !s.is_empty())
+        .unwrap_or(false)
+}
+
+// -- libwayland-client types
+
+pub enum wl_display {}
+pub enum wl_proxy {}
+pub enum wl_egl_window {}
+pub enum wl_cursor_theme {}
+pub enum xkb_context {}
+pub enum xkb_keymap {}
+pub enum xkb_state {}
+
+#[repr(C)]
+pub struct wl_message {
+    pub name: *const c_char,
+    pub signature: *const c_char,
+    pub types: *const *const wl_interface,
+}
+
+#[repr(C)]
+pub struct wl_interface {
+    pub name: *const c_char,
+    pub version: c_int,
+    pub method_count: c_int,
+    pub methods: *const wl_message,
+    pub event_count: c_int,
+    pub events: *const wl_message,
+}
+
+unsafe impl Sync for wl_message {}
+unsafe impl Sync for wl_interface {}
+
+#[repr(C)]
+struct wl_array {
+    size: usize,
+    alloc: usize,
+    data: *mut c_void,
+}
+
+#[repr(C)]
+struct wl_cursor_image {
+    width: u32,
+    height: u32,
+    hotspot_x: u32,
+    hotspot_y: u32,
+    delay: u32,
+}
+
+#[repr(C)]
+struct wl_cursor {
+    image_count: c_uint,
+    images: *mut *mut wl_cursor_image,
+    name: *mut c_char,
+}
+
+/// 24.8 fixed point number
+type wl_fixed = i32;
+
+fn wl_fixed_to_f32(f: wl_fixed) -> f32 {
+    f as f32 / 256.0
+}
+
+// -- protocols that are not part of libwayland-client
+
+// The types of object arguments are only used by libwayland to type-check
+// incoming objects, all of them are left empty
+struct NullTypes([*const wl_interface; 8]);
+unsafe impl Sync for NullTypes {}
+static NULL_TYPES: NullTypes = NullTypes([ptr::null(); 8]);
+
+macro_rules! wl_message {
+    ($name:expr, $signature:expr) => {
+        wl_message {
+            name: concat!($name, "\0").as_ptr() as *const c_char,
+            signature: concat!($signature, "\0").as_ptr() as *const c_char,
+            types: &NULL_TYPES.0 as *const _ as *const *const wl_interface,
+        }
+    };
+}
+
+static XDG_WM_BASE_REQUESTS: [wl_message; 4] = [
+    wl_message!("destroy", ""),
+    wl_message!("create_positioner", "n"),
+    wl_message!("get_xdg_surface", "no"),
+    wl_message!("pong", "u"),
+];
+static XDG_WM_BASE_EVENTS: [wl_message; 1] = [wl_message!("ping", "u")];
+static XDG_WM_BASE_INTERFACE: wl_interface = wl_interface {
+    name: "xdg_wm_base\0".as_ptr() as *const c_char,
+    version: 2,
+    method_count: 4,
+    methods: XDG_WM_BASE_REQUESTS.as_ptr(),
+    event_count: 1,
+    events: XDG_WM_BASE_EVENTS.as_ptr(),
+};
+
+static XDG_SURFACE_REQUESTS: [wl_message; 5] = [
+    wl_message!("destroy", ""),
+    wl_message!("get_toplevel", "n"),
+    wl_message!("get_popup", "n?oo"),
+    wl_message!("set_window_geometry", "iiii"),
+    wl_message!("ack_configure", "u"),
+];
+static XDG_SURFACE_EVENTS: [wl_message; 1] = [wl_message!("configure", "u")];
+static XDG_SURFACE_INTERFACE: wl_interface = wl_interface {
+    name: "xdg_surface\0".as_ptr() as *const c_char,
+    version: 2,
+    method_count: 5,
+    methods: XDG_SURFACE_REQUESTS.as_ptr(),
+    event_count: 1,
+    events: XDG_SURFACE_EVENTS.as_ptr(),
+};
+
+static XDG_TOPLEVEL_REQUESTS: [wl_message; 14] = [
+    wl_message!("destroy", ""),
+    wl_message!("set_parent", "?o"),
+    wl_message!("set_title", "s"),
+    wl_message!("set_app_id", "s"),
+    wl_message!("show_window_menu", "ouii"),
+    wl_message!("move", "ou"),
+    wl_message!("resize", "ouu"),
+    wl_message!("set_max_size", "ii"),
+    wl_message!("set_min_size", "ii"),
+    wl_message!("set_maximized", ""),
+    wl_message!("unset_maximized", ""),
+    wl_message!("set_fullscreen", "?o"),
+    wl_message!("unset_fullscreen", ""),
+    wl_message!("set_minimized", ""),
+];
+static XDG_TOPLEVEL_EVENTS: [wl_message; 2] =
+    [wl_message!("configure", "iia"), wl_message!("close", "")];
+static XDG_TOPLEVEL_INTERFACE: wl_interface = wl_interface {
+    name: "xdg_toplevel\0".as_ptr() as *const c_char,
+    version: 2,
+    method_count: 14,
+    methods: XDG_TOPLEVEL_REQUESTS.as_ptr(),
+    event_count: 2,
+    events: XDG_TOPLEVEL_EVENTS.as_ptr(),
+};
+
+static WP_VIEWPORTER_REQUESTS: [wl_message; 2] = [
+    wl_message!("destroy", ""),
+    wl_message!("get_viewport", "no"),
+];
+static WP_VIEWPORTER_INTERFACE: wl_interface = wl_interface {
+    name: "wp_viewporter\0".as_ptr() as *const c_char,
+    version: 1,
+    method_count: 2,
+    methods: WP_VIEWPORTER_REQUESTS.as_ptr(),
+    event_count: 0,
+    events: ptr::null(),
+};
+
+static WP_VIEWPORT_REQUESTS: [wl_message; 3] = [
+    wl_message!("destroy", ""),
+    wl_message!("set_source", "ffff"),
+    wl_message!("set_destination", "ii"),
+];
+static WP_VIEWPORT_INTERFACE: wl_interface = wl_interface {
+    name: "wp_viewport\0".as_ptr() as *const c_char,
+    version: 1,
+    method_count: 3,
+    methods: WP_VIEWPORT_REQUESTS.as_ptr(),
+    event_count: 0,
+    events: ptr::null(),
+};
+
+static WP_FRACTIONAL_SCALE_MANAGER_V1_REQUESTS: [wl_message; 2] = [
+    wl_message!("destroy", ""),
+    wl_message!("get_fractional_scale", "no"),
+];
+static WP_FRACTIONAL_SCALE_MANAGER_V1_INTERFACE: wl_interface = wl_interface {
+    name: "wp_fractional_scale_manager_v1\0".as_ptr() as *const c_char,
+    version: 1,
+    method_count: 2,
+    methods: WP_FRACTIONAL_SCALE_MANAGER_V1_REQUESTS.as_ptr(),
+    event_count: 0,
+    events: ptr::null(),
+};
+
+static WP_FRACTIONAL_SCALE_V1_REQUESTS: [wl_message; 1] = [wl_message!("destroy", "")];
+static WP_FRACTIONAL_SCALE_V1_EVENTS: [wl_message; 1] = [wl_message!("preferred_scale", "u")];
+static WP_FRACTIONAL_SCALE_V1_INTERFACE: wl_interface = wl_interface {
+    name: "wp_fractional_scale_v1\0".as_ptr() as *const c_char,
+    version: 1,
+    method_count: 1,
+    methods: WP_FRACTIONAL_SCALE_V1_REQUESTS.as_ptr(),
+    event_count: 1,
+    events: WP_FRACTIONAL_SCALE_V1_EVENTS.as_ptr(),
+};
+
+// -- request opcodes
+
+const WL_DISPLAY_GET_REGISTRY: u32 = 1;
+const WL_REGISTRY_BIND: u32 = 0;
+const WL_COMPOSITOR_CREATE_SURFACE: u32 = 0;
+const WL_COMPOSITOR_CREATE_REGION: u32 = 1;
+const WL_SURFACE_DESTROY: u32 = 0;
+const WL_SURFACE_ATTACH: u32 = 1;
+const WL_SURFACE_DAMAGE: u32 = 2;
+const WL_SURFACE_SET_INPUT_REGION: u32 = 5;
+const WL_SURFACE_COMMIT: u32 = 6;
+const WL_SURFACE_SET_BUFFER_SCALE: u32 = 8;
+const WL_SEAT_GET_POINTER: u32 = 0;
+const WL_SEAT_GET_KEYBOARD: u32 = 1;
+const WL_POINTER_SET_CURSOR: u32 = 0;
+const WL_POINTER_RELEASE: u32 = 1;
+const WL_KEYBOARD_RELEASE: u32 = 0;
+const WL_SHM_CREATE_POOL: u32 = 0;
+const WL_SHM_POOL_CREATE_BUFFER: u32 = 0;
+const WL_SHM_POOL_DESTROY: u32 = 1;
+const WL_REGION_DESTROY: u32 = 0;
+const WL_BUFFER_DESTROY: u32 = 0;
+const WL_SHM_FORMAT_ARGB8888: u32 = 0;
+const XDG_WM_BASE_PONG: u32 = 3;
+const XDG_WM_BASE_GET_XDG_SURFACE: u32 = 2;
+const XDG_SURFACE_DESTROY: u32 = 0;
+const XDG_SURFACE_GET_TOPLEVEL: u32 = 1;
+const XDG_SURFACE_ACK_CONFIGURE: u32 = 4;
+const XDG_TOPLEVEL_DESTROY: u32 = 0;
+const XDG_TOPLEVEL_SET_PARENT: u32 = 1;
+const XDG_TOPLEVEL_SET_TITLE: u32 = 2;
+const XDG_TOPLEVEL_SET_APP_ID: u32 = 3;
+const XDG_TOPLEVEL_MOVE: u32 = 5;
+const XDG_TOPLEVEL_RESIZE: u32 = 6;
+const XDG_TOPLEVEL_SET_MAX_SIZE: u32 = 7;
+const XDG_TOPLEVEL_SET_MIN_SIZE: u32 = 8;
+const XDG_TOPLEVEL_SET_MAXIMIZED: u32 = 9;
+const XDG_TOPLEVEL_UNSET_MAXIMIZED: u32 = 10;
+const XDG_TOPLEVEL_SET_FULLSCREEN: u32 = 11;
+const XDG_TOPLEVEL_UNSET_FULLSCREEN: u32 = 12;
+const XDG_TOPLEVEL_SET_MINIMIZED: u32 = 13;
+const WP_VIEWPORT_DESTROY: u32 = 0;
+const WP_VIEWPORT_SET_DESTINATION: u32 = 2;
+const WP_VIEWPORTER_GET_VIEWPORT: u32 = 1;
+const WP_FRACTIONAL_SCALE_MANAGER_GET_FRACTIONAL_SCALE: u32 = 1;
+const WP_FRACTIONAL_SCALE_DESTROY: u32 = 0;
+
+// -- enum values
+
+const WL_SEAT_CAPABILITY_POINTER: u32 = 1;
+const WL_SEAT_CAPABILITY_KEYBOARD: u32 = 2;
+const WL_POINTER_BUTTON_STATE_PRESSED: u32 = 1;
+const WL_POINTER_AXIS_VERTICAL_SCROLL: u32 = 0;
+const WL_POINTER_AXIS_HORIZONTAL_SCROLL: u32 = 1;
+const WL_POINTER_AXIS_SOURCE_FINGER: u32 = 1;
+const WL_POINTER_AXIS_SOURCE_CONTINUOUS: u32 = 2;
+const WL_KEYBOARD_KEYMAP_FORMAT_XKB_V1: u32 = 1;
+const WL_KEYBOARD_KEY_STATE_PRESSED: u32 = 1;
+const XDG_TOPLEVEL_STATE_MAXIMIZED: u32 = 1;
+const XDG_TOPLEVEL_STATE_FULLSCREEN: u32 = 2;
+const XDG_TOPLEVEL_STATE_ACTIVATED: u32 = 4;
+const XKB_KEYMAP_FORMAT_TEXT_V1: c_int = 1;
+
+// linux/input-event-codes.h
+const BTN_LEFT: u32 = 0x110;
+const BTN_RIGHT: u32 = 0x111;
+const BTN_MIDDLE: u32 = 0x112;
+
+/// Distance in surface coordinates that corresponds to one "click" of the mouse wheel
+const SCROLL_WHEEL_STEP: f32 = 10.0;
+
+// -- dynamically loaded functions
+
+type wl_display_connectFuncType = extern "C" fn(*const c_char) -> *mut wl_display;
+type wl_display_disconnectFuncType = extern "C" fn(*mut wl_display);
+type wl_display_get_fdFuncType = extern "C" fn(*mut wl_display) -> c_int;
+type wl_display_roundtripFuncType = extern "C" fn(*mut wl_display) -> c_int;
+type wl_display_dispatch_pendingFuncType = extern "C" fn(*mut wl_display) -> c_int;
+type wl_display_prepare_readFuncType = extern "C" fn(*mut wl_display) -> c_int;
+type wl_display_read_eventsFuncType = extern "C" fn(*mut wl_display) -> c_int;
+type wl_display_cancel_readFuncType = extern "C" fn(*mut wl_display);
+type wl_display_flushFuncType = extern "C" fn(*mut wl_display) -> c_int;
+type wl_proxy_marshalFuncType = unsafe extern "C" fn(*mut wl_proxy, u32, ...);
+type wl_proxy_marshal_constructorFuncType =
+    unsafe extern "C" fn(*mut wl_proxy, u32, *const wl_interface, ...) -> *mut wl_proxy;
+type wl_proxy_marshal_constructor_versionedFuncType =
+    unsafe extern "C" fn(*mut wl_proxy, u32, *const wl_interface, u32, ...) -> *mut wl_proxy;
+type wl_proxy_add_listenerFuncType =
+    extern "C" fn(*mut wl_proxy, *const c_void, *mut c_void) -> c_int;
+type wl_proxy_destroyFuncType = extern "C" fn(*mut wl_proxy);
+
+type wl_egl_window_createFuncType =
+    extern "C" fn(*mut wl_proxy, c_int, c_int) -> *mut wl_egl_window;
+type wl_egl_window_resizeFuncType = extern "C" fn(*mut wl_egl_window, c_int, c_int, c_int, c_int);
+type wl_egl_window_destroyFuncType = extern "C" fn(*mut wl_egl_window);
+
+type wl_cursor_theme_loadFuncType =
+    extern "C" fn(*const c_char, c_int, *mut wl_proxy) -> *mut wl_cursor_theme;
+type wl_cursor_theme_destroyFuncType = extern "C" fn(*mut wl_cursor_theme);
+type wl_cursor_theme_get_cursorFuncType =
+    extern "C" fn(*mut wl_cursor_theme, *const c_char) -> *mut wl_cursor;
+type wl_cursor_image_get_bufferFuncType = extern "C" fn(*mut wl_cursor_image) -> *mut wl_proxy;
+
+type xkb_context_newFuncType = extern "C" fn(c_int) -> *mut xkb_context;
+type xkb_context_unrefFuncType = extern "C" fn(*mut xkb_context);
+type xkb_keymap_new_from_stringFuncType =
+    extern "C" fn(*mut xkb_context, *const c_char, c_int, c_int) -> *mut xkb_keymap;
+type xkb_keymap_unrefFuncType = extern "C" fn(*mut xkb_keymap);
+type xkb_keymap_key_get_syms_by_levelFuncType =
+    extern "C" fn(*mut xkb_keymap, u32, u32, u32, *mut *const u32) -> c_int;
+type xkb_keymap_key_repeatsFuncType = extern "C" fn(*mut xkb_keymap, u32) -> c_int;
+type xkb_state_newFuncType = extern "C" fn(*mut xkb_keymap) -> *mut xkb_state;
+type xkb_state_unrefFuncType = extern "C" fn(*mut xkb_state);
+type xkb_state_update_maskFuncType =
+    extern "C" fn(*mut xkb_state, u32, u32, u32, u32, u32, u32) -> c_int;
+type xkb_state_key_get_one_symFuncType = extern "C" fn(*mut xkb_state, u32) -> u32;
+type xkb_state_key_get_utf32FuncType = extern "C" fn(*mut xkb_state, u32) -> u32;
+type xkb_state_key_get_layoutFuncType = extern "C" fn(*mut xkb_state, u32) -> u32;
+
+// Loads a function (or data symbol) from a dynamically loaded library
+fn load_symbol<T>(library: &Library, symbol: &str) -> Result<T, LinuxStartupError> {
+    use self::LinuxStartupError::Create;
+    use self::LinuxWindowCreateError::Wayland;
+
+    match library.get(symbol) {
+        Some(ptr) if !ptr.is_null() => Ok(unsafe { mem::transmute_copy(&ptr) }),
+        _ => Err(Create(Wayland(format!(
+            "{}: no symbol {}",
+            library, symbol
+        )))),
+    }
+}
+
+struct WaylandClient {
+    pub library: Library,
+    pub wl_display_connect: wl_display_connectFuncType,
+    pub wl_display_disconnect: wl_display_disconnectFuncType,
+    pub wl_display_get_fd: wl_display_get_fdFuncType,
+    pub wl_display_roundtrip: wl_display_roundtripFuncType,
+    pub wl_display_dispatch_pending: wl_display_dispatch_pendingFuncType,
+    pub wl_display_prepare_read: wl_display_prepare_readFuncType,
+    pub wl_display_read_events: wl_display_read_eventsFuncType,
+    pub wl_display_cancel_read: wl_display_cancel_readFuncType,
+    pub wl_display_flush: wl_display_flushFuncType,
+    pub wl_proxy_marshal: wl_proxy_marshalFuncType,
+    pub wl_proxy_marshal_constructor: wl_proxy_marshal_constructorFuncType,
+    pub wl_proxy_marshal_constructor_versioned: wl_proxy_marshal_constructor_versionedFuncType,
+    pub wl_proxy_add_listener: wl_proxy_add_listenerFuncType,
+    pub wl_proxy_destroy: wl_proxy_destroyFuncType,
+    pub wl_registry_interface: *const wl_interface,
+    pub wl_compositor_interface: *const wl_interface,
+    pub wl_surface_interface: *const wl_interface,
+    pub wl_seat_interface: *const wl_interface,
+    pub wl_pointer_interface: *const wl_interface,
+    pub wl_keyboard_interface: *const wl_interface,
+    pub wl_output_interface: *const wl_interface,
+    pub wl_shm_interface: *const wl_interface,
+    pub wl_shm_pool_interface: *const wl_interface,
+    pub wl_buffer_interface: *const wl_interface,
+    pub wl_region_interface: *const wl_interface,
+}
+
+impl WaylandClient {
+    fn new() -> Result<Self, LinuxStartupError> {
+        use self::LinuxStartupError::Create;
+        use self::LinuxWindowCreateError::Wayland;
+
+        let library = Library::load("libwayland-client.so.0")
+            .map_err(|e| Create(Wayland(format!("Could not load libwayland-client: {}", e))))?;
+
+        Ok(Self {
+            wl_display_connect: load_symbol(&library, "wl_display_connect")?,
+            wl_display_disconnect: load_symbol(&library, "wl_display_disconnect")?,
+            wl_display_get_fd: load_symbol(&library, "wl_display_get_fd")?,
+            wl_display_roundtrip: load_symbol(&library, "wl_display_roundtrip")?,
+            wl_display_dispatch_pending: load_symbol(&library, "wl_display_dispatch_pending")?,
+            wl_display_prepare_read: load_symbol(&library, "wl_display_prepare_read")?,
+            wl_display_read_events: load_symbol(&library, "wl_display_read_events")?,
+            wl_display_cancel_read: load_symbol(&library, "wl_display_cancel_read")?,
+            wl_display_flush: load_symbol(&library, "wl_display_flush")?,
+            wl_proxy_marshal: load_symbol(&library, "wl_proxy_marshal")?,
+            wl_proxy_marshal_constructor: load_symbol(&library, "wl_proxy_marshal_constructor")?,
+            wl_proxy_marshal_constructor_versioned: load_symbol(
+                &library,
+                "wl_proxy_marshal_constructor_versioned",
+            )?,
+            wl_proxy_add_listener: load_symbol(&library, "wl_proxy_add_listener")?,
+            wl_proxy_destroy: load_symbol(&library, "wl_proxy_destroy")?,
+            wl_registry_interface: load_symbol(&library, "wl_registry_interface")?,
+            wl_compositor_interface: load_symbol(&library, "wl_compositor_interface")?,
+            wl_surface_interface: load_symbol(&library, "wl_surface_interface")?,
+            wl_seat_interface: load_symbol(&library, "wl_seat_interface")?,
+            wl_pointer_interface: load_symbol(&library, "wl_pointer_interface")?,
+            wl_keyboard_interface: load_symbol(&library, "wl_keyboard_interface")?,
+            wl_output_interface: load_symbol(&library, "wl_output_interface")?,
+            wl_shm_interface: load_symbol(&library, "wl_shm_interface")?,
+            wl_shm_pool_interface: load_symbol(&library, "wl_shm_pool_interface")?,
+            wl_buffer_interface: load_symbol(&library, "wl_buffer_interface")?,
+            wl_region_interface: load_symbol(&library, "wl_region_interface")?,
+            library,
+        })
+    }
+
+    // Binds a global announced by the wl_registry
+    fn bind(
+        &self,
+        registry: *mut wl_proxy,
+        name: u32,
+        interface: *const wl_interface,
+        version: u32,
+    ) -> *mut wl_proxy {
+        unsafe {
+            (self.wl_proxy_marshal_constructor_versioned)(
+                registry,
+                WL_REGISTRY_BIND,
+                interface,
+                version,
+                name,
+                (*interface).name,
+                version,
+                ptr::null_mut::<c_void>(),
+            )
+        }
+    }
+
+    fn add_listener<T>(&self, proxy: *mut wl_proxy, listener: &'static T, data: *mut WaylandApp) {
+        let _ = (self.wl_proxy_add_listener)(
+            proxy,
+            listener as *const T as *const c_void,
+            data as *mut c_void,
+        );
+    }
+}
+
+struct WaylandEgl {
+    pub library: Library,
+    pub wl_egl_window_create: wl_egl_window_createFuncType,
+    pub wl_egl_window_resize: wl_egl_window_resizeFuncType,
+    pub wl_egl_window_destroy: wl_egl_window_destroyFuncType,
+}
+
+impl WaylandEgl {
+    fn new() -> Result<Self, LinuxStartupError> {
+        use self::LinuxStartupError::Create;
+        use self::LinuxWindowCreateError::Wayland;
+
+        let library = Library::load("libwayland-egl.so.1")
+            .map_err(|e| Create(Wayland(format!("Could not load libwayland-egl: {}", e))))?;
+
+        Ok(Self {
+            wl_egl_window_create: load_symbol(&library, "wl_egl_window_create")?,
+            wl_egl_window_resize: load_symbol(&library, "wl_egl_window_resize")?,
+            wl_egl_window_destroy: load_symbol(&library, "wl_egl_window_destroy")?,
+            library,
+        })
+    }
+}
+
+struct WaylandCursor {
+    pub library: Library,
+    pub wl_cursor_theme_load: wl_cursor_theme_loadFuncType,
+    pub wl_cursor_theme_destroy: wl_cursor_theme_destroyFuncType,
+    pub wl_cursor_theme_get_cursor: wl_cursor_theme_get_cursorFuncType,
+    pub wl_cursor_image_get_buffer: wl_cursor_image_get_bufferFuncType,
+}
+
+impl WaylandCursor {
+    fn new() -> Result<Self, LinuxStartupError> {
+        use self::LinuxStartupError::Create;
+        use self::LinuxWindowCreateError::Wayland;
+
+        let library = Library::load("libwayland-cursor.so.0")
+            .map_err(|e| Create(Wayland(format!("Could not load libwayland-cursor: {}", e))))?;
+
+        Ok(Self {
+            wl_cursor_theme_load: load_symbol(&library, "wl_cursor_theme_load")?,
+            wl_cursor_theme_destroy: load_symbol(&library, "wl_cursor_theme_destroy")?,
+            wl_cursor_theme_get_cursor: load_symbol(&library, "wl_cursor_theme_get_cursor")?,
+            wl_cursor_image_get_buffer: load_symbol(&library, "wl_cursor_image_get_buffer")?,
+            library,
+        })
+    }
+}
+
+struct Xkb {
+    pub library: Library,
+    pub xkb_context_new: xkb_context_newFuncType,
+    pub xkb_context_unref: xkb_context_unrefFuncType,
+    pub xkb_keymap_new_from_string: xkb_keymap_new_from_stringFuncType,
+    pub xkb_keymap_unref: xkb_keymap_unrefFuncType,
+    pub xkb_keymap_key_get_syms_by_level: xkb_keymap_key_get_syms_by_levelFuncType,
+    pub xkb_keymap_key_repeats: xkb_keymap_key_repeatsFuncType,
+    pub xkb_state_new: xkb_state_newFuncType,
+    pub xkb_state_unref: xkb_state_unrefFuncType,
+    pub xkb_state_update_mask: xkb_state_update_maskFuncType,
+    pub xkb_state_key_get_one_sym: xkb_state_key_get_one_symFuncType,
+    pub xkb_state_key_get_utf32: xkb_state_key_get_utf32FuncType,
+    pub xkb_state_key_get_layout: xkb_state_key_get_layoutFuncType,
+}
+
+impl Xkb {
+    fn new() -> Result<Self, LinuxStartupError> {
+        use self::LinuxStartupError::Create;
+        use self::LinuxWindowCreateError::Wayland;
+
+        let library = Library::load("libxkbcommon.so.0")
+            .map_err(|e| Create(Wayland(format!("Could not load libxkbcommon: {}", e))))?;
+
+        Ok(Self {
+            xkb_context_new: load_symbol(&library, "xkb_context_new")?,
+            xkb_context_unref: load_symbol(&library, "xkb_context_unref")?,
+            xkb_keymap_new_from_string: load_symbol(&library, "xkb_keymap_new_from_string")?,
+            xkb_keymap_unref: load_symbol(&library, "xkb_keymap_unref")?,
+            xkb_keymap_key_get_syms_by_level: load_symbol(
+                &library,
+                "xkb_keymap_key_get_syms_by_level",
+            )?,
+            xkb_keymap_key_repeats: load_symbol(&library, "xkb_keymap_key_repeats")?,
+            xkb_state_new: load_symbol(&library, "xkb_state_new")?,
+            xkb_state_unref: load_symbol(&library, "xkb_state_unref")?,
+            xkb_state_update_mask: load_symbol(&library, "xkb_state_update_mask")?,
+            xkb_state_key_get_one_sym: load_symbol(&library, "xkb_state_key_get_one_sym")?,
+            xkb_state_key_get_utf32: load_symbol(&library, "xkb_state_key_get_utf32")?,
+            xkb_state_key_get_layout: load_symbol(&library, "xkb_state_key_get_layout")?,
+            library,
+        })
+    }
+}
+
+// -- listeners
+
+#[repr(C)]
+struct wl_registry_listener {
+    global: extern "C" fn(*mut c_void, *mut wl_proxy, u32, *const c_char, u32),
+    global_remove: extern "C" fn(*mut c_void, *mut wl_proxy, u32),
+}
+
+#[repr(C)]
+struct wl_seat_listener {
+    capabilities: extern "C" fn(*mut c_void, *mut wl_proxy, u32),
+    name: extern "C" fn(*mut c_void, *mut wl_proxy, *const c_char),
+}
+
+#[repr(C)]
+struct wl_pointer_listener {
+    enter: extern "C" fn(*mut c_void, *mut wl_proxy, u32, *mut wl_proxy, wl_fixed, wl_fixed),
+    leave: extern "C" fn(*mut c_void, *mut wl_proxy, u32, *mut wl_proxy),
+    motion: extern "C" fn(*mut c_void, *mut wl_proxy, u32, wl_fixed, wl_fixed),
+    button: extern "C" fn(*mut c_void, *mut wl_proxy, u32, u32, u32, u32),
+    axis: extern "C" fn(*mut c_void, *mut wl_proxy, u32, u32, wl_fixed),
+    frame: extern "C" fn(*mut c_void, *mut wl_proxy),
+    axis_source: extern "C" fn(*mut c_void, *mut wl_proxy, u32),
+    axis_stop: extern "C" fn(*mut c_void, *mut wl_proxy, u32, u32),
+    axis_discrete: extern "C" fn(*mut c_void, *mut wl_proxy, u32, i32),
+}
+
+#[repr(C)]
+struct wl_keyboard_listener {
+    keymap: extern "C" fn(*mut c_void, *mut wl_proxy, u32, i32, u32),
+    enter: extern "C" fn(*mut c_void, *mut wl_proxy, u32, *mut wl_proxy, *mut wl_array),
+    leave: extern "C" fn(*mut c_void, *mut wl_proxy, u32, *mut wl_proxy),
+    key: extern "C" fn(*mut c_void, *mut wl_proxy, u32, u32, u32, u32),
+    modifiers: extern "C" fn(*mut c_void, *mut wl_proxy, u32, u32, u32, u32, u32),
+    repeat_info: extern "C" fn(*mut c_void, *mut wl_proxy, i32, i32),
+}
+
+#[repr(C)]
+struct wl_output_listener {
+    geometry: extern "C" fn(
+        *mut c_void,
+        *mut wl_proxy,
+        i32,
+        i32,
+        i32,
+        i32,
+        i32,
+        *const c_char,
+        *const c_char,
+        i32,
+    ),
+    mode: extern "C" fn(*mut c_void, *mut wl_proxy, u32, i32, i32, i32),
+    done: extern "C" fn(*mut c_void, *mut wl_proxy),
+    scale: extern "C" fn(*mut c_void, *mut wl_proxy, i32),
+}
+
+#[repr(C)]
+struct wl_surface_listener {
+    enter: extern "C" fn(*mut c_void, *mut wl_proxy, *mut wl_proxy),
+    leave: extern "C" fn(*mut c_void, *mut wl_proxy, *mut wl_proxy),
+}
+
+#[repr(C)]
+struct xdg_wm_base_listener {
+    ping: extern "C" fn(*mut c_void, *mut wl_proxy, u32),
+}
+
+#[repr(C)]
+struct xdg_surface_listener {
+    configure: extern "C" fn(*mut c_void, *mut wl_proxy, u32),
+}
+
+#[repr(C)]
+struct xdg_toplevel_listener {
+    configure: extern "C" fn(*mut c_void, *mut wl_proxy, i32, i32, *mut wl_array),
+    close: extern "C" fn(*mut c_void, *mut wl_proxy),
+}
+
+#[repr(C)]
+struct wp_fractional_scale_v1_listener {
+    preferred_scale: extern "C" fn(*mut c_void, *mut wl_proxy, u32),
+}
+
+static REGISTRY_LISTENER: wl_registry_listener = wl_registry_listener {
+    global: registry_global,
+    global_remove: registry_global_remove,
+};
+
+static SEAT_LISTENER: wl_seat_listener = wl_seat_listener {
+    capabilities: seat_capabilities,
+    name: seat_name,
+};
+
+static POINTER_LISTENER: wl_pointer_listener = wl_pointer_listener {
+    enter: pointer_enter,
+    leave: pointer_leave,
+    motion: pointer_motion,
+    button: pointer_button,
+    axis: pointer_axis,
+    frame: pointer_frame,
+    axis_source: pointer_axis_source,
+    axis_stop: pointer_axis_stop,
+    axis_discrete: pointer_axis_discrete,
+};
+
+static KEYBOARD_LISTENER: wl_keyboard_listener = wl_keyboard_listener {
+    keymap: keyboard_keymap,
+    enter: keyboard_enter,
+    leave: keyboard_leave,
+    key: keyboard_key,
+    modifiers: keyboard_modifiers,
+    repeat_info: keyboard_repeat_info,
+};
+
+static OUTPUT_LISTENER: wl_output_listener = wl_output_listener {
+    geometry: output_geometry,
+    mode: output_mode,
+    done: output_done,
+    scale: output_scale,
+};
+
+static SURFACE_LISTENER: wl_surface_listener = wl_surface_listener {
+    enter: surface_enter,
+    leave: surface_leave,
+};
+
+static XDG_WM_BASE_LISTENER: xdg_wm_base_listener = xdg_wm_base_listener {
+    ping: xdg_wm_base_ping,
+};
+
+static XDG_SURFACE_LISTENER: xdg_surface_listener = xdg_surface_listener {
+    configure: xdg_surface_configure,
+};
+
+static XDG_TOPLEVEL_LISTENER: xdg_toplevel_listener = xdg_toplevel_listener {
+    configure: xdg_toplevel_configure,
+    close: xdg_toplevel_close,
+};
+
+static FRACTIONAL_SCALE_LISTENER: wp_fractional_scale_v1_listener =
+    wp_fractional_scale_v1_listener {
+        preferred_scale: fractional_scale_preferred_scale,
+    };
+
+pub fn run(app: App, mut root_window: WindowCreateOptions) -> Result<isize, LinuxStartupError> {
+    use self::LinuxStartupError::Create;
+    use self::LinuxWindowCreateError::{Egl as EglError, Wayland};
+
+    let App {
+        data,
+        config,
+        mut windows,
+        image_cache,
+        fc_cache,
+        shortcuts,
+    } = app;
+
+    let wl = Rc::new(WaylandClient::new()?);
+    let wl_egl = Rc::new(WaylandEgl::new()?);
+    let egl = Rc::new(Egl::new()?);
+
+    // cursors and keyboard input are optional
+    let wl_cursor = WaylandCursor::new().ok();
+    let xkb = Xkb::new().ok();
+
+    let display = (wl.wl_display_connect)(ptr::null());
+    if display.is_null() {
+        return Err(Create(Wayland(format!(
+            "Wayland: wl_display_connect() failed"
+        ))));
+    }
+
+    let egl_display = (egl.eglGetDisplay)(display as *mut c_void);
+    if egl_display == EGL_NO_DISPLAY {
+        return Err(Create(EglError(format!(
+            "EGL: eglGetDisplay(): no display"
+        ))));
+    }
+
+    let mut major = 0;
+    let mut minor = 0;
+
+    let init_result = (egl.eglInitialize)(egl_display, &mut major, &mut minor);
+    if init_result != EGL_TRUE {
+        return Err(Create(EglError(format!(
+            "EGL: eglInitialize(): cannot initialize display: {}",
+            init_result
+        ))));
+    }
+
+    // choose OpenGL API for EGL, by default it uses OpenGL ES
+    let egl_bound = (egl.eglBindAPI)(EGL_OPENGL_API);
+    if egl_bound != EGL_TRUE {
+        return Err(Create(EglError(format!(
+            "EGL: eglBindAPI(): Failed to select OpenGL API for EGL: {}",
+            egl_bound
+        ))));
+    }
+
+    let xkb_context = match xkb.as_ref() {
+        Some(xkb) => (xkb.xkb_context_new)(0),
+        None => ptr::null_mut(),
+    };
+
+    let app_data_inner = Rc::new(RefCell::new(ApplicationData {
+        data,
+        config,
+        image_cache,
+        fc_cache,
+        shortcuts,
+    }));
+
+    // written to by the tray icon / notification threads when a
+    // menu item or a notification was clicked
+    let mut wake_fds: [c_int; 2] = [-1, -1];
+    if unsafe { pipe(wake_fds.as_mut_ptr()) } != 0 {
+        wake_fds = [-1, -1];
+    }
+
+    // The listeners only get a raw pointer to the WaylandApp, so the
+    // main loop may not hold a reference to it while dispatching events
+    let app_ptr = Box::into_raw(Box::new(WaylandApp {
+        wl: wl.clone(),
+        wl_egl,
+        wl_cursor,
+        xkb,
+        egl,
+        display,
+        egl_display,
+        registry: ptr::null_mut(),
+        compositor: ptr::null_mut(),
+        wm_base: ptr::null_mut(),
+        shm: ptr::null_mut(),
+        seat: ptr::null_mut(),
+        viewporter: ptr::null_mut(),
+        fractional_scale_manager: ptr::null_mut(),
+        outputs: Vec::new(),
+        pointer: ptr::null_mut(),
+        keyboard: ptr::null_mut(),
+        pointer_serial: 0,
+        pointer_focus: None,
+        keyboard_focus: None,
+        cursor_theme: ptr::null_mut(),
+        cursor_surface: ptr::null_mut(),
+        current_cursor: None,
+        cursor_image_buffer: None,
+        xkb_context,
+        xkb_keymap: ptr::null_mut(),
+        xkb_state: ptr::null_mut(),
+        pointer_axis_source: 0,
+        key_repeat_rate: 25,
+        key_repeat_delay: 600,
+        key_repeat: None,
+        windows: BTreeMap::new(),
+        app_data: SharedApplicationData {
+            inner: app_data_inner.clone(),
+        },
+        new_windows: Vec::new(),
+        windows_to_close: Vec::new(),
+        regenerate_all_windows: false,
+        wake_fds,
+    }));
+
+    let result = unsafe { run_inner(app_ptr, &mut windows, root_window) };
+
+    // destroy all windows before disconnecting
+    let app = unsafe { Box::from_raw(app_ptr) };
+    mem::drop(app);
+    (wl.wl_display_disconnect)(display);
+
+    result
+}
+
+unsafe fn run_inner(
+    app_ptr: *mut WaylandApp,
+    windows: &mut Vec<WindowCreateOptions>,
+    mut root_window: WindowCreateOptions,
+) -> Result<isize, LinuxStartupError> {
+    use self::LinuxStartupError::Create;
+    use self::LinuxWindowCreateError::Wayland;
+
+    let wl = (*app_ptr).wl.clone();
+    let display = (*app_ptr).display;
+
+    // get the globals, then the events of the globals (seat capabilities, output scales)
+    let registry = (wl.wl_proxy_marshal_constructor)(
+        display as *mut wl_proxy,
+        WL_DISPLAY_GET_REGISTRY,
+        wl.wl_registry_interface,
+        ptr::null_mut::<c_void>(),
+    );
+    (*app_ptr).registry = registry;
+    wl.add_listener(registry, &REGISTRY_LISTENER, app_ptr);
+    (wl.wl_display_roundtrip)(display);
+    (wl.wl_display_roundtrip)(display);
+
+    {
+        let app = &mut *app_ptr;
+        if app.compositor.is_null() {
+            return Err(Create(Wayland(format!(
+                "Wayland: compositor does not support wl_compositor"
+            ))));
+        }
+        if app.wm_base.is_null() {
+            return Err(Create(Wayland(format!(
+                "Wayland: compositor does not support xdg_wm_base"
+            ))));
+        }
+        app.load_cursor_theme();
+
+        for options in windows.iter_mut() {
+            app.create_window(options)?;
+        }
+        app.create_window(&mut root_window)?;
+    }
+
+    loop {
+        {
+            let app = &mut *app_ptr;
+            app.apply_deferred_changes();
+            if app.windows.is_empty() {
+                break;
+            }
+            app.render_windows();
+        }
+
+        // read and dispatch events, see wl_display_prepare_read()
+        while (wl.wl_display_prepare_read)(display) != 0 {
+            if (wl.wl_display_dispatch_pending)(display) < 0 {
+                return Err(Create(Wayland(format!(
+                    "Wayland: lost connection to the compositor"
+                ))));
+            }
+        }
+        let _ = (wl.wl_display_flush)(display);
+
+        let wake_fd = (*app_ptr).wake_fds[0];
+        let mut fds = [
+            pollfd {
+                fd: (wl.wl_display_get_fd)(display),
+                events: POLLIN,
+                revents: 0,
+            },
+            pollfd {
+                fd: wake_fd,
+                events: POLLIN,
+                revents: 0,
+            },
+        ];
+
+        let timeout = (*app_ptr).get_poll_timeout();
+        let nfds = if wake_fd < 0 { 1 } else { 2 };
+        let ready = poll(fds.as_mut_ptr(), nfds, timeout);
+
+        if ready > 0 && (fds[0].revents & POLLIN) != 0 {
+            if (wl.wl_display_read_events)(display) < 0 {
+                return Err(Create(Wayland(format!(
+                    "Wayland: lost connection to the compositor"
+                ))));
+            }
+        } else {
+            (wl.wl_display_cancel_read)(display);
+        }
+
+        if (wl.wl_display_dispatch_pending)(display) < 0 {
+            return Err(Create(Wayland(format!(
+                "Wayland: lost connection to the compositor"
+            ))));
+        }
+
+        (*app_ptr).handle_key_repeat();
+        (*app_ptr).run_threads();
+        (*app_ptr).run_timers();
+
+        if ready > 0 && (fds[1].revents & POLLIN) != 0 {
+            let mut buf = [0_u8; 64];
+            let _ = read(wake_fd, buf.as_mut_ptr() as *mut c_void, buf.len());
+            (*app_ptr).process_menu_callbacks();
+        }
+    }
+
+    Ok(0)
+}
+
+struct WaylandOutput {
+    /// Name of the global in the wl_registry
+    name: u32,
+    proxy: *mut wl_proxy,
+    /// Integer scale factor of the output (wl_output.scale)
+    scale: i32,
+}
+
+/// Cursor that is currently set on the pointer
+#[derive(Debug, Clone, PartialEq)]
+enum WaylandCursor {
+    Named(&'static str),
+    /// Image cursor and the name of the cursor to show if it can't be uploaded
+    Image(CustomCursor, &'static str),
+}
+
+struct KeyRepeat {
+    window_id: usize,
+    keycode: u32,
+    next: Instant,
+}
+
+// State shared by all windows, used as the listener data of all protocol objects
+struct WaylandApp {
+    wl: Rc<WaylandClient>,
+    wl_egl: Rc<WaylandEgl>,
+    wl_cursor: Option<WaylandCursor>,
+    xkb: Option<Xkb>,
+    egl: Rc<Egl>,
+    display: *mut wl_display,
+    egl_display: EGLDisplay,
+    // globals, null if not (yet) announced by the compositor
+    registry: *mut wl_proxy,
+    compositor: *mut wl_proxy,
+    wm_base: *mut wl_proxy,
+    shm: *mut wl_proxy,
+    seat: *mut wl_proxy,
+    viewporter: *mut wl_proxy,
+    fractional_scale_manager: *mut wl_proxy,
+    outputs: Vec<WaylandOutput>,
+    // input devices of the seat
+    pointer: *mut wl_proxy,
+    keyboard: *mut wl_proxy,
+    // serial of the last pointer event, needed for set_cursor, move and resize
+    pointer_serial: u32,
+    // windows that currently have the pointer / keyboard focus
+    pointer_focus: Option<usize>,
+    keyboard_focus: Option<usize>,
+    cursor_theme: *mut wl_cursor_theme,
+    cursor_surface: *mut wl_proxy,
+    current_cursor: Option<WaylandCursor>,
+    // wl_buffer of the last uploaded image cursor
+    cursor_image_buffer: Option<(CustomCursor, *mut wl_proxy)>,
+    xkb_context: *mut xkb_context,
+    xkb_keymap: *mut xkb_keymap,
+    xkb_state: *mut xkb_state,
+    // source of the following axis events (wl_pointer.axis_source)
+    pointer_axis_source: u32,
+    // keys per second and delay in milliseconds (wl_keyboard.repeat_info)
+    key_repeat_rate: i32,
+    key_repeat_delay: i32,
+    key_repeat: Option<KeyRepeat>,
+    // windows, indexed by the address of their wl_surface
+    windows: BTreeMap<usize, WaylandWindow>,
+    app_data: SharedApplicationData,
+    // changes requested by callbacks, applied after the events have been dispatched
+    new_windows: Vec<WindowCreateOptions>,
+    windows_to_close: Vec<usize>,
+    regenerate_all_windows: bool,
+    /// Self-pipe to wake up the main loop from the tray icon / notification threads
+    wake_fds: [c_int; 2],
+}
+
+impl WaylandApp {
+    fn create_window(
+        &mut self,
+        options: &mut WindowCreateOptions,
+    ) -> Result<(), LinuxStartupError> {
+        // size and frame of the window when it was closed in the last session,
+        // clients don't know (and can't set) the position of their windows
+        let restored_state = options
+            .persistence_key
+            .as_ref()
+            .and_then(|key| crate::persistence::load_window_state(key.as_str()));
+        if let Some(restored_state) = restored_state.as_ref() {
+            restored_state.restore_size_and_frame(&mut options.state);
+        }
+
+        let mut window = WaylandWindow::new(self, options)?;
+        window.sync_click_through(self.compositor);
+        let window_id = window.id;
+
+        // child windows stay on top of their parent window (the compositor
+        // decides about the position, so they can't be centered over it)
+        let parent = options
+            .parent
+            .into_option()
+            .and_then(|p| self.windows.values().find(|w| w.internal.id == p));
+        if let Some(parent) = parent {
+            unsafe {
+                (window.wl.wl_proxy_marshal)(
+                    window.xdg_toplevel,
+                    XDG_TOPLEVEL_SET_PARENT,
+                    parent.xdg_toplevel,
+                )
+            };
+        }
+
+        self.windows.insert(window_id, window);
+        self.sync_tray_icon(window_id);
+        Ok(())
+    }
+
+    // Creates / closes / regenerates the windows requested by callbacks
+    fn apply_deferred_changes(&mut self) {
+        // windows closed with CallbackInfo::close_window() and the child windows of closed windows
+        self.add_windows_to_close();
+        self.invoke_window_result_callbacks();
+        self.apply_shortcut_changes();
+
+        if mem::replace(&mut self.regenerate_all_windows, false) {
+            if let Ok(mut app_borrow) = self.app_data.inner.try_borrow_mut() {
+                for window in self.windows.values_mut() {
+                    window.make_current();
+                    window.regenerate_dom(&mut *app_borrow);
+                }
+            }
+        }
+
+        for mut options in mem::replace(&mut self.new_windows, Vec::new()) {
+            let _ = self.create_window(&mut options);
+        }
+
+        for window_id in mem::replace(&mut self.windows_to_close, Vec::new()) {
+            if let Some(window) = self.windows.remove(&window_id) {
+                window.save_persisted_state();
+            }
+            if self.pointer_focus == Some(window_id) {
+                self.pointer_focus = None;
+            }
+            if self.keyboard_focus == Some(window_id) {
+                self.keyboard_focus = None;
+                self.key_repeat = None;
+            }
+        }
+
+        self.update_inspector_windows();
+        self.sync_inspector_highlights();
+    }
+
+    // Adds the windows that were closed with CallbackInfo::close_window()
+    // and the child windows of all closed windows to windows_to_close
+    fn add_windows_to_close(&mut self) {
+        let closed_in_callbacks = self
+            .windows
+            .values_mut()
+            .flat_map(|w| mem::take(&mut w.windows_closed))
+            .collect::<Vec<_>>();
+
+        let windows_to_close = &mut self.windows_to_close;
+        windows_to_close.extend(
+            self.windows
+                .values()
+                .filter(|w| closed_in_callbacks.contains(&w.internal.id))
+                .map(|w| w.id),
+        );
+
+        loop {
+            let closed = self
+                .windows
+                .values()
+                .filter(|w| windows_to_close.contains(&w.id))
+                .map(|w| w.internal.id)
+                .collect::<Vec<_>>();
+
+            let children = self
+                .windows
+                .values()
+                .filter(|w| !windows_to_close.contains(&w.id))
+                .filter(|w| w.parent.map(|p| closed.contains(&p)).unwrap_or(false))
+                .map(|w| w.id)
+                .collect::<Vec<_>>();
+
+            if children.is_empty() {
+                break;
+            }
+
+            windows_to_close.extend(children);
+        }
+    }
+
+    // Applies the keyboard shortcuts that were registered / unregistered with
+    // CallbackInfo::register_shortcut()
+    fn apply_shortcut_changes(&mut self) {
+        let changes = self
+            .windows
+            .values_mut()
+            .flat_map(|w| mem::take(&mut w.shortcuts_changed))
+            .collect::<Vec<_>>();
+
+        if changes.is_empty() {
+            return;
+        }
+
+        // shortcuts that conflict with an already registered shortcut are not registered
+        if let Ok(mut app_borrow) = self.app_data.inner.try_borrow_mut() {
+            let _ = app_borrow.shortcuts.apply_changes(&changes);
+        }
+    }
+
+    // Passes the results of closed child windows to the result_callback of their parent window
+    fn invoke_window_result_callbacks(&mut self) {
+        let mut parents = Vec::new();
+
+        for closed_window_id in self.windows_to_close.clone() {
+            let (parent, result_callback, window_result) =
+                match self.windows.get_mut(&closed_window_id) {
+                    Some(w) => (w.parent, w.result_callback.take(), w.window_result.take()),
+                    None => continue,
+                };
+
+            let (mut result_callback, mut window_result) = match (result_callback, window_result) {
+                (Some(c), Some(r)) => (c, r),
+                _ => continue,
+            };
+
+            let windows_to_close = &self.windows_to_close;
+            let parent_window = match self
+                .windows
+                .values_mut()
+                .find(|w| Some(w.internal.id) == parent && !windows_to_close.contains(&w.id))
+            {
+                Some(s) => s,
+                None => continue,
+            };
+
+            let mut app_borrow = match self.app_data.inner.try_borrow_mut() {
+                Ok(o) => o,
+                Err(_) => continue,
+            };
+            let ab = &mut *app_borrow;
+
+            parent_window.make_current();
+            let result = invoke_window_result_callback(
+                parent_window,
+                &mut result_callback,
+                &mut window_result,
+                &mut ab.fc_cache,
+                &mut ab.image_cache,
+                &ab.config,
+                &mut self.new_windows,
+                &mut self.windows_to_close,
+            );
+            self.regenerate_all_windows |= apply_process_event_result(
+                result,
+                parent_window,
+                ab,
+                &mut self.new_windows,
+                &mut self.windows_to_close,
+            );
+            parents.push(parent_window.id);
+        }
+
+        for window_id in parents {
+            self.sync_window_state(window_id);
+        }
+    }
+
+    // Whether the window has an open modal child window, which blocks its input
+    fn has_modal_child(&self, window_id: usize) -> bool {
+        let id = match self.windows.get(&window_id) {
+            Some(s) => s.internal.id,
+            None => return false,
+        };
+        self.windows
+            .values()
+            .any(|w| w.modal && w.parent == Some(id))
+    }
+
+    // Opens the inspector for the window, if it isn't inspected yet (F12, AppConfig::enable_inspector)
+    #[cfg(feature = "css_parser")]
+    fn open_inspector(&mut self, window_id: usize) {
+        if !self.get_inspector_windows(window_id).is_empty() {
+            return;
+        }
+
+        let window = match self.windows.get_mut(&window_id) {
+            Some(s) => s,
+            None => return,
+        };
+
+        // don't inspect the inspector
+        let layout_callback = &mut window.internal.current_window_state.layout_callback;
+        if crate::inspector::get_inspected_window(layout_callback).is_some() {
+            return;
+        }
+
+        self.new_windows.push(crate::inspector::open_inspector(
+            window_id,
+            &window.internal.layout_results,
+        ));
+    }
+
+    #[cfg(not(feature = "css_parser"))]
+    fn open_inspector(&mut self, _: usize) {}
+
+    // Returns the window ID and the layout callback (holding the shared inspector data)
+    // of all inspector windows that are inspecting the given window
+    #[cfg(feature = "css_parser")]
+    fn get_inspector_windows(&mut self, inspected: usize) -> Vec<(usize, LayoutCallback)> {
+        self.windows
+            .values_mut()
+            .filter_map(|w| {
+                let layout_callback = &mut w.internal.current_window_state.layout_callback;
+                if crate::inspector::get_inspected_window(layout_callback) == Some(inspected) {
+                    Some((w.id, layout_callback.clone()))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    // Updates the snapshot of the inspectors of all windows that regenerated their DOM
+    #[cfg(feature = "css_parser")]
+    fn update_inspector_windows(&mut self) {
+        let regenerated = self
+            .windows
+            .values_mut()
+            .filter(|w| mem::replace(&mut w.dom_regenerated, false))
+            .map(|w| w.id)
+            .collect::<Vec<_>>();
+
+        let app_data = self.app_data.inner.clone();
+        let mut app_borrow = match app_data.try_borrow_mut() {
+            Ok(o) => o,
+            Err(_) => return,
+        };
+
+        for inspected in regenerated {
+            let inspectors = self.get_inspector_windows(inspected);
+            let inspected_window = match self.windows.get(&inspected) {
+                Some(s) => s,
+                None => continue,
+            };
+
+            for (_, layout_callback) in inspectors.iter() {
+                crate::inspector::update_inspector(
+                    &mut layout_callback.clone(),
+                    &inspected_window.internal.layout_results,
+                );
+            }
+
+            for (inspector_id, _) in inspectors {
+                if let Some(inspector_window) = self.windows.get_mut(&inspector_id) {
+                    inspector_window.make_current();
+                    inspector_window.regenerate_dom(&mut *app_borrow);
+                    inspector_window.dom_regenerated = false;
+                }
+            }
+        }
+    }
+
+    #[cfg(not(feature = "css_parser"))]
+    fn update_inspector_windows(&mut self) {}
+
+    // Copies the nodes hovered in the inspector windows to the inspected windows,
+    // the highlight is removed once the inspector window is closed
+    #[cfg(feature = "css_parser")]
+    fn sync_inspector_highlights(&mut self) {
+        let highlights = self
+            .windows
+            .values_mut()
+            .filter_map(|w| {
+                let layout_callback = &mut w.internal.current_window_state.layout_callback;
+                let inspected = crate::inspector::get_inspected_window(layout_callback)?;
+                let node_id = crate::inspector::get_inspector_highlight(layout_callback);
+                Some((inspected, node_id))
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        let app_borrow = match self.app_data.inner.try_borrow() {
+            Ok(o) => o,
+            Err(_) => return,
+        };
+
+        for window in self.windows.values_mut() {
+            let node_id = highlights.get(&window.id).copied().flatten();
+            if window.internal.inspector_highlight != node_id {
+                window.internal.inspector_highlight = node_id;
+                window.make_current();
+                window.regenerate_display_list(&app_borrow.image_cache);
+            }
+        }
+    }
+
+    #[cfg(not(feature = "css_parser"))]
+    fn sync_inspector_highlights(&mut self) {}
+
+    fn render_windows(&mut self) {
+        for window in self.windows.values_mut() {
+            if window.needs_redraw && window.is_configured {
+                window.needs_redraw = false;
+                window.render();
+            }
+        }
+    }
+
+    fn find_window(&self, f: impl Fn(&WaylandWindow) -> bool) -> Option<usize> {
+        self.windows.values().find(|w| f(w)).map(|w| w.id)
+    }
+
+    // Hit-tests and calls the callbacks of the window, assuming that the
+    // previous_window_state and current_window_state are set correctly
+    fn process_window_event(&mut self, window_id: usize, needs_hit_test: bool) {
+        let mut cursor = None;
+
+        {
+            let window = match self.windows.get_mut(&window_id) {
+                Some(s) => s,
+                None => return,
+            };
+
+            window.make_current();
+
+            if needs_hit_test {
+                window.update_hit_test();
+            }
+
+            window.internal.update_drag_state();
+
+            let mut app_borrow = match self.app_data.inner.try_borrow_mut() {
+                Ok(o) => o,
+                Err(_) => return,
+            };
+            let ab = &mut *app_borrow;
+
+            if needs_hit_test {
+                cursor = Some(window.get_cursor(&ab.image_cache));
+            }
+
+            let result = process_event(
+                window,
+                &mut ab.fc_cache,
+                &mut ab.image_cache,
+                &ab.config,
+                &mut self.new_windows,
+                &mut self.windows_to_close,
+            );
+
+            self.regenerate_all_windows |= apply_process_event_result(
+                result,
+                window,
+                ab,
+                &mut self.new_windows,
+                &mut self.windows_to_close,
+            );
+        }
+
+        self.sync_window_state(window_id);
+
+        if let Some(cursor) = cursor {
+            self.set_cursor(cursor);
+        }
+    }
+
+    // Forwards changes of the window state (by callbacks or by the compositor)
+    // to the xdg_toplevel and the client-side decorations
+    fn sync_window_state(&mut self, window_id: usize) {
+        self.sync_tray_icon(window_id);
+
+        let window = match self.windows.get_mut(&window_id) {
+            Some(s) => s,
+            None => return,
+        };
+
+        let state = &mut window.internal.current_window_state;
+
+        if state.title.as_str() != window.applied_title.as_str() {
+            let title = encode_utf8(state.title.as_str());
+            unsafe {
+                (window.wl.wl_proxy_marshal)(
+                    window.xdg_toplevel,
+                    XDG_TOPLEVEL_SET_TITLE,
+                    title.as_ptr(),
+                )
+            };
+            window.applied_title = state.title.as_str().into();
+        }
+
+        if state.flags.frame != window.applied_frame {
+            let toplevel = window.xdg_toplevel;
+            let marshal = window.wl.wl_proxy_marshal;
+            unsafe {
+                match window.applied_frame {
+                    WindowFrame::Maximized => marshal(toplevel, XDG_TOPLEVEL_UNSET_MAXIMIZED),
+                    WindowFrame::Fullscreen => marshal(toplevel, XDG_TOPLEVEL_UNSET_FULLSCREEN),
+                    WindowFrame::Normal | WindowFrame::Minimized => {}
+                }
+                match state.flags.frame {
+                    WindowFrame::Maximized => marshal(toplevel, XDG_TOPLEVEL_SET_MAXIMIZED),
+                    WindowFrame::Fullscreen => marshal(
+                        toplevel,
+                        XDG_TOPLEVEL_SET_FULLSCREEN,
+                        ptr::null_mut::<c_void>(),
+                    ),
+                    WindowFrame::Minimized => marshal(toplevel, XDG_TOPLEVEL_SET_MINIMIZED),
+                    WindowFrame::Normal => {}
+                }
+            }
+            window.applied_frame = state.flags.frame;
+        }
+
+        window.sync_click_through(self.compositor);
+
+        #[cfg(feature = "css_parser")]
+        {
+            let is_maximized = window.is_maximized;
+            let is_active = window.is_activated;
+            let state = &mut window.internal.current_window_state;
+            let theme = &state.platform_specific_options.linux_options.wayland_theme;
+
+            let changed = if !state.flags.has_decorations {
+                let was_decorated = decorations::is_decorated(&mut state.layout_callback);
+                decorations::undecorate(&mut state.layout_callback);
+                was_decorated
+            } else if !decorations::is_decorated(&mut state.layout_callback) {
+                // the layout callback has been replaced by a callback
+                decorations::decorate(
+                    &mut state.layout_callback,
+                    state.title.as_str(),
+                    state.flags.is_resizable,
+                    theme,
+                );
+                let _ = decorations::update_decorations(
+                    &mut state.layout_callback,
+                    state.title.as_str(),
+                    is_active,
+                    is_maximized,
+                    state.flags.is_resizable,
+                    theme,
+                );
+                true
+            } else {
+                decorations::update_decorations(
+                    &mut state.layout_callback,
+                    state.title.as_str(),
+                    is_active,
+                    is_maximized,
+                    state.flags.is_resizable,
+                    theme,
+                )
+            };
+
+            if changed {
+                if let Ok(mut app_borrow) = self.app_data.inner.try_borrow_mut() {
+                    window.make_current();
+                    window.regenerate_dom(&mut *app_borrow);
+                }
+            }
+        }
+    }
+
+    // Creates, updates or removes the tray icon of the window
+    fn sync_tray_icon(&mut self, window_id: usize) {
+        let window = match self.windows.get_mut(&window_id) {
+            Some(s) => s,
+            None => return,
+        };
+
+        let tray_icon = window
+            .internal
+            .current_window_state
+            .platform_specific_options
+            .linux_options
+            .tray_icon
+            .as_ref();
+
+        let hash = tray_icon.map(|t| t.get_hash());
+        if hash == window.applied_tray_icon {
+            return;
+        }
+
+        match (tray_icon, window.tray.as_mut()) {
+            (None, _) => window.tray = None,
+            (Some(t), Some(tray)) => tray.update(t),
+            (Some(t), None) => {
+                // there is no XEmbed on Wayland, only StatusNotifierItem
+                if window.wake_fd >= 0 {
+                    let waker = tray::TrayWaker::Pipe(window.wake_fd);
+                    window.tray = tray::SystemTray::new(t, waker, None);
+                }
+            }
+        }
+
+        window.applied_tray_icon = hash;
+    }
+
+    // Invokes the callbacks of the tray menu items and
+    // notifications that were clicked since the last wakeup
+    fn process_menu_callbacks(&mut self) {
+        let window_ids = self.windows.keys().copied().collect::<Vec<_>>();
+
+        for window_id in window_ids {
+            {
+                let window = match self.windows.get_mut(&window_id) {
+                    Some(s) => s,
+                    None => continue,
+                };
+
+                let clicked_tray_items = window
+                    .tray
+                    .as_mut()
+                    .map(|t| t.take_clicked_items())
+                    .unwrap_or_default();
+
+                let notification_callbacks = window
+                    .notifications
+                    .as_mut()
+                    .map(|n| n.take_callbacks())
+                    .unwrap_or_default();
+
+                let menu_callbacks = clicked_tray_items
+                    .into_iter()
+                    .filter_map(|id| window.tray.as_mut()?.get_menu_callback(id).cloned())
+                    .chain(notification_callbacks.into_iter())
+                    .collect::<Vec<_>>();
+
+                if menu_callbacks.is_empty() {
+                    continue;
+                }
+
+                window.make_current();
+
+                let mut app_borrow = match self.app_data.inner.try_borrow_mut() {
+                    Ok(o) => o,
+                    Err(_) => return,
+                };
+                let ab = &mut *app_borrow;
+
+                for menu_callback in menu_callbacks {
+                    let result = invoke_menu_callback(
+                        window,
+                        menu_callback,
+                        &mut ab.fc_cache,
+                        &mut ab.image_cache,
+                        &ab.config,
+                        &mut self.new_windows,
+                        &mut self.windows_to_close,
+                    );
+
+                    self.regenerate_all_windows |= apply_process_event_result(
+                        result,
+                        window,
+                        ab,
+                        &mut self.new_windows,
+                        &mut self.windows_to_close,
+                    );
+                }
+            }
+
+            self.sync_window_state(window_id);
+        }
+    }
+
+    // Invokes the callback of the menu bar item whose accelerator is pressed
+    // or of the keyboard shortcut of the application (App::register_shortcut)
+    // TODO: the menu bar and popup menus (xdg_popup) are not rendered on Wayland yet,
+    // only the keyboard shortcuts of the menu bar work
+    // TODO: global shortcuts (ShortcutScope::Global) can't be grabbed on Wayland,
+    // they would need the GlobalShortcuts portal
+    fn invoke_menu_bar_accelerator(&mut self, window_id: usize) {
+        {
+            let window = match self.windows.get_mut(&window_id) {
+                Some(s) => s,
+                None => return,
+            };
+
+            let mut app_borrow = match self.app_data.inner.try_borrow_mut() {
+                Ok(o) => o,
+                Err(_) => return,
+            };
+            let ab = &mut *app_borrow;
+
+            let keyboard_state = &window.internal.current_window_state.keyboard_state;
+            let menu_callback = match window
+                .internal
+                .get_menu_bar()
+                .and_then(|m| m.get_accelerator_callback(keyboard_state))
+                .or_else(|| {
+                    ab.shortcuts
+                        .handle_key_down(window.internal.id, keyboard_state)
+                }) {
+                Some(s) => s.clone(),
+                None => return,
+            };
+
+            window.make_current();
+
+            let result = invoke_menu_callback(
+                window,
+                menu_callback,
+                &mut ab.fc_cache,
+                &mut ab.image_cache,
+                &ab.config,
+                &mut self.new_windows,
+                &mut self.windows_to_close,
+            );
+
+            self.regenerate_all_windows |= apply_process_event_result(
+                result,
+                window,
+                ab,
+                &mut self.new_windows,
+                &mut self.windows_to_close,
+            );
+        }
+
+        self.sync_window_state(window_id);
+    }
+
+    // Resizes the window to the given logical size, using the current scale of the window
+    fn resize_window(&mut self, window_id: usize, size: LogicalSize) {
+        let outputs = &self.outputs;
+        let window = match self.windows.get_mut(&window_id) {
+            Some(s) => s,
+            None => return,
+        };
+
+        // fractional scale if the compositor supports wp_fractional_scale_v1,
+        // otherwise the highest integer scale of all outputs the window is on
+        let scale = window.preferred_scale.unwrap_or_else(|| {
+            outputs
+                .iter()
+                .filter(|o| window.entered_outputs.contains(&o.proxy))
+                .map(|o| o.scale)
+                .max()
+                .unwrap_or(1) as f32
+        });
+
+        if let Ok(mut app_borrow) = self.app_data.inner.try_borrow_mut() {
+            window.resize(&mut *app_borrow, size, scale);
+        }
+    }
+
+    fn rescale_window(&mut self, window_id: usize) {
+        let size = match self.windows.get(&window_id) {
+            Some(s) => s.internal.current_window_state.size.dimensions,
+            None => return,
+        };
+        self.resize_window(window_id, size);
+    }
+
+    fn load_cursor_theme(&mut self) {
+        let wl_cursor = match self.wl_cursor.as_ref() {
+            Some(s) => s,
+            None => return,
+        };
+
+        if self.shm.is_null() {
+            return;
+        }
+
+        let theme_name = std::env::var("XCURSOR_THEME")
+            .ok()
+            .and_then(|s| CString::new(s).ok());
+        let size = std::env::var("XCURSOR_SIZE")
+            .ok()
+            .and_then(|s| s.parse::<c_int>().ok())
+            .unwrap_or(24);
+
+        self.cursor_theme = (wl_cursor.wl_cursor_theme_load)(
+            theme_name
+                .as_ref()
+                .map(|s| s.as_ptr())
+                .unwrap_or(ptr::null()),
+            size,
+            self.shm,
+        );
+
+        self.cursor_surface = unsafe {
+            (self.wl.wl_proxy_marshal_constructor)(
+                self.compositor,
+                WL_COMPOSITOR_CREATE_SURFACE,
+                self.wl.wl_surface_interface,
+                ptr::null_mut::<c_void>(),
+            )
+        };
+    }
+
+    // Sets the cursor of the pointer: the image cursor if it could be
+    // uploaded, otherwise the named cursor of the XCursor theme
+    fn set_cursor(&mut self, cursor: WaylandCursor) {
+        if self.current_cursor.as_ref() == Some(&cursor) {
+            return;
+        }
+
+        let name = match &cursor {
+            WaylandCursor::Named(name) => *name,
+            WaylandCursor::Image(image, fallback) => {
+                if self.set_image_cursor(image) {
+                    self.current_cursor = Some(cursor);
+                    return;
+                }
+                *fallback
+            }
+        };
+
+        if self.current_cursor == Some(WaylandCursor::Named(name)) {
+            return;
+        }
+
+        let wl_cursor = match self.wl_cursor.as_ref() {
+            Some(s) => s,
+            None => return,
+        };
+
+        if self.pointer.is_null() || self.cursor_theme.is_null() || self.cursor_surface.is_null() {
+            return;
+        }
+
+        let cursor_name = match CString::new(name) {
+            Ok(o) => o,
+            Err(_) => return,
+        };
+
+        let mut cursor =
+            (wl_cursor.wl_cursor_theme_get_cursor)(self.cursor_theme, cursor_name.as_ptr());
+        if cursor.is_null() {
+            cursor = (wl_cursor.wl_cursor_theme_get_cursor)(
+                self.cursor_theme,
+                "left_ptr\0".as_ptr() as *const c_char,
+            );
+        }
+        if cursor.is_null() {
+            return;
+        }
+
+        unsafe {
+            if (*cursor).image_count == 0 {
+                return;
+            }
+
+            let image = *(*cursor).images;
+            let buffer = (wl_cursor.wl_cursor_image_get_buffer)(image);
+            if buffer.is_null() {
+                return;
+            }
+
+            (self.wl.wl_proxy_marshal)(
+                self.pointer,
+                WL_POINTER_SET_CURSOR,
+                self.pointer_serial,
+                self.cursor_surface,
+                (*image).hotspot_x as i32,
+                (*image).hotspot_y as i32,
+            );
+            (self.wl.wl_proxy_marshal)(self.cursor_surface, WL_SURFACE_ATTACH, buffer, 0, 0);
+            (self.wl.wl_proxy_marshal)(
+                self.cursor_surface,
+                WL_SURFACE_DAMAGE,
+                0,
+                0,
+                (*image).width as i32,
+                (*image).height as i32,
+            );
+            (self.wl.wl_proxy_marshal)(self.cursor_surface, WL_SURFACE_COMMIT);
+        }
+
+        self.current_cursor = Some(WaylandCursor::Named(name));
+    }
+
+    // Uploads the image of the cursor into a wl_shm buffer and attaches it to
+    // the cursor surface, returns false if the image could not be uploaded
+    fn set_image_cursor(&mut self, image: &CustomCursor) -> bool {
+        if self.pointer.is_null() || self.cursor_surface.is_null() {
+            return false;
+        }
+
+        let (width, height, pixels) = match cursor::get_argb_pixels(image) {
+            Some(s) => s,
+            None => return false,
+        };
+
+        let is_cached = self
+            .cursor_image_buffer
+            .as_ref()
+            .map(|(cached, _)| cached == image)
+            .unwrap_or(false);
+
+        let old_buffer = if is_cached {
+            None
+        } else {
+            let buffer = match self.create_shm_buffer(width, height, &pixels) {
+                Some(s) => s,
+                None => return false,
+            };
+            self.cursor_image_buffer
+                .replace((image.clone(), buffer))
+                .map(|(_, old)| old)
+        };
+
+        let buffer = match self.cursor_image_buffer.as_ref() {
+            Some((_, buffer)) => *buffer,
+            None => return false,
+        };
+
+        // the hotspot has to be inside of the image
+        let hotspot_x = image.hotspot_x.min(width as u32 - 1) as i32;
+        let hotspot_y = image.hotspot_y.min(height as u32 - 1) as i32;
+
+        unsafe {
+            (self.wl.wl_proxy_marshal)(
+                self.pointer,
+                WL_POINTER_SET_CURSOR,
+                self.pointer_serial,
+                self.cursor_surface,
+                hotspot_x,
+                hotspot_y,
+            );
+            (self.wl.wl_proxy_marshal)(self.cursor_surface, WL_SURFACE_ATTACH, buffer, 0, 0);
+            (self.wl.wl_proxy_marshal)(
+                self.cursor_surface,
+                WL_SURFACE_DAMAGE,
+                0,
+                0,
+                width as i32,
+                height as i32,
+            );
+            (self.wl.wl_proxy_marshal)(self.cursor_surface, WL_SURFACE_COMMIT);
+
+            // the previous image is no longer attached to the cursor surface
+            if let Some(old) = old_buffer {
+                (self.wl.wl_proxy_marshal)(old, WL_BUFFER_DESTROY);
+                (self.wl.wl_proxy_destroy)(old);
+            }
+        }
+
+        true
+    }
+
+    // Creates a wl_buffer (ARGB8888, premultiplied) backed by a memfd
+    fn create_shm_buffer(
+        &self,
+        width: usize,
+        height: usize,
+        pixels: &[u32],
+    ) -> Option<*mut wl_proxy> {
+        if self.shm.is_null() {
+            return None;
+        }
+
+        let stride = width * 4;
+        let size = stride * height;
+
+        unsafe {
+            let fd = memfd_create("azul-cursor\0".as_ptr() as *const c_char, MFD_CLOEXEC);
+            if fd < 0 {
+                return None;
+            }
+
+            if ftruncate(fd, size as i64) != 0 {
+                close(fd);
+                return None;
+            }
+
+            let data = mmap(
+                ptr::null_mut(),
+                size,
+                PROT_READ | PROT_WRITE,
+                MAP_SHARED,
+                fd,
+                0,
+            );
+            if data == MAP_FAILED {
+                close(fd);
+                return None;
+            }
+            ptr::copy_nonoverlapping(pixels.as_ptr() as *const u8, data as *mut u8, size);
+            munmap(data, size);
+
+            let pool = (self.wl.wl_proxy_marshal_constructor)(
+                self.shm,
+                WL_SHM_CREATE_POOL,
+                self.wl.wl_shm_pool_interface,
+                ptr::null_mut::<c_void>(),
+                fd,
+                size as i32,
+            );
+            // the compositor has its own copy of the file descriptor
+            close(fd);
+            if pool.is_null() {
+                return None;
+            }
+
+            let buffer = (self.wl.wl_proxy_marshal_constructor)(
+                pool,
+                WL_SHM_POOL_CREATE_BUFFER,
+                self.wl.wl_buffer_interface,
+                ptr::null_mut::<c_void>(),
+                0 as i32,
+                width as i32,
+                height as i32,
+                stride as i32,
+                WL_SHM_FORMAT_ARGB8888,
+            );
+
+            // the buffer keeps the memory of the pool alive
+            (self.wl.wl_proxy_marshal)(pool, WL_SHM_POOL_DESTROY);
+            (self.wl.wl_proxy_destroy)(pool);
+
+            if buffer.is_null() {
+                None
+            } else {
+                Some(buffer)
+            }
+        }
+    }
+
+    fn handle_key(&mut self, window_id: usize, keycode: u32, is_down: bool) {
+        let (vk, character) = self.translate_key(keycode);
+
+        {
+            let window = match self.windows.get_mut(&window_id) {
+                Some(s) => s,
+                None => return,
+            };
+
+            let scancode = keycode as ScanCode;
+            window.internal.previous_window_state =
+                Some(window.internal.current_window_state.clone());
+            let keyboard_state = &mut window.internal.current_window_state.keyboard_state;
+            keyboard_state.current_char = None.into();
+
+            if is_down {
+                keyboard_state.pressed_scancodes.insert_hm_item(scancode);
+                if let Some(vk) = vk {
+                    keyboard_state.current_virtual_keycode = Some(vk).into();
+                    keyboard_state.pressed_virtual_keycodes.insert_hm_item(vk);
+                }
+            } else {
+                keyboard_state.pressed_scancodes.remove_hm_item(&scancode);
+                if let Some(vk) = vk {
+                    keyboard_state.pressed_virtual_keycodes.remove_hm_item(&vk);
+                    keyboard_state.current_virtual_keycode = None.into();
+                }
+            }
+        }
+
+        self.process_window_event(window_id, false);
+
+        let open_inspector = is_down
+            && vk == Some(VirtualKeyCode::F12)
+            && self
+                .app_data
+                .inner
+                .try_borrow()
+                .map(|a| a.config.enable_inspector)
+                .unwrap_or(false);
+
+        if open_inspector {
+            self.open_inspector(window_id);
+        } else if is_down {
+            self.invoke_menu_bar_accelerator(window_id);
+        }
+
+        // text input is a separate event after the key down (like WM_KEYDOWN + WM_CHAR)
+        let character = match character {
+            Some(c) if is_down => c,
+            _ => return,
+        };
+
+        {
+            let window = match self.windows.get_mut(&window_id) {
+                Some(s) => s,
+                None => return,
+            };
+            window.internal.previous_window_state =
+                Some(window.internal.current_window_state.clone());
+            window
+                .internal
+                .current_window_state
+                .keyboard_state
+                .current_char = Some(character as u32).into();
+        }
+
+        self.process_window_event(window_id, false);
+    }
+
+    // Returns the virtual keycode (independent of the modifiers) and the
+    // text input (dependent on the modifiers) of the xkb keycode
+    fn translate_key(
+        &self,
+        keycode: u32,
+    ) -> (Option<azul_core::window::VirtualKeyCode>, Option<char>) {
+        let xkb = match self.xkb.as_ref() {
+            Some(s) => s,
+            None => return (None, None),
+        };
+
+        if self.xkb_state.is_null() || self.xkb_keymap.is_null() {
+            return (None, None);
+        }
+
+        let layout = (xkb.xkb_state_key_get_layout)(self.xkb_state, keycode);
+        let mut syms = ptr::null();
+        let sym_count =
+            (xkb.xkb_keymap_key_get_syms_by_level)(self.xkb_keymap, keycode, layout, 0, &mut syms);
+        let keysym = if sym_count > 0 && !syms.is_null() {
+            unsafe { *syms }
+        } else {
+            (xkb.xkb_state_key_get_one_sym)(self.xkb_state, keycode)
+        };
+
+        let vk = event::keysym_to_virtual_keycode(keysym as event::KeySym);
+        let character =
+            core::char::from_u32((xkb.xkb_state_key_get_utf32)(self.xkb_state, keycode))
+                .filter(|c| *c != '\0' && !c.is_control());
+
+        (vk, character)
+    }
+
+    fn get_key_repeat_timeout(&self) -> c_int {
+        match self.key_repeat.as_ref() {
+            Some(r) => r
+                .next
+                .saturating_duration_since(Instant::now())
+                .as_millis()
+                .min(c_int::max_value() as u128) as c_int,
+            None => -1,
+        }
+    }
+
+    // Wake up for the next key repeat if a key is held down, when the
+    // next timer is due and every 16ms to poll the messages of running threads
+    fn get_poll_timeout(&self) -> c_int {
+        let key_repeat_timeout = match self.get_key_repeat_timeout() {
+            t if t < 0 => None,
+            t => Some(t),
+        };
+
+        let thread_timeout = if self.windows.values().all(|w| w.internal.threads.is_empty()) {
+            None
+        } else {
+            Some(THREAD_POLL_INTERVAL_MS)
+        };
+
+        let timer_timeout = self.app_data.inner.try_borrow().ok().and_then(|ab| {
+            let now = (ab.config.system_callbacks.get_system_time_fn.cb)();
+            self.windows
+                .values()
+                .flat_map(|w| w.internal.timers.values())
+                .map(|t| t.millis_until_next_run(&now))
+                .min()
+                .map(|t| t.min(c_int::max_value() as u64) as c_int)
+        });
+
+        [key_repeat_timeout, thread_timeout, timer_timeout]
+            .iter()
+            .filter_map(|t| *t)
+            .min()
+            .unwrap_or(-1)
+    }
+
+    // Invokes the WriteBack callbacks of the messages sent by running threads
+    fn run_threads(&mut self) {
+        let window_ids = self
+            .windows
+            .iter()
+            .filter(|(_, w)| !w.internal.threads.is_empty())
+            .map(|(id, _)| *id)
+            .collect::<Vec<_>>();
+
+        for window_id in window_ids {
+            {
+                let window = match self.windows.get_mut(&window_id) {
+                    Some(s) => s,
+                    None => continue,
+                };
+
+                window.make_current();
+
+                let mut app_borrow = match self.app_data.inner.try_borrow_mut() {
+                    Ok(o) => o,
+                    Err(_) => return,
+                };
+                let ab = &mut *app_borrow;
+
+                let result = invoke_threads(
+                    window,
+                    &mut ab.data,
+                    &mut ab.fc_cache,
+                    &mut ab.image_cache,
+                    &ab.config,
+                    &mut self.new_windows,
+                    &mut self.windows_to_close,
+                );
+
+                self.regenerate_all_windows |= apply_process_event_result(
+                    result,
+                    window,
+                    ab,
+                    &mut self.new_windows,
+                    &mut self.windows_to_close,
+                );
+            }
+
+            self.sync_window_state(window_id);
+        }
+    }
+
+    // Runs the timers that are due
+    fn run_timers(&mut self) {
+        let window_ids = self
+            .windows
+            .iter()
+            .filter(|(_, w)| !w.internal.timers.is_empty())
+            .map(|(id, _)| *id)
+            .collect::<Vec<_>>();
+
+        for window_id in window_ids {
+            {
+                let window = match self.windows.get_mut(&window_id) {
+                    Some(s) => s,
+                    None => continue,
+                };
+
+                window.make_current();
+
+                let mut app_borrow = match self.app_data.inner.try_borrow_mut() {
+                    Ok(o) => o,
+                    Err(_) => return,
+                };
+                let ab = &mut *app_borrow;
+
+                let result = invoke_timers(
+                    window,
+                    &mut ab.fc_cache,
+                    &mut ab.image_cache,
+                    &ab.config,
+                    &mut self.new_windows,
+                    &mut self.windows_to_close,
+                );
+
+                self.regenerate_all_windows |= apply_process_event_result(
+                    result,
+                    window,
+                    ab,
+                    &mut self.new_windows,
+                    &mut self.windows_to_close,
+                );
+            }
+
+            self.sync_window_state(window_id);
+        }
+    }
+
+    fn handle_key_repeat(&mut self) {
+        let (window_id, keycode) = match self.key_repeat.as_mut() {
+            Some(r) if r.next <= Instant::now() => {
+                let interval = 1000 / self.key_repeat_rate.max(1) as u64;
+                r.next += std::time::Duration::from_millis(interval);
+                (r.window_id, r.keycode)
+            }
+            _ => return,
+        };
+
+        self.handle_key(window_id, keycode, true);
+    }
+
+    // Handles clicks on the client-side decorations, returns whether the
+    // event was consumed (and should not be forwarded to the callbacks)
+    #[cfg(feature = "css_parser")]
+    fn handle_decoration_button(&mut self, window_id: usize, button: u32, is_down: bool) -> bool {
+        use self::decorations::DecorationHit;
+
+        let seat = self.seat;
+        let serial = self.pointer_serial;
+
+        let window = match self.windows.get_mut(&window_id) {
+            Some(s) => s,
+            None => return false,
+        };
+
+        let state = &mut window.internal.current_window_state;
+        if !state.flags.has_decorations || !decorations::is_decorated(&mut state.layout_callback) {
+            return false;
+        }
+
+        let position = match state.mouse_state.cursor_position {
+            CursorPosition::InWindow(p) => p,
+            _ => return false,
+        };
+
+        let is_resizable = state.flags.is_resizable;
+        let hit = decorations::get_decoration_hit(
+            position,
+            state.size.dimensions,
+            window.is_maximized,
+            is_resizable,
+        );
+
+        let toplevel = window.xdg_toplevel;
+        let marshal = window.wl.wl_proxy_marshal;
+
+        if button == BTN_RIGHT {
+            if is_down && hit == Some(DecorationHit::TitleBar) {
+                unsafe {
+                    marshal(
+                        toplevel,
+                        4, // show_window_menu
+                        seat,
+                        serial,
+                        position.x as i32,
+                        position.y as i32,
+                    )
+                };
+                return true;
+            }
+            return hit.is_some() && hit != Some(DecorationHit::TitleBar);
+        }
+
+        if button != BTN_LEFT {
+            return hit.is_some();
+        }
+
+        if !is_down {
+            let pressed = match window.pressed_decoration.take() {
+                Some(s) => s,
+                None => return false,
+            };
+
+            if hit == Some(pressed) {
+                match pressed {
+                    DecorationHit::Minimize => unsafe {
+                        marshal(toplevel, XDG_TOPLEVEL_SET_MINIMIZED)
+                    },
+                    DecorationHit::Maximize if is_resizable => {
+                        window.toggle_maximized();
+                    }
+                    DecorationHit::Close => self.windows_to_close.push(window_id),
+                    _ => {}
+                }
+            }
+            return true;
+        }
+
+        match hit {
+            Some(DecorationHit::TitleBar) => {
+                let now = Instant::now();
+                let is_double_click = window
+                    .last_title_bar_click
+                    .map(|t| now.duration_since(t).as_millis() < 400)
+                    .unwrap_or(false);
+                if is_double_click && is_resizable {
+                    window.last_title_bar_click = None;
+                    window.toggle_maximized();
+                } else {
+                    window.last_title_bar_click = Some(now);
+                    unsafe { marshal(toplevel, XDG_TOPLEVEL_MOVE, seat, serial) };
+                }
+                true
+            }
+            Some(DecorationHit::Resize(edge)) => {
+                unsafe { marshal(toplevel, XDG_TOPLEVEL_RESIZE, seat, serial, edge as u32) };
+                true
+            }
+            Some(other) => {
+                window.pressed_decoration = Some(other);
+                true
+            }
+            None => false,
+        }
+    }
+
+    // Lets the compositor move or resize the window if the left mouse button was
+    // pressed on a node with a WindowRegion, returns whether it was started
+    fn start_move_resize(&mut self, window_id: usize) -> bool {
+        let window = match self.windows.get(&window_id) {
+            Some(s) => s,
+            None => return false,
+        };
+
+        let region = window
+            .internal
+            .current_window_state
+            .last_hit_test
+            .get_window_region(&window.internal.layout_results);
+
+        let toplevel = window.xdg_toplevel;
+        let marshal = window.wl.wl_proxy_marshal;
+        let (seat, serial) = (self.seat, self.pointer_serial);
+
+        match region.map(get_resize_edge) {
+            Some(None) => unsafe { marshal(toplevel, XDG_TOPLEVEL_MOVE, seat, serial) },
+            Some(Some(edge)) => unsafe {
+                marshal(toplevel, XDG_TOPLEVEL_RESIZE, seat, serial, edge)
+            },
+            None => return false,
+        }
+
+        true
+    }
+}
+
+// Value of xdg_toplevel.resize_edge for the region, None for WindowRegion::Drag
+fn get_resize_edge(region: WindowRegion) -> Option<u32> {
+    use azul_core::window::WindowRegion::*;
+    match region {
+        Client | Drag => None,
+        ResizeN => Some(1),
+        ResizeS => Some(2),
+        ResizeW => Some(4),
+        ResizeNW => Some(5),
+        ResizeSW => Some(6),
+        ResizeE => Some(8),
+        ResizeNE => Some(9),
+        ResizeSE => Some(10),
+    }
+}
+
+impl Drop for WaylandApp {
+    fn drop(&mut self) {
+        // destroy the windows before the globals they were created from
+        self.windows.clear();
+
+        for fd in self.wake_fds.iter() {
+            if *fd >= 0 {
+                unsafe { close(*fd) };
+            }
+        }
+
+        if let Some(xkb) = self.xkb.as_ref() {
+            if !self.xkb_state.is_null() {
+                (xkb.xkb_state_unref)(self.xkb_state);
+            }
+            if !self.xkb_keymap.is_null() {
+                (xkb.xkb_keymap_unref)(self.xkb_keymap);
+            }
+            if !self.xkb_context.is_null() {
+                (xkb.xkb_context_unref)(self.xkb_context);
+            }
+        }
+
+        if let Some(wl_cursor) = self.wl_cursor.as_ref() {
+            if !self.cursor_theme.is_null() {
+                (wl_cursor.wl_cursor_theme_destroy)(self.cursor_theme);
+            }
+        }
+
+        if let Some((_, buffer)) = self.cursor_image_buffer.take() {
+            unsafe { (self.wl.wl_proxy_marshal)(buffer, WL_BUFFER_DESTROY) };
+            (self.wl.wl_proxy_destroy)(buffer);
+        }
+
+        let proxies = [
+            self.cursor_surface,
+            self.pointer,
+            self.keyboard,
+            self.seat,
+            self.shm,
+            self.viewporter,
+            self.fractional_scale_manager,
+            self.wm_base,
+            self.compositor,
+            self.registry,
+        ];
+
+        for proxy in proxies.iter().chain(self.outputs.iter().map(|o| &o.proxy)) {
+            if !proxy.is_null() {
+                (self.wl.wl_proxy_destroy)(*proxy);
+            }
+        }
+    }
+}
+
+struct WaylandWindow {
+    /// Address of the wl_surface, used as the window ID
+    pub id: usize,
+    pub surface: *mut wl_proxy,
+    pub xdg_surface: *mut wl_proxy,
+    pub xdg_toplevel: *mut wl_proxy,
+    /// Viewport + fractional scale object, null if the compositor does
+    /// not support wp_viewporter and wp_fractional_scale_manager_v1
+    pub viewport: *mut wl_proxy,
+    pub fractional_scale: *mut wl_proxy,
+    pub display: *mut wl_display,
+    // EGL OpenGL 3.2 context
+    pub egl_window: *mut wl_egl_window,
+    pub egl_surface: EGLSurface,
+    pub egl_display: EGLDisplay,
+    pub egl_context: EGLContext,
+    // dynamically loaded libraries
+    pub wl: Rc<WaylandClient>,
+    pub wl_egl: Rc<WaylandEgl>,
+    pub egl: Rc<Egl>,
+    // OpenGL functions, loaded from libEGL.so
+    pub gl_functions: GlFunctions,
+    /// See azul-core, stores the entire UI (DOM, CSS styles, layout results, etc.)
+    pub internal: WindowInternal,
+    /// OpenGL context pointer with compiled SVG and FXAA shaders
+    pub gl_context_ptr: OptionGlContextPtr,
+    /// Main render API that can be used to register and un-register fonts and images
+    pub render_api: WrRenderApi,
+    /// WebRender renderer implementation (software or hardware)
+    pub renderer: Option<WrRenderer>,
+    /// Hit-tester, lazily initialized and updated every time the display list changes layout
+    pub hit_tester: AsyncHitTester,
+    /// Whether the first xdg_surface.configure has been acknowledged,
+    /// no buffer may be attached to the surface before that
+    pub is_configured: bool,
+    /// Whether the window has to be re-rendered (Wayland has no expose events)
+    pub needs_redraw: bool,
+    /// State of the last xdg_toplevel.configure, applied on xdg_surface.configure
+    pub pending_size: Option<LogicalSize>,
+    pub pending_states: Vec<u32>,
+    /// xdg_toplevel states of the last configure
+    pub is_activated: bool,
+    pub is_maximized: bool,
+    /// Outputs the surface is currently on (for the integer scale fallback)
+    pub entered_outputs: Vec<*mut wl_proxy>,
+    /// Scale of wp_fractional_scale_v1.preferred_scale
+    pub preferred_scale: Option<f32>,
+    /// Title / frame last sent to the compositor
+    pub applied_title: String,
+    pub applied_frame: WindowFrame,
+    /// Whether the surface currently has an empty input region (`WindowFlags::is_click_through`)
+    pub applied_click_through: bool,
+    /// Tray icon of the window and the hash of the TrayIcon it was last updated with
+    pub tray: Option<tray::SystemTray>,
+    /// Connection to the notification server, created when the first notification is shown
+    pub notifications: Option<notify::Notifications>,
+    /// Write end of the pipe that wakes up the main loop
+    pub wake_fd: c_int,
+    pub applied_tray_icon: Option<u64>,
+    /// Decoration button the left mouse button was pressed on
+    #[cfg(feature = "css_parser")]
+    pub pressed_decoration: Option<decorations::DecorationHit>,
+    pub last_title_bar_click: Option<Instant>,
+    /// Set when the DOM got regenerated, so that the inspectors of the window update their snapshot
+    pub dom_regenerated: bool,
+    /// Parent window of a child window (see `WindowCreateOptions::parent`)
+    pub parent: Option<WindowId>,
+    /// Whether the parent window is blocked while this window is open
+    pub modal: bool,
+    /// Invoked on the parent window with the result of this window when it is closed
+    pub result_callback: Option<WindowResultCallback>,
+    /// Result of this window, set with `CallbackInfo::set_window_result`
+    pub window_result: Option<RefAny>,
+    /// Windows closed with `CallbackInfo::close_window`, closed in the main loop
+    pub windows_closed: Vec<WindowId>,
+    /// Keyboard shortcuts changed in the callbacks of this window (`CallbackInfo::register_shortcut`)
+    pub shortcuts_changed: Vec<ShortcutChange>,
+    /// Key of the saved window size (see `WindowCreateOptions::persistence_key`)
+    pub persistence_key: Option<AzString>,
+}
+
+impl WaylandWindow {
+    fn new(
+        app: &mut WaylandApp,
+        options: &mut WindowCreateOptions,
+    ) -> Result<Self, LinuxStartupError> {
+        use self::LinuxStartupError::Create;
+        use self::LinuxWindowCreateError::{Egl as EglError, Wayland};
+        use crate::{
+            compositor::Compositor,
+            wr_translate::{
+                translate_document_id_wr, translate_id_namespace_wr, wr_translate_debug_flags,
+                wr_translate_document_id,
+            },
+        };
+        use azul_core::callbacks::PipelineId;
+        use azul_core::gl::GlContextPtr;
+        use azul_core::window::{HwAcceleration, RendererType};
+        use webrender::api::ColorF as WrColorF;
+        use webrender::ProgramCache as WrProgramCache;
+
+        let data = app as *mut WaylandApp;
+        let wl = app.wl.clone();
+        let egl = app.egl.clone();
+        let egl_display = app.egl_display;
+
+        #[cfg(feature = "css_parser")]
+        {
+            let state = &mut options.state;
+            if state.flags.has_decorations {
+                decorations::decorate(
+                    &mut state.layout_callback,
+                    state.title.as_str(),
+                    state.flags.is_resizable,
+                    &state.platform_specific_options.linux_options.wayland_theme,
+                );
+            }
+        }
+
+        // the scale of the window is only known once the surface is on an output,
+        // the window is resized as soon as the compositor sends the scale
+        options.state.size.dpi = 96;
+
+        let logical_size = options.state.size.dimensions;
+        let physical_size = logical_size.to_physical(1.0);
+
+        let surface = unsafe {
+            (wl.wl_proxy_marshal_constructor)(
+                app.compositor,
+                WL_COMPOSITOR_CREATE_SURFACE,
+                wl.wl_surface_interface,
+                ptr::null_mut::<c_void>(),
+            )
+        };
+        if surface.is_null() {
+            return Err(Create(Wayland(format!(
+                "Wayland: wl_compositor.create_surface failed"
+            ))));
+        }
+        wl.add_listener(surface, &SURFACE_LISTENER, data);
+
+        let mut fractional_scale = ptr::null_mut();
+        let mut viewport = ptr::null_mut();
+        if !app.fractional_scale_manager.is_null() && !app.viewporter.is_null() {
+            unsafe {
+                fractional_scale = (wl.wl_proxy_marshal_constructor)(
+                    app.fractional_scale_manager,
+                    WP_FRACTIONAL_SCALE_MANAGER_GET_FRACTIONAL_SCALE,
+                    &WP_FRACTIONAL_SCALE_V1_INTERFACE,
+                    ptr::null_mut::<c_void>(),
+                    surface,
+                );
+                viewport = (wl.wl_proxy_marshal_constructor)(
+                    app.viewporter,
+                    WP_VIEWPORTER_GET_VIEWPORT,
+                    &WP_VIEWPORT_INTERFACE,
+                    ptr::null_mut::<c_void>(),
+                    surface,
+                );
+            }
+            wl.add_listener(fractional_scale, &FRACTIONAL_SCALE_LISTENER, data);
+        }
+
+        let xdg_surface = unsafe {
+            (wl.wl_proxy_marshal_constructor)(
+                app.wm_base,
+                XDG_WM_BASE_GET_XDG_SURFACE,
+                &XDG_SURFACE_INTERFACE,
+                ptr::null_mut::<c_void>(),
+                surface,
+            )
+        };
+        wl.add_listener(xdg_surface, &XDG_SURFACE_LISTENER, data);
+
+        let xdg_toplevel = unsafe {
+            (wl.wl_proxy_marshal_constructor)(
+                xdg_surface,
+                XDG_SURFACE_GET_TOPLEVEL,
+                &XDG_TOPLEVEL_INTERFACE,
+                ptr::null_mut::<c_void>(),
+            )
+        };
+        wl.add_listener(xdg_toplevel, &XDG_TOPLEVEL_LISTENER, data);
+
+        let title = encode_utf8(options.state.title.as_str());
+        unsafe { (wl.wl_proxy_marshal)(xdg_toplevel, XDG_TOPLEVEL_SET_TITLE, title.as_ptr()) };
+
+        if let Some(app_id) = options
+            .state
+            .platform_specific_options
+            .linux_options
+            .wayland_app_id
+            .as_ref()
+        {
+            let app_id = encode_utf8(app_id.as_str());
+            unsafe {
+                (wl.wl_proxy_marshal)(xdg_toplevel, XDG_TOPLEVEL_SET_APP_ID, app_id.as_ptr())
+            };
+        }
+
+        let (min_size, max_size) = if options.state.flags.is_resizable {
+            (
+                options.state.size.min_dimensions.into_option(),
+                options.state.size.max_dimensions.into_option(),
+            )
+        } else {
+            (Some(logical_size), Some(logical_size))
+        };
+        unsafe {
+            if let Some(min) = min_size {
+                (wl.wl_proxy_marshal)(
+                    xdg_toplevel,
+                    XDG_TOPLEVEL_SET_MIN_SIZE,
+                    min.width.round() as i32,
+                    min.height.round() as i32,
+                );
+            }
+            if let Some(max) = max_size {
+                (wl.wl_proxy_marshal)(
+                    xdg_toplevel,
+                    XDG_TOPLEVEL_SET_MAX_SIZE,
+                    max.width.round() as i32,
+                    max.height.round() as i32,
+                );
+            }
+            match options.state.flags.frame {
+                WindowFrame::Maximized => {
+                    (wl.wl_proxy_marshal)(xdg_toplevel, XDG_TOPLEVEL_SET_MAXIMIZED)
+                }
+                WindowFrame::Fullscreen => (wl.wl_proxy_marshal)(
+                    xdg_toplevel,
+                    XDG_TOPLEVEL_SET_FULLSCREEN,
+                    ptr::null_mut::<c_void>(),
+                ),
+                WindowFrame::Minimized => {
+                    (wl.wl_proxy_marshal)(xdg_toplevel, XDG_TOPLEVEL_SET_MINIMIZED)
+                }
+                WindowFrame::Normal => {}
+            }
+
+            // commit without a buffer, the compositor answers with the first configure
+            (wl.wl_proxy_marshal)(surface, WL_SURFACE_COMMIT);
+        }
+
+        let egl_window = (app.wl_egl.wl_egl_window_create)(
+            surface,
+            physical_size.width.max(1) as i32,
+            physical_size.height.max(1) as i32,
+        );
+        if egl_window.is_null() {
+            return Err(Create(Wayland(format!(
+                "Wayland: wl_egl_window_create failed"
+            ))));
+        }
+
+        let egl_attr = [
+            EGL_SURFACE_TYPE,
+            EGL_WINDOW_BIT,
+            EGL_CONFORMANT,
+            EGL_OPENGL_BIT,
+            EGL_RENDERABLE_TYPE,
+            EGL_OPENGL_BIT,
+            EGL_COLOR_BUFFER_TYPE,
+            EGL_RGB_BUFFER,
+            EGL_RED_SIZE,
+            8,
+            EGL_GREEN_SIZE,
+            8,
+            EGL_BLUE_SIZE,
+            8,
+            EGL_DEPTH_SIZE,
+            24,
+            EGL_STENCIL_SIZE,
+            8,
+            EGL_NONE,
+        ];
+
+        let mut config: EGLConfig = unsafe { mem::zeroed() };
+        let mut count = 0;
+        let egl_config_chosen =
+            (egl.eglChooseConfig)(egl_display, egl_attr.as_ptr(), &mut config, 1, &mut count);
+        if egl_config_chosen != EGL_TRUE {
+            return Err(Create(EglError(format!(
+                "EGL: eglChooseConfig(): Cannot choose EGL config: {}",
+                egl_config_chosen
+            ))));
+        }
+
+        if count != 1 {
+            return Err(Create(EglError(format!(
+                "EGL: eglChooseConfig(): Expected 1 EglConfig, got {}",
+                count
+            ))));
+        }
+
+        let egl_surface_attr = [
+            EGL_GL_COLORSPACE,
+            EGL_GL_COLORSPACE_LINEAR,
+            EGL_RENDER_BUFFER,
+            EGL_BACK_BUFFER,
+            EGL_NONE,
+        ];
+
+        let egl_surface = (egl.eglCreateWindowSurface)(
+            egl_display,
+            config,
+            egl_window as *mut c_void,
+            egl_surface_attr.as_ptr(),
+        );
+
+        if egl_surface == EGL_NO_SURFACE {
+            return Err(Create(EglError(format!(
+                "EGL: eglCreateWindowSurface(): no surface found"
+            ))));
+        }
+
+        let egl_context_attr = [
+            EGL_CONTEXT_MAJOR_VERSION,
+            3,
+            EGL_CONTEXT_MINOR_VERSION,
+            2,
+            EGL_CONTEXT_OPENGL_PROFILE_MASK,
+            EGL_CONTEXT_OPENGL_CORE_PROFILE_BIT,
+            EGL_NONE,
+        ];
+
+        let egl_context = (egl.eglCreateContext)(
+            egl_display,
+            config,
+            EGL_NO_CONTEXT,
+            egl_context_attr.as_ptr(),
+        );
+        if egl_context == EGL_NO_CONTEXT {
+            let err = (egl.eglGetError)();
+            return Err(Create(EglError(format!(
+                "EGL: eglCreateContext() failed with status {} = {}",
+                err,
+                display_egl_status(err)
+            ))));
+        }
+
+        let egl_is_current =
+            (egl.eglMakeCurrent)(egl_display, egl_surface, egl_surface, egl_context);
+        if egl_is_current != EGL_TRUE {
+            return Err(Create(EglError(format!(
+                "EGL: eglMakeCurrent(): failed to make context current: {}",
+                egl_is_current
+            ))));
+        }
+
+        // eglSwapBuffers() would otherwise block until the compositor sends a frame
+        // callback, which never happens while the window is hidden / minimized
+        let _ = (egl.eglSwapInterval)(egl_display, 0);
+
+        let mut gl_functions = GlFunctions::initialize(egl.clone());
+        gl_functions.load();
+
+        // Initialize WebRender
+        let mut rt = RendererType::Software;
+
+        let renderer_types = match options.renderer.into_option() {
+            Some(s) => match s.hw_accel {
+                HwAcceleration::DontCare => vec![RendererType::Hardware, RendererType::Software],
+                HwAcceleration::Enabled => vec![RendererType::Hardware],
+                HwAcceleration::Disabled => vec![RendererType::Software],
+            },
+            None => vec![RendererType::Hardware, RendererType::Software],
+        };
+
+        // TODO: allow fallback software rendering -
+        // currently just takes the first option
+        for r in renderer_types {
+            rt = r;
+            break;
+        }
+
+        // compiles SVG and FXAA shader programs...
+        let gl_context_ptr = Some(GlContextPtr::new(rt, gl_functions.functions.clone())).into();
+
+        let (mut renderer, sender) = WrRenderer::new(
+            gl_functions.functions.clone(),
+            Box::new(Notifier {}),
+            WrRendererOptions {
+                resource_override_path: None,
+                use_optimized_shaders: true,
+                enable_aa: true,
+                enable_subpixel_aa: true,
+                force_subpixel_aa: true,
+                clear_color: WrColorF {
+                    r: 0.0,
+                    g: 0.0,
+                    b: 0.0,
+                    a: 0.0,
+                }, // transparent
+                panic_on_gl_error: false,
+                precache_flags: WrShaderPrecacheFlags::EMPTY,
+                cached_programs: Some(WrProgramCache::new(None)),
+                enable_multithreading: true,
+                debug_flags: wr_translate_debug_flags(&options.state.debug_state),
+                ..WrRendererOptions::default()
+            },
+            None,
+        )
+        .map_err(|e| Create(EglError(format!("Could not init WebRender: {:?}", e))))?;
+
+        renderer.set_external_image_handler(Box::new(Compositor::default()));
+
+        let mut render_api = sender.create_api();
+
+        let framebuffer_size =
+            WrDeviceIntSize::new(physical_size.width as i32, physical_size.height as i32);
+        let document_id = translate_document_id_wr(render_api.add_document(framebuffer_size));
+        let pipeline_id = PipelineId::new();
+        let id_namespace = translate_id_namespace_wr(render_api.get_namespace_id());
+
+        // hit tester will be empty on startup
+        let hit_tester = render_api
+            .request_hit_tester(wr_translate_document_id(document_id))
+            .resolve();
+
+        let hit_tester_ref = &*hit_tester;
+
+        let mut appdata_lock = match app.app_data.inner.try_borrow_mut() {
+            Ok(o) => o,
+            Err(e) => return Err(Create(EglError(format!("could not lock application data")))),
+        };
+
+        let appdata_lock = &mut *appdata_lock;
+        let fc_cache = &mut appdata_lock.fc_cache;
+        let image_cache = &appdata_lock.image_cache;
+        let data = &mut appdata_lock.data;
+
+        let mut initial_resource_updates = Vec::new();
+        let mut internal = fc_cache.apply_closure(|fc_cache| {
+            use azul_core::window::WindowInternalInit;
+
+            WindowInternal::new(
+                WindowInternalInit {
+                    window_create_options: options.clone(),
+                    document_id,
+                    id_namespace,
+                },
+                data,
+                image_cache,
+                &gl_context_ptr,
+                &mut initial_resource_updates,
+                &crate::app::CALLBACKS,
+                fc_cache,
+                azul_layout::do_the_relayout,
+                |window_state, scroll_states, layout_results| {
+                    crate::wr_translate::fullhittest_new_webrender(
+                        hit_tester_ref,
+                        document_id,
+                        window_state.focused_node,
+                        layout_results,
+                        &window_state.mouse_state.cursor_position,
+                        window_state.size.get_hidpi_factor(),
+                    )
+                },
+            )
+        });
+
+        let mut txn = WrTransaction::new();
+
+        txn.set_document_view(WrDeviceIntRect::from_size(WrDeviceIntSize::new(
+            physical_size.width as i32,
+            physical_size.height as i32,
+        )));
+
+        render_api.send_transaction(wr_translate_document_id(internal.document_id), txn);
+
+        render_api.flush_scene_builder();
+
+        // Build the display list and send it to webrender for the first time
+        rebuild_display_list(
+            &mut internal,
+            &mut render_api,
+            &appdata_lock.image_cache,
+            initial_resource_updates,
+        );
+
+        render_api.flush_scene_builder();
+
+        generate_frame(&mut internal, &mut render_api, true);
+
+        render_api.flush_scene_builder();
+
+        // Update the hit-tester to account for the new hit-testing functionality
+        let hit_tester = render_api.request_hit_tester(wr_translate_document_id(document_id));
+
+        Ok(Self {
+            id: surface as usize,
+            surface,
+            xdg_surface,
+            xdg_toplevel,
+            viewport,
+            fractional_scale,
+            display: app.display,
+            egl_window,
+            egl_surface,
+            egl_display,
+            egl_context,
+            wl,
+            wl_egl: app.wl_egl.clone(),
+            egl,
+            gl_functions,
+            applied_title: internal.current_window_state.title.as_str().into(),
+            applied_frame: internal.current_window_state.flags.frame,
+            applied_click_through: false,
+            tray: None,
+            notifications: None,
+            wake_fd: app.wake_fds[1],
+            applied_tray_icon: None,
+            internal,
+            gl_context_ptr,
+            render_api,
+            renderer: Some(renderer),
+            hit_tester: AsyncHitTester::Requested(hit_tester),
+            is_configured: false,
+            needs_redraw: true,
+            pending_size: None,
+            pending_states: Vec::new(),
+            is_activated: false,
+            is_maximized: false,
+            entered_outputs: Vec::new(),
+            preferred_scale: None,
+            #[cfg(feature = "css_parser")]
+            pressed_decoration: None,
+            last_title_bar_click: None,
+            dom_regenerated: false,
+            parent: options.parent.into_option(),
+            modal: options.modal,
+            result_callback: options.result_callback.clone().into_option(),
+            window_result: None,
+            windows_closed: Vec::new(),
+            shortcuts_changed: Vec::new(),
+            persistence_key: options.persistence_key.clone().into_option(),
+        })
+    }
+
+    // Saves the size and frame of the window if it has a persistence key, called before it
+    // is closed. The position is not known on Wayland, the saved position is kept for X11.
+    fn save_persisted_state(&self) {
+        use azul_core::window::{PersistedWindowState, PhysicalPositionI32};
+
+        let key = match self.persistence_key.as_ref() {
+            Some(s) => s,
+            None => return,
+        };
+
+        let state = &self.internal.current_window_state;
+        let previous = crate::persistence::load_window_state(key.as_str());
+        let persisted = PersistedWindowState {
+            position: previous
+                .map(|p| p.position)
+                .unwrap_or(PhysicalPositionI32::new(0, 0)),
+            size: state.size.dimensions,
+            frame: state.flags.frame,
+            monitor_id: previous.map(|p| p.monitor_id).unwrap_or(state.monitor.id),
+        };
+        crate::persistence::save_window_state(key.as_str(), &persisted);
+    }
+
+    fn make_current(&self) {
+        (self.egl.eglMakeCurrent)(
+            self.egl_display,
+            self.egl_surface,
+            self.egl_surface,
+            self.egl_context,
+        );
+    }
+
+    // Wayland has no expose events, the window is re-rendered after
+    // the current batch of events has been dispatched
+    fn request_redraw(&mut self) {
+        self.needs_redraw = true;
+    }
+
+    fn get_physical_size(&self) -> WrDeviceIntSize {
+        let size = &self.internal.current_window_state.size;
+        let physical_size = size.dimensions.to_physical(size.get_hidpi_factor());
+        WrDeviceIntSize::new(
+            physical_size.width.max(1) as i32,
+            physical_size.height.max(1) as i32,
+        )
+    }
+
+    fn render(&mut self) {
+        let framebuffer_size = self.get_physical_size();
+
+        self.make_current();
+        self.render_api.flush_scene_builder();
+
+        let gl = &self.gl_functions.functions;
+        gl.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
+        gl.disable(gl_context_loader::gl::FRAMEBUFFER_SRGB);
+        gl.disable(gl_context_loader::gl::MULTISAMPLE);
+        gl.viewport(0, 0, framebuffer_size.width, framebuffer_size.height);
+        gl.clear_color(0.0, 0.0, 0.0, 1.0);
+        gl.clear(gl::COLOR_BUFFER_BIT | gl::DEPTH_BUFFER_BIT | gl::STENCIL_BUFFER_BIT);
+
+        let mut current_program = [0_i32];
+        unsafe {
+            gl.get_integer_v(
+                gl_context_loader::gl::CURRENT_PROGRAM,
+                (&mut current_program[..]).into(),
+            );
+        }
+
+        if let Some(r) = self.renderer.as_mut() {
+            r.update();
+            let _ = r.render(framebuffer_size, 0);
+        }
+
+        let _ = (self.egl.eglSwapBuffers)(self.egl_display, self.egl_surface);
+
+        let gl = &self.gl_functions.functions;
+        gl.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
+        gl.bind_texture(gl_context_loader::gl::TEXTURE_2D, 0);
+        gl.use_program(current_program[0] as u32);
+    }
+
+    fn toggle_maximized(&mut self) {
+        let opcode = if self.is_maximized {
+            XDG_TOPLEVEL_UNSET_MAXIMIZED
+        } else {
+            XDG_TOPLEVEL_SET_MAXIMIZED
+        };
+        unsafe { (self.wl.wl_proxy_marshal)(self.xdg_toplevel, opcode) };
+    }
+
+    // Resizes the EGL window and relayouts the window content
+    fn resize(&mut self, app: &mut ApplicationData, size: LogicalSize, scale: f32) {
+        use crate::wr_translate::wr_translate_document_id;
+
+        let mut new_size = self.internal.current_window_state.size;
+        new_size.dimensions = size;
+        new_size.dpi = (scale * 96.0).round() as u32;
+
+        if new_size == self.internal.current_window_state.size {
+            return;
+        }
+
+        self.internal.previous_window_state = Some(self.internal.current_window_state.clone());
+        self.internal.current_window_state.size = new_size;
+
+        let physical_size = self.get_physical_size();
+        (self.wl_egl.wl_egl_window_resize)(
+            self.egl_window,
+            physical_size.width,
+            physical_size.height,
+            0,
+            0,
+        );
+
+        // the buffer has the physical size, the viewport (or the
+        // buffer scale) maps it to the logical size of the surface
+        unsafe {
+            if !self.viewport.is_null() {
+                (self.wl.wl_proxy_marshal)(
+                    self.viewport,
+                    WP_VIEWPORT_SET_DESTINATION,
+                    size.width.round() as i32,
+                    size.height.round() as i32,
+                );
+            } else {
+                (self.wl.wl_proxy_marshal)(
+                    self.surface,
+                    WL_SURFACE_SET_BUFFER_SCALE,
+                    scale.round().max(1.0) as i32,
+                );
+            }
+        }
+
+        self.make_current();
+
+        let image_cache = &app.image_cache;
+        let fc_cache = &mut app.fc_cache;
+        let internal = &mut self.internal;
+        let gl_context_ptr = &self.gl_context_ptr;
+        let theme = internal.current_window_state.theme;
+
+        let resize_result = fc_cache.apply_closure(|fc_cache| {
+            internal.do_quick_resize(
+                image_cache,
+                &crate::app::CALLBACKS,
+                azul_layout::do_the_relayout,
+                fc_cache,
+                gl_context_ptr,
+                &new_size,
+                theme,
+            )
+        });
+
+        let mut txn = WrTransaction::new();
+        wr_synchronize_updated_images(
+            resize_result.updated_images,
+            &self.internal.document_id,
+            &mut txn,
+        );
+        txn.set_document_view(WrDeviceIntRect::from_size(physical_size));
+        self.render_api
+            .send_transaction(wr_translate_document_id(self.internal.document_id), txn);
+
+        self.regenerate_display_list(image_cache);
+    }
+
+    // Lets all pointer input pass through to the surfaces below (empty input region)
+    // or restores the default input region (the entire surface), the region is
+    // double-buffered state and is applied with the next commit of the surface
+    fn sync_click_through(&mut self, compositor: *mut wl_proxy) {
+        let click_through = self.internal.current_window_state.flags.is_click_through;
+        if click_through == self.applied_click_through {
+            return;
+        }
+
+        let marshal = self.wl.wl_proxy_marshal;
+        if click_through {
+            let region = unsafe {
+                (self.wl.wl_proxy_marshal_constructor)(
+                    compositor,
+                    WL_COMPOSITOR_CREATE_REGION,
+                    self.wl.wl_region_interface,
+                    ptr::null_mut::<c_void>(),
+                )
+            };
+            if region.is_null() {
+                return;
+            }
+            unsafe {
+                marshal(self.surface, WL_SURFACE_SET_INPUT_REGION, region);
+                marshal(region, WL_REGION_DESTROY);
+                (self.wl.wl_proxy_destroy)(region);
+            }
+        } else {
+            unsafe {
+                marshal(
+                    self.surface,
+                    WL_SURFACE_SET_INPUT_REGION,
+                    ptr::null_mut::<wl_proxy>(),
+                )
+            };
+        }
+        self.applied_click_through = click_through;
+        self.needs_redraw = true;
+    }
+
+    // Converts surface-local coordinates (already in logical pixels) into a cursor position
+    fn get_cursor_position(&self, x: wl_fixed, y: wl_fixed) -> CursorPosition {
+        CursorPosition::InWindow(LogicalPosition::new(wl_fixed_to_f32(x), wl_fixed_to_f32(y)))
+    }
+
+    // Hit-tests the current cursor position against the last submitted display list
+    fn update_hit_test(&mut self) {
+        let hit_test = crate::wr_translate::fullhittest_new_webrender(
+            &*self.hit_tester.resolve(),
+            self.internal.document_id,
+            self.internal.current_window_state.focused_node,
+            &self.internal.layout_results,
+            &self
+                .internal
+                .current_window_state
+                .mouse_state
+                .cursor_position,
+            self.internal.current_window_state.size.get_hidpi_factor(),
+        );
+        self.internal.current_window_state.last_hit_test = hit_test;
+    }
+
+    // Returns the cursor for the current hit-test: the resize cursors over the
+    // border of the client-side decorations, otherwise the CSS cursor property
+    fn get_cursor(&mut self, image_cache: &ImageCache) -> WaylandCursor {
+        use azul_core::window::{CursorTypeHitTest, OptionMouseCursorType};
+
+        #[cfg(feature = "css_parser")]
+        {
+            let state = &mut self.internal.current_window_state;
+            if let CursorPosition::InWindow(position) = state.mouse_state.cursor_position {
+                if state.flags.has_decorations
+                    && decorations::is_decorated(&mut state.layout_callback)
+                {
+                    if let Some(decorations::DecorationHit::Resize(edge)) =
+                        decorations::get_decoration_hit(
+                            position,
+                            state.size.dimensions,
+                            self.is_maximized,
+                            state.flags.is_resizable,
+                        )
+                    {
+                        return WaylandCursor::Named(edge.get_cursor_name());
+                    }
+                }
+            }
+        }
+
+        let cht = CursorTypeHitTest::new(
+            &self.internal.current_window_state.last_hit_test,
+            &self.internal.layout_results,
+        );
+        self.internal
+            .current_window_state
+            .mouse_state
+            .mouse_cursor_type = OptionMouseCursorType::Some(cht.cursor_icon);
+
+        let name = cursor::get_cursor_name(cht.cursor_icon);
+        match cht.get_custom_cursor(&self.internal.current_window_state, image_cache) {
+            Some(image) => WaylandCursor::Image(image, name),
+            None => WaylandCursor::Named(name),
+        }
+    }
+
+    // Rebuilds the display list, requests a new hit-tester and redraws the window
+    fn regenerate_display_list(&mut self, image_cache: &ImageCache) {
+        use crate::wr_translate::wr_translate_document_id;
+
+        rebuild_display_list(
+            &mut self.internal,
+            &mut self.render_api,
+            image_cache,
+            Vec::new(), // no resource updates
+        );
+
+        let wr_document_id = wr_translate_document_id(self.internal.document_id);
+        self.hit_tester =
+            AsyncHitTester::Requested(self.render_api.request_hit_tester(wr_document_id));
+
+        generate_frame(&mut self.internal, &mut self.render_api, true);
+
+        self.request_redraw();
+    }
+
+    // Invokes the layout callback, restyles and relayouts the window
+    // and submits the new display list to webrender
+    fn regenerate_dom(&mut self, app: &mut ApplicationData) {
+        use crate::wr_translate::wr_translate_document_id;
+        use azul_core::window_state::StyleAndLayoutChanges;
+
+        let data = &mut app.data;
+        let image_cache = &app.image_cache;
+        let fc_cache = &mut app.fc_cache;
+
+        let document_id = self.internal.document_id;
+        let hit_tester = &mut self.hit_tester;
+        let internal = &mut self.internal;
+        let gl_context = &self.gl_context_ptr;
+
+        // unset the focus
+        internal.current_window_state.focused_node = None;
+
+        let mut resource_updates = Vec::new();
+        fc_cache.apply_closure(|fc_cache| {
+            internal.regenerate_styled_dom(
+                data,
+                image_cache,
+                gl_context,
+                &mut resource_updates,
+                internal.get_dpi_scale_factor(),
+                &crate::app::CALLBACKS,
+                fc_cache,
+                azul_layout::do_the_relayout,
+                |window_state, scroll_states, layout_results| {
+                    crate::wr_translate::fullhittest_new_webrender(
+                        &*hit_tester.resolve(),
+                        document_id,
+                        window_state.focused_node,
+                        layout_results,
+                        &window_state.mouse_state.cursor_position,
+                        window_state.size.get_hidpi_factor(),
+                    )
+                },
+            );
+        });
+
+        // rebuild the display list and send it
+        rebuild_display_list(
+            &mut self.internal,
+            &mut self.render_api,
+            image_cache,
+            resource_updates,
+        );
+
+        self.render_api.flush_scene_builder();
+
+        let wr_document_id = wr_translate_document_id(self.internal.document_id);
+        self.hit_tester =
+            AsyncHitTester::Requested(self.render_api.request_hit_tester(wr_document_id));
+
+        self.update_hit_test();
+        self.internal.previous_window_state = None;
+
+        let nodes_to_check = NodesToCheck::simulated_mouse_move(
+            &self.internal.current_window_state.last_hit_test,
+            self.internal.current_window_state.focused_node,
+            self.internal.current_window_state.mouse_state.mouse_down(),
+        );
+
+        let _ = StyleAndLayoutChanges::new(
+            &nodes_to_check,
+            &mut self.internal.layout_results,
+            &image_cache,
+            &mut self.internal.renderer_resources,
+            self.internal.current_window_state.size.get_layout_size(),
+            &self.internal.document_id,
+            None,
+            None,
+            &None,
+            azul_layout::do_the_relayout,
+        );
+
+        self.regenerate_display_list(image_cache);
+        self.dom_regenerated = true;
+    }
+}
+
+impl Drop for WaylandWindow {
+    fn drop(&mut self) {
+        self.make_current();
+        if let Some(renderer) = self.renderer.take() {
+            renderer.deinit();
+        }
+        (self.egl.eglMakeCurrent)(
+            self.egl_display,
+            EGL_NO_SURFACE,
+            EGL_NO_SURFACE,
+            EGL_NO_CONTEXT,
+        );
+
+        (self.wl_egl.wl_egl_window_destroy)(self.egl_window);
+
+        let marshal = self.wl.wl_proxy_marshal;
+        let destroy = self.wl.wl_proxy_destroy;
+        unsafe {
+            if !self.viewport.is_null() {
+                marshal(self.viewport, WP_VIEWPORT_DESTROY);
+                destroy(self.viewport);
+            }
+            if !self.fractional_scale.is_null() {
+                marshal(self.fractional_scale, WP_FRACTIONAL_SCALE_DESTROY);
+                destroy(self.fractional_scale);
+            }
+            marshal(self.xdg_toplevel, XDG_TOPLEVEL_DESTROY);
+            destroy(self.xdg_toplevel);
+            marshal(self.xdg_surface, XDG_SURFACE_DESTROY);
+            destroy(self.xdg_surface);
+            marshal(self.surface, WL_SURFACE_DESTROY);
+            destroy(self.surface);
+        }
+    }
+}
+
+fn encode_utf8(input: &str) -> CString {
+    CString::new(input.replace('\0', "")).unwrap_or_default()
+}
+
+// -- listener implementations
+
+// All listeners get the WaylandApp as their data pointer
+unsafe fn get_app<'a>(data: *mut c_void) -> &'a mut WaylandApp {
+    &mut *(data as *mut WaylandApp)
+}
+
+extern "C" fn registry_global(
+    data: *mut c_void,
+    registry: *mut wl_proxy,
+    name: u32,
+    interface: *const c_char,
+    version: u32,
+) {
+    let app = unsafe { get_app(data) };
+    let wl = app.wl.clone();
+    let interface = unsafe { std::ffi::CStr::from_ptr(interface) };
+
+    match interface.to_str().unwrap_or("") {
+        "wl_compositor" => {
+            app.compositor = wl.bind(registry, name, wl.wl_compositor_interface, version.min(4));
+        }
+        "wl_shm" => {
+            app.shm = wl.bind(registry, name, wl.wl_shm_interface, 1);
+        }
+        "xdg_wm_base" => {
+            app.wm_base = wl.bind(registry, name, &XDG_WM_BASE_INTERFACE, version.min(2));
+            wl.add_listener(app.wm_base, &XDG_WM_BASE_LISTENER, app);
+        }
+        "wl_seat" if app.seat.is_null() => {
+            app.seat = wl.bind(registry, name, wl.wl_seat_interface, version.min(5));
+            wl.add_listener(app.seat, &SEAT_LISTENER, app);
+        }
+        "wl_output" => {
+            let proxy = wl.bind(registry, name, wl.wl_output_interface, version.min(2));
+            wl.add_listener(proxy, &OUTPUT_LISTENER, app);
+            app.outputs.push(WaylandOutput {
+                name,
+                proxy,
+                scale: 1,
+            });
+        }
+        "wp_viewporter" => {
+            app.viewporter = wl.bind(registry, name, &WP_VIEWPORTER_INTERFACE, 1);
+        }
+        "wp_fractional_scale_manager_v1" => {
+            app.fractional_scale_manager =
+                wl.bind(registry, name, &WP_FRACTIONAL_SCALE_MANAGER_V1_INTERFACE, 1);
+        }
+        _ => {}
+    }
+}
+
+extern "C" fn registry_global_remove(data: *mut c_void, _: *mut wl_proxy, name: u32) {
+    let app = unsafe { get_app(data) };
+
+    let output = match app.outputs.iter().position(|o| o.name == name) {
+        Some(s) => app.outputs.remove(s),
+        None => return,
+    };
+
+    (app.wl.wl_proxy_destroy)(output.proxy);
+
+    let window_ids = app
+        .windows
+        .values_mut()
+        .filter_map(|w| {
+            let count = w.entered_outputs.len();
+            w.entered_outputs.retain(|o| *o != output.proxy);
+            if w.entered_outputs.len() != count {
+                Some(w.id)
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+
+    for window_id in window_ids {
+        app.rescale_window(window_id);
+    }
+}
+
+extern "C" fn seat_capabilities(data: *mut c_void, seat: *mut wl_proxy, capabilities: u32) {
+    let app = unsafe { get_app(data) };
+    let wl = app.wl.clone();
+
+    let has_pointer = capabilities & WL_SEAT_CAPABILITY_POINTER != 0;
+    if has_pointer && app.pointer.is_null() {
+        app.pointer = unsafe {
+            (wl.wl_proxy_marshal_constructor)(
+                seat,
+                WL_SEAT_GET_POINTER,
+                wl.wl_pointer_interface,
+                ptr::null_mut::<c_void>(),
+            )
+        };
+        wl.add_listener(app.pointer, &POINTER_LISTENER, app);
+    } else if !has_pointer && !app.pointer.is_null() {
+        unsafe { (wl.wl_proxy_marshal)(app.pointer, WL_POINTER_RELEASE) };
+        (wl.wl_proxy_destroy)(app.pointer);
+        app.pointer = ptr::null_mut();
+        app.current_cursor = None;
+    }
+
+    // TODO: touch screens (wl_touch) and pens (zwp_tablet_v2) are not handled yet,
+    // their events should be passed through a gesture::GestureRecognizer like on X11
+
+    let has_keyboard = capabilities & WL_SEAT_CAPABILITY_KEYBOARD != 0;
+    if has_keyboard && app.keyboard.is_null() {
+        app.keyboard = unsafe {
+            (wl.wl_proxy_marshal_constructor)(
+                seat,
+                WL_SEAT_GET_KEYBOARD,
+                wl.wl_keyboard_interface,
+                ptr::null_mut::<c_void>(),
+            )
+        };
+        wl.add_listener(app.keyboard, &KEYBOARD_LISTENER, app);
+    } else if !has_keyboard && !app.keyboard.is_null() {
+        unsafe { (wl.wl_proxy_marshal)(app.keyboard, WL_KEYBOARD_RELEASE) };
+        (wl.wl_proxy_destroy)(app.keyboard);
+        app.keyboard = ptr::null_mut();
+        app.key_repeat = None;
+    }
+}
+
+extern "C" fn seat_name(_: *mut c_void, _: *mut wl_proxy, _: *const c_char) {}
+
+extern "C" fn pointer_enter(
+    data: *mut c_void,
+    _: *mut wl_proxy,
+    serial: u32,
+    surface: *mut wl_proxy,
+    x: wl_fixed,
+    y: wl_fixed,
+) {
+    let app = unsafe { get_app(data) };
+    let window_id = surface as usize;
+
+    let window = match app.windows.get_mut(&window_id) {
+        Some(s) => s,
+        None => return,
+    };
+
+    app.pointer_serial = serial;
+    app.pointer_focus = Some(window_id);
+    // the compositor resets the cursor when the pointer enters the surface
+    app.current_cursor = None;
+
+    window.internal.previous_window_state = Some(window.internal.current_window_state.clone());
+    window
+        .internal
+        .current_window_state
+        .mouse_state
+        .cursor_position = window.get_cursor_position(x, y);
+
+    app.process_window_event(window_id, true);
+}
+
+extern "C" fn pointer_leave(
+    data: *mut c_void,
+    _: *mut wl_proxy,
+    serial: u32,
+    surface: *mut wl_proxy,
+) {
+    use azul_core::window::{FullHitTest, OptionMouseCursorType};
+
+    let app = unsafe { get_app(data) };
+    let window_id = surface as usize;
+
+    app.pointer_serial = serial;
+    app.pointer_focus = None;
+
+    let window = match app.windows.get_mut(&window_id) {
+        Some(s) => s,
+        None => return,
+    };
+
+    #[cfg(feature = "css_parser")]
+    {
+        window.pressed_decoration = None;
+    }
+
+    let current_focus = window.internal.current_window_state.focused_node;
+    window.internal.previous_window_state = Some(window.internal.current_window_state.clone());
+    let last_seen = match window
+        .internal
+        .current_window_state
+        .mouse_state
+        .cursor_position
+    {
+        CursorPosition::InWindow(i) => i,
+        _ => LogicalPosition::zero(),
+    };
+    let mouse_state = &mut window.internal.current_window_state.mouse_state;
+    mouse_state.cursor_position = CursorPosition::OutOfWindow(last_seen);
+    mouse_state.mouse_cursor_type = OptionMouseCursorType::None;
+    window.internal.current_window_state.last_hit_test = FullHitTest::empty(current_focus);
+
+    app.process_window_event(window_id, false);
+}
+
+extern "C" fn pointer_motion(
+    data: *mut c_void,
+    _: *mut wl_proxy,
+    _: u32,
+    x: wl_fixed,
+    y: wl_fixed,
+) {
+    let app = unsafe { get_app(data) };
+
+    let window_id = match app.pointer_focus {
+        Some(s) => s,
+        None => return,
+    };
+
+    // input of a window that has an open modal child window
+    if app.has_modal_child(window_id) {
+        return;
+    }
+
+    let window = match app.windows.get_mut(&window_id) {
+        Some(s) => s,
+        None => return,
+    };
+
+    window.internal.previous_window_state = Some(window.internal.current_window_state.clone());
+    window
+        .internal
+        .current_window_state
+        .mouse_state
+        .cursor_position = window.get_cursor_position(x, y);
+
+    app.process_window_event(window_id, true);
+}
+
+extern "C" fn pointer_button(
+    data: *mut c_void,
+    _: *mut wl_proxy,
+    serial: u32,
+    _: u32,
+    button: u32,
+    state: u32,
+) {
+    let app = unsafe { get_app(data) };
+    let is_down = state == WL_POINTER_BUTTON_STATE_PRESSED;
+
+    app.pointer_serial = serial;
+
+    let window_id = match app.pointer_focus {
+        Some(s) => s,
+        None => return,
+    };
+
+    // input of a window that has an open modal child window
+    if app.has_modal_child(window_id) {
+        return;
+    }
+
+    #[cfg(feature = "css_parser")]
+    {
+        if app.handle_decoration_button(window_id, button, is_down) {
+            return;
+        }
+    }
+
+    // custom title bars and resize handles
+    if button == BTN_LEFT && is_down && app.start_move_resize(window_id) {
+        return;
+    }
+
+    let window = match app.windows.get_mut(&window_id) {
+        Some(s) => s,
+        None => return,
+    };
+
+    let mut mouse_state = window.internal.current_window_state.mouse_state.clone();
+    match button {
+        BTN_LEFT => mouse_state.left_down = is_down,
+        BTN_RIGHT => mouse_state.right_down = is_down,
+        BTN_MIDDLE => mouse_state.middle_down = is_down,
+        _ => return,
+    }
+
+    window.internal.previous_window_state = Some(window.internal.current_window_state.clone());
+    window.internal.current_window_state.mouse_state = mouse_state;
+
+    app.process_window_event(window_id, true);
+}
+
+extern "C" fn pointer_axis(
+    data: *mut c_void,
+    _: *mut wl_proxy,
+    _: u32,
+    axis: u32,
+    value: wl_fixed,
+) {
+    let app = unsafe { get_app(data) };
+
+    let window_id = match app.pointer_focus {
+        Some(s) => s,
+        None => return,
+    };
+
+    // input of a window that has an open modal child window
+    if app.has_modal_child(window_id) {
+        return;
+    }
+
+    let window = match app.windows.get_mut(&window_id) {
+        Some(s) => s,
+        None => return,
+    };
+
+    // positive values scroll down / right, azul uses positive values for
+    // scrolling up / left (one wheel click = 1.0, same as on X11), the
+    // touchpad reports pixels that are scrolled without acceleration
+    let step = match app.pointer_axis_source {
+        WL_POINTER_AXIS_SOURCE_FINGER | WL_POINTER_AXIS_SOURCE_CONTINUOUS => WHEEL_SCROLL_STEP,
+        _ => SCROLL_WHEEL_STEP,
+    };
+    let delta = -wl_fixed_to_f32(value) / step;
+
+    window.internal.previous_window_state = Some(window.internal.current_window_state.clone());
+    let mouse_state = &mut window.internal.current_window_state.mouse_state;
+    match axis {
+        WL_POINTER_AXIS_VERTICAL_SCROLL => mouse_state.scroll_y = Some(delta).into(),
+        WL_POINTER_AXIS_HORIZONTAL_SCROLL => mouse_state.scroll_x = Some(delta).into(),
+        _ => return,
+    }
+
+    app.process_window_event(window_id, true);
+}
+
+extern "C" fn pointer_frame(_: *mut c_void, _: *mut wl_proxy) {}
+
+extern "C" fn pointer_axis_source(data: *mut c_void, _: *mut wl_proxy, axis_source: u32) {
+    let app = unsafe { get_app(data) };
+    app.pointer_axis_source = axis_source;
+}
+
+// The fingers were lifted from the touchpad: a zero scroll delta
+// tells the default scroll handler to start the kinetic scrolling
+extern "C" fn pointer_axis_stop(data: *mut c_void, _: *mut wl_proxy, _: u32, axis: u32) {
+    let app = unsafe { get_app(data) };
+
+    let window_id = match app.pointer_focus {
+        Some(s) => s,
+        None => return,
+    };
+
+    let window = match app.windows.get_mut(&window_id) {
+        Some(s) => s,
+        None => return,
+    };
+
+    window.internal.previous_window_state = Some(window.internal.current_window_state.clone());
+    let mouse_state = &mut window.internal.current_window_state.mouse_state;
+    match axis {
+        WL_POINTER_AXIS_VERTICAL_SCROLL => mouse_state.scroll_y = Some(0.0).into(),
+        WL_POINTER_AXIS_HORIZONTAL_SCROLL => mouse_state.scroll_x = Some(0.0).into(),
+        _ => return,
+    }
+
+    app.process_window_event(window_id, true);
+}
+
+extern "C" fn pointer_axis_discrete(_: *mut c_void, _: *mut wl_proxy, _: u32, _: i32) {}
+
+extern "C" fn keyboard_keymap(
+    data: *mut c_void,
+    _: *mut wl_proxy,
+    format: u32,
+    fd: i32,
+    size: u32,
+) {
+    let app = unsafe { get_app(data) };
+
+    let xkb = match app.xkb.as_ref() {
+        Some(s) if format == WL_KEYBOARD_KEYMAP_FORMAT_XKB_V1 && !app.xkb_context.is_null() => s,
+        _ => {
+            unsafe { close(fd) };
+            return;
+        }
+    };
+
+    let keymap = unsafe {
+        let map = mmap(
+            ptr::null_mut(),
+            size as usize,
+            PROT_READ,
+            MAP_PRIVATE,
+            fd,
+            0,
+        );
+        if map == MAP_FAILED {
+            close(fd);
+            return;
+        }
+
+        let keymap = (xkb.xkb_keymap_new_from_string)(
+            app.xkb_context,
+            map as *const c_char,
+            XKB_KEYMAP_FORMAT_TEXT_V1,
+            0,
+        );
+
+        munmap(map, size as usize);
+        close(fd);
+        keymap
+    };
+
+    if keymap.is_null() {
+        return;
+    }
+
+    let state = (xkb.xkb_state_new)(keymap);
+    if state.is_null() {
+        (xkb.xkb_keymap_unref)(keymap);
+        return;
+    }
+
+    if !app.xkb_state.is_null() {
+        (xkb.xkb_state_unref)(app.xkb_state);
+    }
+    if !app.xkb_keymap.is_null() {
+        (xkb.xkb_keymap_unref)(app.xkb_keymap);
+    }
+
+    app.xkb_keymap = keymap;
+    app.xkb_state = state;
+}
+
+extern "C" fn keyboard_enter(
+    data: *mut c_void,
+    _: *mut wl_proxy,
+    _: u32,
+    surface: *mut wl_proxy,
+    _: *mut wl_array,
+) {
+    let app = unsafe { get_app(data) };
+    let window_id = surface as usize;
+
+    let window = match app.windows.get_mut(&window_id) {
+        Some(s) => s,
+        None => return,
+    };
+
+    app.keyboard_focus = Some(window_id);
+
+    window.internal.previous_window_state = Some(window.internal.current_window_state.clone());
+    window.internal.current_window_state.flags.has_focus = true;
+
+    app.process_window_event(window_id, false);
+}
+
+extern "C" fn keyboard_leave(data: *mut c_void, _: *mut wl_proxy, _: u32, surface: *mut wl_proxy) {
+    let app = unsafe { get_app(data) };
+    let window_id = surface as usize;
+
+    app.keyboard_focus = None;
+    app.key_repeat = None;
+
+    let window = match app.windows.get_mut(&window_id) {
+        Some(s) => s,
+        None => return,
+    };
+
+    window.internal.previous_window_state = Some(window.internal.current_window_state.clone());
+    window.internal.current_window_state.flags.has_focus = false;
+
+    // the key release events are not sent once the surface lost the
+    // keyboard focus, so the keys would otherwise stay pressed forever
+    let keyboard_state = &mut window.internal.current_window_state.keyboard_state;
+    keyboard_state.current_char = None.into();
+    keyboard_state.current_virtual_keycode = None.into();
+    keyboard_state.pressed_virtual_keycodes = Default::default();
+    keyboard_state.pressed_scancodes = Default::default();
+
+    app.process_window_event(window_id, false);
+}
+
+extern "C" fn keyboard_key(
+    data: *mut c_void,
+    _: *mut wl_proxy,
+    _: u32,
+    _: u32,
+    key: u32,
+    state: u32,
+) {
+    let app = unsafe { get_app(data) };
+
+    let window_id = match app.keyboard_focus {
+        Some(s) => s,
+        None => return,
+    };
+
+    // input of a window that has an open modal child window
+    if app.has_modal_child(window_id) {
+        return;
+    }
+
+    // evdev scancode -> xkb keycode
+    let keycode = key + 8;
+    let is_down = state == WL_KEYBOARD_KEY_STATE_PRESSED;
+
+    // the compositor does not repeat keys, the client has to do it
+    let repeats = match app.xkb.as_ref() {
+        Some(xkb) if !app.xkb_keymap.is_null() => {
+            (xkb.xkb_keymap_key_repeats)(app.xkb_keymap, keycode) != 0
+        }
+        _ => false,
+    };
+
+    if is_down && repeats && app.key_repeat_rate > 0 {
+        app.key_repeat = Some(KeyRepeat {
+            window_id,
+            keycode,
+            next: Instant::now()
+                + std::time::Duration::from_millis(app.key_repeat_delay.max(0) as u64),
+        });
+    } else if app.key_repeat.as_ref().map(|r| r.keycode) == Some(keycode) {
+        app.key_repeat = None;
+    }
+
+    app.handle_key(window_id, keycode, is_down);
+}
+
+extern "C" fn keyboard_modifiers(
+    data: *mut c_void,
+    _: *mut wl_proxy,
+    _: u32,
+    depressed: u32,
+    latched: u32,
+    locked: u32,
+    group: u32,
+) {
+    let app = unsafe { get_app(data) };
+
+    if let Some(xkb) = app.xkb.as_ref() {
+        if !app.xkb_state.is_null() {
+            let _ =
+                (xkb.xkb_state_update_mask)(app.xkb_state, depressed, latched, locked, 0, 0, group);
+        }
+    }
+}
+
+extern "C" fn keyboard_repeat_info(data: *mut c_void, _: *mut wl_proxy, rate: i32, delay: i32) {
+    let app = unsafe { get_app(data) };
+    app.key_repeat_rate = rate;
+    app.key_repeat_delay = delay;
+    if rate <= 0 {
+        app.key_repeat = None;
+    }
+}
+
+extern "C" fn output_geometry(
+    _: *mut c_void,
+    _: *mut wl_proxy,
+    _: i32,
+    _: i32,
+    _: i32,
+    _: i32,
+    _: i32,
+    _: *const c_char,
+    _: *const c_char,
+    _: i32,
+) {
+}
+
+extern "C" fn output_mode(_: *mut c_void, _: *mut wl_proxy, _: u32, _: i32, _: i32, _: i32) {}
+
+extern "C" fn output_done(data: *mut c_void, output: *mut wl_proxy) {
+    let app = unsafe { get_app(data) };
+
+    let window_ids = app
+        .windows
+        .values()
+        .filter(|w| w.preferred_scale.is_none() && w.entered_outputs.contains(&output))
+        .map(|w| w.id)
+        .collect::<Vec<_>>();
+
+    for window_id in window_ids {
+        app.rescale_window(window_id);
+    }
+}
+
+extern "C" fn output_scale(data: *mut c_void, output: *mut wl_proxy, factor: i32) {
+    let app = unsafe { get_app(data) };
+    if let Some(o) = app.outputs.iter_mut().find(|o| o.proxy == output) {
+        o.scale = factor.max(1);
+    }
+}
+
+extern "C" fn surface_enter(data: *mut c_void, surface: *mut wl_proxy, output: *mut wl_proxy) {
+    let app = unsafe { get_app(data) };
+    let window_id = surface as usize;
+
+    match app.windows.get_mut(&window_id) {
+        Some(w) => w.entered_outputs.push(output),
+        None => return,
+    }
+
+    app.rescale_window(window_id);
+}
+
+extern "C" fn surface_leave(data: *mut c_void, surface: *mut wl_proxy, output: *mut wl_proxy) {
+    let app = unsafe { get_app(data) };
+    let window_id = surface as usize;
+
+    match app.windows.get_mut(&window_id) {
+        Some(w) => w.entered_outputs.retain(|o| *o != output),
+        None => return,
+    }
+
+    app.rescale_window(window_id);
+}
+
+extern "C" fn xdg_wm_base_ping(data: *mut c_void, wm_base: *mut wl_proxy, serial: u32) {
+    let app = unsafe { get_app(data) };
+    unsafe { (app.wl.wl_proxy_marshal)(wm_base, XDG_WM_BASE_PONG, serial) };
+}
+
+extern "C" fn xdg_toplevel_configure(
+    data: *mut c_void,
+    toplevel: *mut wl_proxy,
+    width: i32,
+    height: i32,
+    states: *mut wl_array,
+) {
+    let app = unsafe { get_app(data) };
+
+    let window_id = match app.find_window(|w| w.xdg_toplevel == toplevel) {
+        Some(s) => s,
+        None => return,
+    };
+
+    let window = match app.windows.get_mut(&window_id) {
+        Some(s) => s,
+        None => return,
+    };
+
+    // 0 x 0 = the client should decide its own size
+    window.pending_size = if width > 0 && height > 0 {
+        Some(LogicalSize::new(width as f32, height as f32))
+    } else {
+        None
+    };
+
+    window.pending_states = unsafe {
+        if states.is_null() || (*states).data.is_null() {
+            Vec::new()
+        } else {
+            core::slice::from_raw_parts(
+                (*states).data as *const u32,
+                (*states).size / mem::size_of::<u32>(),
+            )
+            .to_vec()
+        }
+    };
+}
+
+extern "C" fn xdg_toplevel_close(data: *mut c_void, toplevel: *mut wl_proxy) {
+    let app = unsafe { get_app(data) };
+    if let Some(window_id) = app.find_window(|w| w.xdg_toplevel == toplevel) {
+        app.windows_to_close.push(window_id);
+    }
+}
+
+extern "C" fn xdg_surface_configure(data: *mut c_void, xdg_surface: *mut wl_proxy, serial: u32) {
+    let app = unsafe { get_app(data) };
+
+    let window_id = match app.find_window(|w| w.xdg_surface == xdg_surface) {
+        Some(s) => s,
+        None => return,
+    };
+
+    let size = {
+        let window = match app.windows.get_mut(&window_id) {
+            Some(s) => s,
+            None => return,
+        };
+
+        unsafe { (app.wl.wl_proxy_marshal)(xdg_surface, XDG_SURFACE_ACK_CONFIGURE, serial) };
+
+        let states = mem::replace(&mut window.pending_states, Vec::new());
+        window.is_configured = true;
+        window.needs_redraw = true;
+        window.is_activated = states.contains(&XDG_TOPLEVEL_STATE_ACTIVATED);
+        window.is_maximized = states.contains(&XDG_TOPLEVEL_STATE_MAXIMIZED);
+
+        let frame = if states.contains(&XDG_TOPLEVEL_STATE_FULLSCREEN) {
+            WindowFrame::Fullscreen
+        } else if window.is_maximized {
+            WindowFrame::Maximized
+        } else {
+            WindowFrame::Normal
+        };
+
+        // the compositor changed the frame: no need to send it back
+        window.internal.current_window_state.flags.frame = frame;
+        window.applied_frame = frame;
+
+        window
+            .pending_size
+            .take()
+            .unwrap_or(window.internal.current_window_state.size.dimensions)
+    };
+
+    app.resize_window(window_id, size);
+    app.sync_window_state(window_id);
+}
+
+extern "C" fn fractional_scale_preferred_scale(
+    data: *mut c_void,
+    fractional_scale: *mut wl_proxy,
+    scale: u32,
+) {
+    let app = unsafe { get_app(data) };
+
+    let window_id = match app.find_window(|w| w.fractional_scale == fractional_scale) {
+        Some(s) => s,
+        None => return,
+    };
+
+    if let Some(window) = app.windows.get_mut(&window_id) {
+        // the scale is sent in 120ths
+        window.preferred_scale = Some(scale as f32 / 120.0);
+    }
+
+    app.rescale_window(window_id);
+}
+
+// Assuming that current_window_state and the previous_window_state of the window
+// are set correctly and the hit-test has been performed, will call the callbacks
+// and return what the application should do next
+#[must_use]
+fn process_event(
+    window: &mut WaylandWindow,
+    fc_cache: &mut LazyFcCache,
+    image_cache: &mut ImageCache,
+    config: &AppConfig,
+    new_windows: &mut Vec<WindowCreateOptions>,
+    destroyed_windows: &mut Vec<usize>,
+) -> ProcessEventResult {
+    use azul_core::window_state::{CallbacksOfHitTest, Events};
+
+    // Get events
+    let events = Events::new(
+        &window.internal.current_window_state,
+        &window.internal.previous_window_state,
+    );
+
+    // Get nodes for events
+    let nodes_to_check =
+        NodesToCheck::new(&window.internal.current_window_state.last_hit_test, &events);
+
+    // Drag the scrollbars before the callbacks see the mouse input
+    let scrollbar_result = window.internal.process_scrollbar_input();
+
+    // Invoke callbacks on nodes
+    let callback_result = fc_cache.apply_closure(|fc_cache| {
+        use azul_core::window::{RawWindowHandle, WaylandHandle};
+
+        let window_handle = RawWindowHandle::Wayland(WaylandHandle {
+            surface: window.surface as *mut c_void,
+            display: window.display as *mut c_void,
+        });
+
+        // the nodes below a pressed scrollbar don't get the mouse input
+        if scrollbar_result.mouse_captured {
+            return window.internal.invoke_scrolled_callbacks(
+                &scrollbar_result.scrolled_nodes,
+                &window_handle,
+                &window.gl_context_ptr,
+                image_cache,
+                fc_cache,
+                &config.system_callbacks,
+            );
+        }
+
+        // Get callbacks for nodes
+        let mut callbacks =
+            CallbacksOfHitTest::new(&nodes_to_check, &events, &window.internal.layout_results);
+
+        let current_scroll_states = window.internal.get_current_scroll_states();
+
+        // Invoke user-defined callbacks in the UI
+        callbacks.call(
+            &window.internal.previous_window_state,
+            &window.internal.current_window_state,
+            &window_handle,
+            &current_scroll_states,
+            &window.gl_context_ptr,
+            &mut window.internal.layout_results,
+            &mut window.internal.scroll_states,
+            image_cache,
+            fc_cache,
+            &config.system_callbacks,
+            &window.internal.renderer_resources,
+        )
+    });
+
+    let result = process_callback_results(
+        callback_result,
+        window,
+        &nodes_to_check,
+        image_cache,
+        fc_cache,
+        new_windows,
+        destroyed_windows,
+    );
+
+    // the scrollbars are drawn on top of the display list
+    if scrollbar_result.needs_redraw {
+        result.max(ProcessEventResult::ShouldUpdateDisplayListCurrentWindow)
+    } else if scrollbar_result.scrolled_nodes.is_empty() {
+        result
+    } else if window.internal.scrollbar_geometry_changed() {
+        // scrolling a nested scroll container can clip the scrollbars inside of it
+        result.max(ProcessEventResult::ShouldUpdateDisplayListCurrentWindow)
+    } else {
+        // dragging a scrollbar only moves the thumbs, see generate_frame()
+        result.max(ProcessEventResult::ShouldReRenderCurrentWindow)
+    }
+}
+
+// Invokes a MenuCallback that is not attached to a DOM node
+// (menu items of the system tray and the menu bar, clicked notifications)
+#[must_use]
+fn invoke_menu_callback(
+    window: &mut WaylandWindow,
+    mut menu_callback: MenuCallback,
+    fc_cache: &mut LazyFcCache,
+    image_cache: &mut ImageCache,
+    config: &AppConfig,
+    new_windows: &mut Vec<WindowCreateOptions>,
+    destroyed_windows: &mut Vec<usize>,
+) -> ProcessEventResult {
+    use azul_core::callbacks::DomNodeId;
+    use azul_core::styled_dom::NodeHierarchyItemId;
+    use azul_core::window::{RawWindowHandle, WaylandHandle};
+
+    window.internal.previous_window_state = Some(window.internal.current_window_state.clone());
+
+    let window_handle = RawWindowHandle::Wayland(WaylandHandle {
+        surface: window.surface as *mut c_void,
+        display: window.display as *mut c_void,
+    });
+
+    let internal = &mut window.internal;
+    let gl_context_ptr = &window.gl_context_ptr;
+
+    let callback_result = fc_cache.apply_closure(|fc_cache| {
+        internal.invoke_menu_callback(
+            &mut menu_callback,
+            DomNodeId {
+                dom: DomId::ROOT_ID,
+                node: NodeHierarchyItemId::from_crate_internal(None),
+            },
+            &window_handle,
+            gl_context_ptr,
+            image_cache,
+            fc_cache,
+            &config.system_callbacks,
+        )
+    });
+
+    let nodes_to_check = NodesToCheck::empty(
+        window
+            .internal
+            .current_window_state
+            .mouse_state
+            .mouse_down(),
+        window.internal.current_window_state.focused_node,
+    );
+
+    process_callback_results(
+        callback_result,
+        window,
+        &nodes_to_check,
+        image_cache,
+        fc_cache,
+        new_windows,
+        destroyed_windows,
+    )
+}
+
+// Invokes the result_callback of a closed child window on its parent window
+#[must_use]
+fn invoke_window_result_callback(
+    window: &mut WaylandWindow,
+    result_callback: &mut WindowResultCallback,
+    window_result: &mut RefAny,
+    fc_cache: &mut LazyFcCache,
+    image_cache: &mut ImageCache,
+    config: &AppConfig,
+    new_windows: &mut Vec<WindowCreateOptions>,
+    destroyed_windows: &mut Vec<usize>,
+) -> ProcessEventResult {
+    use azul_core::window::{RawWindowHandle, WaylandHandle};
+
+    window.internal.previous_window_state = Some(window.internal.current_window_state.clone());
+
+    let window_handle = RawWindowHandle::Wayland(WaylandHandle {
+        surface: window.surface as *mut c_void,
+        display: window.display as *mut c_void,
+    });
+
+    let internal = &mut window.internal;
+    let gl_context_ptr = &window.gl_context_ptr;
+
+    let callback_result = fc_cache.apply_closure(|fc_cache| {
+        internal.invoke_window_result_callback(
+            result_callback,
+            window_result,
+            &window_handle,
+            gl_context_ptr,
+            image_cache,
+            fc_cache,
+            &config.system_callbacks,
+        )
+    });
+
+    let nodes_to_check = NodesToCheck::empty(
+        window
+            .internal
+            .current_window_state
+            .mouse_state
+            .mouse_down(),
+        window.internal.current_window_state.focused_node,
+    );
+
+    process_callback_results(
+        callback_result,
+        window,
+        &nodes_to_check,
+        image_cache,
+        fc_cache,
+        new_windows,
+        destroyed_windows,
+    )
+}
+
+// Polls the messages of all threads of the window and invokes their WriteBack callbacks
+#[must_use]
+fn invoke_threads(
+    window: &mut WaylandWindow,
+    data: &mut RefAny,
+    fc_cache: &mut LazyFcCache,
+    image_cache: &mut ImageCache,
+    config: &AppConfig,
+    new_windows: &mut Vec<WindowCreateOptions>,
+    destroyed_windows: &mut Vec<usize>,
+) -> ProcessEventResult {
+    use azul_core::window::{RawWindowHandle, WaylandHandle};
+
+    window.internal.previous_window_state = Some(window.internal.current_window_state.clone());
+
+    let window_handle = RawWindowHandle::Wayland(WaylandHandle {
+        surface: window.surface as *mut c_void,
+        display: window.display as *mut c_void,
+    });
+
+    let internal = &mut window.internal;
+    let gl_context_ptr = &window.gl_context_ptr;
+
+    let callback_result = fc_cache.apply_closure(|fc_cache| {
+        internal.run_all_threads(
+            data,
+            &window_handle,
+            gl_context_ptr,
+            image_cache,
+            fc_cache,
+            &config.system_callbacks,
+        )
+    });
+
+    let nodes_to_check = NodesToCheck::empty(
+        window
+            .internal
+            .current_window_state
+            .mouse_state
+            .mouse_down(),
+        window.internal.current_window_state.focused_node,
+    );
+
+    process_callback_results(
+        callback_result,
+        window,
+        &nodes_to_check,
+        image_cache,
+        fc_cache,
+        new_windows,
+        destroyed_windows,
+    )
+}
+
+// Runs the timers of the window that are due
+#[must_use]
+fn invoke_timers(
+    window: &mut WaylandWindow,
+    fc_cache: &mut LazyFcCache,
+    image_cache: &mut ImageCache,
+    config: &AppConfig,
+    new_windows: &mut Vec<WindowCreateOptions>,
+    destroyed_windows: &mut Vec<usize>,
+) -> ProcessEventResult {
+    use azul_core::window::{RawWindowHandle, WaylandHandle};
+
+    let window_handle = RawWindowHandle::Wayland(WaylandHandle {
+        surface: window.surface as *mut c_void,
+        display: window.display as *mut c_void,
+    });
+
+    let frame_start = (config.system_callbacks.get_system_time_fn.cb)();
+    let due_timers = window
+        .internal
+        .timers
+        .iter()
+        .filter(|(_, timer)| timer.millis_until_next_run(&frame_start) == 0)
+        .map(|(timer_id, _)| timer_id.id)
+        .collect::<Vec<_>>();
+
+    let mut result = ProcessEventResult::DoNothing;
+
+    for timer_id in due_timers {
+        window.internal.previous_window_state = Some(window.internal.current_window_state.clone());
+
+        let internal = &mut window.internal;
+        let gl_context_ptr = &window.gl_context_ptr;
+
+        let callback_result = fc_cache.apply_closure(|fc_cache| {
+            internal.run_single_timer(
+                timer_id,
+                frame_start.clone(),
+                &window_handle,
+                gl_context_ptr,
+                image_cache,
+                fc_cache,
+                &config.system_callbacks,
+            )
+        });
+
+        let nodes_to_check = NodesToCheck::empty(
+            window
+                .internal
+                .current_window_state
+                .mouse_state
+                .mouse_down(),
+            window.internal.current_window_state.focused_node,
+        );
+
+        result = result.max(process_callback_results(
+            callback_result,
+            window,
+            &nodes_to_check,
+            image_cache,
+            fc_cache,
+            new_windows,
+            destroyed_windows,
+        ));
+    }
+
+    result
+}
+
+#[must_use]
+fn process_callback_results(
+    callback_results: CallCallbacksResult,
+    window: &mut WaylandWindow,
+    nodes_to_check: &NodesToCheck,
+    image_cache: &mut ImageCache,
+    fc_cache: &mut LazyFcCache,
+    new_windows: &mut Vec<WindowCreateOptions>,
+    destroyed_windows: &mut Vec<usize>,
+) -> ProcessEventResult {
+    use crate::wr_translate::wr_translate_document_id;
+    use azul_core::callbacks::Update;
+    use azul_core::window_state::StyleAndLayoutChanges;
+
+    let mut result = ProcessEventResult::DoNothing;
+
+    if callback_results.images_changed.is_some() || callback_results.image_masks_changed.is_some() {
+        let updated_images = window.internal.renderer_resources.update_image_resources(
+            &window.internal.layout_results,
+            callback_results.images_changed.unwrap_or_default(),
+            callback_results.image_masks_changed.unwrap_or_default(),
+            &crate::app::CALLBACKS,
+            &*image_cache,
+            &mut window.internal.gl_texture_cache,
+            window.internal.document_id,
+            window.internal.epoch,
+        );
+
+        if !updated_images.is_empty() {
+            let mut txn = WrTransaction::new();
+            wr_synchronize_updated_images(updated_images, &window.internal.document_id, &mut txn);
+            window
+                .render_api
+                .send_transaction(wr_translate_document_id(window.internal.document_id), txn);
+            result = result.max(ProcessEventResult::ShouldReRenderCurrentWindow);
+        }
+    }
+
+    // timers are run in the main loop (see invoke_timers)
+    if let Some(timers) = callback_results.timers {
+        window.internal.timers.extend(timers.into_iter());
+    }
+    if let Some(timers_removed) = callback_results.timers_removed.as_ref() {
+        window
+            .internal
+            .timers
+            .retain(|id, _| !timers_removed.contains(id));
+    }
+
+    // closed in the main loop, since the windows are identified by their WindowId
+    window
+        .windows_closed
+        .extend(callback_results.windows_closed.into_iter());
+    if let Some(result) = callback_results.window_result {
+        window.window_result = Some(result);
+    }
+
+    // applied in the main loop, since the registry is stored in the application data
+    window
+        .shortcuts_changed
+        .extend(callback_results.shortcuts_changed.into_iter());
+
+    // threads are polled in the main loop (see invoke_threads)
+    if let Some(threads) = callback_results.threads {
+        window.internal.threads.extend(threads.into_iter());
+    }
+    if let Some(threads_removed) = callback_results.threads_removed.as_ref() {
+        window
+            .internal
+            .threads
+            .retain(|id, _| !threads_removed.contains(id));
+    }
+
+    for w in callback_results.windows_created {
+        new_windows.push(w);
+    }
+
+    if !callback_results.notifications.is_empty()
+        && window.notifications.is_none()
+        && window.wake_fd >= 0
+    {
+        window.notifications = notify::Notifications::new(tray::TrayWaker::Pipe(window.wake_fd));
+    }
+    if let Some(notifications) = window.notifications.as_mut() {
+        for notification in callback_results.notifications.iter() {
+            notifications.show(notification);
+        }
+    }
+
+    let scroll = window
+        .internal
+        .current_window_state
+        .process_system_scroll(&window.internal.scroll_states);
+    let need_scroll_render = scroll.is_some() || callback_results.should_scroll_render;
+
+    if let Some(modified) = callback_results.modified_window_state.as_ref() {
+        if modified.flags.is_about_to_close {
+            destroyed_windows.push(window.id);
+        }
+        window.internal.current_window_state = FullWindowState::from_window_state(
+            modified,
+            window.internal.current_window_state.dropped_file.clone(),
+            window.internal.current_window_state.hovered_file.clone(),
+            window.internal.current_window_state.focused_node.clone(),
+            window.internal.current_window_state.last_hit_test.clone(),
+            window.internal.current_window_state.clipboard_sequence,
+            window.internal.current_window_state.drag_state.clone(),
+            window.internal.current_window_state.ime_state.clone(),
+        );
+        if modified.size.get_layout_size()
+            != window.internal.current_window_state.size.get_layout_size()
+        {
+            result = result.max(ProcessEventResult::UpdateHitTesterAndProcessAgain);
+        } else if !need_scroll_render {
+            result = result.max(ProcessEventResult::ShouldReRenderCurrentWindow);
+        }
+    }
+
+    if let Some(drag_data) = callback_results.update_drag_data {
+        window
+            .internal
+            .current_window_state
+            .drag_state
+            .update_drag_data(drag_data);
+    }
+
+    // the drag image is drawn on top of the display list
+    if window
+        .internal
+        .current_window_state
+        .drag_image_changed(&window.internal.previous_window_state)
+    {
+        result = result.max(ProcessEventResult::ShouldUpdateDisplayListCurrentWindow);
+    }
+
+    let layout_callback_changed = window
+        .internal
+        .current_window_state
+        .layout_callback_changed(&window.internal.previous_window_state);
+
+    if layout_callback_changed {
+        return ProcessEventResult::ShouldRegenerateDomCurrentWindow;
+    } else {
+        match callback_results.callbacks_update_screen {
+            Update::RefreshDom => {
+                return ProcessEventResult::ShouldRegenerateDomCurrentWindow;
+            }
+            Update::RefreshDomAllWindows => {
+                return ProcessEventResult::ShouldRegenerateDomAllWindows;
+            }
+            Update::DoNothing => {}
+        }
+    }
+
+    // Re-layout and re-style the window.internal.layout_results
+    let style_layout_changes = StyleAndLayoutChanges::new(
+        &nodes_to_check,
+        &mut window.internal.layout_results,
+        &image_cache,
+        &mut window.internal.renderer_resources,
+        window.internal.current_window_state.size.get_layout_size(),
+        &window.internal.document_id,
+        callback_results.css_properties_changed.as_ref(),
+        callback_results.words_changed.as_ref(),
+        &callback_results.update_focused_node,
+        azul_layout::do_the_relayout,
+    );
+
+    if let Some(rsn) = style_layout_changes.nodes_that_changed_size.as_ref() {
+        let updated_images = fc_cache.apply_closure(|fc_cache| {
+            LayoutResult::resize_images(
+                window.internal.id_namespace,
+                window.internal.document_id,
+                window.internal.epoch,
+                DomId::ROOT_ID,
+                &image_cache,
+                &window.gl_context_ptr,
+                &mut window.internal.layout_results,
+                &mut window.internal.gl_texture_cache,
+                &mut window.internal.renderer_resources,
+                &crate::app::CALLBACKS,
+                azul_layout::do_the_relayout,
+                fc_cache,
+                &window.internal.current_window_state.size,
+                window.internal.current_window_state.theme,
+                &rsn,
+            )
+        });
+
+        if !updated_images.is_empty() {
+            let mut txn = WrTransaction::new();
+            wr_synchronize_updated_images(updated_images, &window.internal.document_id, &mut txn);
+            window
+                .render_api
+                .send_transaction(wr_translate_document_id(window.internal.document_id), txn);
+        }
+    }
+
+    // FOCUS CHANGE HAPPENS HERE!
+    if let Some(focus_change) = style_layout_changes.focus_change.clone() {
+        window.internal.current_window_state.focused_node = focus_change.new;
+    }
+
+    // TODO: system scrolling is not yet implemented
+    if scroll.is_some() {
+        window
+            .internal
+            .current_window_state
+            .mouse_state
+            .reset_scroll_to_zero();
+    }
+
+    if style_layout_changes.did_resize_nodes() {
+        // at least update the hit-tester
+        result.max(ProcessEventResult::UpdateHitTesterAndProcessAgain)
+    } else if style_layout_changes.need_regenerate_display_list()
+        || (need_scroll_render && window.internal.scrollbar_geometry_changed())
+    {
+        // scrolling a nested scroll container can clip the scrollbars inside of it
+        result.max(ProcessEventResult::ShouldUpdateDisplayListCurrentWindow)
+    } else if need_scroll_render || style_layout_changes.need_redraw() {
+        // scrolling only moves the scrollbars, see generate_frame()
+        result.max(ProcessEventResult::ShouldReRenderCurrentWindow)
+    } else {
+        result
+    }
+}
+
+// Regenerates the DOM / display list or re-renders the window, depending on the
+// result of process_event(). Returns whether the DOM of all windows needs to be
+// regenerated, which has to be done by the caller (since it owns all windows)
+fn apply_process_event_result(
+    mut result: ProcessEventResult,
+    window: &mut WaylandWindow,
+    app: &mut ApplicationData,
+    new_windows: &mut Vec<WindowCreateOptions>,
+    destroyed_windows: &mut Vec<usize>,
+) -> bool {
+    loop {
+        match result {
+            ProcessEventResult::DoNothing => return false,
+            ProcessEventResult::ShouldReRenderCurrentWindow => {
+                generate_frame(&mut window.internal, &mut window.render_api, false);
+                window.request_redraw();
+                return false;
+            }
+            ProcessEventResult::ShouldUpdateDisplayListCurrentWindow => {
+                window.regenerate_display_list(&app.image_cache);
+                return false;
+            }
+            ProcessEventResult::UpdateHitTesterAndProcessAgain => {
+                window.internal.previous_window_state =
+                    Some(window.internal.current_window_state.clone());
+                window.regenerate_display_list(&app.image_cache);
+                window.update_hit_test();
+                result = process_event(
+                    window,
+                    &mut app.fc_cache,
+                    &mut app.image_cache,
+                    &app.config,
+                    new_windows,
+                    destroyed_windows,
+                );
+            }
+            ProcessEventResult::ShouldRegenerateDomCurrentWindow => {
+                window.regenerate_dom(app);
+                return false;
+            }
+            ProcessEventResult::ShouldRegenerateDomAllWindows => return true,
+        }
+    }
+}
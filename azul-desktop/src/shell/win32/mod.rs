@@ -6,6 +6,7 @@
 
 mod event;
 mod dpi;
+mod shortcuts;
 
 use crate::{
     app::{App, LazyFcCache},
@@ -32,7 +33,8 @@ use azul_core::{
     },
     callbacks::{
         RefAny, UpdateImageType,
-        DomNodeId, DocumentId
+        DomNodeId, DocumentId,
+        LayoutCallback,
     },
     gl::OptionGlContextPtr,
     task::{Thread, ThreadId, Timer, TimerId},
@@ -44,7 +46,9 @@ use azul_core::{
         LogicalSize, Menu, MenuCallback, MenuItem,
         MonitorVec, WindowCreateOptions, WindowInternal,
         WindowState, FullWindowState, ScrollResult,
-        MouseCursorType, CallCallbacksResult
+        MouseCursorType, CallCallbacksResult,
+        WindowId, WindowResultCallback, PersistedWindowState,
+        PhysicalSize, ShortcutChange, ShortcutRegistry,
     },
     window_state::NodesToCheck,
 };
@@ -86,7 +90,8 @@ use winapi::{
     um::winuser::WM_APP,
 };
 use self::dpi::DpiFunctions;
-use azul_css::FloatValue;
+use self::shortcuts::GlobalHotKeys;
+use azul_css::{AzString, FloatValue};
 
 type TIMERPTR = winapi::shared::basetsd::UINT_PTR;
 
@@ -99,6 +104,8 @@ const AZ_REGENERATE_DOM: u32 = WM_APP + 1;
 const AZ_REGENERATE_DISPLAY_LIST: u32 = WM_APP + 2;
 const AZ_REDO_HIT_TEST: u32 = WM_APP + 3;
 const AZ_GPU_SCROLL_RENDER: u32 = WM_APP + 4;
+const AZ_WINDOW_RESULT: u32 = WM_APP + 5;
+const AZ_SHORTCUT: u32 = WM_APP + 6;
 
 const CLASS_NAME: &str = "AzulApplicationClass";
 
@@ -178,6 +185,7 @@ pub fn run(app: App, root_window: WindowCreateOptions) -> Result<isize, WindowsS
             windows,
             image_cache,
             fc_cache,
+            shortcuts,
         } = app;
 
         let app_data_inner = Rc::new(RefCell::new(ApplicationData {
@@ -190,6 +198,8 @@ pub fn run(app: App, root_window: WindowCreateOptions) -> Result<isize, WindowsS
             active_hwnds: active_hwnds.clone(),
             dwm,
             dpi,
+            shortcuts,
+            global_hot_keys: GlobalHotKeys::new(),
         }));
 
         let w = Window::create(
@@ -199,10 +209,13 @@ pub fn run(app: App, root_window: WindowCreateOptions) -> Result<isize, WindowsS
         )?;
 
         active_hwnds.try_borrow_mut()?.insert(w.hwnd);
-        app_data_inner
-            .try_borrow_mut()?
-            .windows
-            .insert(w.get_id(), w);
+        {
+            // the global shortcuts are registered with the root window
+            let mut app_borrow = app_data_inner.try_borrow_mut()?;
+            let ab = &mut *app_borrow;
+            ab.global_hot_keys.sync(w.hwnd, &ab.shortcuts);
+            ab.windows.insert(w.get_id(), w);
+        }
 
         for opts in windows {
             if let Ok(w) = Window::create(hinstance, opts, SharedApplicationData { inner: app_data_inner.clone() }) {
@@ -425,6 +438,10 @@ struct ApplicationData {
     active_hwnds: Rc<RefCell<BTreeSet<HWND>>>,
     dwm: Option<DwmFunctions>,
     dpi: DpiFunctions,
+    // keyboard shortcuts of the application (App::register_shortcut)
+    shortcuts: ShortcutRegistry,
+    // hot keys of the shortcuts with ShortcutScope::Global
+    global_hot_keys: GlobalHotKeys,
 }
 
 // Extra functions from dwmapi.dll
@@ -1608,6 +1625,24 @@ struct Window {
     thread_timer_running: Option<TIMERPTR>,
     /// characters are combined via two following wparam messages
     high_surrogate: Option<u16>,
+    /// Parent window of a child window (see `WindowCreateOptions::parent`)
+    parent: Option<WindowId>,
+    /// Whether the parent window is disabled while this window is open
+    modal: bool,
+    /// Invoked on the parent window with the result of this window when it is closed
+    result_callback: Option<WindowResultCallback>,
+    /// Result of this window, set with `CallbackInfo::set_window_result`
+    window_result: Option<RefAny>,
+    /// Windows closed with `CallbackInfo::close_window`, resolved in `destroy_windows`
+    windows_closed: Vec<WindowId>,
+    /// Results of closed child windows, handled in `AZ_WINDOW_RESULT`
+    pending_window_results: Vec<(WindowResultCallback, RefAny)>,
+    /// Callbacks of the keyboard shortcuts that were pressed, handled in `AZ_SHORTCUT`
+    pending_shortcuts: Vec<MenuCallback>,
+    /// Keyboard shortcuts changed in the callbacks of this window (`CallbackInfo::register_shortcut`)
+    shortcuts_changed: Vec<ShortcutChange>,
+    /// Key of the saved window geometry (see `WindowCreateOptions::persistence_key`)
+    persistence_key: Option<AzString>,
 }
 
 impl fmt::Debug for Window {
@@ -1695,7 +1730,8 @@ impl Window {
                 winuser::{
                     CreateWindowExW, DestroyWindow, GetClientRect, GetCursorPos, GetDC,
                     GetWindowRect, ReleaseDC, ScreenToClient, SetMenu, CW_USEDEFAULT, WS_CAPTION,
-                    WS_EX_ACCEPTFILES, WS_EX_APPWINDOW, WS_MAXIMIZEBOX, WS_MINIMIZEBOX,
+                    WS_EX_ACCEPTFILES, WS_EX_APPWINDOW, WS_EX_LAYERED, WS_EX_TRANSPARENT,
+                    WS_MAXIMIZEBOX, WS_MINIMIZEBOX,
                     WS_OVERLAPPED, WS_POPUP, WS_SYSMENU, WS_TABSTOP, WS_THICKFRAME,
                     ShowWindow, SW_HIDE, SW_MAXIMIZE, SW_MINIMIZE, SW_NORMAL, SW_SHOWNORMAL,
                 },
@@ -1704,6 +1740,13 @@ impl Window {
         use winapi::um::winuser::{
             SetWindowPos, HWND_TOP, SWP_FRAMECHANGED, SWP_NOMOVE, SWP_NOZORDER,
         };
+        // child windows are owned by their parent window: they stay on top
+        // of it and are destroyed together with it
+        let owner_window = options.parent.into_option().and_then(|p| {
+            let app = shared_application_data.inner.try_borrow().ok()?;
+            app.windows.values().find(|w| w.internal.id == p).map(|w| w.hwnd)
+        });
+
         let parent_window = match options
             .state
             .platform_specific_options
@@ -1712,7 +1755,15 @@ impl Window {
             .as_ref()
         {
             Some(hwnd) => (*hwnd) as HWND,
-            None => ptr::null_mut(),
+            None => owner_window.unwrap_or(ptr::null_mut()),
+        };
+
+        // click-through windows: WS_EX_TRANSPARENT only lets the mouse input
+        // pass through to other windows if the window is also layered
+        let ex_style = if options.state.flags.is_click_through {
+            WS_EX_APPWINDOW | WS_EX_ACCEPTFILES | WS_EX_LAYERED | WS_EX_TRANSPARENT
+        } else {
+            WS_EX_APPWINDOW | WS_EX_ACCEPTFILES
         };
 
         let mut class_name = encode_wide(CLASS_NAME);
@@ -1723,7 +1774,7 @@ impl Window {
         // Create the window
         let hwnd = unsafe {
             CreateWindowExW(
-                WS_EX_APPWINDOW | WS_EX_ACCEPTFILES,
+                ex_style,
                 class_name.as_mut_ptr(),
                 window_title.as_mut_ptr(),
                 WS_OVERLAPPED
@@ -1752,6 +1803,20 @@ impl Window {
             ));
         }
 
+        // a layered window is invisible until its attributes are set
+        if options.state.flags.is_click_through {
+            use winapi::um::winuser::{SetLayeredWindowAttributes, LWA_ALPHA};
+            unsafe { SetLayeredWindowAttributes(hwnd, 0, 255, LWA_ALPHA); }
+        }
+
+        // the parent of a modal window doesn't receive input until the window is destroyed
+        if let Some(owner) = owner_window {
+            if options.modal {
+                use winapi::{shared::minwindef::FALSE, um::winuser::EnableWindow};
+                unsafe { EnableWindow(owner, FALSE); }
+            }
+        }
+
         // Get / store DPI
         // NOTE: GetDpiForWindow would be easier, but it's Win10 only
         let dpi = if let Ok(s) = shared_application_data.inner.try_borrow() {
@@ -1764,6 +1829,14 @@ impl Window {
 
         options.state.size.dpi = dpi;
 
+        // geometry of the window when it was closed in the last session
+        let restored_state = options.persistence_key.as_ref()
+            .and_then(|key| crate::persistence::load_window_state(key.as_str()));
+        if let Some(restored_state) = restored_state.as_ref() {
+            restore_window_placement(hwnd, restored_state, dpi_factor);
+            options.state.flags.frame = restored_state.get_restored_frame();
+        }
+
         // Window created, now try initializing OpenGL context
         let renderer_types = match options.renderer.into_option() {
             Some(s) => match s.hw_accel {
@@ -2106,6 +2179,15 @@ impl Window {
             timers: BTreeMap::new(),
             thread_timer_running: None,
             high_surrogate: None,
+            parent: options.parent.into_option(),
+            modal: options.modal,
+            result_callback: options.result_callback.clone().into_option(),
+            window_result: None,
+            windows_closed: Vec::new(),
+            pending_window_results: Vec::new(),
+            pending_shortcuts: Vec::new(),
+            shortcuts_changed: Vec::new(),
+            persistence_key: options.persistence_key.clone().into_option(),
         };
 
         // invoke the create callback, if there is any
@@ -2167,6 +2249,7 @@ impl Window {
             let mut appdata_lock = shared_application_data.inner.try_borrow_mut().unwrap();
             let mut ab = &mut *appdata_lock;
             destroy_windows(ab, destroyed_windows);
+            apply_shortcut_changes(ab);
 
             unsafe { ReleaseDC(hwnd, hdc); }
         }
@@ -2551,6 +2634,7 @@ unsafe extern "system" fn WindowProc(
         WM_QUIT, WM_HSCROLL, WM_VSCROLL, WM_WINDOWPOSCHANGED,
         WM_KEYUP, WM_KEYDOWN, WM_SYSKEYUP, WM_SYSKEYDOWN,
         WM_CHAR, WM_SYSCHAR, WHEEL_DELTA, WM_SETFOCUS, WM_KILLFOCUS,
+        WM_HOTKEY,
 
         VK_F4,
         CREATESTRUCTW, GWLP_USERDATA,
@@ -2719,6 +2803,8 @@ unsafe extern "system" fn WindowProc(
                     PostMessageW(hwnd, AZ_REGENERATE_DISPLAY_LIST, 0, 0);
                 }
 
+                update_inspector_windows(windows, hwnd_key);
+
                 mem::drop(app_borrow);
                 0
             },
@@ -2763,6 +2849,8 @@ unsafe extern "system" fn WindowProc(
                             gl.get_integer_v(gl_context_loader::gl::CURRENT_PROGRAM, (&mut current_program[..]).into());
                         }
 
+                        current_window.internal.update_drag_state();
+
                         ret = process_event(
                             hinstance,
                             current_window,
@@ -2797,6 +2885,12 @@ unsafe extern "system" fn WindowProc(
                 let mut app_borrow = shared_application_data.inner.try_borrow_mut().unwrap();
                 let mut ab = &mut *app_borrow;
                 destroy_windows(ab, destroyed_windows);
+                apply_shortcut_changes(ab);
+
+                // the hovered node in the inspector may have changed
+                if let Some(mut layout_callback) = ab.windows.get(&hwnd_key).map(|w| w.internal.current_window_state.layout_callback.clone()) {
+                    sync_inspector_highlight(&mut ab.windows, &mut layout_callback, true);
+                }
 
                 mem::drop(ab);
 
@@ -2922,6 +3016,8 @@ unsafe extern "system" fn WindowProc(
                     DefWindowProcW(hwnd, msg, wparam, lparam)
                 }
             },
+            // TODO: touch and pen input (WM_POINTER*) is not handled yet, the pointer
+            // events should be passed through a gesture::GestureRecognizer like on X11
             WM_MOUSEMOVE => {
 
                 use winapi::{
@@ -3002,6 +3098,14 @@ unsafe extern "system" fn WindowProc(
                     mem::drop(app_borrow);
                     DefWindowProcW(hwnd, msg, wparam, lparam)
                 } else {
+                    let inspector_options = get_inspector_create_options(&mut *app_borrow, hwnd_key, wparam);
+                    if let Some(options) = inspector_options {
+                        let hinstance = app_borrow.hinstance;
+                        mem::drop(app_borrow);
+                        create_windows(hinstance, shared_application_data, vec![options]);
+                        return 0;
+                    }
+
                     if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
                         if let Some((scancode, vk)) = event::process_key_params(wparam, lparam) {
                             use winapi::um::winuser::SendMessageW;
@@ -3013,6 +3117,17 @@ unsafe extern "system" fn WindowProc(
                                 current_window.internal.current_window_state.keyboard_state.current_virtual_keycode = Some(vk).into();
                                 current_window.internal.current_window_state.keyboard_state.pressed_virtual_keycodes.insert_hm_item(vk);
                             }
+
+                            // keyboard shortcuts of the application (App::register_shortcut),
+                            // invoked after the key down event has been processed
+                            let window_id = current_window.internal.id;
+                            let keyboard_state = current_window.internal.current_window_state.keyboard_state.clone();
+                            if let Some(callback) = app_borrow.shortcuts.handle_key_down(window_id, &keyboard_state).cloned() {
+                                if let Some(w) = app_borrow.windows.get_mut(&hwnd_key) {
+                                    w.pending_shortcuts.push(callback);
+                                    PostMessageW(hwnd, AZ_SHORTCUT, 0, 0);
+                                }
+                            }
                             mem::drop(app_borrow);
 
                             // NOTE: due to a Win32 bug, the WM_CHAR message gets sent immediately after
@@ -3445,6 +3560,8 @@ unsafe extern "system" fn WindowProc(
                 }
             },
             WM_NCHITTEST => {
+                // TODO: return HTCAPTION / HTTOPLEFT / ... for nodes with a WindowRegion
+                // (custom title bars), needs a hit test at the position in lparam
                 mem::drop(app_borrow);
                 DefWindowProcW(hwnd, msg, wparam, lparam)
             },
@@ -3528,6 +3645,128 @@ unsafe extern "system" fn WindowProc(
                 mem::drop(app_borrow);
                 DefWindowProcW(hwnd, msg, wparam, lparam)
             },
+            AZ_WINDOW_RESULT | AZ_SHORTCUT => {
+
+                use winapi::um::winuser::{GetDC, ReleaseDC};
+
+                let mut ab = &mut *app_borrow;
+                let hinstance = ab.hinstance;
+                let windows = &mut ab.windows;
+                let image_cache = &mut ab.image_cache;
+                let fc_cache = &mut ab.fc_cache;
+                let config = &ab.config;
+
+                let mut ret = ProcessEventResult::DoNothing;
+                let mut new_windows = Vec::new();
+                let mut destroyed_windows = Vec::new();
+
+                match windows.get_mut(&hwnd_key) {
+                    Some(current_window) => {
+
+                        let hDC = GetDC(hwnd);
+
+                        let gl_context = match current_window.gl_context {
+                            Some(c) => {
+                                if !hDC.is_null() {
+                                    wglMakeCurrent(hDC, c);
+                                }
+                            },
+                            None => { },
+                        };
+
+                        let mut current_program = [0_i32];
+
+                        {
+                            let mut gl = &mut current_window.gl_functions.functions;
+                            gl.get_integer_v(gl_context_loader::gl::CURRENT_PROGRAM, (&mut current_program[..]).into());
+                        }
+
+                        // results of the child windows that were closed (see WM_DESTROY)
+                        for (mut result_callback, mut window_result) in mem::take(&mut current_window.pending_window_results) {
+                            ret = ret.max_self(process_window_result(
+                                hinstance,
+                                current_window,
+                                &mut result_callback,
+                                &mut window_result,
+                                fc_cache,
+                                image_cache,
+                                config,
+                                &mut new_windows,
+                                &mut destroyed_windows,
+                            ));
+                        }
+
+                        // callbacks of the pressed keyboard shortcuts (see WM_KEYDOWN and WM_HOTKEY)
+                        for mut callback in mem::take(&mut current_window.pending_shortcuts) {
+                            ret = ret.max_self(process_shortcut(
+                                hinstance,
+                                current_window,
+                                &mut callback,
+                                fc_cache,
+                                image_cache,
+                                config,
+                                &mut new_windows,
+                                &mut destroyed_windows,
+                            ));
+                        }
+
+                        let mut gl = &mut current_window.gl_functions.functions;
+                        gl.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
+                        gl.bind_texture(gl_context_loader::gl::TEXTURE_2D, 0);
+                        gl.use_program(current_program[0] as u32);
+
+                        wglMakeCurrent(ptr::null_mut(), ptr::null_mut());
+                        if !hDC.is_null() {
+                            ReleaseDC(hwnd, hDC);
+                        }
+                    },
+                    None => {
+                        mem::drop(app_borrow);
+                        return DefWindowProcW(hwnd, msg, wparam, lparam);
+                    },
+                }
+
+                // create_windows needs to clone the SharedApplicationData RefCell
+                // drop the borrowed variables and restore them immediately after
+                let hinstance = ab.hinstance;
+                mem::drop(ab);
+                mem::drop(app_borrow);
+                create_windows(hinstance, shared_application_data, new_windows);
+                let mut app_borrow = shared_application_data.inner.try_borrow_mut().unwrap();
+                let mut ab = &mut *app_borrow;
+                destroy_windows(ab, destroyed_windows);
+                apply_shortcut_changes(ab);
+
+                match ret {
+                    ProcessEventResult::DoNothing => { },
+                    ProcessEventResult::ShouldRegenerateDomCurrentWindow => {
+                        PostMessageW(hwnd, AZ_REGENERATE_DOM, 0, 0);
+                    },
+                    ProcessEventResult::ShouldRegenerateDomAllWindows => {
+                        for window in ab.windows.values() {
+                            PostMessageW(window.hwnd, AZ_REGENERATE_DOM, 0, 0);
+                        }
+                    },
+                    ProcessEventResult::ShouldUpdateDisplayListCurrentWindow => {
+                        PostMessageW(hwnd, AZ_REGENERATE_DISPLAY_LIST, 0, 0);
+                    },
+                    ProcessEventResult::UpdateHitTesterAndProcessAgain => {
+                        if let Some(w) = ab.windows.get_mut(&hwnd_key) {
+                            w.internal.previous_window_state = Some(w.internal.current_window_state.clone());
+                            // TODO: submit display list, wait for new hit-tester and update hit-test results
+                            PostMessageW(hwnd, AZ_REGENERATE_DISPLAY_LIST, 0, 0);
+                            PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
+                        }
+                    },
+                    ProcessEventResult::ShouldReRenderCurrentWindow => {
+                        PostMessageW(hwnd, AZ_GPU_SCROLL_RENDER, 0, 0);
+                    },
+                }
+
+                mem::drop(ab);
+                mem::drop(app_borrow);
+                0
+            },
             WM_TIMER => {
 
                 use winapi::um::winuser::{GetDC, ReleaseDC};
@@ -3662,6 +3901,7 @@ unsafe extern "system" fn WindowProc(
                 let mut app_borrow = shared_application_data.inner.try_borrow_mut().unwrap();
                 let mut ab = &mut *app_borrow;
                 destroy_windows(ab, destroyed_windows);
+                apply_shortcut_changes(ab);
 
                 match ret {
                     ProcessEventResult::DoNothing => { },
@@ -3798,6 +4038,7 @@ unsafe extern "system" fn WindowProc(
                     let mut app_borrow = shared_application_data.inner.try_borrow_mut().unwrap();
                     let mut ab = &mut *app_borrow;
                     destroy_windows(ab, destroyed_windows);
+                    apply_shortcut_changes(ab);
 
                     match ret {
                         ProcessEventResult::DoNothing => { },
@@ -3832,6 +4073,22 @@ unsafe extern "system" fn WindowProc(
                     return DefWindowProcW(hwnd, msg, wparam, lparam);
                 }
             },
+            WM_HOTKEY => {
+                // global keyboard shortcut, wparam is the ID passed to RegisterHotKey
+                let ab = &mut *app_borrow;
+                let callback = ab.global_hot_keys
+                    .get_shortcut(wparam as i32)
+                    .and_then(|id| ab.shortcuts.get_shortcut(id))
+                    .map(|s| s.callback.clone());
+
+                if let (Some(callback), Some(current_window)) = (callback, ab.windows.get_mut(&hwnd_key)) {
+                    current_window.pending_shortcuts.push(callback);
+                    PostMessageW(hwnd, AZ_SHORTCUT, 0, 0);
+                }
+
+                mem::drop(app_borrow);
+                0
+            },
             WM_QUIT => {
                 // TODO: execute quit callback
                 mem::drop(app_borrow);
@@ -3854,6 +4111,30 @@ unsafe extern "system" fn WindowProc(
 
                 if let Some(mut current_window) = ab.windows.remove(&(hwnd as usize)) {
 
+                    save_window_placement(&current_window);
+
+                    // hot keys are released together with their window
+                    if ab.global_hot_keys.get_hwnd() == hwnd {
+                        let hot_key_window = get_hot_key_window(ab);
+                        ab.global_hot_keys.sync(hot_key_window, &ab.shortcuts);
+                    }
+
+                    // re-enable the parent of a modal window and pass the result
+                    // of the window to the result_callback of the parent window
+                    if let Some(parent) = ab.windows.values_mut().find(|w| Some(w.internal.id) == current_window.parent) {
+                        use winapi::um::winuser::EnableWindow;
+                        if current_window.modal {
+                            EnableWindow(parent.hwnd, TRUE);
+                        }
+                        if let (Some(c), Some(r)) = (current_window.result_callback.take(), current_window.window_result.take()) {
+                            parent.pending_window_results.push((c, r));
+                            PostMessageW(parent.hwnd, AZ_WINDOW_RESULT, 0, 0);
+                        }
+                    }
+
+                    // if this window is an inspector, remove the highlight from the inspected window
+                    sync_inspector_highlight(&mut ab.windows, &mut current_window.internal.current_window_state.layout_callback, false);
+
                     let hDC = GetDC(hwnd);
                     if let Some(c) = current_window.gl_context {
                         if !hDC.is_null() {
@@ -3997,18 +4278,34 @@ fn process_event(
         &events
     );
 
+    // Drag the scrollbars before the callbacks see the mouse input
+    let scrollbar_result = window.internal.process_scrollbar_input();
+
     // Invoke callbacks on nodes
     let callback_result = fc_cache.apply_closure(|fc_cache| {
 
         use azul_core::window::{RawWindowHandle, WindowsHandle};
 
-        // Get callbacks for nodes
-        let mut callbacks = CallbacksOfHitTest::new(&nodes_to_check, &events, &window.internal.layout_results);
-
         let window_handle = RawWindowHandle::Windows(WindowsHandle {
             hwnd: window.hwnd as *mut _,
             hinstance: hinstance as *mut _,
         });
+
+        // the nodes below a pressed scrollbar don't get the mouse input
+        if scrollbar_result.mouse_captured {
+            return window.internal.invoke_scrolled_callbacks(
+                &scrollbar_result.scrolled_nodes,
+                &window_handle,
+                &window.gl_context_ptr,
+                image_cache,
+                fc_cache,
+                &config.system_callbacks,
+            );
+        }
+
+        // Get callbacks for nodes
+        let mut callbacks = CallbacksOfHitTest::new(&nodes_to_check, &events, &window.internal.layout_results);
+
         let current_scroll_states = window.internal.get_current_scroll_states();
 
         // Invoke user-defined callbacks in the UI
@@ -4027,7 +4324,7 @@ fn process_event(
         )
     });
 
-    return process_callback_results(
+    let result = process_callback_results(
         callback_result,
         window,
         &nodes_to_check,
@@ -4036,6 +4333,19 @@ fn process_event(
         new_windows,
         destroyed_windows
     );
+
+    // the scrollbars are drawn on top of the display list
+    if scrollbar_result.needs_redraw {
+        result.max_self(ProcessEventResult::ShouldUpdateDisplayListCurrentWindow)
+    } else if scrollbar_result.scrolled_nodes.is_empty() {
+        result
+    } else if window.internal.scrollbar_geometry_changed() {
+        // scrolling a nested scroll container can clip the scrollbars inside of it
+        result.max_self(ProcessEventResult::ShouldUpdateDisplayListCurrentWindow)
+    } else {
+        // dragging a scrollbar only moves the thumbs, see generate_frame()
+        result.max_self(ProcessEventResult::ShouldReRenderCurrentWindow)
+    }
 }
 
 #[must_use]
@@ -4131,6 +4441,109 @@ fn process_threads(
     );
 }
 
+// Invokes the result_callback of a closed child window on its parent window
+#[must_use]
+fn process_window_result(
+    hinstance: HINSTANCE,
+    window: &mut Window,
+    result_callback: &mut WindowResultCallback,
+    window_result: &mut RefAny,
+    fc_cache: &mut LazyFcCache,
+    image_cache: &mut ImageCache,
+    config: &AppConfig,
+    new_windows: &mut Vec<WindowCreateOptions>,
+    destroyed_windows: &mut Vec<usize>
+) -> ProcessEventResult {
+
+    use azul_core::window::{RawWindowHandle, WindowsHandle};
+
+    window.internal.previous_window_state = Some(window.internal.current_window_state.clone());
+
+    let callback_result = fc_cache.apply_closure(|fc_cache| {
+
+        let window_handle = RawWindowHandle::Windows(WindowsHandle {
+            hwnd: window.hwnd as *mut _,
+            hinstance: hinstance as *mut _,
+        });
+
+        window.internal.invoke_window_result_callback(
+            result_callback,
+            window_result,
+            &window_handle,
+            &window.gl_context_ptr,
+            image_cache,
+            fc_cache,
+            &config.system_callbacks,
+        )
+    });
+
+    return process_callback_results(
+        callback_result,
+        window,
+        &NodesToCheck::empty(
+            window.internal.current_window_state.mouse_state.mouse_down(),
+            window.internal.current_window_state.focused_node,
+        ),
+        image_cache,
+        fc_cache,
+        new_windows,
+        destroyed_windows
+    );
+}
+
+// Invokes the callback of a pressed keyboard shortcut on the root node of the window
+#[must_use]
+fn process_shortcut(
+    hinstance: HINSTANCE,
+    window: &mut Window,
+    callback: &mut MenuCallback,
+    fc_cache: &mut LazyFcCache,
+    image_cache: &mut ImageCache,
+    config: &AppConfig,
+    new_windows: &mut Vec<WindowCreateOptions>,
+    destroyed_windows: &mut Vec<usize>
+) -> ProcessEventResult {
+
+    use azul_core::window::{RawWindowHandle, WindowsHandle};
+    use azul_core::styled_dom::NodeHierarchyItemId;
+
+    window.internal.previous_window_state = Some(window.internal.current_window_state.clone());
+
+    let callback_result = fc_cache.apply_closure(|fc_cache| {
+
+        let window_handle = RawWindowHandle::Windows(WindowsHandle {
+            hwnd: window.hwnd as *mut _,
+            hinstance: hinstance as *mut _,
+        });
+
+        window.internal.invoke_menu_callback(
+            callback,
+            DomNodeId {
+                dom: DomId::ROOT_ID,
+                node: NodeHierarchyItemId::from_crate_internal(None),
+            },
+            &window_handle,
+            &window.gl_context_ptr,
+            image_cache,
+            fc_cache,
+            &config.system_callbacks,
+        )
+    });
+
+    return process_callback_results(
+        callback_result,
+        window,
+        &NodesToCheck::empty(
+            window.internal.current_window_state.mouse_state.mouse_down(),
+            window.internal.current_window_state.focused_node,
+        ),
+        image_cache,
+        fc_cache,
+        new_windows,
+        destroyed_windows
+    );
+}
+
 #[must_use]
 fn process_callback_results(
     mut callback_results: CallCallbacksResult,
@@ -4183,9 +4596,19 @@ fn process_callback_results(
         new_windows.push(w);
     }
 
+    // TODO: callback_results.notifications (desktop notifications) are only shown on Linux
+
+    // resolved in destroy_windows, since the windows are identified by their WindowId
+    window.windows_closed.extend(callback_results.windows_closed.into_iter());
+    if let Some(result) = callback_results.window_result {
+        window.window_result = Some(result);
+    }
+
+    // applied in apply_shortcut_changes, since the registry is stored in the ApplicationData
+    window.shortcuts_changed.extend(callback_results.shortcuts_changed.into_iter());
 
     let scroll = window.internal.current_window_state.process_system_scroll(&window.internal.scroll_states);
-    let need_scroll_render = scroll.is_some();
+    let need_scroll_render = scroll.is_some() || callback_results.should_scroll_render;
 
     if let Some(modified) = callback_results.modified_window_state.as_ref() {
         if modified.flags.is_about_to_close {
@@ -4197,6 +4620,9 @@ fn process_callback_results(
             window.internal.current_window_state.hovered_file.clone(),
             window.internal.current_window_state.focused_node.clone(),
             window.internal.current_window_state.last_hit_test.clone(),
+            window.internal.current_window_state.clipboard_sequence,
+            window.internal.current_window_state.drag_state.clone(),
+            window.internal.current_window_state.ime_state.clone(),
         );
         if modified.size.get_layout_size() != window.internal.current_window_state.size.get_layout_size() {
             result = result.max_self(ProcessEventResult::UpdateHitTesterAndProcessAgain);
@@ -4205,6 +4631,23 @@ fn process_callback_results(
         }
     }
 
+    if let Some(drag_data) = callback_results.update_drag_data {
+        window
+            .internal
+            .current_window_state
+            .drag_state
+            .update_drag_data(drag_data);
+    }
+
+    // the drag image is drawn on top of the display list
+    if window
+        .internal
+        .current_window_state
+        .drag_image_changed(&window.internal.previous_window_state)
+    {
+        result = result.max_self(ProcessEventResult::ShouldUpdateDisplayListCurrentWindow);
+    }
+
     synchronize_window_state_with_os(
         window.hwnd,
         window.internal.previous_window_state.as_ref(),
@@ -4292,15 +4735,73 @@ fn process_callback_results(
     if style_layout_changes.did_resize_nodes() {
         // at least update the hit-tester
         result.max_self(ProcessEventResult::UpdateHitTesterAndProcessAgain)
-    } else if style_layout_changes.need_regenerate_display_list() {
+    } else if style_layout_changes.need_regenerate_display_list()
+        || (need_scroll_render && window.internal.scrollbar_geometry_changed())
+    {
+        // scrolling a nested scroll container can clip the scrollbars inside of it
         result.max_self(ProcessEventResult::ShouldUpdateDisplayListCurrentWindow)
     } else if need_scroll_render || style_layout_changes.need_redraw() {
+        // scrolling only moves the scrollbars, see generate_frame()
         result.max_self(ProcessEventResult::ShouldReRenderCurrentWindow)
     } else {
         result
     }
 }
 
+// Moves the window to the saved (normal, not maximized) rectangle, the window stays hidden
+fn restore_window_placement(hwnd: HWND, state: &PersistedWindowState, dpi_factor: f32) {
+    use winapi::um::winuser::{SetWindowPlacement, SW_HIDE, WINDOWPLACEMENT};
+
+    let size = state.size.to_physical(dpi_factor);
+    let mut placement: WINDOWPLACEMENT = unsafe { mem::zeroed() };
+    placement.length = mem::size_of::<WINDOWPLACEMENT>() as u32;
+    placement.showCmd = SW_HIDE as u32;
+    placement.rcNormalPosition = RECT {
+        left: state.position.x,
+        top: state.position.y,
+        right: state.position.x + size.width as i32,
+        bottom: state.position.y + size.height as i32,
+    };
+
+    // windows that would be off-screen are moved onto a monitor by Windows
+    unsafe { SetWindowPlacement(hwnd, &placement); }
+}
+
+// Saves the geometry of the window if it has a persistence key, called before it is destroyed.
+// On Windows, the outer rectangle of the window in its normal (not maximized) state is saved.
+fn save_window_placement(window: &Window) {
+    use azul_core::window::{PhysicalPositionI32, WindowFrame};
+    use winapi::um::winuser::{GetWindowPlacement, SW_SHOWMAXIMIZED, SW_SHOWMINIMIZED, WINDOWPLACEMENT};
+
+    let key = match window.persistence_key.as_ref() {
+        Some(s) => s,
+        None => return,
+    };
+
+    let mut placement: WINDOWPLACEMENT = unsafe { mem::zeroed() };
+    placement.length = mem::size_of::<WINDOWPLACEMENT>() as u32;
+    if unsafe { GetWindowPlacement(window.hwnd, &mut placement) } == 0 {
+        return;
+    }
+
+    let rect = placement.rcNormalPosition;
+    let state = &window.internal.current_window_state;
+    let physical_size = PhysicalSize { width: rect.width(), height: rect.height() };
+
+    let persisted = PersistedWindowState {
+        position: PhysicalPositionI32::new(rect.left, rect.top),
+        size: physical_size.to_logical(state.size.get_hidpi_factor()),
+        frame: match placement.showCmd as i32 {
+            SW_SHOWMAXIMIZED => WindowFrame::Maximized,
+            SW_SHOWMINIMIZED => WindowFrame::Minimized,
+            _ => WindowFrame::Normal,
+        },
+        monitor_id: state.monitor.id,
+    };
+
+    crate::persistence::save_window_state(key.as_str(), &persisted);
+}
+
 fn create_windows(hinstance: HINSTANCE, app: &mut SharedApplicationData, new: Vec<WindowCreateOptions>) {
     for opts in new {
         if let Ok(w) = Window::create(hinstance, opts, app.clone()) {
@@ -4311,8 +4812,20 @@ fn create_windows(hinstance: HINSTANCE, app: &mut SharedApplicationData, new: Ve
     }
 }
 
-fn destroy_windows(app: &mut ApplicationData, old: Vec<usize>) {
+fn destroy_windows(app: &mut ApplicationData, mut old: Vec<usize>) {
     use winapi::um::winuser::{PostMessageW, WM_QUIT};
+
+    // windows closed with CallbackInfo::close_window(), their owned
+    // child windows are destroyed together with them
+    let closed_in_callbacks = app.windows.values_mut()
+        .flat_map(|w| mem::take(&mut w.windows_closed))
+        .collect::<Vec<_>>();
+    old.extend(
+        app.windows.values()
+        .filter(|w| closed_in_callbacks.contains(&w.internal.id))
+        .map(|w| w.hwnd as usize)
+    );
+
     for window in old {
         if let Some(w) = app.windows.get(&window) {
             unsafe { PostMessageW(w.hwnd, WM_QUIT, 0, 0); }
@@ -4320,6 +4833,121 @@ fn destroy_windows(app: &mut ApplicationData, old: Vec<usize>) {
     }
 }
 
+// Applies the keyboard shortcuts that were registered / unregistered with
+// CallbackInfo::register_shortcut() and updates the hot keys of the global shortcuts
+fn apply_shortcut_changes(app: &mut ApplicationData) {
+    let changes = app.windows.values_mut()
+        .flat_map(|w| mem::take(&mut w.shortcuts_changed))
+        .collect::<Vec<_>>();
+
+    if changes.is_empty() {
+        return;
+    }
+
+    // shortcuts that conflict with an already registered shortcut are not registered
+    let _ = app.shortcuts.apply_changes(&changes);
+
+    let hwnd = get_hot_key_window(app);
+    app.global_hot_keys.sync(hwnd, &app.shortcuts);
+}
+
+// Returns the window that the global shortcuts are registered with: the current
+// window if it still exists, otherwise any other window (null if there is none)
+fn get_hot_key_window(app: &ApplicationData) -> HWND {
+    let current = app.global_hot_keys.get_hwnd();
+    if app.windows.contains_key(&(current as usize)) {
+        current
+    } else {
+        app.windows.values().next().map(|w| w.hwnd).unwrap_or(ptr::null_mut())
+    }
+}
+
+/// If F12 was pressed in a window that isn't inspected yet, returns the options for the inspector window
+#[cfg(feature = "css_parser")]
+fn get_inspector_create_options(app: &mut ApplicationData, hwnd_key: usize, wparam: WPARAM) -> Option<WindowCreateOptions> {
+    use winapi::um::winuser::VK_F12;
+
+    if !app.config.enable_inspector || wparam as i32 != VK_F12 {
+        return None;
+    }
+
+    if !get_inspector_windows(&mut app.windows, hwnd_key).is_empty() {
+        return None;
+    }
+
+    let window = app.windows.get_mut(&hwnd_key)?;
+
+    // don't inspect the inspector
+    if crate::inspector::get_inspected_window(&mut window.internal.current_window_state.layout_callback).is_some() {
+        return None;
+    }
+
+    Some(crate::inspector::open_inspector(hwnd_key, &window.internal.layout_results))
+}
+
+#[cfg(not(feature = "css_parser"))]
+fn get_inspector_create_options(_: &mut ApplicationData, _: usize, _: WPARAM) -> Option<WindowCreateOptions> {
+    None
+}
+
+/// Returns the HWND and the layout callback (holding the shared inspector data)
+/// of all inspector windows that are inspecting the given window
+#[cfg(feature = "css_parser")]
+fn get_inspector_windows(windows: &mut BTreeMap<usize, Window>, inspected: usize) -> Vec<(HWND, LayoutCallback)> {
+    windows.values_mut().filter_map(|w| {
+        let layout_callback = &mut w.internal.current_window_state.layout_callback;
+        if crate::inspector::get_inspected_window(layout_callback) == Some(inspected) {
+            Some((w.hwnd, layout_callback.clone()))
+        } else {
+            None
+        }
+    }).collect()
+}
+
+/// Updates the snapshot of all inspectors after the DOM of the inspected window got regenerated
+#[cfg(feature = "css_parser")]
+fn update_inspector_windows(windows: &mut BTreeMap<usize, Window>, inspected: usize) {
+    use winapi::um::winuser::PostMessageW;
+
+    let inspectors = get_inspector_windows(windows, inspected);
+    let inspected_window = match windows.get(&inspected) {
+        Some(s) => s,
+        None => return,
+    };
+
+    for (inspector_hwnd, mut layout_callback) in inspectors {
+        crate::inspector::update_inspector(&mut layout_callback, &inspected_window.internal.layout_results);
+        unsafe { PostMessageW(inspector_hwnd, AZ_REGENERATE_DOM, 0, 0); }
+    }
+}
+
+#[cfg(not(feature = "css_parser"))]
+fn update_inspector_windows(_: &mut BTreeMap<usize, Window>, _: usize) { }
+
+/// Copies the node hovered in the inspector window (given by its layout callback) to the inspected
+/// window, `highlight = false` removes the highlight (used when the inspector window is closed)
+#[cfg(feature = "css_parser")]
+fn sync_inspector_highlight(windows: &mut BTreeMap<usize, Window>, layout_callback: &mut LayoutCallback, highlight: bool) {
+    use winapi::um::winuser::PostMessageW;
+
+    let inspected = match crate::inspector::get_inspected_window(layout_callback) {
+        Some(s) => s,
+        None => return,
+    };
+
+    let node_id = if highlight { crate::inspector::get_inspector_highlight(layout_callback) } else { None };
+
+    if let Some(w) = windows.get_mut(&inspected) {
+        if w.internal.inspector_highlight != node_id {
+            w.internal.inspector_highlight = node_id;
+            unsafe { PostMessageW(w.hwnd, AZ_REGENERATE_DISPLAY_LIST, 0, 0); }
+        }
+    }
+}
+
+#[cfg(not(feature = "css_parser"))]
+fn sync_inspector_highlight(_: &mut BTreeMap<usize, Window>, _: &mut LayoutCallback, _: bool) { }
+
 fn run_all_threads() {
     // TODO
 }
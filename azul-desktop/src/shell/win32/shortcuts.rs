@@ -0,0 +1,147 @@
+//! Global keyboard shortcuts (`ShortcutScope::Global`)
+//!
+//! Global shortcuts are registered with `RegisterHotKey`, so that Windows posts a
+//! `WM_HOTKEY` message even if no window of the application has the focus. A hot key
+//! belongs to the window it was registered with, so the hot keys are moved to another
+//! window when that window is destroyed.
+//!
+//! Registering a key combination that is already registered by another application
+//! fails, the shortcut stays registered (but only works inside the application).
+
+use alloc::collections::BTreeMap;
+use azul_core::window::{AcceleratorKey, ShortcutId, ShortcutRegistry, VirtualKeyCode};
+use core::ptr;
+use winapi::{
+    shared::windef::HWND,
+    um::winuser::{
+        RegisterHotKey, UnregisterHotKey, MOD_ALT, MOD_CONTROL, MOD_NOREPEAT, MOD_SHIFT,
+    },
+};
+
+// IDs from 0xC000 on are reserved for shared DLLs
+const MAX_HOT_KEY_ID: i32 = 0xBFFF;
+
+/// Virtual key and modifiers of a registered hot key
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct HotKey {
+    id: i32,
+    vkey: u32,
+    modifiers: u32,
+}
+
+/// Hot keys of the global shortcuts of the registry
+#[derive(Debug)]
+pub(super) struct GlobalHotKeys {
+    /// Window that receives the WM_HOTKEY messages
+    hwnd: HWND,
+    hot_keys: BTreeMap<ShortcutId, HotKey>,
+    next_id: i32,
+}
+
+impl GlobalHotKeys {
+    pub(super) fn new() -> Self {
+        Self {
+            hwnd: ptr::null_mut(),
+            hot_keys: BTreeMap::new(),
+            next_id: 1,
+        }
+    }
+
+    /// Window that the hot keys are registered with
+    pub(super) fn get_hwnd(&self) -> HWND {
+        self.hwnd
+    }
+
+    /// Registers the global shortcuts that were added to the registry with the
+    /// given window and unregisters the ones that were removed
+    pub(super) fn sync(&mut self, hwnd: HWND, registry: &ShortcutRegistry) {
+        if hwnd != self.hwnd {
+            self.unregister_all();
+            self.hwnd = hwnd;
+        }
+
+        if hwnd.is_null() {
+            return;
+        }
+
+        let wanted = registry
+            .get_global_bindings()
+            .filter_map(|b| {
+                let combo = b.shortcut.keys.get(0)?;
+                Some((b.id, get_hot_key(&combo.get_accelerator_keys())?))
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        let removed = self
+            .hot_keys
+            .iter()
+            .filter(|(id, h)| wanted.get(*id) != Some(&(h.vkey, h.modifiers)))
+            .map(|(id, h)| (*id, h.id))
+            .collect::<Vec<_>>();
+
+        for (shortcut_id, hot_key_id) in removed {
+            unsafe { UnregisterHotKey(self.hwnd, hot_key_id) };
+            self.hot_keys.remove(&shortcut_id);
+        }
+
+        for (shortcut_id, (vkey, modifiers)) in wanted {
+            if self.hot_keys.contains_key(&shortcut_id) {
+                continue;
+            }
+
+            let id = self.next_id;
+            self.next_id = if id >= MAX_HOT_KEY_ID { 1 } else { id + 1 };
+
+            // fails if the keys are already registered by another application
+            if unsafe { RegisterHotKey(self.hwnd, id, modifiers | MOD_NOREPEAT as u32, vkey) } != 0
+            {
+                self.hot_keys.insert(
+                    shortcut_id,
+                    HotKey {
+                        id,
+                        vkey,
+                        modifiers,
+                    },
+                );
+            }
+        }
+    }
+
+    /// Returns the shortcut of the hot key ID of a WM_HOTKEY message
+    pub(super) fn get_shortcut(&self, hot_key_id: i32) -> Option<ShortcutId> {
+        self.hot_keys
+            .iter()
+            .find(|(_, h)| h.id == hot_key_id)
+            .map(|(id, _)| *id)
+    }
+
+    fn unregister_all(&mut self) {
+        for hot_key in self.hot_keys.values() {
+            unsafe { UnregisterHotKey(self.hwnd, hot_key.id) };
+        }
+        self.hot_keys.clear();
+    }
+}
+
+// Returns the virtual key and the MOD_* modifiers of a key combination
+fn get_hot_key(keys: &[AcceleratorKey]) -> Option<(u32, u32)> {
+    let mut modifiers = 0;
+    let mut key = None;
+    for k in keys {
+        match k {
+            AcceleratorKey::Ctrl => modifiers |= MOD_CONTROL as u32,
+            AcceleratorKey::Alt => modifiers |= MOD_ALT as u32,
+            AcceleratorKey::Shift => modifiers |= MOD_SHIFT as u32,
+            AcceleratorKey::Key(k) => key = Some(*k),
+        }
+    }
+
+    Some((get_vkey(key?)?, modifiers))
+}
+
+// Inverse of event::vkey_to_winit_vkey
+fn get_vkey(key: VirtualKeyCode) -> Option<u32> {
+    (0x01..=0xfe)
+        .find(|vkey| super::event::vkey_to_winit_vkey(*vkey) == Some(key))
+        .map(|vkey| vkey as u32)
+}
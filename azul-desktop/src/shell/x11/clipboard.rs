@@ -0,0 +1,1100 @@
+//! X11 clipboard: `CLIPBOARD` / `PRIMARY` selection ownership and transfers
+//!
+//! X11 has no clipboard storage: the application that copied something owns the
+//! selection and has to answer the conversion requests of other applications
+//! until another application takes over the selection. The windows only process
+//! events while the main loop is not blocked (and every window has its own
+//! connection), so the selections are owned by a hidden window on a separate
+//! connection, which is driven by a background thread. `X11Clipboard` is a
+//! handle that sends requests to this thread and waits for the reply.
+//!
+//! Data larger than the maximum request size is transferred with the `INCR`
+//! protocol, in both directions.
+
+use super::{
+    Atom, Display, Library, Window, XEvent, XPropertyEvent, XSelectionClearEvent, XSelectionEvent,
+    XSelectionRequestEvent, Xlib, X11_FALSE, X11_TRUE,
+};
+use crate::{
+    app::ClipboardSelection,
+    gl::{c_char, c_int, c_long, c_uchar, c_uint, c_ulong},
+};
+use alloc::{collections::VecDeque, sync::Arc};
+use core::{ffi::c_void, mem, ptr};
+use once_cell::sync::Lazy;
+use std::{
+    ffi::{CStr, CString},
+    os::raw::c_short,
+    sync::{
+        mpsc::{self, Receiver, Sender, TryRecvError},
+        Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+extern "C" {
+    // libc
+    fn pipe(fds: *mut c_int) -> c_int;
+    fn read(fd: c_int, buf: *mut c_void, count: usize) -> isize;
+    fn write(fd: c_int, buf: *const c_void, count: usize) -> isize;
+    fn close(fd: c_int) -> c_int;
+    pub(super) fn poll(fds: *mut pollfd, nfds: c_ulong, timeout: c_int) -> c_int;
+}
+
+#[repr(C)]
+pub(super) struct pollfd {
+    pub fd: c_int,
+    pub events: i16,
+    pub revents: i16,
+}
+
+pub(super) const POLLIN: i16 = 0x1;
+
+const CURRENT_TIME: c_ulong = 0;
+const ANY_PROPERTY_TYPE: Atom = 0;
+const XA_ATOM: Atom = 4;
+const XA_STRING: Atom = 31;
+const PROP_MODE_REPLACE: c_int = 0;
+const PROPERTY_NEW_VALUE: c_int = 0;
+const PROPERTY_DELETE: c_int = 1;
+const PROPERTY_CHANGE_MASK: c_long = 0x0040_0000;
+const X11_SUCCESS: c_int = 0;
+
+const X11_PROPERTY_NOTIFY: c_int = 28;
+const X11_SELECTION_CLEAR: c_int = 29;
+const X11_SELECTION_REQUEST: c_int = 30;
+const X11_SELECTION_NOTIFY: c_int = 31;
+
+const XFIXES_SET_SELECTION_OWNER_NOTIFY_MASK: c_ulong = 1;
+const XFIXES_SELECTION_NOTIFY: c_int = 0;
+
+/// How long a caller waits for the clipboard thread to answer a request
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(3);
+/// How long the selection owner may take to answer a conversion / send the next INCR chunk
+const TRANSFER_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Text formats that are served from / read into a `text/plain;charset=utf-8` item
+const TEXT_MIME_TYPES: &[&str] = &["text/plain;charset=utf-8", "text/plain"];
+
+type XOpenDisplayFuncType = extern "C" fn(*const c_char) -> *mut Display;
+type XCloseDisplayFuncType = extern "C" fn(*mut Display) -> c_int;
+type XDefaultScreenFuncType = extern "C" fn(*mut Display) -> c_int;
+type XRootWindowFuncType = extern "C" fn(*mut Display, c_int) -> Window;
+type XCreateSimpleWindowFuncType = extern "C" fn(
+    *mut Display,
+    Window,
+    c_int,
+    c_int,
+    c_uint,
+    c_uint,
+    c_uint,
+    c_ulong,
+    c_ulong,
+) -> Window;
+type XSelectInputFuncType = extern "C" fn(*mut Display, Window, c_long) -> c_int;
+type XInternAtomFuncType = extern "C" fn(*mut Display, *const c_char, c_int) -> Atom;
+type XGetAtomNameFuncType = extern "C" fn(*mut Display, Atom) -> *mut c_char;
+type XFreeFuncType = extern "C" fn(*mut c_void) -> c_int;
+type XSetSelectionOwnerFuncType = extern "C" fn(*mut Display, Atom, Window, c_ulong) -> c_int;
+type XGetSelectionOwnerFuncType = extern "C" fn(*mut Display, Atom) -> Window;
+type XConvertSelectionFuncType =
+    extern "C" fn(*mut Display, Atom, Atom, Atom, Window, c_ulong) -> c_int;
+type XGetWindowPropertyFuncType = extern "C" fn(
+    *mut Display,
+    Window,
+    Atom,
+    c_long,
+    c_long,
+    c_int,
+    Atom,
+    *mut Atom,
+    *mut c_int,
+    *mut c_ulong,
+    *mut c_ulong,
+    *mut *mut c_uchar,
+) -> c_int;
+type XChangePropertyFuncType =
+    extern "C" fn(*mut Display, Window, Atom, Atom, c_int, c_int, *const c_uchar, c_int) -> c_int;
+type XSendEventFuncType = extern "C" fn(*mut Display, Window, c_int, c_long, *mut XEvent) -> c_int;
+type XPendingFuncType = extern "C" fn(*mut Display) -> c_int;
+type XNextEventFuncType = extern "C" fn(*mut Display, *mut XEvent) -> c_int;
+type XFlushFuncType = extern "C" fn(*mut Display) -> c_int;
+type XConnectionNumberFuncType = extern "C" fn(*mut Display) -> c_int;
+type XMaxRequestSizeFuncType = extern "C" fn(*mut Display) -> c_long;
+
+type XFixesQueryExtensionFuncType = extern "C" fn(*mut Display, *mut c_int, *mut c_int) -> c_int;
+type XFixesSelectSelectionInputFuncType = extern "C" fn(*mut Display, Window, Atom, c_ulong);
+
+pub(super) fn load_function<T>(library: &Library, symbol: &str) -> Option<T> {
+    let ptr = library.get(symbol)?;
+    if ptr.is_null() {
+        None
+    } else {
+        Some(unsafe { mem::transmute_copy(&ptr) })
+    }
+}
+
+// Xlib functions used by the clipboard thread (on its own connection)
+struct ClipboardXlib {
+    pub library: Library,
+    pub XOpenDisplay: XOpenDisplayFuncType,
+    pub XCloseDisplay: XCloseDisplayFuncType,
+    pub XDefaultScreen: XDefaultScreenFuncType,
+    pub XRootWindow: XRootWindowFuncType,
+    pub XCreateSimpleWindow: XCreateSimpleWindowFuncType,
+    pub XSelectInput: XSelectInputFuncType,
+    pub XInternAtom: XInternAtomFuncType,
+    pub XGetAtomName: XGetAtomNameFuncType,
+    pub XFree: XFreeFuncType,
+    pub XSetSelectionOwner: XSetSelectionOwnerFuncType,
+    pub XGetSelectionOwner: XGetSelectionOwnerFuncType,
+    pub XConvertSelection: XConvertSelectionFuncType,
+    pub XGetWindowProperty: XGetWindowPropertyFuncType,
+    pub XChangeProperty: XChangePropertyFuncType,
+    pub XSendEvent: XSendEventFuncType,
+    pub XPending: XPendingFuncType,
+    pub XNextEvent: XNextEventFuncType,
+    pub XFlush: XFlushFuncType,
+    pub XConnectionNumber: XConnectionNumberFuncType,
+    pub XMaxRequestSize: XMaxRequestSizeFuncType,
+}
+
+impl ClipboardXlib {
+    fn new() -> Option<Self> {
+        let library = Library::load("libX11.so").ok()?;
+        Some(Self {
+            XOpenDisplay: load_function(&library, "XOpenDisplay")?,
+            XCloseDisplay: load_function(&library, "XCloseDisplay")?,
+            XDefaultScreen: load_function(&library, "XDefaultScreen")?,
+            XRootWindow: load_function(&library, "XRootWindow")?,
+            XCreateSimpleWindow: load_function(&library, "XCreateSimpleWindow")?,
+            XSelectInput: load_function(&library, "XSelectInput")?,
+            XInternAtom: load_function(&library, "XInternAtom")?,
+            XGetAtomName: load_function(&library, "XGetAtomName")?,
+            XFree: load_function(&library, "XFree")?,
+            XSetSelectionOwner: load_function(&library, "XSetSelectionOwner")?,
+            XGetSelectionOwner: load_function(&library, "XGetSelectionOwner")?,
+            XConvertSelection: load_function(&library, "XConvertSelection")?,
+            XGetWindowProperty: load_function(&library, "XGetWindowProperty")?,
+            XChangeProperty: load_function(&library, "XChangeProperty")?,
+            XSendEvent: load_function(&library, "XSendEvent")?,
+            XPending: load_function(&library, "XPending")?,
+            XNextEvent: load_function(&library, "XNextEvent")?,
+            XFlush: load_function(&library, "XFlush")?,
+            XConnectionNumber: load_function(&library, "XConnectionNumber")?,
+            XMaxRequestSize: load_function(&library, "XMaxRequestSize")?,
+            library,
+        })
+    }
+}
+
+// libXfixes, used by the windows to get notified when the clipboard owner changes
+struct XFixes {
+    pub library: Library,
+    pub XFixesQueryExtension: XFixesQueryExtensionFuncType,
+    pub XFixesSelectSelectionInput: XFixesSelectSelectionInputFuncType,
+}
+
+impl XFixes {
+    fn new() -> Option<Self> {
+        let library = Library::load("libXfixes.so.3").ok()?;
+        Some(Self {
+            XFixesQueryExtension: load_function(&library, "XFixesQueryExtension")?,
+            XFixesSelectSelectionInput: load_function(&library, "XFixesSelectSelectionInput")?,
+            library,
+        })
+    }
+}
+
+static XFIXES: Lazy<Option<XFixes>> = Lazy::new(XFixes::new);
+
+/// Subscribes the window to changes of the `CLIPBOARD` selection owner,
+/// returns the event type of the XFixes selection notify event (which
+/// depends on the display connection) or `None` if XFixes is not available
+pub(super) fn select_clipboard_change_events(
+    xlib: &Xlib,
+    display: *mut Display,
+    window: Window,
+) -> Option<c_int> {
+    let xfixes = XFIXES.as_ref()?;
+
+    let mut event_base = 0;
+    let mut error_base = 0;
+    if (xfixes.XFixesQueryExtension)(display, &mut event_base, &mut error_base) == X11_FALSE {
+        return None;
+    }
+
+    let clipboard_atom =
+        unsafe { (xlib.XInternAtom)(display, "CLIPBOARD\0".as_ptr() as *const c_char, X11_FALSE) };
+
+    (xfixes.XFixesSelectSelectionInput)(
+        display,
+        window,
+        clipboard_atom,
+        XFIXES_SET_SELECTION_OWNER_NOTIFY_MASK,
+    );
+
+    Some(event_base + XFIXES_SELECTION_NOTIFY)
+}
+
+/// Handle to the X11 clipboard thread, which is started on first use and
+/// runs until the process exits (so that the copied data stays available)
+#[derive(Debug, Clone)]
+pub struct X11Clipboard {
+    _private: (),
+}
+
+impl X11Clipboard {
+    /// Returns `None` if the X server can't be reached
+    pub fn new() -> Option<Self> {
+        CLIPBOARD_THREAD.as_ref()?;
+        Some(Self { _private: () })
+    }
+
+    /// Returns the names of the formats the current owner of the selection offers
+    pub fn get_targets(&self, selection: ClipboardSelection) -> Option<Vec<String>> {
+        CLIPBOARD_THREAD
+            .as_ref()?
+            .send(|reply| ClipboardRequest::GetTargets { selection, reply })?
+    }
+
+    /// Returns the first of the `targets` that the owner of the selection
+    /// can convert its contents to, together with the converted data
+    pub fn get_contents(
+        &self,
+        selection: ClipboardSelection,
+        targets: &[&str],
+    ) -> Option<(String, Vec<u8>)> {
+        let targets = targets.iter().map(|t| t.to_string()).collect();
+        CLIPBOARD_THREAD
+            .as_ref()?
+            .send(|reply| ClipboardRequest::Get {
+                selection,
+                targets,
+                reply,
+            })?
+    }
+
+    /// Takes ownership of the selection and serves the given (mime type, data)
+    /// items to other applications, an empty list releases the selection
+    pub fn set_contents(
+        &self,
+        selection: ClipboardSelection,
+        items: Vec<(String, Vec<u8>)>,
+    ) -> Option<()> {
+        let success = CLIPBOARD_THREAD
+            .as_ref()?
+            .send(|reply| ClipboardRequest::Set {
+                selection,
+                items,
+                reply,
+            })?;
+        if success {
+            Some(())
+        } else {
+            None
+        }
+    }
+}
+
+static CLIPBOARD_THREAD: Lazy<Option<ClipboardHandle>> = Lazy::new(ClipboardHandle::spawn);
+
+enum ClipboardRequest {
+    GetTargets {
+        selection: ClipboardSelection,
+        reply: Sender<Option<Vec<String>>>,
+    },
+    Get {
+        selection: ClipboardSelection,
+        targets: Vec<String>,
+        reply: Sender<Option<(String, Vec<u8>)>>,
+    },
+    Set {
+        selection: ClipboardSelection,
+        items: Vec<(String, Vec<u8>)>,
+        reply: Sender<bool>,
+    },
+}
+
+struct ClipboardHandle {
+    sender: Mutex<Sender<ClipboardRequest>>,
+    // write end of the pipe that wakes up the clipboard thread
+    wake_fd: c_int,
+}
+
+impl ClipboardHandle {
+    fn spawn() -> Option<Self> {
+        let mut fds = [0; 2];
+        if unsafe { pipe(fds.as_mut_ptr()) } != 0 {
+            return None;
+        }
+
+        let (sender, receiver) = mpsc::channel();
+        let (startup_sender, startup_receiver) = mpsc::channel();
+        let wake_read_fd = fds[0];
+
+        // the Xlib connection has to be opened on the thread that uses it
+        let spawned = thread::Builder::new()
+            .name("x11-clipboard".to_string())
+            .spawn(move || {
+                let mut clipboard = match ClipboardThread::new(receiver, wake_read_fd) {
+                    Some(s) => s,
+                    None => {
+                        let _ = startup_sender.send(false);
+                        return;
+                    }
+                };
+                let _ = startup_sender.send(true);
+                clipboard.run();
+            });
+
+        if spawned.is_err() || startup_receiver.recv() != Ok(true) {
+            unsafe {
+                close(fds[0]);
+                close(fds[1]);
+            }
+            return None;
+        }
+
+        Some(Self {
+            sender: Mutex::new(sender),
+            wake_fd: fds[1],
+        })
+    }
+
+    fn send<T>(&self, request: impl FnOnce(Sender<T>) -> ClipboardRequest) -> Option<T> {
+        let (reply, result) = mpsc::channel();
+        self.sender.lock().ok()?.send(request(reply)).ok()?;
+        unsafe { write(self.wake_fd, [1_u8].as_ptr() as *const c_void, 1) };
+        result.recv_timeout(REQUEST_TIMEOUT).ok()
+    }
+}
+
+enum ReadReply {
+    Targets(Sender<Option<Vec<String>>>),
+    Data(Sender<Option<(String, Vec<u8>)>>),
+}
+
+// Conversion of a foreign selection, only the first read in the queue is active
+struct PendingRead {
+    selection: ClipboardSelection,
+    // targets to try, in order of preference
+    targets: Vec<String>,
+    current_target: usize,
+    reply: ReadReply,
+    // set once XConvertSelection has been called
+    deadline: Option<Instant>,
+    // set if the owner uses the INCR protocol
+    incr_data: Option<Vec<u8>>,
+}
+
+// INCR transfer of owned data to another application
+struct IncrTransfer {
+    requestor: Window,
+    property: Atom,
+    target: Atom,
+    contents: Arc<Vec<(String, Vec<u8>)>>,
+    item: usize,
+    offset: usize,
+    deadline: Instant,
+}
+
+struct ClipboardAtoms {
+    clipboard: Atom,
+    primary: Atom,
+    targets: Atom,
+    incr: Atom,
+    utf8_string: Atom,
+    text: Atom,
+    // property on the hidden window that converted selections are stored in
+    property: Atom,
+}
+
+struct ClipboardThread {
+    xlib: ClipboardXlib,
+    display: *mut Display,
+    window: Window,
+    atoms: ClipboardAtoms,
+    // owned contents of CLIPBOARD and PRIMARY
+    owned: [Option<Arc<Vec<(String, Vec<u8>)>>>; 2],
+    reads: VecDeque<PendingRead>,
+    transfers: Vec<IncrTransfer>,
+    // data larger than this is sent with INCR
+    chunk_size: usize,
+    receiver: Receiver<ClipboardRequest>,
+    wake_fd: c_int,
+}
+
+impl ClipboardThread {
+    fn new(receiver: Receiver<ClipboardRequest>, wake_fd: c_int) -> Option<Self> {
+        let xlib = ClipboardXlib::new()?;
+
+        let display = (xlib.XOpenDisplay)(ptr::null());
+        if display.is_null() {
+            return None;
+        }
+
+        let screen = (xlib.XDefaultScreen)(display);
+        let root = (xlib.XRootWindow)(display, screen);
+        let window = (xlib.XCreateSimpleWindow)(display, root, 0, 0, 1, 1, 0, 0, 0);
+        if window == 0 {
+            (xlib.XCloseDisplay)(display);
+            return None;
+        }
+        (xlib.XSelectInput)(display, window, PROPERTY_CHANGE_MASK);
+
+        let intern = |name: &str| {
+            let name = CString::new(name).unwrap_or_default();
+            (xlib.XInternAtom)(display, name.as_ptr(), X11_FALSE)
+        };
+
+        let atoms = ClipboardAtoms {
+            clipboard: intern("CLIPBOARD"),
+            primary: intern("PRIMARY"),
+            targets: intern("TARGETS"),
+            incr: intern("INCR"),
+            utf8_string: intern("UTF8_STRING"),
+            text: intern("TEXT"),
+            property: intern("AZUL_CLIPBOARD"),
+        };
+
+        // XMaxRequestSize is in 4-byte units, leave some room for the request header
+        let chunk_size = ((xlib.XMaxRequestSize)(display).max(0) as usize).max(4096);
+
+        Some(Self {
+            xlib,
+            display,
+            window,
+            atoms,
+            owned: [None, None],
+            reads: VecDeque::new(),
+            transfers: Vec::new(),
+            chunk_size,
+            receiver,
+            wake_fd,
+        })
+    }
+
+    fn run(&mut self) {
+        let x11_fd = (self.xlib.XConnectionNumber)(self.display);
+
+        loop {
+            while (self.xlib.XPending)(self.display) > 0 {
+                let mut event = XEvent { pad: [0; 24] };
+                (self.xlib.XNextEvent)(self.display, &mut event);
+                self.handle_event(&event);
+            }
+
+            loop {
+                match self.receiver.try_recv() {
+                    Ok(request) => self.handle_request(request),
+                    Err(TryRecvError::Empty) => break,
+                    Err(TryRecvError::Disconnected) => return,
+                }
+            }
+
+            self.handle_timeouts();
+            self.start_next_read();
+            (self.xlib.XFlush)(self.display);
+
+            let mut fds = [
+                pollfd {
+                    fd: x11_fd,
+                    events: POLLIN,
+                    revents: 0,
+                },
+                pollfd {
+                    fd: self.wake_fd,
+                    events: POLLIN,
+                    revents: 0,
+                },
+            ];
+
+            unsafe { poll(fds.as_mut_ptr(), 2, self.get_poll_timeout()) };
+
+            if fds[1].revents & POLLIN != 0 {
+                let mut buf = [0_u8; 64];
+                unsafe { read(self.wake_fd, buf.as_mut_ptr() as *mut c_void, buf.len()) };
+            }
+        }
+    }
+
+    fn get_selection_atom(&self, selection: ClipboardSelection) -> Atom {
+        match selection {
+            ClipboardSelection::Clipboard => self.atoms.clipboard,
+            ClipboardSelection::Primary => self.atoms.primary,
+        }
+    }
+
+    fn get_selection_index(&self, atom: Atom) -> Option<usize> {
+        if atom == self.atoms.clipboard {
+            Some(0)
+        } else if atom == self.atoms.primary {
+            Some(1)
+        } else {
+            None
+        }
+    }
+
+    fn intern_atom(&self, name: &str) -> Atom {
+        let name = CString::new(name).unwrap_or_default();
+        (self.xlib.XInternAtom)(self.display, name.as_ptr(), X11_FALSE)
+    }
+
+    fn get_atom_name(&self, atom: Atom) -> Option<String> {
+        let name = (self.xlib.XGetAtomName)(self.display, atom);
+        if name.is_null() {
+            return None;
+        }
+        let result = unsafe { CStr::from_ptr(name) }
+            .to_str()
+            .ok()
+            .map(|s| s.to_string());
+        (self.xlib.XFree)(name as *mut c_void);
+        result
+    }
+
+    fn handle_request(&mut self, request: ClipboardRequest) {
+        match request {
+            ClipboardRequest::GetTargets { selection, reply } => {
+                self.reads.push_back(PendingRead {
+                    selection,
+                    targets: vec!["TARGETS".to_string()],
+                    current_target: 0,
+                    reply: ReadReply::Targets(reply),
+                    deadline: None,
+                    incr_data: None,
+                });
+            }
+            ClipboardRequest::Get {
+                selection,
+                targets,
+                reply,
+            } => {
+                self.reads.push_back(PendingRead {
+                    selection,
+                    targets,
+                    current_target: 0,
+                    reply: ReadReply::Data(reply),
+                    deadline: None,
+                    incr_data: None,
+                });
+            }
+            ClipboardRequest::Set {
+                selection,
+                items,
+                reply,
+            } => {
+                let atom = self.get_selection_atom(selection);
+                let index = self.get_selection_index(atom).unwrap_or(0);
+
+                let success = if items.is_empty() {
+                    if self.owned[index].take().is_some() {
+                        (self.xlib.XSetSelectionOwner)(self.display, atom, 0, CURRENT_TIME);
+                    }
+                    true
+                } else {
+                    self.owned[index] = Some(Arc::new(items));
+                    (self.xlib.XSetSelectionOwner)(self.display, atom, self.window, CURRENT_TIME);
+                    let is_owner =
+                        (self.xlib.XGetSelectionOwner)(self.display, atom) == self.window;
+                    if !is_owner {
+                        self.owned[index] = None;
+                    }
+                    is_owner
+                };
+
+                let _ = reply.send(success);
+            }
+        }
+    }
+
+    fn handle_event(&mut self, event: &XEvent) {
+        match event.get_type() {
+            X11_SELECTION_REQUEST => {
+                let request = unsafe { event.selection_request };
+                self.handle_selection_request(&request);
+            }
+            X11_SELECTION_NOTIFY => {
+                let notify = unsafe { event.selection };
+                self.handle_selection_notify(&notify);
+            }
+            X11_SELECTION_CLEAR => {
+                // another application took over the selection
+                let clear = unsafe { event.selection_clear };
+                if clear.window == self.window {
+                    if let Some(index) = self.get_selection_index(clear.selection) {
+                        self.owned[index] = None;
+                    }
+                }
+            }
+            X11_PROPERTY_NOTIFY => {
+                let property = unsafe { event.property };
+                self.handle_property_notify(&property);
+            }
+            _ => {}
+        }
+    }
+
+    // -- reading foreign selections
+
+    // Starts the conversion of the first queued read (if it hasn't been started yet)
+    fn start_next_read(&mut self) {
+        loop {
+            let (selection, target) = match self.reads.front() {
+                Some(r) if r.deadline.is_some() => return,
+                Some(r) => match r.targets.get(r.current_target) {
+                    Some(t) => (r.selection, t.clone()),
+                    None => {
+                        self.finish_read(None);
+                        continue;
+                    }
+                },
+                None => return,
+            };
+
+            let selection_atom = self.get_selection_atom(selection);
+
+            // contents owned by this application can be answered without a roundtrip
+            let owned = self
+                .get_selection_index(selection_atom)
+                .and_then(|i| self.owned[i].clone());
+
+            if let Some(contents) = owned {
+                let is_targets = match self.reads.front().map(|r| &r.reply) {
+                    Some(ReadReply::Targets(_)) => true,
+                    _ => false,
+                };
+                if is_targets {
+                    let targets = self
+                        .get_targets(&contents)
+                        .into_iter()
+                        .filter_map(|atom| self.get_atom_name(atom))
+                        .collect();
+                    self.reply_targets(Some(targets));
+                } else {
+                    let target_atom = self.intern_atom(&target);
+                    match self.find_item(&contents, target_atom) {
+                        Some(i) => self.finish_read(Some(contents[i].1.clone())),
+                        None => self.next_target(),
+                    }
+                }
+                continue;
+            }
+
+            let target_atom = self.intern_atom(&target);
+            (self.xlib.XConvertSelection)(
+                self.display,
+                selection_atom,
+                target_atom,
+                self.atoms.property,
+                self.window,
+                CURRENT_TIME,
+            );
+
+            if let Some(read) = self.reads.front_mut() {
+                read.deadline = Some(Instant::now() + TRANSFER_TIMEOUT);
+            }
+            return;
+        }
+    }
+
+    // The current target could not be converted, try the next one
+    fn next_target(&mut self) {
+        if let Some(read) = self.reads.front_mut() {
+            read.current_target += 1;
+            read.deadline = None;
+            read.incr_data = None;
+        }
+    }
+
+    fn finish_read(&mut self, data: Option<Vec<u8>>) {
+        let read = match self.reads.pop_front() {
+            Some(s) => s,
+            None => return,
+        };
+
+        match read.reply {
+            ReadReply::Targets(reply) => {
+                // TARGETS is a list of atoms (format 32 = c_ulong)
+                let targets = data.map(|data| {
+                    data.chunks_exact(mem::size_of::<Atom>())
+                        .filter_map(|chunk| {
+                            let mut bytes = [0; mem::size_of::<Atom>()];
+                            bytes.copy_from_slice(chunk);
+                            self.get_atom_name(Atom::from_ne_bytes(bytes))
+                        })
+                        .collect()
+                });
+                let _ = reply.send(targets);
+            }
+            ReadReply::Data(reply) => {
+                let target = read.targets.get(read.current_target).cloned();
+                let _ = reply.send(target.and_then(|t| Some((t, data?))));
+            }
+        }
+    }
+
+    fn reply_targets(&mut self, targets: Option<Vec<String>>) {
+        if let Some(read) = self.reads.pop_front() {
+            if let ReadReply::Targets(reply) = read.reply {
+                let _ = reply.send(targets);
+            }
+        }
+    }
+
+    fn handle_selection_notify(&mut self, notify: &XSelectionEvent) {
+        if notify.requestor != self.window {
+            return;
+        }
+
+        match self.reads.front() {
+            Some(r) if r.deadline.is_some() && r.incr_data.is_none() => {}
+            _ => return,
+        }
+
+        // the owner refused the conversion
+        if notify.property == 0 {
+            self.next_target();
+            return;
+        }
+
+        match self.read_property(self.window, notify.property) {
+            Some((property_type, _)) if property_type == self.atoms.incr => {
+                // deleting the property (done by read_property) starts the transfer
+                if let Some(read) = self.reads.front_mut() {
+                    read.incr_data = Some(Vec::new());
+                    read.deadline = Some(Instant::now() + TRANSFER_TIMEOUT);
+                }
+            }
+            Some((_, data)) => self.finish_read(Some(data)),
+            None => self.next_target(),
+        }
+    }
+
+    fn handle_property_notify(&mut self, event: &XPropertyEvent) {
+        if event.state == PROPERTY_DELETE {
+            self.continue_transfer(event.window, event.atom);
+            return;
+        }
+
+        if event.window != self.window
+            || event.atom != self.atoms.property
+            || event.state != PROPERTY_NEW_VALUE
+        {
+            return;
+        }
+
+        match self.reads.front() {
+            Some(r) if r.incr_data.is_some() => {}
+            _ => return,
+        }
+
+        match self.read_property(self.window, self.atoms.property) {
+            // a zero-length chunk ends the transfer
+            Some((_, chunk)) if chunk.is_empty() => {
+                let data = self
+                    .reads
+                    .front_mut()
+                    .and_then(|r| r.incr_data.take())
+                    .unwrap_or_default();
+                self.finish_read(Some(data));
+            }
+            Some((_, chunk)) => {
+                if let Some(read) = self.reads.front_mut() {
+                    if let Some(data) = read.incr_data.as_mut() {
+                        data.extend_from_slice(&chunk);
+                    }
+                    read.deadline = Some(Instant::now() + TRANSFER_TIMEOUT);
+                }
+            }
+            None => self.finish_read(None),
+        }
+    }
+
+    // Reads and deletes a property, returns the type and the raw bytes
+    // (format 32 properties are returned as an array of c_long)
+    fn read_property(&self, window: Window, property: Atom) -> Option<(Atom, Vec<u8>)> {
+        let mut actual_type = 0;
+        let mut actual_format = 0;
+        let mut item_count = 0;
+        let mut bytes_after = 0;
+        let mut data = ptr::null_mut();
+
+        let status = (self.xlib.XGetWindowProperty)(
+            self.display,
+            window,
+            property,
+            0,
+            0x1fff_ffff, // in 4-byte units, read everything
+            X11_TRUE,
+            ANY_PROPERTY_TYPE,
+            &mut actual_type,
+            &mut actual_format,
+            &mut item_count,
+            &mut bytes_after,
+            &mut data,
+        );
+
+        let item_size = match actual_format {
+            8 => 1,
+            16 => mem::size_of::<c_short>(),
+            32 => mem::size_of::<c_long>(),
+            _ => 0,
+        };
+
+        let bytes = if data.is_null() {
+            Vec::new()
+        } else {
+            let bytes =
+                unsafe { core::slice::from_raw_parts(data, item_count as usize * item_size) }
+                    .to_vec();
+            (self.xlib.XFree)(data as *mut c_void);
+            bytes
+        };
+
+        if status != X11_SUCCESS || actual_type == 0 {
+            None
+        } else {
+            Some((actual_type, bytes))
+        }
+    }
+
+    // -- serving owned selections
+
+    fn is_text_mime_type(mime_type: &str) -> bool {
+        TEXT_MIME_TYPES.contains(&mime_type)
+    }
+
+    // Returns the formats the contents can be converted to
+    fn get_targets(&self, contents: &[(String, Vec<u8>)]) -> Vec<Atom> {
+        let mut targets = vec![self.atoms.targets];
+
+        for (mime_type, _) in contents.iter() {
+            targets.push(self.intern_atom(mime_type));
+        }
+
+        if contents.iter().any(|(m, _)| Self::is_text_mime_type(m)) {
+            targets.push(self.atoms.utf8_string);
+            targets.push(XA_STRING);
+            targets.push(self.atoms.text);
+            for mime_type in TEXT_MIME_TYPES {
+                targets.push(self.intern_atom(mime_type));
+            }
+        }
+
+        let mut deduplicated = Vec::with_capacity(targets.len());
+        for target in targets {
+            if !deduplicated.contains(&target) {
+                deduplicated.push(target);
+            }
+        }
+        deduplicated
+    }
+
+    fn find_item(&self, contents: &[(String, Vec<u8>)], target: Atom) -> Option<usize> {
+        let target_name = self.get_atom_name(target)?;
+
+        if let Some(i) = contents.iter().position(|(m, _)| *m == target_name) {
+            return Some(i);
+        }
+
+        let is_text_target = target == self.atoms.utf8_string
+            || target == XA_STRING
+            || target == self.atoms.text
+            || Self::is_text_mime_type(&target_name);
+
+        if is_text_target {
+            contents
+                .iter()
+                .position(|(m, _)| Self::is_text_mime_type(m))
+        } else {
+            None
+        }
+    }
+
+    fn handle_selection_request(&mut self, request: &XSelectionRequestEvent) {
+        // obsolete clients don't set a property
+        let property = if request.property == 0 {
+            request.target
+        } else {
+            request.property
+        };
+
+        let contents = self
+            .get_selection_index(request.selection)
+            .and_then(|i| self.owned[i].clone());
+
+        let accepted = match contents {
+            Some(contents) => {
+                self.send_selection_data(request.requestor, property, request.target, contents)
+            }
+            None => false,
+        };
+
+        let mut event = XEvent { pad: [0; 24] };
+        event.selection = XSelectionEvent {
+            type_: X11_SELECTION_NOTIFY,
+            serial: 0,
+            send_event: X11_TRUE,
+            display: self.display,
+            requestor: request.requestor,
+            selection: request.selection,
+            target: request.target,
+            property: if accepted { property } else { 0 },
+            time: request.time,
+        };
+
+        (self.xlib.XSendEvent)(self.display, request.requestor, X11_FALSE, 0, &mut event);
+    }
+
+    // Writes the converted contents into the property of the requestor,
+    // returns false if the contents can't be converted to the target
+    fn send_selection_data(
+        &mut self,
+        requestor: Window,
+        property: Atom,
+        target: Atom,
+        contents: Arc<Vec<(String, Vec<u8>)>>,
+    ) -> bool {
+        if target == self.atoms.targets {
+            // format 32 properties are passed as an array of c_long
+            let targets = self.get_targets(&contents);
+            (self.xlib.XChangeProperty)(
+                self.display,
+                requestor,
+                property,
+                XA_ATOM,
+                32,
+                PROP_MODE_REPLACE,
+                targets.as_ptr() as *const c_uchar,
+                targets.len() as c_int,
+            );
+            return true;
+        }
+
+        let item = match self.find_item(&contents, target) {
+            Some(s) => s,
+            None => return false,
+        };
+
+        let data = &contents[item].1;
+
+        if data.len() <= self.chunk_size {
+            (self.xlib.XChangeProperty)(
+                self.display,
+                requestor,
+                property,
+                target,
+                8,
+                PROP_MODE_REPLACE,
+                data.as_ptr(),
+                data.len() as c_int,
+            );
+            return true;
+        }
+
+        // INCR: announce the size, the requestor deletes the property
+        // to request the next chunk (see continue_transfer)
+        (self.xlib.XSelectInput)(self.display, requestor, PROPERTY_CHANGE_MASK);
+        let size = [data.len() as c_long];
+        (self.xlib.XChangeProperty)(
+            self.display,
+            requestor,
+            property,
+            self.atoms.incr,
+            32,
+            PROP_MODE_REPLACE,
+            size.as_ptr() as *const c_uchar,
+            1,
+        );
+
+        self.transfers
+            .retain(|t| !(t.requestor == requestor && t.property == property));
+        self.transfers.push(IncrTransfer {
+            requestor,
+            property,
+            target,
+            contents,
+            item,
+            offset: 0,
+            deadline: Instant::now() + TRANSFER_TIMEOUT,
+        });
+
+        true
+    }
+
+    fn continue_transfer(&mut self, requestor: Window, property: Atom) {
+        let index = match self
+            .transfers
+            .iter()
+            .position(|t| t.requestor == requestor && t.property == property)
+        {
+            Some(s) => s,
+            None => return,
+        };
+
+        let chunk_size = self.chunk_size;
+        let transfer = &mut self.transfers[index];
+        let data = &transfer.contents[transfer.item].1;
+        let end = (transfer.offset + chunk_size).min(data.len());
+        let chunk = &data[transfer.offset..end];
+
+        (self.xlib.XChangeProperty)(
+            self.display,
+            requestor,
+            property,
+            transfer.target,
+            8,
+            PROP_MODE_REPLACE,
+            chunk.as_ptr(),
+            chunk.len() as c_int,
+        );
+
+        if chunk.is_empty() {
+            // the zero-length chunk has been sent, transfer complete
+            self.transfers.remove(index);
+            (self.xlib.XSelectInput)(self.display, requestor, 0);
+        } else {
+            transfer.offset = end;
+            transfer.deadline = Instant::now() + TRANSFER_TIMEOUT;
+        }
+    }
+
+    // -- timeouts
+
+    fn handle_timeouts(&mut self) {
+        let now = Instant::now();
+
+        self.transfers.retain(|t| t.deadline > now);
+
+        let timed_out = match self.reads.front() {
+            Some(r) => r.deadline.map(|d| d <= now).unwrap_or(false),
+            None => false,
+        };
+
+        if timed_out {
+            // an unresponsive owner won't answer the other targets either
+            self.finish_read(None);
+        }
+    }
+
+    fn get_poll_timeout(&self) -> c_int {
+        let now = Instant::now();
+
+        self.reads
+            .front()
+            .and_then(|r| r.deadline)
+            .into_iter()
+            .chain(self.transfers.iter().map(|t| t.deadline))
+            .min()
+            .map(|deadline| {
+                deadline
+                    .saturating_duration_since(now)
+                    .as_millis()
+                    .min(c_int::max_value() as u128) as c_int
+            })
+            .unwrap_or(-1)
+    }
+}
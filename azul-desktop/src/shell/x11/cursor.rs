@@ -0,0 +1,309 @@
+//! X11 mouse cursors (libXcursor)
+//!
+//! Named cursors are loaded from the current cursor theme with
+//! `XcursorLibraryLoadCursor`, image cursors (`cursor: url(...)` or
+//! `CallbackInfo::set_custom_cursor()`) are uploaded as ARGB cursors with
+//! `XcursorImageLoadCursor`, which uses XRender if the server supports it.
+//!
+//! Every window caches the cursor handles it created, they are freed when
+//! the window is destroyed (before its display connection is closed).
+
+use super::{Display, Library, Window, X11Cursor};
+use crate::gl::{c_char, c_int};
+use alloc::collections::BTreeMap;
+use azul_core::{
+    app_resources::{RawImageData, RawImageFormat},
+    window::{CustomCursor, MouseCursorType},
+};
+use once_cell::sync::Lazy;
+use std::ffi::CString;
+
+// maximum number of image cursors that are kept in the cache of a window
+const MAX_CACHED_IMAGE_CURSORS: usize = 16;
+
+#[repr(C)]
+struct XcursorImage {
+    version: u32,
+    size: u32,
+    width: u32,
+    height: u32,
+    xhot: u32,
+    yhot: u32,
+    delay: u32,
+    // premultiplied ARGB, row-major
+    pixels: *mut u32,
+}
+
+type XcursorLibraryLoadCursorFuncType = extern "C" fn(*mut Display, *const c_char) -> X11Cursor;
+type XcursorImageCreateFuncType = extern "C" fn(c_int, c_int) -> *mut XcursorImage;
+type XcursorImageDestroyFuncType = extern "C" fn(*mut XcursorImage);
+type XcursorImageLoadCursorFuncType = extern "C" fn(*mut Display, *const XcursorImage) -> X11Cursor;
+type XDefineCursorFuncType = extern "C" fn(*mut Display, Window, X11Cursor) -> c_int;
+type XFreeCursorFuncType = extern "C" fn(*mut Display, X11Cursor) -> c_int;
+type XFlushFuncType = extern "C" fn(*mut Display) -> c_int;
+
+// libXcursor + Xlib functions used for setting the cursor (on the connection of the window)
+struct CursorLib {
+    pub xcursor: Library,
+    pub x11: Library,
+    pub XcursorLibraryLoadCursor: XcursorLibraryLoadCursorFuncType,
+    pub XcursorImageCreate: XcursorImageCreateFuncType,
+    pub XcursorImageDestroy: XcursorImageDestroyFuncType,
+    pub XcursorImageLoadCursor: XcursorImageLoadCursorFuncType,
+    pub XDefineCursor: XDefineCursorFuncType,
+    pub XFreeCursor: XFreeCursorFuncType,
+    pub XFlush: XFlushFuncType,
+}
+
+impl CursorLib {
+    fn new() -> Option<Self> {
+        use super::clipboard::load_function;
+
+        let xcursor = Library::load("libXcursor.so.1").ok()?;
+        let x11 = Library::load("libX11.so").ok()?;
+        Some(Self {
+            XcursorLibraryLoadCursor: load_function(&xcursor, "XcursorLibraryLoadCursor")?,
+            XcursorImageCreate: load_function(&xcursor, "XcursorImageCreate")?,
+            XcursorImageDestroy: load_function(&xcursor, "XcursorImageDestroy")?,
+            XcursorImageLoadCursor: load_function(&xcursor, "XcursorImageLoadCursor")?,
+            XDefineCursor: load_function(&x11, "XDefineCursor")?,
+            XFreeCursor: load_function(&x11, "XFreeCursor")?,
+            XFlush: load_function(&x11, "XFlush")?,
+            xcursor,
+            x11,
+        })
+    }
+}
+
+static CURSOR_LIB: Lazy<Option<CursorLib>> = Lazy::new(CursorLib::new);
+
+/// Cursor that is currently set on a window
+#[derive(Debug, Clone, PartialEq)]
+enum CurrentCursor {
+    Named(&'static str),
+    Image(CustomCursor),
+}
+
+/// Cursor handles of one window, created on the connection of the window
+pub(super) struct WindowCursors {
+    lib: &'static CursorLib,
+    display: *mut Display,
+    window: Window,
+    current: Option<CurrentCursor>,
+    named: BTreeMap<&'static str, X11Cursor>,
+    images: BTreeMap<CustomCursor, X11Cursor>,
+}
+
+impl WindowCursors {
+    /// Returns `None` if libXcursor is not installed
+    pub(super) fn new(display: *mut Display, window: Window) -> Option<Self> {
+        Some(Self {
+            lib: CURSOR_LIB.as_ref()?,
+            display,
+            window,
+            current: None,
+            named: BTreeMap::new(),
+            images: BTreeMap::new(),
+        })
+    }
+
+    /// Sets the cursor of the window: the image cursor if one is given and
+    /// could be uploaded, otherwise the named cursor of the `fallback` type
+    pub(super) fn set_cursor(&mut self, fallback: MouseCursorType, image: Option<&CustomCursor>) {
+        if let Some(image) = image {
+            let new = CurrentCursor::Image(image.clone());
+            if self.current.as_ref() == Some(&new) {
+                return;
+            }
+            if let Some(cursor) = self.get_image_cursor(image) {
+                self.define_cursor(cursor, new);
+                return;
+            }
+        }
+
+        let name = get_cursor_name(fallback);
+        let new = CurrentCursor::Named(name);
+        if self.current.as_ref() == Some(&new) {
+            return;
+        }
+        if let Some(cursor) = self.get_named_cursor(name) {
+            self.define_cursor(cursor, new);
+        }
+    }
+
+    fn define_cursor(&mut self, cursor: X11Cursor, new: CurrentCursor) {
+        (self.lib.XDefineCursor)(self.display, self.window, cursor);
+        (self.lib.XFlush)(self.display);
+        self.current = Some(new);
+    }
+
+    fn get_named_cursor(&mut self, name: &'static str) -> Option<X11Cursor> {
+        if let Some(cursor) = self.named.get(name) {
+            return Some(*cursor);
+        }
+
+        let cname = CString::new(name).ok()?;
+        let cursor = (self.lib.XcursorLibraryLoadCursor)(self.display, cname.as_ptr());
+        if cursor == 0 {
+            return None;
+        }
+        self.named.insert(name, cursor);
+        Some(cursor)
+    }
+
+    fn get_image_cursor(&mut self, image: &CustomCursor) -> Option<X11Cursor> {
+        if let Some(cursor) = self.images.get(image) {
+            return Some(*cursor);
+        }
+
+        let (width, height, pixels) = get_argb_pixels(image)?;
+
+        let xcursor_image = (self.lib.XcursorImageCreate)(width as c_int, height as c_int);
+        if xcursor_image.is_null() {
+            return None;
+        }
+
+        let cursor = unsafe {
+            let x = &mut *xcursor_image;
+            // the hotspot has to be inside of the image
+            x.xhot = image.hotspot_x.min(width as u32 - 1);
+            x.yhot = image.hotspot_y.min(height as u32 - 1);
+            core::ptr::copy_nonoverlapping(pixels.as_ptr(), x.pixels, pixels.len());
+            let cursor = (self.lib.XcursorImageLoadCursor)(self.display, xcursor_image);
+            (self.lib.XcursorImageDestroy)(xcursor_image);
+            cursor
+        };
+
+        if cursor == 0 {
+            return None;
+        }
+
+        if self.images.len() >= MAX_CACHED_IMAGE_CURSORS {
+            self.free_unused_image_cursors();
+        }
+        self.images.insert(image.clone(), cursor);
+        Some(cursor)
+    }
+
+    // Frees all image cursors except the one that is currently set
+    fn free_unused_image_cursors(&mut self) {
+        let current = match self.current.as_ref() {
+            Some(CurrentCursor::Image(i)) => Some(i.clone()),
+            _ => None,
+        };
+        let lib = self.lib;
+        let display = self.display;
+        self.images.retain(|image, cursor| {
+            if Some(image) == current.as_ref() {
+                true
+            } else {
+                (lib.XFreeCursor)(display, *cursor);
+                false
+            }
+        });
+    }
+}
+
+impl Drop for WindowCursors {
+    fn drop(&mut self) {
+        for cursor in self.named.values().chain(self.images.values()) {
+            (self.lib.XFreeCursor)(self.display, *cursor);
+        }
+    }
+}
+
+// Converts the (premultiplied) image of the cursor into premultiplied ARGB pixels
+pub(crate) fn get_argb_pixels(cursor: &CustomCursor) -> Option<(usize, usize, Vec<u32>)> {
+    let image = cursor.image.get_rawimage()?;
+    let pixels = match &image.pixels {
+        RawImageData::U8(u) => u.as_ref(),
+        _ => return None,
+    };
+
+    let argb = |a: u8, r: u8, g: u8, b: u8| {
+        (a as u32) << 24 | (r as u32) << 16 | (g as u32) << 8 | b as u32
+    };
+
+    let argb_pixels: Vec<u32> = match image.data_format {
+        RawImageFormat::BGRA8 => pixels
+            .chunks_exact(4)
+            .map(|p| argb(p[3], p[2], p[1], p[0]))
+            .collect(),
+        RawImageFormat::RGBA8 => pixels
+            .chunks_exact(4)
+            .map(|p| argb(p[3], p[0], p[1], p[2]))
+            .collect(),
+        RawImageFormat::RGB8 => pixels
+            .chunks_exact(3)
+            .map(|p| argb(255, p[0], p[1], p[2]))
+            .collect(),
+        RawImageFormat::R8 => pixels.iter().map(|p| argb(255, *p, *p, *p)).collect(),
+        _ => return None,
+    };
+
+    if image.width == 0 || image.height == 0 || argb_pixels.len() != image.width * image.height {
+        return None;
+    }
+
+    Some((image.width, image.height, argb_pixels))
+}
+
+/// Name of the cursor in the XCursor theme
+pub(crate) fn get_cursor_name(cursor: MouseCursorType) -> &'static str {
+    use azul_core::window::MouseCursorType::*;
+    match cursor {
+        Default | Arrow => "left_ptr",
+        Crosshair => "crosshair",
+        Hand => "hand2",
+        Move => "fleur",
+        Text => "xterm",
+        Wait => "watch",
+        Help => "question_arrow",
+        Progress => "left_ptr_watch",
+        NotAllowed => "crossed_circle",
+        ContextMenu => "context-menu",
+        Cell => "cell",
+        VerticalText => "vertical-text",
+        Alias => "alias",
+        Copy => "copy",
+        NoDrop => "no-drop",
+        Grab => "hand1",
+        Grabbing => "grabbing",
+        AllScroll => "all-scroll",
+        ZoomIn => "zoom-in",
+        ZoomOut => "zoom-out",
+        EResize => "right_side",
+        NResize => "top_side",
+        NeResize => "top_right_corner",
+        NwResize => "top_left_corner",
+        SResize => "bottom_side",
+        SeResize => "bottom_right_corner",
+        SwResize => "bottom_left_corner",
+        WResize => "left_side",
+        EwResize | ColResize => "sb_h_double_arrow",
+        NsResize | RowResize => "sb_v_double_arrow",
+        NeswResize => "fd_double_arrow",
+        NwseResize => "bd_double_arrow",
+    }
+}
+
+#[test]
+fn test_cursor_argb_pixels() {
+    use azul_core::app_resources::{ImageRef, RawImage};
+
+    // opaque pixels, so that premultiplying the alpha doesn't change the colors
+    let image = ImageRef::new_rawimage(RawImage {
+        pixels: RawImageData::U8(vec![255, 0, 0, 255, 0, 128, 255, 255].into()),
+        width: 2,
+        height: 1,
+        premultiplied_alpha: false,
+        data_format: RawImageFormat::RGBA8,
+    })
+    .unwrap();
+
+    let cursor = CustomCursor::new(image, 1, 0);
+    assert_eq!(
+        get_argb_pixels(&cursor),
+        Some((2, 1, vec![0xffff0000, 0xff0080ff]))
+    );
+}
@@ -0,0 +1,968 @@
+//! Minimal D-Bus client on top of `libdbus-1` (loaded at runtime)
+//!
+//! Messages are (de)serialized from / into `DBusValue` trees, so the
+//! protocol implementations (tray icon, ...) don't have to deal with the
+//! message iterators of libdbus. A `DBusConnection` is a private connection,
+//! it must only be used from one thread at a time.
+
+use super::Library;
+use crate::gl::{c_char, c_int, c_uint};
+use core::{ffi::c_void, ptr};
+use once_cell::sync::Lazy;
+use std::ffi::{CStr, CString};
+
+type dbus_bool_t = c_uint;
+
+#[repr(C)]
+struct DBusConnectionRaw {
+    _private: [u8; 0],
+}
+
+#[repr(C)]
+struct DBusMessageRaw {
+    _private: [u8; 0],
+}
+
+#[repr(C)]
+struct DBusError {
+    name: *const c_char,
+    message: *const c_char,
+    dummy: c_uint,
+    padding1: *mut c_void,
+}
+
+impl DBusError {
+    fn new(dbus: &DBus) -> Self {
+        let mut error = DBusError {
+            name: ptr::null(),
+            message: ptr::null(),
+            dummy: 0,
+            padding1: ptr::null_mut(),
+        };
+        (dbus.dbus_error_init)(&mut error);
+        error
+    }
+
+    // returns the error message and frees the error, if an error is set
+    fn take(&mut self, dbus: &DBus) -> Option<String> {
+        if (dbus.dbus_error_is_set)(self) == 0 {
+            return None;
+        }
+        let message = unsafe { cstr_to_string(self.message) }
+            .or_else(|| unsafe { cstr_to_string(self.name) })
+            .unwrap_or_default();
+        (dbus.dbus_error_free)(self);
+        Some(message)
+    }
+}
+
+#[repr(C)]
+struct DBusMessageIter {
+    dummy1: *mut c_void,
+    dummy2: *mut c_void,
+    dummy3: u32,
+    dummy4: c_int,
+    dummy5: c_int,
+    dummy6: c_int,
+    dummy7: c_int,
+    dummy8: c_int,
+    dummy9: c_int,
+    dummy10: c_int,
+    dummy11: c_int,
+    pad1: c_int,
+    pad2: *mut c_void,
+    pad3: *mut c_void,
+}
+
+impl DBusMessageIter {
+    fn new() -> Self {
+        unsafe { core::mem::zeroed() }
+    }
+}
+
+const DBUS_BUS_SESSION: c_int = 0;
+const DBUS_NAME_FLAG_DO_NOT_QUEUE: c_uint = 0x4;
+const DBUS_REQUEST_NAME_REPLY_PRIMARY_OWNER: c_int = 1;
+
+const DBUS_MESSAGE_TYPE_METHOD_CALL: c_int = 1;
+const DBUS_MESSAGE_TYPE_METHOD_RETURN: c_int = 2;
+const DBUS_MESSAGE_TYPE_ERROR: c_int = 3;
+const DBUS_MESSAGE_TYPE_SIGNAL: c_int = 4;
+
+const DBUS_TYPE_INVALID: c_int = 0;
+const DBUS_TYPE_BYTE: c_int = b'y' as c_int;
+const DBUS_TYPE_BOOLEAN: c_int = b'b' as c_int;
+const DBUS_TYPE_INT16: c_int = b'n' as c_int;
+const DBUS_TYPE_UINT16: c_int = b'q' as c_int;
+const DBUS_TYPE_INT32: c_int = b'i' as c_int;
+const DBUS_TYPE_UINT32: c_int = b'u' as c_int;
+const DBUS_TYPE_INT64: c_int = b'x' as c_int;
+const DBUS_TYPE_UINT64: c_int = b't' as c_int;
+const DBUS_TYPE_DOUBLE: c_int = b'd' as c_int;
+const DBUS_TYPE_STRING: c_int = b's' as c_int;
+const DBUS_TYPE_OBJECT_PATH: c_int = b'o' as c_int;
+const DBUS_TYPE_SIGNATURE: c_int = b'g' as c_int;
+const DBUS_TYPE_UNIX_FD: c_int = b'h' as c_int;
+const DBUS_TYPE_ARRAY: c_int = b'a' as c_int;
+const DBUS_TYPE_VARIANT: c_int = b'v' as c_int;
+const DBUS_TYPE_STRUCT: c_int = b'r' as c_int;
+const DBUS_TYPE_DICT_ENTRY: c_int = b'e' as c_int;
+
+/// Error name for calls of unknown methods
+pub(crate) const DBUS_ERROR_UNKNOWN_METHOD: &str = "org.freedesktop.DBus.Error.UnknownMethod";
+/// Error name for calls with wrong arguments
+pub(crate) const DBUS_ERROR_INVALID_ARGS: &str = "org.freedesktop.DBus.Error.InvalidArgs";
+
+type dbus_threads_init_defaultFuncType = extern "C" fn() -> dbus_bool_t;
+type dbus_error_initFuncType = extern "C" fn(*mut DBusError);
+type dbus_error_freeFuncType = extern "C" fn(*mut DBusError);
+type dbus_error_is_setFuncType = extern "C" fn(*const DBusError) -> dbus_bool_t;
+type dbus_freeFuncType = extern "C" fn(*mut c_void);
+type dbus_bus_get_privateFuncType = extern "C" fn(c_int, *mut DBusError) -> *mut DBusConnectionRaw;
+type dbus_bus_registerFuncType =
+    extern "C" fn(*mut DBusConnectionRaw, *mut DBusError) -> dbus_bool_t;
+type dbus_bus_get_unique_nameFuncType = extern "C" fn(*mut DBusConnectionRaw) -> *const c_char;
+type dbus_bus_request_nameFuncType =
+    extern "C" fn(*mut DBusConnectionRaw, *const c_char, c_uint, *mut DBusError) -> c_int;
+type dbus_bus_add_matchFuncType =
+    extern "C" fn(*mut DBusConnectionRaw, *const c_char, *mut DBusError);
+type dbus_connection_set_exit_on_disconnectFuncType =
+    extern "C" fn(*mut DBusConnectionRaw, dbus_bool_t);
+type dbus_connection_open_privateFuncType =
+    extern "C" fn(*const c_char, *mut DBusError) -> *mut DBusConnectionRaw;
+type dbus_connection_closeFuncType = extern "C" fn(*mut DBusConnectionRaw);
+type dbus_connection_unrefFuncType = extern "C" fn(*mut DBusConnectionRaw);
+type dbus_connection_get_unix_fdFuncType =
+    extern "C" fn(*mut DBusConnectionRaw, *mut c_int) -> dbus_bool_t;
+type dbus_connection_read_writeFuncType =
+    extern "C" fn(*mut DBusConnectionRaw, c_int) -> dbus_bool_t;
+type dbus_connection_pop_messageFuncType =
+    extern "C" fn(*mut DBusConnectionRaw) -> *mut DBusMessageRaw;
+type dbus_connection_sendFuncType =
+    extern "C" fn(*mut DBusConnectionRaw, *mut DBusMessageRaw, *mut u32) -> dbus_bool_t;
+type dbus_connection_flushFuncType = extern "C" fn(*mut DBusConnectionRaw);
+type dbus_connection_send_with_reply_and_blockFuncType = extern "C" fn(
+    *mut DBusConnectionRaw,
+    *mut DBusMessageRaw,
+    c_int,
+    *mut DBusError,
+) -> *mut DBusMessageRaw;
+type dbus_message_new_method_callFuncType = extern "C" fn(
+    *const c_char,
+    *const c_char,
+    *const c_char,
+    *const c_char,
+) -> *mut DBusMessageRaw;
+type dbus_message_new_method_returnFuncType =
+    extern "C" fn(*mut DBusMessageRaw) -> *mut DBusMessageRaw;
+type dbus_message_new_errorFuncType =
+    extern "C" fn(*mut DBusMessageRaw, *const c_char, *const c_char) -> *mut DBusMessageRaw;
+type dbus_message_new_signalFuncType =
+    extern "C" fn(*const c_char, *const c_char, *const c_char) -> *mut DBusMessageRaw;
+type dbus_message_unrefFuncType = extern "C" fn(*mut DBusMessageRaw);
+type dbus_message_get_typeFuncType = extern "C" fn(*mut DBusMessageRaw) -> c_int;
+type dbus_message_get_stringFuncType = extern "C" fn(*mut DBusMessageRaw) -> *const c_char;
+type dbus_message_get_serialFuncType = extern "C" fn(*mut DBusMessageRaw) -> u32;
+type dbus_message_iter_initFuncType =
+    extern "C" fn(*mut DBusMessageRaw, *mut DBusMessageIter) -> dbus_bool_t;
+type dbus_message_iter_init_appendFuncType =
+    extern "C" fn(*mut DBusMessageRaw, *mut DBusMessageIter);
+type dbus_message_iter_get_arg_typeFuncType = extern "C" fn(*mut DBusMessageIter) -> c_int;
+type dbus_message_iter_get_basicFuncType = extern "C" fn(*mut DBusMessageIter, *mut c_void);
+type dbus_message_iter_nextFuncType = extern "C" fn(*mut DBusMessageIter) -> dbus_bool_t;
+type dbus_message_iter_recurseFuncType = extern "C" fn(*mut DBusMessageIter, *mut DBusMessageIter);
+type dbus_message_iter_get_signatureFuncType = extern "C" fn(*mut DBusMessageIter) -> *mut c_char;
+type dbus_message_iter_append_basicFuncType =
+    extern "C" fn(*mut DBusMessageIter, c_int, *const c_void) -> dbus_bool_t;
+type dbus_message_iter_open_containerFuncType =
+    extern "C" fn(*mut DBusMessageIter, c_int, *const c_char, *mut DBusMessageIter) -> dbus_bool_t;
+type dbus_message_iter_close_containerFuncType =
+    extern "C" fn(*mut DBusMessageIter, *mut DBusMessageIter) -> dbus_bool_t;
+
+struct DBus {
+    pub library: Library,
+    pub dbus_threads_init_default: dbus_threads_init_defaultFuncType,
+    pub dbus_error_init: dbus_error_initFuncType,
+    pub dbus_error_free: dbus_error_freeFuncType,
+    pub dbus_error_is_set: dbus_error_is_setFuncType,
+    pub dbus_free: dbus_freeFuncType,
+    pub dbus_bus_get_private: dbus_bus_get_privateFuncType,
+    pub dbus_bus_register: dbus_bus_registerFuncType,
+    pub dbus_bus_get_unique_name: dbus_bus_get_unique_nameFuncType,
+    pub dbus_bus_request_name: dbus_bus_request_nameFuncType,
+    pub dbus_bus_add_match: dbus_bus_add_matchFuncType,
+    pub dbus_connection_set_exit_on_disconnect: dbus_connection_set_exit_on_disconnectFuncType,
+    pub dbus_connection_open_private: dbus_connection_open_privateFuncType,
+    pub dbus_connection_close: dbus_connection_closeFuncType,
+    pub dbus_connection_unref: dbus_connection_unrefFuncType,
+    pub dbus_connection_get_unix_fd: dbus_connection_get_unix_fdFuncType,
+    pub dbus_connection_read_write: dbus_connection_read_writeFuncType,
+    pub dbus_connection_pop_message: dbus_connection_pop_messageFuncType,
+    pub dbus_connection_send: dbus_connection_sendFuncType,
+    pub dbus_connection_flush: dbus_connection_flushFuncType,
+    pub dbus_connection_send_with_reply_and_block:
+        dbus_connection_send_with_reply_and_blockFuncType,
+    pub dbus_message_new_method_call: dbus_message_new_method_callFuncType,
+    pub dbus_message_new_method_return: dbus_message_new_method_returnFuncType,
+    pub dbus_message_new_error: dbus_message_new_errorFuncType,
+    pub dbus_message_new_signal: dbus_message_new_signalFuncType,
+    pub dbus_message_unref: dbus_message_unrefFuncType,
+    pub dbus_message_get_type: dbus_message_get_typeFuncType,
+    pub dbus_message_get_path: dbus_message_get_stringFuncType,
+    pub dbus_message_get_interface: dbus_message_get_stringFuncType,
+    pub dbus_message_get_member: dbus_message_get_stringFuncType,
+    pub dbus_message_get_sender: dbus_message_get_stringFuncType,
+    pub dbus_message_get_error_name: dbus_message_get_stringFuncType,
+    pub dbus_message_get_serial: dbus_message_get_serialFuncType,
+    pub dbus_message_get_reply_serial: dbus_message_get_serialFuncType,
+    pub dbus_message_iter_init: dbus_message_iter_initFuncType,
+    pub dbus_message_iter_init_append: dbus_message_iter_init_appendFuncType,
+    pub dbus_message_iter_get_arg_type: dbus_message_iter_get_arg_typeFuncType,
+    pub dbus_message_iter_get_basic: dbus_message_iter_get_basicFuncType,
+    pub dbus_message_iter_next: dbus_message_iter_nextFuncType,
+    pub dbus_message_iter_recurse: dbus_message_iter_recurseFuncType,
+    pub dbus_message_iter_get_signature: dbus_message_iter_get_signatureFuncType,
+    pub dbus_message_iter_append_basic: dbus_message_iter_append_basicFuncType,
+    pub dbus_message_iter_open_container: dbus_message_iter_open_containerFuncType,
+    pub dbus_message_iter_close_container: dbus_message_iter_close_containerFuncType,
+}
+
+impl DBus {
+    fn new() -> Option<Self> {
+        use super::clipboard::load_function;
+
+        let library = Library::load("libdbus-1.so.3").ok()?;
+        let dbus = Self {
+            dbus_threads_init_default: load_function(&library, "dbus_threads_init_default")?,
+            dbus_error_init: load_function(&library, "dbus_error_init")?,
+            dbus_error_free: load_function(&library, "dbus_error_free")?,
+            dbus_error_is_set: load_function(&library, "dbus_error_is_set")?,
+            dbus_free: load_function(&library, "dbus_free")?,
+            dbus_bus_get_private: load_function(&library, "dbus_bus_get_private")?,
+            dbus_bus_register: load_function(&library, "dbus_bus_register")?,
+            dbus_bus_get_unique_name: load_function(&library, "dbus_bus_get_unique_name")?,
+            dbus_bus_request_name: load_function(&library, "dbus_bus_request_name")?,
+            dbus_bus_add_match: load_function(&library, "dbus_bus_add_match")?,
+            dbus_connection_set_exit_on_disconnect: load_function(
+                &library,
+                "dbus_connection_set_exit_on_disconnect",
+            )?,
+            dbus_connection_open_private: load_function(&library, "dbus_connection_open_private")?,
+            dbus_connection_close: load_function(&library, "dbus_connection_close")?,
+            dbus_connection_unref: load_function(&library, "dbus_connection_unref")?,
+            dbus_connection_get_unix_fd: load_function(&library, "dbus_connection_get_unix_fd")?,
+            dbus_connection_read_write: load_function(&library, "dbus_connection_read_write")?,
+            dbus_connection_pop_message: load_function(&library, "dbus_connection_pop_message")?,
+            dbus_connection_send: load_function(&library, "dbus_connection_send")?,
+            dbus_connection_flush: load_function(&library, "dbus_connection_flush")?,
+            dbus_connection_send_with_reply_and_block: load_function(
+                &library,
+                "dbus_connection_send_with_reply_and_block",
+            )?,
+            dbus_message_new_method_call: load_function(&library, "dbus_message_new_method_call")?,
+            dbus_message_new_method_return: load_function(
+                &library,
+                "dbus_message_new_method_return",
+            )?,
+            dbus_message_new_error: load_function(&library, "dbus_message_new_error")?,
+            dbus_message_new_signal: load_function(&library, "dbus_message_new_signal")?,
+            dbus_message_unref: load_function(&library, "dbus_message_unref")?,
+            dbus_message_get_type: load_function(&library, "dbus_message_get_type")?,
+            dbus_message_get_path: load_function(&library, "dbus_message_get_path")?,
+            dbus_message_get_interface: load_function(&library, "dbus_message_get_interface")?,
+            dbus_message_get_member: load_function(&library, "dbus_message_get_member")?,
+            dbus_message_get_sender: load_function(&library, "dbus_message_get_sender")?,
+            dbus_message_get_error_name: load_function(&library, "dbus_message_get_error_name")?,
+            dbus_message_get_serial: load_function(&library, "dbus_message_get_serial")?,
+            dbus_message_get_reply_serial: load_function(
+                &library,
+                "dbus_message_get_reply_serial",
+            )?,
+            dbus_message_iter_init: load_function(&library, "dbus_message_iter_init")?,
+            dbus_message_iter_init_append: load_function(
+                &library,
+                "dbus_message_iter_init_append",
+            )?,
+            dbus_message_iter_get_arg_type: load_function(
+                &library,
+                "dbus_message_iter_get_arg_type",
+            )?,
+            dbus_message_iter_get_basic: load_function(&library, "dbus_message_iter_get_basic")?,
+            dbus_message_iter_next: load_function(&library, "dbus_message_iter_next")?,
+            dbus_message_iter_recurse: load_function(&library, "dbus_message_iter_recurse")?,
+            dbus_message_iter_get_signature: load_function(
+                &library,
+                "dbus_message_iter_get_signature",
+            )?,
+            dbus_message_iter_append_basic: load_function(
+                &library,
+                "dbus_message_iter_append_basic",
+            )?,
+            dbus_message_iter_open_container: load_function(
+                &library,
+                "dbus_message_iter_open_container",
+            )?,
+            dbus_message_iter_close_container: load_function(
+                &library,
+                "dbus_message_iter_close_container",
+            )?,
+            library,
+        };
+        // connections are created on background threads
+        (dbus.dbus_threads_init_default)();
+        Some(dbus)
+    }
+}
+
+static DBUS: Lazy<Option<DBus>> = Lazy::new(DBus::new);
+
+unsafe fn cstr_to_string(s: *const c_char) -> Option<String> {
+    if s.is_null() {
+        None
+    } else {
+        Some(CStr::from_ptr(s).to_string_lossy().into_owned())
+    }
+}
+
+fn to_cstring(s: &str) -> CString {
+    CString::new(s.replace('\0', "")).unwrap_or_default()
+}
+
+/// Argument or return value of a D-Bus message
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum DBusValue {
+    Byte(u8),
+    Bool(bool),
+    Int16(i16),
+    UInt16(u16),
+    Int32(i32),
+    UInt32(u32),
+    Int64(i64),
+    UInt64(u64),
+    Double(f64),
+    String(String),
+    ObjectPath(String),
+    Signature(String),
+    /// Index of a file descriptor, file descriptors are not supported
+    UnixFd(u32),
+    Variant(Box<DBusValue>),
+    /// Signature of the elements and the elements
+    Array(String, Vec<DBusValue>),
+    Struct(Vec<DBusValue>),
+    DictEntry(Box<DBusValue>, Box<DBusValue>),
+}
+
+impl DBusValue {
+    pub(crate) fn string(s: &str) -> Self {
+        DBusValue::String(s.to_string())
+    }
+
+    pub(crate) fn variant(value: DBusValue) -> Self {
+        DBusValue::Variant(Box::new(value))
+    }
+
+    /// Creates a `a{sv}` dictionary (used for property maps and hints)
+    pub(crate) fn dict(entries: Vec<(&str, DBusValue)>) -> Self {
+        DBusValue::Array(
+            "{sv}".to_string(),
+            entries
+                .into_iter()
+                .map(|(k, v)| {
+                    DBusValue::DictEntry(
+                        Box::new(DBusValue::string(k)),
+                        Box::new(DBusValue::variant(v)),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    pub(crate) fn signature(&self) -> String {
+        use self::DBusValue::*;
+        match self {
+            Byte(_) => "y".to_string(),
+            Bool(_) => "b".to_string(),
+            Int16(_) => "n".to_string(),
+            UInt16(_) => "q".to_string(),
+            Int32(_) => "i".to_string(),
+            UInt32(_) => "u".to_string(),
+            Int64(_) => "x".to_string(),
+            UInt64(_) => "t".to_string(),
+            Double(_) => "d".to_string(),
+            String(_) => "s".to_string(),
+            ObjectPath(_) => "o".to_string(),
+            Signature(_) => "g".to_string(),
+            UnixFd(_) => "h".to_string(),
+            Variant(_) => "v".to_string(),
+            Array(element, _) => format!("a{}", element),
+            Struct(fields) => format!(
+                "({})",
+                fields
+                    .iter()
+                    .map(|f| f.signature())
+                    .collect::<Vec<_>>()
+                    .join("")
+            ),
+            DictEntry(k, v) => format!("{{{}{}}}", k.signature(), v.signature()),
+        }
+    }
+
+    /// Returns the value, looking through variants
+    pub(crate) fn inner(&self) -> &DBusValue {
+        match self {
+            DBusValue::Variant(v) => v.inner(),
+            other => other,
+        }
+    }
+
+    pub(crate) fn as_str(&self) -> Option<&str> {
+        match self.inner() {
+            DBusValue::String(s) | DBusValue::ObjectPath(s) | DBusValue::Signature(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_i64(&self) -> Option<i64> {
+        match *self.inner() {
+            DBusValue::Byte(v) => Some(v as i64),
+            DBusValue::Int16(v) => Some(v as i64),
+            DBusValue::UInt16(v) => Some(v as i64),
+            DBusValue::Int32(v) => Some(v as i64),
+            DBusValue::UInt32(v) => Some(v as i64),
+            DBusValue::Int64(v) => Some(v),
+            DBusValue::UInt64(v) => Some(v as i64),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_bool(&self) -> Option<bool> {
+        match *self.inner() {
+            DBusValue::Bool(b) => Some(b),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_slice(&self) -> Option<&[DBusValue]> {
+        match self.inner() {
+            DBusValue::Array(_, v) | DBusValue::Struct(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Looks up a key in a dictionary (array of dict entries)
+    pub(crate) fn get(&self, key: &str) -> Option<&DBusValue> {
+        self.as_slice()?.iter().find_map(|entry| match entry {
+            DBusValue::DictEntry(k, v) if k.as_str() == Some(key) => Some(&**v),
+            _ => None,
+        })
+    }
+
+    fn append(&self, dbus: &DBus, iter: &mut DBusMessageIter) -> bool {
+        use self::DBusValue::*;
+
+        fn basic<T>(dbus: &DBus, iter: &mut DBusMessageIter, ty: c_int, value: &T) -> bool {
+            (dbus.dbus_message_iter_append_basic)(iter, ty, value as *const T as *const c_void) != 0
+        }
+
+        fn string(dbus: &DBus, iter: &mut DBusMessageIter, ty: c_int, s: &str) -> bool {
+            let s = to_cstring(s);
+            let ptr = s.as_ptr();
+            basic(dbus, iter, ty, &ptr)
+        }
+
+        fn container<'a>(
+            dbus: &DBus,
+            iter: &mut DBusMessageIter,
+            ty: c_int,
+            signature: Option<&str>,
+            values: impl Iterator<Item = &'a DBusValue>,
+        ) -> bool {
+            let signature = signature.map(to_cstring);
+            let mut sub = DBusMessageIter::new();
+            let opened = (dbus.dbus_message_iter_open_container)(
+                iter,
+                ty,
+                signature
+                    .as_ref()
+                    .map(|s| s.as_ptr())
+                    .unwrap_or(ptr::null()),
+                &mut sub,
+            ) != 0;
+            if !opened {
+                return false;
+            }
+            let mut ok = true;
+            for v in values {
+                ok = ok && v.append(dbus, &mut sub);
+            }
+            (dbus.dbus_message_iter_close_container)(iter, &mut sub) != 0 && ok
+        }
+
+        match self {
+            Byte(v) => basic(dbus, iter, DBUS_TYPE_BYTE, v),
+            Bool(v) => basic(dbus, iter, DBUS_TYPE_BOOLEAN, &(*v as dbus_bool_t)),
+            Int16(v) => basic(dbus, iter, DBUS_TYPE_INT16, v),
+            UInt16(v) => basic(dbus, iter, DBUS_TYPE_UINT16, v),
+            Int32(v) => basic(dbus, iter, DBUS_TYPE_INT32, v),
+            UInt32(v) => basic(dbus, iter, DBUS_TYPE_UINT32, v),
+            Int64(v) => basic(dbus, iter, DBUS_TYPE_INT64, v),
+            UInt64(v) => basic(dbus, iter, DBUS_TYPE_UINT64, v),
+            Double(v) => basic(dbus, iter, DBUS_TYPE_DOUBLE, v),
+            String(s) => string(dbus, iter, DBUS_TYPE_STRING, s),
+            ObjectPath(s) => string(dbus, iter, DBUS_TYPE_OBJECT_PATH, s),
+            Signature(s) => string(dbus, iter, DBUS_TYPE_SIGNATURE, s),
+            UnixFd(_) => false,
+            Variant(v) => container(
+                dbus,
+                iter,
+                DBUS_TYPE_VARIANT,
+                Some(&v.signature()),
+                core::iter::once(&**v),
+            ),
+            Array(element, values) => {
+                container(dbus, iter, DBUS_TYPE_ARRAY, Some(element), values.iter())
+            }
+            Struct(fields) => container(dbus, iter, DBUS_TYPE_STRUCT, None, fields.iter()),
+            DictEntry(k, v) => container(
+                dbus,
+                iter,
+                DBUS_TYPE_DICT_ENTRY,
+                None,
+                core::iter::once(&**k).chain(core::iter::once(&**v)),
+            ),
+        }
+    }
+
+    // reads the value at the current position of the iterator
+    fn read(dbus: &DBus, iter: &mut DBusMessageIter) -> Option<Self> {
+        use self::DBusValue::*;
+
+        fn basic<T: Default>(dbus: &DBus, iter: &mut DBusMessageIter) -> T {
+            let mut value = T::default();
+            (dbus.dbus_message_iter_get_basic)(iter, &mut value as *mut T as *mut c_void);
+            value
+        }
+
+        fn string(dbus: &DBus, iter: &mut DBusMessageIter) -> std::string::String {
+            let s: *const c_char = {
+                let mut s = ptr::null();
+                (dbus.dbus_message_iter_get_basic)(
+                    iter,
+                    &mut s as *mut *const c_char as *mut c_void,
+                );
+                s
+            };
+            unsafe { cstr_to_string(s) }.unwrap_or_default()
+        }
+
+        fn children(dbus: &DBus, iter: &mut DBusMessageIter) -> Vec<DBusValue> {
+            let mut sub = DBusMessageIter::new();
+            (dbus.dbus_message_iter_recurse)(iter, &mut sub);
+            read_all(dbus, &mut sub)
+        }
+
+        let value = match (dbus.dbus_message_iter_get_arg_type)(iter) {
+            DBUS_TYPE_BYTE => Byte(basic(dbus, iter)),
+            DBUS_TYPE_BOOLEAN => Bool(basic::<dbus_bool_t>(dbus, iter) != 0),
+            DBUS_TYPE_INT16 => Int16(basic(dbus, iter)),
+            DBUS_TYPE_UINT16 => UInt16(basic(dbus, iter)),
+            DBUS_TYPE_INT32 => Int32(basic(dbus, iter)),
+            DBUS_TYPE_UINT32 => UInt32(basic(dbus, iter)),
+            DBUS_TYPE_INT64 => Int64(basic(dbus, iter)),
+            DBUS_TYPE_UINT64 => UInt64(basic(dbus, iter)),
+            DBUS_TYPE_DOUBLE => Double(basic(dbus, iter)),
+            DBUS_TYPE_STRING => String(string(dbus, iter)),
+            DBUS_TYPE_OBJECT_PATH => ObjectPath(string(dbus, iter)),
+            DBUS_TYPE_SIGNATURE => Signature(string(dbus, iter)),
+            DBUS_TYPE_UNIX_FD => UnixFd(basic(dbus, iter)),
+            DBUS_TYPE_VARIANT => Variant(Box::new(children(dbus, iter).into_iter().next()?)),
+            DBUS_TYPE_ARRAY => {
+                let signature = (dbus.dbus_message_iter_get_signature)(iter);
+                let element = unsafe { cstr_to_string(signature) }
+                    .map(|s| s.get(1..).unwrap_or_default().to_string())
+                    .unwrap_or_default();
+                (dbus.dbus_free)(signature as *mut c_void);
+                Array(element, children(dbus, iter))
+            }
+            DBUS_TYPE_STRUCT => Struct(children(dbus, iter)),
+            DBUS_TYPE_DICT_ENTRY => {
+                let mut entry = children(dbus, iter).into_iter();
+                let key = entry.next()?;
+                let value = entry.next()?;
+                DictEntry(Box::new(key), Box::new(value))
+            }
+            _ => return None,
+        };
+
+        Some(value)
+    }
+}
+
+fn read_all(dbus: &DBus, iter: &mut DBusMessageIter) -> Vec<DBusValue> {
+    let mut values = Vec::new();
+    while (dbus.dbus_message_iter_get_arg_type)(iter) != DBUS_TYPE_INVALID {
+        if let Some(v) = DBusValue::read(dbus, iter) {
+            values.push(v);
+        }
+        if (dbus.dbus_message_iter_next)(iter) == 0 {
+            break;
+        }
+    }
+    values
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum DBusMessageType {
+    MethodCall,
+    MethodReturn,
+    Error,
+    Signal,
+}
+
+/// Owned D-Bus message (incoming method call, reply or signal)
+pub(crate) struct DBusMessage {
+    dbus: &'static DBus,
+    raw: *mut DBusMessageRaw,
+}
+
+impl DBusMessage {
+    fn new_method_call(
+        dbus: &'static DBus,
+        destination: &str,
+        path: &str,
+        interface: &str,
+        method: &str,
+    ) -> Option<Self> {
+        let destination = to_cstring(destination);
+        let path = to_cstring(path);
+        let interface = to_cstring(interface);
+        let method = to_cstring(method);
+        let raw = (dbus.dbus_message_new_method_call)(
+            destination.as_ptr(),
+            path.as_ptr(),
+            interface.as_ptr(),
+            method.as_ptr(),
+        );
+        Self::from_raw(dbus, raw)
+    }
+
+    fn from_raw(dbus: &'static DBus, raw: *mut DBusMessageRaw) -> Option<Self> {
+        if raw.is_null() {
+            None
+        } else {
+            Some(Self { dbus, raw })
+        }
+    }
+
+    fn append_args(&self, args: &[DBusValue]) -> bool {
+        let mut iter = DBusMessageIter::new();
+        (self.dbus.dbus_message_iter_init_append)(self.raw, &mut iter);
+        args.iter().all(|a| a.append(self.dbus, &mut iter))
+    }
+
+    pub(crate) fn message_type(&self) -> Option<DBusMessageType> {
+        match (self.dbus.dbus_message_get_type)(self.raw) {
+            DBUS_MESSAGE_TYPE_METHOD_CALL => Some(DBusMessageType::MethodCall),
+            DBUS_MESSAGE_TYPE_METHOD_RETURN => Some(DBusMessageType::MethodReturn),
+            DBUS_MESSAGE_TYPE_ERROR => Some(DBusMessageType::Error),
+            DBUS_MESSAGE_TYPE_SIGNAL => Some(DBusMessageType::Signal),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn path(&self) -> Option<String> {
+        unsafe { cstr_to_string((self.dbus.dbus_message_get_path)(self.raw)) }
+    }
+
+    pub(crate) fn interface(&self) -> Option<String> {
+        unsafe { cstr_to_string((self.dbus.dbus_message_get_interface)(self.raw)) }
+    }
+
+    pub(crate) fn member(&self) -> Option<String> {
+        unsafe { cstr_to_string((self.dbus.dbus_message_get_member)(self.raw)) }
+    }
+
+    pub(crate) fn sender(&self) -> Option<String> {
+        unsafe { cstr_to_string((self.dbus.dbus_message_get_sender)(self.raw)) }
+    }
+
+    pub(crate) fn error_name(&self) -> Option<String> {
+        unsafe { cstr_to_string((self.dbus.dbus_message_get_error_name)(self.raw)) }
+    }
+
+    pub(crate) fn serial(&self) -> u32 {
+        (self.dbus.dbus_message_get_serial)(self.raw)
+    }
+
+    pub(crate) fn reply_serial(&self) -> u32 {
+        (self.dbus.dbus_message_get_reply_serial)(self.raw)
+    }
+
+    pub(crate) fn args(&self) -> Vec<DBusValue> {
+        let mut iter = DBusMessageIter::new();
+        if (self.dbus.dbus_message_iter_init)(self.raw, &mut iter) == 0 {
+            return Vec::new();
+        }
+        read_all(self.dbus, &mut iter)
+    }
+
+    /// Returns true if the message is a call of `interface.method`
+    pub(crate) fn is_method_call(&self, interface: &str, method: &str) -> bool {
+        self.message_type() == Some(DBusMessageType::MethodCall)
+            && self.interface().as_deref() == Some(interface)
+            && self.member().as_deref() == Some(method)
+    }
+
+    /// Returns true if the message is the signal `interface.name`
+    pub(crate) fn is_signal(&self, interface: &str, name: &str) -> bool {
+        self.message_type() == Some(DBusMessageType::Signal)
+            && self.interface().as_deref() == Some(interface)
+            && self.member().as_deref() == Some(name)
+    }
+}
+
+impl Drop for DBusMessage {
+    fn drop(&mut self) {
+        (self.dbus.dbus_message_unref)(self.raw);
+    }
+}
+
+/// Private connection to the session bus (or to the bus at a custom address)
+pub(crate) struct DBusConnection {
+    dbus: &'static DBus,
+    raw: *mut DBusConnectionRaw,
+}
+
+// libdbus is initialized with dbus_threads_init_default(), the connection
+// can be moved to another thread (but is never used from two threads at once)
+unsafe impl Send for DBusConnection {}
+
+impl DBusConnection {
+    /// Connects to the session bus, fails if libdbus-1 is not
+    /// installed or no session bus is running
+    pub(crate) fn session() -> Result<Self, String> {
+        let dbus = DBUS
+            .as_ref()
+            .ok_or_else(|| format!("D-Bus: could not load libdbus-1.so.3"))?;
+        let mut error = DBusError::new(dbus);
+        let raw = (dbus.dbus_bus_get_private)(DBUS_BUS_SESSION, &mut error);
+        if let Some(e) = error.take(dbus) {
+            return Err(format!(
+                "D-Bus: could not connect to the session bus: {}",
+                e
+            ));
+        }
+        if raw.is_null() {
+            return Err(format!("D-Bus: could not connect to the session bus"));
+        }
+        // libdbus calls exit() on disconnect by default
+        (dbus.dbus_connection_set_exit_on_disconnect)(raw, 0);
+        Ok(Self { dbus, raw })
+    }
+
+    /// Connects to the bus at the given address (ex. a private
+    /// `dbus-daemon` that runs a mock notification server)
+    pub(crate) fn open(address: &str) -> Result<Self, String> {
+        let dbus = DBUS
+            .as_ref()
+            .ok_or_else(|| format!("D-Bus: could not load libdbus-1.so.3"))?;
+        let c_address = to_cstring(address);
+        let mut error = DBusError::new(dbus);
+        let raw = (dbus.dbus_connection_open_private)(c_address.as_ptr(), &mut error);
+        if let Some(e) = error.take(dbus) {
+            return Err(format!("D-Bus: could not connect to {}: {}", address, e));
+        }
+        if raw.is_null() {
+            return Err(format!("D-Bus: could not connect to {}", address));
+        }
+        (dbus.dbus_connection_set_exit_on_disconnect)(raw, 0);
+        // closes the connection on failure
+        let connection = Self { dbus, raw };
+        (dbus.dbus_bus_register)(raw, &mut error);
+        if let Some(e) = error.take(dbus) {
+            return Err(format!("D-Bus: could not register at {}: {}", address, e));
+        }
+        Ok(connection)
+    }
+
+    /// Unique name of the connection on the bus (":1.42")
+    pub(crate) fn unique_name(&self) -> Option<String> {
+        unsafe { cstr_to_string((self.dbus.dbus_bus_get_unique_name)(self.raw)) }
+    }
+
+    /// File descriptor of the connection, can be polled for incoming messages
+    pub(crate) fn unix_fd(&self) -> Option<c_int> {
+        let mut fd = -1;
+        if (self.dbus.dbus_connection_get_unix_fd)(self.raw, &mut fd) != 0 && fd >= 0 {
+            Some(fd)
+        } else {
+            None
+        }
+    }
+
+    /// Requests a well-known name, returns true if the connection is now the owner
+    pub(crate) fn request_name(&self, name: &str) -> bool {
+        let name = to_cstring(name);
+        let mut error = DBusError::new(self.dbus);
+        let result = (self.dbus.dbus_bus_request_name)(
+            self.raw,
+            name.as_ptr(),
+            DBUS_NAME_FLAG_DO_NOT_QUEUE,
+            &mut error,
+        );
+        error.take(self.dbus).is_none() && result == DBUS_REQUEST_NAME_REPLY_PRIMARY_OWNER
+    }
+
+    /// Subscribes to signals matching the rule
+    /// (ex. `"type='signal',interface='org.freedesktop.Notifications'"`)
+    pub(crate) fn add_match(&self, rule: &str) -> Result<(), String> {
+        let rule = to_cstring(rule);
+        let mut error = DBusError::new(self.dbus);
+        (self.dbus.dbus_bus_add_match)(self.raw, rule.as_ptr(), &mut error);
+        match error.take(self.dbus) {
+            Some(e) => Err(format!("D-Bus: AddMatch failed: {}", e)),
+            None => Ok(()),
+        }
+    }
+
+    /// Calls a method and blocks until the reply arrives (or the timeout expires)
+    pub(crate) fn call(
+        &self,
+        destination: &str,
+        path: &str,
+        interface: &str,
+        method: &str,
+        args: &[DBusValue],
+        timeout_ms: c_int,
+    ) -> Result<Vec<DBusValue>, String> {
+        let message = DBusMessage::new_method_call(self.dbus, destination, path, interface, method)
+            .ok_or_else(|| format!("D-Bus: out of memory"))?;
+        if !message.append_args(args) {
+            return Err(format!(
+                "D-Bus: invalid arguments for {}.{}",
+                interface, method
+            ));
+        }
+
+        let mut error = DBusError::new(self.dbus);
+        let reply = (self.dbus.dbus_connection_send_with_reply_and_block)(
+            self.raw,
+            message.raw,
+            timeout_ms,
+            &mut error,
+        );
+        if let Some(e) = error.take(self.dbus) {
+            return Err(format!("D-Bus: {}.{} failed: {}", interface, method, e));
+        }
+
+        DBusMessage::from_raw(self.dbus, reply)
+            .map(|r| r.args())
+            .ok_or_else(|| format!("D-Bus: {}.{} failed: no reply", interface, method))
+    }
+
+    /// Calls a method without waiting for the reply, returns the serial of
+    /// the call (which is the `reply_serial()` of the reply)
+    pub(crate) fn call_async(
+        &self,
+        destination: &str,
+        path: &str,
+        interface: &str,
+        method: &str,
+        args: &[DBusValue],
+    ) -> Option<u32> {
+        let message =
+            DBusMessage::new_method_call(self.dbus, destination, path, interface, method)?;
+        if !message.append_args(args) {
+            return None;
+        }
+        self.send(message)
+    }
+
+    /// Emits a signal
+    pub(crate) fn emit_signal(
+        &self,
+        path: &str,
+        interface: &str,
+        name: &str,
+        args: &[DBusValue],
+    ) -> bool {
+        let path = to_cstring(path);
+        let interface = to_cstring(interface);
+        let name = to_cstring(name);
+        let raw =
+            (self.dbus.dbus_message_new_signal)(path.as_ptr(), interface.as_ptr(), name.as_ptr());
+        match DBusMessage::from_raw(self.dbus, raw) {
+            Some(m) if m.append_args(args) => self.send(m).is_some(),
+            _ => false,
+        }
+    }
+
+    /// Sends the return value of a method call
+    pub(crate) fn reply(&self, call: &DBusMessage, args: &[DBusValue]) -> bool {
+        let raw = (self.dbus.dbus_message_new_method_return)(call.raw);
+        match DBusMessage::from_raw(self.dbus, raw) {
+            Some(m) if m.append_args(args) => self.send(m).is_some(),
+            _ => false,
+        }
+    }
+
+    /// Sends an error as the reply to a method call
+    pub(crate) fn reply_error(&self, call: &DBusMessage, name: &str, message: &str) -> bool {
+        let name = to_cstring(name);
+        let message = to_cstring(message);
+        let raw = (self.dbus.dbus_message_new_error)(call.raw, name.as_ptr(), message.as_ptr());
+        match DBusMessage::from_raw(self.dbus, raw) {
+            Some(m) => self.send(m).is_some(),
+            None => false,
+        }
+    }
+
+    fn send(&self, message: DBusMessage) -> Option<u32> {
+        let mut serial = 0;
+        if (self.dbus.dbus_connection_send)(self.raw, message.raw, &mut serial) == 0 {
+            None
+        } else {
+            Some(serial)
+        }
+    }
+
+    /// Writes the outgoing messages and reads the incoming ones, waiting
+    /// at most `timeout_ms` for data, returns false if the connection is closed
+    pub(crate) fn read_write(&self, timeout_ms: c_int) -> bool {
+        (self.dbus.dbus_connection_read_write)(self.raw, timeout_ms) != 0
+    }
+
+    /// Returns the next message that has been read
+    pub(crate) fn pop_message(&self) -> Option<DBusMessage> {
+        DBusMessage::from_raw(self.dbus, (self.dbus.dbus_connection_pop_message)(self.raw))
+    }
+
+    /// Blocks until all outgoing messages have been written
+    pub(crate) fn flush(&self) {
+        (self.dbus.dbus_connection_flush)(self.raw);
+    }
+}
+
+impl Drop for DBusConnection {
+    fn drop(&mut self) {
+        // private connections have to be closed before the last unref
+        (self.dbus.dbus_connection_close)(self.raw);
+        (self.dbus.dbus_connection_unref)(self.raw);
+    }
+}
+
+#[test]
+fn test_dbus_value_signature() {
+    let tooltip = DBusValue::Struct(vec![
+        DBusValue::string(""),
+        DBusValue::Array("(iiay)".to_string(), Vec::new()),
+        DBusValue::string("title"),
+        DBusValue::string("description"),
+    ]);
+    assert_eq!(tooltip.signature(), "(sa(iiay)ss)");
+
+    let hints = DBusValue::dict(vec![("urgency", DBusValue::Byte(2))]);
+    assert_eq!(hints.signature(), "a{sv}");
+    assert_eq!(hints.get("urgency").and_then(|v| v.as_i64()), Some(2));
+    assert_eq!(hints.get("category"), None);
+}
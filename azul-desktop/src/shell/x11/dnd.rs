@@ -0,0 +1,810 @@
+//! X11 drag-and-drop between applications (XDND protocol, version 5)
+//!
+//! Drags between nodes of the same window are handled entirely in azul-core
+//! (see `FullWindowState::update_drag_state`), this module only translates
+//! drags from / to other applications:
+//!
+//! - target: the source sends `XdndEnter` / `XdndPosition` / `XdndLeave` /
+//!   `XdndDrop` client messages to the window, the data is read by converting
+//!   the `XdndSelection` (`text/uri-list` if offered, otherwise UTF-8 text)
+//! - source: once an internal drag leaves the window, the window (which keeps
+//!   receiving the pointer events because of the implicit pointer grab) sends
+//!   the messages to the XDND-aware window under the pointer and serves the
+//!   `XdndSelection` from the `DragData::items`
+//!
+//! Dragged data is transferred in one piece, the `INCR` protocol is not supported.
+
+use super::{
+    Atom, ClientMessageData, Display, Library, Time, Window, XClientMessageEvent, XEvent,
+    XSelectionEvent, XSelectionRequestEvent, X11_FALSE, X11_TRUE,
+};
+use crate::gl::{c_char, c_int, c_long, c_uchar, c_ulong};
+use azul_core::window::{DragData, DRAG_TEXT_MIME_TYPE, DRAG_URI_LIST_MIME_TYPE};
+use core::{ffi::c_void, mem, ptr};
+use once_cell::sync::Lazy;
+use std::ffi::{CStr, CString};
+
+/// Version of the XDND protocol that is implemented
+const XDND_VERSION: c_long = 5;
+
+const ANY_PROPERTY_TYPE: Atom = 0;
+const XA_ATOM: Atom = 4;
+const PROP_MODE_REPLACE: c_int = 0;
+const X11_SUCCESS: c_int = 0;
+
+const X11_CLIENT_MESSAGE: c_int = 33;
+const X11_SELECTION_NOTIFY: c_int = 31;
+
+// how deep the window tree is searched for an XDND-aware window below the pointer
+const MAX_WINDOW_DEPTH: usize = 16;
+
+type XDefaultRootWindowFuncType = extern "C" fn(*mut Display) -> Window;
+type XInternAtomFuncType = extern "C" fn(*mut Display, *const c_char, c_int) -> Atom;
+type XGetAtomNameFuncType = extern "C" fn(*mut Display, Atom) -> *mut c_char;
+type XFreeFuncType = extern "C" fn(*mut c_void) -> c_int;
+type XSetSelectionOwnerFuncType = extern "C" fn(*mut Display, Atom, Window, c_ulong) -> c_int;
+type XConvertSelectionFuncType =
+    extern "C" fn(*mut Display, Atom, Atom, Atom, Window, c_ulong) -> c_int;
+type XGetWindowPropertyFuncType = extern "C" fn(
+    *mut Display,
+    Window,
+    Atom,
+    c_long,
+    c_long,
+    c_int,
+    Atom,
+    *mut Atom,
+    *mut c_int,
+    *mut c_ulong,
+    *mut c_ulong,
+    *mut *mut c_uchar,
+) -> c_int;
+type XChangePropertyFuncType =
+    extern "C" fn(*mut Display, Window, Atom, Atom, c_int, c_int, *const c_uchar, c_int) -> c_int;
+type XSendEventFuncType = extern "C" fn(*mut Display, Window, c_int, c_long, *mut XEvent) -> c_int;
+type XFlushFuncType = extern "C" fn(*mut Display) -> c_int;
+type XTranslateCoordinatesFuncType = extern "C" fn(
+    *mut Display,
+    Window,
+    Window,
+    c_int,
+    c_int,
+    *mut c_int,
+    *mut c_int,
+    *mut Window,
+) -> c_int;
+
+// Xlib functions used for drag-and-drop (on the connection of the window)
+struct XdndXlib {
+    pub library: Library,
+    pub XDefaultRootWindow: XDefaultRootWindowFuncType,
+    pub XInternAtom: XInternAtomFuncType,
+    pub XGetAtomName: XGetAtomNameFuncType,
+    pub XFree: XFreeFuncType,
+    pub XSetSelectionOwner: XSetSelectionOwnerFuncType,
+    pub XConvertSelection: XConvertSelectionFuncType,
+    pub XGetWindowProperty: XGetWindowPropertyFuncType,
+    pub XChangeProperty: XChangePropertyFuncType,
+    pub XSendEvent: XSendEventFuncType,
+    pub XFlush: XFlushFuncType,
+    pub XTranslateCoordinates: XTranslateCoordinatesFuncType,
+}
+
+impl XdndXlib {
+    fn new() -> Option<Self> {
+        use super::clipboard::load_function;
+
+        let library = Library::load("libX11.so").ok()?;
+        Some(Self {
+            XDefaultRootWindow: load_function(&library, "XDefaultRootWindow")?,
+            XInternAtom: load_function(&library, "XInternAtom")?,
+            XGetAtomName: load_function(&library, "XGetAtomName")?,
+            XFree: load_function(&library, "XFree")?,
+            XSetSelectionOwner: load_function(&library, "XSetSelectionOwner")?,
+            XConvertSelection: load_function(&library, "XConvertSelection")?,
+            XGetWindowProperty: load_function(&library, "XGetWindowProperty")?,
+            XChangeProperty: load_function(&library, "XChangeProperty")?,
+            XSendEvent: load_function(&library, "XSendEvent")?,
+            XFlush: load_function(&library, "XFlush")?,
+            XTranslateCoordinates: load_function(&library, "XTranslateCoordinates")?,
+            library,
+        })
+    }
+}
+
+static XDND_XLIB: Lazy<Option<XdndXlib>> = Lazy::new(XdndXlib::new);
+
+struct XdndAtoms {
+    aware: Atom,
+    enter: Atom,
+    position: Atom,
+    status: Atom,
+    leave: Atom,
+    drop: Atom,
+    finished: Atom,
+    selection: Atom,
+    type_list: Atom,
+    action_copy: Atom,
+    targets: Atom,
+    utf8_string: Atom,
+    text: Atom,
+    uri_list: Atom,
+    text_plain_utf8: Atom,
+    text_plain: Atom,
+    // property on the window that the converted selection is stored in
+    property: Atom,
+}
+
+/// Change of the drag state of the window, caused by another application
+pub(super) enum XdndEvent {
+    /// A drag entered the window, the data only lists the offered formats
+    Enter(DragData),
+    /// The drag moved to the given (physical) window coordinates
+    Motion(c_int, c_int),
+    /// The data of the drag was received while hovering over the window
+    DataReceived(DragData),
+    /// The drag left the window or was cancelled
+    Leave,
+    /// The drag was dropped on the window
+    Drop(DragData),
+}
+
+// Drag from another application that is currently over the window
+struct IncomingDrag {
+    source: Window,
+    version: c_long,
+    types: Vec<Atom>,
+    // format that the data is requested in, None if no supported format is offered
+    requested_type: Option<Atom>,
+    // set once the conversion has been requested
+    conversion_requested: bool,
+    data: Option<DragData>,
+    // set if the drop happened before the data was received
+    drop_pending: bool,
+}
+
+// Internal drag that currently hovers over a window of another application
+struct OutgoingDrag {
+    target: Window,
+    accepted: bool,
+}
+
+/// XDND state of one window
+pub(super) struct Xdnd {
+    xlib: &'static XdndXlib,
+    display: *mut Display,
+    window: Window,
+    root: Window,
+    atoms: XdndAtoms,
+    incoming: Option<IncomingDrag>,
+    outgoing: Option<OutgoingDrag>,
+    // (mime type atom, data) of the last internal drag that left the window,
+    // served to the drop target via the XdndSelection
+    outgoing_items: Vec<(Atom, Vec<u8>)>,
+}
+
+impl Xdnd {
+    /// Marks the window as XDND-aware, returns `None` if libX11 can't be loaded
+    pub(super) fn new(display: *mut Display, window: Window) -> Option<Self> {
+        let xlib = XDND_XLIB.as_ref()?;
+
+        let intern = |name: &str| {
+            let name = CString::new(name).unwrap_or_default();
+            (xlib.XInternAtom)(display, name.as_ptr(), X11_FALSE)
+        };
+
+        let atoms = XdndAtoms {
+            aware: intern("XdndAware"),
+            enter: intern("XdndEnter"),
+            position: intern("XdndPosition"),
+            status: intern("XdndStatus"),
+            leave: intern("XdndLeave"),
+            drop: intern("XdndDrop"),
+            finished: intern("XdndFinished"),
+            selection: intern("XdndSelection"),
+            type_list: intern("XdndTypeList"),
+            action_copy: intern("XdndActionCopy"),
+            targets: intern("TARGETS"),
+            utf8_string: intern("UTF8_STRING"),
+            text: intern("TEXT"),
+            uri_list: intern(DRAG_URI_LIST_MIME_TYPE),
+            text_plain_utf8: intern(DRAG_TEXT_MIME_TYPE),
+            text_plain: intern("text/plain"),
+            property: intern("AZ_XDND_DATA"),
+        };
+
+        let version = [XDND_VERSION];
+        (xlib.XChangeProperty)(
+            display,
+            window,
+            atoms.aware,
+            XA_ATOM,
+            32,
+            PROP_MODE_REPLACE,
+            version.as_ptr() as *const c_uchar,
+            1,
+        );
+
+        Some(Self {
+            xlib,
+            display,
+            window,
+            root: (xlib.XDefaultRootWindow)(display),
+            atoms,
+            incoming: None,
+            outgoing: None,
+            outgoing_items: Vec::new(),
+        })
+    }
+
+    fn get_atom_name(&self, atom: Atom) -> Option<String> {
+        let name = (self.xlib.XGetAtomName)(self.display, atom);
+        if name.is_null() {
+            return None;
+        }
+        let result = unsafe { CStr::from_ptr(name) }
+            .to_str()
+            .ok()
+            .map(|s| s.to_string());
+        (self.xlib.XFree)(name as *mut c_void);
+        result
+    }
+
+    // Reads (and optionally deletes) a property, returns the type and the raw
+    // items (format 32 properties are returned as c_long in native byte order)
+    fn read_property(
+        &self,
+        window: Window,
+        property: Atom,
+        delete: bool,
+    ) -> Option<(Atom, c_int, Vec<u8>)> {
+        let mut actual_type = 0;
+        let mut actual_format = 0;
+        let mut item_count = 0;
+        let mut bytes_after = 0;
+        let mut data = ptr::null_mut();
+
+        let status = (self.xlib.XGetWindowProperty)(
+            self.display,
+            window,
+            property,
+            0,
+            0x1fff_ffff, // in 4-byte units, read everything
+            if delete { X11_TRUE } else { X11_FALSE },
+            ANY_PROPERTY_TYPE,
+            &mut actual_type,
+            &mut actual_format,
+            &mut item_count,
+            &mut bytes_after,
+            &mut data,
+        );
+
+        let item_size = match actual_format {
+            8 => 1,
+            16 => mem::size_of::<i16>(),
+            32 => mem::size_of::<c_long>(),
+            _ => 0,
+        };
+
+        let bytes = if data.is_null() {
+            Vec::new()
+        } else {
+            let bytes =
+                unsafe { core::slice::from_raw_parts(data, item_count as usize * item_size) }
+                    .to_vec();
+            (self.xlib.XFree)(data as *mut c_void);
+            bytes
+        };
+
+        if status != X11_SUCCESS || actual_type == 0 {
+            None
+        } else {
+            Some((actual_type, actual_format, bytes))
+        }
+    }
+
+    fn read_atom_list(&self, window: Window, property: Atom) -> Vec<Atom> {
+        match self.read_property(window, property, false) {
+            Some((XA_ATOM, 32, bytes)) => bytes
+                .chunks_exact(mem::size_of::<c_long>())
+                .map(|c| {
+                    let mut long = [0_u8; mem::size_of::<c_long>()];
+                    long.copy_from_slice(c);
+                    c_long::from_ne_bytes(long) as Atom
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn send_client_message(&self, target: Window, message_type: Atom, longs: [c_long; 5]) {
+        let mut event = XEvent { pad: [0; 24] };
+        event.client_message = XClientMessageEvent {
+            type_: X11_CLIENT_MESSAGE,
+            serial: 0,
+            send_event: X11_TRUE,
+            display: self.display,
+            window: target,
+            message_type,
+            format: 32,
+            data: ClientMessageData { longs },
+        };
+        (self.xlib.XSendEvent)(self.display, target, X11_FALSE, 0, &mut event);
+        (self.xlib.XFlush)(self.display);
+    }
+
+    // Converts the offered formats into a DragData, the data of the
+    // requested format is filled in once it has been received
+    fn get_drag_data(&self, types: &[Atom], received: Option<(Atom, Vec<u8>)>) -> DragData {
+        let mut drag_data = DragData::new();
+        for t in types {
+            let mime_type = if *t == self.atoms.utf8_string {
+                DRAG_TEXT_MIME_TYPE.to_string()
+            } else {
+                match self.get_atom_name(*t) {
+                    Some(s) => s,
+                    None => continue,
+                }
+            };
+            let data = match &received {
+                Some((received_type, data)) if received_type == t => data.clone(),
+                _ => Vec::new(),
+            };
+            drag_data.set_item(mime_type.into(), data.into());
+        }
+        drag_data
+    }
+
+    /// Handles a client message sent to the window, returns `None`
+    /// if the message is not an XDND message or needs no processing
+    pub(super) fn handle_client_message(
+        &mut self,
+        message: &XClientMessageEvent,
+    ) -> Option<XdndEvent> {
+        let longs = message.data.as_longs();
+        let source = longs[0] as Window;
+
+        if message.message_type == self.atoms.enter {
+            let version = (longs[1] >> 24) & 0xff;
+            let types = if longs[1] & 1 != 0 {
+                self.read_atom_list(source, self.atoms.type_list)
+            } else {
+                longs[2..5]
+                    .iter()
+                    .filter(|a| **a != 0)
+                    .map(|a| *a as Atom)
+                    .collect()
+            };
+
+            let requested_type = [
+                self.atoms.uri_list,
+                self.atoms.text_plain_utf8,
+                self.atoms.utf8_string,
+                self.atoms.text_plain,
+            ]
+            .iter()
+            .copied()
+            .find(|t| types.contains(t));
+
+            let drag_data = self.get_drag_data(&types, None);
+            self.incoming = Some(IncomingDrag {
+                source,
+                version,
+                types,
+                requested_type,
+                conversion_requested: false,
+                data: None,
+                drop_pending: false,
+            });
+            Some(XdndEvent::Enter(drag_data))
+        } else if message.message_type == self.atoms.position {
+            let root_x = ((longs[2] >> 16) & 0xffff) as c_int;
+            let root_y = (longs[2] & 0xffff) as c_int;
+            let time = longs[3] as Time;
+
+            let incoming = self.incoming.as_mut()?;
+            let accepted = incoming.requested_type.is_some();
+
+            // request the data on the first position message, so that
+            // the callbacks can look at the data before the drop
+            if let Some(requested_type) = incoming.requested_type {
+                if !incoming.conversion_requested {
+                    incoming.conversion_requested = true;
+                    (self.xlib.XConvertSelection)(
+                        self.display,
+                        self.atoms.selection,
+                        requested_type,
+                        self.atoms.property,
+                        self.window,
+                        time,
+                    );
+                }
+            }
+
+            self.send_client_message(
+                source,
+                self.atoms.status,
+                [
+                    self.window as c_long,
+                    // bit 0: accept the drop, bit 1: send position messages for every move
+                    if accepted { 0b11 } else { 0b10 },
+                    0,
+                    0,
+                    if accepted {
+                        self.atoms.action_copy as c_long
+                    } else {
+                        0
+                    },
+                ],
+            );
+
+            let mut x = 0;
+            let mut y = 0;
+            let mut child = 0;
+            (self.xlib.XTranslateCoordinates)(
+                self.display,
+                self.root,
+                self.window,
+                root_x,
+                root_y,
+                &mut x,
+                &mut y,
+                &mut child,
+            );
+            Some(XdndEvent::Motion(x, y))
+        } else if message.message_type == self.atoms.leave {
+            self.incoming.take()?;
+            Some(XdndEvent::Leave)
+        } else if message.message_type == self.atoms.drop {
+            let incoming = self.incoming.as_mut()?;
+            let requested_type = match incoming.requested_type {
+                Some(s) if incoming.source == source => s,
+                _ => {
+                    self.finish_drop(false);
+                    return Some(XdndEvent::Leave);
+                }
+            };
+
+            if let Some(data) = incoming.data.clone() {
+                self.finish_drop(true);
+                return Some(XdndEvent::Drop(data));
+            }
+
+            // the data is still being converted, the drop
+            // is finished once the SelectionNotify arrives
+            incoming.drop_pending = true;
+            if !incoming.conversion_requested {
+                incoming.conversion_requested = true;
+                (self.xlib.XConvertSelection)(
+                    self.display,
+                    self.atoms.selection,
+                    requested_type,
+                    self.atoms.property,
+                    self.window,
+                    longs[2] as Time,
+                );
+            }
+            None
+        } else if message.message_type == self.atoms.status {
+            let outgoing = self.outgoing.as_mut()?;
+            if outgoing.target == source {
+                outgoing.accepted = longs[1] & 1 != 0;
+            }
+            None
+        } else if message.message_type == self.atoms.finished {
+            // the drop target has read the data
+            if self.outgoing.is_none() {
+                self.outgoing_items.clear();
+            }
+            None
+        } else {
+            None
+        }
+    }
+
+    // Sends XdndFinished to the source of the incoming drag
+    fn finish_drop(&mut self, accepted: bool) {
+        let incoming = match self.incoming.take() {
+            Some(s) => s,
+            None => return,
+        };
+        let longs = if incoming.version >= 5 {
+            [
+                self.window as c_long,
+                if accepted { 1 } else { 0 },
+                if accepted {
+                    self.atoms.action_copy as c_long
+                } else {
+                    0
+                },
+                0,
+                0,
+            ]
+        } else {
+            [self.window as c_long, 0, 0, 0, 0]
+        };
+        self.send_client_message(incoming.source, self.atoms.finished, longs);
+    }
+
+    /// Handles the converted `XdndSelection`, returns `None` if the
+    /// event is not an answer to a conversion requested by this module
+    pub(super) fn handle_selection_notify(
+        &mut self,
+        notify: &XSelectionEvent,
+    ) -> Option<XdndEvent> {
+        if notify.selection != self.atoms.selection || notify.requestor != self.window {
+            return None;
+        }
+
+        let received = if notify.property == 0 {
+            None
+        } else {
+            self.read_property(self.window, notify.property, true)
+                .map(|(_, _, bytes)| (notify.target, bytes))
+        };
+
+        let incoming = self.incoming.as_ref()?;
+        let drag_data = self.get_drag_data(&incoming.types, received.clone());
+        let drop_pending = incoming.drop_pending;
+
+        if drop_pending {
+            self.finish_drop(received.is_some());
+            return Some(if received.is_some() {
+                XdndEvent::Drop(drag_data)
+            } else {
+                XdndEvent::Leave
+            });
+        }
+
+        if let Some(incoming) = self.incoming.as_mut() {
+            incoming.data = Some(drag_data.clone());
+        }
+        Some(XdndEvent::DataReceived(drag_data))
+    }
+
+    /// Serves the `XdndSelection` to the drop target, returns
+    /// false if the request is not for the `XdndSelection`
+    pub(super) fn handle_selection_request(&mut self, request: &XSelectionRequestEvent) -> bool {
+        if request.selection != self.atoms.selection {
+            return false;
+        }
+
+        // obsolete clients don't set a property
+        let property = if request.property == 0 {
+            request.target
+        } else {
+            request.property
+        };
+
+        let accepted = if request.target == self.atoms.targets {
+            let mut targets = vec![self.atoms.targets as c_long];
+            targets.extend(self.outgoing_items.iter().map(|(t, _)| *t as c_long));
+            if self.find_text_item().is_some() {
+                targets.push(self.atoms.utf8_string as c_long);
+                targets.push(self.atoms.text as c_long);
+            }
+            (self.xlib.XChangeProperty)(
+                self.display,
+                request.requestor,
+                property,
+                XA_ATOM,
+                32,
+                PROP_MODE_REPLACE,
+                targets.as_ptr() as *const c_uchar,
+                targets.len() as c_int,
+            );
+            true
+        } else {
+            let item = self
+                .outgoing_items
+                .iter()
+                .position(|(t, _)| *t == request.target)
+                .or_else(|| {
+                    if request.target == self.atoms.utf8_string || request.target == self.atoms.text
+                    {
+                        self.find_text_item()
+                    } else {
+                        None
+                    }
+                });
+
+            match item {
+                Some(item) => {
+                    let data = &self.outgoing_items[item].1;
+                    (self.xlib.XChangeProperty)(
+                        self.display,
+                        request.requestor,
+                        property,
+                        request.target,
+                        8,
+                        PROP_MODE_REPLACE,
+                        data.as_ptr(),
+                        data.len() as c_int,
+                    );
+                    true
+                }
+                None => false,
+            }
+        };
+
+        let mut event = XEvent { pad: [0; 24] };
+        event.selection = XSelectionEvent {
+            type_: X11_SELECTION_NOTIFY,
+            serial: 0,
+            send_event: X11_TRUE,
+            display: self.display,
+            requestor: request.requestor,
+            selection: request.selection,
+            target: request.target,
+            property: if accepted { property } else { 0 },
+            time: request.time,
+        };
+        (self.xlib.XSendEvent)(self.display, request.requestor, X11_FALSE, 0, &mut event);
+        (self.xlib.XFlush)(self.display);
+
+        true
+    }
+
+    fn find_text_item(&self) -> Option<usize> {
+        self.outgoing_items
+            .iter()
+            .position(|(t, _)| *t == self.atoms.text_plain_utf8 || *t == self.atoms.text_plain)
+    }
+
+    // Returns the XDND-aware window below the root coordinates and its protocol version
+    fn find_target(&self, root_x: c_int, root_y: c_int) -> Option<(Window, c_long)> {
+        let mut current = self.root;
+        for _ in 0..MAX_WINDOW_DEPTH {
+            let mut x = 0;
+            let mut y = 0;
+            let mut child = 0;
+            (self.xlib.XTranslateCoordinates)(
+                self.display,
+                self.root,
+                current,
+                root_x,
+                root_y,
+                &mut x,
+                &mut y,
+                &mut child,
+            );
+            if child == 0 {
+                return None;
+            }
+            if let Some(version) = self.read_atom_list(child, self.atoms.aware).first() {
+                return Some((child, (*version as c_long).min(XDND_VERSION)));
+            }
+            current = child;
+        }
+        None
+    }
+
+    /// Called for every pointer motion while an internal drag is outside of the
+    /// window: sends the drag to the XDND-aware window below the pointer
+    pub(super) fn source_motion(
+        &mut self,
+        drag_data: &DragData,
+        root_x: c_int,
+        root_y: c_int,
+        time: Time,
+    ) {
+        let target = self
+            .find_target(root_x, root_y)
+            .filter(|(w, _)| *w != self.window);
+
+        let target_changed = self.outgoing.as_ref().map(|o| o.target) != target.map(|t| t.0);
+        if target_changed {
+            self.source_leave();
+
+            let (target, version) = match target {
+                Some(s) => s,
+                None => return,
+            };
+
+            self.outgoing_items = drag_data
+                .items
+                .iter()
+                .map(|item| {
+                    let name = CString::new(item.mime_type.as_str()).unwrap_or_default();
+                    let atom = (self.xlib.XInternAtom)(self.display, name.as_ptr(), X11_FALSE);
+                    (atom, item.data.as_ref().to_vec())
+                })
+                .collect();
+
+            let mut types = self
+                .outgoing_items
+                .iter()
+                .map(|(t, _)| *t as c_long)
+                .collect::<Vec<_>>();
+            if self.find_text_item().is_some() {
+                types.push(self.atoms.utf8_string as c_long);
+            }
+            if types.len() > 3 {
+                (self.xlib.XChangeProperty)(
+                    self.display,
+                    self.window,
+                    self.atoms.type_list,
+                    XA_ATOM,
+                    32,
+                    PROP_MODE_REPLACE,
+                    types.as_ptr() as *const c_uchar,
+                    types.len() as c_int,
+                );
+            }
+
+            (self.xlib.XSetSelectionOwner)(self.display, self.atoms.selection, self.window, time);
+
+            let mut longs = [
+                self.window as c_long,
+                (version << 24) | if types.len() > 3 { 1 } else { 0 },
+                0,
+                0,
+                0,
+            ];
+            for (i, t) in types.iter().take(3).enumerate() {
+                longs[2 + i] = *t;
+            }
+            self.send_client_message(target, self.atoms.enter, longs);
+
+            self.outgoing = Some(OutgoingDrag {
+                target,
+                accepted: false,
+            });
+        }
+
+        let target = match self.outgoing.as_ref() {
+            Some(s) => s.target,
+            None => return,
+        };
+
+        self.send_client_message(
+            target,
+            self.atoms.position,
+            [
+                self.window as c_long,
+                0,
+                ((root_x as c_long) << 16) | (root_y as c_long & 0xffff),
+                time as c_long,
+                self.atoms.action_copy as c_long,
+            ],
+        );
+    }
+
+    /// Called when an internal drag returns into the window or is cancelled
+    pub(super) fn source_leave(&mut self) {
+        if let Some(outgoing) = self.outgoing.take() {
+            self.send_client_message(
+                outgoing.target,
+                self.atoms.leave,
+                [self.window as c_long, 0, 0, 0, 0],
+            );
+        }
+    }
+
+    /// Called when the mouse button is released outside of the window:
+    /// drops the data on the current target, if it accepted the drag
+    pub(super) fn source_release(&mut self, time: Time) {
+        let outgoing = match self.outgoing.as_ref() {
+            Some(s) => s,
+            None => return,
+        };
+
+        if !outgoing.accepted {
+            self.source_leave();
+            return;
+        }
+
+        let target = outgoing.target;
+        self.outgoing = None;
+        self.send_client_message(
+            target,
+            self.atoms.drop,
+            [self.window as c_long, 0, time as c_long, 0, 0],
+        );
+    }
+
+    /// Returns whether a drag is currently being sent to another application
+    pub(super) fn is_dragging_out(&self) -> bool {
+        self.outgoing.is_some()
+    }
+}
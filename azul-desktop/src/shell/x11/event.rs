@@ -0,0 +1,245 @@
+// X11 event handling
+//
+// Translates X11 keysyms (see `/usr/include/X11/keysymdef.h` and
+// `/usr/include/X11/XF86keysym.h`) into the platform-independent
+// `VirtualKeyCode` used by the window state
+
+use azul_core::window::VirtualKeyCode;
+
+pub type KeySym = u64;
+
+/// Maps the unshifted keysym of a key (`XLookupKeysym(event, 0)`) to a `VirtualKeyCode`
+///
+/// Keypad navigation keysyms (numlock off) are mapped to their numpad keys so
+/// that the virtual keycode doesn't depend on the numlock state, same as on Win32.
+pub fn keysym_to_virtual_keycode(keysym: KeySym) -> Option<VirtualKeyCode> {
+    match keysym {
+        // latin 1
+        0x0020 => Some(VirtualKeyCode::Space),
+        0x0027 => Some(VirtualKeyCode::Apostrophe),
+        0x002a => Some(VirtualKeyCode::Asterisk),
+        0x002b => Some(VirtualKeyCode::Plus),
+        0x002c => Some(VirtualKeyCode::Comma),
+        0x002d => Some(VirtualKeyCode::Minus),
+        0x002e => Some(VirtualKeyCode::Period),
+        0x002f => Some(VirtualKeyCode::Slash),
+        0x0030 => Some(VirtualKeyCode::Key0),
+        0x0031 => Some(VirtualKeyCode::Key1),
+        0x0032 => Some(VirtualKeyCode::Key2),
+        0x0033 => Some(VirtualKeyCode::Key3),
+        0x0034 => Some(VirtualKeyCode::Key4),
+        0x0035 => Some(VirtualKeyCode::Key5),
+        0x0036 => Some(VirtualKeyCode::Key6),
+        0x0037 => Some(VirtualKeyCode::Key7),
+        0x0038 => Some(VirtualKeyCode::Key8),
+        0x0039 => Some(VirtualKeyCode::Key9),
+        0x003a => Some(VirtualKeyCode::Colon),
+        0x003b => Some(VirtualKeyCode::Semicolon),
+        0x003d => Some(VirtualKeyCode::Equals),
+        0x0040 => Some(VirtualKeyCode::At),
+        0x0041 | 0x0061 => Some(VirtualKeyCode::A),
+        0x0042 | 0x0062 => Some(VirtualKeyCode::B),
+        0x0043 | 0x0063 => Some(VirtualKeyCode::C),
+        0x0044 | 0x0064 => Some(VirtualKeyCode::D),
+        0x0045 | 0x0065 => Some(VirtualKeyCode::E),
+        0x0046 | 0x0066 => Some(VirtualKeyCode::F),
+        0x0047 | 0x0067 => Some(VirtualKeyCode::G),
+        0x0048 | 0x0068 => Some(VirtualKeyCode::H),
+        0x0049 | 0x0069 => Some(VirtualKeyCode::I),
+        0x004a | 0x006a => Some(VirtualKeyCode::J),
+        0x004b | 0x006b => Some(VirtualKeyCode::K),
+        0x004c | 0x006c => Some(VirtualKeyCode::L),
+        0x004d | 0x006d => Some(VirtualKeyCode::M),
+        0x004e | 0x006e => Some(VirtualKeyCode::N),
+        0x004f | 0x006f => Some(VirtualKeyCode::O),
+        0x0050 | 0x0070 => Some(VirtualKeyCode::P),
+        0x0051 | 0x0071 => Some(VirtualKeyCode::Q),
+        0x0052 | 0x0072 => Some(VirtualKeyCode::R),
+        0x0053 | 0x0073 => Some(VirtualKeyCode::S),
+        0x0054 | 0x0074 => Some(VirtualKeyCode::T),
+        0x0055 | 0x0075 => Some(VirtualKeyCode::U),
+        0x0056 | 0x0076 => Some(VirtualKeyCode::V),
+        0x0057 | 0x0077 => Some(VirtualKeyCode::W),
+        0x0058 | 0x0078 => Some(VirtualKeyCode::X),
+        0x0059 | 0x0079 => Some(VirtualKeyCode::Y),
+        0x005a | 0x007a => Some(VirtualKeyCode::Z),
+        0x005b => Some(VirtualKeyCode::LBracket),
+        0x005c => Some(VirtualKeyCode::Backslash),
+        0x005d => Some(VirtualKeyCode::RBracket),
+        0x005e => Some(VirtualKeyCode::Caret),
+        0x005f => Some(VirtualKeyCode::Underline),
+        0x0060 => Some(VirtualKeyCode::Grave),
+        0x00a5 => Some(VirtualKeyCode::Yen),
+        // XK_ISO_Level3_Shift (AltGr)
+        0xfe03 => Some(VirtualKeyCode::RAlt),
+        // TTY function keys
+        0xff08 => Some(VirtualKeyCode::Back),
+        0xff09 => Some(VirtualKeyCode::Tab),
+        0xff0d => Some(VirtualKeyCode::Return),
+        0xff13 => Some(VirtualKeyCode::Pause),
+        0xff14 => Some(VirtualKeyCode::Scroll),
+        0xff15 => Some(VirtualKeyCode::Sysrq),
+        0xff1b => Some(VirtualKeyCode::Escape),
+        0xffff => Some(VirtualKeyCode::Delete),
+        // international & multi-key character composition
+        0xff20 => Some(VirtualKeyCode::Compose),
+        0xff21 => Some(VirtualKeyCode::Kanji),
+        0xff22 => Some(VirtualKeyCode::NoConvert),
+        0xff23 => Some(VirtualKeyCode::Convert),
+        0xff26 | 0xff2d => Some(VirtualKeyCode::Kana),
+        // cursor control & motion
+        0xff50 => Some(VirtualKeyCode::Home),
+        0xff51 => Some(VirtualKeyCode::Left),
+        0xff52 => Some(VirtualKeyCode::Up),
+        0xff53 => Some(VirtualKeyCode::Right),
+        0xff54 => Some(VirtualKeyCode::Down),
+        0xff55 => Some(VirtualKeyCode::PageUp),
+        0xff56 => Some(VirtualKeyCode::PageDown),
+        0xff57 => Some(VirtualKeyCode::End),
+        // misc functions
+        0xff61 => Some(VirtualKeyCode::Snapshot),
+        0xff63 => Some(VirtualKeyCode::Insert),
+        0xff67 => Some(VirtualKeyCode::Apps),
+        0xff7f => Some(VirtualKeyCode::Numlock),
+        // keypad
+        0xff8d => Some(VirtualKeyCode::NumpadEnter),
+        0xff95 => Some(VirtualKeyCode::Numpad7),
+        0xff96 => Some(VirtualKeyCode::Numpad4),
+        0xff97 => Some(VirtualKeyCode::Numpad8),
+        0xff98 => Some(VirtualKeyCode::Numpad6),
+        0xff99 => Some(VirtualKeyCode::Numpad2),
+        0xff9a => Some(VirtualKeyCode::Numpad9),
+        0xff9b => Some(VirtualKeyCode::Numpad3),
+        0xff9c => Some(VirtualKeyCode::Numpad1),
+        0xff9d => Some(VirtualKeyCode::Numpad5),
+        0xff9e => Some(VirtualKeyCode::Numpad0),
+        0xff9f => Some(VirtualKeyCode::NumpadDecimal),
+        0xffaa => Some(VirtualKeyCode::NumpadMultiply),
+        0xffab => Some(VirtualKeyCode::NumpadAdd),
+        0xffac => Some(VirtualKeyCode::NumpadComma),
+        0xffad => Some(VirtualKeyCode::NumpadSubtract),
+        0xffae => Some(VirtualKeyCode::NumpadDecimal),
+        0xffaf => Some(VirtualKeyCode::NumpadDivide),
+        0xffb0 => Some(VirtualKeyCode::Numpad0),
+        0xffb1 => Some(VirtualKeyCode::Numpad1),
+        0xffb2 => Some(VirtualKeyCode::Numpad2),
+        0xffb3 => Some(VirtualKeyCode::Numpad3),
+        0xffb4 => Some(VirtualKeyCode::Numpad4),
+        0xffb5 => Some(VirtualKeyCode::Numpad5),
+        0xffb6 => Some(VirtualKeyCode::Numpad6),
+        0xffb7 => Some(VirtualKeyCode::Numpad7),
+        0xffb8 => Some(VirtualKeyCode::Numpad8),
+        0xffb9 => Some(VirtualKeyCode::Numpad9),
+        0xffbd => Some(VirtualKeyCode::NumpadEquals),
+        // function keys
+        0xffbe => Some(VirtualKeyCode::F1),
+        0xffbf => Some(VirtualKeyCode::F2),
+        0xffc0 => Some(VirtualKeyCode::F3),
+        0xffc1 => Some(VirtualKeyCode::F4),
+        0xffc2 => Some(VirtualKeyCode::F5),
+        0xffc3 => Some(VirtualKeyCode::F6),
+        0xffc4 => Some(VirtualKeyCode::F7),
+        0xffc5 => Some(VirtualKeyCode::F8),
+        0xffc6 => Some(VirtualKeyCode::F9),
+        0xffc7 => Some(VirtualKeyCode::F10),
+        0xffc8 => Some(VirtualKeyCode::F11),
+        0xffc9 => Some(VirtualKeyCode::F12),
+        0xffca => Some(VirtualKeyCode::F13),
+        0xffcb => Some(VirtualKeyCode::F14),
+        0xffcc => Some(VirtualKeyCode::F15),
+        0xffcd => Some(VirtualKeyCode::F16),
+        0xffce => Some(VirtualKeyCode::F17),
+        0xffcf => Some(VirtualKeyCode::F18),
+        0xffd0 => Some(VirtualKeyCode::F19),
+        0xffd1 => Some(VirtualKeyCode::F20),
+        0xffd2 => Some(VirtualKeyCode::F21),
+        0xffd3 => Some(VirtualKeyCode::F22),
+        0xffd4 => Some(VirtualKeyCode::F23),
+        0xffd5 => Some(VirtualKeyCode::F24),
+        // modifiers
+        0xffe1 => Some(VirtualKeyCode::LShift),
+        0xffe2 => Some(VirtualKeyCode::RShift),
+        0xffe3 => Some(VirtualKeyCode::LControl),
+        0xffe4 => Some(VirtualKeyCode::RControl),
+        0xffe5 => Some(VirtualKeyCode::Capital),
+        0xffe9 => Some(VirtualKeyCode::LAlt),
+        0xffea => Some(VirtualKeyCode::RAlt),
+        0xffeb => Some(VirtualKeyCode::LWin),
+        0xffec => Some(VirtualKeyCode::RWin),
+        // XF86 multimedia keys
+        0x1008ff11 => Some(VirtualKeyCode::VolumeDown),
+        0x1008ff12 => Some(VirtualKeyCode::Mute),
+        0x1008ff13 => Some(VirtualKeyCode::VolumeUp),
+        0x1008ff14 => Some(VirtualKeyCode::PlayPause),
+        0x1008ff15 => Some(VirtualKeyCode::MediaStop),
+        0x1008ff16 => Some(VirtualKeyCode::PrevTrack),
+        0x1008ff17 => Some(VirtualKeyCode::NextTrack),
+        0x1008ff18 => Some(VirtualKeyCode::WebHome),
+        0x1008ff19 => Some(VirtualKeyCode::Mail),
+        0x1008ff1b => Some(VirtualKeyCode::WebSearch),
+        0x1008ff1d => Some(VirtualKeyCode::Calculator),
+        0x1008ff26 => Some(VirtualKeyCode::NavigateBackward),
+        0x1008ff27 => Some(VirtualKeyCode::NavigateForward),
+        0x1008ff28 => Some(VirtualKeyCode::WebStop),
+        0x1008ff29 => Some(VirtualKeyCode::WebRefresh),
+        0x1008ff2a => Some(VirtualKeyCode::Power),
+        0x1008ff2b => Some(VirtualKeyCode::Wake),
+        0x1008ff2f => Some(VirtualKeyCode::Sleep),
+        0x1008ff30 => Some(VirtualKeyCode::WebFavorites),
+        0x1008ff32 => Some(VirtualKeyCode::MediaSelect),
+        0x1008ff33 => Some(VirtualKeyCode::MyComputer),
+        0x1008ff57 => Some(VirtualKeyCode::Copy),
+        0x1008ff58 => Some(VirtualKeyCode::Cut),
+        0x1008ff6d => Some(VirtualKeyCode::Paste),
+        _ => None,
+    }
+}
+
+/// Converts the (shifted) keysym of a key press to the character it produces
+///
+/// `latin1` is the text that `XLookupString` wrote into its buffer, used as a
+/// fallback for legacy keysyms that don't map directly to a unicode codepoint.
+/// Control characters (backspace, return, escape, ...) return `None`, since
+/// these are handled via the virtual keycode, same as `WM_CHAR` on Win32.
+pub fn keysym_to_char(keysym: KeySym, latin1: &[u8]) -> Option<char> {
+    // Ctrl + key: XLookupString produces a control character
+    if let [b] = latin1 {
+        if (*b as char).is_control() {
+            return None;
+        }
+    }
+
+    let c = match keysym {
+        // latin 1 keysyms are identical to their unicode codepoint
+        0x0020..=0x007e | 0x00a0..=0x00ff => core::char::from_u32(keysym as u32),
+        // "0x0100_0000 + codepoint" keysyms
+        0x0100_0000..=0x0110_ffff => core::char::from_u32((keysym - 0x0100_0000) as u32),
+        // keypad digits and operators
+        0xffaa..=0xffb9 | 0xffbd => core::char::from_u32((keysym - 0xff80) as u32),
+        _ => match latin1 {
+            [b] => Some(*b as char),
+            _ => None,
+        },
+    };
+
+    c.filter(|c| !c.is_control())
+}
+
+#[test]
+fn test_keysym_translation() {
+    assert_eq!(keysym_to_virtual_keycode(0x0061), Some(VirtualKeyCode::A));
+    assert_eq!(keysym_to_virtual_keycode(0xffc9), Some(VirtualKeyCode::F12));
+    assert_eq!(
+        keysym_to_virtual_keycode(0xff9e),
+        Some(VirtualKeyCode::Numpad0)
+    );
+    assert_eq!(keysym_to_virtual_keycode(0xfe20), None);
+
+    assert_eq!(keysym_to_char(0x0041, b"A"), Some('A'));
+    assert_eq!(keysym_to_char(0x00e4, &[0xe4]), Some('ä'));
+    assert_eq!(keysym_to_char(0x0100_20ac, &[]), Some('€'));
+    assert_eq!(keysym_to_char(0xffb7, b"7"), Some('7'));
+    assert_eq!(keysym_to_char(0xff0d, b"\r"), None);
+    assert_eq!(keysym_to_char(0xff1b, &[0x1b]), None);
+    assert_eq!(keysym_to_char(0x0061, &[0x01]), None);
+}
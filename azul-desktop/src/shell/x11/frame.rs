@@ -0,0 +1,210 @@
+//! Maximized, fullscreen and minimized state of a window (`_NET_WM_STATE`)
+//!
+//! The state is read when a window with a `persistence_key` is closed and set
+//! before a restored window is mapped: before mapping, the window manager reads
+//! the initial state from the property, afterwards it would have to be changed
+//! with client messages to the root window.
+
+use super::{Atom, Display, Library, Window, X11_FALSE};
+use crate::gl::{c_char, c_int, c_long, c_uchar, c_ulong};
+use azul_core::window::WindowFrame;
+use core::{ffi::c_void, ptr};
+use once_cell::sync::Lazy;
+
+const XA_ATOM: Atom = 4;
+const PROP_MODE_APPEND: c_int = 2;
+const X11_SUCCESS: c_int = 0;
+// maximum number of states that are read
+const MAX_STATES: c_long = 32;
+
+type XInternAtomFuncType = extern "C" fn(*mut Display, *const c_char, c_int) -> Atom;
+type XGetWindowPropertyFuncType = extern "C" fn(
+    *mut Display,
+    Window,
+    Atom,
+    c_long,
+    c_long,
+    c_int,
+    Atom,
+    *mut Atom,
+    *mut c_int,
+    *mut c_ulong,
+    *mut c_ulong,
+    *mut *mut c_uchar,
+) -> c_int;
+type XChangePropertyFuncType =
+    extern "C" fn(*mut Display, Window, Atom, Atom, c_int, c_int, *const c_uchar, c_int) -> c_int;
+type XFreeFuncType = extern "C" fn(*mut c_void) -> c_int;
+
+// Xlib functions used to read and write the window state
+struct FrameXlib {
+    pub library: Library,
+    pub XInternAtom: XInternAtomFuncType,
+    pub XGetWindowProperty: XGetWindowPropertyFuncType,
+    pub XChangeProperty: XChangePropertyFuncType,
+    pub XFree: XFreeFuncType,
+}
+
+impl FrameXlib {
+    fn new() -> Option<Self> {
+        use super::clipboard::load_function;
+
+        let library = Library::load("libX11.so").ok()?;
+
+        Some(Self {
+            XInternAtom: load_function(&library, "XInternAtom")?,
+            XGetWindowProperty: load_function(&library, "XGetWindowProperty")?,
+            XChangeProperty: load_function(&library, "XChangeProperty")?,
+            XFree: load_function(&library, "XFree")?,
+            library,
+        })
+    }
+
+    fn intern_atom(&self, display: *mut Display, name: &str) -> Atom {
+        let name = std::ffi::CString::new(name).unwrap_or_default();
+        (self.XInternAtom)(display, name.as_ptr(), X11_FALSE)
+    }
+
+    fn get_state_atoms(&self, display: *mut Display) -> StateAtoms {
+        StateAtoms {
+            maximized_vert: self.intern_atom(display, "_NET_WM_STATE_MAXIMIZED_VERT"),
+            maximized_horz: self.intern_atom(display, "_NET_WM_STATE_MAXIMIZED_HORZ"),
+            fullscreen: self.intern_atom(display, "_NET_WM_STATE_FULLSCREEN"),
+            hidden: self.intern_atom(display, "_NET_WM_STATE_HIDDEN"),
+        }
+    }
+}
+
+static FRAME_XLIB: Lazy<Option<FrameXlib>> = Lazy::new(FrameXlib::new);
+
+// atoms of the _NET_WM_STATE property that correspond to a WindowFrame
+struct StateAtoms {
+    maximized_vert: Atom,
+    maximized_horz: Atom,
+    fullscreen: Atom,
+    hidden: Atom,
+}
+
+impl StateAtoms {
+    fn get_frame(&self, states: &[Atom]) -> WindowFrame {
+        if states.contains(&self.hidden) {
+            WindowFrame::Minimized
+        } else if states.contains(&self.fullscreen) {
+            WindowFrame::Fullscreen
+        } else if states.contains(&self.maximized_vert) && states.contains(&self.maximized_horz) {
+            WindowFrame::Maximized
+        } else {
+            WindowFrame::Normal
+        }
+    }
+
+    fn get_states(&self, frame: WindowFrame) -> Vec<Atom> {
+        match frame {
+            WindowFrame::Maximized => vec![self.maximized_vert, self.maximized_horz],
+            WindowFrame::Fullscreen => vec![self.fullscreen],
+            // windows can't be mapped in the minimized state
+            WindowFrame::Normal | WindowFrame::Minimized => Vec::new(),
+        }
+    }
+}
+
+/// Returns the current state of the window
+pub(super) fn get_frame(display: *mut Display, window: Window) -> WindowFrame {
+    let xlib = match FRAME_XLIB.as_ref() {
+        Some(s) => s,
+        None => return WindowFrame::Normal,
+    };
+
+    let property = xlib.intern_atom(display, "_NET_WM_STATE");
+    let mut actual_type = 0;
+    let mut actual_format = 0;
+    let mut item_count = 0;
+    let mut bytes_after = 0;
+    let mut data = ptr::null_mut();
+
+    let status = (xlib.XGetWindowProperty)(
+        display,
+        window,
+        property,
+        0,
+        MAX_STATES,
+        X11_FALSE,
+        XA_ATOM,
+        &mut actual_type,
+        &mut actual_format,
+        &mut item_count,
+        &mut bytes_after,
+        &mut data,
+    );
+
+    if data.is_null() {
+        return WindowFrame::Normal;
+    }
+
+    // format 32 properties are returned as an array of longs
+    let states = if status == X11_SUCCESS && actual_format == 32 {
+        unsafe { core::slice::from_raw_parts(data as *const c_ulong, item_count as usize) }
+            .iter()
+            .map(|s| *s as Atom)
+            .collect::<Vec<_>>()
+    } else {
+        Vec::new()
+    };
+    (xlib.XFree)(data as *mut c_void);
+
+    xlib.get_state_atoms(display).get_frame(&states)
+}
+
+/// Sets the initial state of the (not yet mapped) window
+pub(super) fn set_initial_frame(display: *mut Display, window: Window, frame: WindowFrame) {
+    let xlib = match FRAME_XLIB.as_ref() {
+        Some(s) => s,
+        None => return,
+    };
+
+    let states = xlib.get_state_atoms(display).get_states(frame);
+    if states.is_empty() {
+        return;
+    }
+
+    // appended, so that the modal state of child windows is kept
+    let property = xlib.intern_atom(display, "_NET_WM_STATE");
+    (xlib.XChangeProperty)(
+        display,
+        window,
+        property,
+        XA_ATOM,
+        32,
+        PROP_MODE_APPEND,
+        states.as_ptr() as *const c_uchar,
+        states.len() as c_int,
+    );
+}
+
+#[test]
+fn test_window_frame_states() {
+    let atoms = StateAtoms {
+        maximized_vert: 10,
+        maximized_horz: 11,
+        fullscreen: 12,
+        hidden: 13,
+    };
+
+    assert_eq!(atoms.get_frame(&[]), WindowFrame::Normal);
+    // only maximized vertically
+    assert_eq!(atoms.get_frame(&[10, 20]), WindowFrame::Normal);
+    assert_eq!(atoms.get_frame(&[11, 10]), WindowFrame::Maximized);
+    assert_eq!(atoms.get_frame(&[10, 11, 12]), WindowFrame::Fullscreen);
+    assert_eq!(atoms.get_frame(&[10, 11, 13]), WindowFrame::Minimized);
+
+    for frame in [
+        WindowFrame::Normal,
+        WindowFrame::Maximized,
+        WindowFrame::Fullscreen,
+    ]
+    .iter()
+    {
+        assert_eq!(atoms.get_frame(&atoms.get_states(*frame)), *frame);
+    }
+    assert_eq!(atoms.get_states(WindowFrame::Minimized), Vec::new());
+}
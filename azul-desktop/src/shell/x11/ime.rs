@@ -0,0 +1,542 @@
+//! X11 input method support (XIM)
+//!
+//! Every window opens an input method on its own connection and creates an
+//! input context for itself. All events have to be passed through
+//! `XFilterEvent` first: the input method consumes the key presses that are
+//! part of a composition and reports the preedit string through the preedit
+//! callbacks ("on-the-spot" style), the committed text is delivered as a key
+//! press that is read with `Xutf8LookupString`.
+//!
+//! If the input method doesn't support the preedit callbacks, the "over-the-spot"
+//! style is used instead: the input method draws the preedit string itself, at
+//! the position set with `set_spot_location()`.
+
+use super::{Display, Library, Window, XEvent, XKeyEvent, X11_TRUE};
+use crate::gl::{c_char, c_int, c_ulong};
+use alloc::boxed::Box;
+use core::{ffi::c_void, ptr};
+use once_cell::sync::Lazy;
+use std::{
+    ffi::CStr,
+    os::raw::{c_short, c_ushort},
+};
+
+extern "C" {
+    // libc
+    fn setlocale(category: c_int, locale: *const c_char) -> *mut c_char;
+}
+
+// glibc value of LC_CTYPE
+const LC_CTYPE: c_int = 0;
+
+type XIM = *mut c_void;
+type XIC = *mut c_void;
+type XIMStyle = c_ulong;
+
+const XIM_PREEDIT_CALLBACKS: XIMStyle = 0x0002;
+const XIM_PREEDIT_POSITION: XIMStyle = 0x0004;
+const XIM_PREEDIT_NOTHING: XIMStyle = 0x0008;
+const XIM_PREEDIT_NONE: XIMStyle = 0x0010;
+const XIM_STATUS_NOTHING: XIMStyle = 0x0400;
+const XIM_STATUS_NONE: XIMStyle = 0x0800;
+
+// Xutf8LookupString status
+const X_BUFFER_OVERFLOW: c_int = -1;
+const X_LOOKUP_CHARS: c_int = 2;
+const X_LOOKUP_KEYSYM: c_int = 3;
+const X_LOOKUP_BOTH: c_int = 4;
+
+// XIMCaretDirection
+const XIM_FORWARD_CHAR: c_int = 0;
+const XIM_BACKWARD_CHAR: c_int = 1;
+const XIM_LINE_START: c_int = 8;
+const XIM_LINE_END: c_int = 9;
+const XIM_ABSOLUTE_POSITION: c_int = 10;
+
+const XN_INPUT_STYLE: &[u8] = b"inputStyle\0";
+const XN_CLIENT_WINDOW: &[u8] = b"clientWindow\0";
+const XN_FOCUS_WINDOW: &[u8] = b"focusWindow\0";
+const XN_QUERY_INPUT_STYLE: &[u8] = b"queryInputStyle\0";
+const XN_PREEDIT_ATTRIBUTES: &[u8] = b"preeditAttributes\0";
+const XN_PREEDIT_START_CALLBACK: &[u8] = b"preeditStartCallback\0";
+const XN_PREEDIT_DONE_CALLBACK: &[u8] = b"preeditDoneCallback\0";
+const XN_PREEDIT_DRAW_CALLBACK: &[u8] = b"preeditDrawCallback\0";
+const XN_PREEDIT_CARET_CALLBACK: &[u8] = b"preeditCaretCallback\0";
+const XN_SPOT_LOCATION: &[u8] = b"spotLocation\0";
+
+#[repr(C)]
+struct XIMStyles {
+    count_styles: c_ushort,
+    supported_styles: *mut XIMStyle,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+struct XIMCallback {
+    client_data: *mut c_void,
+    callback: *const c_void,
+}
+
+#[repr(C)]
+struct XIMText {
+    length: c_ushort,
+    feedback: *mut c_ulong,
+    encoding_is_wchar: c_int,
+    // union of a multi-byte and a wide char string, only multi-byte strings are requested
+    string: *const c_char,
+}
+
+#[repr(C)]
+struct XIMPreeditDrawCallbackStruct {
+    caret: c_int,
+    chg_first: c_int,
+    chg_length: c_int,
+    text: *const XIMText,
+}
+
+#[repr(C)]
+struct XIMPreeditCaretCallbackStruct {
+    position: c_int,
+    direction: c_int,
+    style: c_int,
+}
+
+#[repr(C)]
+struct XPoint {
+    x: c_short,
+    y: c_short,
+}
+
+type XSetLocaleModifiersFuncType = extern "C" fn(*const c_char) -> *mut c_char;
+type XSupportsLocaleFuncType = extern "C" fn() -> c_int;
+type XOpenIMFuncType = extern "C" fn(*mut Display, *mut c_void, *mut c_char, *mut c_char) -> XIM;
+type XCloseIMFuncType = extern "C" fn(XIM) -> c_int;
+type XGetIMValuesFuncType = unsafe extern "C" fn(XIM, ...) -> *mut c_char;
+type XCreateICFuncType = unsafe extern "C" fn(XIM, ...) -> XIC;
+type XDestroyICFuncType = extern "C" fn(XIC);
+type XSetICValuesFuncType = unsafe extern "C" fn(XIC, ...) -> *mut c_char;
+type XSetICFocusFuncType = extern "C" fn(XIC);
+type XUnsetICFocusFuncType = extern "C" fn(XIC);
+type XFilterEventFuncType = extern "C" fn(*mut XEvent, Window) -> c_int;
+type Xutf8LookupStringFuncType =
+    extern "C" fn(XIC, *mut XKeyEvent, *mut c_char, c_int, *mut c_ulong, *mut c_int) -> c_int;
+type XVaCreateNestedListFuncType = unsafe extern "C" fn(c_int, ...) -> *mut c_void;
+type XFreeFuncType = extern "C" fn(*mut c_void) -> c_int;
+
+// Xlib functions used for the input method (on the connection of the window)
+struct XimXlib {
+    pub library: Library,
+    pub XSetLocaleModifiers: XSetLocaleModifiersFuncType,
+    pub XSupportsLocale: XSupportsLocaleFuncType,
+    pub XOpenIM: XOpenIMFuncType,
+    pub XCloseIM: XCloseIMFuncType,
+    pub XGetIMValues: XGetIMValuesFuncType,
+    pub XCreateIC: XCreateICFuncType,
+    pub XDestroyIC: XDestroyICFuncType,
+    pub XSetICValues: XSetICValuesFuncType,
+    pub XSetICFocus: XSetICFocusFuncType,
+    pub XUnsetICFocus: XUnsetICFocusFuncType,
+    pub XFilterEvent: XFilterEventFuncType,
+    pub Xutf8LookupString: Xutf8LookupStringFuncType,
+    pub XVaCreateNestedList: XVaCreateNestedListFuncType,
+    pub XFree: XFreeFuncType,
+}
+
+impl XimXlib {
+    fn new() -> Option<Self> {
+        use super::clipboard::load_function;
+
+        let library = Library::load("libX11.so").ok()?;
+        Some(Self {
+            XSetLocaleModifiers: load_function(&library, "XSetLocaleModifiers")?,
+            XSupportsLocale: load_function(&library, "XSupportsLocale")?,
+            XOpenIM: load_function(&library, "XOpenIM")?,
+            XCloseIM: load_function(&library, "XCloseIM")?,
+            XGetIMValues: load_function(&library, "XGetIMValues")?,
+            XCreateIC: load_function(&library, "XCreateIC")?,
+            XDestroyIC: load_function(&library, "XDestroyIC")?,
+            XSetICValues: load_function(&library, "XSetICValues")?,
+            XSetICFocus: load_function(&library, "XSetICFocus")?,
+            XUnsetICFocus: load_function(&library, "XUnsetICFocus")?,
+            XFilterEvent: load_function(&library, "XFilterEvent")?,
+            Xutf8LookupString: load_function(&library, "Xutf8LookupString")?,
+            XVaCreateNestedList: load_function(&library, "XVaCreateNestedList")?,
+            XFree: load_function(&library, "XFree")?,
+            library,
+        })
+    }
+}
+
+static XIM_XLIB: Lazy<Option<XimXlib>> = Lazy::new(|| {
+    let xlib = XimXlib::new()?;
+    // the input method is selected from the locale and the XMODIFIERS environment variable
+    unsafe { setlocale(LC_CTYPE, b"\0".as_ptr() as *const c_char) };
+    if (xlib.XSupportsLocale)() == 0 {
+        return None;
+    }
+    (xlib.XSetLocaleModifiers)(b"\0".as_ptr() as *const c_char);
+    Some(xlib)
+});
+
+// Preedit state, written by the preedit callbacks (which Xlib calls during
+// XFilterEvent). Boxed, so that the client data pointer stays valid.
+struct PreeditState {
+    text: Vec<char>,
+    caret: usize,
+    active: bool,
+    changed: bool,
+}
+
+/// Change of the preedit string since the last call to `Xim::take_preedit_change()`
+pub(super) enum PreeditChange {
+    /// The composition started or the preedit string / cursor changed
+    Composition(String, usize),
+    /// The composition ended (the committed text arrives as a key press)
+    Done,
+}
+
+/// Input method and input context of one window
+pub(super) struct Xim {
+    xlib: &'static XimXlib,
+    im: XIM,
+    ic: XIC,
+    style: XIMStyle,
+    preedit: Box<PreeditState>,
+}
+
+impl Xim {
+    /// Opens the input method of the current locale, returns `None`
+    /// if no input method is running or no input style is supported
+    pub(super) fn new(display: *mut Display, window: Window) -> Option<Self> {
+        let xlib = XIM_XLIB.as_ref()?;
+
+        let im = (xlib.XOpenIM)(display, ptr::null_mut(), ptr::null_mut(), ptr::null_mut());
+        if im.is_null() {
+            return None;
+        }
+
+        let style = match Self::select_style(xlib, im) {
+            Some(s) => s,
+            None => {
+                (xlib.XCloseIM)(im);
+                return None;
+            }
+        };
+
+        let mut preedit = Box::new(PreeditState {
+            text: Vec::new(),
+            caret: 0,
+            active: false,
+            changed: false,
+        });
+        let client_data = &mut *preedit as *mut PreeditState as *mut c_void;
+
+        let callback = |f: *const c_void| XIMCallback {
+            client_data,
+            callback: f,
+        };
+        let start_callback = callback(preedit_start as *const c_void);
+        let done_callback = callback(preedit_done as *const c_void);
+        let draw_callback = callback(preedit_draw as *const c_void);
+        let caret_callback = callback(preedit_caret as *const c_void);
+        let spot_location = XPoint { x: 0, y: 0 };
+
+        let preedit_attributes = unsafe {
+            if style & XIM_PREEDIT_CALLBACKS != 0 {
+                (xlib.XVaCreateNestedList)(
+                    0,
+                    XN_PREEDIT_START_CALLBACK.as_ptr(),
+                    &start_callback as *const XIMCallback,
+                    XN_PREEDIT_DONE_CALLBACK.as_ptr(),
+                    &done_callback as *const XIMCallback,
+                    XN_PREEDIT_DRAW_CALLBACK.as_ptr(),
+                    &draw_callback as *const XIMCallback,
+                    XN_PREEDIT_CARET_CALLBACK.as_ptr(),
+                    &caret_callback as *const XIMCallback,
+                    ptr::null::<c_char>(),
+                )
+            } else if style & XIM_PREEDIT_POSITION != 0 {
+                (xlib.XVaCreateNestedList)(
+                    0,
+                    XN_SPOT_LOCATION.as_ptr(),
+                    &spot_location as *const XPoint,
+                    ptr::null::<c_char>(),
+                )
+            } else {
+                ptr::null_mut()
+            }
+        };
+
+        let ic = unsafe {
+            if preedit_attributes.is_null() {
+                (xlib.XCreateIC)(
+                    im,
+                    XN_INPUT_STYLE.as_ptr(),
+                    style,
+                    XN_CLIENT_WINDOW.as_ptr(),
+                    window,
+                    XN_FOCUS_WINDOW.as_ptr(),
+                    window,
+                    ptr::null::<c_char>(),
+                )
+            } else {
+                let ic = (xlib.XCreateIC)(
+                    im,
+                    XN_INPUT_STYLE.as_ptr(),
+                    style,
+                    XN_CLIENT_WINDOW.as_ptr(),
+                    window,
+                    XN_FOCUS_WINDOW.as_ptr(),
+                    window,
+                    XN_PREEDIT_ATTRIBUTES.as_ptr(),
+                    preedit_attributes,
+                    ptr::null::<c_char>(),
+                );
+                (xlib.XFree)(preedit_attributes);
+                ic
+            }
+        };
+
+        if ic.is_null() {
+            (xlib.XCloseIM)(im);
+            return None;
+        }
+
+        Some(Self {
+            xlib,
+            im,
+            ic,
+            style,
+            preedit,
+        })
+    }
+
+    // Prefers drawing the preedit string in the application, then
+    // letting the input method draw it at the text cursor
+    fn select_style(xlib: &XimXlib, im: XIM) -> Option<XIMStyle> {
+        let mut styles: *mut XIMStyles = ptr::null_mut();
+        let error = unsafe {
+            (xlib.XGetIMValues)(
+                im,
+                XN_QUERY_INPUT_STYLE.as_ptr(),
+                &mut styles as *mut *mut XIMStyles,
+                ptr::null::<c_char>(),
+            )
+        };
+        if !error.is_null() || styles.is_null() {
+            return None;
+        }
+
+        let supported = unsafe {
+            let styles = &*styles;
+            if styles.supported_styles.is_null() {
+                Vec::new()
+            } else {
+                core::slice::from_raw_parts(styles.supported_styles, styles.count_styles as usize)
+                    .to_vec()
+            }
+        };
+        (xlib.XFree)(styles as *mut c_void);
+
+        [
+            XIM_PREEDIT_CALLBACKS | XIM_STATUS_NOTHING,
+            XIM_PREEDIT_CALLBACKS | XIM_STATUS_NONE,
+            XIM_PREEDIT_POSITION | XIM_STATUS_NOTHING,
+            XIM_PREEDIT_POSITION | XIM_STATUS_NONE,
+            XIM_PREEDIT_NOTHING | XIM_STATUS_NOTHING,
+            XIM_PREEDIT_NOTHING | XIM_STATUS_NONE,
+            XIM_PREEDIT_NONE | XIM_STATUS_NOTHING,
+            XIM_PREEDIT_NONE | XIM_STATUS_NONE,
+        ]
+        .iter()
+        .copied()
+        .find(|s| supported.contains(s))
+    }
+
+    /// Passes the event to the input method, returns true if the
+    /// input method consumed the event (it must not be processed further)
+    pub(super) fn filter_event(&mut self, event: &mut XEvent) -> bool {
+        (self.xlib.XFilterEvent)(event, 0) == X11_TRUE
+    }
+
+    /// Returns the change of the preedit string since the last call
+    pub(super) fn take_preedit_change(&mut self) -> Option<PreeditChange> {
+        if !self.preedit.changed {
+            return None;
+        }
+        self.preedit.changed = false;
+
+        if self.preedit.active && !self.preedit.text.is_empty() {
+            Some(PreeditChange::Composition(
+                self.preedit.text.iter().collect(),
+                self.preedit.caret.min(self.preedit.text.len()),
+            ))
+        } else {
+            Some(PreeditChange::Done)
+        }
+    }
+
+    /// Returns the UTF-8 text and the keysym of a key press event
+    pub(super) fn lookup_string(&mut self, key: &mut XKeyEvent) -> (String, c_ulong) {
+        let mut buffer = vec![0_u8; 64];
+        let mut keysym = 0;
+        let mut status = 0;
+
+        let mut len = (self.xlib.Xutf8LookupString)(
+            self.ic,
+            key,
+            buffer.as_mut_ptr() as *mut c_char,
+            buffer.len() as c_int,
+            &mut keysym,
+            &mut status,
+        );
+
+        if status == X_BUFFER_OVERFLOW {
+            // len is the required size of the buffer
+            buffer = vec![0_u8; len.max(0) as usize + 1];
+            len = (self.xlib.Xutf8LookupString)(
+                self.ic,
+                key,
+                buffer.as_mut_ptr() as *mut c_char,
+                buffer.len() as c_int,
+                &mut keysym,
+                &mut status,
+            );
+        }
+
+        let text = match status {
+            X_LOOKUP_CHARS | X_LOOKUP_BOTH => {
+                let len = (len.max(0) as usize).min(buffer.len());
+                String::from_utf8_lossy(&buffer[..len]).into_owned()
+            }
+            _ => String::new(),
+        };
+
+        let keysym = match status {
+            X_LOOKUP_KEYSYM | X_LOOKUP_BOTH => keysym,
+            _ => 0,
+        };
+
+        (text, keysym)
+    }
+
+    pub(super) fn set_focus(&mut self, has_focus: bool) {
+        if has_focus {
+            (self.xlib.XSetICFocus)(self.ic);
+        } else {
+            (self.xlib.XUnsetICFocus)(self.ic);
+        }
+    }
+
+    /// Moves the candidate window (or the preedit string drawn by the
+    /// input method) to the given physical window coordinates
+    pub(super) fn set_spot_location(&mut self, x: c_int, y: c_int) {
+        if self.style & (XIM_PREEDIT_CALLBACKS | XIM_PREEDIT_POSITION) == 0 {
+            return;
+        }
+
+        let spot_location = XPoint {
+            x: x.max(c_short::MIN as c_int).min(c_short::MAX as c_int) as c_short,
+            y: y.max(c_short::MIN as c_int).min(c_short::MAX as c_int) as c_short,
+        };
+
+        unsafe {
+            let preedit_attributes = (self.xlib.XVaCreateNestedList)(
+                0,
+                XN_SPOT_LOCATION.as_ptr(),
+                &spot_location as *const XPoint,
+                ptr::null::<c_char>(),
+            );
+            if preedit_attributes.is_null() {
+                return;
+            }
+            (self.xlib.XSetICValues)(
+                self.ic,
+                XN_PREEDIT_ATTRIBUTES.as_ptr(),
+                preedit_attributes,
+                ptr::null::<c_char>(),
+            );
+            (self.xlib.XFree)(preedit_attributes);
+        }
+    }
+}
+
+impl Drop for Xim {
+    fn drop(&mut self) {
+        (self.xlib.XDestroyIC)(self.ic);
+        (self.xlib.XCloseIM)(self.im);
+    }
+}
+
+extern "C" fn preedit_start(_ic: XIC, client_data: *mut c_void, _call_data: *mut c_void) -> c_int {
+    let preedit = unsafe { &mut *(client_data as *mut PreeditState) };
+    preedit.text.clear();
+    preedit.caret = 0;
+    preedit.active = true;
+    preedit.changed = true;
+    -1 // no length limit
+}
+
+extern "C" fn preedit_done(_ic: XIC, client_data: *mut c_void, _call_data: *mut c_void) {
+    let preedit = unsafe { &mut *(client_data as *mut PreeditState) };
+    preedit.text.clear();
+    preedit.caret = 0;
+    preedit.active = false;
+    preedit.changed = true;
+}
+
+extern "C" fn preedit_draw(
+    _ic: XIC,
+    client_data: *mut c_void,
+    call_data: *mut XIMPreeditDrawCallbackStruct,
+) {
+    let preedit = unsafe { &mut *(client_data as *mut PreeditState) };
+    let draw = match unsafe { call_data.as_ref() } {
+        Some(s) => s,
+        None => return,
+    };
+
+    // replace chg_length characters at chg_first with the new text
+    let new_text: Vec<char> = unsafe { draw.text.as_ref() }
+        .filter(|t| t.encoding_is_wchar == 0 && !t.string.is_null())
+        .map(|t| {
+            unsafe { CStr::from_ptr(t.string) }
+                .to_string_lossy()
+                .chars()
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let len = preedit.text.len();
+    let first = (draw.chg_first.max(0) as usize).min(len);
+    let last = (first + draw.chg_length.max(0) as usize).min(len);
+    preedit.text.splice(first..last, new_text);
+    preedit.caret = draw.caret.max(0) as usize;
+    preedit.active = true;
+    preedit.changed = true;
+}
+
+extern "C" fn preedit_caret(
+    _ic: XIC,
+    client_data: *mut c_void,
+    call_data: *mut XIMPreeditCaretCallbackStruct,
+) {
+    let preedit = unsafe { &mut *(client_data as *mut PreeditState) };
+    let caret = match unsafe { call_data.as_mut() } {
+        Some(s) => s,
+        None => return,
+    };
+
+    let len = preedit.text.len();
+    preedit.caret = match caret.direction {
+        XIM_FORWARD_CHAR => (preedit.caret + 1).min(len),
+        XIM_BACKWARD_CHAR => preedit.caret.saturating_sub(1),
+        XIM_LINE_START => 0,
+        XIM_LINE_END => len,
+        XIM_ABSOLUTE_POSITION => (caret.position.max(0) as usize).min(len),
+        _ => preedit.caret,
+    };
+    // the input method reads the new caret position back
+    caret.position = preedit.caret as c_int;
+    preedit.changed = true;
+}
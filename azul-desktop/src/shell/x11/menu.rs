@@ -0,0 +1,1070 @@
+//! Menu bars and popup menus rendered by azul itself
+//!
+//! X11 has no native menu widget, so menus are drawn like the rest of the UI:
+//! the menu bar of a window (`Dom::with_menu_bar`) is prepended to the user DOM
+//! by wrapping the layout callback in `menu_bar_layout` (see the Wayland
+//! decorations), popup menus (context menus, drop-down menus of the menu bar,
+//! sub-menus and the menu of the XEmbed tray icon) are override-redirect windows
+//! whose layout callback is `menu_popup_layout`.
+//!
+//! The state of an open menu (the open sub-menus and their selected items) is
+//! stored in the `OpenMenu` of the window that owns the menu. The shell translates
+//! the mouse and keyboard input into `MenuInput`s and executes the `MenuCommand`s
+//! returned by `OpenMenu::handle_input`, since only the shell can create or
+//! destroy the popup windows.
+//!
+//! The layout of the menus has fixed item sizes, so that the shell can compute the
+//! size of a popup before creating its window and hit-test the items without a
+//! layout pass (the same as the title bar of the Wayland decorations).
+
+use super::{Display, Library};
+use crate::gl::c_int;
+use alloc::{string::String, vec::Vec};
+use azul_core::{
+    callbacks::{
+        DomNodeId, LayoutCallback, LayoutCallbackInfo, MarshaledLayoutCallback,
+        MarshaledLayoutCallbackInner, MenuCallback, RefAny,
+    },
+    dom::{Dom, IdOrClass, IdOrClassVec},
+    id_tree::NodeId,
+    styled_dom::StyledDom,
+    window::{
+        LogicalPosition, LogicalSize, Menu, MenuItem, MenuItemIcon, MenuItemState, MenuItemVec,
+        MenuPopupPosition, PhysicalPositionI32, VirtualKeyCode, VirtualKeyCodeCombo,
+        WindowCreateOptions, WindowPosition, XWindowType,
+    },
+};
+use azul_css::{CssProperty, FloatValue, LayoutFlexGrow};
+use azul_css_parser::CssApiWrapper;
+use once_cell::sync::Lazy;
+
+/// Height of the menu bar in logical pixels
+pub(super) const MENU_BAR_HEIGHT: f32 = 26.0;
+/// Horizontal padding of the items in the menu bar
+const MENU_BAR_ITEM_PADDING: f32 = 10.0;
+/// Height of a regular item in a popup menu
+const ITEM_HEIGHT: f32 = 24.0;
+/// Height of a separator in a popup menu (including its margin)
+const SEPARATOR_HEIGHT: f32 = 9.0;
+/// Padding above the first and below the last item of a popup menu
+const POPUP_PADDING: f32 = 4.0;
+/// Width of the border of a popup menu
+const POPUP_BORDER: f32 = 1.0;
+/// Width of the column that shows the checkmark / icon of an item
+const ICON_COLUMN_WIDTH: f32 = 24.0;
+/// Width of the column that shows the sub-menu arrow of an item
+const ARROW_COLUMN_WIDTH: f32 = 20.0;
+/// Space between the label and the accelerator of an item
+const ACCELERATOR_GAP: f32 = 24.0;
+const MIN_POPUP_WIDTH: f32 = 160.0;
+/// Estimated average width of a character at the menu font size: the popup
+/// window has to be created before its content is laid out
+const CHAR_WIDTH: f32 = 7.5;
+
+/// Rectangle in root window coordinates
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(super) struct ScreenRect {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+}
+
+impl ScreenRect {
+    pub(super) fn new(x: i32, y: i32, width: i32, height: i32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+}
+
+/// Input for an open menu, translated from the X11 events by the shell
+#[derive(Debug)]
+pub(super) enum MenuInput {
+    /// Opens a new menu (closes the menu that is currently open)
+    Open(OpenMenu),
+    /// Cursor moved to `y` (relative to the popup window) in the popup window
+    Hover { window: u64, y: f32 },
+    /// Mouse button released at `y` in the popup window
+    Click { window: u64, y: f32 },
+    /// Key pressed while the menu is open (the window that owns the menu keeps the focus)
+    Key(VirtualKeyCode),
+    /// Click outside of the menu, focus lost, etc.
+    Close,
+}
+
+/// Action that the shell has to execute after handling a `MenuInput`
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum MenuCommand {
+    /// Creates the popup window of the level
+    Open(usize),
+    /// Redraws the popup of the level (its selected item changed)
+    Update(usize),
+    /// Destroys the popup windows (the levels are already removed)
+    Close(Vec<u64>),
+    /// Invokes the callback of the activated item on the window that owns the menu
+    Invoke(MenuCallback, DomNodeId),
+    /// Opens the previous (-1) or next (+1) item of the menu bar
+    SwitchMenuBarItem(isize),
+}
+
+/// One popup of an open menu: the root menu or one of the open sub-menus
+#[derive(Debug)]
+pub(super) struct MenuLevel {
+    pub items: MenuItemVec,
+    pub selected: Option<usize>,
+    /// Position and size of the popup window
+    pub rect: ScreenRect,
+    /// X11 window of the popup, set by the shell once the window is created
+    pub window: Option<u64>,
+}
+
+impl MenuLevel {
+    /// Options for creating the popup window of this level
+    pub(super) fn get_window_options(&self, is_dropdown: bool) -> WindowCreateOptions {
+        let mut options = WindowCreateOptions::default();
+        let state = &mut options.state;
+        state.title = "menu".into();
+        state.size.dimensions = LogicalSize::new(self.rect.width as f32, self.rect.height as f32);
+        state.position =
+            WindowPosition::Initialized(PhysicalPositionI32::new(self.rect.x, self.rect.y));
+        state.flags.has_decorations = false;
+        state.flags.is_resizable = false;
+        state.layout_callback = LayoutCallback::Marshaled(MarshaledLayoutCallback {
+            marshal_data: RefAny::new(MenuPopup {
+                items: self.items.clone(),
+                selected: self.selected,
+            }),
+            cb: MarshaledLayoutCallbackInner {
+                cb: menu_popup_layout,
+            },
+        });
+
+        let linux_options = &mut state.platform_specific_options.linux_options;
+        linux_options.x11_override_redirect = true;
+        linux_options.x11_window_types = vec![if is_dropdown {
+            XWindowType::DropdownMenu
+        } else {
+            XWindowType::PopupMenu
+        }]
+        .into();
+
+        options
+    }
+}
+
+/// Menu that is currently open on a window
+#[derive(Debug)]
+pub(super) struct OpenMenu {
+    /// Node that the menu belongs to (the root node for the menu bar and the tray
+    /// menu), passed to the menu callbacks
+    pub hit_dom_node: DomNodeId,
+    /// Index of the menu bar item that the menu was opened from
+    pub menu_bar_item: Option<usize>,
+    /// Size of the screen, sub-menus are flipped to the left if they don't fit
+    pub screen: ScreenRect,
+    /// Root menu first, followed by the open sub-menus
+    pub levels: Vec<MenuLevel>,
+}
+
+impl OpenMenu {
+    /// Creates a closed menu (the shell still has to create the popup of level 0)
+    /// positioned according to `position` relative to the cursor or the hit rect
+    pub(super) fn new(
+        items: MenuItemVec,
+        position: MenuPopupPosition,
+        cursor: (i32, i32),
+        hit_rect: ScreenRect,
+        screen: ScreenRect,
+        hit_dom_node: DomNodeId,
+        menu_bar_item: Option<usize>,
+    ) -> Self {
+        let size = get_popup_size(items.as_ref());
+        let (x, y) = get_popup_origin(position, cursor, hit_rect, size, screen);
+        Self {
+            hit_dom_node,
+            menu_bar_item,
+            screen,
+            levels: vec![MenuLevel {
+                items,
+                selected: None,
+                rect: ScreenRect::new(x, y, size.0, size.1),
+                window: None,
+            }],
+        }
+    }
+
+    /// Returns the level of the popup menu window
+    pub(super) fn get_level_of_window(&self, window: u64) -> Option<usize> {
+        self.levels.iter().position(|l| l.window == Some(window))
+    }
+
+    /// Returns all popup windows of the menu
+    pub(super) fn get_popup_windows(&self) -> Vec<u64> {
+        self.levels.iter().filter_map(|l| l.window).collect()
+    }
+
+    pub(super) fn is_closed(&self) -> bool {
+        self.levels.is_empty()
+    }
+
+    pub(super) fn handle_input(&mut self, input: MenuInput) -> Vec<MenuCommand> {
+        use azul_core::window::VirtualKeyCode::*;
+
+        let mut commands = Vec::new();
+
+        match input {
+            MenuInput::Open(_) => {}
+            MenuInput::Hover { window, y } => {
+                let level = match self.get_level_of_window(window) {
+                    Some(s) => s,
+                    None => return commands,
+                };
+                let item = get_item_at(self.levels[level].items.as_ref(), y);
+                self.select(level, item, &mut commands);
+            }
+            MenuInput::Click { window, y } => {
+                let level = match self.get_level_of_window(window) {
+                    Some(s) => s,
+                    None => return commands,
+                };
+                let item = get_item_at(self.levels[level].items.as_ref(), y);
+                if let Some(item) = item {
+                    self.select(level, Some(item), &mut commands);
+                    self.activate(level, &mut commands);
+                }
+            }
+            MenuInput::Key(key) => {
+                let level = match self.levels.len().checked_sub(1) {
+                    Some(s) => s,
+                    None => return commands,
+                };
+                match key {
+                    Down | Up | Home | End => {
+                        let l = &self.levels[level];
+                        let items = l.items.as_ref();
+                        let next = match key {
+                            Down => get_next_item(items, l.selected, true),
+                            Up => get_next_item(items, l.selected, false),
+                            Home => get_next_item(items, None, true),
+                            _ => get_next_item(items, None, false),
+                        };
+                        self.select(level, next, &mut commands);
+                    }
+                    Right => {
+                        if self.has_submenu(level) {
+                            self.open_submenu(level, true, &mut commands);
+                        } else if self.menu_bar_item.is_some() {
+                            commands.push(MenuCommand::SwitchMenuBarItem(1));
+                        }
+                    }
+                    Left => {
+                        if level > 0 {
+                            self.close_levels(level, &mut commands);
+                        } else if self.menu_bar_item.is_some() {
+                            commands.push(MenuCommand::SwitchMenuBarItem(-1));
+                        }
+                    }
+                    // closes the innermost popup (the whole menu if only the root menu is open)
+                    Escape => self.close_levels(level, &mut commands),
+                    Return | NumpadEnter | Space => self.activate(level, &mut commands),
+                    _ => {}
+                }
+            }
+            MenuInput::Close => self.close_levels(0, &mut commands),
+        }
+
+        commands
+    }
+
+    // Selects an item of a level: closes the sub-menus of the previously
+    // selected item and opens the sub-menu of the new item (if it has one)
+    fn select(&mut self, level: usize, item: Option<usize>, commands: &mut Vec<MenuCommand>) {
+        let current = match self.levels.get(level) {
+            Some(l) => l.selected,
+            None => return,
+        };
+
+        if current == item {
+            // cursor moved back from the sub-menu to its parent: close the sub-sub-menus
+            if self.levels.len() > level + 2 {
+                self.close_levels(level + 2, commands);
+            }
+            return;
+        }
+
+        self.close_levels(level + 1, commands);
+        self.levels[level].selected = item;
+        commands.push(MenuCommand::Update(level));
+
+        if self.has_submenu(level) {
+            self.open_submenu(level, false, commands);
+        }
+    }
+
+    // Invokes the callback of the selected item or opens its sub-menu
+    fn activate(&mut self, level: usize, commands: &mut Vec<MenuCommand>) {
+        let item = match self.get_selected_item(level) {
+            Some(s) => s,
+            None => return,
+        };
+
+        if !item.children.as_ref().is_empty() {
+            if self.levels.len() == level + 1 {
+                self.open_submenu(level, true, commands);
+            }
+            return;
+        }
+
+        let callback = item.callback.as_ref().cloned();
+        self.close_levels(0, commands);
+        if let Some(callback) = callback {
+            commands.push(MenuCommand::Invoke(callback, self.hit_dom_node));
+        }
+    }
+
+    fn get_selected_item(&self, level: usize) -> Option<&azul_core::window::StringMenuItem> {
+        let l = self.levels.get(level)?;
+        match l.items.as_ref().get(l.selected?)? {
+            MenuItem::String(s) if s.state == MenuItemState::Normal => Some(s),
+            _ => None,
+        }
+    }
+
+    fn has_submenu(&self, level: usize) -> bool {
+        self.get_selected_item(level)
+            .map(|s| !s.children.as_ref().is_empty())
+            .unwrap_or(false)
+    }
+
+    // Opens the sub-menu of the selected item of the level to the right of the item
+    // (or to the left if it doesn't fit on the screen), optionally selecting its first item
+    fn open_submenu(&mut self, level: usize, select_first: bool, commands: &mut Vec<MenuCommand>) {
+        let parent = &self.levels[level];
+        let index = match parent.selected {
+            Some(s) => s,
+            None => return,
+        };
+        let items = match self.get_selected_item(level) {
+            Some(s) => s.children.clone(),
+            None => return,
+        };
+
+        let (width, height) = get_popup_size(items.as_ref());
+        let item_y = parent.rect.y + get_item_offset(parent.items.as_ref(), index) as i32;
+        let mut x = parent.rect.x + parent.rect.width;
+        if x + width > self.screen.x + self.screen.width {
+            x = parent.rect.x - width;
+        }
+        // align the first item of the sub-menu with the parent item
+        let mut y = item_y - (POPUP_PADDING + POPUP_BORDER) as i32;
+        if y + height > self.screen.y + self.screen.height {
+            y = self.screen.y + self.screen.height - height;
+        }
+
+        let selected = if select_first {
+            get_next_item(items.as_ref(), None, true)
+        } else {
+            None
+        };
+
+        self.levels.push(MenuLevel {
+            items,
+            selected,
+            rect: ScreenRect::new(x.max(self.screen.x), y.max(self.screen.y), width, height),
+            window: None,
+        });
+        commands.push(MenuCommand::Open(self.levels.len() - 1));
+    }
+
+    // Removes the level and all levels above it
+    fn close_levels(&mut self, level: usize, commands: &mut Vec<MenuCommand>) {
+        if level >= self.levels.len() {
+            return;
+        }
+        let windows = self
+            .levels
+            .drain(level..)
+            .filter_map(|l| l.window)
+            .collect::<Vec<_>>();
+        if !windows.is_empty() {
+            commands.push(MenuCommand::Close(windows));
+        }
+        if let Some(parent) = level.checked_sub(1) {
+            // the parent item stays selected, but has to be redrawn without the sub-menu
+            commands.push(MenuCommand::Update(parent));
+        }
+    }
+}
+
+fn is_selectable(item: &MenuItem) -> bool {
+    match item {
+        MenuItem::String(s) => s.state == MenuItemState::Normal,
+        _ => false,
+    }
+}
+
+// Returns the next selectable item before or after the current item (wrapping
+// around), skipping separators and disabled items
+fn get_next_item(items: &[MenuItem], current: Option<usize>, forward: bool) -> Option<usize> {
+    let len = items.len();
+    if len == 0 {
+        return None;
+    }
+    let start = match (current, forward) {
+        (Some(c), true) => c + 1,
+        (Some(c), false) => c + len - 1,
+        (None, true) => 0,
+        (None, false) => len - 1,
+    };
+    (0..len)
+        .map(|i| {
+            if forward {
+                (start + i) % len
+            } else {
+                (start + len - i) % len
+            }
+        })
+        .find(|i| is_selectable(&items[*i]))
+}
+
+fn get_item_height(item: &MenuItem) -> f32 {
+    match item {
+        MenuItem::String(_) => ITEM_HEIGHT,
+        MenuItem::Separator => SEPARATOR_HEIGHT,
+        // line breaks only apply to horizontal menus
+        MenuItem::BreakLine => 0.0,
+    }
+}
+
+// Offset of the top of the item relative to the top of the popup window
+fn get_item_offset(items: &[MenuItem], index: usize) -> f32 {
+    POPUP_PADDING + POPUP_BORDER + items.iter().take(index).map(get_item_height).sum::<f32>()
+}
+
+/// Returns the selectable item at `y` (relative to the top of the popup window)
+pub(super) fn get_item_at(items: &[MenuItem], y: f32) -> Option<usize> {
+    let mut top = POPUP_PADDING + POPUP_BORDER;
+    for (i, item) in items.iter().enumerate() {
+        let bottom = top + get_item_height(item);
+        if y >= top && y < bottom {
+            return Some(i).filter(|_| is_selectable(item));
+        }
+        top = bottom;
+    }
+    None
+}
+
+/// Returns the width and height of the popup window in pixels
+pub(super) fn get_popup_size(items: &[MenuItem]) -> (i32, i32) {
+    let content_width = items
+        .iter()
+        .filter_map(|item| match item {
+            MenuItem::String(s) => Some(s),
+            _ => None,
+        })
+        .map(|s| {
+            let label = s.label.as_str().chars().count() as f32 * CHAR_WIDTH;
+            let accelerator = s
+                .accelerator
+                .as_ref()
+                .map(|a| {
+                    format_accelerator(a).chars().count() as f32 * CHAR_WIDTH + ACCELERATOR_GAP
+                })
+                .unwrap_or(0.0);
+            label + accelerator
+        })
+        .fold(0.0_f32, f32::max);
+
+    let width = (content_width + ICON_COLUMN_WIDTH + ARROW_COLUMN_WIDTH + 2.0 * POPUP_BORDER)
+        .max(MIN_POPUP_WIDTH);
+    let height =
+        2.0 * (POPUP_PADDING + POPUP_BORDER) + items.iter().map(get_item_height).sum::<f32>();
+
+    (width.ceil() as i32, height.ceil() as i32)
+}
+
+/// Computes the top left corner of a popup of the given size, so that it opens at the
+/// `position` relative to the cursor / hit rect and stays inside of the screen
+pub(super) fn get_popup_origin(
+    position: MenuPopupPosition,
+    cursor: (i32, i32),
+    hit_rect: ScreenRect,
+    size: (i32, i32),
+    screen: ScreenRect,
+) -> (i32, i32) {
+    use azul_core::window::MenuPopupPosition::*;
+
+    let (cx, cy) = cursor;
+    let (w, h) = size;
+    let r = hit_rect;
+    let screen_right = screen.x + screen.width;
+    let screen_bottom = screen.y + screen.height;
+
+    let (x, y) = match position {
+        BottomLeftOfCursor => (cx - w, cy),
+        BottomRightOfCursor => (cx, cy),
+        TopLeftOfCursor => (cx - w, cy - h),
+        TopRightOfCursor => (cx, cy - h),
+        BottomOfHitRect => (r.x, r.y + r.height),
+        LeftOfHitRect => (r.x - w, r.y),
+        TopOfHitRect => (r.x, r.y - h),
+        RightOfHitRect => (r.x + r.width, r.y),
+        AutoCursor => (
+            if cx + w > screen_right { cx - w } else { cx },
+            if cy + h > screen_bottom { cy - h } else { cy },
+        ),
+        AutoHitRect => (
+            r.x,
+            if r.y + r.height + h > screen_bottom {
+                r.y - h
+            } else {
+                r.y + r.height
+            },
+        ),
+    };
+
+    (
+        x.min(screen_right - w).max(screen.x),
+        y.min(screen_bottom - h).max(screen.y),
+    )
+}
+
+/// Formats the accelerator for display next to the menu item, i.e. "Ctrl+Shift+S"
+pub(super) fn format_accelerator(combo: &VirtualKeyCodeCombo) -> String {
+    use azul_core::window::AcceleratorKey;
+
+    let mut keys = combo.get_accelerator_keys();
+    keys.sort();
+    keys.dedup();
+
+    keys.iter()
+        .map(|k| match k {
+            AcceleratorKey::Ctrl => String::from("Ctrl"),
+            AcceleratorKey::Alt => String::from("Alt"),
+            AcceleratorKey::Shift => String::from("Shift"),
+            AcceleratorKey::Key(k) => format_key(*k),
+        })
+        .collect::<Vec<_>>()
+        .join("+")
+}
+
+fn format_key(key: VirtualKeyCode) -> String {
+    use azul_core::window::VirtualKeyCode::*;
+    match key {
+        Key0 => "0".into(),
+        Key1 => "1".into(),
+        Key2 => "2".into(),
+        Key3 => "3".into(),
+        Key4 => "4".into(),
+        Key5 => "5".into(),
+        Key6 => "6".into(),
+        Key7 => "7".into(),
+        Key8 => "8".into(),
+        Key9 => "9".into(),
+        Escape => "Esc".into(),
+        Back => "Backspace".into(),
+        Return => "Enter".into(),
+        Delete => "Del".into(),
+        Insert => "Ins".into(),
+        PageUp => "PgUp".into(),
+        PageDown => "PgDn".into(),
+        Plus | NumpadAdd => "+".into(),
+        Minus | NumpadSubtract => "-".into(),
+        Comma => ",".into(),
+        Period => ".".into(),
+        Slash => "/".into(),
+        other => format!("{:?}", other),
+    }
+}
+
+/// Marshal data of the `menu_popup_layout` callback
+#[derive(Debug)]
+struct MenuPopup {
+    items: MenuItemVec,
+    selected: Option<usize>,
+}
+
+/// Updates the selected item of a popup window, returns whether
+/// the selection changed (and the DOM has to be regenerated)
+pub(super) fn set_popup_selection(
+    layout_callback: &mut LayoutCallback,
+    selected: Option<usize>,
+) -> bool {
+    let data = match layout_callback {
+        LayoutCallback::Marshaled(m) if m.cb.cb as usize == menu_popup_layout as usize => {
+            &mut m.marshal_data
+        }
+        _ => return false,
+    };
+    let mut popup = match data.downcast_mut::<MenuPopup>() {
+        Some(s) => s,
+        None => return false,
+    };
+    let changed = popup.selected != selected;
+    popup.selected = selected;
+    changed
+}
+
+extern "C" fn menu_popup_layout(
+    marshal_data: &mut RefAny,
+    _: &mut RefAny,
+    _: &mut LayoutCallbackInfo,
+) -> StyledDom {
+    let popup = match marshal_data.downcast_ref::<MenuPopup>() {
+        Some(s) => s,
+        None => return StyledDom::default(),
+    };
+
+    let rows = popup
+        .items
+        .as_ref()
+        .iter()
+        .enumerate()
+        .filter_map(|(i, item)| match item {
+            MenuItem::String(s) => Some(render_popup_item(s, popup.selected == Some(i))),
+            MenuItem::Separator => {
+                Some(Dom::div().with_ids_and_classes(class(&["__azul-native-menu-separator"])))
+            }
+            MenuItem::BreakLine => None,
+        })
+        .collect::<Vec<_>>();
+
+    Dom::body()
+        .with_children(rows.into())
+        .style(CssApiWrapper::from_string(get_popup_css().into()))
+}
+
+fn render_popup_item(item: &azul_core::window::StringMenuItem, is_selected: bool) -> Dom {
+    let icon = match item.icon.as_ref() {
+        Some(MenuItemIcon::Checkbox(true)) => Dom::text("\u{2713}"),
+        Some(MenuItemIcon::Image(image)) => {
+            Dom::image(image.clone()).with_ids_and_classes(class(&["__azul-native-menu-image"]))
+        }
+        _ => Dom::div(),
+    };
+
+    let accelerator = item
+        .accelerator
+        .as_ref()
+        .map(|a| Dom::text(format_accelerator(a).as_str()))
+        .unwrap_or_else(Dom::div);
+
+    let arrow = if item.children.as_ref().is_empty() {
+        Dom::div()
+    } else {
+        Dom::text("\u{25b8}")
+    };
+
+    let mut classes = vec!["__azul-native-menu-item"];
+    if is_selected {
+        classes.push("__azul-native-menu-item-selected");
+    }
+    if item.state == MenuItemState::Greyed {
+        classes.push("__azul-native-menu-item-greyed");
+    }
+
+    Dom::div()
+        .with_ids_and_classes(class(&classes))
+        .with_children(
+            vec![
+                Dom::div()
+                    .with_ids_and_classes(class(&["__azul-native-menu-icon"]))
+                    .with_children(vec![icon].into()),
+                Dom::div()
+                    .with_ids_and_classes(class(&["__azul-native-menu-label"]))
+                    .with_children(vec![Dom::text(item.label.as_str())].into()),
+                Dom::div()
+                    .with_ids_and_classes(class(&[if is_selected {
+                        "__azul-native-menu-accelerator-selected"
+                    } else {
+                        "__azul-native-menu-accelerator"
+                    }]))
+                    .with_children(vec![accelerator].into()),
+                Dom::div()
+                    .with_ids_and_classes(class(&["__azul-native-menu-arrow"]))
+                    .with_children(vec![arrow].into()),
+            ]
+            .into(),
+        )
+}
+
+fn get_popup_css() -> String {
+    format!(
+        "
+        body {{
+            flex-direction: column;
+            padding-top: {padding}px;
+            padding-bottom: {padding}px;
+            background: #ffffff;
+            border: {border}px solid #bfbfbf;
+            color: #2e3436;
+            font-family: sans-serif;
+            font-size: 13px;
+        }}
+        .__azul-native-menu-item {{ height: {item}px; flex-direction: row; align-items: center; }}
+        .__azul-native-menu-item-selected {{ background: #3584e4; color: #ffffff; }}
+        .__azul-native-menu-item-greyed {{ color: #a4a7a7; }}
+        .__azul-native-menu-icon {{ width: {icon}px; justify-content: center; align-items: center; }}
+        .__azul-native-menu-image {{ width: 16px; height: 16px; }}
+        .__azul-native-menu-label {{ flex-grow: 1; }}
+        .__azul-native-menu-accelerator {{ padding-left: {gap}px; color: #7f8282; }}
+        .__azul-native-menu-accelerator-selected {{ padding-left: {gap}px; color: #ffffff; }}
+        .__azul-native-menu-arrow {{ width: {arrow}px; justify-content: center; align-items: center; }}
+        .__azul-native-menu-separator {{ height: 1px; margin-top: {separator_margin}px; margin-bottom: {separator_margin}px; background: #d9d9d9; }}
+        ",
+        padding = POPUP_PADDING,
+        border = POPUP_BORDER,
+        item = ITEM_HEIGHT,
+        icon = ICON_COLUMN_WIDTH,
+        gap = ACCELERATOR_GAP,
+        arrow = ARROW_COLUMN_WIDTH,
+        separator_margin = (SEPARATOR_HEIGHT - 1.0) / 2.0,
+    )
+}
+
+/// Marshal data of the `menu_bar_layout` callback
+#[derive(Debug)]
+struct MenuBar {
+    /// Index of the menu bar item whose drop-down menu is open
+    open_item: Option<usize>,
+    /// Original layout callback of the window
+    inner: LayoutCallback,
+}
+
+/// Wraps the layout callback of the window in `menu_bar_layout`, which renders the
+/// menu bar of the root node (if there is one) above the user DOM. Does nothing if
+/// the callback is already wrapped.
+pub(super) fn add_menu_bar(layout_callback: &mut LayoutCallback) {
+    if get_menu_bar_data(layout_callback).is_some() {
+        return;
+    }
+
+    *layout_callback = LayoutCallback::Marshaled(MarshaledLayoutCallback {
+        marshal_data: RefAny::new(MenuBar {
+            open_item: None,
+            inner: layout_callback.clone(),
+        }),
+        cb: MarshaledLayoutCallbackInner {
+            cb: menu_bar_layout,
+        },
+    });
+}
+
+/// Highlights the menu bar item whose drop-down menu is open, returns
+/// whether it changed (and the DOM of the window has to be regenerated)
+pub(super) fn set_menu_bar_open_item(
+    layout_callback: &mut LayoutCallback,
+    open_item: Option<usize>,
+) -> bool {
+    let data = match get_menu_bar_data(layout_callback) {
+        Some(s) => s,
+        None => return false,
+    };
+    let mut menu_bar = match data.downcast_mut::<MenuBar>() {
+        Some(s) => s,
+        None => return false,
+    };
+    let changed = menu_bar.open_item != open_item;
+    menu_bar.open_item = open_item;
+    changed
+}
+
+fn get_menu_bar_data(layout_callback: &mut LayoutCallback) -> Option<&mut RefAny> {
+    match layout_callback {
+        LayoutCallback::Marshaled(m) if m.cb.cb as usize == menu_bar_layout as usize => {
+            Some(&mut m.marshal_data)
+        }
+        _ => None,
+    }
+}
+
+fn get_menu_bar_item_width(item: &MenuItem) -> Option<f32> {
+    match item {
+        MenuItem::String(s) => {
+            Some(s.label.as_str().chars().count() as f32 * CHAR_WIDTH + 2.0 * MENU_BAR_ITEM_PADDING)
+        }
+        _ => None,
+    }
+}
+
+/// Returns the horizontal offset and width of the menu bar item
+pub(super) fn get_menu_bar_item_rect(menu: &Menu, index: usize) -> Option<(f32, f32)> {
+    let mut x = 0.0;
+    for (i, item) in menu.items.as_ref().iter().enumerate() {
+        let width = match get_menu_bar_item_width(item) {
+            Some(s) => s,
+            None => continue,
+        };
+        if i == index {
+            return Some((x, width));
+        }
+        x += width;
+    }
+    None
+}
+
+/// Returns the menu bar item at the position (relative to the window)
+pub(super) fn get_menu_bar_item_at(menu: &Menu, position: LogicalPosition) -> Option<usize> {
+    if position.y < 0.0 || position.y >= MENU_BAR_HEIGHT {
+        return None;
+    }
+    (0..menu.items.as_ref().len()).find(|i| {
+        get_menu_bar_item_rect(menu, *i)
+            .map(|(x, width)| position.x >= x && position.x < x + width)
+            .unwrap_or(false)
+    })
+}
+
+/// Returns the index of the menu bar item `direction` items away from `current`
+/// (wrapping around), skipping separators and disabled items
+pub(super) fn get_adjacent_menu_bar_item(
+    menu: &Menu,
+    current: usize,
+    direction: isize,
+) -> Option<usize> {
+    get_next_item(menu.items.as_ref(), Some(current), direction > 0).filter(|i| *i != current)
+}
+
+extern "C" fn menu_bar_layout(
+    marshal_data: &mut RefAny,
+    app_data: &mut RefAny,
+    info: &mut LayoutCallbackInfo,
+) -> StyledDom {
+    let mut menu_bar = match marshal_data.downcast_mut::<MenuBar>() {
+        Some(s) => s,
+        None => return StyledDom::default(),
+    };
+
+    let user_dom = match &mut menu_bar.inner {
+        LayoutCallback::Raw(r) => (r.cb)(app_data, info),
+        LayoutCallback::Marshaled(m) => (m.cb.cb)(&mut m.marshal_data, app_data, info),
+    };
+
+    let menu = match user_dom
+        .node_data
+        .as_container()
+        .get(NodeId::ZERO)
+        .and_then(|n| n.get_menu_bar())
+    {
+        Some(s) => (**s).clone(),
+        None => return user_dom,
+    };
+
+    let mut styled_dom = render_menu_bar(&menu, menu_bar.open_item);
+    let user_root = NodeId::new(styled_dom.node_count());
+    styled_dom.append_child(user_dom);
+
+    // let the user DOM fill the remaining space below the menu bar
+    let _ = styled_dom.restyle_user_property(
+        &user_root,
+        &[CssProperty::flex_grow(LayoutFlexGrow {
+            inner: FloatValue::const_new(1),
+        })],
+    );
+
+    // keep the menu on the root node, so that the shell
+    // can still find it with WindowInternal::get_menu_bar()
+    styled_dom.node_data.as_container_mut()[NodeId::ZERO].set_menu_bar(menu);
+
+    styled_dom
+}
+
+fn render_menu_bar(menu: &Menu, open_item: Option<usize>) -> StyledDom {
+    let items = menu
+        .items
+        .as_ref()
+        .iter()
+        .enumerate()
+        .filter_map(|(i, item)| {
+            let (label, state) = match item {
+                MenuItem::String(s) => (s.label.as_str(), s.state),
+                _ => return None,
+            };
+            let width = get_menu_bar_item_width(item)?;
+            let mut classes = vec!["__azul-native-menubar-item"];
+            if open_item == Some(i) {
+                classes.push("__azul-native-menubar-item-open");
+            }
+            if state == MenuItemState::Greyed {
+                classes.push("__azul-native-menu-item-greyed");
+            }
+            Some(
+                Dom::div()
+                    .with_ids_and_classes(class(&classes))
+                    .with_inline_style(&format!("width: {}px;", width))
+                    .with_children(vec![Dom::text(label)].into()),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let bar = Dom::div()
+        .with_ids_and_classes(class(&["__azul-native-menubar"]))
+        .with_children(items.into());
+
+    Dom::body()
+        .with_children(vec![bar].into())
+        .style(CssApiWrapper::from_string(get_menu_bar_css().into()))
+}
+
+fn get_menu_bar_css() -> String {
+    format!(
+        "
+        body {{ flex-direction: column; }}
+        .__azul-native-menubar {{
+            height: {height}px;
+            flex-direction: row;
+            background: #f6f5f4;
+            border-bottom: 1px solid #cdc7c2;
+            color: #2e3436;
+            font-family: sans-serif;
+            font-size: 13px;
+        }}
+        .__azul-native-menubar-item {{ justify-content: center; align-items: center; }}
+        .__azul-native-menubar-item:hover {{ background: #e8e6e3; }}
+        .__azul-native-menubar-item-open {{ background: #3584e4; color: #ffffff; }}
+        .__azul-native-menu-item-greyed {{ color: #a4a7a7; }}
+        ",
+        height = MENU_BAR_HEIGHT,
+    )
+}
+
+fn class(names: &[&str]) -> IdOrClassVec {
+    names
+        .iter()
+        .map(|n| IdOrClass::Class((*n).into()))
+        .collect::<Vec<_>>()
+        .into()
+}
+
+type XDefaultScreenFuncType = extern "C" fn(*mut Display) -> c_int;
+type XDisplayWidthFuncType = extern "C" fn(*mut Display, c_int) -> c_int;
+type XDisplayHeightFuncType = extern "C" fn(*mut Display, c_int) -> c_int;
+
+// Xlib functions used for positioning the popup windows
+struct MenuXlib {
+    pub library: Library,
+    pub XDefaultScreen: XDefaultScreenFuncType,
+    pub XDisplayWidth: XDisplayWidthFuncType,
+    pub XDisplayHeight: XDisplayHeightFuncType,
+}
+
+impl MenuXlib {
+    fn new() -> Option<Self> {
+        use super::clipboard::load_function;
+
+        let library = Library::load("libX11.so").ok()?;
+        Some(Self {
+            XDefaultScreen: load_function(&library, "XDefaultScreen")?,
+            XDisplayWidth: load_function(&library, "XDisplayWidth")?,
+            XDisplayHeight: load_function(&library, "XDisplayHeight")?,
+            library,
+        })
+    }
+}
+
+static MENU_XLIB: Lazy<Option<MenuXlib>> = Lazy::new(MenuXlib::new);
+
+/// Returns the size of the default screen of the display
+pub(super) fn get_screen_rect(display: *mut Display) -> ScreenRect {
+    let xlib = match MENU_XLIB.as_ref() {
+        Some(s) => s,
+        None => return ScreenRect::new(0, 0, i32::MAX / 2, i32::MAX / 2),
+    };
+    let screen = (xlib.XDefaultScreen)(display);
+    ScreenRect::new(
+        0,
+        0,
+        (xlib.XDisplayWidth)(display, screen),
+        (xlib.XDisplayHeight)(display, screen),
+    )
+}
+
+#[test]
+fn test_menu_keyboard_navigation() {
+    use azul_core::callbacks::{CallbackInfo, Update};
+    use azul_core::styled_dom::{DomId, NodeHierarchyItemId};
+    use azul_core::window::StringMenuItem;
+
+    extern "C" fn do_nothing(_: &mut RefAny, _: &mut CallbackInfo) -> Update {
+        Update::DoNothing
+    }
+
+    let item = |label: &str| StringMenuItem::new(String::from(label).into());
+    let mut disabled = item("Disabled");
+    disabled.state = MenuItemState::Greyed;
+    let mut open = item("Open").with_callback(RefAny::new(0_usize), do_nothing);
+    open.accelerator = Some(VirtualKeyCodeCombo {
+        keys: vec![VirtualKeyCode::LControl, VirtualKeyCode::O].into(),
+    })
+    .into();
+    let recent = item("Recent").with_children(vec![MenuItem::String(item("a.txt"))].into());
+
+    let items: MenuItemVec = vec![
+        MenuItem::String(open.clone()),
+        MenuItem::Separator,
+        MenuItem::String(disabled),
+        MenuItem::String(recent),
+    ]
+    .into();
+
+    let root = DomNodeId {
+        dom: DomId::ROOT_ID,
+        node: NodeHierarchyItemId::from_crate_internal(None),
+    };
+    let screen = ScreenRect::new(0, 0, 1920, 1080);
+    let mut menu = OpenMenu::new(
+        items,
+        MenuPopupPosition::AutoCursor,
+        (100, 100),
+        ScreenRect::new(100, 100, 0, 0),
+        screen,
+        root,
+        None,
+    );
+    menu.levels[0].window = Some(1);
+
+    assert_eq!(
+        format_accelerator(open.accelerator.as_ref().unwrap()),
+        "Ctrl+O"
+    );
+    assert_eq!((menu.levels[0].rect.x, menu.levels[0].rect.y), (100, 100));
+
+    // the separator and the disabled item are skipped
+    assert_eq!(
+        menu.handle_input(MenuInput::Key(VirtualKeyCode::Down)),
+        vec![MenuCommand::Update(0)]
+    );
+    assert_eq!(menu.levels[0].selected, Some(0));
+    assert_eq!(
+        menu.handle_input(MenuInput::Key(VirtualKeyCode::Down)),
+        vec![MenuCommand::Update(0), MenuCommand::Open(1)]
+    );
+    assert_eq!(menu.levels[0].selected, Some(3));
+    menu.levels[1].window = Some(2);
+
+    // the sub-menu opens to the right of its parent item
+    let item_y = menu.levels[0].rect.y + get_item_offset(menu.levels[0].items.as_ref(), 3) as i32;
+    assert_eq!(menu.levels[1].rect.x, 100 + menu.levels[0].rect.width);
+    assert_eq!(
+        menu.levels[1].rect.y,
+        item_y - (POPUP_PADDING + POPUP_BORDER) as i32
+    );
+
+    assert_eq!(
+        menu.handle_input(MenuInput::Key(VirtualKeyCode::Left)),
+        vec![MenuCommand::Close(vec![2]), MenuCommand::Update(0)]
+    );
+
+    // hovering over the first item and clicking it invokes its callback
+    let y = get_item_offset(menu.levels[0].items.as_ref(), 0) + 1.0;
+    assert_eq!(get_item_at(menu.levels[0].items.as_ref(), y), Some(0));
+    let commands = menu.handle_input(MenuInput::Click { window: 1, y });
+    assert_eq!(commands.len(), 3);
+    assert_eq!(commands[1], MenuCommand::Close(vec![1]));
+    assert!(matches!(commands[2], MenuCommand::Invoke(_, _)));
+    assert!(menu.is_closed());
+}
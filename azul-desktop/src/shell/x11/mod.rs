@@ -1,3 +1,22 @@
+pub(crate) mod clipboard;
+pub(crate) mod cursor;
+pub(crate) mod dbus;
+mod dnd;
+pub(super) mod event;
+mod frame;
+mod ime;
+#[cfg(feature = "css_parser")]
+mod menu;
+mod monitor;
+mod moveresize;
+pub(crate) mod notify;
+mod owner;
+pub(crate) mod portal;
+mod shortcuts;
+mod touch;
+mod transparency;
+pub(crate) mod tray;
+
 use crate::{
     app::{App, LazyFcCache},
     gl::{c_char, c_int, c_long, c_uchar, c_uint, c_ulong, c_ushort},
@@ -16,21 +35,25 @@ use azul_core::{
         AppConfig, Epoch, GlTextureCache, ImageCache, ImageMask, ImageRef, RendererResources,
         ResourceUpdate,
     },
-    callbacks::{DocumentId, DomNodeId, RefAny, UpdateImageType},
+    callbacks::{DocumentId, DomNodeId, LayoutCallback, RefAny, UpdateImageType},
     display_list::RenderCallbacks,
     dom::NodeId,
+    gesture::TouchEvent,
     gl::OptionGlContextPtr,
     styled_dom::DomId,
-    task::{Thread, ThreadId, Timer, TimerId},
+    task::{GetSystemTimeCallback, Thread, ThreadId, Timer, TimerId},
     ui_solver::LayoutResult,
     window::{
-        CallCallbacksResult, FullWindowState, LogicalSize, Menu, MenuCallback, MenuItem,
-        MonitorVec, MouseCursorType, ScrollResult, WindowCreateOptions, WindowInternal,
-        WindowState,
+        CallCallbacksResult, CursorPosition, FullWindowState, ImePosition, LogicalSize, Menu,
+        MenuCallback, MenuItem, MonitorVec, MouseCursorType, PersistedWindowState,
+        PhysicalPositionI32, ScanCode, ScrollResult, ShortcutChange, ShortcutRegistry,
+        VirtualKeyCode, WindowCreateOptions, WindowId, WindowInternal, WindowPosition,
+        WindowResultCallback, WindowState,
     },
     window_state::NodesToCheck,
     FastBTreeSet, FastHashMap,
 };
+use azul_css::AzString;
 use core::{
     cell::{BorrowError, BorrowMutError, RefCell},
     convert::TryInto,
@@ -60,6 +83,9 @@ use webrender::{
 // TODO: Cache compiled shaders between renderers
 const WR_SHADER_CACHE: Option<&Rc<RefCell<WrShaders>>> = None;
 
+// how often the messages of running threads are polled (same as on Windows)
+const THREAD_POLL_INTERVAL_MS: c_int = 16;
+
 extern "C" {
     // syscalls
     fn dlopen(filename: *const raw::c_char, flags: raw::c_int) -> *mut raw::c_void;
@@ -71,6 +97,7 @@ extern "C" {
 #[derive(Debug)]
 pub enum LinuxWindowCreateError {
     X(String),
+    Wayland(String),
     Egl(String),
     NoGlContext,
     Renderer(WrRendererError),
@@ -105,36 +132,47 @@ impl From<LinuxWindowCreateError> for LinuxStartupError {
 }
 
 pub fn get_monitors(app: &App) -> MonitorVec {
-    MonitorVec::from_const_slice(&[]) // TODO
+    let xlib = match Xlib::new() {
+        Ok(o) => Rc::new(o),
+        Err(_) => return MonitorVec::from_const_slice(&[]),
+    };
+    let dpy = match X11Display::open(xlib) {
+        Some(s) => s,
+        None => return MonitorVec::from_const_slice(&[]),
+    };
+    monitor::get_monitors(dpy.display, || dpy.get_dpi_scale_factor())
 }
 
 // Minimal typedefs from <EGL/egl.h>
 
-type EGLDisplay = *mut c_void;
-type EGLNativeDisplayType = *mut c_void;
-type EGLNativeWindowType = *mut c_void;
-type EGLint = i32;
-type EGLBoolean = u32;
-type EGLenum = u32;
-type EGLConfig = *mut c_void;
-type EGLContext = *mut c_void;
-type EGLSurface = *mut c_void;
-
-type eglGetDisplayFuncType = extern "C" fn(EGLNativeDisplayType) -> EGLDisplay;
-type eglInitializeFuncType = extern "C" fn(EGLDisplay, *mut EGLint, *mut EGLint) -> EGLBoolean;
-type eglBindAPIFuncType = extern "C" fn(EGLenum) -> EGLBoolean;
-type eglChooseConfigFuncType =
+pub(super) type EGLDisplay = *mut c_void;
+pub(super) type EGLNativeDisplayType = *mut c_void;
+pub(super) type EGLNativeWindowType = *mut c_void;
+pub(super) type EGLint = i32;
+pub(super) type EGLBoolean = u32;
+pub(super) type EGLenum = u32;
+pub(super) type EGLConfig = *mut c_void;
+pub(super) type EGLContext = *mut c_void;
+pub(super) type EGLSurface = *mut c_void;
+
+pub(super) type eglGetDisplayFuncType = extern "C" fn(EGLNativeDisplayType) -> EGLDisplay;
+pub(super) type eglInitializeFuncType =
+    extern "C" fn(EGLDisplay, *mut EGLint, *mut EGLint) -> EGLBoolean;
+pub(super) type eglBindAPIFuncType = extern "C" fn(EGLenum) -> EGLBoolean;
+pub(super) type eglChooseConfigFuncType =
     extern "C" fn(EGLDisplay, *const EGLint, *mut EGLConfig, EGLint, *mut EGLint) -> EGLBoolean;
-type eglCreateWindowSurfaceFuncType =
+pub(super) type eglGetConfigAttribFuncType =
+    extern "C" fn(EGLDisplay, EGLConfig, EGLint, *mut EGLint) -> EGLBoolean;
+pub(super) type eglCreateWindowSurfaceFuncType =
     extern "C" fn(EGLDisplay, EGLConfig, EGLNativeWindowType, *const EGLint) -> EGLSurface;
-type eglSwapIntervalFuncType = extern "C" fn(EGLDisplay, EGLint) -> EGLBoolean;
-type eglCreateContextFuncType =
+pub(super) type eglSwapIntervalFuncType = extern "C" fn(EGLDisplay, EGLint) -> EGLBoolean;
+pub(super) type eglCreateContextFuncType =
     extern "C" fn(EGLDisplay, EGLConfig, EGLContext, *const EGLint) -> EGLContext;
-type eglMakeCurrentFuncType =
+pub(super) type eglMakeCurrentFuncType =
     extern "C" fn(EGLDisplay, EGLSurface, EGLSurface, EGLContext) -> EGLBoolean;
-type eglSwapBuffersFuncType = extern "C" fn(EGLDisplay, EGLSurface) -> EGLBoolean;
-type eglGetErrorFuncType = extern "C" fn() -> EGLint;
-type eglGetProcAddressFuncType = extern "C" fn(*const c_char) -> *mut raw::c_void;
+pub(super) type eglSwapBuffersFuncType = extern "C" fn(EGLDisplay, EGLSurface) -> EGLBoolean;
+pub(super) type eglGetErrorFuncType = extern "C" fn() -> EGLint;
+pub(super) type eglGetProcAddressFuncType = extern "C" fn(*const c_char) -> *mut raw::c_void;
 
 type XDefaultScreenFuncType = extern "C" fn(*mut Display) -> c_int;
 type XRootWindowFuncType = extern "C" fn(*mut Display, c_int) -> c_ulong;
@@ -160,36 +198,43 @@ type XOpenDisplayFuncType = extern "C" fn(*const c_char) -> *mut Display;
 type XCloseDisplayFuncType = extern "C" fn(*mut Display) -> c_int;
 type XPendingFuncType = extern "C" fn(*mut Display) -> c_int;
 type XNextEventFuncType = extern "C" fn(*mut Display, *mut XEvent) -> c_int;
+type XConnectionNumberFuncType = extern "C" fn(*mut Display) -> c_int;
 type XSelectInputFuncType = extern "C" fn(_: *mut Display, _: c_ulong, _: c_long) -> c_int;
-
-const EGL_NO_DISPLAY: EGLDisplay = 0 as *mut c_void;
-const EGL_OPENGL_API: EGLenum = 0x30A2;
-const EGL_SURFACE_TYPE: EGLint = 0x3033;
-const EGL_WINDOW_BIT: EGLint = 0x0004;
-const EGL_CONFORMANT: EGLint = 0x3042;
-const EGL_OPENGL_BIT: EGLint = 0x0008;
-const EGL_RENDERABLE_TYPE: EGLint = 0x3040;
-const EGL_COLOR_BUFFER_TYPE: EGLint = 0x303F;
-const EGL_RGB_BUFFER: EGLint = 0x308E;
-const EGL_BLUE_SIZE: EGLint = 0x3022;
-const EGL_GREEN_SIZE: EGLint = 0x3023;
-const EGL_RED_SIZE: EGLint = 0x3024;
-const EGL_DEPTH_SIZE: EGLint = 0x3025;
-const EGL_STENCIL_SIZE: EGLint = 0x3026;
-const EGL_NONE: EGLint = 0x3038;
-const EGL_GL_COLORSPACE: EGLint = 0x3087;
-const EGL_GL_COLORSPACE_LINEAR: EGLint = 0x308A;
-const EGL_RENDER_BUFFER: EGLint = 0x3086;
-const EGL_BACK_BUFFER: EGLint = 0x3084;
-const EGL_NO_SURFACE: EGLSurface = 0 as *mut c_void;
-const EGL_NO_CONTEXT: EGLContext = 0 as *mut c_void;
-const EGL_FALSE: EGLBoolean = 0;
-const EGL_TRUE: EGLBoolean = 1;
-
-const EGL_CONTEXT_MAJOR_VERSION: EGLint = 0x00003098;
-const EGL_CONTEXT_MINOR_VERSION: EGLint = 0x000030fb;
-const EGL_CONTEXT_OPENGL_PROFILE_MASK: EGLint = 0x000030fd;
-const EGL_CONTEXT_OPENGL_CORE_PROFILE_BIT: EGLint = 0x00000001;
+type XLookupKeysymFuncType = extern "C" fn(*mut XKeyEvent, c_int) -> c_ulong;
+type XLookupStringFuncType =
+    extern "C" fn(*mut XKeyEvent, *mut c_char, c_int, *mut c_ulong, *mut c_void) -> c_int;
+type XClearAreaFuncType =
+    extern "C" fn(*mut Display, c_ulong, c_int, c_int, c_uint, c_uint, X11Bool) -> c_int;
+
+pub(super) const EGL_NO_DISPLAY: EGLDisplay = 0 as *mut c_void;
+pub(super) const EGL_OPENGL_API: EGLenum = 0x30A2;
+pub(super) const EGL_SURFACE_TYPE: EGLint = 0x3033;
+pub(super) const EGL_WINDOW_BIT: EGLint = 0x0004;
+pub(super) const EGL_CONFORMANT: EGLint = 0x3042;
+pub(super) const EGL_OPENGL_BIT: EGLint = 0x0008;
+pub(super) const EGL_RENDERABLE_TYPE: EGLint = 0x3040;
+pub(super) const EGL_COLOR_BUFFER_TYPE: EGLint = 0x303F;
+pub(super) const EGL_RGB_BUFFER: EGLint = 0x308E;
+pub(super) const EGL_BLUE_SIZE: EGLint = 0x3022;
+pub(super) const EGL_GREEN_SIZE: EGLint = 0x3023;
+pub(super) const EGL_RED_SIZE: EGLint = 0x3024;
+pub(super) const EGL_ALPHA_SIZE: EGLint = 0x3021;
+pub(super) const EGL_DEPTH_SIZE: EGLint = 0x3025;
+pub(super) const EGL_STENCIL_SIZE: EGLint = 0x3026;
+pub(super) const EGL_NONE: EGLint = 0x3038;
+pub(super) const EGL_GL_COLORSPACE: EGLint = 0x3087;
+pub(super) const EGL_GL_COLORSPACE_LINEAR: EGLint = 0x308A;
+pub(super) const EGL_RENDER_BUFFER: EGLint = 0x3086;
+pub(super) const EGL_BACK_BUFFER: EGLint = 0x3084;
+pub(super) const EGL_NO_SURFACE: EGLSurface = 0 as *mut c_void;
+pub(super) const EGL_NO_CONTEXT: EGLContext = 0 as *mut c_void;
+pub(super) const EGL_FALSE: EGLBoolean = 0;
+pub(super) const EGL_TRUE: EGLBoolean = 1;
+
+pub(super) const EGL_CONTEXT_MAJOR_VERSION: EGLint = 0x00003098;
+pub(super) const EGL_CONTEXT_MINOR_VERSION: EGLint = 0x000030fb;
+pub(super) const EGL_CONTEXT_OPENGL_PROFILE_MASK: EGLint = 0x000030fd;
+pub(super) const EGL_CONTEXT_OPENGL_CORE_PROFILE_BIT: EGLint = 0x00000001;
 
 const WM_PROTOCOLS: u64 = 0;
 
@@ -917,7 +962,11 @@ struct XVisibilityEvent {
 
 const X11_INPUT_OUTPUT: c_int = 1;
 const X11_COPY_FROM_PARENT: c_int = 0;
+const X11_CW_BACK_PIXEL: c_ulong = 0x0002;
+const X11_CW_BORDER_PIXEL: c_ulong = 0x0008;
+const X11_CW_OVERRIDE_REDIRECT: c_ulong = 0x0200;
 const X11_CW_EVENT_MASK: c_ulong = 0x0800;
+const X11_CW_COLORMAP: c_ulong = 0x2000;
 const X11_STRUCTURE_NOTIFY_MASK: c_long = 0x0002_0000;
 const X11_EXPOSURE_MASK: c_long = 0x0000_8000;
 const X11_RESIZE_REDIRECT_MASK: c_long = 0x0004_0000;
@@ -926,12 +975,29 @@ const X11_KEY_RELEASE_MASK: c_long = 0x0000_0002;
 const X11_POINTER_MOTION_MASK: c_long = 0x0000_0040;
 const X11_BUTTON_PRESS_MASK: c_long = 0x0000_0004;
 const X11_BUTTON_RELEASE_MASK: c_long = 0x0000_0008;
+const X11_ENTER_WINDOW_MASK: c_long = 0x0000_0010;
+const X11_LEAVE_WINDOW_MASK: c_long = 0x0000_0020;
+const X11_FOCUS_CHANGE_MASK: c_long = 0x0020_0000;
 
 const X11_FALSE: X11Bool = 0;
-
+const X11_TRUE: X11Bool = 1;
+
+const X11_KEY_PRESS: c_int = 2;
+const X11_KEY_RELEASE: c_int = 3;
+const X11_BUTTON_PRESS: c_int = 4;
+const X11_BUTTON_RELEASE: c_int = 5;
+const X11_MOTION_NOTIFY: c_int = 6;
+const X11_ENTER_NOTIFY: c_int = 7;
+const X11_LEAVE_NOTIFY: c_int = 8;
+const X11_FOCUS_IN: c_int = 9;
+const X11_FOCUS_OUT: c_int = 10;
 const X11_EXPOSE: c_int = 12;
+const X11_CONFIGURE_NOTIFY: c_int = 22;
 const X11_RESIZE_REQUEST: c_int = 25;
+const X11_SELECTION_REQUEST: c_int = 30;
+const X11_SELECTION_NOTIFY: c_int = 31;
 const X11_CLIENT_MESSAGE: c_int = 33;
+const X11_GENERIC_EVENT: c_int = 35;
 
 type X11Bool = c_int;
 type XID = c_ulong;
@@ -969,6 +1035,7 @@ pub fn run(app: App, mut root_window: WindowCreateOptions) -> Result<isize, Linu
         mut windows,
         image_cache,
         fc_cache,
+        shortcuts,
     } = app;
 
     let xlib = Rc::new(Xlib::new()?);
@@ -981,8 +1048,15 @@ pub fn run(app: App, mut root_window: WindowCreateOptions) -> Result<isize, Linu
         config,
         image_cache,
         fc_cache,
+        shortcuts,
     }));
 
+    // grabs the shortcuts with ShortcutScope::Global
+    let mut global_shortcuts = shortcuts::GlobalShortcuts::new();
+    if let Some(g) = global_shortcuts.as_mut() {
+        g.sync(&app_data_inner.borrow().shortcuts);
+    }
+
     for options in windows.iter_mut() {
         let mut window = X11Window::new(
             xlib.clone(),
@@ -1011,14 +1085,81 @@ pub fn run(app: App, mut root_window: WindowCreateOptions) -> Result<isize, Linu
 
     loop {
         let mut windows_to_close = Vec::new();
+        let mut new_windows = Vec::new();
+        let mut regenerate_all_windows = false;
+        // windows in which F12 was pressed (AppConfig::enable_inspector)
+        let mut inspector_requests = Vec::new();
+        // input for the open menus, handled after the events of all windows have
+        // been processed, since it creates and destroys popup windows
+        #[cfg(feature = "css_parser")]
+        let mut menu_inputs = Vec::new();
+
+        let get_system_time_fn = app_data_inner
+            .borrow()
+            .config
+            .system_callbacks
+            .get_system_time_fn;
+        wait_for_events(
+            &xlib,
+            &mut active_windows,
+            global_shortcuts.as_ref(),
+            get_system_time_fn,
+        );
+
+        // windows with an open modal child window don't receive input
+        let modal_children = get_modal_children(&active_windows);
 
         for (window_id, window) in active_windows.iter_mut() {
-            // blocks until next event
+            if (xlib.XPending)(window.dpy.get()) == 0 {
+                continue;
+            }
+
             unsafe { (xlib.XNextEvent)(window.dpy.get(), &mut cur_xevent) };
 
             let cur_event_type = cur_xevent.get_type();
 
+            // the input method consumes the key presses that are part of a composition
+            let filtered_by_ime = window
+                .xim
+                .as_mut()
+                .map(|xim| xim.filter_event(&mut cur_xevent))
+                .unwrap_or(false);
+
+            // set by input events: whether the window state changed and
+            // whether the cursor moved (so that a new hit-test is necessary)
+            let mut needs_processing = false;
+            let mut needs_hit_test = false;
+            // root window position of a mouse button press, to open the context menu
+            // at (the context menu is only known after the hit-test)
+            #[cfg(feature = "css_parser")]
+            let mut context_menu_position = None;
+            // callbacks of the menu bar items whose accelerator was pressed
+            let mut accelerator_callbacks = Vec::new();
+            // set if the window moved or the monitor configuration changed
+            let mut monitor_may_have_changed = false;
+
             match cur_event_type {
+                // handled by the preedit callbacks (see below)
+                _ if filtered_by_ime => {}
+                // events of the XEmbed tray icon window
+                _ if window
+                    .tray
+                    .as_mut()
+                    .map(|t| t.handle_x11_event(&cur_xevent))
+                    .unwrap_or(false) => {}
+                // input of a window that has an open modal child window
+                X11_KEY_PRESS | X11_KEY_RELEASE | X11_BUTTON_PRESS | X11_BUTTON_RELEASE
+                | X11_MOTION_NOTIFY
+                    if modal_children.contains_key(&window.internal.id) =>
+                {
+                    if cur_event_type == X11_KEY_PRESS || cur_event_type == X11_BUTTON_PRESS {
+                        if let Some(child) = modal_children.get(&window.internal.id) {
+                            owner::activate(window.dpy.display, *child);
+                        }
+                    }
+                }
+                // left click on a custom title bar or resize handle
+                X11_BUTTON_PRESS if window.start_move_resize(unsafe { &cur_xevent.button }) => {}
                 // window shown
                 X11_EXPOSE => {
                     let expose_data = unsafe { cur_xevent.expose };
@@ -1042,10 +1183,11 @@ pub fn run(app: App, mut root_window: WindowCreateOptions) -> Result<isize, Linu
                         .disable(gl_context_loader::gl::MULTISAMPLE);
 
                     window.gl_functions.functions.viewport(0, 0, width, height);
+                    let clear_alpha = if window.is_transparent { 0.0 } else { 1.0 };
                     window
                         .gl_functions
                         .functions
-                        .clear_color(0.0, 0.0, 0.0, 1.0);
+                        .clear_color(0.0, 0.0, 0.0, clear_alpha);
                     window.gl_functions.functions.clear(
                         gl::COLOR_BUFFER_BIT | gl::DEPTH_BUFFER_BIT | gl::STENCIL_BUFFER_BIT,
                     );
@@ -1109,10 +1251,11 @@ pub fn run(app: App, mut root_window: WindowCreateOptions) -> Result<isize, Linu
                         .disable(gl_context_loader::gl::MULTISAMPLE);
 
                     window.gl_functions.functions.viewport(0, 0, width, height);
+                    let clear_alpha = if window.is_transparent { 0.0 } else { 1.0 };
                     window
                         .gl_functions
                         .functions
-                        .clear_color(0.0, 0.0, 0.0, 1.0);
+                        .clear_color(0.0, 0.0, 0.0, clear_alpha);
                     window.gl_functions.functions.clear(
                         gl::COLOR_BUFFER_BIT | gl::DEPTH_BUFFER_BIT | gl::STENCIL_BUFFER_BIT,
                     );
@@ -1140,46 +1283,850 @@ pub fn run(app: App, mut root_window: WindowCreateOptions) -> Result<isize, Linu
                         ))));
                     }
                 }
-                // window closed
+                // window moved or resized
+                X11_CONFIGURE_NOTIFY => {
+                    // the coordinates of the event are relative to the
+                    // frame of the window manager, not to the root window
+                    let (x, y) = monitor::get_window_origin(window.dpy.display, window.id);
+                    let position = WindowPosition::Initialized(PhysicalPositionI32::new(x, y));
+                    if window.internal.current_window_state.position != position {
+                        window.internal.previous_window_state =
+                            Some(window.internal.current_window_state.clone());
+                        window.internal.current_window_state.position = position;
+                        monitor_may_have_changed = window.internal.may_have_changed_monitor();
+                        needs_processing = true;
+                    }
+                }
+                // window closed or drag-and-drop message
                 X11_CLIENT_MESSAGE => {
                     let xclient_data = unsafe { cur_xevent.client_message };
                     if (xclient_data.data.as_longs().get(0).copied()
                         == Some(window.wm_delete_window_atom))
                     {
                         windows_to_close.push(*window_id);
+                    } else if let Some(xdnd_event) = window
+                        .xdnd
+                        .as_mut()
+                        .and_then(|x| x.handle_client_message(&xclient_data))
+                    {
+                        needs_hit_test = window.apply_xdnd_event(xdnd_event);
+                        needs_processing = true;
+                    }
+                }
+                // data of a drag from another application received
+                X11_SELECTION_NOTIFY => {
+                    let selection_data = unsafe { &cur_xevent.selection };
+                    if let Some(xdnd_event) = window
+                        .xdnd
+                        .as_mut()
+                        .and_then(|x| x.handle_selection_notify(selection_data))
+                    {
+                        needs_hit_test = window.apply_xdnd_event(xdnd_event);
+                        needs_processing = true;
+                    }
+                }
+                // drop target requests the data of an outgoing drag
+                X11_SELECTION_REQUEST => {
+                    let request_data = unsafe { cur_xevent.selection_request };
+                    if let Some(xdnd) = window.xdnd.as_mut() {
+                        xdnd.handle_selection_request(&request_data);
+                    }
+                }
+                // input of a popup menu is handled by the menu of the window that owns it
+                #[cfg(feature = "css_parser")]
+                X11_MOTION_NOTIFY | X11_BUTTON_PRESS | X11_BUTTON_RELEASE
+                    if window.menu_owner.is_some() =>
+                {
+                    let owner = window.menu_owner.unwrap_or_default();
+                    let hidpi_factor = window.internal.current_window_state.size.get_hidpi_factor();
+                    if cur_event_type == X11_MOTION_NOTIFY {
+                        let motion_data = unsafe { cur_xevent.motion };
+                        menu_inputs.push((
+                            owner,
+                            menu::MenuInput::Hover {
+                                window: *window_id,
+                                y: motion_data.y as f32 / hidpi_factor,
+                            },
+                        ));
+                    } else if cur_event_type == X11_BUTTON_RELEASE {
+                        let button_data = unsafe { cur_xevent.button };
+                        if button_data.button <= 3 {
+                            menu_inputs.push((
+                                owner,
+                                menu::MenuInput::Click {
+                                    window: *window_id,
+                                    y: button_data.y as f32 / hidpi_factor,
+                                },
+                            ));
+                        }
+                    }
+                }
+                // the window that owns the open menu keeps the keyboard focus,
+                // so the keys are sent to the menu instead of the window
+                #[cfg(feature = "css_parser")]
+                X11_KEY_PRESS if window.menu.is_some() => {
+                    let mut key_data = unsafe { cur_xevent.key };
+                    let keysym = (xlib.XLookupKeysym)(&mut key_data, 0);
+                    if let Some(vk) = event::keysym_to_virtual_keycode(keysym as event::KeySym) {
+                        menu_inputs.push((*window_id, menu::MenuInput::Key(vk)));
+                    }
+                }
+                // mouse moved
+                X11_MOTION_NOTIFY => {
+                    let motion_data = unsafe { cur_xevent.motion };
+                    window.internal.previous_window_state =
+                        Some(window.internal.current_window_state.clone());
+                    window
+                        .internal
+                        .current_window_state
+                        .mouse_state
+                        .cursor_position = window.get_cursor_position(motion_data.x, motion_data.y);
+                    window.update_outgoing_drag(
+                        motion_data.x_root,
+                        motion_data.y_root,
+                        motion_data.time,
+                    );
+                    // moving the cursor over the menu bar switches the open drop-down menu
+                    #[cfg(feature = "css_parser")]
+                    {
+                        if let Some(position) = window
+                            .internal
+                            .current_window_state
+                            .mouse_state
+                            .cursor_position
+                            .get_position()
+                        {
+                            if let Some(input) = window.get_menu_bar_input(position, false) {
+                                menu_inputs.push((*window_id, input));
+                            }
+                        }
+                    }
+                    needs_hit_test = true;
+                    needs_processing = true;
+                }
+                // mouse button pressed / released or scroll wheel moved
+                X11_BUTTON_PRESS | X11_BUTTON_RELEASE => {
+                    let button_data = unsafe { cur_xevent.button };
+                    let is_down = cur_event_type == X11_BUTTON_PRESS;
+                    let cursor_position = window.get_cursor_position(button_data.x, button_data.y);
+
+                    let mut mouse_state = window.internal.current_window_state.mouse_state.clone();
+                    mouse_state.cursor_position = cursor_position;
+
+                    // the scroll wheel sends a press + release pair for every
+                    // "click" of the wheel, only the press is relevant
+                    match button_data.button {
+                        1 => mouse_state.left_down = is_down,
+                        2 => mouse_state.middle_down = is_down,
+                        3 => mouse_state.right_down = is_down,
+                        4 if is_down => mouse_state.scroll_y = Some(1.0).into(),
+                        5 if is_down => mouse_state.scroll_y = Some(-1.0).into(),
+                        6 if is_down => mouse_state.scroll_x = Some(1.0).into(),
+                        7 if is_down => mouse_state.scroll_x = Some(-1.0).into(),
+                        _ => {}
                     }
+
+                    // clicks on the menu bar open its menus, clicks anywhere
+                    // else close the open menu or open a context menu
+                    #[cfg(feature = "css_parser")]
+                    {
+                        if is_down && button_data.button <= 3 {
+                            let menu_bar_input = cursor_position
+                                .get_position()
+                                .and_then(|p| window.get_menu_bar_input(p, true));
+                            match menu_bar_input {
+                                Some(input) => menu_inputs.push((*window_id, input)),
+                                None => {
+                                    if window.menu.is_some() {
+                                        menu_inputs.push((*window_id, menu::MenuInput::Close));
+                                    }
+                                    context_menu_position =
+                                        Some((button_data.x_root, button_data.y_root));
+                                }
+                            }
+                        }
+                    }
+
+                    // internal drag released over another application
+                    if !is_down && button_data.button == 1 {
+                        if let Some(xdnd) = window.xdnd.as_mut() {
+                            xdnd.source_release(button_data.time);
+                        }
+                    }
+
+                    if mouse_state != window.internal.current_window_state.mouse_state {
+                        window.internal.previous_window_state =
+                            Some(window.internal.current_window_state.clone());
+                        window.internal.current_window_state.mouse_state = mouse_state;
+                        needs_hit_test = true;
+                        needs_processing = true;
+                    }
+                }
+                // key pressed / released
+                X11_KEY_PRESS | X11_KEY_RELEASE => {
+                    let mut key_data = unsafe { cur_xevent.key };
+
+                    // the keycode identifies the physical key, the unshifted keysym (index 0)
+                    // identifies the key independent of the modifier state
+                    let scancode = key_data.keycode as ScanCode;
+                    let keysym = (xlib.XLookupKeysym)(&mut key_data, 0);
+                    let vk = event::keysym_to_virtual_keycode(keysym as event::KeySym);
+
+                    window
+                        .internal
+                        .current_window_state
+                        .ime_state
+                        .clear_committed_text();
+                    window.internal.previous_window_state =
+                        Some(window.internal.current_window_state.clone());
+                    let current_window_state = &mut window.internal.current_window_state;
+                    let keyboard_state = &mut current_window_state.keyboard_state;
+
+                    if cur_event_type == X11_KEY_PRESS {
+                        let (text, shifted_keysym) = match window.xim.as_mut() {
+                            Some(xim) => xim.lookup_string(&mut key_data),
+                            None => {
+                                let mut text = [0_u8; 32];
+                                let mut shifted_keysym = 0;
+                                let text_len = (xlib.XLookupString)(
+                                    &mut key_data,
+                                    text.as_mut_ptr() as *mut c_char,
+                                    text.len() as c_int,
+                                    &mut shifted_keysym,
+                                    ptr::null_mut(),
+                                );
+                                let text = &text[..(text_len.max(0) as usize).min(text.len())];
+                                (String::from_utf8_lossy(text).into_owned(), shifted_keysym)
+                            }
+                        };
+
+                        // text committed by the input method arrives as a synthetic key
+                        // press (keycode 0) and may consist of more than one character
+                        if text.chars().count() > 1 || (key_data.keycode == 0 && !text.is_empty()) {
+                            current_window_state.ime_state.commit(text.into());
+                            keyboard_state.current_char = None.into();
+                        } else {
+                            keyboard_state.current_char = event::keysym_to_char(
+                                shifted_keysym as event::KeySym,
+                                text.as_bytes(),
+                            )
+                            .map(|c| c as u32)
+                            .into();
+                        }
+                        keyboard_state.pressed_scancodes.insert_hm_item(scancode);
+                        if let Some(vk) = vk {
+                            keyboard_state.current_virtual_keycode = Some(vk).into();
+                            keyboard_state.pressed_virtual_keycodes.insert_hm_item(vk);
+                        }
+
+                        let open_inspector = vk == Some(VirtualKeyCode::F12)
+                            && app_data_inner
+                                .try_borrow()
+                                .map(|a| a.config.enable_inspector)
+                                .unwrap_or(false);
+
+                        // F12 opens the inspector, else the keyboard shortcuts of the menu bar
+                        if open_inspector {
+                            inspector_requests.push(*window_id);
+                        } else if let Some(callback) =
+                            window.internal.get_menu_bar().and_then(|m| {
+                                m.get_accelerator_callback(
+                                    &window.internal.current_window_state.keyboard_state,
+                                )
+                            })
+                        {
+                            accelerator_callbacks.push(callback.clone());
+                        } else if let Ok(mut app_borrow) = app_data_inner.try_borrow_mut() {
+                            // keyboard shortcuts of the application (App::register_shortcut)
+                            if let Some(callback) = app_borrow.shortcuts.handle_key_down(
+                                window.internal.id,
+                                &window.internal.current_window_state.keyboard_state,
+                            ) {
+                                accelerator_callbacks.push(callback.clone());
+                            }
+                        }
+                    } else {
+                        keyboard_state.current_char = None.into();
+                        keyboard_state.pressed_scancodes.remove_hm_item(&scancode);
+                        if let Some(vk) = vk {
+                            keyboard_state.pressed_virtual_keycodes.remove_hm_item(&vk);
+                            keyboard_state.current_virtual_keycode = None.into();
+                        }
+                    }
+
+                    needs_processing = true;
+                }
+                // mouse entered the window
+                X11_ENTER_NOTIFY => {
+                    let crossing_data = unsafe { cur_xevent.crossing };
+                    window.internal.previous_window_state =
+                        Some(window.internal.current_window_state.clone());
+                    window
+                        .internal
+                        .current_window_state
+                        .mouse_state
+                        .cursor_position =
+                        window.get_cursor_position(crossing_data.x, crossing_data.y);
+                    needs_hit_test = true;
+                    needs_processing = true;
+                }
+                // mouse left the window
+                X11_LEAVE_NOTIFY => {
+                    use azul_core::window::{FullHitTest, LogicalPosition, OptionMouseCursorType};
+
+                    let current_focus = window.internal.current_window_state.focused_node;
+                    window.internal.previous_window_state =
+                        Some(window.internal.current_window_state.clone());
+                    let last_seen = match window
+                        .internal
+                        .current_window_state
+                        .mouse_state
+                        .cursor_position
+                    {
+                        CursorPosition::InWindow(i) => i,
+                        _ => LogicalPosition::zero(),
+                    };
+                    window
+                        .internal
+                        .current_window_state
+                        .mouse_state
+                        .cursor_position = CursorPosition::OutOfWindow(last_seen);
+                    window.internal.current_window_state.last_hit_test =
+                        FullHitTest::empty(current_focus);
+                    window
+                        .internal
+                        .current_window_state
+                        .mouse_state
+                        .mouse_cursor_type = OptionMouseCursorType::None;
+                    needs_processing = true;
+                }
+                // window gained / lost keyboard focus
+                X11_FOCUS_IN | X11_FOCUS_OUT => {
+                    let has_focus = cur_event_type == X11_FOCUS_IN;
+                    window.internal.previous_window_state =
+                        Some(window.internal.current_window_state.clone());
+                    window.internal.current_window_state.flags.has_focus = has_focus;
+                    if let Some(xim) = window.xim.as_mut() {
+                        xim.set_focus(has_focus);
+                    }
+                    #[cfg(feature = "css_parser")]
+                    {
+                        if !has_focus && window.menu.is_some() {
+                            menu_inputs.push((*window_id, menu::MenuInput::Close));
+                        }
+                    }
+                    if !has_focus {
+                        // the key release events are sent to the newly focused
+                        // window, so the keys would otherwise stay pressed forever
+                        let keyboard_state =
+                            &mut window.internal.current_window_state.keyboard_state;
+                        keyboard_state.current_char = None.into();
+                        keyboard_state.current_virtual_keycode = None.into();
+                        keyboard_state.pressed_virtual_keycodes = Default::default();
+                        keyboard_state.pressed_scancodes = Default::default();
+                    }
+                    needs_processing = true;
+                }
+                // owner of the CLIPBOARD selection changed (XFixes)
+                t if Some(t) == window.xfixes_selection_notify => {
+                    window.internal.previous_window_state =
+                        Some(window.internal.current_window_state.clone());
+                    window.internal.current_window_state.clipboard_sequence += 1;
+                    needs_processing = true;
+                }
+                // finger or pen touched / moved / lifted (XInput2)
+                X11_GENERIC_EVENT
+                    if window.touch.is_some()
+                        && !modal_children.contains_key(&window.internal.id) =>
+                {
+                    let display = window.dpy.display;
+                    let hidpi_factor = window.internal.current_window_state.size.get_hidpi_factor();
+                    let touch_event = window
+                        .touch
+                        .as_mut()
+                        .and_then(|t| t.translate_event(display, &mut cur_xevent, hidpi_factor));
+                    if let Some(touch_event) = touch_event {
+                        window.internal.previous_window_state =
+                            Some(window.internal.current_window_state.clone());
+                        window.handle_touch_event(touch_event);
+                        needs_hit_test = true;
+                        needs_processing = true;
+                    }
+                }
+                // monitor added, removed, moved or resized (XRandR)
+                t if monitor::is_monitor_change_event(window.xrandr_event_base, t) => {
+                    monitor::update_configuration(&mut cur_xevent);
+                    window.monitors = monitor::get_monitors(window.dpy.display, || {
+                        window.dpy.get_dpi_scale_factor()
+                    });
+                    window.internal.previous_window_state =
+                        Some(window.internal.current_window_state.clone());
+                    monitor_may_have_changed = true;
+                    needs_processing = true;
                 }
                 _ => {}
             }
+
+            // the scale factor of the new monitor may be different
+            if monitor_may_have_changed {
+                if let Ok(mut app_borrow) = app_data_inner.try_borrow_mut() {
+                    window.make_current();
+                    window.update_monitor(&mut *app_borrow);
+                }
+            }
+
+            // the preedit callbacks are called from XFilterEvent
+            if let Some(change) = window
+                .xim
+                .as_mut()
+                .and_then(|xim| xim.take_preedit_change())
+            {
+                if !needs_processing {
+                    window
+                        .internal
+                        .current_window_state
+                        .ime_state
+                        .clear_committed_text();
+                    window.internal.previous_window_state =
+                        Some(window.internal.current_window_state.clone());
+                }
+                let ime_state = &mut window.internal.current_window_state.ime_state;
+                match change {
+                    ime::PreeditChange::Composition(text, cursor) => {
+                        ime_state.set_composition(text.into(), cursor)
+                    }
+                    ime::PreeditChange::Done => ime_state.cancel_composition(),
+                }
+                needs_processing = true;
+            }
+
+            // menu items clicked in the system tray (the tray thread
+            // wakes up the event loop by sending a client message)
+            let clicked_tray_items = window
+                .tray
+                .as_mut()
+                .map(|t| t.take_clicked_items())
+                .unwrap_or_default();
+
+            // callbacks of clicked notifications (woken up the same way)
+            let notification_callbacks = window
+                .notifications
+                .as_mut()
+                .map(|n| n.take_callbacks())
+                .unwrap_or_default();
+
+            let menu_callbacks = clicked_tray_items
+                .into_iter()
+                .filter_map(|id| window.tray.as_mut()?.get_menu_callback(id).cloned())
+                .chain(notification_callbacks.into_iter())
+                .chain(accelerator_callbacks.into_iter())
+                .collect::<Vec<_>>();
+
+            if !menu_callbacks.is_empty() {
+                window.make_current();
+                if let Ok(mut app_borrow) = app_data_inner.try_borrow_mut() {
+                    let ab = &mut *app_borrow;
+                    for menu_callback in menu_callbacks {
+                        let result = invoke_menu_callback(
+                            window,
+                            menu_callback,
+                            get_root_dom_node(),
+                            &mut ab.fc_cache,
+                            &mut ab.image_cache,
+                            &ab.config,
+                            &mut new_windows,
+                            &mut windows_to_close,
+                        );
+                        regenerate_all_windows |= apply_process_event_result(
+                            result,
+                            window,
+                            ab,
+                            &mut new_windows,
+                            &mut windows_to_close,
+                        );
+                    }
+                }
+            }
+
+            // the XEmbed tray icon has no menu protocol, its menu is rendered by azul
+            #[cfg(feature = "css_parser")]
+            {
+                if let Some(menu) = window
+                    .tray
+                    .as_mut()
+                    .and_then(|t| t.take_context_menu_request())
+                    .and_then(|p| window.open_tray_menu(p))
+                {
+                    menu_inputs.push((*window_id, menu::MenuInput::Open(menu)));
+                }
+            }
+
+            if !needs_processing {
+                continue;
+            }
+
+            window.make_current();
+
+            if needs_hit_test {
+                window.update_hit_test();
+            }
+
+            #[cfg(feature = "css_parser")]
+            {
+                if let Some(menu) = context_menu_position.and_then(|p| window.open_context_menu(p))
+                {
+                    menu_inputs.push((*window_id, menu::MenuInput::Open(menu)));
+                }
+            }
+
+            window.internal.update_drag_state();
+
+            let mut app_borrow = match app_data_inner.try_borrow_mut() {
+                Ok(o) => o,
+                Err(_) => continue,
+            };
+            let ab = &mut *app_borrow;
+
+            let result = process_event(
+                window,
+                &mut ab.fc_cache,
+                &mut ab.image_cache,
+                &ab.config,
+                &mut new_windows,
+                &mut windows_to_close,
+            );
+
+            regenerate_all_windows |= apply_process_event_result(
+                result,
+                window,
+                ab,
+                &mut new_windows,
+                &mut windows_to_close,
+            );
+
+            window.update_cursor(&ab.image_cache);
+        }
+
+        // running threads don't wake up the event loop, so their messages
+        // are polled whenever wait_for_events() returns (at least every 16ms)
+        for window in active_windows.values_mut() {
+            if window.internal.threads.is_empty() {
+                continue;
+            }
+
+            let mut app_borrow = match app_data_inner.try_borrow_mut() {
+                Ok(o) => o,
+                Err(_) => continue,
+            };
+            let ab = &mut *app_borrow;
+
+            window.make_current();
+            let result = invoke_threads(
+                window,
+                &mut ab.data,
+                &mut ab.fc_cache,
+                &mut ab.image_cache,
+                &ab.config,
+                &mut new_windows,
+                &mut windows_to_close,
+            );
+            regenerate_all_windows |= apply_process_event_result(
+                result,
+                window,
+                ab,
+                &mut new_windows,
+                &mut windows_to_close,
+            );
+        }
+
+        // timers don't wake up the event loop either, wait_for_events()
+        // returns when the next timer is due
+        for window in active_windows.values_mut() {
+            if window.internal.timers.is_empty() {
+                continue;
+            }
+
+            let mut app_borrow = match app_data_inner.try_borrow_mut() {
+                Ok(o) => o,
+                Err(_) => continue,
+            };
+            let ab = &mut *app_borrow;
+
+            window.make_current();
+            let result = invoke_timers(
+                window,
+                &mut ab.fc_cache,
+                &mut ab.image_cache,
+                &ab.config,
+                &mut new_windows,
+                &mut windows_to_close,
+            );
+            regenerate_all_windows |= apply_process_event_result(
+                result,
+                window,
+                ab,
+                &mut new_windows,
+                &mut windows_to_close,
+            );
+        }
+
+        // fingers that are held down without moving don't send events, so the
+        // long-press is recognized when wait_for_events() times out
+        for window in active_windows.values_mut() {
+            let long_press_timeout = window
+                .touch
+                .as_ref()
+                .and_then(|t| t.get_long_press_timeout());
+            if long_press_timeout != Some(0) {
+                continue;
+            }
+
+            window.internal.previous_window_state =
+                Some(window.internal.current_window_state.clone());
+            if let Some(touch) = window.touch.as_mut() {
+                touch.check_long_press(&mut window.internal.current_window_state.touch_state);
+            }
+
+            let mut app_borrow = match app_data_inner.try_borrow_mut() {
+                Ok(o) => o,
+                Err(_) => continue,
+            };
+            let ab = &mut *app_borrow;
+
+            window.make_current();
+            let result = process_event(
+                window,
+                &mut ab.fc_cache,
+                &mut ab.image_cache,
+                &ab.config,
+                &mut new_windows,
+                &mut windows_to_close,
+            );
+            regenerate_all_windows |= apply_process_event_result(
+                result,
+                window,
+                ab,
+                &mut new_windows,
+                &mut windows_to_close,
+            );
+            window.update_cursor(&ab.image_cache);
+        }
+
+        // global keyboard shortcuts are invoked on the first window,
+        // since the application may not have the focus
+        let pressed_shortcuts = global_shortcuts
+            .as_mut()
+            .map(|g| g.take_pressed())
+            .unwrap_or_default();
+        if let Some(window) = active_windows.values_mut().next() {
+            for shortcut_id in pressed_shortcuts {
+                let mut app_borrow = match app_data_inner.try_borrow_mut() {
+                    Ok(o) => o,
+                    Err(_) => continue,
+                };
+                let ab = &mut *app_borrow;
+
+                let callback = match ab.shortcuts.get_shortcut(shortcut_id) {
+                    Some(s) => s.callback.clone(),
+                    None => continue,
+                };
+
+                window.make_current();
+                let result = invoke_menu_callback(
+                    window,
+                    callback,
+                    get_root_dom_node(),
+                    &mut ab.fc_cache,
+                    &mut ab.image_cache,
+                    &ab.config,
+                    &mut new_windows,
+                    &mut windows_to_close,
+                );
+                regenerate_all_windows |= apply_process_event_result(
+                    result,
+                    window,
+                    ab,
+                    &mut new_windows,
+                    &mut windows_to_close,
+                );
+            }
+        }
+
+        #[cfg(feature = "css_parser")]
+        for (owner_id, input) in menu_inputs {
+            regenerate_all_windows |= handle_menu_input(
+                &mut active_windows,
+                owner_id,
+                input,
+                &xlib,
+                &egl,
+                &app_data_inner,
+                &mut new_windows,
+                &mut windows_to_close,
+            );
+        }
+
+        for window_id in inspector_requests {
+            new_windows.extend(get_inspector_create_options(&mut active_windows, window_id));
+        }
+
+        // windows closed with CallbackInfo::close_window() and the child windows of closed windows
+        add_windows_to_close(&mut active_windows, &mut windows_to_close);
+
+        // the results of closed child windows are passed to their parent window
+        for closed_window_id in windows_to_close.clone() {
+            let (parent, result_callback, window_result) =
+                match active_windows.get_mut(&closed_window_id) {
+                    Some(w) => (w.parent, w.result_callback.take(), w.window_result.take()),
+                    None => continue,
+                };
+
+            let (mut result_callback, mut window_result) = match (result_callback, window_result) {
+                (Some(c), Some(r)) => (c, r),
+                _ => continue,
+            };
+
+            let parent_window = match active_windows
+                .values_mut()
+                .find(|w| Some(w.internal.id) == parent && !windows_to_close.contains(&w.id))
+            {
+                Some(s) => s,
+                None => continue,
+            };
+
+            let mut app_borrow = match app_data_inner.try_borrow_mut() {
+                Ok(o) => o,
+                Err(_) => continue,
+            };
+            let ab = &mut *app_borrow;
+
+            parent_window.make_current();
+            let result = invoke_window_result_callback(
+                parent_window,
+                &mut result_callback,
+                &mut window_result,
+                &mut ab.fc_cache,
+                &mut ab.image_cache,
+                &ab.config,
+                &mut new_windows,
+                &mut windows_to_close,
+            );
+            regenerate_all_windows |= apply_process_event_result(
+                result,
+                parent_window,
+                ab,
+                &mut new_windows,
+                &mut windows_to_close,
+            );
+        }
+
+        if let Ok(mut app_borrow) = app_data_inner.try_borrow_mut() {
+            if apply_shortcut_changes(&mut active_windows, &mut app_borrow.shortcuts) {
+                if let Some(g) = global_shortcuts.as_mut() {
+                    g.sync(&app_borrow.shortcuts);
+                }
+            }
+        }
+
+        if regenerate_all_windows {
+            if let Ok(mut app_borrow) = app_data_inner.try_borrow_mut() {
+                for window in active_windows.values_mut() {
+                    window.make_current();
+                    window.regenerate_dom(&mut *app_borrow);
+                }
+            }
+        }
+
+        for mut options in new_windows {
+            // child windows are centered over their parent window
+            let parent = options
+                .parent
+                .into_option()
+                .and_then(|p| active_windows.values().find(|w| w.internal.id == p));
+            let parent_id = parent.map(|p| p.id);
+            let centered_position = match (parent, options.state.position) {
+                (Some(p), WindowPosition::Uninitialized) => {
+                    Some(p.get_child_window_position(options.state.size.dimensions))
+                }
+                _ => None,
+            };
+            if let Some(position) = centered_position {
+                options.state.position = WindowPosition::Initialized(position);
+            }
+
+            let window = X11Window::new(
+                xlib.clone(),
+                egl.clone(),
+                &mut options,
+                SharedApplicationData {
+                    inner: app_data_inner.clone(),
+                },
+            );
+
+            if let Ok(mut window) = window {
+                if let Some(parent_id) = parent_id {
+                    owner::set_parent(window.dpy.display, window.id, parent_id, window.modal);
+                }
+                if let Some(position) = centered_position {
+                    owner::set_initial_position(
+                        window.dpy.display,
+                        window.id,
+                        position.x,
+                        position.y,
+                    );
+                }
+                window.show();
+                active_windows.insert(window.id, window);
+            }
         }
 
         for w in windows_to_close {
-            active_windows.remove(&w);
+            // the popup windows of the menu are closed together with their owner
+            #[cfg(feature = "css_parser")]
+            {
+                let popups = active_windows
+                    .get(&w)
+                    .and_then(|w| w.menu.as_ref())
+                    .map(|m| m.get_popup_windows())
+                    .unwrap_or_default();
+                for popup in popups {
+                    active_windows.remove(&popup);
+                }
+            }
+            if let Some(window) = active_windows.remove(&w) {
+                window.save_persisted_state();
+            }
         }
 
         if active_windows.is_empty() {
             break;
         }
+
+        if let Ok(mut app_borrow) = app_data_inner.try_borrow_mut() {
+            update_inspector_windows(&mut active_windows, &mut *app_borrow);
+            sync_inspector_highlights(&mut active_windows, &app_borrow.image_cache);
+        }
     }
 
     Ok(0)
 }
 
 #[derive(Debug, Clone)]
-struct SharedApplicationData {
-    inner: Rc<RefCell<ApplicationData>>,
+pub(super) struct SharedApplicationData {
+    pub(super) inner: Rc<RefCell<ApplicationData>>,
 }
 
 // ApplicationData struct that is shared across windows
 #[derive(Debug)]
-struct ApplicationData {
-    data: RefAny,
-    config: AppConfig,
-    image_cache: ImageCache,
-    fc_cache: LazyFcCache,
+pub(super) struct ApplicationData {
+    pub(super) data: RefAny,
+    pub(super) config: AppConfig,
+    pub(super) image_cache: ImageCache,
+    pub(super) fc_cache: LazyFcCache,
+    pub(super) shortcuts: ShortcutRegistry,
 }
 
-fn display_egl_status(e: EGLint) -> &'static str {
+pub(super) fn display_egl_status(e: EGLint) -> &'static str {
     const BAD_ACCESS: EGLint = 0x3002;
     const BAD_ALLOC: EGLint = 0x3003;
     const BAD_ATTRIBUTE: EGLint = 0x3004;
@@ -1212,7 +2159,7 @@ fn display_egl_status(e: EGLint) -> &'static str {
     }
 }
 
-struct Notifier {}
+pub(super) struct Notifier {}
 
 impl WrRenderNotifier for Notifier {
     fn clone(&self) -> Box<dyn WrRenderNotifier> {
@@ -1232,6 +2179,41 @@ impl WrRenderNotifier for Notifier {
 struct X11Window {
     // X11 raw window handle
     pub id: u64,
+    // Input method (XIM) of the window, None if no input method is running
+    // (declared before dpy, so that it is dropped before the display is closed)
+    pub xim: Option<ime::Xim>,
+    // Cursor handles created for the window, None if libXcursor is not installed
+    // (declared before dpy, so that the cursors are freed before the display is closed)
+    pub cursors: Option<cursor::WindowCursors>,
+    // Icon in the system tray, None if LinuxWindowOptions::tray_icon is not set
+    // (the XEmbed tray window is created on the connection of the window)
+    pub tray: Option<tray::SystemTray>,
+    // Connection to the notification server, created when the first notification is shown
+    pub notifications: Option<notify::Notifications>,
+    // Menu that is open on the window (context menu, menu of the menu bar or of the tray icon)
+    #[cfg(feature = "css_parser")]
+    pub menu: Option<menu::OpenMenu>,
+    // Window that owns the menu if this window is a popup menu
+    #[cfg(feature = "css_parser")]
+    pub menu_owner: Option<u64>,
+    // Parent window of a child window (see WindowCreateOptions::parent)
+    pub parent: Option<WindowId>,
+    // Whether the parent window is blocked while this window is open
+    pub modal: bool,
+    // Whether the window has an ARGB visual (the framebuffer is cleared with a transparent color)
+    pub is_transparent: bool,
+    // Key of the saved window geometry (see WindowCreateOptions::persistence_key)
+    pub persistence_key: Option<AzString>,
+    // Invoked on the parent window with the result of this window when it is closed
+    pub result_callback: Option<WindowResultCallback>,
+    // Result of this window, set with CallbackInfo::set_window_result
+    pub window_result: Option<RefAny>,
+    // Other windows closed in the callbacks of this window (CallbackInfo::close_window)
+    pub windows_closed: Vec<WindowId>,
+    // Keyboard shortcuts changed in the callbacks of this window (CallbackInfo::register_shortcut)
+    pub shortcuts_changed: Vec<ShortcutChange>,
+    // Set when the DOM got regenerated, so that the inspectors of the window update their snapshot
+    pub dom_regenerated: bool,
     pub dpy: X11Display,
     // EGL OpenGL 3.2 context
     pub egl_surface: EGLSurface,
@@ -1239,6 +2221,16 @@ struct X11Window {
     pub egl_context: EGLContext,
     // XAtom fired when the window close button is hit
     pub wm_delete_window_atom: c_long,
+    // Event type of the XFixes selection notify event, None if XFixes is not available
+    pub xfixes_selection_notify: Option<c_int>,
+    // First event type of the XRandR events, None if XRandR is not available
+    pub xrandr_event_base: Option<c_int>,
+    // Monitors of the display, re-queried when the monitor configuration changes
+    pub monitors: MonitorVec,
+    // Drag-and-drop from / to other applications, None if XDND is not available
+    pub xdnd: Option<dnd::Xdnd>,
+    // Touch screen and pen input, None if XInput 2.2 is not available
+    pub touch: Option<touch::TouchInput>,
     // X11 library (dynamically loaded)
     pub xlib: Rc<Xlib>,
     // libEGL.so library (dynamically loaded)
@@ -1270,7 +2262,11 @@ struct Xlib {
     pub XCloseDisplay: XCloseDisplayFuncType,
     pub XPending: XPendingFuncType,
     pub XNextEvent: XNextEventFuncType,
+    pub XConnectionNumber: XConnectionNumberFuncType,
     pub XSelectInput: XSelectInputFuncType,
+    pub XLookupKeysym: XLookupKeysymFuncType,
+    pub XLookupString: XLookupStringFuncType,
+    pub XClearArea: XClearAreaFuncType,
 }
 
 impl Xlib {
@@ -1402,6 +2398,17 @@ impl Xlib {
             })
             .ok_or(Create(Egl(format!("X11: no function XNextEvent"))))?;
 
+        let XConnectionNumber: XConnectionNumberFuncType = x11
+            .get("XConnectionNumber")
+            .and_then(|ptr| {
+                if ptr.is_null() {
+                    None
+                } else {
+                    Some(unsafe { mem::transmute(ptr) })
+                }
+            })
+            .ok_or(Create(Egl(format!("X11: no function XConnectionNumber"))))?;
+
         let XSelectInput: XSelectInputFuncType = x11
             .get("XSelectInput")
             .and_then(|ptr| {
@@ -1413,6 +2420,39 @@ impl Xlib {
             })
             .ok_or(Create(Egl(format!("X11: no function XSelectInput"))))?;
 
+        let XLookupKeysym: XLookupKeysymFuncType = x11
+            .get("XLookupKeysym")
+            .and_then(|ptr| {
+                if ptr.is_null() {
+                    None
+                } else {
+                    Some(unsafe { mem::transmute(ptr) })
+                }
+            })
+            .ok_or(Create(Egl(format!("X11: no function XLookupKeysym"))))?;
+
+        let XLookupString: XLookupStringFuncType = x11
+            .get("XLookupString")
+            .and_then(|ptr| {
+                if ptr.is_null() {
+                    None
+                } else {
+                    Some(unsafe { mem::transmute(ptr) })
+                }
+            })
+            .ok_or(Create(Egl(format!("X11: no function XLookupString"))))?;
+
+        let XClearArea: XClearAreaFuncType = x11
+            .get("XClearArea")
+            .and_then(|ptr| {
+                if ptr.is_null() {
+                    None
+                } else {
+                    Some(unsafe { mem::transmute(ptr) })
+                }
+            })
+            .ok_or(Create(Egl(format!("X11: no function XClearArea"))))?;
+
         Ok(Xlib {
             library: x11,
             XDefaultScreen,
@@ -1426,12 +2466,16 @@ impl Xlib {
             XCloseDisplay,
             XPending,
             XNextEvent,
+            XConnectionNumber,
             XSelectInput,
+            XLookupKeysym,
+            XLookupString,
+            XClearArea,
         })
     }
 }
 
-struct Egl {
+pub(super) struct Egl {
     pub library: Library,
     pub eglMakeCurrent: eglMakeCurrentFuncType,
     pub eglSwapBuffers: eglSwapBuffersFuncType,
@@ -1439,6 +2483,7 @@ struct Egl {
     pub eglInitialize: eglInitializeFuncType,
     pub eglBindAPI: eglBindAPIFuncType,
     pub eglChooseConfig: eglChooseConfigFuncType,
+    pub eglGetConfigAttrib: eglGetConfigAttribFuncType,
     pub eglCreateWindowSurface: eglCreateWindowSurfaceFuncType,
     pub eglSwapInterval: eglSwapIntervalFuncType,
     pub eglCreateContext: eglCreateContextFuncType,
@@ -1447,7 +2492,7 @@ struct Egl {
 }
 
 impl Egl {
-    fn new() -> Result<Self, LinuxStartupError> {
+    pub(super) fn new() -> Result<Self, LinuxStartupError> {
         use self::LinuxStartupError::Create;
         use self::LinuxWindowCreateError::{Egl, X};
 
@@ -1514,6 +2559,16 @@ impl Egl {
                 }
             })
             .ok_or(Create(Egl(format!("EGL: no function eglChooseConfig"))))?;
+        let eglGetConfigAttrib: eglGetConfigAttribFuncType = egl
+            .get("eglGetConfigAttrib")
+            .and_then(|ptr| {
+                if ptr.is_null() {
+                    None
+                } else {
+                    Some(unsafe { mem::transmute(ptr) })
+                }
+            })
+            .ok_or(Create(Egl(format!("EGL: no function eglGetConfigAttrib"))))?;
         let eglCreateWindowSurface: eglCreateWindowSurfaceFuncType = egl
             .get("eglCreateWindowSurface")
             .and_then(|ptr| {
@@ -1575,6 +2630,7 @@ impl Egl {
             eglInitialize,
             eglBindAPI,
             eglChooseConfig,
+            eglGetConfigAttrib,
             eglCreateWindowSurface,
             eglSwapInterval,
             eglCreateContext,
@@ -1619,30 +2675,104 @@ impl X11Window {
             | X11_POINTER_MOTION_MASK
             | X11_BUTTON_PRESS_MASK
             | X11_BUTTON_RELEASE_MASK
+            | X11_ENTER_WINDOW_MASK
+            | X11_LEAVE_WINDOW_MASK
+            | X11_FOCUS_CHANGE_MASK
             | X11_STRUCTURE_NOTIFY_MASK;
 
         let mut xattr: XSetWindowAttributes = unsafe { mem::zeroed() };
         xattr.event_mask = mask;
+        let mut xattr_mask = X11_CW_EVENT_MASK;
+
+        // override-redirect windows (popup menus) are not managed by the window manager
+        if options
+            .state
+            .platform_specific_options
+            .linux_options
+            .x11_override_redirect
+        {
+            xattr.override_redirect = X11_TRUE;
+            xattr_mask |= X11_CW_OVERRIDE_REDIRECT;
+        }
 
-        let dpi_scale_factor = dpy.get_dpi_scale_factor();
-        options.state.size.dpi = (dpi_scale_factor.max(0.0) * 96.0).round() as u32;
+        // translucent windows need a visual with an alpha channel
+        let argb_visual = if transparency::is_transparent(&options.state) {
+            transparency::get_argb_visual(dpy.display, scrnum, root)
+        } else {
+            None
+        };
+        if let Some(argb_visual) = argb_visual.as_ref() {
+            xattr.colormap = argb_visual.colormap;
+            xattr.background_pixel = 0;
+            xattr.border_pixel = 0;
+            xattr_mask |= X11_CW_COLORMAP | X11_CW_BACK_PIXEL | X11_CW_BORDER_PIXEL;
+        }
+
+        let monitors = monitor::get_monitors(dpy.display, || dpy.get_dpi_scale_factor());
+
+        // geometry of the window when it was closed in the last session
+        let restored_state = options
+            .persistence_key
+            .as_ref()
+            .and_then(|key| crate::persistence::load_window_state(key.as_str()));
+        if let Some(restored_state) = restored_state.as_ref() {
+            restored_state.restore(&mut options.state, monitors.as_slice());
+        }
+
+        let (x, y) = match options.state.position {
+            WindowPosition::Initialized(p) => (p.x, p.y),
+            WindowPosition::Uninitialized => (0, 0),
+        };
 
+        // the window is created with the scale factor of the monitor it is placed on,
+        // popup menus are positioned and sized in physical pixels by the menu module
         let logical_size = options.state.size.dimensions;
+        let monitor = monitor::get_window_monitor(
+            monitors.as_slice(),
+            x,
+            y,
+            logical_size.width.round().max(0.0) as u32,
+            logical_size.height.round().max(0.0) as u32,
+        )
+        .cloned();
+
+        let dpi_scale_factor = if options
+            .state
+            .platform_specific_options
+            .linux_options
+            .x11_override_redirect
+        {
+            1.0
+        } else {
+            match monitor.as_ref() {
+                Some(m) => m.scale_factor as f32,
+                None => dpy.get_dpi_scale_factor(),
+            }
+        };
+        options.state.size.dpi = (dpi_scale_factor.max(0.0) * 96.0).round() as u32;
+        options.state.monitor = monitor.unwrap_or_default();
+
         let physical_size = logical_size.to_physical(dpi_scale_factor);
 
         let window = unsafe {
             (xlib.XCreateWindow)(
                 dpy.get(),
                 root,
+                x,
+                y,
+                physical_size.width.max(1),
+                physical_size.height.max(1),
                 0,
-                0,
-                logical_size.width.round().max(0.0) as u32,
-                logical_size.height.round().max(0.0) as u32,
-                0,
-                X11_COPY_FROM_PARENT,
+                argb_visual
+                    .as_ref()
+                    .map(|v| v.depth)
+                    .unwrap_or(X11_COPY_FROM_PARENT),
                 X11_INPUT_OUTPUT as u32,
-                ptr::null_mut(), // = CopyFromParent
-                X11_CW_EVENT_MASK,
+                argb_visual
+                    .as_ref()
+                    .map(|v| v.visual)
+                    .unwrap_or(ptr::null_mut()), // null = CopyFromParent
+                xattr_mask,
                 &mut xattr,
             )
         };
@@ -1655,6 +2785,18 @@ impl X11Window {
             (xlib.XSelectInput)(dpy.get(), window, mask);
         }
 
+        if options.state.flags.has_blur_behind_window {
+            transparency::set_blur_behind(dpy.display, window, true);
+        }
+        if options.state.flags.is_click_through {
+            transparency::set_click_through(dpy.display, window, true);
+        }
+
+        if restored_state.is_some() {
+            owner::set_initial_position(dpy.display, window, x, y);
+            frame::set_initial_frame(dpy.display, window, options.state.flags.frame);
+        }
+
         let window_title = encode_ascii(&options.state.title);
         unsafe { (xlib.XStoreName)(dpy.get(), window, window_title.as_ptr() as *const i8) };
 
@@ -1677,6 +2819,49 @@ impl X11Window {
 
         unsafe { (xlib.XSetWMProtocols)(dpy.get(), window, &mut wm_delete_window_atom, 1) };
 
+        // subscribe to clipboard changes (emits WindowEventFilter::ClipboardChanged)
+        let xfixes_selection_notify =
+            clipboard::select_clipboard_change_events(&xlib, dpy.display, window);
+
+        // subscribe to changes of the monitor configuration (XRandR)
+        let xrandr_event_base = monitor::select_monitor_change_events(dpy.display, window);
+
+        // accept drops from other applications (XDND)
+        let xdnd = dnd::Xdnd::new(dpy.display, window);
+
+        // composition of text with an input method (XIM)
+        let xim = ime::Xim::new(dpy.display, window);
+
+        // touch screens and pens (XInput2)
+        let touch = touch::TouchInput::new(dpy.display, window);
+
+        // mouse cursors of the window (theme cursors and image cursors)
+        let cursors = cursor::WindowCursors::new(dpy.display, window);
+
+        // icon in the system tray (StatusNotifierItem or XEmbed)
+        let tray = options
+            .state
+            .platform_specific_options
+            .linux_options
+            .tray_icon
+            .as_ref()
+            .and_then(|t| {
+                tray::SystemTray::new(t, tray::TrayWaker::X11Window(window), Some(dpy.display))
+            });
+
+        // the menu bar is rendered by azul, popup menus don't have a menu bar
+        #[cfg(feature = "css_parser")]
+        {
+            if !options
+                .state
+                .platform_specific_options
+                .linux_options
+                .x11_override_redirect
+            {
+                menu::add_menu_bar(&mut options.state.layout_callback);
+            }
+        }
+
         let egl_display = (egl.eglGetDisplay)(dpy.display as *mut c_void);
         if egl_display == EGL_NO_DISPLAY {
             return Err(Create(EglError(format!(
@@ -1704,7 +2889,7 @@ impl X11Window {
             ))));
         }
 
-        let egl_attr = [
+        let mut egl_attr = vec![
             EGL_SURFACE_TYPE,
             EGL_WINDOW_BIT,
             EGL_CONFORMANT,
@@ -1723,13 +2908,29 @@ impl X11Window {
             24,
             EGL_STENCIL_SIZE,
             8,
-            EGL_NONE,
         ];
+        if argb_visual.is_some() {
+            egl_attr.extend_from_slice(&[EGL_ALPHA_SIZE, 8]);
+        }
+        egl_attr.push(EGL_NONE);
+
+        // the EGL config of a translucent window has to render to its ARGB visual
+        let argb_config = argb_visual.as_ref().and_then(|v| {
+            transparency::choose_egl_config(&egl, egl_display, &egl_attr, v.visual_id)
+        });
 
         let mut config: EGLConfig = unsafe { mem::zeroed() };
         let mut count = 0;
-        let egl_config_chosen =
-            (egl.eglChooseConfig)(egl_display, egl_attr.as_ptr(), &mut config, 1, &mut count);
+        let egl_config_chosen = match argb_config {
+            Some(c) => {
+                config = c;
+                count = 1;
+                EGL_TRUE
+            }
+            None => {
+                (egl.eglChooseConfig)(egl_display, egl_attr.as_ptr(), &mut config, 1, &mut count)
+            }
+        };
         if egl_config_chosen != EGL_TRUE {
             return Err(Create(EglError(format!(
                 "EGL: eglChooseConfig(): Cannot choose EGL config: {}",
@@ -1959,6 +3160,28 @@ impl X11Window {
             egl_display,
             egl_context,
             wm_delete_window_atom: wm_delete_window_atom as i64,
+            xfixes_selection_notify,
+            xrandr_event_base,
+            monitors,
+            xdnd,
+            touch,
+            xim,
+            cursors,
+            tray,
+            notifications: None,
+            #[cfg(feature = "css_parser")]
+            menu: None,
+            #[cfg(feature = "css_parser")]
+            menu_owner: None,
+            parent: options.parent.into_option(),
+            modal: options.modal,
+            is_transparent: argb_visual.is_some(),
+            persistence_key: options.persistence_key.clone().into_option(),
+            result_callback: options.result_callback.into_option(),
+            window_result: None,
+            windows_closed: Vec::new(),
+            shortcuts_changed: Vec::new(),
+            dom_regenerated: false,
             id: window,
             dpy,
             xlib,
@@ -1984,29 +3207,1675 @@ impl X11Window {
     fn show(&mut self) {
         unsafe { (self.xlib.XMapWindow)(self.dpy.get(), self.id) };
     }
-}
 
-struct X11Display {
-    display: *mut Display,
-    xlib: Rc<Xlib>,
-}
+    // Saves the geometry of the window if it has a persistence key, called before it is closed
+    fn save_persisted_state(&self) {
+        let key = match self.persistence_key.as_ref() {
+            Some(s) => s,
+            None => return,
+        };
 
-impl X11Display {
-    fn get<'a>(&'a mut self) -> &'a mut Display {
-        unsafe { &mut *self.display }
+        let state = PersistedWindowState::from_window_state(&self.internal.current_window_state);
+        if let Some(mut state) = state {
+            state.frame = frame::get_frame(self.dpy.display, self.id);
+            crate::persistence::save_window_state(key.as_str(), &state);
+        }
     }
 
-    fn open(xlib: Rc<Xlib>) -> Option<Self> {
-        let dpy = unsafe { (xlib.XOpenDisplay)(&0) };
+    // Root window position of a child window with the given logical size that
+    // centers the child window over this window
+    fn get_child_window_position(&self, child_size: LogicalSize) -> PhysicalPositionI32 {
+        let size = &self.internal.current_window_state.size;
+        let parent_size = size.get_physical_size();
+        let child_size = child_size.to_physical(size.get_hidpi_factor());
+        let (x, y) = owner::get_centered_position(
+            monitor::get_window_origin(self.dpy.display, self.id),
+            (parent_size.width, parent_size.height),
+            (child_size.width, child_size.height),
+        );
+        PhysicalPositionI32::new(x, y)
+    }
 
-        if dpy.is_null() {
-            return None;
-        }
+    // Updates the monitor of the window after it moved or the monitor configuration
+    // changed. If the scale factor of the monitor changed, the window keeps its logical
+    // size and the DOM is laid out again for the new DPI (the display list is scaled
+    // to the new DPI when it is rebuilt)
+    fn update_monitor(&mut self, app: &mut ApplicationData) {
+        use crate::wr_translate::wr_translate_document_id;
+
+        let state = &self.internal.current_window_state;
+        let (x, y) = match state.position {
+            WindowPosition::Initialized(p) => (p.x, p.y),
+            WindowPosition::Uninitialized => (0, 0),
+        };
+        let physical_size = state.size.get_physical_size();
+        let monitor = match monitor::get_window_monitor(
+            self.monitors.as_slice(),
+            x,
+            y,
+            physical_size.width,
+            physical_size.height,
+        ) {
+            Some(s) => s.clone(),
+            None => return,
+        };
 
-        Some(Self { display: dpy, xlib })
-    }
+        let dpi = (monitor.scale_factor.max(0.0) * 96.0).round() as u32;
+        let is_popup = state
+            .platform_specific_options
+            .linux_options
+            .x11_override_redirect;
 
-    /// Return the DPI on X11 systems
+        self.internal.current_window_state.monitor = monitor;
+
+        if is_popup || dpi == self.internal.current_window_state.size.dpi {
+            return;
+        }
+
+        self.internal.current_window_state.size.dpi = dpi;
+        let physical_size = self.internal.current_window_state.size.get_physical_size();
+        monitor::set_window_size(
+            self.dpy.display,
+            self.id,
+            physical_size.width,
+            physical_size.height,
+        );
+
+        let mut txn = WrTransaction::new();
+        txn.set_document_view(WrDeviceIntRect::from_size(WrDeviceIntSize::new(
+            physical_size.width as i32,
+            physical_size.height as i32,
+        )));
+        self.render_api
+            .send_transaction(wr_translate_document_id(self.internal.document_id), txn);
+
+        // fonts and images are re-rasterized for the new DPI
+        self.regenerate_dom(app);
+    }
+
+    // Queues an Expose event for the entire window, so that the
+    // window gets re-rendered on the next iteration of the event loop
+    fn request_redraw(&mut self) {
+        unsafe { (self.xlib.XClearArea)(self.dpy.get(), self.id, 0, 0, 0, 0, X11_TRUE) };
+    }
+
+    // Applies the touch event to the touch state. X11 doesn't emulate the mouse for
+    // clients that select the touch events: the touch moves the cursor (for the
+    // hit-test) and the fingers hold down the left mouse button
+    fn handle_touch_event(&mut self, touch_event: TouchEvent) {
+        use azul_core::window::{TouchPoint, TouchPointKind};
+
+        let touch = match self.touch.as_mut() {
+            Some(s) => s,
+            None => return,
+        };
+
+        let state = &mut self.internal.current_window_state;
+        touch.handle_event(&mut state.touch_state, touch_event);
+
+        let position = match touch_event {
+            TouchEvent::Down(p) | TouchEvent::Move(p) => Some(p.position),
+            TouchEvent::Up(_) | TouchEvent::Cancel(_) => state
+                .touch_state
+                .ended_touch_points
+                .iter()
+                .chain(state.touch_state.cancelled_touch_points.iter())
+                .next()
+                .map(|p| p.position),
+        };
+        if let Some(position) = position {
+            state.mouse_state.cursor_position = CursorPosition::InWindow(position);
+        }
+
+        // pens also move the X11 pointer, so only the fingers press the mouse button
+        let is_finger = |p: &TouchPoint| p.kind == TouchPointKind::Finger;
+        let touch_state = &state.touch_state;
+        if touch_state.touch_points.iter().any(is_finger) {
+            state.mouse_state.left_down = true;
+        } else if touch_state.ended_touch_points.iter().any(is_finger)
+            || touch_state.cancelled_touch_points.iter().any(is_finger)
+        {
+            state.mouse_state.left_down = false;
+        }
+    }
+
+    // Converts physical X11 window coordinates into a logical cursor position,
+    // coordinates outside of the window are reported while a button is held down
+    fn get_cursor_position(&self, x: c_int, y: c_int) -> CursorPosition {
+        use azul_core::window::LogicalPosition;
+
+        let size = &self.internal.current_window_state.size;
+        let hidpi_factor = size.get_hidpi_factor();
+        let physical_size = size.get_physical_size();
+        let position = LogicalPosition::new(x as f32 / hidpi_factor, y as f32 / hidpi_factor);
+
+        if x < 0 || y < 0 || x >= physical_size.width as c_int || y >= physical_size.height as c_int
+        {
+            CursorPosition::OutOfWindow(position)
+        } else {
+            CursorPosition::InWindow(position)
+        }
+    }
+
+    // Creates, updates or removes the system tray icon if
+    // LinuxWindowOptions::tray_icon changed in a callback
+    fn update_tray_icon(&mut self) {
+        let tray_icon = self
+            .internal
+            .current_window_state
+            .platform_specific_options
+            .linux_options
+            .tray_icon
+            .as_ref();
+
+        let changed = self
+            .internal
+            .previous_window_state
+            .as_ref()
+            .map(|p| p.platform_specific_options.linux_options.tray_icon.as_ref() != tray_icon)
+            .unwrap_or(true);
+
+        if !changed {
+            return;
+        }
+
+        match (tray_icon, self.tray.as_mut()) {
+            (None, _) => self.tray = None,
+            (Some(t), Some(tray)) => tray.update(t),
+            (Some(t), None) => {
+                self.tray = tray::SystemTray::new(
+                    t,
+                    tray::TrayWaker::X11Window(self.id),
+                    Some(self.dpy.display),
+                );
+            }
+        }
+    }
+
+    // Sends an internal drag that left the window to the
+    // application below the cursor (or stops sending it)
+    fn update_outgoing_drag(&mut self, root_x: c_int, root_y: c_int, time: Time) {
+        let xdnd = match self.xdnd.as_mut() {
+            Some(s) => s,
+            None => return,
+        };
+
+        let state = &self.internal.current_window_state;
+        let outgoing_drag = state.drag_state.current.as_ref().filter(|d| {
+            !d.is_external() && state.mouse_state.cursor_position.get_position().is_none()
+        });
+
+        match outgoing_drag {
+            Some(drag_data) => xdnd.source_motion(drag_data, root_x, root_y, time),
+            None => {
+                if xdnd.is_dragging_out() {
+                    xdnd.source_leave();
+                }
+            }
+        }
+    }
+
+    // Applies a drag-and-drop event from another application to the window
+    // state, returns whether the cursor position changed (needs a hit-test)
+    fn apply_xdnd_event(&mut self, event: dnd::XdndEvent) -> bool {
+        use azul_core::window::{FullHitTest, LogicalPosition};
+        use dnd::XdndEvent;
+
+        self.internal.previous_window_state = Some(self.internal.current_window_state.clone());
+
+        let current_focus = self.internal.current_window_state.focused_node;
+        let state = &mut self.internal.current_window_state;
+
+        match event {
+            XdndEvent::Enter(drag_data) => {
+                state.drag_state.current = Some(drag_data);
+                state.dropped_file = None;
+                false
+            }
+            XdndEvent::Motion(x, y) => {
+                let hidpi_factor = state.size.get_hidpi_factor();
+                state.mouse_state.cursor_position = CursorPosition::InWindow(LogicalPosition::new(
+                    x as f32 / hidpi_factor,
+                    y as f32 / hidpi_factor,
+                ));
+                true
+            }
+            XdndEvent::DataReceived(drag_data) => {
+                state.hovered_file = drag_data.get_files().into_iter().next();
+                state.drag_state.current = Some(drag_data);
+                false
+            }
+            XdndEvent::Leave => {
+                state.drag_state.current = None;
+                state.hovered_file = None;
+                state.mouse_state.cursor_position = CursorPosition::Uninitialized;
+                state.last_hit_test = FullHitTest::empty(current_focus);
+                false
+            }
+            XdndEvent::Drop(drag_data) => {
+                state.dropped_file = drag_data.get_files().into_iter().next();
+                if state.hovered_file.is_none() {
+                    // emit DroppedFile even if the data arrived with the drop
+                    if let Some(previous) = self.internal.previous_window_state.as_mut() {
+                        previous.hovered_file = state.dropped_file.clone();
+                    }
+                }
+                state.hovered_file = None;
+                state.drag_state.current = None;
+                state.drag_state.dropped = Some(drag_data);
+                false
+            }
+        }
+    }
+
+    // Returns the menu input for a click on / cursor movement over the menu bar:
+    // clicking an item toggles its drop-down menu, while a drop-down menu is open,
+    // moving the cursor to another item opens the menu of that item instead
+    #[cfg(feature = "css_parser")]
+    fn get_menu_bar_input(
+        &mut self,
+        position: azul_core::window::LogicalPosition,
+        is_click: bool,
+    ) -> Option<menu::MenuInput> {
+        let open_item = self.menu.as_ref().and_then(|m| m.menu_bar_item);
+        let item = self
+            .internal
+            .get_menu_bar()
+            .and_then(|m| menu::get_menu_bar_item_at(m, position))?;
+
+        if is_click && open_item == Some(item) {
+            Some(menu::MenuInput::Close)
+        } else if is_click || (open_item.is_some() && open_item != Some(item)) {
+            self.open_menu_bar_item(item).map(menu::MenuInput::Open)
+        } else {
+            None
+        }
+    }
+
+    // Creates the drop-down menu of the menu bar item (below the item)
+    #[cfg(feature = "css_parser")]
+    fn open_menu_bar_item(&self, index: usize) -> Option<menu::OpenMenu> {
+        use azul_core::window::{MenuItemState, MenuPopupPosition};
+
+        let menu_bar = self.internal.get_menu_bar()?;
+        let items = match menu_bar.items.as_ref().get(index)? {
+            MenuItem::String(s) if s.state == MenuItemState::Normal => s.children.clone(),
+            _ => return None,
+        };
+        if items.as_ref().is_empty() {
+            return None;
+        }
+
+        let hidpi_factor = self.internal.current_window_state.size.get_hidpi_factor();
+        let (x, width) = menu::get_menu_bar_item_rect(menu_bar, index)?;
+        let (origin_x, origin_y) = monitor::get_window_origin(self.dpy.display, self.id);
+        let hit_rect = menu::ScreenRect::new(
+            origin_x + (x * hidpi_factor) as i32,
+            origin_y,
+            (width * hidpi_factor) as i32,
+            (menu::MENU_BAR_HEIGHT * hidpi_factor) as i32,
+        );
+
+        Some(menu::OpenMenu::new(
+            items,
+            MenuPopupPosition::BottomOfHitRect,
+            (hit_rect.x, hit_rect.y),
+            hit_rect,
+            menu::get_screen_rect(self.dpy.display),
+            get_root_dom_node(),
+            Some(index),
+        ))
+    }
+
+    // Creates the context menu of the hovered node (if it has one for the pressed
+    // mouse button), `cursor` is the position of the click in root window coordinates
+    #[cfg(feature = "css_parser")]
+    fn open_context_menu(&self, cursor: (c_int, c_int)) -> Option<menu::OpenMenu> {
+        let (context_menu, hit, node) = self.internal.get_context_menu()?;
+
+        let hidpi_factor = self.internal.current_window_state.size.get_hidpi_factor();
+        let item_size = node
+            .node
+            .into_crate_internal()
+            .and_then(|node_id| {
+                let layout_result = self.internal.layout_results.get(node.dom.inner)?;
+                layout_result.rects.as_ref().get(node_id).map(|r| r.size)
+            })
+            .unwrap_or_else(|| LogicalSize::new(0.0, 0.0));
+
+        // the cursor is at point_relative_to_item inside of the hit node
+        let hit_rect = menu::ScreenRect::new(
+            cursor.0 - (hit.point_relative_to_item.x * hidpi_factor) as i32,
+            cursor.1 - (hit.point_relative_to_item.y * hidpi_factor) as i32,
+            (item_size.width * hidpi_factor) as i32,
+            (item_size.height * hidpi_factor) as i32,
+        );
+
+        Some(menu::OpenMenu::new(
+            context_menu.items.clone(),
+            context_menu.position,
+            cursor,
+            hit_rect,
+            menu::get_screen_rect(self.dpy.display),
+            node,
+            None,
+        ))
+    }
+
+    // Creates the menu of the XEmbed tray icon at the position of the click
+    #[cfg(feature = "css_parser")]
+    fn open_tray_menu(&self, cursor: (c_int, c_int)) -> Option<menu::OpenMenu> {
+        let tray_icon = self
+            .internal
+            .current_window_state
+            .platform_specific_options
+            .linux_options
+            .tray_icon
+            .as_ref()?;
+
+        if tray_icon.menu.items.as_ref().is_empty() {
+            return None;
+        }
+
+        Some(menu::OpenMenu::new(
+            tray_icon.menu.items.clone(),
+            tray_icon.menu.position,
+            cursor,
+            menu::ScreenRect::new(cursor.0, cursor.1, 0, 0),
+            menu::get_screen_rect(self.dpy.display),
+            get_root_dom_node(),
+            None,
+        ))
+    }
+
+    // Sets the cursor of the window from the cursor: property of the hovered node,
+    // an image cursor set with CallbackInfo::set_custom_cursor() overrides it
+    fn update_cursor(&mut self, image_cache: &ImageCache) {
+        use azul_core::window::{CursorTypeHitTest, OptionMouseCursorType};
+
+        if self
+            .internal
+            .current_window_state
+            .mouse_state
+            .cursor_position
+            .get_position()
+            .is_none()
+        {
+            return;
+        }
+
+        let cht = CursorTypeHitTest::new(
+            &self.internal.current_window_state.last_hit_test,
+            &self.internal.layout_results,
+        );
+        let custom_cursor = cht.get_custom_cursor(&self.internal.current_window_state, image_cache);
+
+        self.internal
+            .current_window_state
+            .mouse_state
+            .mouse_cursor_type = OptionMouseCursorType::Some(cht.cursor_icon);
+
+        if let Some(cursors) = self.cursors.as_mut() {
+            cursors.set_cursor(cht.cursor_icon, custom_cursor.as_ref());
+        }
+    }
+
+    // Lets the window manager move or resize the window if the left mouse button
+    // was pressed on a node with a WindowRegion, returns whether it was started
+    fn start_move_resize(&self, event: &XButtonEvent) -> bool {
+        if event.button != 1 {
+            return false;
+        }
+
+        let region = self
+            .internal
+            .current_window_state
+            .last_hit_test
+            .get_window_region(&self.internal.layout_results);
+
+        match region {
+            Some(region) => moveresize::start_move_resize(
+                self.dpy.display,
+                self.id,
+                region,
+                event.x_root,
+                event.y_root,
+                event.button,
+            ),
+            None => false,
+        }
+    }
+
+    // Hit-tests the current cursor position against the last submitted display list
+    fn update_hit_test(&mut self) {
+        let hit_test = crate::wr_translate::fullhittest_new_webrender(
+            &*self.hit_tester.resolve(),
+            self.internal.document_id,
+            self.internal.current_window_state.focused_node,
+            &self.internal.layout_results,
+            &self
+                .internal
+                .current_window_state
+                .mouse_state
+                .cursor_position,
+            self.internal.current_window_state.size.get_hidpi_factor(),
+        );
+        self.internal.current_window_state.last_hit_test = hit_test;
+    }
+
+    // Rebuilds the display list, requests a new hit-tester and redraws the window
+    fn regenerate_display_list(&mut self, image_cache: &ImageCache) {
+        use crate::wr_translate::wr_translate_document_id;
+
+        rebuild_display_list(
+            &mut self.internal,
+            &mut self.render_api,
+            image_cache,
+            Vec::new(), // no resource updates
+        );
+
+        let wr_document_id = wr_translate_document_id(self.internal.document_id);
+        self.hit_tester =
+            AsyncHitTester::Requested(self.render_api.request_hit_tester(wr_document_id));
+
+        generate_frame(&mut self.internal, &mut self.render_api, true);
+
+        self.request_redraw();
+    }
+
+    // Invokes the layout callback, restyles and relayouts the window
+    // and submits the new display list to webrender
+    fn regenerate_dom(&mut self, app: &mut ApplicationData) {
+        use crate::wr_translate::wr_translate_document_id;
+        use azul_core::window_state::StyleAndLayoutChanges;
+
+        let data = &mut app.data;
+        let image_cache = &app.image_cache;
+        let fc_cache = &mut app.fc_cache;
+
+        let document_id = self.internal.document_id;
+        let hit_tester = &mut self.hit_tester;
+        let internal = &mut self.internal;
+        let gl_context = &self.gl_context_ptr;
+
+        // unset the focus
+        internal.current_window_state.focused_node = None;
+
+        let mut resource_updates = Vec::new();
+        fc_cache.apply_closure(|fc_cache| {
+            internal.regenerate_styled_dom(
+                data,
+                image_cache,
+                gl_context,
+                &mut resource_updates,
+                internal.get_dpi_scale_factor(),
+                &crate::app::CALLBACKS,
+                fc_cache,
+                azul_layout::do_the_relayout,
+                |window_state, scroll_states, layout_results| {
+                    crate::wr_translate::fullhittest_new_webrender(
+                        &*hit_tester.resolve(),
+                        document_id,
+                        window_state.focused_node,
+                        layout_results,
+                        &window_state.mouse_state.cursor_position,
+                        window_state.size.get_hidpi_factor(),
+                    )
+                },
+            );
+        });
+
+        // rebuild the display list and send it
+        rebuild_display_list(
+            &mut self.internal,
+            &mut self.render_api,
+            image_cache,
+            resource_updates,
+        );
+
+        self.render_api.flush_scene_builder();
+
+        let wr_document_id = wr_translate_document_id(self.internal.document_id);
+        self.hit_tester =
+            AsyncHitTester::Requested(self.render_api.request_hit_tester(wr_document_id));
+
+        self.update_hit_test();
+        self.internal.previous_window_state = None;
+
+        let nodes_to_check = NodesToCheck::simulated_mouse_move(
+            &self.internal.current_window_state.last_hit_test,
+            self.internal.current_window_state.focused_node,
+            self.internal.current_window_state.mouse_state.mouse_down(),
+        );
+
+        let _ = StyleAndLayoutChanges::new(
+            &nodes_to_check,
+            &mut self.internal.layout_results,
+            &image_cache,
+            &mut self.internal.renderer_resources,
+            self.internal.current_window_state.size.get_layout_size(),
+            &self.internal.document_id,
+            None,
+            None,
+            &None,
+            azul_layout::do_the_relayout,
+        );
+
+        self.regenerate_display_list(image_cache);
+        self.dom_regenerated = true;
+    }
+}
+
+// Windows that have an open modal child window (WindowId of the parent => X11 window of the child)
+fn get_modal_children(windows: &BTreeMap<u64, X11Window>) -> BTreeMap<WindowId, u64> {
+    windows
+        .values()
+        .filter(|w| w.modal)
+        .filter_map(|w| Some((w.parent?, w.id)))
+        .collect()
+}
+
+// If F12 was pressed in a window that isn't inspected yet, returns the options for the inspector window
+#[cfg(feature = "css_parser")]
+fn get_inspector_create_options(
+    windows: &mut BTreeMap<u64, X11Window>,
+    window_id: u64,
+) -> Option<WindowCreateOptions> {
+    if !get_inspector_windows(windows, window_id).is_empty() {
+        return None;
+    }
+
+    let window = windows.get_mut(&window_id)?;
+
+    // don't inspect the inspector
+    let layout_callback = &mut window.internal.current_window_state.layout_callback;
+    if crate::inspector::get_inspected_window(layout_callback).is_some() {
+        return None;
+    }
+
+    Some(crate::inspector::open_inspector(
+        window_id as usize,
+        &window.internal.layout_results,
+    ))
+}
+
+#[cfg(not(feature = "css_parser"))]
+fn get_inspector_create_options(
+    _: &mut BTreeMap<u64, X11Window>,
+    _: u64,
+) -> Option<WindowCreateOptions> {
+    None
+}
+
+// Returns the window ID and the layout callback (holding the shared inspector data)
+// of all inspector windows that are inspecting the given window
+#[cfg(feature = "css_parser")]
+fn get_inspector_windows(
+    windows: &mut BTreeMap<u64, X11Window>,
+    inspected: u64,
+) -> Vec<(u64, LayoutCallback)> {
+    windows
+        .values_mut()
+        .filter_map(|w| {
+            let layout_callback = &mut w.internal.current_window_state.layout_callback;
+            if crate::inspector::get_inspected_window(layout_callback) == Some(inspected as usize) {
+                Some((w.id, layout_callback.clone()))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+// Updates the snapshot of the inspectors of all windows that regenerated their DOM
+#[cfg(feature = "css_parser")]
+fn update_inspector_windows(windows: &mut BTreeMap<u64, X11Window>, app: &mut ApplicationData) {
+    let regenerated = windows
+        .values_mut()
+        .filter(|w| mem::replace(&mut w.dom_regenerated, false))
+        .map(|w| w.id)
+        .collect::<Vec<_>>();
+
+    for inspected in regenerated {
+        let inspectors = get_inspector_windows(windows, inspected);
+        let inspected_window = match windows.get(&inspected) {
+            Some(s) => s,
+            None => continue,
+        };
+
+        for (_, layout_callback) in inspectors.iter() {
+            crate::inspector::update_inspector(
+                &mut layout_callback.clone(),
+                &inspected_window.internal.layout_results,
+            );
+        }
+
+        for (inspector_id, _) in inspectors {
+            if let Some(inspector_window) = windows.get_mut(&inspector_id) {
+                inspector_window.make_current();
+                inspector_window.regenerate_dom(app);
+                inspector_window.dom_regenerated = false;
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "css_parser"))]
+fn update_inspector_windows(_: &mut BTreeMap<u64, X11Window>, _: &mut ApplicationData) {}
+
+// Copies the nodes hovered in the inspector windows to the inspected windows,
+// the highlight is removed once the inspector window is closed
+#[cfg(feature = "css_parser")]
+fn sync_inspector_highlights(windows: &mut BTreeMap<u64, X11Window>, image_cache: &ImageCache) {
+    let highlights = windows
+        .values_mut()
+        .filter_map(|w| {
+            let layout_callback = &mut w.internal.current_window_state.layout_callback;
+            let inspected = crate::inspector::get_inspected_window(layout_callback)?;
+            let node_id = crate::inspector::get_inspector_highlight(layout_callback);
+            Some((inspected as u64, node_id))
+        })
+        .collect::<BTreeMap<_, _>>();
+
+    for window in windows.values_mut() {
+        let node_id = highlights.get(&window.id).copied().flatten();
+        if window.internal.inspector_highlight != node_id {
+            window.internal.inspector_highlight = node_id;
+            window.make_current();
+            window.regenerate_display_list(image_cache);
+        }
+    }
+}
+
+#[cfg(not(feature = "css_parser"))]
+fn sync_inspector_highlights(_: &mut BTreeMap<u64, X11Window>, _: &ImageCache) {}
+
+// Applies the keyboard shortcuts that were registered / unregistered with
+// CallbackInfo::register_shortcut(), returns whether the shortcuts changed
+fn apply_shortcut_changes(
+    windows: &mut BTreeMap<u64, X11Window>,
+    registry: &mut ShortcutRegistry,
+) -> bool {
+    let changes = windows
+        .values_mut()
+        .flat_map(|w| mem::take(&mut w.shortcuts_changed))
+        .collect::<Vec<_>>();
+
+    // shortcuts that conflict with an already registered shortcut are not registered
+    let _ = registry.apply_changes(&changes);
+
+    !changes.is_empty()
+}
+
+// Adds the windows that were closed with CallbackInfo::close_window()
+// and the child windows of all closed windows to windows_to_close
+fn add_windows_to_close(windows: &mut BTreeMap<u64, X11Window>, windows_to_close: &mut Vec<u64>) {
+    let closed_in_callbacks = windows
+        .values_mut()
+        .flat_map(|w| mem::take(&mut w.windows_closed))
+        .collect::<Vec<_>>();
+
+    windows_to_close.extend(
+        windows
+            .values()
+            .filter(|w| closed_in_callbacks.contains(&w.internal.id))
+            .map(|w| w.id),
+    );
+
+    loop {
+        let closed = windows
+            .values()
+            .filter(|w| windows_to_close.contains(&w.id))
+            .map(|w| w.internal.id)
+            .collect::<Vec<_>>();
+
+        let children = windows
+            .values()
+            .filter(|w| !windows_to_close.contains(&w.id))
+            .filter(|w| w.parent.map(|p| closed.contains(&p)).unwrap_or(false))
+            .map(|w| w.id)
+            .collect::<Vec<_>>();
+
+        if children.is_empty() {
+            break;
+        }
+
+        windows_to_close.extend(children);
+    }
+}
+
+// Blocks until one of the windows has an event in its queue: every window
+// has its own connection, so waiting in XNextEvent() would block the others.
+// While threads are running, the function returns after 16ms to poll them.
+fn wait_for_events(
+    xlib: &Xlib,
+    windows: &mut BTreeMap<u64, X11Window>,
+    global_shortcuts: Option<&shortcuts::GlobalShortcuts>,
+    get_system_time_fn: GetSystemTimeCallback,
+) {
+    use self::clipboard::{poll, pollfd, POLLIN};
+
+    if windows
+        .values_mut()
+        .any(|w| (xlib.XPending)(w.dpy.get()) > 0)
+        || global_shortcuts.map(|g| g.has_pending()).unwrap_or(false)
+    {
+        return;
+    }
+
+    let mut fds = windows
+        .values_mut()
+        .map(|w| pollfd {
+            fd: (xlib.XConnectionNumber)(w.dpy.get()),
+            events: POLLIN,
+            revents: 0,
+        })
+        .collect::<Vec<_>>();
+
+    if let Some(g) = global_shortcuts {
+        fds.push(pollfd {
+            fd: g.get_fd(),
+            events: POLLIN,
+            revents: 0,
+        });
+    }
+
+    let thread_timeout = if windows.values().any(|w| !w.internal.threads.is_empty()) {
+        Some(THREAD_POLL_INTERVAL_MS)
+    } else {
+        None
+    };
+
+    // wake up when the long-press of a finger is recognized
+    let long_press_timeout = windows
+        .values()
+        .filter_map(|w| w.touch.as_ref()?.get_long_press_timeout())
+        .min()
+        .map(|t| t.min(c_int::MAX as u64) as c_int);
+
+    // wake up when the next timer is due
+    let now = (get_system_time_fn.cb)();
+    let timer_timeout = windows
+        .values()
+        .flat_map(|w| w.internal.timers.values())
+        .map(|t| t.millis_until_next_run(&now))
+        .min()
+        .map(|t| t.min(c_int::MAX as u64) as c_int);
+
+    let timeout = [thread_timeout, long_press_timeout, timer_timeout]
+        .iter()
+        .filter_map(|t| *t)
+        .min()
+        .unwrap_or(-1);
+
+    unsafe { poll(fds.as_mut_ptr(), fds.len() as c_ulong, timeout) };
+}
+
+// Node that is passed to the callbacks of menus that don't
+// belong to a node (the menu bar and the tray icon menu)
+fn get_root_dom_node() -> DomNodeId {
+    use azul_core::styled_dom::NodeHierarchyItemId;
+
+    DomNodeId {
+        dom: DomId::ROOT_ID,
+        node: NodeHierarchyItemId::from_crate_internal(None),
+    }
+}
+
+// Applies the input to the menu of the owner window and creates, redraws or
+// destroys the popup windows of the menu. Returns whether the DOM of all windows
+// has to be regenerated (after the callback of a menu item was invoked)
+#[cfg(feature = "css_parser")]
+fn handle_menu_input(
+    active_windows: &mut BTreeMap<u64, X11Window>,
+    owner_id: u64,
+    input: menu::MenuInput,
+    xlib: &Rc<Xlib>,
+    egl: &Rc<Egl>,
+    app_data: &Rc<RefCell<ApplicationData>>,
+    new_windows: &mut Vec<WindowCreateOptions>,
+    windows_to_close: &mut Vec<u64>,
+) -> bool {
+    use self::menu::{MenuCommand, MenuInput};
+    use azul_core::window::VirtualKeyCode;
+
+    let mut regenerate_all_windows = false;
+
+    let owner = match active_windows.get_mut(&owner_id) {
+        Some(s) => s,
+        None => return false,
+    };
+
+    let mut commands = match input {
+        MenuInput::Open(new_menu) => {
+            let mut commands = owner
+                .menu
+                .as_mut()
+                .map(|m| m.handle_input(MenuInput::Close))
+                .unwrap_or_default();
+            owner.menu = Some(new_menu);
+            commands.push(MenuCommand::Open(0));
+            commands
+        }
+        input => match owner.menu.as_mut() {
+            Some(s) => s.handle_input(input),
+            None => return false,
+        },
+    };
+
+    let mut i = 0;
+    while let Some(command) = commands.get(i).cloned() {
+        i += 1;
+        match command {
+            MenuCommand::Open(level) => {
+                let owner = match active_windows.get_mut(&owner_id) {
+                    Some(s) => s,
+                    None => break,
+                };
+                let mut options = match owner.menu.as_ref().and_then(|m| {
+                    Some(
+                        m.levels
+                            .get(level)?
+                            .get_window_options(m.menu_bar_item.is_some()),
+                    )
+                }) {
+                    Some(s) => s,
+                    None => continue,
+                };
+                let mut popup = match X11Window::new(
+                    xlib.clone(),
+                    egl.clone(),
+                    &mut options,
+                    SharedApplicationData {
+                        inner: app_data.clone(),
+                    },
+                ) {
+                    Ok(o) => o,
+                    Err(_) => continue,
+                };
+                popup.menu_owner = Some(owner_id);
+                popup.show();
+                if let Some(l) = owner.menu.as_mut().and_then(|m| m.levels.get_mut(level)) {
+                    l.window = Some(popup.id);
+                }
+                active_windows.insert(popup.id, popup);
+            }
+            MenuCommand::Update(level) => {
+                let (popup_id, selected) = match active_windows
+                    .get(&owner_id)
+                    .and_then(|o| o.menu.as_ref()?.levels.get(level))
+                {
+                    Some(l) => (l.window, l.selected),
+                    None => continue,
+                };
+                let popup = match popup_id.and_then(|id| active_windows.get_mut(&id)) {
+                    Some(s) => s,
+                    None => continue,
+                };
+                if menu::set_popup_selection(
+                    &mut popup.internal.current_window_state.layout_callback,
+                    selected,
+                ) {
+                    if let Ok(mut app_borrow) = app_data.try_borrow_mut() {
+                        popup.make_current();
+                        popup.regenerate_dom(&mut *app_borrow);
+                    }
+                }
+            }
+            MenuCommand::Close(windows) => {
+                for w in windows {
+                    active_windows.remove(&w);
+                }
+            }
+            MenuCommand::Invoke(callback, hit_dom_node) => {
+                let owner = match active_windows.get_mut(&owner_id) {
+                    Some(s) => s,
+                    None => break,
+                };
+                owner.make_current();
+                if let Ok(mut app_borrow) = app_data.try_borrow_mut() {
+                    let ab = &mut *app_borrow;
+                    let result = invoke_menu_callback(
+                        owner,
+                        callback,
+                        hit_dom_node,
+                        &mut ab.fc_cache,
+                        &mut ab.image_cache,
+                        &ab.config,
+                        new_windows,
+                        windows_to_close,
+                    );
+                    regenerate_all_windows |= apply_process_event_result(
+                        result,
+                        owner,
+                        ab,
+                        new_windows,
+                        windows_to_close,
+                    );
+                }
+            }
+            MenuCommand::SwitchMenuBarItem(direction) => {
+                let owner = match active_windows.get_mut(&owner_id) {
+                    Some(s) => s,
+                    None => break,
+                };
+                let next = owner
+                    .menu
+                    .as_ref()
+                    .and_then(|m| m.menu_bar_item)
+                    .and_then(|current| {
+                        let menu_bar = owner.internal.get_menu_bar()?;
+                        menu::get_adjacent_menu_bar_item(menu_bar, current, direction)
+                    });
+                let mut new_menu = match next.and_then(|n| owner.open_menu_bar_item(n)) {
+                    Some(s) => s,
+                    None => continue,
+                };
+                // select the first item, so that the keyboard navigation
+                // continues in the new menu (its popup doesn't exist yet)
+                let _ = new_menu.handle_input(MenuInput::Key(VirtualKeyCode::Down));
+                if let Some(old_menu) = owner.menu.as_mut() {
+                    commands.extend(old_menu.handle_input(MenuInput::Close));
+                }
+                owner.menu = Some(new_menu);
+                commands.push(MenuCommand::Open(0));
+            }
+        }
+    }
+
+    // the menu bar highlights the item whose drop-down menu is open
+    if let Some(owner) = active_windows.get_mut(&owner_id) {
+        if owner.menu.as_ref().map(|m| m.is_closed()).unwrap_or(false) {
+            owner.menu = None;
+        }
+        let open_item = owner.menu.as_ref().and_then(|m| m.menu_bar_item);
+        if menu::set_menu_bar_open_item(
+            &mut owner.internal.current_window_state.layout_callback,
+            open_item,
+        ) {
+            if let Ok(mut app_borrow) = app_data.try_borrow_mut() {
+                owner.make_current();
+                owner.regenerate_dom(&mut *app_borrow);
+            }
+        }
+    }
+
+    regenerate_all_windows
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub(super) enum ProcessEventResult {
+    DoNothing = 0,
+    ShouldReRenderCurrentWindow = 1,
+    ShouldUpdateDisplayListCurrentWindow = 2,
+    // GPU transforms changed: do another hit-test and recurse
+    // until nothing has changed anymore
+    UpdateHitTesterAndProcessAgain = 3,
+    // Only refresh the display (in case of pure scroll or GPU-only events)
+    ShouldRegenerateDomCurrentWindow = 4,
+    ShouldRegenerateDomAllWindows = 5,
+}
+
+// Assuming that current_window_state and the previous_window_state of the window
+// are set correctly and the hit-test has been performed, will call the callbacks
+// and return what the application should do next
+#[must_use]
+fn process_event(
+    window: &mut X11Window,
+    fc_cache: &mut LazyFcCache,
+    image_cache: &mut ImageCache,
+    config: &AppConfig,
+    new_windows: &mut Vec<WindowCreateOptions>,
+    destroyed_windows: &mut Vec<u64>,
+) -> ProcessEventResult {
+    use azul_core::window_state::{CallbacksOfHitTest, Events};
+
+    // Get events
+    let events = Events::new(
+        &window.internal.current_window_state,
+        &window.internal.previous_window_state,
+    );
+
+    // Get nodes for events
+    let nodes_to_check =
+        NodesToCheck::new(&window.internal.current_window_state.last_hit_test, &events);
+
+    // Drag the scrollbars before the callbacks see the mouse input
+    let scrollbar_result = window.internal.process_scrollbar_input();
+
+    // Invoke callbacks on nodes
+    let callback_result = fc_cache.apply_closure(|fc_cache| {
+        use azul_core::window::{RawWindowHandle, XlibHandle};
+
+        let window_handle = RawWindowHandle::Xlib(XlibHandle {
+            window: window.id,
+            display: window.dpy.display as *mut c_void,
+        });
+
+        // the nodes below a pressed scrollbar don't get the mouse input
+        if scrollbar_result.mouse_captured {
+            return window.internal.invoke_scrolled_callbacks(
+                &scrollbar_result.scrolled_nodes,
+                &window_handle,
+                &window.gl_context_ptr,
+                image_cache,
+                fc_cache,
+                &config.system_callbacks,
+            );
+        }
+
+        // Get callbacks for nodes
+        let mut callbacks =
+            CallbacksOfHitTest::new(&nodes_to_check, &events, &window.internal.layout_results);
+
+        let current_scroll_states = window.internal.get_current_scroll_states();
+
+        // Invoke user-defined callbacks in the UI
+        callbacks.call(
+            &window.internal.previous_window_state,
+            &window.internal.current_window_state,
+            &window_handle,
+            &current_scroll_states,
+            &window.gl_context_ptr,
+            &mut window.internal.layout_results,
+            &mut window.internal.scroll_states,
+            image_cache,
+            fc_cache,
+            &config.system_callbacks,
+            &window.internal.renderer_resources,
+        )
+    });
+
+    let result = process_callback_results(
+        callback_result,
+        window,
+        &nodes_to_check,
+        image_cache,
+        fc_cache,
+        new_windows,
+        destroyed_windows,
+    );
+
+    // the scrollbars are drawn on top of the display list
+    if scrollbar_result.needs_redraw {
+        result.max(ProcessEventResult::ShouldUpdateDisplayListCurrentWindow)
+    } else if scrollbar_result.scrolled_nodes.is_empty() {
+        result
+    } else if window.internal.scrollbar_geometry_changed() {
+        // scrolling a nested scroll container can clip the scrollbars inside of it
+        result.max(ProcessEventResult::ShouldUpdateDisplayListCurrentWindow)
+    } else {
+        // dragging a scrollbar only moves the thumbs, see generate_frame()
+        result.max(ProcessEventResult::ShouldReRenderCurrentWindow)
+    }
+}
+
+// Invokes a MenuCallback on the node that the menu belongs to (the root node for
+// the menu bar, the system tray and clicked notifications)
+#[must_use]
+fn invoke_menu_callback(
+    window: &mut X11Window,
+    mut menu_callback: MenuCallback,
+    hit_dom_node: DomNodeId,
+    fc_cache: &mut LazyFcCache,
+    image_cache: &mut ImageCache,
+    config: &AppConfig,
+    new_windows: &mut Vec<WindowCreateOptions>,
+    destroyed_windows: &mut Vec<u64>,
+) -> ProcessEventResult {
+    use azul_core::window::{RawWindowHandle, XlibHandle};
+
+    window.internal.previous_window_state = Some(window.internal.current_window_state.clone());
+
+    let window_handle = RawWindowHandle::Xlib(XlibHandle {
+        window: window.id,
+        display: window.dpy.display as *mut c_void,
+    });
+
+    let internal = &mut window.internal;
+    let gl_context_ptr = &window.gl_context_ptr;
+
+    let callback_result = fc_cache.apply_closure(|fc_cache| {
+        internal.invoke_menu_callback(
+            &mut menu_callback,
+            hit_dom_node,
+            &window_handle,
+            gl_context_ptr,
+            image_cache,
+            fc_cache,
+            &config.system_callbacks,
+        )
+    });
+
+    let nodes_to_check = NodesToCheck::empty(
+        window
+            .internal
+            .current_window_state
+            .mouse_state
+            .mouse_down(),
+        window.internal.current_window_state.focused_node,
+    );
+
+    process_callback_results(
+        callback_result,
+        window,
+        &nodes_to_check,
+        image_cache,
+        fc_cache,
+        new_windows,
+        destroyed_windows,
+    )
+}
+
+// Polls the messages of all threads of the window and invokes their WriteBack callbacks
+#[must_use]
+fn invoke_threads(
+    window: &mut X11Window,
+    data: &mut RefAny,
+    fc_cache: &mut LazyFcCache,
+    image_cache: &mut ImageCache,
+    config: &AppConfig,
+    new_windows: &mut Vec<WindowCreateOptions>,
+    destroyed_windows: &mut Vec<u64>,
+) -> ProcessEventResult {
+    use azul_core::window::{RawWindowHandle, XlibHandle};
+
+    window.internal.previous_window_state = Some(window.internal.current_window_state.clone());
+
+    let window_handle = RawWindowHandle::Xlib(XlibHandle {
+        window: window.id,
+        display: window.dpy.display as *mut c_void,
+    });
+
+    let internal = &mut window.internal;
+    let gl_context_ptr = &window.gl_context_ptr;
+
+    let callback_result = fc_cache.apply_closure(|fc_cache| {
+        internal.run_all_threads(
+            data,
+            &window_handle,
+            gl_context_ptr,
+            image_cache,
+            fc_cache,
+            &config.system_callbacks,
+        )
+    });
+
+    let nodes_to_check = NodesToCheck::empty(
+        window
+            .internal
+            .current_window_state
+            .mouse_state
+            .mouse_down(),
+        window.internal.current_window_state.focused_node,
+    );
+
+    process_callback_results(
+        callback_result,
+        window,
+        &nodes_to_check,
+        image_cache,
+        fc_cache,
+        new_windows,
+        destroyed_windows,
+    )
+}
+
+// Runs the timers of the window that are due
+#[must_use]
+fn invoke_timers(
+    window: &mut X11Window,
+    fc_cache: &mut LazyFcCache,
+    image_cache: &mut ImageCache,
+    config: &AppConfig,
+    new_windows: &mut Vec<WindowCreateOptions>,
+    destroyed_windows: &mut Vec<u64>,
+) -> ProcessEventResult {
+    use azul_core::window::{RawWindowHandle, XlibHandle};
+
+    let window_handle = RawWindowHandle::Xlib(XlibHandle {
+        window: window.id,
+        display: window.dpy.display as *mut c_void,
+    });
+
+    let frame_start = (config.system_callbacks.get_system_time_fn.cb)();
+    let due_timers = window
+        .internal
+        .timers
+        .iter()
+        .filter(|(_, timer)| timer.millis_until_next_run(&frame_start) == 0)
+        .map(|(timer_id, _)| timer_id.id)
+        .collect::<Vec<_>>();
+
+    let mut result = ProcessEventResult::DoNothing;
+
+    for timer_id in due_timers {
+        window.internal.previous_window_state = Some(window.internal.current_window_state.clone());
+
+        let internal = &mut window.internal;
+        let gl_context_ptr = &window.gl_context_ptr;
+
+        let callback_result = fc_cache.apply_closure(|fc_cache| {
+            internal.run_single_timer(
+                timer_id,
+                frame_start.clone(),
+                &window_handle,
+                gl_context_ptr,
+                image_cache,
+                fc_cache,
+                &config.system_callbacks,
+            )
+        });
+
+        let nodes_to_check = NodesToCheck::empty(
+            window
+                .internal
+                .current_window_state
+                .mouse_state
+                .mouse_down(),
+            window.internal.current_window_state.focused_node,
+        );
+
+        result = result.max(process_callback_results(
+            callback_result,
+            window,
+            &nodes_to_check,
+            image_cache,
+            fc_cache,
+            new_windows,
+            destroyed_windows,
+        ));
+    }
+
+    result
+}
+
+// Invokes the result callback of a closed child window on its parent window
+#[must_use]
+fn invoke_window_result_callback(
+    window: &mut X11Window,
+    result_callback: &mut WindowResultCallback,
+    window_result: &mut RefAny,
+    fc_cache: &mut LazyFcCache,
+    image_cache: &mut ImageCache,
+    config: &AppConfig,
+    new_windows: &mut Vec<WindowCreateOptions>,
+    destroyed_windows: &mut Vec<u64>,
+) -> ProcessEventResult {
+    use azul_core::window::{RawWindowHandle, XlibHandle};
+
+    window.internal.previous_window_state = Some(window.internal.current_window_state.clone());
+
+    let window_handle = RawWindowHandle::Xlib(XlibHandle {
+        window: window.id,
+        display: window.dpy.display as *mut c_void,
+    });
+
+    let internal = &mut window.internal;
+    let gl_context_ptr = &window.gl_context_ptr;
+
+    let callback_result = fc_cache.apply_closure(|fc_cache| {
+        internal.invoke_window_result_callback(
+            result_callback,
+            window_result,
+            &window_handle,
+            gl_context_ptr,
+            image_cache,
+            fc_cache,
+            &config.system_callbacks,
+        )
+    });
+
+    let nodes_to_check = NodesToCheck::empty(
+        window
+            .internal
+            .current_window_state
+            .mouse_state
+            .mouse_down(),
+        window.internal.current_window_state.focused_node,
+    );
+
+    process_callback_results(
+        callback_result,
+        window,
+        &nodes_to_check,
+        image_cache,
+        fc_cache,
+        new_windows,
+        destroyed_windows,
+    )
+}
+
+#[must_use]
+fn process_callback_results(
+    callback_results: CallCallbacksResult,
+    window: &mut X11Window,
+    nodes_to_check: &NodesToCheck,
+    image_cache: &mut ImageCache,
+    fc_cache: &mut LazyFcCache,
+    new_windows: &mut Vec<WindowCreateOptions>,
+    destroyed_windows: &mut Vec<u64>,
+) -> ProcessEventResult {
+    use crate::wr_translate::wr_translate_document_id;
+    use azul_core::callbacks::Update;
+    use azul_core::window_state::StyleAndLayoutChanges;
+
+    let mut result = ProcessEventResult::DoNothing;
+
+    if callback_results.images_changed.is_some() || callback_results.image_masks_changed.is_some() {
+        let updated_images = window.internal.renderer_resources.update_image_resources(
+            &window.internal.layout_results,
+            callback_results.images_changed.unwrap_or_default(),
+            callback_results.image_masks_changed.unwrap_or_default(),
+            &crate::app::CALLBACKS,
+            &*image_cache,
+            &mut window.internal.gl_texture_cache,
+            window.internal.document_id,
+            window.internal.epoch,
+        );
+
+        if !updated_images.is_empty() {
+            let mut txn = WrTransaction::new();
+            wr_synchronize_updated_images(updated_images, &window.internal.document_id, &mut txn);
+            window
+                .render_api
+                .send_transaction(wr_translate_document_id(window.internal.document_id), txn);
+            result = result.max(ProcessEventResult::ShouldReRenderCurrentWindow);
+        }
+    }
+
+    // timers are run in the event loop (see invoke_timers)
+    if let Some(timers) = callback_results.timers {
+        window.internal.timers.extend(timers.into_iter());
+    }
+    if let Some(timers_removed) = callback_results.timers_removed.as_ref() {
+        window
+            .internal
+            .timers
+            .retain(|id, _| !timers_removed.contains(id));
+    }
+
+    // threads are polled in the event loop (see invoke_threads)
+    if let Some(threads) = callback_results.threads {
+        window.internal.threads.extend(threads.into_iter());
+    }
+    if let Some(threads_removed) = callback_results.threads_removed.as_ref() {
+        window
+            .internal
+            .threads
+            .retain(|id, _| !threads_removed.contains(id));
+    }
+
+    for w in callback_results.windows_created {
+        new_windows.push(w);
+    }
+
+    // closed in the event loop, since the windows are identified by their WindowId
+    window
+        .windows_closed
+        .extend(callback_results.windows_closed.into_iter());
+    window
+        .shortcuts_changed
+        .extend(callback_results.shortcuts_changed.into_iter());
+    if let Some(result) = callback_results.window_result {
+        window.window_result = Some(result);
+    }
+
+    if !callback_results.notifications.is_empty() && window.notifications.is_none() {
+        window.notifications = notify::Notifications::new(tray::TrayWaker::X11Window(window.id));
+    }
+    if let Some(notifications) = window.notifications.as_mut() {
+        for notification in callback_results.notifications.iter() {
+            notifications.show(notification);
+        }
+    }
+
+    let scroll = window
+        .internal
+        .current_window_state
+        .process_system_scroll(&window.internal.scroll_states);
+    let need_scroll_render = scroll.is_some() || callback_results.should_scroll_render;
+
+    if let Some(modified) = callback_results.modified_window_state.as_ref() {
+        if modified.flags.is_about_to_close {
+            destroyed_windows.push(window.id);
+        }
+        let flags = &window.internal.current_window_state.flags;
+        if modified.flags.is_click_through != flags.is_click_through {
+            transparency::set_click_through(
+                window.dpy.display,
+                window.id,
+                modified.flags.is_click_through,
+            );
+        }
+        if modified.flags.has_blur_behind_window != flags.has_blur_behind_window {
+            transparency::set_blur_behind(
+                window.dpy.display,
+                window.id,
+                modified.flags.has_blur_behind_window,
+            );
+        }
+        window.internal.current_window_state = FullWindowState::from_window_state(
+            modified,
+            window.internal.current_window_state.dropped_file.clone(),
+            window.internal.current_window_state.hovered_file.clone(),
+            window.internal.current_window_state.focused_node.clone(),
+            window.internal.current_window_state.last_hit_test.clone(),
+            window.internal.current_window_state.clipboard_sequence,
+            window.internal.current_window_state.drag_state.clone(),
+            window.internal.current_window_state.ime_state.clone(),
+        );
+        // a new layout callback has to be wrapped again to render the menu bar
+        #[cfg(feature = "css_parser")]
+        {
+            if window.menu_owner.is_none() {
+                menu::add_menu_bar(&mut window.internal.current_window_state.layout_callback);
+            }
+        }
+        if modified.size.get_layout_size()
+            != window.internal.current_window_state.size.get_layout_size()
+        {
+            result = result.max(ProcessEventResult::UpdateHitTesterAndProcessAgain);
+        } else if !need_scroll_render {
+            result = result.max(ProcessEventResult::ShouldReRenderCurrentWindow);
+        }
+        window.update_tray_icon();
+    }
+
+    // move the candidate window of the input method to the text cursor
+    let ime_position = window.internal.current_window_state.ime_position;
+    let ime_position_changed = window
+        .internal
+        .previous_window_state
+        .as_ref()
+        .map(|p| p.ime_position != ime_position)
+        .unwrap_or(true);
+    if let (true, ImePosition::Initialized(position)) = (ime_position_changed, ime_position) {
+        let hidpi_factor = window.internal.current_window_state.size.get_hidpi_factor();
+        if let Some(xim) = window.xim.as_mut() {
+            xim.set_spot_location(
+                (position.x * hidpi_factor) as c_int,
+                (position.y * hidpi_factor) as c_int,
+            );
+        }
+    }
+
+    if let Some(drag_data) = callback_results.update_drag_data {
+        window
+            .internal
+            .current_window_state
+            .drag_state
+            .update_drag_data(drag_data);
+    }
+
+    // the drag image is drawn on top of the display list
+    if window
+        .internal
+        .current_window_state
+        .drag_image_changed(&window.internal.previous_window_state)
+    {
+        result = result.max(ProcessEventResult::ShouldUpdateDisplayListCurrentWindow);
+    }
+
+    let layout_callback_changed = window
+        .internal
+        .current_window_state
+        .layout_callback_changed(&window.internal.previous_window_state);
+
+    if layout_callback_changed {
+        return ProcessEventResult::ShouldRegenerateDomCurrentWindow;
+    } else {
+        match callback_results.callbacks_update_screen {
+            Update::RefreshDom => {
+                return ProcessEventResult::ShouldRegenerateDomCurrentWindow;
+            }
+            Update::RefreshDomAllWindows => {
+                return ProcessEventResult::ShouldRegenerateDomAllWindows;
+            }
+            Update::DoNothing => {}
+        }
+    }
+
+    // Re-layout and re-style the window.internal.layout_results
+    let style_layout_changes = StyleAndLayoutChanges::new(
+        &nodes_to_check,
+        &mut window.internal.layout_results,
+        &image_cache,
+        &mut window.internal.renderer_resources,
+        window.internal.current_window_state.size.get_layout_size(),
+        &window.internal.document_id,
+        callback_results.css_properties_changed.as_ref(),
+        callback_results.words_changed.as_ref(),
+        &callback_results.update_focused_node,
+        azul_layout::do_the_relayout,
+    );
+
+    if let Some(rsn) = style_layout_changes.nodes_that_changed_size.as_ref() {
+        let updated_images = fc_cache.apply_closure(|fc_cache| {
+            LayoutResult::resize_images(
+                window.internal.id_namespace,
+                window.internal.document_id,
+                window.internal.epoch,
+                DomId::ROOT_ID,
+                &image_cache,
+                &window.gl_context_ptr,
+                &mut window.internal.layout_results,
+                &mut window.internal.gl_texture_cache,
+                &mut window.internal.renderer_resources,
+                &crate::app::CALLBACKS,
+                azul_layout::do_the_relayout,
+                fc_cache,
+                &window.internal.current_window_state.size,
+                window.internal.current_window_state.theme,
+                &rsn,
+            )
+        });
+
+        if !updated_images.is_empty() {
+            let mut txn = WrTransaction::new();
+            wr_synchronize_updated_images(updated_images, &window.internal.document_id, &mut txn);
+            window
+                .render_api
+                .send_transaction(wr_translate_document_id(window.internal.document_id), txn);
+        }
+    }
+
+    // FOCUS CHANGE HAPPENS HERE!
+    if let Some(focus_change) = style_layout_changes.focus_change.clone() {
+        window.internal.current_window_state.focused_node = focus_change.new;
+    }
+
+    // TODO: system scrolling is not yet implemented
+    if scroll.is_some() {
+        window
+            .internal
+            .current_window_state
+            .mouse_state
+            .reset_scroll_to_zero();
+    }
+
+    if style_layout_changes.did_resize_nodes() {
+        // at least update the hit-tester
+        result.max(ProcessEventResult::UpdateHitTesterAndProcessAgain)
+    } else if style_layout_changes.need_regenerate_display_list()
+        || (need_scroll_render && window.internal.scrollbar_geometry_changed())
+    {
+        // scrolling a nested scroll container can clip the scrollbars inside of it
+        result.max(ProcessEventResult::ShouldUpdateDisplayListCurrentWindow)
+    } else if need_scroll_render || style_layout_changes.need_redraw() {
+        // scrolling only moves the scrollbars, see generate_frame()
+        result.max(ProcessEventResult::ShouldReRenderCurrentWindow)
+    } else {
+        result
+    }
+}
+
+// Regenerates the DOM / display list or re-renders the window, depending on the
+// result of process_event(). Returns whether the DOM of all windows needs to be
+// regenerated, which has to be done by the caller (since it owns all windows)
+fn apply_process_event_result(
+    mut result: ProcessEventResult,
+    window: &mut X11Window,
+    app: &mut ApplicationData,
+    new_windows: &mut Vec<WindowCreateOptions>,
+    destroyed_windows: &mut Vec<u64>,
+) -> bool {
+    loop {
+        match result {
+            ProcessEventResult::DoNothing => return false,
+            ProcessEventResult::ShouldReRenderCurrentWindow => {
+                generate_frame(&mut window.internal, &mut window.render_api, false);
+                window.request_redraw();
+                return false;
+            }
+            ProcessEventResult::ShouldUpdateDisplayListCurrentWindow => {
+                window.regenerate_display_list(&app.image_cache);
+                return false;
+            }
+            ProcessEventResult::UpdateHitTesterAndProcessAgain => {
+                window.internal.previous_window_state =
+                    Some(window.internal.current_window_state.clone());
+                window.regenerate_display_list(&app.image_cache);
+                window.update_hit_test();
+                result = process_event(
+                    window,
+                    &mut app.fc_cache,
+                    &mut app.image_cache,
+                    &app.config,
+                    new_windows,
+                    destroyed_windows,
+                );
+            }
+            ProcessEventResult::ShouldRegenerateDomCurrentWindow => {
+                window.regenerate_dom(app);
+                return false;
+            }
+            ProcessEventResult::ShouldRegenerateDomAllWindows => return true,
+        }
+    }
+}
+
+struct X11Display {
+    display: *mut Display,
+    xlib: Rc<Xlib>,
+}
+
+impl X11Display {
+    fn get<'a>(&'a mut self) -> &'a mut Display {
+        unsafe { &mut *self.display }
+    }
+
+    fn open(xlib: Rc<Xlib>) -> Option<Self> {
+        let dpy = unsafe { (xlib.XOpenDisplay)(&0) };
+
+        if dpy.is_null() {
+            return None;
+        }
+
+        Some(Self { display: dpy, xlib })
+    }
+
+    /// Return the DPI on X11 systems
     ///
     /// Note: slow - cache output!
     pub fn get_dpi_scale_factor(&self) -> f32 {
@@ -2039,22 +4908,9 @@ impl X11Display {
             return s;
         }
 
-        // Failed again, try getting monitor info from XRandR
-        // TODO
+        // Failed again: the scale factors of monitors with a different
+        // pixel density are derived from XRandR (see monitor.rs)
         return 1.0;
-
-        /*
-            XRRScreenResources *xrrr = XRRGetScreenResources(d, w);
-            XRRCrtcInfo *xrrci;
-            int i;
-            int ncrtc = xrrr->ncrtc;
-            for (i = 0; i < ncrtc; ++i) {
-                xrrci = XRRGetCrtcInfo(d, xrrr, xrrr->crtcs[i]);
-                printf("%dx%d+%d+%d\n", xrrci->width, xrrci->height, xrrci->x, xrrci->y);
-                XRRFreeCrtcInfo(xrrci);
-            }
-            XRRFreeScreenResources(xrrr);
-        */
     }
 }
 
@@ -2130,11 +4986,11 @@ impl Drop for Library {
 }
 
 // OpenGL functions from wglGetProcAddress OR loaded from opengl32.dll
-struct GlFunctions {
+pub(super) struct GlFunctions {
     _opengl32_dll_handle: Option<Library>,
     egl: Rc<Egl>,
     // implements Rc<dyn gleam::Gl>!
-    functions: Rc<GenericGlContext>,
+    pub(super) functions: Rc<GenericGlContext>,
 }
 
 impl fmt::Debug for GlFunctions {
@@ -2158,7 +5014,7 @@ fn encode_ascii(input: &str) -> Vec<u8> {
 
 impl GlFunctions {
     // Initializes the DLL, but does not load the functions yet
-    fn initialize(egl: Rc<Egl>) -> Self {
+    pub(super) fn initialize(egl: Rc<Egl>) -> Self {
         // zero-initialize all function pointers
         let context: GenericGlContext = unsafe { mem::zeroed() };
         let opengl32_dll = Library::load("GL").ok();
@@ -2171,7 +5027,7 @@ impl GlFunctions {
     }
 
     // Assuming the OpenGL context is current, loads the OpenGL function pointers
-    fn load(&mut self) {
+    pub(super) fn load(&mut self) {
         fn get_func(
             egl: &Egl,
             s: &'static str,
@@ -0,0 +1,531 @@
+//! Monitors and per-monitor scale factors (XRandR)
+//!
+//! The monitors are the connected outputs of the XRandR screen resources.
+//! X11 has no per-monitor DPI setting: the scale factor of the primary
+//! monitor is taken from the `Xft.dpi` resource (falling back to the desktop
+//! settings), the other monitors are scaled relative to the primary monitor
+//! by the ratio of their physical pixel densities.
+//!
+//! Windows subscribe to the RandR events of their display connection and
+//! re-query the monitors when the monitor configuration changes.
+
+use super::{Display, Library, Window, XEvent};
+use crate::gl::{c_char, c_int, c_uint, c_ulong, c_ushort};
+use azul_core::window::{Monitor, MonitorVec, VideoMode};
+use azul_css::{AzString, LayoutPoint, LayoutSize, OptionAzString};
+use once_cell::sync::Lazy;
+use std::ffi::CStr;
+
+type RROutput = c_ulong;
+type RRCrtc = c_ulong;
+type RRMode = c_ulong;
+type Time = c_ulong;
+
+const RR_SCREEN_CHANGE_NOTIFY_MASK: c_int = 1 << 0;
+const RR_CRTC_CHANGE_NOTIFY_MASK: c_int = 1 << 1;
+const RR_OUTPUT_CHANGE_NOTIFY_MASK: c_int = 1 << 2;
+const RR_CONNECTED: c_ushort = 0;
+
+// RRScreenChangeNotify, RRNotify (relative to the event base of the extension)
+const RR_NUMBER_EVENTS: c_int = 2;
+
+// physical pixel densities outside of this range are bogus
+// (some monitors report their aspect ratio or 0 as their size)
+const MIN_PHYSICAL_DPI: f32 = 48.0;
+const MAX_PHYSICAL_DPI: f32 = 640.0;
+
+#[repr(C)]
+struct XRRModeInfo {
+    id: RRMode,
+    width: c_uint,
+    height: c_uint,
+    dot_clock: c_ulong,
+    h_sync_start: c_uint,
+    h_sync_end: c_uint,
+    h_total: c_uint,
+    h_skew: c_uint,
+    v_sync_start: c_uint,
+    v_sync_end: c_uint,
+    v_total: c_uint,
+    name: *mut c_char,
+    name_length: c_uint,
+    mode_flags: c_ulong,
+}
+
+#[repr(C)]
+struct XRRScreenResources {
+    timestamp: Time,
+    config_timestamp: Time,
+    ncrtc: c_int,
+    crtcs: *mut RRCrtc,
+    noutput: c_int,
+    outputs: *mut RROutput,
+    nmode: c_int,
+    modes: *mut XRRModeInfo,
+}
+
+#[repr(C)]
+struct XRROutputInfo {
+    timestamp: Time,
+    crtc: RRCrtc,
+    name: *mut c_char,
+    name_len: c_int,
+    mm_width: c_ulong,
+    mm_height: c_ulong,
+    connection: c_ushort,
+    subpixel_order: c_ushort,
+    ncrtc: c_int,
+    crtcs: *mut RRCrtc,
+    nclone: c_int,
+    clones: *mut RROutput,
+    nmode: c_int,
+    npreferred: c_int,
+    modes: *mut RRMode,
+}
+
+#[repr(C)]
+struct XRRCrtcInfo {
+    timestamp: Time,
+    x: c_int,
+    y: c_int,
+    width: c_uint,
+    height: c_uint,
+    mode: RRMode,
+    rotation: c_ushort,
+    noutput: c_int,
+    outputs: *mut RROutput,
+    rotations: c_ushort,
+    npossible: c_int,
+    possible: *mut RROutput,
+}
+
+type XRRQueryExtensionFuncType = extern "C" fn(*mut Display, *mut c_int, *mut c_int) -> c_int;
+type XRRSelectInputFuncType = extern "C" fn(*mut Display, Window, c_int);
+type XRRUpdateConfigurationFuncType = extern "C" fn(*mut XEvent) -> c_int;
+type XRRGetScreenResourcesCurrentFuncType =
+    extern "C" fn(*mut Display, Window) -> *mut XRRScreenResources;
+type XRRFreeScreenResourcesFuncType = extern "C" fn(*mut XRRScreenResources);
+type XRRGetOutputInfoFuncType =
+    extern "C" fn(*mut Display, *mut XRRScreenResources, RROutput) -> *mut XRROutputInfo;
+type XRRFreeOutputInfoFuncType = extern "C" fn(*mut XRROutputInfo);
+type XRRGetCrtcInfoFuncType =
+    extern "C" fn(*mut Display, *mut XRRScreenResources, RRCrtc) -> *mut XRRCrtcInfo;
+type XRRFreeCrtcInfoFuncType = extern "C" fn(*mut XRRCrtcInfo);
+type XRRGetOutputPrimaryFuncType = extern "C" fn(*mut Display, Window) -> RROutput;
+type XDefaultScreenFuncType = extern "C" fn(*mut Display) -> c_int;
+type XDefaultDepthFuncType = extern "C" fn(*mut Display, c_int) -> c_int;
+type XRootWindowFuncType = extern "C" fn(*mut Display, c_int) -> Window;
+type XResourceManagerStringFuncType = extern "C" fn(*mut Display) -> *const c_char;
+type XResizeWindowFuncType = extern "C" fn(*mut Display, Window, c_uint, c_uint) -> c_int;
+type XTranslateCoordinatesFuncType = extern "C" fn(
+    *mut Display,
+    Window,
+    Window,
+    c_int,
+    c_int,
+    *mut c_int,
+    *mut c_int,
+    *mut Window,
+) -> c_int;
+
+// libXrandr + Xlib functions used for querying the monitors
+struct Xrandr {
+    pub xrandr: Library,
+    pub x11: Library,
+    pub XRRQueryExtension: XRRQueryExtensionFuncType,
+    pub XRRSelectInput: XRRSelectInputFuncType,
+    pub XRRUpdateConfiguration: XRRUpdateConfigurationFuncType,
+    pub XRRGetScreenResourcesCurrent: XRRGetScreenResourcesCurrentFuncType,
+    pub XRRFreeScreenResources: XRRFreeScreenResourcesFuncType,
+    pub XRRGetOutputInfo: XRRGetOutputInfoFuncType,
+    pub XRRFreeOutputInfo: XRRFreeOutputInfoFuncType,
+    pub XRRGetCrtcInfo: XRRGetCrtcInfoFuncType,
+    pub XRRFreeCrtcInfo: XRRFreeCrtcInfoFuncType,
+    pub XRRGetOutputPrimary: XRRGetOutputPrimaryFuncType,
+    pub XDefaultScreen: XDefaultScreenFuncType,
+    pub XDefaultDepth: XDefaultDepthFuncType,
+    pub XRootWindow: XRootWindowFuncType,
+    pub XResourceManagerString: XResourceManagerStringFuncType,
+    pub XResizeWindow: XResizeWindowFuncType,
+    pub XTranslateCoordinates: XTranslateCoordinatesFuncType,
+}
+
+impl Xrandr {
+    fn new() -> Option<Self> {
+        use super::clipboard::load_function;
+
+        let xrandr = Library::load("libXrandr.so.2")
+            .or_else(|_| Library::load("libXrandr.so"))
+            .ok()?;
+        let x11 = Library::load("libX11.so").ok()?;
+
+        Some(Self {
+            XRRQueryExtension: load_function(&xrandr, "XRRQueryExtension")?,
+            XRRSelectInput: load_function(&xrandr, "XRRSelectInput")?,
+            XRRUpdateConfiguration: load_function(&xrandr, "XRRUpdateConfiguration")?,
+            XRRGetScreenResourcesCurrent: load_function(&xrandr, "XRRGetScreenResourcesCurrent")?,
+            XRRFreeScreenResources: load_function(&xrandr, "XRRFreeScreenResources")?,
+            XRRGetOutputInfo: load_function(&xrandr, "XRRGetOutputInfo")?,
+            XRRFreeOutputInfo: load_function(&xrandr, "XRRFreeOutputInfo")?,
+            XRRGetCrtcInfo: load_function(&xrandr, "XRRGetCrtcInfo")?,
+            XRRFreeCrtcInfo: load_function(&xrandr, "XRRFreeCrtcInfo")?,
+            XRRGetOutputPrimary: load_function(&xrandr, "XRRGetOutputPrimary")?,
+            XDefaultScreen: load_function(&x11, "XDefaultScreen")?,
+            XDefaultDepth: load_function(&x11, "XDefaultDepth")?,
+            XRootWindow: load_function(&x11, "XRootWindow")?,
+            XResourceManagerString: load_function(&x11, "XResourceManagerString")?,
+            XResizeWindow: load_function(&x11, "XResizeWindow")?,
+            XTranslateCoordinates: load_function(&x11, "XTranslateCoordinates")?,
+            xrandr,
+            x11,
+        })
+    }
+
+    fn get_root_window(&self, display: *mut Display) -> Window {
+        (self.XRootWindow)(display, (self.XDefaultScreen)(display))
+    }
+}
+
+static XRANDR: Lazy<Option<Xrandr>> = Lazy::new(Xrandr::new);
+
+/// Output of XRandR, before the scale factors are known
+#[derive(Debug, Clone, PartialEq)]
+struct OutputInfo {
+    id: RROutput,
+    name: String,
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+    mm_width: u32,
+    mm_height: u32,
+    refresh_rate: u16,
+}
+
+impl OutputInfo {
+    /// Physical pixel density of the output, `None` if the
+    /// output doesn't report a (plausible) physical size
+    fn get_physical_dpi(&self) -> Option<f32> {
+        // the physical size is not rotated together with the CRTC
+        let pixels = self.width.max(self.height) as f32;
+        let mm = self.mm_width.max(self.mm_height) as f32;
+        if mm <= 0.0 {
+            return None;
+        }
+        let dpi = pixels * 25.4 / mm;
+        if dpi < MIN_PHYSICAL_DPI || dpi > MAX_PHYSICAL_DPI {
+            None
+        } else {
+            Some(dpi)
+        }
+    }
+}
+
+/// Subscribes the window to changes of the monitor configuration, returns
+/// the event base of XRandR (which depends on the display connection) or
+/// `None` if XRandR is not available
+pub(super) fn select_monitor_change_events(display: *mut Display, window: Window) -> Option<c_int> {
+    let xrandr = XRANDR.as_ref()?;
+
+    let mut event_base = 0;
+    let mut error_base = 0;
+    if (xrandr.XRRQueryExtension)(display, &mut event_base, &mut error_base) == 0 {
+        return None;
+    }
+
+    (xrandr.XRRSelectInput)(
+        display,
+        window,
+        RR_SCREEN_CHANGE_NOTIFY_MASK | RR_CRTC_CHANGE_NOTIFY_MASK | RR_OUTPUT_CHANGE_NOTIFY_MASK,
+    );
+
+    Some(event_base)
+}
+
+/// Returns whether the event type is one of the RandR events
+pub(super) fn is_monitor_change_event(event_base: Option<c_int>, event_type: c_int) -> bool {
+    match event_base {
+        Some(base) => event_type >= base && event_type < base + RR_NUMBER_EVENTS,
+        None => false,
+    }
+}
+
+/// Updates the screen size that Xlib caches for the display connection,
+/// has to be called for every RandR event
+pub(super) fn update_configuration(event: &mut XEvent) {
+    if let Some(xrandr) = XRANDR.as_ref() {
+        (xrandr.XRRUpdateConfiguration)(event);
+    }
+}
+
+/// Queries the monitors of the display, `fallback_scale_factor` is only
+/// called if the `Xft.dpi` resource is not set
+///
+/// Note: slow (round-trips to the X server) - cache output!
+pub(super) fn get_monitors<F: FnOnce() -> f32>(
+    display: *mut Display,
+    fallback_scale_factor: F,
+) -> MonitorVec {
+    let xrandr = match XRANDR.as_ref() {
+        Some(s) => s,
+        None => return MonitorVec::from_const_slice(&[]),
+    };
+
+    let root = xrandr.get_root_window(display);
+    let primary = (xrandr.XRRGetOutputPrimary)(display, root);
+    let outputs = get_outputs(xrandr, display, root);
+
+    let bit_depth = (xrandr.XDefaultDepth)(display, (xrandr.XDefaultScreen)(display)).max(0) as u16;
+
+    let resources = (xrandr.XResourceManagerString)(display);
+    let xft_dpi = if resources.is_null() {
+        None
+    } else {
+        let resources = unsafe { CStr::from_ptr(resources) };
+        parse_xft_dpi(&resources.to_string_lossy())
+    };
+
+    let base_scale_factor = match xft_dpi {
+        Some(dpi) => dpi / 96.0,
+        None => fallback_scale_factor(),
+    };
+
+    let primary_index = outputs.iter().position(|o| o.id == primary).unwrap_or(0);
+    let primary_dpi = outputs
+        .get(primary_index)
+        .and_then(|o| o.get_physical_dpi());
+
+    outputs
+        .iter()
+        .enumerate()
+        .map(|(i, o)| Monitor {
+            id: o.id as usize,
+            name: if o.name.is_empty() {
+                OptionAzString::None
+            } else {
+                OptionAzString::Some(AzString::from(o.name.clone()))
+            },
+            size: LayoutSize::new(o.width as isize, o.height as isize),
+            position: LayoutPoint::new(o.x as isize, o.y as isize),
+            scale_factor: get_scale_factor(base_scale_factor, o.get_physical_dpi(), primary_dpi)
+                as f64,
+            video_modes: vec![VideoMode {
+                size: LayoutSize::new(o.width as isize, o.height as isize),
+                bit_depth,
+                refresh_rate: o.refresh_rate,
+            }]
+            .into(),
+            is_primary_monitor: i == primary_index,
+        })
+        .collect::<Vec<_>>()
+        .into()
+}
+
+// Returns the connected outputs that are currently showing a part of the screen
+fn get_outputs(xrandr: &Xrandr, display: *mut Display, root: Window) -> Vec<OutputInfo> {
+    let resources = (xrandr.XRRGetScreenResourcesCurrent)(display, root);
+    if resources.is_null() {
+        return Vec::new();
+    }
+
+    let (output_ids, modes) = unsafe {
+        let r = &*resources;
+        (
+            slice_or_empty(r.outputs, r.noutput),
+            slice_or_empty(r.modes, r.nmode),
+        )
+    };
+
+    let mut outputs = Vec::new();
+
+    for output_id in output_ids.iter().copied() {
+        let output_info = (xrandr.XRRGetOutputInfo)(display, resources, output_id);
+        if output_info.is_null() {
+            continue;
+        }
+
+        let output = unsafe { &*output_info };
+        if output.connection == RR_CONNECTED && output.crtc != 0 {
+            let crtc_info = (xrandr.XRRGetCrtcInfo)(display, resources, output.crtc);
+            if !crtc_info.is_null() {
+                let crtc = unsafe { &*crtc_info };
+                let name = unsafe { slice_or_empty(output.name as *const u8, output.name_len) };
+                let refresh_rate = modes
+                    .iter()
+                    .find(|m| m.id == crtc.mode)
+                    .map(get_refresh_rate)
+                    .unwrap_or(0);
+
+                outputs.push(OutputInfo {
+                    id: output_id,
+                    name: String::from_utf8_lossy(name).into_owned(),
+                    x: crtc.x,
+                    y: crtc.y,
+                    width: crtc.width,
+                    height: crtc.height,
+                    mm_width: output.mm_width as u32,
+                    mm_height: output.mm_height as u32,
+                    refresh_rate,
+                });
+
+                (xrandr.XRRFreeCrtcInfo)(crtc_info);
+            }
+        }
+
+        (xrandr.XRRFreeOutputInfo)(output_info);
+    }
+
+    (xrandr.XRRFreeScreenResources)(resources);
+
+    outputs
+}
+
+unsafe fn slice_or_empty<'a, T>(ptr: *const T, len: c_int) -> &'a [T] {
+    if ptr.is_null() || len <= 0 {
+        &[]
+    } else {
+        core::slice::from_raw_parts(ptr, len as usize)
+    }
+}
+
+fn get_refresh_rate(mode: &XRRModeInfo) -> u16 {
+    let total = mode.h_total as f64 * mode.v_total as f64;
+    if total <= 0.0 {
+        0
+    } else {
+        (mode.dot_clock as f64 / total).round() as u16
+    }
+}
+
+/// Parses the `Xft.dpi` value from the RESOURCE_MANAGER string
+/// (the resources set with `xrdb`)
+fn parse_xft_dpi(resources: &str) -> Option<f32> {
+    resources
+        .lines()
+        .filter_map(|line| {
+            let mut kv = line.splitn(2, ':');
+            let key = kv.next()?.trim();
+            let value = kv.next()?.trim();
+            if key == "Xft.dpi" {
+                value.parse::<f32>().ok()
+            } else {
+                None
+            }
+        })
+        .last()
+        .filter(|dpi| *dpi > 0.0)
+}
+
+/// Returns the scale factor of a monitor: the base scale factor (`Xft.dpi`)
+/// applies to the primary monitor, other monitors are scaled by the ratio of
+/// their pixel density to the pixel density of the primary monitor.
+///
+/// The ratio is rounded to steps of 0.5, so that monitors with similar pixel
+/// densities get the same scale factor
+fn get_scale_factor(
+    base: f32,
+    physical_dpi: Option<f32>,
+    primary_physical_dpi: Option<f32>,
+) -> f32 {
+    let base = if base > 0.0 { base } else { 1.0 };
+    let ratio = match (physical_dpi, primary_physical_dpi) {
+        (Some(dpi), Some(primary_dpi)) => ((dpi / primary_dpi) * 2.0).round().max(1.0) / 2.0,
+        _ => 1.0,
+    };
+    base * ratio
+}
+
+/// Returns the monitor that contains the largest part of the window (in
+/// physical root window coordinates), the primary monitor if the window
+/// is not on any monitor
+pub(super) fn get_window_monitor(
+    monitors: &[Monitor],
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+) -> Option<&Monitor> {
+    let intersection = |m: &Monitor| -> i64 {
+        let left = (x as i64).max(m.position.x as i64);
+        let top = (y as i64).max(m.position.y as i64);
+        let right = (x as i64 + width as i64).min(m.position.x as i64 + m.size.width as i64);
+        let bottom = (y as i64 + height as i64).min(m.position.y as i64 + m.size.height as i64);
+        (right - left).max(0) * (bottom - top).max(0)
+    };
+
+    monitors
+        .iter()
+        .map(|m| (intersection(m), m))
+        .filter(|(area, _)| *area > 0)
+        .max_by_key(|(area, _)| *area)
+        .map(|(_, m)| m)
+        .or_else(|| monitors.iter().find(|m| m.is_primary_monitor))
+}
+
+/// Returns the position of the top left corner of the window in root window coordinates
+pub(super) fn get_window_origin(display: *mut Display, window: Window) -> (i32, i32) {
+    let xrandr = match XRANDR.as_ref() {
+        Some(s) => s,
+        None => return (0, 0),
+    };
+    let root = xrandr.get_root_window(display);
+    let mut x = 0;
+    let mut y = 0;
+    let mut child = 0;
+    (xrandr.XTranslateCoordinates)(display, window, root, 0, 0, &mut x, &mut y, &mut child);
+    (x, y)
+}
+
+/// Resizes the window to the given physical size
+pub(super) fn set_window_size(display: *mut Display, window: Window, width: u32, height: u32) {
+    if let Some(xrandr) = XRANDR.as_ref() {
+        (xrandr.XResizeWindow)(display, window, width.max(1), height.max(1));
+    }
+}
+
+#[test]
+fn test_monitor_scale_factor() {
+    let resources = "Xft.antialias:\t1\nXft.dpi:\t192\nXft.hinting:\t1\n";
+    assert_eq!(parse_xft_dpi(resources), Some(192.0));
+    assert_eq!(parse_xft_dpi("Xft.hinting:\t1\n"), None);
+
+    // 24 inch 1080p and 4K monitors
+    let output = |width, height| OutputInfo {
+        id: 0,
+        name: String::new(),
+        x: 0,
+        y: 0,
+        width,
+        height,
+        mm_width: 527,
+        mm_height: 296,
+        refresh_rate: 60,
+    };
+    let low_dpi = output(1920, 1080).get_physical_dpi();
+    let high_dpi = output(3840, 2160).get_physical_dpi();
+    assert!(low_dpi.is_some() && high_dpi.is_some());
+
+    assert_eq!(get_scale_factor(1.0, high_dpi, low_dpi), 2.0);
+    assert_eq!(get_scale_factor(2.0, low_dpi, high_dpi), 1.0);
+    assert_eq!(get_scale_factor(1.5, low_dpi, low_dpi), 1.5);
+    // no physical size: same scale factor as the primary monitor
+    assert_eq!(get_scale_factor(1.25, None, low_dpi), 1.25);
+
+    // monitors that report their aspect ratio as their size
+    let mut bogus = output(1920, 1080);
+    bogus.mm_width = 16;
+    bogus.mm_height = 9;
+    assert_eq!(bogus.get_physical_dpi(), None);
+
+    let monitor = |x: isize, is_primary_monitor| Monitor {
+        position: LayoutPoint::new(x, 0),
+        size: LayoutSize::new(1920, 1080),
+        is_primary_monitor,
+        ..Monitor::default()
+    };
+    let monitors = [monitor(0, true), monitor(1920, false)];
+    let on_monitor = |x| get_window_monitor(&monitors, x, 100, 800, 600).map(|m| m.position.x);
+    assert_eq!(on_monitor(100), Some(0));
+    assert_eq!(on_monitor(1600), Some(1920));
+    assert_eq!(on_monitor(5000), Some(0));
+}
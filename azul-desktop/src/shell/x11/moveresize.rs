@@ -0,0 +1,153 @@
+//! Moving and resizing undecorated windows from custom title bars
+//!
+//! When the left mouse button is pressed on a node with a `WindowRegion`, the
+//! window manager is asked to take over the interactive move / resize with the
+//! `_NET_WM_MOVERESIZE` client message (EWMH). The implicit pointer grab of the
+//! button press has to be released first, otherwise the window manager can't
+//! grab the pointer. The window won't receive the button release afterwards.
+
+use super::{
+    Atom, ClientMessageData, Display, Library, Window, XClientMessageEvent, XEvent,
+    X11_CLIENT_MESSAGE, X11_FALSE, X11_TRUE,
+};
+use crate::gl::{c_char, c_int, c_long, c_uint, c_ulong};
+use azul_core::window::WindowRegion;
+use once_cell::sync::Lazy;
+
+const CURRENT_TIME: c_ulong = 0;
+const SUBSTRUCTURE_NOTIFY_MASK: c_long = 1 << 19;
+const SUBSTRUCTURE_REDIRECT_MASK: c_long = 1 << 20;
+
+// directions of the _NET_WM_MOVERESIZE message
+const NET_WM_MOVERESIZE_SIZE_TOPLEFT: c_long = 0;
+const NET_WM_MOVERESIZE_SIZE_TOP: c_long = 1;
+const NET_WM_MOVERESIZE_SIZE_TOPRIGHT: c_long = 2;
+const NET_WM_MOVERESIZE_SIZE_RIGHT: c_long = 3;
+const NET_WM_MOVERESIZE_SIZE_BOTTOMRIGHT: c_long = 4;
+const NET_WM_MOVERESIZE_SIZE_BOTTOM: c_long = 5;
+const NET_WM_MOVERESIZE_SIZE_BOTTOMLEFT: c_long = 6;
+const NET_WM_MOVERESIZE_SIZE_LEFT: c_long = 7;
+const NET_WM_MOVERESIZE_MOVE: c_long = 8;
+
+// the request comes from a regular application (not a pager)
+const SOURCE_INDICATION_APPLICATION: c_long = 1;
+
+type XDefaultRootWindowFuncType = extern "C" fn(*mut Display) -> Window;
+type XInternAtomFuncType = extern "C" fn(*mut Display, *const c_char, c_int) -> Atom;
+type XUngrabPointerFuncType = extern "C" fn(*mut Display, c_ulong) -> c_int;
+type XSendEventFuncType = extern "C" fn(*mut Display, Window, c_int, c_long, *mut XEvent) -> c_int;
+type XFlushFuncType = extern "C" fn(*mut Display) -> c_int;
+
+// Xlib functions used to start a move / resize
+struct MoveResizeXlib {
+    pub library: Library,
+    pub XDefaultRootWindow: XDefaultRootWindowFuncType,
+    pub XInternAtom: XInternAtomFuncType,
+    pub XUngrabPointer: XUngrabPointerFuncType,
+    pub XSendEvent: XSendEventFuncType,
+    pub XFlush: XFlushFuncType,
+}
+
+impl MoveResizeXlib {
+    fn new() -> Option<Self> {
+        use super::clipboard::load_function;
+
+        let library = Library::load("libX11.so").ok()?;
+
+        Some(Self {
+            XDefaultRootWindow: load_function(&library, "XDefaultRootWindow")?,
+            XInternAtom: load_function(&library, "XInternAtom")?,
+            XUngrabPointer: load_function(&library, "XUngrabPointer")?,
+            XSendEvent: load_function(&library, "XSendEvent")?,
+            XFlush: load_function(&library, "XFlush")?,
+            library,
+        })
+    }
+}
+
+static MOVE_RESIZE_XLIB: Lazy<Option<MoveResizeXlib>> = Lazy::new(MoveResizeXlib::new);
+
+/// Lets the window manager move or resize the window, `x_root` / `y_root` and
+/// `button` are the ones of the button press that started the operation.
+///
+/// Returns false if the region is `WindowRegion::Client`.
+pub(super) fn start_move_resize(
+    display: *mut Display,
+    window: Window,
+    region: WindowRegion,
+    x_root: c_int,
+    y_root: c_int,
+    button: c_uint,
+) -> bool {
+    let direction = match get_direction(region) {
+        Some(s) => s,
+        None => return false,
+    };
+
+    let xlib = match MOVE_RESIZE_XLIB.as_ref() {
+        Some(s) => s,
+        None => return false,
+    };
+
+    let name = std::ffi::CString::new("_NET_WM_MOVERESIZE").unwrap_or_default();
+    let message_type = (xlib.XInternAtom)(display, name.as_ptr(), X11_FALSE);
+    let root = (xlib.XDefaultRootWindow)(display);
+
+    (xlib.XUngrabPointer)(display, CURRENT_TIME);
+
+    let mut event = XEvent { pad: [0; 24] };
+    event.client_message = XClientMessageEvent {
+        type_: X11_CLIENT_MESSAGE,
+        serial: 0,
+        send_event: X11_TRUE,
+        display,
+        window,
+        message_type,
+        format: 32,
+        data: ClientMessageData {
+            longs: [
+                x_root as c_long,
+                y_root as c_long,
+                direction,
+                button as c_long,
+                SOURCE_INDICATION_APPLICATION,
+            ],
+        },
+    };
+    (xlib.XSendEvent)(
+        display,
+        root,
+        X11_FALSE,
+        SUBSTRUCTURE_NOTIFY_MASK | SUBSTRUCTURE_REDIRECT_MASK,
+        &mut event,
+    );
+    (xlib.XFlush)(display);
+
+    true
+}
+
+// Direction of the _NET_WM_MOVERESIZE message for the region
+fn get_direction(region: WindowRegion) -> Option<c_long> {
+    use azul_core::window::WindowRegion::*;
+    match region {
+        Client => None,
+        Drag => Some(NET_WM_MOVERESIZE_MOVE),
+        ResizeN => Some(NET_WM_MOVERESIZE_SIZE_TOP),
+        ResizeNE => Some(NET_WM_MOVERESIZE_SIZE_TOPRIGHT),
+        ResizeE => Some(NET_WM_MOVERESIZE_SIZE_RIGHT),
+        ResizeSE => Some(NET_WM_MOVERESIZE_SIZE_BOTTOMRIGHT),
+        ResizeS => Some(NET_WM_MOVERESIZE_SIZE_BOTTOM),
+        ResizeSW => Some(NET_WM_MOVERESIZE_SIZE_BOTTOMLEFT),
+        ResizeW => Some(NET_WM_MOVERESIZE_SIZE_LEFT),
+        ResizeNW => Some(NET_WM_MOVERESIZE_SIZE_TOPLEFT),
+    }
+}
+
+#[test]
+fn test_moveresize_direction() {
+    assert_eq!(get_direction(WindowRegion::Client), None);
+    assert_eq!(get_direction(WindowRegion::Drag), Some(8));
+    assert_eq!(get_direction(WindowRegion::ResizeNW), Some(0));
+    assert_eq!(get_direction(WindowRegion::ResizeSE), Some(4));
+    assert_eq!(get_direction(WindowRegion::ResizeW), Some(7));
+}
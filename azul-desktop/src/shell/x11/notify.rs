@@ -0,0 +1,612 @@
+//! Desktop notifications (`CallbackInfo::show_notification`)
+//!
+//! Notifications are sent to the `org.freedesktop.Notifications` server by a
+//! background thread, which also listens for the `ActionInvoked` and
+//! `NotificationClosed` signals. Like the menu items of the tray icon, the
+//! callbacks of the notification stay on the main thread and are invoked by
+//! the window that showed the notification (see `Notifications::take_callbacks`).
+//!
+//! The server is accessed through the `NotificationTransport` trait, so that
+//! the notifications can be sent to a different bus (`DBusConnection::open`)
+//! or to a mock notification daemon in the tests.
+
+use super::{
+    dbus::{DBusConnection, DBusMessage, DBusValue},
+    tray::{get_application_id, TrayWaker},
+};
+use crate::gl::{c_int, c_ulong};
+use alloc::collections::BTreeMap;
+use azul_core::{
+    app_resources::{ImageRef, RawImageData, RawImageFormat},
+    window::{MenuCallback, Notification, NotificationUrgency},
+};
+use core::ffi::c_void;
+use std::{
+    sync::mpsc::{self, Receiver, Sender, TryRecvError},
+    thread,
+};
+
+extern "C" {
+    // libc
+    fn pipe(fds: *mut c_int) -> c_int;
+    fn read(fd: c_int, buf: *mut c_void, count: usize) -> isize;
+    fn write(fd: c_int, buf: *const c_void, count: usize) -> isize;
+    fn close(fd: c_int) -> c_int;
+    fn poll(fds: *mut pollfd, nfds: c_ulong, timeout: c_int) -> c_int;
+}
+
+#[repr(C)]
+struct pollfd {
+    fd: c_int,
+    events: i16,
+    revents: i16,
+}
+
+const POLLIN: i16 = 0x1;
+
+const NOTIFICATIONS_NAME: &str = "org.freedesktop.Notifications";
+const NOTIFICATIONS_PATH: &str = "/org/freedesktop/Notifications";
+
+// timeout for the Notify call, the server usually replies immediately
+const NOTIFY_TIMEOUT_MS: c_int = 5000;
+
+// action key of a click on the notification itself
+const DEFAULT_ACTION_KEY: &str = "default";
+
+/// Icon of a notification, converted to RGBA on the main thread
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct NotificationIcon {
+    pub(crate) width: usize,
+    pub(crate) height: usize,
+    pub(crate) rgba: Vec<u8>,
+}
+
+impl NotificationIcon {
+    fn new(image: &ImageRef) -> Option<Self> {
+        let image = image.get_rawimage()?;
+        let pixels = match &image.pixels {
+            RawImageData::U8(u) => u.as_ref(),
+            _ => return None,
+        };
+
+        let rgba = match image.data_format {
+            RawImageFormat::RGBA8 => pixels.to_vec(),
+            RawImageFormat::BGRA8 => pixels
+                .chunks_exact(4)
+                .flat_map(|p| [p[2], p[1], p[0], p[3]])
+                .collect(),
+            RawImageFormat::RGB8 => pixels
+                .chunks_exact(3)
+                .flat_map(|p| [p[0], p[1], p[2], 255])
+                .collect(),
+            RawImageFormat::R8 => pixels.iter().flat_map(|p| [*p, *p, *p, 255]).collect(),
+            _ => return None,
+        };
+
+        if rgba.len() != image.width * image.height * 4 {
+            return None;
+        }
+
+        Some(Self {
+            width: image.width,
+            height: image.height,
+            rgba,
+        })
+    }
+}
+
+/// Notification that can be sent to the notification thread
+/// (the callbacks stay on the main thread)
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct NotificationRequest {
+    /// Id assigned by the application (not the id assigned by the server)
+    pub(crate) id: u32,
+    pub(crate) app_name: String,
+    pub(crate) title: String,
+    pub(crate) body: String,
+    pub(crate) icon: Option<NotificationIcon>,
+    pub(crate) urgency: NotificationUrgency,
+    /// Labels of the buttons, the action key of a button is its index
+    pub(crate) actions: Vec<String>,
+    /// Whether clicking the notification itself invokes a callback
+    pub(crate) has_default_action: bool,
+}
+
+/// Signal of the notification server
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum ServerEvent {
+    ActionInvoked { server_id: u32, action_key: String },
+    Closed { server_id: u32 },
+}
+
+/// Event of a notification, sent back to the main thread
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum NotificationEvent {
+    Clicked(u32),
+    Action(u32, usize),
+    Closed(u32),
+}
+
+/// Connection to a notification server
+pub(crate) trait NotificationTransport: Send {
+    /// Shows the notification, returns the id assigned by the server
+    fn notify(&mut self, request: &NotificationRequest) -> Result<u32, String>;
+    /// File descriptor that becomes readable when events arrive
+    fn fd(&self) -> Option<c_int>;
+    /// Returns the events received since the last call, None if the connection was lost
+    fn poll_events(&mut self) -> Option<Vec<ServerEvent>>;
+}
+
+/// `org.freedesktop.Notifications` server on a D-Bus connection
+pub(crate) struct DBusNotificationTransport {
+    connection: DBusConnection,
+}
+
+impl DBusNotificationTransport {
+    pub(crate) fn new(connection: DBusConnection) -> Result<Self, String> {
+        connection.add_match(&format!(
+            "type='signal',interface='{}',path='{}'",
+            NOTIFICATIONS_NAME, NOTIFICATIONS_PATH
+        ))?;
+        Ok(Self { connection })
+    }
+
+    pub(crate) fn session() -> Result<Self, String> {
+        Self::new(DBusConnection::session()?)
+    }
+
+    fn translate_signal(message: &DBusMessage) -> Option<ServerEvent> {
+        let args = message.args();
+        let server_id = args.get(0)?.as_i64()? as u32;
+        if message.is_signal(NOTIFICATIONS_NAME, "ActionInvoked") {
+            Some(ServerEvent::ActionInvoked {
+                server_id,
+                action_key: args.get(1)?.as_str()?.to_string(),
+            })
+        } else if message.is_signal(NOTIFICATIONS_NAME, "NotificationClosed") {
+            Some(ServerEvent::Closed { server_id })
+        } else {
+            None
+        }
+    }
+}
+
+impl NotificationTransport for DBusNotificationTransport {
+    fn notify(&mut self, request: &NotificationRequest) -> Result<u32, String> {
+        self.connection
+            .call(
+                NOTIFICATIONS_NAME,
+                NOTIFICATIONS_PATH,
+                NOTIFICATIONS_NAME,
+                "Notify",
+                &get_notify_args(request),
+                NOTIFY_TIMEOUT_MS,
+            )?
+            .get(0)
+            .and_then(|id| id.as_i64())
+            .map(|id| id as u32)
+            .ok_or_else(|| format!("D-Bus: invalid reply to Notify"))
+    }
+
+    fn fd(&self) -> Option<c_int> {
+        self.connection.unix_fd()
+    }
+
+    fn poll_events(&mut self) -> Option<Vec<ServerEvent>> {
+        if !self.connection.read_write(0) {
+            return None; // disconnected from the bus
+        }
+        let mut events = Vec::new();
+        while let Some(message) = self.connection.pop_message() {
+            events.extend(Self::translate_signal(&message));
+        }
+        Some(events)
+    }
+}
+
+// arguments of org.freedesktop.Notifications.Notify:
+// app_name, replaces_id, app_icon, summary, body, actions, hints, expire_timeout
+fn get_notify_args(request: &NotificationRequest) -> Vec<DBusValue> {
+    let mut actions = Vec::new();
+    if request.has_default_action {
+        actions.push(DBusValue::string(DEFAULT_ACTION_KEY));
+        actions.push(DBusValue::string(""));
+    }
+    for (i, label) in request.actions.iter().enumerate() {
+        actions.push(DBusValue::String(i.to_string()));
+        actions.push(DBusValue::string(label));
+    }
+
+    let urgency = match request.urgency {
+        NotificationUrgency::Low => 0,
+        NotificationUrgency::Normal => 1,
+        NotificationUrgency::Critical => 2,
+    };
+    let mut hints = vec![("urgency", DBusValue::Byte(urgency))];
+    if let Some(icon) = request.icon.as_ref() {
+        // (iiibiiay): width, height, rowstride, has alpha, bits per sample, channels, data
+        hints.push((
+            "image-data",
+            DBusValue::Struct(vec![
+                DBusValue::Int32(icon.width as i32),
+                DBusValue::Int32(icon.height as i32),
+                DBusValue::Int32(icon.width as i32 * 4),
+                DBusValue::Bool(true),
+                DBusValue::Int32(8),
+                DBusValue::Int32(4),
+                DBusValue::Array(
+                    "y".to_string(),
+                    icon.rgba.iter().map(|b| DBusValue::Byte(*b)).collect(),
+                ),
+            ]),
+        ));
+    }
+
+    vec![
+        DBusValue::string(&request.app_name),
+        DBusValue::UInt32(0),
+        DBusValue::string(""),
+        DBusValue::string(&request.title),
+        DBusValue::string(&request.body),
+        DBusValue::Array("s".to_string(), actions),
+        DBusValue::dict(hints),
+        DBusValue::Int32(-1),
+    ]
+}
+
+/// Maps the ids of the server to the ids of the application
+/// and translates the signals of the server into `NotificationEvent`s
+pub(crate) struct NotificationServer {
+    transport: Box<dyn NotificationTransport>,
+    // server id -> application id
+    shown: BTreeMap<u32, u32>,
+}
+
+impl NotificationServer {
+    pub(crate) fn new(transport: Box<dyn NotificationTransport>) -> Self {
+        Self {
+            transport,
+            shown: BTreeMap::new(),
+        }
+    }
+
+    pub(crate) fn show(&mut self, request: &NotificationRequest) -> Result<(), String> {
+        let server_id = self.transport.notify(request)?;
+        self.shown.insert(server_id, request.id);
+        Ok(())
+    }
+
+    /// Returns the events of the notifications shown by this application,
+    /// None if the connection to the server was lost
+    pub(crate) fn poll_events(&mut self) -> Option<Vec<NotificationEvent>> {
+        let mut events = Vec::new();
+        for event in self.transport.poll_events()? {
+            match event {
+                ServerEvent::ActionInvoked {
+                    server_id,
+                    action_key,
+                } => {
+                    let id = match self.shown.get(&server_id) {
+                        Some(s) => *s,
+                        None => continue, // notification of another application
+                    };
+                    if action_key == DEFAULT_ACTION_KEY {
+                        events.push(NotificationEvent::Clicked(id));
+                    } else if let Ok(index) = action_key.parse() {
+                        events.push(NotificationEvent::Action(id, index));
+                    }
+                }
+                ServerEvent::Closed { server_id } => {
+                    if let Some(id) = self.shown.remove(&server_id) {
+                        events.push(NotificationEvent::Closed(id));
+                    }
+                }
+            }
+        }
+        Some(events)
+    }
+}
+
+// Callbacks of a notification that is currently shown
+struct NotificationCallbacks {
+    on_click: Option<MenuCallback>,
+    actions: Vec<MenuCallback>,
+}
+
+/// Notifications shown by a window
+pub(crate) struct Notifications {
+    next_id: u32,
+    callbacks: BTreeMap<u32, NotificationCallbacks>,
+    // None once the thread is being shut down
+    sender: Option<Sender<NotificationRequest>>,
+    events: Receiver<NotificationEvent>,
+    // write end of the pipe that wakes up the notification thread
+    wake_fd: c_int,
+}
+
+impl Notifications {
+    /// Connects to the notification server on the session bus, fails
+    /// if libdbus-1 is not installed or no session bus is running
+    pub(crate) fn new(waker: TrayWaker) -> Option<Self> {
+        Self::spawn(
+            || {
+                DBusNotificationTransport::session()
+                    .map(|t| Box::new(t) as Box<dyn NotificationTransport>)
+            },
+            waker,
+        )
+    }
+
+    /// Spawns the notification thread, the transport is created on the thread
+    pub(crate) fn spawn<F>(create_transport: F, waker: TrayWaker) -> Option<Self>
+    where
+        F: FnOnce() -> Result<Box<dyn NotificationTransport>, String> + Send + 'static,
+    {
+        let mut fds = [0; 2];
+        if unsafe { pipe(fds.as_mut_ptr()) } != 0 {
+            return None;
+        }
+
+        let (sender, receiver) = mpsc::channel();
+        let (event_sender, events) = mpsc::channel();
+        let (startup_sender, startup_receiver) = mpsc::channel();
+        let wake_read_fd = fds[0];
+
+        let spawned = thread::Builder::new()
+            .name("notifications".to_string())
+            .spawn(move || {
+                match create_transport() {
+                    Ok(transport) => {
+                        let _ = startup_sender.send(true);
+                        run_notification_thread(
+                            NotificationServer::new(transport),
+                            receiver,
+                            wake_read_fd,
+                            event_sender,
+                            waker,
+                        );
+                    }
+                    Err(_) => {
+                        let _ = startup_sender.send(false);
+                    }
+                }
+                unsafe { close(wake_read_fd) };
+            });
+
+        if spawned.is_err() || startup_receiver.recv() != Ok(true) {
+            unsafe {
+                close(fds[1]);
+                if spawned.is_err() {
+                    close(fds[0]);
+                }
+            }
+            return None;
+        }
+
+        Some(Self {
+            next_id: 0,
+            callbacks: BTreeMap::new(),
+            sender: Some(sender),
+            events,
+            wake_fd: fds[1],
+        })
+    }
+
+    /// Sends the notification to the server, the callbacks are
+    /// stored until the notification is closed
+    pub(crate) fn show(&mut self, notification: &Notification) {
+        self.next_id += 1;
+        let id = self.next_id;
+
+        let request = NotificationRequest {
+            id,
+            app_name: get_application_id(),
+            title: notification.title.as_str().to_string(),
+            body: notification.body.as_str().to_string(),
+            icon: notification
+                .icon
+                .as_ref()
+                .and_then(|i| NotificationIcon::new(i)),
+            urgency: notification.urgency,
+            actions: notification
+                .actions
+                .as_ref()
+                .iter()
+                .map(|a| a.label.as_str().to_string())
+                .collect(),
+            has_default_action: notification.on_click.is_some(),
+        };
+
+        self.callbacks.insert(
+            id,
+            NotificationCallbacks {
+                on_click: notification.on_click.as_ref().cloned(),
+                actions: notification
+                    .actions
+                    .as_ref()
+                    .iter()
+                    .map(|a| a.callback.clone())
+                    .collect(),
+            },
+        );
+
+        if let Some(sender) = self.sender.as_ref() {
+            let _ = sender.send(request);
+        }
+        unsafe { write(self.wake_fd, [1_u8].as_ptr() as *const c_void, 1) };
+    }
+
+    /// Returns the callbacks of the notifications / buttons
+    /// that were clicked since the last call
+    pub(crate) fn take_callbacks(&mut self) -> Vec<MenuCallback> {
+        let mut triggered = Vec::new();
+        for event in self.events.try_iter() {
+            match event {
+                NotificationEvent::Clicked(id) => {
+                    let callback = self.callbacks.get(&id).and_then(|c| c.on_click.as_ref());
+                    triggered.extend(callback.cloned());
+                }
+                NotificationEvent::Action(id, index) => {
+                    let callback = self.callbacks.get(&id).and_then(|c| c.actions.get(index));
+                    triggered.extend(callback.cloned());
+                }
+                NotificationEvent::Closed(id) => {
+                    self.callbacks.remove(&id);
+                }
+            }
+        }
+        triggered
+    }
+}
+
+impl Drop for Notifications {
+    fn drop(&mut self) {
+        // the thread exits once the sender is disconnected (the
+        // notifications stay visible until the server closes them)
+        self.sender = None;
+        unsafe {
+            write(self.wake_fd, [1_u8].as_ptr() as *const c_void, 1);
+            close(self.wake_fd);
+        }
+    }
+}
+
+fn run_notification_thread(
+    mut server: NotificationServer,
+    receiver: Receiver<NotificationRequest>,
+    wake_fd: c_int,
+    events: Sender<NotificationEvent>,
+    waker: TrayWaker,
+) {
+    let transport_fd = server.transport.fd().unwrap_or(-1);
+
+    loop {
+        loop {
+            match receiver.try_recv() {
+                Ok(request) => {
+                    // the callbacks of the notification are removed
+                    // once the notification is closed by the server
+                    if server.show(&request).is_err() {
+                        let _ = events.send(NotificationEvent::Closed(request.id));
+                    }
+                }
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => return,
+            }
+        }
+
+        let new_events = match server.poll_events() {
+            Some(s) => s,
+            None => return,
+        };
+        let needs_wake = new_events
+            .iter()
+            .any(|e| !matches!(e, NotificationEvent::Closed(_)));
+        for event in new_events {
+            let _ = events.send(event);
+        }
+        if needs_wake {
+            waker.wake();
+        }
+
+        let mut fds = [
+            pollfd {
+                fd: wake_fd,
+                events: POLLIN,
+                revents: 0,
+            },
+            pollfd {
+                fd: transport_fd,
+                events: POLLIN,
+                revents: 0,
+            },
+        ];
+
+        let nfds = if transport_fd < 0 { 1 } else { 2 };
+        unsafe { poll(fds.as_mut_ptr(), nfds, -1) };
+
+        if fds[0].revents & POLLIN != 0 {
+            let mut buf = [0_u8; 64];
+            unsafe { read(wake_fd, buf.as_mut_ptr() as *mut c_void, buf.len()) };
+        }
+    }
+}
+
+#[test]
+fn test_notification_events() {
+    use std::sync::{Arc, Mutex};
+
+    // mock notification daemon, records the notifications and
+    // replays the signals that were queued by the test
+    #[derive(Default)]
+    struct MockDaemon {
+        shown: Vec<NotificationRequest>,
+        signals: Vec<ServerEvent>,
+    }
+
+    struct MockTransport(Arc<Mutex<MockDaemon>>);
+
+    impl NotificationTransport for MockTransport {
+        fn notify(&mut self, request: &NotificationRequest) -> Result<u32, String> {
+            let mut daemon = self.0.lock().unwrap();
+            daemon.shown.push(request.clone());
+            Ok(100 + daemon.shown.len() as u32)
+        }
+        fn fd(&self) -> Option<c_int> {
+            None
+        }
+        fn poll_events(&mut self) -> Option<Vec<ServerEvent>> {
+            Some(core::mem::replace(
+                &mut self.0.lock().unwrap().signals,
+                Vec::new(),
+            ))
+        }
+    }
+
+    let request = NotificationRequest {
+        id: 1,
+        app_name: "test".to_string(),
+        title: "New message".to_string(),
+        body: "Hello".to_string(),
+        icon: None,
+        urgency: NotificationUrgency::Critical,
+        actions: vec!["Reply".to_string(), "Ignore".to_string()],
+        has_default_action: true,
+    };
+
+    let daemon = Arc::new(Mutex::new(MockDaemon::default()));
+    let mut server = NotificationServer::new(Box::new(MockTransport(daemon.clone())));
+    server.show(&request).unwrap();
+
+    daemon.lock().unwrap().signals = vec![
+        ServerEvent::ActionInvoked {
+            server_id: 101,
+            action_key: "1".to_string(),
+        },
+        ServerEvent::ActionInvoked {
+            server_id: 101,
+            action_key: "default".to_string(),
+        },
+        // notification of another application
+        ServerEvent::Closed { server_id: 5 },
+        ServerEvent::Closed { server_id: 101 },
+    ];
+
+    assert_eq!(
+        server.poll_events(),
+        Some(vec![
+            NotificationEvent::Action(1, 1),
+            NotificationEvent::Clicked(1),
+            NotificationEvent::Closed(1),
+        ])
+    );
+    assert_eq!(daemon.lock().unwrap().shown, vec![request.clone()]);
+
+    let args = get_notify_args(&request);
+    assert_eq!(args.len(), 8);
+    assert_eq!(args[5].as_slice().map(|a| a.len()), Some(6));
+    assert_eq!(
+        args[6].get("urgency").map(|u| u.inner().clone()),
+        Some(DBusValue::Byte(2))
+    );
+}
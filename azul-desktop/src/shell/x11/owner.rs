@@ -0,0 +1,200 @@
+//! Child windows and modal dialogs
+//!
+//! A child window is marked as transient for its parent (`WM_TRANSIENT_FOR`),
+//! so that the window manager keeps it above the parent and doesn't show it
+//! in the taskbar. Modal windows additionally get `_NET_WM_STATE_MODAL` and
+//! the dialog window type. The window manager is not required to block the
+//! input of the parent, so the event loop drops the input events of windows
+//! that have an open modal child and activates the child instead.
+//!
+//! All properties have to be set before the window is mapped.
+
+use super::{Atom, Display, Library, Window, X11_FALSE};
+use crate::gl::{c_char, c_int, c_long, c_uchar, c_ulong};
+use once_cell::sync::Lazy;
+
+const XA_ATOM: Atom = 4;
+const PROP_MODE_REPLACE: c_int = 0;
+const PROP_MODE_APPEND: c_int = 2;
+const REVERT_TO_PARENT: c_int = 2;
+const CURRENT_TIME: c_ulong = 0;
+// program-specified position, without it most window managers ignore the initial position
+const P_POSITION: c_long = 1 << 2;
+const P_WIN_GRAVITY: c_long = 1 << 9;
+// the position is the one of the window itself, not of the frame of the window manager
+const STATIC_GRAVITY: c_int = 10;
+
+#[repr(C)]
+#[derive(Default)]
+struct XAspect {
+    x: c_int,
+    y: c_int,
+}
+
+#[repr(C)]
+#[derive(Default)]
+struct XSizeHints {
+    flags: c_long,
+    x: c_int,
+    y: c_int,
+    width: c_int,
+    height: c_int,
+    min_width: c_int,
+    min_height: c_int,
+    max_width: c_int,
+    max_height: c_int,
+    width_inc: c_int,
+    height_inc: c_int,
+    min_aspect: XAspect,
+    max_aspect: XAspect,
+    base_width: c_int,
+    base_height: c_int,
+    win_gravity: c_int,
+}
+
+type XSetTransientForHintFuncType = extern "C" fn(*mut Display, Window, Window) -> c_int;
+type XInternAtomFuncType = extern "C" fn(*mut Display, *const c_char, c_int) -> Atom;
+type XChangePropertyFuncType =
+    extern "C" fn(*mut Display, Window, Atom, Atom, c_int, c_int, *const c_uchar, c_int) -> c_int;
+type XSetWMNormalHintsFuncType = extern "C" fn(*mut Display, Window, *mut XSizeHints);
+type XMoveWindowFuncType = extern "C" fn(*mut Display, Window, c_int, c_int) -> c_int;
+type XRaiseWindowFuncType = extern "C" fn(*mut Display, Window) -> c_int;
+type XSetInputFocusFuncType = extern "C" fn(*mut Display, Window, c_int, c_ulong) -> c_int;
+
+// Xlib functions used for child windows
+struct OwnerXlib {
+    pub library: Library,
+    pub XSetTransientForHint: XSetTransientForHintFuncType,
+    pub XInternAtom: XInternAtomFuncType,
+    pub XChangeProperty: XChangePropertyFuncType,
+    pub XSetWMNormalHints: XSetWMNormalHintsFuncType,
+    pub XMoveWindow: XMoveWindowFuncType,
+    pub XRaiseWindow: XRaiseWindowFuncType,
+    pub XSetInputFocus: XSetInputFocusFuncType,
+}
+
+impl OwnerXlib {
+    fn new() -> Option<Self> {
+        use super::clipboard::load_function;
+
+        let library = Library::load("libX11.so").ok()?;
+
+        Some(Self {
+            XSetTransientForHint: load_function(&library, "XSetTransientForHint")?,
+            XInternAtom: load_function(&library, "XInternAtom")?,
+            XChangeProperty: load_function(&library, "XChangeProperty")?,
+            XSetWMNormalHints: load_function(&library, "XSetWMNormalHints")?,
+            XMoveWindow: load_function(&library, "XMoveWindow")?,
+            XRaiseWindow: load_function(&library, "XRaiseWindow")?,
+            XSetInputFocus: load_function(&library, "XSetInputFocus")?,
+            library,
+        })
+    }
+
+    fn intern_atom(&self, display: *mut Display, name: &str) -> Atom {
+        let name = std::ffi::CString::new(name).unwrap_or_default();
+        (self.XInternAtom)(display, name.as_ptr(), X11_FALSE)
+    }
+
+    fn set_atom_property(
+        &self,
+        display: *mut Display,
+        window: Window,
+        property: &str,
+        mode: c_int,
+        atoms: &[Atom],
+    ) {
+        let property = self.intern_atom(display, property);
+        (self.XChangeProperty)(
+            display,
+            window,
+            property,
+            XA_ATOM,
+            32,
+            mode,
+            atoms.as_ptr() as *const c_uchar,
+            atoms.len() as c_int,
+        );
+    }
+}
+
+static OWNER_XLIB: Lazy<Option<OwnerXlib>> = Lazy::new(OwnerXlib::new);
+
+/// Marks the (not yet mapped) window as a child window of `parent`
+pub(super) fn set_parent(display: *mut Display, window: Window, parent: Window, modal: bool) {
+    let xlib = match OWNER_XLIB.as_ref() {
+        Some(s) => s,
+        None => return,
+    };
+
+    (xlib.XSetTransientForHint)(display, window, parent);
+
+    if !modal {
+        return;
+    }
+
+    let dialog = xlib.intern_atom(display, "_NET_WM_WINDOW_TYPE_DIALOG");
+    xlib.set_atom_property(
+        display,
+        window,
+        "_NET_WM_WINDOW_TYPE",
+        PROP_MODE_REPLACE,
+        &[dialog],
+    );
+    // appended to the initial state of a restored window (see frame.rs)
+    let modal = xlib.intern_atom(display, "_NET_WM_STATE_MODAL");
+    xlib.set_atom_property(display, window, "_NET_WM_STATE", PROP_MODE_APPEND, &[modal]);
+}
+
+/// Moves the (not yet mapped) window to the given root window position
+/// (the position of the window content, excluding the window decorations)
+pub(super) fn set_initial_position(display: *mut Display, window: Window, x: i32, y: i32) {
+    let xlib = match OWNER_XLIB.as_ref() {
+        Some(s) => s,
+        None => return,
+    };
+
+    let mut hints = XSizeHints {
+        flags: P_POSITION | P_WIN_GRAVITY,
+        x,
+        y,
+        win_gravity: STATIC_GRAVITY,
+        ..XSizeHints::default()
+    };
+    (xlib.XSetWMNormalHints)(display, window, &mut hints);
+    (xlib.XMoveWindow)(display, window, x, y);
+}
+
+/// Raises and focuses the window (the modal child window
+/// when the user clicks on or types into its parent)
+pub(super) fn activate(display: *mut Display, window: Window) {
+    if let Some(xlib) = OWNER_XLIB.as_ref() {
+        (xlib.XRaiseWindow)(display, window);
+        (xlib.XSetInputFocus)(display, window, REVERT_TO_PARENT, CURRENT_TIME);
+    }
+}
+
+/// Returns the position of a child window with the size `child_size`
+/// that centers it over the parent window (all values in physical pixels)
+pub(super) fn get_centered_position(
+    parent_origin: (i32, i32),
+    parent_size: (u32, u32),
+    child_size: (u32, u32),
+) -> (i32, i32) {
+    let x = parent_origin.0 + (parent_size.0 as i32 - child_size.0 as i32) / 2;
+    let y = parent_origin.1 + (parent_size.1 as i32 - child_size.1 as i32) / 2;
+    (x, y)
+}
+
+#[test]
+fn test_child_window_centered_position() {
+    assert_eq!(
+        get_centered_position((100, 50), (800, 600), (400, 300)),
+        (300, 200)
+    );
+    // a child window larger than its parent overlaps it on all sides
+    assert_eq!(
+        get_centered_position((100, 50), (400, 300), (600, 400)),
+        (0, 0)
+    );
+}
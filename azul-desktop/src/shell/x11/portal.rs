@@ -0,0 +1,281 @@
+//! File dialogs of the XDG desktop portal (`org.freedesktop.portal.FileChooser`)
+//!
+//! The portal shows the file dialog of the desktop environment in a separate
+//! process, which also works in sandboxed (Flatpak) applications. The call
+//! only returns a handle to a request object, the selected files are sent
+//! with the `Response` signal of the request once the dialog is closed.
+//!
+//! `show_file_chooser` blocks until the dialog is closed, it is called from
+//! the thread of an asynchronous dialog (see `dialogs.rs`). If no portal is
+//! running, an error is returned and the caller falls back to tinyfiledialogs.
+
+use super::dbus::{DBusConnection, DBusValue};
+use crate::dialogs::{FileDialogMode, FileDialogOptions, FileTypeList};
+use crate::gl::c_int;
+use core::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+
+const PORTAL_NAME: &str = "org.freedesktop.portal.Desktop";
+const PORTAL_PATH: &str = "/org/freedesktop/portal/desktop";
+const FILE_CHOOSER_INTERFACE: &str = "org.freedesktop.portal.FileChooser";
+const REQUEST_INTERFACE: &str = "org.freedesktop.portal.Request";
+
+// timeout for the OpenFile / SaveFile call (the portal may have to be started first)
+const CALL_TIMEOUT_MS: c_int = 25_000;
+
+// how often the thread checks whether the dialog should be closed
+const POLL_TIMEOUT_MS: c_int = 100;
+
+// response code of a request if the user selected a file
+const RESPONSE_SUCCESS: i64 = 0;
+
+static REQUEST_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// Shows the file dialog of the portal and blocks until it is closed, returns
+/// `Ok(None)` if the user canceled the dialog or `is_canceled` returned true.
+///
+/// `parent_window` identifies the window that the dialog is modal to
+/// (`"x11:<hex window id>"`, empty if unknown)
+pub(crate) fn show_file_chooser(
+    mode: FileDialogMode,
+    options: &FileDialogOptions,
+    parent_window: &str,
+    is_canceled: &mut dyn FnMut() -> bool,
+) -> Result<Option<Vec<String>>, String> {
+    let connection = DBusConnection::session()?;
+
+    // the handle of the request is known in advance, so that the Response
+    // signal can't be missed if the portal replies before the call returns
+    let token = format!(
+        "azul{}_{}",
+        std::process::id(),
+        REQUEST_COUNTER.fetch_add(1, AtomicOrdering::SeqCst)
+    );
+    let sender = connection
+        .unique_name()
+        .ok_or_else(|| "portal: no unique name on the session bus".to_string())?;
+    let expected_handle = get_request_path(&sender, &token);
+    connection.add_match(&get_response_match_rule(&expected_handle))?;
+
+    let method = match mode {
+        FileDialogMode::SaveFile => "SaveFile",
+        _ => "OpenFile",
+    };
+    let args = [
+        DBusValue::string(parent_window),
+        DBusValue::string(options.title.as_str()),
+        get_options(mode, options, &token),
+    ];
+
+    let reply = connection.call(
+        PORTAL_NAME,
+        PORTAL_PATH,
+        FILE_CHOOSER_INTERFACE,
+        method,
+        &args,
+        CALL_TIMEOUT_MS,
+    )?;
+
+    let handle = reply
+        .first()
+        .and_then(|h| h.as_str())
+        .map(|h| h.to_string())
+        .ok_or_else(|| format!("portal: {} returned no request handle", method))?;
+
+    // older portals don't support the handle_token option
+    if handle != expected_handle {
+        connection.add_match(&get_response_match_rule(&handle))?;
+    }
+
+    loop {
+        while let Some(message) = connection.pop_message() {
+            if message.is_signal(REQUEST_INTERFACE, "Response")
+                && message.path().as_deref() == Some(handle.as_str())
+            {
+                return Ok(parse_response(&message.args()));
+            }
+        }
+
+        if is_canceled() {
+            let _ = connection.call_async(PORTAL_NAME, &handle, REQUEST_INTERFACE, "Close", &[]);
+            connection.flush();
+            return Ok(None);
+        }
+
+        if !connection.read_write(POLL_TIMEOUT_MS) {
+            return Err("portal: the connection to the session bus was closed".to_string());
+        }
+    }
+}
+
+// Object path of the request with the given token, see the
+// documentation of the org.freedesktop.portal.Request interface
+fn get_request_path(unique_name: &str, token: &str) -> String {
+    let sender = unique_name.trim_start_matches(':').replace('.', "_");
+    format!("{}/request/{}/{}", PORTAL_PATH, sender, token)
+}
+
+fn get_response_match_rule(handle: &str) -> String {
+    format!(
+        "type='signal',interface='{}',member='Response',path='{}'",
+        REQUEST_INTERFACE, handle
+    )
+}
+
+// Options (a{sv}) of the OpenFile / SaveFile call
+fn get_options(mode: FileDialogMode, options: &FileDialogOptions, token: &str) -> DBusValue {
+    let mut entries = vec![
+        ("handle_token", DBusValue::string(token)),
+        ("modal", DBusValue::Bool(true)),
+    ];
+
+    match mode {
+        FileDialogMode::OpenMultipleFiles => entries.push(("multiple", DBusValue::Bool(true))),
+        FileDialogMode::OpenDirectory => entries.push(("directory", DBusValue::Bool(true))),
+        _ => {}
+    }
+
+    if mode != FileDialogMode::OpenDirectory && !options.filters.is_empty() {
+        let filters = options.filters.iter().map(get_filter).collect();
+        entries.push(("filters", DBusValue::Array("(sa(us))".to_string(), filters)));
+    }
+
+    if let Some(folder) = options.current_folder.as_ref() {
+        // null-terminated byte array
+        let bytes = folder
+            .as_str()
+            .bytes()
+            .chain(core::iter::once(0))
+            .map(DBusValue::Byte)
+            .collect();
+        entries.push(("current_folder", DBusValue::Array("y".to_string(), bytes)));
+    }
+
+    if mode == FileDialogMode::SaveFile {
+        if let Some(name) = options.default_file_name.as_ref() {
+            entries.push(("current_name", DBusValue::string(name.as_str())));
+        }
+    }
+
+    DBusValue::dict(entries)
+}
+
+// Filter (sa(us)): name and list of (type, pattern), type 0 is a glob pattern
+fn get_filter(filter: &FileTypeList) -> DBusValue {
+    let patterns = filter
+        .get_patterns()
+        .into_iter()
+        .map(|p| DBusValue::Struct(vec![DBusValue::UInt32(0), DBusValue::String(p)]))
+        .collect();
+
+    DBusValue::Struct(vec![
+        DBusValue::string(filter.document_descriptor.as_str()),
+        DBusValue::Array("(us)".to_string(), patterns),
+    ])
+}
+
+// Arguments of the Response signal: response code (u) and results (a{sv})
+fn parse_response(args: &[DBusValue]) -> Option<Vec<String>> {
+    let response = args.first()?.as_i64()?;
+    if response != RESPONSE_SUCCESS {
+        return None;
+    }
+
+    let paths = args
+        .get(1)?
+        .get("uris")?
+        .as_slice()?
+        .iter()
+        .filter_map(|uri| uri_to_path(uri.as_str()?))
+        .collect::<Vec<_>>();
+
+    if paths.is_empty() {
+        None
+    } else {
+        Some(paths)
+    }
+}
+
+// Converts a file:// URI into a path ("file:///tmp/a%20b.txt" => "/tmp/a b.txt")
+fn uri_to_path(uri: &str) -> Option<String> {
+    let rest = uri.strip_prefix("file://")?;
+    // skip the host ("file://localhost/tmp")
+    let path = &rest[rest.find('/')?..];
+
+    let mut bytes = Vec::with_capacity(path.len());
+    let mut iter = path.bytes();
+    while let Some(b) = iter.next() {
+        if b != b'%' {
+            bytes.push(b);
+            continue;
+        }
+        let hex = [iter.next()?, iter.next()?];
+        let hex = core::str::from_utf8(&hex).ok()?;
+        bytes.push(u8::from_str_radix(hex, 16).ok()?);
+    }
+
+    String::from_utf8(bytes).ok()
+}
+
+#[test]
+fn test_portal_file_chooser_messages() {
+    use azul_css::{AzString, StringVec};
+
+    assert_eq!(
+        get_request_path(":1.42", "azul1_0"),
+        "/org/freedesktop/portal/desktop/request/1_42/azul1_0"
+    );
+
+    let images = FileTypeList {
+        document_types: StringVec::from_vec(vec![AzString::from("png"), AzString::from("*.jpg")]),
+        document_descriptor: "Images".into(),
+    };
+    let mut options = FileDialogOptions::new("Save image".into());
+    options.filters = vec![images].into();
+    options.current_folder = Some(AzString::from("/tmp")).into();
+    options.default_file_name = Some(AzString::from("image.png")).into();
+
+    let portal_options = get_options(FileDialogMode::SaveFile, &options, "azul1_0");
+    assert_eq!(portal_options.signature(), "a{sv}");
+    let filters = portal_options.get("filters").unwrap();
+    assert_eq!(filters.inner().signature(), "a(sa(us))");
+    let patterns = filters.as_slice().unwrap()[0].as_slice().unwrap()[1].clone();
+    assert_eq!(
+        patterns.as_slice().unwrap()[0],
+        DBusValue::Struct(vec![DBusValue::UInt32(0), DBusValue::string("*.png")])
+    );
+    assert_eq!(
+        portal_options.get("current_name").and_then(|n| n.as_str()),
+        Some("image.png")
+    );
+    assert_eq!(
+        portal_options
+            .get("current_folder")
+            .and_then(|f| f.as_slice())
+            .map(|f| f.len()),
+        Some(5)
+    );
+
+    assert_eq!(
+        uri_to_path("file:///tmp/a%20b.txt").as_deref(),
+        Some("/tmp/a b.txt")
+    );
+    assert_eq!(
+        uri_to_path("file://localhost/tmp/x").as_deref(),
+        Some("/tmp/x")
+    );
+    assert_eq!(uri_to_path("https://example.com/x"), None);
+
+    let results = DBusValue::dict(vec![(
+        "uris",
+        DBusValue::Array(
+            "s".to_string(),
+            vec![DBusValue::string("file:///home/user/image.png")],
+        ),
+    )]);
+    assert_eq!(
+        parse_response(&[DBusValue::UInt32(0), results.clone()]),
+        Some(vec!["/home/user/image.png".to_string()])
+    );
+    // canceled
+    assert_eq!(parse_response(&[DBusValue::UInt32(1), results]), None);
+}
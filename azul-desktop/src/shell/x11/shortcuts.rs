@@ -0,0 +1,307 @@
+//! Global keyboard shortcuts (`ShortcutScope::Global`)
+//!
+//! Global shortcuts are grabbed on the root window with `XGrabKey`, so that the
+//! X server reports the key presses even if no window of the application has the
+//! focus. The grabs are made on a separate connection, since the windows (and
+//! their connections) can be closed in any order.
+//!
+//! A grab only matches the exact modifier state, so every shortcut is grabbed
+//! once for each combination of NumLock and CapsLock. Grabbing a key that is
+//! already grabbed by another application fails with `BadAccess`, the error is
+//! caught and the shortcut stays registered (but only works inside the application).
+
+use super::{event, Display, Library, Window, XErrorEvent, XEvent, X11_KEY_PRESS, X11_TRUE};
+use crate::gl::{c_char, c_int, c_uchar, c_uint};
+use alloc::collections::BTreeMap;
+use azul_core::window::{AcceleratorKey, ShortcutId, ShortcutRegistry, VirtualKeyCode};
+use core::{
+    ptr,
+    sync::atomic::{AtomicBool, Ordering},
+};
+use once_cell::sync::Lazy;
+
+const SHIFT_MASK: c_uint = 1 << 0;
+const LOCK_MASK: c_uint = 1 << 1;
+const CONTROL_MASK: c_uint = 1 << 2;
+const MOD1_MASK: c_uint = 1 << 3;
+// NumLock on most keyboard layouts
+const MOD2_MASK: c_uint = 1 << 4;
+const GRAB_MODE_ASYNC: c_int = 1;
+
+// modifiers that don't change the shortcut
+const IGNORED_MODIFIERS: [c_uint; 4] = [0, LOCK_MASK, MOD2_MASK, LOCK_MASK | MOD2_MASK];
+
+type XErrorHandler = Option<extern "C" fn(*mut Display, *mut XErrorEvent) -> c_int>;
+
+type XOpenDisplayFuncType = extern "C" fn(*const c_char) -> *mut Display;
+type XCloseDisplayFuncType = extern "C" fn(*mut Display) -> c_int;
+type XDefaultRootWindowFuncType = extern "C" fn(*mut Display) -> Window;
+type XConnectionNumberFuncType = extern "C" fn(*mut Display) -> c_int;
+type XKeysymToKeycodeFuncType = extern "C" fn(*mut Display, event::KeySym) -> c_uchar;
+type XGrabKeyFuncType =
+    extern "C" fn(*mut Display, c_int, c_uint, Window, c_int, c_int, c_int) -> c_int;
+type XUngrabKeyFuncType = extern "C" fn(*mut Display, c_int, c_uint, Window) -> c_int;
+type XSetErrorHandlerFuncType = extern "C" fn(XErrorHandler) -> XErrorHandler;
+type XSyncFuncType = extern "C" fn(*mut Display, c_int) -> c_int;
+type XPendingFuncType = extern "C" fn(*mut Display) -> c_int;
+type XNextEventFuncType = extern "C" fn(*mut Display, *mut XEvent) -> c_int;
+
+// Xlib functions used to grab the global shortcuts
+struct ShortcutXlib {
+    pub library: Library,
+    pub XOpenDisplay: XOpenDisplayFuncType,
+    pub XCloseDisplay: XCloseDisplayFuncType,
+    pub XDefaultRootWindow: XDefaultRootWindowFuncType,
+    pub XConnectionNumber: XConnectionNumberFuncType,
+    pub XKeysymToKeycode: XKeysymToKeycodeFuncType,
+    pub XGrabKey: XGrabKeyFuncType,
+    pub XUngrabKey: XUngrabKeyFuncType,
+    pub XSetErrorHandler: XSetErrorHandlerFuncType,
+    pub XSync: XSyncFuncType,
+    pub XPending: XPendingFuncType,
+    pub XNextEvent: XNextEventFuncType,
+}
+
+impl ShortcutXlib {
+    fn new() -> Option<Self> {
+        use super::clipboard::load_function;
+
+        let library = Library::load("libX11.so").ok()?;
+
+        Some(Self {
+            XOpenDisplay: load_function(&library, "XOpenDisplay")?,
+            XCloseDisplay: load_function(&library, "XCloseDisplay")?,
+            XDefaultRootWindow: load_function(&library, "XDefaultRootWindow")?,
+            XConnectionNumber: load_function(&library, "XConnectionNumber")?,
+            XKeysymToKeycode: load_function(&library, "XKeysymToKeycode")?,
+            XGrabKey: load_function(&library, "XGrabKey")?,
+            XUngrabKey: load_function(&library, "XUngrabKey")?,
+            XSetErrorHandler: load_function(&library, "XSetErrorHandler")?,
+            XSync: load_function(&library, "XSync")?,
+            XPending: load_function(&library, "XPending")?,
+            XNextEvent: load_function(&library, "XNextEvent")?,
+            library,
+        })
+    }
+}
+
+static SHORTCUT_XLIB: Lazy<Option<ShortcutXlib>> = Lazy::new(ShortcutXlib::new);
+
+// set by the error handler that is installed while grabbing the keys
+static GRAB_FAILED: AtomicBool = AtomicBool::new(false);
+
+extern "C" fn grab_error_handler(_: *mut Display, _: *mut XErrorEvent) -> c_int {
+    GRAB_FAILED.store(true, Ordering::SeqCst);
+    0
+}
+
+/// Key code and modifier mask of a grabbed key
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct KeyGrab {
+    keycode: c_int,
+    modifiers: c_uint,
+}
+
+/// Connection to the X server that grabs the global shortcuts of the registry
+pub(super) struct GlobalShortcuts {
+    display: *mut Display,
+    root: Window,
+    grabs: BTreeMap<ShortcutId, KeyGrab>,
+}
+
+impl GlobalShortcuts {
+    pub(super) fn new() -> Option<Self> {
+        let xlib = SHORTCUT_XLIB.as_ref()?;
+        let display = (xlib.XOpenDisplay)(ptr::null());
+        if display.is_null() {
+            return None;
+        }
+
+        Some(Self {
+            display,
+            root: (xlib.XDefaultRootWindow)(display),
+            grabs: BTreeMap::new(),
+        })
+    }
+
+    /// File descriptor of the connection, polled by the event loop
+    pub(super) fn get_fd(&self) -> c_int {
+        match SHORTCUT_XLIB.as_ref() {
+            Some(xlib) => (xlib.XConnectionNumber)(self.display),
+            None => -1,
+        }
+    }
+
+    /// Whether key presses were already read from the connection
+    pub(super) fn has_pending(&self) -> bool {
+        SHORTCUT_XLIB
+            .as_ref()
+            .map(|xlib| (xlib.XPending)(self.display) > 0)
+            .unwrap_or(false)
+    }
+
+    /// Grabs the global shortcuts that were added to the registry
+    /// and releases the ones that were removed
+    pub(super) fn sync(&mut self, registry: &ShortcutRegistry) {
+        let xlib = match SHORTCUT_XLIB.as_ref() {
+            Some(s) => s,
+            None => return,
+        };
+
+        let wanted = registry
+            .get_global_bindings()
+            .filter_map(|b| {
+                let combo = b.shortcut.keys.get(0)?;
+                Some((
+                    b.id,
+                    self.get_key_grab(xlib, &combo.get_accelerator_keys())?,
+                ))
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        let removed = self
+            .grabs
+            .iter()
+            .filter(|(id, grab)| wanted.get(*id) != Some(*grab))
+            .map(|(id, grab)| (*id, *grab))
+            .collect::<Vec<_>>();
+
+        for (id, grab) in removed {
+            for extra in IGNORED_MODIFIERS.iter() {
+                (xlib.XUngrabKey)(
+                    self.display,
+                    grab.keycode,
+                    grab.modifiers | extra,
+                    self.root,
+                );
+            }
+            self.grabs.remove(&id);
+        }
+
+        for (id, grab) in wanted {
+            if self.grabs.contains_key(&id) {
+                continue;
+            }
+            if self.grab_key(xlib, grab) {
+                self.grabs.insert(id, grab);
+            }
+        }
+    }
+
+    /// Returns the global shortcuts that were pressed since the last call
+    pub(super) fn take_pressed(&mut self) -> Vec<ShortcutId> {
+        let xlib = match SHORTCUT_XLIB.as_ref() {
+            Some(s) => s,
+            None => return Vec::new(),
+        };
+
+        let mut pressed = Vec::new();
+        let mut cur_xevent = XEvent { pad: [0; 24] };
+
+        while (xlib.XPending)(self.display) > 0 {
+            (xlib.XNextEvent)(self.display, &mut cur_xevent);
+            if cur_xevent.get_type() != X11_KEY_PRESS {
+                continue;
+            }
+
+            let key = unsafe { cur_xevent.key };
+            let modifiers = key.state & (SHIFT_MASK | CONTROL_MASK | MOD1_MASK);
+            pressed.extend(
+                self.grabs
+                    .iter()
+                    .filter(|(_, g)| g.keycode == key.keycode as c_int && g.modifiers == modifiers)
+                    .map(|(id, _)| *id),
+            );
+        }
+
+        pressed
+    }
+
+    // Returns the key code and modifiers of a key combination,
+    // None if the key doesn't exist on the current keyboard layout
+    fn get_key_grab(&self, xlib: &ShortcutXlib, keys: &[AcceleratorKey]) -> Option<KeyGrab> {
+        let mut modifiers = 0;
+        let mut key = None;
+        for k in keys {
+            match k {
+                AcceleratorKey::Ctrl => modifiers |= CONTROL_MASK,
+                AcceleratorKey::Alt => modifiers |= MOD1_MASK,
+                AcceleratorKey::Shift => modifiers |= SHIFT_MASK,
+                AcceleratorKey::Key(k) => key = Some(*k),
+            }
+        }
+
+        let keysym = get_keysym(key?)?;
+        let keycode = (xlib.XKeysymToKeycode)(self.display, keysym);
+        if keycode == 0 {
+            return None;
+        }
+
+        Some(KeyGrab {
+            keycode: keycode as c_int,
+            modifiers,
+        })
+    }
+
+    // Grabs the key for all combinations of the lock modifiers,
+    // returns false if the key is already grabbed by another application
+    fn grab_key(&self, xlib: &ShortcutXlib, grab: KeyGrab) -> bool {
+        GRAB_FAILED.store(false, Ordering::SeqCst);
+        let previous_handler = (xlib.XSetErrorHandler)(Some(grab_error_handler));
+
+        for extra in IGNORED_MODIFIERS.iter() {
+            (xlib.XGrabKey)(
+                self.display,
+                grab.keycode,
+                grab.modifiers | extra,
+                self.root,
+                X11_TRUE,
+                GRAB_MODE_ASYNC,
+                GRAB_MODE_ASYNC,
+            );
+        }
+
+        // errors are only reported once the requests have been processed
+        (xlib.XSync)(self.display, 0);
+        (xlib.XSetErrorHandler)(previous_handler);
+
+        let failed = GRAB_FAILED.load(Ordering::SeqCst);
+        if failed {
+            for extra in IGNORED_MODIFIERS.iter() {
+                (xlib.XUngrabKey)(
+                    self.display,
+                    grab.keycode,
+                    grab.modifiers | extra,
+                    self.root,
+                );
+            }
+        }
+        !failed
+    }
+}
+
+impl Drop for GlobalShortcuts {
+    fn drop(&mut self) {
+        if let Some(xlib) = SHORTCUT_XLIB.as_ref() {
+            // closing the connection releases the grabs
+            (xlib.XCloseDisplay)(self.display);
+        }
+    }
+}
+
+// Inverse of event::keysym_to_virtual_keycode: searches the Latin-1,
+// function key and XF86 keysyms for the key
+fn get_keysym(key: VirtualKeyCode) -> Option<event::KeySym> {
+    (0x0020..=0x00ff)
+        .chain(0xfe00..=0xffff)
+        .chain(0x1008_ff00..=0x1008_ffff)
+        .find(|keysym| event::keysym_to_virtual_keycode(*keysym) == Some(key))
+}
+
+#[test]
+fn test_global_shortcut_keysym() {
+    assert_eq!(get_keysym(VirtualKeyCode::A), Some(0x0041));
+    assert_eq!(get_keysym(VirtualKeyCode::Key5), Some(0x0035));
+    assert_eq!(get_keysym(VirtualKeyCode::F12), Some(0xffc9));
+    assert_eq!(get_keysym(VirtualKeyCode::Space), Some(0x0020));
+}
@@ -0,0 +1,648 @@
+//! Touch screen and pen input (XInput2)
+//!
+//! Every window selects the XI2 touch events of all master devices, the X
+//! server reports each finger with its own touch ID. Pens (graphics tablets)
+//! don't send touch events: their pressure and tilt are only reported in the
+//! valuators of the XI2 motion / button events of the pen device itself, so
+//! these events are selected for every pen that is connected when the window
+//! is created.
+//!
+//! The touch events are passed through a `GestureRecognizer`, which updates
+//! the `TouchState` of the window (touch points, pinch / rotate / pan and
+//! long-press). X11 has no cancelled touches: touches that another client
+//! takes over (i.e. a gesture of the window manager) are reported as ended.
+
+use super::{Display, Library, Window, XEvent, XGenericEventCookie, X11_FALSE};
+use crate::gl::{c_char, c_int, c_uchar, c_ulong};
+use alloc::collections::BTreeMap;
+use azul_core::{
+    gesture::{GestureRecognizer, TouchEvent},
+    window::{LogicalPosition, TouchPoint, TouchPointKind, TouchState},
+};
+use once_cell::sync::Lazy;
+use std::{ffi::CStr, time::Instant};
+
+type Atom = c_ulong;
+
+const XI_ALL_DEVICES: c_int = 0;
+const XI_ALL_MASTER_DEVICES: c_int = 1;
+const XI_SLAVE_POINTER: c_int = 3;
+const XI_VALUATOR_CLASS: c_int = 2;
+const XI_TOUCH_CLASS: c_int = 8;
+
+const XI_BUTTON_PRESS: c_int = 4;
+const XI_BUTTON_RELEASE: c_int = 5;
+const XI_MOTION: c_int = 6;
+const XI_TOUCH_BEGIN: c_int = 18;
+const XI_TOUCH_UPDATE: c_int = 19;
+const XI_TOUCH_END: c_int = 20;
+// (XI_LASTEVENT >> 3) + 1
+const XI_MASK_LEN: usize = 4;
+
+// touch events were added in XInput 2.2
+const XI_MAJOR_VERSION: c_int = 2;
+const XI_MINOR_VERSION: c_int = 2;
+
+// the X server reports touch IDs as 32-bit numbers, the pens get IDs above that
+const PEN_ID_OFFSET: u64 = 1 << 32;
+
+#[repr(C)]
+struct XIEventMask {
+    deviceid: c_int,
+    mask_len: c_int,
+    mask: *mut c_uchar,
+}
+
+#[repr(C)]
+struct XIButtonState {
+    mask_len: c_int,
+    mask: *mut c_uchar,
+}
+
+#[repr(C)]
+struct XIValuatorState {
+    mask_len: c_int,
+    mask: *mut c_uchar,
+    values: *mut f64,
+}
+
+#[repr(C)]
+struct XIModifierState {
+    base: c_int,
+    latched: c_int,
+    locked: c_int,
+    effective: c_int,
+}
+
+#[repr(C)]
+struct XIDeviceEvent {
+    type_: c_int,
+    serial: c_ulong,
+    send_event: c_int,
+    display: *mut Display,
+    extension: c_int,
+    evtype: c_int,
+    time: c_ulong,
+    deviceid: c_int,
+    sourceid: c_int,
+    detail: c_int,
+    root: Window,
+    event: Window,
+    child: Window,
+    root_x: f64,
+    root_y: f64,
+    event_x: f64,
+    event_y: f64,
+    flags: c_int,
+    buttons: XIButtonState,
+    valuators: XIValuatorState,
+    mods: XIModifierState,
+    group: XIModifierState,
+}
+
+#[repr(C)]
+struct XIAnyClassInfo {
+    type_: c_int,
+    sourceid: c_int,
+}
+
+#[repr(C)]
+struct XIValuatorClassInfo {
+    type_: c_int,
+    sourceid: c_int,
+    number: c_int,
+    label: Atom,
+    min: f64,
+    max: f64,
+    value: f64,
+    resolution: c_int,
+    mode: c_int,
+}
+
+#[repr(C)]
+struct XIDeviceInfo {
+    deviceid: c_int,
+    name: *mut c_char,
+    use_: c_int,
+    attachment: c_int,
+    enabled: c_int,
+    num_classes: c_int,
+    classes: *mut *mut XIAnyClassInfo,
+}
+
+type XQueryExtensionFuncType =
+    extern "C" fn(*mut Display, *const c_char, *mut c_int, *mut c_int, *mut c_int) -> c_int;
+type XGetEventDataFuncType = extern "C" fn(*mut Display, *mut XGenericEventCookie) -> c_int;
+type XFreeEventDataFuncType = extern "C" fn(*mut Display, *mut XGenericEventCookie);
+type XInternAtomFuncType = extern "C" fn(*mut Display, *const c_char, c_int) -> Atom;
+type XIQueryVersionFuncType = extern "C" fn(*mut Display, *mut c_int, *mut c_int) -> c_int;
+type XISelectEventsFuncType = extern "C" fn(*mut Display, Window, *mut XIEventMask, c_int) -> c_int;
+type XIQueryDeviceFuncType = extern "C" fn(*mut Display, c_int, *mut c_int) -> *mut XIDeviceInfo;
+type XIFreeDeviceInfoFuncType = extern "C" fn(*mut XIDeviceInfo);
+
+// libXi + Xlib functions used for the touch and pen input
+struct Xinput {
+    pub xi: Library,
+    pub x11: Library,
+    pub XQueryExtension: XQueryExtensionFuncType,
+    pub XGetEventData: XGetEventDataFuncType,
+    pub XFreeEventData: XFreeEventDataFuncType,
+    pub XInternAtom: XInternAtomFuncType,
+    pub XIQueryVersion: XIQueryVersionFuncType,
+    pub XISelectEvents: XISelectEventsFuncType,
+    pub XIQueryDevice: XIQueryDeviceFuncType,
+    pub XIFreeDeviceInfo: XIFreeDeviceInfoFuncType,
+}
+
+impl Xinput {
+    fn new() -> Option<Self> {
+        use super::clipboard::load_function;
+
+        let xi = Library::load("libXi.so.6")
+            .or_else(|_| Library::load("libXi.so"))
+            .ok()?;
+        let x11 = Library::load("libX11.so").ok()?;
+
+        Some(Self {
+            XQueryExtension: load_function(&x11, "XQueryExtension")?,
+            XGetEventData: load_function(&x11, "XGetEventData")?,
+            XFreeEventData: load_function(&x11, "XFreeEventData")?,
+            XInternAtom: load_function(&x11, "XInternAtom")?,
+            XIQueryVersion: load_function(&xi, "XIQueryVersion")?,
+            XISelectEvents: load_function(&xi, "XISelectEvents")?,
+            XIQueryDevice: load_function(&xi, "XIQueryDevice")?,
+            XIFreeDeviceInfo: load_function(&xi, "XIFreeDeviceInfo")?,
+            xi,
+            x11,
+        })
+    }
+
+    // creates the atom if it doesn't exist, since unlabeled valuators have the label 0
+    fn intern_atom(&self, display: *mut Display, name: &[u8]) -> Atom {
+        (self.XInternAtom)(display, name.as_ptr() as *const c_char, X11_FALSE)
+    }
+}
+
+static XINPUT: Lazy<Option<Xinput>> = Lazy::new(Xinput::new);
+
+/// XI2 device event, copied out of the event cookie
+#[derive(Debug, Clone, PartialEq)]
+struct XiEvent {
+    evtype: c_int,
+    deviceid: c_int,
+    detail: c_int,
+    // position relative to the window in physical pixels
+    x: f64,
+    y: f64,
+    // valuator number and value, only the valuators that changed
+    valuators: Vec<(c_int, f64)>,
+}
+
+impl XiEvent {
+    fn from_device_event(e: &XIDeviceEvent) -> Self {
+        let mut valuators = Vec::new();
+        let mut value_index = 0;
+        for number in 0..(e.valuators.mask_len.max(0) * 8) {
+            let byte = unsafe { *e.valuators.mask.add((number / 8) as usize) };
+            if byte & (1 << (number % 8)) != 0 {
+                let value = unsafe { *e.valuators.values.add(value_index) };
+                valuators.push((number, value));
+                value_index += 1;
+            }
+        }
+
+        Self {
+            evtype: e.evtype,
+            deviceid: e.deviceid,
+            detail: e.detail,
+            x: e.event_x,
+            y: e.event_y,
+            valuators,
+        }
+    }
+}
+
+/// Valuator of a pen and its range
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct Valuator {
+    number: c_int,
+    min: f64,
+    max: f64,
+}
+
+/// Pen of a graphics tablet (or the eraser at its back end)
+#[derive(Debug, Clone, PartialEq)]
+struct Pen {
+    kind: TouchPointKind,
+    pressure: Option<Valuator>,
+    tilt_x: Option<Valuator>,
+    tilt_y: Option<Valuator>,
+    // last reported values (motion events only contain the valuators that changed)
+    last: TouchPoint,
+    is_down: bool,
+}
+
+impl Pen {
+    fn update(&mut self, position: LogicalPosition, valuators: &[(c_int, f64)]) -> TouchPoint {
+        let get = |valuator: Option<Valuator>| {
+            let v = valuator?;
+            valuators
+                .iter()
+                .find(|(number, _)| *number == v.number)
+                .map(|(_, value)| (v, *value))
+        };
+
+        self.last.position = position;
+        if let Some((v, value)) = get(self.pressure) {
+            self.last.pressure = if v.max > v.min {
+                ((value - v.min) / (v.max - v.min)).max(0.0).min(1.0) as f32
+            } else {
+                1.0
+            };
+        }
+        // the drivers report the tilt in degrees
+        if let Some((_, value)) = get(self.tilt_x) {
+            self.last.tilt_x = value.max(-90.0).min(90.0) as f32;
+        }
+        if let Some((_, value)) = get(self.tilt_y) {
+            self.last.tilt_y = value.max(-90.0).min(90.0) as f32;
+        }
+        self.last
+    }
+}
+
+/// Touch and pen input of a window
+pub(super) struct TouchInput {
+    // major opcode of the XInputExtension, identifies the XI2 event cookies
+    opcode: c_int,
+    // pens by device ID
+    pens: BTreeMap<c_int, Pen>,
+    recognizer: GestureRecognizer,
+    // timestamps of the touch events are relative to the creation of the window
+    start: Instant,
+}
+
+impl TouchInput {
+    /// Selects the touch and pen events of the window, `None` if the
+    /// X server doesn't support XInput 2.2
+    pub(super) fn new(display: *mut Display, window: Window) -> Option<Self> {
+        let xinput = XINPUT.as_ref()?;
+
+        let mut opcode = 0;
+        let mut event_base = 0;
+        let mut error_base = 0;
+        let has_extension = (xinput.XQueryExtension)(
+            display,
+            b"XInputExtension\0".as_ptr() as *const c_char,
+            &mut opcode,
+            &mut event_base,
+            &mut error_base,
+        );
+        if has_extension == 0 {
+            return None;
+        }
+
+        let mut major = XI_MAJOR_VERSION;
+        let mut minor = XI_MINOR_VERSION;
+        let status = (xinput.XIQueryVersion)(display, &mut major, &mut minor);
+        if status != 0 || (major, minor) < (XI_MAJOR_VERSION, XI_MINOR_VERSION) {
+            return None;
+        }
+
+        let pens = get_pens(xinput, display);
+
+        let mut touch_mask = get_event_mask(&[XI_TOUCH_BEGIN, XI_TOUCH_UPDATE, XI_TOUCH_END]);
+        let mut pen_mask = get_event_mask(&[XI_BUTTON_PRESS, XI_BUTTON_RELEASE, XI_MOTION]);
+
+        let mut masks = vec![XIEventMask {
+            deviceid: XI_ALL_MASTER_DEVICES,
+            mask_len: touch_mask.len() as c_int,
+            mask: touch_mask.as_mut_ptr(),
+        }];
+        masks.extend(pens.keys().map(|deviceid| XIEventMask {
+            deviceid: *deviceid,
+            mask_len: pen_mask.len() as c_int,
+            mask: pen_mask.as_mut_ptr(),
+        }));
+
+        (xinput.XISelectEvents)(display, window, masks.as_mut_ptr(), masks.len() as c_int);
+
+        Some(Self {
+            opcode,
+            pens,
+            recognizer: GestureRecognizer::new(),
+            start: Instant::now(),
+        })
+    }
+
+    /// Reads the XI2 event out of a `GenericEvent`, returns `None` if it
+    /// isn't a touch / pen event
+    pub(super) fn translate_event(
+        &mut self,
+        display: *mut Display,
+        event: &mut XEvent,
+        hidpi_factor: f32,
+    ) -> Option<TouchEvent> {
+        let xinput = XINPUT.as_ref()?;
+
+        let cookie = unsafe { &mut event.generic_event_cookie };
+        if cookie.extension != self.opcode || (xinput.XGetEventData)(display, cookie) == 0 {
+            return None;
+        }
+
+        let xi_event = match cookie.evtype {
+            XI_BUTTON_PRESS | XI_BUTTON_RELEASE | XI_MOTION | XI_TOUCH_BEGIN | XI_TOUCH_UPDATE
+            | XI_TOUCH_END => Some(XiEvent::from_device_event(unsafe {
+                &*(cookie.data as *const XIDeviceEvent)
+            })),
+            _ => None,
+        };
+
+        (xinput.XFreeEventData)(display, cookie);
+
+        self.get_touch_event(&xi_event?, hidpi_factor)
+    }
+
+    /// Applies the touch event to the touch state of the window
+    pub(super) fn handle_event(&mut self, state: &mut TouchState, event: TouchEvent) {
+        let time_ms = self.get_time_ms();
+        self.recognizer.handle_event(state, event, time_ms);
+    }
+
+    /// Recognizes the long-press of a finger that is held down without
+    /// moving (the X server doesn't send events for it)
+    pub(super) fn check_long_press(&mut self, state: &mut TouchState) -> bool {
+        let time_ms = self.get_time_ms();
+        self.recognizer.check_long_press(state, time_ms)
+    }
+
+    /// Milliseconds until a long-press is recognized, the event loop wakes up after that
+    pub(super) fn get_long_press_timeout(&self) -> Option<u64> {
+        self.recognizer.get_long_press_timeout(self.get_time_ms())
+    }
+
+    fn get_time_ms(&self) -> u64 {
+        self.start.elapsed().as_millis() as u64
+    }
+
+    fn get_touch_event(&mut self, e: &XiEvent, hidpi_factor: f32) -> Option<TouchEvent> {
+        let position = LogicalPosition::new(
+            (e.x / hidpi_factor as f64) as f32,
+            (e.y / hidpi_factor as f64) as f32,
+        );
+        let touch_id = e.detail as u32 as u64;
+        let finger = TouchPoint {
+            id: touch_id,
+            kind: TouchPointKind::Finger,
+            position,
+            pressure: 1.0,
+            ..Default::default()
+        };
+
+        match e.evtype {
+            XI_TOUCH_BEGIN => Some(TouchEvent::Down(finger)),
+            XI_TOUCH_UPDATE => Some(TouchEvent::Move(finger)),
+            XI_TOUCH_END => Some(TouchEvent::Up(touch_id)),
+            XI_BUTTON_PRESS | XI_BUTTON_RELEASE | XI_MOTION => {
+                let pen = self.pens.get_mut(&e.deviceid)?;
+                let point = pen.update(position, &e.valuators);
+                // button 1 is the tip of the pen, the other buttons are the pen buttons
+                match e.evtype {
+                    XI_BUTTON_PRESS if e.detail == 1 && !pen.is_down => {
+                        pen.is_down = true;
+                        Some(TouchEvent::Down(point))
+                    }
+                    XI_MOTION if pen.is_down => Some(TouchEvent::Move(point)),
+                    XI_BUTTON_RELEASE if e.detail == 1 && pen.is_down => {
+                        pen.is_down = false;
+                        Some(TouchEvent::Up(point.id))
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+// XISetMask() for all events
+fn get_event_mask(events: &[c_int]) -> [c_uchar; XI_MASK_LEN] {
+    let mut mask = [0; XI_MASK_LEN];
+    for event in events {
+        mask[(event >> 3) as usize] |= 1 << (event & 7);
+    }
+    mask
+}
+
+// Pens are the (slave) pointer devices that report the pressure, but aren't touch screens
+fn get_pens(xinput: &Xinput, display: *mut Display) -> BTreeMap<c_int, Pen> {
+    let pressure_atom = xinput.intern_atom(display, b"Abs Pressure\0");
+    let tilt_x_atom = xinput.intern_atom(display, b"Abs Tilt X\0");
+    let tilt_y_atom = xinput.intern_atom(display, b"Abs Tilt Y\0");
+
+    let mut pens = BTreeMap::new();
+
+    let mut device_count = 0;
+    let devices = (xinput.XIQueryDevice)(display, XI_ALL_DEVICES, &mut device_count);
+    if devices.is_null() {
+        return pens;
+    }
+
+    for i in 0..device_count.max(0) as usize {
+        let device = unsafe { &*devices.add(i) };
+        if device.use_ != XI_SLAVE_POINTER || device.enabled == 0 {
+            continue;
+        }
+
+        let mut is_touch_screen = false;
+        let mut valuators = BTreeMap::new();
+        for c in 0..device.num_classes.max(0) as usize {
+            let class = unsafe { *device.classes.add(c) };
+            match unsafe { (*class).type_ } {
+                XI_TOUCH_CLASS => is_touch_screen = true,
+                XI_VALUATOR_CLASS => {
+                    let v = unsafe { &*(class as *const XIValuatorClassInfo) };
+                    valuators.insert(
+                        v.label,
+                        Valuator {
+                            number: v.number,
+                            min: v.min,
+                            max: v.max,
+                        },
+                    );
+                }
+                _ => {}
+            }
+        }
+
+        let pressure = valuators.get(&pressure_atom).copied();
+        if is_touch_screen || pressure.is_none() {
+            continue;
+        }
+
+        let name = if device.name.is_null() {
+            String::new()
+        } else {
+            unsafe { CStr::from_ptr(device.name) }
+                .to_string_lossy()
+                .to_lowercase()
+        };
+        let kind = if name.contains("eraser") {
+            TouchPointKind::Eraser
+        } else {
+            TouchPointKind::Pen
+        };
+
+        pens.insert(
+            device.deviceid,
+            Pen {
+                kind,
+                pressure,
+                tilt_x: valuators.get(&tilt_x_atom).copied(),
+                tilt_y: valuators.get(&tilt_y_atom).copied(),
+                last: TouchPoint {
+                    id: PEN_ID_OFFSET + device.deviceid as u64,
+                    kind,
+                    pressure: 1.0,
+                    ..Default::default()
+                },
+                is_down: false,
+            },
+        );
+    }
+
+    (xinput.XIFreeDeviceInfo)(devices);
+
+    pens
+}
+
+#[test]
+fn test_xi2_touch_and_pen_events() {
+    let pen_id = PEN_ID_OFFSET + 12;
+    let pen = Pen {
+        kind: TouchPointKind::Pen,
+        pressure: Some(Valuator {
+            number: 2,
+            min: 0.0,
+            max: 2048.0,
+        }),
+        tilt_x: Some(Valuator {
+            number: 3,
+            min: -64.0,
+            max: 63.0,
+        }),
+        tilt_y: None,
+        last: TouchPoint {
+            id: pen_id,
+            kind: TouchPointKind::Pen,
+            pressure: 1.0,
+            ..Default::default()
+        },
+        is_down: false,
+    };
+
+    let mut input = TouchInput {
+        opcode: 131,
+        pens: vec![(12, pen)].into_iter().collect(),
+        recognizer: GestureRecognizer::new(),
+        start: Instant::now(),
+    };
+
+    let event = |evtype, deviceid, detail, x, y, valuators: &[(c_int, f64)]| XiEvent {
+        evtype,
+        deviceid,
+        detail,
+        x,
+        y,
+        valuators: valuators.to_vec(),
+    };
+
+    // recorded on a touch screen (device 9) and a Wacom tablet (pen device 12),
+    // with a scale factor of 2.0
+    let recorded = [
+        event(
+            XI_TOUCH_BEGIN,
+            9,
+            77,
+            200.0,
+            100.0,
+            &[(0, 200.0), (1, 100.0)],
+        ),
+        event(
+            XI_TOUCH_UPDATE,
+            9,
+            77,
+            210.0,
+            104.0,
+            &[(0, 210.0), (1, 104.0)],
+        ),
+        event(XI_TOUCH_END, 9, 77, 210.0, 104.0, &[]),
+        // hovering pen, the tip doesn't touch the tablet
+        event(
+            XI_MOTION,
+            12,
+            0,
+            50.0,
+            50.0,
+            &[(0, 50.0), (1, 50.0), (3, 10.0)],
+        ),
+        // pen button pressed while hovering
+        event(XI_BUTTON_PRESS, 12, 2, 50.0, 50.0, &[]),
+        event(XI_BUTTON_PRESS, 12, 1, 50.0, 50.0, &[(2, 1024.0)]),
+        event(XI_MOTION, 12, 0, 60.0, 50.0, &[(0, 60.0), (2, 2048.0)]),
+        event(XI_BUTTON_RELEASE, 12, 1, 60.0, 50.0, &[(2, 0.0)]),
+        // mouse on another device
+        event(XI_MOTION, 2, 0, 10.0, 10.0, &[(0, 10.0), (1, 10.0)]),
+    ];
+
+    let events = recorded
+        .iter()
+        .map(|e| input.get_touch_event(e, 2.0))
+        .collect::<Vec<_>>();
+
+    let pen_point = |x: f32, pressure: f32| TouchPoint {
+        id: pen_id,
+        kind: TouchPointKind::Pen,
+        position: LogicalPosition::new(x, 25.0),
+        pressure,
+        tilt_x: 10.0,
+        tilt_y: 0.0,
+    };
+
+    assert_eq!(
+        events,
+        vec![
+            Some(TouchEvent::Down(TouchPoint {
+                id: 77,
+                position: LogicalPosition::new(100.0, 50.0),
+                pressure: 1.0,
+                ..Default::default()
+            })),
+            Some(TouchEvent::Move(TouchPoint {
+                id: 77,
+                position: LogicalPosition::new(105.0, 52.0),
+                pressure: 1.0,
+                ..Default::default()
+            })),
+            Some(TouchEvent::Up(77)),
+            None,
+            None,
+            Some(TouchEvent::Down(pen_point(25.0, 0.5))),
+            Some(TouchEvent::Move(pen_point(30.0, 1.0))),
+            Some(TouchEvent::Up(pen_id)),
+            None,
+        ]
+    );
+}
+
+#[test]
+fn test_xi2_event_mask() {
+    assert_eq!(
+        get_event_mask(&[XI_TOUCH_BEGIN, XI_TOUCH_UPDATE, XI_TOUCH_END]),
+        [0, 0, 0b0001_1100, 0]
+    );
+    assert_eq!(
+        get_event_mask(&[XI_BUTTON_PRESS, XI_BUTTON_RELEASE, XI_MOTION]),
+        [0b0111_0000, 0, 0, 0]
+    );
+}
@@ -0,0 +1,270 @@
+//! Translucent and click-through windows
+//!
+//! The compositing manager only blends a window with the windows below it if the
+//! window uses a 32-bit ARGB visual, the default visual of the root window has no
+//! alpha channel. The EGL config of the window has to be the one of that visual.
+//! WebRender renders premultiplied colors, which is the format that compositing
+//! managers expect for ARGB windows, so the framebuffer is presented as-is.
+//!
+//! Click-through windows have an empty input region (XShape extension),
+//! so that all mouse input goes to the windows below them.
+
+use super::{
+    Atom, Colormap, Display, EGLConfig, EGLDisplay, EGLint, Egl, Library, Visual, Window, EGL_TRUE,
+    X11_FALSE,
+};
+use crate::gl::{c_char, c_int, c_uchar, c_ulong};
+use azul_core::window::WindowState;
+use core::{mem, ptr};
+use once_cell::sync::Lazy;
+
+const TRUE_COLOR: c_int = 4;
+const ALLOC_NONE: c_int = 0;
+const XA_CARDINAL: Atom = 6;
+const PROP_MODE_REPLACE: c_int = 0;
+
+const SHAPE_SET: c_int = 0;
+const SHAPE_INPUT: c_int = 2;
+const UNSORTED: c_int = 0;
+
+const EGL_NATIVE_VISUAL_ID: EGLint = 0x302E;
+// maximum number of EGL configs that are searched for the ARGB visual
+const MAX_EGL_CONFIGS: usize = 64;
+
+#[repr(C)]
+struct XVisualInfo {
+    visual: *mut Visual,
+    visualid: c_ulong,
+    screen: c_int,
+    depth: c_int,
+    class: c_int,
+    red_mask: c_ulong,
+    green_mask: c_ulong,
+    blue_mask: c_ulong,
+    colormap_size: c_int,
+    bits_per_rgb: c_int,
+}
+
+#[repr(C)]
+struct XRectangle {
+    x: i16,
+    y: i16,
+    width: u16,
+    height: u16,
+}
+
+type XMatchVisualInfoFuncType =
+    extern "C" fn(*mut Display, c_int, c_int, c_int, *mut XVisualInfo) -> c_int;
+type XCreateColormapFuncType = extern "C" fn(*mut Display, Window, *mut Visual, c_int) -> Colormap;
+type XInternAtomFuncType = extern "C" fn(*mut Display, *const c_char, c_int) -> Atom;
+type XChangePropertyFuncType =
+    extern "C" fn(*mut Display, Window, Atom, Atom, c_int, c_int, *const c_uchar, c_int) -> c_int;
+type XDeletePropertyFuncType = extern "C" fn(*mut Display, Window, Atom) -> c_int;
+type XShapeCombineRectanglesFuncType =
+    extern "C" fn(*mut Display, Window, c_int, c_int, c_int, *mut XRectangle, c_int, c_int, c_int);
+type XShapeCombineMaskFuncType =
+    extern "C" fn(*mut Display, Window, c_int, c_int, c_int, c_ulong, c_int);
+
+// Xlib functions used for translucent windows
+struct TransparencyXlib {
+    pub library: Library,
+    pub XMatchVisualInfo: XMatchVisualInfoFuncType,
+    pub XCreateColormap: XCreateColormapFuncType,
+    pub XInternAtom: XInternAtomFuncType,
+    pub XChangeProperty: XChangePropertyFuncType,
+    pub XDeleteProperty: XDeletePropertyFuncType,
+}
+
+impl TransparencyXlib {
+    fn new() -> Option<Self> {
+        use super::clipboard::load_function;
+
+        let library = Library::load("libX11.so").ok()?;
+
+        Some(Self {
+            XMatchVisualInfo: load_function(&library, "XMatchVisualInfo")?,
+            XCreateColormap: load_function(&library, "XCreateColormap")?,
+            XInternAtom: load_function(&library, "XInternAtom")?,
+            XChangeProperty: load_function(&library, "XChangeProperty")?,
+            XDeleteProperty: load_function(&library, "XDeleteProperty")?,
+            library,
+        })
+    }
+}
+
+// XShape extension (libXext) used for click-through windows
+struct Xext {
+    pub library: Library,
+    pub XShapeCombineRectangles: XShapeCombineRectanglesFuncType,
+    pub XShapeCombineMask: XShapeCombineMaskFuncType,
+}
+
+impl Xext {
+    fn new() -> Option<Self> {
+        use super::clipboard::load_function;
+
+        let library = Library::load("libXext.so.6")
+            .or_else(|_| Library::load("libXext.so"))
+            .ok()?;
+
+        Some(Self {
+            XShapeCombineRectangles: load_function(&library, "XShapeCombineRectangles")?,
+            XShapeCombineMask: load_function(&library, "XShapeCombineMask")?,
+            library,
+        })
+    }
+}
+
+static TRANSPARENCY_XLIB: Lazy<Option<TransparencyXlib>> = Lazy::new(TransparencyXlib::new);
+static XEXT: Lazy<Option<Xext>> = Lazy::new(Xext::new);
+
+/// 32-bit visual with an alpha channel
+pub(super) struct ArgbVisual {
+    pub(super) visual: *mut Visual,
+    pub(super) visual_id: c_ulong,
+    pub(super) depth: c_int,
+    pub(super) colormap: Colormap,
+}
+
+/// Whether the window has to be created with an ARGB visual: the background
+/// color is translucent or the window content should be blurred by the compositor
+pub(super) fn is_transparent(state: &WindowState) -> bool {
+    state.background_color.a < 255 || state.flags.has_blur_behind_window
+}
+
+/// Returns the 32-bit TrueColor visual of the screen and creates a colormap for it,
+/// `None` if the X server doesn't support 32-bit visuals
+pub(super) fn get_argb_visual(
+    display: *mut Display,
+    screen: c_int,
+    root: Window,
+) -> Option<ArgbVisual> {
+    let xlib = TRANSPARENCY_XLIB.as_ref()?;
+
+    let mut info: XVisualInfo = unsafe { mem::zeroed() };
+    if (xlib.XMatchVisualInfo)(display, screen, 32, TRUE_COLOR, &mut info) == 0
+        || info.visual.is_null()
+    {
+        return None;
+    }
+
+    // windows with a visual different from their parent need their own colormap
+    let colormap = (xlib.XCreateColormap)(display, root, info.visual, ALLOC_NONE);
+
+    Some(ArgbVisual {
+        visual: info.visual,
+        visual_id: info.visualid,
+        depth: info.depth,
+        colormap,
+    })
+}
+
+/// Returns the EGL config matching the `attributes` that renders to the visual,
+/// `None` if no config supports it
+pub(super) fn choose_egl_config(
+    egl: &Egl,
+    egl_display: EGLDisplay,
+    attributes: &[EGLint],
+    visual_id: c_ulong,
+) -> Option<EGLConfig> {
+    let mut configs: [EGLConfig; MAX_EGL_CONFIGS] = unsafe { mem::zeroed() };
+    let mut count = 0;
+    let chosen = (egl.eglChooseConfig)(
+        egl_display,
+        attributes.as_ptr(),
+        configs.as_mut_ptr(),
+        MAX_EGL_CONFIGS as EGLint,
+        &mut count,
+    );
+    if chosen != EGL_TRUE {
+        return None;
+    }
+
+    configs
+        .iter()
+        .take(count.max(0) as usize)
+        .find(|config| {
+            let mut config_visual_id = 0;
+            (egl.eglGetConfigAttrib)(
+                egl_display,
+                **config,
+                EGL_NATIVE_VISUAL_ID,
+                &mut config_visual_id,
+            ) == EGL_TRUE
+                && config_visual_id as c_ulong == visual_id
+        })
+        .copied()
+}
+
+/// Asks the compositor to blur the content behind the window (KWin, picom)
+pub(super) fn set_blur_behind(display: *mut Display, window: Window, blur: bool) {
+    let xlib = match TRANSPARENCY_XLIB.as_ref() {
+        Some(s) => s,
+        None => return,
+    };
+
+    let name = std::ffi::CString::new("_KDE_NET_WM_BLUR_BEHIND_REGION").unwrap_or_default();
+    let property = (xlib.XInternAtom)(display, name.as_ptr(), X11_FALSE);
+
+    if blur {
+        // an empty region blurs the whole window
+        (xlib.XChangeProperty)(
+            display,
+            window,
+            property,
+            XA_CARDINAL,
+            32,
+            PROP_MODE_REPLACE,
+            ptr::null(),
+            0,
+        );
+    } else {
+        (xlib.XDeleteProperty)(display, window, property);
+    }
+}
+
+/// Lets all mouse input pass through to the windows below (`click_through = true`)
+/// or restores the default input region of the window
+pub(super) fn set_click_through(display: *mut Display, window: Window, click_through: bool) {
+    let xext = match XEXT.as_ref() {
+        Some(s) => s,
+        None => return,
+    };
+
+    if click_through {
+        (xext.XShapeCombineRectangles)(
+            display,
+            window,
+            SHAPE_INPUT,
+            0,
+            0,
+            ptr::null_mut(),
+            0,
+            SHAPE_SET,
+            UNSORTED,
+        );
+    } else {
+        // a mask of None resets the input region to the bounds of the window
+        (xext.XShapeCombineMask)(display, window, SHAPE_INPUT, 0, 0, 0, SHAPE_SET);
+    }
+}
+
+#[test]
+fn test_transparent_window_state() {
+    use azul_css::ColorU;
+
+    let mut state = WindowState::default();
+    assert!(!is_transparent(&state));
+
+    state.background_color = ColorU {
+        r: 0,
+        g: 0,
+        b: 0,
+        a: 128,
+    };
+    assert!(is_transparent(&state));
+
+    state.background_color = ColorU::WHITE;
+    state.flags.has_blur_behind_window = true;
+    assert!(is_transparent(&state));
+}
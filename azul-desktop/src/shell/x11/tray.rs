@@ -0,0 +1,1157 @@
+//! System tray icon (`LinuxWindowOptions::tray_icon`)
+//!
+//! The icon is exported with the StatusNotifierItem protocol (D-Bus), which is
+//! supported by KDE, most Wayland panels and GNOME (with the AppIndicator
+//! extension). The `Menu` is exported with the `com.canonical.dbusmenu` protocol
+//! and rendered by the panel. The D-Bus connection is driven by a background
+//! thread, clicked menu items are sent back to the window, which invokes the
+//! `MenuCallback` on the main thread (see `SystemTray::take_clicked_items`).
+//!
+//! If no `StatusNotifierWatcher` is running, the XEmbed system tray protocol
+//! (`_NET_SYSTEM_TRAY_S{screen}`) is used as a fallback on X11: the icon is a
+//! small window that is reparented into the tray of the panel.
+
+use super::{
+    dbus::{
+        DBusConnection, DBusMessage, DBusMessageType, DBusValue, DBUS_ERROR_INVALID_ARGS,
+        DBUS_ERROR_UNKNOWN_METHOD,
+    },
+    Atom, ClientMessageData, Display, Library, Window, XClientMessageEvent, XEvent, X11_FALSE,
+    X11_TRUE,
+};
+use crate::gl::{c_char, c_int, c_long, c_uchar, c_uint, c_ulong};
+use alloc::collections::BTreeMap;
+use azul_core::window::{
+    ContextMenuMouseButton, MenuCallback, MenuItem, MenuItemIcon, MenuItemState, TrayIcon,
+};
+use core::{
+    ffi::c_void,
+    ptr,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+use once_cell::sync::Lazy;
+use std::{
+    ffi::CString,
+    sync::mpsc::{self, Receiver, Sender, TryRecvError},
+    thread,
+};
+
+extern "C" {
+    // libc
+    fn pipe(fds: *mut c_int) -> c_int;
+    fn read(fd: c_int, buf: *mut c_void, count: usize) -> isize;
+    fn write(fd: c_int, buf: *const c_void, count: usize) -> isize;
+    fn close(fd: c_int) -> c_int;
+    fn poll(fds: *mut pollfd, nfds: c_ulong, timeout: c_int) -> c_int;
+}
+
+#[repr(C)]
+struct pollfd {
+    fd: c_int,
+    events: i16,
+    revents: i16,
+}
+
+const POLLIN: i16 = 0x1;
+
+const WATCHER_NAME: &str = "org.kde.StatusNotifierWatcher";
+const WATCHER_PATH: &str = "/StatusNotifierWatcher";
+const ITEM_INTERFACE: &str = "org.kde.StatusNotifierItem";
+const ITEM_PATH: &str = "/StatusNotifierItem";
+const MENU_INTERFACE: &str = "com.canonical.dbusmenu";
+const MENU_PATH: &str = "/MenuBar";
+const PROPERTIES_INTERFACE: &str = "org.freedesktop.DBus.Properties";
+const INTROSPECTABLE_INTERFACE: &str = "org.freedesktop.DBus.Introspectable";
+
+// timeout for registering the item at the StatusNotifierWatcher
+const REGISTER_TIMEOUT_MS: c_int = 1000;
+
+// id of the root item of the dbusmenu layout
+const ROOT_MENU_ID: i32 = 0;
+
+static TRAY_ID_GENERATOR: AtomicUsize = AtomicUsize::new(0);
+
+/// Wakes up the main loop after a menu item has been clicked
+#[derive(Debug, Copy, Clone)]
+pub(crate) enum TrayWaker {
+    /// Sends a client message to the X11 window (which blocks in XNextEvent)
+    X11Window(Window),
+    /// Writes to the pipe that the main loop polls
+    Pipe(c_int),
+}
+
+impl TrayWaker {
+    pub(crate) fn wake(&self) {
+        match *self {
+            TrayWaker::X11Window(window) => {
+                let xlib = match TRAY_XLIB.as_ref() {
+                    Some(s) => s,
+                    None => return,
+                };
+                // the connection of the window can't be used from this thread
+                let display = (xlib.XOpenDisplay)(ptr::null());
+                if display.is_null() {
+                    return;
+                }
+                let name = CString::new("_AZUL_TRAY_WAKE").unwrap();
+                let message_type = (xlib.XInternAtom)(display, name.as_ptr(), X11_FALSE);
+                send_client_message(xlib, display, window, window, message_type, [0; 5]);
+                (xlib.XCloseDisplay)(display);
+            }
+            TrayWaker::Pipe(fd) => {
+                unsafe { write(fd, [1_u8].as_ptr() as *const c_void, 1) };
+            }
+        }
+    }
+}
+
+// Menu item that can be sent to the D-Bus thread (the callbacks stay on the main thread)
+#[derive(Debug, Clone, PartialEq)]
+struct TrayMenuItem {
+    id: i32,
+    label: String,
+    enabled: bool,
+    separator: bool,
+    checked: Option<bool>,
+    children: Vec<TrayMenuItem>,
+}
+
+impl TrayMenuItem {
+    fn find(items: &[TrayMenuItem], id: i32) -> Option<&TrayMenuItem> {
+        items.iter().find_map(|i| {
+            if i.id == id {
+                Some(i)
+            } else {
+                Self::find(&i.children, id)
+            }
+        })
+    }
+}
+
+// Converts the menu items, the callbacks are stored under the id of the item
+fn convert_menu_items(
+    items: &[MenuItem],
+    callbacks: &mut BTreeMap<i32, MenuCallback>,
+    next_id: &mut i32,
+) -> Vec<TrayMenuItem> {
+    let mut converted = Vec::new();
+
+    for item in items {
+        *next_id += 1;
+        let id = *next_id;
+        match item {
+            MenuItem::String(s) => {
+                if let Some(callback) = s.callback.as_ref() {
+                    callbacks.insert(id, callback.clone());
+                }
+                converted.push(TrayMenuItem {
+                    id,
+                    label: s.label.as_str().to_string(),
+                    enabled: s.state == MenuItemState::Normal,
+                    separator: false,
+                    checked: match s.icon.as_ref() {
+                        Some(MenuItemIcon::Checkbox(c)) => Some(*c),
+                        _ => None,
+                    },
+                    children: convert_menu_items(s.children.as_ref(), callbacks, next_id),
+                });
+            }
+            MenuItem::Separator => converted.push(TrayMenuItem {
+                id,
+                label: String::new(),
+                enabled: true,
+                separator: true,
+                checked: None,
+                children: Vec::new(),
+            }),
+            // menus in the tray are always vertical
+            MenuItem::BreakLine => {}
+        }
+    }
+
+    converted
+}
+
+// Everything the D-Bus thread needs to know about the icon
+#[derive(Debug, Clone, PartialEq)]
+struct TrayData {
+    icon_size: usize,
+    icon_rgba: Vec<u8>,
+    tooltip: String,
+    // the panel opens the menu on a left click
+    item_is_menu: bool,
+    menu: Vec<TrayMenuItem>,
+}
+
+impl TrayData {
+    fn new(tray_icon: &TrayIcon, callbacks: &mut BTreeMap<i32, MenuCallback>) -> Self {
+        callbacks.clear();
+        let mut next_id = ROOT_MENU_ID;
+        Self {
+            icon_size: tray_icon.icon.get_size(),
+            icon_rgba: tray_icon.icon.get_rgba_bytes().to_vec(),
+            tooltip: tray_icon.tooltip.as_str().to_string(),
+            item_is_menu: tray_icon.menu.context_mouse_btn == ContextMenuMouseButton::Left,
+            menu: convert_menu_items(tray_icon.menu.items.as_ref(), callbacks, &mut next_id),
+        }
+    }
+
+    // returns the RGBA color of the pixel, nearest-neighbour scaled to the given size
+    fn get_pixel(&self, x: usize, y: usize, size: usize) -> [u8; 4] {
+        let src_x = x * self.icon_size / size.max(1);
+        let src_y = y * self.icon_size / size.max(1);
+        let i = (src_y * self.icon_size + src_x) * 4;
+        match self.icon_rgba.get(i..(i + 4)) {
+            Some(p) => [p[0], p[1], p[2], p[3]],
+            None => [0; 4],
+        }
+    }
+}
+
+/// System tray icon of a window
+pub(crate) struct SystemTray {
+    hash: u64,
+    callbacks: BTreeMap<i32, MenuCallback>,
+    backend: TrayBackend,
+}
+
+enum TrayBackend {
+    StatusNotifier(StatusNotifierItem),
+    XEmbed(XEmbedTray),
+}
+
+impl SystemTray {
+    /// Creates the tray icon. The XEmbed fallback is only
+    /// tried if the X11 display of the window is given.
+    pub(crate) fn new(
+        tray_icon: &TrayIcon,
+        waker: TrayWaker,
+        x11_display: Option<*mut Display>,
+    ) -> Option<Self> {
+        let mut callbacks = BTreeMap::new();
+        let data = TrayData::new(tray_icon, &mut callbacks);
+
+        let backend = match StatusNotifierItem::spawn(data.clone(), waker) {
+            Some(s) => TrayBackend::StatusNotifier(s),
+            None => TrayBackend::XEmbed(XEmbedTray::new(x11_display?, data)?),
+        };
+
+        Some(Self {
+            hash: tray_icon.get_hash(),
+            callbacks,
+            backend,
+        })
+    }
+
+    /// Updates the icon, tooltip and menu if the tray icon changed
+    pub(crate) fn update(&mut self, tray_icon: &TrayIcon) {
+        let hash = tray_icon.get_hash();
+        if hash == self.hash {
+            return;
+        }
+        self.hash = hash;
+
+        let data = TrayData::new(tray_icon, &mut self.callbacks);
+        match &mut self.backend {
+            TrayBackend::StatusNotifier(s) => s.update(data),
+            TrayBackend::XEmbed(x) => x.update(data),
+        }
+    }
+
+    /// Returns the ids of the menu items that were clicked since the last call
+    pub(crate) fn take_clicked_items(&mut self) -> Vec<i32> {
+        match &mut self.backend {
+            TrayBackend::StatusNotifier(s) => s.events.try_iter().collect(),
+            TrayBackend::XEmbed(_) => Vec::new(),
+        }
+    }
+
+    /// Returns the position (in root window coordinates) where the icon was clicked
+    /// with the `context_mouse_btn`, if the menu has to be opened by the application
+    pub(crate) fn take_context_menu_request(&mut self) -> Option<(c_int, c_int)> {
+        match &mut self.backend {
+            TrayBackend::StatusNotifier(_) => None,
+            TrayBackend::XEmbed(x) => x.context_menu_request.take(),
+        }
+    }
+
+    pub(crate) fn get_menu_callback(&mut self, id: i32) -> Option<&mut MenuCallback> {
+        self.callbacks.get_mut(&id)
+    }
+
+    /// Handles the events of the XEmbed tray window, returns
+    /// false if the event doesn't belong to the tray window
+    pub(crate) fn handle_x11_event(&mut self, event: &XEvent) -> bool {
+        match &mut self.backend {
+            TrayBackend::StatusNotifier(_) => false,
+            TrayBackend::XEmbed(x) => x.handle_event(event),
+        }
+    }
+}
+
+enum SniRequest {
+    Update(TrayData),
+}
+
+// Handle to the thread that exports the StatusNotifierItem
+struct StatusNotifierItem {
+    // None once the item is being destroyed
+    sender: Option<Sender<SniRequest>>,
+    // ids of the clicked menu items
+    events: Receiver<i32>,
+    // write end of the pipe that wakes up the D-Bus thread
+    wake_fd: c_int,
+}
+
+impl StatusNotifierItem {
+    fn spawn(data: TrayData, waker: TrayWaker) -> Option<Self> {
+        let mut fds = [0; 2];
+        if unsafe { pipe(fds.as_mut_ptr()) } != 0 {
+            return None;
+        }
+
+        let (sender, receiver) = mpsc::channel();
+        let (event_sender, events) = mpsc::channel();
+        let (startup_sender, startup_receiver) = mpsc::channel();
+        let wake_read_fd = fds[0];
+
+        let spawned = thread::Builder::new()
+            .name("tray-icon".to_string())
+            .spawn(move || {
+                match SniThread::new(data, receiver, wake_read_fd, event_sender, waker) {
+                    Ok(mut sni) => {
+                        let _ = startup_sender.send(true);
+                        sni.run();
+                    }
+                    Err(_) => {
+                        let _ = startup_sender.send(false);
+                    }
+                }
+                unsafe { close(wake_read_fd) };
+            });
+
+        if spawned.is_err() || startup_receiver.recv() != Ok(true) {
+            unsafe {
+                close(fds[1]);
+                if spawned.is_err() {
+                    close(fds[0]);
+                }
+            }
+            return None;
+        }
+
+        Some(Self {
+            sender: Some(sender),
+            events,
+            wake_fd: fds[1],
+        })
+    }
+
+    fn update(&mut self, data: TrayData) {
+        if let Some(sender) = self.sender.as_ref() {
+            let _ = sender.send(SniRequest::Update(data));
+        }
+        unsafe { write(self.wake_fd, [1_u8].as_ptr() as *const c_void, 1) };
+    }
+}
+
+impl Drop for StatusNotifierItem {
+    fn drop(&mut self) {
+        // the thread exits once the sender is disconnected, closing
+        // the connection removes the item from the panel
+        self.sender = None;
+        unsafe {
+            write(self.wake_fd, [1_u8].as_ptr() as *const c_void, 1);
+            close(self.wake_fd);
+        }
+    }
+}
+
+struct SniThread {
+    connection: DBusConnection,
+    service_name: String,
+    data: TrayData,
+    // incremented every time the menu changes
+    menu_revision: u32,
+    receiver: Receiver<SniRequest>,
+    wake_fd: c_int,
+    events: Sender<i32>,
+    waker: TrayWaker,
+}
+
+impl SniThread {
+    fn new(
+        data: TrayData,
+        receiver: Receiver<SniRequest>,
+        wake_fd: c_int,
+        events: Sender<i32>,
+        waker: TrayWaker,
+    ) -> Result<Self, String> {
+        let connection = DBusConnection::session()?;
+
+        let service_name = format!(
+            "org.kde.StatusNotifierItem-{}-{}",
+            std::process::id(),
+            TRAY_ID_GENERATOR.fetch_add(1, Ordering::SeqCst) + 1
+        );
+        if !connection.request_name(&service_name) {
+            return Err(format!("D-Bus: could not acquire name {}", service_name));
+        }
+
+        let sni = Self {
+            connection,
+            service_name,
+            data,
+            menu_revision: 1,
+            receiver,
+            wake_fd,
+            events,
+            waker,
+        };
+
+        // fails if no panel supports StatusNotifierItems
+        sni.connection.call(
+            WATCHER_NAME,
+            WATCHER_PATH,
+            WATCHER_NAME,
+            "RegisterStatusNotifierItem",
+            &[DBusValue::string(&sni.service_name)],
+            REGISTER_TIMEOUT_MS,
+        )?;
+
+        // register again if the panel restarts
+        sni.connection.add_match(&format!(
+            "type='signal',interface='org.freedesktop.DBus',member='NameOwnerChanged',arg0='{}'",
+            WATCHER_NAME
+        ))?;
+
+        Ok(sni)
+    }
+
+    fn run(&mut self) {
+        let dbus_fd = match self.connection.unix_fd() {
+            Some(s) => s,
+            None => return,
+        };
+
+        loop {
+            if !self.connection.read_write(0) {
+                return; // disconnected from the bus
+            }
+
+            while let Some(message) = self.connection.pop_message() {
+                self.handle_message(&message);
+            }
+
+            loop {
+                match self.receiver.try_recv() {
+                    Ok(SniRequest::Update(data)) => self.set_data(data),
+                    Err(TryRecvError::Empty) => break,
+                    Err(TryRecvError::Disconnected) => return,
+                }
+            }
+
+            self.connection.flush();
+
+            let mut fds = [
+                pollfd {
+                    fd: dbus_fd,
+                    events: POLLIN,
+                    revents: 0,
+                },
+                pollfd {
+                    fd: self.wake_fd,
+                    events: POLLIN,
+                    revents: 0,
+                },
+            ];
+
+            unsafe { poll(fds.as_mut_ptr(), 2, -1) };
+
+            if fds[1].revents & POLLIN != 0 {
+                let mut buf = [0_u8; 64];
+                unsafe { read(self.wake_fd, buf.as_mut_ptr() as *mut c_void, buf.len()) };
+            }
+        }
+    }
+
+    // emits the change signals for the parts of the icon that changed
+    fn set_data(&mut self, data: TrayData) {
+        let old = core::mem::replace(&mut self.data, data);
+        let c = &self.connection;
+
+        if old.icon_rgba != self.data.icon_rgba || old.icon_size != self.data.icon_size {
+            c.emit_signal(ITEM_PATH, ITEM_INTERFACE, "NewIcon", &[]);
+        }
+        if old.tooltip != self.data.tooltip {
+            c.emit_signal(ITEM_PATH, ITEM_INTERFACE, "NewTitle", &[]);
+            c.emit_signal(ITEM_PATH, ITEM_INTERFACE, "NewToolTip", &[]);
+        }
+        if old.menu != self.data.menu {
+            self.menu_revision += 1;
+            c.emit_signal(
+                MENU_PATH,
+                MENU_INTERFACE,
+                "LayoutUpdated",
+                &[
+                    DBusValue::UInt32(self.menu_revision),
+                    DBusValue::Int32(ROOT_MENU_ID),
+                ],
+            );
+        }
+    }
+
+    fn handle_message(&mut self, message: &DBusMessage) {
+        if message.is_signal("org.freedesktop.DBus", "NameOwnerChanged") {
+            // args: name, old owner, new owner
+            let args = message.args();
+            let new_owner = args.get(2).and_then(|a| a.as_str()).unwrap_or_default();
+            if args.get(0).and_then(|a| a.as_str()) == Some(WATCHER_NAME) && !new_owner.is_empty() {
+                self.connection.call_async(
+                    WATCHER_NAME,
+                    WATCHER_PATH,
+                    WATCHER_NAME,
+                    "RegisterStatusNotifierItem",
+                    &[DBusValue::string(&self.service_name)],
+                );
+            }
+            return;
+        }
+
+        if message.message_type() != Some(DBusMessageType::MethodCall) {
+            return;
+        }
+
+        let path = message.path().unwrap_or_default();
+        let interface = message.interface().unwrap_or_default();
+        let member = message.member().unwrap_or_default();
+        let args = message.args();
+
+        let reply = match (path.as_str(), interface.as_str(), member.as_str()) {
+            (ITEM_PATH, INTROSPECTABLE_INTERFACE, "Introspect") => {
+                Some(vec![DBusValue::string(ITEM_INTROSPECTION_XML)])
+            }
+            (MENU_PATH, INTROSPECTABLE_INTERFACE, "Introspect") => {
+                Some(vec![DBusValue::string(MENU_INTROSPECTION_XML)])
+            }
+            (_, PROPERTIES_INTERFACE, "Get") => {
+                let interface = args.get(0).and_then(|a| a.as_str()).unwrap_or_default();
+                let name = args.get(1).and_then(|a| a.as_str()).unwrap_or_default();
+                self.get_properties(&path, interface)
+                    .into_iter()
+                    .find(|(k, _)| *k == name)
+                    .map(|(_, v)| vec![DBusValue::variant(v)])
+            }
+            (_, PROPERTIES_INTERFACE, "GetAll") => {
+                let interface = args.get(0).and_then(|a| a.as_str()).unwrap_or_default();
+                Some(vec![DBusValue::dict(self.get_properties(&path, interface))])
+            }
+            // the panel shows the exported menu
+            (ITEM_PATH, ITEM_INTERFACE, "Activate")
+            | (ITEM_PATH, ITEM_INTERFACE, "SecondaryActivate")
+            | (ITEM_PATH, ITEM_INTERFACE, "ContextMenu")
+            | (ITEM_PATH, ITEM_INTERFACE, "Scroll") => Some(Vec::new()),
+            (MENU_PATH, MENU_INTERFACE, "GetLayout") => {
+                // args: parent id, recursion depth, property names
+                let parent = args.get(0).and_then(|a| a.as_i64()).unwrap_or(0) as i32;
+                let depth = args.get(1).and_then(|a| a.as_i64()).unwrap_or(-1) as i32;
+                let names = get_string_array(args.get(2));
+                self.get_menu_layout(parent, depth, &names)
+                    .map(|layout| vec![DBusValue::UInt32(self.menu_revision), layout])
+            }
+            (MENU_PATH, MENU_INTERFACE, "GetGroupProperties") => {
+                let names = get_string_array(args.get(1));
+                let items = args
+                    .get(0)
+                    .and_then(|a| a.as_slice())
+                    .unwrap_or_default()
+                    .iter()
+                    .filter_map(|id| id.as_i64())
+                    .filter_map(|id| {
+                        let props = self.get_menu_item_properties(id as i32, &names)?;
+                        Some(DBusValue::Struct(vec![
+                            DBusValue::Int32(id as i32),
+                            DBusValue::dict(props),
+                        ]))
+                    })
+                    .collect();
+                Some(vec![DBusValue::Array("(ia{sv})".to_string(), items)])
+            }
+            (MENU_PATH, MENU_INTERFACE, "GetProperty") => {
+                let id = args.get(0).and_then(|a| a.as_i64()).unwrap_or(-1) as i32;
+                let name = args.get(1).and_then(|a| a.as_str()).unwrap_or_default();
+                self.get_menu_item_properties(id, &[name.to_string()])
+                    .and_then(|props| props.into_iter().next())
+                    .map(|(_, v)| vec![DBusValue::variant(v)])
+            }
+            (MENU_PATH, MENU_INTERFACE, "Event") => {
+                // args: id, event id, data, timestamp
+                let id = args.get(0).and_then(|a| a.as_i64()).unwrap_or(-1) as i32;
+                let event_id = args.get(1).and_then(|a| a.as_str()).unwrap_or_default();
+                self.handle_menu_event(id, event_id);
+                Some(Vec::new())
+            }
+            (MENU_PATH, MENU_INTERFACE, "EventGroup") => {
+                // args: array of (id, event id, data, timestamp)
+                for event in args.get(0).and_then(|a| a.as_slice()).unwrap_or_default() {
+                    let event = event.as_slice().unwrap_or_default();
+                    let id = event.get(0).and_then(|a| a.as_i64()).unwrap_or(-1) as i32;
+                    let event_id = event.get(1).and_then(|a| a.as_str()).unwrap_or_default();
+                    self.handle_menu_event(id, event_id);
+                }
+                Some(vec![DBusValue::Array("i".to_string(), Vec::new())])
+            }
+            (MENU_PATH, MENU_INTERFACE, "AboutToShow") => Some(vec![DBusValue::Bool(false)]),
+            (MENU_PATH, MENU_INTERFACE, "AboutToShowGroup") => Some(vec![
+                DBusValue::Array("i".to_string(), Vec::new()),
+                DBusValue::Array("i".to_string(), Vec::new()),
+            ]),
+            _ => {
+                self.connection.reply_error(
+                    message,
+                    DBUS_ERROR_UNKNOWN_METHOD,
+                    &format!("unknown method {}.{}", interface, member),
+                );
+                return;
+            }
+        };
+
+        match reply {
+            Some(args) => self.connection.reply(message, &args),
+            None => self.connection.reply_error(
+                message,
+                DBUS_ERROR_INVALID_ARGS,
+                &format!("invalid arguments for {}.{}", interface, member),
+            ),
+        };
+    }
+
+    fn handle_menu_event(&mut self, id: i32, event_id: &str) {
+        if event_id != "clicked" {
+            return;
+        }
+        let enabled = TrayMenuItem::find(&self.data.menu, id)
+            .map(|i| i.enabled && !i.separator)
+            .unwrap_or(false);
+        if enabled && self.events.send(id).is_ok() {
+            self.waker.wake();
+        }
+    }
+
+    fn get_properties(&self, path: &str, interface: &str) -> Vec<(&'static str, DBusValue)> {
+        match (path, interface) {
+            (ITEM_PATH, ITEM_INTERFACE) => {
+                let title = DBusValue::string(&self.data.tooltip);
+                vec![
+                    ("Category", DBusValue::string("ApplicationStatus")),
+                    ("Id", DBusValue::string(&get_application_id())),
+                    ("Title", title.clone()),
+                    ("Status", DBusValue::string("Active")),
+                    ("WindowId", DBusValue::Int32(0)),
+                    ("IconName", DBusValue::string("")),
+                    ("IconPixmap", self.get_icon_pixmaps()),
+                    ("ToolTip", {
+                        DBusValue::Struct(vec![
+                            DBusValue::string(""),
+                            DBusValue::Array("(iiay)".to_string(), Vec::new()),
+                            title,
+                            DBusValue::string(""),
+                        ])
+                    }),
+                    ("ItemIsMenu", DBusValue::Bool(self.data.item_is_menu)),
+                    ("Menu", DBusValue::ObjectPath(MENU_PATH.to_string())),
+                ]
+            }
+            (MENU_PATH, MENU_INTERFACE) => vec![
+                ("Version", DBusValue::UInt32(3)),
+                ("TextDirection", DBusValue::string("ltr")),
+                ("Status", DBusValue::string("normal")),
+                (
+                    "IconThemePath",
+                    DBusValue::Array("s".to_string(), Vec::new()),
+                ),
+            ],
+            _ => Vec::new(),
+        }
+    }
+
+    // a(iiay): width, height and ARGB32 pixels in network byte order
+    fn get_icon_pixmaps(&self) -> DBusValue {
+        let argb = self
+            .data
+            .icon_rgba
+            .chunks_exact(4)
+            .flat_map(|p| [p[3], p[0], p[1], p[2]])
+            .map(DBusValue::Byte)
+            .collect();
+        DBusValue::Array(
+            "(iiay)".to_string(),
+            vec![DBusValue::Struct(vec![
+                DBusValue::Int32(self.data.icon_size as i32),
+                DBusValue::Int32(self.data.icon_size as i32),
+                DBusValue::Array("y".to_string(), argb),
+            ])],
+        )
+    }
+
+    // (ia{sv}av): id, properties and children of the item
+    fn get_menu_layout(&self, id: i32, depth: i32, names: &[String]) -> Option<DBusValue> {
+        let children = if id == ROOT_MENU_ID {
+            &self.data.menu
+        } else {
+            &TrayMenuItem::find(&self.data.menu, id)?.children
+        };
+
+        let children = if depth == 0 {
+            Vec::new()
+        } else {
+            children
+                .iter()
+                .filter_map(|c| self.get_menu_layout(c.id, depth - 1, names))
+                .map(DBusValue::variant)
+                .collect()
+        };
+
+        Some(DBusValue::Struct(vec![
+            DBusValue::Int32(id),
+            DBusValue::dict(self.get_menu_item_properties(id, names)?),
+            DBusValue::Array("v".to_string(), children),
+        ]))
+    }
+
+    fn get_menu_item_properties(
+        &self,
+        id: i32,
+        names: &[String],
+    ) -> Option<Vec<(&'static str, DBusValue)>> {
+        let mut props = Vec::new();
+
+        if id == ROOT_MENU_ID {
+            props.push(("children-display", DBusValue::string("submenu")));
+        } else {
+            let item = TrayMenuItem::find(&self.data.menu, id)?;
+            if item.separator {
+                props.push(("type", DBusValue::string("separator")));
+            } else {
+                // underscores mark the access key
+                props.push(("label", DBusValue::String(item.label.replace('_', "__"))));
+                props.push(("enabled", DBusValue::Bool(item.enabled)));
+                if let Some(checked) = item.checked {
+                    props.push(("toggle-type", DBusValue::string("checkmark")));
+                    props.push(("toggle-state", DBusValue::Int32(checked as i32)));
+                }
+                if !item.children.is_empty() {
+                    props.push(("children-display", DBusValue::string("submenu")));
+                }
+            }
+        }
+
+        // an empty list requests all properties
+        if !names.is_empty() {
+            props.retain(|(k, _)| names.iter().any(|n| n == k));
+        }
+
+        Some(props)
+    }
+}
+
+fn get_string_array(value: Option<&DBusValue>) -> Vec<String> {
+    value
+        .and_then(|v| v.as_slice())
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|s| s.as_str().map(|s| s.to_string()))
+        .collect()
+}
+
+// name of the binary, used as the id of the item
+pub(crate) fn get_application_id() -> String {
+    std::env::current_exe()
+        .ok()
+        .and_then(|p| p.file_stem().map(|s| s.to_string_lossy().into_owned()))
+        .unwrap_or_else(|| "azul".to_string())
+}
+
+const ITEM_INTROSPECTION_XML: &str = r#"<!DOCTYPE node PUBLIC "-//freedesktop//DTD D-BUS Object Introspection 1.0//EN" "http://www.freedesktop.org/standards/dbus/1.0/introspect.dtd">
+<node>
+  <interface name="org.kde.StatusNotifierItem">
+    <property name="Category" type="s" access="read"/>
+    <property name="Id" type="s" access="read"/>
+    <property name="Title" type="s" access="read"/>
+    <property name="Status" type="s" access="read"/>
+    <property name="WindowId" type="i" access="read"/>
+    <property name="IconName" type="s" access="read"/>
+    <property name="IconPixmap" type="a(iiay)" access="read"/>
+    <property name="ToolTip" type="(sa(iiay)ss)" access="read"/>
+    <property name="ItemIsMenu" type="b" access="read"/>
+    <property name="Menu" type="o" access="read"/>
+    <method name="ContextMenu"><arg name="x" type="i" direction="in"/><arg name="y" type="i" direction="in"/></method>
+    <method name="Activate"><arg name="x" type="i" direction="in"/><arg name="y" type="i" direction="in"/></method>
+    <method name="SecondaryActivate"><arg name="x" type="i" direction="in"/><arg name="y" type="i" direction="in"/></method>
+    <method name="Scroll"><arg name="delta" type="i" direction="in"/><arg name="orientation" type="s" direction="in"/></method>
+    <signal name="NewTitle"/>
+    <signal name="NewIcon"/>
+    <signal name="NewToolTip"/>
+    <signal name="NewStatus"><arg name="status" type="s"/></signal>
+  </interface>
+</node>"#;
+
+const MENU_INTROSPECTION_XML: &str = r#"<!DOCTYPE node PUBLIC "-//freedesktop//DTD D-BUS Object Introspection 1.0//EN" "http://www.freedesktop.org/standards/dbus/1.0/introspect.dtd">
+<node>
+  <interface name="com.canonical.dbusmenu">
+    <property name="Version" type="u" access="read"/>
+    <property name="TextDirection" type="s" access="read"/>
+    <property name="Status" type="s" access="read"/>
+    <property name="IconThemePath" type="as" access="read"/>
+    <method name="GetLayout">
+      <arg name="parentId" type="i" direction="in"/>
+      <arg name="recursionDepth" type="i" direction="in"/>
+      <arg name="propertyNames" type="as" direction="in"/>
+      <arg name="revision" type="u" direction="out"/>
+      <arg name="layout" type="(ia{sv}av)" direction="out"/>
+    </method>
+    <method name="GetGroupProperties">
+      <arg name="ids" type="ai" direction="in"/>
+      <arg name="propertyNames" type="as" direction="in"/>
+      <arg name="properties" type="a(ia{sv})" direction="out"/>
+    </method>
+    <method name="GetProperty">
+      <arg name="id" type="i" direction="in"/>
+      <arg name="name" type="s" direction="in"/>
+      <arg name="value" type="v" direction="out"/>
+    </method>
+    <method name="Event">
+      <arg name="id" type="i" direction="in"/>
+      <arg name="eventId" type="s" direction="in"/>
+      <arg name="data" type="v" direction="in"/>
+      <arg name="timestamp" type="u" direction="in"/>
+    </method>
+    <method name="EventGroup">
+      <arg name="events" type="a(isvu)" direction="in"/>
+      <arg name="idErrors" type="ai" direction="out"/>
+    </method>
+    <method name="AboutToShow">
+      <arg name="id" type="i" direction="in"/>
+      <arg name="needUpdate" type="b" direction="out"/>
+    </method>
+    <method name="AboutToShowGroup">
+      <arg name="ids" type="ai" direction="in"/>
+      <arg name="updatesNeeded" type="ai" direction="out"/>
+      <arg name="idErrors" type="ai" direction="out"/>
+    </method>
+    <signal name="ItemsPropertiesUpdated">
+      <arg name="updatedProps" type="a(ia{sv})"/>
+      <arg name="removedProps" type="a(ias)"/>
+    </signal>
+    <signal name="LayoutUpdated">
+      <arg name="revision" type="u"/>
+      <arg name="parent" type="i"/>
+    </signal>
+  </interface>
+</node>"#;
+
+// -- XEmbed fallback
+
+const SYSTEM_TRAY_REQUEST_DOCK: c_long = 0;
+const XEMBED_MAPPED: c_long = 1;
+const PARENT_RELATIVE: c_ulong = 1;
+const PROP_MODE_REPLACE: c_int = 0;
+const DEFAULT_TRAY_ICON_SIZE: c_uint = 22;
+
+const EXPOSURE_MASK: c_long = 0x0000_8000;
+const BUTTON_PRESS_MASK: c_long = 0x0000_0004;
+const STRUCTURE_NOTIFY_MASK: c_long = 0x0002_0000;
+
+const EXPOSE: c_int = 12;
+const BUTTON_PRESS: c_int = 4;
+const CONFIGURE_NOTIFY: c_int = 22;
+
+type XOpenDisplayFuncType = extern "C" fn(*const c_char) -> *mut Display;
+type XCloseDisplayFuncType = extern "C" fn(*mut Display) -> c_int;
+type XDefaultScreenFuncType = extern "C" fn(*mut Display) -> c_int;
+type XRootWindowFuncType = extern "C" fn(*mut Display, c_int) -> Window;
+type XInternAtomFuncType = extern "C" fn(*mut Display, *const c_char, c_int) -> Atom;
+type XGetSelectionOwnerFuncType = extern "C" fn(*mut Display, Atom) -> Window;
+type XCreateSimpleWindowFuncType = extern "C" fn(
+    *mut Display,
+    Window,
+    c_int,
+    c_int,
+    c_uint,
+    c_uint,
+    c_uint,
+    c_ulong,
+    c_ulong,
+) -> Window;
+type XDestroyWindowFuncType = extern "C" fn(*mut Display, Window) -> c_int;
+type XSetWindowBackgroundPixmapFuncType = extern "C" fn(*mut Display, Window, c_ulong) -> c_int;
+type XSelectInputFuncType = extern "C" fn(*mut Display, Window, c_long) -> c_int;
+type XStoreNameFuncType = extern "C" fn(*mut Display, Window, *const c_char) -> c_int;
+type XChangePropertyFuncType =
+    extern "C" fn(*mut Display, Window, Atom, Atom, c_int, c_int, *const c_uchar, c_int) -> c_int;
+type XSendEventFuncType = extern "C" fn(*mut Display, Window, c_int, c_long, *mut XEvent) -> c_int;
+type XFlushFuncType = extern "C" fn(*mut Display) -> c_int;
+type XCreateGCFuncType = extern "C" fn(*mut Display, Window, c_ulong, *mut c_void) -> *mut c_void;
+type XFreeGCFuncType = extern "C" fn(*mut Display, *mut c_void) -> c_int;
+type XSetForegroundFuncType = extern "C" fn(*mut Display, *mut c_void, c_ulong) -> c_int;
+type XDrawPointFuncType = extern "C" fn(*mut Display, Window, *mut c_void, c_int, c_int) -> c_int;
+type XClearWindowFuncType = extern "C" fn(*mut Display, Window) -> c_int;
+
+// Xlib functions used for the XEmbed tray window and to wake up the main loop
+struct TrayXlib {
+    pub library: Library,
+    pub XOpenDisplay: XOpenDisplayFuncType,
+    pub XCloseDisplay: XCloseDisplayFuncType,
+    pub XDefaultScreen: XDefaultScreenFuncType,
+    pub XRootWindow: XRootWindowFuncType,
+    pub XInternAtom: XInternAtomFuncType,
+    pub XGetSelectionOwner: XGetSelectionOwnerFuncType,
+    pub XCreateSimpleWindow: XCreateSimpleWindowFuncType,
+    pub XDestroyWindow: XDestroyWindowFuncType,
+    pub XSetWindowBackgroundPixmap: XSetWindowBackgroundPixmapFuncType,
+    pub XSelectInput: XSelectInputFuncType,
+    pub XStoreName: XStoreNameFuncType,
+    pub XChangeProperty: XChangePropertyFuncType,
+    pub XSendEvent: XSendEventFuncType,
+    pub XFlush: XFlushFuncType,
+    pub XCreateGC: XCreateGCFuncType,
+    pub XFreeGC: XFreeGCFuncType,
+    pub XSetForeground: XSetForegroundFuncType,
+    pub XDrawPoint: XDrawPointFuncType,
+    pub XClearWindow: XClearWindowFuncType,
+}
+
+impl TrayXlib {
+    fn new() -> Option<Self> {
+        use super::clipboard::load_function;
+
+        let library = Library::load("libX11.so").ok()?;
+        Some(Self {
+            XOpenDisplay: load_function(&library, "XOpenDisplay")?,
+            XCloseDisplay: load_function(&library, "XCloseDisplay")?,
+            XDefaultScreen: load_function(&library, "XDefaultScreen")?,
+            XRootWindow: load_function(&library, "XRootWindow")?,
+            XInternAtom: load_function(&library, "XInternAtom")?,
+            XGetSelectionOwner: load_function(&library, "XGetSelectionOwner")?,
+            XCreateSimpleWindow: load_function(&library, "XCreateSimpleWindow")?,
+            XDestroyWindow: load_function(&library, "XDestroyWindow")?,
+            XSetWindowBackgroundPixmap: load_function(&library, "XSetWindowBackgroundPixmap")?,
+            XSelectInput: load_function(&library, "XSelectInput")?,
+            XStoreName: load_function(&library, "XStoreName")?,
+            XChangeProperty: load_function(&library, "XChangeProperty")?,
+            XSendEvent: load_function(&library, "XSendEvent")?,
+            XFlush: load_function(&library, "XFlush")?,
+            XCreateGC: load_function(&library, "XCreateGC")?,
+            XFreeGC: load_function(&library, "XFreeGC")?,
+            XSetForeground: load_function(&library, "XSetForeground")?,
+            XDrawPoint: load_function(&library, "XDrawPoint")?,
+            XClearWindow: load_function(&library, "XClearWindow")?,
+            library,
+        })
+    }
+}
+
+static TRAY_XLIB: Lazy<Option<TrayXlib>> = Lazy::new(TrayXlib::new);
+
+fn send_client_message(
+    xlib: &TrayXlib,
+    display: *mut Display,
+    target: Window,
+    window: Window,
+    message_type: Atom,
+    longs: [c_long; 5],
+) {
+    let mut event = XEvent { pad: [0; 24] };
+    event.client_message = XClientMessageEvent {
+        type_: super::X11_CLIENT_MESSAGE,
+        serial: 0,
+        send_event: X11_TRUE,
+        display,
+        window,
+        message_type,
+        format: 32,
+        data: ClientMessageData { longs },
+    };
+    (xlib.XSendEvent)(display, target, X11_FALSE, 0, &mut event);
+    (xlib.XFlush)(display);
+}
+
+// Icon window, docked into the system tray of the panel (on the connection of the window)
+struct XEmbedTray {
+    xlib: &'static TrayXlib,
+    display: *mut Display,
+    window: Window,
+    gc: *mut c_void,
+    size: c_int,
+    data: TrayData,
+    context_menu_request: Option<(c_int, c_int)>,
+}
+
+impl XEmbedTray {
+    fn new(display: *mut Display, data: TrayData) -> Option<Self> {
+        let xlib = TRAY_XLIB.as_ref()?;
+
+        let screen = (xlib.XDefaultScreen)(display);
+        let root = (xlib.XRootWindow)(display, screen);
+        let intern = |name: &str| {
+            let name = CString::new(name).unwrap();
+            (xlib.XInternAtom)(display, name.as_ptr(), X11_FALSE)
+        };
+
+        let manager =
+            (xlib.XGetSelectionOwner)(display, intern(&format!("_NET_SYSTEM_TRAY_S{}", screen)));
+        if manager == 0 {
+            return None; // no system tray
+        }
+
+        let window = (xlib.XCreateSimpleWindow)(
+            display,
+            root,
+            0,
+            0,
+            DEFAULT_TRAY_ICON_SIZE,
+            DEFAULT_TRAY_ICON_SIZE,
+            0,
+            0,
+            0,
+        );
+        if window == 0 {
+            return None;
+        }
+
+        // the background of the tray shines through the transparent pixels
+        (xlib.XSetWindowBackgroundPixmap)(display, window, PARENT_RELATIVE);
+        (xlib.XSelectInput)(
+            display,
+            window,
+            EXPOSURE_MASK | BUTTON_PRESS_MASK | STRUCTURE_NOTIFY_MASK,
+        );
+
+        if let Ok(title) = CString::new(data.tooltip.clone()) {
+            (xlib.XStoreName)(display, window, title.as_ptr());
+        }
+
+        // version 0, mapped by the embedder
+        let xembed_info = intern("_XEMBED_INFO");
+        let info: [c_long; 2] = [0, XEMBED_MAPPED];
+        (xlib.XChangeProperty)(
+            display,
+            window,
+            xembed_info,
+            xembed_info,
+            32,
+            PROP_MODE_REPLACE,
+            info.as_ptr() as *const c_uchar,
+            info.len() as c_int,
+        );
+
+        send_client_message(
+            xlib,
+            display,
+            manager,
+            manager,
+            intern("_NET_SYSTEM_TRAY_OPCODE"),
+            [0, SYSTEM_TRAY_REQUEST_DOCK, window as c_long, 0, 0],
+        );
+
+        let gc = (xlib.XCreateGC)(display, window, 0, ptr::null_mut());
+
+        Some(Self {
+            xlib,
+            display,
+            window,
+            gc,
+            size: DEFAULT_TRAY_ICON_SIZE as c_int,
+            data,
+            context_menu_request: None,
+        })
+    }
+
+    fn update(&mut self, data: TrayData) {
+        self.data = data;
+        if let Ok(title) = CString::new(self.data.tooltip.clone()) {
+            (self.xlib.XStoreName)(self.display, self.window, title.as_ptr());
+        }
+        self.draw();
+        (self.xlib.XFlush)(self.display);
+    }
+
+    fn handle_event(&mut self, event: &XEvent) -> bool {
+        if unsafe { event.any.window } != self.window {
+            return false;
+        }
+
+        match event.get_type() {
+            EXPOSE => self.draw(),
+            CONFIGURE_NOTIFY => {
+                let configure = unsafe { event.configure };
+                self.size = configure.width.min(configure.height).max(1);
+                self.draw();
+            }
+            BUTTON_PRESS => {
+                let button = unsafe { event.button };
+                let context_button = if self.data.item_is_menu { 1 } else { 3 };
+                if button.button == context_button {
+                    self.context_menu_request = Some((button.x_root, button.y_root));
+                }
+            }
+            _ => {}
+        }
+
+        true
+    }
+
+    // draws the icon, scaled to the size of the window (pixels that are
+    // less than half opaque are left out, XEmbed windows have no alpha)
+    fn draw(&mut self) {
+        (self.xlib.XClearWindow)(self.display, self.window);
+
+        let size = self.size.max(1) as usize;
+        for y in 0..size {
+            for x in 0..size {
+                let [r, g, b, a] = self.data.get_pixel(x, y, size);
+                if a < 128 {
+                    continue;
+                }
+                let pixel = ((r as c_ulong) << 16) | ((g as c_ulong) << 8) | (b as c_ulong);
+                (self.xlib.XSetForeground)(self.display, self.gc, pixel);
+                (self.xlib.XDrawPoint)(self.display, self.window, self.gc, x as c_int, y as c_int);
+            }
+        }
+    }
+}
+
+impl Drop for XEmbedTray {
+    fn drop(&mut self) {
+        (self.xlib.XFreeGC)(self.display, self.gc);
+        (self.xlib.XDestroyWindow)(self.display, self.window);
+        (self.xlib.XFlush)(self.display);
+    }
+}
+
+#[test]
+fn test_tray_menu_conversion() {
+    use azul_core::window::StringMenuItem;
+
+    let items =
+        vec![
+            MenuItem::String(StringMenuItem::new("Open".into())),
+            MenuItem::Separator,
+            MenuItem::String(StringMenuItem::new("More".into()).with_children(
+                vec![MenuItem::String(StringMenuItem::new("Sub_item".into()))].into(),
+            )),
+        ];
+
+    let mut callbacks = BTreeMap::new();
+    let mut next_id = ROOT_MENU_ID;
+    let menu = convert_menu_items(&items, &mut callbacks, &mut next_id);
+
+    assert_eq!(menu.len(), 3);
+    assert!(menu[1].separator);
+    assert_eq!(menu[2].children[0].id, 4);
+    assert_eq!(
+        TrayMenuItem::find(&menu, 4).map(|i| i.label.as_str()),
+        Some("Sub_item")
+    );
+    assert!(callbacks.is_empty());
+}
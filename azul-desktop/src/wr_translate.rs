@@ -92,7 +92,7 @@ use azul_core::{
         ExternalImageType, ImageBufferKind, UpdateImage, ImageDirtyRect,
         Epoch, AddFontInstance, FontVariation, FontInstanceOptions,
         FontInstancePlatformOptions, SyntheticItalics, PrimitiveFlags,
-        TransformKey, UpdateImageResult,
+        TransformKey, UpdateImageResult, RendererResources,
     },
     display_list::{
         CachedDisplayList, GlyphInstance, DisplayListScrollFrame,
@@ -101,9 +101,10 @@ use azul_core::{
     },
     dom::TagId,
     display_list::DisplayListImageMask,
+    scrollbar::ScrollbarRect,
     ui_solver::{
         LayoutResult, ExternalScrollId,
-        PositionInfo, ComputedTransform3D,
+        PositionInfo, ComputedTransform3D, PositionedRectangle,
         QuickResizeResult,
     },
     window::{
@@ -126,6 +127,7 @@ use azul_css::{
     BorderStyle as CssBorderStyle,
     LayoutSideOffsets as CssLayoutSideOffsets,
     StyleMixBlendMode as CssMixBlendMode,
+    StyleBackgroundContent,
     U8Vec,
 };
 use webrender::Renderer;
@@ -290,13 +292,19 @@ pub(crate) fn scroll_all_nodes(scroll_states: &ScrollStates, txn: &mut WrTransac
         txn.scroll_node_with_id(
             wr_translate_logical_position(value.get()),
             wr_translate_external_scroll_id(*key),
-            ScrollClamping::ToContentBounds
+            // already clamped in the ScrollStates, which allow overscrolling
+            ScrollClamping::NoClamping
         );
     }
 }
 
 /// Synchronize transform / opacity keys
-pub(crate) fn synchronize_gpu_values(layout_results: &[LayoutResult], dpi: &DpiScaleFactor, txn: &mut WrTransaction) {
+pub(crate) fn synchronize_gpu_values(
+    layout_results: &[LayoutResult],
+    scrollbar_transforms: &[(TransformKey, ComputedTransform3D)],
+    dpi: &DpiScaleFactor,
+    txn: &mut WrTransaction
+) {
 
     use webrender::api::{
         PropertyBindingKey as WrPropertyBindingKey,
@@ -312,6 +320,11 @@ pub(crate) fn synchronize_gpu_values(layout_results: &[LayoutResult], dpi: &DpiS
             Some((key, value))
         }).collect::<Vec<_>>().into_iter()
     })
+    .chain(scrollbar_transforms.iter().map(|(key, value)| {
+        let mut value = *value;
+        value.scale_for_dpi(dpi.inner.get());
+        (key, value)
+    }))
     .map(|(k, v)| WrPropertyValue {
         key: WrPropertyBindingKey::new(k.id as u64),
         value: wr_translate_layout_transform(&v),
@@ -365,7 +378,7 @@ pub(crate) fn rebuild_display_list(
     internal: &mut WindowInternal,
     render_api: &mut WrRenderApi,
     image_cache: &ImageCache,
-    resources: Vec<ResourceUpdate>,
+    mut resources: Vec<ResourceUpdate>,
 ) {
     use crate::wr_translate::{
         wr_translate_display_list, wr_translate_document_id, wr_translate_epoch,
@@ -390,6 +403,51 @@ pub(crate) fn rebuild_display_list(
         image_cache,
     );
 
+    // Draw the node hovered in the inspector window on top of the display list
+    if let Some(node_id) = internal.inspector_highlight {
+        if let Some(rect) = internal.layout_results.get(root_id.inner).and_then(|lr| lr.rects.internal.get(node_id.index())) {
+            push_inspector_highlight(&mut cached_display_list, rect);
+        }
+    }
+
+    // Draw the scrollbars of the scroll containers on top of their content
+    let scrollbar_rects = internal.scrollbar_states.get_scrollbar_rects(
+        &internal.layout_results,
+        &internal.scroll_states,
+        internal.current_window_state.flags.scrollbar_auto_hide_enabled,
+    );
+    push_scrollbars(&mut cached_display_list, &scrollbar_rects, &internal.renderer_resources, image_cache);
+    internal.scrollbar_states.drawn = scrollbar_rects;
+
+    // Draw the image of the current drag at the cursor position
+    let drag_image = internal.current_window_state.drag_state.current.as_ref().and_then(|d| {
+        let image = d.drag_image.as_ref()?.clone();
+        let cursor = internal.current_window_state.mouse_state.cursor_position.get_position()?;
+        Some((image, cursor, d.drag_image_offset))
+    });
+
+    if let Some((image, cursor, offset)) = drag_image {
+        use azul_core::FastBTreeSet;
+        use azul_core::app_resources::{add_resources, build_add_image_resource_updates};
+
+        let mut images = FastBTreeSet::new();
+        images.insert(image.clone());
+        let image_updates = build_add_image_resource_updates(
+            &internal.renderer_resources,
+            internal.id_namespace,
+            internal.epoch,
+            &internal.document_id,
+            &images,
+            azul_core::gl::insert_into_active_gl_textures,
+        );
+        add_resources(&mut internal.renderer_resources, &mut resources, Vec::new(), image_updates);
+
+        if let Some(resolved) = internal.renderer_resources.get_image(&image.get_hash()) {
+            let position = LogicalPosition::new(cursor.x - offset.x, cursor.y - offset.y);
+            push_drag_image(&mut cached_display_list, resolved.key, image.get_size(), position);
+        }
+    }
+
     // Scale everything in the display list to the DPI of the window
     cached_display_list.scale_for_dpi(internal.current_window_state.size.get_hidpi_factor());
 
@@ -422,6 +480,152 @@ pub(crate) fn rebuild_display_list(
     render_api.send_transaction(wr_translate_document_id(internal.document_id), txn);
 }
 
+/// Pushes the drag image as a fixed-position frame on top of the root frame
+fn push_drag_image(display_list: &mut CachedDisplayList, image_key: ImageKey, size: LogicalSize, position: LogicalPosition) {
+
+    use azul_core::ui_solver::PositionInfoInner;
+
+    let root = match &mut display_list.root {
+        DisplayListMsg::Frame(f) => f,
+        _ => return,
+    };
+
+    let mut frame = DisplayListFrame::root(CssLayoutSize::zero(), CssLayoutPoint::zero());
+    frame.size = size;
+    frame.position = PositionInfo::Fixed(PositionInfoInner {
+        x_offset: position.x,
+        y_offset: position.y,
+        static_x_offset: position.x,
+        static_y_offset: position.y,
+    });
+    frame.content.push(LayoutRectContent::Image {
+        size,
+        offset: LogicalPosition::zero(),
+        image_rendering: ImageRendering::Auto,
+        alpha_type: AlphaType::PremultipliedAlpha,
+        image_key,
+        background_color: CssColorU::TRANSPARENT,
+    });
+    root.children.push(DisplayListMsg::Frame(frame));
+}
+
+/// Pushes the tracks and thumbs of the scrollbars as fixed-position frames on top of the
+/// root frame, with an animatable transform so that scrolling doesn't rebuild the display list
+fn push_scrollbars(
+    display_list: &mut CachedDisplayList,
+    scrollbar_rects: &[ScrollbarRect],
+    renderer_resources: &RendererResources,
+    image_cache: &ImageCache,
+) {
+
+    use azul_core::display_list::RectBackground;
+    use azul_core::ui_solver::PositionInfoInner;
+
+    let root = match &mut display_list.root {
+        DisplayListMsg::Frame(f) => f,
+        _ => return,
+    };
+
+    for ScrollbarRect { rect, background, transform_key, .. } in scrollbar_rects.iter() {
+        let content = match background {
+            StyleBackgroundContent::LinearGradient(lg) => RectBackground::LinearGradient(lg.clone()),
+            StyleBackgroundContent::RadialGradient(rg) => RectBackground::RadialGradient(rg.clone()),
+            StyleBackgroundContent::ConicGradient(cg) => RectBackground::ConicGradient(cg.clone()),
+            StyleBackgroundContent::Color(c) => RectBackground::Color(*c),
+            StyleBackgroundContent::Image(id) => {
+                let resolved = image_cache
+                    .get_css_image_id(id)
+                    .and_then(|image_ref| renderer_resources.get_image(&image_ref.get_hash()));
+                match resolved {
+                    Some(r) => RectBackground::Image((r.key, r.descriptor.clone())),
+                    None => continue,
+                }
+            }
+        };
+
+        let mut frame = DisplayListFrame::root(CssLayoutSize::zero(), CssLayoutPoint::zero());
+        frame.size = rect.size;
+        frame.position = PositionInfo::Fixed(PositionInfoInner {
+            x_offset: rect.origin.x,
+            y_offset: rect.origin.y,
+            static_x_offset: rect.origin.x,
+            static_y_offset: rect.origin.y,
+        });
+        frame.transform = Some((*transform_key, ComputedTransform3D::IDENTITY));
+        frame.content.push(LayoutRectContent::Background {
+            content,
+            size: None,
+            offset: None,
+            repeat: None,
+        });
+        root.children.push(DisplayListMsg::Frame(frame));
+    }
+}
+
+/// Pushes translucent margin / border / padding / content boxes of the
+/// rectangle as fixed-position frames on top of the root frame
+fn push_inspector_highlight(display_list: &mut CachedDisplayList, rect: &PositionedRectangle) {
+
+    use azul_core::display_list::RectBackground;
+    use azul_core::ui_solver::PositionInfoInner;
+
+    let root = match &mut display_list.root {
+        DisplayListMsg::Frame(f) => f,
+        _ => return,
+    };
+
+    let origin = rect.position.get_static_offset();
+    let margin = rect.margin;
+    let border = rect.border_widths;
+    let padding = rect.padding;
+
+    let boxes = [
+        // margin box
+        (
+            origin.x - margin.left, origin.y - margin.top,
+            rect.size.width + margin.total_horizontal(), rect.size.height + margin.total_vertical(),
+            CssColorU { r: 246, g: 178, b: 107, a: 100 },
+        ),
+        // border box
+        (
+            origin.x, origin.y,
+            rect.size.width, rect.size.height,
+            CssColorU { r: 255, g: 229, b: 153, a: 100 },
+        ),
+        // padding box
+        (
+            origin.x + border.left, origin.y + border.top,
+            rect.size.width - border.total_horizontal(), rect.size.height - border.total_vertical(),
+            CssColorU { r: 147, g: 196, b: 125, a: 100 },
+        ),
+        // content box
+        (
+            origin.x + border.left + padding.left, origin.y + border.top + padding.top,
+            rect.size.width - border.total_horizontal() - padding.total_horizontal(),
+            rect.size.height - border.total_vertical() - padding.total_vertical(),
+            CssColorU { r: 111, g: 168, b: 220, a: 100 },
+        ),
+    ];
+
+    for (x, y, width, height, color) in boxes.iter() {
+        let mut frame = DisplayListFrame::root(CssLayoutSize::zero(), CssLayoutPoint::zero());
+        frame.size = LogicalSize::new(width.max(0.0), height.max(0.0));
+        frame.position = PositionInfo::Fixed(PositionInfoInner {
+            x_offset: *x,
+            y_offset: *y,
+            static_x_offset: *x,
+            static_y_offset: *y,
+        });
+        frame.content.push(LayoutRectContent::Background {
+            content: RectBackground::Color(*color),
+            size: None,
+            offset: None,
+            repeat: None,
+        });
+        root.children.push(DisplayListMsg::Frame(frame));
+    }
+}
+
 /// Generates a new frame for webrender
 // #[cfg(not(test))]
 pub(crate) fn generate_frame(
@@ -450,8 +654,15 @@ pub(crate) fn generate_frame(
     txn.set_root_pipeline(wr_translate_pipeline_id(PipelineId(0, internal.document_id.id)));
     txn.set_document_view(WrDeviceIntRect::from_origin_and_size(WrDeviceIntPoint::new(0, 0), framebuffer_size));
     scroll_all_nodes(&mut internal.scroll_states, &mut txn);
+    // move the scrollbars to the current scroll position
+    let scrollbar_transforms = internal.scrollbar_states.get_scrollbar_transforms(
+        &internal.layout_results,
+        &internal.scroll_states,
+        internal.current_window_state.flags.scrollbar_auto_hide_enabled,
+    );
     synchronize_gpu_values(
         &internal.layout_results, 
+        &scrollbar_transforms,
         &internal.get_dpi_scale_factor(), 
         &mut txn
     );
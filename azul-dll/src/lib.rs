@@ -29,6 +29,12 @@ pub use AzAppTT as AzApp;
 #[no_mangle] pub extern "C" fn AzApp_addImage(app: &mut AzApp, id: AzString, image: AzImageRef) { app.add_image(id, image) }
 /// Returns a list of monitors - useful for setting the monitor that a window should spawn on.
 #[no_mangle] pub extern "C" fn AzApp_getMonitors(app: &AzApp) -> AzMonitorVec { app.get_monitors() }
+/// Registers a keyboard shortcut, fails if the keys conflict with an already registered shortcut in the same scope
+#[no_mangle] pub extern "C" fn AzApp_registerShortcut(app: &mut AzApp, shortcut: AzShortcut) -> AzResultShortcutIdShortcutError { app.register_shortcut(shortcut) }
+/// Removes a keyboard shortcut, returns whether it was registered
+#[no_mangle] pub extern "C" fn AzApp_unregisterShortcut(app: &mut AzApp, shortcut_id: AzShortcutId) -> bool { app.unregister_shortcut(shortcut_id) }
+/// Returns the registered keyboard shortcuts (i.e. for a keyboard shortcut help screen)
+#[no_mangle] pub extern "C" fn AzApp_getShortcuts(app: &AzApp) -> AzShortcutBindingVec { app.get_shortcuts() }
 /// Runs the application. Due to platform restrictions (specifically `WinMain` on Windows), this function never returns.
 #[no_mangle] pub extern "C" fn AzApp_run(app: &AzApp, window: AzWindowCreateOptions) { app.run(window) }
 /// Destructor: Takes ownership of the `App` pointer and deletes it.
@@ -64,6 +70,19 @@ pub use AzWindowCreateOptionsTT as AzWindowCreateOptions;
 /// Destructor: Takes ownership of the `WindowCreateOptions` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzWindowCreateOptions_delete(object: &mut AzWindowCreateOptions) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Unique ID of a window, used to close a window or to open a child window
+pub use azul_core::window::WindowId as AzWindowIdTT;
+pub use AzWindowIdTT as AzWindowId;
+
+/// Callback that is invoked on the parent window with the result of a child window after the child window has been closed
+pub use azul_core::window::WindowResultCallback as AzWindowResultCallbackTT;
+pub use AzWindowResultCallbackTT as AzWindowResultCallback;
+/// Creates a new `WindowResultCallback` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `WindowResultCallback::new()` constructor.
+#[no_mangle] pub extern "C" fn AzWindowResultCallback_new(data: AzRefAny, callback: AzWriteBackCallbackType) -> AzWindowResultCallback { AzWindowResultCallback { data, callback: AzWriteBackCallback { cb: callback } } }
+/// Destructor: Takes ownership of the `WindowResultCallback` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzWindowResultCallback_delete(object: &mut AzWindowResultCallback) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Force a specific renderer: note that azul will **crash** on startup if the `RendererOptions` are not satisfied.
 pub use azul_core::window::RendererOptions as AzRendererOptionsTT;
 pub use AzRendererOptionsTT as AzRendererOptions;
@@ -182,6 +201,14 @@ pub use AzWindowIconTT as AzWindowIcon;
 /// Destructor: Takes ownership of the `WindowIcon` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzWindowIcon_delete(object: &mut AzWindowIcon) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Icon in the system tray / notification area of the desktop, with a context menu
+pub use azul_core::window::TrayIcon as AzTrayIconTT;
+pub use AzTrayIconTT as AzTrayIcon;
+/// Creates a new tray icon
+#[no_mangle] pub extern "C" fn AzTrayIcon_new(icon: AzWindowIcon, tooltip: AzString, menu: AzMenu) -> AzTrayIcon { AzTrayIcon::new(icon, tooltip, menu) }
+/// Destructor: Takes ownership of the `TrayIcon` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTrayIcon_delete(object: &mut AzTrayIcon) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Application taskbar icon, 256x256x4 bytes in size
 pub use azul_core::window::TaskBarIcon as AzTaskBarIconTT;
 pub use AzTaskBarIconTT as AzTaskBarIcon;
@@ -302,9 +329,23 @@ pub use AzWindowPositionTT as AzWindowPosition;
 pub use azul_core::window::ImePosition as AzImePositionTT;
 pub use AzImePositionTT as AzImePosition;
 
-/// Current state of touch devices / touch inputs
+/// Kind of device that touches the screen / graphics tablet
+pub use azul_core::window::TouchPointKind as AzTouchPointKindTT;
+pub use AzTouchPointKindTT as AzTouchPointKind;
+
+/// Finger or pen that touches the window
+pub use azul_core::window::TouchPoint as AzTouchPointTT;
+pub use AzTouchPointTT as AzTouchPoint;
+
+/// Two-finger gesture, all values are relative to the positions of the fingers at the start of the gesture
+pub use azul_core::window::GestureState as AzGestureStateTT;
+pub use AzGestureStateTT as AzGestureState;
+
+/// Fingers and pens touching the window and the recognized gestures
 pub use azul_core::window::TouchState as AzTouchStateTT;
 pub use AzTouchStateTT as AzTouchState;
+/// Destructor: Takes ownership of the `TouchState` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTouchState_delete(object: &mut AzTouchState) {  unsafe { core::ptr::drop_in_place(object); } }
 
 /// Information about a single (or many) monitors, useful for dock widgets
 pub use azul_core::window::Monitor as AzMonitorTT;
@@ -326,6 +367,70 @@ pub use AzWindowStateTT as AzWindowState;
 /// Destructor: Takes ownership of the `WindowState` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzWindowState_delete(object: &mut AzWindowState) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Image-based mouse cursor, created either from a CSS `cursor: url(...)` property or set at runtime via `CallbackInfo::set_custom_cursor()`. The hotspot is the "click point" of the cursor in pixels, relative to the top left corner of the image.
+pub use azul_core::window::CustomCursor as AzCustomCursorTT;
+pub use AzCustomCursorTT as AzCustomCursor;
+/// Creates a new `CustomCursor` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `CustomCursor::new()` constructor.
+#[no_mangle] pub extern "C" fn AzCustomCursor_new(image: AzImageRef, hotspot_x: u32, hotspot_y: u32) -> AzCustomCursor { AzCustomCursor::new(image, hotspot_x, hotspot_y) }
+/// Destructor: Takes ownership of the `CustomCursor` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzCustomCursor_delete(object: &mut AzCustomCursor) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Desktop notification, shown with `CallbackInfo::show_notification()`. The callbacks are invoked on the window that showed the notification, as long as the notification is visible and the window is open.
+pub use azul_core::window::Notification as AzNotificationTT;
+pub use AzNotificationTT as AzNotification;
+/// Creates a new notification with the given title and body text
+#[no_mangle] pub extern "C" fn AzNotification_new(title: AzString, body: AzString) -> AzNotification { AzNotification::new(title, body) }
+/// Equivalent to the Rust `Notification::with_icon()` function.
+#[no_mangle] pub extern "C" fn AzNotification_withIcon(notification: AzNotification, icon: AzImageRef) -> AzNotification { notification.with_icon(icon) }
+/// Equivalent to the Rust `Notification::with_urgency()` function.
+#[no_mangle] pub extern "C" fn AzNotification_withUrgency(notification: AzNotification, urgency: AzNotificationUrgency) -> AzNotification { notification.with_urgency(urgency) }
+/// Adds a button to the notification
+#[no_mangle] pub extern "C" fn AzNotification_withAction(notification: AzNotification, label: AzString, data: AzRefAny, callback: AzCallbackType) -> AzNotification { notification.with_action(label, data, callback) }
+/// Sets the callback that is invoked when the notification is clicked
+#[no_mangle] pub extern "C" fn AzNotification_withClickCallback(notification: AzNotification, data: AzRefAny, callback: AzCallbackType) -> AzNotification { notification.with_click_callback(data, callback) }
+/// Destructor: Takes ownership of the `Notification` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzNotification_delete(object: &mut AzNotification) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Button of a desktop notification
+pub use azul_core::window::NotificationAction as AzNotificationActionTT;
+pub use AzNotificationActionTT as AzNotificationAction;
+/// Destructor: Takes ownership of the `NotificationAction` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzNotificationAction_delete(object: &mut AzNotificationAction) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Urgency of a desktop notification, critical notifications usually don't time out
+pub use azul_core::window::NotificationUrgency as AzNotificationUrgencyTT;
+pub use AzNotificationUrgencyTT as AzNotificationUrgency;
+
+/// ID of a registered keyboard shortcut, used to unregister the shortcut again
+pub use azul_core::window::ShortcutId as AzShortcutIdTT;
+pub use AzShortcutIdTT as AzShortcutId;
+
+/// Where a keyboard shortcut is active
+pub use azul_core::window::ShortcutScope as AzShortcutScopeTT;
+pub use AzShortcutScopeTT as AzShortcutScope;
+
+/// Keyboard shortcut: a sequence of key combinations (i.e. "Ctrl + K, Ctrl + S") that invokes a callback, registered with `App::register_shortcut` or `CallbackInfo::register_shortcut`
+pub use azul_core::window::Shortcut as AzShortcutTT;
+pub use AzShortcutTT as AzShortcut;
+/// Creates a new `Shortcut` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `Shortcut::new()` constructor.
+#[no_mangle] pub extern "C" fn AzShortcut_new(keys: AzVirtualKeyCodeComboVec, scope: AzShortcutScope, description: AzString, data: AzRefAny, callback: AzCallbackType) -> AzShortcut { AzShortcut::new(keys, scope, description, data, callback) }
+/// Returns the keys as a human-readable string, i.e. "Ctrl+K Ctrl+S"
+#[no_mangle] pub extern "C" fn AzShortcut_getKeyString(shortcut: &AzShortcut) -> AzString { shortcut.get_key_string() }
+/// Destructor: Takes ownership of the `Shortcut` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzShortcut_delete(object: &mut AzShortcut) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Registered keyboard shortcut, see `App::get_shortcuts`
+pub use azul_core::window::ShortcutBinding as AzShortcutBindingTT;
+pub use AzShortcutBindingTT as AzShortcutBinding;
+/// Destructor: Takes ownership of the `ShortcutBinding` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzShortcutBinding_delete(object: &mut AzShortcutBinding) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Error when registering a keyboard shortcut
+pub use azul_core::window::ShortcutError as AzShortcutErrorTT;
+pub use AzShortcutErrorTT as AzShortcutError;
+
 /// Re-export of rust-allocated (stack based) `LayoutCallback` struct
 pub use azul_impl::callbacks::LayoutCallback as AzLayoutCallbackTT;
 pub use AzLayoutCallbackTT as AzLayoutCallback;
@@ -368,6 +473,10 @@ pub use AzCallbackInfoTT as AzCallbackInfo;
 #[no_mangle] pub extern "C" fn AzCallbackInfo_getCurrentWindowState(callbackinfo: &AzCallbackInfo) -> AzWindowState { callbackinfo.get_current_window_state() }
 /// Returns a copy of the internal `KeyboardState`. Same as `self.get_window_state().keyboard_state`
 #[no_mangle] pub extern "C" fn AzCallbackInfo_getCurrentKeyboardState(callbackinfo: &AzCallbackInfo) -> AzKeyboardState { callbackinfo.get_current_keyboard_state() }
+/// Returns the fingers and pens that touch the window (`On::TouchStart`, `On::TouchMove`) and the ones that were just lifted (`On::TouchEnd`)
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getTouchState(callbackinfo: &AzCallbackInfo) -> AzTouchState { callbackinfo.get_touch_state() }
+/// Returns the current two-finger gesture (`On::Pinch`, `On::Rotate`, `On::TwoFingerPan`), `None` if less or more than two fingers touch the window
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getGesture(callbackinfo: &AzCallbackInfo) -> AzOptionGestureState { callbackinfo.get_gesture().into() }
 /// Returns a copy of the internal `MouseState`. Same as `self.get_window_state().mouse_state`
 #[no_mangle] pub extern "C" fn AzCallbackInfo_getCurrentMouseState(callbackinfo: &AzCallbackInfo) -> AzMouseState { callbackinfo.get_current_mouse_state() }
 /// Returns a copy of the current windows `WindowState`.
@@ -382,6 +491,8 @@ pub use AzCallbackInfoTT as AzCallbackInfo;
 #[no_mangle] pub extern "C" fn AzCallbackInfo_getGlContext(callbackinfo: &AzCallbackInfo) -> AzOptionGl { callbackinfo.get_gl_context() }
 /// Returns the x / y offset that this node has been scrolled to by the user or `None` if the node has not been scrolled.
 #[no_mangle] pub extern "C" fn AzCallbackInfo_getScrollPosition(callbackinfo: &AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionLogicalPosition { callbackinfo.get_scroll_position(node_id).into() }
+/// Returns the size of the scroll container, the size of its content and the scroll offset of the node, i.e. to check in an `On::Scrolled` callback whether the end of a lazily loaded list was reached
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getScrollFrame(callbackinfo: &AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionScrollPosition { callbackinfo.get_scroll_frame(node_id).into() }
 /// Returns the `dataset` property of the given Node or `None` if the node doesn't have a `dataset` property.
 #[no_mangle] pub extern "C" fn AzCallbackInfo_getDataset(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionRefAny { callbackinfo.get_dataset(node_id).into() }
 /// Given a dataset, returns the node ID of the "root" `RefAny`, i.e. the `RefAny` with the lowest `instance` count that is set as a `dataset` on any node.
@@ -416,12 +527,20 @@ pub use AzCallbackInfoTT as AzCallbackInfo;
 #[no_mangle] pub extern "C" fn AzCallbackInfo_getComputedCssProperty(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, property_type: AzCssPropertyType) -> AzOptionCssProperty { callbackinfo.get_computed_css_property(node_id, property_type).into() }
 /// Sets the new `WindowState` for the next frame. The window is updated after all callbacks are run.
 #[no_mangle] pub extern "C" fn AzCallbackInfo_setWindowState(callbackinfo: &mut AzCallbackInfo, new_state: AzWindowState) { callbackinfo.set_window_state(new_state); }
+/// Sets an image cursor for the entire window, overriding the CSS `cursor` property of all nodes until `reset_custom_cursor()` is called
+#[no_mangle] pub extern "C" fn AzCallbackInfo_setCustomCursor(callbackinfo: &mut AzCallbackInfo, image: AzImageRef, hotspot_x: u32, hotspot_y: u32) { callbackinfo.set_custom_cursor(image, hotspot_x, hotspot_y); }
+/// Removes the image cursor set by `set_custom_cursor()`
+#[no_mangle] pub extern "C" fn AzCallbackInfo_resetCustomCursor(callbackinfo: &mut AzCallbackInfo) { callbackinfo.reset_custom_cursor(); }
 /// Sets the new `FocusTarget` for the next frame. Note that this will emit a `On::FocusLost` and `On::FocusReceived` event, if the focused node has changed.
 #[no_mangle] pub extern "C" fn AzCallbackInfo_setFocus(callbackinfo: &mut AzCallbackInfo, target: AzFocusTarget) { callbackinfo.set_focus(target); }
 /// Sets a `CssProperty` on a given node to its new value. If this property change affects the layout, this will automatically trigger a relayout and redraw of the screen.
 #[no_mangle] pub extern "C" fn AzCallbackInfo_setCssProperty(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, new_property: AzCssProperty) { callbackinfo.set_css_property(node_id, new_property);  }
 /// Sets the scroll position of the node
 #[no_mangle] pub extern "C" fn AzCallbackInfo_setScrollPosition(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, scroll_position: AzLogicalPosition) { callbackinfo.set_scroll_position(node_id, scroll_position) }
+/// Scrolls the node to the given position: animated if the node has `scroll-behavior: smooth`, otherwise immediately
+#[no_mangle] pub extern "C" fn AzCallbackInfo_scrollTo(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, scroll_position: AzLogicalPosition) { callbackinfo.scroll_to(node_id, scroll_position) }
+/// Scrolls all scroll containers that the node is in (from the innermost to the outermost one) so that the node is visible and aligned according to `align`
+#[no_mangle] pub extern "C" fn AzCallbackInfo_scrollIntoView(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, align: AzScrollIntoViewAlign, smooth: bool) { callbackinfo.scroll_into_view(node_id, align, smooth) }
 /// If the node is a `Text` node, overwrites the `Text` content with the new string, without requiring the entire UI to be rebuilt.
 #[no_mangle] pub extern "C" fn AzCallbackInfo_setStringContents(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, string: AzString) { callbackinfo.set_string_contents(node_id, string) }
 /// Adds a new image identified by an ID to the image cache
@@ -438,8 +557,18 @@ pub use AzCallbackInfoTT as AzCallbackInfo;
 #[no_mangle] pub extern "C" fn AzCallbackInfo_updateImageMask(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, new_mask: AzImageMask) { callbackinfo.update_image_mask(node_id, new_mask) }
 /// Stops the propagation of the current callback event type to the parent. Events are bubbled from the inside out (children first, then parents), this event stops the propagation of the event to the parent.
 #[no_mangle] pub extern "C" fn AzCallbackInfo_stopPropagation(callbackinfo: &mut AzCallbackInfo) { callbackinfo.stop_propagation(); }
-/// Spawns a new window with the given `WindowCreateOptions`.
-#[no_mangle] pub extern "C" fn AzCallbackInfo_createWindow(callbackinfo: &mut AzCallbackInfo, new_window: AzWindowCreateOptions) { callbackinfo.create_window(new_window); }
+/// Spawns a new window with the given `WindowCreateOptions` once the callback returns, returns the ID of the new window.
+#[no_mangle] pub extern "C" fn AzCallbackInfo_createWindow(callbackinfo: &mut AzCallbackInfo, new_window: AzWindowCreateOptions) -> AzWindowId { callbackinfo.create_window(new_window) }
+/// Closes the window with the given ID (and its child windows) once the callback returns. To close the current window, set `WindowFlags::is_about_to_close` instead.
+#[no_mangle] pub extern "C" fn AzCallbackInfo_closeWindow(callbackinfo: &mut AzCallbackInfo, window_id: AzWindowId) { callbackinfo.close_window(window_id); }
+/// Sets the result of the current window: once the window is closed, the result is passed to the `WindowCreateOptions::result_callback` on the parent window
+#[no_mangle] pub extern "C" fn AzCallbackInfo_setWindowResult(callbackinfo: &mut AzCallbackInfo, result: AzRefAny) { callbackinfo.set_window_result(result); }
+/// Shows a desktop notification once the callback returns
+#[no_mangle] pub extern "C" fn AzCallbackInfo_showNotification(callbackinfo: &mut AzCallbackInfo, notification: AzNotification) { callbackinfo.show_notification(notification); }
+/// Registers an application-wide keyboard shortcut once the callback returns and returns its ID. If the shortcut conflicts with an already registered shortcut, it is not registered.
+#[no_mangle] pub extern "C" fn AzCallbackInfo_registerShortcut(callbackinfo: &mut AzCallbackInfo, shortcut: AzShortcut) -> AzShortcutId { callbackinfo.register_shortcut(shortcut) }
+/// Removes the keyboard shortcut once the callback returns
+#[no_mangle] pub extern "C" fn AzCallbackInfo_unregisterShortcut(callbackinfo: &mut AzCallbackInfo, shortcut_id: AzShortcutId) { callbackinfo.unregister_shortcut(shortcut_id); }
 /// Adds a new `Timer` to the runtime. See the documentation for `Timer` for more information.
 #[no_mangle] pub extern "C" fn AzCallbackInfo_startTimer(callbackinfo: &mut AzCallbackInfo, timer: AzTimer) -> AzTimerId { callbackinfo.start_timer(timer) }
 /// Starts an animation timer on a give NodeId - same as a `Timer`, but uses a pre-configured interpolation function to drive the animation timer
@@ -489,6 +618,14 @@ pub use AzPositionInfoTT as AzPositionInfo;
 pub use azul_impl::ui_solver::PositionInfoInner as AzPositionInfoInnerTT;
 pub use AzPositionInfoInnerTT as AzPositionInfoInner;
 
+/// Information about a scroll frame, given to the user by the framework
+pub use azul_impl::callbacks::ScrollPosition as AzScrollPositionTT;
+pub use AzScrollPositionTT as AzScrollPosition;
+
+/// How a node is aligned in its scroll containers by `CallbackInfo::scroll_into_view`
+pub use azul_core::scroll::ScrollIntoViewAlign as AzScrollIntoViewAlignTT;
+pub use AzScrollIntoViewAlignTT as AzScrollIntoViewAlign;
+
 /// Re-export of rust-allocated (stack based) `HidpiAdjustedBounds` struct
 pub use azul_impl::callbacks::HidpiAdjustedBounds as AzHidpiAdjustedBoundsTT;
 pub use AzHidpiAdjustedBoundsTT as AzHidpiAdjustedBounds;
@@ -1481,9 +1618,37 @@ pub use AzScrollbarStyleTT as AzScrollbarStyle;
 /// Destructor: Takes ownership of the `ScrollbarStyle` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzScrollbarStyle_delete(object: &mut AzScrollbarStyle) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Represents a `scroll-behavior` attribute: whether programmatic scrolling (such as `CallbackInfo::scroll_to`) is animated
+pub use azul_impl::css::StyleScrollBehavior as AzStyleScrollBehaviorTT;
+pub use AzStyleScrollBehaviorTT as AzStyleScrollBehavior;
+
+/// Represents a `scroll-snap-type` attribute, for example `y mandatory`
+pub use azul_impl::css::StyleScrollSnapType as AzStyleScrollSnapTypeTT;
+pub use AzStyleScrollSnapTypeTT as AzStyleScrollSnapType;
+
+/// Axis on which a scroll container snaps to its children
+pub use azul_impl::css::ScrollSnapAxis as AzScrollSnapAxisTT;
+pub use AzScrollSnapAxisTT as AzScrollSnapAxis;
+
+/// `mandatory` always snaps to the nearest snap position, `proximity` only snaps if the scroll position comes close to one
+pub use azul_impl::css::ScrollSnapStrictness as AzScrollSnapStrictnessTT;
+pub use AzScrollSnapStrictnessTT as AzScrollSnapStrictness;
+
+/// Represents a `scroll-snap-align` attribute: which edge of the node is aligned with the scroll container when snapping
+pub use azul_impl::css::StyleScrollSnapAlign as AzStyleScrollSnapAlignTT;
+pub use AzStyleScrollSnapAlignTT as AzStyleScrollSnapAlign;
+
 /// Re-export of rust-allocated (stack based) `StyleCursor` struct
 pub use azul_impl::css::StyleCursor as AzStyleCursorTT;
 pub use AzStyleCursorTT as AzStyleCursor;
+/// Destructor: Takes ownership of the `StyleCursor` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleCursor_delete(object: &mut AzStyleCursor) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Custom image cursor, parsed from `cursor: url("image-id") x y, fallback`. The image ID refers to an image in the `ImageCache`, the hotspot is given in pixels relative to the top left corner of the image.
+pub use azul_impl::css::StyleCursorImage as AzStyleCursorImageTT;
+pub use AzStyleCursorImageTT as AzStyleCursorImage;
+/// Destructor: Takes ownership of the `StyleCursorImage` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleCursorImage_delete(object: &mut AzStyleCursorImage) {  unsafe { core::ptr::drop_in_place(object); } }
 
 /// Re-export of rust-allocated (stack based) `StyleFontFamily` struct
 pub use azul_impl::css::StyleFontFamily as AzStyleFontFamilyTT;
@@ -1701,6 +1866,18 @@ pub use AzScrollbarStyleValueTT as AzScrollbarStyleValue;
 /// Destructor: Takes ownership of the `ScrollbarStyleValue` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzScrollbarStyleValue_delete(object: &mut AzScrollbarStyleValue) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Re-export of rust-allocated (stack based) `StyleScrollBehaviorValue` struct
+pub use azul_impl::css::StyleScrollBehaviorValue as AzStyleScrollBehaviorValueTT;
+pub use AzStyleScrollBehaviorValueTT as AzStyleScrollBehaviorValue;
+
+/// Re-export of rust-allocated (stack based) `StyleScrollSnapTypeValue` struct
+pub use azul_impl::css::StyleScrollSnapTypeValue as AzStyleScrollSnapTypeValueTT;
+pub use AzStyleScrollSnapTypeValueTT as AzStyleScrollSnapTypeValue;
+
+/// Re-export of rust-allocated (stack based) `StyleScrollSnapAlignValue` struct
+pub use azul_impl::css::StyleScrollSnapAlignValue as AzStyleScrollSnapAlignValueTT;
+pub use AzStyleScrollSnapAlignValueTT as AzStyleScrollSnapAlignValue;
+
 /// Re-export of rust-allocated (stack based) `StyleBackgroundContentVecValue` struct
 pub use azul_impl::css::StyleBackgroundContentVecValue as AzStyleBackgroundContentVecValueTT;
 pub use AzStyleBackgroundContentVecValueTT as AzStyleBackgroundContentVecValue;
@@ -1792,6 +1969,8 @@ pub use AzLayoutBorderTopWidthValueTT as AzLayoutBorderTopWidthValue;
 /// Re-export of rust-allocated (stack based) `StyleCursorValue` struct
 pub use azul_impl::css::StyleCursorValue as AzStyleCursorValueTT;
 pub use AzStyleCursorValueTT as AzStyleCursorValue;
+/// Destructor: Takes ownership of the `StyleCursorValue` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleCursorValue_delete(object: &mut AzStyleCursorValue) {  unsafe { core::ptr::drop_in_place(object); } }
 
 /// Re-export of rust-allocated (stack based) `StyleFontFamilyVecValue` struct
 pub use azul_impl::css::StyleFontFamilyVecValue as AzStyleFontFamilyVecValueTT;
@@ -4212,6 +4391,30 @@ pub use AzAccessibilityStateVecTT as AzAccessibilityStateVec;
 /// Destructor: Takes ownership of the `AccessibilityStateVec` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzAccessibilityStateVec_delete(object: &mut AzAccessibilityStateVec) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Wrapper over a Rust-allocated `Vec<VirtualKeyCodeCombo>`
+pub use azul_core::window::VirtualKeyCodeComboVec as AzVirtualKeyCodeComboVecTT;
+pub use AzVirtualKeyCodeComboVecTT as AzVirtualKeyCodeComboVec;
+/// Destructor: Takes ownership of the `VirtualKeyCodeComboVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzVirtualKeyCodeComboVec_delete(object: &mut AzVirtualKeyCodeComboVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<ShortcutBinding>`
+pub use azul_core::window::ShortcutBindingVec as AzShortcutBindingVecTT;
+pub use AzShortcutBindingVecTT as AzShortcutBindingVec;
+/// Destructor: Takes ownership of the `ShortcutBindingVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzShortcutBindingVec_delete(object: &mut AzShortcutBindingVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<TouchPoint>`
+pub use azul_core::window::TouchPointVec as AzTouchPointVecTT;
+pub use AzTouchPointVecTT as AzTouchPointVec;
+/// Destructor: Takes ownership of the `TouchPointVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTouchPointVec_delete(object: &mut AzTouchPointVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<NotificationAction>`
+pub use azul_core::window::NotificationActionVec as AzNotificationActionVecTT;
+pub use AzNotificationActionVecTT as AzNotificationActionVec;
+/// Destructor: Takes ownership of the `NotificationActionVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzNotificationActionVec_delete(object: &mut AzNotificationActionVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Wrapper over a Rust-allocated `Vec<MenuItem>`
 pub use azul_core::window::MenuItemVec as AzMenuItemVecTT;
 pub use AzMenuItemVecTT as AzMenuItemVec;
@@ -4234,6 +4437,12 @@ pub use AzTessellatedColoredSvgNodeVecTT as AzTessellatedColoredSvgNodeVec;
 /// Destructor: Takes ownership of the `TessellatedColoredSvgNodeVec` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzTessellatedColoredSvgNodeVec_delete(object: &mut AzTessellatedColoredSvgNodeVec) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Wrapper over a Rust-allocated `Vec<StyleCursor>`
+pub use azul_impl::css::StyleCursorVec as AzStyleCursorVecTT;
+pub use AzStyleCursorVecTT as AzStyleCursorVec;
+/// Destructor: Takes ownership of the `StyleCursorVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleCursorVec_delete(object: &mut AzStyleCursorVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Wrapper over a Rust-allocated `Vec<StyleFontFamily>`
 pub use azul_impl::css::StyleFontFamilyVec as AzStyleFontFamilyVecTT;
 pub use AzStyleFontFamilyVecTT as AzStyleFontFamilyVec;
@@ -4544,6 +4753,11 @@ pub use AzNodeDataVecTT as AzNodeDataVec;
 /// Destructor: Takes ownership of the `NodeDataVec` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzNodeDataVec_delete(object: &mut AzNodeDataVec) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Re-export of rust-allocated (stack based) `StyleCursorVecDestructor` struct
+pub use azul_impl::css::StyleCursorVecDestructor as AzStyleCursorVecDestructorTT;
+pub use AzStyleCursorVecDestructorTT as AzStyleCursorVecDestructor;
+
+pub type AzStyleCursorVecDestructorType = extern "C" fn(&mut AzStyleCursorVec);
 /// Re-export of rust-allocated (stack based) `StyleFontFamilyVecDestructor` struct
 pub use azul_impl::css::StyleFontFamilyVecDestructor as AzStyleFontFamilyVecDestructorTT;
 pub use AzStyleFontFamilyVecDestructorTT as AzStyleFontFamilyVecDestructor;
@@ -4614,6 +4828,26 @@ pub use azul_impl::dom::AccessibilityStateVecDestructor as AzAccessibilityStateV
 pub use AzAccessibilityStateVecDestructorTT as AzAccessibilityStateVecDestructor;
 
 pub type AzAccessibilityStateVecDestructorType = extern "C" fn(&mut AzAccessibilityStateVec);
+/// Re-export of rust-allocated (stack based) `VirtualKeyCodeComboVecDestructor` struct
+pub use azul_core::window::VirtualKeyCodeComboVecDestructor as AzVirtualKeyCodeComboVecDestructorTT;
+pub use AzVirtualKeyCodeComboVecDestructorTT as AzVirtualKeyCodeComboVecDestructor;
+
+pub type AzVirtualKeyCodeComboVecDestructorType = extern "C" fn(&mut AzVirtualKeyCodeComboVec);
+/// Re-export of rust-allocated (stack based) `ShortcutBindingVecDestructor` struct
+pub use azul_core::window::ShortcutBindingVecDestructor as AzShortcutBindingVecDestructorTT;
+pub use AzShortcutBindingVecDestructorTT as AzShortcutBindingVecDestructor;
+
+pub type AzShortcutBindingVecDestructorType = extern "C" fn(&mut AzShortcutBindingVec);
+/// Re-export of rust-allocated (stack based) `TouchPointVecDestructor` struct
+pub use azul_core::window::TouchPointVecDestructor as AzTouchPointVecDestructorTT;
+pub use AzTouchPointVecDestructorTT as AzTouchPointVecDestructor;
+
+pub type AzTouchPointVecDestructorType = extern "C" fn(&mut AzTouchPointVec);
+/// Re-export of rust-allocated (stack based) `NotificationActionVecDestructor` struct
+pub use azul_core::window::NotificationActionVecDestructor as AzNotificationActionVecDestructorTT;
+pub use AzNotificationActionVecDestructorTT as AzNotificationActionVecDestructor;
+
+pub type AzNotificationActionVecDestructorType = extern "C" fn(&mut AzNotificationActionVec);
 /// Re-export of rust-allocated (stack based) `MenuItemVecDestructor` struct
 pub use azul_core::window::MenuItemVecDestructor as AzMenuItemVecDestructorTT;
 pub use AzMenuItemVecDestructorTT as AzMenuItemVecDestructor;
@@ -5089,6 +5323,22 @@ pub use AzOptionImageRefTT as AzOptionImageRef;
 /// Destructor: Takes ownership of the `OptionImageRef` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzOptionImageRef_delete(object: &mut AzOptionImageRef) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Re-export of rust-allocated (stack based) `OptionWindowId` struct
+pub use azul_core::window::OptionWindowId as AzOptionWindowIdTT;
+pub use AzOptionWindowIdTT as AzOptionWindowId;
+
+/// Re-export of rust-allocated (stack based) `OptionWindowResultCallback` struct
+pub use azul_core::window::OptionWindowResultCallback as AzOptionWindowResultCallbackTT;
+pub use AzOptionWindowResultCallbackTT as AzOptionWindowResultCallback;
+/// Destructor: Takes ownership of the `OptionWindowResultCallback` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionWindowResultCallback_delete(object: &mut AzOptionWindowResultCallback) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionCustomCursor` struct
+pub use azul_core::window::OptionCustomCursor as AzOptionCustomCursorTT;
+pub use AzOptionCustomCursorTT as AzOptionCustomCursor;
+/// Destructor: Takes ownership of the `OptionCustomCursor` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionCustomCursor_delete(object: &mut AzOptionCustomCursor) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Re-export of rust-allocated (stack based) `OptionFontRef` struct
 pub use azul_impl::css::OptionFontRef as AzOptionFontRefTT;
 pub use AzOptionFontRefTT as AzOptionFontRef;
@@ -5179,6 +5429,10 @@ pub use AzOptionRefAnyTT as AzOptionRefAny;
 /// Destructor: Takes ownership of the `OptionRefAny` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzOptionRefAny_delete(object: &mut AzOptionRefAny) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Re-export of rust-allocated (stack based) `OptionScrollPosition` struct
+pub use azul_impl::callbacks::OptionScrollPosition as AzOptionScrollPositionTT;
+pub use AzOptionScrollPositionTT as AzOptionScrollPosition;
+
 /// Re-export of rust-allocated (stack based) `OptionInlineText` struct
 pub use azul_impl::callbacks::OptionInlineText as AzOptionInlineTextTT;
 pub use AzOptionInlineTextTT as AzOptionInlineText;
@@ -5235,6 +5489,10 @@ pub use AzOptionTaskBarIconTT as AzOptionTaskBarIcon;
 pub use azul_core::window::OptionHwndHandle as AzOptionHwndHandleTT;
 pub use AzOptionHwndHandleTT as AzOptionHwndHandle;
 
+/// Re-export of rust-allocated (stack based) `OptionGestureState` struct
+pub use azul_core::window::OptionGestureState as AzOptionGestureStateTT;
+pub use AzOptionGestureStateTT as AzOptionGestureState;
+
 /// Re-export of rust-allocated (stack based) `OptionLogicalPosition` struct
 pub use azul_core::window::OptionLogicalPosition as AzOptionLogicalPositionTT;
 pub use AzOptionLogicalPositionTT as AzOptionLogicalPosition;
@@ -5243,6 +5501,12 @@ pub use AzOptionLogicalPositionTT as AzOptionLogicalPosition;
 pub use azul_core::window::OptionPhysicalPositionI32 as AzOptionPhysicalPositionI32TT;
 pub use AzOptionPhysicalPositionI32TT as AzOptionPhysicalPositionI32;
 
+/// Re-export of rust-allocated (stack based) `OptionTrayIcon` struct
+pub use azul_core::window::OptionTrayIcon as AzOptionTrayIconTT;
+pub use AzOptionTrayIconTT as AzOptionTrayIcon;
+/// Destructor: Takes ownership of the `OptionTrayIcon` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionTrayIcon_delete(object: &mut AzOptionTrayIcon) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Re-export of rust-allocated (stack based) `OptionWindowIcon` struct
 pub use azul_core::window::OptionWindowIcon as AzOptionWindowIconTT;
 pub use AzOptionWindowIconTT as AzOptionWindowIcon;
@@ -5357,6 +5621,10 @@ pub use AzResultSvgXmlNodeSvgParseErrorTT as AzResultSvgXmlNodeSvgParseError;
 /// Destructor: Takes ownership of the `ResultSvgXmlNodeSvgParseError` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzResultSvgXmlNodeSvgParseError_delete(object: &mut AzResultSvgXmlNodeSvgParseError) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Re-export of rust-allocated (stack based) `ResultShortcutIdShortcutError` struct
+pub use azul_core::window::ResultShortcutIdShortcutError as AzResultShortcutIdShortcutErrorTT;
+pub use AzResultShortcutIdShortcutErrorTT as AzResultShortcutIdShortcutError;
+
 /// Re-export of rust-allocated (stack based) `ResultSvgSvgParseError` struct
 pub use azul_impl::svg::ResultSvgSvgParseError as AzResultSvgSvgParseErrorTT;
 pub use AzResultSvgSvgParseErrorTT as AzResultSvgSvgParseError;
@@ -5572,6 +5840,12 @@ mod test_sizes {
         Default,
     }
 
+    /// Unique ID of a window, used to close a window or to open a child window
+    #[repr(C)]
+    pub struct AzWindowId {
+        pub id: usize,
+    }
+
     /// Whether the renderer has VSync enabled
     #[repr(C)]
     #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
@@ -6018,10 +6292,26 @@ mod test_sizes {
         LightMode,
     }
 
-    /// Current state of touch devices / touch inputs
+    /// Kind of device that touches the screen / graphics tablet
     #[repr(C)]
-    pub struct AzTouchState {
-        pub unused: u8,
+    pub enum AzTouchPointKind {
+        Finger,
+        Pen,
+        Eraser,
+    }
+
+    /// Urgency of a desktop notification, critical notifications usually don't time out
+    #[repr(C)]
+    pub enum AzNotificationUrgency {
+        Low,
+        Normal,
+        Critical,
+    }
+
+    /// ID of a registered keyboard shortcut, used to unregister the shortcut again
+    #[repr(C)]
+    pub struct AzShortcutId {
+        pub id: usize,
     }
 
     /// C-ABI stable wrapper over a `MarshaledLayoutCallbackInner`
@@ -6089,6 +6379,15 @@ mod test_sizes {
         pub static_y_offset: f32,
     }
 
+    /// How a node is aligned in its scroll containers by `CallbackInfo::scroll_into_view`
+    #[repr(C)]
+    pub enum AzScrollIntoViewAlign {
+        Start,
+        Center,
+        End,
+        Nearest,
+    }
+
     /// How should an animation repeat (loop, ping-pong, etc.)
     #[repr(C)]
     #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
@@ -6184,6 +6483,23 @@ mod test_sizes {
         HoveredFileCancelled,
         FocusReceived,
         FocusLost,
+        DragStart,
+        DragEnter,
+        DragOver,
+        DragLeave,
+        Drop,
+        TextCompositionStart,
+        TextCompositionUpdate,
+        TextCompositionEnd,
+        TouchStart,
+        TouchMove,
+        TouchEnd,
+        TouchCancel,
+        Pinch,
+        Rotate,
+        TwoFingerPan,
+        LongPress,
+        Scrolled,
     }
 
     /// Re-export of rust-allocated (stack based) `HoverEventFilter` struct
@@ -6213,6 +6529,18 @@ mod test_sizes {
         TouchMove,
         TouchEnd,
         TouchCancel,
+        Pinch,
+        Rotate,
+        TwoFingerPan,
+        LongPress,
+        DragStart,
+        DragEnter,
+        DragOver,
+        DragLeave,
+        Drop,
+        TextCompositionStart,
+        TextCompositionUpdate,
+        TextCompositionEnd,
     }
 
     /// Re-export of rust-allocated (stack based) `FocusEventFilter` struct
@@ -6237,6 +6565,9 @@ mod test_sizes {
         VirtualKeyUp,
         FocusReceived,
         FocusLost,
+        TextCompositionStart,
+        TextCompositionUpdate,
+        TextCompositionEnd,
     }
 
     /// Re-export of rust-allocated (stack based) `WindowEventFilter` struct
@@ -6268,10 +6599,26 @@ mod test_sizes {
         TouchMove,
         TouchEnd,
         TouchCancel,
+        Pinch,
+        Rotate,
+        TwoFingerPan,
+        LongPress,
         FocusReceived,
         FocusLost,
         CloseRequested,
         ThemeChanged,
+        WindowFocusReceived,
+        WindowFocusLost,
+        ClipboardChanged,
+        DragStart,
+        DragEnter,
+        DragOver,
+        DragLeave,
+        Drop,
+        DragEnd,
+        TextCompositionStart,
+        TextCompositionUpdate,
+        TextCompositionEnd,
     }
 
     /// Re-export of rust-allocated (stack based) `ComponentEventFilter` struct
@@ -6282,6 +6629,7 @@ mod test_sizes {
         NodeResized,
         DefaultAction,
         Selected,
+        Scrolled,
     }
 
     /// Re-export of rust-allocated (stack based) `ApplicationEventFilter` struct
@@ -6506,6 +6854,9 @@ mod test_sizes {
         BoxShadowTop,
         BoxShadowBottom,
         ScrollbarStyle,
+        ScrollBehavior,
+        ScrollSnapType,
+        ScrollSnapAlign,
         Opacity,
         Transform,
         TransformOrigin,
@@ -6737,39 +7088,36 @@ mod test_sizes {
         Outset,
     }
 
-    /// Re-export of rust-allocated (stack based) `StyleCursor` struct
+    /// Represents a `scroll-behavior` attribute: whether programmatic scrolling (such as `CallbackInfo::scroll_to`) is animated
     #[repr(C)]
-    pub enum AzStyleCursor {
-        Alias,
-        AllScroll,
-        Cell,
-        ColResize,
-        ContextMenu,
-        Copy,
-        Crosshair,
-        Default,
-        EResize,
-        EwResize,
-        Grab,
-        Grabbing,
-        Help,
-        Move,
-        NResize,
-        NsResize,
-        NeswResize,
-        NwseResize,
-        Pointer,
-        Progress,
-        RowResize,
-        SResize,
-        SeResize,
-        Text,
-        Unset,
-        VerticalText,
-        WResize,
-        Wait,
-        ZoomIn,
-        ZoomOut,
+    pub enum AzStyleScrollBehavior {
+        Auto,
+        Smooth,
+    }
+
+    /// Axis on which a scroll container snaps to its children
+    #[repr(C)]
+    pub enum AzScrollSnapAxis {
+        None,
+        X,
+        Y,
+        Both,
+    }
+
+    /// `mandatory` always snaps to the nearest snap position, `proximity` only snaps if the scroll position comes close to one
+    #[repr(C)]
+    pub enum AzScrollSnapStrictness {
+        Proximity,
+        Mandatory,
+    }
+
+    /// Represents a `scroll-snap-align` attribute: which edge of the node is aligned with the scroll container when snapping
+    #[repr(C)]
+    pub enum AzStyleScrollSnapAlign {
+        None,
+        Start,
+        Center,
+        End,
     }
 
     /// Re-export of rust-allocated (stack based) `StyleBackfaceVisibility` struct
@@ -7712,6 +8060,17 @@ mod test_sizes {
         pub cb: AzThreadSenderDestructorFnType,
     }
 
+    /// Re-export of rust-allocated (stack based) `StyleCursorVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzStyleCursorVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzStyleCursorVecDestructorType),
+    }
+
+    /// `AzStyleCursorVecDestructorType` struct
+    pub type AzStyleCursorVecDestructorType = extern "C" fn(&mut AzStyleCursorVec);
+
     /// Re-export of rust-allocated (stack based) `StyleFontFamilyVecDestructor` struct
     #[repr(C, u8)]
     pub enum AzStyleFontFamilyVecDestructor {
@@ -7866,6 +8225,50 @@ mod test_sizes {
     /// `AzAccessibilityStateVecDestructorType` struct
     pub type AzAccessibilityStateVecDestructorType = extern "C" fn(&mut AzAccessibilityStateVec);
 
+    /// Re-export of rust-allocated (stack based) `VirtualKeyCodeComboVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzVirtualKeyCodeComboVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzVirtualKeyCodeComboVecDestructorType),
+    }
+
+    /// `AzVirtualKeyCodeComboVecDestructorType` struct
+    pub type AzVirtualKeyCodeComboVecDestructorType = extern "C" fn(&mut AzVirtualKeyCodeComboVec);
+
+    /// Re-export of rust-allocated (stack based) `ShortcutBindingVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzShortcutBindingVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzShortcutBindingVecDestructorType),
+    }
+
+    /// `AzShortcutBindingVecDestructorType` struct
+    pub type AzShortcutBindingVecDestructorType = extern "C" fn(&mut AzShortcutBindingVec);
+
+    /// Re-export of rust-allocated (stack based) `TouchPointVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzTouchPointVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzTouchPointVecDestructorType),
+    }
+
+    /// `AzTouchPointVecDestructorType` struct
+    pub type AzTouchPointVecDestructorType = extern "C" fn(&mut AzTouchPointVec);
+
+    /// Re-export of rust-allocated (stack based) `NotificationActionVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzNotificationActionVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzNotificationActionVecDestructorType),
+    }
+
+    /// `AzNotificationActionVecDestructorType` struct
+    pub type AzNotificationActionVecDestructorType = extern "C" fn(&mut AzNotificationActionVec);
+
     /// Re-export of rust-allocated (stack based) `MenuItemVecDestructor` struct
     #[repr(C, u8)]
     pub enum AzMenuItemVecDestructor {
@@ -8588,7 +8991,10 @@ mod test_sizes {
         pub has_focus: bool,
         pub has_extended_window_frame: bool,
         pub has_blur_behind_window: bool,
+        pub is_click_through: bool,
         pub smooth_scroll_enabled: bool,
+        pub overscroll_bounce_enabled: bool,
+        pub scrollbar_auto_hide_enabled: bool,
         pub autotab_enabled: bool,
     }
 
@@ -8617,6 +9023,29 @@ mod test_sizes {
         Initialized(AzLogicalPosition),
     }
 
+    /// Finger or pen that touches the window
+    #[repr(C)]
+    pub struct AzTouchPoint {
+        pub id: u64,
+        pub kind: AzTouchPointKind,
+        pub position: AzLogicalPosition,
+        pub pressure: f32,
+        pub tilt_x: f32,
+        pub tilt_y: f32,
+    }
+
+    /// Two-finger gesture, all values are relative to the positions of the fingers at the start of the gesture
+    #[repr(C)]
+    pub struct AzGestureState {
+        pub center: AzLogicalPosition,
+        pub scale: f32,
+        pub rotation: f32,
+        pub translation: AzLogicalPosition,
+        pub is_pinch: bool,
+        pub is_rotate: bool,
+        pub is_pan: bool,
+    }
+
     /// Describes a rendering configuration for a monitor
     #[repr(C)]
     #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
@@ -8626,6 +9055,30 @@ mod test_sizes {
         pub refresh_rate: u16,
     }
 
+    /// Image-based mouse cursor, created either from a CSS `cursor: url(...)` property or set at runtime via `CallbackInfo::set_custom_cursor()`. The hotspot is the "click point" of the cursor in pixels, relative to the top left corner of the image.
+    #[repr(C)]
+    pub struct AzCustomCursor {
+        pub image: AzImageRef,
+        pub hotspot_x: u32,
+        pub hotspot_y: u32,
+    }
+
+    /// Where a keyboard shortcut is active
+    #[repr(C, u8)]
+    pub enum AzShortcutScope {
+        Window(AzWindowId),
+        App,
+        Global,
+    }
+
+    /// Error when registering a keyboard shortcut
+    #[repr(C, u8)]
+    pub enum AzShortcutError {
+        NoKeys,
+        GlobalSequence,
+        Conflict(AzShortcutId),
+    }
+
     /// Combination of node ID + DOM ID, both together can identify a node
     #[repr(C)]
     pub struct AzDomNodeId {
@@ -8642,6 +9095,14 @@ mod test_sizes {
         Relative(AzPositionInfoInner),
     }
 
+    /// Information about a scroll frame, given to the user by the framework
+    #[repr(C)]
+    pub struct AzScrollPosition {
+        pub parent_rect: AzLogicalRect,
+        pub children_rect: AzLogicalRect,
+        pub scroll_offset: AzLogicalPosition,
+    }
+
     /// Re-export of rust-allocated (stack based) `HidpiAdjustedBounds` struct
     #[repr(C)]
     #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
@@ -9107,6 +9568,13 @@ mod test_sizes {
         pub inner: AzPixelValue,
     }
 
+    /// Represents a `scroll-snap-type` attribute, for example `y mandatory`
+    #[repr(C)]
+    pub struct AzStyleScrollSnapType {
+        pub axis: AzScrollSnapAxis,
+        pub strictness: AzScrollSnapStrictness,
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleFontSize` struct
     #[repr(C)]
     pub struct AzStyleFontSize {
@@ -9551,6 +10019,36 @@ mod test_sizes {
         Exact(AzLayoutOverflow),
     }
 
+    /// Re-export of rust-allocated (stack based) `StyleScrollBehaviorValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleScrollBehaviorValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleScrollBehavior),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleScrollSnapTypeValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleScrollSnapTypeValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleScrollSnapType),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleScrollSnapAlignValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleScrollSnapAlignValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleScrollSnapAlign),
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleBorderBottomColorValue` struct
     #[repr(C, u8)]
     pub enum AzStyleBorderBottomColorValue {
@@ -9711,16 +10209,6 @@ mod test_sizes {
         Exact(AzLayoutBorderTopWidth),
     }
 
-    /// Re-export of rust-allocated (stack based) `StyleCursorValue` struct
-    #[repr(C, u8)]
-    pub enum AzStyleCursorValue {
-        Auto,
-        None,
-        Inherit,
-        Initial,
-        Exact(AzStyleCursor),
-    }
-
     /// Re-export of rust-allocated (stack based) `StyleFontSizeValue` struct
     #[repr(C, u8)]
     pub enum AzStyleFontSizeValue {
@@ -10242,6 +10730,15 @@ mod test_sizes {
         pub destructor: AzAccessibilityStateVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<TouchPoint>`
+    #[repr(C)]
+    pub struct AzTouchPointVec {
+        pub(crate) ptr: *const AzTouchPoint,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzTouchPointVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `Vec<MenuItem>`
     #[repr(C)]
     pub struct AzMenuItemVec {
@@ -10251,6 +10748,15 @@ mod test_sizes {
         pub destructor: AzMenuItemVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<StyleCursor>`
+    #[repr(C)]
+    pub struct AzStyleCursorVec {
+        pub(crate) ptr: *const AzStyleCursor,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzStyleCursorVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `Vec<XmlNode>`
     #[repr(C)]
     pub struct AzXmlNodeVec {
@@ -10693,6 +11199,20 @@ mod test_sizes {
         Some(AzImageRef),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionWindowId` struct
+    #[repr(C, u8)]
+    pub enum AzOptionWindowId {
+        None,
+        Some(AzWindowId),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionCustomCursor` struct
+    #[repr(C, u8)]
+    pub enum AzOptionCustomCursor {
+        None,
+        Some(AzCustomCursor),
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionFontRef` struct
     #[repr(C, u8)]
     pub enum AzOptionFontRef {
@@ -10763,6 +11283,13 @@ mod test_sizes {
         Some(AzRefAny),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionScrollPosition` struct
+    #[repr(C, u8)]
+    pub enum AzOptionScrollPosition {
+        None,
+        Some(AzScrollPosition),
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionLayoutPoint` struct
     #[repr(C, u8)]
     pub enum AzOptionLayoutPoint {
@@ -10812,6 +11339,13 @@ mod test_sizes {
         Some(AzSvgDashPattern),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionGestureState` struct
+    #[repr(C, u8)]
+    pub enum AzOptionGestureState {
+        None,
+        Some(AzGestureState),
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionLogicalPosition` struct
     #[repr(C, u8)]
     pub enum AzOptionLogicalPosition {
@@ -10898,6 +11432,13 @@ mod test_sizes {
         Err(AzEncodeImageError),
     }
 
+    /// Re-export of rust-allocated (stack based) `ResultShortcutIdShortcutError` struct
+    #[repr(C, u8)]
+    pub enum AzResultShortcutIdShortcutError {
+        Ok(AzShortcutId),
+        Err(AzShortcutError),
+    }
+
     /// Re-export of rust-allocated (stack based) `NonXmlCharError` struct
     #[repr(C)]
     pub struct AzNonXmlCharError {
@@ -10943,9 +11484,17 @@ mod test_sizes {
         pub enable_visual_panic_hook: bool,
         pub enable_logging_on_panic: bool,
         pub enable_tab_navigation: bool,
+        pub enable_inspector: bool,
         pub system_callbacks: AzSystemCallbacks,
     }
 
+    /// Callback that is invoked on the parent window with the result of a child window after the child window has been closed
+    #[repr(C)]
+    pub struct AzWindowResultCallback {
+        pub data: AzRefAny,
+        pub callback: AzWriteBackCallback,
+    }
+
     /// Small (16x16x4) window icon, usually shown in the window titlebar
     #[repr(C)]
     pub struct AzSmallWindowIconBytes {
@@ -11006,6 +11555,16 @@ mod test_sizes {
         pub scroll_y: AzOptionF32,
     }
 
+    /// Fingers and pens touching the window and the recognized gestures
+    #[repr(C)]
+    pub struct AzTouchState {
+        pub touch_points: AzTouchPointVec,
+        pub ended_touch_points: AzTouchPointVec,
+        pub cancelled_touch_points: AzTouchPointVec,
+        pub gesture: AzOptionGestureState,
+        pub long_press: AzOptionLogicalPosition,
+    }
+
     /// C-ABI stable wrapper over a `MarshaledLayoutCallback`
     #[repr(C)]
     pub struct AzMarshaledLayoutCallback {
@@ -11453,6 +12012,15 @@ mod test_sizes {
         pub destructor: AzOutputConnectionVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<VirtualKeyCodeCombo>`
+    #[repr(C)]
+    pub struct AzVirtualKeyCodeComboVec {
+        pub(crate) ptr: *const AzVirtualKeyCodeCombo,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzVirtualKeyCodeComboVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `Vec<TessellatedSvgNode>`
     #[repr(C)]
     pub struct AzTessellatedSvgNodeVec {
@@ -11537,6 +12105,13 @@ mod test_sizes {
         Some(AzVirtualKeyCodeCombo),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionWindowResultCallback` struct
+    #[repr(C, u8)]
+    pub enum AzOptionWindowResultCallback {
+        None,
+        Some(AzWindowResultCallback),
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionMouseState` struct
     #[repr(C, u8)]
     pub enum AzOptionMouseState {
@@ -11644,6 +12219,14 @@ mod test_sizes {
         pub pos: AzSvgParseErrorPosition,
     }
 
+    /// Icon in the system tray / notification area of the desktop, with a context menu
+    #[repr(C)]
+    pub struct AzTrayIcon {
+        pub icon: AzWindowIcon,
+        pub tooltip: AzString,
+        pub menu: AzMenu,
+    }
+
     /// Window configuration specific to Win32
     #[repr(C)]
     pub struct AzWindowsWindowOptions {
@@ -11723,6 +12306,29 @@ mod test_sizes {
         pub is_primary_monitor: bool,
     }
 
+    /// Button of a desktop notification
+    #[repr(C)]
+    pub struct AzNotificationAction {
+        pub label: AzString,
+        pub callback: AzMenuCallback,
+    }
+
+    /// Keyboard shortcut: a sequence of key combinations (i.e. "Ctrl + K, Ctrl + S") that invokes a callback, registered with `App::register_shortcut` or `CallbackInfo::register_shortcut`
+    #[repr(C)]
+    pub struct AzShortcut {
+        pub keys: AzVirtualKeyCodeComboVec,
+        pub scope: AzShortcutScope,
+        pub description: AzString,
+        pub callback: AzMenuCallback,
+    }
+
+    /// Registered keyboard shortcut, see `App::get_shortcuts`
+    #[repr(C)]
+    pub struct AzShortcutBinding {
+        pub id: AzShortcutId,
+        pub shortcut: AzShortcut,
+    }
+
     /// Re-export of rust-allocated (stack based) `LayoutCallback` struct
     #[repr(C, u8)]
     pub enum AzLayoutCallback {
@@ -11829,6 +12435,15 @@ mod test_sizes {
         pub vertical: AzScrollbarInfo,
     }
 
+    /// Custom image cursor, parsed from `cursor: url("image-id") x y, fallback`. The image ID refers to an image in the `ImageCache`, the hotspot is given in pixels relative to the top left corner of the image.
+    #[repr(C)]
+    pub struct AzStyleCursorImage {
+        pub image: AzString,
+        pub hotspot_x: u32,
+        pub hotspot_y: u32,
+        pub fallback: AzStyleCursorVec,
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleFontFamily` struct
     #[repr(C, u8)]
     pub enum AzStyleFontFamily {
@@ -12097,6 +12712,24 @@ mod test_sizes {
         pub value: AzFmtValue,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<ShortcutBinding>`
+    #[repr(C)]
+    pub struct AzShortcutBindingVec {
+        pub(crate) ptr: *const AzShortcutBinding,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzShortcutBindingVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<NotificationAction>`
+    #[repr(C)]
+    pub struct AzNotificationActionVec {
+        pub(crate) ptr: *const AzNotificationAction,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzNotificationActionVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `Vec<StyleFontFamily>`
     #[repr(C)]
     pub struct AzStyleFontFamilyVec {
@@ -12233,6 +12866,13 @@ mod test_sizes {
         Some(AzWaylandTheme),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionTrayIcon` struct
+    #[repr(C, u8)]
+    pub enum AzOptionTrayIcon {
+        None,
+        Some(AzTrayIcon),
+    }
+
     /// Re-export of rust-allocated (stack based) `ResultRawImageDecodeImageError` struct
     #[repr(C, u8)]
     pub enum AzResultRawImageDecodeImageError {
@@ -12273,6 +12913,18 @@ mod test_sizes {
         pub wayland_theme: AzOptionWaylandTheme,
         pub request_user_attention: bool,
         pub window_icon: AzOptionWindowIcon,
+        pub tray_icon: AzOptionTrayIcon,
+    }
+
+    /// Desktop notification, shown with `CallbackInfo::show_notification()`. The callbacks are invoked on the window that showed the notification, as long as the notification is visible and the window is open.
+    #[repr(C)]
+    pub struct AzNotification {
+        pub title: AzString,
+        pub body: AzString,
+        pub icon: AzOptionImageRef,
+        pub urgency: AzNotificationUrgency,
+        pub actions: AzNotificationActionVec,
+        pub on_click: AzOptionMenuCallback,
     }
 
     /// Re-export of rust-allocated (stack based) `InlineLine` struct
@@ -12296,6 +12948,42 @@ mod test_sizes {
         pub selectors: AzCssPathSelectorVec,
     }
 
+    /// Re-export of rust-allocated (stack based) `StyleCursor` struct
+    #[repr(C, u8)]
+    pub enum AzStyleCursor {
+        Alias,
+        AllScroll,
+        Cell,
+        ColResize,
+        ContextMenu,
+        Copy,
+        Crosshair,
+        Default,
+        EResize,
+        EwResize,
+        Grab,
+        Grabbing,
+        Help,
+        Move,
+        NResize,
+        NsResize,
+        NeswResize,
+        NwseResize,
+        Pointer,
+        Progress,
+        RowResize,
+        SResize,
+        SeResize,
+        Text,
+        Unset,
+        VerticalText,
+        WResize,
+        Wait,
+        ZoomIn,
+        ZoomOut,
+        Image(AzStyleCursorImage),
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleBackgroundContentVecValue` struct
     #[repr(C, u8)]
     pub enum AzStyleBackgroundContentVecValue {
@@ -12306,6 +12994,16 @@ mod test_sizes {
         Exact(AzStyleBackgroundContentVec),
     }
 
+    /// Re-export of rust-allocated (stack based) `StyleCursorValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleCursorValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleCursor),
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleFontFamilyVecValue` struct
     #[repr(C, u8)]
     pub enum AzStyleFontFamilyVecValue {
@@ -12384,6 +13082,9 @@ mod test_sizes {
         BoxShadowTop(AzStyleBoxShadowValue),
         BoxShadowBottom(AzStyleBoxShadowValue),
         ScrollbarStyle(AzScrollbarStyleValue),
+        ScrollBehavior(AzStyleScrollBehaviorValue),
+        ScrollSnapType(AzStyleScrollSnapTypeValue),
+        ScrollSnapAlign(AzStyleScrollSnapAlignValue),
         Opacity(AzStyleOpacityValue),
         Transform(AzStyleTransformVecValue),
         TransformOrigin(AzStyleTransformOriginValue),
@@ -12626,6 +13327,7 @@ mod test_sizes {
         pub platform_specific_options: AzPlatformSpecificOptions,
         pub renderer_options: AzRendererOptions,
         pub background_color: AzColorU,
+        pub custom_cursor: AzOptionCustomCursor,
         pub layout_callback: AzLayoutCallback,
         pub close_callback: AzOptionCallback,
     }
@@ -12648,9 +13350,14 @@ mod test_sizes {
         pub threads_removed: *mut c_void,
         pub current_window_handle: *const AzRawWindowHandle,
         pub new_windows: *mut c_void,
+        pub notifications: *mut c_void,
+        pub shortcuts_changed: *mut c_void,
+        pub windows_closed: *mut c_void,
+        pub window_result: *mut c_void,
         pub system_callbacks: *const AzSystemCallbacks,
         pub stop_propagation: *mut bool,
         pub focus_target: *mut c_void,
+        pub drag_data: *mut c_void,
         pub words_changed_in_callbacks: *mut c_void,
         pub images_changed_in_callbacks: *mut c_void,
         pub image_masks_changed_in_callbacks: *mut c_void,
@@ -12796,6 +13503,11 @@ mod test_sizes {
         pub theme: AzOptionWindowTheme,
         pub create_callback: AzOptionCallback,
         pub hot_reload: bool,
+        pub id: AzWindowId,
+        pub parent: AzOptionWindowId,
+        pub modal: bool,
+        pub result_callback: AzOptionWindowResultCallback,
+        pub persistence_key: AzOptionString,
     }
 
     /// Defines the keyboard input focus target
@@ -13093,6 +13805,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::app::AzAppPtr>(), "AzApp"), (Layout::new::<AzApp>(), "AzApp"));
         assert_eq!((Layout::new::<azul_impl::resources::AppLogLevel>(), "AzAppLogLevel"), (Layout::new::<AzAppLogLevel>(), "AzAppLogLevel"));
         assert_eq!((Layout::new::<azul_impl::resources::LayoutSolverVersion>(), "AzLayoutSolver"), (Layout::new::<AzLayoutSolver>(), "AzLayoutSolver"));
+        assert_eq!((Layout::new::<azul_core::window::WindowId>(), "AzWindowId"), (Layout::new::<AzWindowId>(), "AzWindowId"));
         assert_eq!((Layout::new::<azul_core::window::Vsync>(), "AzVsync"), (Layout::new::<AzVsync>(), "AzVsync"));
         assert_eq!((Layout::new::<azul_core::window::Srgb>(), "AzSrgb"), (Layout::new::<AzSrgb>(), "AzSrgb"));
         assert_eq!((Layout::new::<azul_core::window::HwAcceleration>(), "AzHwAcceleration"), (Layout::new::<AzHwAcceleration>(), "AzHwAcceleration"));
@@ -13121,7 +13834,9 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_core::window::WasmWindowOptions>(), "AzWasmWindowOptions"), (Layout::new::<AzWasmWindowOptions>(), "AzWasmWindowOptions"));
         assert_eq!((Layout::new::<azul_core::window::FullScreenMode>(), "AzFullScreenMode"), (Layout::new::<AzFullScreenMode>(), "AzFullScreenMode"));
         assert_eq!((Layout::new::<azul_core::window::WindowTheme>(), "AzWindowTheme"), (Layout::new::<AzWindowTheme>(), "AzWindowTheme"));
-        assert_eq!((Layout::new::<azul_core::window::TouchState>(), "AzTouchState"), (Layout::new::<AzTouchState>(), "AzTouchState"));
+        assert_eq!((Layout::new::<azul_core::window::TouchPointKind>(), "AzTouchPointKind"), (Layout::new::<AzTouchPointKind>(), "AzTouchPointKind"));
+        assert_eq!((Layout::new::<azul_core::window::NotificationUrgency>(), "AzNotificationUrgency"), (Layout::new::<AzNotificationUrgency>(), "AzNotificationUrgency"));
+        assert_eq!((Layout::new::<azul_core::window::ShortcutId>(), "AzShortcutId"), (Layout::new::<AzShortcutId>(), "AzShortcutId"));
         assert_eq!((Layout::new::<azul_impl::callbacks::MarshaledLayoutCallbackInner>(), "AzMarshaledLayoutCallbackInner"), (Layout::new::<AzMarshaledLayoutCallbackInner>(), "AzMarshaledLayoutCallbackInner"));
         assert_eq!((Layout::new::<azul_impl::callbacks::LayoutCallbackInner>(), "AzLayoutCallbackInner"), (Layout::new::<AzLayoutCallbackInner>(), "AzLayoutCallbackInner"));
         assert_eq!((Layout::new::<azul_impl::callbacks::Callback>(), "AzCallback"), (Layout::new::<AzCallback>(), "AzCallback"));
@@ -13130,6 +13845,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::styled_dom::NodeHierarchyItemId>(), "AzNodeId"), (Layout::new::<AzNodeId>(), "AzNodeId"));
         assert_eq!((Layout::new::<azul_impl::styled_dom::DomId>(), "AzDomId"), (Layout::new::<AzDomId>(), "AzDomId"));
         assert_eq!((Layout::new::<azul_impl::ui_solver::PositionInfoInner>(), "AzPositionInfoInner"), (Layout::new::<AzPositionInfoInner>(), "AzPositionInfoInner"));
+        assert_eq!((Layout::new::<azul_core::scroll::ScrollIntoViewAlign>(), "AzScrollIntoViewAlign"), (Layout::new::<AzScrollIntoViewAlign>(), "AzScrollIntoViewAlign"));
         assert_eq!((Layout::new::<azul_impl::callbacks::AnimationRepeat>(), "AzAnimationRepeat"), (Layout::new::<AzAnimationRepeat>(), "AzAnimationRepeat"));
         assert_eq!((Layout::new::<azul_impl::callbacks::AnimationRepeatCount>(), "AzAnimationRepeatCount"), (Layout::new::<AzAnimationRepeatCount>(), "AzAnimationRepeatCount"));
         assert_eq!((Layout::new::<azul_impl::callbacks::IFrameCallback>(), "AzIFrameCallback"), (Layout::new::<AzIFrameCallback>(), "AzIFrameCallback"));
@@ -13175,7 +13891,10 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::RadialGradientSize>(), "AzRadialGradientSize"), (Layout::new::<AzRadialGradientSize>(), "AzRadialGradientSize"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundRepeat>(), "AzStyleBackgroundRepeat"), (Layout::new::<AzStyleBackgroundRepeat>(), "AzStyleBackgroundRepeat"));
         assert_eq!((Layout::new::<azul_impl::css::BorderStyle>(), "AzBorderStyle"), (Layout::new::<AzBorderStyle>(), "AzBorderStyle"));
-        assert_eq!((Layout::new::<azul_impl::css::StyleCursor>(), "AzStyleCursor"), (Layout::new::<AzStyleCursor>(), "AzStyleCursor"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleScrollBehavior>(), "AzStyleScrollBehavior"), (Layout::new::<AzStyleScrollBehavior>(), "AzStyleScrollBehavior"));
+        assert_eq!((Layout::new::<azul_impl::css::ScrollSnapAxis>(), "AzScrollSnapAxis"), (Layout::new::<AzScrollSnapAxis>(), "AzScrollSnapAxis"));
+        assert_eq!((Layout::new::<azul_impl::css::ScrollSnapStrictness>(), "AzScrollSnapStrictness"), (Layout::new::<AzScrollSnapStrictness>(), "AzScrollSnapStrictness"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleScrollSnapAlign>(), "AzStyleScrollSnapAlign"), (Layout::new::<AzStyleScrollSnapAlign>(), "AzStyleScrollSnapAlign"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackfaceVisibility>(), "AzStyleBackfaceVisibility"), (Layout::new::<AzStyleBackfaceVisibility>(), "AzStyleBackfaceVisibility"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTextAlign>(), "AzStyleTextAlign"), (Layout::new::<AzStyleTextAlign>(), "AzStyleTextAlign"));
         assert_eq!((Layout::new::<crate::widgets::ribbon::Ribbon>(), "AzRibbon"), (Layout::new::<AzRibbon>(), "AzRibbon"));
@@ -13291,6 +14010,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::task::ThreadDestructorCallback>(), "AzThreadDestructorFn"), (Layout::new::<AzThreadDestructorFn>(), "AzThreadDestructorFn"));
         assert_eq!((Layout::new::<azul_impl::task::ThreadReceiverDestructorCallback>(), "AzThreadReceiverDestructorFn"), (Layout::new::<AzThreadReceiverDestructorFn>(), "AzThreadReceiverDestructorFn"));
         assert_eq!((Layout::new::<azul_impl::task::ThreadSenderDestructorCallback>(), "AzThreadSenderDestructorFn"), (Layout::new::<AzThreadSenderDestructorFn>(), "AzThreadSenderDestructorFn"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleCursorVecDestructor>(), "AzStyleCursorVecDestructor"), (Layout::new::<AzStyleCursorVecDestructor>(), "AzStyleCursorVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::css::StyleFontFamilyVecDestructor>(), "AzStyleFontFamilyVecDestructor"), (Layout::new::<AzStyleFontFamilyVecDestructor>(), "AzStyleFontFamilyVecDestructor"));
         assert_eq!((Layout::new::<crate::widgets::list_view::ListViewRowVecDestructor>(), "AzListViewRowVecDestructor"), (Layout::new::<AzListViewRowVecDestructor>(), "AzListViewRowVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::css::StyleFilterVecDestructor>(), "AzStyleFilterVecDestructor"), (Layout::new::<AzStyleFilterVecDestructor>(), "AzStyleFilterVecDestructor"));
@@ -13305,6 +14025,10 @@ mod test_sizes {
         assert_eq!((Layout::new::<crate::widgets::node_graph::OutputConnectionVecDestructor>(), "AzOutputConnectionVecDestructor"), (Layout::new::<AzOutputConnectionVecDestructor>(), "AzOutputConnectionVecDestructor"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::InputNodeAndIndexVecDestructor>(), "AzInputNodeAndIndexVecDestructor"), (Layout::new::<AzInputNodeAndIndexVecDestructor>(), "AzInputNodeAndIndexVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::dom::AccessibilityStateVecDestructor>(), "AzAccessibilityStateVecDestructor"), (Layout::new::<AzAccessibilityStateVecDestructor>(), "AzAccessibilityStateVecDestructor"));
+        assert_eq!((Layout::new::<azul_core::window::VirtualKeyCodeComboVecDestructor>(), "AzVirtualKeyCodeComboVecDestructor"), (Layout::new::<AzVirtualKeyCodeComboVecDestructor>(), "AzVirtualKeyCodeComboVecDestructor"));
+        assert_eq!((Layout::new::<azul_core::window::ShortcutBindingVecDestructor>(), "AzShortcutBindingVecDestructor"), (Layout::new::<AzShortcutBindingVecDestructor>(), "AzShortcutBindingVecDestructor"));
+        assert_eq!((Layout::new::<azul_core::window::TouchPointVecDestructor>(), "AzTouchPointVecDestructor"), (Layout::new::<AzTouchPointVecDestructor>(), "AzTouchPointVecDestructor"));
+        assert_eq!((Layout::new::<azul_core::window::NotificationActionVecDestructor>(), "AzNotificationActionVecDestructor"), (Layout::new::<AzNotificationActionVecDestructor>(), "AzNotificationActionVecDestructor"));
         assert_eq!((Layout::new::<azul_core::window::MenuItemVecDestructor>(), "AzMenuItemVecDestructor"), (Layout::new::<AzMenuItemVecDestructor>(), "AzMenuItemVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::svg::TessellatedSvgNodeVecDestructor>(), "AzTessellatedSvgNodeVecDestructor"), (Layout::new::<AzTessellatedSvgNodeVecDestructor>(), "AzTessellatedSvgNodeVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::svg::TessellatedColoredSvgNodeVecDestructor>(), "AzTessellatedColoredSvgNodeVecDestructor"), (Layout::new::<AzTessellatedColoredSvgNodeVecDestructor>(), "AzTessellatedColoredSvgNodeVecDestructor"));
@@ -13378,9 +14102,15 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_core::window::CursorPosition>(), "AzCursorPosition"), (Layout::new::<AzCursorPosition>(), "AzCursorPosition"));
         assert_eq!((Layout::new::<azul_core::window::WindowPosition>(), "AzWindowPosition"), (Layout::new::<AzWindowPosition>(), "AzWindowPosition"));
         assert_eq!((Layout::new::<azul_core::window::ImePosition>(), "AzImePosition"), (Layout::new::<AzImePosition>(), "AzImePosition"));
+        assert_eq!((Layout::new::<azul_core::window::TouchPoint>(), "AzTouchPoint"), (Layout::new::<AzTouchPoint>(), "AzTouchPoint"));
+        assert_eq!((Layout::new::<azul_core::window::GestureState>(), "AzGestureState"), (Layout::new::<AzGestureState>(), "AzGestureState"));
         assert_eq!((Layout::new::<azul_core::window::VideoMode>(), "AzVideoMode"), (Layout::new::<AzVideoMode>(), "AzVideoMode"));
+        assert_eq!((Layout::new::<azul_core::window::CustomCursor>(), "AzCustomCursor"), (Layout::new::<AzCustomCursor>(), "AzCustomCursor"));
+        assert_eq!((Layout::new::<azul_core::window::ShortcutScope>(), "AzShortcutScope"), (Layout::new::<AzShortcutScope>(), "AzShortcutScope"));
+        assert_eq!((Layout::new::<azul_core::window::ShortcutError>(), "AzShortcutError"), (Layout::new::<AzShortcutError>(), "AzShortcutError"));
         assert_eq!((Layout::new::<azul_impl::callbacks::DomNodeId>(), "AzDomNodeId"), (Layout::new::<AzDomNodeId>(), "AzDomNodeId"));
         assert_eq!((Layout::new::<azul_impl::ui_solver::PositionInfo>(), "AzPositionInfo"), (Layout::new::<AzPositionInfo>(), "AzPositionInfo"));
+        assert_eq!((Layout::new::<azul_impl::callbacks::ScrollPosition>(), "AzScrollPosition"), (Layout::new::<AzScrollPosition>(), "AzScrollPosition"));
         assert_eq!((Layout::new::<azul_impl::callbacks::HidpiAdjustedBounds>(), "AzHidpiAdjustedBounds"), (Layout::new::<AzHidpiAdjustedBounds>(), "AzHidpiAdjustedBounds"));
         assert_eq!((Layout::new::<azul_core::callbacks::InlineGlyph>(), "AzInlineGlyph"), (Layout::new::<AzInlineGlyph>(), "AzInlineGlyph"));
         assert_eq!((Layout::new::<azul_core::callbacks::InlineTextHit>(), "AzInlineTextHit"), (Layout::new::<AzInlineTextHit>(), "AzInlineTextHit"));
@@ -13445,6 +14175,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::StyleBorderTopRightRadius>(), "AzStyleBorderTopRightRadius"), (Layout::new::<AzStyleBorderTopRightRadius>(), "AzStyleBorderTopRightRadius"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBorderTopStyle>(), "AzStyleBorderTopStyle"), (Layout::new::<AzStyleBorderTopStyle>(), "AzStyleBorderTopStyle"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutBorderTopWidth>(), "AzLayoutBorderTopWidth"), (Layout::new::<AzLayoutBorderTopWidth>(), "AzLayoutBorderTopWidth"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleScrollSnapType>(), "AzStyleScrollSnapType"), (Layout::new::<AzStyleScrollSnapType>(), "AzStyleScrollSnapType"));
         assert_eq!((Layout::new::<azul_impl::css::StyleFontSize>(), "AzStyleFontSize"), (Layout::new::<AzStyleFontSize>(), "AzStyleFontSize"));
         assert_eq!((Layout::new::<azul_impl::css::StyleLetterSpacing>(), "AzStyleLetterSpacing"), (Layout::new::<AzStyleLetterSpacing>(), "AzStyleLetterSpacing"));
         assert_eq!((Layout::new::<azul_impl::css::StyleLineHeight>(), "AzStyleLineHeight"), (Layout::new::<AzStyleLineHeight>(), "AzStyleLineHeight"));
@@ -13493,6 +14224,9 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::LayoutWidthValue>(), "AzLayoutWidthValue"), (Layout::new::<AzLayoutWidthValue>(), "AzLayoutWidthValue"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutFlexWrapValue>(), "AzLayoutFlexWrapValue"), (Layout::new::<AzLayoutFlexWrapValue>(), "AzLayoutFlexWrapValue"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutOverflowValue>(), "AzLayoutOverflowValue"), (Layout::new::<AzLayoutOverflowValue>(), "AzLayoutOverflowValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleScrollBehaviorValue>(), "AzStyleScrollBehaviorValue"), (Layout::new::<AzStyleScrollBehaviorValue>(), "AzStyleScrollBehaviorValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleScrollSnapTypeValue>(), "AzStyleScrollSnapTypeValue"), (Layout::new::<AzStyleScrollSnapTypeValue>(), "AzStyleScrollSnapTypeValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleScrollSnapAlignValue>(), "AzStyleScrollSnapAlignValue"), (Layout::new::<AzStyleScrollSnapAlignValue>(), "AzStyleScrollSnapAlignValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBorderBottomColorValue>(), "AzStyleBorderBottomColorValue"), (Layout::new::<AzStyleBorderBottomColorValue>(), "AzStyleBorderBottomColorValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBorderBottomLeftRadiusValue>(), "AzStyleBorderBottomLeftRadiusValue"), (Layout::new::<AzStyleBorderBottomLeftRadiusValue>(), "AzStyleBorderBottomLeftRadiusValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBorderBottomRightRadiusValue>(), "AzStyleBorderBottomRightRadiusValue"), (Layout::new::<AzStyleBorderBottomRightRadiusValue>(), "AzStyleBorderBottomRightRadiusValue"));
@@ -13509,7 +14243,6 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::StyleBorderTopRightRadiusValue>(), "AzStyleBorderTopRightRadiusValue"), (Layout::new::<AzStyleBorderTopRightRadiusValue>(), "AzStyleBorderTopRightRadiusValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBorderTopStyleValue>(), "AzStyleBorderTopStyleValue"), (Layout::new::<AzStyleBorderTopStyleValue>(), "AzStyleBorderTopStyleValue"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutBorderTopWidthValue>(), "AzLayoutBorderTopWidthValue"), (Layout::new::<AzLayoutBorderTopWidthValue>(), "AzLayoutBorderTopWidthValue"));
-        assert_eq!((Layout::new::<azul_impl::css::StyleCursorValue>(), "AzStyleCursorValue"), (Layout::new::<AzStyleCursorValue>(), "AzStyleCursorValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleFontSizeValue>(), "AzStyleFontSizeValue"), (Layout::new::<AzStyleFontSizeValue>(), "AzStyleFontSizeValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleLetterSpacingValue>(), "AzStyleLetterSpacingValue"), (Layout::new::<AzStyleLetterSpacingValue>(), "AzStyleLetterSpacingValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleLineHeightValue>(), "AzStyleLineHeightValue"), (Layout::new::<AzStyleLineHeightValue>(), "AzStyleLineHeightValue"));
@@ -13568,7 +14301,9 @@ mod test_sizes {
         assert_eq!((Layout::new::<crate::widgets::node_graph::OutputNodeAndIndexVec>(), "AzOutputNodeAndIndexVec"), (Layout::new::<AzOutputNodeAndIndexVec>(), "AzOutputNodeAndIndexVec"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::InputNodeAndIndexVec>(), "AzInputNodeAndIndexVec"), (Layout::new::<AzInputNodeAndIndexVec>(), "AzInputNodeAndIndexVec"));
         assert_eq!((Layout::new::<azul_impl::dom::AccessibilityStateVec>(), "AzAccessibilityStateVec"), (Layout::new::<AzAccessibilityStateVec>(), "AzAccessibilityStateVec"));
+        assert_eq!((Layout::new::<azul_core::window::TouchPointVec>(), "AzTouchPointVec"), (Layout::new::<AzTouchPointVec>(), "AzTouchPointVec"));
         assert_eq!((Layout::new::<azul_core::window::MenuItemVec>(), "AzMenuItemVec"), (Layout::new::<AzMenuItemVec>(), "AzMenuItemVec"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleCursorVec>(), "AzStyleCursorVec"), (Layout::new::<AzStyleCursorVec>(), "AzStyleCursorVec"));
         assert_eq!((Layout::new::<azul_impl::xml::XmlNodeVec>(), "AzXmlNodeVec"), (Layout::new::<AzXmlNodeVec>(), "AzXmlNodeVec"));
         assert_eq!((Layout::new::<azul_impl::callbacks::InlineGlyphVec>(), "AzInlineGlyphVec"), (Layout::new::<AzInlineGlyphVec>(), "AzInlineGlyphVec"));
         assert_eq!((Layout::new::<azul_impl::callbacks::InlineTextHitVec>(), "AzInlineTextHitVec"), (Layout::new::<AzInlineTextHitVec>(), "AzInlineTextHitVec"));
@@ -13625,6 +14360,8 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::task::OptionTimerId>(), "AzOptionTimerId"), (Layout::new::<AzOptionTimerId>(), "AzOptionTimerId"));
         assert_eq!((Layout::new::<azul_impl::task::OptionThreadId>(), "AzOptionThreadId"), (Layout::new::<AzOptionThreadId>(), "AzOptionThreadId"));
         assert_eq!((Layout::new::<azul_impl::resources::OptionImageRef>(), "AzOptionImageRef"), (Layout::new::<AzOptionImageRef>(), "AzOptionImageRef"));
+        assert_eq!((Layout::new::<azul_core::window::OptionWindowId>(), "AzOptionWindowId"), (Layout::new::<AzOptionWindowId>(), "AzOptionWindowId"));
+        assert_eq!((Layout::new::<azul_core::window::OptionCustomCursor>(), "AzOptionCustomCursor"), (Layout::new::<AzOptionCustomCursor>(), "AzOptionCustomCursor"));
         assert_eq!((Layout::new::<azul_impl::css::OptionFontRef>(), "AzOptionFontRef"), (Layout::new::<AzOptionFontRef>(), "AzOptionFontRef"));
         assert_eq!((Layout::new::<azul_impl::app::OptionClipboard>(), "AzOptionSystemClipboard"), (Layout::new::<AzOptionSystemClipboard>(), "AzOptionSystemClipboard"));
         assert_eq!((Layout::new::<azul_impl::gl::OptionGlContextPtr>(), "AzOptionGl"), (Layout::new::<AzOptionGl>(), "AzOptionGl"));
@@ -13635,6 +14372,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::task::OptionThreadSendMsg>(), "AzOptionThreadSendMsg"), (Layout::new::<AzOptionThreadSendMsg>(), "AzOptionThreadSendMsg"));
         assert_eq!((Layout::new::<azul_impl::css::OptionLayoutRect>(), "AzOptionLayoutRect"), (Layout::new::<AzOptionLayoutRect>(), "AzOptionLayoutRect"));
         assert_eq!((Layout::new::<azul_impl::callbacks::OptionRefAny>(), "AzOptionRefAny"), (Layout::new::<AzOptionRefAny>(), "AzOptionRefAny"));
+        assert_eq!((Layout::new::<azul_impl::callbacks::OptionScrollPosition>(), "AzOptionScrollPosition"), (Layout::new::<AzOptionScrollPosition>(), "AzOptionScrollPosition"));
         assert_eq!((Layout::new::<azul_impl::css::OptionLayoutPoint>(), "AzOptionLayoutPoint"), (Layout::new::<AzOptionLayoutPoint>(), "AzOptionLayoutPoint"));
         assert_eq!((Layout::new::<azul_impl::css::OptionLayoutSize>(), "AzOptionLayoutSize"), (Layout::new::<AzOptionLayoutSize>(), "AzOptionLayoutSize"));
         assert_eq!((Layout::new::<azul_core::window::OptionWindowTheme>(), "AzOptionWindowTheme"), (Layout::new::<AzOptionWindowTheme>(), "AzOptionWindowTheme"));
@@ -13642,6 +14380,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::callbacks::OptionDomNodeId>(), "AzOptionDomNodeId"), (Layout::new::<AzOptionDomNodeId>(), "AzOptionDomNodeId"));
         assert_eq!((Layout::new::<azul_impl::css::OptionColorU>(), "AzOptionColorU"), (Layout::new::<AzOptionColorU>(), "AzOptionColorU"));
         assert_eq!((Layout::new::<azul_impl::svg::OptionSvgDashPattern>(), "AzOptionSvgDashPattern"), (Layout::new::<AzOptionSvgDashPattern>(), "AzOptionSvgDashPattern"));
+        assert_eq!((Layout::new::<azul_core::window::OptionGestureState>(), "AzOptionGestureState"), (Layout::new::<AzOptionGestureState>(), "AzOptionGestureState"));
         assert_eq!((Layout::new::<azul_core::window::OptionLogicalPosition>(), "AzOptionLogicalPosition"), (Layout::new::<AzOptionLogicalPosition>(), "AzOptionLogicalPosition"));
         assert_eq!((Layout::new::<azul_core::window::OptionPhysicalPositionI32>(), "AzOptionPhysicalPositionI32"), (Layout::new::<AzOptionPhysicalPositionI32>(), "AzOptionPhysicalPositionI32"));
         assert_eq!((Layout::new::<azul_core::window::OptionMouseCursorType>(), "AzOptionMouseCursorType"), (Layout::new::<AzOptionMouseCursorType>(), "AzOptionMouseCursorType"));
@@ -13654,12 +14393,14 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::OptionU8Vec>(), "AzOptionU8Vec"), (Layout::new::<AzOptionU8Vec>(), "AzOptionU8Vec"));
         assert_eq!((Layout::new::<azul_impl::gl::OptionU8VecRef>(), "AzOptionU8VecRef"), (Layout::new::<AzOptionU8VecRef>(), "AzOptionU8VecRef"));
         assert_eq!((Layout::new::<azul_impl::resources::encode::ResultU8VecEncodeImageError>(), "AzResultU8VecEncodeImageError"), (Layout::new::<AzResultU8VecEncodeImageError>(), "AzResultU8VecEncodeImageError"));
+        assert_eq!((Layout::new::<azul_core::window::ResultShortcutIdShortcutError>(), "AzResultShortcutIdShortcutError"), (Layout::new::<AzResultShortcutIdShortcutError>(), "AzResultShortcutIdShortcutError"));
         assert_eq!((Layout::new::<azul_impl::xml::NonXmlCharError>(), "AzNonXmlCharError"), (Layout::new::<AzNonXmlCharError>(), "AzNonXmlCharError"));
         assert_eq!((Layout::new::<azul_impl::xml::InvalidCharError>(), "AzInvalidCharError"), (Layout::new::<AzInvalidCharError>(), "AzInvalidCharError"));
         assert_eq!((Layout::new::<azul_impl::xml::InvalidCharMultipleError>(), "AzInvalidCharMultipleError"), (Layout::new::<AzInvalidCharMultipleError>(), "AzInvalidCharMultipleError"));
         assert_eq!((Layout::new::<azul_impl::xml::InvalidQuoteError>(), "AzInvalidQuoteError"), (Layout::new::<AzInvalidQuoteError>(), "AzInvalidQuoteError"));
         assert_eq!((Layout::new::<azul_impl::xml::InvalidSpaceError>(), "AzInvalidSpaceError"), (Layout::new::<AzInvalidSpaceError>(), "AzInvalidSpaceError"));
         assert_eq!((Layout::new::<azul_impl::resources::AppConfig>(), "AzAppConfig"), (Layout::new::<AzAppConfig>(), "AzAppConfig"));
+        assert_eq!((Layout::new::<azul_core::window::WindowResultCallback>(), "AzWindowResultCallback"), (Layout::new::<AzWindowResultCallback>(), "AzWindowResultCallback"));
         assert_eq!((Layout::new::<azul_core::window::SmallWindowIconBytes>(), "AzSmallWindowIconBytes"), (Layout::new::<AzSmallWindowIconBytes>(), "AzSmallWindowIconBytes"));
         assert_eq!((Layout::new::<azul_core::window::LargeWindowIconBytes>(), "AzLargeWindowIconBytes"), (Layout::new::<AzLargeWindowIconBytes>(), "AzLargeWindowIconBytes"));
         assert_eq!((Layout::new::<azul_core::window::WindowIcon>(), "AzWindowIcon"), (Layout::new::<AzWindowIcon>(), "AzWindowIcon"));
@@ -13667,6 +14408,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_core::window::WindowSize>(), "AzWindowSize"), (Layout::new::<AzWindowSize>(), "AzWindowSize"));
         assert_eq!((Layout::new::<azul_core::window::KeyboardState>(), "AzKeyboardState"), (Layout::new::<AzKeyboardState>(), "AzKeyboardState"));
         assert_eq!((Layout::new::<azul_core::window::MouseState>(), "AzMouseState"), (Layout::new::<AzMouseState>(), "AzMouseState"));
+        assert_eq!((Layout::new::<azul_core::window::TouchState>(), "AzTouchState"), (Layout::new::<AzTouchState>(), "AzTouchState"));
         assert_eq!((Layout::new::<azul_impl::callbacks::MarshaledLayoutCallback>(), "AzMarshaledLayoutCallback"), (Layout::new::<AzMarshaledLayoutCallback>(), "AzMarshaledLayoutCallback"));
         assert_eq!((Layout::new::<azul_core::callbacks::InlineTextContents>(), "AzInlineTextContents"), (Layout::new::<AzInlineTextContents>(), "AzInlineTextContents"));
         assert_eq!((Layout::new::<azul_impl::ui_solver::ResolvedTextLayoutOptions>(), "AzResolvedTextLayoutOptions"), (Layout::new::<AzResolvedTextLayoutOptions>(), "AzResolvedTextLayoutOptions"));
@@ -13714,6 +14456,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::StyleFilterVec>(), "AzStyleFilterVec"), (Layout::new::<AzStyleFilterVec>(), "AzStyleFilterVec"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::InputConnectionVec>(), "AzInputConnectionVec"), (Layout::new::<AzInputConnectionVec>(), "AzInputConnectionVec"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::OutputConnectionVec>(), "AzOutputConnectionVec"), (Layout::new::<AzOutputConnectionVec>(), "AzOutputConnectionVec"));
+        assert_eq!((Layout::new::<azul_core::window::VirtualKeyCodeComboVec>(), "AzVirtualKeyCodeComboVec"), (Layout::new::<AzVirtualKeyCodeComboVec>(), "AzVirtualKeyCodeComboVec"));
         assert_eq!((Layout::new::<azul_impl::svg::TessellatedSvgNodeVec>(), "AzTessellatedSvgNodeVec"), (Layout::new::<AzTessellatedSvgNodeVec>(), "AzTessellatedSvgNodeVec"));
         assert_eq!((Layout::new::<azul_impl::svg::TessellatedColoredSvgNodeVec>(), "AzTessellatedColoredSvgNodeVec"), (Layout::new::<AzTessellatedColoredSvgNodeVec>(), "AzTessellatedColoredSvgNodeVec"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTransformVec>(), "AzStyleTransformVec"), (Layout::new::<AzStyleTransformVec>(), "AzStyleTransformVec"));
@@ -13724,6 +14467,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_core::window::OptionMenu>(), "AzOptionMenu"), (Layout::new::<AzOptionMenu>(), "AzOptionMenu"));
         assert_eq!((Layout::new::<azul_impl::ui_solver::OptionResolvedTextLayoutOptions>(), "AzOptionResolvedTextLayoutOptions"), (Layout::new::<AzOptionResolvedTextLayoutOptions>(), "AzOptionResolvedTextLayoutOptions"));
         assert_eq!((Layout::new::<azul_core::window::OptionVirtualKeyCodeCombo>(), "AzOptionVirtualKeyCodeCombo"), (Layout::new::<AzOptionVirtualKeyCodeCombo>(), "AzOptionVirtualKeyCodeCombo"));
+        assert_eq!((Layout::new::<azul_core::window::OptionWindowResultCallback>(), "AzOptionWindowResultCallback"), (Layout::new::<AzOptionWindowResultCallback>(), "AzOptionWindowResultCallback"));
         assert_eq!((Layout::new::<azul_core::window::OptionMouseState>(), "AzOptionMouseState"), (Layout::new::<AzOptionMouseState>(), "AzOptionMouseState"));
         assert_eq!((Layout::new::<azul_core::window::OptionKeyboardState>(), "AzOptionKeyboardState"), (Layout::new::<AzOptionKeyboardState>(), "AzOptionKeyboardState"));
         assert_eq!((Layout::new::<azul_impl::css::OptionStringVec>(), "AzOptionStringVec"), (Layout::new::<AzOptionStringVec>(), "AzOptionStringVec"));
@@ -13739,10 +14483,14 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::xml::UnknownEntityReferenceError>(), "AzUnknownEntityReferenceError"), (Layout::new::<AzUnknownEntityReferenceError>(), "AzUnknownEntityReferenceError"));
         assert_eq!((Layout::new::<azul_impl::xml::DuplicatedAttributeError>(), "AzDuplicatedAttributeError"), (Layout::new::<AzDuplicatedAttributeError>(), "AzDuplicatedAttributeError"));
         assert_eq!((Layout::new::<azul_impl::xml::InvalidStringError>(), "AzInvalidStringError"), (Layout::new::<AzInvalidStringError>(), "AzInvalidStringError"));
+        assert_eq!((Layout::new::<azul_core::window::TrayIcon>(), "AzTrayIcon"), (Layout::new::<AzTrayIcon>(), "AzTrayIcon"));
         assert_eq!((Layout::new::<azul_core::window::WindowsWindowOptions>(), "AzWindowsWindowOptions"), (Layout::new::<AzWindowsWindowOptions>(), "AzWindowsWindowOptions"));
         assert_eq!((Layout::new::<azul_core::window::WaylandTheme>(), "AzWaylandTheme"), (Layout::new::<AzWaylandTheme>(), "AzWaylandTheme"));
         assert_eq!((Layout::new::<azul_core::window::AzStringPair>(), "AzStringPair"), (Layout::new::<AzStringPair>(), "AzStringPair"));
         assert_eq!((Layout::new::<azul_core::window::Monitor>(), "AzMonitor"), (Layout::new::<AzMonitor>(), "AzMonitor"));
+        assert_eq!((Layout::new::<azul_core::window::NotificationAction>(), "AzNotificationAction"), (Layout::new::<AzNotificationAction>(), "AzNotificationAction"));
+        assert_eq!((Layout::new::<azul_core::window::Shortcut>(), "AzShortcut"), (Layout::new::<AzShortcut>(), "AzShortcut"));
+        assert_eq!((Layout::new::<azul_core::window::ShortcutBinding>(), "AzShortcutBinding"), (Layout::new::<AzShortcutBinding>(), "AzShortcutBinding"));
         assert_eq!((Layout::new::<azul_impl::callbacks::LayoutCallback>(), "AzLayoutCallback"), (Layout::new::<AzLayoutCallback>(), "AzLayoutCallback"));
         assert_eq!((Layout::new::<azul_core::callbacks::InlineWord>(), "AzInlineWord"), (Layout::new::<AzInlineWord>(), "AzInlineWord"));
         assert_eq!((Layout::new::<azul_impl::dom::CallbackData>(), "AzCallbackData"), (Layout::new::<AzCallbackData>(), "AzCallbackData"));
@@ -13754,6 +14502,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundContent>(), "AzStyleBackgroundContent"), (Layout::new::<AzStyleBackgroundContent>(), "AzStyleBackgroundContent"));
         assert_eq!((Layout::new::<azul_impl::css::ScrollbarInfo>(), "AzScrollbarInfo"), (Layout::new::<AzScrollbarInfo>(), "AzScrollbarInfo"));
         assert_eq!((Layout::new::<azul_impl::css::ScrollbarStyle>(), "AzScrollbarStyle"), (Layout::new::<AzScrollbarStyle>(), "AzScrollbarStyle"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleCursorImage>(), "AzStyleCursorImage"), (Layout::new::<AzStyleCursorImage>(), "AzStyleCursorImage"));
         assert_eq!((Layout::new::<azul_impl::css::StyleFontFamily>(), "AzStyleFontFamily"), (Layout::new::<AzStyleFontFamily>(), "AzStyleFontFamily"));
         assert_eq!((Layout::new::<azul_impl::css::ScrollbarStyleValue>(), "AzScrollbarStyleValue"), (Layout::new::<AzScrollbarStyleValue>(), "AzScrollbarStyleValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTransformVecValue>(), "AzStyleTransformVecValue"), (Layout::new::<AzStyleTransformVecValue>(), "AzStyleTransformVecValue"));
@@ -13782,6 +14531,8 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::task::Timer>(), "AzTimer"), (Layout::new::<AzTimer>(), "AzTimer"));
         assert_eq!((Layout::new::<azul_impl::str::FmtValue>(), "AzFmtValue"), (Layout::new::<AzFmtValue>(), "AzFmtValue"));
         assert_eq!((Layout::new::<azul_impl::str::FmtArg>(), "AzFmtArg"), (Layout::new::<AzFmtArg>(), "AzFmtArg"));
+        assert_eq!((Layout::new::<azul_core::window::ShortcutBindingVec>(), "AzShortcutBindingVec"), (Layout::new::<AzShortcutBindingVec>(), "AzShortcutBindingVec"));
+        assert_eq!((Layout::new::<azul_core::window::NotificationActionVec>(), "AzNotificationActionVec"), (Layout::new::<AzNotificationActionVec>(), "AzNotificationActionVec"));
         assert_eq!((Layout::new::<azul_impl::css::StyleFontFamilyVec>(), "AzStyleFontFamilyVec"), (Layout::new::<AzStyleFontFamilyVec>(), "AzStyleFontFamilyVec"));
         assert_eq!((Layout::new::<azul_impl::str::FmtArgVec>(), "AzFmtArgVec"), (Layout::new::<AzFmtArgVec>(), "AzFmtArgVec"));
         assert_eq!((Layout::new::<azul_impl::callbacks::InlineWordVec>(), "AzInlineWordVec"), (Layout::new::<AzInlineWordVec>(), "AzInlineWordVec"));
@@ -13798,13 +14549,17 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::file::OptionFile>(), "AzOptionFile"), (Layout::new::<AzOptionFile>(), "AzOptionFile"));
         assert_eq!((Layout::new::<azul_impl::resources::OptionRawImage>(), "AzOptionRawImage"), (Layout::new::<AzOptionRawImage>(), "AzOptionRawImage"));
         assert_eq!((Layout::new::<azul_core::window::OptionWaylandTheme>(), "AzOptionWaylandTheme"), (Layout::new::<AzOptionWaylandTheme>(), "AzOptionWaylandTheme"));
+        assert_eq!((Layout::new::<azul_core::window::OptionTrayIcon>(), "AzOptionTrayIcon"), (Layout::new::<AzOptionTrayIcon>(), "AzOptionTrayIcon"));
         assert_eq!((Layout::new::<azul_impl::resources::decode::ResultRawImageDecodeImageError>(), "AzResultRawImageDecodeImageError"), (Layout::new::<AzResultRawImageDecodeImageError>(), "AzResultRawImageDecodeImageError"));
         assert_eq!((Layout::new::<azul_impl::xml::XmlStreamError>(), "AzXmlStreamError"), (Layout::new::<AzXmlStreamError>(), "AzXmlStreamError"));
         assert_eq!((Layout::new::<azul_core::window::LinuxWindowOptions>(), "AzLinuxWindowOptions"), (Layout::new::<AzLinuxWindowOptions>(), "AzLinuxWindowOptions"));
+        assert_eq!((Layout::new::<azul_core::window::Notification>(), "AzNotification"), (Layout::new::<AzNotification>(), "AzNotification"));
         assert_eq!((Layout::new::<azul_impl::callbacks::InlineLine>(), "AzInlineLine"), (Layout::new::<AzInlineLine>(), "AzInlineLine"));
         assert_eq!((Layout::new::<azul_core::window::MenuItem>(), "AzMenuItem"), (Layout::new::<AzMenuItem>(), "AzMenuItem"));
         assert_eq!((Layout::new::<azul_impl::css::CssPath>(), "AzCssPath"), (Layout::new::<AzCssPath>(), "AzCssPath"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleCursor>(), "AzStyleCursor"), (Layout::new::<AzStyleCursor>(), "AzStyleCursor"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundContentVecValue>(), "AzStyleBackgroundContentVecValue"), (Layout::new::<AzStyleBackgroundContentVecValue>(), "AzStyleBackgroundContentVecValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleCursorValue>(), "AzStyleCursorValue"), (Layout::new::<AzStyleCursorValue>(), "AzStyleCursorValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleFontFamilyVecValue>(), "AzStyleFontFamilyVecValue"), (Layout::new::<AzStyleFontFamilyVecValue>(), "AzStyleFontFamilyVecValue"));
         assert_eq!((Layout::new::<azul_impl::css::CssProperty>(), "AzCssProperty"), (Layout::new::<AzCssProperty>(), "AzCssProperty"));
         assert_eq!((Layout::new::<crate::widgets::file_input::FileInputStateWrapper>(), "AzFileInputStateWrapper"), (Layout::new::<AzFileInputStateWrapper>(), "AzFileInputStateWrapper"));
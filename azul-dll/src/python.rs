@@ -740,6 +740,12 @@ pub enum AzLayoutSolver {
     Default,
 }
 
+/// Unique ID of a window, used to close a window or to open a child window
+#[repr(C)]
+pub struct AzWindowId {
+    pub id: usize,
+}
+
 /// Whether the renderer has VSync enabled
 #[repr(C)]
 pub enum AzVsync {
@@ -1170,10 +1176,26 @@ pub enum AzWindowTheme {
     LightMode,
 }
 
-/// Current state of touch devices / touch inputs
+/// Kind of device that touches the screen / graphics tablet
 #[repr(C)]
-pub struct AzTouchState {
-    pub unused: u8,
+pub enum AzTouchPointKind {
+    Finger,
+    Pen,
+    Eraser,
+}
+
+/// Urgency of a desktop notification, critical notifications usually don't time out
+#[repr(C)]
+pub enum AzNotificationUrgency {
+    Low,
+    Normal,
+    Critical,
+}
+
+/// ID of a registered keyboard shortcut, used to unregister the shortcut again
+#[repr(C)]
+pub struct AzShortcutId {
+    pub id: usize,
 }
 
 /// C-ABI stable wrapper over a `MarshaledLayoutCallbackInner`
@@ -1239,6 +1261,15 @@ pub struct AzPositionInfoInner {
     pub static_y_offset: f32,
 }
 
+/// How a node is aligned in its scroll containers by `CallbackInfo::scroll_into_view`
+#[repr(C)]
+pub enum AzScrollIntoViewAlign {
+    Start,
+    Center,
+    End,
+    Nearest,
+}
+
 /// How should an animation repeat (loop, ping-pong, etc.)
 #[repr(C)]
 pub enum AzAnimationRepeat {
@@ -1332,6 +1363,23 @@ pub enum AzOn {
     HoveredFileCancelled,
     FocusReceived,
     FocusLost,
+    DragStart,
+    DragEnter,
+    DragOver,
+    DragLeave,
+    Drop,
+    TextCompositionStart,
+    TextCompositionUpdate,
+    TextCompositionEnd,
+    TouchStart,
+    TouchMove,
+    TouchEnd,
+    TouchCancel,
+    Pinch,
+    Rotate,
+    TwoFingerPan,
+    LongPress,
+    Scrolled,
 }
 
 /// Re-export of rust-allocated (stack based) `HoverEventFilter` struct
@@ -1361,6 +1409,18 @@ pub enum AzHoverEventFilter {
     TouchMove,
     TouchEnd,
     TouchCancel,
+    Pinch,
+    Rotate,
+    TwoFingerPan,
+    LongPress,
+    DragStart,
+    DragEnter,
+    DragOver,
+    DragLeave,
+    Drop,
+    TextCompositionStart,
+    TextCompositionUpdate,
+    TextCompositionEnd,
 }
 
 /// Re-export of rust-allocated (stack based) `FocusEventFilter` struct
@@ -1385,6 +1445,9 @@ pub enum AzFocusEventFilter {
     VirtualKeyUp,
     FocusReceived,
     FocusLost,
+    TextCompositionStart,
+    TextCompositionUpdate,
+    TextCompositionEnd,
 }
 
 /// Re-export of rust-allocated (stack based) `WindowEventFilter` struct
@@ -1416,10 +1479,26 @@ pub enum AzWindowEventFilter {
     TouchMove,
     TouchEnd,
     TouchCancel,
+    Pinch,
+    Rotate,
+    TwoFingerPan,
+    LongPress,
     FocusReceived,
     FocusLost,
     CloseRequested,
     ThemeChanged,
+    WindowFocusReceived,
+    WindowFocusLost,
+    ClipboardChanged,
+    DragStart,
+    DragEnter,
+    DragOver,
+    DragLeave,
+    Drop,
+    DragEnd,
+    TextCompositionStart,
+    TextCompositionUpdate,
+    TextCompositionEnd,
 }
 
 /// Re-export of rust-allocated (stack based) `ComponentEventFilter` struct
@@ -1430,6 +1509,7 @@ pub enum AzComponentEventFilter {
     NodeResized,
     DefaultAction,
     Selected,
+    Scrolled,
 }
 
 /// Re-export of rust-allocated (stack based) `ApplicationEventFilter` struct
@@ -1654,6 +1734,9 @@ pub enum AzCssPropertyType {
     BoxShadowTop,
     BoxShadowBottom,
     ScrollbarStyle,
+    ScrollBehavior,
+    ScrollSnapType,
+    ScrollSnapAlign,
     Opacity,
     Transform,
     TransformOrigin,
@@ -1875,39 +1958,36 @@ pub enum AzBorderStyle {
     Outset,
 }
 
-/// Re-export of rust-allocated (stack based) `StyleCursor` struct
+/// Represents a `scroll-behavior` attribute: whether programmatic scrolling (such as `CallbackInfo::scroll_to`) is animated
 #[repr(C)]
-pub enum AzStyleCursor {
-    Alias,
-    AllScroll,
-    Cell,
-    ColResize,
-    ContextMenu,
-    Copy,
-    Crosshair,
-    Default,
-    EResize,
-    EwResize,
-    Grab,
-    Grabbing,
-    Help,
-    Move,
-    NResize,
-    NsResize,
-    NeswResize,
-    NwseResize,
-    Pointer,
-    Progress,
-    RowResize,
-    SResize,
-    SeResize,
-    Text,
-    Unset,
-    VerticalText,
-    WResize,
-    Wait,
-    ZoomIn,
-    ZoomOut,
+pub enum AzStyleScrollBehavior {
+    Auto,
+    Smooth,
+}
+
+/// Axis on which a scroll container snaps to its children
+#[repr(C)]
+pub enum AzScrollSnapAxis {
+    None,
+    X,
+    Y,
+    Both,
+}
+
+/// `mandatory` always snaps to the nearest snap position, `proximity` only snaps if the scroll position comes close to one
+#[repr(C)]
+pub enum AzScrollSnapStrictness {
+    Proximity,
+    Mandatory,
+}
+
+/// Represents a `scroll-snap-align` attribute: which edge of the node is aligned with the scroll container when snapping
+#[repr(C)]
+pub enum AzStyleScrollSnapAlign {
+    None,
+    Start,
+    Center,
+    End,
 }
 
 /// Re-export of rust-allocated (stack based) `StyleBackfaceVisibility` struct
@@ -2835,6 +2915,17 @@ pub struct AzThreadSenderDestructorFn {
     pub cb: AzThreadSenderDestructorFnType,
 }
 
+/// Re-export of rust-allocated (stack based) `StyleCursorVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzStyleCursorVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzStyleCursorVecDestructorType),
+}
+
+/// `AzStyleCursorVecDestructorType` struct
+pub type AzStyleCursorVecDestructorType = extern "C" fn(&mut AzStyleCursorVec);
+
 /// Re-export of rust-allocated (stack based) `StyleFontFamilyVecDestructor` struct
 #[repr(C, u8)]
 pub enum AzStyleFontFamilyVecDestructor {
@@ -2989,6 +3080,50 @@ pub enum AzAccessibilityStateVecDestructor {
 /// `AzAccessibilityStateVecDestructorType` struct
 pub type AzAccessibilityStateVecDestructorType = extern "C" fn(&mut AzAccessibilityStateVec);
 
+/// Re-export of rust-allocated (stack based) `VirtualKeyCodeComboVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzVirtualKeyCodeComboVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzVirtualKeyCodeComboVecDestructorType),
+}
+
+/// `AzVirtualKeyCodeComboVecDestructorType` struct
+pub type AzVirtualKeyCodeComboVecDestructorType = extern "C" fn(&mut AzVirtualKeyCodeComboVec);
+
+/// Re-export of rust-allocated (stack based) `ShortcutBindingVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzShortcutBindingVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzShortcutBindingVecDestructorType),
+}
+
+/// `AzShortcutBindingVecDestructorType` struct
+pub type AzShortcutBindingVecDestructorType = extern "C" fn(&mut AzShortcutBindingVec);
+
+/// Re-export of rust-allocated (stack based) `TouchPointVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzTouchPointVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzTouchPointVecDestructorType),
+}
+
+/// `AzTouchPointVecDestructorType` struct
+pub type AzTouchPointVecDestructorType = extern "C" fn(&mut AzTouchPointVec);
+
+/// Re-export of rust-allocated (stack based) `NotificationActionVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzNotificationActionVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzNotificationActionVecDestructorType),
+}
+
+/// `AzNotificationActionVecDestructorType` struct
+pub type AzNotificationActionVecDestructorType = extern "C" fn(&mut AzNotificationActionVec);
+
 /// Re-export of rust-allocated (stack based) `MenuItemVecDestructor` struct
 #[repr(C, u8)]
 pub enum AzMenuItemVecDestructor {
@@ -3706,7 +3841,10 @@ pub struct AzWindowFlags {
     pub has_focus: bool,
     pub has_extended_window_frame: bool,
     pub has_blur_behind_window: bool,
+    pub is_click_through: bool,
     pub smooth_scroll_enabled: bool,
+    pub overscroll_bounce_enabled: bool,
+    pub scrollbar_auto_hide_enabled: bool,
     pub autotab_enabled: bool,
 }
 
@@ -3732,6 +3870,29 @@ pub enum AzImePosition {
     Initialized(AzLogicalPosition),
 }
 
+/// Finger or pen that touches the window
+#[repr(C)]
+pub struct AzTouchPoint {
+    pub id: u64,
+    pub kind: AzTouchPointKindEnumWrapper,
+    pub position: AzLogicalPosition,
+    pub pressure: f32,
+    pub tilt_x: f32,
+    pub tilt_y: f32,
+}
+
+/// Two-finger gesture, all values are relative to the positions of the fingers at the start of the gesture
+#[repr(C)]
+pub struct AzGestureState {
+    pub center: AzLogicalPosition,
+    pub scale: f32,
+    pub rotation: f32,
+    pub translation: AzLogicalPosition,
+    pub is_pinch: bool,
+    pub is_rotate: bool,
+    pub is_pan: bool,
+}
+
 /// Describes a rendering configuration for a monitor
 #[repr(C)]
 pub struct AzVideoMode {
@@ -3740,6 +3901,30 @@ pub struct AzVideoMode {
     pub refresh_rate: u16,
 }
 
+/// Image-based mouse cursor, created either from a CSS `cursor: url(...)` property or set at runtime via `CallbackInfo::set_custom_cursor()`. The hotspot is the "click point" of the cursor in pixels, relative to the top left corner of the image.
+#[repr(C)]
+pub struct AzCustomCursor {
+    pub image: AzImageRef,
+    pub hotspot_x: u32,
+    pub hotspot_y: u32,
+}
+
+/// Where a keyboard shortcut is active
+#[repr(C, u8)]
+pub enum AzShortcutScope {
+    Window(AzWindowId),
+    App,
+    Global,
+}
+
+/// Error when registering a keyboard shortcut
+#[repr(C, u8)]
+pub enum AzShortcutError {
+    NoKeys,
+    GlobalSequence,
+    Conflict(AzShortcutId),
+}
+
 /// Combination of node ID + DOM ID, both together can identify a node
 #[repr(C)]
 pub struct AzDomNodeId {
@@ -3756,6 +3941,14 @@ pub enum AzPositionInfo {
     Relative(AzPositionInfoInner),
 }
 
+/// Information about a scroll frame, given to the user by the framework
+#[repr(C)]
+pub struct AzScrollPosition {
+    pub parent_rect: AzLogicalRect,
+    pub children_rect: AzLogicalRect,
+    pub scroll_offset: AzLogicalPosition,
+}
+
 /// Re-export of rust-allocated (stack based) `HidpiAdjustedBounds` struct
 #[repr(C)]
 pub struct AzHidpiAdjustedBounds {
@@ -4205,6 +4398,13 @@ pub struct AzLayoutBorderTopWidth {
     pub inner: AzPixelValue,
 }
 
+/// Represents a `scroll-snap-type` attribute, for example `y mandatory`
+#[repr(C)]
+pub struct AzStyleScrollSnapType {
+    pub axis: AzScrollSnapAxisEnumWrapper,
+    pub strictness: AzScrollSnapStrictnessEnumWrapper,
+}
+
 /// Re-export of rust-allocated (stack based) `StyleFontSize` struct
 #[repr(C)]
 pub struct AzStyleFontSize {
@@ -4649,6 +4849,36 @@ pub enum AzLayoutOverflowValue {
     Exact(AzLayoutOverflow),
 }
 
+/// Re-export of rust-allocated (stack based) `StyleScrollBehaviorValue` struct
+#[repr(C, u8)]
+pub enum AzStyleScrollBehaviorValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleScrollBehavior),
+}
+
+/// Re-export of rust-allocated (stack based) `StyleScrollSnapTypeValue` struct
+#[repr(C, u8)]
+pub enum AzStyleScrollSnapTypeValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleScrollSnapType),
+}
+
+/// Re-export of rust-allocated (stack based) `StyleScrollSnapAlignValue` struct
+#[repr(C, u8)]
+pub enum AzStyleScrollSnapAlignValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleScrollSnapAlign),
+}
+
 /// Re-export of rust-allocated (stack based) `StyleBorderBottomColorValue` struct
 #[repr(C, u8)]
 pub enum AzStyleBorderBottomColorValue {
@@ -4809,16 +5039,6 @@ pub enum AzLayoutBorderTopWidthValue {
     Exact(AzLayoutBorderTopWidth),
 }
 
-/// Re-export of rust-allocated (stack based) `StyleCursorValue` struct
-#[repr(C, u8)]
-pub enum AzStyleCursorValue {
-    Auto,
-    None,
-    Inherit,
-    Initial,
-    Exact(AzStyleCursor),
-}
-
 /// Re-export of rust-allocated (stack based) `StyleFontSizeValue` struct
 #[repr(C, u8)]
 pub enum AzStyleFontSizeValue {
@@ -5337,6 +5557,15 @@ pub struct AzAccessibilityStateVec {
     pub destructor: AzAccessibilityStateVecDestructorEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `Vec<TouchPoint>`
+#[repr(C)]
+pub struct AzTouchPointVec {
+    pub(crate) ptr: *const AzTouchPoint,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzTouchPointVecDestructorEnumWrapper,
+}
+
 /// Wrapper over a Rust-allocated `Vec<MenuItem>`
 #[repr(C)]
 pub struct AzMenuItemVec {
@@ -5346,6 +5575,15 @@ pub struct AzMenuItemVec {
     pub destructor: AzMenuItemVecDestructorEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `Vec<StyleCursor>`
+#[repr(C)]
+pub struct AzStyleCursorVec {
+    pub(crate) ptr: *const AzStyleCursorEnumWrapper,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzStyleCursorVecDestructorEnumWrapper,
+}
+
 /// Wrapper over a Rust-allocated `Vec<XmlNode>`
 #[repr(C)]
 pub struct AzXmlNodeVec {
@@ -5788,6 +6026,20 @@ pub enum AzOptionImageRef {
     Some(AzImageRef),
 }
 
+/// Re-export of rust-allocated (stack based) `OptionWindowId` struct
+#[repr(C, u8)]
+pub enum AzOptionWindowId {
+    None,
+    Some(AzWindowId),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionCustomCursor` struct
+#[repr(C, u8)]
+pub enum AzOptionCustomCursor {
+    None,
+    Some(AzCustomCursor),
+}
+
 /// Re-export of rust-allocated (stack based) `OptionFontRef` struct
 #[repr(C, u8)]
 pub enum AzOptionFontRef {
@@ -5858,6 +6110,13 @@ pub enum AzOptionRefAny {
     Some(AzRefAny),
 }
 
+/// Re-export of rust-allocated (stack based) `OptionScrollPosition` struct
+#[repr(C, u8)]
+pub enum AzOptionScrollPosition {
+    None,
+    Some(AzScrollPosition),
+}
+
 /// Re-export of rust-allocated (stack based) `OptionLayoutPoint` struct
 #[repr(C, u8)]
 pub enum AzOptionLayoutPoint {
@@ -5907,6 +6166,13 @@ pub enum AzOptionSvgDashPattern {
     Some(AzSvgDashPattern),
 }
 
+/// Re-export of rust-allocated (stack based) `OptionGestureState` struct
+#[repr(C, u8)]
+pub enum AzOptionGestureState {
+    None,
+    Some(AzGestureState),
+}
+
 /// Re-export of rust-allocated (stack based) `OptionLogicalPosition` struct
 #[repr(C, u8)]
 pub enum AzOptionLogicalPosition {
@@ -5991,6 +6257,13 @@ pub enum AzResultU8VecEncodeImageError {
     Err(AzEncodeImageError),
 }
 
+/// Re-export of rust-allocated (stack based) `ResultShortcutIdShortcutError` struct
+#[repr(C, u8)]
+pub enum AzResultShortcutIdShortcutError {
+    Ok(AzShortcutId),
+    Err(AzShortcutError),
+}
+
 /// Re-export of rust-allocated (stack based) `NonXmlCharError` struct
 #[repr(C)]
 pub struct AzNonXmlCharError {
@@ -6036,9 +6309,17 @@ pub struct AzAppConfig {
     pub enable_visual_panic_hook: bool,
     pub enable_logging_on_panic: bool,
     pub enable_tab_navigation: bool,
+    pub enable_inspector: bool,
     pub system_callbacks: AzSystemCallbacks,
 }
 
+/// Callback that is invoked on the parent window with the result of a child window after the child window has been closed
+#[repr(C)]
+pub struct AzWindowResultCallback {
+    pub data: AzRefAny,
+    pub callback: AzWriteBackCallback,
+}
+
 /// Small (16x16x4) window icon, usually shown in the window titlebar
 #[repr(C)]
 pub struct AzSmallWindowIconBytes {
@@ -6098,6 +6379,16 @@ pub struct AzMouseState {
     pub scroll_y: AzOptionF32EnumWrapper,
 }
 
+/// Fingers and pens touching the window and the recognized gestures
+#[repr(C)]
+pub struct AzTouchState {
+    pub touch_points: AzTouchPointVec,
+    pub ended_touch_points: AzTouchPointVec,
+    pub cancelled_touch_points: AzTouchPointVec,
+    pub gesture: AzOptionGestureStateEnumWrapper,
+    pub long_press: AzOptionLogicalPositionEnumWrapper,
+}
+
 /// C-ABI stable wrapper over a `MarshaledLayoutCallback`
 #[repr(C)]
 pub struct AzMarshaledLayoutCallback {
@@ -6543,6 +6834,15 @@ pub struct AzOutputConnectionVec {
     pub destructor: AzOutputConnectionVecDestructorEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `Vec<VirtualKeyCodeCombo>`
+#[repr(C)]
+pub struct AzVirtualKeyCodeComboVec {
+    pub(crate) ptr: *const AzVirtualKeyCodeCombo,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzVirtualKeyCodeComboVecDestructorEnumWrapper,
+}
+
 /// Wrapper over a Rust-allocated `Vec<TessellatedSvgNode>`
 #[repr(C)]
 pub struct AzTessellatedSvgNodeVec {
@@ -6627,6 +6927,13 @@ pub enum AzOptionVirtualKeyCodeCombo {
     Some(AzVirtualKeyCodeCombo),
 }
 
+/// Re-export of rust-allocated (stack based) `OptionWindowResultCallback` struct
+#[repr(C, u8)]
+pub enum AzOptionWindowResultCallback {
+    None,
+    Some(AzWindowResultCallback),
+}
+
 /// Re-export of rust-allocated (stack based) `OptionMouseState` struct
 #[repr(C, u8)]
 pub enum AzOptionMouseState {
@@ -6733,6 +7040,14 @@ pub struct AzInvalidStringError {
     pub pos: AzSvgParseErrorPosition,
 }
 
+/// Icon in the system tray / notification area of the desktop, with a context menu
+#[repr(C)]
+pub struct AzTrayIcon {
+    pub icon: AzWindowIconEnumWrapper,
+    pub tooltip: AzString,
+    pub menu: AzMenu,
+}
+
 /// Window configuration specific to Win32
 #[repr(C)]
 pub struct AzWindowsWindowOptions {
@@ -6811,6 +7126,29 @@ pub struct AzMonitor {
     pub is_primary_monitor: bool,
 }
 
+/// Button of a desktop notification
+#[repr(C)]
+pub struct AzNotificationAction {
+    pub label: AzString,
+    pub callback: AzMenuCallback,
+}
+
+/// Keyboard shortcut: a sequence of key combinations (i.e. "Ctrl + K, Ctrl + S") that invokes a callback, registered with `App::register_shortcut` or `CallbackInfo::register_shortcut`
+#[repr(C)]
+pub struct AzShortcut {
+    pub keys: AzVirtualKeyCodeComboVec,
+    pub scope: AzShortcutScopeEnumWrapper,
+    pub description: AzString,
+    pub callback: AzMenuCallback,
+}
+
+/// Registered keyboard shortcut, see `App::get_shortcuts`
+#[repr(C)]
+pub struct AzShortcutBinding {
+    pub id: AzShortcutId,
+    pub shortcut: AzShortcut,
+}
+
 /// Re-export of rust-allocated (stack based) `LayoutCallback` struct
 #[repr(C, u8)]
 pub enum AzLayoutCallback {
@@ -6917,6 +7255,15 @@ pub struct AzScrollbarStyle {
     pub vertical: AzScrollbarInfo,
 }
 
+/// Custom image cursor, parsed from `cursor: url("image-id") x y, fallback`. The image ID refers to an image in the `ImageCache`, the hotspot is given in pixels relative to the top left corner of the image.
+#[repr(C)]
+pub struct AzStyleCursorImage {
+    pub image: AzString,
+    pub hotspot_x: u32,
+    pub hotspot_y: u32,
+    pub fallback: AzStyleCursorVec,
+}
+
 /// Re-export of rust-allocated (stack based) `StyleFontFamily` struct
 #[repr(C, u8)]
 pub enum AzStyleFontFamily {
@@ -7181,6 +7528,24 @@ pub struct AzFmtArg {
     pub value: AzFmtValueEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `Vec<ShortcutBinding>`
+#[repr(C)]
+pub struct AzShortcutBindingVec {
+    pub(crate) ptr: *const AzShortcutBinding,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzShortcutBindingVecDestructorEnumWrapper,
+}
+
+/// Wrapper over a Rust-allocated `Vec<NotificationAction>`
+#[repr(C)]
+pub struct AzNotificationActionVec {
+    pub(crate) ptr: *const AzNotificationAction,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzNotificationActionVecDestructorEnumWrapper,
+}
+
 /// Wrapper over a Rust-allocated `Vec<StyleFontFamily>`
 #[repr(C)]
 pub struct AzStyleFontFamilyVec {
@@ -7317,6 +7682,13 @@ pub enum AzOptionWaylandTheme {
     Some(AzWaylandTheme),
 }
 
+/// Re-export of rust-allocated (stack based) `OptionTrayIcon` struct
+#[repr(C, u8)]
+pub enum AzOptionTrayIcon {
+    None,
+    Some(AzTrayIcon),
+}
+
 /// Re-export of rust-allocated (stack based) `ResultRawImageDecodeImageError` struct
 #[repr(C, u8)]
 pub enum AzResultRawImageDecodeImageError {
@@ -7357,6 +7729,18 @@ pub struct AzLinuxWindowOptions {
     pub wayland_theme: AzOptionWaylandThemeEnumWrapper,
     pub request_user_attention: bool,
     pub window_icon: AzOptionWindowIconEnumWrapper,
+    pub tray_icon: AzOptionTrayIconEnumWrapper,
+}
+
+/// Desktop notification, shown with `CallbackInfo::show_notification()`. The callbacks are invoked on the window that showed the notification, as long as the notification is visible and the window is open.
+#[repr(C)]
+pub struct AzNotification {
+    pub title: AzString,
+    pub body: AzString,
+    pub icon: AzOptionImageRefEnumWrapper,
+    pub urgency: AzNotificationUrgencyEnumWrapper,
+    pub actions: AzNotificationActionVec,
+    pub on_click: AzOptionMenuCallbackEnumWrapper,
 }
 
 /// Re-export of rust-allocated (stack based) `InlineLine` struct
@@ -7380,6 +7764,42 @@ pub struct AzCssPath {
     pub selectors: AzCssPathSelectorVec,
 }
 
+/// Re-export of rust-allocated (stack based) `StyleCursor` struct
+#[repr(C, u8)]
+pub enum AzStyleCursor {
+    Alias,
+    AllScroll,
+    Cell,
+    ColResize,
+    ContextMenu,
+    Copy,
+    Crosshair,
+    Default,
+    EResize,
+    EwResize,
+    Grab,
+    Grabbing,
+    Help,
+    Move,
+    NResize,
+    NsResize,
+    NeswResize,
+    NwseResize,
+    Pointer,
+    Progress,
+    RowResize,
+    SResize,
+    SeResize,
+    Text,
+    Unset,
+    VerticalText,
+    WResize,
+    Wait,
+    ZoomIn,
+    ZoomOut,
+    Image(AzStyleCursorImage),
+}
+
 /// Re-export of rust-allocated (stack based) `StyleBackgroundContentVecValue` struct
 #[repr(C, u8)]
 pub enum AzStyleBackgroundContentVecValue {
@@ -7390,6 +7810,16 @@ pub enum AzStyleBackgroundContentVecValue {
     Exact(AzStyleBackgroundContentVec),
 }
 
+/// Re-export of rust-allocated (stack based) `StyleCursorValue` struct
+#[repr(C, u8)]
+pub enum AzStyleCursorValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleCursor),
+}
+
 /// Re-export of rust-allocated (stack based) `StyleFontFamilyVecValue` struct
 #[repr(C, u8)]
 pub enum AzStyleFontFamilyVecValue {
@@ -7468,6 +7898,9 @@ pub enum AzCssProperty {
     BoxShadowTop(AzStyleBoxShadowValue),
     BoxShadowBottom(AzStyleBoxShadowValue),
     ScrollbarStyle(AzScrollbarStyleValue),
+    ScrollBehavior(AzStyleScrollBehaviorValue),
+    ScrollSnapType(AzStyleScrollSnapTypeValue),
+    ScrollSnapAlign(AzStyleScrollSnapAlignValue),
     Opacity(AzStyleOpacityValue),
     Transform(AzStyleTransformVecValue),
     TransformOrigin(AzStyleTransformOriginValue),
@@ -7707,6 +8140,7 @@ pub struct AzWindowState {
     pub platform_specific_options: AzPlatformSpecificOptions,
     pub renderer_options: AzRendererOptions,
     pub background_color: AzColorU,
+    pub custom_cursor: AzOptionCustomCursorEnumWrapper,
     pub layout_callback: AzLayoutCallbackEnumWrapper,
     pub close_callback: AzOptionCallbackEnumWrapper,
 }
@@ -7729,9 +8163,14 @@ pub struct AzCallbackInfo {
     pub threads_removed: *mut c_void,
     pub current_window_handle: *const AzRawWindowHandleEnumWrapper,
     pub new_windows: *mut c_void,
+    pub notifications: *mut c_void,
+    pub shortcuts_changed: *mut c_void,
+    pub windows_closed: *mut c_void,
+    pub window_result: *mut c_void,
     pub system_callbacks: *const AzSystemCallbacks,
     pub stop_propagation: *mut bool,
     pub focus_target: *mut c_void,
+    pub drag_data: *mut c_void,
     pub words_changed_in_callbacks: *mut c_void,
     pub images_changed_in_callbacks: *mut c_void,
     pub image_masks_changed_in_callbacks: *mut c_void,
@@ -7877,6 +8316,11 @@ pub struct AzWindowCreateOptions {
     pub theme: AzOptionWindowThemeEnumWrapper,
     pub create_callback: AzOptionCallbackEnumWrapper,
     pub hot_reload: bool,
+    pub id: AzWindowId,
+    pub parent: AzOptionWindowIdEnumWrapper,
+    pub modal: bool,
+    pub result_callback: AzOptionWindowResultCallbackEnumWrapper,
+    pub persistence_key: AzOptionStringEnumWrapper,
 }
 
 /// Defines the keyboard input focus target
@@ -8237,6 +8681,18 @@ pub struct AzWindowThemeEnumWrapper {
     pub inner: AzWindowTheme,
 }
 
+/// `AzTouchPointKindEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzTouchPointKindEnumWrapper {
+    pub inner: AzTouchPointKind,
+}
+
+/// `AzNotificationUrgencyEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzNotificationUrgencyEnumWrapper {
+    pub inner: AzNotificationUrgency,
+}
+
 /// `AzUpdateImageTypeEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzUpdateImageTypeEnumWrapper {
@@ -8249,6 +8705,12 @@ pub struct AzUpdateEnumWrapper {
     pub inner: AzUpdate,
 }
 
+/// `AzScrollIntoViewAlignEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzScrollIntoViewAlignEnumWrapper {
+    pub inner: AzScrollIntoViewAlign,
+}
+
 /// `AzAnimationRepeatEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzAnimationRepeatEnumWrapper {
@@ -8465,10 +8927,28 @@ pub struct AzBorderStyleEnumWrapper {
     pub inner: AzBorderStyle,
 }
 
-/// `AzStyleCursorEnumWrapper` struct
+/// `AzStyleScrollBehaviorEnumWrapper` struct
 #[repr(transparent)]
-pub struct AzStyleCursorEnumWrapper {
-    pub inner: AzStyleCursor,
+pub struct AzStyleScrollBehaviorEnumWrapper {
+    pub inner: AzStyleScrollBehavior,
+}
+
+/// `AzScrollSnapAxisEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzScrollSnapAxisEnumWrapper {
+    pub inner: AzScrollSnapAxis,
+}
+
+/// `AzScrollSnapStrictnessEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzScrollSnapStrictnessEnumWrapper {
+    pub inner: AzScrollSnapStrictness,
+}
+
+/// `AzStyleScrollSnapAlignEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleScrollSnapAlignEnumWrapper {
+    pub inner: AzStyleScrollSnapAlign,
 }
 
 /// `AzStyleBackfaceVisibilityEnumWrapper` struct
@@ -8609,6 +9089,12 @@ pub struct AzTerminateTimerEnumWrapper {
     pub inner: AzTerminateTimer,
 }
 
+/// `AzStyleCursorVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleCursorVecDestructorEnumWrapper {
+    pub inner: AzStyleCursorVecDestructor,
+}
+
 /// `AzStyleFontFamilyVecDestructorEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzStyleFontFamilyVecDestructorEnumWrapper {
@@ -8693,6 +9179,30 @@ pub struct AzAccessibilityStateVecDestructorEnumWrapper {
     pub inner: AzAccessibilityStateVecDestructor,
 }
 
+/// `AzVirtualKeyCodeComboVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzVirtualKeyCodeComboVecDestructorEnumWrapper {
+    pub inner: AzVirtualKeyCodeComboVecDestructor,
+}
+
+/// `AzShortcutBindingVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzShortcutBindingVecDestructorEnumWrapper {
+    pub inner: AzShortcutBindingVecDestructor,
+}
+
+/// `AzTouchPointVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzTouchPointVecDestructorEnumWrapper {
+    pub inner: AzTouchPointVecDestructor,
+}
+
+/// `AzNotificationActionVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzNotificationActionVecDestructorEnumWrapper {
+    pub inner: AzNotificationActionVecDestructor,
+}
+
 /// `AzMenuItemVecDestructorEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzMenuItemVecDestructorEnumWrapper {
@@ -9095,6 +9605,18 @@ pub struct AzImePositionEnumWrapper {
     pub inner: AzImePosition,
 }
 
+/// `AzShortcutScopeEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzShortcutScopeEnumWrapper {
+    pub inner: AzShortcutScope,
+}
+
+/// `AzShortcutErrorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzShortcutErrorEnumWrapper {
+    pub inner: AzShortcutError,
+}
+
 /// `AzPositionInfoEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzPositionInfoEnumWrapper {
@@ -9335,6 +9857,24 @@ pub struct AzLayoutOverflowValueEnumWrapper {
     pub inner: AzLayoutOverflowValue,
 }
 
+/// `AzStyleScrollBehaviorValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleScrollBehaviorValueEnumWrapper {
+    pub inner: AzStyleScrollBehaviorValue,
+}
+
+/// `AzStyleScrollSnapTypeValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleScrollSnapTypeValueEnumWrapper {
+    pub inner: AzStyleScrollSnapTypeValue,
+}
+
+/// `AzStyleScrollSnapAlignValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleScrollSnapAlignValueEnumWrapper {
+    pub inner: AzStyleScrollSnapAlignValue,
+}
+
 /// `AzStyleBorderBottomColorValueEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzStyleBorderBottomColorValueEnumWrapper {
@@ -9431,12 +9971,6 @@ pub struct AzLayoutBorderTopWidthValueEnumWrapper {
     pub inner: AzLayoutBorderTopWidthValue,
 }
 
-/// `AzStyleCursorValueEnumWrapper` struct
-#[repr(transparent)]
-pub struct AzStyleCursorValueEnumWrapper {
-    pub inner: AzStyleCursorValue,
-}
-
 /// `AzStyleFontSizeValueEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzStyleFontSizeValueEnumWrapper {
@@ -9713,6 +10247,18 @@ pub struct AzOptionImageRefEnumWrapper {
     pub inner: AzOptionImageRef,
 }
 
+/// `AzOptionWindowIdEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionWindowIdEnumWrapper {
+    pub inner: AzOptionWindowId,
+}
+
+/// `AzOptionCustomCursorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionCustomCursorEnumWrapper {
+    pub inner: AzOptionCustomCursor,
+}
+
 /// `AzOptionFontRefEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzOptionFontRefEnumWrapper {
@@ -9773,6 +10319,12 @@ pub struct AzOptionRefAnyEnumWrapper {
     pub inner: AzOptionRefAny,
 }
 
+/// `AzOptionScrollPositionEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionScrollPositionEnumWrapper {
+    pub inner: AzOptionScrollPosition,
+}
+
 /// `AzOptionLayoutPointEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzOptionLayoutPointEnumWrapper {
@@ -9815,6 +10367,12 @@ pub struct AzOptionSvgDashPatternEnumWrapper {
     pub inner: AzOptionSvgDashPattern,
 }
 
+/// `AzOptionGestureStateEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionGestureStateEnumWrapper {
+    pub inner: AzOptionGestureState,
+}
+
 /// `AzOptionLogicalPositionEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzOptionLogicalPositionEnumWrapper {
@@ -9887,6 +10445,12 @@ pub struct AzResultU8VecEncodeImageErrorEnumWrapper {
     pub inner: AzResultU8VecEncodeImageError,
 }
 
+/// `AzResultShortcutIdShortcutErrorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzResultShortcutIdShortcutErrorEnumWrapper {
+    pub inner: AzResultShortcutIdShortcutError,
+}
+
 /// `AzWindowIconEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzWindowIconEnumWrapper {
@@ -9989,6 +10553,12 @@ pub struct AzOptionVirtualKeyCodeComboEnumWrapper {
     pub inner: AzOptionVirtualKeyCodeCombo,
 }
 
+/// `AzOptionWindowResultCallbackEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionWindowResultCallbackEnumWrapper {
+    pub inner: AzOptionWindowResultCallback,
+}
+
 /// `AzOptionMouseStateEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzOptionMouseStateEnumWrapper {
@@ -10145,6 +10715,12 @@ pub struct AzOptionWaylandThemeEnumWrapper {
     pub inner: AzOptionWaylandTheme,
 }
 
+/// `AzOptionTrayIconEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionTrayIconEnumWrapper {
+    pub inner: AzOptionTrayIcon,
+}
+
 /// `AzResultRawImageDecodeImageErrorEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzResultRawImageDecodeImageErrorEnumWrapper {
@@ -10163,12 +10739,24 @@ pub struct AzMenuItemEnumWrapper {
     pub inner: AzMenuItem,
 }
 
+/// `AzStyleCursorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleCursorEnumWrapper {
+    pub inner: AzStyleCursor,
+}
+
 /// `AzStyleBackgroundContentVecValueEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzStyleBackgroundContentVecValueEnumWrapper {
     pub inner: AzStyleBackgroundContentVecValue,
 }
 
+/// `AzStyleCursorValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleCursorValueEnumWrapper {
+    pub inner: AzStyleCursorValue,
+}
+
 /// `AzStyleFontFamilyVecValueEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzStyleFontFamilyVecValueEnumWrapper {
@@ -10328,7 +10916,9 @@ unsafe impl Send for AzInputOutputTypeIdVec { }
 unsafe impl Send for AzOutputNodeAndIndexVec { }
 unsafe impl Send for AzInputNodeAndIndexVec { }
 unsafe impl Send for AzAccessibilityStateVec { }
+unsafe impl Send for AzTouchPointVec { }
 unsafe impl Send for AzMenuItemVec { }
+unsafe impl Send for AzStyleCursorVec { }
 unsafe impl Send for AzXmlNodeVec { }
 unsafe impl Send for AzInlineGlyphVec { }
 unsafe impl Send for AzInlineTextHitVec { }
@@ -10363,6 +10953,7 @@ unsafe impl Send for AzListViewRowVec { }
 unsafe impl Send for AzStyleFilterVec { }
 unsafe impl Send for AzInputConnectionVec { }
 unsafe impl Send for AzOutputConnectionVec { }
+unsafe impl Send for AzVirtualKeyCodeComboVec { }
 unsafe impl Send for AzTessellatedSvgNodeVec { }
 unsafe impl Send for AzTessellatedColoredSvgNodeVec { }
 unsafe impl Send for AzStyleTransformVec { }
@@ -10372,6 +10963,8 @@ unsafe impl Send for AzStyledNodeVec { }
 unsafe impl Send for AzTagIdToNodeIdMappingVec { }
 unsafe impl Send for AzWaylandTheme { }
 unsafe impl Send for AzFile { }
+unsafe impl Send for AzShortcutBindingVec { }
+unsafe impl Send for AzNotificationActionVec { }
 unsafe impl Send for AzStyleFontFamilyVec { }
 unsafe impl Send for AzFmtArgVec { }
 unsafe impl Send for AzInlineWordVec { }
@@ -10408,6 +11001,7 @@ unsafe impl Send for AzStylesheetVec { }
 impl Clone for AzApp { fn clone(&self) -> Self { let r: &azul_impl::app::AzAppPtr = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzAppLogLevelEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::resources::AppLogLevel = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutSolverEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::resources::LayoutSolverVersion = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzWindowId { fn clone(&self) -> Self { let r: &azul_core::window::WindowId = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzVsyncEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::Vsync = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSrgbEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::Srgb = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzHwAccelerationEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::HwAcceleration = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10436,7 +11030,9 @@ impl Clone for AzMacWindowOptions { fn clone(&self) -> Self { let r: &azul_core:
 impl Clone for AzWasmWindowOptions { fn clone(&self) -> Self { let r: &azul_core::window::WasmWindowOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzFullScreenModeEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::FullScreenMode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzWindowThemeEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::WindowTheme = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
-impl Clone for AzTouchState { fn clone(&self) -> Self { let r: &azul_core::window::TouchState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTouchPointKindEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::TouchPointKind = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzNotificationUrgencyEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::NotificationUrgency = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzShortcutId { fn clone(&self) -> Self { let r: &azul_core::window::ShortcutId = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMarshaledLayoutCallbackInner { fn clone(&self) -> Self { let r: &azul_impl::callbacks::MarshaledLayoutCallbackInner = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutCallbackInner { fn clone(&self) -> Self { let r: &azul_impl::callbacks::LayoutCallbackInner = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCallback { fn clone(&self) -> Self { let r: &azul_impl::callbacks::Callback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10445,6 +11041,7 @@ impl Clone for AzUpdateEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl
 impl Clone for AzNodeId { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::NodeHierarchyItemId = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDomId { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::DomId = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzPositionInfoInner { fn clone(&self) -> Self { let r: &azul_impl::ui_solver::PositionInfoInner = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzScrollIntoViewAlignEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::scroll::ScrollIntoViewAlign = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzAnimationRepeatEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::AnimationRepeat = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzAnimationRepeatCountEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::AnimationRepeatCount = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzIFrameCallback { fn clone(&self) -> Self { let r: &azul_impl::callbacks::IFrameCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10490,7 +11087,10 @@ impl Clone for AzShapeEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl:
 impl Clone for AzRadialGradientSizeEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::RadialGradientSize = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackgroundRepeatEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundRepeat = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzBorderStyleEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::BorderStyle = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
-impl Clone for AzStyleCursorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleCursor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleScrollBehaviorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleScrollBehavior = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzScrollSnapAxisEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::ScrollSnapAxis = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzScrollSnapStrictnessEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::ScrollSnapStrictness = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleScrollSnapAlignEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleScrollSnapAlign = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackfaceVisibilityEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackfaceVisibility = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTextAlignEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTextAlign = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzRibbon { fn clone(&self) -> Self { let r: &crate::widgets::ribbon::Ribbon = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10606,6 +11206,7 @@ impl Clone for AzThreadSendFn { fn clone(&self) -> Self { let r: &azul_impl::tas
 impl Clone for AzThreadDestructorFn { fn clone(&self) -> Self { let r: &azul_impl::task::ThreadDestructorCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzThreadReceiverDestructorFn { fn clone(&self) -> Self { let r: &azul_impl::task::ThreadReceiverDestructorCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzThreadSenderDestructorFn { fn clone(&self) -> Self { let r: &azul_impl::task::ThreadSenderDestructorCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleCursorVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleCursorVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleFontFamilyVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleFontFamilyVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzListViewRowVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::list_view::ListViewRowVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleFilterVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleFilterVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10620,6 +11221,10 @@ impl Clone for AzOutputNodeAndIndexVecDestructorEnumWrapper { fn clone(&self) ->
 impl Clone for AzOutputConnectionVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OutputConnectionVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInputNodeAndIndexVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::InputNodeAndIndexVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzAccessibilityStateVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::AccessibilityStateVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzVirtualKeyCodeComboVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::VirtualKeyCodeComboVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzShortcutBindingVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::ShortcutBindingVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTouchPointVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::TouchPointVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzNotificationActionVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::NotificationActionVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMenuItemVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::MenuItemVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTessellatedSvgNodeVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::svg::TessellatedSvgNodeVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTessellatedColoredSvgNodeVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::svg::TessellatedColoredSvgNodeVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10693,9 +11298,15 @@ impl Clone for AzWindowFlags { fn clone(&self) -> Self { let r: &azul_core::wind
 impl Clone for AzCursorPositionEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::CursorPosition = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzWindowPositionEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::WindowPosition = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzImePositionEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::ImePosition = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTouchPoint { fn clone(&self) -> Self { let r: &azul_core::window::TouchPoint = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzGestureState { fn clone(&self) -> Self { let r: &azul_core::window::GestureState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzVideoMode { fn clone(&self) -> Self { let r: &azul_core::window::VideoMode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCustomCursor { fn clone(&self) -> Self { let r: &azul_core::window::CustomCursor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzShortcutScopeEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::ShortcutScope = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzShortcutErrorEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::ShortcutError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDomNodeId { fn clone(&self) -> Self { let r: &azul_impl::callbacks::DomNodeId = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzPositionInfoEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::ui_solver::PositionInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzScrollPosition { fn clone(&self) -> Self { let r: &azul_impl::callbacks::ScrollPosition = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzHidpiAdjustedBounds { fn clone(&self) -> Self { let r: &azul_impl::callbacks::HidpiAdjustedBounds = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineGlyph { fn clone(&self) -> Self { let r: &azul_core::callbacks::InlineGlyph = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineTextHit { fn clone(&self) -> Self { let r: &azul_core::callbacks::InlineTextHit = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10760,6 +11371,7 @@ impl Clone for AzStyleBorderTopLeftRadius { fn clone(&self) -> Self { let r: &az
 impl Clone for AzStyleBorderTopRightRadius { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBorderTopRightRadius = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBorderTopStyle { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBorderTopStyle = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutBorderTopWidth { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutBorderTopWidth = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleScrollSnapType { fn clone(&self) -> Self { let r: &azul_impl::css::StyleScrollSnapType = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleFontSize { fn clone(&self) -> Self { let r: &azul_impl::css::StyleFontSize = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleLetterSpacing { fn clone(&self) -> Self { let r: &azul_impl::css::StyleLetterSpacing = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleLineHeight { fn clone(&self) -> Self { let r: &azul_impl::css::StyleLineHeight = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10808,6 +11420,9 @@ impl Clone for AzLayoutTopValueEnumWrapper { fn clone(&self) -> Self { let r: &a
 impl Clone for AzLayoutWidthValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutWidthValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutFlexWrapValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutFlexWrapValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutOverflowValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutOverflowValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleScrollBehaviorValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleScrollBehaviorValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleScrollSnapTypeValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleScrollSnapTypeValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleScrollSnapAlignValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleScrollSnapAlignValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBorderBottomColorValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBorderBottomColorValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBorderBottomLeftRadiusValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBorderBottomLeftRadiusValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBorderBottomRightRadiusValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBorderBottomRightRadiusValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10824,7 +11439,6 @@ impl Clone for AzStyleBorderTopLeftRadiusValueEnumWrapper { fn clone(&self) -> S
 impl Clone for AzStyleBorderTopRightRadiusValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBorderTopRightRadiusValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBorderTopStyleValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBorderTopStyleValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutBorderTopWidthValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutBorderTopWidthValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
-impl Clone for AzStyleCursorValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleCursorValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleFontSizeValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleFontSizeValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleLetterSpacingValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleLetterSpacingValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleLineHeightValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleLineHeightValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10883,7 +11497,9 @@ impl Clone for AzInputOutputTypeIdVec { fn clone(&self) -> Self { let r: &crate:
 impl Clone for AzOutputNodeAndIndexVec { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OutputNodeAndIndexVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInputNodeAndIndexVec { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::InputNodeAndIndexVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzAccessibilityStateVec { fn clone(&self) -> Self { let r: &azul_impl::dom::AccessibilityStateVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTouchPointVec { fn clone(&self) -> Self { let r: &azul_core::window::TouchPointVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMenuItemVec { fn clone(&self) -> Self { let r: &azul_core::window::MenuItemVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleCursorVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleCursorVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzXmlNodeVec { fn clone(&self) -> Self { let r: &azul_impl::xml::XmlNodeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineGlyphVec { fn clone(&self) -> Self { let r: &azul_impl::callbacks::InlineGlyphVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineTextHitVec { fn clone(&self) -> Self { let r: &azul_impl::callbacks::InlineTextHitVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10940,6 +11556,8 @@ impl Clone for AzOptionPositionInfoEnumWrapper { fn clone(&self) -> Self { let r
 impl Clone for AzOptionTimerIdEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::task::OptionTimerId = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionThreadIdEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::task::OptionThreadId = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionImageRefEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::resources::OptionImageRef = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionWindowIdEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionWindowId = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionCustomCursorEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionCustomCursor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionFontRefEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::OptionFontRef = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionSystemClipboardEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::app::OptionClipboard = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionGlEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::gl::OptionGlContextPtr = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10950,6 +11568,7 @@ impl Clone for AzOptionCallbackEnumWrapper { fn clone(&self) -> Self { let r: &a
 impl Clone for AzOptionThreadSendMsgEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::task::OptionThreadSendMsg = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionLayoutRectEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::OptionLayoutRect = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionRefAnyEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::OptionRefAny = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionScrollPositionEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::OptionScrollPosition = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionLayoutPointEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::OptionLayoutPoint = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionLayoutSizeEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::OptionLayoutSize = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionWindowThemeEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionWindowTheme = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10957,6 +11576,7 @@ impl Clone for AzOptionNodeIdEnumWrapper { fn clone(&self) -> Self { let r: &azu
 impl Clone for AzOptionDomNodeIdEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::OptionDomNodeId = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionColorUEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::OptionColorU = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionSvgDashPatternEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::svg::OptionSvgDashPattern = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionGestureStateEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionGestureState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionLogicalPositionEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionLogicalPosition = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionPhysicalPositionI32EnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionPhysicalPositionI32 = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionMouseCursorTypeEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionMouseCursorType = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10969,12 +11589,14 @@ impl Clone for AzOptionDurationEnumWrapper { fn clone(&self) -> Self { let r: &a
 impl Clone for AzOptionU8VecEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::OptionU8Vec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionU8VecRefEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::gl::OptionU8VecRef = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzResultU8VecEncodeImageErrorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::resources::encode::ResultU8VecEncodeImageError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzResultShortcutIdShortcutErrorEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::ResultShortcutIdShortcutError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNonXmlCharError { fn clone(&self) -> Self { let r: &azul_impl::xml::NonXmlCharError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInvalidCharError { fn clone(&self) -> Self { let r: &azul_impl::xml::InvalidCharError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInvalidCharMultipleError { fn clone(&self) -> Self { let r: &azul_impl::xml::InvalidCharMultipleError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInvalidQuoteError { fn clone(&self) -> Self { let r: &azul_impl::xml::InvalidQuoteError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInvalidSpaceError { fn clone(&self) -> Self { let r: &azul_impl::xml::InvalidSpaceError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzAppConfig { fn clone(&self) -> Self { let r: &azul_impl::resources::AppConfig = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzWindowResultCallback { fn clone(&self) -> Self { let r: &azul_core::window::WindowResultCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSmallWindowIconBytes { fn clone(&self) -> Self { let r: &azul_core::window::SmallWindowIconBytes = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLargeWindowIconBytes { fn clone(&self) -> Self { let r: &azul_core::window::LargeWindowIconBytes = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzWindowIconEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::WindowIcon = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10982,6 +11604,7 @@ impl Clone for AzTaskBarIcon { fn clone(&self) -> Self { let r: &azul_core::wind
 impl Clone for AzWindowSize { fn clone(&self) -> Self { let r: &azul_core::window::WindowSize = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzKeyboardState { fn clone(&self) -> Self { let r: &azul_core::window::KeyboardState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMouseState { fn clone(&self) -> Self { let r: &azul_core::window::MouseState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTouchState { fn clone(&self) -> Self { let r: &azul_core::window::TouchState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMarshaledLayoutCallback { fn clone(&self) -> Self { let r: &azul_impl::callbacks::MarshaledLayoutCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineTextContents { fn clone(&self) -> Self { let r: &azul_core::callbacks::InlineTextContents = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzResolvedTextLayoutOptions { fn clone(&self) -> Self { let r: &azul_impl::ui_solver::ResolvedTextLayoutOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11029,6 +11652,7 @@ impl Clone for AzListViewRowVec { fn clone(&self) -> Self { let r: &crate::widge
 impl Clone for AzStyleFilterVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleFilterVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInputConnectionVec { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::InputConnectionVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOutputConnectionVec { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OutputConnectionVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzVirtualKeyCodeComboVec { fn clone(&self) -> Self { let r: &azul_core::window::VirtualKeyCodeComboVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTessellatedSvgNodeVec { fn clone(&self) -> Self { let r: &azul_impl::svg::TessellatedSvgNodeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTessellatedColoredSvgNodeVec { fn clone(&self) -> Self { let r: &azul_impl::svg::TessellatedColoredSvgNodeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTransformVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11039,6 +11663,7 @@ impl Clone for AzTagIdToNodeIdMappingVec { fn clone(&self) -> Self { let r: &azu
 impl Clone for AzOptionMenuEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionMenu = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionResolvedTextLayoutOptionsEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::ui_solver::OptionResolvedTextLayoutOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionVirtualKeyCodeComboEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionVirtualKeyCodeCombo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionWindowResultCallbackEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionWindowResultCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionMouseStateEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionMouseState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionKeyboardStateEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionKeyboardState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionStringVecEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::OptionStringVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11054,10 +11679,14 @@ impl Clone for AzUnexpectedCloseTagError { fn clone(&self) -> Self { let r: &azu
 impl Clone for AzUnknownEntityReferenceError { fn clone(&self) -> Self { let r: &azul_impl::xml::UnknownEntityReferenceError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDuplicatedAttributeError { fn clone(&self) -> Self { let r: &azul_impl::xml::DuplicatedAttributeError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInvalidStringError { fn clone(&self) -> Self { let r: &azul_impl::xml::InvalidStringError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTrayIcon { fn clone(&self) -> Self { let r: &azul_core::window::TrayIcon = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzWindowsWindowOptions { fn clone(&self) -> Self { let r: &azul_core::window::WindowsWindowOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzWaylandTheme { fn clone(&self) -> Self { let r: &azul_core::window::WaylandTheme = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStringPair { fn clone(&self) -> Self { let r: &azul_core::window::AzStringPair = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMonitor { fn clone(&self) -> Self { let r: &azul_core::window::Monitor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzNotificationAction { fn clone(&self) -> Self { let r: &azul_core::window::NotificationAction = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzShortcut { fn clone(&self) -> Self { let r: &azul_core::window::Shortcut = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzShortcutBinding { fn clone(&self) -> Self { let r: &azul_core::window::ShortcutBinding = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutCallbackEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::LayoutCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineWordEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::callbacks::InlineWord = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCallbackData { fn clone(&self) -> Self { let r: &azul_impl::dom::CallbackData = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11069,6 +11698,7 @@ impl Clone for AzCssPathSelectorEnumWrapper { fn clone(&self) -> Self { let r: &
 impl Clone for AzStyleBackgroundContentEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundContent = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzScrollbarInfo { fn clone(&self) -> Self { let r: &azul_impl::css::ScrollbarInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzScrollbarStyle { fn clone(&self) -> Self { let r: &azul_impl::css::ScrollbarStyle = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleCursorImage { fn clone(&self) -> Self { let r: &azul_impl::css::StyleCursorImage = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleFontFamilyEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleFontFamily = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzScrollbarStyleValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::ScrollbarStyleValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTransformVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11097,6 +11727,8 @@ impl Clone for AzFileTypeList { fn clone(&self) -> Self { let r: &azul_impl::dia
 impl Clone for AzTimer { fn clone(&self) -> Self { let r: &azul_impl::task::Timer = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzFmtValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::str::FmtValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzFmtArg { fn clone(&self) -> Self { let r: &azul_impl::str::FmtArg = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzShortcutBindingVec { fn clone(&self) -> Self { let r: &azul_core::window::ShortcutBindingVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzNotificationActionVec { fn clone(&self) -> Self { let r: &azul_core::window::NotificationActionVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleFontFamilyVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleFontFamilyVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzFmtArgVec { fn clone(&self) -> Self { let r: &azul_impl::str::FmtArgVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineWordVec { fn clone(&self) -> Self { let r: &azul_impl::callbacks::InlineWordVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11113,13 +11745,17 @@ impl Clone for AzOptionFileTypeListEnumWrapper { fn clone(&self) -> Self { let r
 impl Clone for AzOptionFileEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::file::OptionFile = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionRawImageEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::resources::OptionRawImage = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionWaylandThemeEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionWaylandTheme = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionTrayIconEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionTrayIcon = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzResultRawImageDecodeImageErrorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::resources::decode::ResultRawImageDecodeImageError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzXmlStreamErrorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::xml::XmlStreamError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLinuxWindowOptions { fn clone(&self) -> Self { let r: &azul_core::window::LinuxWindowOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzNotification { fn clone(&self) -> Self { let r: &azul_core::window::Notification = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineLine { fn clone(&self) -> Self { let r: &azul_impl::callbacks::InlineLine = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMenuItemEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::MenuItem = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssPath { fn clone(&self) -> Self { let r: &azul_impl::css::CssPath = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleCursorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleCursor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackgroundContentVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundContentVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleCursorValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleCursorValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleFontFamilyVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleFontFamilyVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssPropertyEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssProperty = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzFileInputStateWrapper { fn clone(&self) -> Self { let r: &crate::widgets::file_input::FileInputStateWrapper = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11217,7 +11853,9 @@ impl Drop for AzInputOutputTypeIdVec { fn drop(&mut self) { crate::AzInputOutput
 impl Drop for AzOutputNodeAndIndexVec { fn drop(&mut self) { crate::AzOutputNodeAndIndexVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzInputNodeAndIndexVec { fn drop(&mut self) { crate::AzInputNodeAndIndexVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzAccessibilityStateVec { fn drop(&mut self) { crate::AzAccessibilityStateVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzTouchPointVec { fn drop(&mut self) { crate::AzTouchPointVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzMenuItemVec { fn drop(&mut self) { crate::AzMenuItemVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzStyleCursorVec { fn drop(&mut self) { crate::AzStyleCursorVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzXmlNodeVec { fn drop(&mut self) { crate::AzXmlNodeVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzInlineGlyphVec { fn drop(&mut self) { crate::AzInlineGlyphVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzInlineTextHitVec { fn drop(&mut self) { crate::AzInlineTextHitVec_delete(unsafe { mem::transmute(self) }); } }
@@ -11248,6 +11886,7 @@ impl Drop for AzListViewRowVec { fn drop(&mut self) { crate::AzListViewRowVec_de
 impl Drop for AzStyleFilterVec { fn drop(&mut self) { crate::AzStyleFilterVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzInputConnectionVec { fn drop(&mut self) { crate::AzInputConnectionVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzOutputConnectionVec { fn drop(&mut self) { crate::AzOutputConnectionVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzVirtualKeyCodeComboVec { fn drop(&mut self) { crate::AzVirtualKeyCodeComboVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzTessellatedSvgNodeVec { fn drop(&mut self) { crate::AzTessellatedSvgNodeVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzTessellatedColoredSvgNodeVec { fn drop(&mut self) { crate::AzTessellatedColoredSvgNodeVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzStyleTransformVec { fn drop(&mut self) { crate::AzStyleTransformVec_delete(unsafe { mem::transmute(self) }); } }
@@ -11256,6 +11895,8 @@ impl Drop for AzStringVec { fn drop(&mut self) { crate::AzStringVec_delete(unsaf
 impl Drop for AzStyledNodeVec { fn drop(&mut self) { crate::AzStyledNodeVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzTagIdToNodeIdMappingVec { fn drop(&mut self) { crate::AzTagIdToNodeIdMappingVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzFile { fn drop(&mut self) { crate::AzFile_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzShortcutBindingVec { fn drop(&mut self) { crate::AzShortcutBindingVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzNotificationActionVec { fn drop(&mut self) { crate::AzNotificationActionVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzStyleFontFamilyVec { fn drop(&mut self) { crate::AzStyleFontFamilyVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzFmtArgVec { fn drop(&mut self) { crate::AzFmtArgVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzInlineWordVec { fn drop(&mut self) { crate::AzInlineWordVec_delete(unsafe { mem::transmute(self) }); } }
@@ -11306,6 +11947,28 @@ impl AzApp {
             mem::transmute(self),
         )) }
     }
+    fn register_shortcut(&mut self, shortcut: AzShortcut) -> Result<AzShortcutId, PyErr> {
+        let m: AzResultShortcutIdShortcutError = unsafe { mem::transmute(crate::AzApp_registerShortcut(
+            mem::transmute(self),
+            mem::transmute(shortcut),
+        )) };
+        match m {
+            AzResultShortcutIdShortcutError::Ok(o) => Ok(o.into()),
+            AzResultShortcutIdShortcutError::Err(e) => Err(e.into()),
+        }
+
+    }
+    fn unregister_shortcut(&mut self, shortcut_id: AzShortcutId) -> bool {
+        unsafe { mem::transmute(crate::AzApp_unregisterShortcut(
+            mem::transmute(self),
+            mem::transmute(shortcut_id),
+        )) }
+    }
+    fn get_shortcuts(&self) -> AzShortcutBindingVec {
+        unsafe { mem::transmute(crate::AzApp_getShortcuts(
+            mem::transmute(self),
+        )) }
+    }
     fn run(&self, window: AzWindowCreateOptions) -> () {
         unsafe { mem::transmute(crate::AzApp_run(
             mem::transmute(self),
@@ -11459,6 +12122,41 @@ impl PyObjectProtocol for AzWindowCreateOptions {
     }
 }
 
+#[pymethods]
+impl AzWindowId {
+    #[new]
+    fn __new__(id: usize) -> Self {
+        Self {
+            id,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzWindowId {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::WindowId = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::WindowId = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzWindowResultCallback {
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzWindowResultCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::WindowResultCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::WindowResultCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzRendererOptions {
     #[new]
@@ -12072,6 +12770,29 @@ impl PyObjectProtocol for AzWindowIconEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzTrayIcon {
+    #[new]
+    fn new(icon: AzWindowIconEnumWrapper, tooltip: String, menu: AzMenu) -> AzTrayIcon {
+        let tooltip = pystring_to_azstring(&tooltip);
+        unsafe { mem::transmute(crate::AzTrayIcon_new(
+            mem::transmute(icon),
+            mem::transmute(tooltip),
+            mem::transmute(menu),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTrayIcon {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::TrayIcon = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::TrayIcon = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzTaskBarIcon {
     #[new]
@@ -12511,7 +13232,7 @@ impl PyObjectProtocol for AzWindowSize {
 #[pymethods]
 impl AzWindowFlags {
     #[new]
-    fn __new__(frame: AzWindowFrameEnumWrapper, is_about_to_close: bool, has_decorations: bool, is_visible: bool, is_always_on_top: bool, is_resizable: bool, has_focus: bool, has_extended_window_frame: bool, has_blur_behind_window: bool, smooth_scroll_enabled: bool, autotab_enabled: bool) -> Self {
+    fn __new__(frame: AzWindowFrameEnumWrapper, is_about_to_close: bool, has_decorations: bool, is_visible: bool, is_always_on_top: bool, is_resizable: bool, has_focus: bool, has_extended_window_frame: bool, has_blur_behind_window: bool, is_click_through: bool, smooth_scroll_enabled: bool, overscroll_bounce_enabled: bool, scrollbar_auto_hide_enabled: bool, autotab_enabled: bool) -> Self {
         Self {
             frame,
             is_about_to_close,
@@ -12522,7 +13243,10 @@ impl AzWindowFlags {
             has_focus,
             has_extended_window_frame,
             has_blur_behind_window,
+            is_click_through,
             smooth_scroll_enabled,
+            overscroll_bounce_enabled,
+            scrollbar_auto_hide_enabled,
             autotab_enabled,
         }
     }
@@ -12938,7 +13662,7 @@ impl PyObjectProtocol for AzStringPair {
 #[pymethods]
 impl AzLinuxWindowOptions {
     #[new]
-    fn __new__(x11_visual: AzOptionX11VisualEnumWrapper, x11_screen: AzOptionI32EnumWrapper, x11_wm_classes: AzStringPairVec, x11_override_redirect: bool, x11_window_types: AzXWindowTypeVec, x11_gtk_theme_variant: AzOptionStringEnumWrapper, x11_resize_increments: AzOptionLogicalSizeEnumWrapper, x11_base_size: AzOptionLogicalSizeEnumWrapper, wayland_app_id: AzOptionStringEnumWrapper, wayland_theme: AzOptionWaylandThemeEnumWrapper, request_user_attention: bool, window_icon: AzOptionWindowIconEnumWrapper) -> Self {
+    fn __new__(x11_visual: AzOptionX11VisualEnumWrapper, x11_screen: AzOptionI32EnumWrapper, x11_wm_classes: AzStringPairVec, x11_override_redirect: bool, x11_window_types: AzXWindowTypeVec, x11_gtk_theme_variant: AzOptionStringEnumWrapper, x11_resize_increments: AzOptionLogicalSizeEnumWrapper, x11_base_size: AzOptionLogicalSizeEnumWrapper, wayland_app_id: AzOptionStringEnumWrapper, wayland_theme: AzOptionWaylandThemeEnumWrapper, request_user_attention: bool, window_icon: AzOptionWindowIconEnumWrapper, tray_icon: AzOptionTrayIconEnumWrapper) -> Self {
         Self {
             x11_visual,
             x11_screen,
@@ -12952,6 +13676,7 @@ impl AzLinuxWindowOptions {
             wayland_theme,
             request_user_attention,
             window_icon,
+            tray_icon,
         }
     }
 
@@ -13127,12 +13852,99 @@ impl PyObjectProtocol for AzImePositionEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzTouchPointKindEnumWrapper {
+    #[classattr]
+    fn Finger() -> AzTouchPointKindEnumWrapper { AzTouchPointKindEnumWrapper { inner: AzTouchPointKind::Finger } }
+    #[classattr]
+    fn Pen() -> AzTouchPointKindEnumWrapper { AzTouchPointKindEnumWrapper { inner: AzTouchPointKind::Pen } }
+    #[classattr]
+    fn Eraser() -> AzTouchPointKindEnumWrapper { AzTouchPointKindEnumWrapper { inner: AzTouchPointKind::Eraser } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTouchPointKindEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::TouchPointKind = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::TouchPointKind = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzTouchPointKindEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzTouchPoint {
+    #[new]
+    fn __new__(id: u64, kind: AzTouchPointKindEnumWrapper, position: AzLogicalPosition, pressure: f32, tilt_x: f32, tilt_y: f32) -> Self {
+        Self {
+            id,
+            kind,
+            position,
+            pressure,
+            tilt_x,
+            tilt_y,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTouchPoint {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::TouchPoint = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::TouchPoint = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzGestureState {
+    #[new]
+    fn __new__(center: AzLogicalPosition, scale: f32, rotation: f32, translation: AzLogicalPosition, is_pinch: bool, is_rotate: bool, is_pan: bool) -> Self {
+        Self {
+            center,
+            scale,
+            rotation,
+            translation,
+            is_pinch,
+            is_rotate,
+            is_pan,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzGestureState {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::GestureState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::GestureState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzTouchState {
     #[new]
-    fn __new__(unused: u8) -> Self {
+    fn __new__(touch_points: AzTouchPointVec, ended_touch_points: AzTouchPointVec, cancelled_touch_points: AzTouchPointVec, gesture: AzOptionGestureStateEnumWrapper, long_press: AzOptionLogicalPositionEnumWrapper) -> Self {
         Self {
-            unused,
+            touch_points,
+            ended_touch_points,
+            cancelled_touch_points,
+            gesture,
+            long_press,
         }
     }
 
@@ -13226,6 +14038,241 @@ impl PyObjectProtocol for AzWindowState {
     }
 }
 
+#[pymethods]
+impl AzCustomCursor {
+    #[new]
+    fn new(image: AzImageRef, hotspot_x: u32, hotspot_y: u32) -> AzCustomCursor {
+        unsafe { mem::transmute(crate::AzCustomCursor_new(
+            mem::transmute(image),
+            mem::transmute(hotspot_x),
+            mem::transmute(hotspot_y),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCustomCursor {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::CustomCursor = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::CustomCursor = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzNotification {
+    #[new]
+    fn new(title: String, body: String) -> AzNotification {
+        let title = pystring_to_azstring(&title);
+        let body = pystring_to_azstring(&body);
+        unsafe { mem::transmute(crate::AzNotification_new(
+            mem::transmute(title),
+            mem::transmute(body),
+        )) }
+    }
+    fn with_icon(self, icon: AzImageRef) -> AzNotification {
+        unsafe { mem::transmute(crate::AzNotification_withIcon(
+            mem::transmute(self),
+            mem::transmute(icon),
+        )) }
+    }
+    fn with_urgency(self, urgency: AzNotificationUrgencyEnumWrapper) -> AzNotification {
+        unsafe { mem::transmute(crate::AzNotification_withUrgency(
+            mem::transmute(self),
+            mem::transmute(urgency),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzNotification {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::Notification = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::Notification = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzNotificationAction {
+    #[new]
+    fn __new__(label: AzString, callback: AzMenuCallback) -> Self {
+        Self {
+            label,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzNotificationAction {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::NotificationAction = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::NotificationAction = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzNotificationUrgencyEnumWrapper {
+    #[classattr]
+    fn Low() -> AzNotificationUrgencyEnumWrapper { AzNotificationUrgencyEnumWrapper { inner: AzNotificationUrgency::Low } }
+    #[classattr]
+    fn Normal() -> AzNotificationUrgencyEnumWrapper { AzNotificationUrgencyEnumWrapper { inner: AzNotificationUrgency::Normal } }
+    #[classattr]
+    fn Critical() -> AzNotificationUrgencyEnumWrapper { AzNotificationUrgencyEnumWrapper { inner: AzNotificationUrgency::Critical } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzNotificationUrgencyEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::NotificationUrgency = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::NotificationUrgency = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzNotificationUrgencyEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzShortcutId {
+    #[new]
+    fn __new__(id: usize) -> Self {
+        Self {
+            id,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzShortcutId {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ShortcutId = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ShortcutId = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzShortcutScopeEnumWrapper {
+    #[staticmethod]
+    fn Window(v: AzWindowId) -> AzShortcutScopeEnumWrapper { AzShortcutScopeEnumWrapper { inner: AzShortcutScope::Window(v) } }
+    #[classattr]
+    fn App() -> AzShortcutScopeEnumWrapper { AzShortcutScopeEnumWrapper { inner: AzShortcutScope::App } }
+    #[classattr]
+    fn Global() -> AzShortcutScopeEnumWrapper { AzShortcutScopeEnumWrapper { inner: AzShortcutScope::Global } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzShortcutScope;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzShortcutScope::Window(v) => Ok(vec!["Window".into_py(py), v.clone().into_py(py)]),
+            AzShortcutScope::App => Ok(vec!["App".into_py(py), ().into_py(py)]),
+            AzShortcutScope::Global => Ok(vec!["Global".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzShortcutScopeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ShortcutScope = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ShortcutScope = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzShortcut {
+    fn get_key_string(&self) -> String {
+        az_string_to_py_string(unsafe { mem::transmute(crate::AzShortcut_getKeyString(
+            mem::transmute(self),
+        )) })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzShortcut {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::Shortcut = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::Shortcut = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzShortcutBinding {
+    #[new]
+    fn __new__(id: AzShortcutId, shortcut: AzShortcut) -> Self {
+        Self {
+            id,
+            shortcut,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzShortcutBinding {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ShortcutBinding = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ShortcutBinding = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzShortcutErrorEnumWrapper {
+    #[classattr]
+    fn NoKeys() -> AzShortcutErrorEnumWrapper { AzShortcutErrorEnumWrapper { inner: AzShortcutError::NoKeys } }
+    #[classattr]
+    fn GlobalSequence() -> AzShortcutErrorEnumWrapper { AzShortcutErrorEnumWrapper { inner: AzShortcutError::GlobalSequence } }
+    #[staticmethod]
+    fn Conflict(v: AzShortcutId) -> AzShortcutErrorEnumWrapper { AzShortcutErrorEnumWrapper { inner: AzShortcutError::Conflict(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzShortcutError;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzShortcutError::NoKeys => Ok(vec!["NoKeys".into_py(py), ().into_py(py)]),
+            AzShortcutError::GlobalSequence => Ok(vec!["GlobalSequence".into_py(py), ().into_py(py)]),
+            AzShortcutError::Conflict(v) => Ok(vec!["Conflict".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzShortcutErrorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ShortcutError = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ShortcutError = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzLayoutCallbackEnumWrapper {
     #[staticmethod]
@@ -13383,6 +14430,21 @@ impl AzCallbackInfo {
             mem::transmute(self),
         )) }
     }
+    fn get_touch_state(&self) -> AzTouchState {
+        unsafe { mem::transmute(crate::AzCallbackInfo_getTouchState(
+            mem::transmute(self),
+        )) }
+    }
+    fn get_gesture(&self) -> Option<AzGestureState> {
+        let m: AzOptionGestureState = unsafe { mem::transmute(crate::AzCallbackInfo_getGesture(
+            mem::transmute(self),
+        )) };
+        match m {
+            AzOptionGestureState::Some(s) => Some(unsafe { mem::transmute(s) }),
+            AzOptionGestureState::None => None,
+        }
+
+    }
     fn get_current_mouse_state(&self) -> AzMouseState {
         unsafe { mem::transmute(crate::AzCallbackInfo_getCurrentMouseState(
             mem::transmute(self),
@@ -13443,6 +14505,17 @@ impl AzCallbackInfo {
             AzOptionLogicalPosition::None => None,
         }
 
+    }
+    fn get_scroll_frame(&self, node_id: AzDomNodeId) -> Option<AzScrollPosition> {
+        let m: AzOptionScrollPosition = unsafe { mem::transmute(crate::AzCallbackInfo_getScrollFrame(
+            mem::transmute(self),
+            mem::transmute(node_id),
+        )) };
+        match m {
+            AzOptionScrollPosition::Some(s) => Some(unsafe { mem::transmute(s) }),
+            AzOptionScrollPosition::None => None,
+        }
+
     }
     fn get_dataset(&mut self, node_id: AzDomNodeId) -> Option<AzRefAny> {
         let m: AzOptionRefAny = unsafe { mem::transmute(crate::AzCallbackInfo_getDataset(
@@ -13613,6 +14686,19 @@ impl AzCallbackInfo {
             mem::transmute(new_state),
         )) }
     }
+    fn set_custom_cursor(&mut self, image: AzImageRef, hotspot_x: u32, hotspot_y: u32) -> () {
+        unsafe { mem::transmute(crate::AzCallbackInfo_setCustomCursor(
+            mem::transmute(self),
+            mem::transmute(image),
+            mem::transmute(hotspot_x),
+            mem::transmute(hotspot_y),
+        )) }
+    }
+    fn reset_custom_cursor(&mut self) -> () {
+        unsafe { mem::transmute(crate::AzCallbackInfo_resetCustomCursor(
+            mem::transmute(self),
+        )) }
+    }
     fn set_focus(&mut self, target: AzFocusTargetEnumWrapper) -> () {
         unsafe { mem::transmute(crate::AzCallbackInfo_setFocus(
             mem::transmute(self),
@@ -13633,6 +14719,21 @@ impl AzCallbackInfo {
             mem::transmute(scroll_position),
         )) }
     }
+    fn scroll_to(&mut self, node_id: AzDomNodeId, scroll_position: AzLogicalPosition) -> () {
+        unsafe { mem::transmute(crate::AzCallbackInfo_scrollTo(
+            mem::transmute(self),
+            mem::transmute(node_id),
+            mem::transmute(scroll_position),
+        )) }
+    }
+    fn scroll_into_view(&mut self, node_id: AzDomNodeId, align: AzScrollIntoViewAlignEnumWrapper, smooth: bool) -> () {
+        unsafe { mem::transmute(crate::AzCallbackInfo_scrollIntoView(
+            mem::transmute(self),
+            mem::transmute(node_id),
+            mem::transmute(align),
+            mem::transmute(smooth),
+        )) }
+    }
     fn set_string_contents(&mut self, node_id: AzDomNodeId, string: String) -> () {
         let string = pystring_to_azstring(&string);
         unsafe { mem::transmute(crate::AzCallbackInfo_setStringContents(
@@ -13695,12 +14796,36 @@ impl AzCallbackInfo {
             mem::transmute(self),
         )) }
     }
-    fn create_window(&mut self, new_window: AzWindowCreateOptions) -> () {
+    fn create_window(&mut self, new_window: AzWindowCreateOptions) -> AzWindowId {
         unsafe { mem::transmute(crate::AzCallbackInfo_createWindow(
             mem::transmute(self),
             mem::transmute(new_window),
         )) }
     }
+    fn close_window(&mut self, window_id: AzWindowId) -> () {
+        unsafe { mem::transmute(crate::AzCallbackInfo_closeWindow(
+            mem::transmute(self),
+            mem::transmute(window_id),
+        )) }
+    }
+    fn show_notification(&mut self, notification: AzNotification) -> () {
+        unsafe { mem::transmute(crate::AzCallbackInfo_showNotification(
+            mem::transmute(self),
+            mem::transmute(notification),
+        )) }
+    }
+    fn register_shortcut(&mut self, shortcut: AzShortcut) -> AzShortcutId {
+        unsafe { mem::transmute(crate::AzCallbackInfo_registerShortcut(
+            mem::transmute(self),
+            mem::transmute(shortcut),
+        )) }
+    }
+    fn unregister_shortcut(&mut self, shortcut_id: AzShortcutId) -> () {
+        unsafe { mem::transmute(crate::AzCallbackInfo_unregisterShortcut(
+            mem::transmute(self),
+            mem::transmute(shortcut_id),
+        )) }
+    }
     fn start_timer(&mut self, timer: AzTimer) -> AzTimerId {
         unsafe { mem::transmute(crate::AzCallbackInfo_startTimer(
             mem::transmute(self),
@@ -13931,6 +15056,61 @@ impl PyObjectProtocol for AzPositionInfoInner {
     }
 }
 
+#[pymethods]
+impl AzScrollPosition {
+    #[new]
+    fn __new__(parent_rect: AzLogicalRect, children_rect: AzLogicalRect, scroll_offset: AzLogicalPosition) -> Self {
+        Self {
+            parent_rect,
+            children_rect,
+            scroll_offset,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzScrollPosition {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::ScrollPosition = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::ScrollPosition = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzScrollIntoViewAlignEnumWrapper {
+    #[classattr]
+    fn Start() -> AzScrollIntoViewAlignEnumWrapper { AzScrollIntoViewAlignEnumWrapper { inner: AzScrollIntoViewAlign::Start } }
+    #[classattr]
+    fn Center() -> AzScrollIntoViewAlignEnumWrapper { AzScrollIntoViewAlignEnumWrapper { inner: AzScrollIntoViewAlign::Center } }
+    #[classattr]
+    fn End() -> AzScrollIntoViewAlignEnumWrapper { AzScrollIntoViewAlignEnumWrapper { inner: AzScrollIntoViewAlign::End } }
+    #[classattr]
+    fn Nearest() -> AzScrollIntoViewAlignEnumWrapper { AzScrollIntoViewAlignEnumWrapper { inner: AzScrollIntoViewAlign::Nearest } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzScrollIntoViewAlignEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::scroll::ScrollIntoViewAlign = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::scroll::ScrollIntoViewAlign = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzScrollIntoViewAlignEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
 #[pymethods]
 impl AzHidpiAdjustedBounds {
     #[new]
@@ -15518,6 +16698,40 @@ impl AzOnEnumWrapper {
     fn FocusReceived() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::FocusReceived } }
     #[classattr]
     fn FocusLost() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::FocusLost } }
+    #[classattr]
+    fn DragStart() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::DragStart } }
+    #[classattr]
+    fn DragEnter() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::DragEnter } }
+    #[classattr]
+    fn DragOver() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::DragOver } }
+    #[classattr]
+    fn DragLeave() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::DragLeave } }
+    #[classattr]
+    fn Drop() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::Drop } }
+    #[classattr]
+    fn TextCompositionStart() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::TextCompositionStart } }
+    #[classattr]
+    fn TextCompositionUpdate() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::TextCompositionUpdate } }
+    #[classattr]
+    fn TextCompositionEnd() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::TextCompositionEnd } }
+    #[classattr]
+    fn TouchStart() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::TouchStart } }
+    #[classattr]
+    fn TouchMove() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::TouchMove } }
+    #[classattr]
+    fn TouchEnd() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::TouchEnd } }
+    #[classattr]
+    fn TouchCancel() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::TouchCancel } }
+    #[classattr]
+    fn Pinch() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::Pinch } }
+    #[classattr]
+    fn Rotate() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::Rotate } }
+    #[classattr]
+    fn TwoFingerPan() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::TwoFingerPan } }
+    #[classattr]
+    fn LongPress() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::LongPress } }
+    #[classattr]
+    fn Scrolled() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::Scrolled } }
 }
 
 #[pyproto]
@@ -15631,6 +16845,30 @@ impl AzHoverEventFilterEnumWrapper {
     fn TouchEnd() -> AzHoverEventFilterEnumWrapper { AzHoverEventFilterEnumWrapper { inner: AzHoverEventFilter::TouchEnd } }
     #[classattr]
     fn TouchCancel() -> AzHoverEventFilterEnumWrapper { AzHoverEventFilterEnumWrapper { inner: AzHoverEventFilter::TouchCancel } }
+    #[classattr]
+    fn Pinch() -> AzHoverEventFilterEnumWrapper { AzHoverEventFilterEnumWrapper { inner: AzHoverEventFilter::Pinch } }
+    #[classattr]
+    fn Rotate() -> AzHoverEventFilterEnumWrapper { AzHoverEventFilterEnumWrapper { inner: AzHoverEventFilter::Rotate } }
+    #[classattr]
+    fn TwoFingerPan() -> AzHoverEventFilterEnumWrapper { AzHoverEventFilterEnumWrapper { inner: AzHoverEventFilter::TwoFingerPan } }
+    #[classattr]
+    fn LongPress() -> AzHoverEventFilterEnumWrapper { AzHoverEventFilterEnumWrapper { inner: AzHoverEventFilter::LongPress } }
+    #[classattr]
+    fn DragStart() -> AzHoverEventFilterEnumWrapper { AzHoverEventFilterEnumWrapper { inner: AzHoverEventFilter::DragStart } }
+    #[classattr]
+    fn DragEnter() -> AzHoverEventFilterEnumWrapper { AzHoverEventFilterEnumWrapper { inner: AzHoverEventFilter::DragEnter } }
+    #[classattr]
+    fn DragOver() -> AzHoverEventFilterEnumWrapper { AzHoverEventFilterEnumWrapper { inner: AzHoverEventFilter::DragOver } }
+    #[classattr]
+    fn DragLeave() -> AzHoverEventFilterEnumWrapper { AzHoverEventFilterEnumWrapper { inner: AzHoverEventFilter::DragLeave } }
+    #[classattr]
+    fn Drop() -> AzHoverEventFilterEnumWrapper { AzHoverEventFilterEnumWrapper { inner: AzHoverEventFilter::Drop } }
+    #[classattr]
+    fn TextCompositionStart() -> AzHoverEventFilterEnumWrapper { AzHoverEventFilterEnumWrapper { inner: AzHoverEventFilter::TextCompositionStart } }
+    #[classattr]
+    fn TextCompositionUpdate() -> AzHoverEventFilterEnumWrapper { AzHoverEventFilterEnumWrapper { inner: AzHoverEventFilter::TextCompositionUpdate } }
+    #[classattr]
+    fn TextCompositionEnd() -> AzHoverEventFilterEnumWrapper { AzHoverEventFilterEnumWrapper { inner: AzHoverEventFilter::TextCompositionEnd } }
 }
 
 #[pyproto]
@@ -15693,6 +16931,12 @@ impl AzFocusEventFilterEnumWrapper {
     fn FocusReceived() -> AzFocusEventFilterEnumWrapper { AzFocusEventFilterEnumWrapper { inner: AzFocusEventFilter::FocusReceived } }
     #[classattr]
     fn FocusLost() -> AzFocusEventFilterEnumWrapper { AzFocusEventFilterEnumWrapper { inner: AzFocusEventFilter::FocusLost } }
+    #[classattr]
+    fn TextCompositionStart() -> AzFocusEventFilterEnumWrapper { AzFocusEventFilterEnumWrapper { inner: AzFocusEventFilter::TextCompositionStart } }
+    #[classattr]
+    fn TextCompositionUpdate() -> AzFocusEventFilterEnumWrapper { AzFocusEventFilterEnumWrapper { inner: AzFocusEventFilter::TextCompositionUpdate } }
+    #[classattr]
+    fn TextCompositionEnd() -> AzFocusEventFilterEnumWrapper { AzFocusEventFilterEnumWrapper { inner: AzFocusEventFilter::TextCompositionEnd } }
 }
 
 #[pyproto]
@@ -15799,6 +17043,14 @@ impl AzWindowEventFilterEnumWrapper {
     #[classattr]
     fn TouchCancel() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::TouchCancel } }
     #[classattr]
+    fn Pinch() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::Pinch } }
+    #[classattr]
+    fn Rotate() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::Rotate } }
+    #[classattr]
+    fn TwoFingerPan() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::TwoFingerPan } }
+    #[classattr]
+    fn LongPress() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::LongPress } }
+    #[classattr]
     fn FocusReceived() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::FocusReceived } }
     #[classattr]
     fn FocusLost() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::FocusLost } }
@@ -15806,6 +17058,30 @@ impl AzWindowEventFilterEnumWrapper {
     fn CloseRequested() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::CloseRequested } }
     #[classattr]
     fn ThemeChanged() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::ThemeChanged } }
+    #[classattr]
+    fn WindowFocusReceived() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::WindowFocusReceived } }
+    #[classattr]
+    fn WindowFocusLost() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::WindowFocusLost } }
+    #[classattr]
+    fn ClipboardChanged() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::ClipboardChanged } }
+    #[classattr]
+    fn DragStart() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::DragStart } }
+    #[classattr]
+    fn DragEnter() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::DragEnter } }
+    #[classattr]
+    fn DragOver() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::DragOver } }
+    #[classattr]
+    fn DragLeave() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::DragLeave } }
+    #[classattr]
+    fn Drop() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::Drop } }
+    #[classattr]
+    fn DragEnd() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::DragEnd } }
+    #[classattr]
+    fn TextCompositionStart() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::TextCompositionStart } }
+    #[classattr]
+    fn TextCompositionUpdate() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::TextCompositionUpdate } }
+    #[classattr]
+    fn TextCompositionEnd() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::TextCompositionEnd } }
 }
 
 #[pyproto]
@@ -15840,6 +17116,8 @@ impl AzComponentEventFilterEnumWrapper {
     fn DefaultAction() -> AzComponentEventFilterEnumWrapper { AzComponentEventFilterEnumWrapper { inner: AzComponentEventFilter::DefaultAction } }
     #[classattr]
     fn Selected() -> AzComponentEventFilterEnumWrapper { AzComponentEventFilterEnumWrapper { inner: AzComponentEventFilter::Selected } }
+    #[classattr]
+    fn Scrolled() -> AzComponentEventFilterEnumWrapper { AzComponentEventFilterEnumWrapper { inner: AzComponentEventFilter::Scrolled } }
 }
 
 #[pyproto]
@@ -16948,6 +18226,12 @@ impl AzCssPropertyTypeEnumWrapper {
     #[classattr]
     fn ScrollbarStyle() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::ScrollbarStyle } }
     #[classattr]
+    fn ScrollBehavior() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::ScrollBehavior } }
+    #[classattr]
+    fn ScrollSnapType() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::ScrollSnapType } }
+    #[classattr]
+    fn ScrollSnapAlign() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::ScrollSnapAlign } }
+    #[classattr]
     fn Opacity() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::Opacity } }
     #[classattr]
     fn Transform() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::Transform } }
@@ -19176,6 +20460,148 @@ impl PyObjectProtocol for AzScrollbarStyle {
     }
 }
 
+#[pymethods]
+impl AzStyleScrollBehaviorEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleScrollBehaviorEnumWrapper { AzStyleScrollBehaviorEnumWrapper { inner: AzStyleScrollBehavior::Auto } }
+    #[classattr]
+    fn Smooth() -> AzStyleScrollBehaviorEnumWrapper { AzStyleScrollBehaviorEnumWrapper { inner: AzStyleScrollBehavior::Smooth } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleScrollBehaviorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleScrollBehavior = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleScrollBehavior = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzStyleScrollBehaviorEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzStyleScrollSnapType {
+    #[new]
+    fn __new__(axis: AzScrollSnapAxisEnumWrapper, strictness: AzScrollSnapStrictnessEnumWrapper) -> Self {
+        Self {
+            axis,
+            strictness,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleScrollSnapType {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleScrollSnapType = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleScrollSnapType = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzScrollSnapAxisEnumWrapper {
+    #[classattr]
+    fn None() -> AzScrollSnapAxisEnumWrapper { AzScrollSnapAxisEnumWrapper { inner: AzScrollSnapAxis::None } }
+    #[classattr]
+    fn X() -> AzScrollSnapAxisEnumWrapper { AzScrollSnapAxisEnumWrapper { inner: AzScrollSnapAxis::X } }
+    #[classattr]
+    fn Y() -> AzScrollSnapAxisEnumWrapper { AzScrollSnapAxisEnumWrapper { inner: AzScrollSnapAxis::Y } }
+    #[classattr]
+    fn Both() -> AzScrollSnapAxisEnumWrapper { AzScrollSnapAxisEnumWrapper { inner: AzScrollSnapAxis::Both } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzScrollSnapAxisEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::ScrollSnapAxis = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::ScrollSnapAxis = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzScrollSnapAxisEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzScrollSnapStrictnessEnumWrapper {
+    #[classattr]
+    fn Proximity() -> AzScrollSnapStrictnessEnumWrapper { AzScrollSnapStrictnessEnumWrapper { inner: AzScrollSnapStrictness::Proximity } }
+    #[classattr]
+    fn Mandatory() -> AzScrollSnapStrictnessEnumWrapper { AzScrollSnapStrictnessEnumWrapper { inner: AzScrollSnapStrictness::Mandatory } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzScrollSnapStrictnessEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::ScrollSnapStrictness = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::ScrollSnapStrictness = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzScrollSnapStrictnessEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzStyleScrollSnapAlignEnumWrapper {
+    #[classattr]
+    fn None() -> AzStyleScrollSnapAlignEnumWrapper { AzStyleScrollSnapAlignEnumWrapper { inner: AzStyleScrollSnapAlign::None } }
+    #[classattr]
+    fn Start() -> AzStyleScrollSnapAlignEnumWrapper { AzStyleScrollSnapAlignEnumWrapper { inner: AzStyleScrollSnapAlign::Start } }
+    #[classattr]
+    fn Center() -> AzStyleScrollSnapAlignEnumWrapper { AzStyleScrollSnapAlignEnumWrapper { inner: AzStyleScrollSnapAlign::Center } }
+    #[classattr]
+    fn End() -> AzStyleScrollSnapAlignEnumWrapper { AzStyleScrollSnapAlignEnumWrapper { inner: AzStyleScrollSnapAlign::End } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleScrollSnapAlignEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleScrollSnapAlign = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleScrollSnapAlign = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzStyleScrollSnapAlignEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
 #[pymethods]
 impl AzStyleCursorEnumWrapper {
     #[classattr]
@@ -19238,6 +20664,48 @@ impl AzStyleCursorEnumWrapper {
     fn ZoomIn() -> AzStyleCursorEnumWrapper { AzStyleCursorEnumWrapper { inner: AzStyleCursor::ZoomIn } }
     #[classattr]
     fn ZoomOut() -> AzStyleCursorEnumWrapper { AzStyleCursorEnumWrapper { inner: AzStyleCursor::ZoomOut } }
+    #[staticmethod]
+    fn Image(v: AzStyleCursorImage) -> AzStyleCursorEnumWrapper { AzStyleCursorEnumWrapper { inner: AzStyleCursor::Image(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleCursor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleCursor::Alias => Ok(vec!["Alias".into_py(py), ().into_py(py)]),
+            AzStyleCursor::AllScroll => Ok(vec!["AllScroll".into_py(py), ().into_py(py)]),
+            AzStyleCursor::Cell => Ok(vec!["Cell".into_py(py), ().into_py(py)]),
+            AzStyleCursor::ColResize => Ok(vec!["ColResize".into_py(py), ().into_py(py)]),
+            AzStyleCursor::ContextMenu => Ok(vec!["ContextMenu".into_py(py), ().into_py(py)]),
+            AzStyleCursor::Copy => Ok(vec!["Copy".into_py(py), ().into_py(py)]),
+            AzStyleCursor::Crosshair => Ok(vec!["Crosshair".into_py(py), ().into_py(py)]),
+            AzStyleCursor::Default => Ok(vec!["Default".into_py(py), ().into_py(py)]),
+            AzStyleCursor::EResize => Ok(vec!["EResize".into_py(py), ().into_py(py)]),
+            AzStyleCursor::EwResize => Ok(vec!["EwResize".into_py(py), ().into_py(py)]),
+            AzStyleCursor::Grab => Ok(vec!["Grab".into_py(py), ().into_py(py)]),
+            AzStyleCursor::Grabbing => Ok(vec!["Grabbing".into_py(py), ().into_py(py)]),
+            AzStyleCursor::Help => Ok(vec!["Help".into_py(py), ().into_py(py)]),
+            AzStyleCursor::Move => Ok(vec!["Move".into_py(py), ().into_py(py)]),
+            AzStyleCursor::NResize => Ok(vec!["NResize".into_py(py), ().into_py(py)]),
+            AzStyleCursor::NsResize => Ok(vec!["NsResize".into_py(py), ().into_py(py)]),
+            AzStyleCursor::NeswResize => Ok(vec!["NeswResize".into_py(py), ().into_py(py)]),
+            AzStyleCursor::NwseResize => Ok(vec!["NwseResize".into_py(py), ().into_py(py)]),
+            AzStyleCursor::Pointer => Ok(vec!["Pointer".into_py(py), ().into_py(py)]),
+            AzStyleCursor::Progress => Ok(vec!["Progress".into_py(py), ().into_py(py)]),
+            AzStyleCursor::RowResize => Ok(vec!["RowResize".into_py(py), ().into_py(py)]),
+            AzStyleCursor::SResize => Ok(vec!["SResize".into_py(py), ().into_py(py)]),
+            AzStyleCursor::SeResize => Ok(vec!["SeResize".into_py(py), ().into_py(py)]),
+            AzStyleCursor::Text => Ok(vec!["Text".into_py(py), ().into_py(py)]),
+            AzStyleCursor::Unset => Ok(vec!["Unset".into_py(py), ().into_py(py)]),
+            AzStyleCursor::VerticalText => Ok(vec!["VerticalText".into_py(py), ().into_py(py)]),
+            AzStyleCursor::WResize => Ok(vec!["WResize".into_py(py), ().into_py(py)]),
+            AzStyleCursor::Wait => Ok(vec!["Wait".into_py(py), ().into_py(py)]),
+            AzStyleCursor::ZoomIn => Ok(vec!["ZoomIn".into_py(py), ().into_py(py)]),
+            AzStyleCursor::ZoomOut => Ok(vec!["ZoomOut".into_py(py), ().into_py(py)]),
+            AzStyleCursor::Image(v) => Ok(vec!["Image".into_py(py), v.clone().into_py(py)]),
+        }
+    }
 }
 
 #[pyproto]
@@ -19248,16 +20716,30 @@ impl PyObjectProtocol for AzStyleCursorEnumWrapper {
     fn __repr__(&self) -> Result<String, PyErr> { 
         let m: &azul_impl::css::StyleCursor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
     }
-    fn __richcmp__(&self, other: AzStyleCursorEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
-        match op {
-            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
-            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
-            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
-            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
-            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
-            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+}
+
+#[pymethods]
+impl AzStyleCursorImage {
+    #[new]
+    fn __new__(image: AzString, hotspot_x: u32, hotspot_y: u32, fallback: AzStyleCursorVec) -> Self {
+        Self {
+            image,
+            hotspot_x,
+            hotspot_y,
+            fallback,
         }
     }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleCursorImage {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleCursorImage = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleCursorImage = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
 }
 
 #[pymethods]
@@ -21041,6 +22523,120 @@ impl PyObjectProtocol for AzScrollbarStyleValueEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzStyleScrollBehaviorValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleScrollBehaviorValueEnumWrapper { AzStyleScrollBehaviorValueEnumWrapper { inner: AzStyleScrollBehaviorValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleScrollBehaviorValueEnumWrapper { AzStyleScrollBehaviorValueEnumWrapper { inner: AzStyleScrollBehaviorValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleScrollBehaviorValueEnumWrapper { AzStyleScrollBehaviorValueEnumWrapper { inner: AzStyleScrollBehaviorValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleScrollBehaviorValueEnumWrapper { AzStyleScrollBehaviorValueEnumWrapper { inner: AzStyleScrollBehaviorValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleScrollBehaviorEnumWrapper) -> AzStyleScrollBehaviorValueEnumWrapper { AzStyleScrollBehaviorValueEnumWrapper { inner: AzStyleScrollBehaviorValue::Exact(unsafe { mem::transmute(v) }) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleScrollBehaviorValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleScrollBehaviorValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleScrollBehaviorValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleScrollBehaviorValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleScrollBehaviorValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleScrollBehaviorValue::Exact(v) => Ok(vec!["Exact".into_py(py), { let m: &AzStyleScrollBehaviorEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleScrollBehaviorValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleScrollBehaviorValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleScrollBehaviorValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleScrollSnapTypeValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleScrollSnapTypeValueEnumWrapper { AzStyleScrollSnapTypeValueEnumWrapper { inner: AzStyleScrollSnapTypeValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleScrollSnapTypeValueEnumWrapper { AzStyleScrollSnapTypeValueEnumWrapper { inner: AzStyleScrollSnapTypeValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleScrollSnapTypeValueEnumWrapper { AzStyleScrollSnapTypeValueEnumWrapper { inner: AzStyleScrollSnapTypeValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleScrollSnapTypeValueEnumWrapper { AzStyleScrollSnapTypeValueEnumWrapper { inner: AzStyleScrollSnapTypeValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleScrollSnapType) -> AzStyleScrollSnapTypeValueEnumWrapper { AzStyleScrollSnapTypeValueEnumWrapper { inner: AzStyleScrollSnapTypeValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleScrollSnapTypeValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleScrollSnapTypeValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleScrollSnapTypeValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleScrollSnapTypeValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleScrollSnapTypeValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleScrollSnapTypeValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleScrollSnapTypeValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleScrollSnapTypeValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleScrollSnapTypeValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleScrollSnapAlignValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleScrollSnapAlignValueEnumWrapper { AzStyleScrollSnapAlignValueEnumWrapper { inner: AzStyleScrollSnapAlignValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleScrollSnapAlignValueEnumWrapper { AzStyleScrollSnapAlignValueEnumWrapper { inner: AzStyleScrollSnapAlignValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleScrollSnapAlignValueEnumWrapper { AzStyleScrollSnapAlignValueEnumWrapper { inner: AzStyleScrollSnapAlignValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleScrollSnapAlignValueEnumWrapper { AzStyleScrollSnapAlignValueEnumWrapper { inner: AzStyleScrollSnapAlignValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleScrollSnapAlignEnumWrapper) -> AzStyleScrollSnapAlignValueEnumWrapper { AzStyleScrollSnapAlignValueEnumWrapper { inner: AzStyleScrollSnapAlignValue::Exact(unsafe { mem::transmute(v) }) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleScrollSnapAlignValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleScrollSnapAlignValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleScrollSnapAlignValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleScrollSnapAlignValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleScrollSnapAlignValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleScrollSnapAlignValue::Exact(v) => Ok(vec!["Exact".into_py(py), { let m: &AzStyleScrollSnapAlignEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleScrollSnapAlignValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleScrollSnapAlignValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleScrollSnapAlignValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzStyleBackgroundContentVecValueEnumWrapper {
     #[classattr]
@@ -22542,6 +24138,12 @@ impl AzCssPropertyEnumWrapper {
     #[staticmethod]
     fn ScrollbarStyle(v: AzScrollbarStyleValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::ScrollbarStyle(unsafe { mem::transmute(v) }) } }
     #[staticmethod]
+    fn ScrollBehavior(v: AzStyleScrollBehaviorValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::ScrollBehavior(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn ScrollSnapType(v: AzStyleScrollSnapTypeValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::ScrollSnapType(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn ScrollSnapAlign(v: AzStyleScrollSnapAlignValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::ScrollSnapAlign(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
     fn Opacity(v: AzStyleOpacityValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::Opacity(unsafe { mem::transmute(v) }) } }
     #[staticmethod]
     fn Transform(v: AzStyleTransformVecValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::Transform(unsafe { mem::transmute(v) }) } }
@@ -22631,6 +24233,9 @@ impl AzCssPropertyEnumWrapper {
             AzCssProperty::BoxShadowTop(v) => Ok(vec!["BoxShadowTop".into_py(py), { let m: &AzStyleBoxShadowValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::BoxShadowBottom(v) => Ok(vec!["BoxShadowBottom".into_py(py), { let m: &AzStyleBoxShadowValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::ScrollbarStyle(v) => Ok(vec!["ScrollbarStyle".into_py(py), { let m: &AzScrollbarStyleValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::ScrollBehavior(v) => Ok(vec!["ScrollBehavior".into_py(py), { let m: &AzStyleScrollBehaviorValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::ScrollSnapType(v) => Ok(vec!["ScrollSnapType".into_py(py), { let m: &AzStyleScrollSnapTypeValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::ScrollSnapAlign(v) => Ok(vec!["ScrollSnapAlign".into_py(py), { let m: &AzStyleScrollSnapAlignValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::Opacity(v) => Ok(vec!["Opacity".into_py(py), { let m: &AzStyleOpacityValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::Transform(v) => Ok(vec!["Transform".into_py(py), { let m: &AzStyleTransformVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::TransformOrigin(v) => Ok(vec!["TransformOrigin".into_py(py), { let m: &AzStyleTransformOriginValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
@@ -34082,6 +35687,106 @@ impl PyObjectProtocol for AzAccessibilityStateVec {
     }
 }
 
+#[pymethods]
+impl AzVirtualKeyCodeComboVec {
+    /// Creates a new `VirtualKeyCodeComboVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzVirtualKeyCodeCombo>) -> Self {
+        let m: azul_core::window::VirtualKeyCodeComboVec = azul_core::window::VirtualKeyCodeComboVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the VirtualKeyCodeCombo as a Python array
+    fn array(&self) -> Vec<AzVirtualKeyCodeCombo> {
+        let m: &azul_core::window::VirtualKeyCodeComboVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzVirtualKeyCodeComboVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::VirtualKeyCodeComboVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::VirtualKeyCodeComboVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzShortcutBindingVec {
+    /// Creates a new `ShortcutBindingVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzShortcutBinding>) -> Self {
+        let m: azul_core::window::ShortcutBindingVec = azul_core::window::ShortcutBindingVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the ShortcutBinding as a Python array
+    fn array(&self) -> Vec<AzShortcutBinding> {
+        let m: &azul_core::window::ShortcutBindingVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzShortcutBindingVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ShortcutBindingVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ShortcutBindingVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTouchPointVec {
+    /// Creates a new `TouchPointVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzTouchPoint>) -> Self {
+        let m: azul_core::window::TouchPointVec = azul_core::window::TouchPointVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the TouchPoint as a Python array
+    fn array(&self) -> Vec<AzTouchPoint> {
+        let m: &azul_core::window::TouchPointVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTouchPointVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::TouchPointVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::TouchPointVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzNotificationActionVec {
+    /// Creates a new `NotificationActionVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzNotificationAction>) -> Self {
+        let m: azul_core::window::NotificationActionVec = azul_core::window::NotificationActionVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the NotificationAction as a Python array
+    fn array(&self) -> Vec<AzNotificationAction> {
+        let m: &azul_core::window::NotificationActionVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzNotificationActionVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::NotificationActionVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::NotificationActionVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzMenuItemVec {
     /// Creates a new `MenuItemEnumWrapperVec` from a Python array
@@ -34167,6 +35872,31 @@ impl PyObjectProtocol for AzTessellatedColoredSvgNodeVec {
     }
 }
 
+#[pymethods]
+impl AzStyleCursorVec {
+    /// Creates a new `StyleCursorEnumWrapperVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzStyleCursorEnumWrapper>) -> Self {
+        let m: azul_impl::css::StyleCursorVec = azul_impl::css::StyleCursorVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the StyleCursorEnumWrapper as a Python array
+    fn array(&self) -> Vec<AzStyleCursorEnumWrapper> {
+        let m: &azul_impl::css::StyleCursorVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleCursorVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleCursorVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleCursorVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzStyleFontFamilyVec {
     /// Creates a new `StyleFontFamilyEnumWrapperVec` from a Python array
@@ -35431,6 +37161,36 @@ impl PyObjectProtocol for AzNodeDataVec {
     }
 }
 
+#[pymethods]
+impl AzStyleCursorVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzStyleCursorVecDestructorEnumWrapper { AzStyleCursorVecDestructorEnumWrapper { inner: AzStyleCursorVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzStyleCursorVecDestructorEnumWrapper { AzStyleCursorVecDestructorEnumWrapper { inner: AzStyleCursorVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleCursorVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleCursorVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzStyleCursorVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzStyleCursorVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleCursorVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleCursorVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleCursorVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzStyleFontFamilyVecDestructorEnumWrapper {
     #[classattr]
@@ -35851,6 +37611,126 @@ impl PyObjectProtocol for AzAccessibilityStateVecDestructorEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzVirtualKeyCodeComboVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzVirtualKeyCodeComboVecDestructorEnumWrapper { AzVirtualKeyCodeComboVecDestructorEnumWrapper { inner: AzVirtualKeyCodeComboVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzVirtualKeyCodeComboVecDestructorEnumWrapper { AzVirtualKeyCodeComboVecDestructorEnumWrapper { inner: AzVirtualKeyCodeComboVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzVirtualKeyCodeComboVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzVirtualKeyCodeComboVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzVirtualKeyCodeComboVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzVirtualKeyCodeComboVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzVirtualKeyCodeComboVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::VirtualKeyCodeComboVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::VirtualKeyCodeComboVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzShortcutBindingVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzShortcutBindingVecDestructorEnumWrapper { AzShortcutBindingVecDestructorEnumWrapper { inner: AzShortcutBindingVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzShortcutBindingVecDestructorEnumWrapper { AzShortcutBindingVecDestructorEnumWrapper { inner: AzShortcutBindingVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzShortcutBindingVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzShortcutBindingVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzShortcutBindingVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzShortcutBindingVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzShortcutBindingVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ShortcutBindingVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ShortcutBindingVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTouchPointVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzTouchPointVecDestructorEnumWrapper { AzTouchPointVecDestructorEnumWrapper { inner: AzTouchPointVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzTouchPointVecDestructorEnumWrapper { AzTouchPointVecDestructorEnumWrapper { inner: AzTouchPointVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzTouchPointVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzTouchPointVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzTouchPointVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzTouchPointVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTouchPointVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::TouchPointVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::TouchPointVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzNotificationActionVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzNotificationActionVecDestructorEnumWrapper { AzNotificationActionVecDestructorEnumWrapper { inner: AzNotificationActionVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzNotificationActionVecDestructorEnumWrapper { AzNotificationActionVecDestructorEnumWrapper { inner: AzNotificationActionVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzNotificationActionVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzNotificationActionVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzNotificationActionVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzNotificationActionVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzNotificationActionVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::NotificationActionVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::NotificationActionVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzMenuItemVecDestructorEnumWrapper {
     #[classattr]
@@ -38543,6 +40423,93 @@ impl PyObjectProtocol for AzOptionImageRefEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzOptionWindowIdEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionWindowIdEnumWrapper { AzOptionWindowIdEnumWrapper { inner: AzOptionWindowId::None } }
+    #[staticmethod]
+    fn Some(v: AzWindowId) -> AzOptionWindowIdEnumWrapper { AzOptionWindowIdEnumWrapper { inner: AzOptionWindowId::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionWindowId;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionWindowId::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionWindowId::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionWindowIdEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::OptionWindowId = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::OptionWindowId = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionWindowResultCallbackEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionWindowResultCallbackEnumWrapper { AzOptionWindowResultCallbackEnumWrapper { inner: AzOptionWindowResultCallback::None } }
+    #[staticmethod]
+    fn Some(v: AzWindowResultCallback) -> AzOptionWindowResultCallbackEnumWrapper { AzOptionWindowResultCallbackEnumWrapper { inner: AzOptionWindowResultCallback::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionWindowResultCallback;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionWindowResultCallback::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionWindowResultCallback::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionWindowResultCallbackEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::OptionWindowResultCallback = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::OptionWindowResultCallback = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionCustomCursorEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionCustomCursorEnumWrapper { AzOptionCustomCursorEnumWrapper { inner: AzOptionCustomCursor::None } }
+    #[staticmethod]
+    fn Some(v: AzCustomCursor) -> AzOptionCustomCursorEnumWrapper { AzOptionCustomCursorEnumWrapper { inner: AzOptionCustomCursor::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionCustomCursor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionCustomCursor::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionCustomCursor::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionCustomCursorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::OptionCustomCursor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::OptionCustomCursor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzOptionFontRefEnumWrapper {
     #[classattr]
@@ -39036,6 +41003,35 @@ impl PyObjectProtocol for AzOptionRefAnyEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzOptionScrollPositionEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionScrollPositionEnumWrapper { AzOptionScrollPositionEnumWrapper { inner: AzOptionScrollPosition::None } }
+    #[staticmethod]
+    fn Some(v: AzScrollPosition) -> AzOptionScrollPositionEnumWrapper { AzOptionScrollPositionEnumWrapper { inner: AzOptionScrollPosition::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionScrollPosition;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionScrollPosition::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionScrollPosition::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionScrollPositionEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::OptionScrollPosition = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::OptionScrollPosition = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzOptionInlineTextEnumWrapper {
     #[classattr]
@@ -39382,6 +41378,35 @@ impl PyObjectProtocol for AzOptionHwndHandleEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzOptionGestureStateEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionGestureStateEnumWrapper { AzOptionGestureStateEnumWrapper { inner: AzOptionGestureState::None } }
+    #[staticmethod]
+    fn Some(v: AzGestureState) -> AzOptionGestureStateEnumWrapper { AzOptionGestureStateEnumWrapper { inner: AzOptionGestureState::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionGestureState;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionGestureState::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionGestureState::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionGestureStateEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::OptionGestureState = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::OptionGestureState = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzOptionLogicalPositionEnumWrapper {
     #[classattr]
@@ -39440,6 +41465,35 @@ impl PyObjectProtocol for AzOptionPhysicalPositionI32EnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzOptionTrayIconEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionTrayIconEnumWrapper { AzOptionTrayIconEnumWrapper { inner: AzOptionTrayIcon::None } }
+    #[staticmethod]
+    fn Some(v: AzTrayIcon) -> AzOptionTrayIconEnumWrapper { AzOptionTrayIconEnumWrapper { inner: AzOptionTrayIcon::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionTrayIcon;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionTrayIcon::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionTrayIcon::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionTrayIconEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::OptionTrayIcon = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::OptionTrayIcon = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzOptionWindowIconEnumWrapper {
     #[classattr]
@@ -40105,6 +42159,35 @@ impl PyObjectProtocol for AzResultSvgXmlNodeSvgParseErrorEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzResultShortcutIdShortcutErrorEnumWrapper {
+    #[staticmethod]
+    fn Ok(v: AzShortcutId) -> AzResultShortcutIdShortcutErrorEnumWrapper { AzResultShortcutIdShortcutErrorEnumWrapper { inner: AzResultShortcutIdShortcutError::Ok(v) } }
+    #[staticmethod]
+    fn Err(v: AzShortcutErrorEnumWrapper) -> AzResultShortcutIdShortcutErrorEnumWrapper { AzResultShortcutIdShortcutErrorEnumWrapper { inner: AzResultShortcutIdShortcutError::Err(unsafe { mem::transmute(v) }) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzResultShortcutIdShortcutError;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzResultShortcutIdShortcutError::Ok(v) => Ok(vec!["Ok".into_py(py), v.clone().into_py(py)]),
+            AzResultShortcutIdShortcutError::Err(v) => Ok(vec!["Err".into_py(py), { let m: &AzShortcutErrorEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzResultShortcutIdShortcutErrorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ResultShortcutIdShortcutError = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ResultShortcutIdShortcutError = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzResultSvgSvgParseErrorEnumWrapper {
     #[staticmethod]
@@ -40654,6 +42737,13 @@ impl PyObjectProtocol for AzSvgParseErrorPosition {
 }
 
 
+impl core::convert::From<AzShortcutError> for PyErr {
+    fn from(err: AzShortcutError) -> PyErr {
+        let r: azul_core::window::ShortcutError = unsafe { mem::transmute(err) };
+        PyException::new_err(format!("{}", r))
+    }
+}
+
 impl core::convert::From<AzDecodeImageError> for PyErr {
     fn from(err: AzDecodeImageError) -> PyErr {
         let r: azul_impl::resources::decode::DecodeImageError = unsafe { mem::transmute(err) };
@@ -40704,6 +42794,8 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzSystemCallbacks>()?;
 
     m.add_class::<AzWindowCreateOptions>()?;
+    m.add_class::<AzWindowId>()?;
+    m.add_class::<AzWindowResultCallback>()?;
     m.add_class::<AzRendererOptions>()?;
     m.add_class::<AzVsyncEnumWrapper>()?;
     m.add_class::<AzSrgbEnumWrapper>()?;
@@ -40730,6 +42822,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzSmallWindowIconBytes>()?;
     m.add_class::<AzLargeWindowIconBytes>()?;
     m.add_class::<AzWindowIconEnumWrapper>()?;
+    m.add_class::<AzTrayIcon>()?;
     m.add_class::<AzTaskBarIcon>()?;
     m.add_class::<AzVirtualKeyCodeEnumWrapper>()?;
     m.add_class::<AzAcceleratorKeyEnumWrapper>()?;
@@ -40753,10 +42846,22 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzWindowThemeEnumWrapper>()?;
     m.add_class::<AzWindowPositionEnumWrapper>()?;
     m.add_class::<AzImePositionEnumWrapper>()?;
+    m.add_class::<AzTouchPointKindEnumWrapper>()?;
+    m.add_class::<AzTouchPoint>()?;
+    m.add_class::<AzGestureState>()?;
     m.add_class::<AzTouchState>()?;
     m.add_class::<AzMonitor>()?;
     m.add_class::<AzVideoMode>()?;
     m.add_class::<AzWindowState>()?;
+    m.add_class::<AzCustomCursor>()?;
+    m.add_class::<AzNotification>()?;
+    m.add_class::<AzNotificationAction>()?;
+    m.add_class::<AzNotificationUrgencyEnumWrapper>()?;
+    m.add_class::<AzShortcutId>()?;
+    m.add_class::<AzShortcutScopeEnumWrapper>()?;
+    m.add_class::<AzShortcut>()?;
+    m.add_class::<AzShortcutBinding>()?;
+    m.add_class::<AzShortcutErrorEnumWrapper>()?;
 
     m.add_class::<AzLayoutCallbackEnumWrapper>()?;
     m.add_class::<AzMarshaledLayoutCallback>()?;
@@ -40771,6 +42876,8 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzDomNodeId>()?;
     m.add_class::<AzPositionInfoEnumWrapper>()?;
     m.add_class::<AzPositionInfoInner>()?;
+    m.add_class::<AzScrollPosition>()?;
+    m.add_class::<AzScrollIntoViewAlignEnumWrapper>()?;
     m.add_class::<AzHidpiAdjustedBounds>()?;
     m.add_class::<AzInlineText>()?;
     m.add_class::<AzInlineLine>()?;
@@ -40925,7 +43032,13 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzLayoutBorderTopWidth>()?;
     m.add_class::<AzScrollbarInfo>()?;
     m.add_class::<AzScrollbarStyle>()?;
+    m.add_class::<AzStyleScrollBehaviorEnumWrapper>()?;
+    m.add_class::<AzStyleScrollSnapType>()?;
+    m.add_class::<AzScrollSnapAxisEnumWrapper>()?;
+    m.add_class::<AzScrollSnapStrictnessEnumWrapper>()?;
+    m.add_class::<AzStyleScrollSnapAlignEnumWrapper>()?;
     m.add_class::<AzStyleCursorEnumWrapper>()?;
+    m.add_class::<AzStyleCursorImage>()?;
     m.add_class::<AzStyleFontFamilyEnumWrapper>()?;
     m.add_class::<AzStyleFontSize>()?;
     m.add_class::<AzStyleLetterSpacing>()?;
@@ -40979,6 +43092,9 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzLayoutFlexWrapValueEnumWrapper>()?;
     m.add_class::<AzLayoutOverflowValueEnumWrapper>()?;
     m.add_class::<AzScrollbarStyleValueEnumWrapper>()?;
+    m.add_class::<AzStyleScrollBehaviorValueEnumWrapper>()?;
+    m.add_class::<AzStyleScrollSnapTypeValueEnumWrapper>()?;
+    m.add_class::<AzStyleScrollSnapAlignValueEnumWrapper>()?;
     m.add_class::<AzStyleBackgroundContentVecValueEnumWrapper>()?;
     m.add_class::<AzStyleBackgroundPositionVecValueEnumWrapper>()?;
     m.add_class::<AzStyleBackgroundRepeatVecValueEnumWrapper>()?;
@@ -41274,9 +43390,14 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzOutputConnectionVec>()?;
     m.add_class::<AzInputNodeAndIndexVec>()?;
     m.add_class::<AzAccessibilityStateVec>()?;
+    m.add_class::<AzVirtualKeyCodeComboVec>()?;
+    m.add_class::<AzShortcutBindingVec>()?;
+    m.add_class::<AzTouchPointVec>()?;
+    m.add_class::<AzNotificationActionVec>()?;
     m.add_class::<AzMenuItemVec>()?;
     m.add_class::<AzTessellatedSvgNodeVec>()?;
     m.add_class::<AzTessellatedColoredSvgNodeVec>()?;
+    m.add_class::<AzStyleCursorVec>()?;
     m.add_class::<AzStyleFontFamilyVec>()?;
     m.add_class::<AzXmlNodeVec>()?;
     m.add_class::<AzFmtArgVec>()?;
@@ -41328,6 +43449,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzTagIdToNodeIdMappingVec>()?;
     m.add_class::<AzParentWithNodeDepthVec>()?;
     m.add_class::<AzNodeDataVec>()?;
+    m.add_class::<AzStyleCursorVecDestructorEnumWrapper>()?;
     m.add_class::<AzStyleFontFamilyVecDestructorEnumWrapper>()?;
     m.add_class::<AzListViewRowVecDestructorEnumWrapper>()?;
     m.add_class::<AzStyleFilterVecDestructorEnumWrapper>()?;
@@ -41342,6 +43464,10 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzOutputConnectionVecDestructorEnumWrapper>()?;
     m.add_class::<AzInputNodeAndIndexVecDestructorEnumWrapper>()?;
     m.add_class::<AzAccessibilityStateVecDestructorEnumWrapper>()?;
+    m.add_class::<AzVirtualKeyCodeComboVecDestructorEnumWrapper>()?;
+    m.add_class::<AzShortcutBindingVecDestructorEnumWrapper>()?;
+    m.add_class::<AzTouchPointVecDestructorEnumWrapper>()?;
+    m.add_class::<AzNotificationActionVecDestructorEnumWrapper>()?;
     m.add_class::<AzMenuItemVecDestructorEnumWrapper>()?;
     m.add_class::<AzTessellatedSvgNodeVecDestructorEnumWrapper>()?;
     m.add_class::<AzTessellatedColoredSvgNodeVecDestructorEnumWrapper>()?;
@@ -41434,6 +43560,9 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzOptionU16EnumWrapper>()?;
     m.add_class::<AzOptionU32EnumWrapper>()?;
     m.add_class::<AzOptionImageRefEnumWrapper>()?;
+    m.add_class::<AzOptionWindowIdEnumWrapper>()?;
+    m.add_class::<AzOptionWindowResultCallbackEnumWrapper>()?;
+    m.add_class::<AzOptionCustomCursorEnumWrapper>()?;
     m.add_class::<AzOptionFontRefEnumWrapper>()?;
     m.add_class::<AzOptionSystemClipboardEnumWrapper>()?;
     m.add_class::<AzOptionFileTypeListEnumWrapper>()?;
@@ -41451,6 +43580,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzOptionThreadSendMsgEnumWrapper>()?;
     m.add_class::<AzOptionLayoutRectEnumWrapper>()?;
     m.add_class::<AzOptionRefAnyEnumWrapper>()?;
+    m.add_class::<AzOptionScrollPositionEnumWrapper>()?;
     m.add_class::<AzOptionInlineTextEnumWrapper>()?;
     m.add_class::<AzOptionLayoutPointEnumWrapper>()?;
     m.add_class::<AzOptionLayoutSizeEnumWrapper>()?;
@@ -41463,8 +43593,10 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzOptionWaylandThemeEnumWrapper>()?;
     m.add_class::<AzOptionTaskBarIconEnumWrapper>()?;
     m.add_class::<AzOptionHwndHandleEnumWrapper>()?;
+    m.add_class::<AzOptionGestureStateEnumWrapper>()?;
     m.add_class::<AzOptionLogicalPositionEnumWrapper>()?;
     m.add_class::<AzOptionPhysicalPositionI32EnumWrapper>()?;
+    m.add_class::<AzOptionTrayIconEnumWrapper>()?;
     m.add_class::<AzOptionWindowIconEnumWrapper>()?;
     m.add_class::<AzOptionStringEnumWrapper>()?;
     m.add_class::<AzOptionX11VisualEnumWrapper>()?;
@@ -41489,6 +43621,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzResultRawImageDecodeImageErrorEnumWrapper>()?;
     m.add_class::<AzResultU8VecEncodeImageErrorEnumWrapper>()?;
     m.add_class::<AzResultSvgXmlNodeSvgParseErrorEnumWrapper>()?;
+    m.add_class::<AzResultShortcutIdShortcutErrorEnumWrapper>()?;
     m.add_class::<AzResultSvgSvgParseErrorEnumWrapper>()?;
     m.add_class::<AzSvgParseErrorEnumWrapper>()?;
     m.add_class::<AzXmlErrorEnumWrapper>()?;
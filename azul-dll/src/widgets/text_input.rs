@@ -15,7 +15,7 @@ use azul_desktop::{
 use azul_core::{
     callbacks::{Animation, AnimationRepeatCount, InlineText, DomNodeId},
     task::SystemTimeDiff,
-    window::{KeyboardState, LogicalPosition, VirtualKeyCode},
+    window::{KeyboardState, LogicalPosition, LogicalRect, LogicalSize, VirtualKeyCode},
 };
 use alloc::vec::Vec;
 use alloc::string::String;
@@ -57,6 +57,20 @@ static TEXT_CURSOR_PROPS: &[NodeDataInlineCssProperty] = &[
     Normal(CssProperty::const_transform(StyleTransformVec::from_const_slice(TEXT_CURSOR_TRANSFORM))),
 ];
 
+// -- preedit (IME composition) style, positioned after the label text
+
+static TEXT_PREEDIT_PROPS: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    Normal(CssProperty::const_top(LayoutTop::const_px(0))),
+    Normal(CssProperty::const_left(LayoutLeft::const_px(0))),
+    Normal(CssProperty::const_font_size(StyleFontSize::const_px(11))),
+    Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_4C4C4C })),
+    Normal(CssProperty::const_font_family(SANS_SERIF_FAMILY)),
+    Normal(CssProperty::const_border_bottom_width(LayoutBorderBottomWidth::const_px(1))),
+    Normal(CssProperty::const_border_bottom_style(StyleBorderBottomStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_4C4C4C })),
+];
+
 // -- container style
 
 #[cfg(target_os = "windows")]
@@ -534,6 +548,21 @@ impl TextInput {
                 data: state_ref.clone(),
                 callback: Callback { cb: default_on_virtual_key_down }
             },
+            CallbackData {
+                event: EventFilter::Focus(FocusEventFilter::TextCompositionStart),
+                data: state_ref.clone(),
+                callback: Callback { cb: default_on_text_composition }
+            },
+            CallbackData {
+                event: EventFilter::Focus(FocusEventFilter::TextCompositionUpdate),
+                data: state_ref.clone(),
+                callback: Callback { cb: default_on_text_composition }
+            },
+            CallbackData {
+                event: EventFilter::Focus(FocusEventFilter::TextCompositionEnd),
+                data: state_ref.clone(),
+                callback: Callback { cb: default_on_text_composition }
+            },
             CallbackData {
                 event: EventFilter::Hover(HoverEventFilter::MouseOver),
                 data: state_ref.clone(),
@@ -550,7 +579,10 @@ impl TextInput {
             .with_children(vec![
                 Dom::div()
                 .with_ids_and_classes(vec![Class("__azul-native-text-input-cursor".into())].into())
-                .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TEXT_CURSOR_PROPS))
+                .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TEXT_CURSOR_PROPS)),
+                Dom::text("")
+                .with_ids_and_classes(vec![Class("__azul-native-text-input-preedit".into())].into())
+                .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TEXT_PREEDIT_PROPS))
             ].into())
         ].into())
     }
@@ -645,7 +677,11 @@ fn default_on_text_input_inner(
     let mut text_input = text_input.downcast_mut::<TextInputStateWrapper>()?;
     let keyboard_state = info.get_current_keyboard_state();
 
-    let c = keyboard_state.current_char.into_option()?;
+    // text committed by the input method can contain more than one character
+    let chars: Vec<u32> = match info.get_committed_text() {
+        Some(committed) => committed.as_str().chars().map(|c| c as u32).collect(),
+        None => vec![keyboard_state.current_char.into_option()?],
+    };
     let placeholder_node_id = info.get_first_child(info.get_hit_node())?;
     let label_node_id = info.get_next_sibling(placeholder_node_id)?;
     let cursor_node_id = info.get_first_child(label_node_id)?;
@@ -658,10 +694,10 @@ fn default_on_text_input_inner(
 
         // inner_clone has the new text
         let mut inner_clone = text_input.inner.clone();
-        inner_clone.cursor_pos = inner_clone.cursor_pos.saturating_add(1);
+        inner_clone.cursor_pos = inner_clone.cursor_pos.saturating_add(chars.len());
         inner_clone.text = {
             let mut internal = inner_clone.text.clone().into_library_owned_vec();
-            internal.push(c);
+            internal.extend_from_slice(&chars);
             internal.into()
         };
 
@@ -684,10 +720,10 @@ fn default_on_text_input_inner(
         // append to the text
         text_input.inner.text = {
             let mut internal = text_input.inner.text.clone().into_library_owned_vec();
-            internal.push(c);
+            internal.extend_from_slice(&chars);
             internal.into()
         };
-        text_input.inner.cursor_pos = text_input.inner.cursor_pos.saturating_add(1);
+        text_input.inner.cursor_pos = text_input.inner.cursor_pos.saturating_add(chars.len());
 
         info.set_string_contents(label_node_id, text_input.inner.get_text().into());
     }
@@ -695,6 +731,57 @@ fn default_on_text_input_inner(
     Some(result.update)
 }
 
+extern "C"
+fn default_on_text_composition(
+    text_input: &mut RefAny,
+    info: &mut CallbackInfo
+) -> Update {
+    default_on_text_composition_inner(text_input, info)
+    .unwrap_or(Update::DoNothing)
+}
+
+// Draws the preedit string of the input method (underlined) after the
+// label text and moves the candidate window of the input method below it
+fn default_on_text_composition_inner(
+    text_input: &mut RefAny,
+    info: &mut CallbackInfo
+) -> Option<Update> {
+
+    let text_input = text_input.downcast_ref::<TextInputStateWrapper>()?;
+
+    let placeholder_node_id = info.get_first_child(info.get_hit_node())?;
+    let label_node_id = info.get_next_sibling(placeholder_node_id)?;
+    let cursor_node_id = info.get_first_child(label_node_id)?;
+    let preedit_node_id = info.get_next_sibling(cursor_node_id)?;
+
+    let preedit = info.get_text_composition()
+        .map(|composition| composition.text)
+        .unwrap_or_default();
+
+    // hide the placeholder text while composing
+    let placeholder_opacity = if text_input.inner.text.is_empty() && preedit.as_str().is_empty() { 100 } else { 0 };
+    info.set_css_property(
+        placeholder_node_id,
+        CssProperty::const_opacity(StyleOpacity::const_new(placeholder_opacity))
+    );
+
+    let label_origin = info.get_node_position(label_node_id)?.get_static_offset();
+    let label_size = info.get_node_size(label_node_id)?;
+
+    info.set_css_property(
+        preedit_node_id,
+        CssProperty::const_left(LayoutLeft::px(label_size.width))
+    );
+    info.set_string_contents(preedit_node_id, preedit);
+
+    info.set_ime_caret_rect(LogicalRect::new(
+        LogicalPosition::new(label_origin.x + label_size.width, label_origin.y),
+        LogicalSize::new(1.0, label_size.height),
+    ));
+
+    None
+}
+
 extern "C"
 fn default_on_virtual_key_down(
     text_input: &mut RefAny,
@@ -0,0 +1,1092 @@
+//! Versioned serialization of the display list (`azulc --display-list`, `azulc dl-diff`)
+//!
+//! A `CachedDisplayList` is first converted into a tree of `DisplayListValue`s
+//! (the "dump"), which can then be encoded either as JSON (for snapshot files
+//! that are reviewed in PRs) or as a compact binary format. CSS values are
+//! stored in their printed CSS form, so the dump doesn't depend on the memory
+//! layout of the CSS types. Both encodings carry `DISPLAY_LIST_DUMP_VERSION`:
+//! whenever the structure of the dump changes, the version has to be increased.
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::convert::TryInto;
+use core::fmt;
+use azul_core::{
+    display_list::{
+        BoxShadow, CachedDisplayList, DisplayListFrame, DisplayListMsg,
+        DisplayListScrollFrame, LayoutRectContent, RectBackground,
+        StyleBorderRadius,
+    },
+    ui_solver::PositionInfo,
+    window::{LogicalPosition, LogicalRect, LogicalSize},
+};
+use azul_css::{ColorU, CssPropertyValue, PrintAsCssValue};
+
+/// Version of the dump format, stored in the JSON and the binary header
+pub const DISPLAY_LIST_DUMP_VERSION: u32 = 1;
+/// Value of the `"format"` field of a JSON dump
+pub const DISPLAY_LIST_DUMP_FORMAT: &str = "azul-display-list";
+/// First four bytes of a binary dump, followed by the version as a little-endian u32
+pub const DISPLAY_LIST_BINARY_MAGIC: &[u8; 4] = b"AZDL";
+
+/// Maximum nesting depth of arrays / objects when loading a dump, so that
+/// malformed input can't overflow the stack of the recursive parsers
+const MAX_NESTING_DEPTH: usize = 512;
+
+/// Arrays with more than this number of item pairs (`a.len() * b.len()`) are
+/// compared by position instead of being aligned by their longest common subsequence
+const MAX_LCS_TABLE_SIZE: usize = 4_000_000;
+
+/// Fields containing IDs that are generated at runtime (resource keys,
+/// hit-testing tags, pipeline IDs), ignored by `diff` unless `strict` is set
+const UNSTABLE_FIELDS: &[&str] = &[
+    "tag", "scroll_tag", "scroll_id", "pipeline_id", "epoch",
+    "font_instance_key", "image_key", "transform_key", "opacity_key",
+];
+
+/// Node in the serialized display list tree, equivalent to a JSON value
+///
+/// Objects keep the insertion order of their fields, so that the output is stable.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DisplayListValue {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f32),
+    String(String),
+    Array(Vec<DisplayListValue>),
+    Object(Vec<(String, DisplayListValue)>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DisplayListDumpError {
+    /// Invalid JSON, position = byte offset of the error
+    Json(usize, &'static str),
+    /// Invalid binary dump, position = byte offset of the error
+    Binary(usize, &'static str),
+    /// The dump was written by a newer version of azulc
+    UnsupportedVersion(u32),
+    /// The file is valid JSON, but not a display list dump
+    NotADisplayList,
+}
+
+impl_display!(DisplayListDumpError, {
+    Json(pos, e) => format!("invalid JSON at byte {}: {}", pos, e),
+    Binary(pos, e) => format!("invalid binary display list at byte {}: {}", pos, e),
+    UnsupportedVersion(v) => format!("unsupported display list version {} (supported: {})", v, DISPLAY_LIST_DUMP_VERSION),
+    NotADisplayList => format!("file is not a display list dump (missing \"format\": \"{}\")", DISPLAY_LIST_DUMP_FORMAT),
+});
+
+// -- serialization: CachedDisplayList -> DisplayListValue
+
+/// Converts the display list into a versioned dump
+pub fn serialize_display_list(display_list: &CachedDisplayList) -> DisplayListValue {
+    DisplayListValue::Object(vec![
+        field("format", DisplayListValue::String(DISPLAY_LIST_DUMP_FORMAT.into())),
+        field("version", DisplayListValue::Int(DISPLAY_LIST_DUMP_VERSION as i64)),
+        field("root_size", size(&display_list.root_size)),
+        field("root", msg(&display_list.root)),
+    ])
+}
+
+fn field(key: &str, value: DisplayListValue) -> (String, DisplayListValue) {
+    (key.into(), value)
+}
+
+fn float(f: f32) -> DisplayListValue {
+    DisplayListValue::Float(f)
+}
+
+fn int<I: TryInto<i64>>(i: I) -> DisplayListValue {
+    DisplayListValue::Int(i.try_into().unwrap_or(i64::MAX))
+}
+
+fn string<S: Into<String>>(s: S) -> DisplayListValue {
+    DisplayListValue::String(s.into())
+}
+
+fn color(c: &ColorU) -> DisplayListValue {
+    string(c.to_hash())
+}
+
+fn optional<T, F: Fn(&T) -> DisplayListValue>(o: Option<&T>, f: F) -> DisplayListValue {
+    o.map(|s| f(s)).unwrap_or(DisplayListValue::Null)
+}
+
+fn css<T: PrintAsCssValue>(v: &T) -> DisplayListValue {
+    string(v.print_as_css_value())
+}
+
+fn css_value<T: PrintAsCssValue>(v: &Option<CssPropertyValue<T>>) -> DisplayListValue {
+    optional(v.as_ref(), |v| string(v.get_css_value_fmt()))
+}
+
+fn size(s: &LogicalSize) -> DisplayListValue {
+    DisplayListValue::Object(vec![
+        field("width", float(s.width)),
+        field("height", float(s.height)),
+    ])
+}
+
+fn position(p: &LogicalPosition) -> DisplayListValue {
+    DisplayListValue::Object(vec![
+        field("x", float(p.x)),
+        field("y", float(p.y)),
+    ])
+}
+
+fn rect(r: &LogicalRect) -> DisplayListValue {
+    DisplayListValue::Object(vec![
+        field("origin", position(&r.origin)),
+        field("size", size(&r.size)),
+    ])
+}
+
+fn msg(msg: &DisplayListMsg) -> DisplayListValue {
+    match msg {
+        DisplayListMsg::IFrame(pipeline_id, iframe_size, epoch, display_list) => {
+            DisplayListValue::Object(vec![
+                field("type", string("iframe")),
+                field("pipeline_id", string(format!("{}", pipeline_id))),
+                field("size", size(iframe_size)),
+                field("epoch", string(format!("{}", epoch))),
+                field("root_size", size(&display_list.root_size)),
+                field("root", self::msg(&display_list.root)),
+            ])
+        },
+        DisplayListMsg::Frame(f) => {
+            let mut fields = vec![field("type", string("frame"))];
+            fields.extend(frame(f));
+            DisplayListValue::Object(fields)
+        },
+        DisplayListMsg::ScrollFrame(sf) => scroll_frame(sf),
+    }
+}
+
+fn scroll_frame(sf: &DisplayListScrollFrame) -> DisplayListValue {
+    let mut fields = vec![
+        field("type", string("scroll_frame")),
+        field("parent_rect", rect(&sf.parent_rect)),
+        field("content_rect", rect(&sf.content_rect)),
+        field("scroll_id", int(sf.scroll_id.0)),
+        field("scroll_tag", int((sf.scroll_tag.0).0)),
+    ];
+    fields.extend(frame(&sf.frame));
+    DisplayListValue::Object(fields)
+}
+
+fn frame(f: &DisplayListFrame) -> Vec<(String, DisplayListValue)> {
+
+    let (position_type, p) = match f.position {
+        PositionInfo::Static(p) => ("static", p),
+        PositionInfo::Fixed(p) => ("fixed", p),
+        PositionInfo::Absolute(p) => ("absolute", p),
+        PositionInfo::Relative(p) => ("relative", p),
+    };
+
+    vec![
+        field("size", size(&f.size)),
+        field("position", DisplayListValue::Object(vec![
+            field("type", string(position_type)),
+            field("x", float(p.x_offset)),
+            field("y", float(p.y_offset)),
+            field("static_x", float(p.static_x_offset)),
+            field("static_y", float(p.static_y_offset)),
+        ])),
+        field("flags", DisplayListValue::Object(vec![
+            field("is_backface_visible", DisplayListValue::Bool(f.flags.is_backface_visible)),
+            field("is_scrollbar_container", DisplayListValue::Bool(f.flags.is_scrollbar_container)),
+            field("is_scrollbar_thumb", DisplayListValue::Bool(f.flags.is_scrollbar_thumb)),
+            field("prefer_compositor_surface", DisplayListValue::Bool(f.flags.prefer_compositor_surface)),
+            field("supports_external_compositor_surface", DisplayListValue::Bool(f.flags.supports_external_compositor_surface)),
+        ])),
+        field("mix_blend_mode", optional(f.mix_blend_mode.as_ref(), css)),
+        field("clip_children", optional(f.clip_children.as_ref(), size)),
+        field("clip_mask", optional(f.clip_mask.as_ref(), |m| DisplayListValue::Object(vec![
+            field("image_key", int(m.image.key)),
+            field("rect", rect(&m.rect)),
+            field("repeat", DisplayListValue::Bool(m.repeat)),
+        ]))),
+        field("border_radius", border_radius(&f.border_radius)),
+        field("tag", optional(f.tag.as_ref(), |t| int(t.0))),
+        field("box_shadow", optional(f.box_shadow.as_ref(), box_shadow)),
+        field("transform", optional(f.transform.as_ref(), |(key, t)| DisplayListValue::Object(vec![
+            field("transform_key", int(key.id)),
+            field("matrix", DisplayListValue::Array(
+                t.m.iter().map(|row| DisplayListValue::Array(row.iter().map(|f| float(*f)).collect())).collect()
+            )),
+        ]))),
+        field("opacity", optional(f.opacity.as_ref(), |(key, o)| DisplayListValue::Object(vec![
+            field("opacity_key", int(key.id)),
+            field("value", float(*o)),
+        ]))),
+        field("content", DisplayListValue::Array(f.content.iter().map(content).collect())),
+        field("children", DisplayListValue::Array(f.children.iter().map(msg).collect())),
+    ]
+}
+
+fn border_radius(r: &StyleBorderRadius) -> DisplayListValue {
+    DisplayListValue::Object(vec![
+        field("top_left", css_value(&r.top_left)),
+        field("top_right", css_value(&r.top_right)),
+        field("bottom_left", css_value(&r.bottom_left)),
+        field("bottom_right", css_value(&r.bottom_right)),
+    ])
+}
+
+fn box_shadow(b: &BoxShadow) -> DisplayListValue {
+    DisplayListValue::Object(vec![
+        field("clip_mode", string(format!("{:?}", b.clip_mode))),
+        field("top", css_value(&b.top)),
+        field("right", css_value(&b.right)),
+        field("bottom", css_value(&b.bottom)),
+        field("left", css_value(&b.left)),
+    ])
+}
+
+fn content(c: &LayoutRectContent) -> DisplayListValue {
+    match c {
+        LayoutRectContent::Text { glyphs, font_instance_key, color: text_color, glyph_options, overflow, text_shadow } => {
+            DisplayListValue::Object(vec![
+                field("type", string("text")),
+                field("font_instance_key", int(font_instance_key.key)),
+                field("color", color(text_color)),
+                field("glyph_options", optional(glyph_options.as_ref(), |o| DisplayListValue::Object(vec![
+                    field("render_mode", string(format!("{:?}", o.render_mode))),
+                    field("flags", int(o.flags)),
+                ]))),
+                field("overflow", DisplayListValue::Array(vec![
+                    DisplayListValue::Bool(overflow.0),
+                    DisplayListValue::Bool(overflow.1),
+                ])),
+                field("text_shadow", optional(text_shadow.as_ref(), css)),
+                // [glyph index, x, y, width, height]
+                field("glyphs", DisplayListValue::Array(glyphs.iter().map(|g| DisplayListValue::Array(vec![
+                    int(g.index),
+                    float(g.point.x),
+                    float(g.point.y),
+                    float(g.size.width),
+                    float(g.size.height),
+                ])).collect())),
+            ])
+        },
+        LayoutRectContent::Background { content, size, offset, repeat } => {
+            DisplayListValue::Object(vec![
+                field("type", string("background")),
+                field("content", match content {
+                    RectBackground::Color(c) => color(c),
+                    RectBackground::LinearGradient(g) => css(g),
+                    RectBackground::RadialGradient(g) => css(g),
+                    RectBackground::ConicGradient(g) => css(g),
+                    RectBackground::Image((key, descriptor)) => DisplayListValue::Object(vec![
+                        field("image_key", int(key.key)),
+                        field("width", int(descriptor.width)),
+                        field("height", int(descriptor.height)),
+                        field("format", string(format!("{:?}", descriptor.format))),
+                    ]),
+                }),
+                field("size", optional(size.as_ref(), css)),
+                field("offset", optional(offset.as_ref(), css)),
+                field("repeat", optional(repeat.as_ref(), css)),
+            ])
+        },
+        LayoutRectContent::Image { size: image_size, offset, image_rendering, alpha_type, image_key, background_color } => {
+            DisplayListValue::Object(vec![
+                field("type", string("image")),
+                field("size", size(image_size)),
+                field("offset", position(offset)),
+                field("image_rendering", string(format!("{:?}", image_rendering))),
+                field("alpha_type", string(format!("{:?}", alpha_type))),
+                field("image_key", int(image_key.key)),
+                field("background_color", color(background_color)),
+            ])
+        },
+        LayoutRectContent::Border { widths, colors, styles } => {
+            DisplayListValue::Object(vec![
+                field("type", string("border")),
+                field("widths", DisplayListValue::Object(vec![
+                    field("top", css_value(&widths.top)),
+                    field("right", css_value(&widths.right)),
+                    field("bottom", css_value(&widths.bottom)),
+                    field("left", css_value(&widths.left)),
+                ])),
+                field("colors", DisplayListValue::Object(vec![
+                    field("top", css_value(&colors.top)),
+                    field("right", css_value(&colors.right)),
+                    field("bottom", css_value(&colors.bottom)),
+                    field("left", css_value(&colors.left)),
+                ])),
+                field("styles", DisplayListValue::Object(vec![
+                    field("top", css_value(&styles.top)),
+                    field("right", css_value(&styles.right)),
+                    field("bottom", css_value(&styles.bottom)),
+                    field("left", css_value(&styles.left)),
+                ])),
+            ])
+        },
+    }
+}
+
+// -- JSON encoding
+
+impl DisplayListValue {
+
+    pub fn get(&self, key: &str) -> Option<&DisplayListValue> {
+        match self {
+            DisplayListValue::Object(fields) => fields.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    fn is_scalar(&self) -> bool {
+        !matches!(self, DisplayListValue::Array(_) | DisplayListValue::Object(_))
+    }
+
+    /// Pretty-printed JSON, arrays of scalar values (glyphs, matrix rows) are kept on one line
+    pub fn to_json(&self) -> String {
+        let mut s = String::new();
+        self.write_json(&mut s, 0, true);
+        s.push('\n');
+        s
+    }
+
+    /// JSON without any whitespace
+    pub fn to_json_compact(&self) -> String {
+        let mut s = String::new();
+        self.write_json(&mut s, 0, false);
+        s
+    }
+
+    fn write_json(&self, s: &mut String, indent: usize, pretty: bool) {
+
+        const INDENT: &str = "    ";
+
+        let newline = |s: &mut String, indent: usize| {
+            if pretty {
+                s.push('\n');
+                for _ in 0..indent {
+                    s.push_str(INDENT);
+                }
+            }
+        };
+
+        match self {
+            DisplayListValue::Null => s.push_str("null"),
+            DisplayListValue::Bool(b) => s.push_str(if *b { "true" } else { "false" }),
+            DisplayListValue::Int(i) => s.push_str(&format!("{}", i)),
+            DisplayListValue::Float(f) => s.push_str(&format_float(*f)),
+            DisplayListValue::String(st) => write_json_string(s, st),
+            DisplayListValue::Array(a) if a.is_empty() => s.push_str("[]"),
+            DisplayListValue::Array(a) if a.iter().all(|v| v.is_scalar()) => {
+                s.push('[');
+                for (i, v) in a.iter().enumerate() {
+                    if i != 0 {
+                        s.push_str(if pretty { ", " } else { "," });
+                    }
+                    v.write_json(s, indent, pretty);
+                }
+                s.push(']');
+            },
+            DisplayListValue::Array(a) => {
+                s.push('[');
+                for (i, v) in a.iter().enumerate() {
+                    if i != 0 {
+                        s.push(',');
+                    }
+                    newline(s, indent + 1);
+                    v.write_json(s, indent + 1, pretty);
+                }
+                newline(s, indent);
+                s.push(']');
+            },
+            DisplayListValue::Object(o) if o.is_empty() => s.push_str("{}"),
+            DisplayListValue::Object(o) => {
+                s.push('{');
+                for (i, (k, v)) in o.iter().enumerate() {
+                    if i != 0 {
+                        s.push(',');
+                    }
+                    newline(s, indent + 1);
+                    write_json_string(s, k);
+                    s.push_str(if pretty { ": " } else { ":" });
+                    v.write_json(s, indent + 1, pretty);
+                }
+                newline(s, indent);
+                s.push('}');
+            },
+        }
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, DisplayListDumpError> {
+        let mut parser = JsonParser { input: json.as_bytes(), pos: 0, depth: 0 };
+        let value = parser.parse_value()?;
+        parser.skip_whitespace();
+        if parser.pos != parser.input.len() {
+            return Err(DisplayListDumpError::Json(parser.pos, "trailing characters after JSON value"));
+        }
+        Ok(value)
+    }
+}
+
+impl fmt::Display for DisplayListValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_json_compact())
+    }
+}
+
+/// Floats always contain a "." so that they are parsed back as floats
+fn format_float(f: f32) -> String {
+    if !f.is_finite() {
+        // not representable in JSON
+        String::from("null")
+    } else if f.fract() == 0.0 {
+        format!("{:.1}", f)
+    } else {
+        format!("{}", f)
+    }
+}
+
+fn write_json_string(s: &mut String, st: &str) {
+    s.push('"');
+    for c in st.chars() {
+        match c {
+            '"' => s.push_str("\\\""),
+            '\\' => s.push_str("\\\\"),
+            '\n' => s.push_str("\\n"),
+            '\r' => s.push_str("\\r"),
+            '\t' => s.push_str("\\t"),
+            c if (c as u32) < 0x20 => s.push_str(&format!("\\u{:04x}", c as u32)),
+            c => s.push(c),
+        }
+    }
+    s.push('"');
+}
+
+struct JsonParser<'a> {
+    input: &'a [u8],
+    pos: usize,
+    /// number of arrays / objects the parser is currently in
+    depth: usize,
+}
+
+impl<'a> JsonParser<'a> {
+
+    fn error<T>(&self, e: &'static str) -> Result<T, DisplayListDumpError> {
+        Err(DisplayListDumpError::Json(self.pos, e))
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ') | Some(b'\n') | Some(b'\r') | Some(b'\t') = self.input.get(self.pos) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, literal: &str, value: DisplayListValue) -> Result<DisplayListValue, DisplayListDumpError> {
+        if self.input[self.pos..].starts_with(literal.as_bytes()) {
+            self.pos += literal.len();
+            Ok(value)
+        } else {
+            self.error("unexpected character")
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<DisplayListValue, DisplayListDumpError> {
+        self.skip_whitespace();
+        match self.input.get(self.pos) {
+            None => self.error("unexpected end of input"),
+            Some(b'n') => self.expect("null", DisplayListValue::Null),
+            Some(b't') => self.expect("true", DisplayListValue::Bool(true)),
+            Some(b'f') => self.expect("false", DisplayListValue::Bool(false)),
+            Some(b'"') => Ok(DisplayListValue::String(self.parse_string()?)),
+            Some(b'[') | Some(b'{') => {
+                if self.depth >= MAX_NESTING_DEPTH {
+                    return self.error("nesting too deep");
+                }
+                self.depth += 1;
+                let value = if self.input[self.pos] == b'[' { self.parse_array() } else { self.parse_object() };
+                self.depth -= 1;
+                value
+            },
+            Some(b'-') | Some(b'0'..=b'9') => self.parse_number(),
+            Some(_) => self.error("unexpected character"),
+        }
+    }
+
+    fn parse_array(&mut self) -> Result<DisplayListValue, DisplayListDumpError> {
+        // skip the opening bracket
+        self.pos += 1;
+        let mut items = Vec::new();
+        self.skip_whitespace();
+        if self.input.get(self.pos) == Some(&b']') {
+            self.pos += 1;
+            return Ok(DisplayListValue::Array(items));
+        }
+        loop {
+            items.push(self.parse_value()?);
+            self.skip_whitespace();
+            match self.input.get(self.pos) {
+                Some(b',') => { self.pos += 1; },
+                Some(b']') => { self.pos += 1; return Ok(DisplayListValue::Array(items)); },
+                _ => return self.error("expected \",\" or \"]\""),
+            }
+        }
+    }
+
+    fn parse_object(&mut self) -> Result<DisplayListValue, DisplayListDumpError> {
+        // skip the opening brace
+        self.pos += 1;
+        let mut fields = Vec::new();
+        self.skip_whitespace();
+        if self.input.get(self.pos) == Some(&b'}') {
+            self.pos += 1;
+            return Ok(DisplayListValue::Object(fields));
+        }
+        loop {
+            self.skip_whitespace();
+            if self.input.get(self.pos) != Some(&b'"') {
+                return self.error("expected object key");
+            }
+            let key = self.parse_string()?;
+            self.skip_whitespace();
+            if self.input.get(self.pos) != Some(&b':') {
+                return self.error("expected \":\"");
+            }
+            self.pos += 1;
+            fields.push((key, self.parse_value()?));
+            self.skip_whitespace();
+            match self.input.get(self.pos) {
+                Some(b',') => { self.pos += 1; },
+                Some(b'}') => { self.pos += 1; return Ok(DisplayListValue::Object(fields)); },
+                _ => return self.error("expected \",\" or \"}\""),
+            }
+        }
+    }
+
+    fn parse_number(&mut self) -> Result<DisplayListValue, DisplayListDumpError> {
+        let start = self.pos;
+        let mut is_float = false;
+        while let Some(c) = self.input.get(self.pos) {
+            match c {
+                b'0'..=b'9' | b'-' | b'+' => { },
+                b'.' | b'e' | b'E' => { is_float = true; },
+                _ => break,
+            }
+            self.pos += 1;
+        }
+        let s = core::str::from_utf8(&self.input[start..self.pos]).unwrap_or_default();
+        let value = if is_float {
+            s.parse::<f32>().ok().map(DisplayListValue::Float)
+        } else {
+            s.parse::<i64>().ok().map(DisplayListValue::Int)
+        };
+        match value {
+            Some(v) => Ok(v),
+            None => Err(DisplayListDumpError::Json(start, "invalid number")),
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<String, DisplayListDumpError> {
+        // skip the opening quote
+        self.pos += 1;
+        let mut bytes = Vec::new();
+        loop {
+            match self.input.get(self.pos) {
+                None => return self.error("unterminated string"),
+                Some(b'"') => { self.pos += 1; break; },
+                Some(b'\\') => {
+                    self.pos += 1;
+                    let escaped = match self.input.get(self.pos) {
+                        Some(b'"') => '"',
+                        Some(b'\\') => '\\',
+                        Some(b'/') => '/',
+                        Some(b'b') => '\u{8}',
+                        Some(b'f') => '\u{c}',
+                        Some(b'n') => '\n',
+                        Some(b'r') => '\r',
+                        Some(b't') => '\t',
+                        Some(b'u') => {
+                            let hex = self.input.get((self.pos + 1)..(self.pos + 5))
+                                .and_then(|h| core::str::from_utf8(h).ok())
+                                .and_then(|h| u32::from_str_radix(h, 16).ok());
+                            match hex.and_then(core::char::from_u32) {
+                                Some(c) => { self.pos += 4; c },
+                                None => return self.error("invalid unicode escape"),
+                            }
+                        },
+                        _ => return self.error("invalid escape sequence"),
+                    };
+                    let mut buf = [0; 4];
+                    bytes.extend_from_slice(escaped.encode_utf8(&mut buf).as_bytes());
+                    self.pos += 1;
+                },
+                Some(c) => { bytes.push(*c); self.pos += 1; },
+            }
+        }
+        String::from_utf8(bytes).or_else(|_| self.error("invalid UTF-8 in string"))
+    }
+}
+
+// -- binary encoding
+//
+// header: magic (4 bytes) + version (u32 LE), followed by one value:
+//
+// - 0 = null, 1 = false, 2 = true
+// - 3 = int (zigzag-encoded LEB128)
+// - 4 = float (f32 LE)
+// - 5 = string (LEB128 length + UTF-8 bytes)
+// - 6 = array (LEB128 length + values)
+// - 7 = object (LEB128 length + (key, value) pairs)
+//
+// Object keys are interned: a key is written as the LEB128 index into the table
+// of keys seen so far, an index equal to the table length is followed by a new key.
+
+const TAG_NULL: u8 = 0;
+const TAG_FALSE: u8 = 1;
+const TAG_TRUE: u8 = 2;
+const TAG_INT: u8 = 3;
+const TAG_FLOAT: u8 = 4;
+const TAG_STRING: u8 = 5;
+const TAG_ARRAY: u8 = 6;
+const TAG_OBJECT: u8 = 7;
+
+impl DisplayListValue {
+
+    pub fn to_binary(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.extend_from_slice(DISPLAY_LIST_BINARY_MAGIC);
+        out.extend_from_slice(&DISPLAY_LIST_DUMP_VERSION.to_le_bytes());
+        self.write_binary(&mut out, &mut BTreeMap::new());
+        out
+    }
+
+    fn write_binary<'a>(&'a self, out: &mut Vec<u8>, keys: &mut BTreeMap<&'a str, usize>) {
+        match self {
+            DisplayListValue::Null => out.push(TAG_NULL),
+            DisplayListValue::Bool(false) => out.push(TAG_FALSE),
+            DisplayListValue::Bool(true) => out.push(TAG_TRUE),
+            DisplayListValue::Int(i) => {
+                out.push(TAG_INT);
+                write_varint(out, ((*i << 1) ^ (*i >> 63)) as u64);
+            },
+            DisplayListValue::Float(f) => {
+                out.push(TAG_FLOAT);
+                out.extend_from_slice(&f.to_le_bytes());
+            },
+            DisplayListValue::String(s) => {
+                out.push(TAG_STRING);
+                write_varint(out, s.len() as u64);
+                out.extend_from_slice(s.as_bytes());
+            },
+            DisplayListValue::Array(a) => {
+                out.push(TAG_ARRAY);
+                write_varint(out, a.len() as u64);
+                for v in a.iter() {
+                    v.write_binary(out, keys);
+                }
+            },
+            DisplayListValue::Object(o) => {
+                out.push(TAG_OBJECT);
+                write_varint(out, o.len() as u64);
+                for (k, v) in o.iter() {
+                    match keys.get(k.as_str()) {
+                        Some(index) => write_varint(out, *index as u64),
+                        None => {
+                            let index = keys.len();
+                            keys.insert(k.as_str(), index);
+                            write_varint(out, index as u64);
+                            write_varint(out, k.len() as u64);
+                            out.extend_from_slice(k.as_bytes());
+                        },
+                    }
+                    v.write_binary(out, keys);
+                }
+            },
+        }
+    }
+
+    pub fn from_binary(bytes: &[u8]) -> Result<Self, DisplayListDumpError> {
+
+        if !bytes.starts_with(DISPLAY_LIST_BINARY_MAGIC) || bytes.len() < 8 {
+            return Err(DisplayListDumpError::Binary(0, "missing header"));
+        }
+
+        let version = u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]);
+        if version > DISPLAY_LIST_DUMP_VERSION {
+            return Err(DisplayListDumpError::UnsupportedVersion(version));
+        }
+
+        let mut reader = BinaryReader { input: bytes, pos: 8, keys: Vec::new(), depth: 0 };
+        let value = reader.read_value()?;
+        if reader.pos != bytes.len() {
+            return Err(DisplayListDumpError::Binary(reader.pos, "trailing bytes after value"));
+        }
+        Ok(value)
+    }
+}
+
+fn write_varint(out: &mut Vec<u8>, mut v: u64) {
+    loop {
+        let byte = (v & 0x7f) as u8;
+        v >>= 7;
+        if v == 0 {
+            out.push(byte);
+            return;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+struct BinaryReader<'a> {
+    input: &'a [u8],
+    pos: usize,
+    keys: Vec<String>,
+    /// number of arrays / objects the reader is currently in
+    depth: usize,
+}
+
+impl<'a> BinaryReader<'a> {
+
+    fn error<T>(&self, e: &'static str) -> Result<T, DisplayListDumpError> {
+        Err(DisplayListDumpError::Binary(self.pos, e))
+    }
+
+    fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], DisplayListDumpError> {
+        match self.input.get(self.pos..self.pos.saturating_add(len)) {
+            Some(s) => { self.pos += len; Ok(s) },
+            None => self.error("unexpected end of input"),
+        }
+    }
+
+    fn read_varint(&mut self) -> Result<u64, DisplayListDumpError> {
+        let mut result = 0_u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.read_bytes(1)?[0];
+            result |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(result);
+            }
+        }
+        self.error("varint too long")
+    }
+
+    fn read_string(&mut self) -> Result<String, DisplayListDumpError> {
+        let len = self.read_varint()? as usize;
+        let bytes = self.read_bytes(len)?;
+        String::from_utf8(bytes.to_vec()).or_else(|_| self.error("invalid UTF-8 in string"))
+    }
+
+    fn read_value(&mut self) -> Result<DisplayListValue, DisplayListDumpError> {
+        match self.read_bytes(1)?[0] {
+            tag @ TAG_ARRAY | tag @ TAG_OBJECT => {
+                if self.depth >= MAX_NESTING_DEPTH {
+                    return self.error("nesting too deep");
+                }
+                self.depth += 1;
+                let value = if tag == TAG_ARRAY { self.read_array() } else { self.read_object() };
+                self.depth -= 1;
+                value
+            },
+            TAG_NULL => Ok(DisplayListValue::Null),
+            TAG_FALSE => Ok(DisplayListValue::Bool(false)),
+            TAG_TRUE => Ok(DisplayListValue::Bool(true)),
+            TAG_INT => {
+                let v = self.read_varint()?;
+                Ok(DisplayListValue::Int(((v >> 1) as i64) ^ -((v & 1) as i64)))
+            },
+            TAG_FLOAT => {
+                let b = self.read_bytes(4)?;
+                Ok(DisplayListValue::Float(f32::from_le_bytes([b[0], b[1], b[2], b[3]])))
+            },
+            TAG_STRING => Ok(DisplayListValue::String(self.read_string()?)),
+            _ => self.error("invalid value tag"),
+        }
+    }
+
+    fn read_array(&mut self) -> Result<DisplayListValue, DisplayListDumpError> {
+        let len = self.read_varint()? as usize;
+        let mut items = Vec::new();
+        for _ in 0..len {
+            items.push(self.read_value()?);
+        }
+        Ok(DisplayListValue::Array(items))
+    }
+
+    fn read_object(&mut self) -> Result<DisplayListValue, DisplayListDumpError> {
+        let len = self.read_varint()? as usize;
+        let mut fields = Vec::new();
+        for _ in 0..len {
+            let index = self.read_varint()? as usize;
+            let key = if index == self.keys.len() {
+                let key = self.read_string()?;
+                self.keys.push(key.clone());
+                key
+            } else {
+                match self.keys.get(index) {
+                    Some(k) => k.clone(),
+                    None => return self.error("invalid key index"),
+                }
+            };
+            fields.push((key, self.read_value()?));
+        }
+        Ok(DisplayListValue::Object(fields))
+    }
+}
+
+/// Loads a JSON or binary dump (detected by the header) and checks the format and version
+pub fn load_display_list_dump(bytes: &[u8]) -> Result<DisplayListValue, DisplayListDumpError> {
+
+    let value = if bytes.starts_with(DISPLAY_LIST_BINARY_MAGIC) {
+        DisplayListValue::from_binary(bytes)?
+    } else {
+        let json = core::str::from_utf8(bytes).map_err(|e| DisplayListDumpError::Json(e.valid_up_to(), "invalid UTF-8"))?;
+        DisplayListValue::from_json(json)?
+    };
+
+    if value.get("format") != Some(&DisplayListValue::String(DISPLAY_LIST_DUMP_FORMAT.into())) {
+        return Err(DisplayListDumpError::NotADisplayList);
+    }
+
+    match value.get("version") {
+        Some(DisplayListValue::Int(v)) if *v >= 1 && *v <= DISPLAY_LIST_DUMP_VERSION as i64 => Ok(value),
+        Some(DisplayListValue::Int(v)) => Err(DisplayListDumpError::UnsupportedVersion(*v as u32)),
+        _ => Err(DisplayListDumpError::NotADisplayList),
+    }
+}
+
+// -- structural diff
+
+/// One difference between two dumps, `path` is i.e. `root.children[2].content[0].color`
+#[derive(Debug, Clone, PartialEq)]
+pub enum DisplayListDiff {
+    Added { path: String, value: DisplayListValue },
+    Removed { path: String, value: DisplayListValue },
+    Changed { path: String, old: DisplayListValue, new: DisplayListValue },
+}
+
+impl fmt::Display for DisplayListDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DisplayListDiff::Added { path, value } => write!(f, "+ {}: {}", path, summarize(value)),
+            DisplayListDiff::Removed { path, value } => write!(f, "- {}: {}", path, summarize(value)),
+            DisplayListDiff::Changed { path, old, new } => write!(f, "~ {}: {} -> {}", path, summarize(old), summarize(new)),
+        }
+    }
+}
+
+/// Shortens added / removed subtrees to one line
+fn summarize(value: &DisplayListValue) -> String {
+    const MAX_LEN: usize = 100;
+    let s = value.to_json_compact();
+    if s.chars().count() > MAX_LEN {
+        let mut short = s.chars().take(MAX_LEN).collect::<String>();
+        short.push_str("...");
+        short
+    } else {
+        s
+    }
+}
+
+/// Reports the structural differences between two dumps
+///
+/// Arrays (children, content, glyphs) are aligned by their longest common
+/// subsequence, so inserting a node only reports the inserted node instead of
+/// every following sibling (very large arrays are compared by position). If `strict` is not set, IDs generated at runtime
+/// (image / font keys, hit-testing tags) are ignored.
+pub fn diff_display_lists(a: &DisplayListValue, b: &DisplayListValue, strict: bool) -> Vec<DisplayListDiff> {
+    let mut diffs = Vec::new();
+    diff_values("", a, b, strict, &mut diffs);
+    diffs
+}
+
+fn values_equal(a: &DisplayListValue, b: &DisplayListValue, strict: bool) -> bool {
+    use self::DisplayListValue::*;
+    match (a, b) {
+        (Array(a), Array(b)) => a.len() == b.len() && a.iter().zip(b.iter()).all(|(a, b)| values_equal(a, b, strict)),
+        (Object(a), Object(b)) => {
+            let a = a.iter().filter(|(k, _)| strict || !UNSTABLE_FIELDS.contains(&k.as_str())).collect::<Vec<_>>();
+            let b = b.iter().filter(|(k, _)| strict || !UNSTABLE_FIELDS.contains(&k.as_str())).collect::<Vec<_>>();
+            a.len() == b.len() && a.iter().zip(b.iter()).all(|((ka, va), (kb, vb))| ka == kb && values_equal(va, vb, strict))
+        },
+        (a, b) => a == b,
+    }
+}
+
+fn diff_values(path: &str, a: &DisplayListValue, b: &DisplayListValue, strict: bool, diffs: &mut Vec<DisplayListDiff>) {
+
+    use self::DisplayListValue::*;
+
+    match (a, b) {
+        (Object(fields_a), Object(fields_b)) => {
+            for (key, value_a) in fields_a.iter() {
+                if !strict && UNSTABLE_FIELDS.contains(&key.as_str()) {
+                    continue;
+                }
+                let child_path = if path.is_empty() { key.clone() } else { format!("{}.{}", path, key) };
+                match b.get(key) {
+                    Some(value_b) => diff_values(&child_path, value_a, value_b, strict, diffs),
+                    None => diffs.push(DisplayListDiff::Removed { path: child_path, value: value_a.clone() }),
+                }
+            }
+            for (key, value_b) in fields_b.iter() {
+                if (!strict && UNSTABLE_FIELDS.contains(&key.as_str())) || a.get(key).is_some() {
+                    continue;
+                }
+                let child_path = if path.is_empty() { key.clone() } else { format!("{}.{}", path, key) };
+                diffs.push(DisplayListDiff::Added { path: child_path, value: value_b.clone() });
+            }
+        },
+        (Array(items_a), Array(items_b)) => diff_arrays(path, items_a, items_b, strict, diffs),
+        (a, b) => {
+            if a != b {
+                diffs.push(DisplayListDiff::Changed { path: path.into(), old: a.clone(), new: b.clone() });
+            }
+        },
+    }
+}
+
+fn diff_arrays(path: &str, a: &[DisplayListValue], b: &[DisplayListValue], strict: bool, diffs: &mut Vec<DisplayListDiff>) {
+
+    // unmatched items between two matched items are compared pairwise,
+    // the rest is reported as added / removed
+    let flush_gap = |gap_a: &mut Vec<usize>, gap_b: &mut Vec<usize>, diffs: &mut Vec<DisplayListDiff>| {
+        let paired = gap_a.len().min(gap_b.len());
+        for k in 0..paired {
+            diff_values(&format!("{}[{}]", path, gap_a[k]), &a[gap_a[k]], &b[gap_b[k]], strict, diffs);
+        }
+        for i in gap_a.iter().skip(paired) {
+            diffs.push(DisplayListDiff::Removed { path: format!("{}[{}]", path, i), value: a[*i].clone() });
+        }
+        for j in gap_b.iter().skip(paired) {
+            diffs.push(DisplayListDiff::Added { path: format!("{}[{}]", path, j), value: b[*j].clone() });
+        }
+        gap_a.clear();
+        gap_b.clear();
+    };
+
+    // the LCS table is O(n * m) in time and memory: compare very large arrays by position
+    if a.len().saturating_mul(b.len()) > MAX_LCS_TABLE_SIZE {
+        flush_gap(&mut (0..a.len()).collect(), &mut (0..b.len()).collect(), diffs);
+        return;
+    }
+
+    // lcs[i][j] = length of the longest common subsequence of a[i..] and b[j..]
+    let mut lcs = vec![vec![0_usize; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if values_equal(&a[i], &b[j], strict) {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut gap_a = Vec::new();
+    let mut gap_b = Vec::new();
+
+    while i < a.len() && j < b.len() {
+        if values_equal(&a[i], &b[j], strict) {
+            flush_gap(&mut gap_a, &mut gap_b, diffs);
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            gap_a.push(i);
+            i += 1;
+        } else {
+            gap_b.push(j);
+            j += 1;
+        }
+    }
+
+    gap_a.extend(i..a.len());
+    gap_b.extend(j..b.len());
+    flush_gap(&mut gap_a, &mut gap_b, diffs);
+}
+
+#[test]
+fn test_display_list_dump_roundtrip() {
+
+    use azul_core::display_list::RectBackground;
+    use azul_css::{LayoutPoint, LayoutSize};
+
+    let mut root = DisplayListFrame::root(LayoutSize::new(400, 300), LayoutPoint::zero());
+    root.content.push(LayoutRectContent::Background {
+        content: RectBackground::Color(ColorU { r: 255, g: 0, b: 0, a: 255 }),
+        size: None,
+        offset: None,
+        repeat: None,
+    });
+    root.children.push(DisplayListMsg::Frame(DisplayListFrame::root(LayoutSize::new(100, 50), LayoutPoint::new(10, 20))));
+
+    let display_list = CachedDisplayList {
+        root: DisplayListMsg::Frame(root),
+        root_size: LogicalSize::new(400.0, 300.0),
+    };
+
+    let dump = serialize_display_list(&display_list);
+    assert_eq!(load_display_list_dump(dump.to_json().as_bytes()), Ok(dump.clone()));
+    assert_eq!(load_display_list_dump(&dump.to_binary()), Ok(dump.clone()));
+    assert_eq!(diff_display_lists(&dump, &dump, true), Vec::new());
+}
+
+#[test]
+fn test_display_list_diff_inserted_child() {
+
+    fn frame(width: f32) -> DisplayListValue {
+        DisplayListValue::Object(vec![field("width", float(width))])
+    }
+
+    let a = DisplayListValue::Object(vec![
+        field("children", DisplayListValue::Array(vec![frame(1.0), frame(2.0), frame(3.0)])),
+    ]);
+    let b = DisplayListValue::Object(vec![
+        field("children", DisplayListValue::Array(vec![frame(1.0), frame(5.0), frame(2.0), frame(4.0)])),
+    ]);
+
+    let diffs = diff_display_lists(&a, &b, false).iter().map(|d| d.to_string()).collect::<Vec<_>>();
+    assert_eq!(diffs, vec![
+        String::from("+ children[1]: {\"width\":5.0}"),
+        String::from("~ children[2].width: 3.0 -> 4.0"),
+    ]);
+}
+
+#[test]
+fn test_display_list_dump_nesting_limit() {
+
+    let nested_json = |depth: usize| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+    assert!(DisplayListValue::from_json(&nested_json(MAX_NESTING_DEPTH)).is_ok());
+    assert_eq!(
+        DisplayListValue::from_json(&nested_json(MAX_NESTING_DEPTH + 1)),
+        Err(DisplayListDumpError::Json(MAX_NESTING_DEPTH, "nesting too deep")),
+    );
+
+    let nested_binary = |depth: usize| {
+        let mut bytes = DISPLAY_LIST_BINARY_MAGIC.to_vec();
+        bytes.extend_from_slice(&DISPLAY_LIST_DUMP_VERSION.to_le_bytes());
+        for _ in 1..depth {
+            bytes.extend_from_slice(&[TAG_ARRAY, 1]);
+        }
+        bytes.extend_from_slice(&[TAG_ARRAY, 0]);
+        bytes
+    };
+    assert!(DisplayListValue::from_binary(&nested_binary(MAX_NESTING_DEPTH)).is_ok());
+    assert!(match DisplayListValue::from_binary(&nested_binary(MAX_NESTING_DEPTH + 1)) {
+        Err(DisplayListDumpError::Binary(_, "nesting too deep")) => true,
+        _ => false,
+    });
+}
+
+#[test]
+fn test_display_list_diff_large_arrays_by_position() {
+
+    let a = (0..2001).map(|i| int(i)).collect::<Vec<_>>();
+    let b = core::iter::once(int(-1)).chain(a.iter().cloned()).collect::<Vec<_>>();
+    assert!(a.len() * b.len() > MAX_LCS_TABLE_SIZE);
+
+    let diffs = diff_display_lists(&DisplayListValue::Array(a), &DisplayListValue::Array(b), false);
+    assert_eq!(diffs.len(), 2002);
+    assert_eq!(diffs[0], DisplayListDiff::Changed { path: "[0]".into(), old: int(0), new: int(-1) });
+    assert_eq!(diffs[2001], DisplayListDiff::Added { path: "[2001]".into(), value: int(2000) });
+}
@@ -0,0 +1,729 @@
+//! Canonical source formatter for Azul XML and CSS files (`azulc fmt`)
+//!
+//! The formatter works on the source text instead of the parsed `Css` / `XmlNode`
+//! structures, so that comments survive the round-trip. Each CSS declaration is
+//! run through the regular CSS parser: shorthands are expanded into their
+//! longhand properties, values are re-printed in their canonical form and
+//! declarations are sorted by their `CssPropertyType`. If the canonical form
+//! of a value doesn't parse back to the same property, the original value text
+//! is kept, so formatting never changes the meaning of a stylesheet.
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use azul_css::{
+    CssDeclaration, CssKeyMap, CssPath, CssPathSelector, CssProperty,
+    CssPropertyType, CssPropertyValue, StyleFontFamily,
+};
+use azul_css_parser::{CssParseWarnMsg, ErrorLocation};
+use xmlparser::Tokenizer;
+
+/// Indentation used for one nesting level, both in XML and CSS
+const INDENT: &str = "    ";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FmtError {
+    /// `/*` without a matching `*/`, position = byte offset of the `/*`
+    UnterminatedComment(usize),
+    /// `{` without a matching `}`, position = byte offset of the `{`
+    UnclosedBlock(usize),
+    /// `}` without a matching `{`
+    UnexpectedBlockEnd(usize),
+    /// Declaration without a `:`, i.e. `div { width }`
+    MissingColon(usize),
+    /// Error tokenizing the XML file
+    Xml(String),
+    /// Closing tag doesn't match the opening tag
+    MalformedHierarchy { expected: String, got: String },
+}
+
+impl_display!(FmtError, {
+    UnterminatedComment(pos) => format!("unterminated comment starting at byte {}", pos),
+    UnclosedBlock(pos) => format!("unclosed block starting at byte {}", pos),
+    UnexpectedBlockEnd(pos) => format!("unexpected \"}}\" at byte {}", pos),
+    MissingColon(pos) => format!("expected \":\" in declaration at byte {}", pos),
+    Xml(e) => format!("could not tokenize XML: {}", e),
+    MalformedHierarchy { expected, got } => format!("expected closing tag </{}>, got </{}>", expected, got),
+});
+
+/// Formats a standalone CSS file
+pub fn format_css(css: &str) -> Result<String, FmtError> {
+    let items = parse_css_items(css)?;
+    Ok(print_css_items(&items, 0))
+}
+
+/// Formats an XML file, including the CSS inside of `<style>` blocks
+pub fn format_xml(xml: &str) -> Result<String, FmtError> {
+    let nodes = parse_fmt_xml_nodes(xml)?;
+    let mut s = String::new();
+    print_xml_nodes(&nodes, 0, &mut s)?;
+    Ok(s)
+}
+
+// --- CSS
+
+#[derive(Debug, Clone, PartialEq)]
+enum CssItem<'a> {
+    /// `/* ... */`, including the comment delimiters
+    Comment(&'a str),
+    /// `@keyframes { ... }` or `@import "a";` - not supported by the parser, kept verbatim
+    AtRule(&'a str),
+    /// `div, p { ... }`
+    Rule { selectors: &'a str, body: Vec<CssBodyItem<'a>> },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum CssBodyItem<'a> {
+    Comment(&'a str),
+    Declaration { key: &'a str, value: &'a str },
+}
+
+/// Splits a stylesheet into top-level comments, at-rules and rule blocks
+fn parse_css_items<'a>(css: &'a str) -> Result<Vec<CssItem<'a>>, FmtError> {
+
+    let bytes = css.as_bytes();
+    let mut items = Vec::new();
+    let mut pos = 0;
+
+    loop {
+        pos = skip_whitespace(bytes, pos);
+        if pos >= bytes.len() {
+            break;
+        }
+
+        if bytes[pos..].starts_with(b"/*") {
+            let end = find_comment_end(css, pos)?;
+            items.push(CssItem::Comment(&css[pos..end]));
+            pos = end;
+        } else if bytes[pos] == b'@' {
+            let end = find_at_rule_end(css, pos)?;
+            items.push(CssItem::AtRule(css[pos..end].trim()));
+            pos = end;
+        } else if bytes[pos] == b'}' {
+            return Err(FmtError::UnexpectedBlockEnd(pos));
+        } else {
+            let block_start = match find_unquoted(css, pos, b'{') {
+                Some(s) => s,
+                None => return Err(FmtError::UnclosedBlock(pos)),
+            };
+            let block_end = find_block_end(css, block_start)?;
+            items.push(CssItem::Rule {
+                selectors: css[pos..block_start].trim(),
+                body: parse_css_body(css, block_start + 1, block_end)?,
+            });
+            pos = block_end + 1;
+        }
+    }
+
+    Ok(items)
+}
+
+/// Parses the declarations and comments between the `{` and `}` of a rule block
+fn parse_css_body<'a>(css: &'a str, start: usize, end: usize) -> Result<Vec<CssBodyItem<'a>>, FmtError> {
+
+    let bytes = css.as_bytes();
+    let mut items = Vec::new();
+    let mut pos = start;
+
+    loop {
+        pos = skip_whitespace(bytes, pos);
+        if pos >= end {
+            break;
+        }
+
+        if bytes[pos..].starts_with(b"/*") {
+            let comment_end = find_comment_end(css, pos)?;
+            items.push(CssBodyItem::Comment(&css[pos..comment_end]));
+            pos = comment_end;
+            continue;
+        }
+
+        if bytes[pos] == b';' {
+            pos += 1;
+            continue;
+        }
+
+        let declaration_end = find_unquoted(&css[..end], pos, b';').unwrap_or(end);
+        let declaration = &css[pos..declaration_end];
+        let colon = match declaration.find(':') {
+            Some(s) => s,
+            None => return Err(FmtError::MissingColon(pos)),
+        };
+
+        items.push(CssBodyItem::Declaration {
+            key: declaration[..colon].trim(),
+            value: declaration[(colon + 1)..].trim(),
+        });
+
+        pos = declaration_end + 1;
+    }
+
+    Ok(items)
+}
+
+fn skip_whitespace(bytes: &[u8], mut pos: usize) -> usize {
+    while pos < bytes.len() && bytes[pos].is_ascii_whitespace() {
+        pos += 1;
+    }
+    pos
+}
+
+/// Returns the byte offset directly after the `*/` of the comment starting at `pos`
+fn find_comment_end(css: &str, pos: usize) -> Result<usize, FmtError> {
+    css[(pos + 2)..]
+    .find("*/")
+    .map(|e| pos + 2 + e + 2)
+    .ok_or(FmtError::UnterminatedComment(pos))
+}
+
+/// Finds the next `needle` byte that is not inside of a quoted string or a comment
+fn find_unquoted(css: &str, mut pos: usize, needle: u8) -> Option<usize> {
+
+    let bytes = css.as_bytes();
+    let mut quote = None;
+
+    while pos < bytes.len() {
+        let c = bytes[pos];
+        match quote {
+            Some(q) => if c == q { quote = None; },
+            None => {
+                if c == needle {
+                    return Some(pos);
+                } else if c == b'"' || c == b'\'' {
+                    quote = Some(c);
+                } else if bytes[pos..].starts_with(b"/*") {
+                    pos = find_comment_end(css, pos).ok()? - 1;
+                }
+            }
+        }
+        pos += 1;
+    }
+
+    None
+}
+
+/// Given the position of a `{`, returns the position of the matching `}`
+fn find_block_end(css: &str, block_start: usize) -> Result<usize, FmtError> {
+
+    let mut depth = 0_usize;
+    let mut pos = block_start;
+
+    loop {
+        let next_open = find_unquoted(css, pos, b'{');
+        let next_close = find_unquoted(css, pos, b'}');
+        match (next_open, next_close) {
+            (Some(o), Some(c)) if o < c => {
+                depth += 1;
+                pos = o + 1;
+            },
+            (_, Some(c)) => {
+                depth -= 1;
+                if depth == 0 {
+                    return Ok(c);
+                }
+                pos = c + 1;
+            },
+            (_, None) => return Err(FmtError::UnclosedBlock(block_start)),
+        }
+    }
+}
+
+/// Returns the end of an at-rule, i.e. either the position after the
+/// terminating `;` or after the closing `}` of the at-rule block
+fn find_at_rule_end(css: &str, pos: usize) -> Result<usize, FmtError> {
+    let semicolon = find_unquoted(css, pos, b';');
+    let block_start = find_unquoted(css, pos, b'{');
+    match (semicolon, block_start) {
+        (Some(s), Some(b)) if s < b => Ok(s + 1),
+        (_, Some(b)) => Ok(find_block_end(css, b)? + 1),
+        (Some(s), None) => Ok(s + 1),
+        (None, None) => Ok(css.len()),
+    }
+}
+
+fn print_css_items(items: &[CssItem], indent: usize) -> String {
+
+    let css_key_map = azul_css::get_css_key_map();
+    let tabs = INDENT.repeat(indent);
+    let mut s = String::new();
+
+    for (item_idx, item) in items.iter().enumerate() {
+        match item {
+            CssItem::Comment(c) => {
+                push_comment(&mut s, c, &tabs);
+            },
+            CssItem::AtRule(a) => {
+                for line in a.lines() {
+                    s.push_str(&format!("{}{}\n", tabs, line.trim_end()));
+                }
+            },
+            CssItem::Rule { selectors, body } => {
+                let selectors = selectors
+                    .split(',')
+                    .map(|sel| format_css_selector(sel))
+                    .collect::<Vec<_>>();
+                s.push_str(&format!("{}{} {{\n", tabs, selectors.join(&format!(",\n{}", tabs))));
+                s.push_str(&format_css_rule_body(body, indent + 1, &css_key_map));
+                s.push_str(&format!("{}}}\n", tabs));
+            }
+        }
+
+        // separate rule blocks with one empty line, comments stick to the next item
+        let is_last = item_idx == items.len() - 1;
+        match item {
+            CssItem::Comment(_) => { },
+            _ if !is_last => s.push_str("\n"),
+            _ => { },
+        }
+    }
+
+    s
+}
+
+fn push_comment(s: &mut String, comment: &str, tabs: &str) {
+    for line in comment.lines() {
+        s.push_str(&format!("{}{}\n", tabs, line.trim()));
+    }
+}
+
+/// Normalizes a single selector path such as `div>  p.class` -> `div > p.class`
+fn format_css_selector(selector: &str) -> String {
+
+    use azul_css_parser::parse_css_path;
+
+    let selector = selector.trim();
+
+    let path = match parse_css_path(selector) {
+        Ok(o) => o,
+        // selector isn't supported by azul, only collapse the whitespace
+        Err(_) => return selector.split_whitespace().collect::<Vec<_>>().join(" "),
+    };
+
+    print_css_path(&path)
+}
+
+/// Prints a `CssPath` with spaces around the `>` combinator (`CssPath`s `Display`
+/// implementation prints `div>p`, which is valid, but harder to read)
+pub(crate) fn print_css_path(path: &CssPath) -> String {
+    let mut s = String::new();
+    for selector in path.selectors.as_ref() {
+        match selector {
+            CssPathSelector::DirectChildren => s.push_str(" > "),
+            CssPathSelector::Children => s.push_str(" "),
+            other => s.push_str(&format!("{}", other)),
+        }
+    }
+    collapse_whitespace(&s)
+}
+
+/// One formatted declaration, along with the comments directly above it
+struct FormattedDeclaration {
+    comments: Vec<String>,
+    /// `None` for properties unknown to azul: they are kept in source order after the known ones
+    property_type: Option<CssPropertyType>,
+    text: String,
+}
+
+fn format_css_rule_body(body: &[CssBodyItem], indent: usize, css_key_map: &CssKeyMap) -> String {
+
+    let tabs = INDENT.repeat(indent);
+
+    // The parser stores the declarations of a block in a map sorted by key
+    // and later declarations of the same CssPropertyType override earlier ones,
+    // so reproduce that order before de-duplicating
+    let mut pending_comments = Vec::new();
+    let mut by_key = BTreeMap::new();
+    let mut unknown_keys = Vec::new();
+
+    for item in body {
+        match item {
+            CssBodyItem::Comment(c) => pending_comments.push(c.to_string()),
+            CssBodyItem::Declaration { key, value } => {
+                let key = key.to_lowercase();
+                let comments = core::mem::replace(&mut pending_comments, Vec::new());
+                let is_known = CssPropertyType::from_str(&key, css_key_map).is_some() ||
+                    azul_css::CombinedCssPropertyType::from_str(&key, css_key_map).is_some();
+                if is_known {
+                    // same key twice in one block: the last value wins, but keep both comments
+                    let entry = by_key.entry(key).or_insert_with(|| ("", Vec::new()));
+                    entry.0 = *value;
+                    entry.1.extend(comments.into_iter());
+                } else {
+                    unknown_keys.push(FormattedDeclaration {
+                        comments,
+                        property_type: None,
+                        text: format!("{}: {};", key, collapse_whitespace(value)),
+                    });
+                }
+            }
+        }
+    }
+
+    let mut declarations = Vec::<FormattedDeclaration>::new();
+
+    for (key, (value, comments)) in by_key {
+        let formatted = format_css_declaration(&key, value, css_key_map);
+        let mut comments = Some(comments);
+        for (property_type, text) in formatted {
+            // later declarations with the same type override earlier ones
+            let mut c = comments.take().unwrap_or_default();
+            let existing = match property_type {
+                Some(_) => declarations.iter().position(|d| d.property_type == property_type),
+                None => None,
+            };
+            if let Some(existing) = existing {
+                let mut old = declarations.remove(existing);
+                old.comments.append(&mut c);
+                c = old.comments;
+            }
+            declarations.push(FormattedDeclaration { comments: c, property_type, text });
+        }
+    }
+
+    declarations.sort_by(|a, b| a.property_type.cmp(&b.property_type));
+    declarations.extend(unknown_keys.into_iter());
+
+    let mut s = String::new();
+    for d in declarations.iter() {
+        for c in d.comments.iter() {
+            push_comment(&mut s, c, &tabs);
+        }
+        s.push_str(&format!("{}{}\n", tabs, d.text));
+    }
+    for c in pending_comments.iter() {
+        push_comment(&mut s, c, &tabs);
+    }
+
+    s
+}
+
+/// Formats one `key: value` pair, expanding shorthands into their longhand properties.
+///
+/// Returns the original (whitespace-normalized) declaration if the value can't be parsed
+/// or if the canonical form doesn't parse back to the same value.
+fn format_css_declaration(key: &str, value: &str, css_key_map: &CssKeyMap) -> Vec<(Option<CssPropertyType>, String)> {
+
+    use azul_css_parser::parse_css_declaration;
+
+    let fallback = || {
+        let property_type = CssPropertyType::from_str(key, css_key_map);
+        vec![(property_type, format!("{}: {};", key, collapse_whitespace(value)))]
+    };
+
+    let location = (ErrorLocation::default(), ErrorLocation::default());
+    let mut warnings = Vec::<CssParseWarnMsg>::new();
+    let mut parsed = Vec::new();
+
+    if parse_css_declaration(key, value, location, css_key_map, &mut warnings, &mut parsed).is_err() || parsed.is_empty() {
+        return fallback();
+    }
+
+    let mut formatted = Vec::new();
+
+    for declaration in parsed.iter() {
+        let property_type = declaration.get_type();
+        let text = match declaration {
+            CssDeclaration::Static(prop) => format!("{}: {};", prop.key(), format_css_value(prop).trim()),
+            CssDeclaration::Dynamic(d) => format!(
+                "{}: var(--{}, {});",
+                d.default_value.key(), d.dynamic_id.as_str(), format_css_value(&d.default_value).trim(),
+            ),
+        };
+
+        // verify that the canonical form parses back to the same declaration
+        let text_key = property_type.to_str();
+        let text_value = text[(text_key.len() + 1)..].trim().trim_end_matches(';');
+        let mut reparse_warnings = Vec::new();
+        let mut reparsed = Vec::new();
+        let reparse_ok = parse_css_declaration(
+            text_key, text_value, location, css_key_map, &mut reparse_warnings, &mut reparsed
+        ).is_ok();
+        if !reparse_ok || reparsed != vec![declaration.clone()] {
+            return fallback();
+        }
+
+        formatted.push((Some(property_type), text));
+    }
+
+    formatted
+}
+
+/// Like `CssProperty::value()`, but quotes font names that contain spaces
+fn format_css_value(prop: &CssProperty) -> String {
+    match prop {
+        CssProperty::FontFamily(CssPropertyValue::Exact(families)) => {
+            families
+            .iter()
+            .map(|f| match f {
+                StyleFontFamily::System(s) if s.as_str().contains(char::is_whitespace) => format!("\"{}\"", s.as_str()),
+                StyleFontFamily::System(s) => s.as_str().to_string(),
+                other => CssProperty::FontFamily(CssPropertyValue::Exact(vec![other.clone()].into())).value(),
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+        },
+        other => other.value(),
+    }
+}
+
+fn collapse_whitespace(s: &str) -> String {
+    s.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// --- XML
+
+#[derive(Debug, Clone, PartialEq)]
+enum FmtXmlNode<'a> {
+    /// `<?xml ... ?>`, `<!DOCTYPE ...>`, etc. - printed verbatim
+    Verbatim(&'a str),
+    Comment(&'a str),
+    Text(&'a str),
+    Cdata(&'a str),
+    Element {
+        name: &'a str,
+        attributes: Vec<(&'a str, &'a str)>,
+        children: Vec<FmtXmlNode<'a>>,
+    },
+}
+
+/// Tokenizes the XML into a tree that, unlike `XmlNode`, keeps
+/// comments, the original tag casing and the order of text nodes
+fn parse_fmt_xml_nodes<'a>(xml: &'a str) -> Result<Vec<FmtXmlNode<'a>>, FmtError> {
+
+    use xmlparser::Token::*;
+    use xmlparser::ElementEnd::*;
+
+    // stack of currently open elements: (name, attributes, children),
+    // the first item is a pseudo-element holding the root nodes
+    let mut stack: Vec<(&'a str, Vec<(&'a str, &'a str)>, Vec<FmtXmlNode<'a>>)> = vec![("", Vec::new(), Vec::new())];
+
+    for token in Tokenizer::from_fragment(xml, 0..xml.len()) {
+
+        let token = token.map_err(|e| FmtError::Xml(format!("{}", e)))?;
+
+        let node = match token {
+            Declaration { span, .. } |
+            ProcessingInstruction { span, .. } |
+            EmptyDtd { span, .. } |
+            DtdStart { span, .. } |
+            EntityDeclaration { span, .. } |
+            DtdEnd { span } => FmtXmlNode::Verbatim(span.as_str()),
+            Comment { span, .. } => FmtXmlNode::Comment(span.as_str()),
+            Cdata { span, .. } => FmtXmlNode::Cdata(span.as_str()),
+            Text { text } => FmtXmlNode::Text(text.as_str()),
+            ElementStart { span, .. } => {
+                // span = "<prefix:local"
+                stack.push((&span.as_str()[1..], Vec::new(), Vec::new()));
+                continue;
+            },
+            Attribute { span, value, .. } => {
+                // span = "prefix:local='value'"
+                let key = span.as_str().split('=').next().unwrap_or("").trim();
+                if let Some(last) = stack.last_mut() { last.1.push((key, value.as_str())); }
+                continue;
+            },
+            ElementEnd { end: Open, .. } => continue,
+            ElementEnd { end: Empty, .. } => {
+                match pop_fmt_xml_element(&mut stack) {
+                    Some(s) => s,
+                    None => return Err(FmtError::Xml(format!("unexpected end of element"))),
+                }
+            },
+            ElementEnd { end: Close(..), span } => {
+                // span = "</prefix:local>"
+                let close_name = span.as_str()[2..].trim_end_matches('>').trim();
+                let open_name = stack.last().map(|s| s.0).unwrap_or("");
+                if stack.len() < 2 || open_name != close_name {
+                    return Err(FmtError::MalformedHierarchy {
+                        expected: open_name.to_string(),
+                        got: close_name.to_string(),
+                    });
+                }
+                match pop_fmt_xml_element(&mut stack) {
+                    Some(s) => s,
+                    None => return Err(FmtError::Xml(format!("unexpected closing tag </{}>", close_name))),
+                }
+            },
+        };
+
+        if let Some(last) = stack.last_mut() {
+            last.2.push(node);
+        }
+    }
+
+    if stack.len() != 1 {
+        let open_name = stack.last().map(|s| s.0).unwrap_or("");
+        return Err(FmtError::MalformedHierarchy { expected: open_name.to_string(), got: String::new() });
+    }
+
+    Ok(stack.pop().map(|s| s.2).unwrap_or_default())
+}
+
+fn pop_fmt_xml_element<'a>(
+    stack: &mut Vec<(&'a str, Vec<(&'a str, &'a str)>, Vec<FmtXmlNode<'a>>)>
+) -> Option<FmtXmlNode<'a>> {
+    if stack.len() < 2 {
+        return None;
+    }
+    let (name, attributes, children) = stack.pop()?;
+    Some(FmtXmlNode::Element { name, attributes, children })
+}
+
+fn print_xml_nodes(nodes: &[FmtXmlNode], indent: usize, s: &mut String) -> Result<(), FmtError> {
+
+    let tabs = INDENT.repeat(indent);
+
+    for (node_idx, node) in nodes.iter().enumerate() {
+        match node {
+            FmtXmlNode::Verbatim(v) => {
+                s.push_str(&format!("{}{}\n", tabs, v.trim()));
+            },
+            FmtXmlNode::Comment(c) => {
+                push_comment(s, c, &tabs);
+            },
+            FmtXmlNode::Cdata(c) => {
+                s.push_str(&format!("{}{}\n", tabs, c));
+            },
+            FmtXmlNode::Text(t) if t.trim().is_empty() => {
+                // keep (at most one) empty line between two nodes
+                let is_between_nodes = node_idx != 0 && node_idx != nodes.len() - 1;
+                if is_between_nodes && t.matches('\n').count() > 1 {
+                    s.push_str("\n");
+                }
+            },
+            FmtXmlNode::Text(t) => {
+                for line in dedent_lines(t) {
+                    if line.is_empty() {
+                        s.push_str("\n");
+                    } else {
+                        s.push_str(&format!("{}{}\n", tabs, line));
+                    }
+                }
+            },
+            FmtXmlNode::Element { name, attributes, children } => {
+                print_xml_element(name, attributes, children, indent, s)?;
+            },
+        }
+    }
+
+    Ok(())
+}
+
+/// Strips the leading and trailing empty lines of a text node and removes
+/// the common indentation, so that the relative indentation is preserved
+fn dedent_lines(text: &str) -> Vec<&str> {
+
+    let lines = text.lines().map(|l| l.trim_end()).collect::<Vec<_>>();
+    let first = lines.iter().position(|l| !l.is_empty()).unwrap_or(lines.len());
+    let last = lines.iter().rposition(|l| !l.is_empty()).map(|l| l + 1).unwrap_or(first);
+    let lines = &lines[first..last];
+
+    let common_indent = lines
+        .iter()
+        .filter(|l| !l.is_empty())
+        .map(|l| l.len() - l.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    lines
+    .iter()
+    .map(|l| if l.is_empty() { *l } else { &l[common_indent..] })
+    .collect()
+}
+
+fn print_xml_element(
+    name: &str,
+    attributes: &[(&str, &str)],
+    children: &[FmtXmlNode],
+    indent: usize,
+    s: &mut String,
+) -> Result<(), FmtError> {
+
+    let tabs = INDENT.repeat(indent);
+
+    let attributes = attributes
+        .iter()
+        .map(|(k, v)| if v.contains('"') { format!(" {}='{}'", k, v) } else { format!(" {}=\"{}\"", k, v) })
+        .collect::<String>();
+
+    let non_empty_children = children
+        .iter()
+        .filter(|c| match c {
+            FmtXmlNode::Text(t) => !t.trim().is_empty(),
+            _ => true,
+        })
+        .collect::<Vec<_>>();
+
+    match non_empty_children.as_slice() {
+        [] => {
+            s.push_str(&format!("{}<{}{} />\n", tabs, name, attributes));
+        },
+        [FmtXmlNode::Text(t)] if name.eq_ignore_ascii_case("style") => {
+            s.push_str(&format!("{}<{}{}>\n", tabs, name, attributes));
+            match parse_css_items(t) {
+                Ok(items) => s.push_str(&print_css_items(&items, indent + 1)),
+                Err(_) => print_xml_nodes(children, indent + 1, s)?,
+            }
+            s.push_str(&format!("{}</{}>\n", tabs, name));
+        },
+        [FmtXmlNode::Text(t)] if !t.trim().contains('\n') => {
+            s.push_str(&format!("{}<{}{}>{}</{}>\n", tabs, name, attributes, t.trim(), name));
+        },
+        _ => {
+            s.push_str(&format!("{}<{}{}>\n", tabs, name, attributes));
+            print_xml_nodes(children, indent + 1, s)?;
+            s.push_str(&format!("{}</{}>\n", tabs, name));
+        },
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_format_css_sorts_and_expands() {
+    let input = "/* header */\n#a>.b{ height :  5px;margin:0px; /* w */ width:10px }";
+    let expected = "\
+        /* header */\n\
+        #a > .b {\n\
+        \x20   /* w */\n\
+        \x20   width: 10px;\n\
+        \x20   height: 5px;\n\
+        \x20   margin-top: 0px;\n\
+        \x20   margin-left: 0px;\n\
+        \x20   margin-right: 0px;\n\
+        \x20   margin-bottom: 0px;\n\
+        }\n";
+    assert_eq!(format_css(input).unwrap(), expected);
+    assert_eq!(format_css(expected).unwrap(), expected);
+}
+
+#[test]
+fn test_format_xml_keeps_comments() {
+    let input = "<!-- c --><html><body><p  class='x'>Hello</p><div></div></body></html>";
+    let expected = "\
+        <!-- c -->\n\
+        <html>\n\
+        \x20   <body>\n\
+        \x20       <p class=\"x\">Hello</p>\n\
+        \x20       <div />\n\
+        \x20   </body>\n\
+        </html>\n";
+    assert_eq!(format_xml(input).unwrap(), expected);
+    assert_eq!(format_xml(expected).unwrap(), expected);
+}
+
+#[test]
+fn test_format_css_non_ascii() {
+    let input = ".café{width:10px}/* naïve */ p { content: \"ü; }\" }";
+    let expected = "\
+        .café {\n\
+        \x20   width: 10px;\n\
+        }\n\
+        \n\
+        /* naïve */\n\
+        p {\n\
+        \x20   content: \"ü; }\";\n\
+        }\n";
+    assert_eq!(format_css(input).unwrap(), expected);
+    assert_eq!(format_css(expected).unwrap(), expected);
+}
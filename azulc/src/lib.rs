@@ -25,6 +25,12 @@ extern crate image as image_crate;
 
 /// XML-based DOM serialization and XML-to-Rust compiler implementation
 pub mod xml;
+/// Canonical formatter for XML and CSS source files
+pub mod fmt;
+/// Stylesheet linter: reports unused rules and unsupported properties
+pub mod lint;
+/// Versioned JSON / binary serialization of the display list and structural diffing
+pub mod display_list;
 pub mod svg;
 #[cfg(feature = "font_loading")]
 pub mod font;
@@ -0,0 +1,320 @@
+//! Stylesheet linter (`azulc lint`)
+//!
+//! Cascades the XML through a `StyledDom` and reports, for every CSS rule,
+//! whether it matches any node, whether it contains properties that are parsed
+//! but never read by the layout solver / display list and whether the rule is
+//! fully overridden by later rules or rules with a higher specificity.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use azul_core::{
+    id_tree::NodeId,
+    styled_dom::StyledDom,
+    style::rule_ends_with,
+    xml::{
+        XmlNode, XmlComponentMap, str_to_dom,
+        get_html_node, find_node_by_type,
+    },
+};
+use azul_css::{
+    Css, CssDeclaration, CssPathPseudoSelector, CssPropertyType,
+    get_specificity,
+};
+
+/// Properties that are accepted by the CSS parser, but are not (yet)
+/// read by the layout solver or the display list builder
+pub const UNSUPPORTED_PROPERTIES: &[CssPropertyType] = &[
+    CssPropertyType::Float,
+    CssPropertyType::FlexWrap,
+    CssPropertyType::FlexShrink,
+    CssPropertyType::AlignContent,
+    CssPropertyType::PerspectiveOrigin,
+    CssPropertyType::BackfaceVisibility,
+    CssPropertyType::Filter,
+    CssPropertyType::BackdropFilter,
+];
+
+/// Lint results for the whole file
+#[derive(Debug, Clone, PartialEq)]
+pub struct LintReport {
+    pub rules: Vec<RuleLint>,
+}
+
+/// Lint results for one CSS rule block
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuleLint {
+    /// Index of the stylesheet in the `Css`
+    pub stylesheet: usize,
+    /// Index of the rule in the stylesheet, in source order
+    pub rule: usize,
+    /// Formatted selector of the rule, i.e. `div > .class`
+    pub selector: String,
+    /// Number of nodes that this rule got applied to
+    pub matched_nodes: usize,
+    /// Properties in this rule that are parsed, but have no effect
+    pub unsupported_properties: Vec<CssPropertyType>,
+    /// Declarations using `var()` - dynamic CSS properties aren't applied during the cascade yet
+    pub dynamic_properties: Vec<CssPropertyType>,
+    /// Whether every declaration of this rule is overridden on every
+    /// matched node by a later rule or a rule with a higher specificity
+    pub overridden: bool,
+}
+
+impl RuleLint {
+    pub fn is_unused(&self) -> bool {
+        self.matched_nodes == 0
+    }
+
+    pub fn has_warnings(&self) -> bool {
+        self.is_unused() ||
+        self.overridden ||
+        !self.unsupported_properties.is_empty() ||
+        !self.dynamic_properties.is_empty()
+    }
+}
+
+impl LintReport {
+    pub fn has_warnings(&self) -> bool {
+        self.rules.iter().any(|r| r.has_warnings())
+    }
+
+    /// Human-readable output, one line per warning
+    pub fn to_text(&self) -> String {
+
+        let mut s = String::new();
+
+        for rule in self.rules.iter().filter(|r| r.has_warnings()) {
+            let prefix = format!("stylesheet {}, rule {} \"{}\"", rule.stylesheet, rule.rule, rule.selector);
+            if rule.is_unused() {
+                s.push_str(&format!("{}: unused - selector doesn't match any node\n", prefix));
+            }
+            if rule.overridden {
+                s.push_str(&format!("{}: overridden - every declaration is overridden by a later or more specific rule\n", prefix));
+            }
+            for p in rule.unsupported_properties.iter() {
+                s.push_str(&format!("{}: \"{}\" is parsed, but has no effect\n", prefix, p));
+            }
+            for p in rule.dynamic_properties.iter() {
+                s.push_str(&format!("{}: \"{}\" uses var(), which is ignored during cascading\n", prefix, p));
+            }
+        }
+
+        if s.is_empty() {
+            s.push_str("no warnings\n");
+        }
+
+        s
+    }
+
+    /// Machine-readable output, lists every rule (not just the ones with warnings)
+    pub fn to_json(&self) -> String {
+
+        let rules = self.rules.iter().map(|rule| {
+            format!(
+                "    {{\n        \"stylesheet\": {},\n        \"rule\": {},\n        \"selector\": {},\n        \
+                \"matched_nodes\": {},\n        \"unused\": {},\n        \"overridden\": {},\n        \
+                \"unsupported_properties\": [{}],\n        \"dynamic_properties\": [{}]\n    }}",
+                rule.stylesheet,
+                rule.rule,
+                json_string(&rule.selector),
+                rule.matched_nodes,
+                rule.is_unused(),
+                rule.overridden,
+                rule.unsupported_properties.iter().map(|p| json_string(p.to_str())).collect::<Vec<_>>().join(", "),
+                rule.dynamic_properties.iter().map(|p| json_string(p.to_str())).collect::<Vec<_>>().join(", "),
+            )
+        }).collect::<Vec<_>>();
+
+        if rules.is_empty() {
+            String::from("{\n\"rules\": []\n}\n")
+        } else {
+            format!("{{\n\"rules\": [\n{}\n]\n}}\n", rules.join(",\n"))
+        }
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Parses the `<style>` of the XML file, renders the `<body>` and lints the result
+pub fn lint_xml(root_nodes: &[XmlNode]) -> Result<LintReport, String> {
+
+    let html_node = get_html_node(root_nodes).map_err(|e| format!("{}", e))?;
+
+    let css_string = find_node_by_type(html_node.children.as_ref(), "head")
+        .and_then(|head| find_node_by_type(head.children.as_ref(), "style"))
+        .and_then(|style| style.text.as_ref().map(|s| s.as_str().to_string()))
+        .unwrap_or_default();
+
+    let css = azul_css_parser::new_from_str(&css_string).map_err(|e| format!("{}", e))?;
+
+    let styled_dom = str_to_dom(root_nodes, &mut XmlComponentMap::default())
+        .map_err(|e| format!("{}", e))?;
+
+    Ok(lint_styled_dom(&styled_dom, &css))
+}
+
+/// Lints the rules of the `css` against an already cascaded `StyledDom`
+///
+/// The `css` has to be in source order, i.e. not yet sorted by specificity.
+pub fn lint_styled_dom(styled_dom: &StyledDom, css: &Css) -> LintReport {
+
+    use azul_css::CssPathPseudoSelector::*;
+
+    struct MatchedRule {
+        stylesheet: usize,
+        rule: usize,
+        /// rules with a higher priority override rules with a lower priority
+        priority: (usize, usize),
+        path_ending: Option<Option<CssPathPseudoSelector>>,
+        matched_nodes: Vec<NodeId>,
+        properties: Vec<CssPropertyType>,
+    }
+
+    let mut matched_rules = Vec::new();
+
+    for (stylesheet_idx, stylesheet) in css.stylesheets.as_ref().iter().enumerate() {
+
+        // same order as Css::sort_by_specificity (stable sort), later rules win
+        let mut sorted = stylesheet.rules.as_ref().iter().enumerate().collect::<Vec<_>>();
+        sorted.sort_by(|(_, a), (_, b)| get_specificity(&a.path).cmp(&get_specificity(&b.path)));
+
+        for (sorted_idx, (rule_idx, rule)) in sorted.into_iter().enumerate() {
+            matched_rules.push(MatchedRule {
+                stylesheet: stylesheet_idx,
+                rule: rule_idx,
+                priority: (stylesheet_idx, sorted_idx),
+                path_ending: [None, Some(Hover), Some(Active), Some(Focus)]
+                    .iter()
+                    .find(|p| rule_ends_with(&rule.path, **p))
+                    .copied(),
+                matched_nodes: styled_dom.get_nodes_matching_css_path(&rule.path),
+                properties: rule.declarations.as_ref().iter().filter_map(|d| match d {
+                    CssDeclaration::Static(s) => Some(s.get_type()),
+                    CssDeclaration::Dynamic(_) => None,
+                }).collect(),
+            });
+        }
+    }
+
+    let mut rules = Vec::new();
+
+    for matched_rule in matched_rules.iter() {
+
+        let css_rule = &css.stylesheets.as_ref()[matched_rule.stylesheet].rules.as_ref()[matched_rule.rule];
+
+        let is_overridden_on = |node_id: &NodeId, property: &CssPropertyType| {
+            matched_rules.iter().any(|other| {
+                other.priority > matched_rule.priority &&
+                other.path_ending == matched_rule.path_ending &&
+                other.properties.contains(property) &&
+                other.matched_nodes.contains(node_id)
+            })
+        };
+
+        let overridden = !matched_rule.matched_nodes.is_empty() &&
+            !matched_rule.properties.is_empty() &&
+            matched_rule.matched_nodes.iter().all(|node_id| {
+                matched_rule.properties.iter().all(|p| is_overridden_on(node_id, p))
+            });
+
+        rules.push(RuleLint {
+            stylesheet: matched_rule.stylesheet,
+            rule: matched_rule.rule,
+            selector: crate::fmt::print_css_path(&css_rule.path),
+            matched_nodes: matched_rule.matched_nodes.len(),
+            unsupported_properties: matched_rule.properties
+                .iter()
+                .filter(|p| UNSUPPORTED_PROPERTIES.contains(p))
+                .copied()
+                .collect(),
+            dynamic_properties: css_rule.declarations.as_ref().iter().filter_map(|d| match d {
+                CssDeclaration::Static(_) => None,
+                CssDeclaration::Dynamic(d) => Some(d.default_value.get_type()),
+            }).collect(),
+            overridden,
+        });
+    }
+
+    rules.sort_by(|a, b| (a.stylesheet, a.rule).cmp(&(b.stylesheet, b.rule)));
+
+    LintReport { rules }
+}
+
+#[cfg(test)]
+fn lint_str(xml: &str) -> LintReport {
+    let root_nodes = crate::xml::parse_xml_string(xml).unwrap();
+    lint_xml(root_nodes.as_ref()).unwrap()
+}
+
+#[test]
+fn test_lint_unsupported_and_unused() {
+    let report = lint_str("\
+        <html><head><style>\
+            div { float: left; width: 10px; }\
+            p { width: 10px; }\
+        </style></head>\
+        <body><div></div></body></html>");
+
+    assert_eq!(report.rules.len(), 2);
+
+    assert_eq!(report.rules[0].selector, "div");
+    assert_eq!(report.rules[0].matched_nodes, 1);
+    assert_eq!(report.rules[0].unsupported_properties, vec![CssPropertyType::Float]);
+    assert!(!report.rules[0].is_unused());
+
+    assert_eq!(report.rules[1].selector, "p");
+    assert!(report.rules[1].is_unused());
+    assert!(report.rules[1].unsupported_properties.is_empty());
+
+    assert!(report.has_warnings());
+    assert_eq!(report.to_text(), "\
+        stylesheet 0, rule 0 \"div\": \"float\" is parsed, but has no effect\n\
+        stylesheet 0, rule 1 \"p\": unused - selector doesn't match any node\n");
+}
+
+#[test]
+fn test_lint_overridden() {
+    let report = lint_str("\
+        <html><head><style>\
+            #a { width: 5px; }\
+            div { width: 10px; }\
+        </style></head>\
+        <body><div id=\"a\"></div></body></html>");
+
+    assert_eq!(report.rules.len(), 2);
+    assert!(!report.rules[0].overridden);
+    assert!(report.rules[1].overridden);
+    assert_eq!(report.to_text(), "\
+        stylesheet 0, rule 1 \"div\": overridden - every declaration is overridden by a later or more specific rule\n");
+}
+
+#[test]
+fn test_lint_no_warnings() {
+    let report = lint_str("\
+        <html><head><style>div { width: 10px; }</style></head>\
+        <body><div></div></body></html>");
+
+    assert!(!report.has_warnings());
+    assert_eq!(report.to_text(), "no warnings\n");
+    assert_eq!(report.to_json(), "\
+        {\n\"rules\": [\n    {\n        \"stylesheet\": 0,\n        \"rule\": 0,\n        \
+        \"selector\": \"div\",\n        \"matched_nodes\": 1,\n        \"unused\": false,\n        \
+        \"overridden\": false,\n        \"unsupported_properties\": [],\n        \
+        \"dynamic_properties\": []\n    }\n]\n}\n");
+}
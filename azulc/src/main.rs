@@ -30,6 +30,8 @@ use azul_core::{
 enum Action {
     PrintHelp,
     PrintHtmlCode,
+    /// Size of the window, whether to use the original CSS instead of the computed style
+    PrintHtmlCodeWithLayout(LogicalSize, bool),
     PrintStyledDom,
     PrintRustCode,
     PrintCCode, // unimplemented, does nothing
@@ -37,19 +39,49 @@ enum Action {
     PrintPythonCode, // unimplemented, does nothing
     PrintDebugLayout(LogicalSize),
     PrintScrollClips(LogicalSize),
-    PrintDisplayList(LogicalSize),
+    PrintDisplayList(LogicalSize, DisplayListFormat),
+}
+
+/// Output format of `--display-list`
+#[derive(PartialEq)]
+enum DisplayListFormat {
+    Json,
+    Binary,
+    /// `Debug` output of the `DisplayListMsg`, not stable between versions
+    Debug,
 }
 
 fn print_help() {
     eprintln!("usage: azulc [OPTIONS] file.xml");
+    eprintln!("       azulc fmt [--check] file.xml file.css ...");
+    eprintln!("       azulc lint [--json] file.xml");
+    eprintln!("       azulc dl-diff [--strict] a.json b.json");
     eprintln!("");
     eprintln!("[OPTIONS]:");
     eprintln!("    --language=[rust | c | python | cpp | html]: compile XML file to source code");
+    eprintln!("    --language=html --with-layout WIDTHxHEIGHT [--original-css]: export the HTML with every node");
+    eprintln!("        absolutely positioned at its solved layout rectangle, for comparison with a browser");
+    eprintln!("        --original-css: style the nodes using the original stylesheet instead of the computed style");
     eprintln!("    --debug-layout WIDTHxHEIGHT: print a debug output of the layout solver");
-    eprintln!("    --display-list WIDTHxHEIGHT: print the display list given WIDTH and HEIGHT");
+    eprintln!("    --display-list WIDTHxHEIGHT [--format=debug | json | binary]: print the display list given");
+    eprintln!("        WIDTH and HEIGHT as Debug output (default), versioned JSON or compact binary");
     eprintln!("    --scroll-clips WIDTHxHEIGHT: print the overflowing scroll clips given WIDTH and HEIGHT");
     eprintln!("    --cascade: print the cascaded styled DOM");
     eprintln!("");
+    eprintln!("[fmt]:");
+    eprintln!("    formats XML and CSS files in place (files ending in .css are formatted as CSS)");
+    eprintln!("    --check: don't write the files, exit with an error if any file isn't formatted");
+    eprintln!("");
+    eprintln!("[lint]:");
+    eprintln!("    reports CSS rules that don't match any node, are fully overridden by more");
+    eprintln!("    specific rules or contain properties that have no effect on layout / rendering");
+    eprintln!("    --json: print the report for every rule as JSON");
+    eprintln!("");
+    eprintln!("[dl-diff]:");
+    eprintln!("    reports the structural differences between two display list dumps (written by");
+    eprintln!("    --display-list --format=json | binary), exits with an error if the display lists differ");
+    eprintln!("    --strict: also compare IDs generated at runtime (image / font keys, hit-test tags)");
+    eprintln!("");
     eprintln!("If OPTIONS is empty, the file will be printed to Rust code");
 }
 
@@ -67,13 +99,46 @@ fn main() {
 
     let input_file = args.last();
 
+    if args.get(1).map(|s| s.as_str()) == Some("fmt") {
+        let check = args.iter().skip(2).any(|a| a == "--check");
+        let files = args.iter().skip(2).filter(|a| !a.starts_with("--")).cloned().collect();
+        return format_files(check, files);
+    }
+
+    if args.get(1).map(|s| s.as_str()) == Some("lint") {
+        let json = args.iter().skip(2).any(|a| a == "--json");
+        return lint_file(json, args.iter().skip(2).filter(|a| !a.starts_with("--")).next());
+    }
+
+    if args.get(1).map(|s| s.as_str()) == Some("dl-diff") {
+        let strict = args.iter().skip(2).any(|a| a == "--strict");
+        let files = args.iter().skip(2).filter(|a| !a.starts_with("--")).cloned().collect();
+        return diff_display_list_files(strict, files);
+    }
+
     // select action
     let second_arg = args.get(1);
     let action = match second_arg.as_ref().map(|s| s.as_str()) {
         Some("--help")                  => Action::PrintHelp,
         Some("--cascade")               => Action::PrintStyledDom,
         Some("--language=rust")         => Action::PrintRustCode,
-        Some("--language=html")         => Action::PrintHtmlCode,
+        Some("--language=html")         => {
+            if args.get(2).map(|s| s.as_str()) == Some("--with-layout") {
+                let size = env::args().nth(3).expect("no layout size specified for HTML export");
+                let size_parsed = match azulc_lib::parse_display_list_size(&size) {
+                    Some(s) => s,
+                    None => {
+                        eprintln!("error: layout size \"{}\" could not be parsed", size);
+                        print_help();
+                        exit(-1);
+                    }
+                };
+                let original_css = args.iter().any(|a| a == "--original-css");
+                Action::PrintHtmlCodeWithLayout(LogicalSize::new(size_parsed.0, size_parsed.1), original_css)
+            } else {
+                Action::PrintHtmlCode
+            }
+        },
         Some("--language=c")            => Action::PrintCCode,
         Some("--language=cpp")          => Action::PrintCppCode,
         Some("--language=python")       => Action::PrintPythonCode,
@@ -111,7 +176,17 @@ fn main() {
                     exit(-1);
                 }
             };
-            Action::PrintDisplayList(LogicalSize::new(size_parsed.0, size_parsed.1))
+            let format = match args.iter().find(|a| a.starts_with("--format=")).map(|s| s.as_str()) {
+                None | Some("--format=debug") => DisplayListFormat::Debug,
+                Some("--format=json") => DisplayListFormat::Json,
+                Some("--format=binary") => DisplayListFormat::Binary,
+                Some(other) => {
+                    eprintln!("error: unknown display list format \"{}\"", other);
+                    print_help();
+                    exit(-1);
+                }
+            };
+            Action::PrintDisplayList(LogicalSize::new(size_parsed.0, size_parsed.1), format)
         },
         _ => Action::PrintRustCode,
     };
@@ -176,6 +251,30 @@ fn process(action: Action, file: Option<&String>) {
         Action::PrintHtmlCode => {
             println!("{}", styled_dom.get_html_string("", "", false));
         },
+        Action::PrintHtmlCodeWithLayout(size, original_css) => {
+            let document_id = DocumentId {
+                namespace_id: IdNamespace(0),
+                id: 0,
+            };
+            let epoch = Epoch::new();
+            let mut fake_window_state = FullWindowState::default();
+            fake_window_state.size.dimensions = size;
+            let mut renderer_resources = RendererResources::default();
+
+            let custom_head = if original_css {
+                let css = get_html_node(root_nodes.as_ref()).ok()
+                    .and_then(|html| find_node_by_type(html.children.as_ref(), "head"))
+                    .and_then(|head| find_node_by_type(head.children.as_ref(), "style"))
+                    .and_then(|style| style.text.as_ref().map(|s| s.as_str().to_string()))
+                    .unwrap_or_default();
+                format!("<style>{}</style>", css)
+            } else {
+                String::new()
+            };
+
+            let layout = solve_layout(styled_dom, size, document_id, epoch, &fake_window_state, &mut renderer_resources);
+            println!("{}", layout.styled_dom.get_html_string_with_layout(&layout.rects.as_ref(), &custom_head, "", !original_css));
+        },
         Action::PrintRustCode => {
             match get_rust_code(root_nodes.as_ref()) {
                 Ok(o) => { println!("{}", o); },
@@ -226,7 +325,11 @@ fn process(action: Action, file: Option<&String>) {
             let layout = solve_layout(styled_dom, size, document_id, epoch, &fake_window_state, &mut renderer_resources);
             println!("{:#?}", layout.scrollable_nodes);
         },
-        Action::PrintDisplayList(size) => {
+        Action::PrintDisplayList(size, format) => {
+
+            use std::io::Write;
+            use azulc_lib::display_list::serialize_display_list;
+
             let epoch = Epoch::new();
             let document_id = DocumentId {
                 namespace_id: IdNamespace(0),
@@ -249,13 +352,164 @@ fn process(action: Action, file: Option<&String>) {
                 &image_cache,
             );
 
-            println!("{:#?}", display_list.root);
+            match format {
+                DisplayListFormat::Json => {
+                    print!("{}", serialize_display_list(&display_list).to_json());
+                },
+                DisplayListFormat::Binary => {
+                    let bytes = serialize_display_list(&display_list).to_binary();
+                    if let Err(e) = std::io::stdout().write_all(&bytes) {
+                        eprintln!("error: could not write display list: {}", e);
+                        exit(-1);
+                    }
+                },
+                DisplayListFormat::Debug => {
+                    println!("{:#?}", display_list.root);
+                },
+            }
         },
         // Action::DisplayFile => // TODO: open window and show the file,
         // Action::RenderToPng(output_path) -- TODO!
     }
 }
 
+fn format_files(check: bool, files: Vec<String>) {
+
+    use azulc_lib::fmt::{format_css, format_xml};
+
+    if files.is_empty() {
+        eprintln!("error: no input file given");
+        print_help();
+        exit(-1);
+    }
+
+    let mut unformatted_files = 0;
+
+    for file in files.iter() {
+
+        let file_contents = match fs::read_to_string(file) {
+            Ok(s) => s,
+            Err(e) => {
+                eprintln!("error: could not read file: \"{}\" - error:\r\n{}", file, e);
+                exit(-1);
+            },
+        };
+
+        let is_css = Path::new(file).extension().map(|e| e.eq_ignore_ascii_case("css")).unwrap_or(false);
+        let formatted = if is_css { format_css(&file_contents) } else { format_xml(&file_contents) };
+        let formatted = match formatted {
+            Ok(s) => s,
+            Err(e) => {
+                eprintln!("error: could not format file: \"{}\" - error:\r\n{}", file, e);
+                exit(-1);
+            },
+        };
+
+        if formatted == file_contents {
+            continue;
+        }
+
+        if check {
+            eprintln!("{}: not formatted", file);
+            unformatted_files += 1;
+        } else if let Err(e) = fs::write(file, formatted) {
+            eprintln!("error: could not write file: \"{}\" - error:\r\n{}", file, e);
+            exit(-1);
+        }
+    }
+
+    if unformatted_files > 0 {
+        exit(1);
+    }
+}
+
+fn lint_file(json: bool, file: Option<&String>) {
+
+    use azulc_lib::xml::parse_xml_string;
+    use azulc_lib::lint::lint_xml;
+
+    let input_file = match file {
+        Some(s) => s,
+        None => {
+            eprintln!("error: no input file given");
+            print_help();
+            exit(-1);
+        },
+    };
+
+    let file_contents = match fs::read_to_string(input_file) {
+        Ok(s) => s,
+        Err(e) => {
+            eprintln!("error: could not read file: \"{}\" - error:\r\n{}", input_file, e);
+            exit(-1);
+        },
+    };
+
+    let root_nodes = match parse_xml_string(&file_contents) {
+        Ok(s) => s,
+        Err(e) => {
+            eprintln!("error: input could not be parsed as xml:\r\n{}", e);
+            exit(-1);
+        }
+    };
+
+    let report = match lint_xml(root_nodes.as_ref()) {
+        Ok(s) => s,
+        Err(e) => {
+            eprintln!("error: could not lint file: \"{}\" - error:\r\n{}", input_file, e);
+            exit(-1);
+        }
+    };
+
+    if json {
+        print!("{}", report.to_json());
+    } else {
+        print!("{}", report.to_text());
+    }
+
+    if report.has_warnings() {
+        exit(1);
+    }
+}
+
+fn diff_display_list_files(strict: bool, files: Vec<String>) {
+
+    use azulc_lib::display_list::{diff_display_lists, load_display_list_dump};
+
+    if files.len() != 2 {
+        eprintln!("error: dl-diff needs exactly two input files");
+        print_help();
+        exit(-1);
+    }
+
+    let dumps = files.iter().map(|file| {
+        let bytes = match fs::read(file) {
+            Ok(s) => s,
+            Err(e) => {
+                eprintln!("error: could not read file: \"{}\" - error:\r\n{}", file, e);
+                exit(-1);
+            },
+        };
+        match load_display_list_dump(&bytes) {
+            Ok(s) => s,
+            Err(e) => {
+                eprintln!("error: could not load display list: \"{}\" - error:\r\n{}", file, e);
+                exit(-1);
+            },
+        }
+    }).collect::<Vec<_>>();
+
+    let diffs = diff_display_lists(&dumps[0], &dumps[1], strict);
+
+    for diff in diffs.iter() {
+        println!("{}", diff);
+    }
+
+    if !diffs.is_empty() {
+        exit(1);
+    }
+}
+
 fn solve_layout(
     styled_dom: StyledDom,
     size: LogicalSize,
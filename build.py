@@ -804,11 +804,13 @@ def sort_structs_map(api_data, structs_map):
         "AzDomVec": {"type": "struct", "name": "AzDom"},
         "AzMenuItemVec": {"type": "struct", "name": "AzMenuItem"},
         "AzXmlNodeVec": {"type": "struct", "name": "AzXmlNode"},
+        "AzStyleCursorVec": {"type": "struct", "name": "AzStyleCursor"},
     }
     forward_delcarations = OrderedDict([
         ("AzDomVec", "Dom"),
         ("AzXmlNodeVec", "XmlNode"),
-        ("AzMenuItemVec", "MenuItem")
+        ("AzMenuItemVec", "MenuItem"),
+        ("AzStyleCursorVec", "StyleCursor")
     ])
 
     classes_not_found = OrderedDict([])
@@ -1309,6 +1311,11 @@ def generate_python_api(api_data, structs_map, functions_map):
         ("menu", "StringMenuItem", "with_callback"),
         ("menu", "StringMenuItem", "set_callback"),
         ("menu", "MenuCallback", "new"),
+        ("window", "Notification", "with_action"),
+        ("window", "Notification", "with_click_callback"),
+        ("window", "WindowResultCallback", "new"),
+        ("window", "Shortcut", "new"),
+        ("callbacks", "CallbackInfo", "set_window_result"),
         ("gl", "Texture", "draw_tesselated_svg_gpu_node"),
         ("gl", "Texture", "draw_tesselated_colored_svg_gpu_node"),
         ("svg", "TessellatedGPUSvgNode", "new"),